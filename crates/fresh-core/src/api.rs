@@ -708,6 +708,8 @@ pub struct EditorStateSnapshot {
     pub buffer_saved_diffs: HashMap<BufferId, BufferSavedDiff>,
     /// Primary cursor position for the active buffer
     pub primary_cursor: Option<CursorInfo>,
+    /// Line (0-indexed) of the primary cursor in the active buffer, if known
+    pub primary_cursor_line: Option<usize>,
     /// All cursor positions for the active buffer
     pub all_cursors: Vec<CursorInfo>,
     /// Viewport information for the active buffer
@@ -764,6 +766,7 @@ impl EditorStateSnapshot {
             buffers: HashMap::new(),
             buffer_saved_diffs: HashMap::new(),
             primary_cursor: None,
+            primary_cursor_line: None,
             all_cursors: Vec::new(),
             viewport: None,
             buffer_cursor_positions: HashMap::new(),
@@ -819,6 +822,13 @@ pub enum PluginCommand {
         range: Range<usize>,
     },
 
+    /// Replace a range of text in a buffer as a single undo step
+    ReplaceRange {
+        buffer_id: BufferId,
+        range: Range<usize>,
+        text: String,
+    },
+
     /// Add an overlay to a buffer, returns handle via response channel
     ///
     /// Colors can be specified as RGB tuples or theme keys. When theme keys
@@ -840,6 +850,21 @@ pub enum PluginCommand {
     /// Set status message
     SetStatus { message: String },
 
+    /// Set the text of a named status bar segment
+    /// The segment shows wherever its name is listed in the `status_bar` config;
+    /// clicking it runs `command` if given
+    SetStatusBarSegment {
+        name: String,
+        text: String,
+        command: Option<String>,
+    },
+
+    /// Remove a status bar segment set with SetStatusBarSegment
+    RemoveStatusBarSegment { name: String },
+
+    /// Append a line to the plugin log (errors, slow handlers, rejected edits)
+    LogPluginIssue { message: String },
+
     /// Apply a theme by name
     ApplyTheme { theme_name: String },
 
@@ -1198,6 +1223,10 @@ pub enum PluginCommand {
     /// Remove a top-level menu
     RemoveMenu { menu_label: String },
 
+    /// A plugin was unloaded; `actions` are the action names it had registered
+    /// (menu items running them are removed)
+    PluginUnloaded { name: String, actions: Vec<String> },
+
     /// Create a new virtual buffer (not backed by a file)
     CreateVirtualBuffer {
         /// Display name (e.g., "*Diagnostics*")
@@ -1558,6 +1587,17 @@ pub enum PluginCommand {
         path: PathBuf,
     },
 
+    /// Replace byte ranges in a file (async)
+    /// Edits the open buffer if the file is open, otherwise rewrites the file on disk
+    ReplaceInFile {
+        /// File to edit
+        path: PathBuf,
+        /// Non-overlapping edits, in any order
+        edits: Vec<FileEdit>,
+        /// Callback ID for async response (number of edits applied)
+        callback_id: JsCallbackId,
+    },
+
     /// Load a plugin from a file path
     /// The plugin will be initialized and start receiving events
     LoadPlugin {
@@ -1859,6 +1899,19 @@ pub struct DirEntry {
     pub is_dir: bool,
 }
 
+/// Byte range replacement passed to replaceInFile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(deny_unknown_fields)]
+#[ts(export)]
+pub struct FileEdit {
+    /// Start byte offset
+    pub start: usize,
+    /// End byte offset (exclusive)
+    pub end: usize,
+    /// Replacement text
+    pub text: String,
+}
+
 /// Position in a document (line and character)
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
@@ -2200,6 +2253,16 @@ mod fromjs_impls {
         }
     }
 
+    impl<'js> FromJs<'js> for FileEdit {
+        fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
+            rquickjs_serde::from_value(value).map_err(|e| rquickjs::Error::FromJs {
+                from: "object",
+                to: "FileEdit",
+                message: Some(e.to_string()),
+            })
+        }
+    }
+
     impl<'js> FromJs<'js> for LanguagePackConfig {
         fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
             rquickjs_serde::from_value(value).map_err(|e| rquickjs::Error::FromJs {
@@ -2312,6 +2375,20 @@ impl PluginApi {
         self.send_command(PluginCommand::DeleteRange { buffer_id, range })
     }
 
+    /// Replace a range of text in a buffer as a single undo step
+    pub fn replace_range(
+        &self,
+        buffer_id: BufferId,
+        range: Range<usize>,
+        text: String,
+    ) -> Result<(), String> {
+        self.send_command(PluginCommand::ReplaceRange {
+            buffer_id,
+            range,
+            text,
+        })
+    }
+
     /// Add an overlay (decoration) to a buffer
     /// Add an overlay to a buffer with styling options
     ///
@@ -3088,6 +3165,25 @@ mod tests {
         assert!(json.contains("456"));
     }
 
+    #[test]
+    fn test_plugin_command_replace_in_file() {
+        let command = PluginCommand::ReplaceInFile {
+            path: PathBuf::from("/tmp/a.rs"),
+            edits: vec![FileEdit {
+                start: 3,
+                end: 6,
+                text: "bar".to_string(),
+            }],
+            callback_id: JsCallbackId::new(7),
+        };
+        let json = serde_json::to_string(&command).unwrap();
+        assert!(json.contains("ReplaceInFile"));
+        assert!(json.contains("bar"));
+
+        let invalid = r#"{"start": 0, "end": 1, "replacement": "x"}"#;
+        assert!(serde_json::from_str::<FileEdit>(invalid).is_err());
+    }
+
     #[test]
     fn test_plugin_command_scroll_to_line_center() {
         let command = PluginCommand::ScrollToLineCenter {
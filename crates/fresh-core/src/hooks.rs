@@ -188,6 +188,13 @@ pub enum HookArgs {
         content_x: u16,
         /// Content area Y offset
         content_y: u16,
+        /// Buffer under the click, when it landed in a buffer's text area
+        buffer_id: Option<BufferId>,
+        /// Byte offset the click maps to (clamped to the line end past EOL)
+        byte_position: Option<usize>,
+        /// Column of the click within its visual row, after the gutter and
+        /// horizontal scroll; counts virtual text, unlike `byte_position`
+        text_column: Option<usize>,
     },
 
     /// Mouse move/hover event
@@ -266,6 +273,13 @@ pub enum HookArgs {
         /// The new language identifier (e.g., "markdown", "rust", "text")
         language: String,
     },
+
+    /// Entries were created, deleted or renamed in a directory shown in the
+    /// file explorer
+    FileExplorerPathsChanged {
+        /// Paths that were added or removed
+        paths: Vec<PathBuf>,
+    },
 }
 
 /// Information about a single line for the LinesChanged hook
@@ -607,6 +621,9 @@ pub fn hook_args_to_json(args: &HookArgs) -> Result<serde_json::Value> {
             modifiers,
             content_x,
             content_y,
+            buffer_id,
+            byte_position,
+            text_column,
         } => {
             serde_json::json!({
                 "column": column,
@@ -615,6 +632,9 @@ pub fn hook_args_to_json(args: &HookArgs) -> Result<serde_json::Value> {
                 "modifiers": modifiers,
                 "content_x": content_x,
                 "content_y": content_y,
+                "buffer_id": buffer_id.map(|id| id.0),
+                "byte_position": byte_position,
+                "text_column": text_column,
             })
         }
         HookArgs::MouseMove {
@@ -706,6 +726,12 @@ pub fn hook_args_to_json(args: &HookArgs) -> Result<serde_json::Value> {
                 "language": language,
             })
         }
+        HookArgs::FileExplorerPathsChanged { paths } => {
+            let paths: Vec<_> = paths.iter().map(|p| p.to_string_lossy()).collect();
+            serde_json::json!({
+                "paths": paths,
+            })
+        }
     };
 
     Ok(json_value)
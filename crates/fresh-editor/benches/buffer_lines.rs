@@ -0,0 +1,124 @@
+//! Benchmarks for byte offset ↔ line number conversions.
+//!
+//! Covers a small buffer, a 10 MB buffer below the large file threshold, and
+//! a large-file-mode buffer before and after its line scan.
+//!
+//! Run with `cargo bench --bench buffer_lines`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use fresh::model::buffer::TextBuffer;
+use fresh::model::filesystem::{FileSystem, StdFileSystem};
+use std::sync::Arc;
+
+const MB: usize = 1024 * 1024;
+
+/// Lines of varying length, `size` bytes in total
+fn make_content(size: usize) -> Vec<u8> {
+    let mut content = Vec::with_capacity(size + 200);
+    let mut i = 0;
+    while content.len() < size {
+        content.extend(std::iter::repeat(b'x').take((i * 37) % 150));
+        content.push(b'\n');
+        i += 1;
+    }
+    content.truncate(size);
+    content
+}
+
+fn fs() -> Arc<dyn FileSystem + Send + Sync> {
+    Arc::new(StdFileSystem)
+}
+
+/// Buffers to measure, with the file backing the large one
+fn buffers() -> (Vec<(&'static str, TextBuffer)>, tempfile::NamedTempFile) {
+    let small = TextBuffer::from_bytes(make_content(64 * 1024), fs());
+    let medium = TextBuffer::from_bytes(make_content(10 * MB), fs());
+
+    let file = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(file.path(), make_content(32 * MB)).unwrap();
+    // A threshold below the file size loads it in large file mode
+    let unscanned = TextBuffer::load_from_file(file.path(), MB, fs()).unwrap();
+    let mut scanned = TextBuffer::load_from_file(file.path(), MB, fs()).unwrap();
+    scanned.scan_line_feeds().unwrap();
+
+    (
+        vec![
+            ("small", small),
+            ("medium_10mb", medium),
+            ("large_unscanned", unscanned),
+            ("large_scanned", scanned),
+        ],
+        file,
+    )
+}
+
+/// Offsets spread over the buffer
+fn offsets(len: usize) -> Vec<usize> {
+    (1..=16).map(|i| len / 17 * i).collect()
+}
+
+fn bench_get_line_number(c: &mut Criterion) {
+    let (buffers, _file) = buffers();
+    let mut group = c.benchmark_group("get_line_number");
+    for (name, buffer) in &buffers {
+        let offsets = offsets(buffer.len());
+        group.bench_with_input(BenchmarkId::new("exact", name), buffer, |b, buffer| {
+            b.iter(|| {
+                for &offset in &offsets {
+                    black_box(buffer.get_line_number_exact(black_box(offset)));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("any", name), buffer, |b, buffer| {
+            b.iter(|| {
+                for &offset in &offsets {
+                    black_box(buffer.get_line_number(black_box(offset)));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_line_start_offset(c: &mut Criterion) {
+    let (buffers, _file) = buffers();
+    let mut group = c.benchmark_group("line_start_offset");
+    for (name, buffer) in &buffers {
+        let lines: Vec<usize> = offsets(buffer.len())
+            .into_iter()
+            .map(|offset| buffer.get_line_number(offset))
+            .collect();
+        group.bench_with_input(BenchmarkId::from_parameter(name), buffer, |b, buffer| {
+            b.iter(|| {
+                for &line in &lines {
+                    black_box(buffer.line_start_offset(black_box(line)));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_slice_bytes(c: &mut Criterion) {
+    let (buffers, _file) = buffers();
+    let mut group = c.benchmark_group("slice_bytes");
+    for (name, buffer) in &buffers {
+        let offsets = offsets(buffer.len().saturating_sub(4096));
+        group.bench_with_input(BenchmarkId::from_parameter(name), buffer, |b, buffer| {
+            b.iter(|| {
+                for &offset in &offsets {
+                    black_box(buffer.slice_bytes(offset..offset + 4096));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_get_line_number,
+    bench_line_start_offset,
+    bench_slice_bytes
+);
+criterion_main!(benches);
@@ -0,0 +1,567 @@
+/// <reference path="./lib/fresh.d.ts" />
+
+/**
+ * Diff View Plugin
+ *
+ * Read-only diff between two versions of a text:
+ * - Diff Against Saved: the active buffer against its file on disk
+ * - Diff Two Buffers: the active buffer against another open buffer
+ *
+ * `editor.diff_view_layout` picks the layout:
+ * - "side-by-side": old and new text in two splits tied by a scroll sync
+ *   group, with blank rows padding out insertions and deletions so both
+ *   sides stay aligned line for line
+ * - "unified": one pane with removed lines above the lines that replace them
+ *
+ * Changed lines are tinted, and for modified lines the changed span is
+ * highlighted. Enter jumps to the line in the editable buffer; q closes.
+ */
+
+import { diffLines, splitLines } from "./lib/line-diff.ts";
+
+const editor = getEditor();
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type DiffLayout = "side-by-side" | "unified";
+
+/** One version of the text being compared */
+interface DiffSide {
+  /** Pane title */
+  label: string;
+  /** Lines including terminators (used for diffing and byte offsets) */
+  rawLines: string[];
+  /** Buffer Enter jumps to from this side; null for text read from disk */
+  bufferId: number | null;
+}
+
+/** One aligned row: a line from each side, null where that side is padded */
+interface DiffRow {
+  kind: "context" | "added" | "removed" | "modified";
+  oldLine: number | null;
+  newLine: number | null;
+  /** Nearest old line, for jumping from padding */
+  oldAnchor: number;
+  /** Nearest new line, for jumping from padding */
+  newAnchor: number;
+}
+
+interface DiffViewState {
+  old: DiffSide;
+  new: DiffSide;
+  rows: DiffRow[];
+  /** Split the view replaced, where Enter and q return to */
+  sourceSplitId: number;
+  /** Buffers showing the diff (one for unified, two for side-by-side) */
+  paneBufferIds: number[];
+  /** Split opened for the new side (side-by-side only) */
+  newSplitId: number | null;
+}
+
+// =============================================================================
+// State
+// =============================================================================
+
+const NAMESPACE = "diff-view";
+
+/** Scroll sync group tying the two side-by-side panes */
+const SCROLL_SYNC_GROUP = 0x6466;
+
+let diffState: DiffViewState | null = null;
+
+/** Buffer a "Diff Two Buffers" prompt compares against */
+let pendingBaseBufferId: number | null = null;
+
+editor.defineMode(
+  "diff-view-pane",
+  "normal",
+  [
+    ["Enter", "diff_view_goto"],
+    ["q", "diff_view_close"],
+    ["Escape", "diff_view_close"],
+  ],
+  true // read-only
+);
+
+// =============================================================================
+// Alignment
+// =============================================================================
+
+/**
+ * Strip a line's terminator for display
+ */
+function displayText(rawLine: string | undefined): string {
+  return (rawLine ?? "").replace(/\r?\n$/, "");
+}
+
+/**
+ * Pair the two sides into aligned rows. Within a hunk, old and new lines are
+ * paired up as modifications; the longer side's remainder is padded.
+ */
+function buildRows(oldLines: string[], newLines: string[]): DiffRow[] {
+  const rows: DiffRow[] = [];
+  let oldLine = 0;
+  let newLine = 0;
+
+  const context = (until: number): void => {
+    while (oldLine < until) {
+      rows.push({
+        kind: "context",
+        oldLine,
+        newLine,
+        oldAnchor: oldLine,
+        newAnchor: newLine,
+      });
+      oldLine++;
+      newLine++;
+    }
+  };
+
+  for (const hunk of diffLines(oldLines, newLines)) {
+    context(hunk.oldStart);
+    const oldEnd = hunk.oldStart + hunk.oldCount;
+    const newEnd = hunk.newStart + hunk.newCount;
+    for (let i = 0; i < Math.max(hunk.oldCount, hunk.newCount); i++) {
+      const o = i < hunk.oldCount ? hunk.oldStart + i : null;
+      const n = i < hunk.newCount ? hunk.newStart + i : null;
+      rows.push({
+        kind: o !== null && n !== null ? "modified" : o !== null ? "removed" : "added",
+        oldLine: o,
+        newLine: n,
+        oldAnchor: o ?? oldEnd,
+        newAnchor: n ?? newEnd,
+      });
+    }
+    oldLine = oldEnd;
+    newLine = newEnd;
+  }
+  context(oldLines.length);
+
+  return rows;
+}
+
+/**
+ * Character span that differs between two versions of a line, found by
+ * trimming their common prefix and suffix
+ */
+function changedSpan(oldText: string, newText: string): { old: [number, number]; new: [number, number] } {
+  let prefix = 0;
+  while (prefix < oldText.length && prefix < newText.length && oldText[prefix] === newText[prefix]) {
+    prefix++;
+  }
+  let suffix = 0;
+  while (
+    suffix < oldText.length - prefix &&
+    suffix < newText.length - prefix &&
+    oldText[oldText.length - 1 - suffix] === newText[newText.length - 1 - suffix]
+  ) {
+    suffix++;
+  }
+  return {
+    old: [prefix, oldText.length - suffix],
+    new: [prefix, newText.length - suffix],
+  };
+}
+
+// =============================================================================
+// Rendering
+// =============================================================================
+
+/** A rendered line of a diff pane */
+interface PaneLine {
+  /** Gutter text (line numbers and marker) */
+  gutter: string;
+  text: string;
+  /** Line tint theme key, null for context */
+  bg: string | null;
+  /** Highlighted character span within text, for modified lines */
+  span: [number, number] | null;
+  spanBg: string | null;
+  rowIndex: number;
+  side: "old" | "new";
+}
+
+function lineNumber(line: number | null, width: number): string {
+  return (line === null ? "" : String(line + 1)).padStart(width);
+}
+
+/**
+ * Lines for one side of the side-by-side layout
+ */
+function sideBySideLines(state: DiffViewState, side: "old" | "new"): PaneLine[] {
+  const source = side === "old" ? state.old : state.new;
+  const width = String(source.rawLines.length).length;
+
+  return state.rows.map((row, rowIndex) => {
+    const line = side === "old" ? row.oldLine : row.newLine;
+    const text = line === null ? "" : displayText(source.rawLines[line]);
+    let bg: string | null = null;
+    let span: [number, number] | null = null;
+    if (row.kind === "modified") {
+      bg = "editor.diff_modify_bg";
+      const changed = changedSpan(
+        displayText(state.old.rawLines[row.oldLine as number]),
+        displayText(state.new.rawLines[row.newLine as number])
+      );
+      span = side === "old" ? changed.old : changed.new;
+    } else if (line !== null && row.kind !== "context") {
+      bg = side === "old" ? "editor.diff_remove_bg" : "editor.diff_add_bg";
+    }
+    return {
+      gutter: `${lineNumber(line, width)} `,
+      text,
+      bg,
+      span,
+      spanBg: side === "old" ? "editor.diff_remove_highlight_bg" : "editor.diff_add_highlight_bg",
+      rowIndex,
+      side,
+    };
+  });
+}
+
+/**
+ * Lines for the unified layout: each hunk's old lines, then its new lines
+ */
+function unifiedLines(state: DiffViewState): PaneLine[] {
+  const oldWidth = String(state.old.rawLines.length).length;
+  const newWidth = String(state.new.rawLines.length).length;
+  const lines: PaneLine[] = [];
+
+  const push = (rowIndex: number, side: "old" | "new", marker: string): void => {
+    const row = state.rows[rowIndex];
+    const line = side === "old" ? row.oldLine : row.newLine;
+    const source = side === "old" ? state.old : state.new;
+    let span: [number, number] | null = null;
+    if (row.kind === "modified") {
+      const changed = changedSpan(
+        displayText(state.old.rawLines[row.oldLine as number]),
+        displayText(state.new.rawLines[row.newLine as number])
+      );
+      span = side === "old" ? changed.old : changed.new;
+    }
+    lines.push({
+      gutter: `${lineNumber(row.kind === "context" || side === "old" ? row.oldLine : null, oldWidth)} ` +
+        `${lineNumber(side === "new" ? row.newLine : null, newWidth)} ${marker} `,
+      text: displayText(source.rawLines[line as number]),
+      bg: marker === " " ? null : side === "old" ? "editor.diff_remove_bg" : "editor.diff_add_bg",
+      span,
+      spanBg: side === "old" ? "editor.diff_remove_highlight_bg" : "editor.diff_add_highlight_bg",
+      rowIndex,
+      side,
+    });
+  };
+
+  let i = 0;
+  while (i < state.rows.length) {
+    if (state.rows[i].kind === "context") {
+      push(i, "new", " ");
+      i++;
+      continue;
+    }
+    let end = i;
+    while (end < state.rows.length && state.rows[end].kind !== "context") {
+      end++;
+    }
+    for (let r = i; r < end; r++) {
+      if (state.rows[r].oldLine !== null) push(r, "old", "-");
+    }
+    for (let r = i; r < end; r++) {
+      if (state.rows[r].newLine !== null) push(r, "new", "+");
+    }
+    i = end;
+  }
+
+  return lines;
+}
+
+function paneEntries(lines: PaneLine[]): TextPropertyEntry[] {
+  return lines.map((line, i) => ({
+    text: line.gutter + line.text + (i < lines.length - 1 ? "\n" : ""),
+    properties: { type: "diff-line", row: line.rowIndex, side: line.side },
+  }));
+}
+
+/**
+ * Tint changed lines and highlight the changed span of modified lines
+ */
+function applyHighlights(bufferId: number, lines: PaneLine[]): void {
+  editor.clearNamespace(bufferId, NAMESPACE);
+
+  let offset = 0;
+  for (const line of lines) {
+    const gutterLength = editor.utf8ByteLength(line.gutter);
+    const textLength = editor.utf8ByteLength(line.text);
+
+    editor.addOverlay(bufferId, NAMESPACE, offset, offset + gutterLength, {
+      fg: "editor.line_number_fg",
+    });
+
+    const textStart = offset + gutterLength;
+    if (line.bg !== null) {
+      editor.addOverlay(bufferId, NAMESPACE, textStart, textStart + textLength, {
+        bg: line.bg,
+        extend_to_line_end: true,
+      });
+    }
+    if (line.span !== null && line.span[1] > line.span[0]) {
+      const start = textStart + editor.utf8ByteLength(line.text.slice(0, line.span[0]));
+      const end = textStart + editor.utf8ByteLength(line.text.slice(0, line.span[1]));
+      editor.addOverlay(bufferId, NAMESPACE, start, end, { bg: line.spanBg });
+    }
+
+    offset = textStart + textLength + 1;
+  }
+}
+
+// =============================================================================
+// Opening and Closing
+// =============================================================================
+
+function configuredLayout(): DiffLayout {
+  const config = editor.getConfig() as { editor?: { diff_view_layout?: string } };
+  return config.editor?.diff_view_layout === "unified" ? "unified" : "side-by-side";
+}
+
+/**
+ * Open a diff of two sides in place of the active split
+ */
+async function openDiffView(old: DiffSide, current: DiffSide): Promise<void> {
+  closeDiffView();
+
+  const rows = buildRows(old.rawLines, current.rawLines);
+  const changes = rows.filter((row) => row.kind !== "context").length;
+  if (changes === 0) {
+    editor.setStatus(editor.t("status.no_differences"));
+    return;
+  }
+
+  const sourceSplitId = editor.getActiveSplitId();
+  const state: DiffViewState = {
+    old,
+    new: current,
+    rows,
+    sourceSplitId,
+    paneBufferIds: [],
+    newSplitId: null,
+  };
+  const paneOptions = {
+    mode: "diff-view-pane",
+    readOnly: true,
+    showLineNumbers: false,
+    editingDisabled: true,
+    lineWrap: false,
+  };
+
+  if (configuredLayout() === "unified") {
+    const lines = unifiedLines(state);
+    const result = await editor.createVirtualBufferInExistingSplit({
+      ...paneOptions,
+      name: `*Diff: ${old.label} → ${current.label}*`,
+      splitId: sourceSplitId,
+      entries: paneEntries(lines),
+    });
+    state.paneBufferIds.push(result.bufferId);
+    applyHighlights(result.bufferId, lines);
+  } else {
+    const oldLines = sideBySideLines(state, "old");
+    const newLines = sideBySideLines(state, "new");
+    const oldResult = await editor.createVirtualBufferInExistingSplit({
+      ...paneOptions,
+      name: `*${old.label}*`,
+      splitId: sourceSplitId,
+      entries: paneEntries(oldLines),
+    });
+    state.paneBufferIds.push(oldResult.bufferId);
+
+    const newResult = await editor.createVirtualBufferInSplit({
+      ...paneOptions,
+      name: `*${current.label}*`,
+      ratio: 0.5,
+      direction: "vertical",
+      entries: paneEntries(newLines),
+    });
+    state.paneBufferIds.push(newResult.bufferId);
+    state.newSplitId = newResult.splitId;
+
+    applyHighlights(oldResult.bufferId, oldLines);
+    applyHighlights(newResult.bufferId, newLines);
+
+    if (newResult.splitId !== null) {
+      editor.createScrollSyncGroup(SCROLL_SYNC_GROUP, sourceSplitId, newResult.splitId);
+    }
+  }
+
+  diffState = state;
+  editor.setStatus(editor.t("status.opened", { count: String(changes) }));
+}
+
+/**
+ * Close the diff view, leaving the split on the buffer it showed before
+ */
+function closeDiffView(): void {
+  const state = diffState;
+  if (!state) return;
+  diffState = null;
+
+  if (state.newSplitId !== null) {
+    editor.removeScrollSyncGroup(SCROLL_SYNC_GROUP);
+    editor.closeSplit(state.newSplitId);
+  }
+  for (const bufferId of state.paneBufferIds) {
+    editor.closeBuffer(bufferId);
+  }
+  editor.focusSplit(state.sourceSplitId);
+}
+
+/**
+ * Byte offset of a line start, from a side's lines
+ */
+function lineOffset(side: DiffSide, line: number): number {
+  let offset = 0;
+  for (let i = 0; i < line && i < side.rawLines.length; i++) {
+    offset += editor.utf8ByteLength(side.rawLines[i]);
+  }
+  return offset;
+}
+
+// =============================================================================
+// Commands
+// =============================================================================
+
+function makeSide(label: string, text: string, bufferId: number | null): DiffSide {
+  return { label, rawLines: splitLines(text), bufferId };
+}
+
+function bufferLabel(bufferId: number): string {
+  const path = editor.getBufferPath(bufferId);
+  return path ? editor.pathBasename(path) : editor.t("label.untitled", { id: String(bufferId) });
+}
+
+async function bufferText(bufferId: number): Promise<string> {
+  return await editor.getBufferText(bufferId, 0, editor.getBufferLength(bufferId));
+}
+
+globalThis.diff_against_saved = async function (): Promise<void> {
+  const bufferId = editor.getActiveBufferId();
+  const path = editor.getBufferPath(bufferId);
+  if (!path) {
+    editor.setStatus(editor.t("status.no_file"));
+    return;
+  }
+
+  const saved = editor.readFile(path);
+  if (saved === null) {
+    editor.setStatus(editor.t("status.read_failed", { path }));
+    return;
+  }
+
+  const name = editor.pathBasename(path);
+  await openDiffView(
+    makeSide(editor.t("label.saved", { name }), saved, null),
+    makeSide(editor.t("label.buffer", { name }), await bufferText(bufferId), bufferId)
+  );
+};
+
+globalThis.diff_two_buffers = function (): void {
+  const baseId = editor.getActiveBufferId();
+  const others = editor
+    .listBuffers()
+    .filter((buffer) => buffer.id !== baseId && !buffer.is_virtual);
+  if (others.length === 0) {
+    editor.setStatus(editor.t("status.no_other_buffers"));
+    return;
+  }
+
+  pendingBaseBufferId = baseId;
+  editor.startPrompt(editor.t("prompt.pick_buffer", { name: bufferLabel(baseId) }), "diff-two-buffers");
+  editor.setPromptSuggestions(
+    others.map((buffer) => ({
+      text: bufferLabel(buffer.id),
+      description: buffer.path || undefined,
+      value: String(buffer.id),
+    }))
+  );
+};
+
+globalThis.onDiffViewPromptConfirmed = async function (args: {
+  prompt_type: string;
+  input: string;
+}): Promise<boolean> {
+  if (args.prompt_type !== "diff-two-buffers") return true;
+
+  const baseId = pendingBaseBufferId;
+  pendingBaseBufferId = null;
+  const otherId = parseInt(args.input, 10);
+  if (baseId === null || isNaN(otherId) || !editor.getBufferInfo(otherId)) {
+    editor.setStatus(editor.t("status.no_buffer", { input: args.input }));
+    return true;
+  }
+
+  await openDiffView(
+    makeSide(bufferLabel(baseId), await bufferText(baseId), baseId),
+    makeSide(bufferLabel(otherId), await bufferText(otherId), otherId)
+  );
+  return true;
+};
+
+globalThis.onDiffViewPromptCancelled = function (args: { prompt_type: string }): boolean {
+  if (args.prompt_type === "diff-two-buffers") {
+    pendingBaseBufferId = null;
+  }
+  return true;
+};
+
+/**
+ * Jump from the diff line under the cursor to that line in the editable buffer
+ */
+globalThis.diff_view_goto = function (): void {
+  const state = diffState;
+  if (!state) return;
+
+  const props = editor.getTextPropertiesAtCursor(editor.getActiveBufferId());
+  const row = props.length > 0 ? state.rows[props[0].row as number] : undefined;
+  if (!row) return;
+
+  // Text read from disk has no buffer of its own; its lines map onto the
+  // buffer being compared with it
+  let side = props[0].side === "old" ? state.old : state.new;
+  let line = side === state.old ? row.oldAnchor : row.newAnchor;
+  if (side.bufferId === null) {
+    side = state.new;
+    line = row.newAnchor;
+  }
+  const bufferId = side.bufferId as number;
+  line = Math.max(0, Math.min(line, side.rawLines.length - 1));
+
+  closeDiffView();
+  editor.showBuffer(bufferId);
+  editor.setBufferCursor(bufferId, lineOffset(side, line));
+  editor.scrollToLineCenter(state.sourceSplitId, bufferId, line);
+};
+
+globalThis.diff_view_close = function (): void {
+  closeDiffView();
+};
+
+globalThis.onDiffViewBufferClosed = function (data: { buffer_id: number }): boolean {
+  // A pane was closed some other way (e.g. its tab); take the rest down too
+  if (diffState && diffState.paneBufferIds.includes(data.buffer_id)) {
+    diffState.paneBufferIds = diffState.paneBufferIds.filter((id) => id !== data.buffer_id);
+    closeDiffView();
+  }
+  return true;
+};
+
+// =============================================================================
+// Registration
+// =============================================================================
+
+editor.registerCommand("%cmd.diff_against_saved", "%cmd.diff_against_saved_desc", "diff_against_saved", null);
+editor.registerCommand("%cmd.diff_two_buffers", "%cmd.diff_two_buffers_desc", "diff_two_buffers", null);
+editor.registerCommand("%cmd.diff_view_close", "%cmd.diff_view_close_desc", "diff_view_close", "diff-view-pane");
+
+editor.on("prompt_confirmed", "onDiffViewPromptConfirmed");
+editor.on("prompt_cancelled", "onDiffViewPromptCancelled");
+editor.on("buffer_closed", "onDiffViewBufferClosed");
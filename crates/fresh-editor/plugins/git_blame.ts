@@ -21,6 +21,14 @@ const editor = getEditor();
  * - 'q' to close the blame view
  * - 'y' to yank (copy) the commit hash at cursor
  *
+ * Also provides:
+ * - Line blame: "author, date • summary" dimmed at the end of the cursor line
+ * - Blame File: a read-only split beside the file, scrolled in step with it,
+ *   showing commit, author and age per line, colored from old (cold) to new (hot)
+ *
+ * Both stream `git blame --porcelain` from a background process (under the
+ * editor's process limits) and cache the result per file until it is saved.
+ *
  * Inspired by magit's git-blame-additions feature.
  */
 
@@ -112,6 +120,91 @@ editor.defineMode(
 // Git Blame Parsing
 // =============================================================================
 
+/** Commit metadata; porcelain output only prints it the first time a commit appears */
+interface CommitInfo {
+  author: string;
+  authorTime: string;
+  summary: string;
+}
+
+/**
+ * Incremental parser for git blame --porcelain output. Lines can be fed as
+ * they stream in; each completed blame line is appended to `lines`.
+ */
+interface PorcelainParser {
+  lines: BlameLine[];
+  commits: Map<string, CommitInfo>;
+  hash: string;
+  origLine: number;
+  finalLine: number;
+  info: CommitInfo;
+  /** Called for each completed blame line */
+  onLine: ((line: BlameLine) => void) | null;
+}
+
+function createPorcelainParser(onLine: ((line: BlameLine) => void) | null = null): PorcelainParser {
+  return {
+    lines: [],
+    commits: new Map(),
+    hash: "",
+    origLine: 0,
+    finalLine: 0,
+    info: { author: "", authorTime: "", summary: "" },
+    onLine,
+  };
+}
+
+/**
+ * Feed one line of git blame --porcelain output to the parser
+ */
+function feedPorcelainLine(parser: PorcelainParser, line: string): void {
+  // Check for commit line: <hash> <orig-line> <final-line> [num-lines]
+  const commitMatch = line.match(/^([a-f0-9]{40}) (\d+) (\d+)/);
+  if (commitMatch) {
+    parser.hash = commitMatch[1];
+    parser.origLine = parseInt(commitMatch[2], 10);
+    parser.finalLine = parseInt(commitMatch[3], 10);
+
+    // Reuse this commit's info if we've seen it before
+    const cached = parser.commits.get(parser.hash);
+    parser.info = cached ?? { author: "", authorTime: "", summary: "" };
+    return;
+  }
+
+  // Parse header fields
+  if (line.startsWith("author ")) {
+    parser.info.author = line.slice(7);
+    return;
+  }
+  if (line.startsWith("author-time ")) {
+    parser.info.authorTime = line.slice(12);
+    return;
+  }
+  if (line.startsWith("summary ")) {
+    parser.info.summary = line.slice(8);
+    // Cache this commit's info
+    parser.commits.set(parser.hash, parser.info);
+    return;
+  }
+
+  // Content line (starts with tab)
+  if (line.startsWith("\t")) {
+    const blameLine: BlameLine = {
+      hash: parser.hash,
+      shortHash: parser.hash.slice(0, 7),
+      author: parser.info.author,
+      authorTime: parser.info.authorTime,
+      relativeDate: formatRelativeDate(parseInt(parser.info.authorTime, 10)),
+      summary: parser.info.summary,
+      lineNumber: parser.origLine,
+      finalLineNumber: parser.finalLine,
+      content: line.slice(1),
+    };
+    parser.lines.push(blameLine);
+    parser.onLine?.(blameLine);
+  }
+}
+
 /**
  * Parse git blame --porcelain output
  */
@@ -131,82 +224,12 @@ async function fetchGitBlame(filePath: string, commit: string | null): Promise<B
     return [];
   }
 
-  const lines: BlameLine[] = [];
-  const output = result.stdout;
-  const outputLines = output.split("\n");
-
-  let currentHash = "";
-  let currentAuthor = "";
-  let currentAuthorTime = "";
-  let currentSummary = "";
-  let currentOrigLine = 0;
-  let currentFinalLine = 0;
-
-  // Cache for commit info to avoid redundant parsing
-  const commitInfo: Map<string, { author: string; authorTime: string; summary: string }> = new Map();
-
-  for (let i = 0; i < outputLines.length; i++) {
-    const line = outputLines[i];
-
-    // Check for commit line: <hash> <orig-line> <final-line> [num-lines]
-    const commitMatch = line.match(/^([a-f0-9]{40}) (\d+) (\d+)/);
-    if (commitMatch) {
-      currentHash = commitMatch[1];
-      currentOrigLine = parseInt(commitMatch[2], 10);
-      currentFinalLine = parseInt(commitMatch[3], 10);
-
-      // Check cache for this commit's info
-      const cached = commitInfo.get(currentHash);
-      if (cached) {
-        currentAuthor = cached.author;
-        currentAuthorTime = cached.authorTime;
-        currentSummary = cached.summary;
-      }
-      continue;
-    }
-
-    // Parse header fields
-    if (line.startsWith("author ")) {
-      currentAuthor = line.slice(7);
-      continue;
-    }
-    if (line.startsWith("author-time ")) {
-      currentAuthorTime = line.slice(12);
-      continue;
-    }
-    if (line.startsWith("summary ")) {
-      currentSummary = line.slice(8);
-      // Cache this commit's info
-      commitInfo.set(currentHash, {
-        author: currentAuthor,
-        authorTime: currentAuthorTime,
-        summary: currentSummary,
-      });
-      continue;
-    }
-
-    // Content line (starts with tab)
-    if (line.startsWith("\t")) {
-      const content = line.slice(1);
-
-      // Calculate relative date from author-time
-      const relativeDate = formatRelativeDate(parseInt(currentAuthorTime, 10));
-
-      lines.push({
-        hash: currentHash,
-        shortHash: currentHash.slice(0, 7),
-        author: currentAuthor,
-        authorTime: currentAuthorTime,
-        relativeDate: relativeDate,
-        summary: currentSummary,
-        lineNumber: currentOrigLine,
-        finalLineNumber: currentFinalLine,
-        content: content,
-      });
-    }
+  const parser = createPorcelainParser();
+  for (const line of result.stdout.split("\n")) {
+    feedPorcelainLine(parser, line);
   }
 
-  return lines;
+  return parser.lines;
 }
 
 /**
@@ -669,6 +692,484 @@ globalThis.git_blame_copy_hash = function(): void {
   editor.setStatus(editor.t("status.hash_copied", { short: hash.slice(0, 7), full: hash }));
 };
 
+// =============================================================================
+// Streaming Blame Cache (inline blame and blame file view)
+// =============================================================================
+
+/**
+ * Blame for a saved file, filled in line by line as `git blame --porcelain`
+ * streams its output from a background process
+ */
+interface FileBlame {
+  /** Blame per line of the saved file (0-indexed); holes are still loading */
+  lines: BlameLine[];
+  /** Whether git has finished */
+  done: boolean;
+  /** Whether git failed (untracked file, not a repository, ...) */
+  failed: boolean;
+  /** Background process ID while git is running */
+  processId: number | null;
+}
+
+/** Blame results per file path */
+const blameCache: Map<string, FileBlame> = new Map();
+
+/** Parsers for running blame processes, by process ID */
+const blameRuns: Map<number, PorcelainParser> = new Map();
+
+const NOT_COMMITTED_HASH = "0000000000000000000000000000000000000000";
+
+/**
+ * Get the blame for a file, starting git in the background if it isn't cached.
+ * The result fills in as output arrives; callers re-render when lines land.
+ */
+function getFileBlame(filePath: string): FileBlame {
+  const cached = blameCache.get(filePath);
+  if (cached) return cached;
+
+  const blame: FileBlame = { lines: [], done: false, failed: false, processId: null };
+  blameCache.set(filePath, blame);
+
+  const parser = createPorcelainParser((line) => {
+    blame.lines[line.finalLineNumber - 1] = line;
+    onBlameLineLoaded(filePath, line.finalLineNumber - 1);
+  });
+
+  const lastSlash = filePath.lastIndexOf("/");
+  const cwd = lastSlash > 0 ? filePath.substring(0, lastSlash) : ".";
+  const handle = editor.spawnBackgroundProcess("git", ["blame", "--porcelain", "--", filePath], cwd);
+  blame.processId = handle.processId;
+  blameRuns.set(handle.processId, parser);
+
+  handle.then((result) => {
+    blameRuns.delete(result.process_id);
+    blame.processId = null;
+    blame.done = true;
+    blame.failed = result.exit_code !== 0;
+    onBlameLoaded(filePath);
+  });
+
+  return blame;
+}
+
+/**
+ * Drop the cached blame for a file (after a save), stopping git if it's still running
+ */
+function invalidateFileBlame(filePath: string): void {
+  const blame = blameCache.get(filePath);
+  if (!blame) return;
+
+  if (blame.processId !== null) {
+    blameRuns.delete(blame.processId);
+    editor.killBackgroundProcess(blame.processId);
+  }
+  blameCache.delete(filePath);
+}
+
+/**
+ * Called for each blame line as it streams in
+ */
+function onBlameLineLoaded(filePath: string, line: number): void {
+  if (inlineState.enabled && inlineState.filePath === filePath && inlineState.line === line) {
+    showInlineBlame();
+  }
+}
+
+/**
+ * Called when git blame finishes for a file
+ */
+function onBlameLoaded(filePath: string): void {
+  if (fileViewState.bufferId !== null && fileViewState.filePath === filePath) {
+    renderBlameFileView();
+  }
+}
+
+globalThis.onGitBlameProcessStdout = function (args: {
+  process_id: number;
+  data: string;
+}): boolean {
+  const parser = blameRuns.get(args.process_id);
+  if (parser) {
+    feedPorcelainLine(parser, args.data.replace(/\n$/, ""));
+  }
+  return true;
+};
+
+// =============================================================================
+// Inline Blame (current line)
+// =============================================================================
+
+const INLINE_BLAME_ID = "git-blame-inline";
+
+// Dimmed, so it reads as an annotation rather than code
+const INLINE_BLAME_COLOR = [110, 110, 110] as [number, number, number];
+
+/** Where the inline blame is (or should be, once its line loads) */
+const inlineState = {
+  enabled: false,
+  bufferId: null as number | null,
+  filePath: null as string | null,
+  line: -1,
+};
+
+/**
+ * Format the inline annotation for a blame line
+ */
+function formatInlineBlame(line: BlameLine): string {
+  if (line.hash === NOT_COMMITTED_HASH) {
+    return "    " + editor.t("inline.not_committed");
+  }
+  return "    " + editor.t("inline.blame", {
+    author: line.author,
+    date: formatRelativeDate(parseInt(line.authorTime, 10)),
+    summary: line.summary,
+  });
+}
+
+/**
+ * Remove the inline annotation, if any
+ */
+function clearInlineBlame(): void {
+  if (inlineState.bufferId !== null) {
+    editor.removeVirtualText(inlineState.bufferId, INLINE_BLAME_ID);
+  }
+}
+
+/**
+ * Move the inline annotation to a buffer line (0-indexed)
+ */
+function updateInlineBlame(bufferId: number, line: number): void {
+  clearInlineBlame();
+  inlineState.bufferId = bufferId;
+  inlineState.filePath = null;
+  inlineState.line = line;
+
+  // Blame describes the saved file; line numbers drift once the buffer has
+  // unsaved edits
+  const filePath = editor.getBufferPath(bufferId);
+  if (!inlineState.enabled || !filePath || editor.isBufferModified(bufferId)) {
+    return;
+  }
+  inlineState.filePath = filePath;
+
+  // Blame lines that haven't streamed in yet are shown from onBlameLineLoaded
+  if (getFileBlame(filePath).lines[line]) {
+    showInlineBlame();
+  }
+}
+
+/**
+ * Draw the inline annotation at the end of the tracked line
+ */
+async function showInlineBlame(): Promise<void> {
+  const { bufferId, filePath, line } = inlineState;
+  if (bufferId === null || filePath === null) return;
+
+  const blameLine = blameCache.get(filePath)?.lines[line];
+  if (!blameLine) return;
+
+  const lineEnd = await editor.getLineEndPosition(line);
+  const length = editor.getBufferLength(bufferId);
+  // The cursor may have moved on while we waited
+  if (lineEnd === null || length === 0 || inlineState.bufferId !== bufferId || inlineState.line !== line) {
+    return;
+  }
+
+  // Anchor before the newline; on a last line without one, after the final character
+  const atEof = lineEnd >= length;
+  editor.addVirtualText(
+    bufferId,
+    INLINE_BLAME_ID,
+    atEof ? length - 1 : lineEnd,
+    formatInlineBlame(blameLine),
+    INLINE_BLAME_COLOR[0],
+    INLINE_BLAME_COLOR[1],
+    INLINE_BLAME_COLOR[2],
+    !atEof,
+    false
+  );
+}
+
+/**
+ * Toggle the current-line blame annotation
+ */
+globalThis.git_blame_toggle_line = function (): void {
+  inlineState.enabled = !inlineState.enabled;
+  if (inlineState.enabled) {
+    updateInlineBlame(editor.getActiveBufferId(), editor.getCursorLine());
+    editor.setStatus(editor.t("status.line_blame_on"));
+  } else {
+    clearInlineBlame();
+    editor.setStatus(editor.t("status.line_blame_off"));
+  }
+};
+
+globalThis.onGitBlameCursorMoved = function (data: {
+  buffer_id: number;
+  line: number;
+}): boolean {
+  if (!inlineState.enabled) return true;
+
+  // Hook lines are 1-indexed
+  const line = data.line - 1;
+  if (data.buffer_id !== inlineState.bufferId || line !== inlineState.line) {
+    updateInlineBlame(data.buffer_id, line);
+  }
+  return true;
+};
+
+globalThis.onGitBlameBufferActivated = function (data: {
+  buffer_id: number;
+}): boolean {
+  if (inlineState.enabled) {
+    updateInlineBlame(data.buffer_id, editor.getCursorLine());
+  }
+  return true;
+};
+
+globalThis.onGitBlameAfterEdit = function (data: {
+  buffer_id: number;
+}): boolean {
+  if (inlineState.enabled && data.buffer_id === inlineState.bufferId) {
+    updateInlineBlame(data.buffer_id, inlineState.line);
+  }
+  return true;
+};
+
+globalThis.onGitBlameAfterSave = function (data: {
+  buffer_id: number;
+  path: string;
+}): boolean {
+  invalidateFileBlame(data.path);
+  if (inlineState.enabled && data.buffer_id === inlineState.bufferId) {
+    updateInlineBlame(data.buffer_id, inlineState.line);
+  }
+  if (fileViewState.bufferId !== null && fileViewState.filePath === data.path) {
+    getFileBlame(data.path);
+    renderBlameFileView();
+  }
+  return true;
+};
+
+// =============================================================================
+// Blame File View (aligned split)
+// =============================================================================
+
+const BLAME_FILE_NAMESPACE = "git-blame-file";
+
+/** Scroll sync group tying the blame split to the source split */
+const BLAME_FILE_SYNC_GROUP = 0x626c;
+
+const AUTHOR_WIDTH = 16;
+
+// Heat colors: oldest lines are cold, newest are hot
+const HEAT_COLD = [90, 120, 180] as [number, number, number];
+const HEAT_HOT = [255, 150, 60] as [number, number, number];
+
+const fileViewState = {
+  bufferId: null as number | null,
+  splitId: null as number | null,
+  sourceSplitId: null as number | null,
+  sourceBufferId: null as number | null,
+  filePath: null as string | null,
+};
+
+editor.defineMode(
+  "git-blame-file",
+  "normal",
+  [
+    ["q", "git_blame_file_close"],
+    ["Escape", "git_blame_file_close"],
+    ["y", "git_blame_file_copy_hash"],
+  ],
+  true // read-only
+);
+
+/**
+ * Format one row of the blame file view
+ */
+function formatBlameFileRow(line: BlameLine | undefined): string {
+  if (!line) return "";
+  const author = line.author.length > AUTHOR_WIDTH
+    ? line.author.slice(0, AUTHOR_WIDTH - 1) + "…"
+    : line.author.padEnd(AUTHOR_WIDTH);
+  return `${line.shortHash} ${author} ${formatRelativeDate(parseInt(line.authorTime, 10))}`;
+}
+
+/**
+ * Interpolate the heat color for a commit time
+ */
+function heatColor(time: number, oldest: number, newest: number): [number, number, number] {
+  const t = newest > oldest ? (time - oldest) / (newest - oldest) : 1;
+  return [0, 1, 2].map((i) => Math.round(HEAT_COLD[i] + (HEAT_HOT[i] - HEAT_COLD[i]) * t)) as [
+    number,
+    number,
+    number,
+  ];
+}
+
+/**
+ * Build the view's rows, one per source line
+ */
+async function buildBlameFileEntries(): Promise<TextPropertyEntry[]> {
+  const blame = fileViewState.filePath ? blameCache.get(fileViewState.filePath) : undefined;
+  const blameLines = blame?.lines ?? [];
+  const lineCount = Math.max((await editor.getBufferLineCount()) ?? 0, blameLines.length, 1);
+
+  const entries: TextPropertyEntry[] = [];
+  for (let i = 0; i < lineCount; i++) {
+    const line = blameLines[i];
+    entries.push({
+      text: formatBlameFileRow(line) + (i < lineCount - 1 ? "\n" : ""),
+      properties: {
+        type: "blame",
+        hash: line?.hash ?? null,
+        authorTime: line ? parseInt(line.authorTime, 10) : null,
+      },
+    });
+  }
+  return entries;
+}
+
+/**
+ * Fill the blame file view and color each row by age
+ */
+async function renderBlameFileView(): Promise<void> {
+  const bufferId = fileViewState.bufferId;
+  if (bufferId === null) return;
+
+  const entries = await buildBlameFileEntries();
+  editor.setVirtualBufferContent(bufferId, entries);
+  editor.clearNamespace(bufferId, BLAME_FILE_NAMESPACE);
+
+  const times = entries
+    .map((e) => e.properties?.authorTime as number | null)
+    .filter((t): t is number => t !== null);
+  const oldest = Math.min(...times);
+  const newest = Math.max(...times);
+
+  let offset = 0;
+  for (const entry of entries) {
+    const length = editor.utf8ByteLength(entry.text);
+    const time = entry.properties?.authorTime as number | null;
+    if (time !== null) {
+      editor.addOverlay(bufferId, BLAME_FILE_NAMESPACE, offset, offset + length, {
+        fg: heatColor(time, oldest, newest),
+      });
+    }
+    offset += length;
+  }
+
+  const blame = fileViewState.filePath ? blameCache.get(fileViewState.filePath) : undefined;
+  if (blame?.failed) {
+    editor.setStatus(editor.t("status.no_blame_info"));
+  } else if (blame?.done) {
+    editor.setStatus(editor.t("status.blame_file_ready"));
+  } else {
+    editor.setStatus(editor.t("status.loading"));
+  }
+}
+
+/**
+ * Open a blame view beside the current file, scrolled in step with it
+ */
+globalThis.git_blame_file = async function (): Promise<void> {
+  if (fileViewState.bufferId !== null) {
+    globalThis.git_blame_file_close();
+  }
+
+  const sourceBufferId = editor.getActiveBufferId();
+  const filePath = editor.getBufferPath(sourceBufferId);
+  if (!filePath || filePath === "") {
+    editor.setStatus(editor.t("status.no_file"));
+    return;
+  }
+
+  fileViewState.sourceBufferId = sourceBufferId;
+  fileViewState.sourceSplitId = editor.getActiveSplitId();
+  fileViewState.filePath = filePath;
+  getFileBlame(filePath);
+
+  const result = await editor.createVirtualBufferInSplit({
+    name: `*blame:${editor.pathBasename(filePath)}*`,
+    mode: "git-blame-file",
+    readOnly: true,
+    entries: await buildBlameFileEntries(),
+    ratio: 0.3,
+    direction: "vertical",
+    before: true,
+    showLineNumbers: false,
+    showCursors: true,
+    editingDisabled: true,
+    lineWrap: false,
+  });
+
+  if (result === null || result.splitId === null) {
+    editor.setStatus(editor.t("status.failed_open"));
+    return;
+  }
+
+  fileViewState.bufferId = result.bufferId;
+  fileViewState.splitId = result.splitId;
+  editor.createScrollSyncGroup(BLAME_FILE_SYNC_GROUP, fileViewState.sourceSplitId, result.splitId);
+  renderBlameFileView();
+};
+
+/**
+ * Close the blame file view
+ */
+globalThis.git_blame_file_close = function (): void {
+  if (fileViewState.bufferId === null) return;
+
+  const { bufferId, splitId, sourceSplitId } = fileViewState;
+  fileViewState.bufferId = null;
+  fileViewState.splitId = null;
+  fileViewState.filePath = null;
+
+  editor.removeScrollSyncGroup(BLAME_FILE_SYNC_GROUP);
+  if (splitId !== null) {
+    editor.closeSplit(splitId);
+  }
+  editor.closeBuffer(bufferId);
+  if (sourceSplitId !== null) {
+    editor.focusSplit(sourceSplitId);
+  }
+};
+
+/**
+ * Copy the commit hash of the blame file row under the cursor
+ */
+globalThis.git_blame_file_copy_hash = function (): void {
+  if (fileViewState.bufferId === null) return;
+
+  const props = editor.getTextPropertiesAtCursor(fileViewState.bufferId);
+  const hash = props.length > 0 ? (props[0].hash as string | null) : null;
+  if (!hash) {
+    editor.setStatus(editor.t("status.move_to_line"));
+    return;
+  }
+  if (hash === NOT_COMMITTED_HASH) {
+    editor.setStatus(editor.t("status.not_committed"));
+    return;
+  }
+
+  editor.copyToClipboard(hash);
+  editor.setStatus(editor.t("status.hash_copied", { short: hash.slice(0, 7), full: hash }));
+};
+
+globalThis.onGitBlameBufferClosed = function (data: {
+  buffer_id: number;
+}): boolean {
+  // The view was closed some other way (e.g. its tab)
+  if (data.buffer_id === fileViewState.bufferId) {
+    editor.removeScrollSyncGroup(BLAME_FILE_SYNC_GROUP);
+    fileViewState.bufferId = null;
+    fileViewState.splitId = null;
+    fileViewState.filePath = null;
+  }
+  return true;
+};
+
 // =============================================================================
 // Command Registration
 // =============================================================================
@@ -694,6 +1195,35 @@ editor.registerCommand(
   null
 );
 
+editor.registerCommand(
+  "%cmd.git_blame_toggle_line",
+  "%cmd.git_blame_toggle_line_desc",
+  "git_blame_toggle_line",
+  null
+);
+
+editor.registerCommand(
+  "%cmd.git_blame_file",
+  "%cmd.git_blame_file_desc",
+  "git_blame_file",
+  null
+);
+
+editor.registerCommand(
+  "%cmd.git_blame_file_close",
+  "%cmd.git_blame_file_close_desc",
+  "git_blame_file_close",
+  null
+);
+
+editor.on("onProcessStdout", "onGitBlameProcessStdout");
+editor.on("cursor_moved", "onGitBlameCursorMoved");
+editor.on("buffer_activated", "onGitBlameBufferActivated");
+editor.on("after_insert", "onGitBlameAfterEdit");
+editor.on("after_delete", "onGitBlameAfterEdit");
+editor.on("after_file_save", "onGitBlameAfterSave");
+editor.on("buffer_closed", "onGitBlameBufferClosed");
+
 // =============================================================================
 // Plugin Initialization
 // =============================================================================
@@ -27,8 +27,19 @@ const PRIORITY = {
   untracked: 30,
 };
 
+type Decoration = {
+  path: string;
+  symbol: string;
+  color: [number, number, number];
+  priority: number;
+};
+
 let refreshInFlight = false;
 
+// Decorations currently shown, by absolute path, so a change to a few paths
+// can be applied without re-reading the whole repository status
+let currentDecorations = new Map<string, Decoration>();
+
 function statusToDecoration(status: string, staged: boolean) {
   switch (status) {
     case "A":
@@ -58,7 +69,7 @@ function parseStatusOutput(output: string, repoRoot: string) {
     .split(separator)
     .map((entry) => entry.replace(/\r$/, ""))
     .filter((entry) => entry.length > 0);
-  const byPath = new Map<string, { path: string; symbol: string; color: [number, number, number]; priority: number }>();
+  const byPath = new Map<string, Decoration>();
 
   for (let i = 0; i < entries.length; i++) {
     const entry = entries[i];
@@ -99,7 +110,24 @@ function parseStatusOutput(output: string, repoRoot: string) {
   return Array.from(byPath.values());
 }
 
-async function refreshGitExplorerDecorations() {
+function applyDecorations() {
+  if (currentDecorations.size === 0) {
+    editor.clearFileExplorerDecorations(NAMESPACE);
+  } else {
+    editor.setFileExplorerDecorations(NAMESPACE, Array.from(currentDecorations.values()));
+  }
+}
+
+function clearDecorations() {
+  currentDecorations = new Map();
+  editor.clearFileExplorerDecorations(NAMESPACE);
+}
+
+/**
+ * Refresh status badges. With `paths`, only those paths (and anything
+ * under them) are re-read; otherwise the whole repository is.
+ */
+async function refreshGitExplorerDecorations(paths?: string[]) {
   if (refreshInFlight) {
     return;
   }
@@ -108,33 +136,41 @@ async function refreshGitExplorerDecorations() {
     const cwd = editor.getCwd();
     const rootResult = await editor.spawnProcess("git", ["rev-parse", "--show-toplevel"], cwd);
     if (rootResult.exit_code !== 0) {
-      editor.clearFileExplorerDecorations(NAMESPACE);
+      clearDecorations();
       return;
     }
     const repoRoot = rootResult.stdout.trim();
     if (!repoRoot) {
-      editor.clearFileExplorerDecorations(NAMESPACE);
+      clearDecorations();
       return;
     }
 
-    const statusResult = await editor.spawnProcess(
-      "git",
-      ["status", "--porcelain"],
-      repoRoot
-    );
+    const args = ["status", "--porcelain"];
+    if (paths) {
+      args.push("--", ...paths);
+    }
+    const statusResult = await editor.spawnProcess("git", args, repoRoot);
     if (statusResult.exit_code !== 0) {
-      editor.clearFileExplorerDecorations(NAMESPACE);
+      clearDecorations();
       return;
     }
 
     const decorations = parseStatusOutput(statusResult.stdout, repoRoot);
-    if (decorations.length === 0) {
-      editor.clearFileExplorerDecorations(NAMESPACE);
+    if (paths) {
+      for (const existing of Array.from(currentDecorations.keys())) {
+        if (paths.some((p) => existing === p || existing.startsWith(p + "/"))) {
+          currentDecorations.delete(existing);
+        }
+      }
     } else {
-      editor.setFileExplorerDecorations(NAMESPACE, decorations);
+      currentDecorations = new Map();
+    }
+    for (const decoration of decorations) {
+      currentDecorations.set(decoration.path, decoration);
     }
+    applyDecorations();
   } catch (err) {
-    editor.clearFileExplorerDecorations(NAMESPACE);
+    clearDecorations();
     throw err;
   } finally {
     refreshInFlight = false;
@@ -153,8 +189,15 @@ globalThis.onGitExplorerEditorInitialized = () => {
   refreshGitExplorerDecorations();
 };
 
+globalThis.onGitExplorerPathsChanged = (args: { paths: string[] }) => {
+  if (args.paths.length > 0) {
+    refreshGitExplorerDecorations(args.paths);
+  }
+};
+
 editor.on("after_file_open", "onGitExplorerAfterFileOpen");
 editor.on("after_file_save", "onGitExplorerAfterFileSave");
 editor.on("editor_initialized", "onGitExplorerEditorInitialized");
+editor.on("file_explorer_paths_changed", "onGitExplorerPathsChanged");
 
 refreshGitExplorerDecorations();
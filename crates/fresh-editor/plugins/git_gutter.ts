@@ -1,18 +1,26 @@
 /// <reference path="./lib/fresh.d.ts" />
+import { diffLines as computeLineHunks, splitLines } from "./lib/line-diff.ts";
+
 const editor = getEditor();
 
 
 /**
  * Git Gutter Plugin
  *
- * Shows git diff indicators in the gutter for modified, added, and deleted lines.
- * Uses `git diff` to compare the current buffer content against the index (staged changes)
- * or HEAD if nothing is staged.
+ * Shows git diff indicators in the gutter for added, modified, and deleted lines.
+ * The in-memory buffer content is compared against the HEAD version of the file
+ * (or the index, for files that are staged but not yet committed), so unsaved
+ * edits show up too. The HEAD text is fetched with `git show` when a buffer is
+ * opened, activated, or saved; edits re-run the line diff after a short debounce.
  *
  * Indicator symbols:
- * - │ (green): Added line
- * - │ (yellow): Modified line
- * - ▾ (red): Deleted line(s) below
+ * - + (green): Added line
+ * - ~ (yellow): Modified line
+ * - – (red): Deleted line(s) below
+ *
+ * Commands:
+ * - Next/Previous Change: move the cursor to the next/previous hunk
+ * - Revert Hunk: restore the HEAD text for the hunk under the cursor (one undo step)
  */
 
 // =============================================================================
@@ -31,11 +39,20 @@ const COLORS = {
 
 // Symbols
 const SYMBOLS = {
-  added: "│",
-  modified: "│",
-  deleted: "▾",
+  added: "+",
+  modified: "~",
+  deleted: "–",
 };
 
+/** Delay after the last edit before re-diffing */
+const DEBOUNCE_MS = 300;
+
+/**
+ * Edit distance beyond which the diff gives up and reports the whole
+ * changed region as a single modified hunk (keeps huge rewrites cheap)
+ */
+const MAX_EDIT_DISTANCE = 1000;
+
 // =============================================================================
 // Types
 // =============================================================================
@@ -43,19 +60,25 @@ const SYMBOLS = {
 interface DiffHunk {
   /** Type of change */
   type: "added" | "modified" | "deleted";
-  /** Starting line number in the new file (1-indexed) */
+  /** First buffer line of the hunk (0-indexed); for deletions, the line after the removed text */
   startLine: number;
-  /** Number of lines affected */
+  /** Number of buffer lines in the hunk (0 for deletions) */
   lineCount: number;
+  /** HEAD lines replaced by this hunk, including line endings */
+  oldLines: string[];
 }
 
 interface BufferGitState {
   /** File path for this buffer */
   filePath: string;
+  /** HEAD (or index) content split into lines, null if the file isn't tracked */
+  headLines: string[] | null;
   /** Last known hunks for this buffer */
   hunks: DiffHunk[];
-  /** Whether we're currently updating */
-  updating: boolean;
+  /** Bumped on every diff run so stale async results are dropped */
+  generation: number;
+  /** Bumped on every edit so only the last edit in a burst triggers a diff */
+  debounceTicket: number;
 }
 
 // =============================================================================
@@ -67,112 +90,41 @@ const bufferStates: Map<number, BufferGitState> = new Map();
 
 
 // =============================================================================
-// Git Diff Parsing
+// Line Diff
 // =============================================================================
 
 /**
- * Parse unified diff output to extract hunks
- * Unified diff format:
- * @@ -start,count +start,count @@
+ * Build a hunk for a run of changed lines
  */
-function parseDiffOutput(diffOutput: string): DiffHunk[] {
-  const hunks: DiffHunk[] = [];
-  const lines = diffOutput.split("\n");
-
-  let currentOldLine = 0;
-  let currentNewLine = 0;
-  let inHunk = false;
-  let addedStart = 0;
-  let addedCount = 0;
-  let modifiedStart = 0;
-  let modifiedCount = 0;
-  let deletedAtLine = 0;
-  let deletedCount = 0;
-
-  const flushAdded = () => {
-    if (addedCount > 0) {
-      hunks.push({ type: "added", startLine: addedStart, lineCount: addedCount });
-      addedCount = 0;
-    }
-  };
-
-  const flushModified = () => {
-    if (modifiedCount > 0) {
-      hunks.push({ type: "modified", startLine: modifiedStart, lineCount: modifiedCount });
-      modifiedCount = 0;
-    }
-  };
-
-  const flushDeleted = () => {
-    if (deletedCount > 0) {
-      // Deleted lines are shown as a marker on the line after the deletion
-      hunks.push({ type: "deleted", startLine: deletedAtLine, lineCount: deletedCount });
-      deletedCount = 0;
-    }
-  };
-
-  for (const line of lines) {
-    // Match hunk header: @@ -old_start,old_count +new_start,new_count @@
-    const hunkMatch = line.match(/^@@ -(\d+)(?:,(\d+))? \+(\d+)(?:,(\d+))? @@/);
-    if (hunkMatch) {
-      // Flush any pending changes from previous hunk
-      flushAdded();
-      flushModified();
-      flushDeleted();
-
-      currentOldLine = parseInt(hunkMatch[1], 10);
-      currentNewLine = parseInt(hunkMatch[3], 10);
-      inHunk = true;
-      continue;
-    }
-
-    if (!inHunk) continue;
-
-    if (line.startsWith("+") && !line.startsWith("+++")) {
-      // Added line
-      if (deletedCount > 0) {
-        // If there were deletions right before, this is a modification
-        if (modifiedCount === 0) {
-          modifiedStart = currentNewLine;
-        }
-        modifiedCount++;
-        deletedCount--;
-      } else {
-        // Pure addition
-        if (addedCount === 0) {
-          addedStart = currentNewLine;
-        }
-        addedCount++;
-      }
-      currentNewLine++;
-    } else if (line.startsWith("-") && !line.startsWith("---")) {
-      // Deleted line - flush any pending additions first
-      flushAdded();
-
-      if (deletedCount === 0) {
-        deletedAtLine = currentNewLine;
-      }
-      deletedCount++;
-      currentOldLine++;
-    } else if (line.startsWith(" ")) {
-      // Context line (unchanged)
-      flushAdded();
-      flushModified();
-      flushDeleted();
-      currentOldLine++;
-      currentNewLine++;
-    } else if (line === "\\ No newline at end of file") {
-      // Ignore this marker
-      continue;
-    }
+function makeHunk(startLine: number, lineCount: number, oldLines: string[]): DiffHunk {
+  let type: DiffHunk["type"] = "modified";
+  if (oldLines.length === 0) {
+    type = "added";
+  } else if (lineCount === 0) {
+    type = "deleted";
   }
+  return { type, startLine, lineCount, oldLines };
+}
 
-  // Flush any remaining changes
-  flushAdded();
-  flushModified();
-  flushDeleted();
+/**
+ * Diff HEAD lines against buffer lines
+ */
+function diffLines(oldLines: string[], newLines: string[]): DiffHunk[] {
+  return computeLineHunks(oldLines, newLines, MAX_EDIT_DISTANCE).map((hunk) =>
+    makeHunk(
+      hunk.newStart,
+      hunk.newCount,
+      oldLines.slice(hunk.oldStart, hunk.oldStart + hunk.oldCount)
+    )
+  );
+}
 
-  return hunks;
+/**
+ * Line that carries a hunk's indicator. Deletions are marked on the line
+ * above the removed text.
+ */
+function hunkLine(hunk: DiffHunk): number {
+  return hunk.type === "deleted" ? Math.max(0, hunk.startLine - 1) : hunk.startLine;
 }
 
 // =============================================================================
@@ -191,38 +143,29 @@ function getFileDirectory(filePath: string): string {
 }
 
 /**
- * Check if a file is tracked by git
+ * Get the file name without its directory
  */
-async function isGitTracked(filePath: string): Promise<boolean> {
-  const cwd = getFileDirectory(filePath);
-  const result = await editor.spawnProcess("git", ["ls-files", "--error-unmatch", filePath], cwd);
-  return result.exit_code === 0;
+function getFileName(filePath: string): string {
+  return filePath.substring(filePath.lastIndexOf("/") + 1);
 }
 
 /**
- * Get git diff for a file
- * Compares working tree against HEAD to show all uncommitted changes
- * (both staged and unstaged)
+ * Get the committed content of a file
+ * Reads the HEAD version, falling back to the index for files that are
+ * staged but not yet committed. Returns null for untracked files.
  */
-async function getGitDiff(filePath: string): Promise<string> {
+async function getBaseContent(filePath: string): Promise<string | null> {
   const cwd = getFileDirectory(filePath);
+  const name = getFileName(filePath);
 
-  // Diff against HEAD to show all changes (staged + unstaged) vs last commit
-  const result = await editor.spawnProcess("git", [
-    "diff",
-    "HEAD",
-    "--no-color",
-    "--unified=0", // No context lines for cleaner parsing
-    "--",
-    filePath,
-  ], cwd);
-
-  // Exit code 0 = no differences, 1 = differences found, >1 = error
-  if (result.exit_code <= 1) {
-    return result.stdout;
+  for (const rev of ["HEAD", ""]) {
+    const result = await editor.spawnProcess("git", ["show", `${rev}:./${name}`], cwd);
+    if (result.exit_code === 0) {
+      return result.stdout;
+    }
   }
 
-  return "";
+  return null;
 }
 
 // =============================================================================
@@ -230,83 +173,118 @@ async function getGitDiff(filePath: string): Promise<string> {
 // =============================================================================
 
 /**
- * Update git gutter indicators for a buffer
+ * Read the whole buffer
  */
-async function updateGitGutter(bufferId: number): Promise<void> {
+async function getBufferLines(bufferId: number): Promise<string[]> {
+  const text = await editor.getBufferText(bufferId, 0, editor.getBufferLength(bufferId));
+  return splitLines(text);
+}
+
+/**
+ * Re-diff the buffer against the cached HEAD content and redraw indicators
+ */
+async function refreshIndicators(bufferId: number): Promise<void> {
   const state = bufferStates.get(bufferId);
-  if (!state || state.updating) return;
+  if (!state) return;
+
+  const generation = ++state.generation;
+  const headLines = state.headLines;
+  if (!headLines) {
+    editor.clearLineIndicators(bufferId, NAMESPACE);
+    state.hunks = [];
+    return;
+  }
 
-  state.updating = true;
+  const bufferLines = await getBufferLines(bufferId);
+  // A newer run started while we were reading the buffer
+  if (state.generation !== generation) return;
 
-  try {
-    editor.debug(`Git Gutter: updating for ${state.filePath}`);
+  const hunks = diffLines(headLines, bufferLines);
+  editor.debug(`Git Gutter: ${hunks.length} hunks for ${state.filePath}`);
 
-    // Check if file is git tracked
-    const tracked = await isGitTracked(state.filePath);
-    if (!tracked) {
-      // Clear indicators for non-tracked files
-      editor.debug("Git Gutter: file not tracked by git");
-      editor.clearLineIndicators(bufferId, NAMESPACE);
-      state.hunks = [];
-      return;
+  // Clear existing indicators
+  editor.clearLineIndicators(bufferId, NAMESPACE);
+
+  // Apply new indicators, one batch per change type
+  const lines = { added: [] as number[], modified: [] as number[], deleted: [] as number[] };
+  for (const hunk of hunks) {
+    if (hunk.type === "deleted") {
+      lines.deleted.push(hunkLine(hunk));
+    } else {
+      for (let i = 0; i < hunk.lineCount; i++) {
+        lines[hunk.type].push(hunk.startLine + i);
+      }
     }
+  }
+  for (const type of ["added", "modified", "deleted"] as const) {
+    if (lines[type].length > 0) {
+      const color = COLORS[type];
+      editor.setLineIndicators(
+        bufferId,
+        lines[type],
+        NAMESPACE,
+        SYMBOLS[type],
+        color[0],
+        color[1],
+        color[2],
+        PRIORITY
+      );
+    }
+  }
 
-    editor.debug("Git Gutter: file is tracked, getting diff...");
+  state.hunks = hunks;
+}
 
-    // Get diff
-    const diffOutput = await getGitDiff(state.filePath);
-    editor.debug(`Git Gutter: diff output length = ${diffOutput.length}`);
-    if (diffOutput.length > 0 && diffOutput.length < 500) {
-      editor.debug(`Git Gutter: diff = ${diffOutput.replace(/\n/g, "\\n")}`);
-    }
-    const hunks = parseDiffOutput(diffOutput);
-    editor.debug(`Git Gutter: parsed ${hunks.length} hunks`);
+/**
+ * Fetch the HEAD content for a buffer, then redraw its indicators
+ */
+async function updateGitGutter(bufferId: number): Promise<void> {
+  const state = bufferStates.get(bufferId);
+  if (!state) return;
 
-    // Clear existing indicators
-    editor.clearLineIndicators(bufferId, NAMESPACE);
+  editor.debug(`Git Gutter: updating for ${state.filePath}`);
 
-    // Apply new indicators
-    for (const hunk of hunks) {
-      const color = COLORS[hunk.type];
-      const symbol = SYMBOLS[hunk.type];
-
-      if (hunk.type === "deleted") {
-        // Deleted indicator shows on a single line
-        // Line numbers are 1-indexed in diff, but 0-indexed in editor
-        const line = Math.max(0, hunk.startLine - 1);
-        editor.setLineIndicator(
-          bufferId,
-          line,
-          NAMESPACE,
-          symbol,
-          color[0],
-          color[1],
-          color[2],
-          PRIORITY
-        );
-      } else {
-        // Added/modified indicators show on each affected line
-        for (let i = 0; i < hunk.lineCount; i++) {
-          // Line numbers are 1-indexed in diff, but 0-indexed in editor
-          const line = hunk.startLine - 1 + i;
-          editor.setLineIndicator(
-            bufferId,
-            line,
-            NAMESPACE,
-            symbol,
-            color[0],
-            color[1],
-            color[2],
-            PRIORITY
-          );
-        }
-      }
+  const base = await getBaseContent(state.filePath);
+  if (base === null) {
+    editor.debug("Git Gutter: file not tracked by git");
+  }
+  state.headLines = base === null ? null : splitLines(base);
+
+  await refreshIndicators(bufferId);
+}
+
+/**
+ * Re-diff after edits, once typing pauses
+ */
+function scheduleRefresh(bufferId: number): void {
+  const state = bufferStates.get(bufferId);
+  if (!state || !state.headLines) return;
+
+  const ticket = ++state.debounceTicket;
+  editor.delay(DEBOUNCE_MS).then(() => {
+    if (state.debounceTicket === ticket) {
+      refreshIndicators(bufferId);
     }
+  });
+}
 
-    state.hunks = hunks;
-  } finally {
-    state.updating = false;
+/**
+ * Get or create state for a buffer with a file path
+ */
+function ensureState(bufferId: number, filePath: string): BufferGitState {
+  let state = bufferStates.get(bufferId);
+  if (!state) {
+    state = {
+      filePath,
+      headLines: null,
+      hunks: [],
+      generation: 0,
+      debounceTicket: 0,
+    };
+    bufferStates.set(bufferId, state);
   }
+  state.filePath = filePath;
+  return state;
 }
 
 
@@ -328,41 +306,25 @@ globalThis.onGitGutterAfterFileOpen = function (args: {
     return true;
   }
 
-  // Initialize state for this buffer
-  bufferStates.set(bufferId, {
-    filePath,
-    hunks: [],
-    updating: false,
-  });
-
-  // Update immediately (no debounce for file open)
+  ensureState(bufferId, filePath);
   updateGitGutter(bufferId);
 
   return true;
 };
 
 /**
- * Handle buffer activation - update if we have state but indicators might be stale
+ * Handle buffer activation - HEAD may have moved while the buffer was hidden
  */
 globalThis.onGitGutterBufferActivated = function (args: {
   buffer_id: number;
 }): boolean {
   const bufferId = args.buffer_id;
+  const filePath = editor.getBufferPath(bufferId);
 
-  // If we don't have state yet, try to initialize from buffer path
-  if (!bufferStates.has(bufferId)) {
-    const filePath = editor.getBufferPath(bufferId);
-    if (filePath && filePath !== "") {
-      bufferStates.set(bufferId, {
-        filePath,
-        hunks: [],
-        updating: false,
-      });
-      updateGitGutter(bufferId);
-    }
+  if (filePath && filePath !== "") {
+    ensureState(bufferId, filePath);
+    updateGitGutter(bufferId);
   }
-  // If we already have state, the indicators should be current
-  // (they update on file open and save)
 
   return true;
 };
@@ -374,29 +336,22 @@ globalThis.onGitGutterAfterSave = function (args: {
   buffer_id: number;
   path: string;
 }): boolean {
-  const bufferId = args.buffer_id;
-
   // Update state with new path (in case of save-as)
-  const state = bufferStates.get(bufferId);
-  if (state) {
-    state.filePath = args.path;
-  } else {
-    bufferStates.set(bufferId, {
-      filePath: args.path,
-      hunks: [],
-      updating: false,
-    });
-  }
-
-  // Update immediately after save (no debounce)
-  updateGitGutter(bufferId);
+  ensureState(args.buffer_id, args.path);
+  updateGitGutter(args.buffer_id);
 
   return true;
 };
 
-// Note: Git diff compares the file on disk, not the in-memory buffer.
-// Line indicators automatically track position changes via byte-position markers.
-// A full re-diff happens on save. For unsaved changes, see buffer_modified plugin.
+/**
+ * Handle edits - re-diff the in-memory content after a pause
+ */
+globalThis.onGitGutterAfterEdit = function (args: {
+  buffer_id: number;
+}): boolean {
+  scheduleRefresh(args.buffer_id);
+  return true;
+};
 
 /**
  * Handle buffer closed - cleanup state
@@ -424,14 +379,7 @@ globalThis.git_gutter_refresh = function (): void {
     return;
   }
 
-  // Ensure state exists
-  if (!bufferStates.has(bufferId)) {
-    bufferStates.set(bufferId, {
-      filePath,
-      hunks: [],
-      updating: false,
-    });
-  }
+  ensureState(bufferId, filePath);
 
   // Force immediate update
   updateGitGutter(bufferId).then(() => {
@@ -441,16 +389,109 @@ globalThis.git_gutter_refresh = function (): void {
   });
 };
 
+/**
+ * Move the cursor to the next or previous hunk, wrapping around the buffer
+ */
+async function gotoChange(direction: 1 | -1): Promise<void> {
+  const bufferId = editor.getActiveBufferId();
+  const state = bufferStates.get(bufferId);
+  if (!state) {
+    editor.setStatus(editor.t("status.no_changes"));
+    return;
+  }
+
+  // Make sure hunks reflect edits still waiting on the debounce
+  await refreshIndicators(bufferId);
+  const lines = state.hunks.map(hunkLine);
+  if (lines.length === 0) {
+    editor.setStatus(editor.t("status.no_changes"));
+    return;
+  }
+
+  const cursorLine = editor.getCursorLine();
+  let index = direction > 0 ? 0 : lines.length - 1;
+  if (direction > 0) {
+    const next = lines.findIndex((line) => line > cursorLine);
+    if (next >= 0) index = next;
+  } else {
+    for (let i = lines.length - 1; i >= 0; i--) {
+      if (lines[i] < cursorLine) {
+        index = i;
+        break;
+      }
+    }
+  }
+
+  const position = await editor.getLineStartPosition(lines[index]);
+  if (position === null) return;
+  editor.setBufferCursor(bufferId, position);
+  editor.scrollToLineCenter(editor.getActiveSplitId(), bufferId, lines[index]);
+  editor.setStatus(
+    editor.t("status.change_position", {
+      index: String(index + 1),
+      count: String(lines.length),
+    })
+  );
+}
+
+globalThis.git_gutter_next_change = function (): void {
+  gotoChange(1);
+};
+
+globalThis.git_gutter_prev_change = function (): void {
+  gotoChange(-1);
+};
+
+/**
+ * Restore the HEAD text for the hunk under the cursor
+ */
+globalThis.git_gutter_revert_hunk = async function (): Promise<void> {
+  const bufferId = editor.getActiveBufferId();
+  const state = bufferStates.get(bufferId);
+  if (!state || !state.headLines) {
+    editor.setStatus(editor.t("status.no_hunk"));
+    return;
+  }
+
+  // Diff the current content so the byte range matches the buffer exactly
+  const bufferLines = await getBufferLines(bufferId);
+  const cursorLine = editor.getCursorLine();
+  const hunk = diffLines(state.headLines, bufferLines).find((h) =>
+    h.type === "deleted"
+      ? hunkLine(h) === cursorLine
+      : cursorLine >= h.startLine && cursorLine < h.startLine + h.lineCount
+  );
+  if (!hunk) {
+    editor.setStatus(editor.t("status.no_hunk"));
+    return;
+  }
+
+  let start = 0;
+  for (let i = 0; i < hunk.startLine; i++) {
+    start += editor.utf8ByteLength(bufferLines[i]);
+  }
+  let end = start;
+  for (let i = hunk.startLine; i < hunk.startLine + hunk.lineCount; i++) {
+    end += editor.utf8ByteLength(bufferLines[i]);
+  }
+
+  await editor.replaceInFile(state.filePath, [
+    { start, end, text: hunk.oldLines.join("") },
+  ]);
+  await refreshIndicators(bufferId);
+  editor.setStatus(editor.t("status.hunk_reverted"));
+};
+
 // =============================================================================
 // Registration
 // =============================================================================
 
 // Register event handlers
-// Note: No need to register after-insert/after-delete hooks - indicators
-// automatically track position changes via byte-position markers in the editor.
 editor.on("after_file_open", "onGitGutterAfterFileOpen");
 editor.on("buffer_activated", "onGitGutterBufferActivated");
 editor.on("after_file_save", "onGitGutterAfterSave");
+editor.on("after_insert", "onGitGutterAfterEdit");
+editor.on("after_delete", "onGitGutterAfterEdit");
 editor.on("buffer_closed", "onGitGutterBufferClosed");
 
 // Register commands
@@ -460,16 +501,30 @@ editor.registerCommand(
   "git_gutter_refresh",
   null
 );
+editor.registerCommand(
+  "%cmd.next_change",
+  "%cmd.next_change_desc",
+  "git_gutter_next_change",
+  null
+);
+editor.registerCommand(
+  "%cmd.prev_change",
+  "%cmd.prev_change_desc",
+  "git_gutter_prev_change",
+  null
+);
+editor.registerCommand(
+  "%cmd.revert_hunk",
+  "%cmd.revert_hunk_desc",
+  "git_gutter_revert_hunk",
+  null
+);
 
 // Initialize for the current buffer
 const initBufferId = editor.getActiveBufferId();
 const initPath = editor.getBufferPath(initBufferId);
 if (initPath && initPath !== "") {
-  bufferStates.set(initBufferId, {
-    filePath: initPath,
-    hunks: [],
-    updating: false,
-  });
+  ensureState(initBufferId, initPath);
   updateGitGutter(initBufferId);
 }
 
@@ -18,6 +18,8 @@
 declare function getEditor(): EditorAPI;
 /** Handle for a cancellable async operation */
 interface ProcessHandle<T> extends PromiseLike<T> {
+	/** Process ID, as passed to the onProcessStdout/onProcessStderr hooks */
+	readonly processId: number;
 	/** Promise that resolves to the result when complete */
 	readonly result: Promise<T>;
 	/** Cancel/kill the operation. Returns true if cancelled, false if already completed */
@@ -237,6 +239,20 @@ type DirEntry = {
 	*/
 	is_dir: boolean;
 };
+type FileEdit = {
+	/**
+	* Start byte offset
+	*/
+	start: number;
+	/**
+	* End byte offset (exclusive)
+	*/
+	end: number;
+	/**
+	* Replacement text
+	*/
+	text: string;
+};
 type BufferInfo = {
 	/**
 	* Buffer ID
@@ -680,6 +696,15 @@ interface EditorAPI {
 	warn(msg: string): void;
 	error(msg: string): void;
 	setStatus(msg: string): void;
+	/**
+	* Set the text of a status bar segment, shown where its name is listed in
+	* the `status_bar` config - clicking it runs `command` if given
+	*/
+	setStatusBarSegment(name: string, text: string, command?: string): boolean;
+	/**
+	* Remove a status bar segment set with setStatusBarSegment
+	*/
+	removeStatusBarSegment(name: string): boolean;
 	copyToClipboard(text: string): void;
 	setClipboard(text: string): void;
 	/**
@@ -787,6 +812,10 @@ interface EditorAPI {
 	*/
 	deleteRange(bufferId: number, start: number, end: number): boolean;
 	/**
+	* Replace a range in a buffer with new text as a single undo step
+	*/
+	replaceRange(bufferId: number, start: number, end: number, text: string): boolean;
+	/**
 	* Insert text at cursor position in active buffer
 	*/
 	insertAtCursor(text: string): boolean;
@@ -878,6 +907,14 @@ interface EditorAPI {
 	*/
 	writeFile(path: string, content: string): boolean;
 	/**
+	* Replace byte ranges in a file (async)
+	* 
+	* Edits the buffer in memory if the file is open (a single undo step),
+	* otherwise rewrites the file on disk with an atomic save. Binary and
+	* non-UTF-8 files are rejected. Resolves with the number of edits applied.
+	*/
+	replaceInFile(path: string, edits: FileEdit[]): Promise<number>;
+	/**
 	* Read directory contents (returns array of {name, is_file, is_dir})
 	*/
 	readDir(path: string): DirEntry[];
@@ -0,0 +1,171 @@
+/// <reference path="./fresh.d.ts" />
+
+/**
+ * Line Diff
+ *
+ * Myers' O(ND) line diff shared by plugins that compare two versions of a
+ * file (Git Gutter, Diff View). Works on plain string arrays, so callers
+ * decide whether lines keep their terminators.
+ */
+
+/**
+ * A run of changed lines. Starts are 0-indexed; a count of 0 marks a pure
+ * insertion or deletion at that position.
+ */
+export interface LineHunk {
+  oldStart: number;
+  oldCount: number;
+  newStart: number;
+  newCount: number;
+}
+
+/**
+ * Split text into lines, keeping each line's terminator so hunks can be
+ * restored byte-for-byte
+ */
+export function splitLines(text: string): string[] {
+  const lines = text.split("\n");
+  const last = lines.pop() as string;
+  const result = lines.map((line) => line + "\n");
+  if (last !== "") {
+    result.push(last);
+  }
+  return result;
+}
+
+/**
+ * Find the longest common subsequence of two line arrays with Myers' O(ND)
+ * algorithm. Returns matched index pairs in order, or null if the edit
+ * distance exceeds maxEditDistance.
+ */
+function matchLines(
+  a: string[],
+  b: string[],
+  maxEditDistance: number
+): Array<[number, number]> | null {
+  const n = a.length;
+  const m = b.length;
+  const max = Math.min(n + m, maxEditDistance);
+  const offset = max + 1;
+  const v: number[] = new Array(2 * max + 3).fill(0);
+  const trace: number[][] = [];
+
+  for (let d = 0; d <= max; d++) {
+    trace.push(v.slice());
+    for (let k = -d; k <= d; k += 2) {
+      let x =
+        k === -d || (k !== d && v[offset + k - 1] < v[offset + k + 1])
+          ? v[offset + k + 1]
+          : v[offset + k - 1] + 1;
+      let y = x - k;
+      while (x < n && y < m && a[x] === b[y]) {
+        x++;
+        y++;
+      }
+      v[offset + k] = x;
+
+      if (x >= n && y >= m) {
+        return backtrack(trace, n, m, offset);
+      }
+    }
+  }
+
+  return null;
+}
+
+/**
+ * Walk the Myers trace back from the end to collect the matched line pairs
+ */
+function backtrack(
+  trace: number[][],
+  n: number,
+  m: number,
+  offset: number
+): Array<[number, number]> {
+  const pairs: Array<[number, number]> = [];
+  let x = n;
+  let y = m;
+
+  for (let d = trace.length - 1; d >= 0; d--) {
+    const v = trace[d];
+    const k = x - y;
+    const prevK =
+      k === -d || (k !== d && v[offset + k - 1] < v[offset + k + 1])
+        ? k + 1
+        : k - 1;
+    const prevX = v[offset + prevK];
+    const prevY = prevX - prevK;
+
+    while (x > prevX && y > prevY) {
+      x--;
+      y--;
+      pairs.push([x, y]);
+    }
+    if (d > 0) {
+      x = prevX;
+      y = prevY;
+    }
+  }
+
+  return pairs.reverse();
+}
+
+/**
+ * Diff two line arrays into hunks. If the edit distance exceeds
+ * maxEditDistance, the whole changed region is reported as a single hunk
+ * (keeps huge rewrites cheap).
+ */
+export function diffLines(
+  oldLines: string[],
+  newLines: string[],
+  maxEditDistance = 1000
+): LineHunk[] {
+  // Trim the common prefix and suffix; typical edits leave only a few lines
+  // for the real diff to look at
+  let prefix = 0;
+  while (
+    prefix < oldLines.length &&
+    prefix < newLines.length &&
+    oldLines[prefix] === newLines[prefix]
+  ) {
+    prefix++;
+  }
+  let suffix = 0;
+  while (
+    suffix < oldLines.length - prefix &&
+    suffix < newLines.length - prefix &&
+    oldLines[oldLines.length - 1 - suffix] === newLines[newLines.length - 1 - suffix]
+  ) {
+    suffix++;
+  }
+
+  const a = oldLines.slice(prefix, oldLines.length - suffix);
+  const b = newLines.slice(prefix, newLines.length - suffix);
+  if (a.length === 0 && b.length === 0) {
+    return [];
+  }
+
+  const pairs = matchLines(a, b, maxEditDistance);
+  if (!pairs) {
+    return [{ oldStart: prefix, oldCount: a.length, newStart: prefix, newCount: b.length }];
+  }
+
+  const hunks: LineHunk[] = [];
+  let ai = 0;
+  let bi = 0;
+  pairs.push([a.length, b.length]);
+  for (const [x, y] of pairs) {
+    if (x > ai || y > bi) {
+      hunks.push({
+        oldStart: prefix + ai,
+        oldCount: x - ai,
+        newStart: prefix + bi,
+        newCount: y - bi,
+      });
+    }
+    ai = x + 1;
+    bi = y + 1;
+  }
+
+  return hunks;
+}
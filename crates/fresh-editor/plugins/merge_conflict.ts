@@ -1,4 +1,6 @@
 /// <reference path="./lib/fresh.d.ts" />
+import { splitLines } from "./lib/line-diff.ts";
+
 const editor = getEditor();
 
 
@@ -7,6 +9,8 @@ const editor = getEditor();
  *
  * Provides an interactive merge conflict resolution interface with:
  * - Automatic detection of git conflict markers when files are opened
+ * - Inline highlighting of each conflict with clickable "Accept Ours |
+ *   Accept Theirs | Accept Both" hints, resolvable without leaving the file
  * - Multi-panel UI showing OURS, THEIRS, and editable RESULT
  * - Keyboard navigation between conflicts
  * - One-key resolution (accept ours, theirs, or both)
@@ -114,6 +118,7 @@ const colors = {
   conflictOurs: [50, 80, 100] as [number, number, number],    // Blue-tinted background
   conflictTheirs: [100, 70, 50] as [number, number, number],  // Orange-tinted background
   conflictBase: [70, 70, 70] as [number, number, number],     // Gray for base
+  conflictMarker: [60, 60, 90] as [number, number, number],   // Marker lines in the file
 
   // Intra-line diff colors
   diffAdd: [50, 100, 50] as [number, number, number],         // Green for additions
@@ -1686,6 +1691,328 @@ globalThis.merge_show_help = function(): void {
   editor.setStatus(editor.t("status.help"));
 };
 
+// =============================================================================
+// Inline Conflict Markers
+// =============================================================================
+
+/**
+ * A conflict in an ordinary file buffer. Offsets are UTF-8 bytes; the side
+ * texts keep their line terminators so they can be spliced back as they are.
+ */
+interface InlineConflict {
+  /** Start of the <<<<<<< line */
+  start: number;
+  /** End of the >>>>>>> line, including its terminator */
+  end: number;
+  /** 0-indexed lines of the <<<<<<< and >>>>>>> markers */
+  startLine: number;
+  endLine: number;
+  /** End of the <<<<<<< line text, where the action hints are anchored */
+  hintAnchor: number;
+  /** Display width of the <<<<<<< line, the column the hints start at */
+  hintColumn: number;
+  ours: string;
+  theirs: string;
+  /** Byte ranges to tint */
+  regions: Array<{ start: number; end: number; kind: "marker" | "ours" | "base" | "theirs" }>;
+}
+
+type InlineResolution = "ours" | "theirs" | "both";
+
+const INLINE_NAMESPACE = "merge-inline";
+const INLINE_HINT_PREFIX = "merge-inline-hint-";
+const INLINE_HINT_INDENT = "  ";
+const INLINE_HINT_SEPARATOR = " | ";
+
+const INLINE_REGION_BG = {
+  marker: colors.conflictMarker,
+  ours: colors.conflictOurs,
+  base: colors.conflictBase,
+  theirs: colors.conflictTheirs,
+};
+
+/** Conflicts per buffer, for buffers that currently have any */
+const inlineConflicts: Map<number, InlineConflict[]> = new Map();
+/** Bumped on every scan so a slow scan can't overwrite a newer one */
+const inlineScanGeneration: Map<number, number> = new Map();
+
+const INLINE_RESOLUTIONS: InlineResolution[] = ["ours", "theirs", "both"];
+
+function inlineHintLabels(): string[] {
+  return [
+    editor.t("hint.accept_ours"),
+    editor.t("hint.accept_theirs"),
+    editor.t("hint.accept_both"),
+  ];
+}
+
+/**
+ * Find which hint a column (relative to the start of the hint text) is on
+ */
+function inlineHintAt(column: number): InlineResolution | null {
+  let start = INLINE_HINT_INDENT.length;
+  const labels = inlineHintLabels();
+  for (let i = 0; i < labels.length; i++) {
+    const end = start + [...labels[i]].length;
+    if (column >= start && column < end) {
+      return INLINE_RESOLUTIONS[i];
+    }
+    start = end + INLINE_HINT_SEPARATOR.length;
+  }
+  return null;
+}
+
+/**
+ * Scan text for conflict blocks, line by line. Unterminated blocks are
+ * ignored, and a new <<<<<<< marker abandons the block before it.
+ */
+function findInlineConflicts(text: string): InlineConflict[] {
+  const conflicts: InlineConflict[] = [];
+  let open: Omit<InlineConflict, "end" | "endLine"> | null = null;
+  let section: "ours" | "base" | "theirs" = "ours";
+  let sectionStart = 0;
+  let offset = 0;
+
+  const lines = splitLines(text);
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i];
+    const body = line.replace(/\r?\n$/, "");
+    const lineStart = offset;
+    offset += editor.utf8ByteLength(line);
+
+    const closeSection = (): void => {
+      if (open && lineStart > sectionStart) {
+        open.regions.push({ start: sectionStart, end: lineStart, kind: section });
+      }
+      open?.regions.push({ start: lineStart, end: offset, kind: "marker" });
+      sectionStart = offset;
+    };
+
+    if (/^<{7}(?: |$)/.test(body)) {
+      open = {
+        start: lineStart,
+        startLine: i,
+        hintAnchor: lineStart + editor.utf8ByteLength(body),
+        hintColumn: [...body].length,
+        ours: "",
+        theirs: "",
+        regions: [{ start: lineStart, end: offset, kind: "marker" }],
+      };
+      section = "ours";
+      sectionStart = offset;
+    } else if (!open) {
+      continue;
+    } else if (section === "ours" && /^\|{7}(?: |$)/.test(body)) {
+      closeSection();
+      section = "base";
+    } else if (section !== "theirs" && body === "=======") {
+      closeSection();
+      section = "theirs";
+    } else if (section === "theirs" && /^>{7}(?: |$)/.test(body)) {
+      closeSection();
+      conflicts.push({ ...open, end: offset, endLine: i });
+      open = null;
+    } else if (section === "ours") {
+      open.ours += line;
+    } else if (section === "theirs") {
+      open.theirs += line;
+    }
+  }
+
+  return conflicts;
+}
+
+/**
+ * Tint each conflict and put the action hints on its <<<<<<< line
+ */
+function drawInlineConflicts(bufferId: number, conflicts: InlineConflict[]): void {
+  editor.clearNamespace(bufferId, INLINE_NAMESPACE);
+  editor.removeVirtualTextsByPrefix(bufferId, INLINE_HINT_PREFIX);
+
+  const hint = INLINE_HINT_INDENT + inlineHintLabels().join(INLINE_HINT_SEPARATOR);
+  conflicts.forEach((conflict, index) => {
+    for (const region of conflict.regions) {
+      editor.addOverlay(bufferId, INLINE_NAMESPACE, region.start, region.end, {
+        bg: INLINE_REGION_BG[region.kind],
+        bold: region.kind === "marker",
+        extend_to_line_end: true,
+      });
+    }
+    editor.addVirtualText(
+      bufferId,
+      `${INLINE_HINT_PREFIX}${index}`,
+      conflict.hintAnchor,
+      hint,
+      colors.button[0],
+      colors.button[1],
+      colors.button[2],
+      true,
+      false
+    );
+  });
+}
+
+/**
+ * Re-read a file buffer, redraw its conflicts and report the count when it
+ * changes
+ */
+async function scanInlineConflicts(bufferId: number): Promise<InlineConflict[]> {
+  const info = editor.getBufferInfo(bufferId);
+  if (!info || !info.path || info.is_virtual) return [];
+
+  const generation = (inlineScanGeneration.get(bufferId) ?? 0) + 1;
+  inlineScanGeneration.set(bufferId, generation);
+  const text = await editor.getBufferText(bufferId, 0, editor.getBufferLength(bufferId));
+  if (inlineScanGeneration.get(bufferId) !== generation) {
+    return inlineConflicts.get(bufferId) ?? [];
+  }
+
+  const previous = inlineConflicts.get(bufferId)?.length ?? 0;
+  const conflicts = text.includes("<<<<<<<") ? findInlineConflicts(text) : [];
+  if (conflicts.length === 0 && previous === 0) return conflicts;
+
+  drawInlineConflicts(bufferId, conflicts);
+  if (conflicts.length > 0) {
+    inlineConflicts.set(bufferId, conflicts);
+  } else {
+    inlineConflicts.delete(bufferId);
+  }
+
+  if (conflicts.length !== previous && bufferId === editor.getActiveBufferId()) {
+    editor.setStatus(
+      conflicts.length > 0
+        ? editor.t("status.inline_remaining", { count: String(conflicts.length) })
+        : editor.t("status.inline_all_resolved")
+    );
+  }
+  return conflicts;
+}
+
+/**
+ * Replace the conflict containing `line` with the chosen side. The markers
+ * and the other side go in one edit, so a single undo brings them back.
+ */
+async function resolveInlineConflict(
+  bufferId: number,
+  line: number,
+  resolution: InlineResolution
+): Promise<void> {
+  const info = editor.getBufferInfo(bufferId);
+  const conflicts = await scanInlineConflicts(bufferId);
+  const conflict = conflicts.find((c) => line >= c.startLine && line <= c.endLine);
+  if (!info || !info.path || !conflict) {
+    editor.setStatus(editor.t("status.no_conflict_at_cursor"));
+    return;
+  }
+
+  const text =
+    resolution === "ours"
+      ? conflict.ours
+      : resolution === "theirs"
+        ? conflict.theirs
+        : conflict.ours + conflict.theirs;
+  await editor.replaceInFile(info.path, [{ start: conflict.start, end: conflict.end, text }]);
+  await scanInlineConflicts(bufferId);
+}
+
+function resolveInlineConflictAtCursor(resolution: InlineResolution): Promise<void> {
+  return resolveInlineConflict(editor.getActiveBufferId(), editor.getCursorLine(), resolution);
+}
+
+globalThis.merge_inline_accept_ours = function (): Promise<void> {
+  return resolveInlineConflictAtCursor("ours");
+};
+
+globalThis.merge_inline_accept_theirs = function (): Promise<void> {
+  return resolveInlineConflictAtCursor("theirs");
+};
+
+globalThis.merge_inline_accept_both = function (): Promise<void> {
+  return resolveInlineConflictAtCursor("both");
+};
+
+/**
+ * Move the cursor to the next or previous conflict marker, wrapping around
+ */
+async function gotoInlineConflict(direction: 1 | -1): Promise<void> {
+  const bufferId = editor.getActiveBufferId();
+  const conflicts = await scanInlineConflicts(bufferId);
+  if (conflicts.length === 0) {
+    editor.setStatus(editor.t("status.no_conflicts"));
+    return;
+  }
+
+  const cursorLine = editor.getCursorLine();
+  let index = direction > 0 ? 0 : conflicts.length - 1;
+  if (direction > 0) {
+    const next = conflicts.findIndex((c) => c.startLine > cursorLine);
+    if (next >= 0) index = next;
+  } else {
+    for (let i = conflicts.length - 1; i >= 0; i--) {
+      if (conflicts[i].startLine < cursorLine) {
+        index = i;
+        break;
+      }
+    }
+  }
+
+  const conflict = conflicts[index];
+  editor.setBufferCursor(bufferId, conflict.start);
+  editor.scrollToLineCenter(editor.getActiveSplitId(), bufferId, conflict.startLine);
+  editor.setStatus(
+    editor.t("status.conflict_of", {
+      current: String(index + 1),
+      total: String(conflicts.length),
+    })
+  );
+}
+
+globalThis.merge_inline_next_conflict = function (): Promise<void> {
+  return gotoInlineConflict(1);
+};
+
+globalThis.merge_inline_prev_conflict = function (): Promise<void> {
+  return gotoInlineConflict(-1);
+};
+
+/**
+ * Resolve a conflict when one of its action hints is clicked
+ */
+globalThis.onMergeInlineClick = async function (data: {
+  buffer_id: number | null;
+  byte_position: number | null;
+  text_column: number | null;
+}): Promise<void> {
+  const { buffer_id: bufferId, byte_position: position, text_column: column } = data;
+  if (bufferId === null || position === null || column === null) return;
+
+  const conflict = inlineConflicts
+    .get(bufferId)
+    ?.find((c) => position >= c.start && position <= c.hintAnchor);
+  if (!conflict) return;
+
+  const resolution = inlineHintAt(column - conflict.hintColumn);
+  if (resolution) {
+    await resolveInlineConflict(bufferId, conflict.startLine, resolution);
+  }
+};
+
+globalThis.onMergeInlineBufferActivated = function (data: { buffer_id: number }): void {
+  scanInlineConflicts(data.buffer_id);
+};
+
+globalThis.onMergeInlineAfterEdit = function (data: { buffer_id: number; text?: string }): void {
+  // Only files that already have conflicts, or edits that may add one, need a rescan
+  if (inlineConflicts.has(data.buffer_id) || data.text?.includes("<<<<<<<")) {
+    scanInlineConflicts(data.buffer_id);
+  }
+};
+
+globalThis.onMergeInlineBufferClosed = function (data: { buffer_id: number }): void {
+  inlineConflicts.delete(data.buffer_id);
+  inlineScanGeneration.delete(data.buffer_id);
+};
+
 // =============================================================================
 // Hook Handlers - Auto-Detection
 // =============================================================================
@@ -1750,6 +2077,13 @@ globalThis.onMergeAfterFileOpen = async function(data: { buffer_id: number; path
 
 editor.on("buffer_activated", "onMergeBufferActivated");
 editor.on("after_file_open", "onMergeAfterFileOpen");
+editor.on("after_file_open", "onMergeInlineBufferActivated");
+editor.on("buffer_activated", "onMergeInlineBufferActivated");
+editor.on("after_file_save", "onMergeInlineBufferActivated");
+editor.on("after_insert", "onMergeInlineAfterEdit");
+editor.on("after_delete", "onMergeInlineAfterEdit");
+editor.on("buffer_closed", "onMergeInlineBufferClosed");
+editor.on("mouse_click", "onMergeInlineClick");
 
 // =============================================================================
 // Command Registration - Dynamic based on merge mode state
@@ -1792,6 +2126,13 @@ editor.registerCommand(
   null  // Always visible - entry point command
 );
 
+// Inline commands act on the conflict markers in the current file
+editor.registerCommand("%cmd.accept_ours", "%cmd.accept_ours_desc", "merge_inline_accept_ours", null);
+editor.registerCommand("%cmd.accept_theirs", "%cmd.accept_theirs_desc", "merge_inline_accept_theirs", null);
+editor.registerCommand("%cmd.accept_both", "%cmd.accept_both_desc", "merge_inline_accept_both", null);
+editor.registerCommand("%cmd.next_marker", "%cmd.next_marker_desc", "merge_inline_next_conflict", null);
+editor.registerCommand("%cmd.prev_marker", "%cmd.prev_marker_desc", "merge_inline_prev_conflict", null);
+
 // =============================================================================
 // Plugin Initialization
 // =============================================================================
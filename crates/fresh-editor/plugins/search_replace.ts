@@ -5,37 +5,52 @@ const editor = getEditor();
 /**
  * Multi-File Search & Replace Plugin
  *
- * Provides project-wide search and replace functionality using git grep.
- * Shows results in a virtual buffer split with preview and confirmation.
+ * Provides project-wide search and replace. Candidate files come from git grep
+ * (git-tracked files, or all files not ignored by .gitignore), matches are
+ * grouped by file in a virtual buffer split, and the selected replacements are
+ * applied with editor.replaceInFile: open buffers are edited in memory and
+ * other files are rewritten on disk with an atomic save.
  */
 
-// Result item structure
-interface SearchResult {
-  file: string;
-  line: number;
-  column: number;
-  content: string;
-  selected: boolean; // Whether this result will be replaced
+// A single match inside a file
+interface MatchResult {
+  line: number;        // 1-based line of the match start
+  column: number;      // 1-based column (in characters)
+  start: number;       // Byte offset of the match
+  end: number;         // Byte offset just past the match
+  lineText: string;    // Line containing the match start
+  matched: string;     // Matched text
+  replacement: string; // Text the match will be replaced with
+  selected: boolean;   // Whether this match will be replaced
 }
 
+// All matches in one file
+interface FileResult {
+  file: string; // Path relative to the working directory
+  path: string; // Absolute path
+  matches: MatchResult[];
+}
+
+type SearchScope = "tracked" | "all";
+
 // Plugin state
 let panelOpen = false;
 let resultsBufferId: number | null = null;
 let sourceSplitId: number | null = null;
 let resultsSplitId: number | null = null;
-let searchResults: SearchResult[] = [];
+let fileResults: FileResult[] = [];
 let searchPattern: string = "";
 let replaceText: string = "";
 let searchRegex: boolean = false;
+let searchScope: SearchScope = "tracked";
 
-// Maximum results to display
-const MAX_RESULTS = 200;
+// Maximum matches to collect
+const MAX_RESULTS = 1000;
 
 // Define the search-replace mode with keybindings
 // Inherits from "normal" for cursor navigation (Up/Down)
-// Simplified keybindings following UX best practices:
-// - Enter: Execute replace (primary action)
-// - Space: Toggle selection
+// - Enter: Apply selected replacements (primary action)
+// - Space: Toggle the match or file under the cursor
 // - Escape: Close panel
 editor.defineMode(
   "search-replace-list",
@@ -43,6 +58,11 @@ editor.defineMode(
   [
     ["Return", "search_replace_execute"],
     ["space", "search_replace_toggle_item"],
+    ["a", "search_replace_select_all"],
+    ["n", "search_replace_select_none"],
+    ["r", "search_replace_toggle_regex"],
+    ["t", "search_replace_toggle_scope"],
+    ["o", "search_replace_preview"],
     ["Escape", "search_replace_close"],
   ],
   true // read-only
@@ -57,48 +77,142 @@ function getRelativePath(filePath: string): string {
   return filePath;
 }
 
-// Parse git grep output
-function parseGitGrepLine(line: string): SearchResult | null {
-  const match = line.match(/^([^:]+):(\d+):(\d+):(.*)$/);
-  if (match) {
-    return {
-      file: match[1],
-      line: parseInt(match[2], 10),
-      column: parseInt(match[3], 10),
-      content: match[4],
+// Escape a literal pattern for use in a RegExp
+function escapeRegExp(text: string): string {
+  return text.replace(/[.*+?^${}()|[\]\\]/g, "\\$&");
+}
+
+// Build the matcher for the current search; throws on an invalid regex
+function buildRegex(): RegExp {
+  return new RegExp(searchRegex ? searchPattern : escapeRegExp(searchPattern), "g");
+}
+
+// Expand $1, ${1}, ${name}, $<name>, $& and $$ in the replacement template
+function expandReplacement(match: RegExpExecArray, template: string): string {
+  if (!searchRegex) return template;
+  return template.replace(
+    /\$(\$|&|\d+|\{(\w+)\}|<(\w+)>)/g,
+    (whole: string, token: string, braced?: string, angled?: string) => {
+      if (token === "$") return "$";
+      if (token === "&") return match[0];
+      const name = braced ?? angled ?? token;
+      if (/^\d+$/.test(name)) {
+        const index = parseInt(name, 10);
+        return index < match.length ? (match[index] ?? "") : whole;
+      }
+      return match.groups?.[name] ?? whole;
+    }
+  );
+}
+
+// Find all matches in a file's content
+function findMatches(content: string, regex: RegExp): MatchResult[] {
+  const matches: MatchResult[] = [];
+  let byteOffset = 0;
+  let charOffset = 0;
+  let line = 1;
+  let lineStart = 0;
+
+  regex.lastIndex = 0;
+  let m: RegExpExecArray | null;
+  while ((m = regex.exec(content)) !== null) {
+    if (m[0].length === 0) {
+      // Empty matches can't be replaced meaningfully; step past them
+      regex.lastIndex++;
+      continue;
+    }
+
+    // Advance line and byte counters up to the match
+    for (let i = content.indexOf("\n", charOffset); i !== -1 && i < m.index; i = content.indexOf("\n", i + 1)) {
+      line++;
+      lineStart = i + 1;
+    }
+    byteOffset += editor.utf8ByteLength(content.slice(charOffset, m.index));
+    charOffset = m.index;
+
+    const lineEnd = content.indexOf("\n", lineStart);
+    matches.push({
+      line,
+      column: m.index - lineStart + 1,
+      start: byteOffset,
+      end: byteOffset + editor.utf8ByteLength(m[0]),
+      lineText: content.slice(lineStart, lineEnd === -1 ? content.length : lineEnd),
+      matched: m[0],
+      replacement: expandReplacement(m, replaceText),
       selected: true, // Selected by default
-    };
+    });
+  }
+  return matches;
+}
+
+// Current content of a file: the open buffer if there is one, else the file on disk
+async function readContent(path: string): Promise<string | null> {
+  const bufferId = editor.findBufferByPath(path);
+  if (bufferId !== 0) {
+    return await editor.getBufferText(bufferId, 0, editor.getBufferLength(bufferId));
+  }
+  return editor.readFile(path);
+}
+
+// List files that may contain matches, skipping binary files (-I)
+async function listCandidateFiles(): Promise<string[]> {
+  const cwd = editor.getCwd();
+  const base = ["grep", "-I", "-l", "-z"];
+  if (searchScope === "all") {
+    base.push("--untracked");
+  }
+  const pattern = searchRegex ? ["-P", "-e", searchPattern] : ["-F", "-e", searchPattern];
+
+  let result = await editor.spawnProcess("git", [...base, ...pattern], cwd);
+  if (result.exit_code > 1 && searchRegex) {
+    // git built without PCRE: fall back to extended regex
+    result = await editor.spawnProcess("git", [...base, "-E", "-e", searchPattern], cwd);
+  }
+  if (result.exit_code > 1) {
+    // Not a git repository: search the directory, still honoring .gitignore
+    const noIndex = ["grep", "--no-index", "--exclude-standard", "-I", "-l", "-z"];
+    const fallbackPattern = searchRegex ? ["-E", "-e", searchPattern] : pattern;
+    result = await editor.spawnProcess("git", [...noIndex, ...fallbackPattern], cwd);
+  }
+  if (result.exit_code !== 0) {
+    return [];
+  }
+  return result.stdout.split("\0").filter((f) => f.length > 0);
+}
+
+// Count selected matches
+function selectedCount(): number {
+  let count = 0;
+  for (const file of fileResults) {
+    count += file.matches.filter((m) => m.selected).length;
   }
-  return null;
+  return count;
 }
 
-// Format a result for display
-function formatResult(item: SearchResult, index: number): string {
-  const checkbox = item.selected ? "[x]" : "[ ]";
-  const displayPath = getRelativePath(item.file);
-  const location = `${displayPath}:${item.line}`;
-
-  // Truncate for display
-  const maxLocationLen = 40;
-  const truncatedLocation = location.length > maxLocationLen
-    ? "..." + location.slice(-(maxLocationLen - 3))
-    : location.padEnd(maxLocationLen);
-
-  const trimmedContent = item.content.trim();
-  const maxContentLen = 50;
-  const displayContent = trimmedContent.length > maxContentLen
-    ? trimmedContent.slice(0, maxContentLen - 3) + "..."
-    : trimmedContent;
-
-  return `${checkbox} ${truncatedLocation}  ${displayContent}\n`;
+// Count all matches
+function matchCount(): number {
+  return fileResults.reduce((sum, file) => sum + file.matches.length, 0);
+}
+
+// Truncate text for display
+function truncate(text: string, maxLen: number): string {
+  return text.length > maxLen ? text.slice(0, maxLen - 3) + "..." : text;
+}
+
+// Checkbox for a file: all, none, or some of its matches selected
+function fileCheckbox(file: FileResult): string {
+  const selected = file.matches.filter((m) => m.selected).length;
+  if (selected === file.matches.length) return "[x]";
+  if (selected === 0) return "[ ]";
+  return "[-]";
 }
 
 // Build panel entries
 function buildPanelEntries(): TextPropertyEntry[] {
   const entries: TextPropertyEntry[] = [];
+  const total = matchCount();
 
   // Header
-  const selectedCount = searchResults.filter(r => r.selected).length;
   entries.push({
     text: `═══ ${editor.t("panel.header")} ═══\n`,
     properties: { type: "header" },
@@ -111,43 +225,65 @@ function buildPanelEntries(): TextPropertyEntry[] {
     text: `${editor.t("panel.replace_label")} "${replaceText}"\n`,
     properties: { type: "info" },
   });
+  entries.push({
+    text: `${editor.t("panel.scope_label")} ${editor.t(searchScope === "all" ? "panel.scope_all" : "panel.scope_tracked")}\n`,
+    properties: { type: "info" },
+  });
   entries.push({
     text: `\n`,
     properties: { type: "spacer" },
   });
 
-  if (searchResults.length === 0) {
+  if (total === 0) {
     entries.push({
       text: "  " + editor.t("panel.no_matches") + "\n",
       properties: { type: "empty" },
     });
   } else {
     // Results header
-    const limitNote = searchResults.length >= MAX_RESULTS ? " " + editor.t("panel.limited", { max: String(MAX_RESULTS) }) : "";
+    const limitNote = total >= MAX_RESULTS ? " " + editor.t("panel.limited", { max: String(MAX_RESULTS) }) : "";
     entries.push({
-      text: `${editor.t("panel.results", { count: String(searchResults.length) })}${limitNote} ${editor.t("panel.selected", { selected: String(selectedCount) })}\n`,
+      text: `${editor.t("panel.results", { count: String(total), files: String(fileResults.length) })}${limitNote} ${editor.t("panel.selected", { selected: String(selectedCount()) })}\n`,
       properties: { type: "count" },
     });
-    entries.push({
-      text: `\n`,
-      properties: { type: "spacer" },
-    });
 
-    // Add each result
-    for (let i = 0; i < searchResults.length; i++) {
-      const result = searchResults[i];
+    // Each file, followed by its matches as -/+ preview pairs
+    for (let f = 0; f < fileResults.length; f++) {
+      const file = fileResults[f];
       entries.push({
-        text: formatResult(result, i),
-        properties: {
-          type: "result",
-          index: i,
-          location: {
-            file: result.file,
-            line: result.line,
-            column: result.column,
-          },
-        },
+        text: `\n`,
+        properties: { type: "spacer" },
       });
+      entries.push({
+        text: `${fileCheckbox(file)} ${file.file} (${file.matches.length})\n`,
+        properties: { type: "file", fileIndex: f },
+      });
+
+      for (let i = 0; i < file.matches.length; i++) {
+        const match = file.matches[i];
+        const properties = {
+          type: "match",
+          fileIndex: f,
+          matchIndex: i,
+          location: { file: file.path, line: match.line, column: match.column },
+        };
+        const checkbox = match.selected ? "[x]" : "[ ]";
+        const location = `${match.line}:${match.column}`.padEnd(9);
+        const before = match.lineText.slice(0, match.column - 1);
+        const after = match.lineText.slice(match.column - 1 + match.matched.length);
+        const replaced = match.matched.includes("\n")
+          ? before + match.replacement
+          : before + match.replacement + after;
+
+        entries.push({
+          text: `    ${checkbox} ${location} - ${truncate(match.lineText.trim(), 70)}\n`,
+          properties,
+        });
+        entries.push({
+          text: `        ${"".padEnd(9)} + ${truncate(replaced.trim(), 70)}\n`,
+          properties,
+        });
+      }
     }
   }
 
@@ -173,45 +309,42 @@ function updatePanelContent(): void {
 }
 
 // Perform the search
-async function performSearch(pattern: string, replace: string, isRegex: boolean): Promise<void> {
-  searchPattern = pattern;
-  replaceText = replace;
-  searchRegex = isRegex;
+async function performSearch(): Promise<void> {
+  fileResults = [];
 
-  // Build git grep args
-  const args = ["grep", "-n", "--column", "-I"];
-  if (isRegex) {
-    args.push("-E"); // Extended regex
-  } else {
-    args.push("-F"); // Fixed string
+  let regex: RegExp;
+  try {
+    regex = buildRegex();
+  } catch (e) {
+    editor.setStatus(editor.t("status.invalid_regex", { error: String(e) }));
+    return;
   }
-  args.push("--", pattern);
 
   try {
     const cwd = editor.getCwd();
-    const result = await editor.spawnProcess("git", args, cwd);
-
-    searchResults = [];
-
-    if (result.exit_code === 0) {
-      for (const line of result.stdout.split("\n")) {
-        if (!line.trim()) continue;
-        const match = parseGitGrepLine(line);
-        if (match) {
-          searchResults.push(match);
-          if (searchResults.length >= MAX_RESULTS) break;
-        }
-      }
+    let total = 0;
+
+    for (const file of await listCandidateFiles()) {
+      const path = editor.pathJoin(cwd, file);
+      const content = await readContent(path);
+      if (content === null) continue; // Unreadable or not UTF-8
+
+      const matches = findMatches(content, regex).slice(0, MAX_RESULTS - total);
+      if (matches.length === 0) continue;
+
+      fileResults.push({ file, path, matches });
+      total += matches.length;
+      if (total >= MAX_RESULTS) break;
     }
 
-    if (searchResults.length === 0) {
-      editor.setStatus(editor.t("status.no_matches", { pattern }));
+    if (total === 0) {
+      editor.setStatus(editor.t("status.no_matches", { pattern: searchPattern }));
     } else {
-      editor.setStatus(editor.t("status.found_matches", { count: String(searchResults.length) }));
+      editor.setStatus(editor.t("status.found_matches", { count: String(total), files: String(fileResults.length) }));
     }
   } catch (e) {
     editor.setStatus(editor.t("status.search_error", { error: String(e) }));
-    searchResults = [];
+    fileResults = [];
   }
 }
 
@@ -248,84 +381,73 @@ async function showResultsPanel(): Promise<void> {
   }
 }
 
+// Re-run the search with the current settings and refresh the panel
+async function refreshSearch(): Promise<void> {
+  await performSearch();
+  updatePanelContent();
+}
+
 // Execute replacements
 async function executeReplacements(): Promise<void> {
-  const selectedResults = searchResults.filter(r => r.selected);
-
-  if (selectedResults.length === 0) {
-    editor.setStatus(editor.t("status.no_selected"));
+  let regex: RegExp;
+  try {
+    regex = buildRegex();
+  } catch (e) {
+    editor.setStatus(editor.t("status.invalid_regex", { error: String(e) }));
     return;
   }
 
-  // Group by file
-  const fileGroups: Map<string, SearchResult[]> = new Map();
-  for (const result of selectedResults) {
-    if (!fileGroups.has(result.file)) {
-      fileGroups.set(result.file, []);
-    }
-    fileGroups.get(result.file)!.push(result);
-  }
-
   let filesModified = 0;
   let replacementsCount = 0;
   const errors: string[] = [];
 
-  for (const [filePath, results] of fileGroups) {
-    try {
-      // Read file
-      const content = await editor.readFile(filePath);
-      const lines = content.split("\n");
-
-      // Sort results by line (descending) to avoid offset issues
-      const sortedResults = [...results].sort((a, b) => {
-        if (a.line !== b.line) return b.line - a.line;
-        return b.column - a.column;
-      });
+  for (const file of fileResults) {
+    const selected = file.matches.filter((m) => m.selected);
+    if (selected.length === 0) continue;
 
-      // Apply replacements
-      for (const result of sortedResults) {
-        const lineIndex = result.line - 1;
-        if (lineIndex >= 0 && lineIndex < lines.length) {
-          let line = lines[lineIndex];
-
-          if (searchRegex) {
-            // Regex replacement
-            const regex = new RegExp(searchPattern, "g");
-            lines[lineIndex] = line.replace(regex, replaceText);
-          } else {
-            // Simple string replacement (all occurrences in line)
-            lines[lineIndex] = line.split(searchPattern).join(replaceText);
-          }
-          replacementsCount++;
-        }
+    try {
+      // Re-scan the current content so edits made since the search are
+      // respected; only matches that are still in place get replaced
+      const content = await readContent(file.path);
+      if (content === null) {
+        throw new Error("unreadable file");
+      }
+      const wanted = new Set(selected.map((m) => `${m.start}:${m.matched}`));
+      const edits = findMatches(content, regex)
+        .filter((m) => wanted.has(`${m.start}:${m.matched}`))
+        .map((m) => ({ start: m.start, end: m.end, text: m.replacement }));
+      if (edits.length < selected.length) {
+        errors.push(`${file.file}: ${selected.length - edits.length} matches changed since the search`);
       }
+      if (edits.length === 0) continue;
 
-      // Write back
-      const newContent = lines.join("\n");
-      await editor.writeFile(filePath, newContent);
+      replacementsCount += await editor.replaceInFile(file.path, edits);
       filesModified++;
-
     } catch (e) {
       const errorMessage = e instanceof Error ? e.message : String(e);
-      errors.push(`${filePath}: ${errorMessage}`);
+      errors.push(`${file.file}: ${errorMessage}`);
     }
   }
 
   // Report results
   if (errors.length > 0) {
-    editor.setStatus(editor.t("status.replaced_with_errors", { files: String(filesModified), errors: String(errors.length) }));
+    editor.setStatus(editor.t("status.replaced_with_errors", {
+      count: String(replacementsCount),
+      files: String(filesModified),
+      errors: String(errors.length),
+    }));
     editor.debug(`Replacement errors: ${errors.join(", ")}`);
   } else {
     editor.setStatus(editor.t("status.replaced", { count: String(replacementsCount), files: String(filesModified) }));
   }
 
-  // Close panel after replacement
-  globalThis.search_replace_close();
+  // Close panel after replacement, keeping the summary visible
+  closePanel();
 }
 
 // Start search/replace workflow
 globalThis.start_search_replace = function(): void {
-  searchResults = [];
+  fileResults = [];
   searchPattern = "";
   replaceText = "";
 
@@ -369,7 +491,7 @@ globalThis.onSearchReplaceReplaceConfirmed = async function(args: {
   replaceText = args.input; // Can be empty for deletion
 
   // Perform search and show results
-  await performSearch(searchPattern, replaceText, false);
+  await performSearch();
   await showResultsPanel();
 
   return true;
@@ -388,43 +510,70 @@ globalThis.onSearchReplacePromptCancelled = function(args: {
   return true;
 };
 
-// Toggle selection of current item
+// Set the selection of every match
+function selectAll(selected: boolean): void {
+  for (const file of fileResults) {
+    for (const match of file.matches) {
+      match.selected = selected;
+    }
+  }
+  updatePanelContent();
+  editor.setStatus(editor.t("status.selected_count", { selected: String(selectedCount()), total: String(matchCount()) }));
+}
+
+// Toggle selection of the match or file under the cursor
 globalThis.search_replace_toggle_item = function(): void {
-  if (resultsBufferId === null || searchResults.length === 0) return;
+  if (resultsBufferId === null || fileResults.length === 0) return;
 
   const props = editor.getTextPropertiesAtCursor(resultsBufferId);
-  if (props.length > 0 && typeof props[0].index === "number") {
-    const index = props[0].index as number;
-    if (index >= 0 && index < searchResults.length) {
-      searchResults[index].selected = !searchResults[index].selected;
-      updatePanelContent();
-      const selected = searchResults.filter(r => r.selected).length;
-      editor.setStatus(editor.t("status.selected_count", { selected: String(selected), total: String(searchResults.length) }));
+  if (props.length === 0 || typeof props[0].fileIndex !== "number") return;
+
+  const file = fileResults[props[0].fileIndex as number];
+  if (!file) return;
+
+  if (typeof props[0].matchIndex === "number") {
+    const match = file.matches[props[0].matchIndex as number];
+    if (!match) return;
+    match.selected = !match.selected;
+  } else {
+    // File row: select all of its matches, or clear them if all are selected
+    const select = file.matches.some((m) => !m.selected);
+    for (const match of file.matches) {
+      match.selected = select;
     }
   }
+
+  updatePanelContent();
+  editor.setStatus(editor.t("status.selected_count", { selected: String(selectedCount()), total: String(matchCount()) }));
 };
 
 // Select all items
 globalThis.search_replace_select_all = function(): void {
-  for (const result of searchResults) {
-    result.selected = true;
-  }
-  updatePanelContent();
-  editor.setStatus(editor.t("status.selected_count", { selected: String(searchResults.length), total: String(searchResults.length) }));
+  selectAll(true);
 };
 
 // Select no items
 globalThis.search_replace_select_none = function(): void {
-  for (const result of searchResults) {
-    result.selected = false;
-  }
-  updatePanelContent();
-  editor.setStatus(editor.t("status.selected_count", { selected: "0", total: String(searchResults.length) }));
+  selectAll(false);
+};
+
+// Switch between literal and regex search
+globalThis.search_replace_toggle_regex = async function(): Promise<void> {
+  if (!panelOpen) return;
+  searchRegex = !searchRegex;
+  await refreshSearch();
+};
+
+// Switch between git-tracked files and all non-ignored files
+globalThis.search_replace_toggle_scope = async function(): Promise<void> {
+  if (!panelOpen) return;
+  searchScope = searchScope === "tracked" ? "all" : "tracked";
+  await refreshSearch();
 };
 
 // Execute replacement
 globalThis.search_replace_execute = function(): void {
-  const selected = searchResults.filter(r => r.selected).length;
+  const selected = selectedCount();
   if (selected === 0) {
     editor.setStatus(editor.t("status.no_items_selected"));
     return;
@@ -448,8 +597,8 @@ globalThis.search_replace_preview = function(): void {
   }
 };
 
-// Close the panel
-globalThis.search_replace_close = function(): void {
+// Close the panel and reset its state
+function closePanel(): void {
   if (!panelOpen) return;
 
   if (resultsBufferId !== null) {
@@ -464,7 +613,13 @@ globalThis.search_replace_close = function(): void {
   resultsBufferId = null;
   sourceSplitId = null;
   resultsSplitId = null;
-  searchResults = [];
+  fileResults = [];
+}
+
+// Close the panel
+globalThis.search_replace_close = function(): void {
+  if (!panelOpen) return;
+  closePanel();
   editor.setStatus(editor.t("status.closed"));
 };
 
@@ -0,0 +1,183 @@
+//! Abbreviation expansion for the Editor.
+//!
+//! Typing a word boundary (a space, punctuation or Enter) right after an
+//! abbreviation replaces it with its expansion, e.g. "teh " becomes "the ".
+//! Backspace right after that puts the abbreviation back and keeps the
+//! boundary character.
+//!
+//! The global `abbreviations` table is merged with the language's own
+//! `languages.<name>.abbreviations`, which win. With
+//! `editor.abbreviations_in_code_only` nothing expands inside strings and
+//! comments.
+
+use crate::input::keybindings::{Action, KeyContext};
+use crate::model::event::Event;
+use crate::primitives::abbreviation::lookup_abbreviation;
+use crate::primitives::word_navigation::find_completion_word_start;
+
+use super::types::AbbreviationExpansion;
+use super::Editor;
+
+impl Editor {
+    /// Expand abbreviations before word boundaries, and revert the last
+    /// expansion on Backspace
+    ///
+    /// Returns true when the action was a Backspace that reverted an
+    /// expansion. Expansions only happen for a single cursor without a
+    /// selection.
+    pub(super) fn handle_abbreviation_action(&mut self, action: &Action) -> bool {
+        let expansion = self.abbreviation_expansion.take();
+        if self.is_prompting()
+            || self.key_context != KeyContext::Normal
+            || self.is_editing_disabled()
+            || self.active_cursors().count() > 1
+            || self.active_cursors().primary().selection_range().is_some()
+        {
+            return false;
+        }
+        match action {
+            Action::DeleteBackward => expansion.is_some_and(|e| self.revert_abbreviation(e)),
+            Action::InsertChar(c) if !c.is_alphanumeric() && *c != '_' => {
+                self.expand_abbreviation();
+                false
+            }
+            Action::InsertNewline => {
+                self.expand_abbreviation();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Replace the abbreviation before the cursor with its expansion
+    fn expand_abbreviation(&mut self) {
+        let buffer_id = self.active_buffer();
+        let cursor_id = self.active_cursors().primary_id();
+        let position = self.active_cursors().primary().position;
+        let context_bytes = self.config.editor.highlight_context_bytes;
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+
+        let word_start = find_completion_word_start(&state.buffer, position);
+        if word_start == position {
+            return;
+        }
+        let typed = state.get_text_range(word_start, position);
+        let language_table = self
+            .config
+            .languages
+            .get(&state.language)
+            .and_then(|language| language.abbreviations.as_ref());
+        let tables: Vec<_> = language_table
+            .into_iter()
+            .chain(std::iter::once(&self.config.abbreviations))
+            .collect();
+        let Some(expansion) = lookup_abbreviation(&tables, &typed) else {
+            return;
+        };
+        if expansion == typed {
+            return;
+        }
+
+        if self.config.editor.abbreviations_in_code_only {
+            let inert = state.highlighter.string_and_comment_ranges(
+                &state.buffer,
+                word_start,
+                position,
+                &self.theme,
+                context_bytes,
+            );
+            if inert
+                .iter()
+                .any(|range| range.start <= word_start && word_start < range.end)
+            {
+                return;
+            }
+        }
+
+        let batch = Event::Batch {
+            events: vec![
+                Event::Delete {
+                    range: word_start..position,
+                    deleted_text: typed.clone(),
+                    cursor_id,
+                },
+                Event::Insert {
+                    position: word_start,
+                    text: expansion.clone(),
+                    cursor_id,
+                },
+            ],
+            description: "Expand abbreviation".to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
+
+        self.abbreviation_expansion = Some(AbbreviationExpansion {
+            buffer_id,
+            range: word_start..word_start + expansion.len(),
+            expansion,
+            typed,
+        });
+    }
+
+    /// Put back the abbreviation of `expansion`, keeping what was typed
+    /// after it
+    ///
+    /// Returns false when the expansion was edited since or the cursor is no
+    /// longer after it.
+    fn revert_abbreviation(&mut self, expansion: AbbreviationExpansion) -> bool {
+        let AbbreviationExpansion {
+            buffer_id,
+            range,
+            expansion,
+            typed,
+        } = expansion;
+        if buffer_id != self.active_buffer() || range.end > self.active_state().buffer.len() {
+            return false;
+        }
+        let cursor = *self.active_cursors().primary();
+        if cursor.position <= range.end {
+            return false;
+        }
+        if self
+            .active_state_mut()
+            .get_text_range(range.start, range.end)
+            != expansion
+        {
+            return false;
+        }
+
+        let cursor_id = self.active_cursors().primary_id();
+        let after_insert = range.start + typed.len();
+        let new_position = cursor.position - range.len() + typed.len();
+        let batch = Event::Batch {
+            events: vec![
+                Event::Delete {
+                    range: range.clone(),
+                    deleted_text: expansion,
+                    cursor_id,
+                },
+                Event::Insert {
+                    position: range.start,
+                    text: typed,
+                    cursor_id,
+                },
+                Event::MoveCursor {
+                    cursor_id,
+                    old_position: after_insert,
+                    new_position,
+                    old_anchor: None,
+                    new_anchor: None,
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: 0,
+                },
+            ],
+            description: "Revert abbreviation".to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
+        true
+    }
+}
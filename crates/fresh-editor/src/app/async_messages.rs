@@ -67,7 +67,13 @@ impl Editor {
 
 impl Editor {
     /// Store and apply diagnostics, emit hook for plugins
-    fn store_and_apply_diagnostics(&mut self, uri: String, diagnostics: Vec<Diagnostic>) {
+    ///
+    /// Problems reported by the language's linter are kept alongside them.
+    fn store_and_apply_diagnostics(&mut self, uri: String, mut diagnostics: Vec<Diagnostic>) {
+        if let Some(linter_diagnostics) = self.linter_diagnostics.get(&uri) {
+            diagnostics.extend(linter_diagnostics.iter().cloned());
+        }
+
         // Store diagnostics for later retrieval by plugins
         if diagnostics.is_empty() {
             self.stored_diagnostics.remove(&uri);
@@ -96,6 +102,27 @@ impl Editor {
         );
     }
 
+    /// Replace the diagnostics reported by the linter for a file, keeping
+    /// those from language servers
+    pub(super) fn set_linter_diagnostics(&mut self, uri: String, diagnostics: Vec<Diagnostic>) {
+        let previous = self.linter_diagnostics.remove(&uri).unwrap_or_default();
+        let lsp_diagnostics = self
+            .stored_diagnostics
+            .get(&uri)
+            .map(|stored| {
+                stored
+                    .iter()
+                    .filter(|d| !previous.contains(d))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        if !diagnostics.is_empty() {
+            self.linter_diagnostics.insert(uri.clone(), diagnostics);
+        }
+        self.store_and_apply_diagnostics(uri, lsp_diagnostics);
+    }
+
     /// Handle LSP diagnostics (push model)
     pub(super) fn handle_lsp_diagnostics(&mut self, uri: String, diagnostics: Vec<Diagnostic>) {
         tracing::debug!(
@@ -959,8 +986,22 @@ impl Editor {
             .set_show_gitignored(show_gitignored);
         tracing::debug!("Applied show_gitignored={} on init", show_gitignored);
 
+        // Custom hidden patterns are shown and hidden together with gitignored files
+        for pattern in &self.config.file_explorer.custom_ignore_patterns {
+            view.ignore_patterns_mut()
+                .add_custom_pattern(pattern.clone());
+        }
+        view.ignore_patterns_mut()
+            .set_show_custom_ignored(show_gitignored);
+
         self.file_explorer = Some(view);
         self.set_status_message(t!("status.file_explorer_ready").to_string());
+
+        if std::mem::take(&mut self.pending_file_explorer_reveal) {
+            self.reveal_in_file_explorer();
+        } else {
+            self.follow_active_file_in_explorer();
+        }
     }
 
     /// Handle file explorer node toggle completed
@@ -974,14 +1015,93 @@ impl Editor {
         self.set_status_message(t!("explorer.refreshed_default").to_string());
     }
 
+    /// Handle a file explorer directory listing
+    ///
+    /// A loading directory gets its children; an already expanded directory
+    /// (re-read because it changed on disk) is updated in place.
+    pub(super) fn handle_file_explorer_dir_loaded(
+        &mut self,
+        node_id: NodeId,
+        result: std::io::Result<Vec<crate::services::fs::DirEntry>>,
+    ) {
+        let Some(explorer) = &mut self.file_explorer else {
+            // The view is away on an expand-to-path task; loading directories
+            // are re-listed when it comes back
+            return;
+        };
+        let Some(node) = explorer.tree().get_node(node_id) else {
+            return;
+        };
+
+        if explorer.is_filter_loading(node_id) {
+            let reads = explorer.filter_dir_loaded(node_id, result);
+            explorer.update_scroll_for_selection();
+            for (node_id, path) in reads {
+                self.spawn_file_explorer_dir_listing(node_id, path);
+            }
+        } else if node.is_loading() {
+            match explorer.tree_mut().finish_loading(node_id, result) {
+                Ok(()) => self.file_explorer_dir_expanded(node_id),
+                Err(e) => {
+                    self.set_status_message(
+                        t!("explorer.error", error = e.to_string()).to_string(),
+                    );
+                }
+            }
+        } else if node.is_expanded() {
+            let dir_path = node.entry.path.clone();
+            let entries = match result {
+                Ok(entries) => entries,
+                Err(e) => {
+                    tracing::warn!("Failed to refresh directory {:?}: {}", dir_path, e);
+                    return;
+                }
+            };
+
+            let changed = explorer.sync_dir_children(node_id, entries);
+            if changed.is_empty() {
+                return;
+            }
+            tracing::debug!(
+                "Directory changed: {:?} ({} entries)",
+                dir_path,
+                changed.len()
+            );
+            if let Err(e) = explorer.load_gitignore_for_dir(&dir_path) {
+                tracing::warn!("Failed to load .gitignore from {:?}: {}", dir_path, e);
+            }
+            explorer.update_scroll_for_selection();
+
+            self.plugin_manager.run_hook(
+                "file_explorer_paths_changed",
+                crate::services::plugins::hooks::HookArgs::FileExplorerPathsChanged {
+                    paths: changed,
+                },
+            );
+        }
+    }
+
     /// Handle file explorer expanded to path
     pub(super) fn handle_file_explorer_expanded_to_path(&mut self, mut view: FileTreeView) {
         tracing::trace!(
             "handle_file_explorer_expanded_to_path: restoring file_explorer after async expand"
         );
         view.update_scroll_for_selection();
+
+        // Listings that arrived while the view was away were dropped
+        let loading: Vec<(NodeId, PathBuf)> = view
+            .tree()
+            .all_nodes()
+            .filter(|node| node.is_loading())
+            .map(|node| (node.id, node.entry.path.clone()))
+            .collect();
+
         self.file_explorer = Some(view);
         self.file_explorer_sync_in_progress = false;
+
+        for (node_id, path) in loading {
+            self.spawn_file_explorer_dir_listing(node_id, path);
+        }
     }
 }
 
@@ -1074,6 +1194,7 @@ impl Editor {
     /// Process pending plugin action completions
     #[cfg(feature = "plugins")]
     pub(super) fn process_pending_plugin_actions(&mut self) {
+        let mut errors = Vec::new();
         self.pending_plugin_actions
             .retain(|(action_name, receiver)| {
                 match receiver.try_recv() {
@@ -1086,7 +1207,8 @@ impl Editor {
                                 );
                             }
                             Err(e) => {
-                                tracing::error!("Plugin action '{}' error: {}", action_name, e);
+                                errors
+                                    .push(format!("Plugin action '{}' error: {}", action_name, e));
                             }
                         }
                         false // Remove completed action
@@ -1103,6 +1225,9 @@ impl Editor {
                     }
                 }
             });
+        for error in errors {
+            self.log_plugin_issue(&error);
+        }
     }
 
     /// Process pending LSP server restarts (with exponential backoff)
@@ -0,0 +1,390 @@
+//! Batch scripts: editing files without a terminal.
+//!
+//! `fresh --batch script.fresh file.rs` opens the files, applies the script
+//! through the same action dispatch the keyboard uses, and saves the result
+//! (or prints it with `--stdout`). Scripts use the syntax of the test
+//! harness's input scripts: statements separated by newlines or `;`, `#`
+//! comments and double-quoted strings with `\"`, `\\`, `\n`, `\r` and `\t`
+//! escapes.
+//!
+//! ```text
+//! goto 12 5
+//! replace "old_name" "new_name"
+//! search "fn main"; action move_line_end; type " // entry point"
+//! save
+//! ```
+//!
+//! Statements:
+//!
+//! ```text
+//! open "PATH"                       make PATH the active buffer
+//! goto LINE [COLUMN]                1-based
+//! search "TEXT"                     move to the next match
+//! replace "SEARCH" "REPLACEMENT" [regex]
+//!                                   replace every match
+//! type "TEXT"                       insert at the cursors
+//! fold                              toggle the fold at the cursor
+//! action NAME                       any action by its keybinding name
+//! save
+//! expect_buffer "TEXT"              the active buffer's whole content
+//! ```
+//!
+//! A step fails when it finds nothing to act on, when the editor reports an
+//! error, or when it opens a prompt, since nobody can answer it.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use super::input_recorder::quote;
+use super::Editor;
+use crate::input::keybindings::Action;
+
+/// One statement of a batch script
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchStep {
+    Open(PathBuf),
+    Goto {
+        line: usize,
+        column: Option<usize>,
+    },
+    Search(String),
+    Replace {
+        search: String,
+        replacement: String,
+        regex: bool,
+    },
+    Type(String),
+    Fold,
+    Action(String),
+    Save,
+    ExpectBuffer(String),
+}
+
+/// A parsed statement and the line it starts on
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchLine {
+    pub line: usize,
+    pub step: BatchStep,
+}
+
+impl fmt::Display for BatchStep {
+    /// The statement as it would be written in a script
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchStep::Open(path) => write!(f, "open {}", quote(&path.to_string_lossy())),
+            BatchStep::Goto { line, column } => match column {
+                Some(column) => write!(f, "goto {} {}", line, column),
+                None => write!(f, "goto {}", line),
+            },
+            BatchStep::Search(text) => write!(f, "search {}", quote(text)),
+            BatchStep::Replace {
+                search,
+                replacement,
+                regex,
+            } => {
+                write!(f, "replace {} {}", quote(search), quote(replacement))?;
+                if *regex {
+                    write!(f, " regex")?;
+                }
+                Ok(())
+            }
+            BatchStep::Type(text) => write!(f, "type {}", quote(text)),
+            BatchStep::Fold => write!(f, "fold"),
+            BatchStep::Action(name) => write!(f, "action {}", name),
+            BatchStep::Save => write!(f, "save"),
+            BatchStep::ExpectBuffer(text) => write!(f, "expect_buffer {}", quote(text)),
+        }
+    }
+}
+
+/// A word or a quoted string
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+}
+
+impl Token {
+    fn text(&self) -> &str {
+        match self {
+            Token::Word(text) | Token::Quoted(text) => text,
+        }
+    }
+}
+
+/// Split a script into statements of tokens, each with its line number
+fn tokenize(source: &str) -> Result<Vec<(usize, Vec<Token>)>, String> {
+    let mut statements = Vec::new();
+    let mut current: Vec<Token> = Vec::new();
+    let mut start_line = 1;
+    let mut line = 1;
+    let mut chars = source.chars().peekable();
+
+    let mut finish = |current: &mut Vec<Token>, start_line: usize| {
+        if !current.is_empty() {
+            statements.push((start_line, std::mem::take(current)));
+        }
+    };
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\n' | ';' => {
+                finish(&mut current, start_line);
+                if ch == '\n' {
+                    line += 1;
+                }
+            }
+            '#' => {
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            c if c.is_whitespace() => {}
+            '"' => {
+                if current.is_empty() {
+                    start_line = line;
+                }
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('n') => text.push('\n'),
+                            Some('r') => text.push('\r'),
+                            Some('t') => text.push('\t'),
+                            Some(c @ ('"' | '\\')) => text.push(c),
+                            Some(c) => {
+                                return Err(format!("line {}: unknown escape \\{}", line, c))
+                            }
+                            None => return Err(format!("line {}: unterminated string", line)),
+                        },
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            text.push(c);
+                        }
+                        None => return Err(format!("line {}: unterminated string", line)),
+                    }
+                }
+                current.push(Token::Quoted(text));
+            }
+            c => {
+                if current.is_empty() {
+                    start_line = line;
+                }
+                let mut word = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, ';' | '#' | '"') {
+                        break;
+                    }
+                    word.push(next);
+                    chars.next();
+                }
+                current.push(Token::Word(word));
+            }
+        }
+    }
+    finish(&mut current, start_line);
+    Ok(statements)
+}
+
+fn parse_number(token: Option<&Token>, what: &str) -> Result<usize, String> {
+    let token = token.ok_or_else(|| format!("missing {}", what))?;
+    match token.text().parse() {
+        Ok(0) | Err(_) => Err(format!(
+            "{} must be a positive number, got '{}'",
+            what,
+            token.text()
+        )),
+        Ok(number) => Ok(number),
+    }
+}
+
+fn parse_string(token: Option<&Token>, what: &str) -> Result<String, String> {
+    match token {
+        Some(Token::Quoted(text)) => Ok(text.clone()),
+        Some(Token::Word(word)) => Err(format!("{} must be quoted, got {}", what, word)),
+        None => Err(format!("missing {}", what)),
+    }
+}
+
+fn parse_statement(tokens: &[Token]) -> Result<BatchStep, String> {
+    let (command, args) = tokens.split_first().ok_or("empty statement")?;
+    let max_args = match command.text() {
+        "fold" | "save" => 0,
+        "open" | "search" | "type" | "action" | "expect_buffer" => 1,
+        "goto" => 2,
+        "replace" => 3,
+        other => return Err(format!("unknown statement '{}'", other)),
+    };
+    if args.len() > max_args {
+        return Err(format!(
+            "unexpected '{}' after {}",
+            args[max_args].text(),
+            command.text()
+        ));
+    }
+    Ok(match command.text() {
+        "open" => BatchStep::Open(PathBuf::from(parse_string(args.first(), "path")?)),
+        "goto" => BatchStep::Goto {
+            line: parse_number(args.first(), "line")?,
+            column: args
+                .get(1)
+                .map(|token| parse_number(Some(token), "column"))
+                .transpose()?,
+        },
+        "search" => BatchStep::Search(parse_string(args.first(), "search text")?),
+        "replace" => BatchStep::Replace {
+            search: parse_string(args.first(), "search text")?,
+            replacement: parse_string(args.get(1), "replacement")?,
+            regex: match args.get(2) {
+                None => false,
+                Some(Token::Word(word)) if word == "regex" => true,
+                Some(other) => return Err(format!("expected regex, got '{}'", other.text())),
+            },
+        },
+        "type" => BatchStep::Type(parse_string(args.first(), "text")?),
+        "fold" => BatchStep::Fold,
+        "action" => {
+            let name = args.first().ok_or("missing action name")?.text();
+            if Action::from_str(name, &HashMap::new()).is_none() {
+                return Err(format!("unknown action '{}'", name));
+            }
+            BatchStep::Action(name.to_string())
+        }
+        "save" => BatchStep::Save,
+        "expect_buffer" => BatchStep::ExpectBuffer(parse_string(args.first(), "text")?),
+        _ => unreachable!("checked above"),
+    })
+}
+
+/// Parse a batch script, reporting the first error with its line
+pub fn parse_batch_script(source: &str) -> Result<Vec<BatchLine>, String> {
+    tokenize(source)?
+        .into_iter()
+        .map(|(line, tokens)| {
+            parse_statement(&tokens)
+                .map(|step| BatchLine { line, step })
+                .map_err(|e| format!("line {}: {}", line, e))
+        })
+        .collect()
+}
+
+impl Editor {
+    /// Apply one step of a batch script to the active buffer
+    pub fn run_batch_step(&mut self, step: &BatchStep) -> Result<(), String> {
+        match step {
+            BatchStep::Open(path) => {
+                self.open_file(path).map_err(|e| e.to_string())?;
+            }
+            BatchStep::Goto { line, column } => self.goto_line_col(*line, *column),
+            BatchStep::Search(text) => {
+                self.perform_search(text);
+                if self.search_state.is_none() {
+                    return Err(self.batch_failure("no match"));
+                }
+            }
+            BatchStep::Replace {
+                search,
+                replacement,
+                regex,
+            } => {
+                let use_regex = std::mem::replace(&mut self.search_use_regex, *regex);
+                let count = self.perform_replace(search, replacement);
+                self.search_use_regex = use_regex;
+                if count == 0 {
+                    return Err(self.batch_failure("nothing replaced"));
+                }
+            }
+            BatchStep::Type(text) => self.paste_text(text.clone()),
+            BatchStep::Fold => self.toggle_fold_at_cursor(),
+            BatchStep::Action(name) => {
+                let action = Action::from_str(name, &HashMap::new())
+                    .ok_or_else(|| format!("unknown action '{}'", name))?;
+                self.handle_action(action).map_err(|e| e.to_string())?;
+            }
+            BatchStep::Save => self.save().map_err(|e| e.to_string())?,
+            BatchStep::ExpectBuffer(expected) => {
+                let actual = self
+                    .get_buffer_content(self.active_buffer())
+                    .unwrap_or_default();
+                if actual != *expected {
+                    return Err(format!(
+                        "buffer is {}, expected {}",
+                        quote(&actual),
+                        quote(expected)
+                    ));
+                }
+            }
+        }
+        if let Some(prompt) = &self.prompt {
+            let message = prompt.message.trim().to_string();
+            self.cancel_prompt();
+            return Err(format!(
+                "asked \"{}\", which batch mode can't answer",
+                message
+            ));
+        }
+        Ok(())
+    }
+
+    /// Why a step failed: the editor's status message, or `fallback`
+    fn batch_failure(&self, fallback: &str) -> String {
+        self.get_status_message()
+            .cloned()
+            .unwrap_or_else(|| fallback.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_batch_script() {
+        let script =
+            "# rename\ngoto 3; goto 4 2\nreplace \"a\\\"b\" \"c\" regex\naction sort_lines\nsave\n";
+        let steps = parse_batch_script(script).unwrap();
+        let lines: Vec<usize> = steps.iter().map(|step| step.line).collect();
+        assert_eq!(lines, [2, 2, 3, 4, 5]);
+        assert_eq!(
+            steps[1].step,
+            BatchStep::Goto {
+                line: 4,
+                column: Some(2)
+            }
+        );
+        assert_eq!(
+            steps[2].step,
+            BatchStep::Replace {
+                search: "a\"b".to_string(),
+                replacement: "c".to_string(),
+                regex: true,
+            }
+        );
+        // Steps print back as the script wrote them
+        assert_eq!(steps[2].step.to_string(), "replace \"a\\\"b\" \"c\" regex");
+    }
+
+    #[test]
+    fn test_parse_errors_name_the_line() {
+        assert_eq!(
+            parse_batch_script("save\naction no_such_action").unwrap_err(),
+            "line 2: unknown action 'no_such_action'"
+        );
+        assert_eq!(
+            parse_batch_script("goto 0").unwrap_err(),
+            "line 1: line must be a positive number, got '0'"
+        );
+        assert_eq!(
+            parse_batch_script("type hello").unwrap_err(),
+            "line 1: text must be quoted, got hello"
+        );
+        assert_eq!(
+            parse_batch_script("save now").unwrap_err(),
+            "line 1: unexpected 'now' after save"
+        );
+    }
+}
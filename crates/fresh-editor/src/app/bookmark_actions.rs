@@ -0,0 +1,276 @@
+//! Line bookmarks for the Editor.
+//!
+//! This module contains the bookmarks toggled on lines, as opposed to the
+//! register bookmarks (0-9):
+//! - Toggling and labelling a bookmark on the cursor line
+//! - Cycling through the bookmarks of a buffer
+//! - A picker over the bookmarks of every open buffer
+//!
+//! Each bookmark is a gutter indicator whose marker keeps it on its line
+//! as the buffer is edited.
+
+use rust_i18n::t;
+
+use crate::input::commands::Suggestion;
+use crate::model::event::{BufferId, Event};
+use crate::model::marker::MarkerId;
+use crate::view::margin::LineIndicator;
+use crate::view::prompt::{Prompt, PromptType};
+
+use super::types::LineBookmark;
+use super::Editor;
+
+/// Gutter indicator namespace for line bookmarks
+const BOOKMARK_NAMESPACE: &str = "bookmark";
+
+/// Gutter symbol for a bookmarked line
+const BOOKMARK_SYMBOL: &str = "◆";
+
+/// Wins over the git gutter and modified-line indicators
+const BOOKMARK_PRIORITY: i32 = 20;
+
+impl Editor {
+    /// Toggle a bookmark on the cursor line
+    pub(super) fn toggle_line_bookmark(&mut self) {
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        let line = self.active_state().buffer.get_line_number(position);
+
+        if self.remove_line_bookmarks_on(buffer_id, line) {
+            self.set_status_message(t!("bookmark.removed", line = line + 1).to_string());
+        } else {
+            self.add_line_bookmark(buffer_id, position, None);
+            self.set_status_message(t!("bookmark.added", line = line + 1).to_string());
+        }
+    }
+
+    /// Bookmark the cursor line with a label, replacing a bookmark already there
+    ///
+    /// An empty label adds a plain bookmark.
+    pub(super) fn add_labeled_line_bookmark(&mut self, label: &str) {
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        let line = self.active_state().buffer.get_line_number(position);
+
+        self.remove_line_bookmarks_on(buffer_id, line);
+        let label = (!label.is_empty()).then(|| label.to_string());
+        self.add_line_bookmark(buffer_id, position, label);
+        self.set_status_message(t!("bookmark.added", line = line + 1).to_string());
+    }
+
+    /// Add a bookmark on the line containing `position`
+    pub(super) fn add_line_bookmark(
+        &mut self,
+        buffer_id: BufferId,
+        position: usize,
+        label: Option<String>,
+    ) {
+        let color = self.theme.diagnostic_info_fg;
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let line = state.buffer.get_line_number(position);
+        let line_start = state.buffer.line_start_offset(line).unwrap_or(position);
+        let marker_id = state.margins.set_line_indicator(
+            line_start,
+            BOOKMARK_NAMESPACE.to_string(),
+            LineIndicator::new(BOOKMARK_SYMBOL, color, BOOKMARK_PRIORITY),
+        );
+        self.line_bookmarks
+            .entry(buffer_id)
+            .or_default()
+            .push(LineBookmark { marker_id, label });
+    }
+
+    /// Remove every bookmark on `line`, returning whether there were any
+    fn remove_line_bookmarks_on(&mut self, buffer_id: BufferId, line: usize) -> bool {
+        let (Some(state), Some(bookmarks)) = (
+            self.buffers.get_mut(&buffer_id),
+            self.line_bookmarks.get_mut(&buffer_id),
+        ) else {
+            return false;
+        };
+
+        let count = bookmarks.len();
+        bookmarks.retain(|bookmark| {
+            let on_line = state
+                .margins
+                .line_indicator_position(bookmark.marker_id)
+                .is_some_and(|pos| state.buffer.get_line_number(pos) == line);
+            if on_line {
+                state
+                    .margins
+                    .remove_line_indicator(bookmark.marker_id, BOOKMARK_NAMESPACE);
+            }
+            !on_line
+        });
+        bookmarks.len() != count
+    }
+
+    /// The bookmarks of a buffer with their current line, sorted by line
+    pub(super) fn bookmarked_lines(&self, buffer_id: BufferId) -> Vec<(usize, &LineBookmark)> {
+        let (Some(state), Some(bookmarks)) = (
+            self.buffers.get(&buffer_id),
+            self.line_bookmarks.get(&buffer_id),
+        ) else {
+            return Vec::new();
+        };
+
+        let mut lines: Vec<(usize, &LineBookmark)> = bookmarks
+            .iter()
+            .filter_map(|bookmark| {
+                let pos = state.margins.line_indicator_position(bookmark.marker_id)?;
+                Some((state.buffer.get_line_number(pos), bookmark))
+            })
+            .collect();
+        lines.sort_by_key(|(line, _)| *line);
+        lines
+    }
+
+    /// Move to the next (or previous) bookmarked line of the buffer, wrapping around
+    pub(super) fn goto_adjacent_bookmark(&mut self, forward: bool) {
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        let current = self.active_state().buffer.get_line_number(position);
+
+        let lines: Vec<usize> = self
+            .bookmarked_lines(buffer_id)
+            .into_iter()
+            .map(|(line, _)| line)
+            .collect();
+        let (Some(&first), Some(&last)) = (lines.first(), lines.last()) else {
+            self.set_status_message(t!("bookmark.none_in_buffer").to_string());
+            return;
+        };
+
+        let target = if forward {
+            lines
+                .iter()
+                .copied()
+                .find(|&line| line > current)
+                .unwrap_or(first)
+        } else {
+            lines
+                .iter()
+                .rev()
+                .copied()
+                .find(|&line| line < current)
+                .unwrap_or(last)
+        };
+        self.move_cursor_to_line(target);
+    }
+
+    /// Open a picker listing the bookmarks of every open buffer
+    pub(super) fn start_browse_bookmarks_prompt(&mut self) {
+        let mut buffer_ids: Vec<BufferId> = self.line_bookmarks.keys().copied().collect();
+        buffer_ids.sort_by_key(|id| id.0);
+
+        let mut suggestions = Vec::new();
+        for buffer_id in buffer_ids {
+            let Some(state) = self.buffers.get(&buffer_id) else {
+                continue;
+            };
+            let name = self
+                .buffer_metadata
+                .get(&buffer_id)
+                .map(|m| m.display_name.clone())
+                .unwrap_or_else(|| format!("Buffer {:?}", buffer_id));
+
+            for (line, bookmark) in self.bookmarked_lines(buffer_id) {
+                let location = format!("{}:{}", name, line + 1);
+                let text = match &bookmark.label {
+                    Some(label) => format!("{} ({})", label, location),
+                    None => location,
+                };
+                // Preview of the bookmarked line
+                let preview = state
+                    .buffer
+                    .get_line(line)
+                    .map(|bytes| String::from_utf8_lossy(&bytes).trim().to_string())
+                    .filter(|text| !text.is_empty());
+                suggestions.push(Suggestion {
+                    text,
+                    description: preview,
+                    value: Some(format!("{}:{}", buffer_id.0, bookmark.marker_id.0)),
+                    disabled: false,
+                    keybinding: None,
+                    source: None,
+                });
+            }
+        }
+
+        if suggestions.is_empty() {
+            self.set_status_message(t!("bookmark.none_set").to_string());
+            return;
+        }
+
+        self.prompt = Some(Prompt::with_suggestions(
+            t!("bookmark.browse_prompt").to_string(),
+            PromptType::BrowseBookmarks,
+            suggestions,
+        ));
+    }
+
+    /// Jump to the bookmark picked in the bookmark picker
+    ///
+    /// `value` is the `buffer_id:marker_id` pair of the picked suggestion.
+    pub(super) fn goto_line_bookmark(&mut self, value: &str) {
+        let Some((buffer_id, marker_id)) = value.split_once(':').and_then(|(buffer, marker)| {
+            Some((
+                BufferId(buffer.parse().ok()?),
+                MarkerId(marker.parse().ok()?),
+            ))
+        }) else {
+            return;
+        };
+        let Some(line) = self
+            .bookmarked_lines(buffer_id)
+            .into_iter()
+            .find(|(_, bookmark)| bookmark.marker_id == marker_id)
+            .map(|(line, _)| line)
+        else {
+            return;
+        };
+
+        self.switch_buffer(buffer_id);
+        self.move_cursor_to_line(line);
+    }
+
+    /// Remove every bookmark in the active buffer
+    pub(super) fn clear_buffer_bookmarks(&mut self) {
+        let buffer_id = self.active_buffer();
+        let count = self
+            .line_bookmarks
+            .remove(&buffer_id)
+            .map_or(0, |bookmarks| bookmarks.len());
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state
+                .margins
+                .clear_line_indicators_for_namespace(BOOKMARK_NAMESPACE);
+        }
+        self.set_status_message(t!("bookmark.cleared_buffer", count = count).to_string());
+    }
+
+    /// Move the primary cursor to the start of `line` in the active buffer
+    fn move_cursor_to_line(&mut self, line: usize) {
+        let cursor = *self.active_cursors().primary();
+        let cursor_id = self.active_cursors().primary_id();
+        let Some(new_position) = self.active_state().buffer.line_start_offset(line) else {
+            return;
+        };
+
+        let event = Event::MoveCursor {
+            cursor_id,
+            old_position: cursor.position,
+            new_position,
+            old_anchor: cursor.anchor,
+            new_anchor: None,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: 0,
+        };
+
+        self.active_event_log_mut().append(event.clone());
+        self.apply_event_to_active_buffer(&event);
+        self.track_cursor_movement(&event);
+    }
+}
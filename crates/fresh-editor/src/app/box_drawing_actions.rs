@@ -0,0 +1,289 @@
+//! Box drawing for text diagrams.
+//!
+//! - Draw Box replaces the border of a block selection with `┌─┐│└┘`,
+//!   leaving what is inside alone
+//! - Line Drawing Mode makes the arrow keys draw a line behind the cursor,
+//!   joining the lines it meets, and makes typing overwrite instead of insert
+//!
+//! Both treat the text as a grid of character cells. Lines shorter than the
+//! cell being drawn are padded with spaces, and each change is one undo step.
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::input::keybindings::{Action, KeyContext};
+use crate::model::event::{CursorId, Event};
+use crate::primitives::box_drawing::{self, DOWN, LEFT, RIGHT, UP};
+
+/// Byte offset of cell `column` in a line, the end of the line past it
+fn column_offset(line: &str, column: usize) -> usize {
+    line.char_indices()
+        .nth(column)
+        .map_or(line.len(), |(offset, _)| offset)
+}
+
+impl Editor {
+    /// Text of a line without its line ending, or `None` past the last line
+    fn cell_line(&self, line: usize) -> Option<String> {
+        let bytes = self.active_state().buffer.get_line(line)?;
+        let text = String::from_utf8_lossy(&bytes);
+        Some(text.trim_end_matches(['\n', '\r']).to_string())
+    }
+
+    /// Character in a cell, a space past the end of the text
+    fn cell_char(&self, line: usize, column: usize) -> char {
+        self.cell_line(line)
+            .map_or(' ', |text| box_drawing::char_at(&text, column))
+    }
+
+    /// Line and cell column of a byte offset
+    fn cell_of(&self, position: usize) -> (usize, usize) {
+        let buffer = &self.active_state().buffer;
+        let line = buffer.get_line_number(position);
+        let start = buffer.line_start_offset(line).unwrap_or(0);
+        let column = self.cell_line(line).map_or(0, |text| {
+            let end = position.saturating_sub(start).min(text.len());
+            String::from_utf8_lossy(&text.as_bytes()[..end])
+                .chars()
+                .count()
+        });
+        (line, column)
+    }
+
+    /// Sides of a cell that lines in the cells around it reach into
+    fn neighbor_arms(&self, line: usize, column: usize) -> u8 {
+        let up = match line {
+            0 => ' ',
+            line => self.cell_char(line - 1, column),
+        };
+        let left = match column {
+            0 => ' ',
+            column => self.cell_char(line, column - 1),
+        };
+        box_drawing::arms_from_neighbors([
+            up,
+            self.cell_char(line + 1, column),
+            left,
+            self.cell_char(line, column + 1),
+        ])
+    }
+
+    /// Replace the lines from `first` on with `lines` as one undo step, adding
+    /// any past the end of the buffer, and move the cursor to a cell of the
+    /// new text
+    fn replace_cell_lines(
+        &mut self,
+        first: usize,
+        lines: Vec<String>,
+        cursor: (usize, usize),
+        description: &str,
+    ) {
+        let line_ending = self.active_state().buffer.line_ending().as_str();
+        let buffer_len = self.active_state().buffer.len();
+        let mut events = Vec::new();
+        let mut shift: isize = 0;
+        let mut appended = String::new();
+        let mut cursor_position = None;
+
+        for (line, text) in (first..).zip(&lines) {
+            let Some(old) = self.cell_line(line) else {
+                appended.push_str(line_ending);
+                if line == cursor.0 {
+                    cursor_position = Some(
+                        (buffer_len as isize + shift) as usize
+                            + appended.len()
+                            + column_offset(text, cursor.1),
+                    );
+                }
+                appended.push_str(text);
+                continue;
+            };
+            let start = self
+                .active_state()
+                .buffer
+                .line_start_offset(line)
+                .unwrap_or(buffer_len);
+            if line == cursor.0 {
+                cursor_position =
+                    Some((start as isize + shift) as usize + column_offset(text, cursor.1));
+            }
+            if old == *text {
+                continue;
+            }
+            if !old.is_empty() {
+                events.push(Event::Delete {
+                    range: start..start + old.len(),
+                    deleted_text: old.clone(),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+            if !text.is_empty() {
+                events.push(Event::Insert {
+                    position: start,
+                    text: text.clone(),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+            shift += text.len() as isize - old.len() as isize;
+        }
+        if !appended.is_empty() {
+            events.push(Event::Insert {
+                position: buffer_len,
+                text: appended,
+                cursor_id: CursorId::UNDO_SENTINEL,
+            });
+        }
+
+        let (cursor_id, old_cursor) = {
+            let cursors = self.active_cursors();
+            (cursors.primary_id(), cursors.primary().clone())
+        };
+        let new_position = cursor_position.unwrap_or(old_cursor.position);
+        let move_cursor = Event::MoveCursor {
+            cursor_id,
+            old_position: old_cursor.position,
+            new_position,
+            old_anchor: old_cursor.anchor,
+            new_anchor: None,
+            old_sticky_column: old_cursor.sticky_column,
+            new_sticky_column: cursor.1,
+        };
+
+        if events.is_empty() {
+            self.apply_event_to_active_buffer(&move_cursor);
+        } else {
+            events.push(move_cursor);
+            if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description.to_string())
+            {
+                self.active_event_log_mut().append(bulk_edit);
+            }
+        }
+        self.active_cursors_mut()
+            .primary_mut()
+            .clear_block_selection();
+    }
+
+    /// Replace the border of the block selection with a box
+    pub(super) fn draw_box(&mut self) {
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
+        }
+        let cursor = self.active_cursors().primary().clone();
+        let block_anchor = cursor
+            .block_anchor
+            .filter(|_| cursor.has_block_selection() && self.active_cursors().count() == 1);
+        let Some(block_anchor) = block_anchor else {
+            self.set_status_message(t!("box.needs_block_selection").to_string());
+            return;
+        };
+
+        // The block is held as byte columns; boxes are drawn in cells
+        let anchor_column = self.cell_line(block_anchor.line).map_or(0, |text| {
+            let end = block_anchor.column.min(text.len());
+            String::from_utf8_lossy(&text.as_bytes()[..end])
+                .chars()
+                .count()
+        });
+        let (cursor_line, cursor_column) = self.cell_of(cursor.position);
+        let top = block_anchor.line.min(cursor_line);
+        let bottom = block_anchor.line.max(cursor_line);
+        let left = anchor_column.min(cursor_column);
+        let right = anchor_column.max(cursor_column);
+        if top == bottom || left == right {
+            self.set_status_message(t!("box.too_small").to_string());
+            return;
+        }
+
+        let (width, height) = (right - left + 1, bottom - top + 1);
+        let lines: Vec<String> = (top..=bottom)
+            .map(|line| {
+                let mut text = self.cell_line(line).unwrap_or_default();
+                for column in 0..width {
+                    if let Some(ch) = box_drawing::box_border(line - top, column, width, height) {
+                        box_drawing::set_char(&mut text, left + column, ch);
+                    }
+                }
+                text
+            })
+            .collect();
+        self.replace_cell_lines(top, lines, (cursor_line, cursor_column), "Draw box");
+        self.set_status_message(t!("box.drawn", width = width, height = height).to_string());
+    }
+
+    /// Turn Line Drawing Mode on or off
+    pub(super) fn toggle_line_drawing(&mut self) {
+        self.line_drawing = !self.line_drawing;
+        if self.line_drawing {
+            self.set_status_message(t!("toggle.line_drawing_on").to_string());
+        } else {
+            self.set_status_message(t!("toggle.line_drawing_off").to_string());
+        }
+    }
+
+    /// In Line Drawing Mode, draw with the arrow keys and overwrite typed
+    /// characters. Returns whether the action was handled.
+    ///
+    /// Only a single cursor without a selection draws; otherwise the action
+    /// runs as usual.
+    pub(super) fn handle_line_drawing_action(&mut self, action: &Action) -> bool {
+        if !self.line_drawing
+            || self.get_key_context() != KeyContext::Normal
+            || self.is_editing_disabled()
+            || self.active_cursors().count() > 1
+            || !self.active_cursors().primary().collapsed()
+        {
+            return false;
+        }
+        match action {
+            Action::MoveUp => self.draw_line(UP),
+            Action::MoveDown => self.draw_line(DOWN),
+            Action::MoveLeft => self.draw_line(LEFT),
+            Action::MoveRight => self.draw_line(RIGHT),
+            Action::InsertChar(ch) => self.overwrite_char(*ch),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Draw a line from the cursor's cell into the next one in `direction`
+    /// and move the cursor there.
+    ///
+    /// Each of the two cells joins the lines around it that reach into it, so
+    /// a stroke turns corners, crosses lines and ends on them with the right
+    /// junction. A single arm shows as a whole line, and the cells' own
+    /// characters are replaced.
+    fn draw_line(&mut self, direction: u8) {
+        let (line, column) = self.cell_of(self.active_cursors().primary().position);
+        let (to_line, to_column) = match direction {
+            UP if line > 0 => (line - 1, column),
+            DOWN => (line + 1, column),
+            LEFT if column > 0 => (line, column - 1),
+            RIGHT => (line, column + 1),
+            _ => return,
+        };
+
+        let from_arms = self.neighbor_arms(line, column) | direction;
+        let to_arms = self.neighbor_arms(to_line, to_column) | box_drawing::opposite(direction);
+
+        let first = line.min(to_line);
+        let mut lines: Vec<String> = (first..=line.max(to_line))
+            .map(|line| self.cell_line(line).unwrap_or_default())
+            .collect();
+        for (line, column, arms) in [(line, column, from_arms), (to_line, to_column, to_arms)] {
+            if let Some(glyph) = box_drawing::glyph(arms) {
+                box_drawing::set_char(&mut lines[line - first], column, glyph);
+            }
+        }
+        self.replace_cell_lines(first, lines, (to_line, to_column), "Draw line");
+    }
+
+    /// Replace the character under the cursor, or add it at the end of the
+    /// line, and move past it
+    fn overwrite_char(&mut self, ch: char) {
+        let (line, column) = self.cell_of(self.active_cursors().primary().position);
+        let mut text = self.cell_line(line).unwrap_or_default();
+        box_drawing::set_char(&mut text, column, ch);
+        self.replace_cell_lines(line, vec![text], (line, column + 1), "Overwrite");
+    }
+}
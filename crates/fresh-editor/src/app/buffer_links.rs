@@ -0,0 +1,167 @@
+//! Opening URLs and file paths found in editor buffers.
+//!
+//! Ctrl+click on a link opens it, and while Ctrl is held the link under the
+//! pointer is underlined. Links are found by [`crate::primitives::text_links`]
+//! on the one line under the pointer or cursor, or given by a
+//! [`LINK_PROPERTY`] text property, as in a markdown preview. URLs open in
+//! the system browser; paths open in the editor, at the line of a `:line`
+//! suffix.
+
+use super::markdown_preview::LINK_PROPERTY;
+use super::*;
+use crate::primitives::text_links::{self, TextLinkTarget};
+use crate::services::terminal::LinkTarget;
+use crate::view::folding::indent_folding;
+use crate::view::overlay::{Overlay, OverlayFace, OverlayNamespace};
+use crossterm::event::KeyModifiers;
+use ratatui::style::{Modifier, Style};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+impl Editor {
+    /// The link at `position` of a buffer, with its byte range in the buffer
+    pub(super) fn buffer_link_at(
+        &self,
+        buffer_id: BufferId,
+        position: usize,
+    ) -> Option<(Range<usize>, TextLinkTarget)> {
+        let state = self.buffers.get(&buffer_id)?;
+        // Rendered text carries the targets of links whose text differs
+        if let Some(property) = state
+            .text_properties
+            .get_at(position)
+            .into_iter()
+            .find(|property| property.properties.contains_key(LINK_PROPERTY))
+        {
+            let target = property.properties[LINK_PROPERTY].as_str()?.to_string();
+            let target = if target.contains("://") || target.starts_with("mailto:") {
+                TextLinkTarget::Url(target)
+            } else {
+                TextLinkTarget::Path {
+                    path: target,
+                    line: None,
+                    column: None,
+                }
+            };
+            return Some((property.start..property.end, target));
+        }
+
+        let buffer = &state.buffer;
+        let line_start = indent_folding::find_line_start_byte(buffer, position);
+        let line = buffer.get_line(buffer.get_line_number(position))?;
+        let line = std::str::from_utf8(&line).ok()?;
+        let link = text_links::link_at(line, position - line_start)?;
+        Some((
+            line_start + link.range.start..line_start + link.range.end,
+            link.target,
+        ))
+    }
+
+    /// Open a link found in a buffer
+    pub(super) fn open_buffer_link(&mut self, buffer_id: BufferId, target: TextLinkTarget) {
+        let (target, base_dir) = match target {
+            TextLinkTarget::Url(url) => (LinkTarget::Uri(url), self.working_dir.clone()),
+            TextLinkTarget::Path { path, line, column } => {
+                let base_dir = self.buffer_link_base_dir(buffer_id, &path);
+                (LinkTarget::File { path, line, column }, base_dir)
+            }
+        };
+        self.open_link(target, &base_dir);
+    }
+
+    /// Directory a relative path in a buffer is resolved against: the
+    /// buffer's own directory when the path exists there, otherwise the
+    /// directory links in the buffer normally resolve against
+    fn buffer_link_base_dir(&self, buffer_id: BufferId, path: &str) -> PathBuf {
+        self.buffers
+            .get(&buffer_id)
+            .and_then(|state| state.buffer.file_path())
+            .and_then(Path::parent)
+            .filter(|dir| self.filesystem.exists(&dir.join(path)))
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| self.link_base_dir(buffer_id))
+    }
+
+    /// Underline the link under the pointer while Ctrl is held, as a hint
+    /// that Ctrl+click opens it. Returns true if the underline changed.
+    pub(super) fn update_link_hover(
+        &mut self,
+        col: u16,
+        row: u16,
+        modifiers: KeyModifiers,
+    ) -> bool {
+        let hovered = if modifiers.contains(KeyModifiers::CONTROL) {
+            self.link_at_screen_position(col, row)
+        } else {
+            None
+        };
+        if hovered == self.mouse_state.link_hover {
+            return false;
+        }
+
+        let namespace = OverlayNamespace::from_string("link-hover".to_string());
+        if let Some((buffer_id, _)) = self.mouse_state.link_hover.take() {
+            if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                state
+                    .overlays
+                    .clear_namespace(&namespace, &mut state.marker_list);
+            }
+        }
+        if let Some((buffer_id, range)) = &hovered {
+            if let Some(state) = self.buffers.get_mut(buffer_id) {
+                let overlay = Overlay::with_namespace(
+                    &mut state.marker_list,
+                    range.clone(),
+                    OverlayFace::Style {
+                        style: Style::default().add_modifier(Modifier::UNDERLINED),
+                    },
+                    namespace,
+                );
+                state.overlays.add(overlay);
+            }
+        }
+        self.mouse_state.link_hover = hovered;
+        true
+    }
+
+    /// The buffer and byte range of the link drawn at a screen position
+    fn link_at_screen_position(&self, col: u16, row: u16) -> Option<(BufferId, Range<usize>)> {
+        let (split_id, buffer_id, content_rect) = self
+            .cached_layout
+            .split_areas
+            .iter()
+            .find(|(_, _, content_rect, _, _, _)| {
+                col >= content_rect.x
+                    && col < content_rect.x + content_rect.width
+                    && row >= content_rect.y
+                    && row < content_rect.y + content_rect.height
+            })
+            .map(|(split_id, buffer_id, content_rect, _, _, _)| {
+                (*split_id, *buffer_id, *content_rect)
+            })?;
+        if self.is_composite_buffer(buffer_id) {
+            return None;
+        }
+        let state = self.buffers.get(&buffer_id)?;
+        let compose_width = self
+            .split_view_states
+            .get(&split_id)
+            .and_then(|vs| vs.compose_width);
+        let position = Self::screen_to_buffer_position(
+            col,
+            row,
+            content_rect,
+            state.margins.left_total_width() as u16,
+            &self
+                .cached_layout
+                .view_line_mappings
+                .get(&split_id)
+                .cloned(),
+            state.buffer.len(),
+            false,
+            compose_width,
+        )?;
+        let (range, _) = self.buffer_link_at(buffer_id, position)?;
+        Some((buffer_id, range))
+    }
+}
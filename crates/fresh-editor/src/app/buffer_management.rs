@@ -15,6 +15,7 @@ use std::sync::Arc;
 
 use crate::app::warning_domains::WarningDomain;
 use crate::model::event::{BufferId, Event, LeafId};
+use crate::model::line_index::LINE_INDEX_STRIDE;
 use crate::state::EditorState;
 use crate::view::prompt::PromptType;
 use crate::view::split::SplitViewState;
@@ -22,6 +23,10 @@ use crate::view::split::SplitViewState;
 use super::help;
 use super::Editor;
 
+/// A leaf counted by the line scan:
+/// `(leaf_index, doc_offset, byte_len, lf_count, line start samples)`
+type ScannedLeaf = (usize, usize, usize, usize, Vec<(usize, usize)>);
+
 impl Editor {
     /// Get the preferred split for opening a file.
     /// If the active split has no label, use it (normal case).
@@ -34,6 +39,13 @@ impl Editor {
         self.split_manager.find_unlabeled_leaf().unwrap_or(active)
     }
 
+    /// Line wrap for a new view of a buffer: the buffer language's `line_wrap`
+    /// override if set, otherwise `editor.line_wrap`.
+    pub(crate) fn default_line_wrap(&self, buffer_id: BufferId) -> bool {
+        let language = self.buffers.get(&buffer_id).map(|s| s.language.as_str());
+        crate::config::BufferConfig::resolve(&self.config, language).line_wrap
+    }
+
     /// Open a file and return its buffer ID
     ///
     /// If the file doesn't exist, creates an unsaved buffer with that filename.
@@ -57,15 +69,7 @@ impl Editor {
 
         if is_new_buffer {
             // Save current position before switching to new buffer
-            self.position_history.commit_pending_movement();
-
-            // Explicitly record current position before switching
-            let cursors = self.active_cursors();
-            let position = cursors.primary().position;
-            let anchor = cursors.primary().anchor;
-            self.position_history
-                .record_movement(self.active_buffer(), position, anchor);
-            self.position_history.commit_pending_movement();
+            self.record_jump();
         }
 
         self.set_active_buffer(buffer_id);
@@ -84,6 +88,7 @@ impl Editor {
                 "buffer_activated",
                 crate::services::plugins::hooks::HookArgs::BufferActivated { buffer_id },
             );
+            self.follow_active_file_in_explorer();
         }
 
         // Use display_name from metadata for relative path display
@@ -225,11 +230,13 @@ impl Editor {
                 self.config.editor.large_file_threshold_bytes as usize,
                 Arc::clone(&self.filesystem),
             )?;
-            let detected = crate::primitives::detected_language::DetectedLanguage::from_path(
-                &display_path,
-                &self.grammar_registry,
-                &self.config.languages,
-            );
+            let detected =
+                crate::primitives::detected_language::DetectedLanguage::from_path_and_content(
+                    &display_path,
+                    &buffer,
+                    &self.grammar_registry,
+                    &self.config.languages,
+                );
             EditorState::from_buffer_with_language(buffer, detected)
         } else {
             // File doesn't exist - create empty buffer with the file path set
@@ -249,21 +256,33 @@ impl Editor {
             tracing::info!("Detected binary file: {}", path.display());
         }
 
+        // Protect generated files and files we can't write to
+        let read_only = !is_binary && file_exists && self.opens_read_only(path);
+        if read_only {
+            state.editing_disabled = true;
+        }
+
+        // Log files open following their end, and aren't for editing
+        let log_file = !is_binary && crate::primitives::log_file::is_log_file(&display_path);
+        if log_file {
+            state.log_file = true;
+            state.buffer_settings.follow_tail = true;
+            state.editing_disabled = true;
+        }
+
+        // Color the fields of CSV and TSV files by column
+        if !is_binary {
+            use crate::primitives::delimited;
+            let head = state
+                .buffer
+                .slice_bytes(0..state.buffer.len().min(delimited::SNIFF_BYTES));
+            state.delimiter =
+                delimited::detect_delimiter(&display_path, &String::from_utf8_lossy(&head));
+        }
+
         // Set whitespace visibility, use_tabs, and tab_size based on language config
-        // with fallback to global editor config for tab_size
         // Use the buffer's stored language (already set by from_file_with_languages)
-        let mut whitespace =
-            crate::config::WhitespaceVisibility::from_editor_config(&self.config.editor);
-        if let Some(lang_config) = self.config.languages.get(&state.language) {
-            whitespace = whitespace.with_language_tab_override(lang_config.show_whitespace_tabs);
-            state.buffer_settings.use_tabs = lang_config.use_tabs;
-            // Use language-specific tab_size if set, otherwise fall back to global
-            state.buffer_settings.tab_size =
-                lang_config.tab_size.unwrap_or(self.config.editor.tab_size);
-        } else {
-            state.buffer_settings.tab_size = self.config.editor.tab_size;
-        }
-        state.buffer_settings.whitespace = whitespace;
+        super::language_mode::apply_language_settings(&self.config, &mut state);
 
         // Apply line_numbers default from config
         state
@@ -284,6 +303,9 @@ impl Editor {
             metadata.read_only = true;
             metadata.disable_lsp(t!("buffer.binary_file").to_string());
         }
+        if read_only || log_file {
+            metadata.read_only = true;
+        }
 
         // Notify LSP about the newly opened file (skip for binary files)
         if !is_binary {
@@ -296,17 +318,21 @@ impl Editor {
         // Add buffer to the preferred split's tabs (but don't switch to it)
         // Uses preferred_split_for_file() to avoid opening in labeled splits (e.g., sidebars)
         let target_split = self.preferred_split_for_file();
+        let line_wrap = self.default_line_wrap(buffer_id);
         if let Some(view_state) = self.split_view_states.get_mut(&target_split) {
             view_state.add_buffer(buffer_id);
             // Initialize per-buffer view state for the new buffer with config defaults
             let buf_state = view_state.ensure_buffer_state(buffer_id);
-            buf_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
+            buf_state.viewport.line_wrap_enabled = line_wrap;
             buf_state.rulers = self.config.editor.rulers.clone();
         }
 
         // Restore global file state (scroll/cursor position) if available
         // This persists file positions across projects and editor instances
         self.restore_global_file_state(buffer_id, path, target_split);
+        if log_file {
+            self.follow_log_tail(buffer_id);
+        }
 
         // Emit control event
         self.emit_event(
@@ -377,11 +403,13 @@ impl Editor {
             self.config.editor.large_file_threshold_bytes as usize,
             Arc::clone(&self.local_filesystem),
         )?;
-        let detected = crate::primitives::detected_language::DetectedLanguage::from_path(
-            &display_path,
-            &self.grammar_registry,
-            &self.config.languages,
-        );
+        let detected =
+            crate::primitives::detected_language::DetectedLanguage::from_path_and_content(
+                &display_path,
+                &buffer,
+                &self.grammar_registry,
+                &self.config.languages,
+            );
         let state = EditorState::from_buffer_with_language(buffer, detected);
 
         self.buffers.insert(buffer_id, state);
@@ -395,10 +423,11 @@ impl Editor {
 
         // Add to preferred split's tabs (avoids labeled splits like sidebars)
         let target_split = self.preferred_split_for_file();
+        let line_wrap = self.default_line_wrap(buffer_id);
         if let Some(view_state) = self.split_view_states.get_mut(&target_split) {
             view_state.add_buffer(buffer_id);
             let buf_state = view_state.ensure_buffer_state(buffer_id);
-            buf_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
+            buf_state.viewport.line_wrap_enabled = line_wrap;
             buf_state.rulers = self.config.editor.rulers.clone();
         }
 
@@ -469,11 +498,13 @@ impl Editor {
         )?;
         // Create editor state with the buffer
         // Use display_path for language detection (glob patterns match user-visible paths)
-        let detected = crate::primitives::detected_language::DetectedLanguage::from_path(
-            &display_path,
-            &self.grammar_registry,
-            &self.config.languages,
-        );
+        let detected =
+            crate::primitives::detected_language::DetectedLanguage::from_path_and_content(
+                &display_path,
+                &buffer,
+                &self.grammar_registry,
+                &self.config.languages,
+            );
 
         let mut state = EditorState::from_buffer_with_language(buffer, detected);
 
@@ -491,10 +522,11 @@ impl Editor {
 
         // Add to preferred split's tabs (avoids labeled splits like sidebars)
         let target_split = self.preferred_split_for_file();
+        let line_wrap = self.default_line_wrap(buffer_id);
         if let Some(view_state) = self.split_view_states.get_mut(&target_split) {
             view_state.add_buffer(buffer_id);
             let buf_state = view_state.ensure_buffer_state(buffer_id);
-            buf_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
+            buf_state.viewport.line_wrap_enabled = line_wrap;
             buf_state.rulers = self.config.editor.rulers.clone();
         }
 
@@ -601,11 +633,13 @@ impl Editor {
         )?;
         // Create editor state with the buffer
         // Use display_path for language detection (glob patterns match user-visible paths)
-        let detected = crate::primitives::detected_language::DetectedLanguage::from_path(
-            &display_path,
-            &self.grammar_registry,
-            &self.config.languages,
-        );
+        let detected =
+            crate::primitives::detected_language::DetectedLanguage::from_path_and_content(
+                &display_path,
+                &buffer,
+                &self.grammar_registry,
+                &self.config.languages,
+            );
 
         let mut state = EditorState::from_buffer_with_language(buffer, detected);
 
@@ -623,10 +657,11 @@ impl Editor {
 
         // Add to preferred split's tabs (avoids labeled splits like sidebars)
         let target_split = self.preferred_split_for_file();
+        let line_wrap = self.default_line_wrap(buffer_id);
         if let Some(view_state) = self.split_view_states.get_mut(&target_split) {
             view_state.add_buffer(buffer_id);
             let buf_state = view_state.ensure_buffer_state(buffer_id);
-            buf_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
+            buf_state.viewport.line_wrap_enabled = line_wrap;
             buf_state.rulers = self.config.editor.rulers.clone();
         }
 
@@ -914,15 +949,7 @@ impl Editor {
     /// Create a new empty buffer
     pub fn new_buffer(&mut self) -> BufferId {
         // Save current position before switching to new buffer
-        self.position_history.commit_pending_movement();
-
-        // Explicitly record current position before switching
-        let cursors = self.active_cursors();
-        let position = cursors.primary().position;
-        let anchor = cursors.primary().anchor;
-        self.position_history
-            .record_movement(self.active_buffer(), position, anchor);
-        self.position_history.commit_pending_movement();
+        self.record_jump();
 
         let buffer_id = BufferId(self.next_buffer_id);
         self.next_buffer_id += 1;
@@ -953,9 +980,10 @@ impl Editor {
         // Must happen AFTER set_active_buffer, because switch_buffer creates
         // the new BufferViewState with defaults (show_line_numbers=true).
         let active_split = self.split_manager.active_split();
+        let line_wrap = self.default_line_wrap(buffer_id);
         if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
             view_state.show_line_numbers = self.config.editor.line_numbers;
-            view_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
+            view_state.viewport.line_wrap_enabled = line_wrap;
             view_state.rulers = self.config.editor.rulers.clone();
         }
 
@@ -964,6 +992,41 @@ impl Editor {
         buffer_id
     }
 
+    /// Create an untitled scratch buffer, named "untitled-N" after the lowest
+    /// number no open scratch buffer uses
+    pub fn new_scratch_buffer(&mut self) -> BufferId {
+        let used: std::collections::HashSet<usize> = self
+            .buffer_metadata
+            .values()
+            .filter_map(|metadata| metadata.untitled_number)
+            .collect();
+        let mut number = 1;
+        while used.contains(&number) {
+            number += 1;
+        }
+
+        let buffer_id = self.new_buffer();
+        if let Some(metadata) = self.buffer_metadata.get_mut(&buffer_id) {
+            metadata.display_name = t!("buffer.untitled", number = number).to_string();
+            metadata.untitled_number = Some(number);
+        }
+        buffer_id
+    }
+
+    /// Whether closing a buffer should ask to save it first: it has unsaved
+    /// changes, unless it is a scratch buffer that has been emptied again
+    pub(crate) fn needs_save_prompt(&self, id: BufferId) -> bool {
+        let Some(state) = self.buffers.get(&id) else {
+            return false;
+        };
+        let empty_scratch = state.buffer.is_empty()
+            && self
+                .buffer_metadata
+                .get(&id)
+                .is_some_and(|metadata| metadata.untitled_number.is_some());
+        state.buffer.is_modified() && !empty_scratch
+    }
+
     /// Create a new buffer from stdin content stored in a temp file
     ///
     /// Uses lazy chunk loading for efficient handling of large stdin inputs.
@@ -979,15 +1042,7 @@ impl Editor {
         thread_handle: Option<std::thread::JoinHandle<anyhow::Result<()>>>,
     ) -> AnyhowResult<BufferId> {
         // Save current position before switching to new buffer
-        self.position_history.commit_pending_movement();
-
-        // Explicitly record current position before switching
-        let cursors = self.active_cursors();
-        let position = cursors.primary().position;
-        let anchor = cursors.primary().anchor;
-        self.position_history
-            .record_movement(self.active_buffer(), position, anchor);
-        self.position_history.commit_pending_movement();
+        self.record_jump();
 
         // If the current buffer is empty and unmodified, replace it instead of creating a new one
         // Note: Don't replace composite buffers (they appear empty but are special views)
@@ -1049,10 +1104,11 @@ impl Editor {
 
         // Add buffer to the active split's tabs
         let active_split = self.split_manager.active_split();
+        let line_wrap = self.default_line_wrap(buffer_id);
         if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
             view_state.add_buffer(buffer_id);
             let buf_state = view_state.ensure_buffer_state(buffer_id);
-            buf_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
+            buf_state.viewport.line_wrap_enabled = line_wrap;
             buf_state.rulers = self.config.editor.rulers.clone();
         }
 
@@ -1071,6 +1127,9 @@ impl Editor {
 
         // Status will be updated by poll_stdin_streaming
         self.status_message = Some(t!("stdin.streaming").to_string());
+        if complete {
+            self.detect_stdin_language(buffer_id);
+        }
 
         Ok(buffer_id)
     }
@@ -1166,7 +1225,32 @@ impl Editor {
 
             self.status_message =
                 Some(t!("stdin.read_complete", bytes = stream_state.last_known_size).to_string());
+            let buffer_id = stream_state.buffer_id;
+            self.detect_stdin_language(buffer_id);
+        }
+    }
+
+    /// Pick the language of a stdin buffer from its content, since it has
+    /// no file name to go by. A language the user already set is kept.
+    fn detect_stdin_language(&mut self, buffer_id: BufferId) {
+        let overridden = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .is_some_and(|metadata| metadata.language_override.is_some());
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        if overridden {
+            return;
         }
+        let detected = crate::primitives::detected_language::DetectedLanguage::from_content(
+            &state.buffer,
+            &self.grammar_registry,
+            &self.config.languages,
+        );
+        tracing::debug!("Detected stdin language: {}", detected.name);
+        state.apply_language(detected);
+        super::language_mode::apply_language_settings(&self.config, state);
     }
 
     /// Check if stdin streaming is active (not complete).
@@ -1221,16 +1305,17 @@ impl Editor {
 
         // Add buffer to the active split's open_buffers (tabs)
         let active_split = self.split_manager.active_split();
+        let line_wrap = self.default_line_wrap(buffer_id);
         if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
             view_state.add_buffer(buffer_id);
             let buf_state = view_state.ensure_buffer_state(buffer_id);
-            buf_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
+            buf_state.viewport.line_wrap_enabled = line_wrap;
             buf_state.rulers = self.config.editor.rulers.clone();
         } else {
             // Create view state if it doesn't exist
             let mut view_state =
                 SplitViewState::with_buffer(self.terminal_width, self.terminal_height, buffer_id);
-            view_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
+            view_state.viewport.line_wrap_enabled = line_wrap;
             view_state.rulers = self.config.editor.rulers.clone();
             view_state.show_line_numbers = self.config.editor.line_numbers;
             self.split_view_states.insert(active_split, view_state);
@@ -1518,10 +1603,8 @@ impl Editor {
     /// Close the given buffer
     pub fn close_buffer(&mut self, id: BufferId) -> anyhow::Result<()> {
         // Check for unsaved changes
-        if let Some(state) = self.buffers.get(&id) {
-            if state.buffer.is_modified() {
-                return Err(anyhow::anyhow!("Buffer has unsaved changes"));
-            }
+        if self.needs_save_prompt(id) {
+            return Err(anyhow::anyhow!("Buffer has unsaved changes"));
         }
         self.close_buffer_internal(id)
     }
@@ -1541,6 +1624,7 @@ impl Editor {
 
         // Save file state before closing (for per-file session persistence)
         self.save_file_state_on_close(id);
+        self.remember_closed_tab(id);
 
         // If closing a terminal buffer, clean up terminal-related data structures
         if let Some(terminal_id) = self.terminal_buffers.remove(&id) {
@@ -1634,6 +1718,7 @@ impl Editor {
         self.event_logs.remove(&id);
         self.seen_byte_ranges.remove(&id);
         self.buffer_metadata.remove(&id);
+        self.line_bookmarks.remove(&id);
         if let Some((request_id, _, _)) = self.semantic_tokens_in_flight.remove(&id) {
             self.pending_semantic_token_requests.remove(&request_id);
         }
@@ -1654,6 +1739,7 @@ impl Editor {
             view_state.remove_buffer(id);
             view_state.remove_from_history(id);
         }
+        self.forget_buffer_mru(id);
 
         // If this was the last visible buffer, focus file explorer
         if is_last_visible_buffer {
@@ -1667,20 +1753,68 @@ impl Editor {
     pub fn switch_buffer(&mut self, id: BufferId) {
         if self.buffers.contains_key(&id) && id != self.active_buffer() {
             // Save current position before switching buffers
-            self.position_history.commit_pending_movement();
-
-            // Also explicitly record current position (in case there was no pending movement)
-            let cursors = self.active_cursors();
-            let position = cursors.primary().position;
-            let anchor = cursors.primary().anchor;
-            self.position_history
-                .record_movement(self.active_buffer(), position, anchor);
-            self.position_history.commit_pending_movement();
+            self.record_jump();
 
             self.set_active_buffer(id);
         }
     }
 
+    /// Push a file buffer onto the recently closed stack so it can be reopened
+    fn remember_closed_tab(&mut self, buffer_id: BufferId) {
+        // Keep the stack small; only the last few closes are worth undoing
+        const MAX_CLOSED_TABS: usize = 20;
+
+        let Some(path) = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|meta| meta.file_path())
+            .cloned()
+        else {
+            return;
+        };
+        let cursor = self
+            .split_view_states
+            .values()
+            .find_map(|vs| vs.keyed_states.get(&buffer_id))
+            .map(|bs| bs.cursors.primary().position)
+            .unwrap_or(0);
+
+        self.closed_tabs.retain(|closed| closed.path != path);
+        self.closed_tabs
+            .push(super::types::ClosedTab { path, cursor });
+        if self.closed_tabs.len() > MAX_CLOSED_TABS {
+            self.closed_tabs.remove(0);
+        }
+    }
+
+    /// Reopen the most recently closed file tab at its last cursor position
+    pub fn reopen_closed_tab(&mut self) {
+        let Some(closed) = self.closed_tabs.pop() else {
+            self.set_status_message(t!("buffer.no_closed_tabs").to_string());
+            return;
+        };
+
+        if let Err(e) = self.open_file(&closed.path) {
+            self.set_status_message(t!("file.error_opening", error = e.to_string()).to_string());
+            return;
+        }
+
+        let position = self
+            .active_state()
+            .buffer
+            .snap_to_char_boundary(closed.cursor);
+        let cursors = self.active_cursors_mut();
+        cursors.primary_mut().position = position;
+        cursors.primary_mut().anchor = None;
+
+        let active_split = self.split_manager.active_split();
+        let active_buffer = self.active_buffer();
+        if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
+            let state = self.buffers.get_mut(&active_buffer).unwrap();
+            view_state.ensure_cursor_visible(&mut state.buffer, &state.marker_list);
+        }
+    }
+
     /// Close the current tab in the current split view.
     /// If the tab is the last viewport of the underlying buffer, do the same as close_buffer
     /// (including triggering the save/discard prompt for modified buffers).
@@ -1714,7 +1848,7 @@ impl Editor {
             let has_other_splits = self.split_manager.root().count_leaves() > 1;
             if current_split_tabs.len() <= 1 && has_other_splits {
                 // Check for unsaved changes first
-                if self.active_state().buffer.is_modified() {
+                if self.needs_save_prompt(buffer_id) {
                     let name = self.get_buffer_display_name(buffer_id);
                     let save_key = t!("prompt.key.save").to_string();
                     let discard_key = t!("prompt.key.discard").to_string();
@@ -1741,7 +1875,7 @@ impl Editor {
             }
 
             // Last viewport of this buffer - close the buffer entirely
-            if self.active_state().buffer.is_modified() {
+            if self.needs_save_prompt(buffer_id) {
                 // Buffer has unsaved changes - prompt for confirmation
                 let name = self.get_buffer_display_name(buffer_id);
                 let save_key = t!("prompt.key.save").to_string();
@@ -1831,26 +1965,24 @@ impl Editor {
 
         if is_last_viewport {
             // Last viewport of this buffer - need to close buffer entirely
-            if let Some(state) = self.buffers.get(&buffer_id) {
-                if state.buffer.is_modified() {
-                    // Buffer has unsaved changes - prompt for confirmation
-                    let name = self.get_buffer_display_name(buffer_id);
-                    let save_key = t!("prompt.key.save").to_string();
-                    let discard_key = t!("prompt.key.discard").to_string();
-                    let cancel_key = t!("prompt.key.cancel").to_string();
-                    self.start_prompt(
-                        t!(
-                            "prompt.buffer_modified",
-                            name = name,
-                            save_key = save_key,
-                            discard_key = discard_key,
-                            cancel_key = cancel_key
-                        )
-                        .to_string(),
-                        PromptType::ConfirmCloseBuffer { buffer_id },
-                    );
-                    return false;
-                }
+            if self.needs_save_prompt(buffer_id) {
+                // Buffer has unsaved changes - prompt for confirmation
+                let name = self.get_buffer_display_name(buffer_id);
+                let save_key = t!("prompt.key.save").to_string();
+                let discard_key = t!("prompt.key.discard").to_string();
+                let cancel_key = t!("prompt.key.cancel").to_string();
+                self.start_prompt(
+                    t!(
+                        "prompt.buffer_modified",
+                        name = name,
+                        save_key = save_key,
+                        discard_key = discard_key,
+                        cancel_key = cancel_key
+                    )
+                    .to_string(),
+                    PromptType::ConfirmCloseBuffer { buffer_id },
+                );
+                return false;
             }
             if let Err(e) = self.close_buffer(buffer_id) {
                 self.set_status_message(t!("file.cannot_close", error = e.to_string()).to_string());
@@ -1896,10 +2028,10 @@ impl Editor {
             .map(|vs| vs.open_buffers.clone())
             .unwrap_or_default();
 
-        // Close all tabs except the one we want to keep
+        // Close all tabs except the one we want to keep and pinned ones
         let tabs_to_close: Vec<_> = split_tabs
             .iter()
-            .filter(|&&id| id != keep_buffer_id)
+            .filter(|&&id| id != keep_buffer_id && !self.is_tab_pinned(id, split_id))
             .copied()
             .collect();
 
@@ -1934,8 +2066,13 @@ impl Editor {
             return;
         };
 
-        // Close all tabs after the target
-        let tabs_to_close: Vec<_> = split_tabs.iter().skip(target_idx + 1).copied().collect();
+        // Close all unpinned tabs after the target
+        let tabs_to_close: Vec<_> = split_tabs
+            .iter()
+            .skip(target_idx + 1)
+            .filter(|&&id| !self.is_tab_pinned(id, split_id))
+            .copied()
+            .collect();
 
         let mut closed = 0;
         let mut skipped_modified = 0;
@@ -1964,8 +2101,13 @@ impl Editor {
             return;
         };
 
-        // Close all tabs before the target
-        let tabs_to_close: Vec<_> = split_tabs.iter().take(target_idx).copied().collect();
+        // Close all unpinned tabs before the target
+        let tabs_to_close: Vec<_> = split_tabs
+            .iter()
+            .take(target_idx)
+            .filter(|&&id| !self.is_tab_pinned(id, split_id))
+            .copied()
+            .collect();
 
         let mut closed = 0;
         let mut skipped_modified = 0;
@@ -1992,8 +2134,11 @@ impl Editor {
         let mut closed = 0;
         let mut skipped_modified = 0;
 
-        // Close all tabs (this will eventually close the split when empty)
+        // Close all unpinned tabs (this will eventually close the split when empty)
         for buffer_id in split_tabs {
+            if self.is_tab_pinned(buffer_id, split_id) {
+                continue;
+            }
             if self.close_tab_in_split_silent(buffer_id, split_id) {
                 closed += 1;
             } else {
@@ -2004,6 +2149,36 @@ impl Editor {
         self.set_batch_close_status_message(closed, skipped_modified);
     }
 
+    /// Check if a tab is pinned in a split
+    pub fn is_tab_pinned(&self, buffer_id: BufferId, split_id: LeafId) -> bool {
+        self.split_view_states
+            .get(&split_id)
+            .is_some_and(|vs| vs.is_pinned(buffer_id))
+    }
+
+    /// Pin or unpin the active tab in the active split
+    pub fn toggle_pin_active_tab(&mut self) {
+        let buffer_id = self.active_buffer();
+        let split_id = self.split_manager.active_split();
+        self.toggle_pin_tab_in_split(buffer_id, split_id);
+    }
+
+    /// Pin or unpin a tab in a split
+    pub fn toggle_pin_tab_in_split(&mut self, buffer_id: BufferId, split_id: LeafId) {
+        let Some(view_state) = self.split_view_states.get_mut(&split_id) else {
+            return;
+        };
+        let pinned = !view_state.is_pinned(buffer_id);
+        view_state.set_pinned(buffer_id, pinned);
+        let name = self.get_buffer_display_name(buffer_id);
+        let message = if pinned {
+            t!("buffer.tab_pinned", name = name)
+        } else {
+            t!("buffer.tab_unpinned", name = name)
+        };
+        self.set_status_message(message.to_string());
+    }
+
     /// Set status message for batch close operations
     fn set_batch_close_status_message(&mut self, closed: usize, skipped_modified: usize) {
         let message = match (closed, skipped_modified) {
@@ -2044,11 +2219,8 @@ impl Editor {
         if is_last_viewport {
             // Last viewport of this buffer - need to close buffer entirely
             // Skip modified buffers to avoid prompting during batch operations
-            if let Some(state) = self.buffers.get(&buffer_id) {
-                if state.buffer.is_modified() {
-                    // Skip modified buffers - don't close them
-                    return false;
-                }
+            if self.needs_save_prompt(buffer_id) {
+                return false;
             }
             if let Err(e) = self.close_buffer(buffer_id) {
                 tracing::warn!("Failed to close buffer: {}", e);
@@ -2131,15 +2303,7 @@ impl Editor {
             let next_idx = (idx + 1) % ids.len();
             if ids[next_idx] != self.active_buffer() {
                 // Save current position before switching
-                self.position_history.commit_pending_movement();
-
-                // Also explicitly record current position
-                let cursors = self.active_cursors();
-                let position = cursors.primary().position;
-                let anchor = cursors.primary().anchor;
-                self.position_history
-                    .record_movement(self.active_buffer(), position, anchor);
-                self.position_history.commit_pending_movement();
+                self.record_jump();
 
                 self.set_active_buffer(ids[next_idx]);
             }
@@ -2158,116 +2322,13 @@ impl Editor {
             let prev_idx = if idx == 0 { ids.len() - 1 } else { idx - 1 };
             if ids[prev_idx] != self.active_buffer() {
                 // Save current position before switching
-                self.position_history.commit_pending_movement();
-
-                // Also explicitly record current position
-                let cursors = self.active_cursors();
-                let position = cursors.primary().position;
-                let anchor = cursors.primary().anchor;
-                self.position_history
-                    .record_movement(self.active_buffer(), position, anchor);
-                self.position_history.commit_pending_movement();
+                self.record_jump();
 
                 self.set_active_buffer(ids[prev_idx]);
             }
         }
     }
 
-    /// Navigate back in position history
-    pub fn navigate_back(&mut self) {
-        // Set flag to prevent recording this navigation movement
-        self.in_navigation = true;
-
-        // Commit any pending movement
-        self.position_history.commit_pending_movement();
-
-        // If we're at the end of history (haven't used back yet), save current position
-        // so we can navigate forward to it later
-        if self.position_history.can_go_back() && !self.position_history.can_go_forward() {
-            let cursors = self.active_cursors();
-            let position = cursors.primary().position;
-            let anchor = cursors.primary().anchor;
-            self.position_history
-                .record_movement(self.active_buffer(), position, anchor);
-            self.position_history.commit_pending_movement();
-        }
-
-        // Navigate to the previous position
-        if let Some(entry) = self.position_history.back() {
-            let target_buffer = entry.buffer_id;
-            let target_position = entry.position;
-            let target_anchor = entry.anchor;
-
-            // Switch to the target buffer
-            if self.buffers.contains_key(&target_buffer) {
-                self.set_active_buffer(target_buffer);
-
-                // Move cursor to the saved position
-                let cursors = self.active_cursors();
-                let cursor_id = cursors.primary_id();
-                let old_position = cursors.primary().position;
-                let old_anchor = cursors.primary().anchor;
-                let old_sticky_column = cursors.primary().sticky_column;
-                let event = Event::MoveCursor {
-                    cursor_id,
-                    old_position,
-                    new_position: target_position,
-                    old_anchor,
-                    new_anchor: target_anchor,
-                    old_sticky_column,
-                    new_sticky_column: 0, // Reset sticky column for navigation
-                };
-                let split_id = self.split_manager.active_split();
-                let state = self.buffers.get_mut(&target_buffer).unwrap();
-                let view_state = self.split_view_states.get_mut(&split_id).unwrap();
-                state.apply(&mut view_state.cursors, &event);
-            }
-        }
-
-        // Clear the flag
-        self.in_navigation = false;
-    }
-
-    /// Navigate forward in position history
-    pub fn navigate_forward(&mut self) {
-        // Set flag to prevent recording this navigation movement
-        self.in_navigation = true;
-
-        if let Some(entry) = self.position_history.forward() {
-            let target_buffer = entry.buffer_id;
-            let target_position = entry.position;
-            let target_anchor = entry.anchor;
-
-            // Switch to the target buffer
-            if self.buffers.contains_key(&target_buffer) {
-                self.set_active_buffer(target_buffer);
-
-                // Move cursor to the saved position
-                let cursors = self.active_cursors();
-                let cursor_id = cursors.primary_id();
-                let old_position = cursors.primary().position;
-                let old_anchor = cursors.primary().anchor;
-                let old_sticky_column = cursors.primary().sticky_column;
-                let event = Event::MoveCursor {
-                    cursor_id,
-                    old_position,
-                    new_position: target_position,
-                    old_anchor,
-                    new_anchor: target_anchor,
-                    old_sticky_column,
-                    new_sticky_column: 0, // Reset sticky column for navigation
-                };
-                let split_id = self.split_manager.active_split();
-                let state = self.buffers.get_mut(&target_buffer).unwrap();
-                let view_state = self.split_view_states.get_mut(&split_id).unwrap();
-                state.apply(&mut view_state.cursors, &event);
-            }
-        }
-
-        // Clear the flag
-        self.in_navigation = false;
-    }
-
     /// Get the current mouse hover state for testing
     /// Returns Some((byte_position, screen_x, screen_y)) if hovering over text
     pub fn get_mouse_hover_state(&self) -> Option<(usize, u16, u16)> {
@@ -2361,6 +2422,15 @@ impl Editor {
                     } else if let Some(line) = pending_file.line {
                         self.goto_line_col(line, pending_file.column);
                     }
+                    if pending_file.line.is_some() {
+                        // Scroll now so the target line is on screen in the first frame
+                        let active_split = self.split_manager.active_split();
+                        let active_buffer = self.active_buffer();
+                        if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
+                            let state = self.buffers.get_mut(&active_buffer).unwrap();
+                            view_state.ensure_cursor_visible(&mut state.buffer, &state.marker_list);
+                        }
+                    }
                     // Show hover message popup if specified
                     let has_popup = pending_file.message.is_some();
                     if let Some(ref msg) = pending_file.message {
@@ -2464,11 +2534,15 @@ impl Editor {
 
     /// Process leaves concurrently, yielding for a render after each batch.
     ///
-    /// For loaded leaves, delegates to `TextBuffer::scan_leaf` (shared counting
-    /// logic). For unloaded leaves, extracts I/O parameters and runs them
-    /// concurrently using `tokio::task::spawn_blocking` — each task calls
-    /// `count_line_feeds_in_range` on the filesystem, which remote implementations
-    /// override to count on the server without transferring data.
+    /// For loaded leaves, delegates to `TextBuffer::scan_leaf_line_starts`
+    /// (shared counting logic). For unloaded leaves, extracts I/O parameters
+    /// and runs them concurrently using `tokio::task::spawn_blocking` — each
+    /// task calls `sample_line_starts_in_range` on the filesystem, which remote
+    /// implementations override to count on the server without transferring
+    /// data.
+    ///
+    /// Each batch extends the buffer's line index, so exact line numbers are
+    /// available for the scanned part of the file before the scan completes.
     fn process_line_scan_batch(&mut self, buffer_id: BufferId) -> std::io::Result<()> {
         let _span = tracing::info_span!("process_line_scan_batch").entered();
         let concurrency = self.config.editor.read_concurrency.max(1);
@@ -2476,33 +2550,48 @@ impl Editor {
         let state = self.buffers.get(&buffer_id);
         let scan = self.line_scan_state.as_mut().unwrap();
 
-        let mut results: Vec<(usize, usize)> = Vec::new();
-        let mut io_work: Vec<(usize, std::path::PathBuf, u64, usize)> = Vec::new();
+        let mut results: Vec<ScannedLeaf> = Vec::new();
+        let mut io_work: Vec<(usize, usize, std::path::PathBuf, u64, usize)> = Vec::new();
 
         while scan.next_chunk < scan.chunks.len() && (results.len() + io_work.len()) < concurrency {
             let chunk = scan.chunks[scan.next_chunk].clone();
+            let doc_offset = scan.scanned_bytes;
             scan.next_chunk += 1;
             scan.scanned_bytes += chunk.byte_len;
 
-            if chunk.already_known {
-                continue;
-            }
-
             if let Some(state) = state {
                 let leaf = &scan.leaves[chunk.leaf_index];
 
-                // Use scan_leaf for loaded buffers (shared counting logic with
-                // the TextBuffer-level scan). For unloaded buffers, collect I/O
-                // parameters for concurrent filesystem access.
+                // Use scan_leaf_line_starts for loaded buffers (shared counting
+                // logic with the TextBuffer-level scan). For unloaded buffers,
+                // collect I/O parameters for concurrent filesystem access.
                 match state.buffer.leaf_io_params(leaf) {
                     None => {
-                        // Loaded: count in-memory via scan_leaf
-                        let count = state.buffer.scan_leaf(leaf)?;
-                        results.push((chunk.leaf_index, count));
+                        // Loaded: count in-memory via scan_leaf_line_starts
+                        let (count, samples) = state.buffer.scan_leaf_line_starts(leaf)?;
+                        results.push((
+                            chunk.leaf_index,
+                            doc_offset,
+                            chunk.byte_len,
+                            count,
+                            samples,
+                        ));
+                    }
+                    Some(_) if chunk.already_known => {
+                        // Count known from the tree; the line index gets the
+                        // chunk without samples
+                        let count = leaf.line_feed_cnt.unwrap_or(0);
+                        results.push((
+                            chunk.leaf_index,
+                            doc_offset,
+                            chunk.byte_len,
+                            count,
+                            Vec::new(),
+                        ));
                     }
                     Some((path, offset, len)) => {
                         // Unloaded: batch for concurrent I/O
-                        io_work.push((chunk.leaf_index, path, offset, len));
+                        io_work.push((chunk.leaf_index, doc_offset, path, offset, len));
                     }
                 }
             }
@@ -2519,13 +2608,14 @@ impl Editor {
                 std::io::Error::new(std::io::ErrorKind::Other, "async runtime not available")
             })?;
 
-            let io_results: Vec<std::io::Result<(usize, usize)>> = rt.block_on(async {
+            let io_results: Vec<std::io::Result<ScannedLeaf>> = rt.block_on(async {
                 let mut handles = Vec::with_capacity(io_work.len());
-                for (leaf_idx, path, offset, len) in io_work {
+                for (leaf_idx, doc_offset, path, offset, len) in io_work {
                     let fs = fs.clone();
                     handles.push(tokio::task::spawn_blocking(move || {
-                        let count = fs.count_line_feeds_in_range(&path, offset, len)?;
-                        Ok((leaf_idx, count))
+                        let (count, samples) =
+                            fs.sample_line_starts_in_range(&path, offset, len, LINE_INDEX_STRIDE)?;
+                        Ok((leaf_idx, doc_offset, len, count, samples))
                     }));
                 }
 
@@ -2541,8 +2631,16 @@ impl Editor {
             }
         }
 
-        for (leaf_idx, count) in results {
-            scan.updates.push((leaf_idx, count));
+        results.sort_by_key(|(_, doc_offset, ..)| *doc_offset);
+        for (leaf_idx, doc_offset, len, count, samples) in results {
+            if !scan.chunks[leaf_idx].already_known {
+                scan.updates.push((leaf_idx, count));
+            }
+            if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                state
+                    .buffer
+                    .record_line_scan_chunk(doc_offset, len, count, &samples);
+            }
         }
 
         Ok(())
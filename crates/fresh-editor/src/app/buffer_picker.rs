@@ -0,0 +1,269 @@
+//! Buffer picker: open buffers in most recently used order.
+//!
+//! Ctrl+Tab opens the picker on the previously used buffer, and each further
+//! Ctrl+Tab moves down the list (Ctrl+Shift+Tab moves up). The selected
+//! buffer is shown in the active split while the picker is open; cancelling
+//! puts the original buffer back. When the terminal reports key releases,
+//! letting go of Ctrl switches to the selected buffer, otherwise Enter does.
+//! Delete closes the selected buffer unless it has unsaved changes.
+//!
+//! The most recently used order is updated on every buffer activation and
+//! saved with the workspace.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, ModifierKeyCode};
+use rust_i18n::t;
+
+use super::{BufferId, Editor};
+use crate::input::commands::Suggestion;
+use crate::model::event::LeafId;
+use crate::view::prompt::{Prompt, PromptType};
+
+/// Most recently used buffers remembered
+const MAX_MRU_BUFFERS: usize = 100;
+
+/// State of an open buffer picker
+pub(super) struct BufferPicker {
+    /// Buffer shown before the picker opened, put back on cancel
+    original: BufferId,
+    /// Split that shows the preview
+    split: LeafId,
+    /// Whether releasing Ctrl switches to the selected buffer: the picker was
+    /// opened or cycled with Ctrl held
+    cycling: bool,
+}
+
+impl Editor {
+    /// Move a buffer to the front of the most recently used order
+    pub(super) fn touch_buffer_mru(&mut self, buffer_id: BufferId) {
+        self.buffer_mru.retain(|&id| id != buffer_id);
+        self.buffer_mru.insert(0, buffer_id);
+        self.buffer_mru.truncate(MAX_MRU_BUFFERS);
+    }
+
+    /// Forget a closed buffer
+    pub(super) fn forget_buffer_mru(&mut self, buffer_id: BufferId) {
+        self.buffer_mru.retain(|&id| id != buffer_id);
+    }
+
+    /// Replace the most recently used order, as restored from a workspace
+    pub(super) fn set_buffer_mru(&mut self, order: Vec<BufferId>) {
+        self.buffer_mru = order;
+        self.buffer_mru.truncate(MAX_MRU_BUFFERS);
+    }
+
+    /// Visible buffers, the active one first, then in most recently used
+    /// order, then the ones never activated
+    pub fn buffers_by_recent_use(&self) -> Vec<BufferId> {
+        let mut rest: Vec<BufferId> = self.buffers.keys().copied().collect();
+        rest.sort_by_key(|id| id.0);
+
+        let mut order: Vec<BufferId> = Vec::new();
+        for buffer_id in std::iter::once(self.active_buffer())
+            .chain(self.buffer_mru.iter().copied())
+            .chain(rest)
+        {
+            let hidden = self
+                .buffer_metadata
+                .get(&buffer_id)
+                .is_some_and(|metadata| metadata.hidden_from_tabs);
+            if !hidden && self.buffers.contains_key(&buffer_id) && !order.contains(&buffer_id) {
+                order.push(buffer_id);
+            }
+        }
+        order
+    }
+
+    /// Open the buffer picker on the previously used buffer
+    pub fn start_buffer_picker(&mut self) {
+        let original = self.active_buffer();
+        self.buffer_picker = Some(BufferPicker {
+            original,
+            split: self.split_manager.active_split(),
+            cycling: self.key_modifiers.contains(KeyModifiers::CONTROL),
+        });
+
+        let mut prompt = Prompt::with_suggestions(
+            t!("buffer_picker.prompt").to_string(),
+            PromptType::SwitchBuffer,
+            self.buffer_picker_suggestions(),
+        );
+        // The current buffer leads the list; start on the one used before it
+        if prompt.suggestions.len() > 1 {
+            prompt.selected_suggestion = Some(1);
+        }
+        self.prompt = Some(prompt);
+        self.preview_picked_buffer();
+    }
+
+    fn buffer_picker_suggestions(&self) -> Vec<Suggestion> {
+        self.buffers_by_recent_use()
+            .into_iter()
+            .map(|buffer_id| {
+                let metadata = self.buffer_metadata.get(&buffer_id);
+                let display_name = metadata
+                    .map(|metadata| metadata.display_name.clone())
+                    .unwrap_or_else(|| format!("Buffer {}", buffer_id.0));
+                let has_path = metadata
+                    .and_then(|metadata| metadata.file_path())
+                    .is_some_and(|path| !path.as_os_str().is_empty());
+
+                // Files are listed by name with their path beside them
+                let (name, description) = if has_path {
+                    let name = std::path::Path::new(&display_name)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_else(|| display_name.clone());
+                    (name, Some(display_name))
+                } else {
+                    (display_name, None)
+                };
+                let modified = self
+                    .buffers
+                    .get(&buffer_id)
+                    .is_some_and(|state| state.buffer.is_modified());
+
+                Suggestion {
+                    text: if modified {
+                        format!("{} [+]", name)
+                    } else {
+                        name
+                    },
+                    description,
+                    value: Some(buffer_id.0.to_string()),
+                    disabled: false,
+                    keybinding: None,
+                    source: None,
+                }
+            })
+            .collect()
+    }
+
+    /// Buffer selected in the open picker
+    fn picked_buffer(&self) -> Option<BufferId> {
+        let prompt = self
+            .prompt
+            .as_ref()
+            .filter(|prompt| prompt.prompt_type == PromptType::SwitchBuffer)?;
+        let suggestion = prompt.suggestions.get(prompt.selected_suggestion?)?;
+        suggestion.value.as_ref()?.parse().ok().map(BufferId)
+    }
+
+    /// Show the selected buffer in the picker's split
+    fn preview_picked_buffer(&mut self) {
+        let (Some(picker), Some(buffer_id)) = (&self.buffer_picker, self.picked_buffer()) else {
+            return;
+        };
+        let split = picker.split;
+        self.show_buffer_in_split(split, buffer_id);
+    }
+
+    /// Display a buffer in a split without activating it: no tab is added
+    /// and the most recently used order is left alone
+    fn show_buffer_in_split(&mut self, split: LeafId, buffer_id: BufferId) {
+        if !self.buffers.contains_key(&buffer_id) {
+            return;
+        }
+        self.split_manager.set_split_buffer(split, buffer_id);
+        if let Some(view_state) = self.split_view_states.get_mut(&split) {
+            view_state.switch_buffer(buffer_id);
+        }
+    }
+
+    /// Follow a key handled by the picker's prompt: preview the buffer now
+    /// selected, and note whether Ctrl+Tab is cycling through the list
+    pub(super) fn update_buffer_picker(&mut self, key_event: &KeyEvent) {
+        if self.picked_buffer().is_none() {
+            return;
+        }
+        let Some(picker) = &mut self.buffer_picker else {
+            return;
+        };
+        if key_event.modifiers.contains(KeyModifiers::CONTROL)
+            && matches!(key_event.code, KeyCode::Tab | KeyCode::BackTab)
+        {
+            picker.cycling = true;
+        }
+        self.preview_picked_buffer();
+    }
+
+    /// Switch to the buffer chosen in the picker
+    pub(super) fn confirm_buffer_picker(&mut self, input: &str) {
+        let Some(picker) = self.buffer_picker.take() else {
+            return;
+        };
+        self.show_buffer_in_split(picker.split, picker.original);
+        if let Ok(id) = input.parse::<usize>() {
+            self.switch_buffer(BufferId(id));
+        }
+    }
+
+    /// Put back the buffer the picker was opened from
+    pub(super) fn cancel_buffer_picker(&mut self) {
+        if let Some(picker) = self.buffer_picker.take() {
+            self.show_buffer_in_split(picker.split, picker.original);
+        }
+    }
+
+    /// Close the buffer selected in the picker and keep the picker open
+    pub(super) fn close_buffer_from_picker(&mut self) {
+        let (Some(split), Some(buffer_id)) = (
+            self.buffer_picker.as_ref().map(|picker| picker.split),
+            self.picked_buffer(),
+        ) else {
+            return;
+        };
+        if self.needs_save_prompt(buffer_id) {
+            let name = self
+                .buffer_metadata
+                .get(&buffer_id)
+                .map(|metadata| metadata.display_name.clone())
+                .unwrap_or_default();
+            self.set_status_message(t!("buffer_picker.unsaved", name = name).to_string());
+            return;
+        }
+
+        // Close from the original buffer so the split settles on it, or on
+        // its replacement when the original is the one closed
+        if let Some(original) = self.buffer_picker.as_ref().map(|picker| picker.original) {
+            self.show_buffer_in_split(split, original);
+        }
+        if let Err(e) = self.close_buffer(buffer_id) {
+            tracing::warn!("Failed to close buffer: {}", e);
+            return;
+        }
+        let active = self.active_buffer();
+        if let Some(picker) = &mut self.buffer_picker {
+            picker.original = active;
+        }
+
+        let suggestions = self.buffer_picker_suggestions();
+        if let Some(prompt) = &mut self.prompt {
+            let selected = prompt.selected_suggestion.unwrap_or(0);
+            prompt.original_suggestions = Some(suggestions);
+            prompt.filter_suggestions(false);
+            if !prompt.suggestions.is_empty() {
+                prompt.selected_suggestion = Some(selected.min(prompt.suggestions.len() - 1));
+            }
+        }
+        self.preview_picked_buffer();
+    }
+
+    /// Handle a key release reported by the terminal: letting go of Ctrl
+    /// after Ctrl+Tab switches to the buffer selected in the picker
+    pub fn handle_key_release(&mut self, code: KeyCode) {
+        let ctrl = matches!(
+            code,
+            KeyCode::Modifier(ModifierKeyCode::LeftControl | ModifierKeyCode::RightControl)
+        );
+        let cycling = self
+            .buffer_picker
+            .as_ref()
+            .is_some_and(|picker| picker.cycling);
+        if !ctrl || !cycling || self.picked_buffer().is_none() {
+            return;
+        }
+        if let Some((input, _, _)) = self.confirm_prompt() {
+            self.confirm_buffer_picker(input.trim());
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Independent views for clients attached to one session.
+//!
+//! With `editor.session_independent_views` on, every client of a session
+//! keeps its own splits, tabs, active buffer, cursors, scroll positions and
+//! prompt over the shared buffers. Only one view is live in the editor at a
+//! time; the server activates a client's view before handling its input or
+//! rendering its frame, and the others wait here.
+//!
+//! Edits reach the waiting views as they happen, so their cursors stay on the
+//! same text. Buffers closed meanwhile are dropped from a view when it is next
+//! activated.
+//!
+//! Each client sees the primary cursor and selection of the others drawn over
+//! the buffers they share, in a color of their own and tagged with the name
+//! the client gave when attaching.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::Frame;
+
+use super::types::CachedLayout;
+use super::{buffer_picker, BufferId, Editor};
+use crate::input::keybindings::KeyContext;
+use crate::model::event::LeafId;
+use crate::view::prompt::Prompt;
+use crate::view::split::{SplitManager, SplitViewState};
+
+/// View state of a client whose view is not live
+pub(super) struct ClientView {
+    split_manager: SplitManager,
+    split_view_states: HashMap<LeafId, SplitViewState>,
+    previous_viewports: HashMap<LeafId, (usize, u16, u16)>,
+    cached_layout: CachedLayout,
+    prompt: Option<Prompt>,
+    buffer_picker: Option<buffer_picker::BufferPicker>,
+    key_context: KeyContext,
+    terminal_width: u16,
+    terminal_height: u16,
+}
+
+/// Colors told apart by client id
+const CLIENT_COLORS: [Color; 6] = [
+    Color::Magenta,
+    Color::Cyan,
+    Color::Yellow,
+    Color::Green,
+    Color::Blue,
+    Color::Red,
+];
+
+/// Primary cursor of a client whose view is not live
+struct RemoteCursor {
+    label: String,
+    color: Color,
+    buffer_id: BufferId,
+    position: usize,
+    selection: Option<Range<usize>>,
+}
+
+impl Editor {
+    /// Make a client's view the live one. A client seen for the first time
+    /// starts from a copy of the current view.
+    pub fn activate_client_view(&mut self, client_id: u64) {
+        if self.active_client_view == Some(client_id) {
+            return;
+        }
+        let Some(previous) = self.active_client_view else {
+            // The live view has no owner (first client, or its owner left)
+            if let Some(view) = self.client_views.remove(&client_id) {
+                self.swap_client_view(view);
+                self.repair_client_view();
+            }
+            self.active_client_view = Some(client_id);
+            return;
+        };
+
+        let incoming = self
+            .client_views
+            .remove(&client_id)
+            .unwrap_or_else(|| self.copy_client_view());
+        let outgoing = self.swap_client_view(incoming);
+        self.client_views.insert(previous, outgoing);
+        self.active_client_view = Some(client_id);
+        self.repair_client_view();
+    }
+
+    /// Name a client's cursor is tagged with in the others' views
+    pub fn set_client_name(&mut self, client_id: u64, name: String) {
+        self.client_names.insert(client_id, name);
+    }
+
+    /// Forget the view of a client that has left
+    pub fn remove_client_view(&mut self, client_id: u64) {
+        self.client_names.remove(&client_id);
+        if self.active_client_view == Some(client_id) {
+            self.active_client_view = None;
+        } else {
+            self.client_views.remove(&client_id);
+        }
+    }
+
+    fn copy_client_view(&self) -> ClientView {
+        ClientView {
+            split_manager: self.split_manager.clone(),
+            split_view_states: self.split_view_states.clone(),
+            previous_viewports: self.previous_viewports.clone(),
+            cached_layout: self.cached_layout.clone(),
+            prompt: None,
+            buffer_picker: None,
+            key_context: KeyContext::Normal,
+            terminal_width: self.terminal_width,
+            terminal_height: self.terminal_height,
+        }
+    }
+
+    /// Put a view in place of the live one and return the live one
+    fn swap_client_view(&mut self, view: ClientView) -> ClientView {
+        ClientView {
+            split_manager: std::mem::replace(&mut self.split_manager, view.split_manager),
+            split_view_states: std::mem::replace(
+                &mut self.split_view_states,
+                view.split_view_states,
+            ),
+            previous_viewports: std::mem::replace(
+                &mut self.previous_viewports,
+                view.previous_viewports,
+            ),
+            cached_layout: std::mem::replace(&mut self.cached_layout, view.cached_layout),
+            prompt: std::mem::replace(&mut self.prompt, view.prompt),
+            buffer_picker: std::mem::replace(&mut self.buffer_picker, view.buffer_picker),
+            key_context: std::mem::replace(&mut self.key_context, view.key_context),
+            terminal_width: std::mem::replace(&mut self.terminal_width, view.terminal_width),
+            terminal_height: std::mem::replace(&mut self.terminal_height, view.terminal_height),
+        }
+    }
+
+    /// Catch a view up with buffers closed or shortened while it waited
+    fn repair_client_view(&mut self) {
+        let Some(fallback) = self.buffers.keys().min_by_key(|id| id.0).copied() else {
+            return;
+        };
+        for leaf in self.split_manager.leaf_split_ids() {
+            let shown = self.split_manager.get_buffer_id(leaf.into());
+            if shown.is_some_and(|id| !self.buffers.contains_key(&id)) {
+                self.split_manager.set_split_buffer(leaf, fallback);
+            }
+        }
+
+        for view_state in self.split_view_states.values_mut() {
+            if !self.buffers.contains_key(&view_state.active_buffer) {
+                view_state.switch_buffer(fallback);
+            }
+            view_state
+                .keyed_states
+                .retain(|id, _| self.buffers.contains_key(id));
+            view_state
+                .open_buffers
+                .retain(|id| self.buffers.contains_key(id));
+            if view_state.open_buffers.is_empty() {
+                view_state.open_buffers.push(view_state.active_buffer);
+            }
+            view_state
+                .focus_history
+                .retain(|id| self.buffers.contains_key(id));
+
+            for (buffer_id, buffer_view) in view_state.keyed_states.iter_mut() {
+                let len = self
+                    .buffers
+                    .get(buffer_id)
+                    .map_or(0, |state| state.buffer.len());
+                buffer_view.cursors.map(|cursor| {
+                    cursor.position = cursor.position.min(len);
+                    cursor.anchor = cursor.anchor.map(|anchor| anchor.min(len));
+                });
+                buffer_view.viewport.top_byte = buffer_view.viewport.top_byte.min(len);
+            }
+            view_state.layout_dirty = true;
+        }
+    }
+
+    /// Move the cursors of waiting views past an edit to a buffer
+    pub(super) fn adjust_client_view_cursors(
+        &mut self,
+        buffer_id: BufferId,
+        adjustments: &[(usize, usize, usize)],
+    ) {
+        for view in self.client_views.values_mut() {
+            for view_state in view.split_view_states.values_mut() {
+                if let Some(buffer_view) = view_state.keyed_states.get_mut(&buffer_id) {
+                    for (edit_pos, old_len, new_len) in adjustments {
+                        buffer_view
+                            .cursors
+                            .adjust_for_edit(*edit_pos, *old_len, *new_len);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Primary cursors of the clients whose views are waiting
+    fn remote_cursors(&self) -> Vec<RemoteCursor> {
+        let mut client_ids: Vec<u64> = self.client_views.keys().copied().collect();
+        client_ids.sort_unstable();
+        client_ids
+            .into_iter()
+            .filter_map(|client_id| {
+                let view = &self.client_views[&client_id];
+                let view_state = view
+                    .split_view_states
+                    .get(&view.split_manager.active_split())?;
+                let cursor = view_state.cursors.primary();
+                Some(RemoteCursor {
+                    label: self
+                        .client_names
+                        .get(&client_id)
+                        .cloned()
+                        .unwrap_or_else(|| format!("client {}", client_id)),
+                    color: CLIENT_COLORS[client_id as usize % CLIENT_COLORS.len()],
+                    buffer_id: view_state.active_buffer,
+                    position: cursor.position,
+                    selection: cursor.selection_range(),
+                })
+            })
+            .collect()
+    }
+
+    /// Draw the other clients' cursors and selections over the splits of the
+    /// live view that show the same buffer
+    pub(super) fn render_remote_cursors(&self, frame: &mut Frame) {
+        if self.active_client_view.is_none() || self.client_views.is_empty() {
+            return;
+        }
+        let cursors = self.remote_cursors();
+        let buf = frame.buffer_mut();
+
+        for (leaf, buffer_id, content_rect, _, _, _) in &self.cached_layout.split_areas {
+            let (Some(mappings), Some(state)) = (
+                self.cached_layout.view_line_mappings.get(leaf),
+                self.buffers.get(buffer_id),
+            ) else {
+                continue;
+            };
+            let compose_width = self
+                .split_view_states
+                .get(leaf)
+                .and_then(|view_state| view_state.compose_width);
+            let area = Self::adjust_content_rect_for_compose(*content_rect, compose_width);
+            let text_x = area.x + state.margins.left_total_width() as u16;
+            // Screen cell of a visual row and column, if inside the text area
+            let cell_at = |row: usize, col: usize| -> Option<(u16, u16)> {
+                let x = text_x.checked_add(u16::try_from(col).ok()?)?;
+                let y = area.y.checked_add(u16::try_from(row).ok()?)?;
+                (x < area.right() && y < area.bottom()).then_some((x, y))
+            };
+
+            for cursor in cursors.iter().filter(|c| c.buffer_id == *buffer_id) {
+                if let Some(selection) = &cursor.selection {
+                    let style = Style::default()
+                        .fg(cursor.color)
+                        .add_modifier(Modifier::UNDERLINED);
+                    for (row, mapping) in mappings.iter().enumerate() {
+                        for col in 0..mapping.visual_to_char.len() {
+                            let selected = mapping
+                                .source_byte_at_visual_col(col)
+                                .is_some_and(|byte| selection.contains(&byte));
+                            if let Some(cell) = cell_at(row, col)
+                                .filter(|_| selected)
+                                .and_then(|position| buf.cell_mut(position))
+                            {
+                                cell.set_style(style);
+                            }
+                        }
+                    }
+                }
+
+                let (Some(row), Some(col)) = (
+                    self.cached_layout.find_visual_row(*leaf, cursor.position),
+                    self.cached_layout
+                        .byte_to_visual_column(*leaf, cursor.position),
+                ) else {
+                    continue;
+                };
+                let Some((x, y)) = cell_at(row, col) else {
+                    continue;
+                };
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_style(Style::default().fg(Color::Black).bg(cursor.color));
+                }
+
+                // Name tag above the cursor, or below it on the top row
+                let tag_y = if y > area.y { y - 1 } else { y + 1 };
+                if tag_y < area.bottom() {
+                    let tag = format!(" {} ", cursor.label);
+                    buf.set_stringn(
+                        x,
+                        tag_y,
+                        &tag,
+                        usize::from(area.right() - x),
+                        Style::default().fg(Color::Black).bg(cursor.color),
+                    );
+                }
+            }
+        }
+    }
+}
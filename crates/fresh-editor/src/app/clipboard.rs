@@ -2,21 +2,38 @@
 //!
 //! This module contains clipboard operations and multi-cursor actions:
 //! - Copy/cut/paste operations
+//! - Copying only the visible text of a selection, without folded lines
 //! - Copy with formatting (HTML with syntax highlighting)
+//! - Paste from the clipboard history and cycling through older entries
+//! - Spreading a multi-line paste across cursors, one line each
 //! - Multi-cursor add above/below/at next match
+//! - Aligning cursors on a regex match
 
 use rust_i18n::t;
 
+use crate::input::commands::Suggestion;
+use crate::input::keybindings::Action;
 use crate::input::multi_cursor::{
-    add_cursor_above, add_cursor_at_next_match, add_cursor_below, AddCursorResult,
+    add_cursor_above, add_cursor_at_next_match, add_cursor_below, pattern_positions,
+    AddCursorResult,
 };
 use crate::model::buffer::Buffer;
-use crate::model::cursor::Position2D;
+use crate::model::cursor::{Cursor, Position2D};
 use crate::model::event::{CursorId, Event};
 use crate::primitives::word_navigation::{find_word_start_left, find_word_start_right};
+use crate::view::folding::visible_byte_ranges;
+use crate::view::prompt::{Prompt, PromptType};
 
+use super::types::PasteCycle;
 use super::Editor;
 
+/// Maximum number of characters shown for an entry in the paste history picker
+const HISTORY_PREVIEW_CHARS: usize = 60;
+
+/// Pastes at least this large are applied as one bulk edit, sent to LSP
+/// servers as a single full-document change, and reported with their size
+const LARGE_PASTE_BYTES: usize = 256 * 1024;
+
 /// Convert byte offset to 2D position (line, column)
 fn byte_to_2d(buffer: &Buffer, byte_pos: usize) -> Position2D {
     let line = buffer.get_line_number(byte_pos);
@@ -116,6 +133,64 @@ impl Editor {
         }
     }
 
+    /// Copy the selection, leaving out the lines hidden inside collapsed folds
+    ///
+    /// The visible pieces are joined with newlines. Block selections and
+    /// copies without a selection work as in [`Self::copy_selection`].
+    pub fn copy_visible_selection(&mut self) {
+        let has_block_selection = self
+            .active_cursors()
+            .iter()
+            .any(|(_, cursor)| cursor.has_block_selection());
+        let ranges: Vec<_> = self
+            .active_cursors()
+            .iter()
+            .filter_map(|(_, cursor)| cursor.selection_range())
+            .collect();
+        if has_block_selection || ranges.is_empty() {
+            self.copy_selection();
+            return;
+        }
+
+        let split_id = self.split_manager.active_split();
+        let buffer_id = self.active_buffer();
+        let hidden = {
+            let state = self.active_state();
+            self.split_view_states
+                .get(&split_id)
+                .and_then(|view_state| view_state.keyed_states.get(&buffer_id))
+                .map(|buf_state| {
+                    buf_state
+                        .folds
+                        .hidden_byte_ranges(&state.buffer, &state.marker_list)
+                })
+                .unwrap_or_default()
+        };
+
+        let mut text = String::new();
+        let state = self.active_state_mut();
+        for range in ranges {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            let mut range_text = String::new();
+            for piece in visible_byte_ranges(range, &hidden) {
+                // A fold hides whole lines, so the piece before it usually
+                // ends with the header's newline already
+                if !range_text.is_empty() && !range_text.ends_with('\n') {
+                    range_text.push('\n');
+                }
+                range_text.push_str(&state.get_text_range(piece.start, piece.end));
+            }
+            text.push_str(&range_text);
+        }
+
+        if !text.is_empty() {
+            self.clipboard.copy(text);
+            self.status_message = Some(t!("clipboard.copied").to_string());
+        }
+    }
+
     /// Extract text from block (rectangular) selection
     ///
     /// For block selection, we need to extract a rectangular region defined by:
@@ -233,7 +308,7 @@ impl Editor {
         use crate::services::styled_html::render_styled_html;
 
         // Get the requested theme from registry
-        let theme = match self.theme_registry.get_cloned(theme_name) {
+        let theme = match self.load_theme(theme_name) {
             Some(t) => t,
             None => {
                 self.status_message = Some(format!("Theme '{}' not found", theme_name));
@@ -306,6 +381,7 @@ impl Editor {
                         Some(crate::primitives::highlighter::HighlightSpan {
                             range: start..end,
                             color: span.color,
+                            modifiers: ratatui::style::Modifier::empty(),
                         })
                     } else {
                         None
@@ -508,7 +584,149 @@ impl Editor {
         };
 
         // Use paste_text which handles line ending normalization
-        self.paste_text(text);
+        self.paste_text(text.clone());
+        self.start_paste_cycle(&text);
+    }
+
+    /// Open a picker over the clipboard history, newest first
+    pub(super) fn start_paste_from_history_prompt(&mut self) {
+        let suggestions: Vec<Suggestion> = self
+            .clipboard
+            .history()
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let line_count = entry.lines().count();
+                Suggestion {
+                    text: history_preview(entry),
+                    description: (line_count > 1)
+                        .then(|| t!("clipboard.history_lines", count = line_count).to_string()),
+                    value: Some(index.to_string()),
+                    disabled: false,
+                    keybinding: None,
+                    source: None,
+                }
+            })
+            .collect();
+
+        if suggestions.is_empty() {
+            self.set_status_message(t!("clipboard.history_empty").to_string());
+            return;
+        }
+
+        self.prompt = Some(Prompt::with_suggestions(
+            t!("clipboard.history_prompt").to_string(),
+            PromptType::PasteFromHistory,
+            suggestions,
+        ));
+    }
+
+    /// Paste an entry of the clipboard history, moving it to the front
+    ///
+    /// The entry also becomes the system clipboard content.
+    pub(super) fn paste_from_history(&mut self, index: usize) {
+        let Some(text) = self.clipboard.history().get(index).cloned() else {
+            return;
+        };
+        self.clipboard.copy(text.clone());
+        self.paste_text(text.clone());
+        self.start_paste_cycle(&text);
+    }
+
+    /// Replace the text just pasted with the next older clipboard history entry
+    ///
+    /// Without a paste to replace, pastes the newest entry instead. Past the
+    /// oldest entry the cycle wraps around to the newest.
+    pub(super) fn cycle_paste(&mut self) {
+        let Some(cycle) = self
+            .paste_cycle
+            .take()
+            .filter(|cycle| self.paste_cycle_valid(cycle))
+        else {
+            self.paste();
+            return;
+        };
+
+        let history_len = self.clipboard.history().len();
+        if history_len == 0 {
+            return;
+        }
+        let index = cycle.next % history_len;
+        let text = self.clipboard.history()[index].clone();
+
+        // Select the previous paste at each cursor so the new text replaces it
+        self.active_cursors_mut().map(|cursor| {
+            cursor.anchor = Some(cursor.position.saturating_sub(cycle.len));
+        });
+        self.paste_text(text.clone());
+
+        self.paste_cycle = (!self.paste_is_distributed(&text)).then(|| PasteCycle {
+            next: index + 1,
+            ..self.new_paste_cycle(&text)
+        });
+        self.set_status_message(
+            t!("clipboard.cycled", index = index + 1, count = history_len).to_string(),
+        );
+    }
+
+    /// Remember a paste into the active buffer so Cycle Paste can replace it
+    fn start_paste_cycle(&mut self, text: &str) {
+        if self.prompt.is_some() || self.terminal_mode || self.paste_is_distributed(text) {
+            return;
+        }
+        // Continue with the entry after the pasted one, or with the newest
+        // when the text didn't come from the history
+        let next = self
+            .clipboard
+            .history()
+            .iter()
+            .position(|entry| entry == text)
+            .map_or(0, |index| index + 1);
+        self.paste_cycle = Some(PasteCycle {
+            next,
+            ..self.new_paste_cycle(text)
+        });
+    }
+
+    fn new_paste_cycle(&self, text: &str) -> PasteCycle {
+        PasteCycle {
+            buffer_id: self.active_buffer(),
+            next: 0,
+            len: self.paste_text_for_buffer(text).len(),
+            positions: self
+                .active_cursors()
+                .iter()
+                .map(|(_, cursor)| cursor.position)
+                .collect(),
+        }
+    }
+
+    /// Whether the cursors still sit right after the paste that `cycle` recorded
+    fn paste_cycle_valid(&self, cycle: &PasteCycle) -> bool {
+        cycle.buffer_id == self.active_buffer()
+            && self
+                .active_cursors()
+                .iter()
+                .map(|(_, cursor)| (cursor.position, cursor.anchor))
+                .eq(cycle.positions.iter().map(|&position| (position, None)))
+    }
+
+    /// Whether pasting `text` puts one of its lines at each cursor
+    fn paste_is_distributed(&self, text: &str) -> bool {
+        let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+        distributed_lines(&normalized, self.active_cursors().count()).is_some()
+    }
+
+    /// Convert pasted text to the active buffer's line ending format
+    fn paste_text_for_buffer(&self, text: &str) -> String {
+        // Normalize line endings: first convert all to LF, then to buffer's format
+        // This handles Windows clipboard (CRLF), old Mac (CR), and Unix (LF)
+        let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+        match self.active_state().buffer.line_ending() {
+            crate::model::buffer::LineEnding::LF => normalized,
+            crate::model::buffer::LineEnding::CRLF => normalized.replace('\n', "\r\n"),
+            crate::model::buffer::LineEnding::CR => normalized.replace('\n', "\r"),
+        }
     }
 
     /// Paste text directly into the editor
@@ -516,10 +734,13 @@ impl Editor {
     /// Handles:
     /// - Line ending normalization (CRLF/CR → buffer's format)
     /// - Single cursor paste
-    /// - Multi-cursor paste (pastes at each cursor)
+    /// - Multi-cursor paste (pastes at each cursor, or one line per cursor
+    ///   when the text has as many lines as there are cursors)
     /// - Selection replacement (deletes selection before inserting)
     /// - Atomic undo (single undo step for entire operation)
     /// - Routing to prompt if one is open
+    ///
+    /// The text is inserted as is: no auto-indent or auto-pairing.
     pub fn paste_text(&mut self, paste_text: String) {
         if paste_text.is_empty() {
             return;
@@ -544,16 +765,11 @@ impl Editor {
         }
 
         // Convert to buffer's line ending format
-        let buffer_line_ending = self.active_state().buffer.line_ending();
-        let paste_text = match buffer_line_ending {
-            crate::model::buffer::LineEnding::LF => normalized,
-            crate::model::buffer::LineEnding::CRLF => normalized.replace('\n', "\r\n"),
-            crate::model::buffer::LineEnding::CR => normalized.replace('\n', "\r"),
-        };
+        let paste_text = self.paste_text_for_buffer(&normalized);
 
         let mut events = Vec::new();
 
-        // Collect cursor info sorted in reverse order by position
+        // Collect cursor info sorted by position
         let mut cursor_data: Vec<_> = self
             .active_cursors()
             .iter()
@@ -566,24 +782,33 @@ impl Editor {
                 (cursor_id, selection, insert_position)
             })
             .collect();
-        cursor_data.sort_by_key(|(_, _, pos)| std::cmp::Reverse(*pos));
+        cursor_data.sort_by_key(|(_, _, pos)| *pos);
+
+        // Lines go to cursors in document order
+        let texts: Vec<String> = match distributed_lines(&normalized, cursor_data.len()) {
+            Some(lines) => lines.into_iter().map(str::to_string).collect(),
+            None => vec![paste_text.clone(); cursor_data.len()],
+        };
+        let pasted_len: usize = texts.iter().map(String::len).sum();
 
         // Get deleted text for each selection
         let cursor_data_with_text: Vec<_> = {
             let state = self.active_state_mut();
             cursor_data
                 .into_iter()
-                .map(|(cursor_id, selection, insert_position)| {
+                .zip(texts)
+                .rev()
+                .map(|((cursor_id, selection, insert_position), text)| {
                     let deleted_text = selection
                         .as_ref()
                         .map(|r| state.get_text_range(r.start, r.end));
-                    (cursor_id, selection, insert_position, deleted_text)
+                    (cursor_id, selection, insert_position, deleted_text, text)
                 })
                 .collect()
         };
 
-        // Build events for each cursor
-        for (cursor_id, selection, insert_position, deleted_text) in cursor_data_with_text {
+        // Build events for each cursor, last position first
+        for (cursor_id, selection, insert_position, deleted_text, text) in cursor_data_with_text {
             if let (Some(range), Some(text)) = (selection, deleted_text) {
                 events.push(Event::Delete {
                     range,
@@ -593,14 +818,15 @@ impl Editor {
             }
             events.push(Event::Insert {
                 position: insert_position,
-                text: paste_text.clone(),
+                text,
                 cursor_id,
             });
         }
 
         // Apply events with atomic undo using bulk edit for O(n) performance
-        if events.len() > 1 {
-            // Use optimized bulk edit for multi-cursor paste
+        let large = pasted_len >= LARGE_PASTE_BYTES;
+        if events.len() > 1 || large {
+            // Use optimized bulk edit for multi-cursor and large pastes
             if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, "Paste".to_string()) {
                 self.active_event_log_mut().append(bulk_edit);
             }
@@ -609,7 +835,16 @@ impl Editor {
             self.apply_event_to_active_buffer(&event);
         }
 
-        self.status_message = Some(t!("clipboard.pasted").to_string());
+        self.status_message = Some(if large {
+            t!(
+                "clipboard.pasted_large",
+                lines = normalized.lines().count(),
+                size = crate::i18n::format::format_size(pasted_len as u64)
+            )
+            .to_string()
+        } else {
+            t!("clipboard.pasted").to_string()
+        });
     }
 
     /// Set clipboard content for testing purposes
@@ -756,6 +991,41 @@ impl Editor {
         }
     }
 
+    /// Put one cursor on the first match of `pattern` in each covered line,
+    /// then align the cursors to a common column
+    pub fn align_by_pattern(&mut self, pattern: &str) {
+        let regex = match regex::Regex::new(pattern) {
+            Ok(regex) => regex,
+            Err(e) => {
+                self.set_status_message(
+                    t!("error.invalid_regex", error = e.to_string()).to_string(),
+                );
+                return;
+            }
+        };
+
+        let cursors = self.active_cursors().clone();
+        let positions = pattern_positions(self.active_state_mut(), &cursors, &regex);
+        let Some((&first, rest)) = positions.split_first() else {
+            self.set_status_message(t!("align.no_matches").to_string());
+            return;
+        };
+
+        {
+            let cursors = self.active_cursors_mut();
+            cursors.remove_secondary();
+            *cursors.primary_mut() = Cursor::new(first);
+            for &position in rest {
+                cursors.add(Cursor::new(position));
+            }
+            cursors.normalize();
+        }
+
+        if let Err(e) = self.handle_action(Action::AlignCursors) {
+            tracing::warn!("Failed to align cursors: {}", e);
+        }
+    }
+
     // =========================================================================
     // Vi-style yank operations (copy range without requiring selection)
     // =========================================================================
@@ -936,3 +1206,26 @@ impl Editor {
         }
     }
 }
+
+/// The lines of `text` to paste one per cursor, when it has exactly as many
+/// lines as there are cursors. A trailing newline doesn't start a line.
+fn distributed_lines(text: &str, cursor_count: usize) -> Option<Vec<&str>> {
+    if cursor_count < 2 {
+        return None;
+    }
+    let lines: Vec<&str> = text.lines().collect();
+    (lines.len() == cursor_count).then_some(lines)
+}
+
+/// First line of a clipboard history entry, shortened for the picker
+fn history_preview(text: &str) -> String {
+    let first_line = text.trim_start().lines().next().unwrap_or("").trim_end();
+    if first_line.chars().count() > HISTORY_PREVIEW_CHARS {
+        let shortened: String = first_line.chars().take(HISTORY_PREVIEW_CHARS).collect();
+        format!("{}…", shortened)
+    } else if first_line.len() < text.trim().len() {
+        format!("{}…", first_line)
+    } else {
+        first_line.to_string()
+    }
+}
@@ -0,0 +1,174 @@
+//! Color swatches and the color adjuster.
+//!
+//! With `color_swatches` on, a one-cell swatch is drawn before each color
+//! literal in view. Clicking a swatch, or running "Adjust Color" with the
+//! cursor on a literal, opens a prompt holding the literal's three channels.
+//! While it is open the swatch shows the typed color; confirming rewrites the
+//! literal in the notation it was written in.
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::model::buffer::Buffer;
+use crate::model::event::{BufferId, Event, LeafId};
+use crate::primitives::color_literal::{self, ColorLiteral};
+use crate::view::folding::indent_folding;
+use crate::view::prompt::PromptType;
+
+impl Editor {
+    /// Start of the color literal whose swatch is drawn at `(col, row)`
+    pub(super) fn color_swatch_at(
+        &self,
+        split_id: LeafId,
+        buffer_id: BufferId,
+        content_rect: ratatui::layout::Rect,
+        col: u16,
+        row: u16,
+    ) -> Option<usize> {
+        if !self.config.editor.color_swatches {
+            return None;
+        }
+        let state = self.buffers.get(&buffer_id)?;
+        let compose_width = self
+            .split_view_states
+            .get(&split_id)
+            .and_then(|vs| vs.compose_width);
+        let content_rect = Self::adjust_content_rect_for_compose(content_rect, compose_width);
+        let gutter_width = state.margins.left_total_width() as u16;
+        let text_col = col.checked_sub(content_rect.x)?.checked_sub(gutter_width)? as usize;
+        let visual_row = row.checked_sub(content_rect.y)? as usize;
+        let mapping = self
+            .cached_layout
+            .view_line_mappings
+            .get(&split_id)?
+            .get(visual_row)?;
+
+        // The swatch is the virtual cell right before the literal
+        let char_idx = *mapping.visual_to_char.get(text_col)?;
+        if mapping.char_source_bytes.get(char_idx)?.is_some() {
+            return None;
+        }
+        let literal_start = (*mapping.char_source_bytes.get(char_idx + 1)?)?;
+        color_literal_around(&state.buffer, literal_start)
+            .filter(|literal| literal.range.start == literal_start)
+            .map(|literal| literal.range.start)
+    }
+
+    /// Open the color adjuster for the color literal at the cursor
+    pub fn adjust_color_at_cursor(&mut self) {
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        match color_literal_around(&self.active_state().buffer, position) {
+            Some(literal) => self.start_adjust_color_prompt(buffer_id, literal),
+            None => self.set_status_message(t!("color.no_literal_at_cursor").to_string()),
+        }
+    }
+
+    /// Open the color adjuster for the color literal starting at `start`
+    pub(super) fn adjust_color_at(&mut self, buffer_id: BufferId, start: usize) {
+        let literal = self
+            .buffers
+            .get(&buffer_id)
+            .and_then(|state| color_literal_around(&state.buffer, start));
+        if let Some(literal) = literal {
+            self.start_adjust_color_prompt(buffer_id, literal);
+        }
+    }
+
+    fn start_adjust_color_prompt(&mut self, buffer_id: BufferId, literal: ColorLiteral) {
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.color_preview = Some((literal.range.start, literal.rgb()));
+        }
+        let [a, b, c] = literal.channels;
+        let message = t!(
+            "color.adjust_prompt",
+            channels = literal.notation.channel_names()
+        )
+        .to_string();
+        self.start_prompt_with_initial_text(
+            message,
+            PromptType::AdjustColor { buffer_id, literal },
+            format!("{a} {b} {c}"),
+        );
+    }
+
+    /// Show the color typed so far on the literal's swatch. Input that
+    /// doesn't parse leaves the last good color.
+    pub(super) fn preview_adjusted_color(
+        &mut self,
+        buffer_id: BufferId,
+        literal: &ColorLiteral,
+        input: &str,
+    ) {
+        let Some(channels) = color_literal::parse_channels(literal.notation, input) else {
+            return;
+        };
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.color_preview = Some((literal.range.start, literal.notation.to_rgb(channels)));
+        }
+    }
+
+    pub(super) fn clear_color_preview(&mut self, buffer_id: BufferId) {
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.color_preview = None;
+        }
+    }
+
+    /// Rewrite the literal with the channels typed in the prompt
+    pub(super) fn apply_adjusted_color(
+        &mut self,
+        buffer_id: BufferId,
+        literal: &ColorLiteral,
+        input: &str,
+    ) {
+        self.clear_color_preview(buffer_id);
+        let Some(channels) = color_literal::parse_channels(literal.notation, input) else {
+            self.set_status_message(
+                t!(
+                    "color.invalid_channels",
+                    channels = literal.notation.channel_names()
+                )
+                .to_string(),
+            );
+            return;
+        };
+        if buffer_id != self.active_buffer() {
+            return;
+        }
+        let text = literal.notation.format(channels);
+        let old_text = self
+            .active_state_mut()
+            .get_text_range(literal.range.start, literal.range.end);
+        if old_text == text {
+            return;
+        }
+        let cursor_id = self.active_cursors().primary_id();
+        let batch = Event::Batch {
+            events: vec![
+                Event::Delete {
+                    range: literal.range.clone(),
+                    deleted_text: old_text,
+                    cursor_id,
+                },
+                Event::Insert {
+                    position: literal.range.start,
+                    text,
+                    cursor_id,
+                },
+            ],
+            description: "Adjust color".to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
+    }
+}
+
+/// The color literal on `position`'s line that contains it or ends there,
+/// with its range in buffer bytes
+fn color_literal_around(buffer: &Buffer, position: usize) -> Option<ColorLiteral> {
+    let line_start = indent_folding::find_line_start_byte(buffer, position);
+    let line = buffer.get_line(buffer.get_line_number(position))?;
+    let mut literal = color_literal::color_literal_at(&line, position - line_start)?;
+    literal.range = line_start + literal.range.start..line_start + literal.range.end;
+    Some(literal)
+}
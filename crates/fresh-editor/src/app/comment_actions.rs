@@ -0,0 +1,486 @@
+//! Comment toggling for the Editor.
+//!
+//! This module contains the comment commands:
+//! - Toggle line comments on the lines under every cursor
+//! - Toggle block comments around each selection
+//!
+//! Comment syntax comes from the language config (`comment_prefix`,
+//! `block_comment_start`, `block_comment_end`), falling back to built-in
+//! defaults for well-known languages.
+
+use std::ops::Range;
+
+use rust_i18n::t;
+
+use crate::model::buffer::Buffer;
+use crate::model::event::{CursorId, Event};
+
+use super::Editor;
+
+/// Built-in line comment prefix for languages whose config doesn't set one.
+fn default_line_comment(language: &str) -> Option<&'static str> {
+    match language {
+        "rust" | "javascript" | "typescript" | "c" | "cpp" | "csharp" | "c_sharp" | "go"
+        | "java" | "odin" | "zig" | "php" | "pascal" | "swift" | "kotlin" | "scala" | "dart"
+        | "jsonc" | "typst" | "templ" | "scss" | "less" | "protobuf" | "groovy" => Some("//"),
+        "python" | "bash" | "ruby" | "perl" | "r" | "julia" | "toml" | "yaml" | "makefile"
+        | "dockerfile" | "cmake" | "nix" | "elixir" | "powershell" | "gitignore" | "gitconfig"
+        | "gitattributes" | "git-rebase" | "git-commit" => Some("#"),
+        "lua" | "sql" | "haskell" | "elm" | "ada" => Some("--"),
+        "latex" | "erlang" | "matlab" => Some("%"),
+        "lisp" | "clojure" | "scheme" | "racket" | "ini" => Some(";"),
+        "vim" => Some("\""),
+        _ => None,
+    }
+}
+
+/// Built-in block comment delimiters for languages whose config doesn't set them.
+fn default_block_comment(language: &str) -> Option<(&'static str, &'static str)> {
+    match language {
+        "rust" | "javascript" | "typescript" | "c" | "cpp" | "csharp" | "c_sharp" | "go"
+        | "java" | "odin" | "php" | "swift" | "kotlin" | "scala" | "dart" | "jsonc" | "typst"
+        | "templ" | "css" | "scss" | "less" | "sql" | "protobuf" | "groovy" => Some(("/*", "*/")),
+        "html" | "xml" | "markdown" | "mdx" | "vue" | "svelte" => Some(("<!--", "-->")),
+        "lua" => Some(("--[[", "]]")),
+        "haskell" | "elm" => Some(("{-", "-}")),
+        "ocaml" | "fsharp" | "pascal" => Some(("(*", "*)")),
+        _ => None,
+    }
+}
+
+/// A single edit made by a comment toggle, in original buffer coordinates.
+#[derive(Debug, Clone, PartialEq)]
+struct CommentEdit {
+    position: usize,
+    delete_len: usize,
+    text: String,
+    /// Whether a cursor sitting exactly at `position` stays before the inserted text.
+    keep_left: bool,
+}
+
+impl CommentEdit {
+    fn insert(position: usize, text: String, keep_left: bool) -> Self {
+        Self {
+            position,
+            delete_len: 0,
+            text,
+            keep_left,
+        }
+    }
+
+    fn delete(range: Range<usize>) -> Self {
+        Self {
+            position: range.start,
+            delete_len: range.len(),
+            text: String::new(),
+            keep_left: false,
+        }
+    }
+}
+
+/// Where `pos` ends up after applying `edits` (sorted by position).
+fn map_position(pos: usize, edits: &[CommentEdit]) -> usize {
+    let mut shift: isize = 0;
+    for edit in edits {
+        if pos < edit.position || (pos == edit.position && edit.keep_left) {
+            break;
+        }
+        if pos < edit.position + edit.delete_len {
+            // Inside deleted text: collapse to where the deletion happened
+            return (edit.position as isize + shift) as usize;
+        }
+        shift += edit.text.len() as isize - edit.delete_len as isize;
+    }
+    (pos as isize + shift) as usize
+}
+
+/// Start offset and text (without line ending) of each line touched by `range`.
+/// A range ending at the start of a line doesn't include that line.
+fn lines_in_range(
+    buffer: &mut Buffer,
+    range: Range<usize>,
+    estimated_line_length: usize,
+) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut iter = buffer.line_iterator(range.start, estimated_line_length);
+    while let Some((line_start, content)) = iter.next_line() {
+        if !lines.is_empty() && line_start >= range.end {
+            break;
+        }
+        let has_newline = content.ends_with('\n');
+        let line_end = line_start + content.len();
+        lines.push((
+            line_start,
+            content.trim_end_matches(['\n', '\r']).to_string(),
+        ));
+        if !has_newline || line_end > range.end {
+            break;
+        }
+    }
+    lines
+}
+
+fn leading_whitespace_len(text: &str) -> usize {
+    text.len() - text.trim_start_matches([' ', '\t']).len()
+}
+
+/// Edits that toggle `prefix` on `lines`.
+///
+/// Blank lines are left alone unless every line is blank. If all other lines
+/// already start with the prefix they are uncommented; otherwise the prefix is
+/// inserted at the smallest indentation so the markers line up.
+/// Returns the edits and whether the lines were commented.
+fn line_comment_edits(lines: &[(usize, String)], prefix: &str) -> (Vec<CommentEdit>, bool) {
+    let marker = prefix.trim_end();
+    let non_blank: Vec<_> = lines
+        .iter()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .collect();
+    let targets = if non_blank.is_empty() {
+        lines.iter().collect()
+    } else {
+        non_blank
+    };
+
+    let uncomment = targets
+        .iter()
+        .all(|(_, text)| text.trim_start().starts_with(marker));
+
+    let edits = if uncomment {
+        targets
+            .iter()
+            .map(|(line_start, text)| {
+                let start = line_start + leading_whitespace_len(text);
+                let rest = text.trim_start_matches([' ', '\t']);
+                let len = if rest.starts_with(prefix) {
+                    prefix.len()
+                } else {
+                    marker.len()
+                };
+                CommentEdit::delete(start..start + len)
+            })
+            .collect()
+    } else {
+        let indent = targets
+            .iter()
+            .map(|(_, text)| leading_whitespace_len(text))
+            .min()
+            .unwrap_or(0);
+        targets
+            .iter()
+            .map(|(line_start, _)| {
+                // A cursor at column 0 stays there so whole-line selections keep the prefix
+                CommentEdit::insert(line_start + indent, prefix.to_string(), indent == 0)
+            })
+            .collect()
+    };
+    (edits, !uncomment)
+}
+
+/// Edits that toggle a block comment on `range`, whose text is `text`.
+///
+/// `before` and `after` are the text just outside the range, used to uncomment
+/// when the range is the inside of an existing comment.
+fn block_comment_edits(
+    range: Range<usize>,
+    text: &str,
+    before: &str,
+    after: &str,
+    open: &str,
+    close: &str,
+) -> Vec<CommentEdit> {
+    // The range itself is a comment: remove its delimiters and the padding inside them
+    if text.len() >= open.len() + close.len() && text.starts_with(open) && text.ends_with(close) {
+        let inner = &text[open.len()..text.len() - close.len()];
+        let open_len = open.len() + usize::from(inner.starts_with(' '));
+        let close_len = close.len() + usize::from(inner.len() >= 2 && inner.ends_with(' '));
+        return vec![
+            CommentEdit::delete(range.start..range.start + open_len),
+            CommentEdit::delete(range.end - close_len..range.end),
+        ];
+    }
+
+    // The range is the inside of a comment: remove the delimiters around it
+    let padded_open = format!("{} ", open);
+    let padded_close = format!(" {}", close);
+    let open_len = if before.ends_with(&padded_open) {
+        Some(padded_open.len())
+    } else if before.ends_with(open) {
+        Some(open.len())
+    } else {
+        None
+    };
+    let close_len = if after.starts_with(&padded_close) {
+        Some(padded_close.len())
+    } else if after.starts_with(close) {
+        Some(close.len())
+    } else {
+        None
+    };
+    if let (Some(open_len), Some(close_len)) = (open_len, close_len) {
+        return vec![
+            CommentEdit::delete(range.start - open_len..range.start),
+            CommentEdit::delete(range.end..range.end + close_len),
+        ];
+    }
+
+    vec![
+        CommentEdit::insert(range.start, padded_open, false),
+        CommentEdit::insert(range.end, padded_close, true),
+    ]
+}
+
+impl Editor {
+    /// The line comment prefix and block comment delimiters for the active buffer.
+    pub(super) fn comment_syntax(&self) -> (Option<String>, Option<(String, String)>) {
+        let language = &self.active_state().language;
+        let lang_config = self.config.languages.get(language);
+
+        let line = lang_config
+            .and_then(|config| config.comment_prefix.clone())
+            .or_else(|| default_line_comment(language).map(String::from));
+        let block = lang_config
+            .and_then(|config| {
+                config
+                    .block_comment_start
+                    .clone()
+                    .zip(config.block_comment_end.clone())
+            })
+            .or_else(|| {
+                default_block_comment(language)
+                    .map(|(open, close)| (open.to_string(), close.to_string()))
+            });
+        (line, block)
+    }
+
+    /// Apply comment edits as a single undo step, keeping every cursor and
+    /// selection on the same text.
+    fn apply_comment_edits(&mut self, mut edits: Vec<CommentEdit>, description: String) -> bool {
+        if edits.is_empty() {
+            return false;
+        }
+        edits.sort_by_key(|edit| (edit.position, edit.keep_left));
+
+        let mut events: Vec<Event> = edits
+            .iter()
+            .map(|edit| {
+                if edit.delete_len > 0 {
+                    let range = edit.position..edit.position + edit.delete_len;
+                    Event::Delete {
+                        deleted_text: self
+                            .active_state_mut()
+                            .get_text_range(range.start, range.end),
+                        range,
+                        cursor_id: CursorId::UNDO_SENTINEL,
+                    }
+                } else {
+                    Event::Insert {
+                        position: edit.position,
+                        text: edit.text.clone(),
+                        cursor_id: CursorId::UNDO_SENTINEL,
+                    }
+                }
+            })
+            .collect();
+
+        for (cursor_id, cursor) in self.active_cursors().iter() {
+            events.push(Event::MoveCursor {
+                cursor_id,
+                old_position: cursor.position,
+                new_position: map_position(cursor.position, &edits),
+                old_anchor: cursor.anchor,
+                new_anchor: cursor.anchor.map(|anchor| map_position(anchor, &edits)),
+                old_sticky_column: cursor.sticky_column,
+                new_sticky_column: 0,
+            });
+        }
+
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description) {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+        true
+    }
+
+    /// Toggle line comments on the current line or selection of every cursor
+    pub(super) fn toggle_comment(&mut self) {
+        let Some(prefix) = self.comment_syntax().0 else {
+            self.set_status_message(t!("lines.no_line_comment").to_string());
+            return;
+        };
+        // Ensure there's a trailing space for consistent formatting
+        let prefix = if prefix.ends_with(' ') {
+            prefix
+        } else {
+            format!("{} ", prefix)
+        };
+
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let ranges: Vec<Range<usize>> = self
+            .active_cursors()
+            .iter()
+            .map(|(_, cursor)| {
+                cursor
+                    .selection_range()
+                    .unwrap_or(cursor.position..cursor.position)
+            })
+            .collect();
+
+        let state = self.active_state_mut();
+        let mut lines: Vec<(usize, String)> = ranges
+            .into_iter()
+            .flat_map(|range| lines_in_range(&mut state.buffer, range, estimated_line_length))
+            .collect();
+        lines.sort_by_key(|(line_start, _)| *line_start);
+        lines.dedup_by_key(|(line_start, _)| *line_start);
+
+        let (edits, commented) = line_comment_edits(&lines, &prefix);
+        let line_count = edits.len();
+        let action_desc = if commented { "Comment" } else { "Uncomment" };
+        if self.apply_comment_edits(edits, format!("{} lines", action_desc)) {
+            self.set_status_message(
+                t!("lines.action", action = action_desc, count = line_count).to_string(),
+            );
+        }
+    }
+
+    /// Toggle a block comment around the selection of every cursor.
+    /// A cursor without a selection comments the text of its line.
+    pub(super) fn toggle_block_comment(&mut self) {
+        let Some((open, close)) = self.comment_syntax().1 else {
+            self.set_status_message(t!("lines.no_block_comment").to_string());
+            return;
+        };
+
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let cursor_ranges: Vec<(Option<Range<usize>>, usize)> = self
+            .active_cursors()
+            .iter()
+            .map(|(_, cursor)| (cursor.selection_range(), cursor.position))
+            .collect();
+
+        let state = self.active_state_mut();
+        let mut ranges: Vec<Range<usize>> = cursor_ranges
+            .into_iter()
+            .filter_map(|(selection, position)| match selection {
+                Some(range) => Some(range),
+                None => {
+                    // Comment the line's text, without its indentation or trailing spaces
+                    let (line_start, text) = lines_in_range(
+                        &mut state.buffer,
+                        position..position,
+                        estimated_line_length,
+                    )
+                    .into_iter()
+                    .next()?;
+                    let start = line_start + leading_whitespace_len(&text);
+                    let end = line_start + text.trim_end().len();
+                    (start < end).then_some(start..end)
+                }
+            })
+            .collect();
+        ranges.sort_by_key(|range| range.start);
+        ranges.dedup();
+
+        let buffer_len = state.buffer.len();
+        let edits: Vec<CommentEdit> = ranges
+            .into_iter()
+            .flat_map(|range| {
+                let text = state.get_text_range(range.start, range.end);
+                let before =
+                    state.get_text_range(range.start.saturating_sub(open.len() + 1), range.start);
+                let after =
+                    state.get_text_range(range.end, buffer_len.min(range.end + close.len() + 1));
+                block_comment_edits(range, &text, &before, &after, &open, &close)
+            })
+            .collect();
+
+        self.apply_comment_edits(edits, "Toggle block comment".to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<(usize, String)> {
+        let mut offset = 0;
+        text.split('\n')
+            .map(|line| {
+                let start = offset;
+                offset += line.len() + 1;
+                (start, line.to_string())
+            })
+            .collect()
+    }
+
+    fn apply(text: &str, edits: &[CommentEdit]) -> String {
+        let mut result = text.to_string();
+        for edit in edits.iter().rev() {
+            result.replace_range(edit.position..edit.position + edit.delete_len, &edit.text);
+        }
+        result
+    }
+
+    #[test]
+    fn test_line_comment_aligns_at_min_indent() {
+        let text = "    if x {\n        y();\n\n    }";
+        let (edits, commented) = line_comment_edits(&lines(text), "// ");
+        assert!(commented);
+        assert_eq!(
+            apply(text, &edits),
+            "    // if x {\n    //     y();\n\n    // }"
+        );
+    }
+
+    #[test]
+    fn test_line_comment_uncomments_when_all_commented() {
+        let text = "  // a\n  //b\n\n  // c";
+        let (edits, commented) = line_comment_edits(&lines(text), "// ");
+        assert!(!commented);
+        assert_eq!(apply(text, &edits), "  a\n  b\n\n  c");
+    }
+
+    #[test]
+    fn test_line_comment_mixed_lines_are_commented() {
+        let text = "# a\nb";
+        let (edits, commented) = line_comment_edits(&lines(text), "# ");
+        assert!(commented);
+        assert_eq!(apply(text, &edits), "# # a\n# b");
+    }
+
+    #[test]
+    fn test_line_comment_blank_line() {
+        let (edits, commented) = line_comment_edits(&lines(""), "-- ");
+        assert!(commented);
+        assert_eq!(apply("", &edits), "-- ");
+    }
+
+    #[test]
+    fn test_block_comment_wraps_and_unwraps() {
+        let text = "let x = value;";
+        let edits = block_comment_edits(8..13, "value", "x = ", ";", "/*", "*/");
+        let commented = apply(text, &edits);
+        assert_eq!(commented, "let x = /* value */;");
+
+        // Selecting the whole comment removes it
+        let edits = block_comment_edits(8..19, "/* value */", "x = ", ";", "/*", "*/");
+        assert_eq!(apply(&commented, &edits), text);
+
+        // So does selecting only the commented text
+        let edits = block_comment_edits(11..16, "value", "x = /* ", " */;", "/*", "*/");
+        assert_eq!(apply(&commented, &edits), text);
+    }
+
+    #[test]
+    fn test_map_position_keeps_selection_on_text() {
+        let edits = block_comment_edits(8..13, "value", "x = ", ";", "/*", "*/");
+        assert_eq!(map_position(8, &edits), 11);
+        assert_eq!(map_position(13, &edits), 16);
+        assert_eq!(map_position(14, &edits), 20);
+
+        // Positions inside a removed delimiter collapse to its start
+        let edits = vec![CommentEdit::delete(4..7)];
+        assert_eq!(map_position(5, &edits), 4);
+        assert_eq!(map_position(9, &edits), 6);
+    }
+}
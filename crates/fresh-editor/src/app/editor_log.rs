@@ -0,0 +1,214 @@
+//! Editor log buffer.
+//!
+//! "Show Logs" opens the tail of this session's log file in the read-only
+//! `*Editor Log*` buffer. While the buffer is open, lines appended to the log
+//! are added to it, and the view keeps following the end if the cursor was
+//! there. When the log is rotated or truncated, the buffer is reloaded from
+//! the new file.
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::input::keybindings::Action;
+
+/// The name of the editor log buffer
+pub const EDITOR_LOG_BUFFER_NAME: &str = "*Editor Log*";
+
+/// How much of the end of the log is shown when the buffer is opened
+const MAX_TAIL_BYTES: u64 = 256 * 1024;
+
+/// How often the log file is checked for new lines
+const EDITOR_LOG_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+impl Editor {
+    /// Set the log file shown by "Show Logs"
+    pub fn set_editor_log_path(&mut self, path: PathBuf) {
+        self.editor_log_path = Some(path);
+    }
+
+    /// The log file shown by "Show Logs", if logging is set up
+    pub fn editor_log_path(&self) -> Option<&Path> {
+        self.editor_log_path.as_deref()
+    }
+
+    /// Open the editor log buffer, showing the tail of the log file
+    pub fn open_editor_log(&mut self) {
+        if let Some(buffer_id) = self.find_buffer_by_name(EDITOR_LOG_BUFFER_NAME) {
+            self.set_active_buffer(buffer_id);
+            return;
+        }
+        let Some(path) = self.editor_log_path.clone() else {
+            self.set_status_message(t!("status.editor_log_unavailable").to_string());
+            return;
+        };
+        let (content, offset) = match read_tail(&path) {
+            Ok(tail) => tail,
+            Err(e) => {
+                self.set_status_message(
+                    t!(
+                        "status.editor_log_read_failed",
+                        path = path.display().to_string(),
+                        error = e.to_string()
+                    )
+                    .to_string(),
+                );
+                return;
+            }
+        };
+        self.editor_log_offset = offset;
+
+        let buffer_id = self.create_virtual_buffer(
+            EDITOR_LOG_BUFFER_NAME.to_string(),
+            "special".to_string(),
+            true,
+        );
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.buffer.insert(0, &content);
+            state.buffer.clear_modified();
+            state.editing_disabled = true;
+            state.margins.configure_for_line_numbers(false);
+        }
+        self.set_active_buffer(buffer_id);
+        self.move_editor_log_to_end();
+        self.set_status_message(
+            t!(
+                "status.editor_log_opened",
+                path = path.display().to_string()
+            )
+            .to_string(),
+        );
+    }
+
+    /// Add lines written to the log since the last check to the open log
+    /// buffer. Returns true if the buffer changed.
+    pub fn check_editor_log(&mut self) -> bool {
+        let now = self.time_source.now();
+        if self
+            .editor_log_checked_at
+            .is_some_and(|at| now.duration_since(at) < EDITOR_LOG_POLL_INTERVAL)
+        {
+            return false;
+        }
+        self.editor_log_checked_at = Some(now);
+
+        let Some(buffer_id) = self.find_buffer_by_name(EDITOR_LOG_BUFFER_NAME) else {
+            return false;
+        };
+        let Some(path) = self.editor_log_path.clone() else {
+            return false;
+        };
+        let Ok(len) = std::fs::metadata(&path).map(|m| m.len()) else {
+            return false;
+        };
+        if len == self.editor_log_offset {
+            return false;
+        }
+
+        // A shorter file means the log was rotated or truncated; start over
+        let rotated = len < self.editor_log_offset;
+        let read = if rotated {
+            read_tail(&path)
+        } else {
+            read_from(&path, self.editor_log_offset)
+        };
+        let Ok((text, offset)) = read else {
+            return false;
+        };
+        self.editor_log_offset = offset;
+        if text.is_empty() && !rotated {
+            return false;
+        }
+
+        let is_active = self.active_buffer() == buffer_id;
+        let Some(old_end) = self.buffers.get(&buffer_id).map(|s| s.buffer.len()) else {
+            return false;
+        };
+        let follow = is_active && (rotated || self.active_cursors().primary().position >= old_end);
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return false;
+        };
+        if rotated {
+            state.buffer.delete(0..old_end);
+        }
+        let end = state.buffer.len();
+        state.buffer.insert(end, &text);
+        state.buffer.clear_modified();
+        if follow {
+            self.move_editor_log_to_end();
+        }
+        true
+    }
+
+    fn move_editor_log_to_end(&mut self) {
+        if let Some(events) = self.action_to_events(Action::MoveDocumentEnd) {
+            for event in &events {
+                self.apply_event_to_active_buffer(event);
+            }
+        }
+    }
+}
+
+/// The last `MAX_TAIL_BYTES` of the log, starting at a line boundary, and
+/// the offset just past what was read
+fn read_tail(path: &Path) -> std::io::Result<(String, u64)> {
+    let len = std::fs::metadata(path)?.len();
+    let start = len.saturating_sub(MAX_TAIL_BYTES);
+    let (text, offset) = read_from(path, start)?;
+    if start == 0 {
+        return Ok((text, offset));
+    }
+    let text = match text.find('\n') {
+        Some(newline) => text[newline + 1..].to_string(),
+        None => String::new(),
+    };
+    Ok((text, offset))
+}
+
+/// Complete lines from `start` to the end of the log, and the offset just
+/// past them; a partly written last line is left for the next read
+fn read_from(path: &Path, start: u64) -> std::io::Result<(String, u64)> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(start))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    let complete = bytes.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    bytes.truncate(complete);
+    Ok((
+        String::from_utf8_lossy(&bytes).into_owned(),
+        start + complete as u64,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_from_leaves_partial_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fresh-1.log");
+        std::fs::write(&path, "one\ntwo\nthr").unwrap();
+
+        assert_eq!(read_from(&path, 0).unwrap(), ("one\ntwo\n".to_string(), 8));
+        assert_eq!(read_from(&path, 4).unwrap(), ("two\n".to_string(), 8));
+    }
+
+    #[test]
+    fn test_read_tail_starts_at_line_boundary() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fresh-1.log");
+        let line = "x".repeat(99) + "\n";
+        std::fs::write(&path, line.repeat(3000)).unwrap();
+
+        let (text, offset) = read_tail(&path).unwrap();
+        assert_eq!(offset, 300_000);
+        assert!(text.starts_with('x') && text.ends_with('\n'));
+        assert_eq!(text.len() % 100, 0);
+        assert!(text.len() as u64 <= MAX_TAIL_BYTES);
+    }
+}
@@ -0,0 +1,182 @@
+//! Exporting the selection or buffer as styled HTML or ANSI text.
+//!
+//! The text is highlighted with the current theme and rendered by
+//! [`crate::services::styled_html`], numbered like the gutter when line
+//! numbers are shown. The user then picks whether the result is copied to the
+//! clipboard or written to a file chosen with the Save As prompt.
+
+use std::path::Path;
+
+use rust_i18n::t;
+
+use crate::input::commands::Suggestion;
+use crate::primitives::highlighter::HighlightSpan;
+use crate::services::styled_html::{render_ansi, render_html, ExportOptions};
+use crate::view::prompt::{Prompt, PromptType};
+
+use super::Editor;
+
+/// Format of an exported snippet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Html,
+    Ansi,
+}
+
+impl ExportFormat {
+    fn name(self) -> &'static str {
+        match self {
+            ExportFormat::Html => "HTML",
+            ExportFormat::Ansi => "ANSI",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Html => "html",
+            ExportFormat::Ansi => "ans",
+        }
+    }
+}
+
+/// A rendered snippet waiting for the user to pick where it goes
+pub(super) struct PendingExport {
+    format: ExportFormat,
+    content: String,
+}
+
+impl Editor {
+    /// Render the selection, or the whole buffer without one, and ask
+    /// whether to copy it or write it to a file
+    pub fn export_styled(&mut self, format: ExportFormat) {
+        let buffer_id = self.active_buffer();
+        let range = self
+            .active_cursors()
+            .primary()
+            .selection_range()
+            .unwrap_or_else(|| 0..self.active_state().buffer.len());
+        let line_numbers = self.is_line_numbers_visible();
+
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let text = state.get_text_range(range.start, range.end);
+        if text.is_empty() {
+            self.set_status_message(t!("clipboard.no_text").to_string());
+            return;
+        }
+        let first_line = state.buffer.get_line_number(range.start) + 1;
+        let spans: Vec<HighlightSpan> = state
+            .highlighter
+            .highlight_viewport(
+                &state.buffer,
+                range.start,
+                range.end,
+                &self.theme,
+                self.config.editor.highlight_context_bytes,
+            )
+            .into_iter()
+            .filter_map(|span| {
+                let start = span.range.start.max(range.start) - range.start;
+                let end = span.range.end.min(range.end).saturating_sub(range.start);
+                (start < end).then_some(HighlightSpan {
+                    range: start..end,
+                    ..span
+                })
+            })
+            .collect();
+
+        let options = ExportOptions {
+            first_line_number: line_numbers.then_some(first_line),
+        };
+        let content = match format {
+            ExportFormat::Html => render_html(&text, &spans, &self.theme, options),
+            ExportFormat::Ansi => render_ansi(&text, &spans, &self.theme, options),
+        };
+        self.pending_export = Some(PendingExport { format, content });
+
+        let suggestion = |text: String, value: &str| Suggestion {
+            text,
+            description: None,
+            value: Some(value.to_string()),
+            disabled: false,
+            keybinding: None,
+            source: None,
+        };
+        self.prompt = Some(Prompt::with_suggestions(
+            t!("export.destination_prompt", format = format.name()).to_string(),
+            PromptType::ExportDestination,
+            vec![
+                suggestion(t!("export.to_clipboard").to_string(), "clipboard"),
+                suggestion(t!("export.to_file").to_string(), "file"),
+            ],
+        ));
+    }
+
+    /// Send the pending export where the destination prompt said
+    pub(super) fn finish_export(&mut self, destination: &str) {
+        let Some(export) = self.pending_export.take() else {
+            return;
+        };
+        if destination != "file" {
+            let format = export.format.name();
+            self.clipboard.copy(export.content);
+            self.set_status_message(t!("export.copied", format = format).to_string());
+            return;
+        }
+
+        // Suggest the buffer's file name with the format's extension
+        let initial = self
+            .active_state()
+            .buffer
+            .file_path()
+            .map(|path| {
+                let path = path.strip_prefix(&self.working_dir).unwrap_or(path);
+                let mut name = path.as_os_str().to_os_string();
+                name.push(".");
+                name.push(export.format.extension());
+                name.to_string_lossy().to_string()
+            })
+            .unwrap_or_else(|| format!("snippet.{}", export.format.extension()));
+        self.pending_export = Some(export);
+        self.start_prompt_with_initial_text(
+            t!("export.file_prompt").to_string(),
+            PromptType::SaveFileAs,
+            initial,
+        );
+        self.init_file_open_state();
+    }
+
+    /// Whether the Save As prompt is choosing a file for an export
+    pub(super) fn is_exporting(&self) -> bool {
+        self.pending_export.is_some()
+    }
+
+    /// Write the pending export to `path`, chosen with the Save As prompt
+    ///
+    /// Returns false when no export is pending, so the buffer is saved instead.
+    pub(super) fn write_pending_export(&mut self, path: &Path) -> bool {
+        let Some(export) = self.pending_export.take() else {
+            return false;
+        };
+        match self.filesystem.write_file(path, export.content.as_bytes()) {
+            Ok(()) => self.set_status_message(
+                t!(
+                    "export.saved",
+                    format = export.format.name(),
+                    path = path.display().to_string()
+                )
+                .to_string(),
+            ),
+            Err(e) => self.set_status_message(
+                t!(
+                    "export.failed",
+                    path = path.display().to_string(),
+                    error = e.to_string()
+                )
+                .to_string(),
+            ),
+        }
+        true
+    }
+}
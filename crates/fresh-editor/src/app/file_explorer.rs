@@ -75,6 +75,13 @@ impl Editor {
     }
 
     pub fn sync_file_explorer_to_active_file(&mut self) {
+        self.expand_file_explorer_to_active_file(false);
+    }
+
+    /// Expand the file explorer to the active buffer's file and select it.
+    /// Files outside the working directory are only shown (under "External")
+    /// when `include_external` is set.
+    fn expand_file_explorer_to_active_file(&mut self, include_external: bool) {
         if !self.file_explorer_visible {
             return;
         }
@@ -89,7 +96,7 @@ impl Editor {
                 let target_path = file_path.clone();
                 let working_dir = self.working_dir.clone();
 
-                if target_path.starts_with(&working_dir) {
+                if include_external || target_path.starts_with(&working_dir) {
                     if let Some(mut view) = self.file_explorer.take() {
                         tracing::trace!(
                             "sync_file_explorer_to_active_file: taking file_explorer for async expand to {:?}",
@@ -117,6 +124,52 @@ impl Editor {
         }
     }
 
+    /// Show the active buffer's file in the file explorer: expand the
+    /// directories leading to it, select it and scroll it into view
+    pub fn reveal_in_file_explorer(&mut self) {
+        if self.file_explorer_visible {
+            self.focus_file_explorer();
+        } else {
+            self.toggle_file_explorer();
+        }
+
+        if self.file_explorer.is_none() && !self.file_explorer_sync_in_progress {
+            // Still initializing; reveal once the tree is loaded
+            self.pending_file_explorer_reveal = true;
+        } else {
+            self.expand_file_explorer_to_active_file(true);
+        }
+    }
+
+    /// Reveal the active file when the active buffer changes, if follow mode is on
+    pub(super) fn follow_active_file_in_explorer(&mut self) {
+        if self.config.file_explorer.follow_active_file {
+            self.expand_file_explorer_to_active_file(true);
+        }
+    }
+
+    pub fn toggle_file_explorer_follow(&mut self) {
+        let follow = !self.config.file_explorer.follow_active_file;
+        self.config.file_explorer.follow_active_file = follow;
+
+        let msg = if follow {
+            t!("explorer.following_active_file")
+        } else {
+            t!("explorer.not_following_active_file")
+        };
+        self.set_status_message(msg.to_string());
+
+        if follow {
+            self.expand_file_explorer_to_active_file(true);
+        }
+
+        // Persist to config so the setting survives across sessions
+        self.persist_config_change(
+            "/file_explorer/follow_active_file",
+            serde_json::Value::Bool(follow),
+        );
+    }
+
     pub fn focus_file_explorer(&mut self) {
         if self.file_explorer_visible {
             // Dismiss transient popups and clear hover state when focusing file explorer
@@ -248,10 +301,14 @@ impl Editor {
             return;
         };
 
-        let (is_dir, is_expanded, name) = if let Some(explorer) = &self.file_explorer {
+        let (is_dir, is_open, name) = if let Some(explorer) = &self.file_explorer {
             let node = explorer.tree().get_node(selected_id);
             if let Some(node) = node {
-                (node.is_dir(), node.is_expanded(), node.entry.name.clone())
+                (
+                    node.is_dir(),
+                    node.is_expanded() || node.is_loading(),
+                    node.entry.name.clone(),
+                )
             } else {
                 return;
             }
@@ -263,80 +320,78 @@ impl Editor {
             return;
         }
 
-        let status_msg = if is_expanded {
-            t!("explorer.collapsing").to_string()
-        } else {
-            t!("explorer.loading_dir", name = &name).to_string()
-        };
-        self.set_status_message(status_msg);
+        // Collapsing a loading directory drops its pending listing
+        if is_open {
+            if let Some(explorer) = &mut self.file_explorer {
+                explorer.tree_mut().collapse_node(selected_id);
+            }
+            self.set_status_message(t!("explorer.collapsed", name = &name).to_string());
+            return;
+        }
 
-        if let (Some(runtime), Some(explorer)) = (&self.tokio_runtime, &mut self.file_explorer) {
-            let tree = explorer.tree_mut();
-            let result = runtime.block_on(tree.toggle_node(selected_id));
+        // Read the directory on a background task; the row shows a spinner
+        // until FileExplorerDirLoaded arrives
+        let path = self
+            .file_explorer
+            .as_mut()
+            .and_then(|explorer| explorer.tree_mut().start_loading(selected_id));
+        match path {
+            Some(path) => {
+                self.set_status_message(t!("explorer.loading_dir", name = &name).to_string());
+                self.spawn_file_explorer_dir_listing(selected_id, path);
+            }
+            // Nothing to read (e.g. the external section)
+            None => self.file_explorer_dir_expanded(selected_id),
+        }
+    }
 
-            let final_name = explorer
-                .tree()
-                .get_node(selected_id)
-                .map(|n| n.entry.name.clone());
-            let final_expanded = explorer
-                .tree()
-                .get_node(selected_id)
-                .map(|n| n.is_expanded())
-                .unwrap_or(false);
+    /// List a directory on a background task and post the result back as
+    /// `AsyncMessage::FileExplorerDirLoaded`
+    pub(super) fn spawn_file_explorer_dir_listing(
+        &self,
+        node_id: crate::view::file_tree::NodeId,
+        path: PathBuf,
+    ) {
+        if let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) {
+            let fs_manager = Arc::clone(&self.fs_manager);
+            let sender = bridge.sender();
 
-            // Track if we need to rebuild decoration cache (for symlink directories)
-            let mut needs_decoration_rebuild = false;
+            runtime.spawn(async move {
+                let result = fs_manager.list_dir_with_metadata(path).await;
+                // Receiver may have been dropped during shutdown.
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = sender.send(AsyncMessage::FileExplorerDirLoaded { node_id, result });
+            });
+        }
+    }
 
-            match result {
-                Ok(()) => {
-                    if final_expanded {
-                        let node_info = explorer
-                            .tree()
-                            .get_node(selected_id)
-                            .map(|n| (n.entry.path.clone(), n.entry.is_symlink()));
-
-                        if let Some((dir_path, is_symlink)) = node_info {
-                            if let Err(e) = explorer.load_gitignore_for_dir(&dir_path) {
-                                tracing::warn!(
-                                    "Failed to load .gitignore from {:?}: {}",
-                                    dir_path,
-                                    e
-                                );
-                            }
+    /// Finish expanding a directory once its children are in the tree
+    pub(super) fn file_explorer_dir_expanded(&mut self, node_id: crate::view::file_tree::NodeId) {
+        let Some(explorer) = &mut self.file_explorer else {
+            return;
+        };
+        let Some(node) = explorer.tree().get_node(node_id) else {
+            return;
+        };
+        let name = node.entry.name.clone();
+        let dir_path = node.entry.path.clone();
+        let is_symlink = node.entry.is_symlink();
 
-                            // If a symlink directory was just expanded, we need to rebuild
-                            // the decoration cache so decorations under the canonical target
-                            // also appear under the symlink path
-                            if is_symlink {
-                                tracing::debug!(
-                                    "Symlink directory expanded, will rebuild decoration cache: {:?}",
-                                    dir_path
-                                );
-                                needs_decoration_rebuild = true;
-                            }
-                        }
-                    }
+        if let Err(e) = explorer.load_gitignore_for_dir(&dir_path) {
+            tracing::warn!("Failed to load .gitignore from {:?}: {}", dir_path, e);
+        }
 
-                    if let Some(name) = final_name {
-                        let msg = if final_expanded {
-                            t!("explorer.expanded", name = &name).to_string()
-                        } else {
-                            t!("explorer.collapsed", name = &name).to_string()
-                        };
-                        self.set_status_message(msg);
-                    }
-                }
-                Err(e) => {
-                    self.set_status_message(
-                        t!("explorer.error", error = e.to_string()).to_string(),
-                    );
-                }
-            }
+        self.set_status_message(t!("explorer.expanded", name = &name).to_string());
 
-            // Rebuild decoration cache outside the explorer borrow
-            if needs_decoration_rebuild {
-                self.rebuild_file_explorer_decoration_cache();
-            }
+        // If a symlink directory was just expanded, we need to rebuild
+        // the decoration cache so decorations under the canonical target
+        // also appear under the symlink path
+        if is_symlink {
+            tracing::debug!(
+                "Symlink directory expanded, will rebuild decoration cache: {:?}",
+                dir_path
+            );
+            self.rebuild_file_explorer_decoration_cache();
         }
     }
 
@@ -436,6 +491,9 @@ impl Editor {
 
                         match result {
                             Ok(_) => {
+                                if let Some(index) = self.file_provider.index() {
+                                    index.insert(&path_clone);
+                                }
                                 let parent_id =
                                     get_parent_node_id(explorer.tree(), selected_id, node.is_dir());
                                 let tree = explorer.tree_mut();
@@ -571,6 +629,10 @@ impl Editor {
 
         match delete_result {
             Ok(_) => {
+                if let Some(index) = self.file_provider.index() {
+                    index.remove(&path);
+                }
+
                 // Refresh the parent directory in the file explorer
                 if let Some(explorer) = &mut self.file_explorer {
                     if let Some(runtime) = &self.tokio_runtime {
@@ -699,6 +761,16 @@ impl Editor {
 
             match result {
                 Ok(_) => {
+                    if let Some(index) = self.file_provider.index() {
+                        index.remove(&original_path);
+                        if self.filesystem.is_dir(&new_path).unwrap_or(false) {
+                            // The directory's files are only known to a walk
+                            index.refresh();
+                        } else {
+                            index.insert(&new_path);
+                        }
+                    }
+
                     // Refresh the parent directory and select the renamed item
                     if let Some(explorer) = &mut self.file_explorer {
                         if let Some(selected_id) = explorer.get_selected() {
@@ -811,25 +883,60 @@ impl Editor {
         );
     }
 
-    /// Clear the file explorer search
+    /// Open the file explorer filter, which narrows the tree to matching paths
+    ///
+    /// Collapsed directories are read on background tasks, and the filter
+    /// narrows the tree as their listings arrive.
+    pub fn file_explorer_filter(&mut self) {
+        let Some(explorer) = &mut self.file_explorer else {
+            return;
+        };
+        // Once the filter is open, "/" is part of the path being typed
+        if explorer.is_filter_open() {
+            explorer.filter_push_char('/');
+            explorer.update_scroll_for_selection();
+            return;
+        }
+        let reads = explorer.open_filter();
+        explorer.update_scroll_for_selection();
+        for (node_id, path) in reads {
+            self.spawn_file_explorer_dir_listing(node_id, path);
+        }
+        self.set_status_message(t!("explorer.filter_opened").to_string());
+    }
+
+    /// Clear the file explorer search, or close the filter if it is open
     pub fn file_explorer_search_clear(&mut self) {
         if let Some(explorer) = &mut self.file_explorer {
-            explorer.search_clear();
+            if explorer.is_filter_open() {
+                explorer.close_filter();
+                explorer.update_scroll_for_selection();
+            } else {
+                explorer.search_clear();
+            }
         }
     }
 
-    /// Add a character to the file explorer search
+    /// Add a character to the file explorer search (or filter)
     pub fn file_explorer_search_push_char(&mut self, c: char) {
         if let Some(explorer) = &mut self.file_explorer {
-            explorer.search_push_char(c);
+            if explorer.is_filter_open() {
+                explorer.filter_push_char(c);
+            } else {
+                explorer.search_push_char(c);
+            }
             explorer.update_scroll_for_selection();
         }
     }
 
-    /// Remove a character from the file explorer search (backspace)
+    /// Remove a character from the file explorer search or filter (backspace)
     pub fn file_explorer_search_pop_char(&mut self) {
         if let Some(explorer) = &mut self.file_explorer {
-            explorer.search_pop_char();
+            if explorer.is_filter_open() {
+                explorer.filter_pop_char();
+            } else {
+                explorer.search_pop_char();
+            }
             explorer.update_scroll_for_selection();
         }
     }
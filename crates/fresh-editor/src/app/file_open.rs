@@ -640,49 +640,14 @@ impl FileOpenState {
     }
 }
 
-/// Format file size in human-readable form
+/// Format file size in human-readable form for the active locale
 pub fn format_size(size: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-
-    if size >= GB {
-        format!("{:.1} GB", size as f64 / GB as f64)
-    } else if size >= MB {
-        format!("{:.1} MB", size as f64 / MB as f64)
-    } else if size >= KB {
-        format!("{:.1} KB", size as f64 / KB as f64)
-    } else {
-        format!("{} B", size)
-    }
+    crate::i18n::format::format_size(size)
 }
 
-/// Format timestamp in relative or absolute form
+/// Format timestamp in relative or absolute form for the active locale
 pub fn format_modified(time: SystemTime) -> String {
-    let now = SystemTime::now();
-    match now.duration_since(time) {
-        Ok(duration) => {
-            let secs = duration.as_secs();
-            if secs < 60 {
-                "just now".to_string()
-            } else if secs < 3600 {
-                format!("{} min ago", secs / 60)
-            } else if secs < 86400 {
-                format!("{} hr ago", secs / 3600)
-            } else if secs < 86400 * 7 {
-                format!("{} days ago", secs / 86400)
-            } else {
-                // Format as date
-                let datetime: chrono::DateTime<chrono::Local> = time.into();
-                datetime.format("%Y-%m-%d").to_string()
-            }
-        }
-        Err(_) => {
-            // Time is in the future
-            let datetime: chrono::DateTime<chrono::Local> = time.into();
-            datetime.format("%Y-%m-%d").to_string()
-        }
-    }
+    crate::i18n::format::format_relative_time(time)
 }
 
 #[cfg(test)]
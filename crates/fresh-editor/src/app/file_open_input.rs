@@ -459,7 +459,7 @@ impl Editor {
             .map(|p| p.to_path_buf());
         let is_different_file = current_file_path.as_ref() != Some(&path);
 
-        if is_different_file && path.is_file() {
+        if (is_different_file || self.is_exporting()) && path.is_file() {
             // File exists and is different from current - ask for confirmation
             let filename = path
                 .file_name()
@@ -4,10 +4,13 @@
 //! - Saving buffers
 //! - Reverting to saved version
 //! - Auto-revert and file change polling
+//! - Files deleted or changed on disk under unsaved changes
 //! - LSP file notifications (open, change)
 //! - File modification time tracking
 //! - Save conflict detection
 
+use crate::config::AutoSaveMode;
+use crate::input::keybindings::Action;
 use crate::model::buffer::SudoSaveRequired;
 use crate::view::prompt::PromptType;
 use std::path::{Path, PathBuf};
@@ -60,8 +63,13 @@ impl Editor {
         path: Option<PathBuf>,
         silent: bool,
     ) -> anyhow::Result<()> {
-        // Auto-detect language if it's currently "text" and we have a path
-        if let Some(ref p) = path {
+        // Auto-detect language if it's currently "text" and we have a path,
+        // unless plain text was chosen by hand
+        let overridden = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .is_some_and(|meta| meta.language_override.is_some());
+        if let Some(p) = path.as_ref().filter(|_| !overridden) {
             if let Some(state) = self.buffers.get_mut(&buffer_id) {
                 if state.language == "text" {
                     let detected =
@@ -84,12 +92,12 @@ impl Editor {
             event_log.mark_saved();
         }
 
-        // Update file modification time after save
+        // Update file modification time after save, and list new files in
+        // Quick Open
         if let Some(ref p) = path {
-            if let Ok(metadata) = self.filesystem.metadata(p) {
-                if let Some(mtime) = metadata.modified {
-                    self.file_mod_times.insert(p.clone(), mtime);
-                }
+            self.watch_file(p);
+            if let Some(index) = self.file_provider.index() {
+                index.insert(p);
             }
         }
 
@@ -170,43 +178,137 @@ impl Editor {
         }
 
         self.last_persistent_auto_save = self.time_source.now();
+        self.auto_save_buffers(self.buffers.keys().copied().collect())
+    }
 
-        // Collect info for modified buffers that have a file path
-        let mut to_save = Vec::new();
-        for (id, state) in &self.buffers {
-            if state.buffer.is_modified() {
-                if let Some(path) = state.buffer.file_path() {
-                    to_save.push((*id, path.to_path_buf()));
+    /// Run the `auto_save` mode: save buffers left since the last tick
+    /// ("on_focus_change") or once editing has been idle long enough
+    /// ("after_delay"). Returns the number of buffers saved.
+    pub fn process_auto_save(&mut self) -> anyhow::Result<usize> {
+        match self.config.editor.auto_save {
+            AutoSaveMode::Off => {
+                self.auto_save_pending.clear();
+                self.last_buffer_edit = None;
+                Ok(0)
+            }
+            AutoSaveMode::OnFocusChange => {
+                let mut pending = std::mem::take(&mut self.auto_save_pending);
+                pending.dedup();
+                self.auto_save_buffers(pending)
+            }
+            AutoSaveMode::AfterDelay => {
+                let Some(last_edit) = self.last_buffer_edit else {
+                    return Ok(0);
+                };
+                let delay = std::time::Duration::from_millis(self.config.editor.auto_save_delay_ms);
+                if self.time_source.elapsed_since(last_edit) < delay {
+                    return Ok(0);
                 }
+                self.last_buffer_edit = None;
+                self.auto_save_buffers(self.buffers.keys().copied().collect())
             }
         }
+    }
+
+    /// Run the saves that happen on a timer: `auto_save_enabled`, the
+    /// `auto_save` mode and the workspace. Called every tick by both the local
+    /// event loop and the session server.
+    pub fn process_periodic_saves(&mut self) {
+        if let Err(e) = self.auto_save_persistent_buffers() {
+            tracing::debug!("Auto-save (disk) error: {}", e);
+        }
+        if let Err(e) = self.process_auto_save() {
+            tracing::debug!("Auto-save error: {}", e);
+        }
+        if let Err(e) = self.auto_save_workspace() {
+            tracing::debug!("Workspace auto-save error: {}", e);
+        }
+    }
+
+    /// Save all modified buffers when the terminal loses focus, if `auto_save`
+    /// is "on_focus_change"
+    pub fn handle_focus_lost(&mut self) {
+        if self.config.editor.auto_save != AutoSaveMode::OnFocusChange {
+            return;
+        }
+        if let Err(e) = self.auto_save_buffers(self.buffers.keys().copied().collect()) {
+            tracing::debug!("Auto-save on focus loss failed: {}", e);
+        }
+    }
 
+    /// Auto-save the given buffers, skipping any that don't qualify
+    /// Returns the number of buffers saved
+    fn auto_save_buffers(&mut self, ids: Vec<BufferId>) -> anyhow::Result<usize> {
         let mut count = 0;
-        for (id, path) in to_save {
-            if let Some(state) = self.buffers.get_mut(&id) {
-                match state.buffer.save() {
-                    Ok(()) => {
-                        self.finalize_save_buffer(id, Some(path), true)?;
-                        count += 1;
-                    }
-                    Err(e) => {
-                        // Skip if sudo is required (auto-save can't handle prompts)
-                        if e.downcast_ref::<SudoSaveRequired>().is_some() {
-                            tracing::debug!(
-                                "Auto-save skipped for {:?} (sudo required)",
-                                path.display()
-                            );
-                        } else {
-                            tracing::warn!("Auto-save failed for {:?}: {}", path.display(), e);
-                        }
-                    }
-                }
+        for id in ids {
+            if self.auto_save_buffer(id)? {
+                count += 1;
             }
         }
-
         Ok(count)
     }
 
+    /// Path to write for an auto-save of `buffer_id`, or None if the buffer
+    /// is unmodified, has no file, or is read-only
+    fn auto_save_path(&self, buffer_id: BufferId) -> Option<PathBuf> {
+        let state = self.buffers.get(&buffer_id)?;
+        if !state.buffer.is_modified() || state.editing_disabled || state.is_composite_buffer {
+            return None;
+        }
+        if let Some(meta) = self.buffer_metadata.get(&buffer_id) {
+            if meta.read_only || meta.is_virtual() {
+                return None;
+            }
+        }
+        state.buffer.file_path().map(Path::to_path_buf)
+    }
+
+    /// Save one buffer without prompting
+    ///
+    /// A file that another program changed since it was loaded is not
+    /// overwritten: the active buffer gets the usual save conflict prompt,
+    /// others a status message. Returns true if the buffer was written.
+    fn auto_save_buffer(&mut self, buffer_id: BufferId) -> anyhow::Result<bool> {
+        let Some(path) = self.auto_save_path(buffer_id) else {
+            return Ok(false);
+        };
+
+        if self.file_changed_on_disk(&path).is_some() {
+            if buffer_id == self.active_buffer() && !self.is_prompting() {
+                self.start_prompt(
+                    t!("file.file_changed_prompt").to_string(),
+                    PromptType::ConfirmSaveConflict,
+                );
+            } else {
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                self.set_status_message(t!("file.auto_save_conflict", name = name).to_string());
+            }
+            return Ok(false);
+        }
+
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return Ok(false);
+        };
+        match state.buffer.save() {
+            Ok(()) => {
+                self.finalize_save_buffer(buffer_id, Some(path), true)?;
+                Ok(true)
+            }
+            Err(e) => {
+                // Skip if sudo is required (auto-save can't handle prompts)
+                if e.downcast_ref::<SudoSaveRequired>().is_some() {
+                    tracing::debug!("Auto-save skipped for {:?} (sudo required)", path.display());
+                } else {
+                    tracing::warn!("Auto-save failed for {:?}: {}", path.display(), e);
+                }
+                Ok(false)
+            }
+        }
+    }
+
     /// Revert the active buffer to the last saved version on disk
     /// Returns Ok(true) if reverted, Ok(false) if no file path, Err on failure
     pub fn revert_file(&mut self) -> anyhow::Result<bool> {
@@ -236,6 +338,7 @@ impl Editor {
         // Preserve user settings before reloading
         let old_buffer_settings = self.active_state().buffer_settings.clone();
         let old_editing_disabled = self.active_state().editing_disabled;
+        let old_log_file = self.active_state().log_file;
 
         // Load the file content fresh from disk
         let mut new_state = EditorState::from_file_with_languages(
@@ -259,6 +362,7 @@ impl Editor {
         // Restore user settings (tab size, indentation, etc.)
         new_state.buffer_settings = old_buffer_settings;
         new_state.editing_disabled = old_editing_disabled;
+        new_state.log_file = old_log_file;
         // Line number visibility is in per-split BufferViewState (survives buffer replacement)
 
         // Replace the current buffer with the new state
@@ -289,15 +393,13 @@ impl Editor {
         self.seen_byte_ranges.remove(&buffer_id);
 
         // Update the file modification time
-        if let Ok(metadata) = self.filesystem.metadata(&path) {
-            if let Some(mtime) = metadata.modified {
-                self.file_mod_times.insert(path.clone(), mtime);
-            }
-        }
+        self.watch_file(&path);
 
         // Notify LSP that the file was changed
         self.notify_lsp_file_changed(&path);
 
+        self.refresh_log_view(buffer_id);
+
         self.status_message = Some(t!("status.reverted").to_string());
         Ok(true)
     }
@@ -337,14 +439,17 @@ impl Editor {
         }
         self.last_auto_revert_poll = self.time_source.now();
 
-        // Collect paths of open files that need checking
-        let files_to_check: Vec<PathBuf> = self
+        // Collect paths of open files that need checking, once per file
+        let mut files_to_check: Vec<PathBuf> = self
             .buffers
             .values()
             .filter_map(|state| state.buffer.file_path().map(PathBuf::from))
             .collect();
+        files_to_check.sort();
+        files_to_check.dedup();
 
         let mut any_changed = false;
+        let mut changed_files = Vec::new();
 
         for path in files_to_check {
             // Get current mtime
@@ -353,19 +458,23 @@ impl Editor {
                     Some(mtime) => mtime,
                     None => continue,
                 },
-                Err(_) => continue, // File might have been deleted
+                Err(_) => {
+                    any_changed |= self.mark_deleted_on_disk(&path);
+                    continue;
+                }
             };
+            if self.files_deleted_on_disk.remove(&path) {
+                // Recreated: compared with what was loaded like any other change
+                tracing::info!("File reappeared on disk: {:?}", path);
+                any_changed = true;
+            }
 
-            // Check if mtime has changed
+            // Check if mtime has changed. Any difference counts, not just a
+            // newer time: a file renamed over this one (an atomic save by
+            // another program) can carry an older modification time.
             if let Some(&stored_mtime) = self.file_mod_times.get(&path) {
                 if current_mtime != stored_mtime {
-                    // Handle the file change (this includes debouncing)
-                    // Note: file_mod_times is updated by handle_file_changed after successful revert,
-                    // not here, to avoid the race where the revert check sees the already-updated mtime
-                    let path_str = path.display().to_string();
-                    if self.handle_async_file_changed(path_str) {
-                        any_changed = true;
-                    }
+                    changed_files.push(path);
                 }
             } else {
                 // First time seeing this file, record its mtime
@@ -373,13 +482,40 @@ impl Editor {
             }
         }
 
+        // Handle the changes as one batch, after every file was checked
+        // Note: file_mod_times is updated by handle_file_changed after successful revert,
+        // not here, to avoid the race where the revert check sees the already-updated mtime
+        for path in changed_files {
+            // Handle the file change (this includes debouncing)
+            if self.handle_async_file_changed(path.display().to_string()) {
+                any_changed = true;
+            }
+        }
+
         any_changed
     }
 
+    /// Mark an open file that is gone from disk as deleted, keeping the
+    /// buffer's content. Returns true if it was newly marked.
+    fn mark_deleted_on_disk(&mut self, path: &Path) -> bool {
+        // Only files that were on disk, and are gone rather than unreadable
+        if !self.file_mod_times.contains_key(path)
+            || self.files_deleted_on_disk.contains(path)
+            || self.filesystem.exists(path)
+        {
+            return false;
+        }
+        tracing::info!("File deleted on disk: {:?}", path);
+        self.files_deleted_on_disk.insert(path.to_path_buf());
+        self.set_status_message(t!("file.deleted_on_disk", name = file_name(path)).to_string());
+        true
+    }
+
     /// Poll for file tree changes (called from main loop)
     ///
     /// Checks modification times of expanded directories to detect new/deleted files.
-    /// Returns true if any directory was refreshed (requires re-render).
+    /// Changed directories are re-read on a background task and updated in
+    /// place when the listing arrives. Returns true if any directory changed.
     pub fn poll_file_tree_changes(&mut self) -> bool {
         // Check poll interval
         let poll_interval =
@@ -399,12 +535,14 @@ impl Editor {
         let expanded_dirs: Vec<(NodeId, PathBuf)> = explorer
             .tree()
             .all_nodes()
-            .filter(|node| node.is_dir() && node.is_expanded())
+            .filter(|node| {
+                node.is_dir() && node.is_expanded() && !explorer.tree().is_external_section(node.id)
+            })
             .map(|node| (node.id, node.entry.path.clone()))
             .collect();
 
         // Check mtimes and collect directories that need refresh
-        let mut dirs_to_refresh: Vec<NodeId> = Vec::new();
+        let mut dirs_to_refresh: Vec<(NodeId, PathBuf)> = Vec::new();
 
         for (node_id, path) in expanded_dirs {
             // Get current mtime
@@ -421,8 +559,8 @@ impl Editor {
                 if current_mtime != stored_mtime {
                     // Update stored mtime
                     self.dir_mod_times.insert(path.clone(), current_mtime);
-                    dirs_to_refresh.push(node_id);
                     tracing::debug!("Directory changed: {:?}", path);
+                    dirs_to_refresh.push((node_id, path));
                 }
             } else {
                 // First time seeing this directory, record its mtime
@@ -430,22 +568,14 @@ impl Editor {
             }
         }
 
-        // Refresh changed directories
-        if dirs_to_refresh.is_empty() {
-            return false;
-        }
-
-        // Refresh each changed directory
-        if let (Some(runtime), Some(explorer)) = (&self.tokio_runtime, &mut self.file_explorer) {
-            for node_id in dirs_to_refresh {
-                let tree = explorer.tree_mut();
-                if let Err(e) = runtime.block_on(tree.refresh_node(node_id)) {
-                    tracing::warn!("Failed to refresh directory: {}", e);
-                }
-            }
+        // Re-read changed directories in the background; the tree is updated
+        // in place when the listings arrive, keeping expansion and selection
+        let any_changed = !dirs_to_refresh.is_empty();
+        for (node_id, path) in dirs_to_refresh {
+            self.spawn_file_explorer_dir_listing(node_id, path);
         }
 
-        true
+        any_changed
     }
 
     /// Notify LSP server about a newly opened file
@@ -609,6 +739,14 @@ impl Editor {
                 self.file_mod_times.insert(path.to_path_buf(), mtime);
             }
         }
+        // The file on disk is now the one the buffer was loaded from or saved to
+        self.files_deleted_on_disk.remove(path);
+        self.external_change_conflicts.remove(path);
+    }
+
+    /// Whether an open file has been deleted on disk
+    pub fn is_deleted_on_disk(&self, path: &Path) -> bool {
+        self.files_deleted_on_disk.contains(path)
     }
 
     /// Notify LSP that a file's contents changed (e.g., after revert)
@@ -732,10 +870,10 @@ impl Editor {
                 }
             })
             .unwrap_or_default();
-        let (old_buffer_settings, old_editing_disabled) = self
+        let (old_buffer_settings, old_editing_disabled, old_log_file) = self
             .buffers
             .get(&buffer_id)
-            .map(|s| (s.buffer_settings.clone(), s.editing_disabled))
+            .map(|s| (s.buffer_settings.clone(), s.editing_disabled, s.log_file))
             .unwrap_or_default();
 
         // Load the file content fresh from disk
@@ -761,6 +899,7 @@ impl Editor {
         // Restore user settings (tab size, indentation, etc.)
         new_state.buffer_settings = old_buffer_settings;
         new_state.editing_disabled = old_editing_disabled;
+        new_state.log_file = old_log_file;
         // Line number visibility is in per-split BufferViewState (survives buffer replacement)
 
         // Replace the buffer content
@@ -784,15 +923,13 @@ impl Editor {
         self.seen_byte_ranges.remove(&buffer_id);
 
         // Update the file modification time
-        if let Ok(metadata) = self.filesystem.metadata(path) {
-            if let Some(mtime) = metadata.modified {
-                self.file_mod_times.insert(path.to_path_buf(), mtime);
-            }
-        }
+        self.watch_file(path);
 
         // Notify LSP that the file was changed
         self.notify_lsp_file_changed(path);
 
+        self.refresh_log_view(buffer_id);
+
         Ok(())
     }
 
@@ -837,22 +974,18 @@ impl Editor {
                 None => continue, // Can't read file, skip
             };
 
-            let dominated_by_stored = self
+            let unchanged = self
                 .file_mod_times
                 .get(&path)
-                .map(|stored| current_mtime <= *stored)
-                .unwrap_or(false);
+                .is_some_and(|stored| current_mtime == *stored);
 
-            if dominated_by_stored {
+            if unchanged {
                 continue;
             }
 
-            // If buffer has local modifications, show a warning (don't auto-revert)
+            // If buffer has local modifications, ask what to do (don't auto-revert)
             if state.buffer.is_modified() {
-                self.status_message = Some(format!(
-                    "File {} changed on disk (buffer has unsaved changes)",
-                    path.display()
-                ));
+                self.report_external_change_conflict(buffer_id, &path, current_mtime);
                 continue;
             }
 
@@ -864,35 +997,104 @@ impl Editor {
                 let still_needs_revert = self
                     .file_mod_times
                     .get(&path)
-                    .map(|stored| current_mtime > *stored)
+                    .map(|stored| current_mtime != *stored)
                     .unwrap_or(true);
 
                 if !still_needs_revert {
                     continue;
                 }
 
-                // Check if this buffer is currently displayed in the active split
-                let is_active_buffer = buffer_id == self.active_buffer();
+                self.reload_buffer_from_disk(buffer_id, &path);
+            }
+        }
+    }
 
-                if is_active_buffer {
-                    // Use revert_file() which preserves viewport for active buffer
-                    if let Err(e) = self.revert_file() {
-                        tracing::error!("Failed to auto-revert file {:?}: {}", path, e);
-                    } else {
-                        tracing::info!("Auto-reverted file: {:?}", path);
-                    }
-                } else {
-                    // Use revert_buffer_by_id() which doesn't touch any viewport
-                    // This prevents corrupting the active split's viewport state
-                    if let Err(e) = self.revert_buffer_by_id(buffer_id, &path) {
-                        tracing::error!("Failed to auto-revert background file {:?}: {}", path, e);
-                    } else {
-                        tracing::info!("Auto-reverted file: {:?}", path);
-                    }
-                }
+    /// Reload a buffer from its file, keeping the viewport of the active split
+    fn reload_buffer_from_disk(&mut self, buffer_id: BufferId, path: &Path) {
+        // Check if this buffer is currently displayed in the active split
+        let is_active_buffer = buffer_id == self.active_buffer();
 
-                // Update the modification time tracking for this file
-                self.watch_file(&path);
+        if is_active_buffer {
+            // Use revert_file() which preserves viewport for active buffer
+            if let Err(e) = self.revert_file() {
+                tracing::error!("Failed to auto-revert file {:?}: {}", path, e);
+            } else {
+                tracing::info!("Auto-reverted file: {:?}", path);
+            }
+        } else {
+            // Use revert_buffer_by_id() which doesn't touch any viewport
+            // This prevents corrupting the active split's viewport state
+            if let Err(e) = self.revert_buffer_by_id(buffer_id, path) {
+                tracing::error!("Failed to auto-revert background file {:?}: {}", path, e);
+            } else {
+                tracing::info!("Auto-reverted file: {:?}", path);
+            }
+        }
+
+        // Update the modification time tracking for this file
+        self.watch_file(path);
+    }
+
+    /// Ask whether to keep a modified buffer or reload it, once per change
+    /// of its file on disk. Falls back to a status message while another
+    /// prompt is open.
+    fn report_external_change_conflict(
+        &mut self,
+        buffer_id: BufferId,
+        path: &Path,
+        mtime: std::time::SystemTime,
+    ) {
+        if self.external_change_conflicts.get(path) == Some(&mtime) {
+            return;
+        }
+        self.external_change_conflicts
+            .insert(path.to_path_buf(), mtime);
+
+        let name = file_name(path);
+        if self.prompt.is_some() {
+            self.set_status_message(t!("file.changed_on_disk_unsaved", name = name).to_string());
+            return;
+        }
+        self.start_prompt(
+            t!(
+                "prompt.external_change_conflict",
+                name = name,
+                keep_key = t!("prompt.key.keep").to_string(),
+                reload_key = t!("prompt.key.reload").to_string(),
+                diff_key = t!("prompt.key.diff").to_string()
+            )
+            .to_string(),
+            PromptType::ConfirmExternalChange { buffer_id },
+        );
+    }
+
+    /// Act on the answer to [`Self::report_external_change_conflict`]
+    pub(super) fn handle_confirm_external_change(&mut self, input: &str, buffer_id: BufferId) {
+        let Some(path) = self
+            .buffers
+            .get(&buffer_id)
+            .and_then(|state| state.buffer.file_path())
+            .map(Path::to_path_buf)
+        else {
+            return;
+        };
+        let first_char = input.trim().to_lowercase().chars().next();
+        let key = |label: String| label.to_lowercase().chars().next();
+
+        if first_char == key(t!("prompt.key.keep").to_string()) {
+            // Saving now writes over the file without asking again
+            self.watch_file(&path);
+            self.set_status_message(
+                t!("file.kept_local_changes", name = file_name(&path)).to_string(),
+            );
+        } else if first_char == key(t!("prompt.key.reload").to_string()) {
+            self.reload_buffer_from_disk(buffer_id, &path);
+        } else if first_char == key(t!("prompt.key.diff").to_string()) {
+            self.switch_buffer(buffer_id);
+            if let Err(e) =
+                self.handle_action(Action::PluginAction("diff_against_saved".to_string()))
+            {
+                tracing::warn!("Failed to open diff against saved: {}", e);
             }
         }
     }
@@ -901,7 +1103,12 @@ impl Editor {
     /// Returns Some(current_mtime) if there's a conflict, None otherwise
     pub fn check_save_conflict(&self) -> Option<std::time::SystemTime> {
         let path = self.active_state().buffer.file_path()?;
+        self.file_changed_on_disk(path)
+    }
 
+    /// Returns Some(current_mtime) if `path` changed on disk since it was last
+    /// loaded or saved
+    fn file_changed_on_disk(&self, path: &Path) -> Option<std::time::SystemTime> {
         // Get current file modification time
         let current_mtime = match self.filesystem.metadata(path).ok().and_then(|m| m.modified) {
             Some(mtime) => mtime,
@@ -910,7 +1117,7 @@ impl Editor {
 
         // Compare with our recorded modification time
         match self.file_mod_times.get(path) {
-            Some(recorded_mtime) if current_mtime > *recorded_mtime => {
+            Some(recorded_mtime) if current_mtime != *recorded_mtime => {
                 // File was modified externally since we last loaded/saved it
                 Some(current_mtime)
             }
@@ -918,3 +1125,11 @@ impl Editor {
         }
     }
 }
+
+/// File name of a path for messages
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .into_owned()
+}
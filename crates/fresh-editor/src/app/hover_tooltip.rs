@@ -0,0 +1,194 @@
+//! Mouse hover tooltips.
+//!
+//! When the pointer rests on one cell for `mouse_tooltip_delay_ms`, a tooltip
+//! explains what is under it: the messages of diagnostics (and other overlays
+//! with a message) covering that text, the first lines hidden by a collapsed
+//! fold when over its placeholder, or the full path of a file when over its
+//! tab, whose title only shows the file name. Moving the pointer, clicking or
+//! pressing a key dismisses it.
+
+use std::time::Duration;
+
+use crossterm::event::{MouseEvent, MouseEventKind};
+
+use super::types::HoverTarget;
+use super::Editor;
+use crate::model::event::{BufferId, LeafId};
+use crate::view::popup_mouse::{popup_areas_to_layout_info, PopupHitTester};
+use crate::view::ui::tooltip::Tooltip;
+
+/// Hidden lines shown for a collapsed fold
+const FOLD_PREVIEW_LINES: usize = 10;
+
+impl Editor {
+    /// Feed a mouse event to the tooltip dwell tracking. Returns true if a
+    /// shown tooltip was dismissed.
+    pub(super) fn track_hover_tooltip(&mut self, mouse_event: &MouseEvent) -> bool {
+        let dismissed = if matches!(mouse_event.kind, MouseEventKind::Moved) {
+            let now = self.time_source.now();
+            self.mouse_state
+                .hover_dwell
+                .moved(mouse_event.column, mouse_event.row, now)
+        } else {
+            self.mouse_state.hover_dwell.reset()
+        };
+        if dismissed {
+            self.mouse_state.tooltip = None;
+        }
+        dismissed
+    }
+
+    /// Dismiss the hover tooltip and wait for the pointer to move again.
+    /// Returns true if a tooltip was shown.
+    pub(super) fn dismiss_hover_tooltip(&mut self) -> bool {
+        self.mouse_state.tooltip = None;
+        self.mouse_state.hover_dwell.reset()
+    }
+
+    /// The hover tooltip currently shown
+    pub fn hover_tooltip(&self) -> Option<&Tooltip> {
+        self.mouse_state.tooltip.as_ref()
+    }
+
+    /// Show a tooltip once the pointer has rested for the tooltip delay.
+    /// Returns true if a tooltip was shown.
+    pub fn check_hover_tooltip_timer(&mut self) -> bool {
+        let delay = Duration::from_millis(self.config.editor.mouse_tooltip_delay_ms);
+        let Some((col, row)) = self
+            .mouse_state
+            .hover_dwell
+            .due(self.time_source.now(), delay)
+        else {
+            return false;
+        };
+        // Looked up once per resting position, whether or not there is a tooltip
+        self.mouse_state.hover_dwell.mark_shown();
+        let Some(lines) = self.tooltip_lines_at(col, row) else {
+            return false;
+        };
+        self.mouse_state.tooltip = Some(Tooltip {
+            anchor: (col, row),
+            lines,
+        });
+        true
+    }
+
+    /// What the tooltip for the cell at `(col, row)` says, if anything
+    fn tooltip_lines_at(&self, col: u16, row: u16) -> Option<Vec<String>> {
+        // Menus, prompts and dialogs cover the editor; popups have their own text
+        if self.menu_state.active_menu.is_some()
+            || self.tab_context_menu.is_some()
+            || self.prompt.is_some()
+            || self.keybinding_editor.is_some()
+            || self.settings_state.as_ref().is_some_and(|s| s.visible)
+        {
+            return None;
+        }
+        let layouts = popup_areas_to_layout_info(&self.cached_layout.popup_areas);
+        if PopupHitTester::new(&layouts, &self.active_state().popups).is_over_popup(col, row) {
+            return None;
+        }
+
+        if let Some(HoverTarget::TabName(buffer_id, _)) = self.mouse_state.hover_target {
+            let path = self.buffers.get(&buffer_id)?.buffer.file_path()?;
+            return Some(vec![path.display().to_string()]);
+        }
+
+        let (split_id, buffer_id, content_rect) = self
+            .cached_layout
+            .split_areas
+            .iter()
+            .find(|(_, _, rect, _, _, _)| {
+                col >= rect.x
+                    && col < rect.x + rect.width
+                    && row >= rect.y
+                    && row < rect.y + rect.height
+            })
+            .map(|(split_id, buffer_id, rect, _, _, _)| (*split_id, *buffer_id, *rect))?;
+        self.content_tooltip_lines(split_id, buffer_id, content_rect, col, row)
+    }
+
+    /// Tooltip for a cell of a split's text area
+    fn content_tooltip_lines(
+        &self,
+        split_id: LeafId,
+        buffer_id: BufferId,
+        content_rect: ratatui::layout::Rect,
+        col: u16,
+        row: u16,
+    ) -> Option<Vec<String>> {
+        let state = self.buffers.get(&buffer_id)?;
+        let compose_width = self
+            .split_view_states
+            .get(&split_id)
+            .and_then(|vs| vs.compose_width);
+        let content_rect = Self::adjust_content_rect_for_compose(content_rect, compose_width);
+        let gutter_width = state.margins.left_total_width() as u16;
+        let content_col = col.checked_sub(content_rect.x)?;
+        let text_col = content_col.checked_sub(gutter_width)? as usize;
+        let visual_row = row.checked_sub(content_rect.y)? as usize;
+
+        let mapping = self
+            .cached_layout
+            .view_line_mappings
+            .get(&split_id)?
+            .get(visual_row)?;
+        let char_idx = *mapping.visual_to_char.get(text_col)?;
+
+        match *mapping.char_source_bytes.get(char_idx)? {
+            Some(byte) => {
+                let mut overlays: Vec<_> = state
+                    .overlays
+                    .at_position(byte, &state.marker_list)
+                    .into_iter()
+                    .filter(|overlay| overlay.message.is_some())
+                    .collect();
+                overlays.sort_by_key(|overlay| std::cmp::Reverse(overlay.priority));
+                let lines: Vec<String> = overlays
+                    .iter()
+                    .filter_map(|overlay| overlay.message.as_deref())
+                    .flat_map(str::lines)
+                    .map(str::to_string)
+                    .collect();
+                (!lines.is_empty()).then_some(lines)
+            }
+            None => {
+                // Fold placeholders are the virtual text at the end of the
+                // header line, before its newline
+                let after = &mapping.char_source_bytes[char_idx + 1..];
+                if !after
+                    .iter()
+                    .take(after.len().saturating_sub(1))
+                    .all(Option::is_none)
+                {
+                    return None;
+                }
+                let line_byte = mapping.char_source_bytes.iter().flatten().next()?;
+                let header_line = state.buffer.get_line_number(*line_byte);
+                let fold = self
+                    .split_view_states
+                    .get(&split_id)?
+                    .keyed_states
+                    .get(&buffer_id)?
+                    .folds
+                    .resolved_ranges(&state.buffer, &state.marker_list)
+                    .into_iter()
+                    .find(|fold| fold.header_line == header_line)?;
+
+                let last = fold.end_line.min(fold.start_line + FOLD_PREVIEW_LINES - 1);
+                let mut lines: Vec<String> = (fold.start_line..=last)
+                    .filter_map(|line| state.buffer.get_line(line))
+                    .map(|bytes| {
+                        String::from_utf8_lossy(&bytes)
+                            .trim_end_matches(['\n', '\r'])
+                            .replace('\t', "    ")
+                    })
+                    .collect();
+                if fold.end_line > last {
+                    lines.push("…".to_string());
+                }
+                Some(lines)
+            }
+        }
+    }
+}
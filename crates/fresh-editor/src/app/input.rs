@@ -1,3 +1,4 @@
+use super::types::{InputTarget, SelectionUnit};
 use super::*;
 use crate::model::event::LeafId;
 use crate::services::plugins::hooks::HookArgs;
@@ -23,6 +24,15 @@ impl Editor {
         }
     }
 
+    /// Where input currently goes; see [`InputTarget`]
+    pub fn input_target(&self) -> InputTarget {
+        InputTarget {
+            context: self.get_key_context(),
+            split: self.split_manager.active_split(),
+            buffer: self.active_buffer(),
+        }
+    }
+
     /// Handle a key event and return whether it was handled
     /// This is the central key handling logic used by both main.rs and tests
     pub fn handle_key(
@@ -40,8 +50,15 @@ impl Editor {
             modifiers
         );
 
+        // Any key may change what the workspace would restore
+        self.mark_workspace_dirty();
+
+        // Any key dismisses a hover tooltip
+        self.dismiss_hover_tooltip();
+
         // Create key event for dispatch methods
         let key_event = crossterm::event::KeyEvent::new(code, modifiers);
+        self.key_modifiers = modifiers;
 
         // Event debug dialog intercepts ALL key events before any other processing.
         // This must be checked here (not just in main.rs/gui) so it works in
@@ -57,10 +74,13 @@ impl Editor {
         }
 
         // Clear skip_ensure_visible flag so cursor becomes visible after key press
-        // (scroll actions will set it again if needed)
+        // (scroll actions will set it again if needed). A Quick Open line preview
+        // keeps its scroll position until the prompt closes.
         let active_split = self.split_manager.active_split();
         if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
-            view_state.viewport.clear_skip_ensure_visible();
+            if self.quick_open_preview.is_none() {
+                view_state.viewport.clear_skip_ensure_visible();
+            }
         }
 
         // Determine the current context first
@@ -95,6 +115,8 @@ impl Editor {
 
         // Try hierarchical modal input dispatch first (Settings, Menu, Prompt, Popup)
         if self.dispatch_modal_input(&key_event).is_some() {
+            // The buffer picker previews whichever buffer is now selected
+            self.update_buffer_picker(&key_event);
             return Ok(());
         }
 
@@ -106,6 +128,13 @@ impl Editor {
 
         // Only check buffer mode keybindings if we're not in a higher-priority context
         // (Menu, Prompt, Popup should take precedence over mode bindings)
+        if context == crate::input::keybindings::KeyContext::Normal && self.vim_enabled() {
+            use crate::input::vim::VimOutcome;
+            if let VimOutcome::Run(actions, fixup) = self.vim.handle_key(code, modifiers) {
+                return self.run_vim_actions(actions, fixup);
+            }
+        }
+
         let should_check_mode_bindings = matches!(
             context,
             crate::input::keybindings::KeyContext::Normal
@@ -140,7 +169,7 @@ impl Editor {
                 if is_potential_chord {
                     // This could be the start of a chord - add to state and wait
                     tracing::debug!("Potential chord prefix in editor mode");
-                    self.chord_state.push((code, modifiers));
+                    self.push_chord_key(code, modifiers);
                     return Ok(());
                 }
 
@@ -195,7 +224,7 @@ impl Editor {
             crate::input::keybindings::ChordResolution::Partial => {
                 // Partial match - add to chord state and wait for more keys
                 tracing::debug!("Partial chord match - waiting for next key");
-                self.chord_state.push((code, modifiers));
+                self.push_chord_key(code, modifiers);
                 return Ok(());
             }
             crate::input::keybindings::ChordResolution::NoMatch => {
@@ -234,6 +263,68 @@ impl Editor {
         self.handle_action(action)
     }
 
+    /// Extend the pending chord sequence and restart its timeout
+    fn push_chord_key(
+        &mut self,
+        code: crossterm::event::KeyCode,
+        modifiers: crossterm::event::KeyModifiers,
+    ) {
+        self.chord_state.push((code, modifiers));
+        let timeout_ms = self.config.editor.chord_timeout_ms;
+        self.chord_deadline = (timeout_ms > 0)
+            .then(|| std::time::Instant::now() + std::time::Duration::from_millis(timeout_ms));
+    }
+
+    /// Whether keys in the text area go through the Vim state machine.
+    /// Plugin-defined editor modes take precedence.
+    pub(crate) fn vim_enabled(&self) -> bool {
+        self.config.editor.keymap == crate::config::EditorKeymap::Vim && self.editor_mode.is_none()
+    }
+
+    /// Current Vim mode, or `None` when Vim keys are off
+    pub fn vim_mode(&self) -> Option<crate::input::vim::VimMode> {
+        self.vim_enabled().then(|| self.vim.mode())
+    }
+
+    /// Run the actions produced by the Vim state machine for one key
+    fn run_vim_actions(
+        &mut self,
+        actions: Vec<Action>,
+        fixup: crate::input::vim::SelectionFixup,
+    ) -> AnyhowResult<()> {
+        use crate::input::vim::SelectionFixup;
+
+        for action in actions {
+            // Cut and Copy fall back to the whole line when nothing is
+            // selected; a Vim motion that didn't move (e.g. `dw` at the end
+            // of the buffer) must not do that
+            if matches!(action, Action::Cut | Action::Copy)
+                && self
+                    .active_cursors()
+                    .iter()
+                    .all(|(_, cursor)| cursor.selection_range().is_none())
+            {
+                continue;
+            }
+            self.handle_action(action)?;
+        }
+
+        match fixup {
+            SelectionFixup::None => {}
+            SelectionFixup::Clear => {
+                self.active_cursors_mut()
+                    .map(|cursor| cursor.clear_selection());
+            }
+            SelectionFixup::CollapseToStart => {
+                self.active_cursors_mut().map(|cursor| {
+                    cursor.position = cursor.selection_start();
+                    cursor.clear_selection();
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// Handle an action (for normal mode and command execution).
     /// Used by the app module internally and by the GUI module for native menu dispatch.
     pub(crate) fn handle_action(&mut self, action: Action) -> AnyhowResult<()> {
@@ -242,6 +333,36 @@ impl Editor {
         // Record action to macro if recording
         self.record_macro_action(&action);
 
+        // Remember the last edit made in the text area for RepeatLast
+        // (a completion popup may be open while typing)
+        if matches!(
+            self.get_key_context(),
+            crate::input::keybindings::KeyContext::Normal
+                | crate::input::keybindings::KeyContext::Popup
+        ) {
+            self.repeat.record(&action);
+        }
+
+        // Cycle Paste only follows a paste
+        if !matches!(action, Action::Paste | Action::CyclePaste) {
+            self.paste_cycle = None;
+        }
+
+        // Word boundaries expand abbreviations, Backspace right after reverts
+        if self.handle_abbreviation_action(&action) {
+            return Ok(());
+        }
+
+        // Tab and Shift+Tab move between the tabstops of an inserted snippet
+        if self.handle_snippet_action(&action) {
+            return Ok(());
+        }
+
+        // Line Drawing Mode draws with the arrow keys and overwrites typing
+        if self.handle_line_drawing_action(&action) {
+            return Ok(());
+        }
+
         match action {
             Action::Quit => self.quit(),
             Action::ForceQuit => {
@@ -259,6 +380,10 @@ impl Editor {
                         String::new(),
                     );
                     self.init_file_open_state();
+                } else if self.saves_active_file_as() {
+                    // Don't write over a protected file, offer another name instead
+                    self.start_save_as_prompt();
+                    self.set_status_message(t!("file.read_only_save_as").to_string());
                 } else if self.check_save_conflict().is_some() {
                     // Check if file was modified externally since we opened/saved it
                     self.start_prompt(
@@ -269,27 +394,7 @@ impl Editor {
                     self.save()?;
                 }
             }
-            Action::SaveAs => {
-                // Get current filename as default suggestion
-                let current_path = self
-                    .active_state()
-                    .buffer
-                    .file_path()
-                    .map(|p| {
-                        // Make path relative to working_dir if possible
-                        p.strip_prefix(&self.working_dir)
-                            .unwrap_or(p)
-                            .to_string_lossy()
-                            .to_string()
-                    })
-                    .unwrap_or_default();
-                self.start_prompt_with_initial_text(
-                    t!("file.save_as_prompt").to_string(),
-                    PromptType::SaveFileAs,
-                    current_path,
-                );
-                self.init_file_open_state();
-            }
+            Action::SaveAs => self.start_save_as_prompt(),
             Action::Open => {
                 self.start_prompt(t!("file.open_prompt").to_string(), PromptType::OpenFile);
                 self.prefill_open_file_prompt();
@@ -323,7 +428,14 @@ impl Editor {
                 self.start_incremental_line_scan(false);
             }
             Action::New => {
-                self.new_buffer();
+                self.new_scratch_buffer();
+            }
+            Action::NewScratchWithLanguage => {
+                self.start_language_prompt(
+                    t!("buffer.new_scratch_prompt").to_string(),
+                    PromptType::NewScratchBuffer,
+                    None,
+                );
             }
             Action::Close | Action::CloseTab => {
                 // Both Close and CloseTab use close_tab() which handles:
@@ -332,6 +444,16 @@ impl Editor {
                 // - Properly closing the buffer
                 self.close_tab();
             }
+            Action::CloseOtherTabs => {
+                let split_id = self.split_manager.active_split();
+                self.close_other_tabs_in_split(self.active_buffer(), split_id);
+            }
+            Action::CloseTabsToRight => {
+                let split_id = self.split_manager.active_split();
+                self.close_tabs_to_right_in_split(self.active_buffer(), split_id);
+            }
+            Action::ReopenClosedTab => self.reopen_closed_tab(),
+            Action::TogglePinTab => self.toggle_pin_active_tab(),
             Action::Revert => {
                 // Check if buffer has unsaved changes - prompt for confirmation
                 if self.active_state().buffer.is_modified() {
@@ -412,7 +534,10 @@ impl Editor {
                 }
                 self.copy_selection()
             }
+            Action::CopyVisible => self.copy_visible_selection(),
             Action::CopyWithTheme(theme) => self.copy_selection_with_theme(&theme),
+            Action::ExportHtml => self.export_styled(super::export_actions::ExportFormat::Html),
+            Action::ExportAnsi => self.export_styled(super::export_actions::ExportFormat::Ansi),
             Action::Cut => {
                 if self.is_editing_disabled() {
                     self.set_status_message(t!("buffer.editing_disabled").to_string());
@@ -427,6 +552,21 @@ impl Editor {
                 }
                 self.paste()
             }
+            Action::PasteFromHistory => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.start_paste_from_history_prompt();
+            }
+            Action::InsertUnicodeChar => self.start_unicode_picker(),
+            Action::CyclePaste => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.cycle_paste();
+            }
             Action::YankWordForward => self.yank_word_forward(),
             Action::YankWordBackward => self.yank_word_backward(),
             Action::YankToLineEnd => self.yank_to_line_end(),
@@ -437,6 +577,21 @@ impl Editor {
             Action::Redo => {
                 self.handle_redo();
             }
+            Action::RepeatLast => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                let Some(actions) = self.repeat.begin_replay() else {
+                    self.set_status_message(t!("repeat.nothing").to_string());
+                    return Ok(());
+                };
+                let result = actions
+                    .into_iter()
+                    .try_for_each(|action| self.handle_action(action));
+                self.repeat.end_replay();
+                result?;
+            }
             Action::ShowHelp => {
                 self.open_help_manual();
             }
@@ -449,9 +604,36 @@ impl Editor {
             Action::ShowStatusLog => {
                 self.open_status_log();
             }
+            Action::ShowPluginLog => {
+                self.open_plugin_log();
+            }
+            Action::ShowLogs => {
+                self.open_editor_log();
+            }
+            Action::TogglePerformanceHud => {
+                self.toggle_performance_hud();
+            }
+            Action::DumpPerformanceReport => {
+                self.dump_performance_report();
+            }
+            Action::ManagePlugins => {
+                self.open_plugin_panel();
+            }
+            Action::PluginPanelToggle => {
+                self.plugin_panel_toggle();
+            }
+            Action::PluginPanelReload => {
+                self.plugin_panel_reload();
+            }
+            Action::PluginPanelDetails => {
+                self.plugin_panel_details();
+            }
             Action::ShowLspStatus => {
                 self.show_lsp_status_popup();
             }
+            Action::ShowProcessStatus => {
+                self.show_process_status();
+            }
             Action::ClearWarnings => {
                 self.clear_warnings();
             }
@@ -495,21 +677,8 @@ impl Editor {
                 // Start Quick Open with file suggestions (default mode)
                 self.start_quick_open();
             }
-            Action::ToggleLineWrap => {
-                self.config.editor.line_wrap = !self.config.editor.line_wrap;
-
-                // Update all viewports to reflect the new line wrap setting
-                for view_state in self.split_view_states.values_mut() {
-                    view_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
-                }
-
-                let state = if self.config.editor.line_wrap {
-                    t!("view.state_enabled").to_string()
-                } else {
-                    t!("view.state_disabled").to_string()
-                };
-                self.set_status_message(t!("view.line_wrap_state", state = state).to_string());
-            }
+            Action::ToggleLineWrap => self.toggle_line_wrap(),
+            Action::ToggleReadOnly => self.toggle_read_only(),
             Action::ToggleComposeMode => {
                 self.handle_toggle_compose_mode();
             }
@@ -554,6 +723,7 @@ impl Editor {
             Action::LspCompletion => {
                 self.request_completion();
             }
+            Action::ExpandSnippet => self.expand_snippet_at_cursor(),
             Action::LspGotoDefinition => {
                 self.request_goto_definition()?;
             }
@@ -659,10 +829,21 @@ impl Editor {
             Action::AddCursorNextMatch => self.add_cursor_at_next_match(),
             Action::AddCursorAbove => self.add_cursor_above(),
             Action::AddCursorBelow => self.add_cursor_below(),
+            Action::AlignByPattern => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.start_prompt(
+                    t!("align.pattern_prompt").to_string(),
+                    PromptType::AlignByPattern,
+                );
+            }
             Action::NextBuffer => self.next_buffer(),
             Action::PrevBuffer => self.prev_buffer(),
             Action::SwitchToPreviousTab => self.switch_to_previous_tab(),
             Action::SwitchToTabByName => self.start_switch_to_tab_prompt(),
+            Action::BufferPicker => self.start_buffer_picker(),
 
             // Tab scrolling (manual scroll - don't auto-adjust)
             Action::ScrollTabsLeft => {
@@ -686,14 +867,24 @@ impl Editor {
             Action::CloseSplit => self.close_active_split(),
             Action::NextSplit => self.next_split(),
             Action::PrevSplit => self.prev_split(),
-            Action::IncreaseSplitSize => self.adjust_split_size(0.05),
-            Action::DecreaseSplitSize => self.adjust_split_size(-0.05),
+            Action::IncreaseSplitSize => self.resize_active_split(1),
+            Action::DecreaseSplitSize => self.resize_active_split(-1),
             Action::ToggleMaximizeSplit => self.toggle_maximize_split(),
+            Action::EqualizeSplits => self.equalize_splits(),
+            Action::ToggleScrollLink => self.toggle_scroll_link(),
             Action::ToggleFileExplorer => self.toggle_file_explorer(),
             Action::ToggleMenuBar => self.toggle_menu_bar(),
             Action::ToggleTabBar => self.toggle_tab_bar(),
             Action::ToggleVerticalScrollbar => self.toggle_vertical_scrollbar(),
             Action::ToggleHorizontalScrollbar => self.toggle_horizontal_scrollbar(),
+            Action::ToggleIndentGuides => self.toggle_indent_guides(),
+            Action::ToggleMinimap => self.toggle_minimap(),
+            Action::ToggleColorSwatches => self.toggle_color_swatches(),
+            Action::AdjustColor => self.adjust_color_at_cursor(),
+            Action::ToggleAlignedView => self.toggle_aligned_view(),
+            Action::ToggleMarkdownPreview => self.toggle_markdown_preview(),
+            Action::ToggleFollowTail => self.toggle_follow_tail(),
+            Action::FilterLogLines => self.start_log_filter_prompt(),
             Action::ToggleLineNumbers => self.toggle_line_numbers(),
             Action::ToggleScrollSync => self.toggle_scroll_sync(),
             Action::ToggleMouseCapture => self.toggle_mouse_capture(),
@@ -753,8 +944,24 @@ impl Editor {
                     self.set_status_message(status.to_string());
                 }
             }
+            Action::ToggleRenderWhitespace => {
+                let buffer_id = self.active_buffer();
+                // Terminal output is shown as the program wrote it
+                if !self.is_terminal_buffer(buffer_id) {
+                    if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                        let whitespace = &mut state.buffer_settings.whitespace;
+                        whitespace.cycle_render_whitespace();
+                        let mode = whitespace.render_whitespace.as_str();
+                        self.set_status_message(
+                            t!("toggle.render_whitespace", mode = mode).to_string(),
+                        );
+                    }
+                }
+            }
             Action::ResetBufferSettings => self.reset_buffer_settings(),
             Action::FocusFileExplorer => self.focus_file_explorer(),
+            Action::RevealInFileExplorer => self.reveal_in_file_explorer(),
+            Action::ToggleFileExplorerFollow => self.toggle_file_explorer_follow(),
             Action::FocusEditor => self.focus_editor(),
             Action::FileExplorerUp => self.file_explorer_navigate_up(),
             Action::FileExplorerDown => self.file_explorer_navigate_down(),
@@ -772,9 +979,13 @@ impl Editor {
             Action::FileExplorerToggleGitignored => self.file_explorer_toggle_gitignored(),
             Action::FileExplorerSearchClear => self.file_explorer_search_clear(),
             Action::FileExplorerSearchBackspace => self.file_explorer_search_pop_char(),
+            Action::FileExplorerFilter => self.file_explorer_filter(),
             Action::RemoveSecondaryCursors => {
-                // Convert action to events and apply them
-                if let Some(events) = self.action_to_events(Action::RemoveSecondaryCursors) {
+                // With a single cursor, Escape dismisses the search highlights instead
+                if self.active_cursors().count() == 1 && self.search_state.is_some() {
+                    self.clear_search_highlights();
+                } else if let Some(events) = self.action_to_events(Action::RemoveSecondaryCursors) {
+                    // Convert action to events and apply them
                     // Wrap in batch for atomic undo
                     let batch = Event::Batch {
                         events: events.clone(),
@@ -845,27 +1056,48 @@ impl Editor {
                 }
             }
 
-            Action::SmartHome => {
+            Action::SmartHome | Action::SelectSmartHome => {
+                let extend_selection = action == Action::SelectSmartHome;
+                // With smart_home off, Home goes straight to the line start
+                if !self.config.editor.smart_home {
+                    return self.apply_action_as_events(if extend_selection {
+                        Action::SelectLineStart
+                    } else {
+                        Action::MoveLineStart
+                    });
+                }
                 // In composite (diff) views, use LineStart movement
                 let buffer_id = self.active_buffer();
                 if self.is_composite_buffer(buffer_id) {
-                    if let Some(_handled) =
-                        self.handle_composite_action(buffer_id, &Action::SmartHome)
-                    {
+                    if let Some(_handled) = self.handle_composite_action(buffer_id, &action) {
                         return Ok(());
                     }
                 }
-                self.smart_home();
+                self.smart_home(extend_selection);
             }
             Action::ToggleComment => {
                 self.toggle_comment();
             }
+            Action::ToggleBlockComment => {
+                self.toggle_block_comment();
+            }
+            Action::DrawBox => self.draw_box(),
+            Action::ToggleLineDrawing => self.toggle_line_drawing(),
+            Action::JoinLines => self.join_lines(),
+            Action::HardWrap => self.start_hard_wrap_prompt(),
+            Action::SurroundSelection => self.start_surround_prompt(),
+            Action::ChangeSurrounding => self.start_change_surrounding_prompt(),
             Action::ToggleFold => {
                 self.toggle_fold_at_cursor();
             }
             Action::GoToMatchingBracket => {
-                self.goto_matching_bracket();
+                self.goto_matching_bracket(false);
+            }
+            Action::SelectToMatchingBracket => {
+                self.goto_matching_bracket(true);
             }
+            Action::ExpandSelection => self.expand_selection(),
+            Action::ShrinkSelection => self.shrink_selection(),
             Action::JumpToNextError => {
                 self.jump_to_next_error();
             }
@@ -884,6 +1116,27 @@ impl Editor {
             Action::ListBookmarks => {
                 self.list_bookmarks();
             }
+            Action::ToggleBookmark => {
+                self.toggle_line_bookmark();
+            }
+            Action::AddLabeledBookmark => {
+                self.start_prompt(
+                    t!("bookmark.label_prompt").to_string(),
+                    PromptType::BookmarkLabel,
+                );
+            }
+            Action::NextBookmark => {
+                self.goto_adjacent_bookmark(true);
+            }
+            Action::PreviousBookmark => {
+                self.goto_adjacent_bookmark(false);
+            }
+            Action::BrowseBookmarks => {
+                self.start_browse_bookmarks_prompt();
+            }
+            Action::ClearBufferBookmarks => {
+                self.clear_buffer_bookmarks();
+            }
             Action::ToggleSearchCaseSensitive => {
                 self.search_case_sensitive = !self.search_case_sensitive;
                 let state = if self.search_case_sensitive {
@@ -970,6 +1223,17 @@ impl Editor {
                 };
                 self.set_status_message(t!("search.confirm_each_state", state = state).to_string());
             }
+            Action::ToggleSearchPreserveCase => {
+                self.search_preserve_case = !self.search_preserve_case;
+                let state = if self.search_preserve_case {
+                    "enabled"
+                } else {
+                    "disabled"
+                };
+                self.set_status_message(
+                    t!("search.preserve_case_state", state = state).to_string(),
+                );
+            }
             Action::FileBrowserToggleHidden => {
                 // Toggle hidden files in file browser (handled via file_open_toggle_hidden)
                 self.file_open_toggle_hidden();
@@ -1076,6 +1340,14 @@ impl Editor {
             Action::CloseTerminal => {
                 self.close_terminal();
             }
+            Action::SwitchTerminal => self.start_switch_terminal_prompt(),
+            Action::RenameTerminal => self.start_rename_terminal_prompt(),
+            Action::RunSelectionInTerminal => self.run_selection_in_terminal(),
+            Action::RunFileInTerminal => self.run_file_in_terminal(),
+            Action::OpenLinkUnderCursor => self.open_link_under_cursor(),
+            Action::RunTask => self.start_run_task_prompt(),
+            Action::RerunLastTask => self.rerun_last_task(),
+            Action::CancelTask => self.cancel_task(),
             Action::FocusTerminal => {
                 // If viewing a terminal buffer, switch to terminal mode
                 if self.is_terminal_buffer(self.active_buffer()) {
@@ -1183,6 +1455,9 @@ impl Editor {
             Action::OpenKeybindingEditor => {
                 self.open_keybinding_editor();
             }
+            Action::ResetCommandRanking => {
+                self.reset_command_ranking();
+            }
             Action::PromptConfirm => {
                 if let Some((input, prompt_type, selected_index)) = self.confirm_prompt() {
                     use super::prompt_actions::PromptResult;
@@ -1234,6 +1509,9 @@ impl Editor {
                     self.handle_insert_char_editor(c)?;
                 }
             }
+            Action::PromptBindCommand => {
+                self.bind_palette_command();
+            }
             // Prompt clipboard actions
             Action::PromptCopy => {
                 if let Some(prompt) = &self.prompt {
@@ -1336,6 +1614,11 @@ impl Editor {
         let active_split = self.split_manager.active_split();
         let buffer_id = self.active_buffer();
 
+        // Scrolling up through a followed log file stops following it
+        if delta < 0 {
+            self.pause_log_follow(buffer_id);
+        }
+
         // Check if this is a composite buffer - if so, use composite scroll
         if self.is_composite_buffer(buffer_id) {
             let max_row = self
@@ -1357,6 +1640,12 @@ impl Editor {
             return Ok(());
         }
 
+        // Splits with synchronized scrolling scroll together
+        if !self.scroll_linked_splits().is_empty() {
+            self.handle_scroll_event(delta as isize);
+            return Ok(());
+        }
+
         // Get view_transform tokens from SplitViewState (if any)
         let view_transform_tokens = self
             .split_view_states
@@ -1427,16 +1716,25 @@ impl Editor {
         Ok(())
     }
 
-    /// Handle horizontal scroll (Shift+ScrollWheel or native ScrollLeft/ScrollRight)
+    /// Scroll the split under `(col, row)` horizontally by `delta` columns
+    /// (negative scrolls left). Scrolling right stops once the end of the
+    /// longest visible line is on screen.
     pub(super) fn handle_horizontal_scroll(
         &mut self,
-        _col: u16,
-        _row: u16,
+        col: u16,
+        row: u16,
         delta: i32,
     ) -> AnyhowResult<()> {
-        let active_split = self.split_manager.active_split();
+        // Scroll the split under the pointer, falling back to the active one
+        let (split_id, buffer_id) = self
+            .cached_layout
+            .split_areas
+            .iter()
+            .find(|(_, _, rect, _, _, _)| crate::view::ui::point_in_rect(*rect, col, row))
+            .map(|(split_id, buffer_id, _, _, _, _)| (*split_id, *buffer_id))
+            .unwrap_or_else(|| (self.split_manager.active_split(), self.active_buffer()));
 
-        if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
+        if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
             // Don't scroll horizontally when line wrap is enabled
             if view_state.viewport.line_wrap_enabled {
                 return Ok(());
@@ -1450,12 +1748,22 @@ impl Editor {
                     .left_column
                     .saturating_sub(columns_to_scroll);
             } else {
-                // Scroll right - clamp to max_line_length_seen
+                // Scroll right - clamp to the longest visible line
                 let visible_width = view_state.viewport.width as usize;
-                let max_scroll = view_state
-                    .viewport
-                    .max_line_length_seen
-                    .saturating_sub(visible_width);
+                let longest_line = self
+                    .buffers
+                    .get_mut(&buffer_id)
+                    .map(|state| {
+                        longest_line_length(
+                            &mut state.buffer,
+                            view_state.viewport.top_byte,
+                            view_state.viewport.height as usize,
+                        )
+                    })
+                    .unwrap_or(view_state.viewport.max_line_length_seen);
+                let max_scroll = longest_line
+                    .saturating_sub(visible_width)
+                    .max(view_state.viewport.left_column);
                 let new_left = view_state
                     .viewport
                     .left_column
@@ -1803,6 +2111,59 @@ impl Editor {
         Ok(())
     }
 
+    /// Handle a click or drag in the minimap: scroll so the source lines under
+    /// the mouse are centered in the viewport.
+    pub(super) fn handle_minimap_jump(
+        &mut self,
+        row: u16,
+        split_id: LeafId,
+        buffer_id: BufferId,
+        minimap_rect: ratatui::layout::Rect,
+        row_starts: &[usize],
+    ) -> AnyhowResult<()> {
+        let relative_row = row.saturating_sub(minimap_rect.y) as usize;
+        let Some(&target_byte) = row_starts.get(relative_row).or_else(|| row_starts.last()) else {
+            return Ok(());
+        };
+
+        let viewport_height = self
+            .split_view_states
+            .get(&split_id)
+            .map(|vs| vs.viewport.height as usize)
+            .unwrap_or(10);
+        let estimated_line_length = self.config.editor.estimated_line_length;
+
+        let top_byte = if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            // Step back half a viewport so the target lines land in the middle
+            let mut iter = state
+                .buffer
+                .line_iterator(target_byte, estimated_line_length);
+            let mut top_byte = iter.current_position();
+            for _ in 0..viewport_height / 2 {
+                match iter.prev() {
+                    Some((line_start, _)) => top_byte = line_start,
+                    None => break,
+                }
+            }
+            let max_top_byte =
+                Self::calculate_max_scroll_position(&mut state.buffer, viewport_height);
+            top_byte.min(max_top_byte)
+        } else {
+            return Ok(());
+        };
+
+        if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
+            view_state.viewport.top_byte = top_byte;
+            view_state.viewport.top_view_line_offset = 0;
+            // Skip ensure_visible so the scroll position isn't undone during render
+            view_state.viewport.set_skip_ensure_visible();
+        }
+
+        self.move_cursor_to_visible_area(split_id, buffer_id);
+
+        Ok(())
+    }
+
     /// Handle scrollbar jump (click on track) for composite buffers.
     /// Maps the click ratio to a row-based scroll position.
     fn handle_composite_scrollbar_jump(
@@ -2262,7 +2623,7 @@ impl Editor {
         Some(position)
     }
 
-    fn adjust_content_rect_for_compose(
+    pub(super) fn adjust_content_rect_for_compose(
         content_rect: ratatui::layout::Rect,
         compose_width: Option<u16>,
     ) -> ratatui::layout::Rect {
@@ -2425,9 +2786,60 @@ impl Editor {
             String::new()
         };
 
+        // Get cached view line mappings for this split (before mutable borrow of buffers)
+        let cached_mappings = self
+            .cached_layout
+            .view_line_mappings
+            .get(&split_id)
+            .cloned();
+
+        // Get fallback from SplitViewState viewport
+        let fallback = self
+            .split_view_states
+            .get(&split_id)
+            .map(|vs| vs.viewport.top_byte)
+            .unwrap_or(0);
+
+        // Get compose width for this split (adjusts content rect for centered layout)
+        let compose_width = self
+            .split_view_states
+            .get(&split_id)
+            .and_then(|vs| vs.compose_width);
+
         // Dispatch MouseClick hook to plugins
-        // Plugins can handle clicks on their virtual buffers
+        // Plugins can handle clicks on their virtual buffers, or on virtual
+        // text in a file buffer via the clicked text column
         if self.plugin_manager.has_hook_handlers("mouse_click") {
+            let (byte_position, text_column) = self
+                .buffers
+                .get(&buffer_id)
+                .filter(|_| !self.is_composite_buffer(buffer_id))
+                .map(|state| {
+                    let gutter_width = state.margins.left_total_width() as u16;
+                    let position = Self::screen_to_buffer_position(
+                        col,
+                        row,
+                        content_rect,
+                        gutter_width,
+                        &cached_mappings,
+                        fallback,
+                        true,
+                        compose_width,
+                    );
+                    let adjusted_rect =
+                        Self::adjust_content_rect_for_compose(content_rect, compose_width);
+                    let left_column = self
+                        .split_view_states
+                        .get(&split_id)
+                        .map(|vs| vs.viewport.left_column)
+                        .unwrap_or(0);
+                    let text_column = col
+                        .saturating_sub(adjusted_rect.x)
+                        .checked_sub(gutter_width)
+                        .map(|c| c as usize + left_column);
+                    (position, text_column)
+                })
+                .unwrap_or((None, None));
             self.plugin_manager.run_hook(
                 "mouse_click",
                 HookArgs::MouseClick {
@@ -2437,6 +2849,9 @@ impl Editor {
                     modifiers: modifiers_str,
                     content_x: content_rect.x,
                     content_y: content_rect.y,
+                    buffer_id: byte_position.map(|_| buffer_id),
+                    byte_position,
+                    text_column,
                 },
             );
         }
@@ -2455,25 +2870,13 @@ impl Editor {
             self.key_context = crate::input::keybindings::KeyContext::Normal;
         }
 
-        // Get cached view line mappings for this split (before mutable borrow of buffers)
-        let cached_mappings = self
-            .cached_layout
-            .view_line_mappings
-            .get(&split_id)
-            .cloned();
-
-        // Get fallback from SplitViewState viewport
-        let fallback = self
-            .split_view_states
-            .get(&split_id)
-            .map(|vs| vs.viewport.top_byte)
-            .unwrap_or(0);
-
-        // Get compose width for this split (adjusts content rect for centered layout)
-        let compose_width = self
-            .split_view_states
-            .get(&split_id)
-            .and_then(|vs| vs.compose_width);
+        // Clicking a color swatch opens the color adjuster for its literal
+        if let Some(literal_start) =
+            self.color_swatch_at(split_id, buffer_id, content_rect, col, row)
+        {
+            self.adjust_color_at(buffer_id, literal_start);
+            return Ok(());
+        }
 
         // Calculate clicked position in buffer
         let (toggle_fold_byte, onclick_action, target_position, cursor_snapshot) =
@@ -2572,16 +2975,53 @@ impl Editor {
             return Ok(());
         }
 
+        // Ctrl+click opens the URL or file path under the pointer
+        if modifiers == KeyModifiers::CONTROL {
+            if let Some((_, target)) = self.buffer_link_at(buffer_id, target_position) {
+                self.open_buffer_link(buffer_id, target);
+                return Ok(());
+            }
+        }
+
         // Move cursor to clicked position (respect shift for selection)
         // Both modifiers supported since some terminals intercept shift+click.
         let extend_selection =
             modifiers.contains(KeyModifiers::SHIFT) || modifiers.contains(KeyModifiers::CONTROL);
-        let new_anchor = if extend_selection {
-            Some(old_anchor.unwrap_or(old_position))
+
+        // A word/line selection made with the mouse keeps growing by words or
+        // lines, as long as it hasn't been changed since
+        let unit = self.mouse_state.drag_selection_unit;
+        let unit_extension = match &self.mouse_state.drag_selection_origin {
+            Some(origin)
+                if extend_selection
+                    && unit != SelectionUnit::Char
+                    && self.mouse_state.unit_selection
+                        == Some((old_anchor.unwrap_or(old_position), old_position)) =>
+            {
+                self.buffers.get(&buffer_id).map(|state| {
+                    super::mouse_input::extend_unit_selection(
+                        origin,
+                        &super::mouse_input::unit_range_at(&state.buffer, unit, target_position),
+                    )
+                })
+            }
+            _ => None,
+        };
+        if unit_extension.is_none() {
+            self.mouse_state.drag_selection_unit = SelectionUnit::Char;
+            self.mouse_state.drag_selection_origin = None;
+            self.mouse_state.unit_selection = None;
+        }
+
+        let (new_anchor, target_position) = if let Some((anchor, position)) = unit_extension {
+            self.mouse_state.unit_selection = Some((anchor, position));
+            (Some(anchor), position)
+        } else if extend_selection {
+            (Some(old_anchor.unwrap_or(old_position)), target_position)
         } else if deselect_on_move {
-            None
+            (None, target_position)
         } else {
-            old_anchor
+            (old_anchor, target_position)
         };
 
         let new_sticky_column = self
@@ -2866,6 +3306,22 @@ impl Editor {
     /// Start the language selection prompt
     fn start_set_language_prompt(&mut self) {
         let current_language = self.active_state().language.clone();
+        self.start_language_prompt(
+            "Language: ".to_string(),
+            PromptType::SetLanguage,
+            Some(current_language),
+        );
+    }
+
+    /// Prompt for one of the available languages, marking
+    /// `current_language` (a config language ID) as current
+    fn start_language_prompt(
+        &mut self,
+        message: String,
+        prompt_type: PromptType,
+        current_language: Option<String>,
+    ) {
+        let current_language = current_language.unwrap_or_default();
 
         // Build suggestions from all available syntect syntaxes + Plain Text option
         let mut suggestions: Vec<crate::input::commands::Suggestion> = vec![
@@ -2922,8 +3378,8 @@ impl Editor {
         let current_index = current_index_found.unwrap_or(0);
 
         self.prompt = Some(crate::view::prompt::Prompt::with_suggestions(
-            "Language: ".to_string(),
-            PromptType::SetLanguage,
+            message,
+            prompt_type,
             suggestions,
         ));
 
@@ -2953,9 +3409,9 @@ impl Editor {
                 let is_current = info.name == *current_theme_name;
                 let description = match (is_current, info.pack.is_empty()) {
                     (true, true) => Some("(current)".to_string()),
-                    (true, false) => Some(format!("{} (current)", info.pack)),
+                    (true, false) => Some(format!("{} (current)", info.pack_label())),
                     (false, true) => None,
-                    (false, false) => Some(info.pack.clone()),
+                    (false, false) => Some(info.pack_label()),
                 };
                 crate::input::commands::Suggestion {
                     text: info.name.clone(),
@@ -2989,7 +3445,7 @@ impl Editor {
     /// Apply a theme by name and persist it to config
     pub(super) fn apply_theme(&mut self, theme_name: &str) {
         if !theme_name.is_empty() {
-            if let Some(theme) = self.theme_registry.get_cloned(theme_name) {
+            if let Some(theme) = self.load_theme(theme_name) {
                 self.theme = theme;
 
                 // Set terminal cursor color to match theme
@@ -3017,7 +3473,7 @@ impl Editor {
     /// Used for live preview when navigating theme selection
     pub(super) fn preview_theme(&mut self, theme_name: &str) {
         if !theme_name.is_empty() && theme_name != self.theme.name {
-            if let Some(theme) = self.theme_registry.get_cloned(theme_name) {
+            if let Some(theme) = self.load_theme(theme_name) {
                 self.theme = theme;
                 self.theme.set_terminal_cursor_color();
             }
@@ -3382,13 +3838,7 @@ impl Editor {
             // Update the config in memory
             self.config.locale = crate::config::LocaleName(Some(locale_name.to_string()));
 
-            // Regenerate menus with the new locale
-            self.menus = crate::config::MenuConfig::translated();
-
-            // Refresh command palette commands with new locale
-            if let Ok(mut registry) = self.command_registry.write() {
-                registry.refresh_builtin_commands();
-            }
+            self.refresh_translated_ui();
 
             // Persist to config file
             self.save_locale_to_config();
@@ -3397,6 +3847,51 @@ impl Editor {
         }
     }
 
+    /// Rebuild the UI text that is translated once rather than on every render
+    ///
+    /// Call after the locale or `highlight_missing_translations` changes.
+    pub(super) fn refresh_translated_ui(&mut self) {
+        crate::i18n::set_highlight_missing(self.config.highlight_missing_translations);
+
+        // Regenerate menus with the new locale
+        self.menus = crate::config::MenuConfig::translated();
+
+        // Refresh command palette commands with new locale
+        if let Ok(mut registry) = self.command_registry.write() {
+            registry.refresh_builtin_commands();
+        }
+
+        // Settings labels are translated when the panel state is built; a hidden
+        // panel without pending edits is rebuilt on next open
+        if self
+            .settings_state
+            .as_ref()
+            .is_some_and(|state| !state.visible && !state.has_changes())
+        {
+            self.settings_state = None;
+        }
+
+        // Format the clock for the new locale on the next tick
+        self.status_bar_refreshed_at = None;
+    }
+
+    /// Apply `config.locale` after the config changed, auto-detecting when unset
+    pub(super) fn apply_config_locale(&mut self, old_locale: &crate::config::LocaleName) {
+        if *old_locale != self.config.locale {
+            match self.config.locale.as_option() {
+                Some(locale) => {
+                    crate::i18n::init_with_config(Some(locale));
+                    tracing::info!("Locale changed to '{}'", locale);
+                }
+                None => {
+                    crate::i18n::init();
+                    tracing::info!("Locale reset to auto-detect");
+                }
+            }
+        }
+        self.refresh_translated_ui();
+    }
+
     /// Save the current locale setting to the user's config file
     fn save_locale_to_config(&mut self) {
         // Create the directory if it doesn't exist
@@ -3429,14 +3924,7 @@ impl Editor {
 
             if is_valid && prev_id != self.active_buffer() {
                 // Save current position before switching
-                self.position_history.commit_pending_movement();
-
-                let cursors = self.active_cursors();
-                let position = cursors.primary().position;
-                let anchor = cursors.primary().anchor;
-                self.position_history
-                    .record_movement(self.active_buffer(), position, anchor);
-                self.position_history.commit_pending_movement();
+                self.record_jump();
 
                 self.set_active_buffer(prev_id);
             } else if !is_valid {
@@ -3529,14 +4017,7 @@ impl Editor {
 
         if buffer_id != self.active_buffer() {
             // Save current position before switching
-            self.position_history.commit_pending_movement();
-
-            let cursors = self.active_cursors();
-            let position = cursors.primary().position;
-            let anchor = cursors.primary().anchor;
-            self.position_history
-                .record_movement(self.active_buffer(), position, anchor);
-            self.position_history.commit_pending_movement();
+            self.record_jump();
 
             self.set_active_buffer(buffer_id);
         }
@@ -3610,6 +4091,29 @@ impl Editor {
         Ok(())
     }
 
+    /// Open the Save As prompt with the current file name as default
+    fn start_save_as_prompt(&mut self) {
+        // Get current filename as default suggestion
+        let current_path = self
+            .active_state()
+            .buffer
+            .file_path()
+            .map(|p| {
+                // Make path relative to working_dir if possible
+                p.strip_prefix(&self.working_dir)
+                    .unwrap_or(p)
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .unwrap_or_default();
+        self.start_prompt_with_initial_text(
+            t!("file.save_as_prompt").to_string(),
+            PromptType::SaveFileAs,
+            current_path,
+        );
+        self.init_file_open_state();
+    }
+
     /// Apply an action by converting it to events.
     ///
     /// This is the catch-all handler for actions that can be converted to buffer events
@@ -3641,6 +4145,8 @@ impl Editor {
                 | Action::MoveLineDown
                 | Action::DedentSelection
                 | Action::ToggleComment
+                | Action::ToggleBlockComment
+                | Action::AlignCursors
         );
 
         if is_editing_action && self.is_editing_disabled() {
@@ -3649,6 +4155,12 @@ impl Editor {
         }
 
         if let Some(events) = self.action_to_events(action) {
+            // Catch edits not listed above (e.g. transpose, case changes)
+            if self.is_editing_disabled() && events.iter().any(Event::modifies_buffer) {
+                self.set_status_message(t!("buffer.editing_disabled").to_string());
+                return Ok(());
+            }
+
             if events.len() > 1 {
                 // Check if this batch contains buffer modifications
                 let has_buffer_mods = events
@@ -3688,21 +4200,21 @@ impl Editor {
 
         Ok(())
     }
+}
 
-    /// Track cursor movement in position history if applicable.
-    pub(super) fn track_cursor_movement(&mut self, event: &Event) {
-        if self.in_navigation {
-            return;
-        }
-
-        if let Event::MoveCursor {
-            new_position,
-            new_anchor,
-            ..
-        } = event
-        {
-            self.position_history
-                .record_movement(self.active_buffer(), *new_position, *new_anchor);
-        }
+/// Length of the longest of the `count` lines starting at `top_byte`
+fn longest_line_length(
+    buffer: &mut crate::model::buffer::Buffer,
+    top_byte: usize,
+    count: usize,
+) -> usize {
+    let mut iter = buffer.line_iterator(top_byte, 80);
+    let mut longest = 0;
+    for _ in 0..count {
+        let Some((_, content)) = iter.next_line() else {
+            break;
+        };
+        longest = longest.max(content.trim_end_matches(['\n', '\r']).len());
     }
+    longest
 }
@@ -259,6 +259,9 @@ impl Editor {
                     }
                 }
             }
+            DeferredAction::CloseBufferFromPicker => {
+                self.close_buffer_from_picker();
+            }
             DeferredAction::PromptSelectionChanged { selected_index } => {
                 // Fire hook for plugin prompts so they can update live preview
                 if let Some(prompt) = &self.prompt {
@@ -396,7 +399,7 @@ impl Editor {
             }
             DeferredAction::CancelInteractiveReplace => {
                 self.cancel_prompt();
-                self.interactive_replace_state = None;
+                self.end_interactive_replace();
             }
 
             // Terminal mode actions
@@ -0,0 +1,315 @@
+//! Recording terminal input as a replayable script.
+//!
+//! `fresh --record-input session.fresh` writes every key, mouse, paste and
+//! resize event to a script in the same line-based format the e2e test
+//! harness replays (`tests/common/input_script.rs`), so a reproduction from a
+//! bug report can be dropped into a test and extended with `expect_screen`
+//! checkpoints:
+//!
+//! ```text
+//! resize 120 40
+//! key Ctrl+P
+//! type "sort lines"
+//! key Enter
+//! wait 1500
+//! ```
+//!
+//! Consecutive unmodified characters are written as one `type` line and runs
+//! of mouse moves as their last position. Each line is written as soon as it
+//! is complete, so the script survives a crash.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{
+    Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton,
+    MouseEvent, MouseEventKind,
+};
+
+use super::Editor;
+
+/// Pauses shorter than this are not written as `wait`
+const MIN_RECORDED_WAIT: Duration = Duration::from_millis(500);
+
+/// Longer pauses are recorded as this long
+const MAX_RECORDED_WAIT: Duration = Duration::from_secs(5);
+
+/// Writes input events to a script file
+pub(crate) struct InputRecorder {
+    file: File,
+    last_event: Instant,
+    /// Characters typed since the last other event
+    pending_text: String,
+    /// Position of the last mouse move not written yet
+    pending_move: Option<(u16, u16)>,
+}
+
+impl InputRecorder {
+    fn create(path: &Path, width: u16, height: u16) -> io::Result<Self> {
+        let mut recorder = Self {
+            file: File::create(path)?,
+            last_event: Instant::now(),
+            pending_text: String::new(),
+            pending_move: None,
+        };
+        recorder.write_line(&format!(
+            "# Recorded by fresh {}",
+            env!("CARGO_PKG_VERSION")
+        ))?;
+        recorder.write_line(&format!("resize {} {}", width, height))?;
+        Ok(recorder)
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        writeln!(self.file, "{}", line)
+    }
+
+    /// Write the typed text and mouse move held back for coalescing
+    fn flush_pending(&mut self) -> io::Result<()> {
+        if !self.pending_text.is_empty() {
+            let text = std::mem::take(&mut self.pending_text);
+            self.write_line(&format!("type {}", quote(&text)))?;
+        }
+        if let Some((col, row)) = self.pending_move.take() {
+            self.write_line(&format!("mouse move {} {}", col, row))?;
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, event: &CrosstermEvent) -> io::Result<()> {
+        let line = match event {
+            CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => {
+                if let Some(ch) = typed_char(key) {
+                    if let Some((col, row)) = self.pending_move.take() {
+                        self.write_line(&format!("mouse move {} {}", col, row))?;
+                    }
+                    self.record_wait()?;
+                    self.pending_text.push(ch);
+                    return Ok(());
+                }
+                format_key(key.code, key.modifiers).map(|combo| format!("key {}", combo))
+            }
+            CrosstermEvent::Mouse(mouse) if mouse.kind == MouseEventKind::Moved => {
+                if !self.pending_text.is_empty() {
+                    self.flush_pending()?;
+                }
+                self.record_wait()?;
+                self.pending_move = Some((mouse.column, mouse.row));
+                return Ok(());
+            }
+            CrosstermEvent::Mouse(mouse) => Some(format_mouse(mouse)),
+            CrosstermEvent::Paste(text) => Some(format!("paste {}", quote(text))),
+            CrosstermEvent::Resize(width, height) => Some(format!("resize {} {}", width, height)),
+            _ => None,
+        };
+        let Some(line) = line else {
+            return Ok(());
+        };
+        self.flush_pending()?;
+        self.record_wait()?;
+        self.write_line(&line)
+    }
+
+    /// Write a `wait` for a long pause, which usually lets async work finish
+    fn record_wait(&mut self) -> io::Result<()> {
+        let now = Instant::now();
+        let pause = now.duration_since(self.last_event);
+        self.last_event = now;
+        if pause < MIN_RECORDED_WAIT {
+            return Ok(());
+        }
+        self.flush_pending()?;
+        let millis = pause.min(MAX_RECORDED_WAIT).as_millis() / 100 * 100;
+        self.write_line(&format!("wait {}", millis))
+    }
+}
+
+impl Drop for InputRecorder {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush_pending() {
+            tracing::warn!("Failed to finish input recording: {}", e);
+        }
+    }
+}
+
+/// The character a key press types, if it has no other effect
+fn typed_char(key: &KeyEvent) -> Option<char> {
+    match key.code {
+        KeyCode::Char(ch) if key.modifiers.difference(KeyModifiers::SHIFT).is_empty() => Some(ch),
+        _ => None,
+    }
+}
+
+/// A key as written in scripts, e.g. `Ctrl+Shift+Left` or `"Ctrl+;"`
+///
+/// None for keys scripts can't express.
+pub fn format_key(code: KeyCode, modifiers: KeyModifiers) -> Option<String> {
+    let key = match code {
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => "BackTab".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(ch) => ch.to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        _ => return None,
+    };
+    let combo = format!("{}{}", modifier_prefix(modifiers), key);
+    if combo.contains(|c: char| c == ';' || c == '#' || c == '"' || c.is_whitespace()) {
+        Some(quote(&combo))
+    } else {
+        Some(combo)
+    }
+}
+
+/// Modifiers as written before a key, e.g. `Ctrl+Shift+`
+fn modifier_prefix(modifiers: KeyModifiers) -> String {
+    [
+        (KeyModifiers::CONTROL, "Ctrl+"),
+        (KeyModifiers::ALT, "Alt+"),
+        (KeyModifiers::SHIFT, "Shift+"),
+        (KeyModifiers::SUPER, "Super+"),
+    ]
+    .into_iter()
+    .filter(|(modifier, _)| modifiers.contains(*modifier))
+    .map(|(_, name)| name)
+    .collect()
+}
+
+/// A mouse event as written in scripts, e.g. `mouse down left 10 4`
+fn format_mouse(mouse: &MouseEvent) -> String {
+    let button = |button: &MouseButton| match button {
+        MouseButton::Left => "left",
+        MouseButton::Right => "right",
+        MouseButton::Middle => "middle",
+    };
+    let kind = match &mouse.kind {
+        MouseEventKind::Down(b) => format!("down {}", button(b)),
+        MouseEventKind::Up(b) => format!("up {}", button(b)),
+        MouseEventKind::Drag(b) => format!("drag {}", button(b)),
+        MouseEventKind::Moved => "move".to_string(),
+        MouseEventKind::ScrollUp => "scroll_up".to_string(),
+        MouseEventKind::ScrollDown => "scroll_down".to_string(),
+        MouseEventKind::ScrollLeft => "scroll_left".to_string(),
+        MouseEventKind::ScrollRight => "scroll_right".to_string(),
+    };
+    let mut line = format!("mouse {} {} {}", kind, mouse.column, mouse.row);
+    if !mouse.modifiers.is_empty() {
+        line.push(' ');
+        line.push_str(modifier_prefix(mouse.modifiers).trim_end_matches('+'));
+    }
+    line
+}
+
+/// A string literal in script syntax
+pub(super) fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+impl Editor {
+    /// Record all input from now on to a script at `path`
+    pub fn start_input_recording(&mut self, path: &Path) -> io::Result<()> {
+        self.input_recorder = Some(InputRecorder::create(
+            path,
+            self.terminal_width,
+            self.terminal_height,
+        )?);
+        Ok(())
+    }
+
+    /// Record a terminal event if input recording is on
+    pub fn record_input(&mut self, event: &CrosstermEvent) {
+        let Some(recorder) = self.input_recorder.as_mut() else {
+            return;
+        };
+        if let Err(e) = recorder.record(event) {
+            tracing::warn!("Stopping input recording: {}", e);
+            self.input_recorder = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_key() {
+        assert_eq!(
+            format_key(KeyCode::Char('p'), KeyModifiers::CONTROL).as_deref(),
+            Some("Ctrl+p")
+        );
+        assert_eq!(
+            format_key(KeyCode::Left, KeyModifiers::CONTROL | KeyModifiers::SHIFT).as_deref(),
+            Some("Ctrl+Shift+Left")
+        );
+        assert_eq!(
+            format_key(KeyCode::Char(';'), KeyModifiers::CONTROL).as_deref(),
+            Some("\"Ctrl+;\"")
+        );
+        assert_eq!(format_key(KeyCode::Null, KeyModifiers::NONE), None);
+    }
+
+    #[test]
+    fn test_recording_coalesces_typing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.fresh");
+        let mut recorder = InputRecorder::create(&path, 80, 24).unwrap();
+        let press = |code, modifiers| CrosstermEvent::Key(KeyEvent::new(code, modifiers));
+
+        for event in [
+            press(KeyCode::Char('h'), KeyModifiers::NONE),
+            press(KeyCode::Char('I'), KeyModifiers::SHIFT),
+            press(KeyCode::Char('"'), KeyModifiers::NONE),
+            press(KeyCode::Enter, KeyModifiers::NONE),
+            CrosstermEvent::Paste("a\nb".to_string()),
+            CrosstermEvent::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column: 3,
+                row: 2,
+                modifiers: KeyModifiers::SHIFT,
+            }),
+        ] {
+            recorder.record(&event).unwrap();
+        }
+        drop(recorder);
+
+        let script = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = script.lines().skip(1).collect();
+        assert_eq!(
+            lines,
+            [
+                "resize 80 24",
+                "type \"hI\\\"\"",
+                "key Enter",
+                "paste \"a\\nb\"",
+                "mouse down left 3 2 Shift",
+            ]
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Jump list (Navigate Back/Forward) for the Editor.
+//!
+//! This module contains the editor side of the per-split jump list:
+//! - Recording the position left behind by a far jump
+//! - Walking the list with Navigate Back and Navigate Forward
+//! - Reopening buffers that were closed since the jump was recorded
+
+use crate::input::position_history::{PositionEntry, PositionHistory, FAR_JUMP_LINES};
+use crate::model::event::{BufferId, Event};
+
+use super::Editor;
+
+impl Editor {
+    /// Get the jump list of the active split
+    pub fn jump_list(&self) -> &PositionHistory {
+        let split_id = self.split_manager.active_split();
+        &self.split_view_states.get(&split_id).unwrap().jump_list
+    }
+
+    /// Record the primary cursor's position before a far jump
+    ///
+    /// Called before buffer switches, go to line, search confirmation and
+    /// go to definition. Does nothing while navigating the list itself.
+    pub(super) fn record_jump(&mut self) {
+        let cursors = self.active_cursors();
+        let position = cursors.primary().position;
+        let anchor = cursors.primary().anchor;
+        self.push_jump(self.active_buffer(), position, anchor);
+    }
+
+    /// Track cursor movement in position history if applicable.
+    ///
+    /// A move of the primary cursor across more than `FAR_JUMP_LINES` lines
+    /// records where it came from.
+    pub(super) fn track_cursor_movement(&mut self, event: &Event) {
+        if self.in_navigation {
+            return;
+        }
+
+        if let Event::MoveCursor {
+            cursor_id,
+            old_position,
+            new_position,
+            old_anchor,
+            ..
+        } = event
+        {
+            if *cursor_id != self.active_cursors().primary_id() {
+                return;
+            }
+            let buffer_id = self.active_buffer();
+            let Some(state) = self.buffers.get(&buffer_id) else {
+                return;
+            };
+            let old_line = state.buffer.get_line_number(*old_position);
+            let new_line = state.buffer.get_line_number(*new_position);
+            if old_line.abs_diff(new_line) > FAR_JUMP_LINES {
+                self.push_jump(buffer_id, *old_position, *old_anchor);
+            }
+        }
+    }
+
+    /// Push an entry onto the active split's jump list
+    fn push_jump(&mut self, buffer_id: BufferId, position: usize, anchor: Option<usize>) {
+        if self.in_navigation {
+            return;
+        }
+        let path = self
+            .buffers
+            .get(&buffer_id)
+            .and_then(|state| state.buffer.file_path())
+            .map(|path| path.to_path_buf());
+        let entry = PositionEntry::new(buffer_id, position, anchor).with_path(path);
+        let split_id = self.split_manager.active_split();
+        if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
+            view_state.jump_list.push(entry);
+        }
+    }
+
+    /// Navigate back in position history
+    pub fn navigate_back(&mut self) {
+        // If we're at the end of history (haven't used back yet), save current position
+        // so we can navigate forward to it later
+        let jump_list = self.jump_list();
+        if !jump_list.is_empty() && !jump_list.can_go_forward() {
+            self.record_jump();
+        }
+
+        // Set flag to prevent recording this navigation movement
+        self.in_navigation = true;
+
+        // Skip entries whose buffer can't be brought back
+        while let Some(entry) = self.active_jump_list_mut().back().cloned() {
+            if self.goto_jump_entry(entry) {
+                break;
+            }
+        }
+
+        // Clear the flag
+        self.in_navigation = false;
+    }
+
+    /// Navigate forward in position history
+    pub fn navigate_forward(&mut self) {
+        // Set flag to prevent recording this navigation movement
+        self.in_navigation = true;
+
+        while let Some(entry) = self.active_jump_list_mut().forward().cloned() {
+            if self.goto_jump_entry(entry) {
+                break;
+            }
+        }
+
+        // Clear the flag
+        self.in_navigation = false;
+    }
+
+    fn active_jump_list_mut(&mut self) -> &mut PositionHistory {
+        let split_id = self.split_manager.active_split();
+        &mut self.split_view_states.get_mut(&split_id).unwrap().jump_list
+    }
+
+    /// Move to a jump list entry, reopening its file if the buffer was closed
+    ///
+    /// Returns false when the entry's buffer is gone and can't be reopened.
+    fn goto_jump_entry(&mut self, entry: PositionEntry) -> bool {
+        let target_buffer = if self.buffers.contains_key(&entry.buffer_id) {
+            entry.buffer_id
+        } else {
+            let Some(path) = entry.path.as_deref() else {
+                return false;
+            };
+            match self.open_file(path) {
+                Ok(buffer_id) => {
+                    for view_state in self.split_view_states.values_mut() {
+                        view_state
+                            .jump_list
+                            .rebind_buffer(entry.buffer_id, buffer_id);
+                    }
+                    buffer_id
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to reopen {} from jump list: {}", path.display(), e);
+                    return false;
+                }
+            }
+        };
+
+        // Switch to the target buffer
+        self.set_active_buffer(target_buffer);
+
+        // Move cursor to the saved position, clamped in case the file shrank
+        let buffer_len = self
+            .buffers
+            .get(&target_buffer)
+            .map_or(0, |state| state.buffer.len());
+        let cursors = self.active_cursors();
+        let cursor_id = cursors.primary_id();
+        let old_position = cursors.primary().position;
+        let old_anchor = cursors.primary().anchor;
+        let old_sticky_column = cursors.primary().sticky_column;
+        let event = Event::MoveCursor {
+            cursor_id,
+            old_position,
+            new_position: entry.position.min(buffer_len),
+            old_anchor,
+            new_anchor: entry.anchor.map(|anchor| anchor.min(buffer_len)),
+            old_sticky_column,
+            new_sticky_column: 0, // Reset sticky column for navigation
+        };
+        let split_id = self.split_manager.active_split();
+        let state = self.buffers.get_mut(&target_buffer).unwrap();
+        let view_state = self.split_view_states.get_mut(&split_id).unwrap();
+        state.apply(&mut view_state.cursors, &event);
+        true
+    }
+}
@@ -1,9 +1,12 @@
 //! KeybindingEditor - the main editor state and logic.
 
-use super::helpers::{format_chord_keys, key_code_to_config_name, modifiers_to_config_names};
+use super::helpers::{
+    format_chord_keys, format_key_sequence, key_code_to_config_name, key_sequence_to_config,
+    modifiers_to_config_names, same_binding,
+};
 use super::types::*;
 use crate::config::{Config, Keybinding};
-use crate::input::keybindings::{format_keybinding, Action, KeybindingResolver};
+use crate::input::keybindings::{format_keybinding, Action, KeyContext, KeybindingResolver};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use rust_i18n::t;
 use std::collections::HashMap;
@@ -167,14 +170,21 @@ impl KeybindingEditor {
                     key_code: KeyCode::Null,
                     modifiers: KeyModifiers::NONE,
                     is_chord: false,
+                    chord_keys: Vec::new(),
                 });
             }
         }
 
-        // Sort by context, then by action name
+        // Group by context (unbound actions first), then sort by action name
+        let context_rank = |context: &str| {
+            CONTEXTS
+                .iter()
+                .position(|c| *c == context)
+                .map_or(0, |i| i + 1)
+        };
         bindings.sort_by(|a, b| {
-            a.context
-                .cmp(&b.context)
+            context_rank(&a.context)
+                .cmp(&context_rank(&b.context))
                 .then(a.action_display.cmp(&b.action_display))
         });
 
@@ -187,12 +197,21 @@ impl KeybindingEditor {
         source: BindingSource,
         _resolver: &KeybindingResolver,
     ) -> Option<ResolvedBinding> {
-        let context = kb.when.as_deref().unwrap_or("normal").to_string();
+        let context = KeyContext::for_binding(kb).to_context_name().to_string();
 
         if !kb.keys.is_empty() {
             // Chord binding
             let key_display = format_chord_keys(&kb.keys);
             let action_display = KeybindingResolver::format_action_from_str(&kb.action);
+            let chord_keys = kb
+                .keys
+                .iter()
+                .filter_map(|kp| {
+                    let key_code = KeybindingResolver::parse_key_public(&kp.key)?;
+                    let modifiers = KeybindingResolver::parse_modifiers_public(&kp.modifiers);
+                    Some((key_code, modifiers))
+                })
+                .collect();
             Some(ResolvedBinding {
                 key_display,
                 action: kb.action.clone(),
@@ -202,6 +221,7 @@ impl KeybindingEditor {
                 key_code: KeyCode::Null,
                 modifiers: KeyModifiers::NONE,
                 is_chord: true,
+                chord_keys,
             })
         } else if !kb.key.is_empty() {
             // Single key binding
@@ -218,6 +238,7 @@ impl KeybindingEditor {
                 key_code,
                 modifiers,
                 is_chord: false,
+                chord_keys: Vec::new(),
             })
         } else {
             None
@@ -303,6 +324,9 @@ impl KeybindingEditor {
                             let query = self.search_query.to_lowercase();
                             let matches = binding.action.to_lowercase().contains(&query)
                                 || binding.action_display.to_lowercase().contains(&query)
+                                || KeybindingResolver::format_action_english(&binding.action)
+                                    .to_lowercase()
+                                    .contains(&query)
                                 || binding.key_display.to_lowercase().contains(&query)
                                 || binding.context.to_lowercase().contains(&query);
                             if !matches {
@@ -430,16 +454,13 @@ impl KeybindingEditor {
 
     /// Cycle context filter
     pub fn cycle_context_filter(&mut self) {
-        let contexts = vec![
-            ContextFilter::All,
-            ContextFilter::Specific("global".to_string()),
-            ContextFilter::Specific("normal".to_string()),
-            ContextFilter::Specific("prompt".to_string()),
-            ContextFilter::Specific("popup".to_string()),
-            ContextFilter::Specific("file_explorer".to_string()),
-            ContextFilter::Specific("menu".to_string()),
-            ContextFilter::Specific("terminal".to_string()),
-        ];
+        let contexts: Vec<ContextFilter> = std::iter::once(ContextFilter::All)
+            .chain(
+                CONTEXTS
+                    .iter()
+                    .map(|c| ContextFilter::Specific(c.to_string())),
+            )
+            .collect();
 
         let current_idx = contexts
             .iter()
@@ -465,6 +486,31 @@ impl KeybindingEditor {
         self.edit_dialog = Some(EditBindingState::new_add());
     }
 
+    /// Show the bindings of an action and open the add dialog for it,
+    /// waiting for the key to bind
+    pub fn bind_action(&mut self, action_name: &str) {
+        self.search_active = true;
+        self.search_focused = false;
+        self.search_mode = SearchMode::Text;
+        self.search_query = action_name.to_string();
+        self.apply_filters();
+
+        let mut dialog = EditBindingState::new_add();
+        dialog.action_text = action_name.to_string();
+        dialog.action_cursor = action_name.len();
+        // Bind in the context the action is already bound in, if any
+        let context_index = self
+            .bindings
+            .iter()
+            .find(|b| b.action == action_name && !b.context.is_empty())
+            .and_then(|b| dialog.context_options.iter().position(|c| *c == b.context));
+        if let Some(index) = context_index {
+            dialog.context_option_index = index;
+            dialog.context = dialog.context_options[index].clone();
+        }
+        self.edit_dialog = Some(dialog);
+    }
+
     /// Open the edit binding dialog for the selected binding
     pub fn open_edit_dialog(&mut self) {
         if let Some(binding) = self.selected_binding().cloned() {
@@ -503,12 +549,10 @@ impl KeybindingEditor {
                 // If this binding was added in the current session, just
                 // remove it from pending_adds. Otherwise track for removal
                 // from the persisted config.
-                let found_in_adds = self.pending_adds.iter().position(|kb| {
-                    kb.action == config_kb.action
-                        && kb.key == config_kb.key
-                        && kb.modifiers == config_kb.modifiers
-                        && kb.when == config_kb.when
-                });
+                let found_in_adds = self
+                    .pending_adds
+                    .iter()
+                    .position(|kb| same_binding(kb, &config_kb));
                 if let Some(pos) = found_in_adds {
                     self.pending_adds.remove(pos);
                 } else {
@@ -531,6 +575,7 @@ impl KeybindingEditor {
                         key_code: KeyCode::Null,
                         modifiers: KeyModifiers::NONE,
                         is_chord: false,
+                        chord_keys: Vec::new(),
                     });
                 }
 
@@ -553,14 +598,11 @@ impl KeybindingEditor {
                     } else {
                         modifiers_to_config_names(binding.modifiers)
                     },
-                    keys: Vec::new(),
+                    keys: key_sequence_to_config(&binding.chord_keys),
                     action: "noop".to_string(),
                     args: HashMap::new(),
-                    when: if binding.context.is_empty() {
-                        None
-                    } else {
-                        Some(binding.context.clone())
-                    },
+                    when: None,
+                    context: Some(binding.context.clone()),
                 };
                 self.pending_adds.push(noop_kb);
 
@@ -575,6 +617,7 @@ impl KeybindingEditor {
                     key_code: self.bindings[idx].key_code,
                     modifiers: self.bindings[idx].modifiers,
                     is_chord: self.bindings[idx].is_chord,
+                    chord_keys: self.bindings[idx].chord_keys.clone(),
                 };
                 self.has_changes = true;
 
@@ -591,6 +634,7 @@ impl KeybindingEditor {
                         key_code: KeyCode::Null,
                         modifiers: KeyModifiers::NONE,
                         is_chord: false,
+                        chord_keys: Vec::new(),
                     });
                 }
 
@@ -614,14 +658,11 @@ impl KeybindingEditor {
             } else {
                 modifiers_to_config_names(binding.modifiers)
             },
-            keys: Vec::new(),
+            keys: key_sequence_to_config(&binding.chord_keys),
             action: binding.action.clone(),
             args: HashMap::new(),
-            when: if binding.context.is_empty() {
-                None
-            } else {
-                Some(binding.context.clone())
-            },
+            when: None,
+            context: Some(binding.context.clone()),
         }
     }
 
@@ -633,7 +674,7 @@ impl KeybindingEditor {
             None => return None,
         };
 
-        if dialog.key_code.is_none() || dialog.action_text.is_empty() {
+        if dialog.keys.is_empty() || dialog.action_text.is_empty() {
             self.edit_dialog = Some(dialog);
             return Some(t!("keybinding_editor.error_key_action_required").to_string());
         }
@@ -657,18 +698,31 @@ impl KeybindingEditor {
             return Some(err_msg);
         }
 
-        let key_code = dialog.key_code.unwrap();
-        let modifiers = dialog.modifiers;
-        let key_name = key_code_to_config_name(key_code);
-        let modifier_names = modifiers_to_config_names(modifiers);
+        // A single key is stored as key + modifiers, a sequence as a chord
+        let is_chord = dialog.keys.len() > 1;
+        let (key_code, modifiers) = if is_chord {
+            (KeyCode::Null, KeyModifiers::NONE)
+        } else {
+            dialog.keys[0]
+        };
+        let chord_keys = if is_chord {
+            dialog.keys.clone()
+        } else {
+            Vec::new()
+        };
 
         let new_binding = Keybinding {
-            key: key_name,
-            modifiers: modifier_names,
-            keys: Vec::new(),
+            key: if is_chord {
+                String::new()
+            } else {
+                key_code_to_config_name(key_code)
+            },
+            modifiers: modifiers_to_config_names(modifiers),
+            keys: key_sequence_to_config(&chord_keys),
             action: dialog.action_text.clone(),
             args: HashMap::new(),
-            when: Some(dialog.context.clone()),
+            when: None,
+            context: Some(dialog.context.clone()),
         };
 
         // Add as custom binding
@@ -676,7 +730,7 @@ impl KeybindingEditor {
         self.has_changes = true;
 
         // Update display
-        let key_display = format_keybinding(&key_code, &modifiers);
+        let key_display = format_key_sequence(&dialog.keys);
         let action_display = KeybindingResolver::format_action_from_str(&dialog.action_text);
 
         let resolved = ResolvedBinding {
@@ -687,7 +741,8 @@ impl KeybindingEditor {
             source: BindingSource::Custom,
             key_code,
             modifiers,
-            is_chord: false,
+            is_chord,
+            chord_keys,
         };
 
         if let Some(edit_idx) = dialog.editing_index {
@@ -704,32 +759,53 @@ impl KeybindingEditor {
         None
     }
 
-    /// Check for conflicts with the given key combination
-    pub fn find_conflicts(
-        &self,
-        key_code: KeyCode,
-        modifiers: KeyModifiers,
-        context: &str,
-    ) -> Vec<String> {
+    /// Check for conflicts with the given key sequence in the given context.
+    ///
+    /// Only bindings of the same context collide: a context binding takes
+    /// precedence over a global one instead of clashing with it. Besides
+    /// identical bindings, this reports bindings that are a prefix of the
+    /// sequence or that the sequence is a prefix of, since one of the two
+    /// could never be reached.
+    pub fn find_conflicts(&self, keys: &[(KeyCode, KeyModifiers)], context: &str) -> Vec<String> {
         let mut conflicts = Vec::new();
+        if keys.is_empty() {
+            return conflicts;
+        }
 
         for binding in &self.bindings {
-            if !binding.is_chord
-                && binding.key_code == key_code
-                && binding.modifiers == modifiers
-                && (binding.context == context
-                    || binding.context == "global"
-                    || context == "global")
-            {
+            if binding.source == BindingSource::Unbound {
+                continue;
+            }
+            if binding.context != context {
+                continue;
+            }
+
+            let single = [(binding.key_code, binding.modifiers)];
+            let existing: &[(KeyCode, KeyModifiers)] = if binding.is_chord {
+                &binding.chord_keys
+            } else {
+                &single
+            };
+            let shared = existing.len().min(keys.len());
+            if shared == 0 || existing[..shared] != keys[..shared] {
+                continue;
+            }
+
+            let source = if binding.source == BindingSource::Custom {
+                "custom"
+            } else {
+                "keymap"
+            };
+            if existing.len() == keys.len() {
                 conflicts.push(format!(
                     "{} ({}, {})",
-                    binding.action_display,
-                    binding.context,
-                    if binding.source == BindingSource::Custom {
-                        "custom"
-                    } else {
-                        "keymap"
-                    }
+                    binding.action_display, binding.context, source
+                ));
+            } else {
+                // Prefix overlap: name the other sequence so it can be found
+                conflicts.push(format!(
+                    "{}: {} ({}, {})",
+                    binding.key_display, binding.action_display, binding.context, source
                 ));
             }
         }
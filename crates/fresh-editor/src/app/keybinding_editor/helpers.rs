@@ -1,7 +1,7 @@
 //! Helper/utility functions for the keybinding editor.
 
-use crate::config::KeyPress;
-use crate::input::keybindings::{format_keybinding, KeybindingResolver};
+use crate::config::{KeyPress, Keybinding};
+use crate::input::keybindings::{format_keybinding, KeyContext, KeybindingResolver};
 use crossterm::event::{KeyCode, KeyModifiers};
 
 /// Format chord keys for display
@@ -16,6 +16,34 @@ pub fn format_chord_keys(keys: &[KeyPress]) -> String {
         .join(" ")
 }
 
+/// Format a recorded key sequence for display (e.g., "Ctrl+K Ctrl+S")
+pub fn format_key_sequence(keys: &[(KeyCode, KeyModifiers)]) -> String {
+    keys.iter()
+        .map(|(code, modifiers)| format_keybinding(code, modifiers))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Convert a key sequence to the config representation of a chord
+pub fn key_sequence_to_config(keys: &[(KeyCode, KeyModifiers)]) -> Vec<KeyPress> {
+    keys.iter()
+        .map(|(code, modifiers)| KeyPress {
+            key: key_code_to_config_name(*code),
+            modifiers: modifiers_to_config_names(*modifiers),
+        })
+        .collect()
+}
+
+/// Whether two config entries describe the same binding. Contexts are
+/// compared after resolution, so `"when": "normal"` matches `"context": "editor"`.
+pub fn same_binding(a: &Keybinding, b: &Keybinding) -> bool {
+    a.action == b.action
+        && a.key == b.key
+        && a.modifiers == b.modifiers
+        && a.keys == b.keys
+        && KeyContext::for_binding(a) == KeyContext::for_binding(b)
+}
+
 /// Convert a KeyCode back to a config-friendly name
 pub fn key_code_to_config_name(key_code: KeyCode) -> String {
     match key_code {
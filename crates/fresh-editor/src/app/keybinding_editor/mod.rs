@@ -9,4 +9,5 @@ mod helpers;
 mod types;
 
 pub use editor::KeybindingEditor;
+pub use helpers::same_binding;
 pub use types::*;
@@ -1,8 +1,17 @@
 //! Data types for the keybinding editor.
 
+use super::helpers::format_key_sequence;
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::layout::Rect;
 
+/// Longest key sequence the add/edit dialog records (e.g. Emacs "C-x r t")
+pub const MAX_SEQUENCE_KEYS: usize = 3;
+
+/// Binding contexts in display order; bindings are grouped in this order
+pub const CONTEXTS: &[&str] = &[
+    "global", "editor", "prompt", "popup", "explorer", "menu", "terminal", "settings",
+];
+
 /// Where a binding comes from
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BindingSource {
@@ -36,7 +45,7 @@ pub struct ResolvedBinding {
     pub action: String,
     /// Human-readable action description (e.g., "Save")
     pub action_display: String,
-    /// Context / when clause (e.g., "normal", "global")
+    /// Context name (e.g., "editor", "global"), empty for unbound actions
     pub context: String,
     /// Where this binding comes from
     pub source: BindingSource,
@@ -46,6 +55,8 @@ pub struct ResolvedBinding {
     pub modifiers: KeyModifiers,
     /// Whether this is a chord (multi-key) binding
     pub is_chord: bool,
+    /// The full key sequence of a chord binding (empty for single keys)
+    pub chord_keys: Vec<(KeyCode, KeyModifiers)>,
 }
 
 /// Mode for the edit/add dialog
@@ -64,10 +75,11 @@ pub enum EditMode {
 pub struct EditBindingState {
     /// The mode of the edit dialog
     pub mode: EditMode,
-    /// The recorded key code (if any)
-    pub key_code: Option<KeyCode>,
-    /// The recorded modifiers
-    pub modifiers: KeyModifiers,
+    /// The recorded key sequence; more than one key makes a chord binding
+    pub keys: Vec<(KeyCode, KeyModifiers)>,
+    /// Whether the next recorded key starts a new sequence instead of
+    /// extending the current one (set when editing an existing binding)
+    pub restart_recording: bool,
     /// The formatted key display
     pub key_display: String,
     /// The action name being edited
@@ -104,24 +116,16 @@ impl EditBindingState {
     pub fn new_add() -> Self {
         Self {
             mode: EditMode::RecordingKey,
-            key_code: None,
-            modifiers: KeyModifiers::NONE,
+            keys: Vec::new(),
+            restart_recording: false,
             key_display: String::new(),
             action_text: String::new(),
             action_cursor: 0,
-            context: "normal".to_string(),
+            context: "editor".to_string(),
             editing_index: None,
             conflicts: Vec::new(),
-            context_options: vec![
-                "global".to_string(),
-                "normal".to_string(),
-                "prompt".to_string(),
-                "popup".to_string(),
-                "file_explorer".to_string(),
-                "menu".to_string(),
-                "terminal".to_string(),
-            ],
-            context_option_index: 1, // default to "normal"
+            context_options: CONTEXTS.iter().map(|c| c.to_string()).collect(),
+            context_option_index: 1, // default to "editor"
             context_dropdown_open: false,
             selected_button: 0,
             focus_area: 0,
@@ -133,28 +137,29 @@ impl EditBindingState {
     }
 
     pub fn new_edit(index: usize, binding: &ResolvedBinding) -> Self {
-        let context_options = vec![
-            "global".to_string(),
-            "normal".to_string(),
-            "prompt".to_string(),
-            "popup".to_string(),
-            "file_explorer".to_string(),
-            "menu".to_string(),
-            "terminal".to_string(),
-        ];
+        let context_options: Vec<String> = CONTEXTS.iter().map(|c| c.to_string()).collect();
         let context_option_index = context_options
             .iter()
             .position(|c| c == &binding.context)
             .unwrap_or(1);
 
+        let keys = if binding.is_chord {
+            binding.chord_keys.clone()
+        } else if binding.key_code == KeyCode::Null {
+            // Unbound action: nothing recorded yet
+            Vec::new()
+        } else {
+            vec![(binding.key_code, binding.modifiers)]
+        };
+
         Self {
             mode: EditMode::RecordingKey,
-            key_code: Some(binding.key_code),
-            modifiers: binding.modifiers,
+            keys,
+            restart_recording: true,
             key_display: binding.key_display.clone(),
             action_text: binding.action.clone(),
             action_cursor: binding.action.len(),
-            context: binding.context.clone(),
+            context: context_options[context_option_index].clone(),
             editing_index: Some(index),
             conflicts: Vec::new(),
             context_options,
@@ -168,6 +173,24 @@ impl EditBindingState {
             action_error: None,
         }
     }
+
+    /// Record a key press verbatim. Keys accumulate into a sequence
+    /// ("Ctrl+K Ctrl+S"); once the sequence is full, the next key starts over.
+    pub fn record_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        if self.restart_recording || self.keys.len() >= MAX_SEQUENCE_KEYS {
+            self.keys.clear();
+        }
+        self.restart_recording = false;
+        self.keys.push((code, modifiers));
+        self.key_display = format_key_sequence(&self.keys);
+    }
+
+    /// Forget the recorded keys
+    pub fn clear_keys(&mut self) {
+        self.keys.clear();
+        self.restart_recording = false;
+        self.key_display.clear();
+    }
 }
 
 /// Search mode for the keybinding editor
@@ -2,12 +2,13 @@
 //!
 //! This module provides the action handlers for the keybinding editor modal.
 
-use super::keybinding_editor::KeybindingEditor;
+use super::keybinding_editor::{same_binding, KeybindingEditor};
 use super::Editor;
 use crate::input::handler::InputResult;
 use crate::view::keybinding_editor::{handle_keybinding_editor_input, KeybindingEditorAction};
 use crate::view::ui::point_in_rect;
 use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use rust_i18n::t;
 
 impl Editor {
     /// Open the keybinding editor modal
@@ -20,6 +21,44 @@ impl Editor {
         ));
     }
 
+    /// Open the keybinding editor ready to bind the command highlighted in
+    /// the command palette
+    pub(super) fn bind_palette_command(&mut self) {
+        let Some(text) = self
+            .prompt
+            .as_ref()
+            .filter(|prompt| prompt.is_command_palette())
+            .and_then(|prompt| {
+                prompt
+                    .selected_suggestion
+                    .and_then(|i| prompt.suggestions.get(i))
+            })
+            .map(|suggestion| suggestion.text.clone())
+        else {
+            self.set_status_message(t!("status.no_selection").to_string());
+            return;
+        };
+
+        let action_name = self
+            .command_registry
+            .read()
+            .unwrap()
+            .get_all()
+            .iter()
+            .find(|cmd| cmd.get_localized_name() == text)
+            .and_then(|cmd| cmd.action.config_name());
+        let Some(action_name) = action_name else {
+            self.set_status_message(t!("status.command_not_bindable", command = text).to_string());
+            return;
+        };
+
+        self.cancel_prompt();
+        self.open_keybinding_editor();
+        if let Some(editor) = self.keybinding_editor.as_mut() {
+            editor.bind_action(&action_name);
+        }
+    }
+
     /// Handle input when keybinding editor is active
     pub fn handle_keybinding_editor_input(&mut self, event: &KeyEvent) -> InputResult {
         let mut editor = match self.keybinding_editor.take() {
@@ -60,12 +99,9 @@ impl Editor {
 
         // Remove deleted custom bindings from config
         for remove in editor.get_pending_removes() {
-            self.config.keybindings.retain(|kb| {
-                !(kb.action == remove.action
-                    && kb.key == remove.key
-                    && kb.modifiers == remove.modifiers
-                    && kb.when == remove.when)
-            });
+            self.config
+                .keybindings
+                .retain(|kb| !same_binding(kb, remove));
         }
 
         // Add new custom bindings
@@ -0,0 +1,112 @@
+//! Setting a buffer's language by hand.
+//!
+//! "Set Language" overrides the language detected from a file's path and
+//! content. Changing it swaps the highlighter, re-applies the language's tab
+//! and whitespace settings, and reopens the document on the LSP server for
+//! the new language. The override is remembered in the workspace.
+
+use super::Editor;
+use crate::config::{Config, WhitespaceVisibility};
+use crate::model::event::BufferId;
+use crate::primitives::detected_language::DetectedLanguage;
+use crate::services::plugins::hooks::HookArgs;
+use crate::state::EditorState;
+
+/// Language picker entry for no highlighting
+pub(super) const PLAIN_TEXT: &str = "Plain Text";
+
+impl Editor {
+    /// Set a buffer's language to `name`, a syntax name or "Plain Text",
+    /// overriding detection. Returns false if there is no such language.
+    pub(super) fn set_buffer_language(&mut self, buffer_id: BufferId, name: &str) -> bool {
+        let plain_text = name == PLAIN_TEXT || name.eq_ignore_ascii_case("text");
+        let detected = if plain_text {
+            DetectedLanguage::plain_text()
+        } else {
+            match DetectedLanguage::from_syntax_name(
+                name,
+                &self.grammar_registry,
+                &self.config.languages,
+            ) {
+                Some(detected) => detected,
+                None => return false,
+            }
+        };
+        let Some(old_language) = self.buffers.get(&buffer_id).map(|s| s.language.clone()) else {
+            return false;
+        };
+        let language = detected.name.clone();
+        let language_changed = language != old_language;
+        if language_changed {
+            self.close_lsp_document(buffer_id, &old_language);
+        }
+
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.apply_language(detected);
+            apply_language_settings(&self.config, state);
+        }
+        let lsp_enabled = match self.buffer_metadata.get_mut(&buffer_id) {
+            Some(metadata) => {
+                metadata.language_override =
+                    Some(if plain_text { PLAIN_TEXT } else { name }.to_string());
+                metadata.lsp_enabled && metadata.file_uri().is_some()
+            }
+            None => false,
+        };
+        if language_changed && lsp_enabled {
+            self.send_lsp_did_open_for_buffer(buffer_id, &language);
+        }
+
+        #[cfg(feature = "plugins")]
+        self.update_plugin_state_snapshot();
+        self.plugin_manager.run_hook(
+            "language_changed",
+            HookArgs::LanguageChanged {
+                buffer_id,
+                language,
+            },
+        );
+        true
+    }
+
+    /// Close a buffer's document on the LSP server for `language` and drop
+    /// the diagnostics that server reported for it
+    fn close_lsp_document(&mut self, buffer_id: BufferId, language: &str) {
+        let Some(metadata) = self.buffer_metadata.get_mut(&buffer_id) else {
+            return;
+        };
+        let Some(uri) = metadata.file_uri().cloned() else {
+            return;
+        };
+        if let Some(handle) = self
+            .lsp
+            .as_mut()
+            .and_then(|lsp| lsp.get_handle_mut(language))
+        {
+            if metadata.lsp_opened_with.contains(&handle.id()) {
+                if let Err(e) = handle.did_close(uri.clone()) {
+                    tracing::warn!("Failed to send didClose to LSP: {}", e);
+                }
+            }
+        }
+        metadata.lsp_opened_with.clear();
+        self.stored_diagnostics.remove(uri.as_str());
+        self.diagnostic_result_ids.remove(uri.as_str());
+    }
+}
+
+/// Apply the tab size, tab insertion and whitespace visibility configured
+/// for a buffer's language, falling back to the editor-wide settings
+pub(super) fn apply_language_settings(config: &Config, state: &mut EditorState) {
+    let mut whitespace = WhitespaceVisibility::from_editor_config(&config.editor);
+    if let Some(lang_config) = config.languages.get(&state.language) {
+        whitespace = whitespace.with_language_tab_override(lang_config.show_whitespace_tabs);
+        state.buffer_settings.use_tabs = lang_config.use_tabs;
+        // Use language-specific tab_size if set, otherwise fall back to global
+        state.buffer_settings.tab_size = lang_config.tab_size.unwrap_or(config.editor.tab_size);
+    } else {
+        state.buffer_settings.use_tabs = false;
+        state.buffer_settings.tab_size = config.editor.tab_size;
+    }
+    state.buffer_settings.whitespace = whitespace;
+}
@@ -0,0 +1,208 @@
+//! Log files: following their end as they grow, and filtering their lines.
+//!
+//! A log file opens read-only with its cursor on the last line, and each
+//! time the file watcher reloads it the cursor and view move on to the new
+//! end. Moving the cursor off the last line or scrolling up pauses that
+//! until Toggle Follow Tail resumes it. The filter hides the lines not
+//! matching a pattern with folds, so the lines shown keep their numbers.
+
+use regex::bytes::Regex;
+use rust_i18n::t;
+
+use super::Editor;
+use crate::model::cursor::Cursor;
+use crate::model::event::BufferId;
+use crate::primitives::log_file::hidden_line_ranges;
+use crate::view::folding::{indent_folding, FoldManager};
+use crate::view::prompt::PromptType;
+
+impl Editor {
+    /// Follow the end of the active log file, or stop following it
+    pub fn toggle_follow_tail(&mut self) {
+        let buffer_id = self.active_buffer();
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        if !state.log_file {
+            self.set_status_message(t!("toggle.follow_tail_unavailable").to_string());
+            return;
+        }
+        state.buffer_settings.follow_tail = !state.buffer_settings.follow_tail;
+        if state.buffer_settings.follow_tail {
+            self.follow_log_tail(buffer_id);
+            self.set_status_message(t!("toggle.follow_tail_on").to_string());
+        } else {
+            self.set_status_message(t!("toggle.follow_tail_off").to_string());
+        }
+    }
+
+    /// Move the cursor to the end of a log file in every split showing it,
+    /// scrolling it into view
+    pub(super) fn follow_log_tail(&mut self, buffer_id: BufferId) {
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let end = state.buffer.len();
+        for view_state in self.split_view_states.values_mut() {
+            if let Some(buf_state) = view_state.keyed_states.get_mut(&buffer_id) {
+                buf_state.cursors.remove_secondary();
+                *buf_state.cursors.primary_mut() = Cursor::new(end);
+                buf_state.ensure_cursor_visible(&mut state.buffer, &state.marker_list);
+            }
+        }
+    }
+
+    /// Stop following a log file, saying how to resume
+    pub(super) fn pause_log_follow(&mut self, buffer_id: BufferId) {
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        if !state.buffer_settings.follow_tail {
+            return;
+        }
+        state.buffer_settings.follow_tail = false;
+        self.set_status_message(t!("log.follow_paused").to_string());
+    }
+
+    /// Pause following the active log file once its cursor left the last
+    /// line
+    pub fn check_log_follow(&mut self) {
+        let buffer_id = self.active_buffer();
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            return;
+        };
+        if !state.buffer_settings.follow_tail {
+            return;
+        }
+        let last_line_start =
+            indent_folding::find_line_start_byte(&state.buffer, state.buffer.len());
+        if self.active_cursors().primary().position < last_line_start {
+            self.pause_log_follow(buffer_id);
+        }
+    }
+
+    /// Bring the view of a log file up to date after it was loaded again:
+    /// filter the new text, and move on to its end when following
+    pub(super) fn refresh_log_view(&mut self, buffer_id: BufferId) {
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            return;
+        };
+        if !state.log_file {
+            return;
+        }
+        let follow = state.buffer_settings.follow_tail;
+        let filtered = state.buffer_settings.log_filter.is_some();
+
+        // The markers of the folds went with the old text
+        for view_state in self.split_view_states.values_mut() {
+            if let Some(buf_state) = view_state.keyed_states.get_mut(&buffer_id) {
+                buf_state.folds = FoldManager::new();
+            }
+        }
+        if filtered {
+            self.apply_log_filter(buffer_id);
+        }
+        if follow {
+            self.follow_log_tail(buffer_id);
+        }
+    }
+
+    /// Ask for the pattern the lines of the active log file must match
+    pub(super) fn start_log_filter_prompt(&mut self) {
+        let Some(state) = self.buffers.get(&self.active_buffer()) else {
+            return;
+        };
+        if !state.log_file {
+            self.set_status_message(t!("log.filter_unavailable").to_string());
+            return;
+        }
+        let current = state.buffer_settings.log_filter.clone().unwrap_or_default();
+        self.start_prompt_with_initial_text(
+            t!("log.filter_prompt").to_string(),
+            PromptType::FilterLogLines,
+            current,
+        );
+    }
+
+    /// Filter the active log file by the pattern typed in the prompt; an
+    /// empty pattern shows every line again
+    pub(super) fn handle_log_filter(&mut self, input: &str) {
+        let buffer_id = self.active_buffer();
+        let filter = if input.trim().is_empty() {
+            None
+        } else if let Err(e) = Regex::new(input) {
+            self.set_status_message(t!("error.invalid_regex", error = e.to_string()).to_string());
+            return;
+        } else {
+            Some(input.to_string())
+        };
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let cleared = filter.is_none();
+        state.buffer_settings.log_filter = filter;
+
+        let shown = self.apply_log_filter(buffer_id);
+        if cleared {
+            self.set_status_message(t!("log.filter_cleared").to_string());
+        } else {
+            self.set_status_message(t!("log.filter_applied", count = shown).to_string());
+        }
+    }
+
+    /// Fold away the lines of a log file not matching its filter in every
+    /// split showing it, moving cursors out of the hidden lines. Returns how
+    /// many lines are left showing.
+    fn apply_log_filter(&mut self, buffer_id: BufferId) -> usize {
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return 0;
+        };
+        let len = state.buffer.len();
+        let text = match state.buffer.get_text_range_mut(0, len) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                tracing::warn!("Failed to read log file to filter: {}", e);
+                return 0;
+            }
+        };
+        let hidden = state
+            .buffer_settings
+            .log_filter
+            .as_deref()
+            .and_then(|pattern| Regex::new(pattern).ok())
+            .map(|pattern| hidden_line_ranges(&text, &pattern))
+            .unwrap_or_default();
+        let line_count = |bytes: &[u8]| bytes.split_inclusive(|&b| b == b'\n').count();
+
+        for view_state in self.split_view_states.values_mut() {
+            let Some(buf_state) = view_state.keyed_states.get_mut(&buffer_id) else {
+                continue;
+            };
+            buf_state.folds.clear(&mut state.marker_list);
+            for range in &hidden {
+                let count = line_count(&text[range.clone()]);
+                buf_state.folds.add(
+                    &mut state.marker_list,
+                    range.start,
+                    range.end,
+                    Some(t!("log.hidden_lines", count = count).to_string()),
+                );
+            }
+            // The cursor goes on to the next line shown
+            buf_state.cursors.map(|cursor| {
+                if let Some(range) = hidden.iter().find(|range| range.contains(&cursor.position)) {
+                    cursor.position = range.end.min(len);
+                    cursor.clear_selection();
+                }
+            });
+            buf_state.ensure_cursor_visible(&mut state.buffer, &state.marker_list);
+        }
+        self.invalidate_layouts_for_buffer(buffer_id);
+
+        let hidden_lines: usize = hidden
+            .iter()
+            .map(|range| line_count(&text[range.clone()]))
+            .sum();
+        line_count(&text) - hidden_lines
+    }
+}
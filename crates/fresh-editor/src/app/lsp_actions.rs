@@ -21,14 +21,18 @@ impl Editor {
             return;
         };
         let language = state.language.clone();
+        self.restart_lsp_server(&language);
+    }
 
-        // Attempt restart
+    /// Restart or start the LSP server for `language` and re-send didOpen
+    /// for its buffers
+    pub(super) fn restart_lsp_server(&mut self, language: &str) {
         let Some(lsp) = self.lsp.as_mut() else {
             self.set_status_message(t!("lsp.no_manager").to_string());
             return;
         };
 
-        let (success, message) = lsp.manual_restart(&language);
+        let (success, message) = lsp.manual_restart(language);
         self.status_message = Some(message);
 
         if !success {
@@ -36,7 +40,125 @@ impl Editor {
         }
 
         // Re-send didOpen for all buffers of this language
-        self.reopen_buffers_for_language(&language);
+        self.reopen_buffers_for_language(language);
+    }
+
+    /// A language server grew past its memory limit and is being stopped.
+    /// Stop it for good instead of letting crash recovery restart it, and
+    /// offer to restart it.
+    pub(super) fn handle_lsp_memory_limit_exceeded(
+        &mut self,
+        language: &str,
+        rss_bytes: u64,
+        limit_bytes: u64,
+    ) {
+        if let Some(lsp) = self.lsp.as_mut() {
+            lsp.shutdown_server(language);
+        }
+        let usage = crate::i18n::format::format_size(rss_bytes);
+        let limit = crate::i18n::format::format_size(limit_bytes);
+        if self.prompt.is_some() {
+            self.set_status_message(
+                t!(
+                    "lsp.memory_limit_exceeded",
+                    language = language,
+                    usage = usage,
+                    limit = limit
+                )
+                .to_string(),
+            );
+            return;
+        }
+        self.start_prompt(
+            t!(
+                "prompt.lsp_memory_limit_restart",
+                language = language,
+                usage = usage,
+                limit = limit
+            )
+            .to_string(),
+            PromptType::ConfirmLspRestart {
+                language: language.to_string(),
+            },
+        );
+    }
+
+    /// Show the running language servers with their process IDs and
+    /// sampled memory and CPU use
+    pub fn show_process_status(&mut self) {
+        use crate::view::popup::{Popup, PopupPosition};
+
+        let mut languages = self
+            .lsp
+            .as_ref()
+            .map(|lsp| lsp.running_servers())
+            .unwrap_or_default();
+        if languages.is_empty() {
+            self.set_status_message(t!("lsp.no_servers_running").to_string());
+            return;
+        }
+        languages.sort();
+
+        let lines: Vec<String> = languages
+            .iter()
+            .map(|language| {
+                let lsp = self.lsp.as_ref();
+                let command = lsp
+                    .and_then(|lsp| lsp.get_config(language))
+                    .map(|config| config.command.clone())
+                    .unwrap_or_default();
+                let stats = lsp
+                    .and_then(|lsp| lsp.get_handle(language))
+                    .and_then(|handle| handle.process_stats());
+                let state = lsp
+                    .and_then(|lsp| lsp.get_handle(language))
+                    .map(|handle| format!("{:?}", handle.state()).to_lowercase())
+                    .unwrap_or_default();
+                match stats {
+                    Some(stats) => {
+                        let memory = match stats.usage {
+                            Some(usage) => crate::i18n::format::format_size(usage.rss_bytes),
+                            None => "-".to_string(),
+                        };
+                        let memory = match stats.memory_limit_bytes {
+                            Some(limit) => {
+                                format!("{} / {}", memory, crate::i18n::format::format_size(limit))
+                            }
+                            None => memory,
+                        };
+                        let cpu = stats
+                            .usage
+                            .map(|usage| format!("{:.1}%", usage.cpu_percent))
+                            .unwrap_or_else(|| "-".to_string());
+                        t!(
+                            "lsp.process_status_line",
+                            language = language,
+                            command = command,
+                            pid = stats.pid.to_string(),
+                            state = state,
+                            memory = memory,
+                            cpu = cpu
+                        )
+                        .to_string()
+                    }
+                    None => t!(
+                        "lsp.process_status_not_running",
+                        language = language,
+                        command = command,
+                        state = state
+                    )
+                    .to_string(),
+                }
+            })
+            .collect();
+
+        let mut popup = Popup::text(lines, &self.theme);
+        popup.title = Some(t!("lsp.process_status_title").to_string());
+        popup.position = PopupPosition::Centered;
+        popup.width = 80;
+        if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
+            state.popups.show(popup);
+        }
     }
 
     /// Re-send didOpen notifications for all buffers of a given language.
@@ -486,7 +608,7 @@ impl Editor {
     }
 
     /// Send LSP didOpen notification for a buffer
-    fn send_lsp_did_open_for_buffer(
+    pub(super) fn send_lsp_did_open_for_buffer(
         &mut self,
         buffer_id: crate::model::event::BufferId,
         language: &str,
@@ -47,9 +47,22 @@ impl Editor {
         self.pending_completion_request = None;
         self.update_lsp_status_from_server_statuses();
 
+        // Snippets and buffer words fill in below the server's items
+        let mut items = items;
+        let snippets = self.snippet_completion_items(&items);
+        items.extend(snippets);
+        let words = self.word_completion_items(&items);
+        items.extend(words);
+        self.show_completion_popup(items);
+
+        Ok(())
+    }
+
+    /// Show the completion popup for the items matching the word at the cursor
+    pub(crate) fn show_completion_popup(&mut self, items: Vec<lsp_types::CompletionItem>) {
         if items.is_empty() {
             tracing::debug!("No completion items received");
-            return Ok(());
+            return;
         }
 
         // Get the partial word at cursor to filter completions
@@ -89,7 +102,7 @@ impl Editor {
 
         if filtered_items.is_empty() {
             tracing::debug!("No completion items match prefix '{}'", prefix);
-            return Ok(());
+            return;
         }
 
         // Convert CompletionItem to PopupListItem
@@ -175,8 +188,6 @@ impl Editor {
             "Showing completion popup with {} items",
             self.completion_items.as_ref().map_or(0, |i| i.len())
         );
-
-        Ok(())
     }
 
     /// Handle LSP go-to-definition response
@@ -204,6 +215,9 @@ impl Editor {
         // For now, just jump to the first location
         let location = &locations[0];
 
+        // Remember where the jump started, even when it stays in this file
+        self.record_jump();
+
         // Convert URI to file path
         if let Ok(path) = uri_to_path(&location.uri) {
             // Open the file
@@ -448,6 +462,9 @@ impl Editor {
             self.next_lsp_request_id += 1;
             self.pending_completion_request = Some(request_id);
             self.lsp_status = "LSP: completion...".to_string();
+        } else {
+            // No language server for this buffer, offer its words instead
+            self.show_word_completion();
         }
     }
 
@@ -0,0 +1,306 @@
+//! Markdown preview: a read-only split beside a markdown buffer showing it
+//! rendered by [`crate::view::markdown::render_markdown`].
+//!
+//! The preview catches up with its source once edits pause, and scrolls with
+//! it through a scroll sync group whose anchors map each source line to the
+//! first rendered line of its block. Links keep their targets in a
+//! [`LINK_PROPERTY`] text property, so Ctrl+click and Open Link follow them.
+
+use std::ops::Range;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use ratatui::style::Style;
+use rust_i18n::t;
+
+use super::Editor;
+use crate::model::event::{BufferId, SplitDirection};
+use crate::primitives::detected_language::DetectedLanguage;
+use crate::primitives::text_property::TextPropertyEntry;
+use crate::view::markdown::{render_markdown, MarkdownOptions};
+use crate::view::overlay::{Overlay, OverlayFace, OverlayNamespace};
+use crate::view::scroll_sync::ScrollSyncGroupId;
+use crate::view::split::SplitViewState;
+
+/// Pause in editing after which a preview is rendered again
+const PREVIEW_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Text property holding where a link in rendered text points: a URL or an
+/// absolute path
+pub(super) const LINK_PROPERTY: &str = "link";
+
+/// The preview of one markdown buffer
+pub(super) struct MarkdownPreview {
+    /// Read-only buffer holding the rendered text
+    buffer_id: BufferId,
+    sync_group: ScrollSyncGroupId,
+    /// Source buffer version the preview shows
+    version: u64,
+    /// Newer source version waiting to be shown, and when it was first seen
+    pending: Option<(u64, Instant)>,
+}
+
+impl Editor {
+    /// Open a rendered preview of the active markdown buffer in a split
+    /// beside it, or close the preview if one is open. Works from either
+    /// split.
+    pub fn toggle_markdown_preview(&mut self) {
+        let active = self.active_buffer();
+        let source = self
+            .markdown_previews
+            .iter()
+            .find(|(_, preview)| preview.buffer_id == active)
+            .map_or(active, |(&source, _)| source);
+
+        if self.markdown_previews.contains_key(&source) {
+            self.close_markdown_preview(source);
+            if let Some(&split) = self.split_manager.splits_for_buffer(source).first() {
+                self.split_manager.set_active_split(split);
+            }
+            self.set_status_message(t!("toggle.markdown_preview_off").to_string());
+            return;
+        }
+
+        let is_markdown = self
+            .buffers
+            .get(&source)
+            .is_some_and(|state| state.language == "markdown");
+        if !is_markdown {
+            self.set_status_message(t!("toggle.markdown_preview_unavailable").to_string());
+            return;
+        }
+        self.open_markdown_preview(source);
+    }
+
+    fn open_markdown_preview(&mut self, source: BufferId) {
+        let source_split = self.split_manager.active_split();
+        let name = format!("*Preview: {}*", self.get_buffer_display_name(source));
+        let buffer_id = self.create_virtual_buffer(name, "special".to_string(), true);
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            // The name ends in `.md`, but the rendered text isn't markdown
+            state.apply_language(DetectedLanguage::plain_text());
+            state.editing_disabled = true;
+        }
+        // The preview gets its own split rather than a tab beside its source
+        if let Some(view_state) = self.split_view_states.get_mut(&source_split) {
+            view_state.remove_buffer(buffer_id);
+        }
+
+        let preview_split =
+            match self
+                .split_manager
+                .split_active(SplitDirection::Vertical, buffer_id, 0.5)
+            {
+                Ok(split) => split,
+                Err(e) => {
+                    self.set_status_message(t!("split.error", error = e.to_string()).to_string());
+                    if let Err(e) = self.force_close_buffer(buffer_id) {
+                        tracing::warn!("Failed to close markdown preview buffer: {}", e);
+                    }
+                    return;
+                }
+            };
+        let mut view_state =
+            SplitViewState::with_buffer(self.terminal_width, self.terminal_height, buffer_id);
+        view_state.viewport.line_wrap_enabled = true;
+        view_state.show_line_numbers = false;
+        self.split_view_states.insert(preview_split, view_state);
+        // Editing goes on in the source
+        self.split_manager.set_active_split(source_split);
+
+        let sync_group = self
+            .scroll_sync_manager
+            .create_group(source_split.into(), preview_split.into());
+        self.markdown_previews.insert(
+            source,
+            MarkdownPreview {
+                buffer_id,
+                sync_group,
+                version: 0,
+                pending: None,
+            },
+        );
+        self.render_markdown_preview(source);
+        self.set_status_message(t!("toggle.markdown_preview_on").to_string());
+    }
+
+    /// Close the preview of `source`, with its split
+    fn close_markdown_preview(&mut self, source: BufferId) {
+        let Some(preview) = self.markdown_previews.remove(&source) else {
+            return;
+        };
+        self.scroll_sync_manager.remove_group(preview.sync_group);
+        for split in self.split_manager.splits_for_buffer(preview.buffer_id) {
+            if self.split_manager.close_split(split).is_ok() {
+                self.split_view_states.remove(&split);
+            }
+        }
+        if self.buffers.contains_key(&preview.buffer_id) {
+            if let Err(e) = self.force_close_buffer(preview.buffer_id) {
+                tracing::warn!("Failed to close markdown preview buffer: {}", e);
+            }
+        }
+    }
+
+    /// Render the source of a preview into its buffer and map the source
+    /// lines to the rendered ones for scrolling
+    fn render_markdown_preview(&mut self, source: BufferId) {
+        let Some(preview) = self.markdown_previews.get_mut(&source) else {
+            return;
+        };
+        let Some(state) = self.buffers.get_mut(&source) else {
+            return;
+        };
+        preview.version = state.buffer.version();
+        preview.pending = None;
+        let (buffer_id, sync_group) = (preview.buffer_id, preview.sync_group);
+
+        let len = state.buffer.len();
+        let text = match state.buffer.get_text_range_mut(0, len) {
+            Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+            Err(e) => {
+                tracing::warn!("Failed to read markdown for preview: {}", e);
+                return;
+            }
+        };
+        let base_dir = state
+            .buffer
+            .file_path()
+            .and_then(Path::parent)
+            .map(Path::to_path_buf);
+        let rendered = render_markdown(
+            &text,
+            &self.theme,
+            Some(&self.grammar_registry),
+            MarkdownOptions::default(),
+        );
+
+        let mut entries = Vec::new();
+        let mut styles: Vec<(Range<usize>, Style)> = Vec::new();
+        let mut offset = 0;
+        for line in &rendered.lines {
+            for span in &line.spans {
+                let mut entry = TextPropertyEntry::text(span.text.clone());
+                if let Some(target) = span
+                    .link_url
+                    .as_deref()
+                    .and_then(|url| link_target(url, base_dir.as_deref()))
+                {
+                    entry = entry.with_property(LINK_PROPERTY, serde_json::Value::String(target));
+                }
+                if span.style != Style::default() {
+                    styles.push((offset..offset + span.text.len(), span.style));
+                }
+                offset += span.text.len();
+                entries.push(entry);
+            }
+            entries.push(TextPropertyEntry::text("\n"));
+            offset += 1;
+        }
+
+        if let Err(e) = self.set_virtual_buffer_content(buffer_id, entries) {
+            tracing::warn!("Failed to update markdown preview: {}", e);
+            return;
+        }
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            let namespace = OverlayNamespace::from_string("markdown-preview".to_string());
+            state
+                .overlays
+                .clear_namespace(&namespace, &mut state.marker_list);
+            for (range, style) in styles {
+                let overlay = Overlay::with_namespace(
+                    &mut state.marker_list,
+                    range,
+                    OverlayFace::Style { style },
+                    namespace.clone(),
+                );
+                state.overlays.add(overlay);
+            }
+        }
+        self.invalidate_layouts_for_buffer(buffer_id);
+        self.scroll_sync_manager
+            .set_anchors(sync_group, rendered.sync_anchors());
+    }
+
+    /// Render previews again once edits to their source pause, and forget
+    /// previews whose buffer was closed.
+    /// Returns true if a preview changed.
+    pub fn check_markdown_preview_timer(&mut self) -> bool {
+        let now = self.time_source.now();
+        let mut due = Vec::new();
+        let mut closed = Vec::new();
+        for (&source, preview) in &mut self.markdown_previews {
+            let Some(state) = self.buffers.get(&source) else {
+                closed.push(source);
+                continue;
+            };
+            if !self.buffers.contains_key(&preview.buffer_id) {
+                closed.push(source);
+                continue;
+            }
+            let version = state.buffer.version();
+            if version == preview.version {
+                preview.pending = None;
+                continue;
+            }
+            match preview.pending {
+                Some((pending, since)) if pending == version => {
+                    if now.duration_since(since) >= PREVIEW_DEBOUNCE {
+                        due.push(source);
+                    }
+                }
+                // Each further edit restarts the wait
+                _ => preview.pending = Some((version, now)),
+            }
+        }
+
+        for &source in &closed {
+            self.close_markdown_preview(source);
+        }
+        for &source in &due {
+            self.render_markdown_preview(source);
+        }
+        !closed.is_empty() || !due.is_empty()
+    }
+}
+
+/// Where a link in a previewed document points: URLs as they are, and paths
+/// made absolute against the document's directory. Links to headings in the
+/// document itself have no target.
+fn link_target(dest: &str, base_dir: Option<&Path>) -> Option<String> {
+    if dest.contains("://") || dest.starts_with("mailto:") {
+        return Some(dest.to_string());
+    }
+    let path = dest.split('#').next().unwrap_or_default();
+    if path.is_empty() {
+        return None;
+    }
+    let path = match base_dir {
+        Some(dir) => dir.join(path),
+        None => Path::new(path).to_path_buf(),
+    };
+    Some(path.to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_target() {
+        let dir = Path::new("/docs");
+        assert_eq!(
+            link_target("https://example.com/a#b", Some(dir)).as_deref(),
+            Some("https://example.com/a#b")
+        );
+        assert_eq!(
+            link_target("guide/intro.md#setup", Some(dir)).as_deref(),
+            Some("/docs/guide/intro.md")
+        );
+        assert_eq!(
+            link_target("/etc/hosts", Some(dir)).as_deref(),
+            Some("/etc/hosts")
+        );
+        assert_eq!(link_target("#usage", Some(dir)), None);
+    }
+}
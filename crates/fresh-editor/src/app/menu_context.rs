@@ -48,6 +48,8 @@ impl Editor {
         let menu_bar = self.menu_bar_visible;
         let vertical_scrollbar = self.config.editor.show_vertical_scrollbar;
         let horizontal_scrollbar = self.config.editor.show_horizontal_scrollbar;
+        let indent_guides = self.config.editor.indent_guides;
+        let minimap = self.config.editor.minimap;
 
         // File explorer state
         let show_hidden = self.is_file_explorer_showing_hidden();
@@ -84,12 +86,14 @@ impl Editor {
             .set(context_keys::SESSION_MODE, session_mode)
             .set(context_keys::VERTICAL_SCROLLBAR, vertical_scrollbar)
             .set(context_keys::HORIZONTAL_SCROLLBAR, horizontal_scrollbar)
+            .set(context_keys::INDENT_GUIDES, indent_guides)
+            .set(context_keys::MINIMAP, minimap)
             .set(context_keys::SCROLL_SYNC, scroll_sync)
             .set(context_keys::HAS_SAME_BUFFER_SPLITS, has_same_buffer_splits);
     }
 
     /// Check if line numbers are visible in the active split.
-    fn is_line_numbers_visible(&self) -> bool {
+    pub(super) fn is_line_numbers_visible(&self) -> bool {
         let active_split = self.split_manager.active_split();
         self.split_view_states
             .get(&active_split)
@@ -98,7 +102,7 @@ impl Editor {
     }
 
     /// Check if line wrap is enabled in the active split.
-    fn is_line_wrap_enabled(&self) -> bool {
+    pub(super) fn is_line_wrap_enabled(&self) -> bool {
         let active_split = self.split_manager.active_split();
         self.split_view_states
             .get(&active_split)
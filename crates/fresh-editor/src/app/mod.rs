@@ -1,44 +1,77 @@
+mod abbreviation_actions;
 mod async_messages;
+pub mod batch;
+mod bookmark_actions;
+mod box_drawing_actions;
+mod buffer_links;
 mod buffer_management;
+mod buffer_picker;
 mod calibration_actions;
 pub mod calibration_wizard;
+mod client_views;
 mod clipboard;
+mod color_adjust;
+mod comment_actions;
 mod composite_buffer_actions;
+mod editor_log;
 pub mod event_debug;
 mod event_debug_actions;
+mod export_actions;
 mod file_explorer;
 pub mod file_open;
 mod file_open_input;
 mod file_operations;
 mod help;
+mod hover_tooltip;
 mod input;
 mod input_dispatch;
+mod input_recorder;
+mod jump_list_actions;
 pub mod keybinding_editor;
 mod keybinding_editor_actions;
+mod language_mode;
+mod log_file_actions;
 mod lsp_actions;
 mod lsp_requests;
+mod markdown_preview;
 mod menu_actions;
 mod menu_context;
+mod motion_actions;
 mod mouse_input;
 mod on_save_actions;
+mod perf_hud;
 mod plugin_commands;
+mod plugin_log;
+mod plugin_panel;
 mod popup_actions;
 mod prompt_actions;
+mod quick_open_modes;
+mod quit_actions;
+mod read_only_actions;
 mod recovery_actions;
+mod reflow_actions;
 mod regex_replace;
 mod render;
+mod selection_expansion;
 mod settings_actions;
 mod shell_command;
+mod snippet_actions;
 mod split_actions;
+mod status_bar_segments;
+mod surround_actions;
 mod tab_drag;
+mod task_runner;
 mod terminal;
 mod terminal_input;
+mod terminal_links;
 mod terminal_mouse;
 mod toggle_actions;
 pub mod types;
 mod undo_actions;
+mod unicode_input;
 mod view_actions;
 pub mod warning_domains;
+mod word_completion;
 pub mod workspace;
 
 use anyhow::Result as AnyhowResult;
@@ -67,15 +100,33 @@ pub fn editor_tick(
     if editor.check_mouse_hover_timer() {
         needs_render = true;
     }
+    if editor.check_hover_tooltip_timer() {
+        needs_render = true;
+    }
     if editor.check_semantic_highlight_timer() {
         needs_render = true;
     }
+    if editor.check_search_refresh_timer() {
+        needs_render = true;
+    }
     if editor.check_completion_trigger_timer() {
         needs_render = true;
     }
+    if editor.check_markdown_preview_timer() {
+        needs_render = true;
+    }
+    if editor.check_chord_timeout() {
+        needs_render = true;
+    }
     if editor.check_warning_log() {
         needs_render = true;
     }
+    if editor.check_editor_log() {
+        needs_render = true;
+    }
+    if editor.check_status_bar_refresh() {
+        needs_render = true;
+    }
     if editor.poll_stdin_streaming() {
         needs_render = true;
     }
@@ -83,9 +134,7 @@ pub fn editor_tick(
     if let Err(e) = editor.auto_recovery_save_dirty_buffers() {
         tracing::debug!("Auto-recovery-save error: {}", e);
     }
-    if let Err(e) = editor.auto_save_persistent_buffers() {
-        tracing::debug!("Auto-save (disk) error: {}", e);
-    }
+    editor.process_periodic_saves();
 
     if editor.take_full_redraw_request() {
         clear_terminal()?;
@@ -128,18 +177,17 @@ pub(crate) fn normalize_path(path: &std::path::Path) -> std::path::PathBuf {
 }
 
 use self::types::{
-    Bookmark, CachedLayout, EventLineInfo, InteractiveReplaceState, LspMessageEntry,
-    LspProgressInfo, MacroRecordingState, MouseState, SearchState, TabContextMenu,
-    DEFAULT_BACKGROUND_FILE,
+    AbbreviationExpansion, Bookmark, CachedLayout, ClosedTab, EventLineInfo,
+    InteractiveReplaceState, LineBookmark, LspMessageEntry, LspProgressInfo, MacroRecordingState,
+    MouseState, PasteCycle, SearchState, TabContextMenu, DEFAULT_BACKGROUND_FILE,
 };
-use crate::config::Config;
+use crate::config::{AutoSaveMode, Config};
 use crate::config_io::{ConfigLayer, ConfigResolver, DirectoryContext};
 use crate::input::actions::action_to_events as convert_action_to_events;
 use crate::input::buffer_mode::ModeRegistry;
 use crate::input::command_registry::CommandRegistry;
 use crate::input::commands::Suggestion;
 use crate::input::keybindings::{Action, KeyContext, KeybindingResolver};
-use crate::input::position_history::PositionHistory;
 use crate::input::quick_open::{
     FileProvider, GotoLineProvider, QuickOpenContext, QuickOpenProvider, QuickOpenRegistry,
 };
@@ -147,6 +195,7 @@ use crate::model::cursor::Cursors;
 use crate::model::event::{Event, EventLog, LeafId, SplitDirection, SplitId};
 use crate::model::filesystem::FileSystem;
 use crate::services::async_bridge::{AsyncBridge, AsyncMessage};
+use crate::services::file_index::FileIndex;
 use crate::services::fs::FsManager;
 use crate::services::lsp::manager::LspManager;
 use crate::services::plugins::PluginManager;
@@ -159,7 +208,8 @@ use crate::view::prompt::{Prompt, PromptType};
 use crate::view::scroll_sync::ScrollSyncManager;
 use crate::view::split::{SplitManager, SplitViewState};
 use crate::view::ui::{
-    FileExplorerRenderer, SplitRenderer, StatusBarRenderer, SuggestionsRenderer,
+    FileExplorerRenderer, PluginStatusSegment, SplitRenderer, StatusBarRenderer,
+    SuggestionsRenderer,
 };
 use crossterm::event::{KeyCode, KeyModifiers};
 #[cfg(feature = "plugins")]
@@ -270,6 +320,9 @@ pub struct Editor {
     /// All loaded themes (embedded + user)
     theme_registry: crate::view::theme::ThemeRegistry,
 
+    /// Light/dark background reported by the terminal, used by the "auto" theme
+    terminal_appearance: Option<crate::services::terminal_background::Appearance>,
+
     /// Optional ANSI background image
     ansi_background: Option<crate::primitives::ansi_background::AnsiBackground>,
 
@@ -285,6 +338,17 @@ pub struct Editor {
     /// Shared clipboard (handles both internal and system clipboard)
     clipboard: crate::services::clipboard::Clipboard,
 
+    /// The paste that Cycle Paste replaces, while no other action has run since
+    paste_cycle: Option<PasteCycle>,
+
+    /// Whether Line Drawing Mode is on: arrow keys draw lines and typing
+    /// overwrites
+    line_drawing: bool,
+
+    /// The abbreviation expansion Backspace reverts, while no other action has
+    /// run since
+    abbreviation_expansion: Option<AbbreviationExpansion>,
+
     /// Should the editor quit?
     should_quit: bool,
 
@@ -314,10 +378,28 @@ pub struct Editor {
     /// Plugin-provided status message (displayed alongside the core status)
     plugin_status_message: Option<String>,
 
+    /// Status bar segments set by plugins, by segment name
+    plugin_status_segments: HashMap<String, PluginStatusSegment>,
+
+    /// Git branch shown by the git_branch status bar segment
+    status_bar_git_branch: Option<String>,
+
+    /// Time shown by the clock status bar segment
+    status_bar_clock: Option<String>,
+
+    /// When the git branch and clock segments were last refreshed
+    status_bar_refreshed_at: Option<Instant>,
+
     /// Accumulated plugin errors (for test assertions)
     /// These are collected when plugin error messages are received
     plugin_errors: Vec<String>,
 
+    /// Plugin errors, slow handlers and rejected edits, shown in `*Plugin Log*`
+    plugin_log: Vec<String>,
+
+    /// Last load error of each plugin that failed to load, shown in `*Plugins*`
+    plugin_load_errors: HashMap<String, String>,
+
     /// Active prompt (minibuffer)
     prompt: Option<Prompt>,
 
@@ -357,6 +439,9 @@ pub struct Editor {
     /// Used for side-by-side diff views where two panes need to scroll together
     scroll_sync_manager: ScrollSyncManager,
 
+    /// Rendered previews of markdown buffers, by source buffer
+    markdown_previews: HashMap<BufferId, markdown_preview::MarkdownPreview>,
+
     /// File explorer view (optional, only when open)
     file_explorer: Option<FileTreeView>,
 
@@ -390,6 +475,9 @@ pub struct Editor {
     /// Pending show_gitignored setting to apply when file explorer is initialized (from session restore)
     pending_file_explorer_show_gitignored: Option<bool>,
 
+    /// Whether to reveal the active file once the file explorer is initialized
+    pending_file_explorer_reveal: bool,
+
     /// File explorer decorations by namespace
     file_explorer_decorations: HashMap<String, Vec<crate::view::file_tree::FileExplorerDecoration>>,
 
@@ -432,9 +520,6 @@ pub struct Editor {
     /// Working directory for file explorer (set at initialization)
     working_dir: PathBuf,
 
-    /// Position history for back/forward navigation
-    pub position_history: PositionHistory,
-
     /// Flag to prevent recording movements during navigation
     in_navigation: bool,
 
@@ -448,6 +533,9 @@ pub struct Editor {
     /// Stored when completion popup is shown, used for re-filtering as user types
     completion_items: Option<Vec<lsp_types::CompletionItem>>,
 
+    /// User snippet files from the config dir
+    snippets: crate::services::snippets::SnippetLibrary,
+
     /// Scheduled completion trigger time (for debounced quick suggestions)
     /// When Some, completion will be triggered when this instant is reached
     scheduled_completion_trigger: Option<Instant>,
@@ -464,6 +552,42 @@ pub struct Editor {
     /// Symbol name for pending references request
     pending_references_symbol: String,
 
+    /// Pending LSP document symbols request ID and the buffer it is for
+    pending_document_symbols_request: Option<(u64, BufferId)>,
+
+    /// Symbols listed by the Quick Open `@` mode, and the buffer they belong to
+    quick_open_symbols: Option<(BufferId, Vec<quick_open_modes::QuickOpenSymbol>)>,
+
+    /// Scroll position to restore when a Quick Open `:` line preview is cancelled
+    quick_open_preview: Option<quick_open_modes::QuickOpenPreview>,
+
+    /// Selections grown by Expand Selection, for Shrink Selection to walk back
+    selection_expansion: Option<selection_expansion::SelectionExpansion>,
+
+    /// Buffers in most recently activated order, for the buffer picker
+    buffer_mru: Vec<BufferId>,
+
+    /// Open buffer picker and the buffer it was opened from
+    buffer_picker: Option<buffer_picker::BufferPicker>,
+
+    /// Prompt a picked Unicode character goes into, set aside while the
+    /// picker is open
+    unicode_target_prompt: Option<Prompt>,
+
+    /// Views of the other clients attached to the session, by client ID,
+    /// when each client has its own view
+    client_views: HashMap<u64, client_views::ClientView>,
+
+    /// Client whose view is live, when each client has its own view
+    active_client_view: Option<u64>,
+
+    /// Names the clients gave when attaching, shown on their cursors
+    client_names: HashMap<u64, String>,
+
+    /// Modifiers of the key being handled, so the buffer picker knows whether
+    /// it was opened with Ctrl held
+    key_modifiers: KeyModifiers,
+
     /// Pending LSP signature help request ID (if any)
     pending_signature_help_request: Option<u64>,
 
@@ -538,6 +662,9 @@ pub struct Editor {
     /// Tab context menu state (right-click on tabs)
     tab_context_menu: Option<TabContextMenu>,
 
+    /// Recently closed file tabs, most recent last (for "Reopen Closed Tab")
+    closed_tabs: Vec<ClosedTab>,
+
     /// Cached layout areas from last render (for mouse hit testing)
     pub(crate) cached_layout: CachedLayout,
 
@@ -598,6 +725,10 @@ pub struct Editor {
     /// Maps file URI string to Vec of diagnostics for that file
     stored_diagnostics: HashMap<String, Vec<lsp_types::Diagnostic>>,
 
+    /// Diagnostics reported by language linters per URI (also included in
+    /// `stored_diagnostics`)
+    linter_diagnostics: HashMap<String, Vec<lsp_types::Diagnostic>>,
+
     /// Stored LSP folding ranges per URI
     /// Maps file URI string to Vec of folding ranges for that file
     stored_folding_ranges: HashMap<String, Vec<lsp_types::FoldingRange>>,
@@ -608,12 +739,17 @@ pub struct Editor {
     /// Bookmarks (character key -> bookmark)
     bookmarks: HashMap<char, Bookmark>,
 
+    /// Line bookmarks per buffer, in the order they were added
+    line_bookmarks: HashMap<BufferId, Vec<LineBookmark>>,
+
     /// Global search options (persist across searches)
     search_case_sensitive: bool,
     search_whole_word: bool,
     search_use_regex: bool,
     /// Whether to confirm each replacement (interactive/query-replace mode)
     search_confirm_each: bool,
+    /// Whether replacements adapt to the case of the matched text
+    search_preserve_case: bool,
 
     /// Macro storage (key -> list of recorded actions)
     macros: HashMap<char, Vec<Action>>,
@@ -642,6 +778,15 @@ pub struct Editor {
     /// Stores the keys pressed so far in a chord sequence
     chord_state: Vec<(crossterm::event::KeyCode, crossterm::event::KeyModifiers)>,
 
+    /// When the pending chord sequence expires (see `editor.chord_timeout_ms`)
+    chord_deadline: Option<Instant>,
+
+    /// Modal input state, used when `editor.keymap` is "vim"
+    vim: crate::input::vim::VimState,
+
+    /// Last repeatable edit, replayed by `Action::RepeatLast`
+    repeat: crate::input::repeat::RepeatState,
+
     /// Pending LSP confirmation - language name awaiting user confirmation
     /// When Some, a confirmation popup is shown asking user to approve LSP spawn
     pending_lsp_confirmation: Option<String>,
@@ -650,6 +795,14 @@ pub struct Editor {
     /// Used when closing a modified buffer that needs to be saved first
     pending_close_buffer: Option<BufferId>,
 
+    /// Items still to be reviewed before quitting, in order
+    /// When Some, each is shown and confirmed in turn and the editor quits after the last
+    quit_review: Option<Vec<BufferId>>,
+
+    /// Rendered HTML or ANSI export waiting for its destination
+    /// When Some, the Save As prompt writes it instead of saving the buffer
+    pending_export: Option<export_actions::PendingExport>,
+
     /// Whether auto-revert mode is enabled (automatically reload files when changed on disk)
     auto_revert_enabled: bool,
 
@@ -659,10 +812,27 @@ pub struct Editor {
     /// Last time we polled for directory changes (for file tree refresh)
     last_file_tree_poll: std::time::Instant,
 
+    /// Last time we polled the user themes directory for edits
+    last_theme_poll: std::time::Instant,
+
+    /// Theme file modification times as of the last theme load
+    theme_files_stamp: Vec<(PathBuf, std::time::SystemTime)>,
+
+    /// Buffer last told that its byte offset gutter ignores `line_number_mode`
+    line_number_mode_noted: Option<BufferId>,
+
     /// Last known modification times for open files (for auto-revert)
     /// Maps file path to last known modification time
     file_mod_times: HashMap<PathBuf, std::time::SystemTime>,
 
+    /// Open files found deleted on disk; their buffers keep their content
+    /// and are shown as deleted until the file reappears or is saved
+    files_deleted_on_disk: HashSet<PathBuf>,
+
+    /// Modified buffers' files that changed on disk, with the modification
+    /// time the user was asked about (asked once per change)
+    external_change_conflicts: HashMap<PathBuf, std::time::SystemTime>,
+
     /// Last known modification times for expanded directories (for file tree refresh)
     /// Maps directory path to last known modification time
     dir_mod_times: HashMap<PathBuf, std::time::SystemTime>,
@@ -692,6 +862,15 @@ pub struct Editor {
     /// Last persistent auto-save time for rate limiting (disk)
     last_persistent_auto_save: std::time::Instant,
 
+    /// Time of the last edit not yet covered by an "after_delay" auto-save
+    last_buffer_edit: Option<std::time::Instant>,
+
+    /// Buffers left while `auto_save` is "on_focus_change", saved on the next tick
+    auto_save_pending: Vec<BufferId>,
+
+    /// Debounces periodic workspace saves (disabled unless persistence is on)
+    workspace_tracker: workspace::WorkspaceTracker,
+
     /// Active custom contexts for command visibility
     /// Plugin-defined contexts like "config-editor" that control command availability
     active_custom_contexts: HashSet<String>,
@@ -706,6 +885,15 @@ pub struct Editor {
     /// Status message log path (for viewing full status history)
     status_log_path: Option<PathBuf>,
 
+    /// Main log file, shown by "Show Logs" in `*Editor Log*`
+    editor_log_path: Option<PathBuf>,
+
+    /// Bytes of the log already shown in `*Editor Log*`
+    editor_log_offset: u64,
+
+    /// When the log file was last checked for new lines
+    editor_log_checked_at: Option<std::time::Instant>,
+
     /// Warning domain registry for extensible warning indicators
     /// Contains LSP warnings, general warnings, and can be extended by plugins
     warning_domains: WarningDomainRegistry,
@@ -738,6 +926,26 @@ pub struct Editor {
     /// When switching to a terminal in this set, terminal mode is automatically re-entered.
     terminal_mode_resume: std::collections::HashSet<BufferId>,
 
+    /// Terminal buffer that was shown most recently; "Run Selection in Terminal"
+    /// and "Run File" send to it when no terminal is active
+    last_terminal_buffer: Option<BufferId>,
+
+    /// Tasks from the `tasks` config whose terminals are still open, by terminal
+    task_runs: HashMap<crate::services::terminal::TerminalId, task_runner::TaskRun>,
+
+    /// Name of the task run most recently, for "Rerun Last Task"
+    last_task: Option<String>,
+
+    /// Script the terminal input is recorded to (`--record-input`)
+    input_recorder: Option<input_recorder::InputRecorder>,
+
+    /// Finds clickable file locations and hyperlinks in terminal output
+    terminal_links: crate::services::terminal::LinkMatcher,
+
+    /// URLs opened from links. Editors built for tests record them here
+    /// instead of handing them to the system browser.
+    opened_urls: Option<Vec<String>>,
+
     /// Timestamp of the previous mouse click (for multi-click detection)
     previous_click_time: Option<std::time::Instant>,
 
@@ -757,6 +965,9 @@ pub struct Editor {
     /// Event debug dialog state (when event debug modal is open)
     pub(crate) event_debug: Option<event_debug::EventDebug>,
 
+    /// Whether the performance HUD is drawn over the editor
+    perf_hud_visible: bool,
+
     /// Keybinding editor state (when keybinding editor modal is open)
     pub(crate) keybinding_editor: Option<keybinding_editor::KeybindingEditor>,
 
@@ -919,7 +1130,7 @@ impl Editor {
     ) -> AnyhowResult<Self> {
         let grammar_registry = grammar_registry
             .unwrap_or_else(|| crate::primitives::grammar::GrammarRegistry::empty());
-        Self::with_options(
+        let mut editor = Self::with_options(
             config,
             width,
             height,
@@ -930,7 +1141,9 @@ impl Editor {
             time_source,
             color_capability,
             grammar_registry,
-        )
+        )?;
+        editor.opened_urls = Some(Vec::new());
+        Ok(editor)
     }
 
     /// Create a new editor with custom options
@@ -964,12 +1177,18 @@ impl Editor {
         // Load all themes into registry
         let theme_loader = crate::view::theme::ThemeLoader::new(dir_context.themes_dir());
         let theme_registry = theme_loader.load_all();
+        let theme_files_stamp = theme_loader.files_stamp();
+        let theme_error = theme_registry
+            .errors()
+            .first()
+            .map(|error| t!("view.theme_error", error = error.to_string()).to_string());
 
         // Get active theme from registry, falling back to default if not found
-        let theme = theme_registry.get_cloned(&config.theme).unwrap_or_else(|| {
+        let theme_name = crate::services::terminal_background::resolve_theme_name(&config, None);
+        let mut theme = theme_registry.get_cloned(theme_name).unwrap_or_else(|| {
             tracing::warn!(
                 "Theme '{}' not found, falling back to default theme",
-                config.theme.0
+                theme_name.0
             );
             theme_registry
                 .get_cloned(&crate::config::ThemeName(
@@ -977,6 +1196,7 @@ impl Editor {
                 ))
                 .expect("Default theme must exist")
         });
+        theme.set_syntax_styles(&config.syntax_styles);
 
         // Set terminal cursor color to match theme
         theme.set_terminal_cursor_color();
@@ -988,6 +1208,8 @@ impl Editor {
 
         let keybindings = KeybindingResolver::new(&config);
 
+        crate::i18n::set_highlight_missing(config.highlight_missing_translations);
+
         // Create an empty initial buffer
         let mut buffers = HashMap::new();
         let mut event_logs = HashMap::new();
@@ -1065,10 +1287,21 @@ impl Editor {
         let fs_manager = Arc::new(FsManager::new(Arc::clone(&filesystem)));
 
         // Initialize command registry (always available, used by both plugins and core)
-        let command_registry = Arc::new(RwLock::new(CommandRegistry::new()));
+        let mut command_registry = CommandRegistry::new();
+        if let Err(e) = command_registry.load_usage(&dir_context.command_usage_path()) {
+            tracing::warn!("Failed to load command usage: {}", e);
+        }
+        let command_registry = Arc::new(RwLock::new(command_registry));
 
         // Initialize file provider for Quick Open (stored separately for cache management)
         let file_provider = Arc::new(FileProvider::new());
+        // Index the workspace in the background so Quick Open doesn't list
+        // files with a subprocess. Remote workspaces keep the git path.
+        if config.editor.file_index && filesystem.remote_connection_info().is_none() {
+            let file_index = FileIndex::new(working_dir.clone());
+            file_index.refresh();
+            file_provider.set_index(Some(file_index));
+        }
 
         // Initialize Quick Open registry with providers
         let mut quick_open_registry = QuickOpenRegistry::new();
@@ -1097,6 +1330,7 @@ impl Editor {
         // 3. From embedded plugins (for cargo-binstall, when embed-plugins feature is enabled)
         // 4. User plugins directory (~/.config/fresh/plugins)
         // 5. Package manager installed plugins (~/.config/fresh/plugins/packages/*)
+        let mut plugin_load_errors = HashMap::new();
         if plugin_manager.is_active() {
             let mut plugin_dirs: Vec<std::path::PathBuf> = vec![];
 
@@ -1166,6 +1400,17 @@ impl Editor {
                 let (errors, discovered_plugins) =
                     plugin_manager.load_plugins_from_dir_with_config(&plugin_dir, &config.plugins);
 
+                // Remember which plugin each error belongs to for the plugin panel
+                for (name, plugin_config) in &discovered_plugins {
+                    let Some(path) = &plugin_config.path else {
+                        continue;
+                    };
+                    let needle = format!("{:?}", path);
+                    if let Some(err) = errors.iter().find(|err| err.contains(&needle)) {
+                        plugin_load_errors.insert(name.clone(), err.clone());
+                    }
+                }
+
                 // Merge discovered plugins into config
                 // discovered_plugins already contains the merged config (saved enabled state + discovered path)
                 for (name, plugin_config) in discovered_plugins {
@@ -1193,6 +1438,8 @@ impl Editor {
         let check_for_updates = config.check_for_updates;
         let show_menu_bar = config.editor.show_menu_bar;
         let show_tab_bar = config.editor.show_tab_bar;
+        let terminal_links =
+            crate::services::terminal::LinkMatcher::new(&config.terminal.link_patterns);
 
         // Start periodic update checker if enabled (also sends daily telemetry)
         let update_checker = if check_for_updates {
@@ -1223,11 +1470,15 @@ impl Editor {
             pending_grammars: Vec::new(),
             theme,
             theme_registry,
+            terminal_appearance: None,
             ansi_background: None,
             ansi_background_path: None,
             background_fade: crate::primitives::ansi_background::DEFAULT_BACKGROUND_FADE,
             keybindings,
             clipboard: crate::services::clipboard::Clipboard::new(),
+            paste_cycle: None,
+            line_drawing: false,
+            abbreviation_expansion: None,
             should_quit: false,
             should_detach: false,
             session_mode: false,
@@ -1235,9 +1486,15 @@ impl Editor {
             session_name: None,
             pending_escape_sequences: Vec::new(),
             restart_with_dir: None,
-            status_message: None,
+            status_message: theme_error,
             plugin_status_message: None,
+            plugin_status_segments: HashMap::new(),
+            status_bar_git_branch: None,
+            status_bar_clock: None,
+            status_bar_refreshed_at: None,
             plugin_errors: Vec::new(),
+            plugin_log: Vec::new(),
+            plugin_load_errors,
             prompt: None,
             terminal_width: width,
             terminal_height: height,
@@ -1250,6 +1507,7 @@ impl Editor {
             split_view_states,
             previous_viewports: HashMap::new(),
             scroll_sync_manager: ScrollSyncManager::new(),
+            markdown_previews: HashMap::new(),
             file_explorer: None,
             fs_manager,
             filesystem,
@@ -1260,6 +1518,7 @@ impl Editor {
             file_explorer_width_percent: file_explorer_width,
             pending_file_explorer_show_hidden: None,
             pending_file_explorer_show_gitignored: None,
+            pending_file_explorer_reveal: false,
             menu_bar_visible: show_menu_bar,
             file_explorer_decorations: HashMap::new(),
             file_explorer_decoration_cache:
@@ -1274,16 +1533,27 @@ impl Editor {
             menu_state: crate::view::ui::MenuState::new(dir_context.themes_dir()),
             menus: crate::config::MenuConfig::translated(),
             working_dir,
-            position_history: PositionHistory::new(),
             in_navigation: false,
             next_lsp_request_id: 0,
             pending_completion_request: None,
             completion_items: None,
+            snippets: crate::services::snippets::SnippetLibrary::new(&dir_context.config_dir),
             scheduled_completion_trigger: None,
             pending_goto_definition_request: None,
             pending_hover_request: None,
             pending_references_request: None,
             pending_references_symbol: String::new(),
+            pending_document_symbols_request: None,
+            quick_open_symbols: None,
+            quick_open_preview: None,
+            selection_expansion: None,
+            buffer_mru: Vec::new(),
+            buffer_picker: None,
+            unicode_target_prompt: None,
+            client_views: HashMap::new(),
+            active_client_view: None,
+            client_names: HashMap::new(),
+            key_modifiers: KeyModifiers::NONE,
             pending_signature_help_request: None,
             pending_code_actions_request: None,
             pending_inlay_hints_request: None,
@@ -1312,6 +1582,7 @@ impl Editor {
             lsp_status: String::new(),
             mouse_state: MouseState::default(),
             tab_context_menu: None,
+            closed_tabs: Vec::new(),
             cached_layout: CachedLayout::default(),
             command_registry,
             quick_open_registry,
@@ -1323,7 +1594,12 @@ impl Editor {
             prompt_histories: {
                 // Load prompt histories from disk if available
                 let mut histories = HashMap::new();
-                for history_name in ["search", "replace", "goto_line"] {
+                for history_name in [
+                    "search",
+                    "replace",
+                    "goto_line",
+                    unicode_input::UNICODE_HISTORY,
+                ] {
                     let path = dir_context.prompt_history_path(history_name);
                     let history = crate::input::input_history::InputHistory::load_from_file(&path)
                         .unwrap_or_else(|e| {
@@ -1341,13 +1617,16 @@ impl Editor {
             lsp_log_messages: Vec::new(),
             diagnostic_result_ids: HashMap::new(),
             stored_diagnostics: HashMap::new(),
+            linter_diagnostics: HashMap::new(),
             stored_folding_ranges: HashMap::new(),
             event_broadcaster: crate::model::control_event::EventBroadcaster::default(),
             bookmarks: HashMap::new(),
+            line_bookmarks: HashMap::new(),
             search_case_sensitive: true,
             search_whole_word: false,
             search_use_regex: false,
             search_confirm_each: false,
+            search_preserve_case: false,
             macros: HashMap::new(),
             macro_recording: None,
             last_macro_register: None,
@@ -1357,12 +1636,22 @@ impl Editor {
             #[cfg(feature = "plugins")]
             plugin_render_requested: false,
             chord_state: Vec::new(),
+            chord_deadline: None,
+            vim: crate::input::vim::VimState::new(),
+            repeat: crate::input::repeat::RepeatState::new(),
             pending_lsp_confirmation: None,
             pending_close_buffer: None,
+            quit_review: None,
+            pending_export: None,
             auto_revert_enabled: true,
             last_auto_revert_poll: time_source.now(),
             last_file_tree_poll: time_source.now(),
+            last_theme_poll: time_source.now(),
+            theme_files_stamp,
+            line_number_mode_noted: None,
             file_mod_times: HashMap::new(),
+            files_deleted_on_disk: HashSet::new(),
+            external_change_conflicts: HashMap::new(),
             dir_mod_times: HashMap::new(),
             file_rapid_change_counts: HashMap::new(),
             file_open_state: None,
@@ -1378,10 +1667,16 @@ impl Editor {
             time_source: time_source.clone(),
             last_auto_recovery_save: time_source.now(),
             last_persistent_auto_save: time_source.now(),
+            last_buffer_edit: None,
+            auto_save_pending: Vec::new(),
+            workspace_tracker: workspace::WorkspaceTracker::new(false, time_source.now()),
             active_custom_contexts: HashSet::new(),
             editor_mode: None,
             warning_log: None,
             status_log_path: None,
+            editor_log_path: None,
+            editor_log_offset: 0,
+            editor_log_checked_at: None,
             warning_domains: WarningDomainRegistry::new(),
             update_checker,
             terminal_manager: crate::services::terminal::TerminalManager::new(),
@@ -1391,12 +1686,19 @@ impl Editor {
             terminal_mode: false,
             keyboard_capture: false,
             terminal_mode_resume: std::collections::HashSet::new(),
+            last_terminal_buffer: None,
+            task_runs: HashMap::new(),
+            last_task: None,
+            input_recorder: None,
+            terminal_links,
+            opened_urls: None,
             previous_click_time: None,
             previous_click_position: None,
             click_count: 0,
             settings_state: None,
             calibration_wizard: None,
             event_debug: None,
+            perf_hud_visible: false,
             keybinding_editor: None,
             key_translator: crate::input::key_translator::KeyTranslator::load_from_config_dir(
                 &dir_context.config_dir,
@@ -1938,6 +2240,27 @@ impl Editor {
         true
     }
 
+    /// Drop a pending chord prefix once `editor.chord_timeout_ms` has passed
+    /// without the next key arriving.
+    /// Returns true if the prefix was dropped (the status bar hint changes).
+    pub fn check_chord_timeout(&mut self) -> bool {
+        let Some(deadline) = self.chord_deadline else {
+            return false;
+        };
+        if self.chord_state.is_empty() {
+            self.chord_deadline = None;
+            return false;
+        }
+        if Instant::now() < deadline {
+            return false;
+        }
+
+        tracing::debug!("Chord sequence timed out, clearing state");
+        self.chord_state.clear();
+        self.chord_deadline = None;
+        true
+    }
+
     /// Load an ANSI background image from a user-provided path
     fn load_ansi_background(&mut self, input: &str) -> AnyhowResult<()> {
         let trimmed = input.trim();
@@ -2001,6 +2324,12 @@ impl Editor {
             return; // No change
         }
 
+        // Leaving a buffer counts as a focus change for auto-save. The save
+        // waits for the next tick so that a buffer being closed is not written.
+        if self.config.editor.auto_save == AutoSaveMode::OnFocusChange {
+            self.auto_save_pending.push(self.active_buffer());
+        }
+
         // Dismiss transient popups and clear hover state when switching buffers
         self.on_editor_focus_lost();
 
@@ -2020,6 +2349,8 @@ impl Editor {
 
         // Update split manager (single source of truth)
         self.split_manager.set_active_buffer_id(buffer_id);
+        self.touch_buffer_mru(previous);
+        self.touch_buffer_mru(buffer_id);
 
         // Switch per-buffer view state in the active split
         let active_split = self.split_manager.active_split();
@@ -2030,6 +2361,10 @@ impl Editor {
             view_state.push_focus(previous);
         }
 
+        if self.is_terminal_buffer(buffer_id) {
+            self.last_terminal_buffer = Some(buffer_id);
+        }
+
         // If switching to a terminal buffer that should resume terminal mode, re-enter it
         if self.terminal_mode_resume.contains(&buffer_id) && self.is_terminal_buffer(buffer_id) {
             self.terminal_mode = true;
@@ -2043,8 +2378,10 @@ impl Editor {
         // Ensure the newly active tab is visible
         self.ensure_active_tab_visible(active_split, buffer_id, self.effective_tabs_width());
 
-        // Note: We don't sync file explorer here to avoid flicker during tab switches.
-        // File explorer syncs when explicitly focused via focus_file_explorer().
+        // Note: We don't sync file explorer here to avoid flicker during tab switches,
+        // unless follow mode is on. Otherwise it syncs when explicitly focused via
+        // focus_file_explorer().
+        self.follow_active_file_in_explorer();
 
         // Update plugin state snapshot BEFORE firing the hook so that
         // the handler sees the new active buffer, not the old one.
@@ -2104,13 +2441,15 @@ impl Editor {
 
             // Handle buffer change side effects
             if previous_buffer != buffer_id {
-                self.position_history.commit_pending_movement();
+                self.touch_buffer_mru(previous_buffer);
+                self.touch_buffer_mru(buffer_id);
                 if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
                     view_state.add_buffer(buffer_id);
                     view_state.push_focus(previous_buffer);
                 }
-                // Note: We don't sync file explorer here to avoid flicker during split focus changes.
-                // File explorer syncs when explicitly focused via focus_file_explorer().
+                // Note: We don't sync file explorer here to avoid flicker during split focus
+                // changes, unless follow mode is on.
+                self.follow_active_file_in_explorer();
             }
         } else {
             // Same split, different buffer (tab switch) - use set_active_buffer for terminal resume
@@ -2235,6 +2574,9 @@ impl Editor {
             let state = self.buffers.get_mut(&active_buf).unwrap();
             state.apply(cursors, event);
         }
+        if event.modifies_buffer() {
+            self.last_buffer_edit = Some(self.time_source.now());
+        }
 
         // 1c. Invalidate layouts for all views of this buffer after content changes
         // Note: recovery_pending is set automatically by the buffer on edits
@@ -2243,6 +2585,7 @@ impl Editor {
                 self.invalidate_layouts_for_buffer(self.active_buffer());
                 self.schedule_semantic_tokens_full_refresh(self.active_buffer());
                 self.schedule_folding_ranges_refresh(self.active_buffer());
+                self.schedule_search_refresh(self.active_buffer());
             }
             Event::Batch { events, .. } => {
                 let has_edits = events
@@ -2252,6 +2595,7 @@ impl Editor {
                     self.invalidate_layouts_for_buffer(self.active_buffer());
                     self.schedule_semantic_tokens_full_refresh(self.active_buffer());
                     self.schedule_folding_ranges_refresh(self.active_buffer());
+                    self.schedule_search_refresh(self.active_buffer());
                 }
             }
             _ => {}
@@ -2359,6 +2703,15 @@ impl Editor {
         // Apply bulk edits
         let _delta = state.buffer.apply_bulk_edits(&edit_refs);
 
+        // Keep the tabstops of a snippet being filled in on their text
+        // (edits are in descending order, so each one sees original positions)
+        if let Some(session) = &mut state.snippet_session {
+            for (pos, del, text) in &edits {
+                session.adjust_for_delete(*pos, *del);
+                session.adjust_for_insert(*pos, text.len());
+            }
+        }
+
         // Snapshot buffer state after edits (for redo)
         let new_snapshot = state.buffer.snapshot_buffer_state();
 
@@ -2367,19 +2720,29 @@ impl Editor {
         let mut new_cursors: Vec<(CursorId, usize, Option<usize>)> = old_cursors.clone();
 
         // Calculate position adjustments from edits (sorted ascending by position)
-        // Each entry is (edit_position, delta) where delta = insert_len - delete_len
-        let mut position_deltas: Vec<(usize, isize)> = Vec::new();
-        for (pos, del_len, text) in &edits {
-            let delta = text.len() as isize - *del_len as isize;
-            position_deltas.push((*pos, delta));
-        }
-        position_deltas.sort_by_key(|(pos, _)| *pos);
+        // Each entry is (edit_position, delta, cursor_id) where delta = insert_len - delete_len
+        let mut position_deltas: Vec<(usize, isize, CursorId)> = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Insert {
+                    position,
+                    text,
+                    cursor_id,
+                } => Some((*position, text.len() as isize, *cursor_id)),
+                Event::Delete {
+                    range, cursor_id, ..
+                } => Some((range.start, -(range.len() as isize), *cursor_id)),
+                _ => None,
+            })
+            .collect();
+        position_deltas.sort_by_key(|(pos, _, _)| *pos);
 
-        // Helper: calculate cumulative shift for a position based on edits at lower positions
-        let calc_shift = |original_pos: usize| -> isize {
+        // Helper: calculate cumulative shift for a position based on edits at lower positions,
+        // optionally ignoring the edits made by one cursor
+        let calc_shift = |original_pos: usize, exclude: Option<CursorId>| -> isize {
             let mut shift: isize = 0;
-            for (edit_pos, delta) in &position_deltas {
-                if *edit_pos < original_pos {
+            for (edit_pos, delta, edit_cursor) in &position_deltas {
+                if *edit_pos < original_pos && exclude != Some(*edit_cursor) {
                     shift += delta;
                 }
             }
@@ -2391,8 +2754,9 @@ impl Editor {
         // These take precedence over implicit cursor updates from Insert/Delete
         for (cursor_id, ref mut pos, ref mut anchor) in &mut new_cursors {
             let mut found_move_cursor = false;
-            // Save original position before any modifications - needed for shift calculation
+            // Save original position and anchor before any modifications - needed for shift calculation
             let original_pos = *pos;
+            let original_anchor = *anchor;
 
             // Check if this cursor has an Insert at its original position (auto-close pattern).
             // For auto-close, Insert is at cursor position and MoveCursor is relative to original state.
@@ -2413,15 +2777,23 @@ impl Editor {
                 {
                     if event_cursor == cursor_id {
                         // Only adjust for shifts if the Insert was at the cursor's original position
-                        // (like auto-close). For other operations (like indent where Insert is at
-                        // line start), the MoveCursor already accounts for the shift.
-                        let shift = if insert_at_cursor_pos {
-                            calc_shift(original_pos)
+                        // (like auto-close or surround). For other operations (like indent where
+                        // Insert is at line start), the MoveCursor already accounts for the shift.
+                        // The cursor's own edits are already reflected in its MoveCursor.
+                        if insert_at_cursor_pos {
+                            let shift = calc_shift(original_pos, Some(*cursor_id));
+                            *pos = (*new_position as isize + shift) as usize;
+                            *anchor = new_anchor.map(|a| {
+                                let anchor_shift = calc_shift(
+                                    original_anchor.unwrap_or(original_pos),
+                                    Some(*cursor_id),
+                                );
+                                (a as isize + anchor_shift) as usize
+                            });
                         } else {
-                            0
-                        };
-                        *pos = (*new_position as isize + shift) as usize;
-                        *anchor = *new_anchor;
+                            *pos = *new_position;
+                            *anchor = *new_anchor;
+                        }
                         found_move_cursor = true;
                     }
                 }
@@ -2438,7 +2810,7 @@ impl Editor {
                         } if event_cursor == cursor_id => {
                             // For insert, cursor moves to end of inserted text
                             // Account for shifts from edits at lower positions
-                            let shift = calc_shift(*position);
+                            let shift = calc_shift(*position, None);
                             let adjusted_pos = (*position as isize + shift) as usize;
                             *pos = adjusted_pos + text.len();
                             *anchor = None;
@@ -2450,7 +2822,7 @@ impl Editor {
                         } if event_cursor == cursor_id => {
                             // For delete, cursor moves to start of deleted range
                             // Account for shifts from edits at lower positions
-                            let shift = calc_shift(range.start);
+                            let shift = calc_shift(range.start, None);
                             *pos = (range.start as isize + shift) as usize;
                             *anchor = None;
                         }
@@ -2695,6 +3067,10 @@ impl Editor {
         use crate::view::ui::view_pipeline::ViewLineIterator;
 
         let active_split = self.split_manager.active_split();
+        // Scrolling up stops following a log file
+        if line_offset < 0 {
+            self.pause_log_follow(self.active_buffer());
+        }
 
         // Check if this split is in a scroll sync group (anchor-based sync for diffs)
         // Mark both splits to skip ensure_visible so cursor doesn't override scroll
@@ -2753,16 +3129,9 @@ impl Editor {
                             .viewport
                             .scroll_view_lines(&view_lines, line_offset);
                     } else {
-                        // No view transform - use traditional buffer-based scrolling
-                        if line_offset > 0 {
-                            view_state
-                                .viewport
-                                .scroll_down(buffer, line_offset as usize);
-                        } else {
-                            view_state
-                                .viewport
-                                .scroll_up(buffer, line_offset.unsigned_abs());
-                        }
+                        // No view transform - scroll by buffer lines, stepping over
+                        // folds so linked splits move by the same lines shown
+                        view_state.scroll_shown_lines(buffer, &state.marker_list, line_offset);
                     }
                     // Mark to skip ensure_visible on next render so the scroll isn't undone
                     view_state.viewport.set_skip_ensure_visible();
@@ -2864,28 +3233,23 @@ impl Editor {
                 continue;
             };
 
-            if let Some(state) = self.buffers.get_mut(&buffer_id) {
-                let buffer = &mut state.buffer;
-                let view_state = self.split_view_states.get_mut(&split_id);
-
-                if let Some(view_state) = view_state {
-                    // Recenter viewport on cursor
-                    let cursor = *view_state.cursors.primary();
-                    let viewport_height = view_state.viewport.visible_line_count();
-                    let target_rows_from_top = viewport_height / 2;
-
-                    // Move backwards from cursor position target_rows_from_top lines
-                    let mut iter = buffer.line_iterator(cursor.position, 80);
-                    for _ in 0..target_rows_from_top {
-                        if iter.prev().is_none() {
-                            break;
-                        }
-                    }
-                    let new_top_byte = iter.current_position();
-                    view_state.viewport.top_byte = new_top_byte;
-                    // Mark to skip ensure_visible on next render so the scroll isn't undone
-                    view_state.viewport.set_skip_ensure_visible();
-                }
+            if let (Some(state), Some(view_state)) = (
+                self.buffers.get_mut(&buffer_id),
+                self.split_view_states.get_mut(&split_id),
+            ) {
+                // Recenter viewport on cursor, skipping lines hidden by folds
+                let hidden: Vec<(usize, usize)> = view_state
+                    .folds
+                    .resolved_ranges(&state.buffer, &state.marker_list)
+                    .into_iter()
+                    .map(|r| (r.start_byte, r.end_byte))
+                    .collect();
+                let cursor = *view_state.cursors.primary();
+                view_state
+                    .viewport
+                    .center_on_cursor(&mut state.buffer, &cursor, &hidden);
+                // Mark to skip ensure_visible on next render so the scroll isn't undone
+                view_state.viewport.set_skip_ensure_visible();
             }
         }
     }
@@ -2966,6 +3330,8 @@ impl Editor {
             self.active_custom_contexts
                 .remove(crate::types::context_keys::SESSION_MODE);
         }
+        // OSC 52 copies have to reach the client's terminal, not our stdout
+        self.clipboard.set_passthrough(session_mode);
     }
 
     /// Check if running in session mode
@@ -2999,6 +3365,20 @@ impl Editor {
         std::mem::take(&mut self.pending_escape_sequences)
     }
 
+    /// Take the OSC 52 clipboard sequences queued by copies (session mode
+    /// only), for the clients whose terminal accepts them
+    pub fn take_clipboard_sequences(&mut self) -> Vec<u8> {
+        self.clipboard.take_passthrough_sequences().into_bytes()
+    }
+
+    /// Set the colors the terminal can show; frames are converted to them
+    pub fn set_color_capability(
+        &mut self,
+        color_capability: crate::view::color_support::ColorCapability,
+    ) {
+        self.color_capability = color_capability;
+    }
+
     /// Check if the editor should restart with a new working directory
     pub fn should_restart(&self) -> bool {
         self.restart_with_dir.is_some()
@@ -3040,6 +3420,53 @@ impl Editor {
         &self.theme
     }
 
+    /// Name of the theme the config selects, with "auto" resolved from the
+    /// terminal background
+    pub(crate) fn effective_theme_name(&self) -> crate::config::ThemeName {
+        crate::services::terminal_background::resolve_theme_name(
+            &self.config,
+            self.terminal_appearance,
+        )
+        .clone()
+    }
+
+    /// Look up a theme by name, with the config's syntax style overrides applied
+    pub(crate) fn load_theme(&self, name: &str) -> Option<crate::view::theme::Theme> {
+        let mut theme = self.theme_registry.get_cloned(name)?;
+        theme.set_syntax_styles(&self.config.syntax_styles);
+        Some(theme)
+    }
+
+    /// Whether the theme follows the terminal background, so the terminal
+    /// should be asked for its background color
+    pub fn wants_terminal_background(&self) -> bool {
+        self.config.theme.is_auto()
+    }
+
+    /// Record the terminal's background color. With the "auto" theme this
+    /// switches to `light_theme` or `dark_theme` to match it.
+    pub fn set_terminal_background(&mut self, r: u8, g: u8, b: u8) {
+        let appearance = crate::services::terminal_background::Appearance::from_rgb(r, g, b);
+        let old_theme = self.effective_theme_name();
+        self.terminal_appearance = Some(appearance);
+        let new_theme = self.effective_theme_name();
+        if old_theme == new_theme {
+            return;
+        }
+
+        if let Some(theme) = self.load_theme(&new_theme) {
+            self.theme = theme;
+            self.theme.set_terminal_cursor_color();
+            tracing::info!(
+                "Terminal background is {:?}, switched to theme '{}'",
+                appearance,
+                new_theme.0
+            );
+        } else {
+            tracing::error!("Theme '{}' not found", new_theme.0);
+        }
+    }
+
     /// Check if the settings dialog is open and visible
     pub fn is_settings_open(&self) -> bool {
         self.settings_state.as_ref().is_some_and(|s| s.visible)
@@ -3047,42 +3474,14 @@ impl Editor {
 
     /// Request the editor to quit
     pub fn quit(&mut self) {
-        // Check for unsaved buffers
-        let modified_count = self.count_modified_buffers();
-        if modified_count > 0 {
-            // Prompt user for confirmation with translated keys
-            let discard_key = t!("prompt.key.discard").to_string();
-            let cancel_key = t!("prompt.key.cancel").to_string();
-            let msg = if modified_count == 1 {
-                t!(
-                    "prompt.quit_modified_one",
-                    discard_key = discard_key,
-                    cancel_key = cancel_key
-                )
-                .to_string()
-            } else {
-                t!(
-                    "prompt.quit_modified_many",
-                    count = modified_count,
-                    discard_key = discard_key,
-                    cancel_key = cancel_key
-                )
-                .to_string()
-            };
-            self.start_prompt(msg, PromptType::ConfirmQuitWithModified);
+        // Ask about unsaved work first
+        if self.has_unsaved_buffers() {
+            self.start_quit_dialog();
         } else {
             self.should_quit = true;
         }
     }
 
-    /// Count the number of modified buffers
-    fn count_modified_buffers(&self) -> usize {
-        self.buffers
-            .values()
-            .filter(|state| state.buffer.is_modified())
-            .count()
-    }
-
     /// Resize all buffers to match new terminal size
     pub fn resize(&mut self, width: u16, height: u16) {
         // Update terminal dimensions for future buffer creation
@@ -3221,6 +3620,14 @@ impl Editor {
         // Clear status message since hints are now shown in the popup
         self.status_message = None;
 
+        // Symbols are fetched again the first time `@` is typed
+        self.quick_open_symbols = None;
+
+        // Pick up files created outside the editor since the last walk
+        if let Some(index) = self.file_provider.index().filter(|index| index.is_stale()) {
+            index.refresh();
+        }
+
         // Start with ">" prefix for command mode by default
         let mut prompt = Prompt::with_suggestions(String::new(), PromptType::QuickOpen, vec![]);
         prompt.input = ">".to_string();
@@ -3233,6 +3640,11 @@ impl Editor {
 
     /// Update Quick Open suggestions based on current input
     fn update_quick_open_suggestions(&mut self, input: &str) {
+        // Leaving the line mode puts back the scroll position it previewed
+        if !input.starts_with(':') {
+            self.restore_quick_open_preview();
+        }
+
         let suggestions = if input.starts_with('>') {
             // Command mode
             let query = &input[1..];
@@ -3255,7 +3667,12 @@ impl Editor {
         } else if input.starts_with(':') {
             // Go to line mode
             let line_str = &input[1..];
+            self.preview_quick_open_line(line_str);
             self.get_goto_line_suggestions(line_str)
+        } else if input.starts_with('@') {
+            // Symbol mode
+            let query = &input[1..];
+            self.get_symbol_suggestions(query)
         } else {
             // File mode (default)
             self.get_file_suggestions(input)
@@ -3360,6 +3777,14 @@ impl Editor {
         }]
     }
 
+    /// Size of the workspace file index, once its first walk has finished
+    pub fn file_index_stats(&self) -> Option<crate::services::file_index::FileIndexStats> {
+        self.file_provider
+            .index()
+            .filter(|index| index.is_ready())
+            .map(|index| index.stats())
+    }
+
     /// Get file suggestions for Quick Open
     fn get_file_suggestions(&self, query: &str) -> Vec<Suggestion> {
         // Use the file provider's file loading mechanism
@@ -3401,7 +3826,7 @@ impl Editor {
             ) {
                 self.prompt = None;
                 // Also cancel interactive replace if active
-                self.interactive_replace_state = None;
+                self.end_interactive_replace();
                 // Clear search highlights from current buffer
                 let ns = self.search_namespace.clone();
                 let state = self.active_state_mut();
@@ -3599,6 +4024,16 @@ impl Editor {
 
     /// Cancel the current prompt and return to normal mode
     pub fn cancel_prompt(&mut self) {
+        // The Unicode picker hands back the prompt it was opened from
+        if self
+            .prompt
+            .as_ref()
+            .is_some_and(|prompt| prompt.prompt_type == PromptType::InsertUnicodeChar)
+        {
+            self.cancel_unicode_picker();
+            return;
+        }
+
         // Extract theme to restore if this is a SelectTheme prompt
         let theme_to_restore = if let Some(ref prompt) = self.prompt {
             if let PromptType::SelectTheme { original_theme } = &prompt.prompt_type {
@@ -3645,6 +4080,10 @@ impl Editor {
                     self.file_open_state = None;
                     self.file_browser_layout = None;
                 }
+                PromptType::AdjustColor { buffer_id, .. } => {
+                    let buffer_id = *buffer_id;
+                    self.clear_color_preview(buffer_id);
+                }
                 PromptType::AsyncPrompt => {
                     // Resolve the pending async prompt callback with null (cancelled)
                     if let Some(callback_id) = self.pending_async_prompt_callback.take() {
@@ -3655,9 +4094,13 @@ impl Editor {
                 _ => {}
             }
         }
+        self.restore_quick_open_preview();
+        self.cancel_buffer_picker();
 
         self.prompt = None;
+        self.quit_review = None;
         self.pending_search_range = None;
+        self.pending_export = None;
         self.status_message = Some(t!("search.cancelled").to_string());
 
         // Restore original theme if we were in SelectTheme prompt
@@ -3724,7 +4167,15 @@ impl Editor {
                     | PromptType::SelectTheme { .. }
                     | PromptType::SelectLocale
                     | PromptType::SwitchToTab
+                    | PromptType::SwitchBuffer
+                    | PromptType::SwitchTerminal
+                    | PromptType::RunTask
+                    | PromptType::BrowseBookmarks
+                    | PromptType::PasteFromHistory
+                    | PromptType::InsertUnicodeChar
+                    | PromptType::ExportDestination
                     | PromptType::SetLanguage
+                    | PromptType::NewScratchBuffer
                     | PromptType::SetEncoding
                     | PromptType::SetLineEnding
                     | PromptType::Plugin { .. }
@@ -3806,6 +4257,24 @@ impl Editor {
                 }
             }
 
+            // For replace prompts, keep the prompt open until the search
+            // pattern compiles so an invalid regex can be fixed in place
+            let replace_search = match &prompt.prompt_type {
+                PromptType::ReplaceSearch | PromptType::QueryReplaceSearch => Some(&final_input),
+                PromptType::Replace { search } | PromptType::QueryReplace { search } => {
+                    Some(search)
+                }
+                _ => None,
+            };
+            let regex_error = replace_search
+                .and_then(|search| self.build_replace_regex(search).err())
+                .map(|e| regex_replace::regex_error_message(&e));
+            if let Some(error) = regex_error {
+                self.prompt = Some(prompt);
+                self.set_status_message(t!("replace.invalid_regex", error = error).to_string());
+                return None;
+            }
+
             // Add to appropriate history based on prompt type
             if let Some(key) = Self::prompt_type_to_history_key(&prompt.prompt_type) {
                 let history = self.get_or_create_prompt_history(&key);
@@ -3980,6 +4449,9 @@ impl Editor {
                 // For OpenFile/SwitchProject/SaveFileAs, update the file browser filter (native implementation)
                 self.update_file_open_filter();
             }
+            PromptType::AdjustColor { buffer_id, literal } => {
+                self.preview_adjusted_color(buffer_id, &literal, &input);
+            }
             PromptType::Plugin { custom_type } => {
                 // Reset history navigation when user types - allows Up to navigate history
                 let key = format!("plugin:{}", custom_type);
@@ -4004,17 +4476,24 @@ impl Editor {
                 }
             }
             PromptType::SwitchToTab
+            | PromptType::SwitchBuffer
+            | PromptType::SwitchTerminal
+            | PromptType::RunTask
             | PromptType::SelectTheme { .. }
             | PromptType::StopLspServer
+            | PromptType::PasteFromHistory
             | PromptType::SetLanguage
+            | PromptType::NewScratchBuffer
             | PromptType::SetEncoding
             | PromptType::SetLineEnding => {
                 if let Some(prompt) = &mut self.prompt {
                     prompt.filter_suggestions(false);
                 }
             }
-            PromptType::SelectLocale => {
-                // Locale selection also matches on description (language names)
+            PromptType::InsertUnicodeChar => self.update_unicode_picker(),
+            PromptType::SelectLocale | PromptType::BrowseBookmarks => {
+                // Locale selection also matches on description (language names),
+                // and bookmarks on the preview of their line
                 if let Some(prompt) = &mut self.prompt {
                     prompt.filter_suggestions(true);
                 }
@@ -4055,6 +4534,7 @@ impl Editor {
                     semantic_tokens_full_delta,
                     semantic_tokens_range,
                     folding_ranges_supported,
+                    selection_ranges_supported,
                 } => {
                     tracing::info!("LSP server initialized for language: {}", language);
                     tracing::debug!(
@@ -4078,6 +4558,7 @@ impl Editor {
                             semantic_tokens_range,
                         );
                         lsp.set_folding_ranges_supported(&language, folding_ranges_supported);
+                        lsp.set_selection_ranges_supported(&language, selection_ranges_supported);
                     }
 
                     // Send didOpen for all open buffers of this language
@@ -4152,6 +4633,13 @@ impl Editor {
                         }
                     }
                 }
+                AsyncMessage::LspMemoryLimitExceeded {
+                    language,
+                    rss_bytes,
+                    limit_bytes,
+                } => {
+                    self.handle_lsp_memory_limit_exceeded(&language, rss_bytes, limit_bytes);
+                }
                 AsyncMessage::LspCompletion { request_id, items } => {
                     if let Err(e) = self.handle_completion_response(request_id, items) {
                         tracing::error!("Error handling completion response: {}", e);
@@ -4221,6 +4709,15 @@ impl Editor {
                 } => {
                     self.handle_lsp_folding_ranges(request_id, uri, ranges);
                 }
+                AsyncMessage::LspDocumentSymbols {
+                    request_id,
+                    symbols,
+                } => {
+                    self.handle_document_symbols_response(request_id, symbols);
+                }
+                AsyncMessage::LspSelectionRanges { request_id, ranges } => {
+                    self.handle_selection_ranges_response(request_id, ranges);
+                }
                 AsyncMessage::LspSemanticTokens {
                     request_id,
                     uri,
@@ -4247,6 +4744,9 @@ impl Editor {
                 AsyncMessage::FileExplorerRefreshNode(node_id) => {
                     self.handle_file_explorer_refresh_node(node_id);
                 }
+                AsyncMessage::FileExplorerDirLoaded { node_id, result } => {
+                    self.handle_file_explorer_dir_loaded(node_id, result);
+                }
                 AsyncMessage::FileExplorerExpandedToPath(view) => {
                     self.handle_file_explorer_expanded_to_path(view);
                 }
@@ -4395,7 +4895,11 @@ impl Editor {
                         self.sync_terminal_to_buffer(buffer_id);
 
                         // Append exit message to the backing file and reload
-                        let exit_msg = "\n[Terminal process exited]\n";
+                        let task_summary = self.finish_task_run(terminal_id);
+                        let exit_msg = match &task_summary {
+                            Some(summary) => format!("\n[{}]\n", summary),
+                            None => "\n[Terminal process exited]\n".to_string(),
+                        };
 
                         if let Some(backing_path) =
                             self.terminal_backing_files.get(&terminal_id).cloned()
@@ -4425,9 +4929,9 @@ impl Editor {
                         // Remove from terminal_buffers so it's no longer treated as a terminal
                         self.terminal_buffers.remove(&buffer_id);
 
-                        self.set_status_message(
-                            t!("terminal.exited", id = terminal_id.0).to_string(),
-                        );
+                        self.set_status_message(task_summary.unwrap_or_else(|| {
+                            t!("terminal.exited", id = terminal_id.0).to_string()
+                        }));
                     }
                     self.terminal_manager.close(terminal_id);
                 }
@@ -4505,9 +5009,15 @@ impl Editor {
         // Poll for file changes (auto-revert) and file tree changes
         let file_changes = self.poll_file_changes();
         let tree_changes = self.poll_file_tree_changes();
+        let theme_changes = self.poll_theme_changes();
 
         // Trigger render if any async messages, plugin commands were processed, or plugin requested render
-        needs_render || processed_any_commands || plugin_render || file_changes || tree_changes
+        needs_render
+            || processed_any_commands
+            || plugin_render
+            || file_changes
+            || tree_changes
+            || theme_changes
     }
 
     /// Update LSP status bar string from active progress operations
@@ -4673,6 +5183,22 @@ impl Editor {
                     selection: primary_selection.clone(),
                 });
 
+                // Composite buffers track the focused pane's source line
+                // themselves; large files only know lines once indexed
+                let active_buffer = self.active_buffer();
+                snapshot.primary_cursor_line = if self.is_composite_buffer(active_buffer) {
+                    self.buffers
+                        .get(&active_buffer)
+                        .map(|state| state.primary_cursor_line_number.value())
+                } else {
+                    self.buffers.get(&active_buffer).and_then(|state| {
+                        state
+                            .buffer
+                            .line_count()
+                            .map(|_| state.buffer.get_line_number(primary_position))
+                    })
+                };
+
                 // All cursors
                 snapshot.all_cursors = active_cursors
                     .iter()
@@ -4707,6 +5233,7 @@ impl Editor {
                 });
             } else {
                 snapshot.primary_cursor = None;
+                snapshot.primary_cursor_line = None;
                 snapshot.all_cursors.clear();
                 snapshot.viewport = None;
                 snapshot.selected_text = None;
@@ -4785,6 +5312,13 @@ impl Editor {
             PluginCommand::DeleteRange { buffer_id, range } => {
                 self.handle_delete_range(buffer_id, range);
             }
+            PluginCommand::ReplaceRange {
+                buffer_id,
+                range,
+                text,
+            } => {
+                self.handle_replace_range(buffer_id, range, text);
+            }
             PluginCommand::InsertAtCursor { text } => {
                 self.handle_insert_at_cursor(text);
             }
@@ -4941,6 +5475,9 @@ impl Editor {
             PluginCommand::RemoveMenu { menu_label } => {
                 self.handle_remove_menu(menu_label);
             }
+            PluginCommand::PluginUnloaded { name, actions } => {
+                self.handle_plugin_unloaded(name, actions);
+            }
 
             // ==================== Split Commands ====================
             PluginCommand::FocusSplit { split_id } => {
@@ -5084,6 +5621,19 @@ impl Editor {
             PluginCommand::SetStatus { message } => {
                 self.handle_set_status(message);
             }
+            PluginCommand::SetStatusBarSegment {
+                name,
+                text,
+                command,
+            } => {
+                self.handle_set_status_bar_segment(name, text, command);
+            }
+            PluginCommand::RemoveStatusBarSegment { name } => {
+                self.plugin_status_segments.remove(&name);
+            }
+            PluginCommand::LogPluginIssue { message } => {
+                self.log_plugin_issue(&message);
+            }
             PluginCommand::ApplyTheme { theme_name } => {
                 self.apply_theme(&theme_name);
             }
@@ -5306,13 +5856,23 @@ impl Editor {
                     // Receiver may be dropped if editor is shutting down
                     #[allow(clippy::let_underscore_must_use)]
                     let handle = runtime.spawn(async move {
-                        let mut child = match TokioCommand::new(&command)
-                            .args(&args)
+                        let mut cmd = TokioCommand::new(&command);
+                        cmd.args(&args)
                             .current_dir(&effective_cwd)
                             .stdout(std::process::Stdio::piped())
                             .stderr(std::process::Stdio::piped())
-                            .spawn()
+                            // Killing the task must stop the process too
+                            .kill_on_drop(true);
+
+                        // Long-running plugin jobs (e.g. `git blame` on a huge file)
+                        // get the same resource limits as language servers
+                        if let Err(e) = crate::services::process_limits::ProcessLimits::default()
+                            .apply_to_command(&mut cmd)
                         {
+                            tracing::warn!("Failed to apply process limits: {}", e);
+                        }
+
+                        let mut child = match cmd.spawn() {
                             Ok(child) => child,
                             Err(e) => {
                                 let _ = sender.send(
@@ -5335,7 +5895,7 @@ impl Editor {
                         let pid = process_id;
 
                         // Spawn stdout reader
-                        if let Some(stdout) = stdout {
+                        let stdout_task = stdout.map(|stdout| {
                             let sender = sender_stdout;
                             tokio::spawn(async move {
                                 let reader = BufReader::new(stdout);
@@ -5350,11 +5910,11 @@ impl Editor {
                                         ),
                                     );
                                 }
-                            });
-                        }
+                            })
+                        });
 
                         // Spawn stderr reader
-                        if let Some(stderr) = stderr {
+                        let stderr_task = stderr.map(|stderr| {
                             let sender = sender_stderr;
                             tokio::spawn(async move {
                                 let reader = BufReader::new(stderr);
@@ -5369,8 +5929,8 @@ impl Editor {
                                         ),
                                     );
                                 }
-                            });
-                        }
+                            })
+                        });
 
                         // Wait for process to complete
                         let exit_code = match child.wait().await {
@@ -5378,6 +5938,12 @@ impl Editor {
                             Err(_) => -1,
                         };
 
+                        // Drain the readers first so plugins see all output
+                        // before the exit resolves their promise
+                        for task in [stdout_task, stderr_task].into_iter().flatten() {
+                            let _ = task.await;
+                        }
+
                         let _ = sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
                             fresh_core::api::PluginAsyncMessage::ProcessExit {
                                 process_id,
@@ -5993,6 +6559,13 @@ impl Editor {
             PluginCommand::SaveBufferToPath { buffer_id, path } => {
                 self.handle_save_buffer_to_path(buffer_id, path);
             }
+            PluginCommand::ReplaceInFile {
+                path,
+                edits,
+                callback_id,
+            } => {
+                self.handle_replace_in_file(path, edits, callback_id);
+            }
 
             // ==================== Plugin Management ====================
             #[cfg(feature = "plugins")]
@@ -7433,7 +8006,7 @@ mod tests {
         assert_eq!(editor.active_cursors().primary().position, 10);
 
         // Call goto_matching_bracket
-        editor.goto_matching_bracket();
+        editor.goto_matching_bracket(false);
 
         // Should move to closing brace '}' at position 29
         // "fn main() { let x = (1 + 2); }"
@@ -7476,7 +8049,7 @@ mod tests {
         });
 
         // Call goto_matching_bracket
-        editor.goto_matching_bracket();
+        editor.goto_matching_bracket(false);
 
         // Should move to opening paren '('
         assert_eq!(editor.active_cursors().primary().position, 20);
@@ -7516,7 +8089,7 @@ mod tests {
         });
 
         // Call goto_matching_bracket
-        editor.goto_matching_bracket();
+        editor.goto_matching_bracket(false);
 
         // Should jump to last '}'
         assert_eq!(editor.active_cursors().primary().position, 10);
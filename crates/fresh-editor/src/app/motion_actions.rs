@@ -0,0 +1,268 @@
+//! Jump motions for the Editor.
+//!
+//! This module contains the cursor jumps that need more than the buffer text:
+//! - Smart home, which respects soft-wrapped rows
+//! - Go to matching bracket, which consults syntax highlighting and folds
+//!
+//! Both have extend-selection variants and move every cursor.
+
+use rust_i18n::t;
+
+use crate::model::cursor::Cursor;
+use crate::model::event::{CursorId, Event, LeafId};
+use crate::view::bracket_highlight_overlay::{find_matching_bracket, is_bracket};
+
+use super::Editor;
+
+/// Bytes around the cursors parsed for string and comment scopes when
+/// matching brackets. Brackets further away are treated as code.
+pub(super) const BRACKET_SYNTAX_WINDOW: usize = 64 * 1024;
+
+impl Editor {
+    /// Smart home: toggle between line start and first non-whitespace character
+    ///
+    /// Moves every cursor; with `extend_selection` the selection is kept and
+    /// extended instead of cleared.
+    pub(super) fn smart_home(&mut self, extend_selection: bool) {
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let line_wrap = self.is_line_wrap_enabled();
+        let split_id = self.split_manager.active_split();
+        let cursors = self.cursor_snapshot();
+
+        let mut events = Vec::new();
+        for (cursor_id, cursor) in cursors {
+            // When line wrap is on, use the visual (soft-wrapped) line boundaries,
+            // falling back to the physical line if the visual lookup fails
+            let visual = if line_wrap {
+                self.smart_home_visual_line(split_id, cursor.position, estimated_line_length)
+            } else {
+                None
+            };
+            let Some(new_pos) = visual
+                .or_else(|| self.smart_home_physical_line(cursor.position, estimated_line_length))
+            else {
+                continue;
+            };
+
+            let new_anchor = extend_selection.then(|| cursor.anchor.unwrap_or(cursor.position));
+            events.push(Event::MoveCursor {
+                cursor_id,
+                old_position: cursor.position,
+                new_position: new_pos,
+                old_anchor: cursor.anchor,
+                new_anchor,
+                old_sticky_column: cursor.sticky_column,
+                new_sticky_column: 0,
+            });
+        }
+
+        self.apply_cursor_moves(events, "Smart home");
+    }
+
+    /// Compute the smart-home target on the physical line containing `cursor_pos`.
+    fn smart_home_physical_line(
+        &mut self,
+        cursor_pos: usize,
+        estimated_line_length: usize,
+    ) -> Option<usize> {
+        let state = self.active_state_mut();
+        let mut iter = state
+            .buffer
+            .line_iterator(cursor_pos, estimated_line_length);
+        let (line_start, line_content) = iter.next_line()?;
+
+        // Find first non-whitespace character
+        let first_non_ws = line_content
+            .chars()
+            .take_while(|c| *c != '\n')
+            .position(|c| !c.is_whitespace())
+            .map(|offset| line_start + offset)
+            .unwrap_or(line_start);
+
+        // Toggle: if at first non-ws, go to line start; otherwise go to first non-ws
+        if cursor_pos == first_non_ws {
+            Some(line_start)
+        } else {
+            Some(first_non_ws)
+        }
+    }
+
+    /// Compute the smart-home target for a visual (soft-wrapped) line.
+    ///
+    /// On the **first** visual row of a physical line the cursor toggles between
+    /// the first non-whitespace character and position 0 (standard smart-home).
+    ///
+    /// On a **continuation** (wrapped) row the cursor moves to the visual row
+    /// start; if already there it jumps to the physical line's first
+    /// non-whitespace character, matching plain Home's visual-then-logical order.
+    fn smart_home_visual_line(
+        &mut self,
+        split_id: LeafId,
+        cursor_pos: usize,
+        estimated_line_length: usize,
+    ) -> Option<usize> {
+        let visual_start = self
+            .cached_layout
+            .visual_line_start(split_id, cursor_pos, false)?;
+
+        // Determine the physical line start to tell first-row from continuation.
+        let buffer_id = self.split_manager.active_buffer_id()?;
+        let state = self.buffers.get_mut(&buffer_id)?;
+        let mut iter = state
+            .buffer
+            .line_iterator(visual_start, estimated_line_length);
+        let (phys_line_start, content) = iter.next_line()?;
+
+        let is_first_visual_row = visual_start == phys_line_start;
+
+        if is_first_visual_row {
+            // First visual row: toggle first-non-ws ↔ physical line start
+            let visual_end = self
+                .cached_layout
+                .visual_line_end(split_id, cursor_pos, false)
+                .unwrap_or(visual_start);
+            let visual_len = visual_end.saturating_sub(visual_start);
+            let first_non_ws = content
+                .chars()
+                .take(visual_len)
+                .take_while(|c| *c != '\n')
+                .position(|c| !c.is_whitespace())
+                .map(|offset| visual_start + offset)
+                .unwrap_or(visual_start);
+
+            if cursor_pos == first_non_ws {
+                Some(visual_start)
+            } else {
+                Some(first_non_ws)
+            }
+        } else {
+            // Continuation row: go to visual line start, or on to the physical line
+            if cursor_pos == visual_start {
+                let first_non_ws = content
+                    .char_indices()
+                    .take_while(|(_, c)| *c != '\n')
+                    .find(|(_, c)| !c.is_whitespace())
+                    .map(|(offset, _)| phys_line_start + offset)
+                    .unwrap_or(phys_line_start);
+                Some(first_non_ws)
+            } else {
+                Some(visual_start)
+            }
+        }
+    }
+
+    /// Go to matching bracket
+    ///
+    /// Every cursor on a `(`, `[`, `{` or `<` (or their closers) jumps to the
+    /// matching bracket. With `extend_selection` the selection grows to take
+    /// in both brackets. Brackets inside strings and comments are told apart
+    /// from code using the syntax highlighting, and a collapsed fold hiding
+    /// the match is expanded so the cursor stays visible.
+    pub(super) fn goto_matching_bracket(&mut self, extend_selection: bool) {
+        let buffer_id = self.active_buffer();
+        let context_bytes = self.config.editor.highlight_context_bytes;
+        let cursors = self.cursor_snapshot();
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+
+        let positions: Vec<usize> = cursors.iter().map(|(_, cursor)| cursor.position).collect();
+        let (Some(&lowest), Some(&highest)) = (positions.iter().min(), positions.iter().max())
+        else {
+            return;
+        };
+        let inert = state.highlighter.string_and_comment_ranges(
+            &state.buffer,
+            lowest.saturating_sub(BRACKET_SYNTAX_WINDOW),
+            (highest + 1 + BRACKET_SYNTAX_WINDOW).min(state.buffer.len()),
+            &self.theme,
+            context_bytes,
+        );
+
+        let mut on_bracket = false;
+        let mut targets = Vec::new();
+        let mut events = Vec::new();
+        for (cursor_id, cursor) in cursors {
+            let pos = cursor.position;
+            let byte = state
+                .buffer
+                .slice_bytes(pos..(pos + 1).min(state.buffer.len()));
+            if !byte.first().is_some_and(|&b| is_bracket(b as char)) {
+                continue;
+            }
+            on_bracket = true;
+            let Some(target) = find_matching_bracket(&state.buffer, pos, &inert) else {
+                continue;
+            };
+            targets.push(target);
+
+            // Extending takes in both brackets, whichever way the jump goes
+            let (new_position, new_anchor) = match (extend_selection, target > pos) {
+                (false, _) => (target, None),
+                (true, true) => (target + 1, Some(cursor.anchor.unwrap_or(pos))),
+                (true, false) => (target, Some(cursor.anchor.unwrap_or(pos + 1))),
+            };
+            events.push(Event::MoveCursor {
+                cursor_id,
+                old_position: pos,
+                new_position,
+                old_anchor: cursor.anchor,
+                new_anchor,
+                old_sticky_column: cursor.sticky_column,
+                new_sticky_column: 0,
+            });
+        }
+
+        if events.is_empty() {
+            let message = if on_bracket {
+                t!("diagnostics.bracket_no_match")
+            } else {
+                t!("diagnostics.bracket_none")
+            };
+            self.set_status_message(message.to_string());
+            return;
+        }
+
+        // Unfold any collapsed region hiding a match
+        let split_id = self.split_manager.active_split();
+        if let (Some(state), Some(view_state)) = (
+            self.buffers.get_mut(&buffer_id),
+            self.split_view_states.get_mut(&split_id),
+        ) {
+            let folds = &mut view_state.ensure_buffer_state(buffer_id).folds;
+            for target in targets {
+                folds.remove_if_contains_byte(&mut state.marker_list, target);
+            }
+        }
+
+        let description = if extend_selection {
+            "Select to matching bracket"
+        } else {
+            "Go to matching bracket"
+        };
+        self.apply_cursor_moves(events, description);
+    }
+
+    /// Copy out the active cursors so they can be read while the buffer
+    /// state is borrowed
+    pub(super) fn cursor_snapshot(&self) -> Vec<(CursorId, Cursor)> {
+        self.active_cursors()
+            .iter()
+            .map(|(cursor_id, cursor)| (cursor_id, *cursor))
+            .collect()
+    }
+
+    /// Apply cursor moves, batching several into one undo step
+    pub(super) fn apply_cursor_moves(&mut self, events: Vec<Event>, description: &str) {
+        let event = match events.len() {
+            0 => return,
+            1 => events.into_iter().next().unwrap(),
+            _ => Event::Batch {
+                events,
+                description: description.to_string(),
+            },
+        };
+        self.active_event_log_mut().append(event.clone());
+        self.apply_event_to_active_buffer(&event);
+    }
+}
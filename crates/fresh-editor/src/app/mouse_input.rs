@@ -7,15 +7,19 @@
 //! - Split separator dragging
 //! - Text selection via mouse
 
+use super::types::SelectionUnit;
 use super::*;
 use crate::input::keybindings::Action;
+use crate::model::buffer::Buffer;
 use crate::model::event::{ContainerId, CursorId, LeafId, SplitDirection};
+use crate::primitives::word_navigation::{find_word_end, find_word_start};
 use crate::services::plugins::hooks::HookArgs;
 use crate::view::popup_mouse::{popup_areas_to_layout_info, PopupHitTester};
 use crate::view::prompt::PromptType;
 use crate::view::ui::tabs::TabHit;
 use anyhow::Result as AnyhowResult;
 use rust_i18n::t;
+use std::ops::Range;
 
 impl Editor {
     /// Handle a mouse event.
@@ -29,6 +33,11 @@ impl Editor {
         let col = mouse_event.column;
         let row = mouse_event.row;
 
+        // Clicks, drags and scrolls can change the layout, cursors or scroll
+        if !matches!(mouse_event.kind, MouseEventKind::Moved) {
+            self.mark_workspace_dirty();
+        }
+
         // Detect multi-click (double/triple) for left button down events
         let (is_double_click, is_triple_click) =
             if matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Left)) {
@@ -93,6 +102,11 @@ impl Editor {
             }
         }
 
+        // Any mouse activity dismisses a hover tooltip and restarts the wait
+        if self.track_hover_tooltip(&mouse_event) {
+            needs_render = true;
+        }
+
         // Update mouse cursor position for software cursor rendering (used by GPM)
         // When GPM is active, we always need to re-render to update the cursor position
         let cursor_moved = self.mouse_cursor_position != Some((col, row));
@@ -113,6 +127,12 @@ impl Editor {
         if let Some(result) = self.try_forward_mouse_to_terminal(col, row, mouse_event) {
             return result;
         }
+        if self.try_open_terminal_link(col, row, mouse_event) {
+            return Ok(true);
+        }
+
+        // Columns per horizontal wheel step (Shift+wheel, ScrollLeft/Right)
+        let horizontal_step = self.config.editor.mouse_horizontal_scroll_columns as i32;
 
         match mouse_event.kind {
             MouseEventKind::Down(MouseButton::Left) => {
@@ -168,6 +188,7 @@ impl Editor {
                 self.mouse_state.dragging_horizontal_scrollbar = None;
                 self.mouse_state.drag_start_hcol = None;
                 self.mouse_state.drag_start_left_column = None;
+                self.mouse_state.dragging_minimap = None;
                 self.mouse_state.dragging_separator = None;
                 self.mouse_state.drag_start_position = None;
                 self.mouse_state.drag_start_ratio = None;
@@ -185,7 +206,7 @@ impl Editor {
 
                 // If we finished dragging a separator, resize visible terminals
                 if was_dragging_separator {
-                    self.resize_visible_terminals();
+                    self.split_layout_changed();
                 }
 
                 needs_render = true;
@@ -224,6 +245,11 @@ impl Editor {
                 let hover_changed = self.update_hover_target(col, row);
                 needs_render = needs_render || hover_changed;
 
+                // Underline the link under the pointer while Ctrl is held
+                if self.update_link_hover(col, row, mouse_event.modifiers) {
+                    needs_render = true;
+                }
+
                 // Track LSP hover state for mouse-triggered hover popups
                 self.update_lsp_hover_state(col, row);
             }
@@ -233,7 +259,7 @@ impl Editor {
                     .modifiers
                     .contains(crossterm::event::KeyModifiers::SHIFT)
                 {
-                    self.handle_horizontal_scroll(col, row, -3)?;
+                    self.handle_horizontal_scroll(col, row, -horizontal_step)?;
                     needs_render = true;
                 } else if self.handle_prompt_scroll(-3) {
                     // Check if prompt with suggestions is active and should handle scroll
@@ -268,7 +294,7 @@ impl Editor {
                     .modifiers
                     .contains(crossterm::event::KeyModifiers::SHIFT)
                 {
-                    self.handle_horizontal_scroll(col, row, 3)?;
+                    self.handle_horizontal_scroll(col, row, horizontal_step)?;
                     needs_render = true;
                 } else if self.handle_prompt_scroll(3) {
                     // Check if prompt with suggestions is active and should handle scroll
@@ -298,12 +324,12 @@ impl Editor {
             }
             MouseEventKind::ScrollLeft => {
                 // Native horizontal scroll left
-                self.handle_horizontal_scroll(col, row, -3)?;
+                self.handle_horizontal_scroll(col, row, -horizontal_step)?;
                 needs_render = true;
             }
             MouseEventKind::ScrollRight => {
                 // Native horizontal scroll right
-                self.handle_horizontal_scroll(col, row, 3)?;
+                self.handle_horizontal_scroll(col, row, horizontal_step)?;
                 needs_render = true;
             }
             MouseEventKind::Down(MouseButton::Right) => {
@@ -311,6 +337,12 @@ impl Editor {
                 self.handle_right_click(col, row)?;
                 needs_render = true;
             }
+            MouseEventKind::Down(MouseButton::Middle) => {
+                // Middle-click on a tab closes it
+                if self.handle_middle_click(col, row) {
+                    needs_render = true;
+                }
+            }
             _ => {
                 // Ignore other mouse events for now
             }
@@ -930,6 +962,7 @@ impl Editor {
                     SearchOptionsHover::WholeWord => HoverTarget::SearchOptionWholeWord,
                     SearchOptionsHover::Regex => HoverTarget::SearchOptionRegex,
                     SearchOptionsHover::ConfirmEach => HoverTarget::SearchOptionConfirmEach,
+                    SearchOptionsHover::PreserveCase => HoverTarget::SearchOptionPreserveCase,
                     SearchOptionsHover::None => return None,
                 });
             }
@@ -1081,6 +1114,7 @@ impl Editor {
 
         // Now select the word under cursor
         self.handle_action(Action::SelectWord)?;
+        self.start_unit_selection(split_id, SelectionUnit::Word);
 
         Ok(())
     }
@@ -1203,6 +1237,7 @@ impl Editor {
 
         // Now select the entire line
         self.handle_action(Action::SelectLine)?;
+        self.start_unit_selection(split_id, SelectionUnit::Line);
 
         Ok(())
     }
@@ -1335,12 +1370,7 @@ impl Editor {
 
                 if let Some(url) = link_url {
                     // Open the URL in the default browser
-                    #[cfg(feature = "runtime")]
-                    if let Err(e) = open::that(&url) {
-                        self.set_status_message(format!("Failed to open URL: {}", e));
-                    } else {
-                        self.set_status_message(format!("Opening: {}", url));
-                    }
+                    self.open_url(&url);
                     return Ok(());
                 }
 
@@ -1471,6 +1501,27 @@ impl Editor {
             }
         }
 
+        // Check if click is on a minimap
+        let minimap_hit = self
+            .cached_layout
+            .minimap_areas
+            .iter()
+            .find(|(_, _, minimap_rect, _)| {
+                col >= minimap_rect.x
+                    && col < minimap_rect.x + minimap_rect.width
+                    && row >= minimap_rect.y
+                    && row < minimap_rect.y + minimap_rect.height
+            })
+            .cloned();
+
+        if let Some((split_id, buffer_id, minimap_rect, row_starts)) = minimap_hit {
+            self.focus_split(split_id, buffer_id);
+            self.handle_minimap_jump(row, split_id, buffer_id, minimap_rect, &row_starts)?;
+            self.mouse_state.dragging_minimap =
+                Some((split_id, buffer_id, minimap_rect, row_starts));
+            return Ok(());
+        }
+
         // Check if click is on a scrollbar
         let scrollbar_hit = self.cached_layout.split_areas.iter().find_map(
             |(split_id, buffer_id, _content_rect, scrollbar_rect, thumb_start, thumb_end)| {
@@ -1642,6 +1693,34 @@ impl Editor {
                         return self.handle_action(Action::ShowStatusLog);
                     }
                 }
+
+                // Check cursor position - click opens go to line
+                if let Some((pos_row, pos_start, pos_end)) =
+                    self.cached_layout.status_bar_cursor_position_area
+                {
+                    if row == pos_row && col >= pos_start && col < pos_end {
+                        return self.handle_action(Action::GotoLine);
+                    }
+                }
+
+                // Check plugin segments - click runs the segment's command
+                let clicked_segment = self
+                    .cached_layout
+                    .status_bar_plugin_areas
+                    .iter()
+                    .find(|(_, seg_row, seg_start, seg_end)| {
+                        row == *seg_row && col >= *seg_start && col < *seg_end
+                    })
+                    .map(|(name, ..)| name.clone());
+                if let Some(name) = clicked_segment {
+                    let command = self
+                        .plugin_status_segments
+                        .get(&name)
+                        .and_then(|segment| segment.command.clone());
+                    if let Some(command) = command {
+                        return self.handle_action(Action::PluginAction(command));
+                    }
+                }
             }
         }
 
@@ -1662,6 +1741,9 @@ impl Editor {
                     SearchOptionsHover::ConfirmEach => {
                         return self.handle_action(Action::ToggleSearchConfirmEach);
                     }
+                    SearchOptionsHover::PreserveCase => {
+                        return self.handle_action(Action::ToggleSearchPreserveCase);
+                    }
                     SearchOptionsHover::None => {}
                 }
             }
@@ -1775,7 +1857,12 @@ impl Editor {
             match hit {
                 TabHit::CloseButton(buffer_id) => {
                     self.focus_split(split_id, buffer_id);
-                    self.close_tab_in_split(buffer_id, split_id);
+                    // A pinned tab shows a pin marker in place of the close button
+                    if self.is_tab_pinned(buffer_id, split_id) {
+                        self.toggle_pin_tab_in_split(buffer_id, split_id);
+                    } else {
+                        self.close_tab_in_split(buffer_id, split_id);
+                    }
                     return Ok(());
                 }
                 TabHit::TabName(buffer_id) => {
@@ -1853,6 +1940,13 @@ impl Editor {
 
     /// Handle mouse drag event
     pub(super) fn handle_mouse_drag(&mut self, col: u16, row: u16) -> AnyhowResult<()> {
+        // If dragging in a minimap, keep the viewport centered under the mouse
+        if let Some((split_id, buffer_id, minimap_rect, row_starts)) =
+            self.mouse_state.dragging_minimap.clone()
+        {
+            return self.handle_minimap_jump(row, split_id, buffer_id, minimap_rect, &row_starts);
+        }
+
         // If dragging scrollbar, update scroll position
         if let Some(dragging_split_id) = self.mouse_state.dragging_scrollbar {
             // Find the buffer and scrollbar rect for this split
@@ -2105,6 +2199,19 @@ impl Editor {
                 return Ok(());
             };
 
+            // After a double/triple-click the selection grows by whole words
+            // or lines, keeping the clicked one selected in either direction
+            let (anchor_position, target_position) = match (
+                self.mouse_state.drag_selection_unit,
+                &self.mouse_state.drag_selection_origin,
+            ) {
+                (SelectionUnit::Char, _) | (_, None) => (anchor_position, target_position),
+                (unit, Some(origin)) => extend_unit_selection(
+                    origin,
+                    &unit_range_at(&state.buffer, unit, target_position),
+                ),
+            };
+
             let (primary_cursor_id, old_position, old_anchor, old_sticky_column) = self
                 .split_view_states
                 .get(&leaf_id)
@@ -2144,11 +2251,37 @@ impl Editor {
             {
                 state.apply(cursors, &event);
             }
+            if self.mouse_state.drag_selection_unit != SelectionUnit::Char {
+                self.mouse_state.unit_selection = Some((anchor_position, target_position));
+            }
         }
 
         Ok(())
     }
 
+    /// After a double/triple-click selected a word/line, let dragging and
+    /// Shift+click extend the selection by that unit
+    fn start_unit_selection(&mut self, split_id: LeafId, unit: SelectionUnit) {
+        let Some(cursor) = self
+            .split_view_states
+            .get(&split_id)
+            .map(|vs| *vs.cursors.primary())
+        else {
+            return;
+        };
+        let anchor = cursor.anchor.unwrap_or(cursor.position);
+        let origin = cursor
+            .selection_range()
+            .unwrap_or(cursor.position..cursor.position);
+
+        self.mouse_state.dragging_text_selection = true;
+        self.mouse_state.drag_selection_split = Some(split_id);
+        self.mouse_state.drag_selection_anchor = Some(origin.start);
+        self.mouse_state.drag_selection_unit = unit;
+        self.mouse_state.drag_selection_origin = Some(origin);
+        self.mouse_state.unit_selection = Some((anchor, cursor.position));
+    }
+
     /// Handle file explorer border drag for resizing
     pub(super) fn handle_file_explorer_border_drag(&mut self, col: u16) -> AnyhowResult<()> {
         let Some((start_col, _start_row)) = self.mouse_state.drag_start_position else {
@@ -2215,6 +2348,9 @@ impl Editor {
 
             // Update the split ratio
             self.split_manager.set_ratio(split_id, new_ratio);
+            for view_state in self.split_view_states.values_mut() {
+                view_state.viewport.clear_skip_ensure_visible();
+            }
         }
 
         Ok(())
@@ -2262,6 +2398,25 @@ impl Editor {
         Ok(())
     }
 
+    /// Handle middle-click event. Returns true if a tab was closed.
+    pub(super) fn handle_middle_click(&mut self, col: u16, row: u16) -> bool {
+        let tab_hit =
+            self.cached_layout.tab_layouts.iter().find_map(
+                |(split_id, tab_layout)| match tab_layout.hit_test(col, row) {
+                    Some(TabHit::TabName(buffer_id) | TabHit::CloseButton(buffer_id)) => {
+                        Some((*split_id, buffer_id))
+                    }
+                    _ => None,
+                },
+            );
+
+        let Some((split_id, buffer_id)) = tab_hit else {
+            return false;
+        };
+        self.close_tab_in_split(buffer_id, split_id);
+        true
+    }
+
     /// Handle left-click on tab context menu
     pub(super) fn handle_tab_context_menu_click(
         &mut self,
@@ -2330,6 +2485,9 @@ impl Editor {
             TabContextMenuItem::CloseAll => {
                 self.close_all_tabs_in_split(leaf_id);
             }
+            TabContextMenuItem::TogglePin => {
+                self.toggle_pin_tab_in_split(buffer_id, leaf_id);
+            }
         }
 
         Ok(())
@@ -2594,3 +2752,56 @@ impl Editor {
         }
     }
 }
+
+/// The word or line containing `position`, or an empty range at `position`
+/// for character selections and positions between words
+pub(super) fn unit_range_at(buffer: &Buffer, unit: SelectionUnit, position: usize) -> Range<usize> {
+    match unit {
+        SelectionUnit::Char => position..position,
+        SelectionUnit::Word => {
+            let start = find_word_start(buffer, position);
+            let end = find_word_end(buffer, start);
+            if start < end && position <= end {
+                start..end
+            } else {
+                position..position
+            }
+        }
+        SelectionUnit::Line => {
+            let line = buffer.get_line_number(position);
+            let start = buffer.line_start_offset(line).unwrap_or(position);
+            let end = buffer.line_start_offset(line + 1).unwrap_or(buffer.len());
+            start..end.max(start)
+        }
+    }
+}
+
+/// The (anchor, position) of a selection covering both `origin` and
+/// `target`. Extending forwards anchors at the start of `origin`, extending
+/// backwards at its end, so the originally clicked word or line stays selected.
+pub(super) fn extend_unit_selection(
+    origin: &Range<usize>,
+    target: &Range<usize>,
+) -> (usize, usize) {
+    if target.start >= origin.start {
+        (origin.start, target.end.max(origin.end))
+    } else {
+        (origin.end, target.start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extend_unit_selection_in_both_directions() {
+        let origin = 10..15;
+        // Forwards: from the origin's start to the end of the target unit
+        assert_eq!(extend_unit_selection(&origin, &(20..24)), (10, 24));
+        // Within the origin itself: just the origin
+        assert_eq!(extend_unit_selection(&origin, &(10..15)), (10, 15));
+        // Backwards: from the origin's end to the start of the target unit
+        assert_eq!(extend_unit_selection(&origin, &(2..6)), (15, 2));
+    }
+}
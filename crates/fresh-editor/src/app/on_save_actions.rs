@@ -3,22 +3,34 @@
 //! This module handles running configured actions when files are saved,
 //! such as formatters, linters, and other tools.
 
-use std::io::Write;
+use std::collections::HashSet;
+use std::io::{Read, Write};
 use std::path::Path;
-use std::process::{Command, Stdio};
-use std::time::Duration;
+use std::process::{Command, Output, Stdio};
+use std::time::{Duration, Instant};
 
 use super::Editor;
-use crate::config::{FormatterConfig, OnSaveAction};
-use crate::model::event::Event;
+use crate::config::{
+    BufferConfig, FormatterConfig, LinterConfig, OnSaveAction, TrimTrailingWhitespace,
+};
+use crate::model::event::{CursorId, Event};
+use crate::model::line_diff::line_edits;
+use crate::services::process_limits::ProcessLimits;
+use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use regex::Regex;
 use rust_i18n::t;
 
+/// Line indicator namespace used by the git gutter plugin
+const GIT_GUTTER_NAMESPACE: &str = "git-gutter";
+
 /// Result of running a formatter or on-save action
 enum ActionResult {
     /// Action ran successfully, contains output
     Success(String),
     /// Command not found
     CommandNotFound(String),
+    /// Command exited with an error status, contains its error output
+    Failed(String),
     /// Action failed with error
     Error(String),
 }
@@ -36,30 +48,27 @@ impl Editor {
 
         let mut ran_any_action = false;
 
-        // Run whitespace cleanup actions first (before formatter)
-        if self.config.editor.trim_trailing_whitespace_on_save {
-            if self.trim_trailing_whitespace()? {
-                ran_any_action = true;
-            }
-        }
-
-        if self.config.editor.ensure_final_newline_on_save {
-            if self.ensure_final_newline()? {
-                ran_any_action = true;
-            }
-        }
-
-        // If whitespace cleanup made changes, re-save
-        if ran_any_action {
+        // Get language from buffer's stored state
+        let language = self.active_state().language.clone();
+        let buffer_config = BufferConfig::resolve(&self.config, Some(&language));
+
+        // Everything logged from here on is folded into one undo step
+        let log_start = self.active_event_log().len();
+
+        // Run whitespace cleanup first (before formatter)
+        let cleanup = self.whitespace_cleanup_events(
+            buffer_config.trim_trailing_whitespace_on_save,
+            buffer_config.ensure_final_newline_on_save,
+            true,
+        );
+        if self.apply_whitespace_cleanup(cleanup, "On-save whitespace cleanup") {
             if let Err(e) = self.active_state_mut().buffer.save() {
                 return Err(format!("Failed to re-save after whitespace cleanup: {}", e));
             }
             self.active_event_log_mut().mark_saved();
+            ran_any_action = true;
         }
 
-        // Get language from buffer's stored state
-        let language = self.active_state().language.clone();
-
         let lang_config = match self.config.languages.get(&language) {
             Some(lc) => lc.clone(),
             None => return Ok(ran_any_action),
@@ -75,6 +84,9 @@ impl Editor {
                         if let Err(e) = self.active_state_mut().buffer.save() {
                             return Err(format!("Failed to re-save after format: {}", e));
                         }
+                        // One undo restores the buffer as it was before saving
+                        self.active_event_log_mut()
+                            .merge_since(log_start, "On-save format".to_string());
                         self.active_event_log_mut().mark_saved();
                         ran_any_action = true;
                     }
@@ -84,6 +96,9 @@ impl Editor {
                             cmd
                         ));
                     }
+                    ActionResult::Failed(output) => {
+                        return Err(self.show_formatter_error(&formatter.command, &output));
+                    }
                     ActionResult::Error(e) => {
                         return Err(e);
                     }
@@ -91,10 +106,18 @@ impl Editor {
             }
         }
 
-        // Run on_save actions (linters, etc.)
         let project_root = std::env::current_dir()
             .unwrap_or_else(|_| path.parent().unwrap_or(Path::new(".")).to_path_buf());
 
+        // Run the linter, showing what it reports as diagnostics
+        if let Some(ref linter) = lang_config.linter {
+            if self.run_linter(linter, &path, &project_root)? {
+                ran_any_action = true;
+            }
+        }
+
+        // Run on_save actions (linters, etc.)
+
         for action in &lang_config.on_save {
             if !action.enabled {
                 continue;
@@ -156,6 +179,9 @@ impl Editor {
                 Ok(())
             }
             ActionResult::CommandNotFound(cmd) => Err(format!("Formatter '{}' not found", cmd)),
+            ActionResult::Failed(output) => {
+                Err(self.show_formatter_error(&formatter.command, &output))
+            }
             ActionResult::Error(e) => Err(e),
         }
     }
@@ -169,9 +195,6 @@ impl Editor {
             return ActionResult::CommandNotFound(formatter.command.clone());
         }
 
-        // Build the command
-        let shell = detect_shell();
-
         // Build the full command string with arguments
         let mut cmd_parts = vec![formatter.command.clone()];
         for arg in &formatter.args {
@@ -184,97 +207,130 @@ impl Editor {
         let project_root = std::env::current_dir()
             .unwrap_or_else(|_| file_path.parent().unwrap_or(Path::new(".")).to_path_buf());
 
-        // Set up the command
-        let mut cmd = Command::new(&shell);
-        cmd.args(["-c", &full_command])
-            .current_dir(&project_root)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
-
-        if formatter.stdin {
-            cmd.stdin(Stdio::piped());
-        } else {
-            cmd.stdin(Stdio::null());
-        }
-
-        // Spawn the process
-        let mut child = match cmd.spawn() {
-            Ok(c) => c,
-            Err(e) => {
+        let input = formatter
+            .stdin
+            .then(|| self.active_state().buffer.to_string().unwrap_or_default());
+
+        let output = match run_shell_command(
+            &formatter.command,
+            &full_command,
+            &project_root,
+            input,
+            Duration::from_millis(formatter.timeout_ms),
+        ) {
+            Ok(Some(output)) => output,
+            Ok(None) => {
                 return ActionResult::Error(format!(
-                    "Failed to run '{}': {}",
-                    formatter.command, e
+                    "Formatter '{}' timed out after {}ms",
+                    formatter.command, formatter.timeout_ms
                 ));
             }
+            Err(e) => return ActionResult::Error(e),
         };
 
-        // Write buffer content to stdin if configured
-        if formatter.stdin {
-            let content = self.active_state().buffer.to_string().unwrap_or_default();
-            if let Some(mut stdin) = child.stdin.take() {
-                if let Err(e) = stdin.write_all(content.as_bytes()) {
-                    return ActionResult::Error(format!("Failed to write to stdin: {}", e));
-                }
+        if output.status.success() {
+            match String::from_utf8(output.stdout) {
+                Ok(s) => ActionResult::Success(s),
+                Err(e) => ActionResult::Error(format!("Invalid UTF-8 in output: {}", e)),
             }
+        } else {
+            ActionResult::Failed(error_output(&output))
         }
+    }
 
-        // Wait for the process with timeout
-        let timeout = Duration::from_millis(formatter.timeout_ms);
-        let start = std::time::Instant::now();
-
-        loop {
-            match child.try_wait() {
-                Ok(Some(status)) => {
-                    let output = match child.wait_with_output() {
-                        Ok(o) => o,
-                        Err(e) => {
-                            return ActionResult::Error(format!("Failed to get output: {}", e))
-                        }
-                    };
+    /// Show the error output of a failed formatter in a popup.
+    /// Returns the message for the status bar.
+    fn show_formatter_error(&mut self, command: &str, output: &str) -> String {
+        use crate::view::popup::{Popup, PopupPosition};
+        use ratatui::style::Style;
+
+        let lines: Vec<String> = output.lines().map(String::from).collect();
+        let mut popup = Popup::text(lines, &self.theme);
+        popup.title = Some(t!("format.failed_title", formatter = command).to_string());
+        popup.transient = false;
+        popup.position = PopupPosition::BelowCursor;
+        popup.width = 80;
+        popup.max_height = 15;
+        popup.border_style = Style::default().fg(self.theme.popup_border_fg);
+        popup.background_style = Style::default().bg(self.theme.popup_bg);
+
+        if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
+            state.popups.show(popup);
+        }
 
-                    if status.success() {
-                        return match String::from_utf8(output.stdout) {
-                            Ok(s) => ActionResult::Success(s),
-                            Err(e) => {
-                                ActionResult::Error(format!("Invalid UTF-8 in output: {}", e))
-                            }
-                        };
-                    } else {
-                        let stderr = String::from_utf8_lossy(&output.stderr);
-                        let stdout = String::from_utf8_lossy(&output.stdout);
-                        let error_output = if !stderr.is_empty() {
-                            stderr.trim().to_string()
-                        } else if !stdout.is_empty() {
-                            stdout.trim().to_string()
-                        } else {
-                            format!("exit code {:?}", status.code())
-                        };
-                        return ActionResult::Error(format!(
-                            "Formatter '{}' failed: {}",
-                            formatter.command, error_output
-                        ));
-                    }
-                }
-                Ok(None) => {
-                    if start.elapsed() > timeout {
-                        // Best-effort kill of timed-out process.
-                        #[allow(clippy::let_underscore_must_use)]
-                        let _ = child.kill();
-                        return ActionResult::Error(format!(
-                            "Formatter '{}' timed out after {}ms",
-                            formatter.command, formatter.timeout_ms
-                        ));
-                    }
-                    std::thread::sleep(Duration::from_millis(10));
-                }
-                Err(e) => {
-                    return ActionResult::Error(format!(
-                        "Failed to wait for '{}': {}",
-                        formatter.command, e
-                    ));
-                }
-            }
+        format!(
+            "Formatter '{}' failed: {}",
+            command,
+            output.lines().next().unwrap_or_default()
+        )
+    }
+
+    /// Run the linter on a saved file and show the problems it reports as
+    /// diagnostics. Returns Ok(false) if the linter isn't installed.
+    fn run_linter(
+        &mut self,
+        linter: &LinterConfig,
+        file_path: &Path,
+        project_root: &Path,
+    ) -> Result<bool, String> {
+        if !command_exists(&linter.command) {
+            return Ok(false);
+        }
+        let pattern = Regex::new(&linter.pattern)
+            .map_err(|e| format!("Invalid pattern for linter '{}': {}", linter.command, e))?;
+
+        let file_path_str = file_path.display().to_string();
+        let mut cmd_parts = vec![linter.command.clone()];
+        for arg in &linter.args {
+            cmd_parts.push(arg.replace("$FILE", &file_path_str));
+        }
+
+        // If no arguments contain $FILE, append the file path
+        let has_file_arg = linter.args.iter().any(|a| a.contains("$FILE"));
+        if !has_file_arg && !linter.stdin {
+            cmd_parts.push(file_path_str.clone());
+        }
+
+        let input = linter
+            .stdin
+            .then(|| self.active_state().buffer.to_string().unwrap_or_default());
+
+        let output = run_shell_command(
+            &linter.command,
+            &cmd_parts.join(" "),
+            project_root,
+            input,
+            Duration::from_millis(linter.timeout_ms),
+        )?
+        .ok_or_else(|| {
+            format!(
+                "Linter '{}' timed out after {}ms",
+                linter.command, linter.timeout_ms
+            )
+        })?;
+
+        // Linters exit with an error status when they find problems, so only
+        // their output matters
+        let mut diagnostics = Vec::new();
+        for stream in [&output.stdout, &output.stderr] {
+            diagnostics.extend(parse_lint_output(
+                &pattern,
+                &String::from_utf8_lossy(stream),
+                file_path,
+                project_root,
+                &linter.command,
+            ));
+        }
+
+        let uri = self
+            .buffer_metadata
+            .get(&self.active_buffer())
+            .and_then(|m| m.file_uri())
+            .map(|uri| uri.as_str().to_string());
+        if let Some(uri) = uri {
+            self.set_linter_diagnostics(uri, diagnostics);
         }
+        Ok(true)
     }
 
     /// Run a single on-save action (linter, etc.).
@@ -291,9 +347,6 @@ impl Editor {
             return ActionResult::CommandNotFound(action.command.clone());
         }
 
-        // Build the command
-        let shell = detect_shell();
-
         let mut cmd_parts = vec![action.command.clone()];
         for arg in &action.args {
             cmd_parts.push(arg.replace("$FILE", &file_path_str));
@@ -317,151 +370,77 @@ impl Editor {
             })
             .unwrap_or_else(|| project_root.to_path_buf());
 
-        // Set up the command
-        let mut cmd = Command::new(&shell);
-        cmd.args(["-c", &full_command])
-            .current_dir(&working_dir)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
-
-        if action.stdin {
-            cmd.stdin(Stdio::piped());
-        } else {
-            cmd.stdin(Stdio::null());
-        }
-
-        // Spawn the process
-        let mut child = match cmd.spawn() {
-            Ok(c) => c,
-            Err(e) => {
-                return ActionResult::Error(format!("Failed to run '{}': {}", action.command, e));
+        let input = action
+            .stdin
+            .then(|| self.active_state().buffer.to_string().unwrap_or_default());
+
+        let output = match run_shell_command(
+            &action.command,
+            &full_command,
+            &working_dir,
+            input,
+            Duration::from_millis(action.timeout_ms),
+        ) {
+            Ok(Some(output)) => output,
+            Ok(None) => {
+                return ActionResult::Error(format!(
+                    "On-save action '{}' timed out after {}ms",
+                    action.command, action.timeout_ms
+                ));
             }
+            Err(e) => return ActionResult::Error(e),
         };
 
-        // Write buffer content to stdin if configured
-        if action.stdin {
-            let content = self.active_state().buffer.to_string().unwrap_or_default();
-            if let Some(mut stdin) = child.stdin.take() {
-                if let Err(e) = stdin.write_all(content.as_bytes()) {
-                    return ActionResult::Error(format!("Failed to write to stdin: {}", e));
-                }
-            }
-        }
-
-        // Wait for the process with timeout
-        let timeout = Duration::from_millis(action.timeout_ms);
-        let start = std::time::Instant::now();
-
-        loop {
-            match child.try_wait() {
-                Ok(Some(status)) => {
-                    let output = match child.wait_with_output() {
-                        Ok(o) => o,
-                        Err(e) => {
-                            return ActionResult::Error(format!("Failed to get output: {}", e))
-                        }
-                    };
-
-                    if status.success() {
-                        return match String::from_utf8(output.stdout) {
-                            Ok(s) => ActionResult::Success(s),
-                            Err(e) => {
-                                ActionResult::Error(format!("Invalid UTF-8 in output: {}", e))
-                            }
-                        };
-                    } else {
-                        let stderr = String::from_utf8_lossy(&output.stderr);
-                        let stdout = String::from_utf8_lossy(&output.stdout);
-                        let error_output = if !stderr.is_empty() {
-                            stderr.trim().to_string()
-                        } else if !stdout.is_empty() {
-                            stdout.trim().to_string()
-                        } else {
-                            format!("exit code {:?}", status.code())
-                        };
-                        return ActionResult::Error(format!(
-                            "On-save action '{}' failed: {}",
-                            action.command, error_output
-                        ));
-                    }
-                }
-                Ok(None) => {
-                    if start.elapsed() > timeout {
-                        // Best-effort kill of timed-out process.
-                        #[allow(clippy::let_underscore_must_use)]
-                        let _ = child.kill();
-                        return ActionResult::Error(format!(
-                            "On-save action '{}' timed out after {}ms",
-                            action.command, action.timeout_ms
-                        ));
-                    }
-                    std::thread::sleep(Duration::from_millis(10));
-                }
-                Err(e) => {
-                    return ActionResult::Error(format!(
-                        "Failed to wait for '{}': {}",
-                        action.command, e
-                    ));
-                }
+        if output.status.success() {
+            match String::from_utf8(output.stdout) {
+                Ok(s) => ActionResult::Success(s),
+                Err(e) => ActionResult::Error(format!("Invalid UTF-8 in output: {}", e)),
             }
+        } else {
+            ActionResult::Error(format!(
+                "On-save action '{}' failed: {}",
+                action.command,
+                error_output(&output)
+            ))
         }
     }
 
     /// Replace the active buffer's content with new output.
+    ///
+    /// Only the lines that differ are edited, so cursors, markers and folds
+    /// elsewhere in the buffer stay where they are.
     fn replace_buffer_with_output(&mut self, output: &str) -> Result<(), String> {
-        let cursor_id = self.active_cursors().primary_id();
-
-        // Get current buffer content
-        let buffer_content = self.active_state().buffer.to_string().unwrap_or_default();
-
-        // Only replace if content is different
-        if buffer_content == output {
-            return Ok(());
-        }
-
-        let buffer_len = buffer_content.len();
-
-        // Capture cursor position and selection state before replacement
-        let old_cursor_pos = self.active_cursors().primary().position;
-        let old_anchor = self.active_cursors().primary().anchor;
-        let old_sticky_column = self.active_cursors().primary().sticky_column;
-
-        // Delete all content and insert new
-        let delete_event = Event::Delete {
-            range: 0..buffer_len,
-            deleted_text: buffer_content,
-            cursor_id,
-        };
-        let insert_event = Event::Insert {
-            position: 0,
-            text: output.to_string(),
-            cursor_id,
+        // Large files that aren't fully loaded can't be compared
+        let Some(buffer_content) = self.active_state().buffer.to_string() else {
+            return Err("Cannot format a file that isn't fully loaded".to_string());
         };
 
-        // After delete+insert, cursor will be at output.len()
-        // Restore cursor to original position (or clamp to new buffer length)
-        let new_buffer_len = output.len();
-        let new_cursor_pos = old_cursor_pos.min(new_buffer_len);
-
-        // Only add MoveCursor event if position actually changes
-        let mut events = vec![delete_event, insert_event];
-        if new_cursor_pos != new_buffer_len {
-            let move_cursor_event = Event::MoveCursor {
-                cursor_id,
-                old_position: new_buffer_len, // Where cursor is after insert
-                new_position: new_cursor_pos,
-                old_anchor: None,
-                new_anchor: old_anchor.map(|a| a.min(new_buffer_len)),
-                old_sticky_column: 0,
-                new_sticky_column: old_sticky_column,
-            };
-            events.push(move_cursor_event);
+        // Apply back to front so earlier ranges stay valid
+        let mut events = Vec::new();
+        for (range, text) in line_edits(&buffer_content, output).into_iter().rev() {
+            if !range.is_empty() {
+                events.push(Event::Delete {
+                    deleted_text: buffer_content[range.clone()].to_string(),
+                    range: range.clone(),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+            if !text.is_empty() {
+                events.push(Event::Insert {
+                    position: range.start,
+                    text,
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+        }
+        if events.is_empty() {
+            return Ok(());
         }
 
         // Apply as a batch for atomic undo
         let batch = Event::Batch {
             events,
-            description: "On-save format".to_string(),
+            description: "Format".to_string(),
         };
         self.active_event_log_mut().append(batch.clone());
         self.apply_event_to_active_buffer(&batch);
@@ -472,47 +451,319 @@ impl Editor {
     /// Trim trailing whitespace from all lines in the active buffer.
     /// Returns Ok(true) if any changes were made, Ok(false) if buffer unchanged.
     pub fn trim_trailing_whitespace(&mut self) -> Result<bool, String> {
-        let content = self.active_state().buffer.to_string().unwrap_or_default();
-
-        // Process each line and trim trailing whitespace
-        let trimmed: String = content
-            .lines()
-            .map(|line| line.trim_end())
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        // Preserve original trailing newline if present
-        let trimmed = if content.ends_with('\n') && !trimmed.ends_with('\n') {
-            format!("{}\n", trimmed)
-        } else {
-            trimmed
+        let events = self.whitespace_cleanup_events(TrimTrailingWhitespace::All, false, false);
+        Ok(self.apply_whitespace_cleanup(events, "Trim trailing whitespace"))
+    }
+
+    /// Ensure the buffer ends with a newline.
+    /// Returns Ok(true) if a newline was added, Ok(false) if already ends with newline.
+    pub fn ensure_final_newline(&mut self) -> Result<bool, String> {
+        let events = self.whitespace_cleanup_events(TrimTrailingWhitespace::Off, true, false);
+        Ok(self.apply_whitespace_cleanup(events, "Ensure final newline"))
+    }
+
+    /// Build the edits that trim trailing whitespace and add a missing final
+    /// newline to the active buffer.
+    ///
+    /// Only the whitespace itself is deleted, so cursors on other lines keep
+    /// their line and column. With `keep_cursor_whitespace`, whitespace to the
+    /// left of a cursor sitting inside it is kept so the cursor column survives.
+    fn whitespace_cleanup_events(
+        &self,
+        trim: TrimTrailingWhitespace,
+        final_newline: bool,
+        keep_cursor_whitespace: bool,
+    ) -> Vec<Event> {
+        let state = self.active_state();
+        // Large files that aren't fully loaded are left alone
+        let Some(content) = state.buffer.to_string() else {
+            return Vec::new();
         };
 
-        if trimmed == content {
-            return Ok(false);
+        let modified_lines: Option<HashSet<usize>> = match trim {
+            TrimTrailingWhitespace::ModifiedLinesOnly => Some(
+                state
+                    .margins
+                    .line_indicator_positions(GIT_GUTTER_NAMESPACE)
+                    .into_iter()
+                    .map(|pos| state.buffer.get_line_number(pos))
+                    .collect(),
+            ),
+            _ => None,
+        };
+        let cursors: Vec<_> = self
+            .active_cursors()
+            .iter()
+            .map(|(id, cursor)| (id, *cursor))
+            .collect();
+
+        let mut events = Vec::new();
+
+        // Append the newline first: the deletions below all come before it
+        if final_newline && !content.is_empty() && !content.ends_with('\n') {
+            let line_ending = state.buffer.line_ending().as_str();
+            let end = content.len();
+            events.push(Event::Insert {
+                position: end,
+                text: line_ending.to_string(),
+                cursor_id: CursorId::UNDO_SENTINEL,
+            });
+            // Keep cursors at the end of the last line rather than after the newline
+            for (cursor_id, cursor) in cursors.iter().filter(|(_, c)| c.position == end) {
+                events.push(Event::MoveCursor {
+                    cursor_id: *cursor_id,
+                    old_position: end + line_ending.len(),
+                    new_position: end,
+                    old_anchor: cursor
+                        .anchor
+                        .map(|a| if a == end { a + line_ending.len() } else { a }),
+                    new_anchor: cursor.anchor,
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: cursor.sticky_column,
+                });
+            }
         }
 
-        self.replace_buffer_with_output(&trimmed)?;
-        Ok(true)
+        if trim != TrimTrailingWhitespace::Off {
+            let mut deletions = Vec::new();
+            let mut line_start = 0;
+            for (line, text) in content.split_inclusive('\n').enumerate() {
+                let body = text.strip_suffix('\n').unwrap_or(text);
+                let body = body.strip_suffix('\r').unwrap_or(body);
+                let line_end = line_start + body.len();
+                let trim_start = line_start + body.trim_end().len();
+
+                let selected = modified_lines
+                    .as_ref()
+                    .is_none_or(|lines| lines.contains(&line));
+                if selected && trim_start < line_end {
+                    let start = if keep_cursor_whitespace {
+                        cursors
+                            .iter()
+                            .map(|(_, c)| c.position)
+                            .filter(|&pos| pos > trim_start && pos <= line_end)
+                            .max()
+                            .unwrap_or(trim_start)
+                    } else {
+                        trim_start
+                    };
+                    if start < line_end {
+                        deletions.push(start..line_end);
+                    }
+                }
+                line_start += text.len();
+            }
+
+            // Delete back to front so earlier ranges stay valid
+            for range in deletions.into_iter().rev() {
+                events.push(Event::Delete {
+                    deleted_text: content[range.clone()].to_string(),
+                    range,
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+        }
+
+        events
     }
 
-    /// Ensure the buffer ends with a newline.
-    /// Returns Ok(true) if a newline was added, Ok(false) if already ends with newline.
-    pub fn ensure_final_newline(&mut self) -> Result<bool, String> {
-        let content = self.active_state().buffer.to_string().unwrap_or_default();
+    /// Apply whitespace cleanup edits as a single undo step.
+    /// Returns true if there was anything to apply.
+    fn apply_whitespace_cleanup(&mut self, events: Vec<Event>, description: &str) -> bool {
+        if events.is_empty() {
+            return false;
+        }
+        let batch = Event::Batch {
+            events,
+            description: description.to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
+        true
+    }
+}
 
-        // Empty buffers don't need a newline
-        if content.is_empty() {
-            return Ok(false);
+/// Run a shell command line under the process limits and wait for it.
+///
+/// `input` is written to the command's stdin. Output is read while the command
+/// runs so large files can't fill the pipes and stall it.
+/// Returns None if it didn't finish within `timeout` and was killed.
+fn run_shell_command(
+    name: &str,
+    command_line: &str,
+    working_dir: &Path,
+    input: Option<String>,
+    timeout: Duration,
+) -> Result<Option<Output>, String> {
+    let shell = detect_shell();
+    let mut cmd = Command::new(&shell);
+    cmd.args(["-c", command_line])
+        .current_dir(working_dir)
+        .stdin(if input.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    // Formatters and linters get the same resource limits as language servers
+    if let Err(e) = ProcessLimits::default().apply_to_std_command(&mut cmd) {
+        tracing::warn!("Failed to apply process limits: {}", e);
+    }
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to run '{}': {}", name, e))?;
+
+    if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+        std::thread::spawn(move || {
+            // A command that exits without reading all its input is not an error
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = stdin.write_all(input.as_bytes());
+        });
+    }
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => {
+                if start.elapsed() > timeout {
+                    // Best-effort kill of timed-out process.
+                    #[allow(clippy::let_underscore_must_use)]
+                    let _ = child.kill();
+                    #[allow(clippy::let_underscore_must_use)]
+                    let _ = child.wait();
+                    return Ok(None);
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            Err(e) => return Err(format!("Failed to wait for '{}': {}", name, e)),
         }
+    };
+
+    let collect = |reader: std::thread::JoinHandle<Vec<u8>>| {
+        reader
+            .join()
+            .map_err(|_| format!("Failed to get output of '{}'", name))
+    };
+    Ok(Some(Output {
+        status,
+        stdout: collect(stdout)?,
+        stderr: collect(stderr)?,
+    }))
+}
 
-        if content.ends_with('\n') {
-            return Ok(false);
+/// Read a child's output pipe to the end on a separate thread
+fn read_in_background<R: Read + Send + 'static>(
+    pipe: Option<R>,
+) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            // Whatever was read before an error is still useful
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = pipe.read_to_end(&mut bytes);
         }
+        bytes
+    })
+}
 
-        let with_newline = format!("{}\n", content);
-        self.replace_buffer_with_output(&with_newline)?;
-        Ok(true)
+/// Error message of a command that failed: its stderr, else its stdout
+fn error_output(output: &Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !stderr.trim().is_empty() {
+        stderr.trim().to_string()
+    } else if !stdout.trim().is_empty() {
+        stdout.trim().to_string()
+    } else {
+        format!("exit code {:?}", output.status.code())
+    }
+}
+
+/// Turn the lines of linter output matching `pattern` into diagnostics.
+///
+/// Lines naming another file than `file_path` are skipped.
+fn parse_lint_output(
+    pattern: &Regex,
+    output: &str,
+    file_path: &Path,
+    working_dir: &Path,
+    source: &str,
+) -> Vec<Diagnostic> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let captures = pattern.captures(line)?;
+            if let Some(file) = captures.name("file") {
+                if !is_lint_target(file.as_str(), file_path, working_dir) {
+                    return None;
+                }
+            }
+            let line_number: u32 = captures.name("line")?.as_str().parse().ok()?;
+            let line_index = line_number.saturating_sub(1);
+            let column = captures
+                .name("col")
+                .and_then(|col| col.as_str().parse::<u32>().ok())
+                .map_or(0, |col| col.saturating_sub(1));
+            let message = captures
+                .name("message")
+                .map_or(line, |message| message.as_str())
+                .trim()
+                .to_string();
+            let severity = captures
+                .name("severity")
+                .map_or(DiagnosticSeverity::WARNING, |severity| {
+                    lint_severity(severity.as_str())
+                });
+            // Highlight from the column to the end of the line
+            Some(Diagnostic::new(
+                Range::new(
+                    Position::new(line_index, column),
+                    Position::new(line_index + 1, 0),
+                ),
+                Some(severity),
+                None,
+                Some(source.to_string()),
+                message,
+                None,
+                None,
+            ))
+        })
+        .collect()
+}
+
+/// Whether a file name printed by a linter refers to the linted file
+fn is_lint_target(name: &str, file_path: &Path, working_dir: &Path) -> bool {
+    // Tools reading stdin print a placeholder instead of a path
+    if matches!(name, "-" | "<stdin>" | "stdin") {
+        return true;
+    }
+    let path = working_dir.join(name);
+    if path == file_path {
+        return true;
+    }
+    match (path.canonicalize(), file_path.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Severity for the `severity` group of a lint pattern, e.g. "error" or "W"
+fn lint_severity(text: &str) -> DiagnosticSeverity {
+    let text = text.to_lowercase();
+    if text.starts_with('e') || text.contains("error") || text.contains("fatal") {
+        DiagnosticSeverity::ERROR
+    } else if text.starts_with('i') || text.contains("note") {
+        DiagnosticSeverity::INFORMATION
+    } else if text.starts_with('h') || text.contains("style") {
+        DiagnosticSeverity::HINT
+    } else {
+        DiagnosticSeverity::WARNING
     }
 }
 
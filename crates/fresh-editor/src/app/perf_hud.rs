@@ -0,0 +1,94 @@
+//! Performance HUD and report.
+//!
+//! The HUD overlays frame time, render phase times, output size, marker count
+//! and LSP latency recorded by `services::perf`, and the size of the workspace
+//! file index. Turning it on enables that recording; turning it off disables
+//! it again. "Dump Performance Report" writes the same numbers as JSON for
+//! attaching to bug reports.
+
+use std::path::Path;
+
+use ratatui::layout::Rect;
+use ratatui::Frame;
+use rust_i18n::t;
+use serde::Serialize;
+
+use super::Editor;
+use crate::services::perf::{self, PerfSnapshot};
+
+/// Contents of a performance report file
+#[derive(Debug, Serialize)]
+struct PerformanceReport {
+    version: &'static str,
+    created_at: String,
+    /// Whether samples were being recorded when the report was written
+    recording: bool,
+    active_buffer_bytes: usize,
+    active_buffer_markers: usize,
+    stats: PerfSnapshot,
+}
+
+impl Editor {
+    /// Show or hide the performance HUD, recording samples while it is shown
+    pub fn toggle_performance_hud(&mut self) {
+        self.perf_hud_visible = !self.perf_hud_visible;
+        perf::set_enabled(self.perf_hud_visible);
+        let message = if self.perf_hud_visible {
+            t!("status.perf_hud_shown")
+        } else {
+            t!("status.perf_hud_hidden")
+        };
+        self.set_status_message(message.to_string());
+    }
+
+    /// Whether the performance HUD is shown
+    pub fn is_performance_hud_visible(&self) -> bool {
+        self.perf_hud_visible
+    }
+
+    /// Write the recorded performance data as JSON to the log directory
+    pub fn dump_performance_report(&mut self) {
+        let path = crate::services::log_dirs::log_dir().join(format!(
+            "perf-report-{}.json",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        match self.write_performance_report(&path) {
+            Ok(()) => self.set_status_message(
+                t!(
+                    "status.perf_report_written",
+                    path = path.display().to_string()
+                )
+                .to_string(),
+            ),
+            Err(e) => self.set_status_message(
+                t!("status.perf_report_failed", error = e.to_string()).to_string(),
+            ),
+        }
+    }
+
+    /// Write the recorded performance data as JSON to `path`
+    pub fn write_performance_report(&self, path: &Path) -> anyhow::Result<()> {
+        let state = self.active_state();
+        let report = PerformanceReport {
+            version: env!("CARGO_PKG_VERSION"),
+            created_at: chrono::Local::now().to_rfc3339(),
+            recording: perf::is_enabled(),
+            active_buffer_bytes: state.buffer.len(),
+            active_buffer_markers: state.marker_list.marker_count(),
+            stats: perf::snapshot(),
+        };
+        std::fs::write(path, serde_json::to_string_pretty(&report)? + "\n")?;
+        Ok(())
+    }
+
+    pub(super) fn render_perf_hud(&self, frame: &mut Frame, area: Rect) {
+        crate::view::perf_hud::render_perf_hud(
+            frame,
+            area,
+            &perf::snapshot(),
+            self.active_state().marker_list.marker_count(),
+            self.file_index_stats(),
+            &self.theme,
+        );
+    }
+}
@@ -4,11 +4,14 @@
 
 use crate::model::cursor::Cursors;
 use crate::model::event::{BufferId, ContainerId, CursorId, Event, LeafId, OverlayFace, SplitId};
+use crate::primitives::detected_language::DetectedLanguage;
 use crate::view::overlay::{OverlayHandle, OverlayNamespace};
 use crate::view::split::SplitViewState;
+use crate::view::ui::PluginStatusSegment;
 use anyhow::Result as AnyhowResult;
 use fresh_core::api::{
-    LayoutHints, MenuPosition, OverlayOptions, PluginResponse, ViewTransformPayload,
+    FileEdit, JsCallbackId, LayoutHints, MenuPosition, OverlayOptions, PluginResponse,
+    ViewTransformPayload,
 };
 
 use super::Editor;
@@ -500,6 +503,43 @@ impl Editor {
         }
     }
 
+    /// Handle PluginUnloaded command
+    ///
+    /// Removes menu items that ran one of the plugin's actions and plugin
+    /// menus left with nothing to run. An open menu is closed so its index
+    /// into `all_menus()` can't go stale.
+    pub(super) fn handle_plugin_unloaded(&mut self, name: String, actions: Vec<String>) {
+        if actions.is_empty() {
+            return;
+        }
+        let mut removed = 0;
+        for menu in self
+            .menus
+            .menus
+            .iter_mut()
+            .chain(self.menu_state.plugin_menus.iter_mut())
+        {
+            removed += remove_menu_items_for_actions(&mut menu.items, &actions);
+        }
+        let menus_before = self.menu_state.plugin_menus.len();
+        self.menu_state.plugin_menus.retain(|m| {
+            m.items
+                .iter()
+                .any(|item| !matches!(item, crate::config::MenuItem::Separator { .. }))
+        });
+        let menus_removed = menus_before - self.menu_state.plugin_menus.len();
+
+        if removed > 0 || menus_removed > 0 {
+            tracing::info!(
+                "Plugin '{}' unloaded: removed {} menu item(s) and {} menu(s)",
+                name,
+                removed,
+                menus_removed
+            );
+            self.close_menu_with_auto_hide();
+        }
+    }
+
     // ==================== Split Commands ====================
 
     /// Handle FocusSplit command
@@ -707,6 +747,32 @@ impl Editor {
 
     // ==================== Text Editing Commands ====================
 
+    /// Check that a plugin edit fits inside the buffer
+    ///
+    /// Plugins compute offsets from snapshots that may be stale by the time
+    /// the command arrives, so a bad range is logged and dropped.
+    fn validate_plugin_edit(
+        &mut self,
+        buffer_id: BufferId,
+        range: &std::ops::Range<usize>,
+    ) -> bool {
+        let Some(len) = self.buffers.get(&buffer_id).map(|s| s.buffer.len()) else {
+            self.log_plugin_issue(&format!(
+                "Edit rejected: buffer {} does not exist",
+                buffer_id.0
+            ));
+            return false;
+        };
+        if range.start > range.end || range.end > len {
+            self.log_plugin_issue(&format!(
+                "Edit rejected: range {}..{} is outside buffer {} (length {})",
+                range.start, range.end, buffer_id.0, len
+            ));
+            return false;
+        }
+        true
+    }
+
     /// Handle InsertText command
     pub(super) fn handle_insert_text(
         &mut self,
@@ -714,6 +780,9 @@ impl Editor {
         position: usize,
         text: String,
     ) {
+        if !self.validate_plugin_edit(buffer_id, &(position..position)) {
+            return;
+        }
         let text_len = text.len();
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
             let event = Event::Insert {
@@ -741,6 +810,9 @@ impl Editor {
         buffer_id: BufferId,
         range: std::ops::Range<usize>,
     ) {
+        if !self.validate_plugin_edit(buffer_id, &range) {
+            return;
+        }
         let delete_start = range.start;
         let delete_len = range.end.saturating_sub(range.start);
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
@@ -766,6 +838,46 @@ impl Editor {
         }
     }
 
+    /// Handle ReplaceRange command
+    ///
+    /// The delete and insert are applied as one bulk edit, so a single undo
+    /// restores the original text and LSP servers see the change.
+    pub(super) fn handle_replace_range(
+        &mut self,
+        buffer_id: BufferId,
+        range: std::ops::Range<usize>,
+        text: String,
+    ) {
+        if !self.validate_plugin_edit(buffer_id, &range) {
+            return;
+        }
+        let mut events = Vec::new();
+        if !range.is_empty() {
+            let deleted_text = self
+                .buffers
+                .get_mut(&buffer_id)
+                .map(|state| state.get_text_range(range.start, range.end))
+                .unwrap_or_default();
+            events.push(Event::Delete {
+                range: range.clone(),
+                deleted_text,
+                cursor_id: CursorId(0),
+            });
+        }
+        if !text.is_empty() {
+            events.push(Event::Insert {
+                position: range.start,
+                text,
+                cursor_id: CursorId(0),
+            });
+        }
+        if let Err(e) =
+            self.apply_events_to_buffer_as_bulk_edit(buffer_id, events, "Plugin edit".to_string())
+        {
+            self.log_plugin_issue(&format!("Edit failed: {}", e));
+        }
+    }
+
     /// Handle InsertAtCursor command
     pub(super) fn handle_insert_at_cursor(&mut self, text: String) {
         // Read cursor position first to avoid borrow conflicts
@@ -1172,6 +1284,7 @@ impl Editor {
                 || lower.contains("error in")
             {
                 self.plugin_errors.push(message.clone());
+                self.log_plugin_issue(&message);
             }
             // Clear core status message so only plugin message shows
             self.status_message = None;
@@ -1179,6 +1292,17 @@ impl Editor {
         }
     }
 
+    /// Handle SetStatusBarSegment command
+    pub(super) fn handle_set_status_bar_segment(
+        &mut self,
+        name: String,
+        text: String,
+        command: Option<String>,
+    ) {
+        self.plugin_status_segments
+            .insert(name, PluginStatusSegment { text, command });
+    }
+
     /// Handle StartPrompt command
     pub(super) fn handle_start_prompt(&mut self, label: String, prompt_type: String) {
         // Create a plugin-controlled prompt
@@ -1313,6 +1437,8 @@ impl Editor {
         // Convert from plugin API command to internal command
         let internal_command = EditorCommand {
             name: command.name.clone(),
+            id: command.action_name.clone(),
+            english_name: command.name.clone(),
             description: command.description,
             action: Action::PluginAction(command.action_name),
             contexts: vec![], // Plugin commands available in all contexts by default
@@ -1429,6 +1555,135 @@ impl Editor {
         }
     }
 
+    // ==================== File Edit Commands ====================
+
+    /// Handle ReplaceInFile command
+    pub(super) fn handle_replace_in_file(
+        &mut self,
+        path: std::path::PathBuf,
+        edits: Vec<FileEdit>,
+        callback_id: JsCallbackId,
+    ) {
+        match self.replace_in_file(&path, edits) {
+            Ok(count) => {
+                self.plugin_manager
+                    .resolve_callback(callback_id, count.to_string());
+            }
+            Err(e) => {
+                tracing::warn!("ReplaceInFile failed for {:?}: {}", path, e);
+                self.plugin_manager.reject_callback(callback_id, e);
+            }
+        }
+    }
+
+    /// Apply byte range edits to a file, returning the number applied
+    ///
+    /// Open buffers are edited in memory as one undoable batch; other files
+    /// are read, edited and written back through the atomic write path.
+    fn replace_in_file(
+        &mut self,
+        path: &std::path::Path,
+        mut edits: Vec<FileEdit>,
+    ) -> Result<usize, String> {
+        // Apply from the end so earlier offsets stay valid
+        edits.sort_by(|a, b| b.start.cmp(&a.start));
+        if edits.iter().any(|e| e.start > e.end) || edits.windows(2).any(|w| w[1].end > w[0].start)
+        {
+            return Err("edits overlap or have inverted ranges".to_string());
+        }
+
+        let path = self
+            .filesystem
+            .canonicalize(path)
+            .unwrap_or_else(|_| path.to_path_buf());
+        let open_buffer = self
+            .buffers
+            .iter()
+            .find(|(_, state)| state.buffer.file_path() == Some(path.as_path()))
+            .map(|(id, _)| *id);
+
+        if let Some(buffer_id) = open_buffer {
+            let state = self.buffers.get_mut(&buffer_id).unwrap();
+            if edits.first().is_some_and(|e| e.end > state.buffer.len()) {
+                return Err("edit is past the end of the buffer".to_string());
+            }
+            // An offset inside a character would split its UTF-8 sequence
+            let on_boundary = |pos: usize| state.buffer.snap_to_char_boundary(pos) == pos;
+            if edits
+                .iter()
+                .any(|e| !on_boundary(e.start) || !on_boundary(e.end))
+            {
+                return Err("edit does not match the buffer contents".to_string());
+            }
+
+            let mut events = Vec::new();
+            for edit in &edits {
+                if edit.end > edit.start {
+                    events.push(Event::Delete {
+                        range: edit.start..edit.end,
+                        deleted_text: state.get_text_range(edit.start, edit.end),
+                        cursor_id: CursorId(0),
+                    });
+                }
+                if !edit.text.is_empty() {
+                    events.push(Event::Insert {
+                        position: edit.start,
+                        text: edit.text.clone(),
+                        cursor_id: CursorId(0),
+                    });
+                }
+            }
+            if events.is_empty() {
+                return Ok(0);
+            }
+
+            let batch = Event::Batch {
+                events,
+                description: "Replace in files".to_string(),
+            };
+            // Apply to buffer with dummy cursors (real cursors adjusted below)
+            state.apply(&mut Cursors::default(), &batch);
+            if let Some(log) = self.event_logs.get_mut(&buffer_id) {
+                log.append(batch);
+            }
+            for leaf_id in self.split_manager.splits_for_buffer(buffer_id) {
+                if let Some(view_state) = self.split_view_states.get_mut(&leaf_id) {
+                    for edit in &edits {
+                        view_state.cursors.adjust_for_edit(
+                            edit.start,
+                            edit.end - edit.start,
+                            edit.text.len(),
+                        );
+                    }
+                }
+            }
+            return Ok(edits.len());
+        }
+
+        let bytes = self
+            .filesystem
+            .read_file(&path)
+            .map_err(|e| e.to_string())?;
+        let (_, is_binary) = crate::model::encoding::detect_encoding_or_binary(&bytes);
+        if is_binary {
+            return Err("binary file".to_string());
+        }
+        let mut content = String::from_utf8(bytes).map_err(|_| "not UTF-8".to_string())?;
+        for edit in &edits {
+            if edit.end > content.len()
+                || !content.is_char_boundary(edit.start)
+                || !content.is_char_boundary(edit.end)
+            {
+                return Err("edit does not match the file contents".to_string());
+            }
+            content.replace_range(edit.start..edit.end, &edit.text);
+        }
+        self.filesystem
+            .write_file(&path, content.as_bytes())
+            .map_err(|e| e.to_string())?;
+        Ok(edits.len())
+    }
+
     // ==================== Clipboard Commands ====================
 
     /// Handle SetClipboard command
@@ -1468,6 +1723,8 @@ impl Editor {
         // Convert LanguagePackConfig to the internal LanguageConfig format
         let lang_config = crate::config::LanguageConfig {
             comment_prefix: config.comment_prefix,
+            block_comment_start: config.block_comment_start,
+            block_comment_end: config.block_comment_end,
             auto_indent: config.auto_indent.unwrap_or(true),
             use_tabs: config.use_tabs.unwrap_or(false),
             tab_size: config.tab_size,
@@ -1583,22 +1840,24 @@ impl Editor {
                 );
                 self.grammar_registry = std::sync::Arc::new(new_registry);
 
-                // Re-detect syntax for all buffers that might now have highlighting
+                // Re-detect syntax for all buffers that might now have highlighting,
+                // except those whose language was set by hand
                 // Collect buffer IDs and paths first to avoid borrow issues
                 let buffers_to_update: Vec<_> = self
                     .buffer_metadata
                     .iter()
+                    .filter(|(_, meta)| meta.language_override.is_none())
                     .filter_map(|(id, meta)| meta.file_path().map(|p| (*id, p.to_path_buf())))
                     .collect();
 
                 for (buf_id, path) in buffers_to_update {
                     if let Some(state) = self.buffers.get_mut(&buf_id) {
-                        let detected =
-                            crate::primitives::detected_language::DetectedLanguage::from_path(
-                                &path,
-                                &self.grammar_registry,
-                                &self.config.languages,
-                            );
+                        let detected = DetectedLanguage::from_path_and_content(
+                            &path,
+                            &state.buffer,
+                            &self.grammar_registry,
+                            &self.config.languages,
+                        );
 
                         // Only update if the new engine has highlighting capability
                         // or if the current one doesn't (don't downgrade)
@@ -1628,3 +1887,25 @@ impl Editor {
         }
     }
 }
+
+/// Remove menu items (including inside submenus) that run one of `actions`
+///
+/// Returns how many were removed.
+fn remove_menu_items_for_actions(
+    items: &mut Vec<crate::config::MenuItem>,
+    actions: &[String],
+) -> usize {
+    use crate::config::MenuItem;
+
+    let before = items.len();
+    items.retain(
+        |item| !matches!(item, MenuItem::Action { action, .. } if actions.contains(action)),
+    );
+    let mut removed = before - items.len();
+    for item in items.iter_mut() {
+        if let MenuItem::Submenu { items, .. } = item {
+            removed += remove_menu_items_for_actions(items, actions);
+        }
+    }
+    removed
+}
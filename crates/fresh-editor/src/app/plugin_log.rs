@@ -0,0 +1,79 @@
+//! Plugin log buffer.
+//!
+//! Problems caused by plugins - errors thrown from handlers, hooks or actions
+//! that block the plugin thread for too long, and edits rejected because their
+//! range doesn't fit the buffer - are collected here instead of interrupting
+//! the user. The `*Plugin Log*` buffer shows them and follows new entries
+//! while it is open.
+
+use rust_i18n::t;
+
+use super::{BufferId, Editor};
+
+/// The name of the plugin log buffer
+pub const PLUGIN_LOG_BUFFER_NAME: &str = "*Plugin Log*";
+
+/// Oldest entries are dropped past this many lines
+const MAX_PLUGIN_LOG_LINES: usize = 1000;
+
+impl Editor {
+    /// Append a timestamped line to the plugin log
+    pub(crate) fn log_plugin_issue(&mut self, message: &str) {
+        tracing::warn!(target: "plugins", "{}", message);
+        let line = format!(
+            "[{}] {}\n",
+            chrono::Local::now().format("%H:%M:%S"),
+            message.trim_end()
+        );
+        if self.plugin_log.len() >= MAX_PLUGIN_LOG_LINES {
+            self.plugin_log.remove(0);
+        }
+        self.plugin_log.push(line.clone());
+
+        if let Some(buffer_id) = self.find_buffer_by_name(PLUGIN_LOG_BUFFER_NAME) {
+            if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                let end = state.buffer.len();
+                state.buffer.insert(end, &line);
+                state.buffer.clear_modified();
+            }
+        }
+    }
+
+    /// Lines logged for plugins so far, oldest first
+    pub fn plugin_log(&self) -> &[String] {
+        &self.plugin_log
+    }
+
+    /// Open the plugin log buffer
+    pub fn open_plugin_log(&mut self) {
+        if let Some(buffer_id) = self.find_buffer_by_name(PLUGIN_LOG_BUFFER_NAME) {
+            self.set_active_buffer(buffer_id);
+            return;
+        }
+
+        let content = self.plugin_log.concat();
+        let buffer_id = self.create_virtual_buffer(
+            PLUGIN_LOG_BUFFER_NAME.to_string(),
+            "special".to_string(),
+            true,
+        );
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.buffer.insert(0, &content);
+            state.buffer.clear_modified();
+            state.editing_disabled = true;
+            state.margins.configure_for_line_numbers(false);
+        }
+        self.set_active_buffer(buffer_id);
+        if self.plugin_log.is_empty() {
+            self.set_status_message(t!("status.plugin_log_empty").to_string());
+        }
+    }
+
+    /// Buffer shown with the given tab name
+    pub(super) fn find_buffer_by_name(&self, name: &str) -> Option<BufferId> {
+        self.buffer_metadata
+            .iter()
+            .find(|(_, m)| m.display_name == name)
+            .map(|(id, _)| *id)
+    }
+}
@@ -0,0 +1,365 @@
+//! Manage Plugins panel.
+//!
+//! Lists the plugins found in the plugin directories with their version, load
+//! status and last error. In the `*Plugins*` buffer, Space enables or disables
+//! the plugin under the cursor (persisted to the `plugins` config), `r`
+//! reloads it and Enter opens its details, including the full error with the
+//! JavaScript stack trace when loading failed.
+
+use std::path::{Path, PathBuf};
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::primitives::text_property::TextPropertyEntry;
+
+/// The name of the plugin panel buffer
+pub const PLUGIN_PANEL_BUFFER_NAME: &str = "*Plugins*";
+
+/// Buffer mode of the plugin panel (see `ModeRegistry::new`)
+pub const PLUGIN_PANEL_MODE: &str = "plugin-manager";
+
+/// Text property holding the plugin name of each panel line
+const PLUGIN_PROPERTY: &str = "plugin";
+
+/// A plugin as shown in the panel
+#[derive(Debug, Clone, PartialEq)]
+struct PluginPanelEntry {
+    name: String,
+    path: Option<PathBuf>,
+    version: Option<String>,
+    enabled: bool,
+    loaded: bool,
+    error: Option<String>,
+}
+
+impl PluginPanelEntry {
+    fn status(&self) -> String {
+        if let Some(error) = &self.error {
+            let first_line = error.lines().next().unwrap_or_default();
+            format!("error: {}", first_line)
+        } else if self.loaded {
+            "loaded".to_string()
+        } else if !self.enabled {
+            "disabled".to_string()
+        } else {
+            "not loaded".to_string()
+        }
+    }
+}
+
+impl Editor {
+    /// Open the Manage Plugins panel, or refresh it if it is already open
+    pub fn open_plugin_panel(&mut self) {
+        let entries = self.plugin_panel_entries();
+        let content = plugin_panel_content(&entries);
+
+        let buffer_id = match self.find_buffer_by_name(PLUGIN_PANEL_BUFFER_NAME) {
+            Some(buffer_id) => buffer_id,
+            None => {
+                let buffer_id = self.create_virtual_buffer(
+                    PLUGIN_PANEL_BUFFER_NAME.to_string(),
+                    PLUGIN_PANEL_MODE.to_string(),
+                    true,
+                );
+                if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                    state.editing_disabled = true;
+                    state.margins.configure_for_line_numbers(false);
+                }
+                buffer_id
+            }
+        };
+        if let Err(e) = self.set_virtual_buffer_content(buffer_id, content) {
+            tracing::error!("Failed to fill plugin panel: {}", e);
+        }
+        self.set_active_buffer(buffer_id);
+    }
+
+    /// Enable or disable the plugin under the cursor and save the choice
+    pub fn plugin_panel_toggle(&mut self) {
+        let Some(entry) = self.plugin_panel_selection() else {
+            return;
+        };
+        let enabled = !entry.enabled;
+
+        let old_plugins = self.config.plugins.clone();
+        let config = self.config.plugins.entry(entry.name.clone()).or_default();
+        config.enabled = enabled;
+        if config.path.is_none() {
+            config.path = entry.path.clone();
+        }
+        self.apply_plugin_config_changes(&old_plugins);
+        self.persist_config_change(
+            &format!("/plugins/{}/enabled", entry.name),
+            serde_json::Value::Bool(enabled),
+        );
+
+        let message = if enabled {
+            t!("status.plugin_enabled", name = &entry.name)
+        } else {
+            t!("status.plugin_disabled", name = &entry.name)
+        };
+        self.set_status_message(message.to_string());
+        self.open_plugin_panel();
+    }
+
+    /// Tear down the plugin under the cursor and run it again
+    pub fn plugin_panel_reload(&mut self) {
+        let Some(entry) = self.plugin_panel_selection() else {
+            return;
+        };
+        if !entry.enabled {
+            self.set_status_message(
+                t!("status.plugin_reload_disabled", name = &entry.name).to_string(),
+            );
+            return;
+        }
+        let Some(path) = entry.path.clone() else {
+            return;
+        };
+
+        if entry.loaded {
+            if let Err(e) = self.plugin_manager.unload_plugin(&entry.name) {
+                tracing::warn!("Failed to unload plugin '{}': {}", entry.name, e);
+            }
+        }
+        match self.plugin_manager.load_plugin(&path) {
+            Ok(()) => {
+                self.plugin_load_errors.remove(&entry.name);
+                self.set_status_message(
+                    t!("status.plugin_reloaded", name = &entry.name).to_string(),
+                );
+            }
+            Err(e) => {
+                let error = format!("{:#}", e);
+                self.log_plugin_issue(&format!(
+                    "Failed to load plugin '{}': {}",
+                    entry.name, error
+                ));
+                self.plugin_load_errors.insert(entry.name.clone(), error);
+                self.set_status_message(
+                    t!("status.plugin_reload_failed", name = &entry.name).to_string(),
+                );
+            }
+        }
+        self.open_plugin_panel();
+    }
+
+    /// Show path, version, status and the full last error of the plugin
+    /// under the cursor
+    pub fn plugin_panel_details(&mut self) {
+        let Some(entry) = self.plugin_panel_selection() else {
+            return;
+        };
+
+        let mut content = format!("Plugin: {}\n", entry.name);
+        content.push_str(&"=".repeat(content.len() - 1));
+        content.push_str("\n\n");
+        if let Some(path) = &entry.path {
+            content.push_str(&format!("Path:    {}\n", path.display()));
+        }
+        content.push_str(&format!(
+            "Version: {}\n",
+            entry.version.as_deref().unwrap_or("-")
+        ));
+        content.push_str(&format!("Status:  {}\n", entry.status()));
+        if let Some(error) = &entry.error {
+            content.push_str("\nLast error:\n\n");
+            content.push_str(error);
+            content.push('\n');
+        }
+
+        let name = format!("*Plugin: {}*", entry.name);
+        let buffer_id = match self.find_buffer_by_name(&name) {
+            Some(buffer_id) => buffer_id,
+            None => {
+                let buffer_id = self.create_virtual_buffer(name, "special".to_string(), true);
+                if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                    state.editing_disabled = true;
+                    state.margins.configure_for_line_numbers(false);
+                }
+                buffer_id
+            }
+        };
+        if let Err(e) =
+            self.set_virtual_buffer_content(buffer_id, vec![TextPropertyEntry::text(content)])
+        {
+            tracing::error!("Failed to fill plugin details: {}", e);
+        }
+        self.set_active_buffer(buffer_id);
+    }
+
+    /// Plugin on the cursor line of the plugin panel
+    fn plugin_panel_selection(&self) -> Option<PluginPanelEntry> {
+        let buffer_id = self.active_buffer();
+        if self.find_buffer_by_name(PLUGIN_PANEL_BUFFER_NAME) != Some(buffer_id) {
+            return None;
+        }
+        let position = self.active_cursors().primary().position;
+        let name = self
+            .buffers
+            .get(&buffer_id)?
+            .text_properties
+            .get_at(position)
+            .into_iter()
+            .find_map(|p| {
+                p.properties
+                    .get(PLUGIN_PROPERTY)?
+                    .as_str()
+                    .map(String::from)
+            })?;
+        self.plugin_panel_entries()
+            .into_iter()
+            .find(|entry| entry.name == name)
+    }
+
+    /// Discovered and loaded plugins, sorted by name
+    fn plugin_panel_entries(&self) -> Vec<PluginPanelEntry> {
+        let loaded = self.loaded_plugins();
+        let mut entries: Vec<PluginPanelEntry> = self
+            .config
+            .plugins
+            .iter()
+            .map(|(name, config)| PluginPanelEntry {
+                name: name.clone(),
+                path: config.path.clone(),
+                version: None,
+                enabled: config.enabled,
+                loaded: false,
+                error: None,
+            })
+            .collect();
+        for (name, path) in loaded {
+            match entries.iter_mut().find(|entry| entry.name == name) {
+                Some(entry) => entry.loaded = true,
+                None => entries.push(PluginPanelEntry {
+                    name,
+                    path: Some(path),
+                    version: None,
+                    enabled: true,
+                    loaded: true,
+                    error: None,
+                }),
+            }
+        }
+        for entry in &mut entries {
+            entry.version = entry.path.as_deref().and_then(plugin_version);
+            entry.error = self.plugin_load_errors.get(&entry.name).cloned();
+        }
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        entries
+    }
+
+    /// Names and paths of the plugins currently running
+    #[cfg(feature = "plugins")]
+    fn loaded_plugins(&self) -> Vec<(String, PathBuf)> {
+        self.plugin_manager
+            .list_plugins()
+            .into_iter()
+            .map(|info| (info.name, info.path))
+            .collect()
+    }
+
+    /// Names and paths of the plugins currently running
+    #[cfg(not(feature = "plugins"))]
+    fn loaded_plugins(&self) -> Vec<(String, PathBuf)> {
+        Vec::new()
+    }
+}
+
+/// Lines of the plugin panel, each tagged with its plugin name
+fn plugin_panel_content(entries: &[PluginPanelEntry]) -> Vec<TextPropertyEntry> {
+    let mut lines = vec![
+        TextPropertyEntry::text("Plugins\n"),
+        TextPropertyEntry::text("=======\n\n"),
+        TextPropertyEntry::text(
+            "Space: enable/disable   r: reload   Enter: details   q: close\n\n",
+        ),
+    ];
+    if entries.is_empty() {
+        lines.push(TextPropertyEntry::text("No plugins found.\n"));
+    }
+    let name_width = entries
+        .iter()
+        .map(|entry| entry.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(12);
+    for entry in entries {
+        let line = format!(
+            "  [{}] {:name_width$}  {:10}  {}\n",
+            if entry.enabled { "x" } else { " " },
+            entry.name,
+            entry.version.as_deref().unwrap_or("-"),
+            entry.status(),
+        );
+        lines.push(TextPropertyEntry::text(line).with_property(
+            PLUGIN_PROPERTY,
+            serde_json::Value::String(entry.name.clone()),
+        ));
+    }
+    lines
+}
+
+/// Version of a plugin file
+///
+/// Taken from a `@version` tag (or `version:` line) in the comments at the top
+/// of the file, falling back to the `version` of a `package.json` next to it.
+fn plugin_version(path: &Path) -> Option<String> {
+    if let Ok(source) = std::fs::read_to_string(path) {
+        if let Some(version) = header_version(&source) {
+            return Some(version);
+        }
+    }
+    let manifest = std::fs::read_to_string(path.parent()?.join("package.json")).ok()?;
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).ok()?;
+    manifest.get("version")?.as_str().map(String::from)
+}
+
+/// Version from the leading comment block of a plugin source
+fn header_version(source: &str) -> Option<String> {
+    for line in source.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let comment = ["///", "//", "/**", "/*", "*/", "*"]
+            .iter()
+            .find_map(|prefix| line.strip_prefix(prefix))?
+            .trim();
+        let rest = comment
+            .strip_prefix("@version")
+            .or_else(|| comment.strip_prefix("version:"))
+            .or_else(|| comment.strip_prefix("Version:"));
+        if let Some(version) = rest
+            .map(|rest| rest.trim_start_matches(':'))
+            .and_then(|rest| rest.split_whitespace().next())
+        {
+            return Some(version.to_string());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_version() {
+        assert_eq!(
+            header_version("/// <reference path=\"./lib/fresh.d.ts\" />\n// @version 1.2.0\nconst editor = getEditor();\n")
+                .as_deref(),
+            Some("1.2.0")
+        );
+        assert_eq!(
+            header_version("/**\n * Git blame\n * Version: 0.3\n */\n").as_deref(),
+            Some("0.3")
+        );
+        // Only the leading comment block counts
+        assert_eq!(
+            header_version("const editor = getEditor();\n// @version 9.9.9\n"),
+            None
+        );
+    }
+}
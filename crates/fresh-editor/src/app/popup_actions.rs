@@ -4,8 +4,9 @@
 
 use super::Editor;
 use crate::model::event::Event;
-use crate::primitives::snippet::{expand_snippet, is_snippet};
+use crate::primitives::snippet::is_snippet;
 use crate::primitives::word_navigation::find_completion_word_start;
+use lsp_types::InsertTextFormat;
 use rust_i18n::t;
 
 /// Result of handling a popup confirmation.
@@ -81,16 +82,9 @@ impl Editor {
     }
 
     /// Insert completion text, replacing the word prefix at cursor.
-    /// If the text contains LSP snippet syntax, it will be expanded.
+    /// Snippet items (and text with LSP snippet syntax from unknown items)
+    /// go through the snippet engine instead.
     fn insert_completion_text(&mut self, text: String) {
-        // Check if this is a snippet and expand it
-        let (insert_text, cursor_offset) = if is_snippet(&text) {
-            let expanded = expand_snippet(&text);
-            (expanded.text, Some(expanded.cursor_offset))
-        } else {
-            (text, None)
-        };
-
         let (cursor_id, cursor_pos, word_start) = {
             let cursors = self.active_cursors();
             let cursor_id = cursors.primary_id();
@@ -100,6 +94,17 @@ impl Editor {
             (cursor_id, cursor_pos, word_start)
         };
 
+        let snippet_format = self
+            .completion_items
+            .iter()
+            .flatten()
+            .find(|item| *item.insert_text.as_ref().unwrap_or(&item.label) == text)
+            .map(|item| item.insert_text_format == Some(InsertTextFormat::SNIPPET));
+        if snippet_format.unwrap_or_else(|| is_snippet(&text)) {
+            self.insert_snippet(word_start..cursor_pos, &text);
+            return;
+        }
+
         let deleted_text = if word_start < cursor_pos {
             self.active_state_mut()
                 .get_text_range(word_start, cursor_pos)
@@ -125,35 +130,12 @@ impl Editor {
 
         let insert_event = Event::Insert {
             position: insert_pos,
-            text: insert_text.clone(),
+            text,
             cursor_id,
         };
 
         self.active_event_log_mut().append(insert_event.clone());
         self.apply_event_to_active_buffer(&insert_event);
-
-        // If this was a snippet, position cursor at the snippet's $0 location
-        if let Some(offset) = cursor_offset {
-            let new_cursor_pos = insert_pos + offset;
-            // Get current cursor position after the insert
-            let current_pos = self.active_cursors().primary().position;
-            if current_pos != new_cursor_pos {
-                let move_event = Event::MoveCursor {
-                    cursor_id,
-                    old_position: current_pos,
-                    new_position: new_cursor_pos,
-                    old_anchor: None,
-                    new_anchor: None,
-                    old_sticky_column: 0,
-                    new_sticky_column: 0,
-                };
-                let split_id = self.split_manager.active_split();
-                let buffer_id = self.active_buffer();
-                let state = self.buffers.get_mut(&buffer_id).unwrap();
-                let cursors = &mut self.split_view_states.get_mut(&split_id).unwrap().cursors;
-                state.apply(cursors, &move_event);
-            }
-        }
     }
 
     /// Handle PopupCancel action.
@@ -137,6 +137,7 @@ impl Editor {
             }
             PromptType::GotoLine => match input.trim().parse::<usize>() {
                 Ok(line_num) if line_num > 0 => {
+                    self.record_jump();
                     self.goto_line_col(line_num, None);
                     self.set_status_message(t!("goto.jumped", line = line_num).to_string());
                 }
@@ -156,6 +157,7 @@ impl Editor {
                     .unwrap_or(trimmed);
                 match num_str.parse::<usize>() {
                     Ok(offset) => {
+                        self.record_jump();
                         self.goto_byte_offset(offset);
                         self.set_status_message(
                             t!("goto.jumped_byte", offset = offset).to_string(),
@@ -233,6 +235,23 @@ impl Editor {
                     "Bookmark",
                 );
             }
+            PromptType::BookmarkLabel => {
+                self.add_labeled_line_bookmark(input.trim());
+            }
+            PromptType::BrowseBookmarks => {
+                self.goto_line_bookmark(input.trim());
+            }
+            PromptType::PasteFromHistory => {
+                if let Ok(index) = input.parse::<usize>() {
+                    self.paste_from_history(index);
+                }
+            }
+            PromptType::ExportDestination => {
+                self.finish_export(input.trim());
+            }
+            PromptType::InsertUnicodeChar => {
+                self.insert_unicode_char(input.trim());
+            }
             PromptType::Plugin { custom_type } => {
                 tracing::info!(
                     "prompt_confirmed: dispatching hook for prompt_type='{}', input='{}', selected_index={:?}",
@@ -276,6 +295,15 @@ impl Editor {
                     self.set_status_message(t!("buffer.save_cancelled").to_string());
                 }
             }
+            PromptType::ConfirmExternalChange { buffer_id } => {
+                self.handle_confirm_external_change(&input, buffer_id);
+            }
+            PromptType::ConfirmLspRestart { language } => {
+                let input_lower = input.trim().to_lowercase();
+                if input_lower == "y" || input_lower == "yes" {
+                    self.restart_lsp_server(&language);
+                }
+            }
             PromptType::ConfirmSudoSave { info } => {
                 let input_lower = input.trim().to_lowercase();
                 if input_lower == "y" || input_lower == "yes" {
@@ -340,6 +368,8 @@ impl Editor {
                 if input_lower == "o" || input_lower == "overwrite" {
                     self.perform_save_file_as(path);
                 } else {
+                    self.pending_export = None;
+                    self.quit_review = None;
                     self.set_status_message(t!("buffer.save_cancelled").to_string());
                 }
             }
@@ -349,13 +379,10 @@ impl Editor {
                 }
             }
             PromptType::ConfirmQuitWithModified => {
-                let input_lower = input.trim().to_lowercase();
-                let discard_key = t!("prompt.key.discard").to_string().to_lowercase();
-                if input_lower == discard_key || input_lower == "discard" {
-                    self.should_quit = true;
-                } else {
-                    self.set_status_message(t!("buffer.close_cancelled").to_string());
-                }
+                self.handle_confirm_quit(&input);
+            }
+            PromptType::QuitReview { buffer_id } => {
+                self.handle_quit_review(&input, buffer_id);
             }
             PromptType::LspRename {
                 original_text,
@@ -435,6 +462,31 @@ impl Editor {
                     self.switch_to_tab(BufferId(id));
                 }
             }
+            PromptType::SwitchBuffer => {
+                self.confirm_buffer_picker(input.trim());
+            }
+            PromptType::SwitchTerminal => {
+                if let Ok(id) = input.trim().parse::<usize>() {
+                    self.switch_to_terminal(BufferId(id));
+                }
+            }
+            PromptType::RunTask => {
+                self.run_task(input.trim());
+            }
+            PromptType::RenameTerminal { buffer_id } => {
+                self.rename_terminal(buffer_id, &input);
+            }
+            PromptType::AdjustColor { buffer_id, literal } => {
+                self.apply_adjusted_color(buffer_id, &literal, &input);
+            }
+            PromptType::ConfirmCloseSplit => {
+                let input_lower = input.trim().to_lowercase();
+                if input_lower == "y" || input_lower == "yes" {
+                    self.close_active_split_and_terminals();
+                } else {
+                    self.set_status_message(t!("split.close_cancelled").to_string());
+                }
+            }
             PromptType::QueryReplaceConfirm => {
                 // This is handled by InsertChar, not PromptConfirm
                 // But if somehow Enter is pressed, treat it as skip (n)
@@ -447,6 +499,21 @@ impl Editor {
             PromptType::AddRuler => {
                 self.handle_add_ruler(&input);
             }
+            PromptType::AlignByPattern => {
+                self.align_by_pattern(&input);
+            }
+            PromptType::HardWrap => {
+                self.handle_hard_wrap_width(&input);
+            }
+            PromptType::FilterLogLines => {
+                self.handle_log_filter(&input);
+            }
+            PromptType::SurroundWith => {
+                self.handle_surround_with(&input);
+            }
+            PromptType::ChangeSurrounding => {
+                self.handle_change_surrounding(&input);
+            }
             PromptType::RemoveRuler => {
                 self.handle_remove_ruler(&input);
             }
@@ -462,6 +529,9 @@ impl Editor {
             PromptType::SetLanguage => {
                 self.handle_set_language(&input);
             }
+            PromptType::NewScratchBuffer => {
+                self.handle_new_scratch_buffer(&input);
+            }
             PromptType::ShellCommand { replace } => {
                 self.handle_shell_command(&input, replace);
             }
@@ -495,7 +565,7 @@ impl Editor {
             .map(|p| p.to_path_buf());
         let is_different_file = current_file_path.as_ref() != Some(&full_path);
 
-        if is_different_file && full_path.is_file() {
+        if (is_different_file || self.is_exporting()) && full_path.is_file() {
             // File exists and is different from current - ask for confirmation
             let filename = full_path
                 .file_name()
@@ -514,6 +584,10 @@ impl Editor {
 
     /// Perform the actual SaveFileAs operation (called after confirmation if needed).
     pub(crate) fn perform_save_file_as(&mut self, full_path: std::path::PathBuf) {
+        if self.write_pending_export(&full_path) {
+            return;
+        }
+
         let before_idx = self.active_event_log().current_index();
         let before_len = self.active_event_log().len();
         tracing::debug!(
@@ -600,6 +674,8 @@ impl Editor {
                     } else {
                         self.set_status_message(t!("buffer.saved_and_closed").to_string());
                     }
+                } else if self.quit_review.is_some() {
+                    self.review_next_quit_item();
                 } else {
                     self.set_status_message(
                         t!("file.saved_as", path = full_path.display().to_string()).to_string(),
@@ -608,6 +684,7 @@ impl Editor {
             }
             Err(e) => {
                 self.pending_close_buffer = None;
+                self.quit_review = None;
                 self.set_status_message(t!("file.error_saving", error = e.to_string()).to_string());
             }
         }
@@ -917,53 +994,41 @@ impl Editor {
 
     /// Handle SetLanguage prompt confirmation.
     fn handle_set_language(&mut self, input: &str) {
-        use crate::primitives::detected_language::DetectedLanguage;
-
         let trimmed = input.trim();
-
-        // Check for "Plain Text" (no highlighting)
-        if trimmed == "Plain Text" || trimmed.to_lowercase() == "text" {
-            let buffer_id = self.active_buffer();
-            if let Some(state) = self.buffers.get_mut(&buffer_id) {
-                state.apply_language(DetectedLanguage::plain_text());
-                self.set_status_message("Language set to Plain Text".to_string());
-            }
-            #[cfg(feature = "plugins")]
-            self.update_plugin_state_snapshot();
-            self.plugin_manager.run_hook(
-                "language_changed",
-                crate::services::plugins::hooks::HookArgs::LanguageChanged {
-                    buffer_id: self.active_buffer(),
-                    language: "text".to_string(),
-                },
-            );
-            return;
+        let buffer_id = self.active_buffer();
+        if self.set_buffer_language(buffer_id, trimmed) {
+            let name = if trimmed.eq_ignore_ascii_case("text") {
+                super::language_mode::PLAIN_TEXT
+            } else {
+                trimmed
+            };
+            self.set_status_message(format!("Language set to {}", name));
+        } else {
+            self.set_status_message(format!("Unknown language: {}", input));
         }
+    }
 
-        // Try to find the syntax by name and resolve canonical language ID from config
-        if let Some(detected) = DetectedLanguage::from_syntax_name(
-            trimmed,
-            &self.grammar_registry,
-            &self.config.languages,
-        ) {
-            let language = detected.name.clone();
-            let buffer_id = self.active_buffer();
-            if let Some(state) = self.buffers.get_mut(&buffer_id) {
-                state.apply_language(detected);
-                self.set_status_message(format!("Language set to {}", trimmed));
-            }
-            #[cfg(feature = "plugins")]
-            self.update_plugin_state_snapshot();
-            self.plugin_manager.run_hook(
-                "language_changed",
-                crate::services::plugins::hooks::HookArgs::LanguageChanged {
-                    buffer_id,
-                    language,
-                },
-            );
-        } else {
+    /// Handle NewScratchBuffer prompt confirmation.
+    fn handle_new_scratch_buffer(&mut self, input: &str) {
+        let trimmed = input.trim();
+        let known = trimmed == super::language_mode::PLAIN_TEXT
+            || trimmed.eq_ignore_ascii_case("text")
+            || self.grammar_registry.find_syntax_by_name(trimmed).is_some();
+        if !known {
             self.set_status_message(format!("Unknown language: {}", input));
+            return;
         }
+        let buffer_id = self.new_scratch_buffer();
+        self.set_buffer_language(buffer_id, trimmed);
+        let name = self.get_buffer_display_name(buffer_id);
+        self.set_status_message(
+            t!(
+                "buffer.new_scratch_language",
+                name = name,
+                language = trimmed
+            )
+            .to_string(),
+        );
     }
 
     /// Handle register-based input (macros, bookmarks).
@@ -1098,8 +1163,10 @@ impl Editor {
         if input.starts_with(':') {
             // Go to line mode
             let line_str = &input[1..];
+            self.accept_quick_open_preview();
             if let Ok(line_num) = line_str.parse::<usize>() {
                 if line_num > 0 {
+                    self.record_jump();
                     self.goto_line_col(line_num, None);
                     self.set_status_message(t!("goto.jumped", line = line_num).to_string());
                 } else {
@@ -1111,6 +1178,13 @@ impl Editor {
             return PromptResult::Done;
         }
 
+        if input.starts_with('@') {
+            // Symbol mode - jump to the selected symbol
+            let query = &input[1..];
+            self.goto_quick_open_symbol(query, selected_index);
+            return PromptResult::Done;
+        }
+
         // Default: file mode - open the selected file
         self.handle_quick_open_file(input, selected_index)
     }
@@ -1250,4 +1324,15 @@ impl Editor {
         self.set_status_message(t!("status.no_selection").to_string());
         PromptResult::Done
     }
+
+    /// Forget command palette usage, so commands are listed alphabetically again
+    pub(super) fn reset_command_ranking(&mut self) {
+        let mut registry = self.command_registry.write().unwrap();
+        registry.reset_usage();
+        if let Err(e) = registry.save_usage(&self.dir_context.command_usage_path()) {
+            tracing::warn!("Failed to save command usage: {}", e);
+        }
+        drop(registry);
+        self.set_status_message(t!("status.command_ranking_reset").to_string());
+    }
 }
@@ -0,0 +1,368 @@
+//! Line and symbol modes of Quick Open.
+//!
+//! `:42` previews line 42 by scrolling the active split while the number is
+//! typed. The previous scroll position comes back when the prefix is deleted
+//! or Quick Open is cancelled, and is dropped when the jump is confirmed.
+//!
+//! `@` lists the symbols of the active buffer, requested from its language
+//! server with `textDocument/documentSymbol` when the mode is entered and
+//! indented by nesting.
+
+use lsp_types::{DocumentSymbol, DocumentSymbolResponse, SymbolKind};
+use rust_i18n::t;
+
+use super::{BufferId, Editor};
+use crate::input::commands::Suggestion;
+use crate::input::fuzzy::fuzzy_match;
+use crate::model::event::LeafId;
+use crate::view::prompt::PromptType;
+
+/// Scroll position of a split before a `:` line preview
+pub(super) struct QuickOpenPreview {
+    split: LeafId,
+    top_byte: usize,
+    top_view_line_offset: usize,
+    left_column: usize,
+}
+
+/// A document symbol flattened for listing in Quick Open
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct QuickOpenSymbol {
+    name: String,
+    kind: SymbolKind,
+    /// Nesting level, 0 for top-level symbols
+    depth: usize,
+    /// LSP position (line, UTF-16 character) of the symbol name
+    line: u32,
+    character: u32,
+}
+
+impl Editor {
+    /// Scroll the active split to show `line_str` while it is typed after `:`
+    ///
+    /// Anything that isn't a line number puts the original scroll back.
+    pub(super) fn preview_quick_open_line(&mut self, line_str: &str) {
+        let line = match line_str.parse::<usize>() {
+            Ok(line) if line > 0 => line,
+            _ => {
+                self.restore_quick_open_preview();
+                return;
+            }
+        };
+
+        let split = self.split_manager.active_split();
+        let buffer_id = self.active_buffer();
+        let (Some(state), Some(view_state)) = (
+            self.buffers.get_mut(&buffer_id),
+            self.split_view_states.get_mut(&split),
+        ) else {
+            return;
+        };
+        let viewport = &mut view_state.viewport;
+        if self.quick_open_preview.is_none() {
+            self.quick_open_preview = Some(QuickOpenPreview {
+                split,
+                top_byte: viewport.top_byte,
+                top_view_line_offset: viewport.top_view_line_offset,
+                left_column: viewport.left_column,
+            });
+        }
+
+        // Center the target line
+        let top_line = (line - 1).saturating_sub(viewport.height as usize / 2);
+        viewport.scroll_to(&mut state.buffer, top_line);
+        viewport.left_column = 0;
+        viewport.set_skip_ensure_visible();
+    }
+
+    /// Put back the scroll position from before the `:` line preview
+    pub(super) fn restore_quick_open_preview(&mut self) {
+        let Some(preview) = self.quick_open_preview.take() else {
+            return;
+        };
+        if let Some(view_state) = self.split_view_states.get_mut(&preview.split) {
+            view_state.viewport.top_byte = preview.top_byte;
+            view_state.viewport.top_view_line_offset = preview.top_view_line_offset;
+            view_state.viewport.left_column = preview.left_column;
+            view_state.viewport.set_skip_ensure_visible();
+        }
+    }
+
+    /// Keep the current scroll position after confirming a `:` jump, and let
+    /// the viewport follow the cursor again
+    pub(super) fn accept_quick_open_preview(&mut self) {
+        if let Some(preview) = self.quick_open_preview.take() {
+            if let Some(view_state) = self.split_view_states.get_mut(&preview.split) {
+                view_state.viewport.clear_skip_ensure_visible();
+            }
+        }
+    }
+
+    /// Suggestions for the `@` symbol mode
+    ///
+    /// The first call for a buffer asks its language server for the symbols;
+    /// the list is filled in when the response arrives.
+    pub(super) fn get_symbol_suggestions(&mut self, query: &str) -> Vec<Suggestion> {
+        let buffer_id = self.active_buffer();
+        let loaded = matches!(&self.quick_open_symbols, Some((id, _)) if *id == buffer_id);
+        if !loaded {
+            let pending = matches!(
+                self.pending_document_symbols_request,
+                Some((_, id)) if id == buffer_id
+            );
+            let message = if pending || self.request_document_symbols(buffer_id) {
+                t!("quick_open.symbols_loading")
+            } else {
+                t!("quick_open.no_symbol_provider")
+            };
+            return vec![Self::symbol_hint(message.to_string())];
+        }
+
+        let symbols = match &self.quick_open_symbols {
+            Some((_, symbols)) => symbols,
+            None => return Vec::new(),
+        };
+        let suggestions: Vec<Suggestion> = Self::filter_symbols(symbols, query)
+            .into_iter()
+            .map(|index| {
+                let symbol = &symbols[index];
+                Suggestion {
+                    text: format!(
+                        "{}{} {}",
+                        "  ".repeat(symbol.depth),
+                        symbol_kind_icon(symbol.kind),
+                        symbol.name
+                    ),
+                    description: Some(
+                        t!("quick_open.symbol_line", line = symbol.line + 1).to_string(),
+                    ),
+                    value: Some(index.to_string()),
+                    disabled: false,
+                    keybinding: None,
+                    source: None,
+                }
+            })
+            .collect();
+
+        if suggestions.is_empty() {
+            return vec![Self::symbol_hint(t!("quick_open.no_symbols").to_string())];
+        }
+        suggestions
+    }
+
+    /// Jump to the symbol selected in the `@` mode
+    pub(super) fn goto_quick_open_symbol(&mut self, query: &str, selected_index: Option<usize>) {
+        let buffer_id = self.active_buffer();
+        let symbol = self
+            .quick_open_symbols
+            .as_ref()
+            .filter(|(id, _)| *id == buffer_id)
+            .and_then(|(_, symbols)| {
+                let index = *Self::filter_symbols(symbols, query).get(selected_index?)?;
+                symbols.get(index).cloned()
+            });
+        let Some(symbol) = symbol else {
+            self.set_status_message(t!("status.no_selection").to_string());
+            return;
+        };
+
+        let position = self
+            .active_state()
+            .buffer
+            .lsp_position_to_byte(symbol.line as usize, symbol.character as usize);
+        self.record_jump();
+        self.goto_byte_offset(position);
+        self.set_status_message(t!("goto.jumped", line = symbol.line + 1).to_string());
+    }
+
+    /// Ask the language server of a buffer for its symbols
+    ///
+    /// Returns false when the buffer has no language server.
+    fn request_document_symbols(&mut self, buffer_id: BufferId) -> bool {
+        let request_id = self.next_lsp_request_id;
+        let sent = self
+            .with_lsp_for_buffer(buffer_id, |handle, uri, _language| {
+                handle.document_symbols(request_id, uri.clone()).is_ok()
+            })
+            .unwrap_or(false);
+        if sent {
+            self.next_lsp_request_id += 1;
+            self.pending_document_symbols_request = Some((request_id, buffer_id));
+        }
+        sent
+    }
+
+    /// Store the symbols received from the language server and refresh the
+    /// `@` suggestions if Quick Open is still showing them
+    pub(crate) fn handle_document_symbols_response(
+        &mut self,
+        request_id: u64,
+        symbols: Option<DocumentSymbolResponse>,
+    ) {
+        let buffer_id = match self.pending_document_symbols_request {
+            Some((pending_id, buffer_id)) if pending_id == request_id => buffer_id,
+            _ => return,
+        };
+        self.pending_document_symbols_request = None;
+        self.quick_open_symbols = Some((buffer_id, flatten_document_symbols(symbols)));
+
+        let input = self
+            .prompt
+            .as_ref()
+            .filter(|prompt| prompt.prompt_type == PromptType::QuickOpen)
+            .map(|prompt| prompt.input.clone());
+        if let Some(input) = input.filter(|input| input.starts_with('@')) {
+            self.update_quick_open_suggestions(&input);
+        }
+    }
+
+    /// Indices of the symbols matching `query`: document order without a
+    /// query, best match first with one
+    fn filter_symbols(symbols: &[QuickOpenSymbol], query: &str) -> Vec<usize> {
+        if query.is_empty() {
+            return (0..symbols.len()).collect();
+        }
+        let mut matches: Vec<(usize, i32)> = symbols
+            .iter()
+            .enumerate()
+            .filter_map(|(index, symbol)| {
+                let result = fuzzy_match(query, &symbol.name);
+                result.matched.then_some((index, result.score))
+            })
+            .collect();
+        matches.sort_by(|a, b| b.1.cmp(&a.1));
+        matches.into_iter().map(|(index, _)| index).collect()
+    }
+
+    fn symbol_hint(text: String) -> Suggestion {
+        Suggestion {
+            text,
+            description: None,
+            value: None,
+            disabled: true,
+            keybinding: None,
+            source: None,
+        }
+    }
+}
+
+/// Flatten a documentSymbol response into document order, keeping the
+/// nesting depth of hierarchical responses
+fn flatten_document_symbols(response: Option<DocumentSymbolResponse>) -> Vec<QuickOpenSymbol> {
+    fn push_nested(symbols: Vec<DocumentSymbol>, depth: usize, out: &mut Vec<QuickOpenSymbol>) {
+        for symbol in symbols {
+            out.push(QuickOpenSymbol {
+                name: symbol.name,
+                kind: symbol.kind,
+                depth,
+                line: symbol.selection_range.start.line,
+                character: symbol.selection_range.start.character,
+            });
+            if let Some(children) = symbol.children {
+                push_nested(children, depth + 1, out);
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    match response {
+        Some(DocumentSymbolResponse::Nested(symbols)) => push_nested(symbols, 0, &mut out),
+        Some(DocumentSymbolResponse::Flat(symbols)) => {
+            for symbol in symbols {
+                out.push(QuickOpenSymbol {
+                    name: symbol.name,
+                    kind: symbol.kind,
+                    depth: usize::from(symbol.container_name.is_some()),
+                    line: symbol.location.range.start.line,
+                    character: symbol.location.range.start.character,
+                });
+            }
+            out.sort_by_key(|symbol| (symbol.line, symbol.character));
+        }
+        None => {}
+    }
+    out
+}
+
+/// Short icon for a symbol kind, in the style of the completion popup
+fn symbol_kind_icon(kind: SymbolKind) -> &'static str {
+    match kind {
+        SymbolKind::FUNCTION | SymbolKind::METHOD | SymbolKind::CONSTRUCTOR => "λ",
+        SymbolKind::STRUCT | SymbolKind::CLASS | SymbolKind::INTERFACE => "S",
+        SymbolKind::ENUM => "E",
+        SymbolKind::ENUM_MEMBER => "e",
+        SymbolKind::VARIABLE | SymbolKind::FIELD | SymbolKind::PROPERTY => "v",
+        SymbolKind::CONSTANT => "c",
+        SymbolKind::MODULE | SymbolKind::NAMESPACE | SymbolKind::PACKAGE => "m",
+        SymbolKind::TYPE_PARAMETER => "T",
+        _ => "•",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lsp_types::{Position, Range};
+
+    #[allow(deprecated)]
+    fn symbol(
+        name: &str,
+        kind: SymbolKind,
+        line: u32,
+        children: Option<Vec<DocumentSymbol>>,
+    ) -> DocumentSymbol {
+        let range = Range::new(Position::new(line, 4), Position::new(line, 10));
+        DocumentSymbol {
+            name: name.to_string(),
+            detail: None,
+            kind,
+            tags: None,
+            deprecated: None,
+            range,
+            selection_range: range,
+            children,
+        }
+    }
+
+    #[test]
+    fn test_flatten_nested_symbols() {
+        let response = DocumentSymbolResponse::Nested(vec![
+            symbol(
+                "Point",
+                SymbolKind::STRUCT,
+                0,
+                Some(vec![
+                    symbol("x", SymbolKind::FIELD, 1, None),
+                    symbol("y", SymbolKind::FIELD, 2, None),
+                ]),
+            ),
+            symbol("main", SymbolKind::FUNCTION, 5, None),
+        ]);
+
+        let symbols = flatten_document_symbols(Some(response));
+        let listed: Vec<(&str, usize, u32)> = symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.depth, s.line))
+            .collect();
+        assert_eq!(
+            listed,
+            vec![("Point", 0, 0), ("x", 1, 1), ("y", 1, 2), ("main", 0, 5)]
+        );
+        assert_eq!(symbols[3].character, 4);
+        assert!(flatten_document_symbols(None).is_empty());
+    }
+
+    #[test]
+    fn test_filter_symbols() {
+        let symbols = flatten_document_symbols(Some(DocumentSymbolResponse::Nested(vec![
+            symbol("parse_header", SymbolKind::FUNCTION, 0, None),
+            symbol("Parser", SymbolKind::STRUCT, 3, None),
+            symbol("render", SymbolKind::FUNCTION, 9, None),
+        ])));
+
+        assert_eq!(Editor::filter_symbols(&symbols, ""), vec![0, 1, 2]);
+        let matches = Editor::filter_symbols(&symbols, "parse");
+        assert_eq!(matches.len(), 2);
+        assert!(!matches.contains(&2));
+    }
+}
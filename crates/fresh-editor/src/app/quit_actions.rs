@@ -0,0 +1,243 @@
+//! Quitting with unsaved work.
+//!
+//! Quitting while buffers have unsaved changes opens one dialog that lists
+//! them, along with terminals whose process is still running. Everything can
+//! be saved or discarded at once, or reviewed: each item is then shown in the
+//! editor and confirmed on its own, and cancelling at any point keeps the
+//! editor open.
+
+use rust_i18n::t;
+
+use super::{BufferId, Editor};
+use crate::input::commands::Suggestion;
+use crate::view::prompt::PromptType;
+
+impl Editor {
+    /// Whether any buffer has changes that would be lost by quitting
+    pub(super) fn has_unsaved_buffers(&self) -> bool {
+        self.buffers.keys().any(|&id| self.is_unsaved_buffer(id))
+    }
+
+    /// Whether the quit dialog, or the review it started, is on screen
+    pub fn is_quit_dialog_open(&self) -> bool {
+        self.prompt.as_ref().is_some_and(|prompt| {
+            matches!(
+                prompt.prompt_type,
+                PromptType::ConfirmQuitWithModified | PromptType::QuitReview { .. }
+            )
+        })
+    }
+
+    fn is_unsaved_buffer(&self, id: BufferId) -> bool {
+        !self.terminal_buffers.contains_key(&id) && self.needs_save_prompt(id)
+    }
+
+    fn is_running_terminal(&self, id: BufferId) -> bool {
+        self.terminal_buffers
+            .get(&id)
+            .and_then(|&terminal_id| self.terminal_manager.get(terminal_id))
+            .is_some_and(|handle| handle.is_alive())
+    }
+
+    /// Buffers with unsaved changes, then terminals with a running process
+    fn quit_items(&self) -> Vec<BufferId> {
+        let mut ids: Vec<BufferId> = self.buffers.keys().copied().collect();
+        ids.sort_by_key(|id| id.0);
+        let (mut items, terminals): (Vec<BufferId>, Vec<BufferId>) = ids
+            .into_iter()
+            .filter(|&id| self.is_unsaved_buffer(id) || self.is_running_terminal(id))
+            .partition(|&id| self.is_unsaved_buffer(id));
+        items.extend(terminals);
+        items
+    }
+
+    fn quit_item_name(&self, id: BufferId) -> String {
+        self.buffer_metadata
+            .get(&id)
+            .map(|metadata| metadata.display_name.clone())
+            .unwrap_or_else(|| format!("Buffer {}", id.0))
+    }
+
+    /// Open the dialog listing everything quitting would lose
+    pub(super) fn start_quit_dialog(&mut self) {
+        self.quit_review = None;
+        let items = self.quit_items();
+        let suggestions: Vec<Suggestion> = items
+            .iter()
+            .map(|&id| Suggestion {
+                text: self.quit_item_name(id),
+                description: Some(if self.is_unsaved_buffer(id) {
+                    t!("quit.unsaved_changes").to_string()
+                } else {
+                    t!("quit.running_process").to_string()
+                }),
+                value: None,
+                disabled: true,
+                keybinding: None,
+                source: None,
+            })
+            .collect();
+
+        let save_key = t!("prompt.key.save").to_string();
+        let discard_key = t!("prompt.key.discard").to_string();
+        let review_key = t!("prompt.key.review").to_string();
+        let cancel_key = t!("prompt.key.cancel").to_string();
+        let msg = if items.len() == 1 {
+            t!(
+                "prompt.quit_unsaved_one",
+                save_key = save_key,
+                discard_key = discard_key,
+                review_key = review_key,
+                cancel_key = cancel_key
+            )
+            .to_string()
+        } else {
+            t!(
+                "prompt.quit_unsaved_many",
+                count = items.len(),
+                save_key = save_key,
+                discard_key = discard_key,
+                review_key = review_key,
+                cancel_key = cancel_key
+            )
+            .to_string()
+        };
+
+        self.start_prompt_with_suggestions(msg, PromptType::ConfirmQuitWithModified, suggestions);
+        // The list is for reading only; the answer is typed
+        if let Some(prompt) = &mut self.prompt {
+            prompt.selected_suggestion = None;
+        }
+    }
+
+    /// Handle the answer to the quit dialog
+    pub(super) fn handle_confirm_quit(&mut self, input: &str) {
+        let input_lower = input.trim().to_lowercase();
+        let save_key = t!("prompt.key.save").to_string().to_lowercase();
+        let discard_key = t!("prompt.key.discard").to_string().to_lowercase();
+        let review_key = t!("prompt.key.review").to_string().to_lowercase();
+
+        let first_char = input_lower.chars().next();
+        if first_char == save_key.chars().next() {
+            self.save_all_and_quit();
+        } else if first_char == discard_key.chars().next() {
+            self.should_quit = true;
+        } else if first_char == review_key.chars().next() {
+            self.quit_review = Some(self.quit_items());
+            self.review_next_quit_item();
+        } else {
+            self.set_status_message(t!("quit.cancelled").to_string());
+        }
+    }
+
+    /// Save every buffer that can be saved as is, then review the rest
+    fn save_all_and_quit(&mut self) {
+        let original = self.active_buffer();
+        let mut needs_review = Vec::new();
+        for id in self.quit_items() {
+            if !self.is_unsaved_buffer(id) {
+                continue;
+            }
+            self.set_active_buffer(id);
+            let can_save = self.active_state().buffer.file_path().is_some()
+                && !self.saves_active_file_as()
+                && self.check_save_conflict().is_none();
+            if !can_save {
+                needs_review.push(id);
+                continue;
+            }
+            if let Err(e) = self.save() {
+                self.set_status_message(t!("file.save_failed", error = e.to_string()).to_string());
+                return;
+            }
+            if self.prompt.is_some() {
+                // Saving needs confirmation (sudo); stay open and let it be answered
+                return;
+            }
+        }
+        self.set_active_buffer(original);
+
+        self.quit_review = Some(needs_review);
+        self.review_next_quit_item();
+    }
+
+    /// Show the next item under review and ask about it, or quit when none
+    /// are left
+    pub(super) fn review_next_quit_item(&mut self) {
+        let Some(items) = &mut self.quit_review else {
+            return;
+        };
+        if items.is_empty() {
+            self.quit_review = None;
+            self.should_quit = true;
+            return;
+        }
+        let id = items.remove(0);
+        if !self.is_unsaved_buffer(id) && !self.is_running_terminal(id) {
+            // Dealt with since the list was made
+            self.review_next_quit_item();
+            return;
+        }
+
+        self.switch_buffer(id);
+        let name = self.quit_item_name(id);
+        let discard_key = t!("prompt.key.discard").to_string();
+        let cancel_key = t!("prompt.key.cancel").to_string();
+        let msg = if self.is_unsaved_buffer(id) {
+            t!(
+                "prompt.buffer_modified",
+                name = name,
+                save_key = t!("prompt.key.save").to_string(),
+                discard_key = discard_key,
+                cancel_key = cancel_key
+            )
+            .to_string()
+        } else {
+            t!(
+                "prompt.quit_running_process",
+                name = name,
+                discard_key = discard_key,
+                cancel_key = cancel_key
+            )
+            .to_string()
+        };
+        self.start_prompt(msg, PromptType::QuitReview { buffer_id: id });
+    }
+
+    /// Handle the answer about one reviewed item
+    pub(super) fn handle_quit_review(&mut self, input: &str, buffer_id: BufferId) {
+        let input_lower = input.trim().to_lowercase();
+        let save_key = t!("prompt.key.save").to_string().to_lowercase();
+        let discard_key = t!("prompt.key.discard").to_string().to_lowercase();
+
+        let first_char = input_lower.chars().next();
+        if first_char == save_key.chars().next() && self.is_unsaved_buffer(buffer_id) {
+            self.switch_buffer(buffer_id);
+            if self.active_state().buffer.file_path().is_none() {
+                // The review carries on once the buffer has a name
+                self.start_prompt_with_initial_text(
+                    t!("file.save_as_prompt").to_string(),
+                    PromptType::SaveFileAs,
+                    String::new(),
+                );
+                self.init_file_open_state();
+                return;
+            }
+            if let Err(e) = self.save() {
+                self.quit_review = None;
+                self.set_status_message(t!("file.save_failed", error = e.to_string()).to_string());
+                return;
+            }
+            if self.prompt.is_some() {
+                self.quit_review = None;
+                return;
+            }
+            self.review_next_quit_item();
+        } else if first_char == discard_key.chars().next() {
+            self.review_next_quit_item();
+        } else {
+            self.quit_review = None;
+            self.set_status_message(t!("quit.cancelled").to_string());
+        }
+    }
+}
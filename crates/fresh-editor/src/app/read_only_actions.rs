@@ -0,0 +1,131 @@
+//! Read-only file buffers for the Editor.
+//!
+//! This module contains the read-only flag of file-backed buffers:
+//! - Opening files without write permission, or matching
+//!   `editor.readonly_globs`, read-only
+//! - Toggling the flag on the active buffer
+//!
+//! A read-only buffer blocks edits through `editing_disabled`; navigation,
+//! search, folding and copy keep working. Virtual buffers and binary files
+//! manage their own flag and can't be toggled. Saving a file locked by a glob
+//! or the toggle opens Save As, while a file without write permission saves
+//! normally, which offers to use sudo.
+
+use std::path::Path;
+
+use rust_i18n::t;
+
+use crate::model::event::BufferId;
+use crate::primitives::glob_match::{filename_glob_matches, is_path_pattern, path_glob_matches};
+
+use super::Editor;
+
+impl Editor {
+    /// Whether a file should be opened read-only
+    pub(super) fn opens_read_only(&self, path: &Path) -> bool {
+        if !self.filesystem.is_writable(path) {
+            return true;
+        }
+        path.strip_prefix(&self.working_dir).is_ok_and(|relative| {
+            readonly_glob_matches(&self.config.editor.readonly_globs, relative)
+        })
+    }
+
+    /// Mark a buffer read-only (or editable again)
+    pub(super) fn set_buffer_read_only(&mut self, buffer_id: BufferId, read_only: bool) {
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.editing_disabled = read_only;
+        }
+        if let Some(metadata) = self.buffer_metadata.get_mut(&buffer_id) {
+            metadata.read_only = read_only;
+        }
+    }
+
+    /// Toggle the read-only flag of the active buffer
+    ///
+    /// Making a file without write permission editable warns that saving
+    /// it will need sudo.
+    pub(super) fn toggle_read_only(&mut self) {
+        let buffer_id = self.active_buffer();
+        let Some(metadata) = self.buffer_metadata.get(&buffer_id) else {
+            return;
+        };
+        if metadata.is_virtual() || metadata.binary {
+            self.set_status_message(t!("buffer.read_only_fixed").to_string());
+            return;
+        }
+
+        if !metadata.read_only {
+            self.set_buffer_read_only(buffer_id, true);
+            self.set_status_message(t!("buffer.read_only_on").to_string());
+            return;
+        }
+
+        let writable = self.is_file_writable(buffer_id);
+        self.set_buffer_read_only(buffer_id, false);
+        if writable {
+            self.set_status_message(t!("buffer.read_only_off").to_string());
+        } else {
+            self.set_status_message(t!("buffer.read_only_off_no_permission").to_string());
+        }
+    }
+
+    /// Whether the active buffer is a read-only file (not a virtual buffer)
+    pub(super) fn is_active_file_read_only(&self) -> bool {
+        self.buffer_metadata
+            .get(&self.active_buffer())
+            .is_some_and(|metadata| metadata.read_only && !metadata.is_virtual())
+    }
+
+    /// Whether saving the active buffer should open Save As: it's a file
+    /// locked by `readonly_globs` or the toggle, not one that is read-only
+    /// for lack of write permission.
+    pub(super) fn saves_active_file_as(&self) -> bool {
+        self.is_active_file_read_only() && self.is_file_writable(self.active_buffer())
+    }
+
+    /// Whether the buffer's file can be written, as far as permissions go
+    fn is_file_writable(&self, buffer_id: BufferId) -> bool {
+        self.buffer_metadata
+            .get(&buffer_id)
+            .and_then(|metadata| metadata.file_path())
+            .filter(|path| !path.as_os_str().is_empty())
+            .is_none_or(|path| self.filesystem.is_writable(path))
+    }
+}
+
+/// Whether a path relative to the working directory matches one of the globs
+///
+/// Patterns with a "/" match the whole relative path, others the file name.
+fn readonly_glob_matches(globs: &[String], relative: &Path) -> bool {
+    let path = relative.to_string_lossy().replace('\\', "/");
+    let filename = relative
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    globs.iter().any(|glob| {
+        if is_path_pattern(glob) {
+            path_glob_matches(glob, &path)
+        } else {
+            filename_glob_matches(glob, &filename)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readonly_glob_matches() {
+        let globs = vec!["target/**".to_string(), "*.min.js".to_string()];
+        assert!(readonly_glob_matches(
+            &globs,
+            Path::new("target/debug/build/out.rs")
+        ));
+        assert!(readonly_glob_matches(&globs, Path::new("web/app.min.js")));
+        assert!(!readonly_glob_matches(&globs, Path::new("src/target.rs")));
+        assert!(!readonly_glob_matches(&globs, Path::new("web/app.js")));
+        assert!(!readonly_glob_matches(&[], Path::new("target/x")));
+    }
+}
@@ -79,11 +79,23 @@ impl Editor {
                             }
                         }
                     } else {
-                        // Unsaved buffer - create new buffer with recovered content
-                        self.new_buffer();
-                        let state = self.active_state_mut();
-                        state.buffer.insert(0, &text);
-                        state.buffer.set_modified(true);
+                        // Unsaved buffer - refill the scratch buffer the workspace
+                        // restored for it, or create one with the recovered content
+                        let restored = self
+                            .buffer_metadata
+                            .iter()
+                            .find(|(_, meta)| meta.recovery_id.as_deref() == Some(&entry.id))
+                            .map(|(&buffer_id, _)| buffer_id);
+                        let buffer_id = restored.unwrap_or_else(|| self.new_scratch_buffer());
+                        if let Some(meta) = self.buffer_metadata.get_mut(&buffer_id) {
+                            meta.recovery_id = Some(entry.id.clone());
+                        }
+                        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                            let total = state.buffer.total_bytes();
+                            state.buffer.delete(0..total);
+                            state.buffer.insert(0, &text);
+                            state.buffer.set_modified(true);
+                        }
                         recovered_count += 1;
                         tracing::info!("Recovered unsaved buffer");
                     }
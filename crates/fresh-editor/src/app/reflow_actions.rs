@@ -0,0 +1,296 @@
+//! Joining and rewrapping lines.
+//!
+//! - Join Lines joins the lines of each selection into one, or the cursor's
+//!   line and the next when nothing is selected
+//! - Hard Wrap rewraps the paragraphs of each selection, or the paragraph at
+//!   the cursor, at a width asked for in a prompt
+//!
+//! Both know the comment markers of the buffer's language (see
+//! [`crate::primitives::reflow`]), work at every cursor, and are one undo
+//! step.
+
+use std::ops::Range;
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::model::event::{CursorId, Event};
+use crate::primitives::reflow;
+use crate::view::prompt::PromptType;
+
+/// Where `pos` ends up after replacing each range with its text (sorted,
+/// not overlapping). A position inside a replaced range moves to its start.
+fn map_position(pos: usize, edits: &[(Range<usize>, String)]) -> usize {
+    let mut shift: isize = 0;
+    for (range, text) in edits {
+        if pos <= range.start {
+            break;
+        }
+        if pos < range.end {
+            return (range.start as isize + shift) as usize;
+        }
+        shift += text.len() as isize - range.len() as isize;
+    }
+    (pos as isize + shift) as usize
+}
+
+/// Merge spans of lines that overlap, sorting them first
+fn merge_line_spans(mut spans: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    spans.sort();
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (first, last) in spans {
+        match merged.last_mut() {
+            Some(span) if first <= span.1 => span.1 = span.1.max(last),
+            _ => merged.push((first, last)),
+        }
+    }
+    merged
+}
+
+impl Editor {
+    /// Comment markers that start a line in the active buffer: the line
+    /// comment prefix, and `*` for the inside of `/* */` comments
+    fn reflow_markers(&self) -> Vec<String> {
+        let (line, block) = self.comment_syntax();
+        line.map(|prefix| prefix.trim().to_string())
+            .into_iter()
+            .chain(
+                block
+                    .filter(|(open, _)| open.ends_with('*'))
+                    .map(|_| "*".to_string()),
+            )
+            .filter(|marker| !marker.is_empty())
+            .collect()
+    }
+
+    /// Start offset and text (without line ending) of a line
+    fn reflow_line(&self, line: usize) -> Option<(usize, String)> {
+        let buffer = &self.active_state().buffer;
+        let start = buffer.line_start_offset(line)?;
+        let bytes = buffer.get_line(line)?;
+        let text = String::from_utf8_lossy(&bytes);
+        Some((start, text.trim_end_matches(['\n', '\r']).to_string()))
+    }
+
+    /// First and last line of each cursor's selection, and whether it has
+    /// one. A selection ending at the start of a line doesn't take it in.
+    fn cursor_line_spans(&self) -> Vec<(usize, usize, bool)> {
+        let buffer = &self.active_state().buffer;
+        self.active_cursors()
+            .iter()
+            .map(|(_, cursor)| match cursor.selection_range() {
+                Some(range) => {
+                    let first = buffer.get_line_number(range.start);
+                    let mut last = buffer.get_line_number(range.end);
+                    if last > first && buffer.line_start_offset(last) == Some(range.end) {
+                        last -= 1;
+                    }
+                    (first, last, true)
+                }
+                None => {
+                    let line = buffer.get_line_number(cursor.position);
+                    (line, line, false)
+                }
+            })
+            .collect()
+    }
+
+    /// Replace ranges of the buffer as one undo step, keeping every cursor
+    /// and selection on the same text
+    fn apply_reflow_edits(&mut self, edits: Vec<(Range<usize>, String)>, description: &str) {
+        let mut events = Vec::new();
+        for (range, text) in &edits {
+            if !range.is_empty() {
+                events.push(Event::Delete {
+                    range: range.clone(),
+                    deleted_text: self
+                        .active_state_mut()
+                        .get_text_range(range.start, range.end),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+            if !text.is_empty() {
+                events.push(Event::Insert {
+                    position: range.start,
+                    text: text.clone(),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+        }
+
+        for (cursor_id, cursor) in self.active_cursors().iter() {
+            events.push(Event::MoveCursor {
+                cursor_id,
+                old_position: cursor.position,
+                new_position: map_position(cursor.position, &edits),
+                old_anchor: cursor.anchor,
+                new_anchor: cursor.anchor.map(|anchor| map_position(anchor, &edits)),
+                old_sticky_column: cursor.sticky_column,
+                new_sticky_column: 0,
+            });
+        }
+
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description.to_string()) {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+    }
+
+    /// Join the lines of every cursor's selection, or its line and the next
+    pub(super) fn join_lines(&mut self) {
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
+        }
+        let markers = self.reflow_markers();
+        let markers: Vec<&str> = markers.iter().map(String::as_str).collect();
+        let spans = self
+            .cursor_line_spans()
+            .into_iter()
+            .map(|(first, last, _)| (first, last.max(first + 1)))
+            .collect();
+
+        let mut edits = Vec::new();
+        let mut joined = 0;
+        for (first, last) in merge_line_spans(spans) {
+            let lines: Vec<(usize, String)> = (first..=last)
+                .map_while(|line| self.reflow_line(line))
+                .collect();
+            let (Some((start, first_text)), Some((last_start, last_text))) =
+                (lines.first(), lines.last())
+            else {
+                continue;
+            };
+            if lines.len() < 2 {
+                continue;
+            }
+            let texts: Vec<&str> = lines.iter().map(|(_, text)| text.as_str()).collect();
+            // The joined line starts with the first line as it was, so only
+            // what follows it changes
+            let kept = first_text.trim_end().len();
+            let text = reflow::join_lines(&texts, &markers)[kept..].to_string();
+            edits.push((start + kept..last_start + last_text.len(), text));
+            joined += lines.len();
+        }
+
+        if edits.is_empty() {
+            return;
+        }
+        self.apply_reflow_edits(edits, "Join lines");
+        self.set_status_message(t!("reflow.joined", count = joined).to_string());
+    }
+
+    /// Ask for the width to rewrap at, starting from `max_line_length`
+    pub(super) fn start_hard_wrap_prompt(&mut self) {
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
+        }
+        self.start_prompt_with_initial_text(
+            t!("reflow.width_prompt").to_string(),
+            PromptType::HardWrap,
+            self.config.editor.max_line_length.to_string(),
+        );
+    }
+
+    /// Rewrap at the width typed in the Hard Wrap prompt
+    pub(super) fn handle_hard_wrap_width(&mut self, input: &str) {
+        match input.trim().parse::<usize>() {
+            Ok(width) if width > 0 => self.hard_wrap(width),
+            _ => {
+                self.set_status_message(t!("reflow.invalid_width", input = input).to_string());
+            }
+        }
+    }
+
+    /// Rewrap the paragraphs of every cursor's selection, or the paragraph
+    /// at the cursor, so no line is wider than `width`
+    pub(super) fn hard_wrap(&mut self, width: usize) {
+        let markers = self.reflow_markers();
+        let markers: Vec<&str> = markers.iter().map(String::as_str).collect();
+        let tab_size = self.active_state().buffer_settings.tab_size;
+        let line_ending = self.active_state().buffer.line_ending().as_str();
+
+        // A line is in the paragraph of its neighbor when neither is blank
+        // and both have the same comment marker
+        let paragraph_key = |text: &str| {
+            let (_, marker, rest) = reflow::split_prefix(text, &markers);
+            (!rest.trim().is_empty()).then_some(marker)
+        };
+        let spans = self
+            .cursor_line_spans()
+            .into_iter()
+            .filter_map(|(first, last, selected)| {
+                if selected {
+                    return Some((first, last));
+                }
+                let key = paragraph_key(&self.reflow_line(first)?.1)?;
+                let same = |line: usize| {
+                    self.reflow_line(line)
+                        .is_some_and(|(_, text)| paragraph_key(&text) == Some(key))
+                };
+                let first = (0..first)
+                    .rev()
+                    .take_while(|&line| same(line))
+                    .last()
+                    .unwrap_or(first);
+                let last = (last + 1..)
+                    .take_while(|&line| same(line))
+                    .last()
+                    .unwrap_or(last);
+                Some((first, last))
+            })
+            .collect();
+
+        let mut edits = Vec::new();
+        for (first, last) in merge_line_spans(spans) {
+            let lines: Vec<(usize, String)> = (first..=last)
+                .map_while(|line| self.reflow_line(line))
+                .collect();
+            let (Some((start, _)), Some((last_start, last_text))) = (lines.first(), lines.last())
+            else {
+                continue;
+            };
+            let texts: Vec<&str> = lines.iter().map(|(_, text)| text.as_str()).collect();
+            let wrapped = reflow::hard_wrap(&texts, width, tab_size, &markers);
+            if wrapped == texts {
+                continue;
+            }
+            edits.push((
+                *start..last_start + last_text.len(),
+                wrapped.join(line_ending),
+            ));
+        }
+
+        if edits.is_empty() {
+            self.set_status_message(t!("reflow.nothing_to_wrap", width = width).to_string());
+            return;
+        }
+        self.apply_reflow_edits(edits, "Hard wrap");
+        self.set_status_message(t!("reflow.wrapped", width = width).to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_position() {
+        let edits = vec![(4..10, "ab".to_string()), (20..22, String::new())];
+        assert_eq!(map_position(2, &edits), 2);
+        // The start of a replaced range stays, inside it moves to the start
+        assert_eq!(map_position(4, &edits), 4);
+        assert_eq!(map_position(7, &edits), 4);
+        assert_eq!(map_position(10, &edits), 6);
+        assert_eq!(map_position(21, &edits), 16);
+        assert_eq!(map_position(30, &edits), 24);
+    }
+
+    #[test]
+    fn test_merge_line_spans() {
+        assert_eq!(
+            merge_line_spans(vec![(5, 6), (0, 1), (1, 2), (8, 8)]),
+            vec![(0, 2), (5, 6), (8, 8)]
+        );
+    }
+}
@@ -1,27 +1,59 @@
 /// Pure, buffer-agnostic helpers for regex find-and-replace.
 
 /// Build a [`regex::bytes::Regex`] from user-supplied search settings.
-/// Returns `None` when `use_regex` is false.
+///
+/// Returns `Ok(None)` for a plain, case-sensitive search, which is matched
+/// directly against the piece tree. A plain search that is case-insensitive or
+/// whole-word is escaped and compiled so it honours those options too.
 pub fn build_regex(
     search: &str,
     use_regex: bool,
     whole_word: bool,
     case_sensitive: bool,
-) -> Option<regex::bytes::Regex> {
-    if !use_regex {
-        return None;
+) -> Result<Option<regex::bytes::Regex>, regex::Error> {
+    if !use_regex && case_sensitive && !whole_word {
+        return Ok(None);
     }
 
+    let pattern = if use_regex {
+        search.to_string()
+    } else {
+        regex::escape(search)
+    };
     let pattern = if whole_word {
-        format!(r"\b{}\b", search)
+        format!(r"\b{}\b", pattern)
     } else {
-        search.to_string()
+        pattern
     };
 
     regex::bytes::RegexBuilder::new(&pattern)
         .case_insensitive(!case_sensitive)
         .build()
-        .ok()
+        .map(Some)
+}
+
+/// One-line description of a regex compile error, suitable for the status bar.
+///
+/// The regex crate renders syntax errors over several lines (the pattern, a
+/// caret, then `error: ...`); only the final message is kept.
+pub fn regex_error_message(error: &regex::Error) -> String {
+    let text = error.to_string();
+    text.lines()
+        .rev()
+        .find_map(|line| line.trim().strip_prefix("error: "))
+        .unwrap_or(text.trim())
+        .to_string()
+}
+
+/// How the replacement text is turned into the text that replaces a match.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReplaceOptions {
+    /// Expand `$1`/`${name}` references and `\u`, `\l`, `\U`, `\L`, `\E`
+    /// case modifiers (regex mode).
+    pub expand_captures: bool,
+    /// Adapt the replacement to the case of the matched text, so replacing
+    /// "item" with "entry" turns "Item" into "Entry" and "ITEM" into "ENTRY".
+    pub preserve_case: bool,
 }
 
 /// Normalize `$N` capture references to `${N}` so the regex crate doesn't
@@ -61,6 +93,119 @@ fn normalize_replacement(template: &str) -> String {
     out
 }
 
+/// A case conversion requested by a `\u`, `\l`, `\U`, `\L` or `\E` escape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaseModifier {
+    /// `\u`: uppercase the next character
+    UpperNext,
+    /// `\l`: lowercase the next character
+    LowerNext,
+    /// `\U`: uppercase until `\E`
+    Upper,
+    /// `\L`: lowercase until `\E`
+    Lower,
+    /// `\E`: end a `\U` or `\L` span
+    End,
+}
+
+/// Split a replacement template into literal/capture segments separated by
+/// case modifiers. Returns `None` when the template has no modifiers.
+fn split_case_modifiers(template: &str) -> Option<Vec<(Option<CaseModifier>, String)>> {
+    let mut segments = vec![(None, String::new())];
+    let mut found = false;
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        let modifier = match (c, chars.peek()) {
+            ('\\', Some('u')) => Some(CaseModifier::UpperNext),
+            ('\\', Some('l')) => Some(CaseModifier::LowerNext),
+            ('\\', Some('U')) => Some(CaseModifier::Upper),
+            ('\\', Some('L')) => Some(CaseModifier::Lower),
+            ('\\', Some('E')) => Some(CaseModifier::End),
+            _ => None,
+        };
+        if let Some(modifier) = modifier {
+            chars.next();
+            found = true;
+            segments.push((Some(modifier), String::new()));
+        } else if let Some((_, text)) = segments.last_mut() {
+            text.push(c);
+        }
+    }
+    found.then_some(segments)
+}
+
+/// Expand `template` against one set of captures, applying case modifiers.
+fn expand_captures(caps: &regex::bytes::Captures<'_>, template: &str) -> String {
+    let expand = |text: &str| {
+        let mut dst = Vec::new();
+        caps.expand(normalize_replacement(text).as_bytes(), &mut dst);
+        String::from_utf8_lossy(&dst).into_owned()
+    };
+
+    let Some(segments) = split_case_modifiers(template) else {
+        return expand(template);
+    };
+
+    let mut out = String::new();
+    let mut span: Option<CaseModifier> = None;
+    let mut next: Option<CaseModifier> = None;
+    for (modifier, text) in segments {
+        match modifier {
+            Some(CaseModifier::UpperNext | CaseModifier::LowerNext) => next = modifier,
+            Some(CaseModifier::Upper | CaseModifier::Lower) => span = modifier,
+            Some(CaseModifier::End) => span = None,
+            None => {}
+        }
+
+        let expanded = expand(&text);
+        let expanded = match span {
+            Some(CaseModifier::Upper) => expanded.to_uppercase(),
+            Some(CaseModifier::Lower) => expanded.to_lowercase(),
+            _ => expanded,
+        };
+        let mut rest = expanded.chars();
+        if let Some(first) = rest.clone().next() {
+            match next.take() {
+                Some(CaseModifier::UpperNext) => {
+                    rest.next();
+                    out.extend(first.to_uppercase());
+                }
+                Some(CaseModifier::LowerNext) => {
+                    rest.next();
+                    out.extend(first.to_lowercase());
+                }
+                _ => {}
+            }
+        }
+        out.push_str(rest.as_str());
+    }
+    out
+}
+
+/// Adapt `replacement` to the case pattern of `matched`: all-uppercase,
+/// all-lowercase and capitalized matches are mirrored, anything else (mixed
+/// case, no letters) leaves the replacement as typed.
+pub fn preserve_case(matched: &str, replacement: &str) -> String {
+    let letters: Vec<char> = matched.chars().filter(|c| c.is_alphabetic()).collect();
+    let Some(&first) = letters.first() else {
+        return replacement.to_string();
+    };
+
+    if letters.len() > 1 && letters.iter().all(|c| c.is_uppercase()) {
+        replacement.to_uppercase()
+    } else if letters.iter().all(|c| c.is_lowercase()) {
+        replacement.to_lowercase()
+    } else if first.is_uppercase() && letters[1..].iter().all(|c| c.is_lowercase()) {
+        let mut chars = replacement.chars();
+        match chars.next() {
+            Some(c) => c.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    } else {
+        replacement.to_string()
+    }
+}
+
 /// A single match found in a buffer, together with its expanded replacement.
 #[derive(Debug, Clone)]
 pub struct ReplaceMatch {
@@ -72,43 +217,56 @@ pub struct ReplaceMatch {
     pub replacement: String,
 }
 
-/// Find every match of `regex` in `haystack` and expand `replacement_template`
-/// for each one (honouring `$1`, `${name}`, etc.).
+/// Find every match of `regex` in `haystack` and compute its replacement
+/// from `replacement_template` according to `options`.
 pub fn collect_regex_matches(
     regex: &regex::bytes::Regex,
     haystack: &[u8],
     replacement_template: &str,
+    options: ReplaceOptions,
 ) -> Vec<ReplaceMatch> {
-    let normalized = normalize_replacement(replacement_template);
     regex
         .captures_iter(haystack)
         .map(|caps| {
             let m = caps.get(0).unwrap();
-            let mut expanded = Vec::new();
-            caps.expand(normalized.as_bytes(), &mut expanded);
+            let expanded = if options.expand_captures {
+                expand_captures(&caps, replacement_template)
+            } else {
+                replacement_template.to_string()
+            };
+            let replacement = if options.preserve_case {
+                preserve_case(&String::from_utf8_lossy(m.as_bytes()), &expanded)
+            } else {
+                expanded
+            };
             ReplaceMatch {
                 offset: m.start(),
                 len: m.len(),
-                replacement: String::from_utf8_lossy(&expanded).into_owned(),
+                replacement,
             }
         })
         .collect()
 }
 
-/// Expand capture-group references in `replacement_template` against a single
-/// match. Returns the template unchanged when no captures are found.
+/// Compute the replacement for a single match according to `options`.
+/// Capture references are only expanded when a regex is given and matches.
 pub fn expand_replacement(
-    regex: &regex::bytes::Regex,
+    regex: Option<&regex::bytes::Regex>,
     matched_bytes: &[u8],
     replacement_template: &str,
+    options: ReplaceOptions,
 ) -> String {
-    if let Some(caps) = regex.captures(matched_bytes) {
-        let normalized = normalize_replacement(replacement_template);
-        let mut dst = Vec::new();
-        caps.expand(normalized.as_bytes(), &mut dst);
-        String::from_utf8_lossy(&dst).into_owned()
+    let expanded = match regex.filter(|_| options.expand_captures) {
+        Some(regex) => match regex.captures(matched_bytes) {
+            Some(caps) => expand_captures(&caps, replacement_template),
+            None => replacement_template.to_string(),
+        },
+        None => replacement_template.to_string(),
+    };
+    if options.preserve_case {
+        preserve_case(&String::from_utf8_lossy(matched_bytes), &expanded)
     } else {
-        replacement_template.to_string()
+        expanded
     }
 }
 
@@ -116,37 +274,42 @@ pub fn expand_replacement(
 mod tests {
     use super::*;
 
+    const REGEX: ReplaceOptions = ReplaceOptions {
+        expand_captures: true,
+        preserve_case: false,
+    };
+
     #[test]
     fn build_regex_returns_none_when_disabled() {
-        assert!(build_regex("foo", false, false, true).is_none());
+        assert!(build_regex("foo", false, false, true).unwrap().is_none());
     }
 
     #[test]
     fn build_regex_basic_pattern() {
-        let re = build_regex("foo.*bar", true, false, true).unwrap();
+        let re = build_regex("foo.*bar", true, false, true).unwrap().unwrap();
         assert!(re.is_match(b"foo123bar"));
         assert!(!re.is_match(b"baz"));
     }
 
     #[test]
     fn build_regex_case_insensitive() {
-        let re = build_regex("hello", true, false, false).unwrap();
+        let re = build_regex("hello", true, false, false).unwrap().unwrap();
         assert!(re.is_match(b"HELLO"));
         assert!(re.is_match(b"hello"));
     }
 
     #[test]
     fn build_regex_whole_word() {
-        let re = build_regex("foo", true, true, true).unwrap();
+        let re = build_regex("foo", true, true, true).unwrap().unwrap();
         assert!(re.is_match(b"foo bar"));
         assert!(!re.is_match(b"foobar"));
     }
 
     #[test]
     fn collect_regex_matches_literal_replacement() {
-        let re = build_regex("Sig:.*", true, false, true).unwrap();
+        let re = build_regex("Sig:.*", true, false, true).unwrap().unwrap();
         let input = b"AAAAAA\nSig: hello\nBBBBBB\nSig: world\nCCCCCC";
-        let matches = collect_regex_matches(&re, input, "");
+        let matches = collect_regex_matches(&re, input, "", REGEX);
 
         assert_eq!(matches.len(), 2);
         assert_eq!(matches[0].offset, 7);
@@ -157,9 +320,11 @@ mod tests {
 
     #[test]
     fn collect_regex_matches_with_capture_groups() {
-        let re = build_regex(r"(\w+)@(\w+)", true, false, true).unwrap();
+        let re = build_regex(r"(\w+)@(\w+)", true, false, true)
+            .unwrap()
+            .unwrap();
         let input = b"alice@example bob@test";
-        let matches = collect_regex_matches(&re, input, "$2=$1");
+        let matches = collect_regex_matches(&re, input, "$2=$1", REGEX);
 
         assert_eq!(matches.len(), 2);
         assert_eq!(matches[0].replacement, "example=alice");
@@ -168,17 +333,19 @@ mod tests {
 
     #[test]
     fn expand_replacement_with_groups() {
-        let re = build_regex(r"(\d+)-(\d+)", true, false, true).unwrap();
+        let re = build_regex(r"(\d+)-(\d+)", true, false, true)
+            .unwrap()
+            .unwrap();
         let matched = b"123-456";
-        let result = expand_replacement(&re, matched, "$2/$1");
+        let result = expand_replacement(Some(&re), matched, "$2/$1", REGEX);
         assert_eq!(result, "456/123");
     }
 
     #[test]
     fn expand_replacement_no_groups() {
-        let re = build_regex("hello", true, false, true).unwrap();
+        let re = build_regex("hello", true, false, true).unwrap().unwrap();
         let matched = b"hello";
-        let result = expand_replacement(&re, matched, "world");
+        let result = expand_replacement(Some(&re), matched, "world", REGEX);
         assert_eq!(result, "world");
     }
 
@@ -201,13 +368,76 @@ mod tests {
     /// Matches Python: re.sub(r'bla(bla)', r'oo\1oo', 'blablabla') == 'ooblaoobla'
     #[test]
     fn collect_regex_matches_capture_group_blabla() {
-        let re = build_regex(r"bla(bla)", true, false, true).unwrap();
+        let re = build_regex(r"bla(bla)", true, false, true)
+            .unwrap()
+            .unwrap();
         let input = b"blablabla";
-        let matches = collect_regex_matches(&re, input, "oo$1oo");
+        let matches = collect_regex_matches(&re, input, "oo$1oo", REGEX);
 
         assert_eq!(matches.len(), 1);
         assert_eq!(matches[0].offset, 0);
         assert_eq!(matches[0].len, 6);
         assert_eq!(matches[0].replacement, "ooblaoo");
     }
+
+    #[test]
+    fn build_regex_literal_case_insensitive_is_escaped() {
+        let re = build_regex("a.b", false, false, false).unwrap().unwrap();
+        assert!(re.is_match(b"A.B"));
+        assert!(!re.is_match(b"axb"));
+    }
+
+    #[test]
+    fn build_regex_reports_invalid_pattern() {
+        let err = build_regex("foo(", true, false, true).unwrap_err();
+        assert_eq!(regex_error_message(&err), "unclosed group");
+    }
+
+    #[test]
+    fn case_modifiers_in_replacement() {
+        let re = build_regex(r"(\w+)_(\w+)", true, false, true)
+            .unwrap()
+            .unwrap();
+        let expand = |template| expand_replacement(Some(&re), b"get_value", template, REGEX);
+        assert_eq!(expand(r"$1\u$2"), "getValue");
+        assert_eq!(expand(r"\U$1\E_$2"), "GET_value");
+        assert_eq!(expand(r"\u\L$1$2"), "Getvalue");
+        assert_eq!(expand(r"\U$1\l_$2"), "GET_VALUE");
+        // Without modifiers the template is expanded as before
+        assert_eq!(expand("$2-$1"), "value-get");
+    }
+
+    #[test]
+    fn literal_replacement_is_not_expanded() {
+        let re = build_regex("x", false, false, false).unwrap().unwrap();
+        let matches = collect_regex_matches(&re, b"x X", r"$1\u", ReplaceOptions::default());
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].replacement, r"$1\u");
+    }
+
+    #[test]
+    fn preserve_case_follows_matched_text() {
+        assert_eq!(preserve_case("item", "entry"), "entry");
+        assert_eq!(preserve_case("Item", "entry"), "Entry");
+        assert_eq!(preserve_case("ITEM", "entry"), "ENTRY");
+        // Mixed case and letterless matches keep the replacement as typed
+        assert_eq!(preserve_case("iTem", "entry"), "entry");
+        assert_eq!(preserve_case("42", "Entry"), "Entry");
+        // A single capital letter reads as capitalized, not all-caps
+        assert_eq!(preserve_case("I", "we"), "We");
+    }
+
+    #[test]
+    fn collect_regex_matches_preserves_case() {
+        let re = build_regex("item", false, false, false).unwrap().unwrap();
+        let options = ReplaceOptions {
+            expand_captures: false,
+            preserve_case: true,
+        };
+        let replaced: Vec<String> = collect_regex_matches(&re, b"item Item ITEM", "entry", options)
+            .into_iter()
+            .map(|m| m.replacement)
+            .collect();
+        assert_eq!(replaced, vec!["entry", "Entry", "ENTRY"]);
+    }
 }
@@ -1,21 +1,40 @@
 use super::*;
+use crate::primitives::indent_pattern::{IndentPatterns, IndentRules};
 use anyhow::Result as AnyhowResult;
 use rust_i18n::t;
+use std::time::{Duration, Instant};
+
+/// Delay before recomputing search matches after an edit in a large buffer
+const SEARCH_REFRESH_DEBOUNCE_MS: u64 = 300;
+
+/// Buffers up to this size recompute their search matches on the next render
+const SEARCH_REFRESH_IMMEDIATE_BYTES: usize = 1024 * 1024;
 
 impl Editor {
     /// Render the editor to the terminal
     pub fn render(&mut self, frame: &mut Frame) {
         let _span = tracing::info_span!("render").entered();
+        let frame_start = crate::services::perf::start();
         let size = frame.area();
 
         // Save frame dimensions for recompute_layout (used by macro replay)
         self.cached_layout.last_frame_width = size.width;
         self.cached_layout.last_frame_height = size.height;
 
+        // Bring search matches up to date with edits made since the last frame
+        self.check_search_refresh_timer();
+        // Likewise markdown previews whose source edits have paused
+        self.check_markdown_preview_timer();
+        // Stop following a log file whose cursor was moved off its end
+        self.check_log_follow();
+
         // For scroll sync groups, we need to update the active split's viewport position BEFORE
         // calling sync_scroll_groups, so that the sync reads the correct position.
         // Otherwise, cursor movements like 'G' (go to end) won't sync properly because
         // viewport.top_byte hasn't been updated yet.
+        // Scroll settings can change with a config reload
+        self.apply_scroll_settings();
+
         let active_split = self.split_manager.active_split();
         {
             let _span = tracing::info_span!("pre_sync_ensure_visible").entered();
@@ -405,6 +424,22 @@ impl Editor {
 
         let is_maximized = self.split_manager.is_maximized();
 
+        // Large files without a line index show byte offsets in the gutter, which
+        // have no relative form; say so once instead of silently ignoring the mode
+        let active_buffer = self.active_buffer();
+        let byte_offset_gutter = self.config.editor.line_number_mode
+            != crate::config::LineNumberMode::Absolute
+            && self
+                .buffers
+                .get(&active_buffer)
+                .is_some_and(|state| state.buffer.line_count().is_none());
+        if !byte_offset_gutter {
+            self.line_number_mode_noted = None;
+        } else if self.line_number_mode_noted != Some(active_buffer) {
+            self.line_number_mode_noted = Some(active_buffer);
+            self.set_status_message(t!("view.line_number_mode_ignored").to_string());
+        }
+
         let _content_span = tracing::trace_span!("render_content").entered();
         let (
             split_areas,
@@ -413,6 +448,7 @@ impl Editor {
             maximize_split_areas,
             view_line_mappings,
             horizontal_scrollbar_areas,
+            minimap_areas,
         ) = SplitRenderer::render_content(
             frame,
             editor_content_area,
@@ -436,7 +472,14 @@ impl Editor {
             hovered_close_split,
             hovered_maximize_split,
             is_maximized,
-            self.config.editor.relative_line_numbers,
+            self.config.editor.line_number_mode,
+            self.config.editor.indent_guides,
+            self.config.editor.highlight_current_line,
+            self.config.editor.bracket_pair_colorization,
+            self.config.editor.color_swatches,
+            self.config.editor.syntax_highlight_max_column,
+            self.config.editor.minimap,
+            self.config.editor.minimap_min_width,
             self.tab_bar_visible,
             self.config.editor.use_terminal_bg,
             self.session_mode,
@@ -523,6 +566,7 @@ impl Editor {
 
         self.cached_layout.split_areas = split_areas;
         self.cached_layout.horizontal_scrollbar_areas = horizontal_scrollbar_areas;
+        self.cached_layout.minimap_areas = minimap_areas;
         self.cached_layout.tab_layouts = tab_layouts;
         self.cached_layout.close_split_areas = close_split_areas;
         self.cached_layout.maximize_split_areas = maximize_split_areas;
@@ -532,6 +576,9 @@ impl Editor {
             .get_separators_with_ids(editor_content_area);
         self.cached_layout.editor_content_area = Some(editor_content_area);
 
+        // Other clients' cursors over the buffers this view shares with them
+        self.render_remote_cursors(frame);
+
         // Render hover highlights for separators and scrollbars
         self.render_hover_highlights(frame);
 
@@ -607,11 +654,19 @@ impl Editor {
         }
 
         // Clone all immutable values before the mutable borrow
-        let display_name = self
+        let mut display_name = self
             .buffer_metadata
             .get(&self.active_buffer())
             .map(|m| m.display_name.clone())
             .unwrap_or_else(|| "[No Name]".to_string());
+        if self
+            .active_state()
+            .buffer
+            .file_path()
+            .is_some_and(|path| self.is_deleted_on_disk(path))
+        {
+            display_name = format!("{} {}", display_name, t!("status.deleted_on_disk"));
+        }
         let status_message = self.status_message.clone();
         let plugin_status_message = self.plugin_status_message.clone();
         let prompt = self.prompt.clone();
@@ -637,7 +692,7 @@ impl Editor {
                 };
 
             // Compute status bar hover state for styling
-            use crate::view::ui::status_bar::StatusBarHover;
+            use crate::view::ui::status_bar::{StatusBarHover, StatusBarSegments};
             let status_bar_hover = match &self.mouse_state.hover_target {
                 Some(HoverTarget::StatusBarLspIndicator) => StatusBarHover::LspIndicator,
                 Some(HoverTarget::StatusBarWarningBadge) => StatusBarHover::WarningBadge,
@@ -655,6 +710,14 @@ impl Editor {
             // Get session name for display (only in session mode)
             let session_name = self.session_name().map(|s| s.to_string());
 
+            let vim_mode = self.vim_mode().map(|mode| mode.label());
+
+            let search_match = self.search_match_indicator();
+
+            let clipboard_internal_only = self.clipboard.internal_fallback_only();
+            let read_only = self.is_active_file_read_only();
+
+            let scroll_linked = !self.scroll_linked_splits().is_empty();
             let active_split = self.split_manager.active_split();
             let active_buf = self.active_buffer();
             let default_cursors = crate::model::cursor::Cursors::new();
@@ -663,6 +726,13 @@ impl Editor {
                 .get(&active_split)
                 .map(|vs| &vs.cursors)
                 .unwrap_or(&default_cursors);
+            let status_bar_segments = StatusBarSegments {
+                config: &self.config.status_bar,
+                plugin_segments: &self.plugin_status_segments,
+                git_branch: self.status_bar_git_branch.as_deref(),
+                clock: self.status_bar_clock.as_deref(),
+                scroll_linked,
+            };
             let status_bar_layout = StatusBarRenderer::render_status_bar(
                 frame,
                 main_chunks[status_bar_idx],
@@ -681,6 +751,11 @@ impl Editor {
                 status_bar_hover,             // Pass hover state for indicator styling
                 remote_connection.as_deref(), // Pass remote connection info
                 session_name.as_deref(),      // Pass session name for status bar display
+                vim_mode,                     // Pass modal editing mode for display
+                search_match,                 // Pass search match indicator
+                clipboard_internal_only,      // Pass clipboard fallback indicator
+                read_only,                    // Pass read-only file indicator
+                &status_bar_segments,         // Pass configured segments
             );
 
             // Store status bar layout for click detection
@@ -694,24 +769,24 @@ impl Editor {
             self.cached_layout.status_bar_encoding_area = status_bar_layout.encoding_indicator;
             self.cached_layout.status_bar_language_area = status_bar_layout.language_indicator;
             self.cached_layout.status_bar_message_area = status_bar_layout.message_area;
+            self.cached_layout.status_bar_cursor_position_area = status_bar_layout.cursor_position;
+            self.cached_layout.status_bar_plugin_areas = status_bar_layout.plugin_segments;
         }
 
         // Render search options bar when in search prompt
         if show_search_options {
-            // Show "Confirm" option only in replace modes
-            let confirm_each = self.prompt.as_ref().and_then(|p| {
-                if matches!(
+            // Show "Confirm" and "Preserve case" options only in replace modes
+            let replace_mode = self.prompt.as_ref().is_some_and(|p| {
+                matches!(
                     p.prompt_type,
                     PromptType::ReplaceSearch
                         | PromptType::Replace { .. }
                         | PromptType::QueryReplaceSearch
                         | PromptType::QueryReplace { .. }
-                ) {
-                    Some(self.search_confirm_each)
-                } else {
-                    None
-                }
+                )
             });
+            let confirm_each = replace_mode.then_some(self.search_confirm_each);
+            let preserve_case = replace_mode.then_some(self.search_preserve_case);
 
             // Determine hover state for search options
             use crate::view::ui::status_bar::SearchOptionsHover;
@@ -720,6 +795,7 @@ impl Editor {
                 Some(HoverTarget::SearchOptionWholeWord) => SearchOptionsHover::WholeWord,
                 Some(HoverTarget::SearchOptionRegex) => SearchOptionsHover::Regex,
                 Some(HoverTarget::SearchOptionConfirmEach) => SearchOptionsHover::ConfirmEach,
+                Some(HoverTarget::SearchOptionPreserveCase) => SearchOptionsHover::PreserveCase,
                 _ => SearchOptionsHover::None,
             };
 
@@ -730,6 +806,7 @@ impl Editor {
                 self.search_whole_word,
                 self.search_use_regex,
                 confirm_each,
+                preserve_case,
                 &theme,
                 &keybindings_cloned,
                 search_options_hover,
@@ -1021,11 +1098,25 @@ impl Editor {
             }
         }
 
+        // Hover tooltip on top of everything, unless a popup (e.g. LSP hover)
+        // has appeared since
+        if let Some(tooltip) = &self.mouse_state.tooltip {
+            if !self.active_state().popups.is_visible() {
+                crate::view::ui::tooltip::render_tooltip(frame, size, tooltip, &self.theme);
+            }
+        }
+
+        if self.perf_hud_visible {
+            self.render_perf_hud(frame, size);
+        }
+
         // Convert all colors for terminal capability (256/16 color fallback)
         crate::view::color_support::convert_buffer_colors(
             frame.buffer_mut(),
             self.color_capability,
         );
+
+        crate::services::perf::end_frame(frame_start);
     }
 
     /// Render the Quick Open hints line showing available mode prefixes
@@ -1211,6 +1302,8 @@ impl Editor {
         // Clear the area first
         frame.render_widget(Clear, area);
 
+        let pinned = self.is_tab_pinned(menu.buffer_id, menu.split_id);
+
         // Build the menu lines
         let mut lines = Vec::new();
         for (idx, item) in items.iter().enumerate() {
@@ -1227,7 +1320,7 @@ impl Editor {
             };
 
             // Pad the label to fill the menu width
-            let label = item.label();
+            let label = item.label(pinned);
             let content_width = (menu_width as usize).saturating_sub(2); // -2 for borders
             let padded_label = format!(" {:<width$}", label, width = content_width - 1);
 
@@ -2060,8 +2153,29 @@ impl Editor {
         }
 
         let buffer_id = self.active_buffer();
+        let is_terminal = self.is_terminal_buffer(buffer_id);
         let state = self.buffers.get_mut(&buffer_id).unwrap();
 
+        // Resolve the auto-close pairs for this buffer. Terminal buffers pass
+        // keys through to the shell, so they never auto-close.
+        state.buffer_settings.auto_close_pairs = if !self.config.editor.auto_close || is_terminal {
+            Some(Vec::new())
+        } else {
+            self.config
+                .languages
+                .get(&state.language)
+                .and_then(|lang_config| lang_config.parsed_auto_close_pairs())
+        };
+
+        // Resolve the auto-indent rules, compiling them only when they changed
+        let indent_patterns = match self.config.languages.get(&state.language) {
+            Some(lang_config) => lang_config.indent_patterns(&state.language),
+            None => IndentPatterns::for_language(&state.language),
+        };
+        if *state.buffer_settings.indent_rules.patterns() != indent_patterns {
+            state.buffer_settings.indent_rules = IndentRules::new(indent_patterns);
+        }
+
         // Use per-buffer tab_size which respects language overrides and user changes
         let tab_size = state.buffer_settings.tab_size;
 
@@ -2087,7 +2201,7 @@ impl Editor {
         &mut self,
         action: &Action,
         split_id: LeafId,
-        _estimated_line_length: usize,
+        estimated_line_length: usize,
     ) -> Option<Vec<Event>> {
         // Classify the action
         enum VisualAction {
@@ -2096,6 +2210,11 @@ impl Editor {
             LineStart { is_select: bool },
         }
 
+        let line_wrap = self
+            .split_view_states
+            .get(&split_id)
+            .is_some_and(|vs| vs.viewport.line_wrap_enabled);
+
         // Note: We don't intercept BlockSelectUp/Down because block selection has
         // special semantics (setting block_anchor) that require the default handler
         let visual_action = match action {
@@ -2118,18 +2237,10 @@ impl Editor {
             // When line wrapping is off, Home/End should move to the physical line
             // start/end, not the visual (horizontally-scrolled) row boundary.
             // Fall through to the standard handler which uses line_iterator.
-            Action::MoveLineEnd if self.config.editor.line_wrap => {
-                VisualAction::LineEnd { is_select: false }
-            }
-            Action::SelectLineEnd if self.config.editor.line_wrap => {
-                VisualAction::LineEnd { is_select: true }
-            }
-            Action::MoveLineStart if self.config.editor.line_wrap => {
-                VisualAction::LineStart { is_select: false }
-            }
-            Action::SelectLineStart if self.config.editor.line_wrap => {
-                VisualAction::LineStart { is_select: true }
-            }
+            Action::MoveLineEnd if line_wrap => VisualAction::LineEnd { is_select: false },
+            Action::SelectLineEnd if line_wrap => VisualAction::LineEnd { is_select: true },
+            Action::MoveLineStart if line_wrap => VisualAction::LineStart { is_select: false },
+            Action::SelectLineStart if line_wrap => VisualAction::LineStart { is_select: true },
             _ => return None, // Not a visual line action
         };
 
@@ -2161,6 +2272,15 @@ impl Editor {
                             .slice_bytes(cursor.position - 1..cursor.position);
                         prev.first() == Some(&b'\n')
                     };
+                    // Physical line bounds, for the second Home/End press
+                    let line_bounds = state
+                        .buffer
+                        .line_iterator(cursor.position, estimated_line_length)
+                        .next_line()
+                        .map(|(line_start, content)| {
+                            let content_len = content.trim_end_matches(['\r', '\n']).len();
+                            (line_start, line_start + content_len)
+                        });
                     (
                         cursor_id,
                         cursor.position,
@@ -2169,6 +2289,7 @@ impl Editor {
                         cursor.deselect_on_move,
                         at_line_ending,
                         at_line_start,
+                        line_bounds,
                     )
                 })
                 .collect()
@@ -2184,6 +2305,7 @@ impl Editor {
             deselect_on_move,
             at_line_ending,
             at_line_start,
+            line_bounds,
         ) in cursor_data
         {
             let (new_pos, new_sticky) = match &visual_action {
@@ -2212,23 +2334,35 @@ impl Editor {
                     }
                 }
                 VisualAction::LineEnd { .. } => {
-                    // Allow advancing to next visual segment only if not at a physical line ending
-                    let allow_advance = !at_line_ending;
+                    // First press goes to the end of the visual row; pressing again
+                    // at a wrap point goes to the end of the physical line
                     match self
                         .cached_layout
-                        .visual_line_end(split_id, position, allow_advance)
+                        .visual_line_end(split_id, position, false)
                     {
+                        Some(end_pos) if end_pos == position && !at_line_ending => {
+                            match line_bounds {
+                                Some((_, line_end)) => (line_end, 0),
+                                None => return None,
+                            }
+                        }
                         Some(end_pos) => (end_pos, 0),
                         None => return None,
                     }
                 }
                 VisualAction::LineStart { .. } => {
-                    // Allow advancing to previous visual segment only if not at a physical line start
-                    let allow_advance = !at_line_start;
+                    // First press goes to the start of the visual row; pressing again
+                    // on a continuation row goes to the start of the physical line
                     match self
                         .cached_layout
-                        .visual_line_start(split_id, position, allow_advance)
+                        .visual_line_start(split_id, position, false)
                     {
+                        Some(start_pos) if start_pos == position && !at_line_start => {
+                            match line_bounds {
+                                Some((line_start, _)) => (line_start, 0),
+                                None => return None,
+                            }
+                        }
                         Some(start_pos) => (start_pos, 0),
                         None => return None,
                     }
@@ -2293,36 +2427,12 @@ impl Editor {
             return;
         }
 
-        // Get theme colors and search settings before borrowing state
+        // Get theme colors before borrowing state
         let search_bg = self.theme.search_match_bg;
         let search_fg = self.theme.search_match_fg;
-        let case_sensitive = self.search_case_sensitive;
-        let whole_word = self.search_whole_word;
-        let use_regex = self.search_use_regex;
         let ns = self.search_namespace.clone();
 
-        // Build regex pattern if regex mode is enabled, or escape for literal search
-        let regex_pattern = if use_regex {
-            if whole_word {
-                format!(r"\b{}\b", query)
-            } else {
-                query.to_string()
-            }
-        } else {
-            let escaped = regex::escape(query);
-            if whole_word {
-                format!(r"\b{}\b", escaped)
-            } else {
-                escaped
-            }
-        };
-
-        // Build regex with case sensitivity
-        let regex = regex::RegexBuilder::new(&regex_pattern)
-            .case_insensitive(!case_sensitive)
-            .build();
-
-        let regex = match regex {
+        let regex = match self.build_search_regex(query) {
             Ok(r) => r,
             Err(_) => {
                 // Invalid regex, clear highlights and return
@@ -2421,11 +2531,6 @@ impl Editor {
             }
         };
 
-        // Get search settings
-        let case_sensitive = self.search_case_sensitive;
-        let whole_word = self.search_whole_word;
-        let use_regex = self.search_use_regex;
-
         // Determine search boundaries
         let (search_start, search_end) = if let Some(ref range) = search_range {
             (range.start, range.end)
@@ -2433,27 +2538,7 @@ impl Editor {
             (0, buffer_content.len())
         };
 
-        // Build regex pattern
-        let regex_pattern = if use_regex {
-            if whole_word {
-                format!(r"\b{}\b", query)
-            } else {
-                query.to_string()
-            }
-        } else {
-            let escaped = regex::escape(query);
-            if whole_word {
-                format!(r"\b{}\b", escaped)
-            } else {
-                escaped
-            }
-        };
-
-        // Build regex with case sensitivity
-        let regex = match regex::RegexBuilder::new(&regex_pattern)
-            .case_insensitive(!case_sensitive)
-            .build()
-        {
+        let regex = match self.build_search_regex(query) {
             Ok(r) => r,
             Err(e) => {
                 self.search_state = None;
@@ -2487,30 +2572,7 @@ impl Editor {
 
         // Create overlays for ALL matches (not just visible ones)
         // This ensures F3 can find matches outside viewport and markers track through edits
-        {
-            let search_bg = self.theme.search_match_bg;
-            let search_fg = self.theme.search_match_fg;
-            let ns = self.search_namespace.clone();
-            let state = self.active_state_mut();
-
-            // Clear existing (visible-only) overlays from incremental search
-            state.overlays.clear_namespace(&ns, &mut state.marker_list);
-
-            // Create overlays for all matches
-            for &(match_pos, match_len) in &match_ranges {
-                let search_style = ratatui::style::Style::default().fg(search_fg).bg(search_bg);
-                let overlay = crate::view::overlay::Overlay::with_namespace(
-                    &mut state.marker_list,
-                    match_pos..(match_pos + match_len),
-                    crate::view::overlay::OverlayFace::Style {
-                        style: search_style,
-                    },
-                    ns.clone(),
-                )
-                .with_priority_value(10);
-                state.overlays.add(overlay);
-            }
-        }
+        self.set_search_match_overlays(&match_ranges);
 
         // Find the first match at or after the current cursor position
         let cursor_pos = self.active_cursors().primary().position;
@@ -2521,6 +2583,9 @@ impl Editor {
 
         // Move cursor to the first match
         let match_pos = matches[current_match_index];
+        if match_pos != cursor_pos {
+            self.record_jump();
+        }
         {
             let active_split = self.split_manager.active_split();
             let active_buffer = self.active_buffer();
@@ -2542,6 +2607,8 @@ impl Editor {
             current_match_index: Some(current_match_index),
             wrap_search: search_range.is_none(), // Only wrap if not searching in selection
             search_range,
+            buffer_id: self.active_buffer(),
+            refresh_at: None,
         });
 
         let msg = if self.search_state.as_ref().unwrap().search_range.is_some() {
@@ -2562,6 +2629,149 @@ impl Editor {
         self.set_status_message(msg);
     }
 
+    /// Build the regex for a search query from the current search options
+    fn build_search_regex(&self, query: &str) -> Result<regex::Regex, regex::Error> {
+        // Escape the query unless regex mode is enabled
+        let pattern = if self.search_use_regex {
+            query.to_string()
+        } else {
+            regex::escape(query)
+        };
+        let pattern = if self.search_whole_word {
+            format!(r"\b{}\b", pattern)
+        } else {
+            pattern
+        };
+
+        regex::RegexBuilder::new(&pattern)
+            .case_insensitive(!self.search_case_sensitive)
+            .build()
+    }
+
+    /// Replace the search highlights in the active buffer with one overlay per match
+    fn set_search_match_overlays(&mut self, match_ranges: &[(usize, usize)]) {
+        let search_style = ratatui::style::Style::default()
+            .fg(self.theme.search_match_fg)
+            .bg(self.theme.search_match_bg);
+        let ns = self.search_namespace.clone();
+        let state = self.active_state_mut();
+
+        // Clear existing (visible-only) overlays from incremental search
+        state.overlays.clear_namespace(&ns, &mut state.marker_list);
+
+        for &(match_pos, match_len) in match_ranges {
+            let overlay = crate::view::overlay::Overlay::with_namespace(
+                &mut state.marker_list,
+                match_pos..(match_pos + match_len),
+                crate::view::overlay::OverlayFace::Style {
+                    style: search_style,
+                },
+                ns.clone(),
+            )
+            .with_priority_value(10);
+            state.overlays.add(overlay);
+        }
+    }
+
+    /// Schedule recomputing the matches of the active search after its buffer
+    /// was edited, so the match count and highlights follow the text.
+    /// Small buffers refresh on the next render; large ones once edits pause.
+    pub(super) fn schedule_search_refresh(&mut self, buffer_id: BufferId) {
+        let large = self
+            .buffers
+            .get(&buffer_id)
+            .is_some_and(|state| state.buffer.len() > SEARCH_REFRESH_IMMEDIATE_BYTES);
+        // Interactive replace and search-in-selection track their own matches
+        if self.interactive_replace_state.is_some() {
+            return;
+        }
+        let Some(search_state) = self.search_state.as_mut() else {
+            return;
+        };
+        if search_state.buffer_id != buffer_id || search_state.search_range.is_some() {
+            return;
+        }
+
+        let delay = if large {
+            Duration::from_millis(SEARCH_REFRESH_DEBOUNCE_MS)
+        } else {
+            Duration::ZERO
+        };
+        search_state.refresh_at = Some(Instant::now() + delay);
+    }
+
+    /// Recompute the search matches if a refresh scheduled after an edit is due.
+    /// The refresh waits while the searched buffer isn't the active one.
+    /// Returns true if the matches were recomputed.
+    pub fn check_search_refresh_timer(&mut self) -> bool {
+        let due = self.search_state.as_ref().is_some_and(|search_state| {
+            search_state.buffer_id == self.active_buffer()
+                && search_state
+                    .refresh_at
+                    .is_some_and(|refresh_at| Instant::now() >= refresh_at)
+        });
+        if !due {
+            return false;
+        }
+
+        self.refresh_search_matches();
+        true
+    }
+
+    /// Recompute the matches of the active search in the active buffer without
+    /// moving the cursor
+    fn refresh_search_matches(&mut self) {
+        let Some(search_state) = self.search_state.as_mut() else {
+            return;
+        };
+        search_state.refresh_at = None;
+        let query = search_state.query.clone();
+
+        let Ok(regex) = self.build_search_regex(&query) else {
+            return;
+        };
+        let buffer_content = {
+            let state = self.active_state_mut();
+            let total_bytes = state.buffer.len();
+            match state.buffer.get_text_range_mut(0, total_bytes) {
+                Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                Err(e) => {
+                    tracing::warn!("Failed to load buffer for search refresh: {}", e);
+                    return;
+                }
+            }
+        };
+
+        let match_ranges: Vec<(usize, usize)> = regex
+            .find_iter(&buffer_content)
+            .map(|m| (m.start(), m.end() - m.start()))
+            .collect();
+        self.set_search_match_overlays(&match_ranges);
+
+        if let Some(search_state) = self.search_state.as_mut() {
+            search_state.matches = match_ranges.iter().map(|(pos, _)| *pos).collect();
+            // Keep the current index so F3 continues from where it was
+            search_state.current_match_index = search_state
+                .current_match_index
+                .map(|index| index.min(search_state.matches.len().saturating_sub(1)));
+        }
+    }
+
+    /// The search match indicator for the status bar: the match the cursor is
+    /// on (if any) and the total number of matches in the active buffer
+    fn search_match_indicator(&self) -> Option<(Option<usize>, usize)> {
+        let search_state = self.search_state.as_ref()?;
+        if search_state.buffer_id != self.active_buffer() || self.prompt.is_some() {
+            return None;
+        }
+
+        let cursor_pos = self.active_cursors().primary().position;
+        let current = search_state
+            .current_match_index
+            .filter(|&index| search_state.matches.get(index) == Some(&cursor_pos));
+        Some((current, search_state.matches.len()))
+    }
+
     /// Get current match positions from search overlays (which use markers that track edits)
     /// This ensures positions are always up-to-date even after buffer modifications
     fn get_search_match_positions(&self) -> Vec<usize> {
@@ -2584,8 +2794,29 @@ impl Editor {
         positions
     }
 
+    /// If the active search was made in another buffer, run it again in the
+    /// active one (which moves to the first match after the cursor).
+    /// Returns true if the search was re-run.
+    fn search_in_other_buffer(&mut self) -> bool {
+        let query = match &self.search_state {
+            Some(search_state)
+                if search_state.buffer_id != self.active_buffer()
+                    && search_state.search_range.is_none() =>
+            {
+                search_state.query.clone()
+            }
+            _ => return false,
+        };
+        self.perform_search(&query);
+        true
+    }
+
     /// Find the next match
     pub(super) fn find_next(&mut self) {
+        if self.search_in_other_buffer() {
+            return;
+        }
+
         // Get current positions from overlay markers (auto-updated with buffer edits)
         // Fall back to search_state.matches if no overlays exist (e.g., find_selection_next)
         let overlay_positions = self.get_search_match_positions();
@@ -2616,7 +2847,6 @@ impl Editor {
 
             search_state.current_match_index = Some(next_index);
             let match_pos = match_positions[next_index];
-            let matches_len = match_positions.len();
 
             {
                 let active_split = self.split_manager.active_split();
@@ -2629,15 +2859,6 @@ impl Editor {
                     view_state.ensure_cursor_visible(&mut state.buffer, &state.marker_list);
                 }
             }
-
-            self.set_status_message(
-                t!(
-                    "search.match_of",
-                    current = next_index + 1,
-                    total = matches_len
-                )
-                .to_string(),
-            );
         } else {
             let find_key = self
                 .get_keybinding_for_action("find")
@@ -2648,6 +2869,10 @@ impl Editor {
 
     /// Find the previous match
     pub(super) fn find_previous(&mut self) {
+        if self.search_in_other_buffer() {
+            return;
+        }
+
         // Get current positions from overlay markers first (auto-updated with buffer edits)
         // Fall back to search_state.matches if no overlays exist (e.g., find_selection_previous)
         let overlay_positions = self.get_search_match_positions();
@@ -2679,7 +2904,6 @@ impl Editor {
 
             search_state.current_match_index = Some(prev_index);
             let match_pos = match_positions[prev_index];
-            let matches_len = match_positions.len();
 
             {
                 let active_split = self.split_manager.active_split();
@@ -2692,15 +2916,6 @@ impl Editor {
                     view_state.ensure_cursor_visible(&mut state.buffer, &state.marker_list);
                 }
             }
-
-            self.set_status_message(
-                t!(
-                    "search.match_of",
-                    current = prev_index + 1,
-                    total = matches_len
-                )
-                .to_string(),
-            );
         } else {
             let find_key = self
                 .get_keybinding_for_action("find")
@@ -2876,8 +3091,11 @@ impl Editor {
 
     /// Perform a replace-all operation
     /// Build a compiled byte-regex for replace operations using current search settings.
-    /// Returns None when regex mode is off (plain text matching should be used).
-    fn build_replace_regex(&self, search: &str) -> Option<regex::bytes::Regex> {
+    /// Returns `Ok(None)` for a plain, case-sensitive search (matched directly).
+    pub(super) fn build_replace_regex(
+        &self,
+        search: &str,
+    ) -> Result<Option<regex::bytes::Regex>, regex::Error> {
         super::regex_replace::build_regex(
             search,
             self.search_use_regex,
@@ -2886,6 +3104,25 @@ impl Editor {
         )
     }
 
+    /// How replacements are computed under the current search settings
+    fn replace_options(&self) -> super::regex_replace::ReplaceOptions {
+        super::regex_replace::ReplaceOptions {
+            expand_captures: self.search_use_regex,
+            preserve_case: self.search_preserve_case,
+        }
+    }
+
+    /// Build the replace regex, reporting an invalid pattern in the status bar
+    fn build_replace_regex_or_report(
+        &mut self,
+        search: &str,
+    ) -> Result<Option<regex::bytes::Regex>, ()> {
+        self.build_replace_regex(search).map_err(|e| {
+            let error = super::regex_replace::regex_error_message(&e);
+            self.set_status_message(t!("replace.invalid_regex", error = error).to_string());
+        })
+    }
+
     /// Get the length of a regex match at a given position in the buffer.
     fn get_regex_match_len(&mut self, regex: &regex::bytes::Regex, pos: usize) -> Option<usize> {
         let state = self.active_state_mut();
@@ -2897,33 +3134,39 @@ impl Editor {
         regex.find(&bytes).map(|m| m.len())
     }
 
-    /// Expand capture group references (e.g. $1, $2, ${name}) in the replacement string
-    /// for a regex match at the given buffer position. Returns the expanded replacement.
-    fn expand_regex_replacement(
+    /// Compute the replacement for the match at the given buffer position,
+    /// expanding capture groups (e.g. $1, ${name}, \u) and preserving case
+    /// as `options` request.
+    fn replacement_for_match(
         &mut self,
-        regex: &regex::bytes::Regex,
+        regex: Option<&regex::bytes::Regex>,
+        options: super::regex_replace::ReplaceOptions,
         pos: usize,
         match_len: usize,
         replacement: &str,
     ) -> String {
         let state = self.active_state_mut();
         if let Ok(bytes) = state.buffer.get_text_range_mut(pos, match_len) {
-            return super::regex_replace::expand_replacement(regex, &bytes, replacement);
+            return super::regex_replace::expand_replacement(regex, &bytes, replacement, options);
         }
         replacement.to_string()
     }
 
-    /// Replaces all occurrences of the search query with the replacement text
+    /// Replaces all occurrences of the search query with the replacement text,
+    /// returning how many were replaced
     ///
     /// OPTIMIZATION: Uses BulkEdit for O(n) tree operations instead of O(n²)
     /// This directly edits the piece tree without loading the entire buffer into memory
-    pub(super) fn perform_replace(&mut self, search: &str, replacement: &str) {
+    pub(super) fn perform_replace(&mut self, search: &str, replacement: &str) -> usize {
         if search.is_empty() {
             self.set_status_message(t!("replace.empty_query").to_string());
-            return;
+            return 0;
         }
 
-        let compiled_regex = self.build_replace_regex(search);
+        let Ok(compiled_regex) = self.build_replace_regex_or_report(search) else {
+            return 0;
+        };
+        let options = self.replace_options();
 
         // Find all matches first (before making any modifications)
         // Each match is (position, length, expanded_replacement)
@@ -2938,16 +3181,22 @@ impl Editor {
                     Err(e) => {
                         tracing::warn!("Failed to load buffer for replace: {}", e);
                         self.set_status_message(t!("error.buffer_not_loaded").to_string());
-                        return;
+                        return 0;
                     }
                 }
             };
-            super::regex_replace::collect_regex_matches(regex, &buffer_bytes, replacement)
+            super::regex_replace::collect_regex_matches(regex, &buffer_bytes, replacement, options)
                 .into_iter()
                 .map(|m| (m.offset, m.len, m.replacement))
                 .collect()
         } else {
-            // Plain text mode - replacement is used literally
+            // Plain text mode - every match is exactly `search`, so the
+            // replacement is the same for all of them
+            let replacement = if options.preserve_case {
+                super::regex_replace::preserve_case(search, replacement)
+            } else {
+                replacement.to_string()
+            };
             let state = self.active_state();
             let buffer_len = state.buffer.len();
             let mut matches = Vec::new();
@@ -2959,7 +3208,7 @@ impl Editor {
                     current_pos,
                     Some(current_pos..buffer_len),
                 ) {
-                    matches.push((offset, search.len(), replacement.to_string()));
+                    matches.push((offset, search.len(), replacement.clone()));
                     current_pos = offset + search.len();
                 } else {
                     break;
@@ -2972,7 +3221,7 @@ impl Editor {
 
         if count == 0 {
             self.set_status_message(t!("search.no_occurrences", search = search).to_string());
-            return;
+            return 0;
         }
 
         // Get cursor info for the event
@@ -3024,6 +3273,7 @@ impl Editor {
             )
             .to_string(),
         );
+        count
     }
 
     /// Start interactive replace mode (query-replace)
@@ -3033,7 +3283,9 @@ impl Editor {
             return;
         }
 
-        let compiled_regex = self.build_replace_regex(search);
+        let Ok(compiled_regex) = self.build_replace_regex_or_report(search) else {
+            return;
+        };
 
         // Find the first match lazily (don't find all matches upfront)
         let start_pos = self.active_cursors().primary().position;
@@ -3079,6 +3331,9 @@ impl Editor {
             has_wrapped: false,
             replacements_made: 0,
             regex: compiled_regex,
+            options: self.replace_options(),
+            buffer_id: self.active_buffer(),
+            undo_start: self.active_event_log().current_index(),
         });
 
         // Move cursor to first match
@@ -3109,11 +3364,18 @@ impl Editor {
         match c {
             'y' | 'Y' => {
                 // Replace current match
-                self.replace_current_match(&ir_state)?;
+                let inserted_len = self.replace_current_match(&ir_state)?;
                 ir_state.replacements_made += 1;
 
+                // After wrapping, replacements land before the starting match,
+                // which moves it by the change in length
+                if ir_state.has_wrapped {
+                    ir_state.start_pos = (ir_state.start_pos + inserted_len)
+                        .saturating_sub(ir_state.current_match_len);
+                }
+
                 // Find next match lazily (after the replacement)
-                let search_pos = ir_state.current_match_pos + ir_state.replacement.len();
+                let search_pos = ir_state.current_match_pos + inserted_len;
                 if let Some((next_match, match_len, wrapped)) =
                     self.find_next_match_for_replace(&ir_state, search_pos)
                 {
@@ -3188,17 +3450,14 @@ impl Editor {
                         let deleted_text = self
                             .active_state_mut()
                             .get_text_range(match_pos, match_pos + match_len);
-                        // Expand capture group references if in regex mode
-                        let replacement_text = if let Some(ref regex) = ir_state.regex {
-                            self.expand_regex_replacement(
-                                regex,
-                                match_pos,
-                                match_len,
-                                &ir_state.replacement,
-                            )
-                        } else {
-                            ir_state.replacement.clone()
-                        };
+                        // Expand capture groups / preserve case as configured
+                        let replacement_text = self.replacement_for_match(
+                            ir_state.regex.as_ref(),
+                            ir_state.options,
+                            match_pos,
+                            match_len,
+                            &ir_state.replacement,
+                        );
                         events.push(Event::Delete {
                             range: match_pos..match_pos + match_len,
                             deleted_text,
@@ -3324,21 +3583,25 @@ impl Editor {
         }
     }
 
-    /// Replace the current match in interactive replace mode
+    /// Replace the current match in interactive replace mode.
+    /// Returns the length in bytes of the inserted replacement.
     pub(super) fn replace_current_match(
         &mut self,
         ir_state: &InteractiveReplaceState,
-    ) -> AnyhowResult<()> {
+    ) -> AnyhowResult<usize> {
         let match_pos = ir_state.current_match_pos;
         let match_len = ir_state.current_match_len;
         let range = match_pos..(match_pos + match_len);
 
-        // Expand capture group references if in regex mode
-        let replacement_text = if let Some(ref regex) = ir_state.regex {
-            self.expand_regex_replacement(regex, match_pos, match_len, &ir_state.replacement)
-        } else {
-            ir_state.replacement.clone()
-        };
+        // Expand capture groups / preserve case as configured
+        let replacement_text = self.replacement_for_match(
+            ir_state.regex.as_ref(),
+            ir_state.options,
+            match_pos,
+            match_len,
+            &ir_state.replacement,
+        );
+        let inserted_len = replacement_text.len();
 
         // Get the deleted text for the event
         let deleted_text = self
@@ -3385,11 +3648,12 @@ impl Editor {
             ),
         };
 
-        // Apply the batch through the event log
+        // Apply the batch through the event log; the batches of one run are
+        // merged into a single undo step when it ends
         self.active_event_log_mut().append(batch.clone());
         self.apply_event_to_active_buffer(&batch);
 
-        Ok(())
+        Ok(inserted_len)
     }
 
     /// Move cursor to the current match in interactive replace
@@ -3420,9 +3684,26 @@ impl Editor {
         }
     }
 
+    /// Leave interactive replace, folding every replacement made during the
+    /// run into a single undo step
+    pub(super) fn end_interactive_replace(&mut self) {
+        let Some(ir_state) = self.interactive_replace_state.take() else {
+            return;
+        };
+        if let Some(event_log) = self.event_logs.get_mut(&ir_state.buffer_id) {
+            event_log.merge_since(
+                ir_state.undo_start,
+                format!(
+                    "Query replace '{}' with '{}'",
+                    ir_state.search, ir_state.replacement
+                ),
+            );
+        }
+    }
+
     /// Finish interactive replace and show summary
     pub(super) fn finish_interactive_replace(&mut self, replacements_made: usize) {
-        self.interactive_replace_state = None;
+        self.end_interactive_replace();
         self.prompt = None; // Clear the query-replace prompt
 
         // Clear search highlights
@@ -3433,419 +3714,6 @@ impl Editor {
         self.set_status_message(t!("search.replaced_count", count = replacements_made).to_string());
     }
 
-    /// Smart home: toggle between line start and first non-whitespace character
-    pub(super) fn smart_home(&mut self) {
-        let estimated_line_length = self.config.editor.estimated_line_length;
-        let cursor = *self.active_cursors().primary();
-        let cursor_id = self.active_cursors().primary_id();
-
-        // When line wrap is on, use the visual (soft-wrapped) line boundaries
-        if self.config.editor.line_wrap {
-            let split_id = self.split_manager.active_split();
-            if let Some(new_pos) =
-                self.smart_home_visual_line(split_id, cursor.position, estimated_line_length)
-            {
-                let event = Event::MoveCursor {
-                    cursor_id,
-                    old_position: cursor.position,
-                    new_position: new_pos,
-                    old_anchor: cursor.anchor,
-                    new_anchor: None,
-                    old_sticky_column: cursor.sticky_column,
-                    new_sticky_column: 0,
-                };
-                self.active_event_log_mut().append(event.clone());
-                self.apply_event_to_active_buffer(&event);
-                return;
-            }
-            // Fall through to physical line logic if visual lookup fails
-        }
-
-        let state = self.active_state_mut();
-
-        // Get physical line information
-        let mut iter = state
-            .buffer
-            .line_iterator(cursor.position, estimated_line_length);
-        if let Some((line_start, line_content)) = iter.next_line() {
-            // Find first non-whitespace character
-            let first_non_ws = line_content
-                .chars()
-                .take_while(|c| *c != '\n')
-                .position(|c| !c.is_whitespace())
-                .map(|offset| line_start + offset)
-                .unwrap_or(line_start);
-
-            // Toggle: if at first non-ws, go to line start; otherwise go to first non-ws
-            let new_pos = if cursor.position == first_non_ws {
-                line_start
-            } else {
-                first_non_ws
-            };
-
-            let event = Event::MoveCursor {
-                cursor_id,
-                old_position: cursor.position,
-                new_position: new_pos,
-                old_anchor: cursor.anchor,
-                new_anchor: None,
-                old_sticky_column: cursor.sticky_column,
-                new_sticky_column: 0,
-            };
-
-            self.active_event_log_mut().append(event.clone());
-            self.apply_event_to_active_buffer(&event);
-        }
-    }
-
-    /// Compute the smart-home target for a visual (soft-wrapped) line.
-    ///
-    /// On the **first** visual row of a physical line the cursor toggles between
-    /// the first non-whitespace character and position 0 (standard smart-home).
-    ///
-    /// On a **continuation** (wrapped) row the cursor moves to the visual row
-    /// start; if already there it advances to the previous visual row's start
-    /// so that repeated Home presses walk all the way back to position 0.
-    fn smart_home_visual_line(
-        &mut self,
-        split_id: LeafId,
-        cursor_pos: usize,
-        estimated_line_length: usize,
-    ) -> Option<usize> {
-        let visual_start = self
-            .cached_layout
-            .visual_line_start(split_id, cursor_pos, false)?;
-
-        // Determine the physical line start to tell first-row from continuation.
-        let buffer_id = self.split_manager.active_buffer_id()?;
-        let state = self.buffers.get_mut(&buffer_id)?;
-        let mut iter = state
-            .buffer
-            .line_iterator(visual_start, estimated_line_length);
-        let (phys_line_start, content) = iter.next_line()?;
-
-        let is_first_visual_row = visual_start == phys_line_start;
-
-        if is_first_visual_row {
-            // First visual row: toggle first-non-ws ↔ physical line start
-            let visual_end = self
-                .cached_layout
-                .visual_line_end(split_id, cursor_pos, false)
-                .unwrap_or(visual_start);
-            let visual_len = visual_end.saturating_sub(visual_start);
-            let first_non_ws = content
-                .chars()
-                .take(visual_len)
-                .take_while(|c| *c != '\n')
-                .position(|c| !c.is_whitespace())
-                .map(|offset| visual_start + offset)
-                .unwrap_or(visual_start);
-
-            if cursor_pos == first_non_ws {
-                Some(visual_start)
-            } else {
-                Some(first_non_ws)
-            }
-        } else {
-            // Continuation row: go to visual line start, or advance backward
-            if cursor_pos == visual_start {
-                // Already at start – advance to previous visual row's start
-                self.cached_layout
-                    .visual_line_start(split_id, cursor_pos, true)
-            } else {
-                Some(visual_start)
-            }
-        }
-    }
-
-    /// Toggle comment on the current line or selection
-    pub(super) fn toggle_comment(&mut self) {
-        // Determine comment prefix from language config
-        // If no language detected or no comment prefix configured, do nothing
-        let language = &self.active_state().language;
-        let comment_prefix = self
-            .config
-            .languages
-            .get(language)
-            .and_then(|lang_config| lang_config.comment_prefix.clone());
-
-        let comment_prefix: String = match comment_prefix {
-            Some(prefix) => {
-                // Ensure there's a trailing space for consistent formatting
-                if prefix.ends_with(' ') {
-                    prefix
-                } else {
-                    format!("{} ", prefix)
-                }
-            }
-            None => return, // No comment prefix for this language, do nothing
-        };
-
-        let estimated_line_length = self.config.editor.estimated_line_length;
-
-        let cursor = *self.active_cursors().primary();
-        let cursor_id = self.active_cursors().primary_id();
-        let state = self.active_state_mut();
-
-        // Save original selection info to restore after edit
-        let original_anchor = cursor.anchor;
-        let original_position = cursor.position;
-        let had_selection = original_anchor.is_some();
-
-        let (start_pos, end_pos) = if let Some(range) = cursor.selection_range() {
-            (range.start, range.end)
-        } else {
-            let iter = state
-                .buffer
-                .line_iterator(cursor.position, estimated_line_length);
-            let line_start = iter.current_position();
-            (line_start, cursor.position)
-        };
-
-        // Find all line starts in the range
-        let buffer_len = state.buffer.len();
-        let mut line_starts = Vec::new();
-        let mut iter = state.buffer.line_iterator(start_pos, estimated_line_length);
-        let mut current_pos = iter.current_position();
-        line_starts.push(current_pos);
-
-        while let Some((_, content)) = iter.next_line() {
-            current_pos += content.len();
-            if current_pos >= end_pos || current_pos >= buffer_len {
-                break;
-            }
-            let next_iter = state
-                .buffer
-                .line_iterator(current_pos, estimated_line_length);
-            let next_start = next_iter.current_position();
-            if next_start != *line_starts.last().unwrap() {
-                line_starts.push(next_start);
-            }
-            iter = state
-                .buffer
-                .line_iterator(current_pos, estimated_line_length);
-        }
-
-        // Determine if we should comment or uncomment
-        // If all lines are commented, uncomment; otherwise comment
-        let all_commented = line_starts.iter().all(|&line_start| {
-            let line_bytes = state
-                .buffer
-                .slice_bytes(line_start..buffer_len.min(line_start + comment_prefix.len() + 10));
-            let line_str = String::from_utf8_lossy(&line_bytes);
-            let trimmed = line_str.trim_start();
-            trimmed.starts_with(comment_prefix.trim())
-        });
-
-        let mut events = Vec::new();
-        // Track (edit_position, byte_delta) for calculating new cursor positions
-        // delta is positive for insertions, negative for deletions
-        let mut position_deltas: Vec<(usize, isize)> = Vec::new();
-
-        if all_commented {
-            // Uncomment: remove comment prefix from each line
-            for &line_start in line_starts.iter().rev() {
-                let line_bytes = state
-                    .buffer
-                    .slice_bytes(line_start..buffer_len.min(line_start + 100));
-                let line_str = String::from_utf8_lossy(&line_bytes);
-
-                // Find where the comment prefix starts (after leading whitespace)
-                let leading_ws: usize = line_str
-                    .chars()
-                    .take_while(|c| c.is_whitespace() && *c != '\n')
-                    .map(|c| c.len_utf8())
-                    .sum();
-                let rest = &line_str[leading_ws..];
-
-                if rest.starts_with(comment_prefix.trim()) {
-                    let remove_len = if rest.starts_with(&comment_prefix) {
-                        comment_prefix.len()
-                    } else {
-                        comment_prefix.trim().len()
-                    };
-                    let deleted_text = String::from_utf8_lossy(&state.buffer.slice_bytes(
-                        line_start + leading_ws..line_start + leading_ws + remove_len,
-                    ))
-                    .to_string();
-                    events.push(Event::Delete {
-                        range: (line_start + leading_ws)..(line_start + leading_ws + remove_len),
-                        deleted_text,
-                        cursor_id,
-                    });
-                    position_deltas.push((line_start, -(remove_len as isize)));
-                }
-            }
-        } else {
-            // Comment: add comment prefix to each line
-            let prefix_len = comment_prefix.len();
-            for &line_start in line_starts.iter().rev() {
-                events.push(Event::Insert {
-                    position: line_start,
-                    text: comment_prefix.to_string(),
-                    cursor_id,
-                });
-                position_deltas.push((line_start, prefix_len as isize));
-            }
-        }
-
-        if events.is_empty() {
-            return;
-        }
-
-        let action_desc = if all_commented {
-            "Uncomment"
-        } else {
-            "Comment"
-        };
-
-        // If there was a selection, add a MoveCursor event to restore it
-        if had_selection {
-            // Sort deltas by position ascending for calculation
-            position_deltas.sort_by_key(|(pos, _)| *pos);
-
-            // Calculate cumulative shift for a position based on edits at or before that position
-            let calc_shift = |original_pos: usize| -> isize {
-                let mut shift: isize = 0;
-                for (edit_pos, delta) in &position_deltas {
-                    if *edit_pos < original_pos {
-                        shift += delta;
-                    }
-                }
-                shift
-            };
-
-            let anchor_shift = calc_shift(original_anchor.unwrap_or(0));
-            let position_shift = calc_shift(original_position);
-
-            let new_anchor = (original_anchor.unwrap_or(0) as isize + anchor_shift).max(0) as usize;
-            let new_position = (original_position as isize + position_shift).max(0) as usize;
-
-            events.push(Event::MoveCursor {
-                cursor_id,
-                old_position: original_position,
-                new_position,
-                old_anchor: original_anchor,
-                new_anchor: Some(new_anchor),
-                old_sticky_column: 0,
-                new_sticky_column: 0,
-            });
-        }
-
-        // Use optimized bulk edit for multi-line comment toggle
-        let description = format!("{} lines", action_desc);
-        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description) {
-            self.active_event_log_mut().append(bulk_edit);
-        }
-
-        self.set_status_message(
-            t!(
-                "lines.action",
-                action = action_desc,
-                count = line_starts.len()
-            )
-            .to_string(),
-        );
-    }
-
-    /// Go to matching bracket
-    pub(super) fn goto_matching_bracket(&mut self) {
-        let cursor = *self.active_cursors().primary();
-        let cursor_id = self.active_cursors().primary_id();
-        let state = self.active_state_mut();
-
-        let pos = cursor.position;
-        if pos >= state.buffer.len() {
-            self.set_status_message(t!("diagnostics.bracket_none").to_string());
-            return;
-        }
-
-        let bytes = state.buffer.slice_bytes(pos..pos + 1);
-        if bytes.is_empty() {
-            self.set_status_message(t!("diagnostics.bracket_none").to_string());
-            return;
-        }
-
-        let ch = bytes[0] as char;
-        let (opening, closing, forward) = match ch {
-            '(' => ('(', ')', true),
-            ')' => ('(', ')', false),
-            '[' => ('[', ']', true),
-            ']' => ('[', ']', false),
-            '{' => ('{', '}', true),
-            '}' => ('{', '}', false),
-            '<' => ('<', '>', true),
-            '>' => ('<', '>', false),
-            _ => {
-                self.set_status_message(t!("diagnostics.bracket_none").to_string());
-                return;
-            }
-        };
-
-        // Find matching bracket
-        let buffer_len = state.buffer.len();
-        let mut depth = 1;
-        let matching_pos = if forward {
-            let mut search_pos = pos + 1;
-            let mut found = None;
-            while search_pos < buffer_len && depth > 0 {
-                let b = state.buffer.slice_bytes(search_pos..search_pos + 1);
-                if !b.is_empty() {
-                    let c = b[0] as char;
-                    if c == opening {
-                        depth += 1;
-                    } else if c == closing {
-                        depth -= 1;
-                        if depth == 0 {
-                            found = Some(search_pos);
-                        }
-                    }
-                }
-                search_pos += 1;
-            }
-            found
-        } else {
-            let mut search_pos = pos.saturating_sub(1);
-            let mut found = None;
-            loop {
-                let b = state.buffer.slice_bytes(search_pos..search_pos + 1);
-                if !b.is_empty() {
-                    let c = b[0] as char;
-                    if c == closing {
-                        depth += 1;
-                    } else if c == opening {
-                        depth -= 1;
-                        if depth == 0 {
-                            found = Some(search_pos);
-                            break;
-                        }
-                    }
-                }
-                if search_pos == 0 {
-                    break;
-                }
-                search_pos -= 1;
-            }
-            found
-        };
-
-        if let Some(new_pos) = matching_pos {
-            let event = Event::MoveCursor {
-                cursor_id,
-                old_position: cursor.position,
-                new_position: new_pos,
-                old_anchor: cursor.anchor,
-                new_anchor: None,
-                old_sticky_column: cursor.sticky_column,
-                new_sticky_column: 0,
-            };
-            self.active_event_log_mut().append(event.clone());
-            self.apply_event_to_active_buffer(&event);
-        } else {
-            self.set_status_message(t!("diagnostics.bracket_no_match").to_string());
-        }
-    }
-
     /// Jump to next error/diagnostic
     pub(super) fn jump_to_next_error(&mut self) {
         let diagnostic_ns = self.lsp_diagnostic_namespace.clone();
@@ -4134,7 +4002,14 @@ impl Editor {
             false, // lsp_waiting — not relevant for layout
             self.config.editor.estimated_line_length,
             self.config.editor.highlight_context_bytes,
-            self.config.editor.relative_line_numbers,
+            self.config.editor.line_number_mode,
+            self.config.editor.indent_guides,
+            self.config.editor.highlight_current_line,
+            self.config.editor.bracket_pair_colorization,
+            self.config.editor.color_swatches,
+            self.config.editor.syntax_highlight_max_column,
+            self.config.editor.minimap,
+            self.config.editor.minimap_min_width,
             self.config.editor.use_terminal_bg,
             self.session_mode,
             self.software_cursor_only,
@@ -4288,6 +4163,8 @@ impl Editor {
             lsp_opened_with: std::collections::HashSet::new(),
             hidden_from_tabs: false,
             recovery_id: None,
+            language_override: None,
+            untitled_number: None,
         };
         self.buffer_metadata.insert(buffer_id, metadata);
 
@@ -4363,6 +4240,8 @@ impl Editor {
             lsp_opened_with: std::collections::HashSet::new(),
             hidden_from_tabs: false,
             recovery_id: None,
+            language_override: None,
+            untitled_number: None,
         };
         self.buffer_metadata.insert(buffer_id, metadata);
 
@@ -4484,6 +4363,12 @@ impl Editor {
                 tracing::debug!("Saved {} history to {:?}", key, path);
             }
         }
+
+        // Save command palette usage
+        let path = self.dir_context.command_usage_path();
+        if let Err(e) = self.command_registry.read().unwrap().save_usage(&path) {
+            tracing::warn!("Failed to save command usage: {}", e);
+        }
     }
 
     /// Ensure the active tab in a split is visible by adjusting its scroll offset.
@@ -4727,6 +4612,20 @@ impl Editor {
         }
     }
 
+    /// Copy the configured scroll margins and modes into every viewport
+    fn apply_scroll_settings(&mut self) {
+        let editor = &self.config.editor;
+        for view_state in self.split_view_states.values_mut() {
+            for buf_state in view_state.keyed_states.values_mut() {
+                let viewport = &mut buf_state.viewport;
+                viewport.scroll_offset = editor.vertical_scroll_offset;
+                viewport.horizontal_scroll_offset = editor.horizontal_scroll_offset;
+                viewport.scroll_past_end = editor.scroll_past_end;
+                viewport.cursor_centering = editor.cursor_centering;
+            }
+        }
+    }
+
     /// Pre-sync ensure_visible for scroll sync groups
     ///
     /// When the active split is in a scroll sync group, we need to update its viewport
@@ -0,0 +1,585 @@
+//! Expand Selection and Shrink Selection.
+//!
+//! Expand Selection grows each cursor's selection to the next scope around
+//! it: the word, the contents of the string, comment or brackets it is in,
+//! those with their delimiters, the enclosing block, and at last the whole
+//! buffer. When the language server answers `textDocument/selectionRange`,
+//! its scopes come first. Each cursor keeps the selections it went through so
+//! Shrink Selection can walk back down; moving the cursors any other way, or
+//! editing, forgets them.
+
+use std::ops::Range;
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::model::buffer::Buffer;
+use crate::model::cursor::Cursor;
+use crate::model::event::{BufferId, CursorId, Event};
+use crate::primitives::word_navigation::is_word_char;
+use crate::state::EditorState;
+use crate::view::folding::indent_folding;
+
+/// Bytes around the cursors searched for strings, brackets and blocks.
+/// Scopes reaching further out are skipped on the way to the whole buffer.
+const SCOPE_WINDOW: usize = 64 * 1024;
+
+/// Most enclosing indentation blocks walked out through at once
+const MAX_BLOCK_DEPTH: usize = 32;
+
+/// Selections grown by Expand Selection in one buffer
+pub(crate) struct SelectionExpansion {
+    buffer_id: BufferId,
+    /// Buffer version the selections were made at
+    version: u64,
+    cursors: Vec<ExpandedCursor>,
+    /// Scopes the language server gave around each cursor, innermost first
+    lsp_scopes: Vec<Vec<Range<usize>>>,
+    /// Unanswered request for the language server's scopes, and how many
+    /// times to expand once they arrive
+    pending: Option<(u64, usize)>,
+}
+
+/// A cursor and the selections Expand Selection made for it
+struct ExpandedCursor {
+    id: CursorId,
+    /// The cursor as it was before the first expansion
+    original: Cursor,
+    /// Selection made by each expansion, innermost first
+    steps: Vec<Range<usize>>,
+}
+
+impl ExpandedCursor {
+    /// Selection the next expansion grows from
+    fn current(&self) -> Range<usize> {
+        match self.steps.last() {
+            Some(step) => step.clone(),
+            None => self
+                .original
+                .selection_range()
+                .unwrap_or(self.original.position..self.original.position),
+        }
+    }
+
+    /// Position and anchor the cursor was left with
+    fn placed(&self) -> (usize, Option<usize>) {
+        match self.steps.last() {
+            Some(step) => (step.end, Some(step.start)),
+            None => (self.original.position, self.original.anchor),
+        }
+    }
+}
+
+impl SelectionExpansion {
+    fn new(buffer_id: BufferId, version: u64, cursors: &[(CursorId, Cursor)]) -> Self {
+        Self {
+            buffer_id,
+            version,
+            cursors: cursors
+                .iter()
+                .map(|&(id, original)| ExpandedCursor {
+                    id,
+                    original,
+                    steps: Vec::new(),
+                })
+                .collect(),
+            lsp_scopes: Vec::new(),
+            pending: None,
+        }
+    }
+
+    /// Whether the buffer and its cursors are as the expansions left them
+    fn is_current(
+        &self,
+        buffer_id: BufferId,
+        version: u64,
+        cursors: &[(CursorId, Cursor)],
+    ) -> bool {
+        self.buffer_id == buffer_id
+            && self.version == version
+            && self.cursors.len() == cursors.len()
+            && self
+                .cursors
+                .iter()
+                .zip(cursors)
+                .all(|(expanded, (id, cursor))| {
+                    expanded.id == *id && expanded.placed() == (cursor.position, cursor.anchor)
+                })
+    }
+}
+
+impl Editor {
+    /// Grow every cursor's selection to the next scope around it
+    pub(super) fn expand_selection(&mut self) {
+        let buffer_id = self.active_buffer();
+        let version = self.active_state().buffer.version();
+        let cursors = self.cursor_snapshot();
+
+        let current = self
+            .selection_expansion
+            .as_ref()
+            .is_some_and(|expansion| expansion.is_current(buffer_id, version, &cursors));
+        if !current {
+            self.selection_expansion = Some(SelectionExpansion::new(buffer_id, version, &cursors));
+            if self.request_selection_ranges(buffer_id, &cursors) {
+                return;
+            }
+        } else if let Some((_, steps)) = self
+            .selection_expansion
+            .as_mut()
+            .and_then(|expansion| expansion.pending.as_mut())
+        {
+            // The language server hasn't answered yet; expand once it has
+            *steps += 1;
+            return;
+        }
+
+        self.expand_selection_step();
+    }
+
+    /// Put every cursor back to the selection it had before the last
+    /// expansion
+    pub(super) fn shrink_selection(&mut self) {
+        let buffer_id = self.active_buffer();
+        let version = self.active_state().buffer.version();
+        let cursors = self.cursor_snapshot();
+
+        let expansion = self
+            .selection_expansion
+            .take()
+            .filter(|expansion| expansion.is_current(buffer_id, version, &cursors))
+            .filter(|expansion| expansion.cursors.iter().any(|c| !c.steps.is_empty()));
+        let Some(mut expansion) = expansion else {
+            self.set_status_message(t!("selection.nothing_to_shrink").to_string());
+            return;
+        };
+        // An answer arriving now shouldn't grow the selections again
+        expansion.pending = None;
+
+        let mut events = Vec::new();
+        for (expanded, (_, cursor)) in expansion.cursors.iter_mut().zip(&cursors) {
+            if expanded.steps.pop().is_none() {
+                continue;
+            }
+            let (position, anchor) = expanded.placed();
+            let sticky_column = if expanded.steps.is_empty() {
+                expanded.original.sticky_column
+            } else {
+                0
+            };
+            events.push(move_to(
+                expanded.id,
+                cursor,
+                position,
+                anchor,
+                sticky_column,
+            ));
+        }
+        self.selection_expansion = Some(expansion);
+        self.apply_cursor_moves(events, "Shrink selection");
+    }
+
+    /// Grow each cursor of the current expansion by one scope
+    fn expand_selection_step(&mut self) {
+        let cursors = self.cursor_snapshot();
+        let context_bytes = self.config.editor.highlight_context_bytes;
+        let Some(mut expansion) = self.selection_expansion.take() else {
+            return;
+        };
+        let Some(state) = self.buffers.get_mut(&expansion.buffer_id) else {
+            return;
+        };
+
+        let currents: Vec<Range<usize>> = expansion.cursors.iter().map(|c| c.current()).collect();
+        let len = state.buffer.len();
+        let window_start = currents
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .unwrap_or(0)
+            .saturating_sub(SCOPE_WINDOW);
+        let window_end = currents
+            .iter()
+            .map(|range| range.end)
+            .max()
+            .unwrap_or(0)
+            .saturating_add(SCOPE_WINDOW)
+            .min(len);
+        let text = match state
+            .buffer
+            .get_text_range_mut(window_start, window_end - window_start)
+        {
+            Ok(text) => text,
+            Err(e) => {
+                tracing::warn!("Failed to read text to expand the selection: {}", e);
+                self.selection_expansion = Some(expansion);
+                return;
+            }
+        };
+        let inert = state.highlighter.string_and_comment_ranges(
+            &state.buffer,
+            window_start,
+            window_end,
+            &self.theme,
+            context_bytes,
+        );
+
+        let mut events = Vec::new();
+        for (index, (expanded, current)) in expansion.cursors.iter_mut().zip(currents).enumerate() {
+            let lsp_scope = expansion
+                .lsp_scopes
+                .get(index)
+                .and_then(|scopes| smallest_enclosing(scopes.iter().cloned(), &current));
+            let next = lsp_scope.or_else(|| {
+                let mut scopes = text_scopes(&text, window_start, &current, &inert);
+                scopes.extend(enclosing_block(state, &current));
+                scopes.push(0..len);
+                smallest_enclosing(scopes, &current)
+            });
+            let (Some(next), Some((_, cursor))) = (next, cursors.get(index)) else {
+                continue;
+            };
+            events.push(move_to(expanded.id, cursor, next.end, Some(next.start), 0));
+            expanded.steps.push(next);
+        }
+        self.selection_expansion = Some(expansion);
+        self.apply_cursor_moves(events, "Expand selection");
+    }
+
+    /// Ask the language server for the scopes around the cursors, when it
+    /// offers them. Returns whether a request was sent.
+    fn request_selection_ranges(
+        &mut self,
+        buffer_id: BufferId,
+        cursors: &[(CursorId, Cursor)],
+    ) -> bool {
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            return false;
+        };
+        let supported = self
+            .lsp
+            .as_ref()
+            .is_some_and(|lsp| lsp.selection_ranges_supported(&state.language));
+        if !supported {
+            return false;
+        }
+        let positions: Vec<lsp_types::Position> = cursors
+            .iter()
+            .map(|(_, cursor)| {
+                let start = cursor
+                    .selection_range()
+                    .map_or(cursor.position, |range| range.start);
+                let (line, character) = state.buffer.position_to_lsp_position(start);
+                lsp_types::Position::new(line as u32, character as u32)
+            })
+            .collect();
+
+        let request_id = self.next_lsp_request_id;
+        let sent = self
+            .with_lsp_for_buffer(buffer_id, |handle, uri, _language| {
+                handle
+                    .selection_ranges(request_id, uri.clone(), positions)
+                    .is_ok()
+            })
+            .unwrap_or(false);
+        if sent {
+            self.next_lsp_request_id += 1;
+            if let Some(expansion) = self.selection_expansion.as_mut() {
+                expansion.pending = Some((request_id, 1));
+            }
+        }
+        sent
+    }
+
+    /// Keep the scopes the language server sent and make the expansions
+    /// asked for while waiting for them
+    pub(crate) fn handle_selection_ranges_response(
+        &mut self,
+        request_id: u64,
+        ranges: Vec<lsp_types::SelectionRange>,
+    ) {
+        let Some(expansion) = self.selection_expansion.as_mut() else {
+            return;
+        };
+        let steps = match expansion.pending {
+            Some((pending_id, steps)) if pending_id == request_id => steps,
+            _ => return,
+        };
+        expansion.pending = None;
+        if let Some(state) = self.buffers.get(&expansion.buffer_id) {
+            expansion.lsp_scopes = ranges
+                .iter()
+                .map(|range| selection_range_chain(&state.buffer, range))
+                .collect();
+        }
+
+        let buffer_id = self.active_buffer();
+        let version = self.active_state().buffer.version();
+        let cursors = self.cursor_snapshot();
+        let current = self
+            .selection_expansion
+            .as_ref()
+            .is_some_and(|expansion| expansion.is_current(buffer_id, version, &cursors));
+        if current {
+            for _ in 0..steps {
+                self.expand_selection_step();
+            }
+        }
+    }
+}
+
+/// Event moving a cursor from where `cursor` is to `position`, selecting
+/// from `anchor`
+fn move_to(
+    cursor_id: CursorId,
+    cursor: &Cursor,
+    position: usize,
+    anchor: Option<usize>,
+    sticky_column: usize,
+) -> Event {
+    Event::MoveCursor {
+        cursor_id,
+        old_position: cursor.position,
+        new_position: position,
+        old_anchor: cursor.anchor,
+        new_anchor: anchor,
+        old_sticky_column: cursor.sticky_column,
+        new_sticky_column: sticky_column,
+    }
+}
+
+/// Byte ranges of a chain of selection ranges, innermost first
+fn selection_range_chain(buffer: &Buffer, range: &lsp_types::SelectionRange) -> Vec<Range<usize>> {
+    let mut chain = Vec::new();
+    let mut next = Some(range);
+    while let Some(range) = next {
+        let start = buffer.lsp_position_to_byte(
+            range.range.start.line as usize,
+            range.range.start.character as usize,
+        );
+        let end = buffer.lsp_position_to_byte(
+            range.range.end.line as usize,
+            range.range.end.character as usize,
+        );
+        chain.push(start..end);
+        next = range.parent.as_deref();
+    }
+    chain
+}
+
+/// The smallest of `scopes` that takes in all of `range` and more
+fn smallest_enclosing(
+    scopes: impl IntoIterator<Item = Range<usize>>,
+    range: &Range<usize>,
+) -> Option<Range<usize>> {
+    scopes
+        .into_iter()
+        .filter(|scope| scope.start <= range.start && scope.end >= range.end)
+        .filter(|scope| scope.len() > range.len())
+        .min_by_key(|scope| scope.len())
+}
+
+/// Scopes found in the text around `range`: the word it is in, the string
+/// or comment around it with and without its quotes, and the innermost
+/// brackets around it with and without the brackets. `text` starts at
+/// `offset` in the buffer, and `inert` holds the ranges highlighted as
+/// strings or comments.
+fn text_scopes(
+    text: &[u8],
+    offset: usize,
+    range: &Range<usize>,
+    inert: &[Range<usize>],
+) -> Vec<Range<usize>> {
+    let mut scopes = Vec::new();
+    let is_word_byte = |b: u8| is_word_char(b) || b >= 0x80;
+    let (start, end) = (range.start - offset, range.end - offset);
+
+    // The word, when the range is within one
+    if text[start..end].iter().all(|&b| is_word_byte(b)) {
+        let mut word_start = start;
+        while word_start > 0 && is_word_byte(text[word_start - 1]) {
+            word_start -= 1;
+        }
+        let mut word_end = end;
+        while word_end < text.len() && is_word_byte(text[word_end]) {
+            word_end += 1;
+        }
+        if word_start < word_end {
+            scopes.push(offset + word_start..offset + word_end);
+        }
+    }
+
+    // The string or comment around the range
+    let home = inert
+        .iter()
+        .position(|scope| scope.start <= range.start && scope.end >= range.end);
+    if let Some(scope) = home.map(|index| inert[index].clone()) {
+        let first = text.get(scope.start.wrapping_sub(offset)).copied();
+        let last = text.get(scope.end.wrapping_sub(offset + 1)).copied();
+        if scope.len() >= 2 && first == last && matches!(first, Some(b'"' | b'\'' | b'`')) {
+            scopes.push(scope.start + 1..scope.end - 1);
+        }
+        scopes.push(scope);
+    }
+
+    // Brackets pair within the string or comment holding the range, or
+    // within code once all of one is selected
+    let home = home.filter(|&index| inert[index] != *range);
+    let region = |pos: usize| {
+        let index = inert.partition_point(|scope| scope.end <= offset + pos);
+        inert
+            .get(index)
+            .filter(|scope| scope.start <= offset + pos)
+            .map(|_| index)
+    };
+    if let Some((open, close)) = enclosing_brackets(text, start, end, |pos| region(pos) == home) {
+        scopes.push(offset + open + 1..offset + close);
+        scopes.push(offset + open..offset + close + 1);
+    }
+    scopes
+}
+
+/// Positions of the innermost `()`, `[]` or `{}` pair around `start..end`
+/// in `text`, counting only brackets for which `counts` holds
+fn enclosing_brackets(
+    text: &[u8],
+    start: usize,
+    end: usize,
+    counts: impl Fn(usize) -> bool,
+) -> Option<(usize, usize)> {
+    let mut innermost: Option<(usize, usize)> = None;
+    for (open, close) in [(b'(', b')'), (b'[', b']'), (b'{', b'}')] {
+        let mut depth = 0usize;
+        for i in (0..start).rev() {
+            if (text[i] != open && text[i] != close) || !counts(i) {
+                continue;
+            }
+            if text[i] == close {
+                depth += 1;
+                continue;
+            }
+            if depth > 0 {
+                depth -= 1;
+                continue;
+            }
+            // An unclosed opener; its closer has to come after the range
+            let Some(j) = matching_close(text, i, open, close, &counts) else {
+                break;
+            };
+            if j >= end {
+                if innermost.is_none_or(|(o, _)| i > o) {
+                    innermost = Some((i, j));
+                }
+                break;
+            }
+        }
+    }
+    innermost
+}
+
+/// Position of the bracket closing the one at `open_pos`
+fn matching_close(
+    text: &[u8],
+    open_pos: usize,
+    open: u8,
+    close: u8,
+    counts: &impl Fn(usize) -> bool,
+) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, &b) in text.iter().enumerate().skip(open_pos + 1) {
+        if (b != open && b != close) || !counts(i) {
+            continue;
+        }
+        if b == open {
+            depth += 1;
+        } else if depth == 0 {
+            return Some(i);
+        } else {
+            depth -= 1;
+        }
+    }
+    None
+}
+
+/// The smallest block around `range`, as whole lines: a folding range from
+/// the language server when it sent any, or else an indentation block
+fn enclosing_block(state: &EditorState, range: &Range<usize>) -> Option<Range<usize>> {
+    let buffer = &state.buffer;
+    if !state.folding_ranges.is_empty() {
+        let blocks = state.folding_ranges.iter().filter_map(|fold| {
+            let start = buffer.line_start_offset(fold.start_line as usize)?;
+            let end = buffer
+                .line_start_offset(fold.end_line as usize + 1)
+                .unwrap_or_else(|| buffer.len());
+            Some(start..end)
+        });
+        return smallest_enclosing(blocks, range);
+    }
+
+    let tab_size = state.buffer_settings.tab_size;
+    let max_scan_bytes = crate::config::INDENT_FOLD_MAX_SCAN_LINES * buffer.estimated_line_length();
+    let mut target = range.start;
+    for _ in 0..MAX_BLOCK_DEPTH {
+        let (header, _, end) = indent_folding::find_fold_range_at_byte(
+            buffer,
+            target,
+            tab_size,
+            max_scan_bytes,
+            crate::config::INDENT_FOLD_MAX_UPWARD_SCAN,
+        )?;
+        let block = header..end;
+        if smallest_enclosing([block.clone()], range).is_some() {
+            return Some(block);
+        }
+        if header == 0 {
+            return None;
+        }
+        // Look for the block around this one, starting from the line above
+        target = header - 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Next scope of `text` around `range`, leaving out blocks
+    fn next_scope(text: &str, range: Range<usize>) -> Option<Range<usize>> {
+        let mut scopes = text_scopes(text.as_bytes(), 0, &range, &[]);
+        scopes.push(0..text.len());
+        smallest_enclosing(scopes, &range)
+    }
+
+    #[test]
+    fn test_expands_word_contents_then_brackets() {
+        let text = "call(first, [second, third])";
+        let word = next_scope(text, 14..14).unwrap();
+        assert_eq!(&text[word.clone()], "second");
+        let contents = next_scope(text, word).unwrap();
+        assert_eq!(&text[contents.clone()], "second, third");
+        let pair = next_scope(text, contents).unwrap();
+        assert_eq!(&text[pair.clone()], "[second, third]");
+        let outer = next_scope(text, pair).unwrap();
+        assert_eq!(&text[outer.clone()], "first, [second, third]");
+        assert_eq!(next_scope(text, outer), Some(4..28));
+        assert_eq!(next_scope(text, 4..28), Some(0..28));
+        assert_eq!(next_scope(text, 0..28), None);
+    }
+
+    #[test]
+    fn test_brackets_skip_strings() {
+        let text = r#"f(")", x)"#;
+        // The ")" in the string, from 2 to 5, doesn't close the call
+        let inert = [2..5];
+        let scopes = text_scopes(text.as_bytes(), 0, &(7..8), &inert);
+        assert!(scopes.contains(&(2..8)));
+        assert!(scopes.contains(&(1..9)));
+
+        // Inside the string, its quotes come before the call's brackets
+        let scopes = text_scopes(text.as_bytes(), 0, &(3..4), &inert);
+        assert_eq!(smallest_enclosing(scopes, &(3..4)), Some(2..5));
+        let scopes = text_scopes(text.as_bytes(), 0, &(2..5), &inert);
+        assert_eq!(smallest_enclosing(scopes, &(2..5)), Some(2..8));
+    }
+}
@@ -63,8 +63,10 @@ impl Editor {
 
     /// Save the settings from the modal to config
     pub fn save_settings(&mut self) {
-        let old_theme = self.config.theme.clone();
+        let old_theme = self.effective_theme_name();
+        let old_syntax_styles = self.config.syntax_styles.clone();
         let old_locale = self.config.locale.clone();
+        let old_highlight_missing = self.config.highlight_missing_translations;
         let old_plugins = self.config.plugins.clone();
 
         // Get target layer, new config, and the actual changes made
@@ -99,33 +101,24 @@ impl Editor {
         self.user_config_raw = Config::read_user_config_raw(&self.working_dir);
 
         // Apply runtime changes
-        if old_theme != self.config.theme {
-            if let Some(theme) = self.theme_registry.get_cloned(&self.config.theme) {
+        let new_theme = self.effective_theme_name();
+        if old_theme != new_theme {
+            if let Some(theme) = self.load_theme(&new_theme) {
                 self.theme = theme;
-                tracing::info!("Theme changed to '{}'", self.config.theme.0);
+                tracing::info!("Theme changed to '{}'", new_theme.0);
             } else {
-                tracing::error!("Theme '{}' not found", self.config.theme.0);
-                self.set_status_message(format!("Theme '{}' not found", self.config.theme.0));
+                tracing::error!("Theme '{}' not found", new_theme.0);
+                self.set_status_message(format!("Theme '{}' not found", new_theme.0));
             }
+        } else if old_syntax_styles != self.config.syntax_styles {
+            self.theme.set_syntax_styles(&self.config.syntax_styles);
         }
 
         // Apply locale change at runtime
-        if old_locale != self.config.locale {
-            if let Some(locale) = self.config.locale.as_option() {
-                crate::i18n::set_locale(locale);
-                // Regenerate menus with the new locale
-                self.menus = crate::config::MenuConfig::translated();
-                tracing::info!("Locale changed to '{}'", locale);
-            } else {
-                // Auto-detect from environment
-                crate::i18n::init();
-                self.menus = crate::config::MenuConfig::translated();
-                tracing::info!("Locale reset to auto-detect");
-            }
-            // Refresh command palette commands with new locale
-            if let Ok(mut registry) = self.command_registry.write() {
-                registry.refresh_builtin_commands();
-            }
+        if old_locale != self.config.locale
+            || old_highlight_missing != self.config.highlight_missing_translations
+        {
+            self.apply_config_locale(&old_locale);
         }
 
         // Handle plugin enable/disable changes
@@ -134,6 +127,9 @@ impl Editor {
         // Update keybindings
         self.keybindings = KeybindingResolver::new(&self.config);
 
+        self.terminal_links =
+            crate::services::terminal::LinkMatcher::new(&self.config.terminal.link_patterns);
+
         // Update LSP configs
         if let Some(ref mut lsp) = self.lsp {
             for (language, lsp_config) in &self.config.lsp {
@@ -151,19 +147,12 @@ impl Editor {
 
         // Propagate tab_size/use_tabs/whitespace visibility to all open buffers
         // Each buffer resolves its settings from its language + the new global config
-        for state in self.buffers.values_mut() {
-            let mut whitespace =
-                crate::config::WhitespaceVisibility::from_editor_config(&self.config.editor);
-            if let Some(lang_config) = self.config.languages.get(&state.language) {
-                state.buffer_settings.tab_size =
-                    lang_config.tab_size.unwrap_or(self.config.editor.tab_size);
-                state.buffer_settings.use_tabs = lang_config.use_tabs;
-                whitespace =
-                    whitespace.with_language_tab_override(lang_config.show_whitespace_tabs);
-            } else {
-                state.buffer_settings.tab_size = self.config.editor.tab_size;
+        for (buffer_id, state) in self.buffers.iter_mut() {
+            // Terminal buffers keep their own plain settings
+            if self.terminal_buffers.contains_key(buffer_id) {
+                continue;
             }
-            state.buffer_settings.whitespace = whitespace;
+            super::language_mode::apply_language_settings(&self.config, state);
         }
 
         // Save ONLY the changes to disk (preserves external edits to the config file)
@@ -578,7 +567,7 @@ impl Editor {
     }
 
     /// Apply plugin configuration changes by loading/unloading plugins as needed
-    fn apply_plugin_config_changes(
+    pub(super) fn apply_plugin_config_changes(
         &mut self,
         old_plugins: &std::collections::HashMap<String, crate::config::PluginConfig>,
     ) {
@@ -606,11 +595,15 @@ impl Editor {
                     if let Err(e) = self.plugin_manager.load_plugin(path) {
                         tracing::error!("Failed to load plugin '{}': {}", name, e);
                         self.set_status_message(format!("Failed to load plugin '{}': {}", name, e));
+                        self.plugin_load_errors.insert(name, format!("{:#}", e));
+                    } else {
+                        self.plugin_load_errors.remove(&name);
                     }
                 }
             } else {
                 // Plugin was enabled, now disabled - unload it
                 tracing::info!("Unloading disabled plugin: {}", name);
+                self.plugin_load_errors.remove(&name);
                 if let Err(e) = self.plugin_manager.unload_plugin(&name) {
                     tracing::error!("Failed to unload plugin '{}': {}", name, e);
                     self.set_status_message(format!("Failed to unload plugin '{}': {}", name, e));
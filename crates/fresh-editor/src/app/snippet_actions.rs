@@ -0,0 +1,268 @@
+//! Snippet expansion for the Editor.
+//!
+//! This module contains:
+//! - Inserting a snippet at the cursor, indented like the current line
+//! - The snippet session: Tab and Shift+Tab select the next and previous
+//!   tabstop, mirrored tabstops get one cursor each so they change together
+//! - Expand Snippet, and snippet items for the completion popup
+//!
+//! User snippets come from the config dir (see `services::snippets`). LSP
+//! completion items in snippet format go through the same engine.
+
+use std::ops::Range;
+
+use lsp_types::{CompletionItem, CompletionItemKind, InsertTextFormat};
+use rust_i18n::t;
+
+use crate::input::keybindings::Action;
+use crate::model::buffer::Buffer;
+use crate::model::cursor::Cursor;
+use crate::model::event::Event;
+use crate::primitives::snippet::{expand_snippet, SnippetSession};
+use crate::primitives::word_navigation::find_completion_word_start;
+use crate::services::snippets::Snippet;
+
+use super::Editor;
+
+impl Editor {
+    /// Replace `range` with a snippet and start its tabstop session
+    ///
+    /// Lines after the first continue at the indentation of the cursor line,
+    /// and tabs in the body become the buffer's indentation unit.
+    pub(super) fn insert_snippet(&mut self, range: Range<usize>, body: &str) {
+        let (indent, indent_unit) = {
+            let state = self.active_state();
+            let settings = &state.buffer_settings;
+            let unit = if settings.use_tabs {
+                "\t".to_string()
+            } else {
+                " ".repeat(settings.tab_size)
+            };
+            (line_indent(&state.buffer, range.start), unit)
+        };
+        let expanded = expand_snippet(&body.replace('\t', &indent_unit)).indented(&indent);
+
+        let cursor_id = self.active_cursors().primary_id();
+        let mut events = Vec::new();
+        if !range.is_empty() {
+            let deleted_text = self
+                .active_state_mut()
+                .get_text_range(range.start, range.end);
+            events.push(Event::Delete {
+                range: range.clone(),
+                deleted_text,
+                cursor_id,
+            });
+        }
+        events.push(Event::Insert {
+            position: range.start,
+            text: expanded.text.clone(),
+            cursor_id,
+        });
+        let batch = Event::Batch {
+            events,
+            description: "Insert snippet".to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
+
+        let final_position = range.start + expanded.cursor_offset;
+        let session = SnippetSession::new(&expanded, range.start);
+        let has_tabstops = session.is_some();
+        self.active_state_mut().snippet_session = session;
+        if has_tabstops {
+            self.select_snippet_tabstop();
+        } else {
+            self.set_snippet_cursors(&[final_position..final_position]);
+        }
+    }
+
+    /// Handle an action while a snippet session is active
+    ///
+    /// Tab and Shift+Tab move between tabstops and return true. Escape,
+    /// undo and redo end the session, as does Tab once the cursor left the
+    /// current tabstop (it then indents as usual).
+    pub(super) fn handle_snippet_action(&mut self, action: &Action) -> bool {
+        if self.active_state().snippet_session.is_none() {
+            return false;
+        }
+        match action {
+            Action::InsertTab | Action::DedentSelection => {
+                if !self.cursor_in_snippet_tabstop() {
+                    self.end_snippet_session();
+                    return false;
+                }
+                if let Some(session) = &mut self.active_state_mut().snippet_session {
+                    if matches!(action, Action::InsertTab) {
+                        session.next();
+                    } else if !session.prev() {
+                        return true;
+                    }
+                }
+                self.select_snippet_tabstop();
+                true
+            }
+            Action::RemoveSecondaryCursors | Action::Undo | Action::Redo => {
+                self.end_snippet_session();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Expand the snippet whose prefix is the word before the cursor
+    ///
+    /// When the word only starts some prefixes, those snippets are offered in
+    /// the completion popup instead.
+    pub(super) fn expand_snippet_at_cursor(&mut self) {
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
+        }
+        let (range, typed) = self.completion_word();
+        let language = self.active_state().language.clone();
+        let snippets = self.snippets.matching(&language, &typed);
+
+        if let Some(snippet) = snippets
+            .iter()
+            .find(|snippet| snippet.prefixes.contains(&typed))
+        {
+            self.insert_snippet(range, &snippet.body);
+        } else if snippets.is_empty() {
+            self.set_status_message(t!("status.no_snippet", prefix = typed).to_string());
+        } else {
+            let items = snippets
+                .iter()
+                .filter_map(|snippet| snippet_item(snippet, &typed))
+                .collect();
+            self.show_completion_popup(items);
+        }
+    }
+
+    /// Completion items for the snippets matching the word being typed
+    ///
+    /// Snippets whose prefix is already offered in `existing` are left out.
+    pub(super) fn snippet_completion_items(
+        &mut self,
+        existing: &[CompletionItem],
+    ) -> Vec<CompletionItem> {
+        let (_, typed) = self.completion_word();
+        if typed.is_empty() {
+            return Vec::new();
+        }
+        let language = self.active_state().language.clone();
+        self.snippets
+            .matching(&language, &typed)
+            .iter()
+            .filter_map(|snippet| snippet_item(snippet, &typed))
+            .filter(|item| !existing.iter().any(|other| other.label == item.label))
+            .collect()
+    }
+
+    /// The word being typed before the cursor, with its range
+    fn completion_word(&mut self) -> (Range<usize>, String) {
+        let cursor = self.active_cursors().primary().position;
+        let word_start = find_completion_word_start(&self.active_state().buffer, cursor);
+        let typed = self.active_state_mut().get_text_range(word_start, cursor);
+        (word_start..cursor, typed)
+    }
+
+    /// Select the current tabstop, ending the session at the final one
+    fn select_snippet_tabstop(&mut self) {
+        let Some(session) = &self.active_state().snippet_session else {
+            return;
+        };
+        let ranges = session.current_ranges().to_vec();
+        let at_final = session.is_at_final();
+        self.set_snippet_cursors(&ranges);
+        if at_final {
+            self.end_snippet_session();
+        }
+    }
+
+    fn end_snippet_session(&mut self) {
+        self.active_state_mut().snippet_session = None;
+    }
+
+    /// Whether the primary cursor is on the current tabstop
+    fn cursor_in_snippet_tabstop(&self) -> bool {
+        let position = self.active_cursors().primary().position;
+        self.active_state()
+            .snippet_session
+            .as_ref()
+            .is_some_and(|session| {
+                session
+                    .current_ranges()
+                    .iter()
+                    .any(|range| range.start <= position && position <= range.end)
+            })
+    }
+
+    /// Put a cursor on each range, selecting it; the first one is primary
+    fn set_snippet_cursors(&mut self, ranges: &[Range<usize>]) {
+        let Some(first) = ranges.first() else {
+            return;
+        };
+        let split_id = self.split_manager.active_split();
+        let buffer_id = self.active_buffer();
+        let state = self.buffers.get_mut(&buffer_id).unwrap();
+        let cursors = &mut self.split_view_states.get_mut(&split_id).unwrap().cursors;
+
+        cursors.remove_secondary();
+        let primary = *cursors.primary();
+        let event = Event::MoveCursor {
+            cursor_id: cursors.primary_id(),
+            old_position: primary.position,
+            new_position: first.end,
+            old_anchor: primary.anchor,
+            new_anchor: (!first.is_empty()).then_some(first.start),
+            old_sticky_column: primary.sticky_column,
+            new_sticky_column: 0,
+        };
+        state.apply(cursors, &event);
+
+        for range in &ranges[1..] {
+            cursors.add(if range.is_empty() {
+                Cursor::new(range.start)
+            } else {
+                Cursor::with_selection(range.start, range.end)
+            });
+        }
+    }
+}
+
+/// Completion item for a snippet, labelled with its prefix matching `typed`
+fn snippet_item(snippet: &Snippet, typed: &str) -> Option<CompletionItem> {
+    let typed = typed.to_lowercase();
+    let prefix = snippet
+        .prefixes
+        .iter()
+        .find(|prefix| prefix.to_lowercase().starts_with(&typed))?;
+    Some(CompletionItem {
+        label: prefix.clone(),
+        kind: Some(CompletionItemKind::SNIPPET),
+        detail: Some(
+            snippet
+                .description
+                .clone()
+                .unwrap_or_else(|| snippet.name.clone()),
+        ),
+        insert_text: Some(snippet.body.clone()),
+        insert_text_format: Some(InsertTextFormat::SNIPPET),
+        ..Default::default()
+    })
+}
+
+/// Leading whitespace of the line containing `position`
+fn line_indent(buffer: &Buffer, position: usize) -> String {
+    let line_start = buffer
+        .offset_to_position(position)
+        .and_then(|pos| buffer.line_start_offset(pos.line))
+        .unwrap_or(position);
+    buffer
+        .slice_bytes(line_start..position)
+        .iter()
+        .take_while(|&&b| b == b' ' || b == b'\t')
+        .map(|&b| b as char)
+        .collect()
+}
@@ -11,6 +11,7 @@ use rust_i18n::t;
 
 use crate::model::event::{BufferId, ContainerId, Event, LeafId, SplitDirection, SplitId};
 use crate::view::folding::CollapsedFoldLineRange;
+use crate::view::prompt::PromptType;
 use crate::view::split::SplitViewState;
 
 use super::Editor;
@@ -55,6 +56,13 @@ impl Editor {
                 )>>()
         });
 
+        // The new view keeps the buffer's current wrap setting and jump list
+        let line_wrap = self.is_line_wrap_enabled();
+        let jump_list = self
+            .split_view_states
+            .get(&active_split)
+            .map(|vs| vs.jump_list.clone());
+
         match self
             .split_manager
             .split_active(direction, current_buffer_id, 0.5)
@@ -65,9 +73,12 @@ impl Editor {
                     self.terminal_height,
                     current_buffer_id,
                 );
-                view_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
+                view_state.viewport.line_wrap_enabled = line_wrap;
                 view_state.rulers = self.config.editor.rulers.clone();
                 view_state.show_line_numbers = self.config.editor.line_numbers;
+                if let Some(jump_list) = jump_list {
+                    view_state.jump_list = jump_list;
+                }
 
                 // Copy keyed states from source split for OTHER buffers (not the active one).
                 // The active buffer gets a fresh cursor in the new split.
@@ -115,7 +126,75 @@ impl Editor {
     }
 
     /// Close the active split
+    ///
+    /// If the split holds terminals that are still running and aren't shown
+    /// in any other split, asks for confirmation first; confirming stops them.
     pub fn close_active_split(&mut self) {
+        let terminals = self.running_terminals_only_in_active_split();
+        if terminals.is_empty() {
+            self.close_active_split_now();
+            return;
+        }
+
+        let names = terminals
+            .iter()
+            .map(|&buffer_id| self.get_buffer_display_name(buffer_id))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.start_prompt(
+            t!("split.confirm_close_terminals", names = names).to_string(),
+            PromptType::ConfirmCloseSplit,
+        );
+    }
+
+    /// Close the active split and stop the running terminals that were only
+    /// shown in it
+    pub(crate) fn close_active_split_and_terminals(&mut self) {
+        let terminals = self.running_terminals_only_in_active_split();
+        let closing_split = self.split_manager.active_split();
+        self.close_active_split_now();
+        if self.split_manager.active_split() == closing_split {
+            // The split couldn't be closed; leave its terminals alone
+            return;
+        }
+
+        for buffer_id in terminals {
+            if let Err(e) = self.close_buffer(buffer_id) {
+                tracing::warn!("Failed to close terminal buffer: {}", e);
+            }
+        }
+    }
+
+    /// Terminal buffers in the active split that have a live process and are
+    /// not open in any other split
+    fn running_terminals_only_in_active_split(&self) -> Vec<BufferId> {
+        // Closing the last split fails anyway, so there's nothing to confirm
+        if self.split_manager.root().count_leaves() <= 1 {
+            return Vec::new();
+        }
+        let active_split = self.split_manager.active_split();
+        let Some(view_state) = self.split_view_states.get(&active_split) else {
+            return Vec::new();
+        };
+
+        view_state
+            .open_buffers
+            .iter()
+            .copied()
+            .filter(|buffer_id| {
+                self.get_terminal_id(*buffer_id)
+                    .and_then(|terminal_id| self.terminal_manager.get(terminal_id))
+                    .is_some_and(|handle| handle.is_alive())
+            })
+            .filter(|buffer_id| {
+                !self.split_view_states.iter().any(|(&split_id, vs)| {
+                    split_id != active_split && vs.open_buffers.contains(buffer_id)
+                })
+            })
+            .collect()
+    }
+
+    fn close_active_split_now(&mut self) {
         let closing_split = self.split_manager.active_split();
 
         // Get the tabs from the split we're closing before we close it
@@ -281,19 +360,43 @@ impl Editor {
                 }
             }
         }
+
+        self.adjust_client_view_cursors(current_buffer_id, &adjustments);
     }
 
-    /// Adjust the size of the active split
-    pub fn adjust_split_size(&mut self, delta: f32) {
+    /// Grow the active split by `cells` rows or columns (negative shrinks it)
+    pub fn resize_active_split(&mut self, cells: i32) {
         let active_split = self.split_manager.active_split();
-        if let Some(container) = self.split_manager.parent_container_of(active_split) {
-            self.split_manager.adjust_ratio(container, delta);
+        let Some(editor_area) = self.cached_layout.editor_content_area else {
+            return;
+        };
+        if self
+            .split_manager
+            .resize_leaf(active_split, cells, editor_area)
+        {
+            self.split_layout_changed();
+        } else {
+            self.set_status_message(t!("split.at_limit").to_string());
+        }
+    }
 
-            let percent = (delta * 100.0) as i32;
-            self.set_status_message(t!("split.size_adjusted", percent = percent).to_string());
-            // Resize visible terminals to match new split dimensions
-            self.resize_visible_terminals();
+    /// Give every split an equal share of the screen
+    pub fn equalize_splits(&mut self) {
+        self.split_manager.distribute_splits_evenly();
+        self.split_layout_changed();
+        self.set_status_message(t!("split.equalized").to_string());
+    }
+
+    /// Bring panes in line with new split sizes
+    ///
+    /// A pane that shrank may be scrolled so its cursor is out of view, so
+    /// every viewport re-checks its cursor on the next render.
+    pub(crate) fn split_layout_changed(&mut self) {
+        for view_state in self.split_view_states.values_mut() {
+            view_state.viewport.clear_skip_ensure_visible();
         }
+        // Resize visible terminals to match new split dimensions
+        self.resize_visible_terminals();
     }
 
     /// Toggle maximize state for the active split
@@ -312,6 +415,60 @@ impl Editor {
         }
     }
 
+    /// Link the active split with the next one so scrolling either scrolls
+    /// both, or unlink them
+    pub fn toggle_scroll_link(&mut self) {
+        let linked = self.scroll_linked_splits();
+        if !linked.is_empty() {
+            for split_id in linked {
+                if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
+                    view_state.sync_group = None;
+                }
+            }
+            self.set_status_message(t!("split.scroll_unlinked").to_string());
+            return;
+        }
+
+        let active_split = self.split_manager.active_split();
+        let leaves = self.split_manager.root().leaf_split_ids();
+        let Some(index) = leaves.iter().position(|&id| id == active_split) else {
+            return;
+        };
+        let partner = leaves[(index + 1) % leaves.len()];
+        if partner == active_split {
+            self.set_status_message(t!("split.scroll_link_needs_two").to_string());
+            return;
+        }
+        // A link is named after the split it was made from
+        let group_id = SplitId::from(active_split).0 as u32;
+        for split_id in [active_split, partner] {
+            if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
+                view_state.sync_group = Some(group_id);
+            }
+        }
+        self.set_status_message(t!("split.scroll_linked").to_string());
+    }
+
+    /// Splits scrolling together with the active one, including it; empty
+    /// when it isn't linked to another split that's still open
+    pub(crate) fn scroll_linked_splits(&self) -> Vec<LeafId> {
+        let active_split = self.split_manager.active_split();
+        let Some(group_id) = self
+            .split_view_states
+            .get(&active_split)
+            .and_then(|vs| vs.sync_group)
+        else {
+            return Vec::new();
+        };
+        let splits = self
+            .split_manager
+            .get_splits_in_group(group_id, &self.split_view_states);
+        if splits.len() < 2 {
+            return Vec::new();
+        }
+        splits
+    }
+
     /// Get cached separator areas for testing
     /// Returns (split_id, direction, x, y, length) tuples
     pub fn get_separator_areas(&self) -> &[(ContainerId, SplitDirection, u16, u16, u16)] {
@@ -0,0 +1,107 @@
+//! Values of the status bar segments that don't come from the buffer.
+//!
+//! The `git_branch` and `clock` segments change without any input, so they
+//! are refreshed from the editor tick every few seconds, and only when one of
+//! the `status_bar` groups lists them. The branch is read from `.git/HEAD` of
+//! the working directory (or one of its parents) without running git.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use super::Editor;
+
+/// How often the git branch and the clock are refreshed
+const STATUS_BAR_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+impl Editor {
+    /// Refresh the git branch and clock segments
+    ///
+    /// Returns true when either changed and the status bar needs a redraw.
+    pub fn check_status_bar_refresh(&mut self) -> bool {
+        let now = Instant::now();
+        if self
+            .status_bar_refreshed_at
+            .is_some_and(|at| now.duration_since(at) < STATUS_BAR_REFRESH_INTERVAL)
+        {
+            return false;
+        }
+        self.status_bar_refreshed_at = Some(now);
+
+        let status_bar = &self.config.status_bar;
+        let clock = status_bar
+            .contains("clock")
+            .then(|| crate::i18n::format::format_clock(&chrono::Local::now()));
+        let git_branch = if status_bar.contains("git_branch") {
+            self.read_git_branch()
+        } else {
+            None
+        };
+
+        let changed = clock != self.status_bar_clock || git_branch != self.status_bar_git_branch;
+        self.status_bar_clock = clock;
+        self.status_bar_git_branch = git_branch;
+        changed
+    }
+
+    /// Branch checked out in the repository containing the working directory
+    fn read_git_branch(&self) -> Option<String> {
+        let head_path = self
+            .working_dir
+            .ancestors()
+            .find_map(|dir| self.git_dir(dir))?
+            .join("HEAD");
+        let head = self.filesystem.read_file(&head_path).ok()?;
+        parse_git_head(&String::from_utf8_lossy(&head))
+    }
+
+    /// Git directory of a repository rooted at `dir`
+    ///
+    /// Worktrees and submodules have a `.git` file pointing to it instead.
+    fn git_dir(&self, dir: &Path) -> Option<PathBuf> {
+        let dot_git = dir.join(".git");
+        if self.filesystem.is_dir(&dot_git).unwrap_or(false) {
+            return Some(dot_git);
+        }
+        let contents = self.filesystem.read_file(&dot_git).ok()?;
+        let contents = String::from_utf8_lossy(&contents);
+        let target = contents.trim().strip_prefix("gitdir:")?.trim();
+        Some(dir.join(target))
+    }
+}
+
+/// Branch name from the contents of `.git/HEAD`
+///
+/// A detached HEAD gives the short commit hash.
+fn parse_git_head(head: &str) -> Option<String> {
+    let head = head.trim();
+    if let Some(reference) = head.strip_prefix("ref:") {
+        let reference = reference.trim();
+        let name = reference.strip_prefix("refs/heads/").unwrap_or(reference);
+        return (!name.is_empty()).then(|| name.to_string());
+    }
+    let hash: String = head.chars().take(7).collect();
+    (hash.len() == 7 && hash.chars().all(|c| c.is_ascii_hexdigit())).then_some(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_git_head() {
+        assert_eq!(
+            parse_git_head("ref: refs/heads/main\n").as_deref(),
+            Some("main")
+        );
+        assert_eq!(
+            parse_git_head("ref: refs/heads/feature/status-bar").as_deref(),
+            Some("feature/status-bar")
+        );
+        assert_eq!(
+            parse_git_head("0688802b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f\n").as_deref(),
+            Some("0688802")
+        );
+        assert_eq!(parse_git_head(""), None);
+        assert_eq!(parse_git_head("garbage"), None);
+    }
+}
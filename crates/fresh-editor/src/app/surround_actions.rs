@@ -0,0 +1,333 @@
+//! Surrounding text with delimiters, and changing the ones around it.
+//!
+//! - Surround Selection With… wraps each selection, or the word at a cursor
+//!   without one, in the pair typed in a prompt
+//! - Change Surrounding replaces the innermost quotes or brackets around
+//!   each cursor with the pair typed in a prompt, or removes them when the
+//!   prompt is left empty
+//!
+//! Both work at every cursor and are one undo step.
+
+use std::ops::Range;
+
+use rust_i18n::t;
+
+use super::motion_actions::BRACKET_SYNTAX_WINDOW;
+use super::Editor;
+use crate::model::buffer::Buffer;
+use crate::model::cursor::Cursor;
+use crate::model::event::{CursorId, Event};
+use crate::primitives::surround::{enclosing_quotes, surround_pair};
+use crate::primitives::word_navigation::{find_word_end, find_word_start, is_word_char};
+use crate::view::bracket_highlight_overlay::find_enclosing_bracket;
+use crate::view::prompt::PromptType;
+
+/// Where `pos` ends up after replacing each range with its text (sorted,
+/// not overlapping). Text inserted right at `pos` lands before it when
+/// `after` is set, and after it otherwise.
+fn shift_position(pos: usize, edits: &[(Range<usize>, String)], after: bool) -> usize {
+    let mut shift: isize = 0;
+    for (range, text) in edits {
+        let before = range.end < pos || (range.end == pos && (after || !range.is_empty()));
+        if !before {
+            break;
+        }
+        shift += text.len() as isize - range.len() as isize;
+    }
+    (pos as isize + shift) as usize
+}
+
+/// The word at `pos`, or an empty range there when it isn't on one
+fn word_at(buffer: &Buffer, pos: usize) -> Range<usize> {
+    let start = find_word_start(buffer, pos);
+    let end = find_word_end(buffer, start);
+    let on_word = buffer
+        .slice_bytes(start..(start + 1).min(buffer.len()))
+        .first()
+        .is_some_and(|&b| is_word_char(b) || b >= 0x80);
+    if on_word && start <= pos && pos <= end {
+        start..end
+    } else {
+        pos..pos
+    }
+}
+
+impl Editor {
+    /// Ask for the pair to surround the selections with
+    pub(super) fn start_surround_prompt(&mut self) {
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
+        }
+        self.start_prompt(t!("surround.prompt").to_string(), PromptType::SurroundWith);
+    }
+
+    /// Surround the selections with the pair typed in the prompt
+    pub(super) fn handle_surround_with(&mut self, input: &str) {
+        match surround_pair(input) {
+            Some((open, close)) => self.surround_selections(&open, &close),
+            None => {
+                self.set_status_message(t!("surround.invalid_pair", input = input).to_string());
+            }
+        }
+    }
+
+    /// Wrap every cursor's selection, or the word at a cursor without one,
+    /// in `open` and `close`. Selections stay on the text inside; a cursor
+    /// on no word is left between the two.
+    pub(super) fn surround_selections(&mut self, open: &str, close: &str) {
+        let buffer = &self.active_state().buffer;
+        let targets: Vec<(Cursor, Range<usize>)> = self
+            .active_cursors()
+            .iter()
+            .map(|(_, cursor)| {
+                let range = cursor
+                    .selection_range()
+                    .unwrap_or_else(|| word_at(buffer, cursor.position));
+                (*cursor, range)
+            })
+            .collect();
+        let mut ranges: Vec<&Range<usize>> = targets.iter().map(|(_, range)| range).collect();
+        ranges.sort_by_key(|range| range.start);
+
+        // A range overlapping the one before it is left alone, as is an
+        // empty one touching it, so the pairs can't interleave
+        let mut edits: Vec<(Range<usize>, String)> = Vec::new();
+        let mut wrapped: Vec<Range<usize>> = Vec::new();
+        for range in ranges {
+            if let Some(last) = wrapped.last() {
+                let touching = range.start == last.end && (range.is_empty() || last.is_empty());
+                if range.start < last.end || touching {
+                    continue;
+                }
+            }
+            if range.is_empty() {
+                edits.push((range.start..range.start, format!("{open}{close}")));
+            } else {
+                // Next to the close of the range before, the open goes after it
+                match edits.last_mut() {
+                    Some((last, text)) if last.start == range.start => text.push_str(open),
+                    _ => edits.push((range.start..range.start, open.to_string())),
+                }
+                edits.push((range.end..range.end, close.to_string()));
+            }
+            wrapped.push(range.clone());
+        }
+
+        let mut moves = Vec::new();
+        for (cursor, range) in &targets {
+            let place = |pos: usize| {
+                if range.is_empty() && wrapped.contains(range) {
+                    shift_position(pos, &edits, false) + open.len()
+                } else {
+                    shift_position(pos, &edits, pos != range.end)
+                }
+            };
+            moves.push((place(cursor.position), cursor.anchor.map(place)));
+        }
+        self.apply_surround_edits(&edits, moves, "Surround selection");
+    }
+
+    /// Innermost quotes or brackets around each cursor's selection or
+    /// position, the primary cursor's first. Cursors sharing a pair give it
+    /// once.
+    fn surrounding_pairs(&mut self) -> Vec<(usize, usize)> {
+        let context_bytes = self.config.editor.highlight_context_bytes;
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let cursors = self.active_cursors();
+        let primary_id = cursors.primary_id();
+        let mut ranges: Vec<(CursorId, Range<usize>)> = cursors
+            .iter()
+            .map(|(id, cursor)| {
+                let range = cursor
+                    .selection_range()
+                    .unwrap_or(cursor.position..cursor.position);
+                (id, range)
+            })
+            .collect();
+        ranges.sort_by_key(|(id, _)| *id != primary_id);
+
+        let lowest = ranges.iter().map(|(_, range)| range.start).min();
+        let highest = ranges.iter().map(|(_, range)| range.end).max();
+        let buffer_id = self.active_buffer();
+        let (Some(lowest), Some(highest), Some(state)) =
+            (lowest, highest, self.buffers.get_mut(&buffer_id))
+        else {
+            return Vec::new();
+        };
+        let inert = state.highlighter.string_and_comment_ranges(
+            &state.buffer,
+            lowest.saturating_sub(BRACKET_SYNTAX_WINDOW),
+            (highest + 1 + BRACKET_SYNTAX_WINDOW).min(state.buffer.len()),
+            &self.theme,
+            context_bytes,
+        );
+
+        let mut pairs = Vec::new();
+        for (_, range) in ranges {
+            let brackets = find_enclosing_bracket(&state.buffer, range.start, range.end, &inert);
+            let mut iter = state
+                .buffer
+                .line_iterator(range.start, estimated_line_length);
+            let line_start = iter.current_position();
+            let quotes = iter.next_line().and_then(|(_, line)| {
+                let line = line.trim_end_matches(['\n', '\r']);
+                if range.end > line_start + line.len() {
+                    return None;
+                }
+                let (open, close) = enclosing_quotes(
+                    line.as_bytes(),
+                    range.start - line_start,
+                    range.end - line_start,
+                )?;
+                Some((line_start + open, line_start + close))
+            });
+            let pair = match (brackets, quotes) {
+                (Some(brackets), Some(quotes)) if quotes.0 > brackets.0 => Some(quotes),
+                (Some(brackets), _) => Some(brackets),
+                (None, quotes) => quotes,
+            };
+            if let Some(pair) = pair.filter(|pair| !pairs.contains(pair)) {
+                pairs.push(pair);
+            }
+        }
+        pairs
+    }
+
+    /// Ask for the pair to put in place of the one around the cursor
+    pub(super) fn start_change_surrounding_prompt(&mut self) {
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
+        }
+        let Some(&(open, close)) = self.surrounding_pairs().first() else {
+            self.set_status_message(t!("surround.no_pair").to_string());
+            return;
+        };
+        let state = self.active_state_mut();
+        let open = state.get_text_range(open, open + 1);
+        let close = state.get_text_range(close, close + 1);
+        self.start_prompt(
+            t!("surround.change_prompt", open = open, close = close).to_string(),
+            PromptType::ChangeSurrounding,
+        );
+    }
+
+    /// Replace the pairs around the cursors with the one typed in the
+    /// prompt, or remove them when it was left empty
+    pub(super) fn handle_change_surrounding(&mut self, input: &str) {
+        let replacement = if input.is_empty() {
+            Some((String::new(), String::new()))
+        } else {
+            surround_pair(input)
+        };
+        let Some((open, close)) = replacement else {
+            self.set_status_message(t!("surround.invalid_pair", input = input).to_string());
+            return;
+        };
+        let pairs = self.surrounding_pairs();
+        if pairs.is_empty() {
+            self.set_status_message(t!("surround.no_pair").to_string());
+            return;
+        }
+
+        let mut edits: Vec<(Range<usize>, String)> = pairs
+            .iter()
+            .flat_map(|&(open_pos, close_pos)| {
+                [
+                    (open_pos..open_pos + 1, open.clone()),
+                    (close_pos..close_pos + 1, close.clone()),
+                ]
+            })
+            .collect();
+        edits.sort_by_key(|(range, _)| range.start);
+        edits.dedup_by_key(|(range, _)| range.start);
+
+        let moves = self
+            .active_cursors()
+            .iter()
+            .map(|(_, cursor)| {
+                let place = |pos: usize| shift_position(pos, &edits, false);
+                (place(cursor.position), cursor.anchor.map(place))
+            })
+            .collect();
+        let description = if input.is_empty() {
+            "Remove surrounding"
+        } else {
+            "Change surrounding"
+        };
+        self.apply_surround_edits(&edits, moves, description);
+    }
+
+    /// Replace each range with its text as one undo step, then put the
+    /// cursors, in the order of `active_cursors`, at their new position and
+    /// anchor
+    fn apply_surround_edits(
+        &mut self,
+        edits: &[(Range<usize>, String)],
+        moves: Vec<(usize, Option<usize>)>,
+        description: &str,
+    ) {
+        let mut events = Vec::new();
+        for (range, text) in edits {
+            if !range.is_empty() {
+                events.push(Event::Delete {
+                    range: range.clone(),
+                    deleted_text: self
+                        .active_state_mut()
+                        .get_text_range(range.start, range.end),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+            if !text.is_empty() {
+                events.push(Event::Insert {
+                    position: range.start,
+                    text: text.clone(),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+        }
+
+        for ((cursor_id, cursor), (position, anchor)) in self.active_cursors().iter().zip(moves) {
+            events.push(Event::MoveCursor {
+                cursor_id,
+                old_position: cursor.position,
+                new_position: position,
+                old_anchor: cursor.anchor,
+                new_anchor: anchor,
+                old_sticky_column: cursor.sticky_column,
+                new_sticky_column: 0,
+            });
+        }
+
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description.to_string()) {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_position() {
+        // "(ab)(cd)" from "ab" and "cd" both selected
+        let edits = vec![
+            (0..0, "(".to_string()),
+            (2..2, ")(".to_string()),
+            (4..4, ")".to_string()),
+        ];
+        assert_eq!(shift_position(0, &edits, true), 1);
+        assert_eq!(shift_position(2, &edits, false), 3);
+        assert_eq!(shift_position(2, &edits, true), 5);
+        assert_eq!(shift_position(4, &edits, false), 7);
+
+        // `"ab"` changed to `(ab)`, and a removed pair
+        let edits = vec![(0..1, "(".to_string()), (3..4, ")".to_string())];
+        assert_eq!(shift_position(2, &edits, false), 2);
+        let edits = vec![(0..1, String::new()), (3..4, String::new())];
+        assert_eq!(shift_position(3, &edits, false), 2);
+        assert_eq!(shift_position(4, &edits, false), 2);
+    }
+}
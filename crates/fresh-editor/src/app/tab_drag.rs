@@ -232,6 +232,13 @@ impl Editor {
                     target_idx
                 };
                 let final_idx = adjusted_idx.min(view_state.open_buffers.len());
+                // Pinned tabs stay in the pinned group and unpinned ones after it
+                let pinned_count = view_state.pinned_tab_count();
+                let final_idx = if view_state.is_pinned(buffer_id) {
+                    final_idx.min(pinned_count)
+                } else {
+                    final_idx.max(pinned_count)
+                };
                 view_state.open_buffers.insert(final_idx, buffer_id);
             }
         }
@@ -255,6 +262,7 @@ impl Editor {
         // Remove from source split's tab bar
         if let Some(source_view_state) = self.split_view_states.get_mut(&source_split_id) {
             source_view_state.open_buffers.retain(|&id| id != buffer_id);
+            source_view_state.pinned_buffers.remove(&buffer_id);
 
             // If the source split was showing this buffer, switch to another
             if self.split_manager.get_buffer_id(source_split_id.into()) == Some(buffer_id) {
@@ -270,7 +278,9 @@ impl Editor {
             // Don't add duplicate
             if !target_view_state.open_buffers.contains(&buffer_id) {
                 let idx = insert_idx.unwrap_or(target_view_state.open_buffers.len());
-                let final_idx = idx.min(target_view_state.open_buffers.len());
+                let final_idx = idx
+                    .min(target_view_state.open_buffers.len())
+                    .max(target_view_state.pinned_tab_count());
                 target_view_state.open_buffers.insert(final_idx, buffer_id);
             }
         }
@@ -314,6 +324,7 @@ impl Editor {
             if let Some(source_view_state) = self.split_view_states.get_mut(&source_split_id) {
                 let had = source_view_state.open_buffers.contains(&buffer_id);
                 source_view_state.open_buffers.retain(|&id| id != buffer_id);
+                source_view_state.pinned_buffers.remove(&buffer_id);
 
                 // If the source split was showing this buffer, switch to another
                 if self.split_manager.get_buffer_id(source_split_id.into()) == Some(buffer_id) {
@@ -0,0 +1,239 @@
+//! Running the named tasks from the `tasks` config.
+//!
+//! "Run Task" picks a task and runs its command from the workspace root in a
+//! dedicated `*Task: <name>*` terminal, so output keeps its ANSI colors and
+//! file locations matched by `terminal.link_patterns` can be clicked. Running a
+//! task again replaces its previous panel. When the command exits, its exit
+//! status and duration are appended to the panel and shown in the status bar.
+
+use std::time::{Duration, Instant};
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::model::event::BufferId;
+use crate::services::terminal::TerminalId;
+
+/// A task started from the Run Task picker
+#[derive(Debug, Clone)]
+pub(crate) struct TaskRun {
+    name: String,
+    buffer_id: BufferId,
+    started: Instant,
+    cancelled: bool,
+}
+
+/// Tab name of a task's output panel
+fn task_panel_name(name: &str) -> String {
+    format!("*Task: {}*", name)
+}
+
+/// Duration as "0.4s" or "2m 05s"
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+impl Editor {
+    /// Open the "Run Task" picker listing the configured tasks
+    pub fn start_run_task_prompt(&mut self) {
+        if self.config.tasks.is_empty() {
+            self.set_status_message(t!("terminal.no_tasks").to_string());
+            return;
+        }
+        let mut tasks: Vec<(&String, &String)> = self.config.tasks.iter().collect();
+        tasks.sort();
+
+        let mut suggestions: Vec<crate::input::commands::Suggestion> = tasks
+            .into_iter()
+            .map(|(name, command)| crate::input::commands::Suggestion {
+                text: name.clone(),
+                description: Some(command.clone()),
+                value: Some(name.clone()),
+                disabled: false,
+                keybinding: None,
+                source: None,
+            })
+            .collect();
+        // The last task run comes first
+        if let Some(last) = &self.last_task {
+            if let Some(index) = suggestions.iter().position(|s| &s.text == last) {
+                let suggestion = suggestions.remove(index);
+                suggestions.insert(0, suggestion);
+            }
+        }
+
+        self.prompt = Some(crate::view::prompt::Prompt::with_suggestions(
+            t!("terminal.task_prompt").to_string(),
+            crate::view::prompt::PromptType::RunTask,
+            suggestions,
+        ));
+        if let Some(prompt) = self.prompt.as_mut() {
+            prompt.selected_suggestion = Some(0);
+        }
+    }
+
+    /// Run a configured task in its own terminal panel
+    pub fn run_task(&mut self, name: &str) {
+        let Some(command) = self.config.tasks.get(name).cloned() else {
+            self.set_status_message(t!("terminal.task_not_found", name = name).to_string());
+            return;
+        };
+
+        // Each task keeps a single panel; a new run replaces the last one
+        let panel_name = task_panel_name(name);
+        if let Some(old) = self.find_buffer_by_name(&panel_name) {
+            self.task_runs.retain(|_, run| run.buffer_id != old);
+            if let Err(e) = self.close_buffer(old) {
+                tracing::warn!("Failed to close previous task panel: {}", e);
+            }
+        }
+
+        let (cols, rows) = self.get_terminal_dimensions();
+        if let Some(ref bridge) = self.async_bridge {
+            self.terminal_manager.set_async_bridge(bridge.clone());
+        }
+        let terminal_root = self.dir_context.terminal_dir_for(&self.working_dir);
+        if let Err(e) = self.filesystem.create_dir_all(&terminal_root) {
+            tracing::warn!("Failed to create terminal directory: {}", e);
+        }
+        let predicted_terminal_id = self.terminal_manager.next_terminal_id();
+        let backing_path =
+            terminal_root.join(format!("fresh-terminal-{}.txt", predicted_terminal_id.0));
+        self.terminal_backing_files
+            .insert(predicted_terminal_id, backing_path.clone());
+
+        let terminal_id = match self.terminal_manager.spawn_with_command(
+            cols,
+            rows,
+            Some(self.working_dir.clone()),
+            None,
+            Some(backing_path),
+            Some(command.as_str()),
+        ) {
+            Ok(terminal_id) => terminal_id,
+            Err(e) => {
+                self.terminal_backing_files.remove(&predicted_terminal_id);
+                self.set_status_message(
+                    t!("terminal.failed_to_open", error = e.to_string()).to_string(),
+                );
+                return;
+            }
+        };
+
+        let buffer_id =
+            self.create_terminal_buffer_attached(terminal_id, self.split_manager.active_split());
+        if let Some(metadata) = self.buffer_metadata.get_mut(&buffer_id) {
+            metadata.display_name = panel_name;
+        }
+        self.task_runs.insert(
+            terminal_id,
+            TaskRun {
+                name: name.to_string(),
+                buffer_id,
+                started: Instant::now(),
+                cancelled: false,
+            },
+        );
+        self.last_task = Some(name.to_string());
+
+        self.switch_to_terminal(buffer_id);
+        self.set_status_message(
+            t!("terminal.task_running", name = name, command = command).to_string(),
+        );
+    }
+
+    /// Run the last task again
+    pub fn rerun_last_task(&mut self) {
+        match self.last_task.clone() {
+            Some(name) => self.run_task(&name),
+            None => self.set_status_message(t!("terminal.no_last_task").to_string()),
+        }
+    }
+
+    /// Stop the task shown in the active panel, or the most recently started
+    /// task that is still running
+    pub fn cancel_task(&mut self) {
+        let active = self.active_buffer();
+        let running = |editor: &Self, terminal_id: &TerminalId| {
+            editor
+                .terminal_manager
+                .get(*terminal_id)
+                .is_some_and(|handle| handle.is_alive())
+        };
+        let target = self
+            .task_runs
+            .iter()
+            .filter(|(terminal_id, _)| running(self, terminal_id))
+            .max_by_key(|(_, run)| (run.buffer_id == active, run.started))
+            .map(|(&terminal_id, _)| terminal_id);
+
+        let Some(terminal_id) = target else {
+            self.set_status_message(t!("terminal.no_running_task").to_string());
+            return;
+        };
+        if let Some(handle) = self.terminal_manager.get(terminal_id) {
+            handle.shutdown();
+        }
+        if let Some(run) = self.task_runs.get_mut(&terminal_id) {
+            run.cancelled = true;
+            let name = run.name.clone();
+            self.set_status_message(t!("terminal.task_cancelling", name = name).to_string());
+        }
+    }
+
+    /// Summary of a task whose terminal just exited, or None if the terminal
+    /// was not running a task
+    pub(super) fn finish_task_run(&mut self, terminal_id: TerminalId) -> Option<String> {
+        let run = self.task_runs.remove(&terminal_id)?;
+        let duration = format_duration(run.started.elapsed());
+        let exit_code = self
+            .terminal_manager
+            .get(terminal_id)
+            .and_then(|handle| handle.exit_code());
+
+        let summary = if run.cancelled {
+            t!(
+                "terminal.task_cancelled",
+                name = run.name,
+                duration = duration
+            )
+        } else {
+            match exit_code {
+                Some(0) => t!(
+                    "terminal.task_succeeded",
+                    name = run.name,
+                    duration = duration
+                ),
+                Some(code) => t!(
+                    "terminal.task_failed",
+                    name = run.name,
+                    code = code,
+                    duration = duration
+                ),
+                None => t!(
+                    "terminal.task_finished",
+                    name = run.name,
+                    duration = duration
+                ),
+            }
+        };
+        Some(summary.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_millis(400)), "0.4s");
+        assert_eq!(format_duration(Duration::from_millis(12_340)), "12.3s");
+        assert_eq!(format_duration(Duration::from_secs(125)), "2m 05s");
+    }
+}
@@ -284,6 +284,277 @@ impl Editor {
         }
     }
 
+    /// Open the "Switch Terminal" picker listing every open terminal
+    pub fn start_switch_terminal_prompt(&mut self) {
+        let mut terminals: Vec<(BufferId, TerminalId)> = self
+            .terminal_buffers
+            .iter()
+            .map(|(&buffer_id, &terminal_id)| (buffer_id, terminal_id))
+            .collect();
+        if terminals.is_empty() {
+            self.set_status_message(t!("terminal.none_open").to_string());
+            return;
+        }
+        terminals.sort_by_key(|&(_, terminal_id)| terminal_id.0);
+
+        let active_buffer = self.active_buffer();
+        let current_index = terminals
+            .iter()
+            .position(|&(buffer_id, _)| buffer_id == active_buffer)
+            .unwrap_or(0);
+
+        let suggestions: Vec<crate::input::commands::Suggestion> = terminals
+            .iter()
+            .map(|&(buffer_id, terminal_id)| {
+                let handle = self.terminal_manager.get(terminal_id);
+                let mut description = handle.map(|h| h.shell().to_string()).unwrap_or_default();
+                if let Some(cwd) = handle.and_then(|h| h.cwd()) {
+                    description.push_str(&format!(" — {}", cwd.display()));
+                }
+                if !handle.is_some_and(|h| h.is_alive()) {
+                    description.push_str(&format!(" ({})", t!("terminal.exited_label")));
+                }
+
+                crate::input::commands::Suggestion {
+                    text: self.get_buffer_display_name(buffer_id),
+                    description: Some(description),
+                    value: Some(buffer_id.0.to_string()),
+                    disabled: false,
+                    keybinding: None,
+                    source: None,
+                }
+            })
+            .collect();
+
+        self.prompt = Some(crate::view::prompt::Prompt::with_suggestions(
+            t!("terminal.switch_prompt").to_string(),
+            crate::view::prompt::PromptType::SwitchTerminal,
+            suggestions,
+        ));
+        if let Some(prompt) = self.prompt.as_mut() {
+            prompt.selected_suggestion = Some(current_index);
+        }
+    }
+
+    /// Show a terminal in the active split and resume terminal mode if it is
+    /// still running
+    pub(crate) fn switch_to_terminal(&mut self, buffer_id: BufferId) {
+        let Some(&terminal_id) = self.terminal_buffers.get(&buffer_id) else {
+            return;
+        };
+        self.set_active_buffer(buffer_id);
+
+        if self
+            .terminal_manager
+            .get(terminal_id)
+            .is_some_and(|h| h.is_alive())
+        {
+            self.terminal_mode = true;
+            self.key_context = crate::input::keybindings::KeyContext::Terminal;
+            self.resize_visible_terminals();
+        }
+    }
+
+    /// Prompt for a new title for the active terminal
+    pub fn start_rename_terminal_prompt(&mut self) {
+        let buffer_id = self.active_buffer();
+        let Some(title) = self.terminal_title(buffer_id) else {
+            self.set_status_message(t!("status.not_viewing_terminal").to_string());
+            return;
+        };
+        self.start_prompt_with_initial_text(
+            t!("terminal.rename_prompt").to_string(),
+            crate::view::prompt::PromptType::RenameTerminal { buffer_id },
+            title,
+        );
+    }
+
+    /// Set the title shown in a terminal's tab
+    pub(crate) fn rename_terminal(&mut self, buffer_id: BufferId, title: &str) {
+        let title = title.trim();
+        if title.is_empty() || !self.is_terminal_buffer(buffer_id) {
+            return;
+        }
+        if let Some(metadata) = self.buffer_metadata.get_mut(&buffer_id) {
+            metadata.display_name = format!("*{}*", title);
+        }
+        self.set_status_message(t!("terminal.renamed", title = title).to_string());
+    }
+
+    /// Title of a terminal buffer, without the asterisks around it in the tab
+    pub(crate) fn terminal_title(&self, buffer_id: BufferId) -> Option<String> {
+        if !self.is_terminal_buffer(buffer_id) {
+            return None;
+        }
+        let name = self.buffer_metadata.get(&buffer_id)?.display_name.as_str();
+        Some(
+            name.strip_prefix('*')
+                .and_then(|n| n.strip_suffix('*'))
+                .unwrap_or(name)
+                .to_string(),
+        )
+    }
+
+    /// Send the selection (or the current line when nothing is selected) to
+    /// a terminal and run it
+    pub fn run_selection_in_terminal(&mut self) {
+        if self.is_terminal_buffer(self.active_buffer()) {
+            self.set_status_message(t!("terminal.run_from_terminal").to_string());
+            return;
+        }
+
+        let text = self.selection_or_current_line();
+        if text.trim().is_empty() {
+            self.set_status_message(t!("terminal.nothing_to_run").to_string());
+            return;
+        }
+
+        let Some(buffer_id) = self.run_target_terminal() else {
+            return;
+        };
+        self.send_to_terminal(buffer_id, &text);
+        self.set_status_message(
+            t!(
+                "terminal.sent_selection",
+                name = self.get_buffer_display_name(buffer_id)
+            )
+            .to_string(),
+        );
+    }
+
+    /// Run the active file with its language's `run_command` in a terminal,
+    /// starting from the workspace root
+    pub fn run_file_in_terminal(&mut self) {
+        if self.is_terminal_buffer(self.active_buffer()) {
+            self.set_status_message(t!("terminal.run_from_terminal").to_string());
+            return;
+        }
+
+        let Some(path) = self
+            .active_state()
+            .buffer
+            .file_path()
+            .map(|p| p.to_path_buf())
+        else {
+            self.set_status_message(t!("terminal.run_file_no_file").to_string());
+            return;
+        };
+        let language = self.active_state().language.clone();
+        let Some(template) = self
+            .config
+            .languages
+            .get(&language)
+            .and_then(|lc| lc.run_command.clone())
+        else {
+            self.set_status_message(t!("terminal.no_run_command", language = language).to_string());
+            return;
+        };
+
+        let Some(buffer_id) = self.run_target_terminal() else {
+            return;
+        };
+        let shell = self
+            .get_terminal_id(buffer_id)
+            .and_then(|terminal_id| self.terminal_manager.get(terminal_id))
+            .map(|handle| handle.shell().to_string())
+            .unwrap_or_else(crate::services::terminal::detect_shell);
+        let syntax = ShellSyntax::of(&shell);
+
+        // "$FILE" is accepted too, like in formatter and linter commands
+        let file = syntax.quote(&path.display().to_string());
+        let command = template.replace("{file}", &file).replace("$FILE", &file);
+        let cd = syntax.cd(&self.working_dir.display().to_string());
+        self.send_to_terminal(buffer_id, &cd);
+        self.send_to_terminal(buffer_id, &command);
+        self.switch_to_terminal(buffer_id);
+        self.set_status_message(t!("terminal.running", command = command).to_string());
+    }
+
+    /// Terminal that "Run Selection in Terminal" and "Run File" send to: the
+    /// active terminal, the most recently shown one, or the newest one still
+    /// running. Opens a new terminal if none is running.
+    fn run_target_terminal(&mut self) -> Option<BufferId> {
+        let is_running = |editor: &Self, buffer_id: BufferId| {
+            editor
+                .get_terminal_id(buffer_id)
+                .and_then(|terminal_id| editor.terminal_manager.get(terminal_id))
+                .is_some_and(|handle| handle.is_alive())
+        };
+
+        let active = self.active_buffer();
+        if is_running(self, active) {
+            return Some(active);
+        }
+        if let Some(buffer_id) = self.last_terminal_buffer {
+            if is_running(self, buffer_id) {
+                return Some(buffer_id);
+            }
+        }
+        let newest = self
+            .terminal_buffers
+            .iter()
+            .filter(|(&buffer_id, _)| is_running(self, buffer_id))
+            .max_by_key(|(_, terminal_id)| terminal_id.0)
+            .map(|(&buffer_id, _)| buffer_id);
+        if newest.is_some() {
+            return newest;
+        }
+
+        // Keep the editor buffer in view; the new terminal only gets a tab
+        let previous = self.active_buffer();
+        self.open_terminal();
+        let opened = self.active_buffer();
+        if !self.is_terminal_buffer(opened) {
+            return None;
+        }
+        self.set_active_buffer(previous);
+        Some(opened)
+    }
+
+    /// Write text followed by Enter to a terminal's PTY. Multi-line text is
+    /// sent as a bracketed paste when the shell supports it, so it runs as
+    /// one block instead of line by line.
+    fn send_to_terminal(&self, buffer_id: BufferId, text: &str) {
+        let Some(handle) = self
+            .get_terminal_id(buffer_id)
+            .and_then(|terminal_id| self.terminal_manager.get(terminal_id))
+        else {
+            return;
+        };
+
+        let text = text.replace("\r\n", "\n");
+        let text = text.trim_end_matches('\n');
+        let bracketed = text.contains('\n')
+            && handle
+                .state
+                .lock()
+                .is_ok_and(|state| state.uses_bracketed_paste());
+
+        let mut data = Vec::new();
+        if bracketed {
+            data.extend_from_slice(b"\x1b[200~");
+            data.extend_from_slice(text.as_bytes());
+            data.extend_from_slice(b"\x1b[201~");
+        } else {
+            data.extend_from_slice(text.replace('\n', "\r").as_bytes());
+        }
+        data.push(b'\r');
+        handle.write(&data);
+    }
+
+    /// Selected text of the active buffer, or the line under the cursor
+    fn selection_or_current_line(&mut self) -> String {
+        let cursor = self.active_cursors().primary().clone();
+        let state = self.active_state_mut();
+        if let Some(range) = cursor.selection_range() {
+            return state.get_text_range(range.start, range.end);
+        }
+        let mut iter = state.buffer.line_iterator(cursor.position, 80);
+        iter.next_line()
+            .map(|(_, content)| content.trim_end_matches(['\r', '\n']).to_string())
+            .unwrap_or_default()
+    }
+
     /// Check if a buffer is a terminal buffer
     pub fn is_terminal_buffer(&self, buffer_id: BufferId) -> bool {
         self.terminal_buffers.contains_key(&buffer_id)
@@ -753,6 +1024,16 @@ impl Editor {
                             content.push(state.get_line(row));
                         }
 
+                        // Underline file locations and hyperlinks in the visible rows
+                        for line in &mut content {
+                            for link in self.terminal_links.find_links(line) {
+                                let end = link.end.min(line.len());
+                                for cell in &mut line[link.start..end] {
+                                    cell.underline = true;
+                                }
+                            }
+                        }
+
                         // Clear the content area first
                         frame.render_widget(ratatui::widgets::Clear, *content_rect);
 
@@ -954,3 +1235,80 @@ fn encode_x10_mouse(
 
     Some(vec![0x1b, b'[', b'M', cb, cx, cy])
 }
+
+/// Quoting rules of the shell running in a terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShellSyntax {
+    Posix,
+    PowerShell,
+    Cmd,
+}
+
+impl ShellSyntax {
+    /// Syntax of the shell at `shell` (a path or program name)
+    fn of(shell: &str) -> Self {
+        let name = shell.to_lowercase();
+        if name.contains("powershell") || name.contains("pwsh") {
+            Self::PowerShell
+        } else if name.ends_with("cmd.exe") || name.ends_with("cmd") {
+            Self::Cmd
+        } else {
+            Self::Posix
+        }
+    }
+
+    /// Quote a path if it contains anything but plain path characters
+    fn quote(self, path: &str) -> String {
+        if !path.is_empty()
+            && path
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '/' | '\\' | '.' | '_' | '-' | ':'))
+        {
+            return path.to_string();
+        }
+        match self {
+            Self::Posix => format!("'{}'", path.replace('\'', "'\\''")),
+            Self::PowerShell => format!("'{}'", path.replace('\'', "''")),
+            // Windows paths can't contain double quotes
+            Self::Cmd => format!("\"{}\"", path),
+        }
+    }
+
+    /// Command changing to `dir`, across drives for cmd.exe
+    fn cd(self, dir: &str) -> String {
+        match self {
+            Self::Cmd => format!("cd /d {}", self.quote(dir)),
+            _ => format!("cd {}", self.quote(dir)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_syntax_quoting() {
+        assert_eq!(ShellSyntax::of("/bin/bash"), ShellSyntax::Posix);
+        assert_eq!(ShellSyntax::of("pwsh.exe"), ShellSyntax::PowerShell);
+        assert_eq!(
+            ShellSyntax::of(r"C:\Windows\System32\cmd.exe"),
+            ShellSyntax::Cmd
+        );
+
+        assert_eq!(ShellSyntax::Posix.quote("/src/main.py"), "/src/main.py");
+        assert_eq!(
+            ShellSyntax::Posix.quote("/my dir/it's.py"),
+            r"'/my dir/it'\''s.py'"
+        );
+        assert_eq!(
+            ShellSyntax::PowerShell.quote(r"C:\my dir\it's.py"),
+            r"'C:\my dir\it''s.py'"
+        );
+        assert_eq!(
+            ShellSyntax::Cmd.quote(r"C:\my dir\it's.py"),
+            r#""C:\my dir\it's.py""#
+        );
+        assert_eq!(ShellSyntax::Cmd.cd(r"D:\work"), r"cd /d D:\work");
+    }
+}
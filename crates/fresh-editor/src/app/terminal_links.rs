@@ -0,0 +1,180 @@
+//! Opening file locations and hyperlinks found in terminal output.
+//!
+//! Links are found by [`crate::services::terminal::LinkMatcher`]. Clicking a
+//! link in a live terminal opens it; in scrollback mode (or any other buffer)
+//! "Open Link Under Cursor" does the same for the link at the cursor, trying
+//! the URLs and paths of [`crate::primitives::text_links`] first outside
+//! terminals. File
+//! locations open in an editor split next to the terminal, with relative paths
+//! resolved against the terminal's working directory.
+
+use super::*;
+use crate::services::terminal::LinkTarget;
+use rust_i18n::t;
+use std::path::{Path, PathBuf};
+
+impl Editor {
+    /// Open the link at a cell of a live terminal.
+    /// Returns false if there is no link there.
+    pub(super) fn open_terminal_link_at(
+        &mut self,
+        buffer_id: BufferId,
+        term_col: u16,
+        term_row: u16,
+    ) -> bool {
+        let Some(handle) = self
+            .get_terminal_id(buffer_id)
+            .and_then(|terminal_id| self.terminal_manager.get(terminal_id))
+        else {
+            return false;
+        };
+        let cells = match handle.state.lock() {
+            Ok(state) => state.get_line(term_row),
+            Err(_) => return false,
+        };
+        let Some(link) = self
+            .terminal_links
+            .find_links(&cells)
+            .into_iter()
+            .find(|link| link.contains(term_col as usize))
+        else {
+            return false;
+        };
+
+        let base_dir = self.link_base_dir(buffer_id);
+        self.open_link(link.target, &base_dir);
+        true
+    }
+
+    /// Open the file location or hyperlink under the cursor
+    pub fn open_link_under_cursor(&mut self) {
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        if !self.is_terminal_buffer(buffer_id) {
+            if let Some((_, target)) = self.buffer_link_at(buffer_id, position) {
+                self.open_buffer_link(buffer_id, target);
+                return;
+            }
+        }
+        let line = {
+            let state = self.active_state_mut();
+            let mut iter = state.buffer.line_iterator(position, 80);
+            iter.next_line()
+        };
+        let Some((line_start, content)) = line else {
+            self.set_status_message(t!("terminal.no_link").to_string());
+            return;
+        };
+
+        let column = content
+            .get(..position.saturating_sub(line_start))
+            .map(|before| before.chars().count())
+            .unwrap_or(0);
+        let Some(link) = self
+            .terminal_links
+            .find_in_text(content.trim_end_matches(['\r', '\n']))
+            .into_iter()
+            .find(|link| link.contains(column))
+        else {
+            self.set_status_message(t!("terminal.no_link").to_string());
+            return;
+        };
+
+        let base_dir = self.link_base_dir(buffer_id);
+        self.open_link(link.target, &base_dir);
+    }
+
+    /// Directory relative link paths are resolved against: the terminal's
+    /// working directory, or the workspace root for other buffers
+    pub(super) fn link_base_dir(&self, buffer_id: BufferId) -> PathBuf {
+        self.get_terminal_id(buffer_id)
+            .and_then(|terminal_id| self.terminal_manager.get(terminal_id))
+            .and_then(|handle| handle.cwd())
+            .unwrap_or_else(|| self.working_dir.clone())
+    }
+
+    pub(super) fn open_link(&mut self, target: LinkTarget, base_dir: &Path) {
+        match target {
+            LinkTarget::Uri(uri) => self.open_url(&uri),
+            LinkTarget::File { path, line, column } => {
+                let path = match path.strip_prefix("~/") {
+                    Some(rest) => match self.filesystem.home_dir() {
+                        Ok(home) => home.join(rest),
+                        Err(_) => PathBuf::from(&path),
+                    },
+                    None => PathBuf::from(&path),
+                };
+                let path = if path.is_relative() {
+                    base_dir.join(path)
+                } else {
+                    path
+                };
+                if !self.filesystem.exists(&path) {
+                    self.set_status_message(
+                        t!("terminal.link_not_found", path = path.display().to_string())
+                            .to_string(),
+                    );
+                    return;
+                }
+
+                self.focus_editor_split_for_link();
+                if let Err(e) = self.open_file(&path) {
+                    self.set_status_message(
+                        t!("file.error_opening", error = e.to_string()).to_string(),
+                    );
+                    return;
+                }
+                if let Some(line) = line {
+                    self.goto_line_col(line, column);
+                }
+            }
+        }
+    }
+
+    /// Open a URL in the system browser, or record it in editors built for
+    /// tests
+    pub(super) fn open_url(&mut self, url: &str) {
+        if let Some(opened_urls) = &mut self.opened_urls {
+            opened_urls.push(url.to_string());
+            self.set_status_message(format!("Opening: {}", url));
+            return;
+        }
+        #[cfg(feature = "runtime")]
+        match open::that(url) {
+            Ok(()) => self.set_status_message(format!("Opening: {}", url)),
+            Err(e) => self.set_status_message(format!("Failed to open URL: {}", e)),
+        }
+    }
+
+    /// URLs opened from links so far (only recorded in editors built for tests)
+    #[doc(hidden)]
+    pub fn opened_urls_for_test(&self) -> &[String] {
+        self.opened_urls.as_deref().unwrap_or_default()
+    }
+
+    /// Make sure files opened from a terminal don't replace it: focus another
+    /// split that isn't showing a terminal, or split the terminal's pane
+    fn focus_editor_split_for_link(&mut self) {
+        if !self.is_terminal_buffer(self.active_buffer()) {
+            return;
+        }
+
+        let active_split = self.split_manager.active_split();
+        let editor_split = self
+            .split_manager
+            .leaf_split_ids()
+            .into_iter()
+            .filter(|&split_id| split_id != active_split)
+            .find_map(|split_id| {
+                self.split_manager
+                    .buffer_for_split(split_id)
+                    .filter(|&buffer_id| !self.is_terminal_buffer(buffer_id))
+                    .map(|buffer_id| (split_id, buffer_id))
+            });
+
+        match editor_split {
+            Some((split_id, buffer_id)) => self.focus_split(split_id, buffer_id),
+            None => self.split_pane_vertical(),
+        }
+    }
+}
@@ -5,7 +5,8 @@
 //!
 //! When in alternate screen mode, mouse events that fall within the terminal's content
 //! area are converted to terminal escape sequences and sent to the PTY, allowing
-//! full-screen terminal programs to receive and handle mouse input.
+//! full-screen terminal programs to receive and handle mouse input. Otherwise a left
+//! click on a file location or hyperlink in a live terminal opens it.
 
 use super::*;
 use crate::input::handler::{TerminalMouseButton, TerminalMouseEventKind};
@@ -39,6 +40,34 @@ impl Editor {
         Some(self.forward_mouse_to_terminal(col, row, content_rect, mouse_event))
     }
 
+    /// Open the file location or hyperlink under a left click in a live terminal.
+    /// Returns true if a link was clicked.
+    pub(super) fn try_open_terminal_link(
+        &mut self,
+        col: u16,
+        row: u16,
+        mouse_event: MouseEvent,
+    ) -> bool {
+        if mouse_event.kind != MouseEventKind::Down(MouseButton::Left) {
+            return false;
+        }
+        let Some((buffer_id, content_rect)) = self.get_terminal_content_area_at_position(col, row)
+        else {
+            return false;
+        };
+        // Outside terminal mode the active terminal shows its scrollback buffer,
+        // where "Open Link Under Cursor" applies instead
+        if buffer_id == self.active_buffer() && !self.terminal_mode {
+            return false;
+        }
+
+        self.open_terminal_link_at(
+            buffer_id,
+            col.saturating_sub(content_rect.x),
+            row.saturating_sub(content_rect.y),
+        )
+    }
+
     /// Get the terminal buffer and its content area if the mouse position is over a terminal buffer.
     /// Returns the buffer ID and content rect if found.
     fn get_terminal_content_area_at_position(
@@ -112,34 +112,85 @@ impl Editor {
         self.set_status_message(status.to_string());
     }
 
-    /// Reset buffer settings (tab_size, use_tabs, whitespace visibility) to config defaults
-    pub fn reset_buffer_settings(&mut self) {
-        use crate::config::WhitespaceVisibility;
+    /// Toggle line wrap for the active buffer, in every split showing it.
+    /// New buffers keep using `editor.line_wrap` and per-language overrides.
+    pub fn toggle_line_wrap(&mut self) {
         let buffer_id = self.active_buffer();
-
-        // Determine settings from config using buffer's stored language
-        let mut whitespace = WhitespaceVisibility::from_editor_config(&self.config.editor);
-        let (tab_size, use_tabs) = if let Some(state) = self.buffers.get(&buffer_id) {
-            let language = &state.language;
-            if let Some(lang_config) = self.config.languages.get(language) {
-                whitespace =
-                    whitespace.with_language_tab_override(lang_config.show_whitespace_tabs);
-                (
-                    lang_config.tab_size.unwrap_or(self.config.editor.tab_size),
-                    lang_config.use_tabs,
-                )
-            } else {
-                (self.config.editor.tab_size, false)
+        let enabled = !self.is_line_wrap_enabled();
+        for view_state in self.split_view_states.values_mut() {
+            if let Some(buf_state) = view_state.keyed_states.get_mut(&buffer_id) {
+                buf_state.viewport.line_wrap_enabled = enabled;
+                if enabled {
+                    buf_state.viewport.left_column = 0;
+                }
             }
+        }
+
+        let state = if enabled {
+            t!("view.state_enabled").to_string()
+        } else {
+            t!("view.state_disabled").to_string()
+        };
+        self.set_status_message(t!("view.line_wrap_state", state = state).to_string());
+    }
+
+    /// Toggle indent guide visibility
+    pub fn toggle_indent_guides(&mut self) {
+        self.config.editor.indent_guides = !self.config.editor.indent_guides;
+        let status = if self.config.editor.indent_guides {
+            t!("toggle.indent_guides_shown")
+        } else {
+            t!("toggle.indent_guides_hidden")
+        };
+        self.set_status_message(status.to_string());
+    }
+
+    /// Toggle color swatches before color literals
+    pub fn toggle_color_swatches(&mut self) {
+        self.config.editor.color_swatches = !self.config.editor.color_swatches;
+        let status = if self.config.editor.color_swatches {
+            t!("toggle.color_swatches_shown")
+        } else {
+            t!("toggle.color_swatches_hidden")
+        };
+        self.set_status_message(status.to_string());
+    }
+
+    /// Toggle padding the columns of the active CSV or TSV file so they
+    /// line up. The file itself doesn't change.
+    pub fn toggle_aligned_view(&mut self) {
+        let Some(state) = self.buffers.get_mut(&self.active_buffer()) else {
+            return;
+        };
+        if state.delimiter.is_none() {
+            self.set_status_message(t!("toggle.aligned_view_unavailable").to_string());
+            return;
+        }
+        state.aligned_columns = !state.aligned_columns;
+        let status = if state.aligned_columns {
+            t!("toggle.aligned_view_on")
+        } else {
+            t!("toggle.aligned_view_off")
+        };
+        self.set_status_message(status.to_string());
+    }
+
+    /// Toggle minimap visibility
+    pub fn toggle_minimap(&mut self) {
+        self.config.editor.minimap = !self.config.editor.minimap;
+        let status = if self.config.editor.minimap {
+            t!("toggle.minimap_shown")
         } else {
-            (self.config.editor.tab_size, false)
+            t!("toggle.minimap_hidden")
         };
+        self.set_status_message(status.to_string());
+    }
 
-        // Apply settings to buffer
+    /// Reset buffer settings (tab_size, use_tabs, whitespace visibility) to config defaults
+    pub fn reset_buffer_settings(&mut self) {
+        let buffer_id = self.active_buffer();
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
-            state.buffer_settings.tab_size = tab_size;
-            state.buffer_settings.use_tabs = use_tabs;
-            state.buffer_settings.whitespace = whitespace;
+            super::language_mode::apply_language_settings(&self.config, state);
         }
 
         self.set_status_message(t!("toggle.buffer_settings_reset").to_string());
@@ -281,20 +332,32 @@ impl Editor {
     /// and emits a config_changed event so plugins can update their state accordingly.
     /// Uses the layered config system to properly merge with defaults.
     pub fn reload_config(&mut self) {
-        let old_theme = self.config.theme.clone();
+        let old_theme = self.effective_theme_name();
+        let old_syntax_styles = self.config.syntax_styles.clone();
+        let old_locale = self.config.locale.clone();
+        let old_highlight_missing = self.config.highlight_missing_translations;
         self.config = Config::load_with_layers(&self.dir_context, &self.working_dir);
 
         // Refresh cached raw user config for plugins
         self.user_config_raw = Config::read_user_config_raw(&self.working_dir);
 
         // Apply theme change if needed
-        if old_theme != self.config.theme {
-            if let Some(theme) = self.theme_registry.get_cloned(&self.config.theme) {
+        let new_theme = self.effective_theme_name();
+        if old_theme != new_theme {
+            if let Some(theme) = self.load_theme(&new_theme) {
                 self.theme = theme;
-                tracing::info!("Theme changed to '{}'", self.config.theme.0);
+                tracing::info!("Theme changed to '{}'", new_theme.0);
             } else {
-                tracing::error!("Theme '{}' not found", self.config.theme.0);
+                tracing::error!("Theme '{}' not found", new_theme.0);
             }
+        } else if old_syntax_styles != self.config.syntax_styles {
+            self.theme.set_syntax_styles(&self.config.syntax_styles);
+        }
+
+        if old_locale != self.config.locale
+            || old_highlight_missing != self.config.highlight_missing_translations
+        {
+            self.apply_config_locale(&old_locale);
         }
 
         // Always reload keybindings (complex types don't implement PartialEq)
@@ -303,6 +366,9 @@ impl Editor {
         // Update clipboard configuration
         self.clipboard.apply_config(&self.config.clipboard);
 
+        self.terminal_links =
+            crate::services::terminal::LinkMatcher::new(&self.config.terminal.link_patterns);
+
         // Update LSP configs
         if let Some(ref mut lsp) = self.lsp {
             for (language, lsp_config) in &self.config.lsp {
@@ -329,9 +395,10 @@ impl Editor {
 
         let theme_loader = ThemeLoader::new(self.dir_context.themes_dir());
         self.theme_registry = theme_loader.load_all();
+        self.theme_files_stamp = theme_loader.files_stamp();
 
         // Re-apply current theme if it still exists, otherwise it might have been updated
-        if let Some(theme) = self.theme_registry.get_cloned(&self.config.theme) {
+        if let Some(theme) = self.load_theme(&self.effective_theme_name()) {
             self.theme = theme;
         }
 
@@ -340,10 +407,37 @@ impl Editor {
             self.theme_registry.len()
         );
 
+        if let Some(error) = self.theme_registry.errors().first() {
+            let message = t!("view.theme_error", error = error.to_string()).to_string();
+            self.set_status_message(message);
+        }
+
         // Emit event so plugins know themes changed
         self.emit_event("themes_changed", serde_json::json!({}));
     }
 
+    /// Poll the user themes directory (called from main loop)
+    ///
+    /// Reloads themes when a theme file is added, removed or edited, so theme
+    /// authors see their changes immediately. Returns true if themes were reloaded.
+    pub fn poll_theme_changes(&mut self) -> bool {
+        use crate::view::theme::ThemeLoader;
+
+        let poll_interval =
+            std::time::Duration::from_millis(self.config.editor.auto_revert_poll_interval_ms);
+        if self.time_source.elapsed_since(self.last_theme_poll) < poll_interval {
+            return false;
+        }
+        self.last_theme_poll = self.time_source.now();
+
+        let stamp = ThemeLoader::new(self.dir_context.themes_dir()).files_stamp();
+        if stamp == self.theme_files_stamp {
+            return false;
+        }
+        self.reload_themes();
+        true
+    }
+
     /// Persist a single config change to the user config file.
     ///
     /// Used when toggling settings via menu/command palette so that
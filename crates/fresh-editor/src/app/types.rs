@@ -1,6 +1,7 @@
 use crate::app::file_open::SortMode;
-use crate::input::keybindings::Action;
+use crate::input::keybindings::{Action, KeyContext};
 use crate::model::event::{BufferId, ContainerId, LeafId, SplitDirection};
+use crate::model::marker::MarkerId;
 use crate::services::async_bridge::LspMessageType;
 use ratatui::layout::Rect;
 use rust_i18n::t;
@@ -35,6 +36,10 @@ pub(super) struct SearchState {
     pub wrap_search: bool,
     /// Optional search range (for search in selection)
     pub search_range: Option<Range<usize>>,
+    /// Buffer the matches belong to
+    pub buffer_id: BufferId,
+    /// When to recompute the matches after the buffer was edited
+    pub refresh_at: Option<std::time::Instant>,
 }
 
 /// A bookmark in the editor (position in a specific buffer)
@@ -46,6 +51,41 @@ pub(super) struct Bookmark {
     pub position: usize,
 }
 
+/// A line bookmark, drawn as a symbol in the gutter
+#[derive(Debug, Clone)]
+pub(super) struct LineBookmark {
+    /// Marker of the gutter indicator, which tracks the line through edits
+    pub marker_id: MarkerId,
+    /// Optional short label shown in the bookmark picker
+    pub label: Option<String>,
+}
+
+/// A paste that Cycle Paste can still replace with an older clipboard entry
+#[derive(Debug, Clone)]
+pub(super) struct PasteCycle {
+    /// Buffer the text was pasted into
+    pub buffer_id: BufferId,
+    /// Clipboard history index of the entry to paste next
+    pub next: usize,
+    /// Byte length of the pasted text ending at each cursor
+    pub len: usize,
+    /// Cursor positions right after the paste
+    pub positions: Vec<usize>,
+}
+
+/// An abbreviation expansion that Backspace can still revert
+#[derive(Debug, Clone)]
+pub(super) struct AbbreviationExpansion {
+    /// Buffer the abbreviation was expanded in
+    pub buffer_id: BufferId,
+    /// Range of the expansion in the buffer
+    pub range: std::ops::Range<usize>,
+    /// The text the abbreviation expanded to
+    pub expansion: String,
+    /// The abbreviation as it was typed
+    pub typed: String,
+}
+
 /// State for interactive replace (query-replace)
 #[derive(Debug, Clone)]
 pub(super) struct InteractiveReplaceState {
@@ -63,8 +103,15 @@ pub(super) struct InteractiveReplaceState {
     pub has_wrapped: bool,
     /// Number of replacements made so far
     pub replacements_made: usize,
-    /// Compiled regex for regex-mode replace (None when regex mode is off)
+    /// Compiled regex (None for a plain, case-sensitive search)
     pub regex: Option<regex::bytes::Regex>,
+    /// Capture expansion and case preservation settings for this run
+    pub options: crate::app::regex_replace::ReplaceOptions,
+    /// Buffer being replaced in
+    pub buffer_id: BufferId,
+    /// Event log index when the run started; everything logged after it is
+    /// folded into one undo step when the run ends
+    pub undo_start: usize,
 }
 
 /// The kind of buffer (file-backed or virtual)
@@ -120,6 +167,14 @@ pub struct BufferMetadata {
     /// For file-backed buffers, recovery ID is computed from the path hash.
     /// For unnamed buffers, this is generated once and reused across auto-saves.
     pub recovery_id: Option<String>,
+
+    /// Language chosen with "Set Language", overriding detection: a syntax
+    /// name or "Plain Text". Persisted in the workspace.
+    pub language_override: Option<String>,
+
+    /// Number of a scratch buffer made with "New File" ("untitled-2").
+    /// Cleared when the buffer is saved to a file.
+    pub untitled_number: Option<usize>,
 }
 
 impl BufferMetadata {
@@ -175,6 +230,8 @@ impl BufferMetadata {
             lsp_opened_with: HashSet::new(),
             hidden_from_tabs: false,
             recovery_id: None,
+            language_override: None,
+            untitled_number: None,
         }
     }
 
@@ -194,6 +251,8 @@ impl BufferMetadata {
             lsp_opened_with: HashSet::new(),
             hidden_from_tabs: false,
             recovery_id: None,
+            language_override: None,
+            untitled_number: None,
         }
     }
 
@@ -232,6 +291,8 @@ impl BufferMetadata {
             lsp_opened_with: HashSet::new(),
             hidden_from_tabs: false,
             recovery_id: None,
+            language_override: None,
+            untitled_number: None,
         }
     }
 
@@ -332,6 +393,8 @@ impl BufferMetadata {
             lsp_opened_with: HashSet::new(),
             hidden_from_tabs: false,
             recovery_id: None,
+            language_override: None,
+            untitled_number: None,
         }
     }
 
@@ -349,6 +412,8 @@ impl BufferMetadata {
             lsp_opened_with: HashSet::new(),
             hidden_from_tabs: true,
             recovery_id: None,
+            language_override: None,
+            untitled_number: None,
         }
     }
 
@@ -450,6 +515,8 @@ pub enum HoverTarget {
     SearchOptionRegex,
     /// Hovering over the search options "Confirm Each" checkbox
     SearchOptionConfirmEach,
+    /// Hovering over the search options "Preserve Case" checkbox
+    SearchOptionPreserveCase,
     /// Hovering over a tab context menu item (item_index)
     TabContextMenuItem(usize),
 }
@@ -467,6 +534,8 @@ pub enum TabContextMenuItem {
     CloseToLeft,
     /// Close all tabs
     CloseAll,
+    /// Pin or unpin this tab
+    TogglePin,
 }
 
 impl TabContextMenuItem {
@@ -478,17 +547,21 @@ impl TabContextMenuItem {
             Self::CloseToRight,
             Self::CloseToLeft,
             Self::CloseAll,
+            Self::TogglePin,
         ]
     }
 
-    /// Get the display label for this menu item
-    pub fn label(&self) -> String {
+    /// Get the display label for this menu item. `pinned` is whether the
+    /// tab the menu was opened on is pinned.
+    pub fn label(&self, pinned: bool) -> String {
         match self {
             Self::Close => t!("tab.close").to_string(),
             Self::CloseOthers => t!("tab.close_others").to_string(),
             Self::CloseToRight => t!("tab.close_to_right").to_string(),
             Self::CloseToLeft => t!("tab.close_to_left").to_string(),
             Self::CloseAll => t!("tab.close_all").to_string(),
+            Self::TogglePin if pinned => t!("tab.unpin").to_string(),
+            Self::TogglePin => t!("tab.pin").to_string(),
         }
     }
 }
@@ -539,6 +612,29 @@ impl TabContextMenu {
     }
 }
 
+/// A file tab that was closed, kept so it can be reopened where it was left
+#[derive(Debug, Clone)]
+pub struct ClosedTab {
+    /// Absolute path of the file
+    pub path: PathBuf,
+    /// Primary cursor position (byte offset) when the tab was closed
+    pub cursor: usize,
+}
+
+/// Where input is routed: the keybinding context and the focused split and
+/// buffer
+///
+/// The event loop handles every queued input event before drawing once, but
+/// only while this stays the same. An event that opens a prompt, menu or
+/// popup, or moves focus to another buffer, ends the batch, so the events
+/// after it are handled only once the user can see where they go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputTarget {
+    pub context: KeyContext,
+    pub split: LeafId,
+    pub buffer: BufferId,
+}
+
 /// Drop zone for tab drag-and-drop
 /// Indicates where a dragged tab will be placed when released
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -607,6 +703,15 @@ impl TabDragState {
     }
 }
 
+/// Granularity of a mouse selection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) enum SelectionUnit {
+    #[default]
+    Char,
+    Word,
+    Line,
+}
+
 /// Mouse state tracking
 #[derive(Debug, Clone, Default)]
 pub(super) struct MouseState {
@@ -618,6 +723,10 @@ pub(super) struct MouseState {
     pub drag_start_hcol: Option<u16>,
     /// Initial left_column when starting horizontal scrollbar drag
     pub drag_start_left_column: Option<usize>,
+    /// Minimap being dragged: (split_id, buffer_id, minimap_rect, row start bytes).
+    /// Captured when the drag starts so the mapping doesn't shift as the
+    /// minimap scrolls along with the viewport.
+    pub dragging_minimap: Option<(LeafId, BufferId, Rect, Vec<usize>)>,
     /// Last mouse position
     pub last_position: Option<(u16, u16)>,
     /// Mouse hover for LSP: byte position being hovered, timer start, and screen position
@@ -625,6 +734,10 @@ pub(super) struct MouseState {
     pub lsp_hover_state: Option<(usize, std::time::Instant, u16, u16)>,
     /// Whether we've already sent a hover request for the current position
     pub lsp_hover_request_sent: bool,
+    /// Pointer rest tracking for hover tooltips
+    pub hover_dwell: crate::input::hover_dwell::HoverDwell,
+    /// The hover tooltip currently shown, if any
+    pub tooltip: Option<crate::view::ui::tooltip::Tooltip>,
     /// Initial mouse row when starting to drag the scrollbar thumb
     /// Used to calculate relative movement rather than jumping
     pub drag_start_row: Option<u16>,
@@ -646,12 +759,25 @@ pub(super) struct MouseState {
     pub drag_start_explorer_width: Option<f32>,
     /// Current hover target (if any)
     pub hover_target: Option<HoverTarget>,
+    /// The link underlined while Ctrl is held over it: buffer and byte range
+    pub link_hover: Option<(BufferId, Range<usize>)>,
     /// Whether we're currently doing a text selection drag
     pub dragging_text_selection: bool,
     /// The split where text selection started
     pub drag_selection_split: Option<LeafId>,
     /// The buffer byte position where the selection anchor is
     pub drag_selection_anchor: Option<usize>,
+    /// What the selection snaps to while dragging or Shift+clicking:
+    /// characters after a single click, words after a double-click, lines
+    /// after a triple-click
+    pub drag_selection_unit: SelectionUnit,
+    /// The word or line selected by the double/triple-click, which stays
+    /// selected whichever way the selection is extended
+    pub drag_selection_origin: Option<Range<usize>>,
+    /// The (anchor, position) of the last word/line selection made with the
+    /// mouse, so Shift+click only keeps that granularity while the selection
+    /// is unchanged
+    pub unit_selection: Option<(usize, usize)>,
     /// Tab drag state (for drag-to-split functionality)
     pub dragging_tab: Option<TabDragState>,
     /// Whether we're currently dragging a popup scrollbar (popup index)
@@ -746,6 +872,9 @@ pub(crate) struct CachedLayout {
     /// Horizontal scrollbar areas per split
     /// (split_id, buffer_id, horizontal_scrollbar_rect, max_content_width, thumb_start_col, thumb_end_col)
     pub horizontal_scrollbar_areas: Vec<(LeafId, BufferId, Rect, usize, usize, usize)>,
+    /// Minimap areas per split
+    /// (split_id, buffer_id, minimap_rect, start byte of each minimap row)
+    pub minimap_areas: Vec<(LeafId, BufferId, Rect, Vec<usize>)>,
     /// Split separator positions for drag resize
     /// (container_id, direction, x, y, length)
     pub separator_areas: Vec<(ContainerId, SplitDirection, u16, u16, u16)>,
@@ -783,6 +912,10 @@ pub(crate) struct CachedLayout {
     pub status_bar_language_area: Option<(u16, u16, u16)>,
     /// Status bar message area (row, start_col, end_col) - clickable to show status log
     pub status_bar_message_area: Option<(u16, u16, u16)>,
+    /// Status bar cursor position area (row, start_col, end_col) - clickable to go to a line
+    pub status_bar_cursor_position_area: Option<(u16, u16, u16)>,
+    /// Status bar plugin segment areas (name, row, start_col, end_col)
+    pub status_bar_plugin_areas: Vec<(String, u16, u16, u16)>,
     /// Search options layout for checkbox hit testing
     pub search_options_layout: Option<crate::view::ui::status_bar::SearchOptionsLayout>,
     /// Menu bar layout for hit testing
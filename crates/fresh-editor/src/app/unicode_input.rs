@@ -0,0 +1,151 @@
+//! Inserting Unicode characters by codepoint or by name.
+//!
+//! Insert Unicode Character opens a picker over a table of named characters,
+//! most recently inserted first. Typing filters it by name or codepoint, and
+//! a codepoint typed in full (`U+2713`, `2713`) is offered at the top even
+//! when the table doesn't name it. The character goes in at every cursor,
+//! or into the prompt the picker was opened from; that prompt is set aside
+//! while the picker is open and comes back when it closes.
+//!
+//! Recently inserted characters are kept as a prompt history, so they are
+//! saved and restored with the other histories.
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::input::commands::Suggestion;
+use crate::input::input_history::InputHistory;
+use crate::primitives::unicode_names::{
+    format_codepoint, parse_codepoint, unicode_name, UNICODE_NAMES,
+};
+use crate::view::prompt::{Prompt, PromptType};
+
+/// Prompt history holding the recently inserted characters
+pub(super) const UNICODE_HISTORY: &str = "unicode_char";
+
+/// Recently inserted characters remembered
+const MAX_RECENT_CHARS: usize = 50;
+
+/// Picker entry for a character
+fn char_suggestion(ch: char, name: Option<&str>) -> Suggestion {
+    Suggestion {
+        text: match name {
+            Some(name) => format!("{}  {}", ch, name),
+            None => ch.to_string(),
+        },
+        description: Some(format_codepoint(ch)),
+        value: Some(format_codepoint(ch)),
+        disabled: false,
+        keybinding: None,
+        source: None,
+    }
+}
+
+impl Editor {
+    /// Recently inserted characters, most recent first
+    fn recent_unicode_chars(&self) -> Vec<char> {
+        self.prompt_histories
+            .get(UNICODE_HISTORY)
+            .map(|history| {
+                history
+                    .items()
+                    .iter()
+                    .rev()
+                    .filter_map(|item| item.chars().next())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Open the Unicode character picker
+    pub(super) fn start_unicode_picker(&mut self) {
+        if self
+            .prompt
+            .as_ref()
+            .is_some_and(|prompt| prompt.prompt_type == PromptType::InsertUnicodeChar)
+        {
+            return;
+        }
+        if self.prompt.is_none() && self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
+        }
+
+        let recent = self.recent_unicode_chars();
+        let suggestions: Vec<Suggestion> = recent
+            .iter()
+            .map(|&ch| char_suggestion(ch, unicode_name(ch)))
+            .chain(
+                UNICODE_NAMES
+                    .iter()
+                    .filter(|(ch, _)| !recent.contains(ch))
+                    .map(|&(ch, name)| char_suggestion(ch, Some(name))),
+            )
+            .collect();
+
+        self.unicode_target_prompt = self.prompt.take();
+        self.prompt = Some(Prompt::with_suggestions(
+            t!("unicode.prompt").to_string(),
+            PromptType::InsertUnicodeChar,
+            suggestions,
+        ));
+    }
+
+    /// Filter the picker on the typed name or codepoint, offering a typed
+    /// codepoint first
+    pub(super) fn update_unicode_picker(&mut self) {
+        let Some(prompt) = &mut self.prompt else {
+            return;
+        };
+        prompt.filter_suggestions(true);
+        let Some(ch) = parse_codepoint(&prompt.input) else {
+            return;
+        };
+        let value = format_codepoint(ch);
+        prompt
+            .suggestions
+            .retain(|suggestion| suggestion.value.as_deref() != Some(value.as_str()));
+        prompt
+            .suggestions
+            .insert(0, char_suggestion(ch, unicode_name(ch)));
+        prompt.selected_suggestion = Some(0);
+    }
+
+    /// Insert the character picked or typed as a codepoint
+    pub(super) fn insert_unicode_char(&mut self, input: &str) {
+        self.prompt = self.unicode_target_prompt.take();
+        let Some(ch) = parse_codepoint(input) else {
+            self.set_status_message(t!("unicode.invalid", input = input).to_string());
+            return;
+        };
+
+        let history = self
+            .prompt_histories
+            .entry(UNICODE_HISTORY.to_string())
+            .or_default();
+        let mut recent: Vec<String> = history
+            .items()
+            .iter()
+            .filter(|item| item.chars().next() != Some(ch))
+            .cloned()
+            .collect();
+        recent.push(ch.to_string());
+        let excess = recent.len().saturating_sub(MAX_RECENT_CHARS);
+        *history = InputHistory::from_items(recent.split_off(excess));
+
+        self.paste_text(ch.to_string());
+        self.set_status_message(
+            t!(
+                "unicode.inserted",
+                char = ch.to_string(),
+                codepoint = format_codepoint(ch)
+            )
+            .to_string(),
+        );
+    }
+
+    /// Close the picker, putting back the prompt it was opened from
+    pub(super) fn cancel_unicode_picker(&mut self) {
+        self.prompt = self.unicode_target_prompt.take();
+    }
+}
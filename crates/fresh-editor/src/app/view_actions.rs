@@ -9,7 +9,7 @@ use rust_i18n::t;
 impl Editor {
     /// Toggle between Compose and Source view modes.
     pub fn handle_toggle_compose_mode(&mut self) {
-        let default_wrap = self.config.editor.line_wrap;
+        let default_wrap = self.default_line_wrap(self.active_buffer());
         let default_line_numbers = self.config.editor.line_numbers;
         let active_split = self.split_manager.active_split();
 
@@ -0,0 +1,118 @@
+//! Word completion for the Editor.
+//!
+//! This module offers words from the buffer text as completion items, so
+//! completion also works for plain text and languages without a server:
+//! - On their own when no language server handles the buffer
+//! - Below the server's items when one does
+//!
+//! Words come from each buffer's `WordIndex`. `editor.word_completion`
+//! chooses between the active buffer and all open buffers, and
+//! `editor.word_completion_min_chars` sets how much must be typed first.
+
+use std::collections::HashSet;
+
+use lsp_types::{CompletionItem, CompletionItemKind};
+use rust_i18n::t;
+
+use crate::config::WordCompletion;
+use crate::model::event::BufferId;
+use crate::primitives::word_navigation::find_completion_word_start;
+
+use super::Editor;
+
+/// Bytes read on each side of the cursor to rank words by proximity
+const NEARBY_BYTES: usize = 4096;
+
+/// Most words offered in one popup
+const MAX_WORD_ITEMS: usize = 50;
+
+impl Editor {
+    /// Completion items for the words matching the word being typed
+    ///
+    /// Words already offered in `existing` are left out. Returns nothing when
+    /// word completion is off or fewer than `word_completion_min_chars`
+    /// characters were typed.
+    pub(super) fn word_completion_items(
+        &mut self,
+        existing: &[CompletionItem],
+    ) -> Vec<CompletionItem> {
+        let mode = self.config.editor.word_completion;
+        let min_chars = self.config.editor.word_completion_min_chars.max(1);
+        if mode == WordCompletion::Off {
+            return Vec::new();
+        }
+
+        let active = self.active_buffer();
+        let cursor = self.active_cursors().primary().position;
+        let Some(state) = self.buffers.get_mut(&active) else {
+            return Vec::new();
+        };
+        let word_start = find_completion_word_start(&state.buffer, cursor);
+        let prefix =
+            String::from_utf8_lossy(&state.buffer.slice_bytes(word_start..cursor)).into_owned();
+        if prefix.chars().count() < min_chars {
+            return Vec::new();
+        }
+
+        let nearby_start = cursor.saturating_sub(NEARBY_BYTES);
+        let nearby_end = (cursor + NEARBY_BYTES).min(state.buffer.len());
+        let nearby = state.buffer.slice_bytes(nearby_start..nearby_end);
+        state.word_index.refresh(&state.buffer);
+
+        let mut seen: HashSet<String> = existing.iter().map(|item| item.label.clone()).collect();
+        let mut items = Vec::new();
+        let detail = t!("lsp.completion_buffer_word").to_string();
+        for word in state
+            .word_index
+            .matching_words(&prefix, &nearby, cursor - nearby_start)
+        {
+            if seen.insert(word.to_string()) {
+                items.push(word_item(word, &detail));
+            }
+        }
+
+        if mode == WordCompletion::OpenBuffers {
+            let mut others: Vec<BufferId> = self
+                .buffer_metadata
+                .iter()
+                .filter(|(id, metadata)| **id != active && !metadata.is_virtual())
+                .map(|(id, _)| *id)
+                .collect();
+            others.sort_by_key(|id| id.0);
+            for id in others {
+                let name = self.buffer_metadata[&id].display_name.clone();
+                let Some(state) = self.buffers.get_mut(&id) else {
+                    continue;
+                };
+                state.word_index.refresh(&state.buffer);
+                for word in state.word_index.matching_words(&prefix, &[], 0) {
+                    if seen.insert(word.to_string()) {
+                        items.push(word_item(word, &name));
+                    }
+                }
+            }
+        }
+
+        items.truncate(MAX_WORD_ITEMS);
+        items
+    }
+
+    /// Show snippet and word completions for a buffer no language server
+    /// handles
+    pub(super) fn show_word_completion(&mut self) {
+        let mut items = self.snippet_completion_items(&[]);
+        let words = self.word_completion_items(&items);
+        items.extend(words);
+        self.show_completion_popup(items);
+    }
+}
+
+/// A completion item inserting a plain word
+fn word_item(word: &str, detail: &str) -> CompletionItem {
+    CompletionItem {
+        label: word.to_string(),
+        kind: Some(CompletionItemKind::TEXT),
+        detail: Some(detail.to_string()),
+        ..Default::default()
+    }
+}
@@ -26,6 +26,8 @@ use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
+use rust_i18n::t;
+
 use crate::state::EditorState;
 
 use crate::model::event::{BufferId, LeafId, SplitDirection, SplitId};
@@ -34,10 +36,10 @@ use crate::state::ViewMode;
 use crate::view::split::{SplitNode, SplitViewState};
 use crate::workspace::{
     FileExplorerState, PersistedFileWorkspace, SearchOptions, SerializedBookmark, SerializedCursor,
-    SerializedFileState, SerializedFoldRange, SerializedScroll, SerializedSplitDirection,
-    SerializedSplitNode, SerializedSplitViewState, SerializedTabRef, SerializedTerminalWorkspace,
-    SerializedViewMode, Workspace, WorkspaceConfigOverrides, WorkspaceError, WorkspaceHistories,
-    WORKSPACE_VERSION,
+    SerializedFileState, SerializedFoldRange, SerializedLineBookmark, SerializedScratchBuffer,
+    SerializedScroll, SerializedSplitDirection, SerializedSplitNode, SerializedSplitViewState,
+    SerializedTabRef, SerializedTerminalWorkspace, SerializedViewMode, Workspace,
+    WorkspaceConfigOverrides, WorkspaceError, WorkspaceHistories, WORKSPACE_VERSION,
 };
 
 use super::types::Bookmark;
@@ -59,10 +61,10 @@ pub struct WorkspaceTracker {
 
 impl WorkspaceTracker {
     /// Create a new workspace tracker
-    pub fn new(enabled: bool) -> Self {
+    pub fn new(enabled: bool, now: Instant) -> Self {
         Self {
             dirty: false,
-            last_save: Instant::now(),
+            last_save: now,
             save_interval: std::time::Duration::from_secs(5),
             enabled,
         }
@@ -81,14 +83,14 @@ impl WorkspaceTracker {
     }
 
     /// Check if a save is needed and enough time has passed
-    pub fn should_save(&self) -> bool {
-        self.enabled && self.dirty && self.last_save.elapsed() >= self.save_interval
+    pub fn should_save(&self, now: Instant) -> bool {
+        self.enabled && self.dirty && now.duration_since(self.last_save) >= self.save_interval
     }
 
     /// Record that a save was performed
-    pub fn record_save(&mut self) {
+    pub fn record_save(&mut self, now: Instant) {
         self.dirty = false;
-        self.last_save = Instant::now();
+        self.last_save = now;
     }
 
     /// Check if there are unsaved changes (for shutdown)
@@ -106,7 +108,7 @@ impl Editor {
         let mut terminals = Vec::new();
         let mut terminal_indices: HashMap<TerminalId, usize> = HashMap::new();
         let mut seen = HashSet::new();
-        for terminal_id in self.terminal_buffers.values().copied() {
+        for (&buffer_id, &terminal_id) in &self.terminal_buffers {
             if seen.insert(terminal_id) {
                 let idx = terminals.len();
                 terminal_indices.insert(terminal_id, idx);
@@ -135,6 +137,11 @@ impl Editor {
                         root.join(format!("fresh-terminal-{}.txt", terminal_id.0))
                     });
 
+                // Only titles set with "Rename Terminal" are worth keeping
+                let title = self
+                    .terminal_title(buffer_id)
+                    .filter(|title| *title != format!("Terminal {}", terminal_id.0));
+
                 terminals.push(SerializedTerminalWorkspace {
                     terminal_index: idx,
                     cwd,
@@ -143,6 +150,7 @@ impl Editor {
                     rows,
                     log_path,
                     backing_path,
+                    title,
                 });
             }
         }
@@ -219,7 +227,7 @@ impl Editor {
         // Capture config overrides (only store deviations from defaults)
         let config_overrides = WorkspaceConfigOverrides {
             line_numbers: Some(self.config.editor.line_numbers),
-            relative_line_numbers: Some(self.config.editor.relative_line_numbers),
+            line_number_mode: Some(self.config.editor.line_number_mode),
             line_wrap: Some(self.config.editor.line_wrap),
             syntax_highlighting: Some(self.config.editor.syntax_highlighting),
             enable_inlay_hints: Some(self.config.editor.enable_inlay_hints),
@@ -259,18 +267,51 @@ impl Editor {
             whole_word: self.search_whole_word,
             use_regex: self.search_use_regex,
             confirm_each: self.search_confirm_each,
+            preserve_case: self.search_preserve_case,
         };
 
         // Capture bookmarks
         let bookmarks =
             serialize_bookmarks(&self.bookmarks, &self.buffer_metadata, &self.working_dir);
 
+        // Capture line bookmarks of files inside working_dir
+        let mut line_bookmarks = Vec::new();
+        for &buffer_id in self.line_bookmarks.keys() {
+            let Some(rel_path) = self
+                .buffer_metadata
+                .get(&buffer_id)
+                .and_then(|meta| meta.file_path())
+                .and_then(|abs_path| abs_path.strip_prefix(&self.working_dir).ok())
+            else {
+                continue;
+            };
+            for (line, bookmark) in self.bookmarked_lines(buffer_id) {
+                line_bookmarks.push(SerializedLineBookmark {
+                    file_path: rel_path.to_path_buf(),
+                    line,
+                    label: bookmark.label.clone(),
+                });
+            }
+        }
+
+        // Capture languages set by hand for files inside working_dir
+        let language_overrides = self
+            .buffer_metadata
+            .values()
+            .filter_map(|meta| {
+                let language = meta.language_override.clone()?;
+                let rel_path = meta.file_path()?.strip_prefix(&self.working_dir).ok()?;
+                Some((rel_path.to_path_buf(), language))
+            })
+            .collect();
+
         // Capture external files (files outside working_dir)
         // These are stored as absolute paths since they can't be made relative
         let external_files: Vec<PathBuf> = self
             .buffer_metadata
             .values()
             .filter_map(|meta| meta.file_path())
+            .filter(|abs_path| !abs_path.as_os_str().is_empty())
             .filter(|abs_path| abs_path.strip_prefix(&self.working_dir).is_err())
             .cloned()
             .collect();
@@ -278,6 +319,36 @@ impl Editor {
             tracing::debug!("Captured {} external files", external_files.len());
         }
 
+        // Capture untitled scratch buffers that hold something
+        let scratch_buffers: Vec<SerializedScratchBuffer> = self
+            .buffer_metadata
+            .iter()
+            .filter_map(|(buffer_id, meta)| {
+                let number = meta.untitled_number?;
+                let content = self.buffers.get(buffer_id)?.buffer.to_string()?;
+                (!content.is_empty()).then(|| SerializedScratchBuffer {
+                    number,
+                    content,
+                    language: meta.language_override.clone(),
+                    recovery_id: meta.recovery_id.clone(),
+                })
+            })
+            .collect();
+
+        // Capture the buffer picker's most recently used order of files
+        let buffer_mru: Vec<PathBuf> = self
+            .buffers_by_recent_use()
+            .into_iter()
+            .filter_map(|buffer_id| {
+                let path = self.buffer_metadata.get(&buffer_id)?.file_path()?;
+                (!path.as_os_str().is_empty()).then(|| {
+                    path.strip_prefix(&self.working_dir)
+                        .unwrap_or(path)
+                        .to_path_buf()
+                })
+            })
+            .collect();
+
         Workspace {
             version: WORKSPACE_VERSION,
             working_dir: self.working_dir.clone(),
@@ -289,8 +360,12 @@ impl Editor {
             histories,
             search_options,
             bookmarks,
+            line_bookmarks,
+            language_overrides,
             terminals,
             external_files,
+            scratch_buffers,
+            buffer_mru,
             saved_at: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
@@ -311,9 +386,39 @@ impl Editor {
         self.save_all_global_file_states();
 
         let workspace = self.capture_workspace();
+        self.workspace_tracker.record_save(self.time_source.now());
         workspace.save()
     }
 
+    /// Enable or disable workspace persistence
+    ///
+    /// When enabled, [`Editor::auto_save_workspace`] saves the workspace a few
+    /// seconds after user input, so a crash or killed session loses little.
+    pub fn set_workspace_enabled(&mut self, enabled: bool) {
+        self.workspace_tracker = WorkspaceTracker::new(enabled, self.time_source.now());
+    }
+
+    /// Mark the workspace as changed since it was last saved
+    pub(crate) fn mark_workspace_dirty(&mut self) {
+        self.workspace_tracker.mark_dirty();
+    }
+
+    /// Save the workspace if it changed and the debounce interval has passed
+    ///
+    /// Unlike [`Editor::save_workspace`], this leaves terminal backing files
+    /// alone: syncing appends the visible screen, so it must only happen once,
+    /// on exit. Returns true if a save was attempted.
+    pub fn auto_save_workspace(&mut self) -> Result<bool, WorkspaceError> {
+        let now = self.time_source.now();
+        if !self.workspace_tracker.should_save(now) {
+            return Ok(false);
+        }
+        // Record first so a failing disk doesn't retry on every tick
+        self.workspace_tracker.record_save(now);
+        self.capture_workspace().save()?;
+        Ok(true)
+    }
+
     /// Save global file states for all open file buffers
     fn save_all_global_file_states(&self) {
         // Collect all file states from all splits
@@ -444,8 +549,8 @@ impl Editor {
         if let Some(line_numbers) = workspace.config_overrides.line_numbers {
             self.config.editor.line_numbers = line_numbers;
         }
-        if let Some(relative_line_numbers) = workspace.config_overrides.relative_line_numbers {
-            self.config.editor.relative_line_numbers = relative_line_numbers;
+        if let Some(line_number_mode) = workspace.config_overrides.line_number_mode {
+            self.config.editor.line_number_mode = line_number_mode;
         }
         if let Some(line_wrap) = workspace.config_overrides.line_wrap {
             self.config.editor.line_wrap = line_wrap;
@@ -468,6 +573,7 @@ impl Editor {
         self.search_whole_word = workspace.search_options.whole_word;
         self.search_use_regex = workspace.search_options.use_regex;
         self.search_confirm_each = workspace.search_options.confirm_each;
+        self.search_preserve_case = workspace.search_options.preserve_case;
 
         // 3. Restore histories (merge with any existing)
         tracing::debug!(
@@ -517,6 +623,7 @@ impl Editor {
             file_paths
         );
         let mut path_to_buffer: HashMap<PathBuf, BufferId> = HashMap::new();
+        let mut missing_files: Vec<PathBuf> = Vec::new();
 
         for rel_path in file_paths {
             let abs_path = self.working_dir.join(&rel_path);
@@ -538,6 +645,7 @@ impl Editor {
                 }
             } else {
                 tracing::debug!("Skipping non-existent file: {:?}", abs_path);
+                missing_files.push(rel_path);
             }
         }
 
@@ -567,6 +675,7 @@ impl Editor {
                     }
                 } else {
                     tracing::debug!("Skipping non-existent external file: {:?}", abs_path);
+                    missing_files.push(abs_path.clone());
                 }
             }
         }
@@ -584,6 +693,13 @@ impl Editor {
             }
         }
 
+        // Recreate untitled scratch buffers and build number -> buffer map
+        let scratch_buffer_map: HashMap<usize, BufferId> = workspace
+            .scratch_buffers
+            .iter()
+            .map(|scratch| (scratch.number, self.restore_scratch_buffer(scratch)))
+            .collect();
+
         // 6. Rebuild split layout from the saved tree
         // Map old split IDs to new ones as we create splits
         let mut split_id_map: HashMap<usize, SplitId> = HashMap::new();
@@ -591,6 +707,7 @@ impl Editor {
             &workspace.split_layout,
             &path_to_buffer,
             &terminal_buffer_map,
+            &scratch_buffer_map,
             &workspace.split_states,
             &mut split_id_map,
             true, // is_first_leaf - the first leaf reuses the existing split
@@ -621,12 +738,64 @@ impl Editor {
             }
         }
 
+        // 8. Restore line bookmarks
+        for bookmark in &workspace.line_bookmarks {
+            if let Some(&buffer_id) = path_to_buffer.get(&bookmark.file_path) {
+                // Skip lines past the end of a file that shrank
+                let Some(position) = self
+                    .buffers
+                    .get(&buffer_id)
+                    .and_then(|state| state.buffer.line_start_offset(bookmark.line))
+                else {
+                    continue;
+                };
+                self.add_line_bookmark(buffer_id, position, bookmark.label.clone());
+            }
+        }
+
+        // 9. Restore languages set by hand
+        for (rel_path, language) in &workspace.language_overrides {
+            if let Some(&buffer_id) = path_to_buffer.get(rel_path) {
+                self.set_buffer_language(buffer_id, language);
+            }
+        }
+
+        // 10. Restore the buffer picker's most recently used order
+        let buffer_mru = workspace
+            .buffer_mru
+            .iter()
+            .filter_map(|path| {
+                let abs_path = self.working_dir.join(path);
+                self.buffer_metadata
+                    .iter()
+                    .find(|(_, meta)| meta.file_path() == Some(&abs_path))
+                    .map(|(&buffer_id, _)| buffer_id)
+            })
+            .collect();
+        self.set_buffer_mru(buffer_mru);
+
         tracing::debug!(
             "Workspace restore complete: {} splits, {} buffers",
             self.split_view_states.len(),
             self.buffers.len()
         );
 
+        if !missing_files.is_empty() {
+            let files = missing_files
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            self.set_status_message(
+                t!(
+                    "status.workspace_missing_files",
+                    count = missing_files.len(),
+                    files = files
+                )
+                .to_string(),
+            );
+        }
+
         // Fire buffer_activated for the active buffer so plugins can
         // re-enable compose mode (the plugin's composeBuffers set is empty
         // after restart). Only fires for the active buffer — other buffers
@@ -648,6 +817,25 @@ impl Editor {
         Ok(())
     }
 
+    /// Recreate an untitled scratch buffer with its content, which is still
+    /// unsaved
+    fn restore_scratch_buffer(&mut self, scratch: &SerializedScratchBuffer) -> BufferId {
+        let buffer_id = self.new_buffer();
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.buffer.insert(0, &scratch.content);
+            state.buffer.set_modified(true);
+        }
+        if let Some(metadata) = self.buffer_metadata.get_mut(&buffer_id) {
+            metadata.display_name = t!("buffer.untitled", number = scratch.number).to_string();
+            metadata.untitled_number = Some(scratch.number);
+            metadata.recovery_id = scratch.recovery_id.clone();
+        }
+        if let Some(language) = &scratch.language {
+            self.set_buffer_language(buffer_id, language);
+        }
+        buffer_id
+    }
+
     /// Restore a terminal from serialized workspace metadata.
     ///
     /// Uses the incremental streaming architecture for fast restore:
@@ -720,6 +908,11 @@ impl Editor {
 
         // Create buffer for this terminal
         let buffer_id = self.create_terminal_buffer_detached(terminal_id);
+        if let Some(title) = &terminal.title {
+            if let Some(metadata) = self.buffer_metadata.get_mut(&buffer_id) {
+                metadata.display_name = format!("*{}*", title);
+            }
+        }
 
         // Load backing file directly as read-only buffer (skip log replay)
         // The backing file already contains complete terminal state from last workspace
@@ -788,6 +981,7 @@ impl Editor {
         node: &SerializedSplitNode,
         path_to_buffer: &HashMap<PathBuf, BufferId>,
         terminal_buffers: &HashMap<usize, BufferId>,
+        scratch_buffers: &HashMap<usize, BufferId>,
         split_states: &HashMap<usize, SerializedSplitViewState>,
         split_id_map: &mut HashMap<usize, SplitId>,
         is_first_leaf: bool,
@@ -830,6 +1024,7 @@ impl Editor {
                     split_states,
                     path_to_buffer,
                     terminal_buffers,
+                    scratch_buffers,
                 );
             }
             SerializedSplitNode::Terminal {
@@ -867,6 +1062,7 @@ impl Editor {
                     split_states,
                     path_to_buffer,
                     terminal_buffers,
+                    scratch_buffers,
                 );
             }
             SerializedSplitNode::Split {
@@ -881,6 +1077,7 @@ impl Editor {
                     first,
                     path_to_buffer,
                     terminal_buffers,
+                    scratch_buffers,
                     split_states,
                     split_id_map,
                     is_first_leaf,
@@ -909,7 +1106,8 @@ impl Editor {
                             self.terminal_height,
                             second_buffer_id,
                         );
-                        view_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
+                        view_state.viewport.line_wrap_enabled =
+                            self.default_line_wrap(second_buffer_id);
                         view_state.rulers = self.config.editor.rulers.clone();
                         view_state.show_line_numbers = self.config.editor.line_numbers;
                         self.split_view_states.insert(new_leaf_id, view_state);
@@ -922,6 +1120,7 @@ impl Editor {
                             second,
                             path_to_buffer,
                             terminal_buffers,
+                            scratch_buffers,
                             split_states,
                             split_id_map,
                             false,
@@ -943,6 +1142,7 @@ impl Editor {
         split_states: &HashMap<usize, SerializedSplitViewState>,
         path_to_buffer: &HashMap<PathBuf, BufferId>,
         terminal_buffers: &HashMap<usize, BufferId>,
+        scratch_buffers: &HashMap<usize, BufferId>,
     ) {
         // Try to find the saved state for this split
         let Some(split_state) = split_states.get(&saved_split_id) else {
@@ -985,6 +1185,30 @@ impl Editor {
                                 .line_wrap_enabled = false;
                         }
                     }
+                    SerializedTabRef::Scratch(number) => {
+                        if let Some(&buffer_id) = scratch_buffers.get(number) {
+                            if !view_state.open_buffers.contains(&buffer_id) {
+                                view_state.open_buffers.push(buffer_id);
+                            }
+                            view_state.ensure_buffer_state(buffer_id);
+                        }
+                    }
+                }
+            }
+
+            // Pinned tabs are the first pinned_tab_count entries of open_tabs
+            for tab in split_state
+                .open_tabs
+                .iter()
+                .take(split_state.pinned_tab_count)
+            {
+                let buffer_id = match tab {
+                    SerializedTabRef::File(rel) => path_to_buffer.get(rel).copied(),
+                    SerializedTabRef::Terminal(index) => terminal_buffers.get(index).copied(),
+                    SerializedTabRef::Scratch(number) => scratch_buffers.get(number).copied(),
+                };
+                if let Some(buffer_id) = buffer_id {
+                    view_state.set_pinned(buffer_id, true);
                 }
             }
 
@@ -993,6 +1217,7 @@ impl Editor {
                     active_buffer_id = match tab {
                         SerializedTabRef::File(rel) => path_to_buffer.get(rel).copied(),
                         SerializedTabRef::Terminal(index) => terminal_buffers.get(index).copied(),
+                        SerializedTabRef::Scratch(number) => scratch_buffers.get(number).copied(),
                     };
                 }
             }
@@ -1222,12 +1447,18 @@ fn serialize_split_view_state(
     let mut open_tabs = Vec::new();
     let mut open_files = Vec::new();
     let mut active_tab_index = None;
+    let mut pinned_tab_count = 0;
 
     for buffer_id in &view_state.open_buffers {
         let tab_index = open_tabs.len();
+        // Pinned tabs lead open_buffers, so the saved ones lead open_tabs too
+        let pinned = view_state.is_pinned(*buffer_id);
         if let Some(terminal_id) = terminal_buffers.get(buffer_id) {
             if let Some(idx) = terminal_indices.get(terminal_id) {
                 open_tabs.push(SerializedTabRef::Terminal(*idx));
+                if pinned {
+                    pinned_tab_count = open_tabs.len();
+                }
                 if Some(*buffer_id) == active_buffer {
                     active_tab_index = Some(tab_index);
                 }
@@ -1235,6 +1466,26 @@ fn serialize_split_view_state(
             }
         }
 
+        // Scratch buffers are kept while they hold something
+        let scratch_number = buffer_metadata
+            .get(buffer_id)
+            .and_then(|meta| meta.untitled_number)
+            .filter(|_| {
+                buffers
+                    .get(buffer_id)
+                    .is_some_and(|state| !state.buffer.is_empty())
+            });
+        if let Some(number) = scratch_number {
+            open_tabs.push(SerializedTabRef::Scratch(number));
+            if pinned {
+                pinned_tab_count = open_tabs.len();
+            }
+            if Some(*buffer_id) == active_buffer {
+                active_tab_index = Some(tab_index);
+            }
+            continue;
+        }
+
         if let Some(rel_path) = buffer_metadata
             .get(buffer_id)
             .and_then(|meta| meta.file_path())
@@ -1242,6 +1493,9 @@ fn serialize_split_view_state(
         {
             open_tabs.push(SerializedTabRef::File(rel_path.to_path_buf()));
             open_files.push(rel_path.to_path_buf());
+            if pinned {
+                pinned_tab_count = open_tabs.len();
+            }
             if Some(*buffer_id) == active_buffer {
                 active_tab_index = Some(tab_index);
             }
@@ -1337,6 +1591,7 @@ fn serialize_split_view_state(
         open_files,
         active_file_index,
         file_states,
+        pinned_tab_count,
         tab_scroll_offset: view_state.tab_scroll_offset,
         view_mode: active_view_mode,
         compose_width: active_compose_width,
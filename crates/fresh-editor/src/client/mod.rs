@@ -41,6 +41,8 @@ pub enum ClientExitReason {
     Detached,
     /// Version mismatch between client and server
     VersionMismatch { server_version: String },
+    /// Server turned the client away during the handshake
+    Rejected { reason: String },
     /// Connection error
     Error(io::Error),
 }
@@ -74,14 +76,17 @@ pub fn run_client_with_connection(
     conn.write_control(&hello_json)?;
 
     // Read server response
-    let response = conn
-        .read_control()?
-        .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "Server closed connection"))?;
+    let response = conn.read_control()?.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "Server closed connection during handshake (is the session from an older fresh?)",
+        )
+    })?;
 
     let server_msg: ServerControl =
         serde_json::from_str(&response).map_err(|e| io::Error::other(e.to_string()))?;
 
-    match server_msg {
+    let capabilities = match server_msg {
         ServerControl::Hello(server_hello) => {
             if server_hello.protocol_version != PROTOCOL_VERSION {
                 return Ok(ClientExitReason::VersionMismatch {
@@ -93,29 +98,35 @@ pub fn run_client_with_connection(
                 server_hello.session_id,
                 server_hello.server_version
             );
+            server_hello.capabilities
         }
         ServerControl::VersionMismatch(mismatch) => {
             return Ok(ClientExitReason::VersionMismatch {
                 server_version: mismatch.server_version,
             });
         }
+        ServerControl::Quit { reason } => {
+            return Ok(ClientExitReason::Rejected { reason });
+        }
         ServerControl::Error { message } => {
             return Err(io::Error::other(format!("Server error: {}", message)));
         }
         _ => {
             return Err(io::Error::other("Unexpected server response"));
         }
-    }
+    };
 
-    run_client_relay(conn)
+    run_client_relay(conn, &capabilities)
 }
 
 /// Run the relay loop with an already-handshaked connection
 ///
-/// Use this when handshake has already been performed externally.
-/// Caller must have already enabled raw mode.
+/// Use this when handshake has already been performed externally, passing
+/// the capabilities the server answered with. Caller must have already
+/// enabled raw mode.
 pub fn run_client_relay(
     #[allow(unused_mut)] mut conn: ClientConnection,
+    #[cfg_attr(windows, allow(unused_variables))] capabilities: &[String],
 ) -> io::Result<ClientExitReason> {
     // Set up for relay
     // On Windows, don't set nonblocking here - the relay loop uses try_read() which handles this
@@ -130,8 +141,15 @@ pub fn run_client_relay(
 
     // Run the platform-specific relay loop
     #[cfg(unix)]
-    return relay_unix::relay_loop(&mut conn, resize_flag);
-
+    return relay_unix::relay_loop(
+        &mut conn,
+        resize_flag,
+        capabilities
+            .iter()
+            .any(|c| c == crate::server::protocol::capability::DETACH_KEY),
+    );
+
+    // The Windows relay has no detach key
     #[cfg(windows)]
     return relay_windows::relay_loop(&mut conn);
 }
@@ -9,12 +9,23 @@ use nix::poll::{poll, PollFd, PollFlags};
 
 use super::{get_terminal_size, ClientExitReason};
 use crate::server::ipc::ClientConnection;
-use crate::server::protocol::ClientControl;
+use crate::server::protocol::{ClientControl, ServerControl};
+use crate::services::terminal_background;
+
+/// Byte sent by Ctrl+\ in the legacy keyboard encoding
+const DETACH_KEY: u8 = 0x1c;
+
+/// xterm window operations: de-iconify, then raise the window
+const RAISE_WINDOW: &[u8] = b"\x1b[1t\x1b[5t";
 
 /// Main relay loop - bidirectional byte forwarding using poll()
+///
+/// With `detach_key`, pressing Ctrl+\ detaches right here, so it works even
+/// when the server is too busy to handle input.
 pub fn relay_loop(
     conn: &mut ClientConnection,
     resize_flag: Arc<AtomicBool>,
+    detach_key: bool,
 ) -> io::Result<ClientExitReason> {
     let mut stdin = io::stdin();
     let mut stdout = io::stdout();
@@ -25,6 +36,16 @@ pub fn relay_loop(
     let stdin_fd = stdin.as_raw_fd();
     let (data_fd, ctrl_fd) = conn.as_raw_fds();
 
+    // Answers to background queries go to the server as control messages;
+    // the rest is regular input
+    let mut responses = terminal_background::ResponseSplitter::default();
+
+    // Messages that arrived together with the server's hello are already
+    // buffered, so polling the socket wouldn't report them
+    if let Some(reason) = handle_control_messages(conn, &mut stdout, &mut responses)? {
+        return Ok(reason);
+    }
+
     loop {
         // Check for resize
         if resize_flag.swap(false, Ordering::SeqCst) {
@@ -51,7 +72,14 @@ pub fn relay_loop(
 
         match poll(&mut fds, nix::poll::PollTimeout::from(100u8)) {
             // 100ms timeout for resize check
-            Ok(0) => continue, // Timeout, check resize
+            Ok(0) => {
+                // A partial background reply that stalled was typed input
+                let held = responses.flush();
+                if !held.is_empty() {
+                    conn.write_data(&held)?;
+                }
+                continue;
+            }
             Ok(_) => {}
             Err(nix::errno::Errno::EINTR) => continue,
             Err(e) => return Err(io::Error::other(e.to_string())),
@@ -71,7 +99,22 @@ pub fn relay_loop(
                     return Ok(ClientExitReason::Detached);
                 }
                 Ok(n) => {
-                    conn.write_data(&stdin_buf[..n])?;
+                    let input = &stdin_buf[..n];
+                    if detach_key && input == [DETACH_KEY] {
+                        let detach_msg = serde_json::to_string(&ClientControl::Detach).unwrap();
+                        conn.write_control(&detach_msg)?;
+                        return Ok(ClientExitReason::Detached);
+                    }
+                    let (input, colors) = responses.feed(input);
+                    for (r, g, b) in colors {
+                        let msg =
+                            serde_json::to_string(&ClientControl::BackgroundColor { r, g, b })
+                                .unwrap();
+                        conn.write_control(&msg)?;
+                    }
+                    if !input.is_empty() {
+                        conn.write_data(&input)?;
+                    }
                 }
                 Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
                 Err(e) => return Err(e),
@@ -104,23 +147,8 @@ pub fn relay_loop(
             .map(|r| r.contains(PollFlags::POLLIN))
             .unwrap_or(false)
         {
-            if let Ok(Some(msg)) = conn.read_control() {
-                if let Ok(ctrl) =
-                    serde_json::from_str::<crate::server::protocol::ServerControl>(&msg)
-                {
-                    match ctrl {
-                        crate::server::protocol::ServerControl::Quit { reason } => {
-                            tracing::debug!("Server sent quit: {}", reason);
-                            return Ok(ClientExitReason::ServerQuit);
-                        }
-                        crate::server::protocol::ServerControl::Pong => {
-                            // Ignore pong responses
-                        }
-                        _ => {
-                            // Ignore other control messages
-                        }
-                    }
-                }
+            if let Some(reason) = handle_control_messages(conn, &mut stdout, &mut responses)? {
+                return Ok(reason);
             }
         }
 
@@ -160,3 +188,49 @@ pub fn setup_resize_handler(flag: Arc<AtomicBool>) -> io::Result<()> {
 
     Ok(())
 }
+
+/// Handle the control messages the server has sent. Returns the reason to
+/// exit when the server has quit.
+fn handle_control_messages(
+    conn: &ClientConnection,
+    stdout: &mut io::Stdout,
+    responses: &mut terminal_background::ResponseSplitter,
+) -> io::Result<Option<ClientExitReason>> {
+    loop {
+        let msg = match conn.try_read_control() {
+            Ok(Some(msg)) => msg,
+            Ok(None) => return Ok(None),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                return Ok(Some(ClientExitReason::ServerQuit));
+            }
+            Err(e) => {
+                tracing::debug!("Control read error: {}", e);
+                return Ok(None);
+            }
+        };
+        let Ok(ctrl) = serde_json::from_str::<ServerControl>(&msg) else {
+            continue;
+        };
+        match ctrl {
+            ServerControl::Quit { reason } => {
+                tracing::debug!("Server sent quit: {}", reason);
+                return Ok(Some(ClientExitReason::ServerQuit));
+            }
+            ServerControl::Pong => {
+                // Ignore pong responses
+            }
+            ServerControl::QueryBackground => {
+                stdout.write_all(terminal_background::BACKGROUND_QUERY)?;
+                stdout.flush()?;
+                responses.expect_response();
+            }
+            ServerControl::Raise => {
+                stdout.write_all(RAISE_WINDOW)?;
+                stdout.flush()?;
+            }
+            _ => {
+                // Ignore other control messages
+            }
+        }
+    }
+}
@@ -19,8 +19,6 @@ pub fn relay_loop(conn: &mut ClientConnection) -> io::Result<ClientExitReason> {
 
     let mut stdout = io::stdout();
     let mut data_buf = [0u8; 4096];
-    let mut control_buf = Vec::new();
-    let mut control_byte = [0u8; 1];
     let mut last_size = get_terminal_size().ok();
 
     loop {
@@ -123,40 +121,28 @@ pub fn relay_loop(conn: &mut ClientConnection) -> io::Result<ClientExitReason> {
 
         // 3. Check for control messages from server (non-blocking)
         tracing::debug!("[loop] try_read control pipe");
-        match conn.control.try_read(&mut control_byte) {
-            Ok(0) => {
-                tracing::debug!("[loop] Control pipe EOF - server quit");
-                // Control pipe closed
-                return Ok(ClientExitReason::ServerQuit);
-            }
-            Ok(1) => {
-                did_work = true;
-                if control_byte[0] == b'\n' {
-                    if let Ok(msg) = String::from_utf8(control_buf.clone()) {
-                        tracing::debug!("[loop] Control message: {}", msg);
-                        if let Ok(ctrl) = serde_json::from_str::<ServerControl>(&msg) {
-                            match ctrl {
-                                ServerControl::Quit { .. } => {
-                                    tracing::debug!("[loop] Server sent quit");
-                                    return Ok(ClientExitReason::ServerQuit);
-                                }
-                                _ => {}
-                            }
-                        }
+        loop {
+            match conn.try_read_control() {
+                Ok(Some(msg)) => {
+                    did_work = true;
+                    tracing::debug!("[loop] Control message: {}", msg);
+                    if let Ok(ServerControl::Quit { .. }) = serde_json::from_str(&msg) {
+                        tracing::debug!("[loop] Server sent quit");
+                        return Ok(ClientExitReason::ServerQuit);
                     }
-                    control_buf.clear();
-                } else {
-                    control_buf.push(control_byte[0]);
+                }
+                Ok(None) => break,
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                    tracing::debug!("[loop] Control pipe EOF - server quit");
+                    // Control pipe closed
+                    return Ok(ClientExitReason::ServerQuit);
+                }
+                Err(e) => {
+                    tracing::debug!("[loop] Control pipe error: {:?}", e);
+                    // Control pipe error - continue, data pipe is primary
+                    break;
                 }
             }
-            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
-                // No control messages
-            }
-            Err(e) => {
-                tracing::debug!("[loop] Control pipe error: {:?}", e);
-                // Control pipe error - continue, data pipe is primary
-            }
-            _ => {}
         }
 
         // 4. Check for terminal resize
@@ -1,3 +1,4 @@
+use crate::primitives::indent_pattern::IndentPatterns;
 use crate::types::{context_keys, LspServerConfig, ProcessLimits};
 
 use rust_i18n::t;
@@ -16,7 +17,15 @@ pub struct ThemeName(pub String);
 impl ThemeName {
     /// Built-in theme options shown in the settings dropdown
     pub const BUILTIN_OPTIONS: &'static [&'static str] =
-        &["dark", "light", "high-contrast", "nostalgia"];
+        &["dark", "light", "high-contrast", "nostalgia", Self::AUTO];
+
+    /// Follow the terminal background, using `light_theme` or `dark_theme`
+    pub const AUTO: &'static str = "auto";
+
+    /// Whether this is the "auto" setting rather than a concrete theme
+    pub fn is_auto(&self) -> bool {
+        self.0 == Self::AUTO
+    }
 }
 
 impl Deref for ThemeName {
@@ -300,6 +309,198 @@ impl JsonSchema for LineEndingOption {
     }
 }
 
+/// How line numbers are displayed in the gutter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineNumberMode {
+    /// Every line shows its own line number
+    #[default]
+    Absolute,
+    /// Lines show their distance from the cursor line, which shows 0
+    Relative,
+    /// Like relative, but the cursor line shows its absolute line number
+    Hybrid,
+}
+
+impl JsonSchema for LineNumberMode {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("LineNumberMode")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "How line numbers are displayed in the gutter",
+            "type": "string",
+            "enum": ["absolute", "relative", "hybrid"],
+            "default": "absolute"
+        })
+    }
+}
+
+/// Which spaces are drawn as space indicators (·), on top of the
+/// per-position `whitespace_spaces_*` settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderWhitespace {
+    /// Only what the per-position settings enable
+    #[default]
+    None,
+    /// Spaces after the last non-space character of a line
+    Trailing,
+    /// Every space except single spaces between words
+    Boundary,
+    /// Every space
+    All,
+}
+
+impl RenderWhitespace {
+    /// The mode that follows this one when cycling through them
+    pub fn next(self) -> Self {
+        match self {
+            Self::None => Self::Trailing,
+            Self::Trailing => Self::Boundary,
+            Self::Boundary => Self::All,
+            Self::All => Self::None,
+        }
+    }
+
+    /// The name used in config files
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Trailing => "trailing",
+            Self::Boundary => "boundary",
+            Self::All => "all",
+        }
+    }
+}
+
+impl JsonSchema for RenderWhitespace {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("RenderWhitespace")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Which spaces are drawn as space indicators",
+            "type": "string",
+            "enum": ["none", "trailing", "boundary", "all"],
+            "default": "none"
+        })
+    }
+}
+
+/// How the diff view lays out the two versions being compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiffViewLayout {
+    /// Old and new text in two panes that scroll together
+    #[default]
+    SideBySide,
+    /// Removed and added lines interleaved in one pane
+    Unified,
+}
+
+impl JsonSchema for DiffViewLayout {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("DiffViewLayout")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "How the diff view lays out the two versions being compared",
+            "type": "string",
+            "enum": ["side-by-side", "unified"],
+            "default": "side-by-side"
+        })
+    }
+}
+
+/// When modified buffers are written to disk without an explicit save
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoSaveMode {
+    /// Only save when asked
+    #[default]
+    Off,
+    /// Save when the terminal loses focus or another buffer is shown
+    OnFocusChange,
+    /// Save once editing has paused for `auto_save_delay_ms`
+    AfterDelay,
+}
+
+impl JsonSchema for AutoSaveMode {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("AutoSaveMode")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "When modified buffers are written to disk without an explicit save",
+            "type": "string",
+            "enum": ["off", "on_focus_change", "after_delay"],
+            "default": "off"
+        })
+    }
+}
+
+/// Which lines have trailing whitespace removed when saving
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrimTrailingWhitespace {
+    /// Leave trailing whitespace alone
+    #[default]
+    Off,
+    /// Trim every line
+    All,
+    /// Trim only lines marked as changed in the git gutter
+    ModifiedLinesOnly,
+}
+
+impl<'de> Deserialize<'de> for TrimTrailingWhitespace {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Older configs store this setting as a plain boolean
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Bool(bool),
+            Mode(String),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Bool(true) => Ok(Self::All),
+            Repr::Bool(false) => Ok(Self::Off),
+            Repr::Mode(mode) => match mode.as_str() {
+                "off" => Ok(Self::Off),
+                "all" => Ok(Self::All),
+                "modified_lines_only" => Ok(Self::ModifiedLinesOnly),
+                other => Err(serde::de::Error::unknown_variant(
+                    other,
+                    &["off", "all", "modified_lines_only"],
+                )),
+            },
+        }
+    }
+}
+
+impl JsonSchema for TrimTrailingWhitespace {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("TrimTrailingWhitespace")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Which lines have trailing whitespace removed when saving",
+            "type": "string",
+            "enum": ["off", "all", "modified_lines_only"],
+            "default": "off"
+        })
+    }
+}
+
 /// Controls whether Enter accepts a completion suggestion.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -328,6 +529,108 @@ impl JsonSchema for AcceptSuggestionOnEnter {
     }
 }
 
+/// Where word completion takes words from when no language server offers
+/// completions (or to fill in below them).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WordCompletion {
+    /// No word completion
+    Off,
+    /// Words of the current buffer
+    #[default]
+    Buffer,
+    /// Words of every open buffer
+    OpenBuffers,
+}
+
+impl JsonSchema for WordCompletion {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("WordCompletion")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Where word completion takes words from",
+            "type": "string",
+            "enum": ["off", "buffer", "open_buffers"],
+            "default": "buffer"
+        })
+    }
+}
+
+/// Most detailed level written to the editor log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    #[default]
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// Names as written in config and on the command line
+    pub const NAMES: [&'static str; 5] = ["error", "warn", "info", "debug", "trace"];
+}
+
+impl std::str::FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(Self::Error),
+            "warn" => Ok(Self::Warn),
+            "info" => Ok(Self::Info),
+            "debug" => Ok(Self::Debug),
+            "trace" => Ok(Self::Trace),
+            _ => Err(format!("unknown log level '{}'", s)),
+        }
+    }
+}
+
+impl JsonSchema for LogLevel {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("LogLevel")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Most detailed level written to the editor log",
+            "type": "string",
+            "enum": ["error", "warn", "info", "debug", "trace"],
+            "default": "debug"
+        })
+    }
+}
+
+/// Input model for editing keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EditorKeymap {
+    /// Non-modal editing: keys insert text, commands use modifiers
+    #[default]
+    Default,
+    /// Modal editing with Vim-style normal, insert and visual modes
+    Vim,
+}
+
+impl JsonSchema for EditorKeymap {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("EditorKeymap")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Input model for editing keys",
+            "type": "string",
+            "enum": ["default", "vim"],
+            "default": "default"
+        })
+    }
+}
+
 impl PartialEq<KeybindingMapName> for str {
     fn eq(&self, other: &KeybindingMapName) -> bool {
         self == other.0
@@ -356,20 +659,46 @@ pub struct Config {
     #[serde(default)]
     pub version: u32,
 
-    /// Color theme name
+    /// Color theme name, or "auto" to pick `light_theme` or `dark_theme`
+    /// based on the terminal's background color
     #[serde(default = "default_theme_name")]
     pub theme: ThemeName,
 
+    /// Theme used by "auto" on terminals with a light background
+    #[serde(default = "default_light_theme_name")]
+    pub light_theme: ThemeName,
+
+    /// Theme used by "auto" on terminals with a dark background
+    #[serde(default = "default_dark_theme_name")]
+    pub dark_theme: ThemeName,
+
+    /// Syntax highlighting style overrides layered on the theme, keyed by
+    /// TextMate scope (e.g. "keyword.control"). A key also applies to scopes
+    /// it is a dot-separated prefix of; the longest matching key wins.
+    #[serde(default)]
+    pub syntax_styles: HashMap<String, SyntaxStyle>,
+
     /// UI locale (language) for translations
     /// If not set, auto-detected from environment (LC_ALL, LC_MESSAGES, LANG)
     #[serde(default)]
     pub locale: LocaleName,
 
+    /// Mark UI strings that fall back to English because the locale has no
+    /// translation for them, shown as ⟦text⟧ (for translators)
+    #[serde(default)]
+    pub highlight_missing_translations: bool,
+
     /// Check for new versions on startup (default: true).
     /// When enabled, also sends basic anonymous telemetry (version, OS, terminal type).
     #[serde(default = "default_true")]
     pub check_for_updates: bool,
 
+    /// Most detailed messages written to the editor log: "error", "warn",
+    /// "info", "debug" or "trace". The --log-level flag and RUST_LOG take
+    /// precedence. Applies on restart.
+    #[serde(default)]
+    pub log_level: LogLevel,
+
     /// Editor behavior settings (indentation, line numbers, wrapping, etc.)
     #[serde(default)]
     pub editor: EditorConfig,
@@ -390,6 +719,10 @@ pub struct Config {
     #[serde(default)]
     pub terminal: TerminalConfig,
 
+    /// Status bar layout (which segments are shown where)
+    #[serde(default)]
+    pub status_bar: StatusBarConfig,
+
     /// Custom keybindings (overrides for the active map)
     #[serde(default)]
     pub keybindings: Vec<Keybinding>,
@@ -403,6 +736,17 @@ pub struct Config {
     #[serde(default = "default_keybinding_map_name")]
     pub active_keybinding_map: KeybindingMapName,
 
+    /// Abbreviations expanded when a word boundary is typed after them,
+    /// e.g. "teh": "the". Languages add their own with
+    /// `languages.<name>.abbreviations`, which win over these.
+    #[serde(default)]
+    pub abbreviations: HashMap<String, String>,
+
+    /// Named shell commands for the Run Task picker, e.g. "build": "cargo build".
+    /// Tasks run from the workspace root in their own terminal.
+    #[serde(default)]
+    pub tasks: HashMap<String, String>,
+
     /// Per-language configuration overrides (tab size, formatters, etc.)
     #[serde(default)]
     pub languages: HashMap<String, LanguageConfig>,
@@ -441,6 +785,35 @@ fn default_theme_name() -> ThemeName {
     ThemeName("high-contrast".to_string())
 }
 
+fn default_light_theme_name() -> ThemeName {
+    ThemeName("light".to_string())
+}
+
+fn default_dark_theme_name() -> ThemeName {
+    ThemeName("dark".to_string())
+}
+
+/// Style override for a syntax highlighting scope
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SyntaxStyle {
+    /// Foreground color as "#RRGGBB" or a color name (e.g. "LightRed").
+    /// Keeps the theme's color when not set.
+    #[serde(default)]
+    pub fg: Option<String>,
+
+    /// Render in bold
+    #[serde(default)]
+    pub bold: bool,
+
+    /// Render in italics
+    #[serde(default)]
+    pub italic: bool,
+
+    /// Underline
+    #[serde(default)]
+    pub underline: bool,
+}
+
 /// Resolved whitespace indicator visibility for a buffer.
 ///
 /// These are the final resolved flags after applying master toggle,
@@ -450,9 +823,16 @@ pub struct WhitespaceVisibility {
     pub spaces_leading: bool,
     pub spaces_inner: bool,
     pub spaces_trailing: bool,
+    /// Inner spaces that are part of a run of two or more
+    pub spaces_inner_runs: bool,
     pub tabs_leading: bool,
     pub tabs_inner: bool,
     pub tabs_trailing: bool,
+    /// Space rendering mode that was applied on top of the flags above
+    pub render_whitespace: RenderWhitespace,
+    /// Give trailing spaces and tabs a warning background. Independent of
+    /// the indicator flags and the master toggle.
+    pub highlight_trailing: bool,
 }
 
 impl Default for WhitespaceVisibility {
@@ -462,9 +842,12 @@ impl Default for WhitespaceVisibility {
             spaces_leading: false,
             spaces_inner: false,
             spaces_trailing: false,
+            spaces_inner_runs: false,
             tabs_leading: true,
             tabs_inner: true,
             tabs_trailing: true,
+            render_whitespace: RenderWhitespace::None,
+            highlight_trailing: false,
         }
     }
 }
@@ -474,24 +857,71 @@ impl WhitespaceVisibility {
     pub fn from_editor_config(editor: &EditorConfig) -> Self {
         if !editor.whitespace_show {
             return Self {
-                spaces_leading: false,
-                spaces_inner: false,
-                spaces_trailing: false,
-                tabs_leading: false,
-                tabs_inner: false,
-                tabs_trailing: false,
+                highlight_trailing: editor.highlight_trailing_whitespace,
+                ..Self::hidden()
             };
         }
-        Self {
+        let mut visibility = Self {
             spaces_leading: editor.whitespace_spaces_leading,
             spaces_inner: editor.whitespace_spaces_inner,
             spaces_trailing: editor.whitespace_spaces_trailing,
+            spaces_inner_runs: false,
             tabs_leading: editor.whitespace_tabs_leading,
             tabs_inner: editor.whitespace_tabs_inner,
             tabs_trailing: editor.whitespace_tabs_trailing,
+            render_whitespace: RenderWhitespace::None,
+            highlight_trailing: editor.highlight_trailing_whitespace,
+        };
+        visibility.apply_render_whitespace(editor.render_whitespace);
+        visibility
+    }
+
+    /// No indicators at all
+    fn hidden() -> Self {
+        Self {
+            spaces_leading: false,
+            spaces_inner: false,
+            spaces_trailing: false,
+            spaces_inner_runs: false,
+            tabs_leading: false,
+            tabs_inner: false,
+            tabs_trailing: false,
+            render_whitespace: RenderWhitespace::None,
+            highlight_trailing: false,
+        }
+    }
+
+    /// Turn on the space indicators a rendering mode calls for. Tab
+    /// indicators are left as they are.
+    pub fn apply_render_whitespace(&mut self, mode: RenderWhitespace) {
+        self.render_whitespace = mode;
+        match mode {
+            RenderWhitespace::None => {}
+            RenderWhitespace::Trailing => self.spaces_trailing = true,
+            RenderWhitespace::Boundary => {
+                self.spaces_leading = true;
+                self.spaces_trailing = true;
+                self.spaces_inner_runs = true;
+            }
+            RenderWhitespace::All => {
+                self.spaces_leading = true;
+                self.spaces_inner = true;
+                self.spaces_trailing = true;
+            }
         }
     }
 
+    /// Switch to the next space rendering mode, replacing whatever space
+    /// indicators were shown before
+    pub fn cycle_render_whitespace(&mut self) {
+        let mode = self.render_whitespace.next();
+        self.spaces_leading = false;
+        self.spaces_inner = false;
+        self.spaces_trailing = false;
+        self.spaces_inner_runs = false;
+        self.apply_render_whitespace(mode);
+    }
+
     /// Apply a language-level override for tab visibility.
     /// When the language sets `show_whitespace_tabs: false`, all tab positions are disabled.
     pub fn with_language_tab_override(mut self, show_whitespace_tabs: bool) -> Self {
@@ -505,7 +935,7 @@ impl WhitespaceVisibility {
 
     /// Returns true if any space indicator is enabled
     pub fn any_spaces(&self) -> bool {
-        self.spaces_leading || self.spaces_inner || self.spaces_trailing
+        self.spaces_leading || self.spaces_inner || self.spaces_trailing || self.spaces_inner_runs
     }
 
     /// Returns true if any tab indicator is enabled
@@ -522,18 +952,13 @@ impl WhitespaceVisibility {
     /// When turning off, all positions are disabled.
     /// When turning on, restores to default visibility (tabs all on, spaces all off).
     pub fn toggle_all(&mut self) {
+        let highlight_trailing = self.highlight_trailing;
         if self.any_visible() {
-            *self = Self {
-                spaces_leading: false,
-                spaces_inner: false,
-                spaces_trailing: false,
-                tabs_leading: false,
-                tabs_inner: false,
-                tabs_trailing: false,
-            };
+            *self = Self::hidden();
         } else {
             *self = Self::default();
         }
+        self.highlight_trailing = highlight_trailing;
     }
 }
 
@@ -546,10 +971,12 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub line_numbers: bool,
 
-    /// Show line numbers relative to cursor position
-    #[serde(default = "default_false")]
+    /// How line numbers are displayed: "absolute", "relative" (distance from
+    /// the cursor line), or "hybrid" (relative, with the absolute number on
+    /// the cursor line). Ignored in large files shown with byte offsets.
+    #[serde(default)]
     #[schemars(extend("x-section" = "Display"))]
-    pub relative_line_numbers: bool,
+    pub line_number_mode: LineNumberMode,
 
     /// Wrap long lines to fit the window width (default for new views)
     #[serde(default = "default_true")]
@@ -592,6 +1019,34 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub show_horizontal_scrollbar: bool,
 
+    /// Allow scrolling past the end of the file until the last line reaches
+    /// the top of the window, instead of keeping it at the bottom edge.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub scroll_past_end: bool,
+
+    /// Lines kept visible above and below the cursor when it moves
+    /// towards the top or bottom edge of the window.
+    /// Default: 3
+    #[serde(default = "default_vertical_scroll_offset")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub vertical_scroll_offset: usize,
+
+    /// Columns kept visible left and right of the cursor when it moves
+    /// towards the side of the window (line wrap off).
+    /// Default: 5
+    #[serde(default = "default_horizontal_scroll_offset")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub horizontal_scroll_offset: usize,
+
+    /// Keep the cursor line vertically centered, scrolling the view on
+    /// every line the cursor moves (except near the start of the file).
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub cursor_centering: bool,
+
     /// Use the terminal's default background color instead of the theme's editor background.
     /// When enabled, the editor background inherits from the terminal emulator,
     /// allowing transparency or custom terminal backgrounds to show through.
@@ -615,6 +1070,52 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub rulers: Vec<usize>,
 
+    /// Draw vertical guide lines at each indent level, highlighting the
+    /// guide of the block containing the cursor.
+    /// Can be toggled at runtime via command palette or the View menu.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub indent_guides: bool,
+
+    /// Give the line containing the cursor a subtle background across the
+    /// whole window width, including the placeholder of a collapsed fold.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub highlight_current_line: bool,
+
+    /// Draw a one-cell swatch of the color before each `#rrggbb`, `#rgb`,
+    /// `rgb(…)` and `hsl(…)` literal in view. Clicking a swatch opens a prompt
+    /// to adjust the color.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub color_swatches: bool,
+
+    /// Show a minimap strip beside the scrollbar: a squeezed outline of the
+    /// buffer with the visible region highlighted and ticks for diagnostics
+    /// and search matches. Click or drag it to scroll.
+    /// Can be toggled at runtime via command palette or the View menu.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub minimap: bool,
+
+    /// Minimum split width (in columns) for the minimap to be shown.
+    /// Narrower splits hide it to leave room for text.
+    /// Default: 120
+    #[serde(default = "default_minimap_min_width")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub minimap_min_width: usize,
+
+    /// Layout of the "Diff Against Saved" and "Diff Two Buffers" views:
+    /// "side-by-side" (two scroll-synced panes) or "unified" (one pane).
+    /// Default: "side-by-side"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Display"))]
+    pub diff_view_layout: DiffViewLayout,
+
     // ===== Whitespace =====
     /// Master toggle for whitespace indicator visibility.
     /// When disabled, no whitespace indicators (·, →) are shown regardless
@@ -666,6 +1167,21 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Whitespace"))]
     pub whitespace_tabs_trailing: bool,
 
+    /// Which spaces are drawn as space indicators (·), in addition to the
+    /// per-position settings above: "none", "trailing", "boundary" (all but
+    /// single spaces between words) or "all". Tabs keep their → indicator.
+    /// Default: "none"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Whitespace"))]
+    pub render_whitespace: RenderWhitespace,
+
+    /// Give trailing spaces and tabs a warning background, whatever the
+    /// indicator settings.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Whitespace"))]
+    pub highlight_trailing_whitespace: bool,
+
     // ===== Editing =====
     /// Number of spaces per tab character
     #[serde(default = "default_tab_size")]
@@ -677,6 +1193,36 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Editing"))]
     pub auto_indent: bool,
 
+    /// Insert the closing bracket or quote when typing an opening one, and
+    /// wrap the selection in the pair when text is selected.
+    /// Pairs can be changed per language with `auto_close_pairs`.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub auto_close: bool,
+
+    /// Home first moves to the first non-whitespace character of the line and
+    /// only goes to column 0 when pressed again. When off, Home always goes
+    /// to column 0.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub smart_home: bool,
+
+    /// Only expand abbreviations in code, not inside strings and comments
+    /// (as told by the syntax highlighting).
+    /// Default: false
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub abbreviations_in_code_only: bool,
+
+    /// Column Hard Wrap rewraps paragraphs at, unless another width is typed
+    /// in its prompt.
+    /// Default: 80
+    #[serde(default = "default_max_line_length")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub max_line_length: usize,
+
     /// Minimum lines to keep visible above/below cursor when scrolling
     #[serde(default = "default_scroll_offset")]
     #[schemars(extend("x-section" = "Editing"))]
@@ -691,17 +1237,27 @@ pub struct EditorConfig {
     pub default_line_ending: LineEndingOption,
 
     /// Remove trailing whitespace from lines when saving.
-    /// Default: false
-    #[serde(default = "default_false")]
+    /// "all" trims every line, "modified_lines_only" trims only lines the
+    /// git gutter marks as changed. Whitespace before the cursor is kept.
+    /// Default: "off"
+    #[serde(default)]
     #[schemars(extend("x-section" = "Editing"))]
-    pub trim_trailing_whitespace_on_save: bool,
+    pub trim_trailing_whitespace_on_save: TrimTrailingWhitespace,
 
     /// Ensure files end with a newline when saving.
     /// Default: false
-    #[serde(default = "default_false")]
+    #[serde(default = "default_false", alias = "insert_final_newline")]
     #[schemars(extend("x-section" = "Editing"))]
     pub ensure_final_newline_on_save: bool,
 
+    /// Files opened read-only, as globs relative to the working directory
+    /// (e.g. "target/**"). Patterns without a "/" match the file name.
+    /// Files without write permission are always opened read-only.
+    /// Default: ["target/**", "node_modules/**"]
+    #[serde(default = "default_readonly_globs")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub readonly_globs: Vec<String>,
+
     // ===== Bracket Matching =====
     /// Highlight matching bracket pairs when cursor is on a bracket.
     /// Default: true
@@ -716,6 +1272,13 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Bracket Matching"))]
     pub rainbow_brackets: bool,
 
+    /// Color every bracket pair in view by its nesting depth, cycling through
+    /// a small palette.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Bracket Matching"))]
+    pub bracket_pair_colorization: bool,
+
     // ===== Completion =====
     /// Enable quick suggestions (VS Code-like behavior).
     /// When enabled, completion suggestions appear automatically while typing,
@@ -750,6 +1313,23 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Completion"))]
     pub accept_suggestion_on_enter: AcceptSuggestionOnEnter,
 
+    /// Complete words found in the text, for plain text and languages
+    /// without a language server. Language server completions come first
+    /// and words fill in below them.
+    /// - "off": no word completion
+    /// - "buffer": words of the current buffer
+    /// - "open_buffers": words of every open buffer
+    /// Default: "buffer"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Completion"))]
+    pub word_completion: WordCompletion,
+
+    /// Number of characters of a word to type before words are suggested.
+    /// Default: 3
+    #[serde(default = "default_word_completion_min_chars")]
+    #[schemars(extend("x-section" = "Completion"))]
+    pub word_completion_min_chars: usize,
+
     // ===== LSP =====
     /// Whether to enable LSP inlay hints (type hints, parameter hints, etc.)
     #[serde(default = "default_true")]
@@ -778,6 +1358,21 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Mouse"))]
     pub mouse_hover_delay_ms: u64,
 
+    /// Delay in milliseconds the mouse must rest before a tooltip appears.
+    /// Tooltips show diagnostic messages, the lines hidden by a collapsed fold,
+    /// and the full path of a tab.
+    /// Default: 400ms
+    #[serde(default = "default_mouse_tooltip_delay")]
+    #[schemars(extend("x-section" = "Mouse"))]
+    pub mouse_tooltip_delay_ms: u64,
+
+    /// Number of columns scrolled per horizontal wheel step (Shift+wheel or
+    /// a sideways wheel/trackpad swipe) when line wrap is off.
+    /// Default: 3
+    #[serde(default = "default_mouse_horizontal_scroll_columns")]
+    #[schemars(extend("x-section" = "Mouse"))]
+    pub mouse_horizontal_scroll_columns: usize,
+
     /// Time window in milliseconds for detecting double-clicks.
     /// Two clicks within this time are treated as a double-click (word selection).
     /// Default: 500ms
@@ -801,6 +1396,22 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Recovery"))]
     pub auto_save_interval_secs: u32,
 
+    /// Save modified files without an explicit save: "off",
+    /// "on_focus_change" (when the terminal loses focus or you switch to
+    /// another buffer) or "after_delay" (once typing pauses for
+    /// auto_save_delay_ms). Files changed on disk by another program are
+    /// never overwritten silently.
+    /// Default: "off"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Recovery"))]
+    pub auto_save: AutoSaveMode,
+
+    /// Idle time in milliseconds before "after_delay" auto-save writes files.
+    /// Default: 1000
+    #[serde(default = "default_auto_save_delay_ms")]
+    #[schemars(extend("x-section" = "Recovery"))]
+    pub auto_save_delay_ms: u64,
+
     // ===== Recovery =====
     /// Whether to enable file recovery (Emacs-style auto-save)
     /// When enabled, buffers are periodically saved to recovery files
@@ -856,8 +1467,36 @@ pub struct EditorConfig {
     /// Requires terminal support (kitty keyboard protocol).
     /// Default: false
     #[serde(default = "default_false")]
-    #[schemars(extend("x-section" = "Keyboard"))]
-    pub keyboard_report_all_keys_as_escape_codes: bool,
+    #[schemars(extend("x-section" = "Keyboard"))]
+    pub keyboard_report_all_keys_as_escape_codes: bool,
+
+    /// Editing model for the text area.
+    /// - "default": non-modal editing
+    /// - "vim": modal editing with normal, insert, visual and visual-line modes
+    /// The active keybinding map still applies in insert mode and to keys
+    /// with Ctrl/Alt modifiers.
+    /// Default: "default"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Keyboard"))]
+    pub keymap: EditorKeymap,
+
+    /// How long in milliseconds to wait for the next key of a multi-key
+    /// binding (e.g. "Ctrl+K Ctrl+S") before the pending prefix is dropped.
+    /// Set to 0 to wait indefinitely.
+    /// Default: 3000
+    #[serde(default = "default_chord_timeout")]
+    #[schemars(extend("x-section" = "Keyboard"))]
+    pub chord_timeout_ms: u64,
+
+    // ===== Sessions =====
+    /// Give each client attached to a session its own view: splits, tabs,
+    /// active buffer, cursors and scroll position. Buffers and their edits
+    /// stay shared. When off, every client shows the same screen.
+    /// Takes effect when the session starts.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Sessions"))]
+    pub session_independent_views: bool,
 
     // ===== Performance =====
     /// Maximum time in milliseconds for syntax highlighting per frame
@@ -878,6 +1517,14 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Performance"))]
     pub highlight_context_bytes: usize,
 
+    /// Long lines are syntax highlighted up to this column (in bytes); the rest
+    /// of the line, and any lines below it in view, render unstyled. Keeps
+    /// minified files with megabyte-long lines responsive. 0 means no limit.
+    /// Default: 10000
+    #[serde(default = "default_syntax_highlight_max_column")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub syntax_highlight_max_column: usize,
+
     /// File size threshold in bytes for "large file" behavior
     /// Files larger than this will:
     /// - Skip LSP features
@@ -910,6 +1557,15 @@ pub struct EditorConfig {
     #[serde(default = "default_file_tree_poll_interval")]
     #[schemars(extend("x-section" = "Performance"))]
     pub file_tree_poll_interval_ms: u64,
+
+    /// Keep an in-memory index of the workspace's files for Quick Open.
+    /// The index is built in the background, respects .gitignore, and is
+    /// updated as files are saved, created and deleted. When off, files are
+    /// listed with git ls-files (or fd/find) instead.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub file_index: bool,
 }
 
 fn default_tab_size() -> usize {
@@ -953,14 +1609,30 @@ fn default_quick_suggestions_delay() -> u64 {
     10 // 10ms like VS Code
 }
 
+fn default_chord_timeout() -> u64 {
+    3000
+}
+
 fn default_accept_suggestion_on_enter() -> AcceptSuggestionOnEnter {
     AcceptSuggestionOnEnter::On
 }
 
+fn default_word_completion_min_chars() -> usize {
+    3
+}
+
 fn default_scroll_offset() -> usize {
     3
 }
 
+fn default_readonly_globs() -> Vec<String> {
+    vec!["target/**".to_string(), "node_modules/**".to_string()]
+}
+
+fn default_minimap_min_width() -> usize {
+    120
+}
+
 fn default_highlight_timeout() -> u64 {
     5
 }
@@ -973,10 +1645,18 @@ fn default_estimated_line_length() -> usize {
     80
 }
 
+fn default_max_line_length() -> usize {
+    80
+}
+
 fn default_auto_save_interval() -> u32 {
     30 // 30 seconds between persistent auto-saves
 }
 
+fn default_auto_save_delay_ms() -> u64 {
+    1000
+}
+
 fn default_auto_recovery_save_interval() -> u32 {
     2 // 2 seconds between recovery saves
 }
@@ -985,10 +1665,30 @@ fn default_highlight_context_bytes() -> usize {
     10_000 // 10KB context for accurate syntax highlighting
 }
 
+fn default_syntax_highlight_max_column() -> usize {
+    10_000
+}
+
 fn default_mouse_hover_delay() -> u64 {
     500 // 500ms delay before showing hover info
 }
 
+fn default_mouse_tooltip_delay() -> u64 {
+    400 // 400ms of rest before showing a tooltip
+}
+
+fn default_vertical_scroll_offset() -> usize {
+    3 // Keep 3 lines of context above/below the cursor
+}
+
+fn default_horizontal_scroll_offset() -> usize {
+    5 // Keep 5 columns of context left/right of the cursor
+}
+
+fn default_mouse_horizontal_scroll_columns() -> usize {
+    3 // 3 columns per horizontal wheel step
+}
+
 fn default_double_click_time() -> u64 {
     500 // 500ms window for detecting double-clicks
 }
@@ -1006,8 +1706,12 @@ impl Default for EditorConfig {
         Self {
             tab_size: default_tab_size(),
             auto_indent: true,
+            auto_close: true,
+            smart_home: true,
+            abbreviations_in_code_only: false,
+            max_line_length: default_max_line_length(),
             line_numbers: true,
-            relative_line_numbers: false,
+            line_number_mode: LineNumberMode::default(),
             scroll_offset: default_scroll_offset(),
             syntax_highlighting: true,
             line_wrap: true,
@@ -1019,35 +1723,58 @@ impl Default for EditorConfig {
             enable_semantic_tokens_full: false,
             auto_save_enabled: false,
             auto_save_interval_secs: default_auto_save_interval(),
+            auto_save: AutoSaveMode::default(),
+            auto_save_delay_ms: default_auto_save_delay_ms(),
             recovery_enabled: true,
             auto_recovery_save_interval_secs: default_auto_recovery_save_interval(),
             highlight_context_bytes: default_highlight_context_bytes(),
+            syntax_highlight_max_column: default_syntax_highlight_max_column(),
             mouse_hover_enabled: true,
             mouse_hover_delay_ms: default_mouse_hover_delay(),
+            mouse_tooltip_delay_ms: default_mouse_tooltip_delay(),
+            mouse_horizontal_scroll_columns: default_mouse_horizontal_scroll_columns(),
             double_click_time_ms: default_double_click_time(),
             auto_revert_poll_interval_ms: default_auto_revert_poll_interval(),
             read_concurrency: default_read_concurrency(),
             file_tree_poll_interval_ms: default_file_tree_poll_interval(),
+            file_index: true,
             default_line_ending: LineEndingOption::default(),
-            trim_trailing_whitespace_on_save: false,
+            trim_trailing_whitespace_on_save: TrimTrailingWhitespace::Off,
             ensure_final_newline_on_save: false,
+            readonly_globs: default_readonly_globs(),
             highlight_matching_brackets: true,
             rainbow_brackets: true,
+            bracket_pair_colorization: false,
             cursor_style: CursorStyle::default(),
             keyboard_disambiguate_escape_codes: true,
             keyboard_report_event_types: false,
             keyboard_report_alternate_keys: true,
             keyboard_report_all_keys_as_escape_codes: false,
+            keymap: EditorKeymap::Default,
+            chord_timeout_ms: default_chord_timeout(),
+            session_independent_views: false,
             quick_suggestions: true,
             quick_suggestions_delay_ms: default_quick_suggestions_delay(),
             suggest_on_trigger_characters: true,
             accept_suggestion_on_enter: default_accept_suggestion_on_enter(),
+            word_completion: WordCompletion::Buffer,
+            word_completion_min_chars: default_word_completion_min_chars(),
             show_menu_bar: true,
             show_tab_bar: true,
             show_vertical_scrollbar: true,
             show_horizontal_scrollbar: false,
+            scroll_past_end: false,
+            vertical_scroll_offset: default_vertical_scroll_offset(),
+            horizontal_scroll_offset: default_horizontal_scroll_offset(),
+            cursor_centering: false,
             use_terminal_bg: false,
             rulers: Vec::new(),
+            indent_guides: false,
+            highlight_current_line: false,
+            color_swatches: false,
+            minimap: false,
+            minimap_min_width: default_minimap_min_width(),
+            diff_view_layout: DiffViewLayout::default(),
             whitespace_show: true,
             whitespace_spaces_leading: false,
             whitespace_spaces_inner: false,
@@ -1055,6 +1782,8 @@ impl Default for EditorConfig {
             whitespace_tabs_leading: true,
             whitespace_tabs_inner: true,
             whitespace_tabs_trailing: true,
+            render_whitespace: RenderWhitespace::default(),
+            highlight_trailing_whitespace: false,
         }
     }
 }
@@ -1074,10 +1803,14 @@ pub struct FileExplorerConfig {
     #[serde(default = "default_false")]
     pub show_gitignored: bool,
 
-    /// Custom patterns to ignore (in addition to .gitignore)
+    /// Custom patterns to hide, like "target/" or "*.pyc" (shown together with gitignored files)
     #[serde(default)]
     pub custom_ignore_patterns: Vec<String>,
 
+    /// Whether to reveal the active buffer's file in the file explorer whenever it changes
+    #[serde(default = "default_false")]
+    pub follow_active_file: bool,
+
     /// Width of file explorer as percentage (0.0 to 1.0)
     #[serde(default = "default_explorer_width")]
     pub width: f32,
@@ -1087,18 +1820,55 @@ fn default_explorer_width() -> f32 {
     0.3 // 30% of screen width
 }
 
+/// Where clipboard text is read from and written to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardProvider {
+    /// Pick providers from the environment
+    #[default]
+    Auto,
+    /// The OS clipboard (X11/Wayland, macOS, Windows)
+    Native,
+    /// OSC 52 escape sequences to the terminal, forwarded over SSH and
+    /// client-server sessions
+    Osc52,
+    /// The editor's own clipboard only
+    Internal,
+}
+
+impl JsonSchema for ClipboardProvider {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("ClipboardProvider")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Where clipboard text is read from and written to",
+            "type": "string",
+            "enum": ["auto", "native", "osc52", "internal"],
+            "default": "auto"
+        })
+    }
+}
+
 /// Clipboard configuration
 ///
 /// Controls which clipboard methods are used for copy/paste operations.
-/// By default, all methods are enabled and the editor tries them in order:
-/// 1. OSC 52 escape sequences (works in modern terminals like Kitty, Alacritty, Wezterm)
-/// 2. System clipboard via X11/Wayland APIs (works in Gnome Console, XFCE Terminal, etc.)
+/// By default the editor picks them from the environment and tries them in order:
+/// 1. System clipboard via OS APIs (X11/Wayland, macOS, Windows), when a display is available
+/// 2. OSC 52 escape sequences (works in modern terminals like Kitty, Alacritty, Wezterm),
+///    tried first over SSH and in client-server sessions
 /// 3. Internal clipboard (always available as fallback)
 ///
 /// If you experience hangs or issues (e.g., when using PuTTY or certain SSH setups),
 /// you can disable specific methods.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ClipboardConfig {
+    /// Clipboard to use: "auto" (default) picks from the environment,
+    /// "native", "osc52" or "internal" use only that one (plus the internal fallback)
+    #[serde(default)]
+    pub provider: ClipboardProvider,
+
     /// Enable OSC 52 escape sequences for clipboard access (default: true)
     /// Disable this if your terminal doesn't support OSC 52 or if it causes hangs
     #[serde(default = "default_true")]
@@ -1108,13 +1878,24 @@ pub struct ClipboardConfig {
     /// Disable this if you don't have a display server or it causes issues
     #[serde(default = "default_true")]
     pub use_system_clipboard: bool,
+
+    /// Number of copied texts kept for "Paste from History" (default: 20)
+    /// Only the newest entry is shared with the system clipboard
+    #[serde(default = "default_clipboard_history_size")]
+    pub history_size: usize,
+}
+
+fn default_clipboard_history_size() -> usize {
+    20
 }
 
 impl Default for ClipboardConfig {
     fn default() -> Self {
         Self {
+            provider: ClipboardProvider::Auto,
             use_osc52: true,
             use_system_clipboard: true,
+            history_size: default_clipboard_history_size(),
         }
     }
 }
@@ -1126,12 +1907,201 @@ pub struct TerminalConfig {
     /// automatically jump back to terminal mode (default: true)
     #[serde(default = "default_true")]
     pub jump_to_end_on_output: bool,
+
+    /// Regexes that turn file locations in terminal output into clickable links.
+    /// Each pattern needs a `file` group and may have `line` and `col` groups.
+    #[serde(default = "default_terminal_link_patterns")]
+    pub link_patterns: Vec<String>,
+}
+
+fn default_terminal_link_patterns() -> Vec<String> {
+    vec![
+        // Python tracebacks: File "app.py", line 12
+        r#"File "(?P<file>[^"]+)", line (?P<line>\d+)"#.to_string(),
+        // rustc, gcc, grep -n and friends: path/to/file.ext:line[:col]
+        r"(?P<file>(?:[A-Za-z]:)?[\w.~/\\-]*\w\.\w+):(?P<line>\d+)(?::(?P<col>\d+))?".to_string(),
+    ]
 }
 
 impl Default for TerminalConfig {
     fn default() -> Self {
         Self {
             jump_to_end_on_output: true,
+            link_patterns: default_terminal_link_patterns(),
+        }
+    }
+}
+
+/// A status bar segment, written as its name or as
+/// `{ "segment": "<name>", "min_width": <columns> }`
+///
+/// Built-in segments: mode, session, remote, clipboard, file_name, modified,
+/// read_only, cursor_position, csv_column, log_follow, scroll_link,
+/// selection_size, selection_words, diagnostics, cursor_count,
+/// search_matches, chord, messages, git_branch, clock, line_ending, encoding,
+/// language, lsp_status, warnings, update, palette. Other names show the segment a plugin set with
+/// `setStatusBarSegment`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusBarSegment {
+    /// Name of the segment
+    pub segment: String,
+    /// Hide the segment when the status bar is narrower than this many
+    /// columns, so less important segments go first on small terminals
+    pub min_width: u16,
+}
+
+impl StatusBarSegment {
+    pub fn new(segment: &str) -> Self {
+        Self {
+            segment: segment.to_string(),
+            min_width: 0,
+        }
+    }
+}
+
+impl Serialize for StatusBarSegment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if self.min_width == 0 {
+            return serializer.serialize_str(&self.segment);
+        }
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("StatusBarSegment", 2)?;
+        state.serialize_field("segment", &self.segment)?;
+        state.serialize_field("min_width", &self.min_width)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for StatusBarSegment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Name(String),
+            Detailed {
+                segment: String,
+                #[serde(default)]
+                min_width: u16,
+            },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Name(segment) => Self {
+                segment,
+                min_width: 0,
+            },
+            Repr::Detailed { segment, min_width } => Self { segment, min_width },
+        })
+    }
+}
+
+impl JsonSchema for StatusBarSegment {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("StatusBarSegment")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "A status bar segment, written as its name or as an object with the name and the narrowest status bar width it is shown at",
+            "anyOf": [
+                { "type": "string" },
+                {
+                    "type": "object",
+                    "properties": {
+                        "segment": { "type": "string" },
+                        "min_width": { "type": "integer", "minimum": 0, "default": 0 }
+                    },
+                    "required": ["segment"]
+                }
+            ]
+        })
+    }
+}
+
+/// Status bar configuration
+///
+/// The status bar has a left, a center and a right group of segments.
+/// Segments on the left and in the center are separated by " | ", segments
+/// on the right are padded and styled as indicators. When the left group
+/// doesn't fit, its text is cut off with "...".
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StatusBarConfig {
+    /// Segments on the left, in order
+    #[serde(default = "default_status_bar_left")]
+    pub left: Vec<StatusBarSegment>,
+
+    /// Segments centered in the space between the left and right groups
+    #[serde(default)]
+    pub center: Vec<StatusBarSegment>,
+
+    /// Segments on the right, in order
+    #[serde(default = "default_status_bar_right")]
+    pub right: Vec<StatusBarSegment>,
+}
+
+fn default_status_bar_left() -> Vec<StatusBarSegment> {
+    [
+        "mode",
+        "session",
+        "remote",
+        "clipboard",
+        "file_name",
+        "modified",
+        "read_only",
+        "cursor_position",
+        "csv_column",
+        "log_follow",
+        "scroll_link",
+        "selection_size",
+        "diagnostics",
+        "cursor_count",
+        "search_matches",
+        "chord",
+        "messages",
+    ]
+    .into_iter()
+    .map(StatusBarSegment::new)
+    .collect()
+}
+
+fn default_status_bar_right() -> Vec<StatusBarSegment> {
+    [
+        "line_ending",
+        "encoding",
+        "language",
+        "lsp_status",
+        "warnings",
+        "update",
+        "palette",
+    ]
+    .into_iter()
+    .map(StatusBarSegment::new)
+    .collect()
+}
+
+impl StatusBarConfig {
+    /// Whether a segment is shown in any of the groups
+    pub fn contains(&self, segment: &str) -> bool {
+        self.left
+            .iter()
+            .chain(&self.center)
+            .chain(&self.right)
+            .any(|entry| entry.segment == segment)
+    }
+}
+
+impl Default for StatusBarConfig {
+    fn default() -> Self {
+        Self {
+            left: default_status_bar_left(),
+            center: Vec::new(),
+            right: default_status_bar_right(),
         }
     }
 }
@@ -1184,6 +2154,7 @@ impl Default for FileExplorerConfig {
             show_hidden: false,
             show_gitignored: false,
             custom_ignore_patterns: Vec::new(),
+            follow_active_file: false,
             width: default_explorer_width(),
         }
     }
@@ -1198,7 +2169,7 @@ pub struct FileBrowserConfig {
 }
 
 /// A single key in a sequence
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct KeyPress {
     /// Key name (e.g., "a", "Enter", "F1")
     pub key: String,
@@ -1234,6 +2205,13 @@ pub struct Keybinding {
     /// Optional condition (e.g., "mode == insert")
     #[serde(default)]
     pub when: Option<String>,
+
+    /// Context the binding applies in: "editor", "prompt", "explorer",
+    /// "terminal" or "menu" ("global", "popup" and "settings" are also
+    /// accepted). Takes precedence over `when`; bindings with neither apply
+    /// in the editor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Keymap configuration (for built-in and user-defined keymaps)
@@ -1302,10 +2280,62 @@ pub struct OnSaveAction {
     pub enabled: bool,
 }
 
+/// Lint command whose output is shown as diagnostics
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(extend("x-display-field" = "/command"))]
+pub struct LinterConfig {
+    /// The lint command to run (e.g., "shellcheck", "flake8")
+    pub command: String,
+
+    /// Arguments to pass to the linter
+    /// Use "$FILE" to include the file path; it is appended when missing
+    /// and stdin is off
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    /// Whether to pass buffer content via stdin (default: false)
+    #[serde(default)]
+    pub stdin: bool,
+
+    /// Regex matching one problem per output line, with the named groups
+    /// `line` and `message`, and optionally `file`, `col` and `severity`.
+    /// Lines and columns are 1-based.
+    /// Default: "^(?P<file>[^:]+):(?P<line>\d+):(?P<col>\d+): (?P<message>.*)$"
+    #[serde(default = "default_linter_pattern")]
+    pub pattern: String,
+
+    /// Timeout in milliseconds (default: 10000)
+    #[serde(default = "default_on_save_timeout")]
+    pub timeout_ms: u64,
+}
+
+fn default_linter_pattern() -> String {
+    r"^(?P<file>[^:]+):(?P<line>\d+):(?P<col>\d+): (?P<message>.*)$".to_string()
+}
+
 fn default_on_save_timeout() -> u64 {
     10000
 }
 
+/// Regex rules for auto-indent, changing a language's built-in ones
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct IndentRulesConfig {
+    /// A line matching this regex indents the line after it one level more
+    /// (e.g. "\\{\\s*$"). An empty string turns the rule off.
+    #[serde(default)]
+    pub increase_indent_pattern: Option<String>,
+
+    /// A line matching this regex sits one level less than the line above it
+    /// (e.g. "^\\s*\\}"). An empty string turns the rule off.
+    #[serde(default)]
+    pub decrease_indent_pattern: Option<String>,
+
+    /// Characters that, when typed, dedent the line if it matches
+    /// `decrease_indent_pattern` (e.g. "}])")
+    #[serde(default)]
+    pub dedent_triggers: Option<String>,
+}
+
 /// Language-specific configuration
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[schemars(extend("x-display-field" = "/grammar"))]
@@ -1326,6 +2356,15 @@ pub struct LanguageConfig {
     #[serde(default)]
     pub comment_prefix: Option<String>,
 
+    /// Opening delimiter for block comments (e.g. "/*").
+    /// If not specified, a built-in default is used for well-known languages.
+    #[serde(default)]
+    pub block_comment_start: Option<String>,
+
+    /// Closing delimiter for block comments (e.g. "*/").
+    #[serde(default)]
+    pub block_comment_end: Option<String>,
+
     /// Whether to auto-indent
     #[serde(default = "default_true")]
     pub auto_indent: bool,
@@ -1368,6 +2407,86 @@ pub struct LanguageConfig {
     /// Note: Use `formatter` + `format_on_save` for formatting, not on_save
     #[serde(default)]
     pub on_save: Vec<OnSaveAction>,
+
+    /// Lint command run when a file of this language is saved; the problems
+    /// it reports are shown alongside LSP diagnostics
+    #[serde(default)]
+    pub linter: Option<LinterConfig>,
+
+    /// Command used by "Run File" to run a file of this language in a terminal
+    /// Use "{file}" to include the file path (e.g. "python3 {file}")
+    #[serde(default)]
+    pub run_command: Option<String>,
+
+    /// Whether to wrap long lines for this language.
+    /// If not specified, falls back to the global editor.line_wrap setting.
+    #[serde(default)]
+    pub line_wrap: Option<bool>,
+
+    /// Bracket and quote pairs auto-closed when typing, each written as the
+    /// opening and closing character (e.g. "()" or "<>").
+    /// If not specified, the built-in pairs are used; an empty list disables auto-closing.
+    #[serde(default)]
+    pub auto_close_pairs: Option<Vec<String>>,
+
+    /// Regex rules for auto-indent. Rules not specified keep the built-in
+    /// ones for Rust, Python, YAML and C-like languages.
+    #[serde(default)]
+    pub indent_rules: Option<IndentRulesConfig>,
+
+    /// Abbreviations for this language, taking precedence over the global
+    /// `abbreviations` with the same name.
+    #[serde(default)]
+    pub abbreviations: Option<HashMap<String, String>>,
+
+    /// Which lines to trim trailing whitespace from when saving.
+    /// If not specified, falls back to editor.trim_trailing_whitespace_on_save.
+    #[serde(default)]
+    pub trim_trailing_whitespace_on_save: Option<TrimTrailingWhitespace>,
+
+    /// Whether to ensure files end with a newline when saving.
+    /// If not specified, falls back to editor.ensure_final_newline_on_save.
+    #[serde(default, alias = "insert_final_newline")]
+    pub ensure_final_newline_on_save: Option<bool>,
+}
+
+impl LanguageConfig {
+    /// Parse `auto_close_pairs` into (open, close) characters.
+    ///
+    /// Entries that aren't exactly two characters are skipped.
+    /// Returns None when the language doesn't override the pairs.
+    pub fn parsed_auto_close_pairs(&self) -> Option<Vec<(char, char)>> {
+        self.auto_close_pairs.as_ref().map(|pairs| {
+            pairs
+                .iter()
+                .filter_map(|pair| {
+                    let mut chars = pair.chars();
+                    match (chars.next(), chars.next(), chars.next()) {
+                        (Some(open), Some(close), None) => Some((open, close)),
+                        _ => None,
+                    }
+                })
+                .collect()
+        })
+    }
+
+    /// Auto-indent patterns for this language: the built-in ones for
+    /// `language`, with those set in `indent_rules` in their place
+    pub fn indent_patterns(&self, language: &str) -> IndentPatterns {
+        let mut patterns = IndentPatterns::for_language(language);
+        if let Some(rules) = &self.indent_rules {
+            if let Some(increase) = &rules.increase_indent_pattern {
+                patterns.increase = Some(increase.clone());
+            }
+            if let Some(decrease) = &rules.decrease_indent_pattern {
+                patterns.decrease = Some(decrease.clone());
+            }
+            if let Some(triggers) = &rules.dedent_triggers {
+                patterns.dedent_triggers = triggers.clone();
+            }
+        }
+        patterns
+    }
 }
 
 /// Resolved editor configuration for a specific buffer.
@@ -1404,6 +2523,15 @@ pub struct BufferConfig {
 
     /// Path to custom TextMate grammar (if any)
     pub textmate_grammar: Option<std::path::PathBuf>,
+
+    /// Whether new views of this buffer wrap long lines
+    pub line_wrap: bool,
+
+    /// Which lines to trim trailing whitespace from when saving
+    pub trim_trailing_whitespace_on_save: TrimTrailingWhitespace,
+
+    /// Whether to ensure the file ends with a newline when saving
+    pub ensure_final_newline_on_save: bool,
 }
 
 impl BufferConfig {
@@ -1430,6 +2558,9 @@ impl BufferConfig {
             on_save: Vec::new(),
             highlighter: HighlighterPreference::Auto,
             textmate_grammar: None,
+            line_wrap: editor.line_wrap,
+            trim_trailing_whitespace_on_save: editor.trim_trailing_whitespace_on_save,
+            ensure_final_newline_on_save: editor.ensure_final_newline_on_save,
         };
 
         // Apply language-specific overrides if available
@@ -1465,6 +2596,19 @@ impl BufferConfig {
 
                 // TextMate grammar path: from language config
                 config.textmate_grammar = lang_config.textmate_grammar.clone();
+
+                // Line wrap: language setting if specified, else global
+                if let Some(line_wrap) = lang_config.line_wrap {
+                    config.line_wrap = line_wrap;
+                }
+
+                // Whitespace cleanup on save: language setting if specified, else global
+                if let Some(trim) = lang_config.trim_trailing_whitespace_on_save {
+                    config.trim_trailing_whitespace_on_save = trim;
+                }
+                if let Some(final_newline) = lang_config.ensure_final_newline_on_save {
+                    config.ensure_final_newline_on_save = final_newline;
+                }
             }
         }
 
@@ -1599,16 +2743,24 @@ impl Default for Config {
         Self {
             version: 0,
             theme: default_theme_name(),
+            light_theme: default_light_theme_name(),
+            dark_theme: default_dark_theme_name(),
+            syntax_styles: HashMap::new(),
             locale: LocaleName::default(),
+            highlight_missing_translations: false,
             check_for_updates: true,
+            log_level: LogLevel::default(),
             editor: EditorConfig::default(),
             file_explorer: FileExplorerConfig::default(),
             file_browser: FileBrowserConfig::default(),
             clipboard: ClipboardConfig::default(),
             terminal: TerminalConfig::default(),
+            status_bar: StatusBarConfig::default(),
             keybindings: vec![], // User customizations only; defaults come from active_keybinding_map
             keybinding_maps: HashMap::new(), // User-defined maps go here
             active_keybinding_map: default_keybinding_map_name(),
+            abbreviations: HashMap::new(),
+            tasks: HashMap::new(),
             languages: Self::default_languages(),
             lsp: Self::default_lsp_config(),
             warnings: WarningsConfig::default(),
@@ -1750,6 +2902,13 @@ impl MenuConfig {
                         when: Some(context_keys::HAS_SELECTION.to_string()),
                         checkbox: None,
                     },
+                    MenuItem::Action {
+                        label: t!("menu.edit.copy_visible").to_string(),
+                        action: "copy_visible".to_string(),
+                        args: HashMap::new(),
+                        when: Some(context_keys::HAS_SELECTION.to_string()),
+                        checkbox: None,
+                    },
                     MenuItem::DynamicSubmenu {
                         label: t!("menu.edit.copy_with_formatting").to_string(),
                         source: "copy_with_theme".to_string(),
@@ -1865,6 +3024,20 @@ impl MenuConfig {
                         when: None,
                         checkbox: Some(context_keys::LINE_WRAP.to_string()),
                     },
+                    MenuItem::Action {
+                        label: t!("menu.view.indent_guides").to_string(),
+                        action: "toggle_indent_guides".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: Some(context_keys::INDENT_GUIDES.to_string()),
+                    },
+                    MenuItem::Action {
+                        label: t!("menu.view.minimap").to_string(),
+                        action: "toggle_minimap".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: Some(context_keys::MINIMAP.to_string()),
+                    },
                     MenuItem::Action {
                         label: t!("menu.view.mouse_support").to_string(),
                         action: "toggle_mouse_capture".to_string(),
@@ -1988,6 +3161,13 @@ impl MenuConfig {
                         when: None,
                         checkbox: None,
                     },
+                    MenuItem::Action {
+                        label: t!("menu.view.equalize_splits").to_string(),
+                        action: "equalize_splits".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: None,
+                    },
                     MenuItem::Separator { separator: true },
                     MenuItem::Submenu {
                         label: t!("menu.terminal").to_string(),
@@ -2102,6 +3282,13 @@ impl MenuConfig {
                         when: None,
                         checkbox: None,
                     },
+                    MenuItem::Action {
+                        label: t!("menu.selection.shrink_selection").to_string(),
+                        action: "shrink_selection".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: None,
+                    },
                     MenuItem::Separator { separator: true },
                     MenuItem::Action {
                         label: t!("menu.selection.add_cursor_above").to_string(),
@@ -2488,6 +3675,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "rust".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2502,6 +3691,14 @@ impl Config {
                 }),
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: Some("cargo run".to_string()),
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2512,6 +3709,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "javascript".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2526,6 +3725,14 @@ impl Config {
                 }),
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: Some("node {file}".to_string()),
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2536,6 +3743,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "typescript".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2550,6 +3759,14 @@ impl Config {
                 }),
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2560,6 +3777,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "python".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2578,6 +3797,14 @@ impl Config {
                 }),
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: Some("python3 {file}".to_string()),
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2588,6 +3815,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "c".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2602,6 +3831,14 @@ impl Config {
                 }),
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2619,6 +3856,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "cpp".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2633,6 +3872,14 @@ impl Config {
                 }),
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2643,6 +3890,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "c_sharp".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2652,6 +3901,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2677,6 +3934,8 @@ impl Config {
                 ],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2686,6 +3945,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: Some("bash {file}".to_string()),
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2700,6 +3967,8 @@ impl Config {
                 ],
                 grammar: "make".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2709,6 +3978,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2719,6 +3996,8 @@ impl Config {
                 filenames: vec!["Dockerfile".to_string(), "Containerfile".to_string()],
                 grammar: "dockerfile".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2728,6 +4007,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2738,6 +4025,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "json".to_string(),
                 comment_prefix: None,
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2752,6 +4041,14 @@ impl Config {
                 }),
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2762,6 +4059,8 @@ impl Config {
                 filenames: vec!["Cargo.lock".to_string()],
                 grammar: "toml".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2771,6 +4070,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2781,6 +4088,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "yaml".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2795,6 +4104,14 @@ impl Config {
                 }),
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2805,6 +4122,8 @@ impl Config {
                 filenames: vec!["README".to_string()],
                 grammar: "markdown".to_string(),
                 comment_prefix: None,
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2814,6 +4133,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2825,6 +4152,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "go".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2839,6 +4168,14 @@ impl Config {
                 }),
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: Some("go run {file}".to_string()),
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2849,6 +4186,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "odin".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2858,6 +4197,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2868,6 +4215,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "zig".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2877,6 +4226,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2887,6 +4244,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "java".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2896,6 +4255,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2913,6 +4280,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "latex".to_string(),
                 comment_prefix: Some("%".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2922,6 +4291,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2932,6 +4309,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "go".to_string(), // Templ uses Go-like syntax
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2941,6 +4320,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2952,6 +4339,8 @@ impl Config {
                 filenames: vec!["git-rebase-todo".to_string()],
                 grammar: "Git Rebase Todo".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2961,6 +4350,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -2976,6 +4373,8 @@ impl Config {
                 ],
                 grammar: "Git Commit Message".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -2985,6 +4384,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -3000,6 +4407,8 @@ impl Config {
                 ],
                 grammar: "Gitignore".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -3009,6 +4418,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -3019,6 +4436,8 @@ impl Config {
                 filenames: vec![".gitconfig".to_string(), ".gitmodules".to_string()],
                 grammar: "Git Config".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -3028,6 +4447,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -3038,6 +4465,8 @@ impl Config {
                 filenames: vec![".gitattributes".to_string()],
                 grammar: "Git Attributes".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -3047,6 +4476,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -3057,6 +4494,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Typst".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -3066,6 +4505,14 @@ impl Config {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -3732,6 +5179,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "go".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -3746,6 +5195,14 @@ mod tests {
                 }),
                 format_on_save: true,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -3790,4 +5247,103 @@ mod tests {
         let tabs_config = BufferConfig::resolve(&config_with_tabs, Some("makefile"));
         assert_eq!(tabs_config.indent_string(), "\t");
     }
+
+    #[test]
+    fn test_buffer_config_language_line_wrap_override() {
+        let mut config = Config::default();
+        config.editor.line_wrap = true;
+        config.languages.insert(
+            "csv".to_string(),
+            LanguageConfig {
+                line_wrap: Some(false),
+                ..Default::default()
+            },
+        );
+
+        assert!(!BufferConfig::resolve(&config, Some("csv")).line_wrap);
+        assert!(BufferConfig::resolve(&config, Some("rust")).line_wrap);
+        assert!(BufferConfig::resolve(&config, None).line_wrap);
+    }
+
+    #[test]
+    fn test_whitespace_on_save_settings() {
+        // The old boolean form is still accepted
+        let json = r#"{
+            "editor": { "trim_trailing_whitespace_on_save": true },
+            "languages": {
+                "markdown": {
+                    "trim_trailing_whitespace_on_save": "off",
+                    "insert_final_newline": true
+                },
+                "rust": { "trim_trailing_whitespace_on_save": "modified_lines_only" }
+            }
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.editor.trim_trailing_whitespace_on_save,
+            TrimTrailingWhitespace::All
+        );
+
+        let markdown = BufferConfig::resolve(&config, Some("markdown"));
+        assert_eq!(
+            markdown.trim_trailing_whitespace_on_save,
+            TrimTrailingWhitespace::Off
+        );
+        assert!(markdown.ensure_final_newline_on_save);
+
+        let rust = BufferConfig::resolve(&config, Some("rust"));
+        assert_eq!(
+            rust.trim_trailing_whitespace_on_save,
+            TrimTrailingWhitespace::ModifiedLinesOnly
+        );
+        assert!(!rust.ensure_final_newline_on_save);
+    }
+
+    #[test]
+    fn test_auto_close_pairs_setting() {
+        let json = r#"{
+            "editor": { "auto_close": false },
+            "languages": {
+                "html": { "auto_close_pairs": ["<>", "\"\"", "bad", ""] },
+                "text": { "auto_close_pairs": [] }
+            }
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert!(!config.editor.auto_close);
+        assert!(Config::default().editor.auto_close);
+
+        assert_eq!(
+            config.languages["html"].parsed_auto_close_pairs(),
+            Some(vec![('<', '>'), ('"', '"')])
+        );
+        assert_eq!(
+            config.languages["text"].parsed_auto_close_pairs(),
+            Some(vec![])
+        );
+        assert_eq!(LanguageConfig::default().parsed_auto_close_pairs(), None);
+    }
+
+    #[test]
+    fn test_indent_rules_setting() {
+        let json = r#"{
+            "languages": {
+                "python": { "indent_rules": { "decrease_indent_pattern": "", "dedent_triggers": "" } },
+                "text": { "indent_rules": { "increase_indent_pattern": "->$" } }
+            }
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+
+        // Rules not set keep the built-in ones
+        let python = config.languages["python"].indent_patterns("python");
+        assert_eq!(
+            python.increase,
+            IndentPatterns::for_language("python").increase
+        );
+        assert_eq!(python.decrease, Some(String::new()));
+        assert_eq!(python.dedent_triggers, "");
+
+        let text = config.languages["text"].indent_patterns("text");
+        assert_eq!(text.increase.as_deref(), Some("->$"));
+        assert_eq!(text.decrease, None);
+    }
 }
@@ -5,9 +5,12 @@
 //! These are separated from config.rs to allow schema-only builds.
 
 use crate::config::{Config, ConfigError};
+use crate::model::filesystem::{FileSystem, StdFileSystem};
 use crate::partial_config::{Merge, PartialConfig, SessionConfig};
 use serde_json::Value;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 // ============================================================================
 // JSON Utilities
@@ -177,7 +180,7 @@ fn find_changed_paths_recursive(
 
 /// Current config schema version.
 /// Increment this when making breaking changes to config structure.
-pub const CURRENT_CONFIG_VERSION: u32 = 1;
+pub const CURRENT_CONFIG_VERSION: u32 = 3;
 
 /// Apply all necessary migrations to bring a config JSON to the current version.
 pub fn migrate_config(mut value: Value) -> Result<Value, ConfigError> {
@@ -187,8 +190,14 @@ pub fn migrate_config(mut value: Value) -> Result<Value, ConfigError> {
     if version < 1 {
         value = migrate_v0_to_v1(value)?;
     }
+    if version < 2 {
+        value = migrate_v1_to_v2(value)?;
+    }
+    if version < 3 {
+        value = migrate_v2_to_v3(value)?;
+    }
     // Future migrations:
-    // if version < 2 { value = migrate_v1_to_v2(value)?; }
+    // if version < 4 { value = migrate_v3_to_v4(value)?; }
 
     Ok(value)
 }
@@ -215,6 +224,53 @@ fn migrate_v0_to_v1(mut value: Value) -> Result<Value, ConfigError> {
     Ok(value)
 }
 
+/// Migration from v1 to v2.
+/// Keybindings gained a `context` field; flat bindings that specified neither
+/// `context` nor `when` always applied in the editor, so say so explicitly.
+fn migrate_v1_to_v2(mut value: Value) -> Result<Value, ConfigError> {
+    if let Value::Object(ref mut map) = value {
+        map.insert("version".to_string(), Value::Number(2.into()));
+
+        if let Some(Value::Array(ref mut bindings)) = map.get_mut("keybindings") {
+            for binding in bindings.iter_mut() {
+                if let Value::Object(ref mut binding_map) = binding {
+                    let has_context = binding_map
+                        .get("context")
+                        .is_some_and(|context| !context.is_null());
+                    let has_when = binding_map.get("when").is_some_and(|when| !when.is_null());
+                    if !has_context && !has_when {
+                        binding_map.insert("context".to_string(), Value::from("editor"));
+                    }
+                }
+            }
+        }
+    }
+    Ok(value)
+}
+
+/// Migration from v2 to v3.
+/// The `relative_line_numbers` flag became `line_number_mode`. The flag kept
+/// the absolute number on the cursor line, which is now the "hybrid" mode.
+fn migrate_v2_to_v3(mut value: Value) -> Result<Value, ConfigError> {
+    if let Value::Object(ref mut map) = value {
+        map.insert("version".to_string(), Value::Number(3.into()));
+
+        if let Some(Value::Object(ref mut editor_map)) = map.get_mut("editor") {
+            if let Some(relative) = editor_map.remove("relative_line_numbers") {
+                let mode = if relative.as_bool() == Some(true) {
+                    "hybrid"
+                } else {
+                    "absolute"
+                };
+                editor_map
+                    .entry("line_number_mode")
+                    .or_insert(Value::from(mode));
+            }
+        }
+    }
+    Ok(value)
+}
+
 /// Represents a configuration layer in the 4-level hierarchy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConfigLayer {
@@ -247,6 +303,7 @@ impl ConfigLayer {
 pub struct ConfigResolver {
     dir_context: DirectoryContext,
     working_dir: PathBuf,
+    filesystem: Arc<dyn FileSystem + Send + Sync>,
 }
 
 impl ConfigResolver {
@@ -255,9 +312,44 @@ impl ConfigResolver {
         Self {
             dir_context,
             working_dir,
+            filesystem: Arc::new(StdFileSystem),
         }
     }
 
+    /// Read and write config files through `filesystem` instead of the local disk.
+    pub fn with_filesystem(mut self, filesystem: Arc<dyn FileSystem + Send + Sync>) -> Self {
+        self.filesystem = filesystem;
+        self
+    }
+
+    /// Read a config file, or None if it doesn't exist.
+    fn read_config_file(&self, path: &Path) -> Result<Option<String>, ConfigError> {
+        if !self.filesystem.exists(path) {
+            return Ok(None);
+        }
+        let bytes = self
+            .filesystem
+            .read_file(path)
+            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
+        String::from_utf8(bytes)
+            .map(Some)
+            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Write a config file in place, creating its directory if needed.
+    fn write_config_file(&self, path: &Path, contents: &str) -> Result<(), ConfigError> {
+        if let Some(parent_dir) = path.parent() {
+            self.filesystem
+                .create_dir_all(parent_dir)
+                .map_err(|e| ConfigError::IoError(format!("{}: {}", parent_dir.display(), e)))?;
+        }
+        // Truncate and rewrite rather than replace, so symlinked configs keep their link
+        self.filesystem
+            .create_file(path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))
+    }
+
     /// Load all layers and merge them into a resolved Config.
     ///
     /// Layers are merged from highest to lowest precedence:
@@ -299,12 +391,12 @@ impl ConfigResolver {
     /// Checks new location first (.fresh/config.json), falls back to legacy (config.json).
     pub fn project_config_path(&self) -> PathBuf {
         let new_path = self.working_dir.join(".fresh").join("config.json");
-        if new_path.exists() {
+        if self.filesystem.exists(&new_path) {
             return new_path;
         }
         // Fall back to legacy location for backward compatibility
         let legacy_path = self.working_dir.join("config.json");
-        if legacy_path.exists() {
+        if self.filesystem.exists(&legacy_path) {
             return legacy_path;
         }
         // Return new path as default for new projects
@@ -365,12 +457,9 @@ impl ConfigResolver {
 
     /// Load a layer from a specific path, applying migrations if needed.
     fn load_layer_from_path(&self, path: &Path) -> Result<Option<PartialConfig>, ConfigError> {
-        if !path.exists() {
+        let Some(content) = self.read_config_file(path)? else {
             return Ok(None);
-        }
-
-        let content = std::fs::read_to_string(path)
-            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
+        };
 
         // Parse as raw JSON first
         let value: Value = serde_json::from_str(&content)
@@ -416,20 +505,11 @@ impl ConfigResolver {
             ConfigLayer::System => unreachable!(),
         };
 
-        // Ensure parent directory exists
-        if let Some(parent_dir) = path.parent() {
-            std::fs::create_dir_all(parent_dir)
-                .map_err(|e| ConfigError::IoError(format!("{}: {}", parent_dir.display(), e)))?;
-        }
-
         // Read existing file content (if any) as PartialConfig.
         // This preserves any manual edits made externally while the editor was running.
-        let existing: PartialConfig = if path.exists() {
-            let content = std::fs::read_to_string(&path)
-                .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
-            serde_json::from_str(&content).unwrap_or_default()
-        } else {
-            PartialConfig::default()
+        let existing: PartialConfig = match self.read_config_file(&path)? {
+            Some(content) => serde_json::from_str(&content).unwrap_or_default(),
+            None => PartialConfig::default(),
         };
 
         // Merge: delta values take precedence, existing fills in gaps where delta is None
@@ -445,8 +525,7 @@ impl ConfigResolver {
 
         let json = serde_json::to_string_pretty(&clean_merged)
             .map_err(|e| ConfigError::SerializeError(e.to_string()))?;
-        std::fs::write(&path, json)
-            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
+        self.write_config_file(&path, &json)?;
 
         Ok(())
     }
@@ -495,19 +574,12 @@ impl ConfigResolver {
             ConfigLayer::System => unreachable!(),
         };
 
-        // Ensure parent directory exists
-        if let Some(parent_dir) = path.parent() {
-            std::fs::create_dir_all(parent_dir)
-                .map_err(|e| ConfigError::IoError(format!("{}: {}", parent_dir.display(), e)))?;
-        }
-
         // Read existing file content as JSON
-        let mut result: Value = if path.exists() {
-            let content = std::fs::read_to_string(&path)
-                .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
-            serde_json::from_str(&content).unwrap_or(Value::Object(Default::default()))
-        } else {
-            Value::Object(Default::default())
+        let mut result: Value = match self.read_config_file(&path)? {
+            Some(content) => {
+                serde_json::from_str(&content).unwrap_or(Value::Object(Default::default()))
+            }
+            None => Value::Object(Default::default()),
         };
 
         // For each changed path, update the file:
@@ -532,8 +604,7 @@ impl ConfigResolver {
 
         let json = serde_json::to_string_pretty(&clean)
             .map_err(|e| ConfigError::SerializeError(e.to_string()))?;
-        std::fs::write(&path, json)
-            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
+        self.write_config_file(&path, &json)?;
 
         Ok(())
     }
@@ -562,19 +633,12 @@ impl ConfigResolver {
             ConfigLayer::System => unreachable!(),
         };
 
-        // Ensure parent directory exists
-        if let Some(parent_dir) = path.parent() {
-            std::fs::create_dir_all(parent_dir)
-                .map_err(|e| ConfigError::IoError(format!("{}: {}", parent_dir.display(), e)))?;
-        }
-
         // Read existing file content as JSON
-        let mut config_value: Value = if path.exists() {
-            let content = std::fs::read_to_string(&path)
-                .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
-            serde_json::from_str(&content).unwrap_or(Value::Object(Default::default()))
-        } else {
-            Value::Object(Default::default())
+        let mut config_value: Value = match self.read_config_file(&path)? {
+            Some(content) => {
+                serde_json::from_str(&content).unwrap_or(Value::Object(Default::default()))
+            }
+            None => Value::Object(Default::default()),
         };
 
         // Apply deletions first
@@ -598,8 +662,7 @@ impl ConfigResolver {
 
         let json = serde_json::to_string_pretty(&clean)
             .map_err(|e| ConfigError::SerializeError(e.to_string()))?;
-        std::fs::write(&path, json)
-            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
+        self.write_config_file(&path, &json)?;
 
         Ok(())
     }
@@ -608,16 +671,9 @@ impl ConfigResolver {
     pub fn save_session(&self, session: &SessionConfig) -> Result<(), ConfigError> {
         let path = self.session_config_path();
 
-        // Ensure .fresh directory exists
-        if let Some(parent_dir) = path.parent() {
-            std::fs::create_dir_all(parent_dir)
-                .map_err(|e| ConfigError::IoError(format!("{}: {}", parent_dir.display(), e)))?;
-        }
-
         let json = serde_json::to_string_pretty(session)
             .map_err(|e| ConfigError::SerializeError(e.to_string()))?;
-        std::fs::write(&path, json)
-            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
+        self.write_config_file(&path, &json)?;
 
         tracing::debug!("Saved session config to {}", path.display());
         Ok(())
@@ -634,8 +690,9 @@ impl ConfigResolver {
     /// Clear the session config file on editor exit.
     pub fn clear_session(&self) -> Result<(), ConfigError> {
         let path = self.session_config_path();
-        if path.exists() {
-            std::fs::remove_file(&path)
+        if self.filesystem.exists(&path) {
+            self.filesystem
+                .remove_file(&path)
                 .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
             tracing::debug!("Cleared session config at {}", path.display());
         }
@@ -1006,6 +1063,11 @@ impl DirectoryContext {
         self.prompt_history_path("goto_line")
     }
 
+    /// Get the command palette usage file path
+    pub fn command_usage_path(&self) -> std::path::PathBuf {
+        self.data_dir.join("command_usage.json")
+    }
+
     /// Get the terminals root directory
     pub fn terminals_dir(&self) -> std::path::PathBuf {
         self.data_dir.join("terminals")
@@ -1258,7 +1320,58 @@ mod tests {
 
         let migrated = migrate_config(input).unwrap();
 
-        assert_eq!(migrated.get("version"), Some(&serde_json::json!(1)));
+        assert_eq!(
+            migrated.get("version"),
+            Some(&serde_json::json!(CURRENT_CONFIG_VERSION))
+        );
+    }
+
+    #[test]
+    fn migration_sets_editor_context_on_flat_keybindings() {
+        let input = serde_json::json!({
+            "version": 1,
+            "keybindings": [
+                {"key": "k", "modifiers": ["ctrl"], "action": "save"},
+                {"key": "k", "modifiers": ["ctrl"], "action": "quit", "when": "prompt"},
+                {"key": "j", "action": "noop", "context": "terminal"}
+            ]
+        });
+
+        let migrated = migrate_config(input).unwrap();
+
+        let bindings = migrated.get("keybindings").unwrap().as_array().unwrap();
+        assert_eq!(
+            bindings[0].get("context"),
+            Some(&serde_json::json!("editor"))
+        );
+        assert!(bindings[1].get("context").is_none());
+        assert_eq!(
+            bindings[2].get("context"),
+            Some(&serde_json::json!("terminal"))
+        );
+    }
+
+    #[test]
+    fn migration_converts_relative_line_numbers_to_mode() {
+        let input = serde_json::json!({
+            "version": 2,
+            "editor": {"relative_line_numbers": true}
+        });
+
+        let migrated = migrate_config(input).unwrap();
+
+        let editor = migrated.get("editor").unwrap();
+        assert_eq!(
+            editor.get("line_number_mode"),
+            Some(&serde_json::json!("hybrid"))
+        );
+        assert!(editor.get("relative_line_numbers").is_none());
+
+        let partial: PartialConfig = serde_json::from_value(migrated).unwrap();
+        assert_eq!(
+            partial.editor.unwrap().line_number_mode,
+            Some(crate::config::LineNumberMode::Hybrid)
+        );
     }
 
     #[test]
@@ -1343,6 +1456,32 @@ mod tests {
         assert!(!resolver.session_config_path().exists());
     }
 
+    #[test]
+    fn resolver_uses_provided_filesystem() {
+        use crate::model::virtual_filesystem::VirtualFileSystem;
+
+        let fs = VirtualFileSystem::new();
+        let dir_context = DirectoryContext::for_testing(Path::new("/home/user"));
+        let resolver = ConfigResolver::new(dir_context, PathBuf::from("/project"))
+            .with_filesystem(Arc::new(fs.clone()));
+        fs.insert_file(
+            &resolver.user_config_path(),
+            br#"{"editor": {"tab_size": 2}}"#.to_vec(),
+        );
+
+        let mut session = SessionConfig::new();
+        session.set_theme(crate::config::ThemeName::from("dark"));
+        resolver.save_session(&session).unwrap();
+
+        let session_path = resolver.session_config_path();
+        assert!(fs.exists(&session_path));
+        assert!(!session_path.exists());
+
+        let config = resolver.resolve().unwrap();
+        assert_eq!(config.editor.tab_size, 2);
+        assert_eq!(config.theme, crate::config::ThemeName::from("dark"));
+    }
+
     #[test]
     fn load_session_returns_empty_when_no_file() {
         let (_temp, resolver) = create_test_resolver();
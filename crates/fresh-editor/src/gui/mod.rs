@@ -46,7 +46,8 @@ pub fn run_gui(
     let log_path = log_file
         .cloned()
         .unwrap_or_else(crate::services::log_dirs::main_log_path);
-    let _tracing_handles = crate::services::tracing_setup::init_global(&log_path);
+    let tracing_handles =
+        crate::services::tracing_setup::init_global(&log_path, crate::config::LogLevel::default());
     tracing::info!("GUI mode starting");
 
     let dir_context = DirectoryContext::from_system()?;
@@ -59,6 +60,9 @@ pub fn run_gui(
     } else {
         config::Config::load_with_layers(&dir_context, &working_dir)
     };
+    if let Some(handles) = &tracing_handles {
+        handles.level.set(loaded_config.log_level);
+    }
 
     // On macOS GUI, auto-select the macos-gui keybinding map (Cmd-key shortcuts)
     // unless the user has explicitly set a different keymap.
@@ -100,6 +104,7 @@ pub fn run_gui(
 
         // ratatui-wgpu does not render a hardware cursor.
         editor.set_software_cursor_only(true);
+        editor.set_editor_log_path(log_path.clone());
 
         let workspace_enabled = !no_session_flag && file_locations.is_empty();
 
@@ -111,6 +116,7 @@ pub fn run_gui(
             editor.show_file_explorer();
         }
 
+        editor.set_workspace_enabled(workspace_enabled);
         if workspace_enabled {
             match editor.try_restore_workspace() {
                 Ok(true) => tracing::info!("Workspace restored"),
@@ -19,8 +19,12 @@ use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::RwLock;
 
+#[cfg(feature = "runtime")]
+pub mod format;
 pub mod runtime_backend;
 
+pub use runtime_backend::{highlight_missing, set_highlight_missing};
+
 /// Type alias for the nested plugin strings map.
 /// Structure: plugin_name -> locale -> key -> translated_string
 type PluginStringsMap = HashMap<String, HashMap<String, HashMap<String, String>>>;
@@ -43,7 +47,16 @@ pub fn translate_plugin_string(
     key: &str,
     args: &HashMap<String, String>,
 ) -> String {
-    let locale = current_locale();
+    translate_plugin_string_in(plugin_name, key, args, &current_locale())
+}
+
+/// Translate a string for a plugin into a specific locale.
+pub fn translate_plugin_string_in(
+    plugin_name: &str,
+    key: &str,
+    args: &HashMap<String, String>,
+    locale: &str,
+) -> String {
     let all_strings = PLUGIN_STRINGS.read().unwrap();
 
     let plugin_map: &HashMap<String, HashMap<String, String>> = match all_strings.get(plugin_name) {
@@ -59,11 +72,22 @@ pub fn translate_plugin_string(
         }
     };
 
-    // Try current locale, then fallback to English
-    let lang_map: Option<&HashMap<String, String>> =
-        plugin_map.get(&locale).or_else(|| plugin_map.get("en"));
+    // Try the requested locale, then fall back to English for this key
+    let translated = plugin_map.get(locale).and_then(|m| m.get(key));
+    let fallback = || {
+        plugin_map
+            .get("en")
+            .and_then(|m| m.get(key))
+            .map(|english| {
+                if locale != "en" && highlight_missing() {
+                    runtime_backend::mark_missing(english)
+                } else {
+                    english.clone()
+                }
+            })
+    };
 
-    let template: &String = match lang_map.and_then(|m| m.get(key)) {
+    let template: String = match translated.cloned().or_else(fallback) {
         Some(t) => t,
         None => {
             tracing::debug!(
@@ -71,14 +95,14 @@ pub fn translate_plugin_string(
                 key,
                 plugin_name,
                 locale,
-                plugin_map.get(&locale).or_else(|| plugin_map.get("en")).map(|m| m.keys().take(5).collect::<Vec<_>>())
+                plugin_map.get(locale).or_else(|| plugin_map.get("en")).map(|m| m.keys().take(5).collect::<Vec<_>>())
             );
             return key.to_string();
         }
     };
 
     // Simple interpolation: %{variable}
-    let mut result = template.clone();
+    let mut result = template;
     for (k, v) in args {
         result = result.replace(&format!("%{{{}}}", k), v);
     }
@@ -0,0 +1,118 @@
+//! Locale-aware formatting of numbers, file sizes, dates and times.
+//!
+//! The conventions come from the active locale's strings, so translators
+//! control them like any other text:
+//! - `locale.decimal_separator`
+//! - `locale.size_b` .. `locale.size_gb` (unit and its placement)
+//! - `locale.date_format` and `locale.time_format` (chrono strftime patterns)
+//! - `locale.just_now`, `locale.minutes_ago`, `locale.hours_ago`, `locale.days_ago`
+
+use std::time::SystemTime;
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local};
+use rust_i18n::t;
+
+/// Patterns used when a locale's pattern is not valid strftime
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+const DEFAULT_TIME_FORMAT: &str = "%H:%M";
+
+/// Replace the `.` of a formatted number with the locale's decimal separator
+fn localize_decimal(formatted: &str, separator: &str) -> String {
+    formatted.replacen('.', separator, 1)
+}
+
+/// `pattern` if chrono can format with it, otherwise `default`
+fn valid_pattern<'a>(pattern: &'a str, default: &'a str) -> &'a str {
+    if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+        tracing::warn!("Invalid locale date/time pattern '{}'", pattern);
+        default
+    } else {
+        pattern
+    }
+}
+
+/// A number with `precision` decimals, e.g. "1.5" or "1,5"
+pub fn format_decimal(value: f64, precision: usize) -> String {
+    localize_decimal(
+        &format!("{:.*}", precision, value),
+        &t!("locale.decimal_separator"),
+    )
+}
+
+/// File size in human-readable form, e.g. "1.5 KB" or "1,5 Ko"
+pub fn format_size(size: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    let scaled = |unit: u64| format_decimal(size as f64 / unit as f64, 1);
+    if size >= GB {
+        t!("locale.size_gb", size = scaled(GB)).to_string()
+    } else if size >= MB {
+        t!("locale.size_mb", size = scaled(MB)).to_string()
+    } else if size >= KB {
+        t!("locale.size_kb", size = scaled(KB)).to_string()
+    } else {
+        t!("locale.size_b", size = size).to_string()
+    }
+}
+
+/// A calendar date in the locale's order, e.g. "2024-03-01" or "01.03.2024"
+pub fn format_date(datetime: &DateTime<Local>) -> String {
+    let pattern = t!("locale.date_format");
+    datetime
+        .format(valid_pattern(&pattern, DEFAULT_DATE_FORMAT))
+        .to_string()
+}
+
+/// Time of day for the status bar clock, e.g. "14:05"
+pub fn format_clock(datetime: &DateTime<Local>) -> String {
+    let pattern = t!("locale.time_format");
+    datetime
+        .format(valid_pattern(&pattern, DEFAULT_TIME_FORMAT))
+        .to_string()
+}
+
+/// How long ago `time` was, e.g. "5 min ago", or its date after a week
+pub fn format_relative_time(time: SystemTime) -> String {
+    match SystemTime::now().duration_since(time) {
+        Ok(duration) => {
+            let secs = duration.as_secs();
+            if secs < 60 {
+                t!("locale.just_now").to_string()
+            } else if secs < 3600 {
+                t!("locale.minutes_ago", count = secs / 60).to_string()
+            } else if secs < 86400 {
+                t!("locale.hours_ago", count = secs / 3600).to_string()
+            } else if secs < 86400 * 7 {
+                t!("locale.days_ago", count = secs / 86400).to_string()
+            } else {
+                format_date(&time.into())
+            }
+        }
+        // Time is in the future
+        Err(_) => format_date(&time.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_localize_decimal() {
+        assert_eq!(localize_decimal("1.5", "."), "1.5");
+        assert_eq!(localize_decimal("1.5", ","), "1,5");
+        assert_eq!(localize_decimal("12", ","), "12");
+    }
+
+    #[test]
+    fn test_valid_pattern() {
+        assert_eq!(valid_pattern("%d.%m.%Y", DEFAULT_DATE_FORMAT), "%d.%m.%Y");
+        assert_eq!(
+            valid_pattern("%Q", DEFAULT_DATE_FORMAT),
+            DEFAULT_DATE_FORMAT
+        );
+    }
+}
@@ -2,10 +2,15 @@
 //!
 //! This backend replaces the compile-time macro expansion with runtime JSON parsing,
 //! significantly reducing compiler memory usage while maintaining the same functionality.
+//!
+//! Keys missing from the active locale fall back to English one key at a time
+//! (rust-i18n's `fallback = "en"`). With `set_highlight_missing(true)` those
+//! fallbacks are wrapped in `⟦…⟧` so untranslated strings stand out in the UI.
 
 use once_cell::sync::Lazy;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::RwLock;
 
 /// Embedded locale JSON files (same binary size as macro approach)
@@ -73,6 +78,45 @@ const EMBEDDED_LOCALES: &[(&str, &str)] = &[
 static TRANSLATIONS: Lazy<RwLock<HashMap<String, HashMap<&'static str, &'static str>>>> =
     Lazy::new(|| RwLock::new(HashMap::new()));
 
+/// Whether English fallbacks are marked (the `highlight_missing_translations` option)
+static HIGHLIGHT_MISSING: AtomicBool = AtomicBool::new(false);
+
+/// Marked English strings, built once per key while highlighting is on
+static MARKED_FALLBACKS: Lazy<RwLock<HashMap<&'static str, &'static str>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Mark strings that fall back to English for a missing key
+pub fn set_highlight_missing(enabled: bool) {
+    HIGHLIGHT_MISSING.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether strings that fall back to English are marked
+pub fn highlight_missing() -> bool {
+    HIGHLIGHT_MISSING.load(Ordering::Relaxed)
+}
+
+/// Wrap an untranslated string so it stands out
+pub fn mark_missing(text: &str) -> String {
+    format!("⟦{}⟧", text)
+}
+
+/// The English string for `key`, marked as missing a translation
+fn marked_fallback(key: &str) -> Option<&'static str> {
+    if let Some(marked) = MARKED_FALLBACKS.read().unwrap().get(key) {
+        return Some(*marked);
+    }
+    ensure_loaded("en");
+    let (key_static, english) = TRANSLATIONS
+        .read()
+        .unwrap()
+        .get("en")?
+        .get_key_value(key)
+        .map(|(k, v)| (*k, *v))?;
+    let marked: &'static str = Box::leak(mark_missing(english).into_boxed_str());
+    MARKED_FALLBACKS.write().unwrap().insert(key_static, marked);
+    Some(marked)
+}
+
 /// Parse and flatten a locale's JSON, leaking strings for 'static lifetime
 fn parse_locale(json_str: &str) -> HashMap<&'static str, &'static str> {
     let value: Value = serde_json::from_str(json_str).expect("Valid JSON");
@@ -144,8 +188,15 @@ impl rust_i18n::Backend for RuntimeBackend {
 
     fn translate(&self, locale: &str, key: &str) -> Option<&str> {
         ensure_loaded(locale);
-        let translations = TRANSLATIONS.read().unwrap();
-        translations.get(locale)?.get(key).copied()
+        let translated = TRANSLATIONS
+            .read()
+            .unwrap()
+            .get(locale)
+            .and_then(|strings| strings.get(key).copied());
+        if translated.is_none() && locale != "en" && highlight_missing() {
+            return marked_fallback(key);
+        }
+        translated
     }
 }
 
@@ -222,6 +273,18 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_marked_fallback() {
+        let english = RuntimeBackend::new()
+            .translate("en", "action.copy")
+            .unwrap();
+        assert_eq!(
+            marked_fallback("action.copy"),
+            Some(&*mark_missing(english))
+        );
+        assert_eq!(marked_fallback("nonexistent.key"), None);
+    }
+
     #[test]
     fn test_lazy_loading() {
         let backend = RuntimeBackend::new();
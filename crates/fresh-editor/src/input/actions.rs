@@ -5,10 +5,13 @@ use crate::input::line_move::{move_lines, LineMoveDirection};
 use crate::model::buffer::{Buffer, LineEnding};
 use crate::model::cursor::{Cursors, Position2D, SelectionMode};
 use crate::model::event::{CursorId, Event};
-use crate::primitives::display_width::{byte_offset_at_visual_column, str_width};
+use crate::primitives::display_width::{byte_offset_at_visual_column, char_width, str_width};
+use crate::primitives::indent_pattern::PatternIndentCalculator;
+use crate::primitives::line_iterator::LineIterator;
+use crate::primitives::number_literal::{add_to_number, number_at};
 use crate::primitives::word_navigation::{
     find_word_end, find_word_end_right, find_word_start, find_word_start_left,
-    find_word_start_right,
+    find_word_start_right, words_to_transpose,
 };
 use crate::state::EditorState;
 use std::ops::Range;
@@ -399,31 +402,60 @@ fn convert_block_selection_to_cursors(
     events
 }
 
+/// Bracket and quote pairs auto-closed in a language that doesn't configure its own.
+pub fn default_auto_close_pairs(language: &str) -> Vec<(char, char)> {
+    let mut pairs = vec![('(', ')'), ('[', ']'), ('{', '}')];
+    // Disable auto-closing quotes in plain text files
+    if language != "text" {
+        pairs.push(('"', '"'));
+        pairs.push(('`', '`'));
+        // Disable auto-closing single quotes in markdown (used as apostrophes)
+        if !matches!(language, "markdown" | "mdx") {
+            pairs.push(('\'', '\''));
+        }
+    }
+    pairs
+}
+
 /// Get the matching close character for auto-pairing.
 pub fn get_auto_close_char(ch: char, auto_indent: bool, language: &str) -> Option<char> {
     if !auto_indent {
         return None;
     }
-    // Disable auto-closing quotes in plain text files
-    if language == "text" && matches!(ch, '"' | '\'' | '`') {
-        return None;
-    }
-    // Disable auto-closing single quotes in markdown (used as apostrophes)
-    if matches!(language, "markdown" | "mdx") && ch == '\'' {
+    close_char_for(&default_auto_close_pairs(language), ch)
+}
+
+/// The bracket and quote pairs auto-closed in this buffer.
+fn auto_close_pairs(state: &EditorState) -> Vec<(char, char)> {
+    state
+        .buffer_settings
+        .auto_close_pairs
+        .clone()
+        .unwrap_or_else(|| default_auto_close_pairs(&state.language))
+}
+
+/// The closing character paired with `open`, if any.
+fn close_char_for(pairs: &[(char, char)], open: char) -> Option<char> {
+    pairs
+        .iter()
+        .find(|(pair_open, _)| *pair_open == open)
+        .map(|(_, close)| *close)
+}
+
+/// The character starting at byte `pos`, if any.
+fn char_at(buffer: &Buffer, pos: usize) -> Option<char> {
+    if pos >= buffer.len() {
         return None;
     }
-    match ch {
-        '(' => Some(')'),
-        '[' => Some(']'),
-        '{' => Some('}'),
-        '"' => Some('"'),
-        '\'' => Some('\''),
-        '`' => Some('`'),
-        _ => None,
-    }
+    let end = buffer.next_char_boundary(pos);
+    std::str::from_utf8(&buffer.slice_bytes(pos..end))
+        .ok()?
+        .chars()
+        .next()
 }
 
-/// Calculate the correct indent for a closing delimiter using tree-sitter.
+/// Calculate the correct indent for a closing delimiter, using tree-sitter
+/// when the buffer has a grammar.
 fn calculate_closing_delimiter_indent(
     state: &mut EditorState,
     insert_position: usize,
@@ -437,7 +469,14 @@ fn calculate_closing_delimiter_indent(
             .calculate_dedent_for_delimiter(&state.buffer, insert_position, ch, language, tab_size)
             .unwrap_or(0)
     } else {
-        0
+        // No tree-sitter grammar: match the unclosed delimiter by pattern
+        PatternIndentCalculator::calculate_dedent_for_delimiter(
+            &state.buffer,
+            insert_position,
+            ch,
+            tab_size,
+        )
+        .unwrap_or(0)
     }
 }
 
@@ -522,11 +561,16 @@ fn handle_skip_over_with_dedent(
 }
 
 /// Handle simple skip-over: move cursor past existing closing bracket/quote.
-fn handle_skip_over(events: &mut Vec<Event>, cursor_id: CursorId, insert_position: usize) {
+fn handle_skip_over(
+    events: &mut Vec<Event>,
+    cursor_id: CursorId,
+    insert_position: usize,
+    ch: char,
+) {
     events.push(Event::MoveCursor {
         cursor_id,
         old_position: insert_position,
-        new_position: insert_position + 1,
+        new_position: insert_position + ch.len_utf8(),
         old_anchor: None,
         new_anchor: None,
         old_sticky_column: 0,
@@ -570,10 +614,68 @@ fn handle_auto_dedent(
     });
 }
 
+/// Handle dedent by the language's indent rules: when typing a dedent trigger
+/// makes the line match the decrease rule, move the line left and insert the
+/// character. Returns true if handled (caller should continue to next cursor).
+fn handle_rules_dedent(
+    state: &EditorState,
+    events: &mut Vec<Event>,
+    cursor_id: CursorId,
+    ch: char,
+    insert_position: usize,
+    line_start: usize,
+    tab_size: usize,
+) -> bool {
+    let line_number = state.buffer.get_line_number(line_start);
+    let Some(bytes) = state.buffer.get_line(line_number) else {
+        return false;
+    };
+    let line = String::from_utf8_lossy(&bytes);
+    let line = line.trim_end_matches(['\n', '\r']);
+    let offset = insert_position - line_start;
+    let indent_len = line.len() - line.trim_start_matches([' ', '\t']).len();
+    // Typing inside the indentation leaves it alone
+    if offset < indent_len || offset > line.len() || !line.is_char_boundary(offset) {
+        return false;
+    }
+
+    let typed = format!("{}{}{}", &line[..offset], ch, &line[offset..]);
+    let Some(indent) = PatternIndentCalculator::calculate_dedent_with_rules(
+        &state.buffer,
+        line_start,
+        &typed,
+        tab_size,
+        &state.buffer_settings.indent_rules,
+    ) else {
+        return false;
+    };
+
+    events.push(Event::Delete {
+        range: line_start..line_start + indent_len,
+        deleted_text: line[..indent_len].to_string(),
+        cursor_id,
+    });
+    let indent_str = indent_to_string(indent, state.buffer_settings.use_tabs, tab_size);
+    let indent_byte_len = indent_str.len();
+    if indent_byte_len > 0 {
+        events.push(Event::Insert {
+            position: line_start,
+            text: indent_str,
+            cursor_id,
+        });
+    }
+    events.push(Event::Insert {
+        position: insert_position - indent_len + indent_byte_len,
+        text: ch.to_string(),
+        cursor_id,
+    });
+    true
+}
+
 /// Check if auto-close should happen based on character after cursor.
-fn should_auto_close(char_after: Option<u8>) -> bool {
+fn should_auto_close(char_after: Option<char>) -> bool {
     let is_alphanumeric_after = char_after
-        .map(|b| b.is_ascii_alphanumeric() || b == b'_')
+        .map(|c| c.is_alphanumeric() || c == '_')
         .unwrap_or(false);
     !is_alphanumeric_after
 }
@@ -588,6 +690,7 @@ fn handle_auto_close(
 ) {
     // Insert opening + closing character
     let text = format!("{}{}", ch, close_char);
+    let text_len = text.len();
     events.push(Event::Insert {
         position: insert_position,
         text,
@@ -596,8 +699,8 @@ fn handle_auto_close(
     // Move cursor between the brackets
     events.push(Event::MoveCursor {
         cursor_id,
-        old_position: insert_position + 2,
-        new_position: insert_position + 1,
+        old_position: insert_position + text_len,
+        new_position: insert_position + ch.len_utf8(),
         old_anchor: None,
         new_anchor: None,
         old_sticky_column: 0,
@@ -605,14 +708,54 @@ fn handle_auto_close(
     });
 }
 
+/// Handle auto-surround: wrap the selection in the pair instead of replacing it,
+/// keeping the wrapped text selected.
+fn handle_surround(
+    events: &mut Vec<Event>,
+    cursor_id: CursorId,
+    selection: Range<usize>,
+    cursor_at_end: bool,
+    open: char,
+    close: char,
+) {
+    // Insert the closing character first so the selection start stays valid
+    events.push(Event::Insert {
+        position: selection.end,
+        text: close.to_string(),
+        cursor_id,
+    });
+    events.push(Event::Insert {
+        position: selection.start,
+        text: open.to_string(),
+        cursor_id,
+    });
+    let start = selection.start + open.len_utf8();
+    let end = selection.end + open.len_utf8();
+    let (new_position, new_anchor) = if cursor_at_end {
+        (end, start)
+    } else {
+        (start, end)
+    };
+    events.push(Event::MoveCursor {
+        cursor_id,
+        old_position: start,
+        new_position,
+        old_anchor: None,
+        new_anchor: Some(new_anchor),
+        old_sticky_column: 0,
+        new_sticky_column: 0,
+    });
+}
+
 /// Cursor context data collected before processing insertions.
 struct InsertCursorData {
     cursor_id: CursorId,
     selection: Option<Range<usize>>,
+    cursor_at_end: bool,
     insert_position: usize,
     line_start: usize,
     only_spaces: bool,
-    char_after: Option<u8>,
+    char_after: Option<char>,
     deleted_text: Option<String>,
 }
 
@@ -634,7 +777,8 @@ fn collect_insert_cursor_data(state: &mut EditorState, cursors: &Cursors) -> Vec
                 .as_ref()
                 .map(|r| r.start)
                 .unwrap_or(cursor.position);
-            (*cursor_id, selection, insert_position)
+            let cursor_at_end = selection.as_ref().is_some_and(|r| cursor.position == r.end);
+            (*cursor_id, selection, cursor_at_end, insert_position)
         })
         .collect();
 
@@ -643,7 +787,7 @@ fn collect_insert_cursor_data(state: &mut EditorState, cursors: &Cursors) -> Vec
     // Collect all cursor data with buffer access
     cursor_info
         .into_iter()
-        .map(|(cursor_id, selection, insert_position)| {
+        .map(|(cursor_id, selection, cursor_at_end, insert_position)| {
             // Calculate line start for auto-dedent
             let mut line_start = insert_position;
             while line_start > 0 {
@@ -658,15 +802,7 @@ fn collect_insert_cursor_data(state: &mut EditorState, cursors: &Cursors) -> Vec
             let only_spaces = line_before_cursor.iter().all(|&b| b == b' ' || b == b'\t');
 
             let check_pos = selection.as_ref().map(|r| r.end).unwrap_or(insert_position);
-            let char_after = if check_pos < state.buffer.len() {
-                state
-                    .buffer
-                    .slice_bytes(check_pos..check_pos + 1)
-                    .first()
-                    .copied()
-            } else {
-                None
-            };
+            let char_after = char_at(&state.buffer, check_pos);
 
             let deleted_text = selection
                 .as_ref()
@@ -675,6 +811,7 @@ fn collect_insert_cursor_data(state: &mut EditorState, cursors: &Cursors) -> Vec
             InsertCursorData {
                 cursor_id,
                 selection,
+                cursor_at_end,
                 insert_position,
                 line_start,
                 only_spaces,
@@ -695,10 +832,32 @@ fn insert_char_events(
     auto_indent: bool,
 ) {
     let is_closing_delimiter = matches!(ch, '}' | ')' | ']');
-    let auto_close_char = get_auto_close_char(ch, auto_indent, &state.language);
+    let pairs = if auto_indent {
+        auto_close_pairs(state)
+    } else {
+        Vec::new()
+    };
+    let auto_close_char = close_char_for(&pairs, ch);
+    let is_pair_close = pairs.iter().any(|(_, close)| *close == ch);
+    let is_dedent_trigger = auto_indent && state.buffer_settings.indent_rules.is_dedent_trigger(ch);
     let cursor_data = collect_insert_cursor_data(state, cursors);
 
     for data in cursor_data {
+        let has_selection = data.selection.is_some();
+
+        // Wrap the selection instead of replacing it when typing an opening character
+        if let (Some(range), Some(close_char)) = (data.selection.clone(), auto_close_char) {
+            handle_surround(
+                events,
+                data.cursor_id,
+                range,
+                data.cursor_at_end,
+                ch,
+                close_char,
+            );
+            continue;
+        }
+
         // Delete selection if present
         if let (Some(range), Some(text)) = (data.selection, data.deleted_text) {
             events.push(Event::Delete {
@@ -709,11 +868,9 @@ fn insert_char_events(
         }
 
         // Try skip-over logic for closing brackets/quotes
-        // Single quotes are excluded in markdown (apostrophes, not paired quotes)
-        let skip_single_quote = ch == '\'' && matches!(state.language.as_str(), "markdown" | "mdx");
-        if auto_indent && matches!(ch, ')' | ']' | '}' | '"' | '\'' | '`') && !skip_single_quote {
-            if let Some(next_byte) = data.char_after {
-                if next_byte == ch as u8 {
+        if is_pair_close {
+            if let Some(next_char) = data.char_after {
+                if next_char == ch {
                     // Try skip-over with dedent for closing delimiters
                     if is_closing_delimiter
                         && data.only_spaces
@@ -731,7 +888,7 @@ fn insert_char_events(
                         continue;
                     }
                     // Simple skip-over
-                    handle_skip_over(events, data.cursor_id, data.insert_position);
+                    handle_skip_over(events, data.cursor_id, data.insert_position, ch);
                     continue;
                 }
             }
@@ -755,6 +912,22 @@ fn insert_char_events(
             continue;
         }
 
+        // Try dedent by the language's indent rules (e.g. Python's `else:`)
+        if is_dedent_trigger
+            && !has_selection
+            && handle_rules_dedent(
+                state,
+                events,
+                data.cursor_id,
+                ch,
+                data.insert_position,
+                data.line_start,
+                tab_size,
+            )
+        {
+            continue;
+        }
+
         // Try auto-close
         if let Some(close_char) = auto_close_char {
             if should_auto_close(data.char_after) {
@@ -825,6 +998,163 @@ fn transform_case<F>(
     }
 }
 
+/// Add to the number under or after each cursor on its line, leaving the
+/// cursor on the number's last character so repeating keeps changing it.
+/// `amount` is given the index of the number in buffer order; cursors on the
+/// same number change it once.
+fn add_to_numbers<F>(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    estimated_line_length: usize,
+    amount: F,
+) where
+    F: Fn(usize) -> i64,
+{
+    let mut sorted: Vec<_> = cursors.iter().collect();
+    sorted.sort_by_key(|(_, cursor)| cursor.position);
+
+    // Each cursor with the index of its number, if it has one
+    let mut numbers: Vec<(Range<usize>, String)> = Vec::new();
+    let mut targets = Vec::new();
+    for (cursor_id, cursor) in sorted {
+        let pos = cursor.position;
+        let mut iter = state.buffer.line_iterator(pos, estimated_line_length);
+        let line_start = iter.current_position();
+        let found = iter.next_line().and_then(|(_, line)| {
+            let line = line.trim_end_matches(['\n', '\r']);
+            let span = number_at(line, pos - line_start)?;
+            Some((
+                line_start + span.start..line_start + span.end,
+                line[span].to_string(),
+            ))
+        });
+        let index = found.map(|(range, number)| {
+            if numbers.last().map(|(last, _)| last) != Some(&range) {
+                numbers.push((range, number));
+            }
+            numbers.len() - 1
+        });
+        targets.push((cursor_id, *cursor, index));
+    }
+
+    let changed: Vec<Option<String>> = numbers
+        .iter()
+        .enumerate()
+        .map(|(index, (_, number))| add_to_number(number, amount(index)))
+        .collect();
+    if changed.iter().all(Option::is_none) {
+        return;
+    }
+
+    // The edits are replayed in one go, so cursors are placed in final
+    // positions: shifted by the change in length of the numbers before them
+    let shift_before = |pos: usize| -> isize {
+        numbers
+            .iter()
+            .zip(&changed)
+            .filter(|((range, _), _)| range.end <= pos)
+            .filter_map(|((range, _), new)| {
+                new.as_ref()
+                    .map(|new| new.len() as isize - range.len() as isize)
+            })
+            .sum()
+    };
+    for (cursor_id, cursor, index) in targets {
+        let new_position = match index.and_then(|i| Some((&numbers[i].0, changed[i].as_ref()?))) {
+            Some((range, new)) => {
+                (range.start as isize + shift_before(range.start)) as usize + new.len() - 1
+            }
+            None => (cursor.position as isize + shift_before(cursor.position)) as usize,
+        };
+        events.push(Event::MoveCursor {
+            cursor_id,
+            old_position: cursor.position,
+            new_position,
+            old_anchor: cursor.anchor,
+            new_anchor: None,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: 0,
+        });
+    }
+
+    for ((range, number), new) in numbers.into_iter().zip(changed).rev() {
+        let Some(new) = new else {
+            continue;
+        };
+        events.push(Event::Delete {
+            range: range.clone(),
+            deleted_text: number,
+            cursor_id: CursorId::UNDO_SENTINEL,
+        });
+        events.push(Event::Insert {
+            position: range.start,
+            text: new,
+            cursor_id: CursorId::UNDO_SENTINEL,
+        });
+    }
+}
+
+/// Visual column of `pos` within its line, expanding tabs to `tab_size` stops.
+fn visual_column_with_tabs(state: &mut EditorState, pos: usize, tab_size: usize) -> usize {
+    let line = state.buffer.get_line_number(pos);
+    let line_start = state.buffer.line_start_offset(line).unwrap_or(0);
+    let prefix = state.get_text_range(line_start, pos);
+    let tab_size = tab_size.max(1);
+    prefix.chars().fold(0, |col, ch| {
+        if ch == '\t' {
+            col + tab_size - (col % tab_size)
+        } else {
+            col + char_width(ch)
+        }
+    })
+}
+
+/// Pad cursors with spaces so they all end up at the column of the rightmost one.
+///
+/// Cursors with a selection align on the selection start, which is what block
+/// selections (already split into per-line cursors) use as their left edge.
+/// Only the leftmost cursor on each line takes part: padding a line twice would
+/// push its other cursors past the target column.
+fn align_cursors(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    tab_size: usize,
+) {
+    let mut points: Vec<(usize, CursorId, usize, usize)> = cursors
+        .iter()
+        .map(|(cursor_id, cursor)| {
+            let pos = cursor.selection_start();
+            let line = state.buffer.get_line_number(pos);
+            (line, cursor_id, pos, 0)
+        })
+        .collect();
+    points.sort_by_key(|(line, _, pos, _)| (*line, *pos));
+    points.dedup_by_key(|(line, _, _, _)| *line);
+
+    if points.len() < 2 {
+        return;
+    }
+
+    for point in points.iter_mut() {
+        point.3 = visual_column_with_tabs(state, point.2, tab_size);
+    }
+    let target = points.iter().map(|(_, _, _, col)| *col).max().unwrap_or(0);
+
+    // Insert from the end of the buffer backwards so earlier positions stay valid
+    points.sort_by_key(|(_, _, pos, _)| std::cmp::Reverse(*pos));
+    for (_, cursor_id, pos, col) in points {
+        if col < target {
+            events.push(Event::Insert {
+                position: pos,
+                text: " ".repeat(target - col),
+                cursor_id,
+            });
+        }
+    }
+}
+
 /// Convert an action into a sequence of events that can be applied to the editor state
 ///
 /// # Parameters
@@ -947,13 +1277,23 @@ pub fn action_to_events(
 
                 if auto_indent {
                     let use_tabs = state.buffer_settings.use_tabs;
+                    // The language's increase rule, when the line matches it
+                    let rules_indent = PatternIndentCalculator::calculate_indent_with_rules(
+                        &state.buffer,
+                        indent_position,
+                        tab_size,
+                        &state.buffer_settings.indent_rules,
+                    );
                     if let Some(language) = state.highlighter.language() {
                         // Use tree-sitter-based indent when we have a highlighter
-                        if let Some(indent_width) = state
-                            .indent_calculator
-                            .borrow_mut()
-                            .calculate_indent(&state.buffer, indent_position, language, tab_size)
-                        {
+                        if let Some(indent_width) = rules_indent.or_else(|| {
+                            state.indent_calculator.borrow_mut().calculate_indent(
+                                &state.buffer,
+                                indent_position,
+                                language,
+                                tab_size,
+                            )
+                        }) {
                             let indent_str = indent_to_string(indent_width, use_tabs, tab_size);
                             text.push_str(&indent_str);
 
@@ -981,12 +1321,13 @@ pub fn action_to_events(
                         }
                     } else {
                         // Fallback for files without syntax highlighting (e.g., .txt)
-                        let indent_width =
+                        let indent_width = rules_indent.unwrap_or_else(|| {
                             crate::primitives::indent::IndentCalculator::calculate_indent_no_language(
                                 &state.buffer,
                                 indent_position,
                                 tab_size,
-                            );
+                            )
+                        });
                         let indent_str = indent_to_string(indent_width, use_tabs, tab_size);
                         text.push_str(&indent_str);
 
@@ -1415,55 +1756,56 @@ pub fn action_to_events(
 
         Action::MoveLineStart => {
             for (cursor_id, cursor) in cursors.iter() {
-                let mut iter = state
-                    .buffer
-                    .line_iterator(cursor.position, estimated_line_length);
-                if let Some((line_start, _)) = iter.next_line() {
-                    // Preserve anchor if deselect_on_move is false (Emacs mark mode)
-                    let new_anchor = if cursor.deselect_on_move {
-                        None
-                    } else {
-                        cursor.anchor
-                    };
-                    events.push(Event::MoveCursor {
-                        cursor_id,
-                        old_position: cursor.position,
-                        new_position: line_start,
-                        old_anchor: cursor.anchor,
-                        new_anchor,
-                        old_sticky_column: cursor.sticky_column,
-                        new_sticky_column: 0, // Reset sticky column
-                    });
-                }
+                // Scan bytes rather than reading the line, so Home stays
+                // cheap on long minified lines
+                let line_start = LineIterator::line_start(
+                    &mut state.buffer,
+                    cursor.position,
+                    estimated_line_length,
+                );
+                // Preserve anchor if deselect_on_move is false (Emacs mark mode)
+                let new_anchor = if cursor.deselect_on_move {
+                    None
+                } else {
+                    cursor.anchor
+                };
+                events.push(Event::MoveCursor {
+                    cursor_id,
+                    old_position: cursor.position,
+                    new_position: line_start,
+                    old_anchor: cursor.anchor,
+                    new_anchor,
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: 0, // Reset sticky column
+                });
             }
         }
 
         Action::MoveLineEnd => {
             for (cursor_id, cursor) in cursors.iter() {
-                let mut iter = state
-                    .buffer
-                    .line_iterator(cursor.position, estimated_line_length);
-                if let Some((line_start, line_content)) = iter.next_line() {
-                    // In both LF and CRLF mode, cursor lands at the first byte of line ending
-                    // For LF: cursor on \n. For CRLF: cursor on \r (before both \r\n)
-                    let line_end = line_start + content_len_without_line_ending(&line_content);
+                // In both LF and CRLF mode, cursor lands at the first byte of line ending
+                // For LF: cursor on \n. For CRLF: cursor on \r (before both \r\n)
+                let line_end = LineIterator::line_end(
+                    &mut state.buffer,
+                    cursor.position,
+                    estimated_line_length,
+                );
 
-                    // Preserve anchor if deselect_on_move is false (Emacs mark mode)
-                    let new_anchor = if cursor.deselect_on_move {
-                        None
-                    } else {
-                        cursor.anchor
-                    };
-                    events.push(Event::MoveCursor {
-                        cursor_id,
-                        old_position: cursor.position,
-                        new_position: line_end,
-                        old_anchor: cursor.anchor,
-                        new_anchor,
-                        old_sticky_column: cursor.sticky_column,
-                        new_sticky_column: 0, // Reset sticky column
-                    });
-                }
+                // Preserve anchor if deselect_on_move is false (Emacs mark mode)
+                let new_anchor = if cursor.deselect_on_move {
+                    None
+                } else {
+                    cursor.anchor
+                };
+                events.push(Event::MoveCursor {
+                    cursor_id,
+                    old_position: cursor.position,
+                    new_position: line_end,
+                    old_anchor: cursor.anchor,
+                    new_anchor,
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: 0, // Reset sticky column
+                });
             }
         }
 
@@ -1846,47 +2188,45 @@ pub fn action_to_events(
 
         Action::SelectLineStart => {
             for (cursor_id, cursor) in cursors.iter() {
-                let mut iter = state
-                    .buffer
-                    .line_iterator(cursor.position, estimated_line_length);
+                let line_start = LineIterator::line_start(
+                    &mut state.buffer,
+                    cursor.position,
+                    estimated_line_length,
+                );
                 let anchor = cursor.anchor.unwrap_or(cursor.position);
 
-                if let Some((line_start, _)) = iter.next_line() {
-                    events.push(Event::MoveCursor {
-                        cursor_id,
-                        old_position: cursor.position,
-                        new_position: line_start,
-                        old_anchor: cursor.anchor,
-                        new_anchor: Some(anchor),
-                        old_sticky_column: cursor.sticky_column,
-                        new_sticky_column: 0, // Reset sticky column
-                    });
-                }
+                events.push(Event::MoveCursor {
+                    cursor_id,
+                    old_position: cursor.position,
+                    new_position: line_start,
+                    old_anchor: cursor.anchor,
+                    new_anchor: Some(anchor),
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: 0, // Reset sticky column
+                });
             }
         }
 
         Action::SelectLineEnd => {
             for (cursor_id, cursor) in cursors.iter() {
-                let mut iter = state
-                    .buffer
-                    .line_iterator(cursor.position, estimated_line_length);
+                // In both LF and CRLF mode, cursor lands at the first byte of line ending
+                // For LF: cursor on \n. For CRLF: cursor on \r (before both \r\n)
+                let line_end = LineIterator::line_end(
+                    &mut state.buffer,
+                    cursor.position,
+                    estimated_line_length,
+                );
                 let anchor = cursor.anchor.unwrap_or(cursor.position);
 
-                if let Some((line_start, line_content)) = iter.next_line() {
-                    // In both LF and CRLF mode, cursor lands at the first byte of line ending
-                    // For LF: cursor on \n. For CRLF: cursor on \r (before both \r\n)
-                    let line_end = line_start + content_len_without_line_ending(&line_content);
-
-                    events.push(Event::MoveCursor {
-                        cursor_id,
-                        old_position: cursor.position,
-                        new_position: line_end,
-                        old_anchor: cursor.anchor,
-                        new_anchor: Some(anchor),
-                        old_sticky_column: cursor.sticky_column,
-                        new_sticky_column: 0, // Reset sticky column
-                    });
-                }
+                events.push(Event::MoveCursor {
+                    cursor_id,
+                    old_position: cursor.position,
+                    new_position: line_end,
+                    old_anchor: cursor.anchor,
+                    new_anchor: Some(anchor),
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: 0, // Reset sticky column
+                });
             }
         }
 
@@ -2097,6 +2437,11 @@ pub fn action_to_events(
             // Sort cursors by position (reverse order) to avoid position shifts
             let mut cursor_vec: Vec<_> = cursors.iter().collect();
             cursor_vec.sort_by_key(|(_, c)| std::cmp::Reverse(c.position));
+            let pairs = if auto_indent {
+                auto_close_pairs(state)
+            } else {
+                Vec::new()
+            };
 
             // Collect all deletions first, checking for smart dedent and auto-pair deletion
             let deletions: Vec<_> = cursor_vec
@@ -2150,37 +2495,16 @@ pub fn action_to_events(
                         let delete_from = state.buffer.prev_char_boundary(cursor.position);
                         let delete_from = adjust_position_for_crlf_left(&state.buffer, delete_from);
 
-                        // Check for auto-pair deletion when auto_indent is enabled
-                        // Note: Auto-pairs are ASCII-only, so we can safely check single bytes
-                        if auto_indent && cursor.position < state.buffer.len() {
-                            let char_before = state
-                                .buffer
-                                .slice_bytes(delete_from..cursor.position)
-                                .first()
-                                .copied();
-                            let char_after = state
-                                .buffer
-                                .slice_bytes(cursor.position..cursor.position + 1)
-                                .first()
-                                .copied();
-
-                            // Check if we're between matching brackets/quotes
-                            let is_matching_pair = matches!(
-                                (char_before, char_after),
-                                (Some(b'('), Some(b')'))
-                                    | (Some(b'['), Some(b']'))
-                                    | (Some(b'{'), Some(b'}'))
-                                    | (Some(b'"'), Some(b'"'))
-                                    | (Some(b'\''), Some(b'\''))
-                                    | (Some(b'`'), Some(b'`'))
-                            );
-
-                            if is_matching_pair {
-                                // Delete both opening and closing characters
-                                Some((*cursor_id, delete_from..cursor.position + 1))
-                            } else {
-                                Some((*cursor_id, delete_from..cursor.position))
-                            }
+                        // Check if we're between an empty pair of brackets/quotes
+                        let pair_close = char_at(&state.buffer, delete_from)
+                            .and_then(|open| close_char_for(&pairs, open))
+                            .filter(|&close| {
+                                char_at(&state.buffer, cursor.position) == Some(close)
+                            });
+
+                        if let Some(close) = pair_close {
+                            // Delete both opening and closing characters
+                            Some((*cursor_id, delete_from..cursor.position + close.len_utf8()))
                         } else {
                             Some((*cursor_id, delete_from..cursor.position))
                         }
@@ -2358,34 +2682,106 @@ pub fn action_to_events(
         }
 
         Action::TransposeChars => {
-            // Transpose the character before the cursor with the one at the cursor
-            // Collect cursor positions first to avoid borrow issues
-            let cursor_positions: Vec<_> = cursors.iter().map(|(id, c)| (id, c.position)).collect();
-
+            // Transpose the character before the cursor with the one at the cursor.
+            // Cursors go from the end of the buffer back, and one whose pair
+            // overlaps a pair already swapped is skipped.
+            let mut cursor_positions: Vec<_> =
+                cursors.iter().map(|(id, c)| (id, c.position)).collect();
+            cursor_positions.sort_by_key(|&(_, pos)| std::cmp::Reverse(pos));
+
+            let mut limit = usize::MAX;
             for (cursor_id, pos) in cursor_positions {
                 // Need at least 2 characters: one before and one at cursor
-                if pos > 0 && pos < state.buffer.len() {
-                    // Get the two characters as a string
-                    let text = state.get_text_range(pos - 1, pos + 1);
-                    let chars: Vec<char> = text.chars().collect();
-                    if chars.len() >= 2 {
-                        // Delete both characters and insert them swapped
-                        events.push(Event::Delete {
-                            range: (pos - 1)..(pos + 1),
-                            deleted_text: text,
-                            cursor_id,
-                        });
-                        let swapped = format!("{}{}", chars[1], chars[0]);
-                        events.push(Event::Insert {
-                            position: pos - 1,
-                            text: swapped,
-                            cursor_id,
-                        });
-                    }
+                if pos == 0 || pos >= state.buffer.len() {
+                    continue;
+                }
+                let start = state.buffer.prev_grapheme_boundary(pos);
+                let end = state.buffer.next_grapheme_boundary(pos);
+                if start == pos || end == pos || end > limit {
+                    continue;
                 }
+                let before = state.get_text_range(start, pos);
+                let after = state.get_text_range(pos, end);
+                // Delete both characters and insert them swapped
+                events.push(Event::Delete {
+                    range: start..end,
+                    deleted_text: format!("{}{}", before, after),
+                    cursor_id,
+                });
+                events.push(Event::Insert {
+                    position: start,
+                    text: format!("{}{}", after, before),
+                    cursor_id,
+                });
+                limit = start;
+            }
+        }
+
+        Action::TransposeWords => {
+            // Swap the word under (or before) the cursor with the next word on
+            // its line, leaving the cursor after them so repeating moves the
+            // word further along. Overlapping swaps are skipped as above.
+            let mut cursor_positions: Vec<_> =
+                cursors.iter().map(|(id, c)| (id, c.position)).collect();
+            cursor_positions.sort_by_key(|&(_, pos)| std::cmp::Reverse(pos));
+
+            let mut limit = usize::MAX;
+            for (cursor_id, pos) in cursor_positions {
+                let mut iter = state.buffer.line_iterator(pos, estimated_line_length);
+                let line_start = iter.current_position();
+                let Some((_, line)) = iter.next_line() else {
+                    continue;
+                };
+                let line = line.trim_end_matches(['\n', '\r']);
+                let Some((first, second)) = words_to_transpose(line, pos - line_start) else {
+                    continue;
+                };
+                let range = line_start + first.start..line_start + second.end;
+                if range.end > limit {
+                    continue;
+                }
+                events.push(Event::Delete {
+                    range: range.clone(),
+                    deleted_text: line[first.start..second.end].to_string(),
+                    cursor_id,
+                });
+                events.push(Event::Insert {
+                    position: range.start,
+                    text: format!(
+                        "{}{}{}",
+                        &line[second.clone()],
+                        &line[first.end..second.start],
+                        &line[first]
+                    ),
+                    cursor_id,
+                });
+                limit = range.start;
             }
         }
 
+        Action::IncrementNumber(amount) => {
+            add_to_numbers(state, cursors, &mut events, estimated_line_length, |_| {
+                amount
+            });
+        }
+
+        Action::DecrementNumber(amount) => {
+            add_to_numbers(state, cursors, &mut events, estimated_line_length, |_| {
+                amount.saturating_neg()
+            });
+        }
+
+        Action::IncrementNumberSequence(step) => {
+            // The first number goes up by `step`, the next by twice that, ...
+            add_to_numbers(
+                state,
+                cursors,
+                &mut events,
+                estimated_line_length,
+                |index| step.saturating_mul(index as i64 + 1),
+            );
+        }
+
         Action::ToUpperCase => {
             transform_case(state, cursors, &mut events, |s| s.to_uppercase());
         }
@@ -2436,6 +2832,10 @@ pub fn action_to_events(
             }
         }
 
+        Action::AlignCursors => {
+            align_cursors(state, cursors, &mut events, tab_size);
+        }
+
         Action::OpenLine => {
             // Insert a newline at cursor position but don't move cursor
             // (like pressing Enter but staying on current line)
@@ -2584,14 +2984,20 @@ pub fn action_to_events(
         | Action::Open
         | Action::SwitchProject
         | Action::New
+        | Action::NewScratchWithLanguage
         | Action::Close
         | Action::CloseTab
+        | Action::CloseOtherTabs
+        | Action::CloseTabsToRight
+        | Action::ReopenClosedTab
+        | Action::TogglePinTab
         | Action::GotoLine
         | Action::ScanLineIndex
         | Action::NextBuffer
         | Action::PrevBuffer
         | Action::SwitchToPreviousTab
         | Action::SwitchToTabByName
+        | Action::BufferPicker
         | Action::NavigateBack
         | Action::NavigateForward
         | Action::SplitHorizontal
@@ -2600,9 +3006,15 @@ pub fn action_to_events(
         | Action::NextSplit
         | Action::PrevSplit
         | Action::Copy
+        | Action::CopyVisible
         | Action::CopyWithTheme(_)
+        | Action::ExportHtml
+        | Action::ExportAnsi
         | Action::Cut
         | Action::Paste
+        | Action::PasteFromHistory
+        | Action::CyclePaste
+        | Action::InsertUnicodeChar
         | Action::YankWordForward
         | Action::YankWordBackward
         | Action::YankToLineEnd
@@ -2610,36 +3022,68 @@ pub fn action_to_events(
         | Action::AddCursorNextMatch
         | Action::AddCursorAbove
         | Action::AddCursorBelow
+        | Action::AlignByPattern
         | Action::CommandPalette
         | Action::QuickOpen
         | Action::ShowHelp
         | Action::ToggleLineWrap
+        | Action::ToggleReadOnly
         | Action::ToggleComposeMode
         | Action::SetComposeWidth
         | Action::IncreaseSplitSize
         | Action::DecreaseSplitSize
         | Action::ToggleMaximizeSplit
+        | Action::EqualizeSplits
+        | Action::ToggleScrollLink
         | Action::Undo
         | Action::Redo
+        | Action::RepeatLast
         | Action::GoToMatchingBracket
+        | Action::SelectToMatchingBracket
+        | Action::ExpandSelection
+        | Action::ShrinkSelection
         | Action::JumpToNextError
         | Action::JumpToPreviousError
         | Action::ShowKeyboardShortcuts
         | Action::ShowWarnings
         | Action::ShowStatusLog
+        | Action::ShowPluginLog
+        | Action::ShowLogs
+        | Action::TogglePerformanceHud
+        | Action::DumpPerformanceReport
+        | Action::ManagePlugins
+        | Action::PluginPanelToggle
+        | Action::PluginPanelReload
+        | Action::PluginPanelDetails
         | Action::ShowLspStatus
+        | Action::ShowProcessStatus
         | Action::ClearWarnings
         | Action::SmartHome
+        | Action::SelectSmartHome
         | Action::ToggleComment
+        | Action::ToggleBlockComment
         | Action::ToggleFold
+        | Action::DrawBox
+        | Action::ToggleLineDrawing
+        | Action::JoinLines
+        | Action::HardWrap
+        | Action::SurroundSelection
+        | Action::ChangeSurrounding
         | Action::SetBookmark(_)
         | Action::JumpToBookmark(_)
         | Action::ClearBookmark(_)
         | Action::ListBookmarks
+        | Action::ToggleBookmark
+        | Action::AddLabeledBookmark
+        | Action::NextBookmark
+        | Action::PreviousBookmark
+        | Action::BrowseBookmarks
+        | Action::ClearBufferBookmarks
         | Action::ToggleSearchCaseSensitive
         | Action::ToggleSearchWholeWord
         | Action::ToggleSearchRegex
         | Action::ToggleSearchConfirmEach
+        | Action::ToggleSearchPreserveCase
         | Action::StartMacroRecording
         | Action::StopMacroRecording
         | Action::PlayMacro(_)
@@ -2673,6 +3117,7 @@ pub fn action_to_events(
         | Action::PromptCopy
         | Action::PromptCut
         | Action::PromptPaste
+        | Action::PromptBindCommand
         | Action::PromptMoveLeftSelecting
         | Action::PromptMoveRightSelecting
         | Action::PromptMoveHomeSelecting
@@ -2693,7 +3138,17 @@ pub fn action_to_events(
         | Action::ToggleTabBar
         | Action::ToggleVerticalScrollbar
         | Action::ToggleHorizontalScrollbar
+        | Action::ToggleIndentGuides
+        | Action::ToggleMinimap
+        | Action::ToggleColorSwatches
+        | Action::AdjustColor
+        | Action::ToggleAlignedView
+        | Action::ToggleMarkdownPreview
+        | Action::ToggleFollowTail
+        | Action::FilterLogLines
         | Action::FocusFileExplorer
+        | Action::RevealInFileExplorer
+        | Action::ToggleFileExplorerFollow
         | Action::FocusEditor
         | Action::SetBackground
         | Action::SetBackgroundBlend
@@ -2713,7 +3168,9 @@ pub fn action_to_events(
         | Action::FileExplorerToggleGitignored
         | Action::FileExplorerSearchClear
         | Action::FileExplorerSearchBackspace
+        | Action::FileExplorerFilter
         | Action::LspCompletion
+        | Action::ExpandSnippet
         | Action::LspGotoDefinition
         | Action::LspReferences
         | Action::LspRename
@@ -2765,6 +3222,14 @@ pub fn action_to_events(
         | Action::TerminalEscape
         | Action::ToggleKeyboardCapture
         | Action::TerminalPaste
+        | Action::SwitchTerminal
+        | Action::RenameTerminal
+        | Action::RunSelectionInTerminal
+        | Action::RunFileInTerminal
+        | Action::OpenLinkUnderCursor
+        | Action::RunTask
+        | Action::RerunLastTask
+        | Action::CancelTask
         | Action::OpenSettings
         | Action::CloseSettings
         | Action::SettingsSave
@@ -2783,6 +3248,7 @@ pub fn action_to_events(
         | Action::ToggleIndentationStyle
         | Action::ToggleTabIndicators
         | Action::ToggleWhitespaceIndicators
+        | Action::ToggleRenderWhitespace
         | Action::ToggleDebugHighlights
         | Action::ResetBufferSettings
         | Action::ShellCommand
@@ -2790,6 +3256,7 @@ pub fn action_to_events(
         | Action::CalibrateInput
         | Action::EventDebug
         | Action::OpenKeybindingEditor
+        | Action::ResetCommandRanking
         | Action::AddRuler
         | Action::RemoveRuler => return None,
 
@@ -2833,55 +3300,6 @@ pub fn action_to_events(
                 }
             }
         }
-
-        Action::ExpandSelection => {
-            // Expand selection for each cursor
-            for (cursor_id, cursor) in cursors.iter() {
-                if let Some(anchor) = cursor.anchor {
-                    // Already have a selection - expand by one word to the right
-                    // First move to the start of the next word, then to its end
-                    let next_word_start = find_word_start_right(&state.buffer, cursor.position);
-                    let new_end = find_word_end(&state.buffer, next_word_start);
-                    events.push(Event::MoveCursor {
-                        cursor_id,
-                        old_position: cursor.position,
-                        new_position: new_end,
-                        old_anchor: cursor.anchor,
-                        new_anchor: Some(anchor),
-                        old_sticky_column: cursor.sticky_column,
-                        new_sticky_column: 0, // Reset sticky column
-                    });
-                } else {
-                    // No selection - select from cursor to end of current word
-                    let word_start = find_word_start(&state.buffer, cursor.position);
-                    let word_end = find_word_end(&state.buffer, cursor.position);
-
-                    // If cursor is on non-word char OR at the end of a word,
-                    // select from current position to end of next word
-                    let (final_start, final_end) =
-                        if word_start == word_end || cursor.position == word_end {
-                            // Find the next word (skip non-word characters to find it)
-                            let next_start = find_word_start_right(&state.buffer, cursor.position);
-                            let next_end = find_word_end(&state.buffer, next_start);
-                            // Select FROM cursor position TO the end of next word
-                            (cursor.position, next_end)
-                        } else {
-                            // On a word char - select from cursor to end of current word
-                            (cursor.position, word_end)
-                        };
-
-                    events.push(Event::MoveCursor {
-                        cursor_id,
-                        old_position: cursor.position,
-                        new_position: final_end,
-                        old_anchor: cursor.anchor,
-                        new_anchor: Some(final_start),
-                        old_sticky_column: cursor.sticky_column,
-                        new_sticky_column: 0, // Reset sticky column
-                    });
-                }
-            }
-        }
     }
 
     Some(events)
@@ -3281,6 +3699,64 @@ mod tests {
         assert_eq!(cursors.get(CursorId(1)).unwrap().position, 4);
     }
 
+    #[test]
+    fn test_transpose_chars_skips_overlapping_cursors() {
+        let mut state = EditorState::new(
+            80,
+            24,
+            crate::config::LARGE_FILE_THRESHOLD_BYTES as usize,
+            test_fs(),
+        );
+        let mut cursors = Cursors::new();
+
+        state.apply(
+            &mut cursors,
+            &Event::Insert {
+                position: 0,
+                text: "abcd".to_string(),
+                cursor_id: CursorId(0),
+            },
+        );
+
+        // Cursors at 2 and 3: the pairs "bc" and "cd" share the 'c'
+        let pos = cursors.primary().position;
+        state.apply(
+            &mut cursors,
+            &Event::MoveCursor {
+                cursor_id: CursorId(0),
+                old_position: pos,
+                new_position: 2,
+                old_anchor: None,
+                new_anchor: None,
+                old_sticky_column: 0,
+                new_sticky_column: 0,
+            },
+        );
+        state.apply(
+            &mut cursors,
+            &Event::AddCursor {
+                position: 3,
+                cursor_id: CursorId(1),
+                anchor: None,
+            },
+        );
+
+        let events = action_to_events(
+            &mut state,
+            &mut cursors,
+            Action::TransposeChars,
+            4,
+            false,
+            80,
+            24,
+        )
+        .unwrap();
+        for event in events {
+            state.apply(&mut cursors, &event);
+        }
+        assert_eq!(state.buffer.to_string().unwrap(), "abdc");
+    }
+
     #[test]
     fn test_move_line_up_large_file_unloaded_chunks() {
         use crate::model::buffer::TextBuffer;
@@ -5018,6 +5494,8 @@ mod tests {
             test_fs(),
         );
         let mut cursors = Cursors::new();
+        // Quotes aren't auto-closed in plain text
+        state.language = "rust".to_string();
 
         // Insert empty string literal
         state.apply(
@@ -5222,6 +5700,290 @@ mod tests {
 
         assert_eq!(state.buffer.to_string().unwrap(), "(bc)");
     }
+
+    /// Type `ch` at every cursor and apply the resulting events.
+    fn type_char(state: &mut EditorState, cursors: &mut Cursors, ch: char) {
+        let events =
+            action_to_events(state, cursors, Action::InsertChar(ch), 4, true, 80, 24).unwrap();
+        for event in events {
+            state.apply(cursors, &event);
+        }
+    }
+
+    fn state_with_text(text: &str) -> (EditorState, Cursors) {
+        let mut state = EditorState::new(
+            80,
+            24,
+            crate::config::LARGE_FILE_THRESHOLD_BYTES as usize,
+            test_fs(),
+        );
+        let mut cursors = Cursors::new();
+        state.apply(
+            &mut cursors,
+            &Event::Insert {
+                position: 0,
+                text: text.to_string(),
+                cursor_id: CursorId(0),
+            },
+        );
+        (state, cursors)
+    }
+
+    #[test]
+    fn test_surround_selection_with_brackets() {
+        let (mut state, mut cursors) = state_with_text("let x = value;");
+        // Select "value" with the cursor at the end
+        cursors.primary_mut().anchor = Some(8);
+        cursors.primary_mut().position = 13;
+
+        type_char(&mut state, &mut cursors, '(');
+
+        assert_eq!(state.buffer.to_string().unwrap(), "let x = (value);");
+        // The original text stays selected, inside the brackets
+        assert_eq!(cursors.primary().anchor, Some(9));
+        assert_eq!(cursors.primary().position, 14);
+
+        // Surrounding again nests the pairs
+        type_char(&mut state, &mut cursors, '[');
+        assert_eq!(state.buffer.to_string().unwrap(), "let x = ([value]);");
+    }
+
+    #[test]
+    fn test_surround_backward_selection_keeps_direction() {
+        let (mut state, mut cursors) = state_with_text("abc def");
+        state.language = "rust".to_string();
+        // Select "def" with the cursor at the start
+        cursors.primary_mut().anchor = Some(7);
+        cursors.primary_mut().position = 4;
+
+        type_char(&mut state, &mut cursors, '"');
+
+        assert_eq!(state.buffer.to_string().unwrap(), "abc \"def\"");
+        assert_eq!(cursors.primary().position, 5);
+        assert_eq!(cursors.primary().anchor, Some(8));
+    }
+
+    #[test]
+    fn test_surround_multiple_cursors() {
+        let (mut state, mut cursors) = state_with_text("one\ntwo");
+        cursors.primary_mut().anchor = Some(0);
+        cursors.primary_mut().position = 3;
+        let mut second = crate::model::cursor::Cursor::new(7);
+        second.anchor = Some(4);
+        cursors.add(second);
+
+        type_char(&mut state, &mut cursors, '{');
+
+        assert_eq!(state.buffer.to_string().unwrap(), "{one}\n{two}");
+        let mut selections: Vec<_> = cursors
+            .iter()
+            .map(|(_, c)| (c.anchor, c.position))
+            .collect();
+        selections.sort();
+        assert_eq!(selections, vec![(Some(1), 4), (Some(7), 10)]);
+    }
+
+    #[test]
+    fn test_closing_char_replaces_selection() {
+        let (mut state, mut cursors) = state_with_text("abc");
+        cursors.primary_mut().anchor = Some(0);
+        cursors.primary_mut().position = 3;
+
+        // Only opening characters surround; a closing one replaces the selection
+        type_char(&mut state, &mut cursors, ')');
+
+        assert_eq!(state.buffer.to_string().unwrap(), ")");
+        assert_eq!(cursors.primary().position, 1);
+    }
+
+    #[test]
+    fn test_auto_close_pairs_language_override() {
+        let (mut state, mut cursors) = state_with_text("f");
+        state.buffer_settings.auto_close_pairs = Some(vec![('<', '>')]);
+
+        type_char(&mut state, &mut cursors, '<');
+        assert_eq!(state.buffer.to_string().unwrap(), "f<>");
+        assert_eq!(cursors.primary().position, 2);
+
+        // Brackets not in the list are typed as-is
+        type_char(&mut state, &mut cursors, '(');
+        assert_eq!(state.buffer.to_string().unwrap(), "f<(>");
+
+        // Typing the closing character skips over it
+        type_char(&mut state, &mut cursors, ')');
+        type_char(&mut state, &mut cursors, '>');
+        assert_eq!(state.buffer.to_string().unwrap(), "f<()>");
+        assert_eq!(cursors.primary().position, 5);
+    }
+
+    #[test]
+    fn test_indent_rules_dedent_on_trigger_at_every_cursor() {
+        use crate::primitives::indent_pattern::{IndentPatterns, IndentRules};
+
+        let text = "if a:\n    b()\n    else\nwhile c:\n    if d:\n        e()\n        else";
+        let (mut state, mut cursors) = state_with_text(text);
+        state.buffer_settings.indent_rules =
+            IndentRules::new(IndentPatterns::for_language("python"));
+        cursors.primary_mut().position = text.find("else").unwrap() + 4;
+        cursors.add(crate::model::cursor::Cursor::new(text.len()));
+
+        type_char(&mut state, &mut cursors, ':');
+        assert_eq!(
+            state.buffer.to_string().unwrap(),
+            "if a:\n    b()\nelse:\nwhile c:\n    if d:\n        e()\n    else:"
+        );
+        let mut positions: Vec<_> = cursors.iter().map(|(_, c)| c.position).collect();
+        positions.sort();
+        assert_eq!(positions, vec![19, state.buffer.len()]);
+
+        // A line already dedented is left where it is
+        type_char(&mut state, &mut cursors, ':');
+        assert!(state.buffer.to_string().unwrap().ends_with("    else::"));
+    }
+
+    #[test]
+    fn test_indent_rules_on_newline() {
+        use crate::primitives::indent_pattern::{IndentPatterns, IndentRules};
+
+        let (mut state, mut cursors) = state_with_text("steps:\n  run: |");
+        state.buffer_settings.indent_rules = IndentRules::new(IndentPatterns::for_language("yaml"));
+        cursors.primary_mut().position = state.buffer.len();
+        let events = action_to_events(
+            &mut state,
+            &mut cursors,
+            Action::InsertNewline,
+            2,
+            true,
+            80,
+            24,
+        )
+        .unwrap();
+        for event in events {
+            state.apply(&mut cursors, &event);
+        }
+        assert_eq!(state.buffer.to_string().unwrap(), "steps:\n  run: |\n    ");
+    }
+
+    #[test]
+    fn test_auto_close_disabled_with_empty_pairs() {
+        let (mut state, mut cursors) = state_with_text("abc");
+        state.buffer_settings.auto_close_pairs = Some(Vec::new());
+        cursors.primary_mut().position = 3;
+
+        type_char(&mut state, &mut cursors, '(');
+        assert_eq!(state.buffer.to_string().unwrap(), "abc(");
+
+        // No pair deletion either
+        state.apply(
+            &mut cursors,
+            &Event::Insert {
+                position: 4,
+                text: ")".to_string(),
+                cursor_id: CursorId(0),
+            },
+        );
+        cursors.primary_mut().position = 4;
+        let events = action_to_events(
+            &mut state,
+            &mut cursors,
+            Action::DeleteBackward,
+            4,
+            true,
+            80,
+            24,
+        )
+        .unwrap();
+        for event in events {
+            state.apply(&mut cursors, &event);
+        }
+        assert_eq!(state.buffer.to_string().unwrap(), "abc)");
+
+        // Selections are replaced instead of surrounded
+        cursors.primary_mut().anchor = Some(0);
+        cursors.primary_mut().position = 3;
+        type_char(&mut state, &mut cursors, '[');
+        assert_eq!(state.buffer.to_string().unwrap(), "[)");
+    }
+
+    #[test]
+    fn test_align_cursors_pads_to_rightmost_column() {
+        let mut state = EditorState::new(
+            80,
+            24,
+            crate::config::LARGE_FILE_THRESHOLD_BYTES as usize,
+            test_fs(),
+        );
+        let mut cursors = Cursors::new();
+
+        state.apply(
+            &mut cursors,
+            &Event::Insert {
+                position: 0,
+                text: "a = 1\nbbb = 2\ncc = 3".to_string(),
+                cursor_id: CursorId(0),
+            },
+        );
+
+        // One cursor before each "="
+        cursors.primary_mut().position = 2;
+        cursors.add(crate::model::cursor::Cursor::new(10));
+        cursors.add(crate::model::cursor::Cursor::new(17));
+
+        let events = action_to_events(
+            &mut state,
+            &mut cursors,
+            Action::AlignCursors,
+            4,
+            false,
+            80,
+            24,
+        )
+        .unwrap();
+
+        // The rightmost cursor needs no padding
+        assert_eq!(events.len(), 2);
+        for event in events {
+            state.apply(&mut cursors, &event);
+        }
+
+        assert_eq!(
+            state.buffer.to_string().unwrap(),
+            "a   = 1\nbbb = 2\ncc  = 3"
+        );
+    }
+
+    #[test]
+    fn test_align_cursors_single_cursor_noop() {
+        let mut state = EditorState::new(
+            80,
+            24,
+            crate::config::LARGE_FILE_THRESHOLD_BYTES as usize,
+            test_fs(),
+        );
+        let mut cursors = Cursors::new();
+
+        state.apply(
+            &mut cursors,
+            &Event::Insert {
+                position: 0,
+                text: "a = 1".to_string(),
+                cursor_id: CursorId(0),
+            },
+        );
+
+        let events = action_to_events(
+            &mut state,
+            &mut cursors,
+            Action::AlignCursors,
+            4,
+            false,
+            80,
+            24,
+        )
+        .unwrap();
+
+        assert!(events.is_empty());
+    }
 }
 
 #[cfg(test)]
@@ -106,6 +106,24 @@ impl ModeRegistry {
 
         registry.register(special_mode);
 
+        // Manage Plugins panel: act on the plugin under the cursor
+        let plugin_manager_mode = BufferMode::new("plugin-manager")
+            .with_parent("special")
+            .with_read_only(true)
+            .with_binding(
+                KeyCode::Char(' '),
+                KeyModifiers::NONE,
+                "plugin_panel_toggle",
+            )
+            .with_binding(
+                KeyCode::Char('r'),
+                KeyModifiers::NONE,
+                "plugin_panel_reload",
+            )
+            .with_binding(KeyCode::Enter, KeyModifiers::NONE, "plugin_panel_details");
+
+        registry.register(plugin_manager_mode);
+
         registry
     }
 
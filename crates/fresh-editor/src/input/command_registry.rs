@@ -7,6 +7,9 @@ use crate::input::commands::{get_all_commands, Command, Suggestion};
 use crate::input::fuzzy::fuzzy_match;
 use crate::input::keybindings::Action;
 use crate::input::keybindings::KeyContext;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::{Arc, RwLock};
 
 /// Registry for managing editor commands
@@ -23,18 +26,35 @@ pub struct CommandRegistry {
     /// Command usage history (most recent first)
     /// Used to sort command palette suggestions by recency
     command_history: Vec<String>,
+
+    /// How many times each command was executed
+    /// Used together with the history to rank frequently used commands higher
+    command_usage: HashMap<String, u32>,
+}
+
+/// Command usage as persisted in the state directory
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CommandUsage {
+    #[serde(default)]
+    history: Vec<String>,
+    #[serde(default)]
+    counts: HashMap<String, u32>,
 }
 
 impl CommandRegistry {
     /// Maximum number of commands to keep in history
     const MAX_HISTORY_SIZE: usize = 50;
 
+    /// Usage count above which a command doesn't rank any higher
+    const MAX_USAGE_COUNT: u32 = 25;
+
     /// Create a new command registry with built-in commands
     pub fn new() -> Self {
         Self {
             builtin_commands: get_all_commands(),
             plugin_commands: Arc::new(RwLock::new(Vec::new())),
             command_history: Vec::new(),
+            command_usage: HashMap::new(),
         }
     }
 
@@ -48,6 +68,11 @@ impl CommandRegistry {
     /// This moves the command to the front of the history list.
     /// Recently used commands appear first in suggestions.
     pub fn record_usage(&mut self, command_name: &str) {
+        *self
+            .command_usage
+            .entry(command_name.to_string())
+            .or_insert(0) += 1;
+
         // Remove existing entry if present
         self.command_history.retain(|name| name != command_name);
 
@@ -68,6 +93,58 @@ impl CommandRegistry {
             .position(|name| name == command_name)
     }
 
+    /// Ranking bonus from how recently and how often a command was used
+    ///
+    /// Zero for commands that were never used.
+    fn usage_rank(&self, command_name: &str) -> i32 {
+        let count = self
+            .command_usage
+            .get(command_name)
+            .copied()
+            .unwrap_or(0)
+            .min(Self::MAX_USAGE_COUNT);
+        let recency = self
+            .history_position(command_name)
+            .map_or(0, |pos| Self::MAX_HISTORY_SIZE - pos);
+        (count * 4) as i32 + recency as i32
+    }
+
+    /// Load usage history and counts saved by [`Self::save_usage`]
+    ///
+    /// A missing file leaves the registry untouched.
+    pub fn load_usage(&mut self, path: &Path) -> std::io::Result<()> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        let usage: CommandUsage = serde_json::from_str(&contents)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        self.command_history = usage.history;
+        self.command_history.truncate(Self::MAX_HISTORY_SIZE);
+        self.command_usage = usage.counts;
+        Ok(())
+    }
+
+    /// Save usage history and counts so the ranking survives restarts
+    pub fn save_usage(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let usage = CommandUsage {
+            history: self.command_history.clone(),
+            counts: self.command_usage.clone(),
+        };
+        let json = serde_json::to_string_pretty(&usage).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+
+    /// Forget all usage, so the palette goes back to alphabetical order
+    pub fn reset_usage(&mut self) {
+        self.command_history.clear();
+        self.command_usage.clear();
+    }
+
     /// Register a new command (typically from a plugin)
     ///
     /// If a command with the same name already exists, it will be replaced.
@@ -157,9 +234,11 @@ impl CommandRegistry {
 
     /// Filter commands by fuzzy matching query with context awareness
     ///
-    /// When query is empty, commands are sorted by recency (most recently used first).
-    /// When query is not empty, commands are sorted by match quality (fzf-style scoring)
-    /// with recency as tiebreaker for equal scores.
+    /// When query is empty, commands are sorted by usage (recently and frequently
+    /// used first).
+    /// When query is not empty, commands whose name starts with the query come
+    /// first, then commands are sorted by match quality (fzf-style scoring)
+    /// boosted by usage.
     /// Disabled commands always appear after enabled ones.
     pub fn filter(
         &self,
@@ -205,7 +284,7 @@ impl CommandRegistry {
                 }
                 let keybinding =
                     keybinding_resolver.get_keybinding_for_action(&cmd.action, current_context);
-                let history_pos = self.history_position(&cmd.name);
+                let rank = self.usage_rank(&cmd.name);
 
                 let suggestion = Suggestion::with_source(
                     localized_name,
@@ -214,28 +293,20 @@ impl CommandRegistry {
                     keybinding,
                     Some(cmd.source.clone()),
                 );
-                (suggestion, history_pos, score)
+                (suggestion, rank, score)
             };
 
-        // First, try to match by name only
+        // First, try to match by name only: the localized name, the English
+        // name and the id all count, but the localized name is displayed
         // Commands with unmet custom contexts are completely hidden
-        let mut suggestions: Vec<(Suggestion, Option<usize>, i32)> = commands
+        let mut suggestions: Vec<(Suggestion, i32, i32)> = commands
             .iter()
             .filter(|cmd| is_visible(cmd))
             .filter_map(|cmd| {
                 let localized_name = cmd.get_localized_name();
-                let name_result = fuzzy_match(query, &localized_name);
-                if name_result.matched {
-                    let localized_desc = cmd.get_localized_description();
-                    Some(make_suggestion(
-                        cmd,
-                        name_result.score,
-                        localized_name,
-                        localized_desc,
-                    ))
-                } else {
-                    None
-                }
+                let score = Self::name_match_score(query, cmd, &localized_name)?;
+                let localized_desc = cmd.get_localized_description();
+                Some(make_suggestion(cmd, score, localized_name, localized_desc))
             })
             .collect();
 
@@ -265,37 +336,54 @@ impl CommandRegistry {
 
         // Sort by:
         // 1. Disabled status (enabled first)
-        // 2. Fuzzy match score (higher is better) - only when query is not empty
-        // 3. History position (recent first, then never-used alphabetically)
+        // 2. Exact prefix match of the name - only when query is not empty
+        // 3. Fuzzy match score boosted by usage - only when query is not empty
+        // 4. Usage rank (recent and frequent first, then never-used alphabetically)
         let has_query = !query.is_empty();
-        suggestions.sort_by(|(a, a_hist, a_score), (b, b_hist, b_score)| {
+        let query_lower = query.to_lowercase();
+        let is_prefix_match = |s: &Suggestion| s.text.to_lowercase().starts_with(&query_lower);
+        suggestions.sort_by(|(a, a_rank, a_score), (b, b_rank, b_score)| {
             // First sort by disabled status
             match a.disabled.cmp(&b.disabled) {
                 std::cmp::Ordering::Equal => {}
                 other => return other,
             }
 
-            // When there's a query, sort by fuzzy score (higher is better)
             if has_query {
-                match b_score.cmp(a_score) {
+                // Usage never lifts a command above one the query is a prefix of
+                match is_prefix_match(b).cmp(&is_prefix_match(a)) {
                     std::cmp::Ordering::Equal => {}
                     other => return other,
                 }
-            }
 
-            // Then sort by history position (lower = more recent = better)
-            match (a_hist, b_hist) {
-                (Some(a_pos), Some(b_pos)) => a_pos.cmp(b_pos),
-                (Some(_), None) => std::cmp::Ordering::Less, // In history beats not in history
-                (None, Some(_)) => std::cmp::Ordering::Greater,
-                (None, None) => a.text.cmp(&b.text), // Alphabetical for never-used commands
+                // Then by fuzzy score (higher is better), with usage as a bonus
+                match (b_score + b_rank / 3).cmp(&(a_score + a_rank / 3)) {
+                    std::cmp::Ordering::Equal => {}
+                    other => return other,
+                }
             }
+
+            // Then by usage rank, alphabetical for equally used commands
+            b_rank.cmp(a_rank).then_with(|| a.text.cmp(&b.text))
         });
 
         // Extract just the suggestions
         suggestions.into_iter().map(|(s, _, _)| s).collect()
     }
 
+    /// Best fuzzy score of `query` against a command's localized name, English
+    /// name and id, or None if none of them match
+    fn name_match_score(query: &str, cmd: &Command, localized_name: &str) -> Option<i32> {
+        let english_name = cmd.get_english_name();
+        [localized_name, english_name.as_str(), cmd.id.as_str()]
+            .into_iter()
+            .filter(|name| !name.is_empty())
+            .map(|name| fuzzy_match(query, name))
+            .filter(|result| result.matched)
+            .map(|result| result.score)
+            .max()
+    }
+
     /// Get count of registered plugin commands
     pub fn plugin_command_count(&self) -> usize {
         self.plugin_commands.read().unwrap().len()
@@ -349,6 +437,8 @@ mod tests {
 
         let custom_command = Command {
             name: "Test Command".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "A test command".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -370,6 +460,8 @@ mod tests {
 
         let custom_command = Command {
             name: "Test Command".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "A test command".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -390,6 +482,8 @@ mod tests {
 
         let command1 = Command {
             name: "Test Command".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "First version".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -399,6 +493,8 @@ mod tests {
 
         let command2 = Command {
             name: "Test Command".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "Second version".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -422,6 +518,8 @@ mod tests {
 
         registry.register(Command {
             name: "Plugin A: Command 1".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -431,6 +529,8 @@ mod tests {
 
         registry.register(Command {
             name: "Plugin A: Command 2".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -440,6 +540,8 @@ mod tests {
 
         registry.register(Command {
             name: "Plugin B: Command".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -467,6 +569,8 @@ mod tests {
 
         registry.register(Command {
             name: "Test Save".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "Test save command".to_string(),
             action: Action::None,
             contexts: vec![KeyContext::Normal],
@@ -501,6 +605,8 @@ mod tests {
 
         registry.register(Command {
             name: "Normal Only".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "Available only in normal context".to_string(),
             action: Action::None,
             contexts: vec![KeyContext::Normal],
@@ -510,6 +616,8 @@ mod tests {
 
         registry.register(Command {
             name: "Popup Only".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "Available only in popup context".to_string(),
             action: Action::None,
             contexts: vec![KeyContext::Popup],
@@ -552,6 +660,8 @@ mod tests {
 
         registry.register(Command {
             name: "Custom 1".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -561,6 +671,8 @@ mod tests {
 
         registry.register(Command {
             name: "Custom 2".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -584,6 +696,8 @@ mod tests {
         // Override it with a plugin command
         registry.register(Command {
             name: "Save File".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "Custom save implementation".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -686,6 +800,8 @@ mod tests {
         // Register some custom commands (never used)
         registry.register(Command {
             name: "Zebra Command".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -695,6 +811,8 @@ mod tests {
 
         registry.register(Command {
             name: "Alpha Command".to_string(),
+            id: String::new(),
+            english_name: String::new(),
             description: "".to_string(),
             action: Action::None,
             contexts: vec![],
@@ -737,6 +855,153 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_frequent_commands_rank_higher() {
+        use crate::config::Config;
+        use crate::input::keybindings::KeybindingResolver;
+
+        let mut registry = CommandRegistry::new();
+        let config = Config::default();
+        let keybindings = KeybindingResolver::new(&config);
+
+        // Quit is used most often, Open File most recently
+        for _ in 0..3 {
+            registry.record_usage("Quit");
+        }
+        registry.record_usage("Save File");
+        registry.record_usage("Open File");
+
+        let empty_contexts = std::collections::HashSet::new();
+        let results = registry.filter(
+            "",
+            KeyContext::Normal,
+            &keybindings,
+            false,
+            &empty_contexts,
+            None,
+        );
+
+        let quit_pos = results.iter().position(|s| s.text == "Quit").unwrap();
+        let open_pos = results.iter().position(|s| s.text == "Open File").unwrap();
+        let save_pos = results.iter().position(|s| s.text == "Save File").unwrap();
+        assert!(quit_pos < open_pos, "Quit should come before Open File");
+        assert!(
+            open_pos < save_pos,
+            "Open File should come before Save File"
+        );
+    }
+
+    #[test]
+    fn test_usage_does_not_override_prefix_match() {
+        use crate::config::Config;
+        use crate::input::keybindings::KeybindingResolver;
+
+        let mut registry = CommandRegistry::new();
+        let config = Config::default();
+        let keybindings = KeybindingResolver::new(&config);
+
+        for name in ["Zap Saver", "Saveable Zebra"] {
+            registry.register(Command {
+                name: name.to_string(),
+                id: String::new(),
+                english_name: String::new(),
+                description: "".to_string(),
+                action: Action::None,
+                contexts: vec![],
+                custom_contexts: vec![],
+                source: CommandSource::Builtin,
+            });
+        }
+        for _ in 0..50 {
+            registry.record_usage("Zap Saver");
+        }
+
+        let empty_contexts = std::collections::HashSet::new();
+        let results = registry.filter(
+            "save",
+            KeyContext::Normal,
+            &keybindings,
+            false,
+            &empty_contexts,
+            None,
+        );
+
+        let zap_pos = results.iter().position(|s| s.text == "Zap Saver").unwrap();
+        let prefix_pos = results
+            .iter()
+            .position(|s| s.text == "Saveable Zebra")
+            .unwrap();
+        assert!(
+            prefix_pos < zap_pos,
+            "Prefix match should come before the frequently used command"
+        );
+    }
+
+    #[test]
+    fn test_filter_matches_english_name_and_id() {
+        use crate::config::Config;
+        use crate::input::keybindings::KeybindingResolver;
+
+        let registry = CommandRegistry::new();
+        let config = Config::default();
+        let keybindings = KeybindingResolver::new(&config);
+
+        registry.register(Command {
+            name: "Zeilenumbruch umschalten".to_string(),
+            id: "toggle_wrapped_lines".to_string(),
+            english_name: "Toggle Wrapped Lines".to_string(),
+            description: "".to_string(),
+            action: Action::None,
+            contexts: vec![],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        });
+
+        let empty_contexts = std::collections::HashSet::new();
+        for query in ["zeilenumbruch", "toggle wrapped", "toggle_wrapped_lines"] {
+            let results = registry.filter(
+                query,
+                KeyContext::Normal,
+                &keybindings,
+                false,
+                &empty_contexts,
+                None,
+            );
+            assert!(
+                results.iter().any(|s| s.text == "Zeilenumbruch umschalten"),
+                "'{}' should find the command by its localized label",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn test_usage_persistence_and_reset() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data").join("command_usage.json");
+
+        let mut registry = CommandRegistry::new();
+        registry.record_usage("Save File");
+        registry.record_usage("Save File");
+        registry.record_usage("Quit");
+        registry.save_usage(&path).unwrap();
+
+        let mut loaded = CommandRegistry::new();
+        loaded.load_usage(&path).unwrap();
+        assert_eq!(loaded.history_position("Quit"), Some(0));
+        assert_eq!(loaded.history_position("Save File"), Some(1));
+        assert_eq!(loaded.command_usage.get("Save File"), Some(&2));
+
+        loaded.reset_usage();
+        assert_eq!(loaded.history_position("Quit"), None);
+        assert_eq!(loaded.usage_rank("Save File"), 0);
+
+        // A missing file is not an error
+        let mut fresh = CommandRegistry::new();
+        fresh.load_usage(&dir.path().join("missing.json")).unwrap();
+        assert_eq!(fresh.usage_rank("Quit"), 0);
+    }
+
     #[test]
     fn test_required_commands_exist() {
         // This test ensures that all required command palette entries exist.
@@ -18,6 +18,12 @@ pub enum CommandSource {
 pub struct Command {
     /// Command name (e.g., "Open File")
     pub name: String,
+    /// Locale-independent id: the action name for built-in commands (e.g.
+    /// "lsp_goto_definition"), the handler name for plugin commands
+    pub id: String,
+    /// Name in English, so the palette also finds a command by its English
+    /// name when the UI is in another language
+    pub english_name: String,
     /// Command description
     pub description: String,
     /// The action to trigger
@@ -46,6 +52,21 @@ impl Command {
         self.name.clone()
     }
 
+    /// Get the English name of the command
+    pub fn get_english_name(&self) -> String {
+        if self.english_name.starts_with('%') {
+            if let CommandSource::Plugin(ref plugin_name) = self.source {
+                return crate::i18n::translate_plugin_string_in(
+                    plugin_name,
+                    &self.english_name[1..],
+                    &std::collections::HashMap::new(),
+                    "en",
+                );
+            }
+        }
+        self.english_name.clone()
+    }
+
     /// Get the localized description of the command
     pub fn get_localized_description(&self) -> String {
         if self.description.starts_with('%') {
@@ -204,6 +225,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.new_scratch_with_language",
+        desc_key: "cmd.new_scratch_with_language_desc",
+        action: || Action::NewScratchWithLanguage,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.close_buffer",
         desc_key: "cmd.close_buffer_desc",
@@ -218,6 +246,34 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.close_other_tabs",
+        desc_key: "cmd.close_other_tabs_desc",
+        action: || Action::CloseOtherTabs,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.close_tabs_to_right",
+        desc_key: "cmd.close_tabs_to_right_desc",
+        action: || Action::CloseTabsToRight,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.reopen_closed_tab",
+        desc_key: "cmd.reopen_closed_tab_desc",
+        action: || Action::ReopenClosedTab,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_pin_tab",
+        desc_key: "cmd.toggle_pin_tab_desc",
+        action: || Action::TogglePinTab,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.revert_file",
         desc_key: "cmd.revert_file_desc",
@@ -282,6 +338,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.repeat_last",
+        desc_key: "cmd.repeat_last_desc",
+        action: || Action::RepeatLast,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.copy",
         desc_key: "cmd.copy_desc",
@@ -289,6 +352,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.copy_visible",
+        desc_key: "cmd.copy_visible_desc",
+        action: || Action::CopyVisible,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.copy_with_formatting",
         desc_key: "cmd.copy_with_formatting_desc",
@@ -296,6 +366,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.export_html",
+        desc_key: "cmd.export_html_desc",
+        action: || Action::ExportHtml,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.export_ansi",
+        desc_key: "cmd.export_ansi_desc",
+        action: || Action::ExportAnsi,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.cut",
         desc_key: "cmd.cut_desc",
@@ -310,6 +394,27 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.paste_from_history",
+        desc_key: "cmd.paste_from_history_desc",
+        action: || Action::PasteFromHistory,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.insert_unicode_char",
+        desc_key: "cmd.insert_unicode_char_desc",
+        action: || Action::InsertUnicodeChar,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.cycle_paste",
+        desc_key: "cmd.cycle_paste_desc",
+        action: || Action::CyclePaste,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.delete_line",
         desc_key: "cmd.delete_line_desc",
@@ -345,6 +450,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.transpose_words",
+        desc_key: "cmd.transpose_words_desc",
+        action: || Action::TransposeWords,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.transform_uppercase",
         desc_key: "cmd.transform_uppercase_desc",
@@ -380,6 +492,27 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.increment_number",
+        desc_key: "cmd.increment_number_desc",
+        action: || Action::IncrementNumber(1),
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.decrement_number",
+        desc_key: "cmd.decrement_number_desc",
+        action: || Action::DecrementNumber(1),
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.increment_number_sequence",
+        desc_key: "cmd.increment_number_sequence_desc",
+        action: || Action::IncrementNumberSequence(1),
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.recenter",
         desc_key: "cmd.recenter_desc",
@@ -423,6 +556,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.shrink_selection",
+        desc_key: "cmd.shrink_selection_desc",
+        action: || Action::ShrinkSelection,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Multi-cursor
     CommandDef {
         name_key: "cmd.add_cursor_above",
@@ -452,6 +592,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.align_cursors",
+        desc_key: "cmd.align_cursors_desc",
+        action: || Action::AlignCursors,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.align_by_pattern",
+        desc_key: "cmd.align_by_pattern_desc",
+        action: || Action::AlignByPattern,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Buffer navigation
     CommandDef {
         name_key: "cmd.next_buffer",
@@ -481,6 +635,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.buffer_picker",
+        desc_key: "cmd.buffer_picker_desc",
+        action: || Action::BufferPicker,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
     // Split operations
     CommandDef {
         name_key: "cmd.split_horizontal",
@@ -538,6 +699,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.equalize_splits",
+        desc_key: "cmd.equalize_splits_desc",
+        action: || Action::EqualizeSplits,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_scroll_link",
+        desc_key: "cmd.toggle_scroll_link_desc",
+        action: || Action::ToggleScrollLink,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // View toggles
     CommandDef {
         name_key: "cmd.toggle_line_numbers",
@@ -639,6 +814,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_render_whitespace",
+        desc_key: "cmd.toggle_render_whitespace_desc",
+        action: || Action::ToggleRenderWhitespace,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.reset_buffer_settings",
         desc_key: "cmd.reset_buffer_settings_desc",
@@ -717,6 +899,62 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, FileExplorer, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_indent_guides",
+        desc_key: "cmd.toggle_indent_guides_desc",
+        action: || Action::ToggleIndentGuides,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_minimap",
+        desc_key: "cmd.toggle_minimap_desc",
+        action: || Action::ToggleMinimap,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_color_swatches",
+        desc_key: "cmd.toggle_color_swatches_desc",
+        action: || Action::ToggleColorSwatches,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.adjust_color",
+        desc_key: "cmd.adjust_color_desc",
+        action: || Action::AdjustColor,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_aligned_view",
+        desc_key: "cmd.toggle_aligned_view_desc",
+        action: || Action::ToggleAlignedView,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_markdown_preview",
+        desc_key: "cmd.toggle_markdown_preview_desc",
+        action: || Action::ToggleMarkdownPreview,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_follow_tail",
+        desc_key: "cmd.toggle_follow_tail_desc",
+        action: || Action::ToggleFollowTail,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.filter_log_lines",
+        desc_key: "cmd.filter_log_lines_desc",
+        action: || Action::FilterLogLines,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.focus_file_explorer",
         desc_key: "cmd.focus_file_explorer_desc",
@@ -724,6 +962,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.reveal_in_file_explorer",
+        desc_key: "cmd.reveal_in_file_explorer_desc",
+        action: || Action::RevealInFileExplorer,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_file_explorer_follow",
+        desc_key: "cmd.toggle_file_explorer_follow_desc",
+        action: || Action::ToggleFileExplorerFollow,
+        contexts: &[Normal, FileExplorer],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.focus_editor",
         desc_key: "cmd.focus_editor_desc",
@@ -766,6 +1018,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[FileExplorer],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.explorer_filter",
+        desc_key: "cmd.explorer_filter_desc",
+        action: || Action::FileExplorerFilter,
+        contexts: &[FileExplorer],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_hidden_files",
         desc_key: "cmd.toggle_hidden_files_desc",
@@ -788,6 +1047,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_read_only",
+        desc_key: "cmd.toggle_read_only_desc",
+        action: || Action::ToggleReadOnly,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.set_background",
         desc_key: "cmd.set_background_desc",
@@ -881,6 +1147,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.select_smart_home",
+        desc_key: "cmd.select_smart_home_desc",
+        action: || Action::SelectSmartHome,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.show_completions",
         desc_key: "cmd.show_completions_desc",
@@ -888,6 +1161,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.expand_snippet",
+        desc_key: "cmd.expand_snippet_desc",
+        action: || Action::ExpandSnippet,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.goto_definition",
         desc_key: "cmd.goto_definition_desc",
@@ -973,6 +1253,55 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_block_comment",
+        desc_key: "cmd.toggle_block_comment_desc",
+        action: || Action::ToggleBlockComment,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.draw_box",
+        desc_key: "cmd.draw_box_desc",
+        action: || Action::DrawBox,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_line_drawing",
+        desc_key: "cmd.toggle_line_drawing_desc",
+        action: || Action::ToggleLineDrawing,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.join_lines",
+        desc_key: "cmd.join_lines_desc",
+        action: || Action::JoinLines,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.hard_wrap",
+        desc_key: "cmd.hard_wrap_desc",
+        action: || Action::HardWrap,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.surround_selection",
+        desc_key: "cmd.surround_selection_desc",
+        action: || Action::SurroundSelection,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.change_surrounding",
+        desc_key: "cmd.change_surrounding_desc",
+        action: || Action::ChangeSurrounding,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.dedent_selection",
         desc_key: "cmd.dedent_selection_desc",
@@ -987,6 +1316,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.select_to_matching_bracket",
+        desc_key: "cmd.select_to_matching_bracket_desc",
+        action: || Action::SelectToMatchingBracket,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Error navigation
     CommandDef {
         name_key: "cmd.jump_to_next_error",
@@ -1018,6 +1354,48 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_bookmark",
+        desc_key: "cmd.toggle_bookmark_desc",
+        action: || Action::ToggleBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.add_labeled_bookmark",
+        desc_key: "cmd.add_labeled_bookmark_desc",
+        action: || Action::AddLabeledBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.next_bookmark",
+        desc_key: "cmd.next_bookmark_desc",
+        action: || Action::NextBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.previous_bookmark",
+        desc_key: "cmd.previous_bookmark_desc",
+        action: || Action::PreviousBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.browse_bookmarks",
+        desc_key: "cmd.browse_bookmarks_desc",
+        action: || Action::BrowseBookmarks,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.clear_buffer_bookmarks",
+        desc_key: "cmd.clear_buffer_bookmarks_desc",
+        action: || Action::ClearBufferBookmarks,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.list_macros",
         desc_key: "cmd.list_macros_desc",
@@ -1089,6 +1467,41 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.show_plugin_log",
+        desc_key: "cmd.show_plugin_log_desc",
+        action: || Action::ShowPluginLog,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.show_logs",
+        desc_key: "cmd.show_logs_desc",
+        action: || Action::ShowLogs,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_performance_hud",
+        desc_key: "cmd.toggle_performance_hud_desc",
+        action: || Action::TogglePerformanceHud,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.dump_performance_report",
+        desc_key: "cmd.dump_performance_report_desc",
+        action: || Action::DumpPerformanceReport,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.manage_plugins",
+        desc_key: "cmd.manage_plugins_desc",
+        action: || Action::ManagePlugins,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.show_lsp_status",
         desc_key: "cmd.show_lsp_status_desc",
@@ -1096,6 +1509,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.show_process_status",
+        desc_key: "cmd.show_process_status_desc",
+        action: || Action::ShowProcessStatus,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.clear_warnings",
         desc_key: "cmd.clear_warnings_desc",
@@ -1166,6 +1586,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.reset_command_ranking",
+        desc_key: "cmd.reset_command_ranking_desc",
+        action: || Action::ResetCommandRanking,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     // Input calibration
     CommandDef {
         name_key: "cmd.calibrate_input",
@@ -1203,6 +1630,62 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.switch_terminal",
+        desc_key: "cmd.switch_terminal_desc",
+        action: || Action::SwitchTerminal,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.rename_terminal",
+        desc_key: "cmd.rename_terminal_desc",
+        action: || Action::RenameTerminal,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.run_selection_in_terminal",
+        desc_key: "cmd.run_selection_in_terminal_desc",
+        action: || Action::RunSelectionInTerminal,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.run_file_in_terminal",
+        desc_key: "cmd.run_file_in_terminal_desc",
+        action: || Action::RunFileInTerminal,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.open_link_under_cursor",
+        desc_key: "cmd.open_link_under_cursor_desc",
+        action: || Action::OpenLinkUnderCursor,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.run_task",
+        desc_key: "cmd.run_task_desc",
+        action: || Action::RunTask,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.rerun_last_task",
+        desc_key: "cmd.rerun_last_task_desc",
+        action: || Action::RerunLastTask,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.cancel_task",
+        desc_key: "cmd.cancel_task_desc",
+        action: || Action::CancelTask,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     // Shell command operations
     CommandDef {
         name_key: "cmd.shell_command",
@@ -1228,17 +1711,45 @@ static COMMAND_DEFS: &[CommandDef] = &[
     },
 ];
 
+/// Locale-independent id of a built-in command
+///
+/// The action's config name when it has one, otherwise the command's key
+/// without the `cmd.` prefix.
+fn command_id(def: &CommandDef, action: &Action, action_names: &[(Action, String)]) -> String {
+    action_names
+        .iter()
+        .find(|(named, _)| named == action)
+        .map(|(_, name)| name.clone())
+        .unwrap_or_else(|| {
+            def.name_key
+                .strip_prefix("cmd.")
+                .unwrap_or(def.name_key)
+                .to_string()
+        })
+}
+
 /// Get all available commands for the command palette
 pub fn get_all_commands() -> Vec<Command> {
+    let no_args = std::collections::HashMap::new();
+    let action_names: Vec<(Action, String)> = Action::all_action_names()
+        .into_iter()
+        .filter_map(|name| Some((Action::from_str(&name, &no_args)?, name)))
+        .collect();
+
     COMMAND_DEFS
         .iter()
-        .map(|def| Command {
-            name: t!(def.name_key).to_string(),
-            description: t!(def.desc_key).to_string(),
-            action: (def.action)(),
-            contexts: def.contexts.to_vec(),
-            custom_contexts: def.custom_contexts.iter().map(|s| s.to_string()).collect(),
-            source: CommandSource::Builtin,
+        .map(|def| {
+            let action = (def.action)();
+            Command {
+                name: t!(def.name_key).to_string(),
+                id: command_id(def, &action, &action_names),
+                english_name: t!(def.name_key, locale = "en").to_string(),
+                description: t!(def.desc_key).to_string(),
+                action,
+                contexts: def.contexts.to_vec(),
+                custom_contexts: def.custom_contexts.iter().map(|s| s.to_string()).collect(),
+                source: CommandSource::Builtin,
+            }
         })
         .collect()
 }
@@ -1258,13 +1769,9 @@ pub fn filter_commands(
         cmd.contexts.is_empty() || cmd.contexts.contains(&current_context)
     };
 
-    // Helper function for fuzzy matching
-    let matches_query = |cmd: &Command| -> bool {
-        if query.is_empty() {
-            return true;
-        }
-
-        let name_lower = cmd.name.to_lowercase();
+    // Helper function for fuzzy matching against one name
+    let matches_name = |name: &str| -> bool {
+        let name_lower = name.to_lowercase();
         let mut query_chars = query_lower.chars();
         let mut current_char = query_chars.next();
 
@@ -1281,6 +1788,14 @@ pub fn filter_commands(
         current_char.is_none() // All query characters matched
     };
 
+    // A command matches by its localized name, English name or id
+    let matches_query = |cmd: &Command| -> bool {
+        query.is_empty()
+            || matches_name(&cmd.name)
+            || matches_name(&cmd.english_name)
+            || matches_name(&cmd.id)
+    };
+
     // Filter and convert to suggestions
     let mut suggestions: Vec<Suggestion> = commands
         .into_iter()
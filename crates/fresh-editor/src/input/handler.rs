@@ -147,6 +147,8 @@ pub enum DeferredAction {
     PromptSelectionChanged {
         selected_index: usize,
     },
+    /// Close the buffer selected in the buffer picker
+    CloseBufferFromPicker,
 
     // Popup actions
     ClosePopup,
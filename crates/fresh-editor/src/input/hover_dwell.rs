@@ -0,0 +1,113 @@
+//! Mouse dwell tracking for hover tooltips
+//!
+//! Fed with mouse motion, [`HoverDwell`] notices when the pointer has rested
+//! on one cell for the tooltip delay. Moving to another cell, pressing a key
+//! or clicking starts over, so a tooltip never outlives the position it was
+//! shown for.
+
+use std::time::{Duration, Instant};
+
+/// Where the pointer is in the rest -> tooltip cycle
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HoverDwell {
+    /// No pointer position to wait on (after a key press or click)
+    #[default]
+    Idle,
+    /// The pointer arrived at `(col, row)` at `since`
+    Resting { col: u16, row: u16, since: Instant },
+    /// A tooltip is shown for `(col, row)`
+    Shown { col: u16, row: u16 },
+}
+
+impl HoverDwell {
+    /// The pointer moved to `(col, row)`. Returns true if a shown tooltip
+    /// should be dismissed.
+    pub fn moved(&mut self, col: u16, row: u16, now: Instant) -> bool {
+        let was_shown = match *self {
+            Self::Resting { col: c, row: r, .. } if (c, r) == (col, row) => return false,
+            Self::Shown { col: c, row: r } if (c, r) == (col, row) => return false,
+            Self::Shown { .. } => true,
+            _ => false,
+        };
+        *self = Self::Resting {
+            col,
+            row,
+            since: now,
+        };
+        was_shown
+    }
+
+    /// A key press or mouse button ended the hover. Returns true if a shown
+    /// tooltip should be dismissed.
+    pub fn reset(&mut self) -> bool {
+        let was_shown = matches!(self, Self::Shown { .. });
+        *self = Self::Idle;
+        was_shown
+    }
+
+    /// The cell the pointer has rested on for at least `delay`, if a tooltip
+    /// isn't already shown for it
+    pub fn due(&self, now: Instant, delay: Duration) -> Option<(u16, u16)> {
+        match *self {
+            Self::Resting { col, row, since } if now.duration_since(since) >= delay => {
+                Some((col, row))
+            }
+            _ => None,
+        }
+    }
+
+    /// The tooltip for the resting position was handled (shown, or there was
+    /// nothing to show), so it isn't looked up again until the pointer moves
+    pub fn mark_shown(&mut self) {
+        if let Self::Resting { col, row, .. } = *self {
+            *self = Self::Shown { col, row };
+        }
+    }
+
+    /// Whether a tooltip is currently shown
+    pub fn is_shown(&self) -> bool {
+        matches!(self, Self::Shown { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELAY: Duration = Duration::from_millis(400);
+
+    #[test]
+    fn test_due_after_resting_for_delay() {
+        let start = Instant::now();
+        let mut dwell = HoverDwell::default();
+        assert_eq!(dwell.due(start, DELAY), None);
+
+        dwell.moved(5, 3, start);
+        assert_eq!(dwell.due(start + Duration::from_millis(399), DELAY), None);
+        // Motion reports for the same cell don't restart the wait
+        dwell.moved(5, 3, start + Duration::from_millis(200));
+        assert_eq!(dwell.due(start + DELAY, DELAY), Some((5, 3)));
+
+        dwell.mark_shown();
+        assert!(dwell.is_shown());
+        assert_eq!(dwell.due(start + DELAY * 2, DELAY), None);
+    }
+
+    #[test]
+    fn test_movement_and_keys_dismiss() {
+        let start = Instant::now();
+        let mut dwell = HoverDwell::default();
+        dwell.moved(5, 3, start);
+        dwell.mark_shown();
+
+        assert!(!dwell.moved(5, 3, start + DELAY));
+        assert!(dwell.moved(6, 3, start + DELAY));
+        assert_eq!(dwell.due(start + DELAY, DELAY), None);
+        assert_eq!(dwell.due(start + DELAY * 2, DELAY), Some((6, 3)));
+
+        dwell.mark_shown();
+        assert!(dwell.reset());
+        assert!(!dwell.reset());
+        assert_eq!(dwell.due(start + DELAY * 3, DELAY), None);
+    }
+}
@@ -192,7 +192,7 @@ pub fn terminal_key_equivalents(
 /// Context in which a keybinding is active
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KeyContext {
-    /// Global bindings that work in all contexts (checked first with highest priority)
+    /// Global bindings that work in all contexts (checked before keymap bindings of the active context)
     Global,
     /// Normal editing mode
     Normal,
@@ -231,6 +231,54 @@ impl KeyContext {
         })
     }
 
+    /// Parse context from the `context` field of a keybinding
+    /// ("editor", "prompt", "explorer", "terminal", "menu", ...)
+    pub fn from_context_name(name: &str) -> Option<Self> {
+        Some(match name.trim() {
+            "global" => Self::Global,
+            "editor" => Self::Normal,
+            "prompt" => Self::Prompt,
+            "popup" => Self::Popup,
+            "explorer" => Self::FileExplorer,
+            "menu" => Self::Menu,
+            "terminal" => Self::Terminal,
+            "settings" => Self::Settings,
+            _ => return None,
+        })
+    }
+
+    /// Convert context to the name used by the `context` field of a keybinding
+    pub fn to_context_name(self) -> &'static str {
+        match self {
+            Self::Global => "global",
+            Self::Normal => "editor",
+            Self::Prompt => "prompt",
+            Self::Popup => "popup",
+            Self::FileExplorer => "explorer",
+            Self::Menu => "menu",
+            Self::Terminal => "terminal",
+            Self::Settings => "settings",
+        }
+    }
+
+    /// Context a configured keybinding applies in.
+    /// The `context` field wins over the legacy `when` clause; bindings with
+    /// neither apply in the editor.
+    pub fn for_binding(binding: &crate::config::Keybinding) -> Self {
+        if let Some(ref context) = binding.context {
+            if let Some(ctx) =
+                Self::from_context_name(context).or_else(|| Self::from_when_clause(context))
+            {
+                return ctx;
+            }
+        }
+        binding
+            .when
+            .as_deref()
+            .and_then(Self::from_when_clause)
+            .unwrap_or(Self::Normal)
+    }
+
     /// Convert context to "when" clause string
     pub fn to_when_clause(self) -> &'static str {
         match self {
@@ -291,6 +339,7 @@ pub enum Action {
     SelectWord,
     SelectLine,
     ExpandSelection,
+    ShrinkSelection,
 
     // Block/rectangular selection (column-wise)
     BlockSelectLeft,
@@ -307,8 +356,12 @@ pub enum Action {
     DeleteToLineEnd,
     DeleteToLineStart,
     TransposeChars,
+    TransposeWords, // Swap the word under the cursor with the next one
     OpenLine,
     DuplicateLine,
+    IncrementNumber(i64), // Add to the number under or after each cursor
+    DecrementNumber(i64),
+    IncrementNumberSequence(i64), // Add step, 2*step, ... to the numbers at successive cursors
 
     // View
     Recenter,
@@ -318,9 +371,15 @@ pub enum Action {
 
     // Clipboard
     Copy,
+    CopyVisible, // Copy the selection without the lines hidden in folds
     CopyWithTheme(String),
+    ExportHtml, // Render the selection or buffer as HTML with theme colors
+    ExportAnsi, // Render the selection or buffer as text with ANSI colors
     Cut,
     Paste,
+    PasteFromHistory, // Pick an earlier copy from the clipboard history and paste it
+    CyclePaste,       // Replace the text just pasted with the next older clipboard entry
+    InsertUnicodeChar, // Pick a Unicode character by name or codepoint and insert it
 
     // Vi-style yank (copy without selection, then restore cursor)
     YankWordForward,
@@ -333,6 +392,8 @@ pub enum Action {
     AddCursorBelow,
     AddCursorNextMatch,
     RemoveSecondaryCursors,
+    AlignCursors,   // Pad cursors with spaces up to the rightmost cursor's column
+    AlignByPattern, // Place cursors on a regex match per line, then align them
 
     // File operations
     Save,
@@ -340,8 +401,13 @@ pub enum Action {
     Open,
     SwitchProject,
     New,
+    NewScratchWithLanguage, // Pick a language, then open an untitled buffer highlighted as it
     Close,
     CloseTab,
+    CloseOtherTabs,
+    CloseTabsToRight,
+    ReopenClosedTab,
+    TogglePinTab,
     Quit,
     ForceQuit,
     Detach,
@@ -355,26 +421,42 @@ pub enum Action {
     GotoLine,
     ScanLineIndex,
     GoToMatchingBracket,
+    SelectToMatchingBracket,
     JumpToNextError,
     JumpToPreviousError,
 
     // Smart editing
     SmartHome,
+    SelectSmartHome,
     DedentSelection,
     ToggleComment,
+    ToggleBlockComment,
     ToggleFold,
+    DrawBox,           // Draw a box on the border of the block selection
+    ToggleLineDrawing, // Arrow keys draw box-drawing lines, typing overwrites
+    JoinLines,         // Join the selected lines, or the line and the next, into one
+    HardWrap,          // Rewrap the selected paragraphs at a width asked for
+    SurroundSelection, // Wrap the selections in a pair asked for
+    ChangeSurrounding, // Replace or remove the quotes or brackets around the cursor
 
     // Bookmarks
     SetBookmark(char),
     JumpToBookmark(char),
     ClearBookmark(char),
     ListBookmarks,
+    ToggleBookmark,
+    AddLabeledBookmark,
+    NextBookmark,
+    PreviousBookmark,
+    BrowseBookmarks,
+    ClearBufferBookmarks,
 
     // Search options
     ToggleSearchCaseSensitive,
     ToggleSearchWholeWord,
     ToggleSearchRegex,
     ToggleSearchConfirmEach,
+    ToggleSearchPreserveCase,
 
     // Macros
     StartMacroRecording,
@@ -394,6 +476,7 @@ pub enum Action {
     // Undo/redo
     Undo,
     Redo,
+    RepeatLast, // Replay the last repeatable edit at the current cursors
 
     // View
     ScrollUp,
@@ -402,12 +485,22 @@ pub enum Action {
     ShowKeyboardShortcuts,
     ShowWarnings,
     ShowStatusLog,
+    ShowPluginLog,
+    ShowLogs,
+    TogglePerformanceHud,
+    DumpPerformanceReport,
+    ManagePlugins,
+    PluginPanelToggle,
+    PluginPanelReload,
+    PluginPanelDetails,
     ShowLspStatus,
+    ShowProcessStatus,
     ClearWarnings,
     CommandPalette, // TODO: Consider dropping this now that we have QuickOpen
     /// Quick Open - unified prompt with prefix-based provider routing
     QuickOpen,
     ToggleLineWrap,
+    ToggleReadOnly,
     ToggleComposeMode,
     SetComposeWidth,
     SelectTheme,
@@ -420,6 +513,7 @@ pub enum Action {
     PrevBuffer,
     SwitchToPreviousTab,
     SwitchToTabByName,
+    BufferPicker, // Pick an open buffer in most recently used order, with a preview
 
     // Tab scrolling
     ScrollTabsLeft,
@@ -438,6 +532,9 @@ pub enum Action {
     IncreaseSplitSize,
     DecreaseSplitSize,
     ToggleMaximizeSplit,
+    EqualizeSplits,
+    /// Scroll the active split and the next one together
+    ToggleScrollLink,
 
     // Prompt mode actions
     PromptConfirm,
@@ -464,6 +561,7 @@ pub enum Action {
     PromptCopy,
     PromptCut,
     PromptPaste,
+    PromptBindCommand, // Bind the command highlighted in the command palette
     // Prompt selection actions
     PromptMoveLeftSelecting,
     PromptMoveRightSelecting,
@@ -494,7 +592,23 @@ pub enum Action {
     // Scrollbar visibility
     ToggleVerticalScrollbar,
     ToggleHorizontalScrollbar,
+    // Indent guide visibility
+    ToggleIndentGuides,
+    // Minimap visibility
+    ToggleMinimap,
+    // Color swatches before color literals, and adjusting a literal's color
+    ToggleColorSwatches,
+    AdjustColor,
+    // Padding the columns of a CSV or TSV file so they line up
+    ToggleAlignedView,
+    // A rendered preview of a markdown buffer in a split beside it
+    ToggleMarkdownPreview,
+    // Keeping the view on the end of a log file, and hiding its lines by pattern
+    ToggleFollowTail,
+    FilterLogLines,
     FocusFileExplorer,
+    RevealInFileExplorer,
+    ToggleFileExplorerFollow,
     FocusEditor,
     FileExplorerUp,
     FileExplorerDown,
@@ -512,9 +626,11 @@ pub enum Action {
     FileExplorerToggleGitignored,
     FileExplorerSearchClear,
     FileExplorerSearchBackspace,
+    FileExplorerFilter,
 
     // LSP operations
     LspCompletion,
+    ExpandSnippet,
     LspGotoDefinition,
     LspReferences,
     LspRename,
@@ -544,6 +660,7 @@ pub enum Action {
     ToggleIndentationStyle,
     ToggleTabIndicators,
     ToggleWhitespaceIndicators,
+    ToggleRenderWhitespace,
     ResetBufferSettings,
     AddRuler,
     RemoveRuler,
@@ -590,12 +707,20 @@ pub enum Action {
     SettingsDecrement,   // Decrement number value or previous dropdown option
 
     // Terminal operations
-    OpenTerminal,          // Open a new terminal in the current split
-    CloseTerminal,         // Close the current terminal
-    FocusTerminal,         // Focus the terminal buffer (if viewing terminal, focus input)
-    TerminalEscape,        // Escape from terminal mode back to editor
-    ToggleKeyboardCapture, // Toggle keyboard capture mode (all keys go to terminal)
-    TerminalPaste,         // Paste clipboard contents into terminal as a single batch
+    OpenTerminal,           // Open a new terminal in the current split
+    CloseTerminal,          // Close the current terminal
+    FocusTerminal,          // Focus the terminal buffer (if viewing terminal, focus input)
+    TerminalEscape,         // Escape from terminal mode back to editor
+    ToggleKeyboardCapture,  // Toggle keyboard capture mode (all keys go to terminal)
+    TerminalPaste,          // Paste clipboard contents into terminal as a single batch
+    SwitchTerminal,         // Pick one of the open terminals to show
+    RenameTerminal,         // Rename the current terminal's tab
+    RunSelectionInTerminal, // Send the selection or current line to a terminal
+    RunFileInTerminal,      // Run the current file with its language's run command
+    OpenLinkUnderCursor,    // Open the file location or hyperlink under the cursor
+    RunTask,                // Pick a task from the `tasks` config and run it
+    RerunLastTask,          // Run the last task again
+    CancelTask,             // Stop the running task
 
     // Shell command operations
     ShellCommand,        // Run shell command on buffer/selection, output to new buffer
@@ -614,6 +739,7 @@ pub enum Action {
 
     // Keybinding editor
     OpenKeybindingEditor, // Open the keybinding editor modal
+    ResetCommandRanking,  // Forget command palette usage history and counts
 
     // No-op
     None,
@@ -673,6 +799,16 @@ impl Action {
         }
     }
 
+    /// Name to use for this action in a keybinding config
+    ///
+    /// None for actions that need arguments or can't be bound from config.
+    pub fn config_name(&self) -> Option<String> {
+        let no_args = HashMap::new();
+        Self::all_action_names()
+            .into_iter()
+            .find(|name| Self::from_str(name, &no_args).as_ref() == Some(self))
+    }
+
     define_action_str_mapping! {
         args;
         simple {
@@ -714,6 +850,7 @@ impl Action {
             "select_word" => SelectWord,
             "select_line" => SelectLine,
             "expand_selection" => ExpandSelection,
+            "shrink_selection" => ShrinkSelection,
 
             "block_select_left" => BlockSelectLeft,
             "block_select_right" => BlockSelectRight,
@@ -728,14 +865,19 @@ impl Action {
             "delete_to_line_end" => DeleteToLineEnd,
             "delete_to_line_start" => DeleteToLineStart,
             "transpose_chars" => TransposeChars,
+            "transpose_words" => TransposeWords,
             "open_line" => OpenLine,
             "duplicate_line" => DuplicateLine,
             "recenter" => Recenter,
             "set_mark" => SetMark,
 
             "copy" => Copy,
+            "copy_visible" => CopyVisible,
             "cut" => Cut,
             "paste" => Paste,
+            "paste_from_history" => PasteFromHistory,
+            "insert_unicode_char" => InsertUnicodeChar,
+            "cycle_paste" => CyclePaste,
 
             "yank_word_forward" => YankWordForward,
             "yank_word_backward" => YankWordBackward,
@@ -746,14 +888,21 @@ impl Action {
             "add_cursor_below" => AddCursorBelow,
             "add_cursor_next_match" => AddCursorNextMatch,
             "remove_secondary_cursors" => RemoveSecondaryCursors,
+            "align_cursors" => AlignCursors,
+            "align_by_pattern" => AlignByPattern,
 
             "save" => Save,
             "save_as" => SaveAs,
             "open" => Open,
             "switch_project" => SwitchProject,
             "new" => New,
+            "new_scratch_with_language" => NewScratchWithLanguage,
             "close" => Close,
             "close_tab" => CloseTab,
+            "close_other_tabs" => CloseOtherTabs,
+            "close_tabs_to_right" => CloseTabsToRight,
+            "reopen_closed_tab" => ReopenClosedTab,
+            "toggle_pin_tab" => TogglePinTab,
             "quit" => Quit,
             "force_quit" => ForceQuit,
             "detach" => Detach,
@@ -763,20 +912,36 @@ impl Action {
             "goto_line" => GotoLine,
             "scan_line_index" => ScanLineIndex,
             "goto_matching_bracket" => GoToMatchingBracket,
+            "select_to_matching_bracket" => SelectToMatchingBracket,
             "jump_to_next_error" => JumpToNextError,
             "jump_to_previous_error" => JumpToPreviousError,
 
             "smart_home" => SmartHome,
+            "select_smart_home" => SelectSmartHome,
             "dedent_selection" => DedentSelection,
             "toggle_comment" => ToggleComment,
+            "toggle_block_comment" => ToggleBlockComment,
             "toggle_fold" => ToggleFold,
+            "draw_box" => DrawBox,
+            "toggle_line_drawing" => ToggleLineDrawing,
+            "join_lines" => JoinLines,
+            "hard_wrap" => HardWrap,
+            "surround_selection" => SurroundSelection,
+            "change_surrounding" => ChangeSurrounding,
 
             "list_bookmarks" => ListBookmarks,
+            "toggle_bookmark" => ToggleBookmark,
+            "add_labeled_bookmark" => AddLabeledBookmark,
+            "next_bookmark" => NextBookmark,
+            "previous_bookmark" => PreviousBookmark,
+            "browse_bookmarks" => BrowseBookmarks,
+            "clear_buffer_bookmarks" => ClearBufferBookmarks,
 
             "toggle_search_case_sensitive" => ToggleSearchCaseSensitive,
             "toggle_search_whole_word" => ToggleSearchWholeWord,
             "toggle_search_regex" => ToggleSearchRegex,
             "toggle_search_confirm_each" => ToggleSearchConfirmEach,
+            "toggle_search_preserve_case" => ToggleSearchPreserveCase,
 
             "start_macro_recording" => StartMacroRecording,
             "stop_macro_recording" => StopMacroRecording,
@@ -790,6 +955,7 @@ impl Action {
 
             "undo" => Undo,
             "redo" => Redo,
+            "repeat_last" => RepeatLast,
 
             "scroll_up" => ScrollUp,
             "scroll_down" => ScrollDown,
@@ -797,16 +963,29 @@ impl Action {
             "keyboard_shortcuts" => ShowKeyboardShortcuts,
             "show_warnings" => ShowWarnings,
             "show_status_log" => ShowStatusLog,
+            "show_plugin_log" => ShowPluginLog,
+            "show_logs" => ShowLogs,
+            "toggle_performance_hud" => TogglePerformanceHud,
+            "dump_performance_report" => DumpPerformanceReport,
+            "manage_plugins" => ManagePlugins,
+            "plugin_panel_toggle" => PluginPanelToggle,
+            "plugin_panel_reload" => PluginPanelReload,
+            "plugin_panel_details" => PluginPanelDetails,
             "show_lsp_status" => ShowLspStatus,
+            "show_process_status" => ShowProcessStatus,
+            "export_html" => ExportHtml,
+            "export_ansi" => ExportAnsi,
             "clear_warnings" => ClearWarnings,
             "command_palette" => CommandPalette,
             "quick_open" => QuickOpen,
             "toggle_line_wrap" => ToggleLineWrap,
+            "toggle_read_only" => ToggleReadOnly,
             "toggle_compose_mode" => ToggleComposeMode,
             "set_compose_width" => SetComposeWidth,
 
             "next_buffer" => NextBuffer,
             "prev_buffer" => PrevBuffer,
+            "buffer_picker" => BufferPicker,
 
             "navigate_back" => NavigateBack,
             "navigate_forward" => NavigateForward,
@@ -819,6 +998,8 @@ impl Action {
             "increase_split_size" => IncreaseSplitSize,
             "decrease_split_size" => DecreaseSplitSize,
             "toggle_maximize_split" => ToggleMaximizeSplit,
+            "equalize_splits" => EqualizeSplits,
+            "toggle_scroll_link" => ToggleScrollLink,
 
             "prompt_confirm" => PromptConfirm,
             "prompt_cancel" => PromptCancel,
@@ -838,6 +1019,7 @@ impl Action {
             "prompt_copy" => PromptCopy,
             "prompt_cut" => PromptCut,
             "prompt_paste" => PromptPaste,
+            "prompt_bind_command" => PromptBindCommand,
             "prompt_move_left_selecting" => PromptMoveLeftSelecting,
             "prompt_move_right_selecting" => PromptMoveRightSelecting,
             "prompt_move_home_selecting" => PromptMoveHomeSelecting,
@@ -863,7 +1045,17 @@ impl Action {
             "toggle_tab_bar" => ToggleTabBar,
             "toggle_vertical_scrollbar" => ToggleVerticalScrollbar,
             "toggle_horizontal_scrollbar" => ToggleHorizontalScrollbar,
+            "toggle_indent_guides" => ToggleIndentGuides,
+            "toggle_minimap" => ToggleMinimap,
+            "toggle_color_swatches" => ToggleColorSwatches,
+            "adjust_color" => AdjustColor,
+            "toggle_aligned_view" => ToggleAlignedView,
+            "toggle_markdown_preview" => ToggleMarkdownPreview,
+            "toggle_follow_tail" => ToggleFollowTail,
+            "filter_log_lines" => FilterLogLines,
             "focus_file_explorer" => FocusFileExplorer,
+            "reveal_in_file_explorer" => RevealInFileExplorer,
+            "toggle_file_explorer_follow" => ToggleFileExplorerFollow,
             "focus_editor" => FocusEditor,
             "file_explorer_up" => FileExplorerUp,
             "file_explorer_down" => FileExplorerDown,
@@ -881,8 +1073,10 @@ impl Action {
             "file_explorer_toggle_gitignored" => FileExplorerToggleGitignored,
             "file_explorer_search_clear" => FileExplorerSearchClear,
             "file_explorer_search_backspace" => FileExplorerSearchBackspace,
+            "file_explorer_filter" => FileExplorerFilter,
 
             "lsp_completion" => LspCompletion,
+            "expand_snippet" => ExpandSnippet,
             "lsp_goto_definition" => LspGotoDefinition,
             "lsp_references" => LspReferences,
             "lsp_rename" => LspRename,
@@ -912,6 +1106,7 @@ impl Action {
             "toggle_indentation_style" => ToggleIndentationStyle,
             "toggle_tab_indicators" => ToggleTabIndicators,
             "toggle_whitespace_indicators" => ToggleWhitespaceIndicators,
+            "toggle_render_whitespace" => ToggleRenderWhitespace,
             "reset_buffer_settings" => ResetBufferSettings,
 
             "dump_config" => DumpConfig,
@@ -939,6 +1134,14 @@ impl Action {
             "terminal_escape" => TerminalEscape,
             "toggle_keyboard_capture" => ToggleKeyboardCapture,
             "terminal_paste" => TerminalPaste,
+            "switch_terminal" => SwitchTerminal,
+            "rename_terminal" => RenameTerminal,
+            "run_selection_in_terminal" => RunSelectionInTerminal,
+            "run_file_in_terminal" => RunFileInTerminal,
+            "open_link_under_cursor" => OpenLinkUnderCursor,
+            "run_task" => RunTask,
+            "rerun_last_task" => RerunLastTask,
+            "cancel_task" => CancelTask,
 
             "shell_command" => ShellCommand,
             "shell_command_replace" => ShellCommandReplace,
@@ -950,6 +1153,7 @@ impl Action {
             "calibrate_input" => CalibrateInput,
             "event_debug" => EventDebug,
             "open_keybinding_editor" => OpenKeybindingEditor,
+            "reset_command_ranking" => ResetCommandRanking,
 
             "noop" => None,
 
@@ -974,6 +1178,18 @@ impl Action {
             "show_macro" => ShowMacro,
         }
         custom {
+            "increment_number" => {
+                let by = args.get("by").and_then(|v| v.as_i64()).unwrap_or(1);
+                Self::IncrementNumber(by)
+            },
+            "decrement_number" => {
+                let by = args.get("by").and_then(|v| v.as_i64()).unwrap_or(1);
+                Self::DecrementNumber(by)
+            },
+            "increment_number_sequence" => {
+                let step = args.get("by").and_then(|v| v.as_i64()).unwrap_or(1);
+                Self::IncrementNumberSequence(step)
+            },
             "copy_with_theme" => {
                 // Empty theme = open theme picker prompt
                 let theme = args.get("theme").and_then(|v| v.as_str()).unwrap_or("");
@@ -1020,6 +1236,7 @@ impl Action {
                 | Action::SelectWordRight
                 | Action::SelectWordEnd
                 | Action::SelectLineStart
+                | Action::SelectSmartHome
                 | Action::SelectLineEnd
                 | Action::SelectDocumentStart
                 | Action::SelectDocumentEnd
@@ -1029,6 +1246,7 @@ impl Action {
                 | Action::SelectWord
                 | Action::SelectLine
                 | Action::ExpandSelection
+                | Action::ShrinkSelection
                 // Block selection
                 | Action::BlockSelectLeft
                 | Action::BlockSelectRight
@@ -1046,13 +1264,19 @@ impl Action {
                 | Action::DeleteToLineEnd
                 | Action::DeleteToLineStart
                 | Action::TransposeChars
+                | Action::TransposeWords
                 | Action::OpenLine
                 | Action::DuplicateLine
+                | Action::IncrementNumber(_)
+                | Action::DecrementNumber(_)
+                | Action::IncrementNumberSequence(_)
                 | Action::MoveLineUp
                 | Action::MoveLineDown
+                | Action::AlignCursors
                 // Clipboard editing (but not Copy)
                 | Action::Cut
                 | Action::Paste
+                | Action::CyclePaste
                 // Undo/Redo
                 | Action::Undo
                 | Action::Redo
@@ -1075,14 +1299,35 @@ impl Action {
                 | Action::DeleteToLineEnd
                 | Action::DeleteToLineStart
                 | Action::TransposeChars
+                | Action::TransposeWords
                 | Action::OpenLine
                 | Action::DuplicateLine
+                | Action::IncrementNumber(_)
+                | Action::DecrementNumber(_)
+                | Action::IncrementNumberSequence(_)
                 | Action::MoveLineUp
                 | Action::MoveLineDown
+                | Action::AlignCursors
                 | Action::Cut
                 | Action::Paste
         )
     }
+
+    /// Check if this action is an edit that `RepeatLast` can replay.
+    /// Movements, selections and UI actions are not.
+    pub fn is_repeatable(&self) -> bool {
+        self.is_editing()
+            || matches!(
+                self,
+                Action::DedentSelection
+                    | Action::ToggleComment
+                    | Action::ToggleBlockComment
+                    | Action::ToUpperCase
+                    | Action::ToLowerCase
+                    | Action::SortLines
+                    | Action::JoinLines
+            )
+    }
 }
 
 /// Result of chord resolution
@@ -1137,12 +1382,7 @@ impl KeybindingResolver {
     /// Load default bindings from a vector of keybinding definitions (into default_bindings/default_chord_bindings)
     fn load_default_bindings_from_vec(&mut self, bindings: &[crate::config::Keybinding]) {
         for binding in bindings {
-            // Determine context from "when" clause
-            let context = if let Some(ref when) = binding.when {
-                KeyContext::from_when_clause(when).unwrap_or(KeyContext::Normal)
-            } else {
-                KeyContext::Normal
-            };
+            let context = KeyContext::for_binding(binding);
 
             if let Some(action) = Action::from_str(&binding.action, &binding.args) {
                 // Check if this is a chord binding (has keys field)
@@ -1229,12 +1469,7 @@ impl KeybindingResolver {
     /// Load custom bindings from a vector of keybinding definitions (into bindings/chord_bindings)
     fn load_bindings_from_vec(&mut self, bindings: &[crate::config::Keybinding]) {
         for binding in bindings {
-            // Determine context from "when" clause
-            let context = if let Some(ref when) = binding.when {
-                KeyContext::from_when_clause(when).unwrap_or(KeyContext::Normal)
-            } else {
-                KeyContext::Normal
-            };
+            let context = KeyContext::for_binding(binding);
 
             if let Some(action) = Action::from_str(&binding.action, &binding.args) {
                 // Check if this is a chord binding (has keys field)
@@ -1308,6 +1543,7 @@ impl KeybindingResolver {
                 | Action::SplitVertical
                 | Action::CloseSplit
                 | Action::ToggleMaximizeSplit
+                | Action::EqualizeSplits
                 // Tab/buffer navigation
                 | Action::NextBuffer
                 | Action::PrevBuffer
@@ -1320,6 +1556,9 @@ impl KeybindingResolver {
                 | Action::OpenTerminal
                 | Action::CloseTerminal
                 | Action::TerminalPaste
+                | Action::SwitchTerminal
+                | Action::RerunLastTask
+                | Action::CancelTask
                 // File explorer
                 | Action::ToggleFileExplorer
                 // Menu bar
@@ -1348,15 +1587,16 @@ impl KeybindingResolver {
             context
         );
 
-        // Check all chord binding sources in priority order
+        // Check all chord binding sources in priority order: a custom binding
+        // for this specific context beats any global one
         let search_order = vec![
+            (&self.chord_bindings, &context, "custom context"),
             (&self.chord_bindings, &KeyContext::Global, "custom global"),
             (
                 &self.default_chord_bindings,
                 &KeyContext::Global,
                 "default global",
             ),
-            (&self.chord_bindings, &context, "custom context"),
             (&self.default_chord_bindings, &context, "default context"),
         ];
 
@@ -1400,7 +1640,20 @@ impl KeybindingResolver {
             context
         );
 
-        // Check Global bindings first (highest priority - work in all contexts)
+        // Custom bindings for this specific context come first, so users can
+        // override a global binding in one context only
+        if let Some(context_bindings) = self.bindings.get(&context) {
+            if let Some(action) = context_bindings.get(&(event.code, event.modifiers)) {
+                tracing::trace!(
+                    "  -> Found in custom {} bindings: {:?}",
+                    context.to_when_clause(),
+                    action
+                );
+                return action.clone();
+            }
+        }
+
+        // Check Global bindings next (work in all contexts)
         if let Some(global_bindings) = self.bindings.get(&KeyContext::Global) {
             if let Some(action) = global_bindings.get(&(event.code, event.modifiers)) {
                 tracing::trace!("  -> Found in custom global bindings: {:?}", action);
@@ -1415,18 +1668,6 @@ impl KeybindingResolver {
             }
         }
 
-        // Try context-specific custom bindings
-        if let Some(context_bindings) = self.bindings.get(&context) {
-            if let Some(action) = context_bindings.get(&(event.code, event.modifiers)) {
-                tracing::trace!(
-                    "  -> Found in custom {} bindings: {:?}",
-                    context.to_when_clause(),
-                    action
-                );
-                return action.clone();
-            }
-        }
-
         // Try context-specific default bindings
         if let Some(context_bindings) = self.default_bindings.get(&context) {
             if let Some(action) = context_bindings.get(&(event.code, event.modifiers)) {
@@ -1797,6 +2038,7 @@ impl KeybindingResolver {
             Action::SelectWord => t!("action.select_word"),
             Action::SelectLine => t!("action.select_line"),
             Action::ExpandSelection => t!("action.expand_selection"),
+            Action::ShrinkSelection => t!("action.shrink_selection"),
             Action::BlockSelectLeft => t!("action.block_select_left"),
             Action::BlockSelectRight => t!("action.block_select_right"),
             Action::BlockSelectUp => t!("action.block_select_up"),
@@ -1809,15 +2051,27 @@ impl KeybindingResolver {
             Action::DeleteToLineEnd => t!("action.delete_to_line_end"),
             Action::DeleteToLineStart => t!("action.delete_to_line_start"),
             Action::TransposeChars => t!("action.transpose_chars"),
+            Action::TransposeWords => t!("action.transpose_words"),
             Action::OpenLine => t!("action.open_line"),
+            Action::IncrementNumber(1) => t!("action.increment_number"),
+            Action::IncrementNumber(by) => t!("action.increment_number_by", by = by),
+            Action::DecrementNumber(1) => t!("action.decrement_number"),
+            Action::DecrementNumber(by) => t!("action.decrement_number_by", by = by),
+            Action::IncrementNumberSequence(_) => t!("action.increment_number_sequence"),
             Action::DuplicateLine => t!("action.duplicate_line"),
             Action::Recenter => t!("action.recenter"),
             Action::SetMark => t!("action.set_mark"),
             Action::Copy => t!("action.copy"),
+            Action::CopyVisible => t!("action.copy_visible"),
             Action::CopyWithTheme(theme) if theme.is_empty() => t!("action.copy_with_formatting"),
             Action::CopyWithTheme(theme) => t!("action.copy_with_theme", theme = theme),
+            Action::ExportHtml => t!("action.export_html"),
+            Action::ExportAnsi => t!("action.export_ansi"),
             Action::Cut => t!("action.cut"),
             Action::Paste => t!("action.paste"),
+            Action::PasteFromHistory => t!("action.paste_from_history"),
+            Action::InsertUnicodeChar => t!("action.insert_unicode_char"),
+            Action::CyclePaste => t!("action.cycle_paste"),
             Action::YankWordForward => t!("action.yank_word_forward"),
             Action::YankWordBackward => t!("action.yank_word_backward"),
             Action::YankToLineEnd => t!("action.yank_to_line_end"),
@@ -1826,13 +2080,20 @@ impl KeybindingResolver {
             Action::AddCursorBelow => t!("action.add_cursor_below"),
             Action::AddCursorNextMatch => t!("action.add_cursor_next_match"),
             Action::RemoveSecondaryCursors => t!("action.remove_secondary_cursors"),
+            Action::AlignCursors => t!("action.align_cursors"),
+            Action::AlignByPattern => t!("action.align_by_pattern"),
             Action::Save => t!("action.save"),
             Action::SaveAs => t!("action.save_as"),
             Action::Open => t!("action.open"),
             Action::SwitchProject => t!("action.switch_project"),
             Action::New => t!("action.new"),
+            Action::NewScratchWithLanguage => t!("action.new_scratch_with_language"),
             Action::Close => t!("action.close"),
             Action::CloseTab => t!("action.close_tab"),
+            Action::CloseOtherTabs => t!("action.close_other_tabs"),
+            Action::CloseTabsToRight => t!("action.close_tabs_to_right"),
+            Action::ReopenClosedTab => t!("action.reopen_closed_tab"),
+            Action::TogglePinTab => t!("action.toggle_pin_tab"),
             Action::Quit => t!("action.quit"),
             Action::ForceQuit => t!("action.force_quit"),
             Action::Detach => t!("action.detach"),
@@ -1844,20 +2105,36 @@ impl KeybindingResolver {
             Action::GotoLine => t!("action.goto_line"),
             Action::ScanLineIndex => t!("action.scan_line_index"),
             Action::GoToMatchingBracket => t!("action.goto_matching_bracket"),
+            Action::SelectToMatchingBracket => t!("action.select_to_matching_bracket"),
             Action::JumpToNextError => t!("action.jump_to_next_error"),
             Action::JumpToPreviousError => t!("action.jump_to_previous_error"),
             Action::SmartHome => t!("action.smart_home"),
+            Action::SelectSmartHome => t!("action.select_smart_home"),
             Action::DedentSelection => t!("action.dedent_selection"),
             Action::ToggleComment => t!("action.toggle_comment"),
+            Action::ToggleBlockComment => t!("action.toggle_block_comment"),
             Action::ToggleFold => t!("action.toggle_fold"),
+            Action::DrawBox => t!("action.draw_box"),
+            Action::ToggleLineDrawing => t!("action.toggle_line_drawing"),
+            Action::JoinLines => t!("action.join_lines"),
+            Action::HardWrap => t!("action.hard_wrap"),
+            Action::SurroundSelection => t!("action.surround_selection"),
+            Action::ChangeSurrounding => t!("action.change_surrounding"),
             Action::SetBookmark(c) => t!("action.set_bookmark", key = c),
             Action::JumpToBookmark(c) => t!("action.jump_to_bookmark", key = c),
             Action::ClearBookmark(c) => t!("action.clear_bookmark", key = c),
             Action::ListBookmarks => t!("action.list_bookmarks"),
+            Action::ToggleBookmark => t!("action.toggle_bookmark"),
+            Action::AddLabeledBookmark => t!("action.add_labeled_bookmark"),
+            Action::NextBookmark => t!("action.next_bookmark"),
+            Action::PreviousBookmark => t!("action.previous_bookmark"),
+            Action::BrowseBookmarks => t!("action.browse_bookmarks"),
+            Action::ClearBufferBookmarks => t!("action.clear_buffer_bookmarks"),
             Action::ToggleSearchCaseSensitive => t!("action.toggle_search_case_sensitive"),
             Action::ToggleSearchWholeWord => t!("action.toggle_search_whole_word"),
             Action::ToggleSearchRegex => t!("action.toggle_search_regex"),
             Action::ToggleSearchConfirmEach => t!("action.toggle_search_confirm_each"),
+            Action::ToggleSearchPreserveCase => t!("action.toggle_search_preserve_case"),
             Action::StartMacroRecording => t!("action.start_macro_recording"),
             Action::StopMacroRecording => t!("action.stop_macro_recording"),
             Action::PlayMacro(c) => t!("action.play_macro", key = c),
@@ -1871,17 +2148,28 @@ impl KeybindingResolver {
             Action::PromptJumpToBookmark => t!("action.prompt_jump_to_bookmark"),
             Action::Undo => t!("action.undo"),
             Action::Redo => t!("action.redo"),
+            Action::RepeatLast => t!("action.repeat_last"),
             Action::ScrollUp => t!("action.scroll_up"),
             Action::ScrollDown => t!("action.scroll_down"),
             Action::ShowHelp => t!("action.show_help"),
             Action::ShowKeyboardShortcuts => t!("action.show_keyboard_shortcuts"),
             Action::ShowWarnings => t!("action.show_warnings"),
             Action::ShowStatusLog => t!("action.show_status_log"),
+            Action::ShowPluginLog => t!("action.show_plugin_log"),
+            Action::ShowLogs => t!("action.show_logs"),
+            Action::TogglePerformanceHud => t!("action.toggle_performance_hud"),
+            Action::DumpPerformanceReport => t!("action.dump_performance_report"),
+            Action::ManagePlugins => t!("action.manage_plugins"),
+            Action::PluginPanelToggle => t!("action.plugin_panel_toggle"),
+            Action::PluginPanelReload => t!("action.plugin_panel_reload"),
+            Action::PluginPanelDetails => t!("action.plugin_panel_details"),
             Action::ShowLspStatus => t!("action.show_lsp_status"),
+            Action::ShowProcessStatus => t!("action.show_process_status"),
             Action::ClearWarnings => t!("action.clear_warnings"),
             Action::CommandPalette => t!("action.command_palette"),
             Action::QuickOpen => t!("action.quick_open"),
             Action::ToggleLineWrap => t!("action.toggle_line_wrap"),
+            Action::ToggleReadOnly => t!("action.toggle_read_only"),
             Action::ToggleComposeMode => t!("action.toggle_compose_mode"),
             Action::SetComposeWidth => t!("action.set_compose_width"),
             Action::NextBuffer => t!("action.next_buffer"),
@@ -1896,6 +2184,8 @@ impl KeybindingResolver {
             Action::IncreaseSplitSize => t!("action.increase_split_size"),
             Action::DecreaseSplitSize => t!("action.decrease_split_size"),
             Action::ToggleMaximizeSplit => t!("action.toggle_maximize_split"),
+            Action::EqualizeSplits => t!("action.equalize_splits"),
+            Action::ToggleScrollLink => t!("action.toggle_scroll_link"),
             Action::PromptConfirm => t!("action.prompt_confirm"),
             Action::PromptConfirmWithText(ref text) => {
                 format!("{} ({})", t!("action.prompt_confirm"), text).into()
@@ -1920,6 +2210,7 @@ impl KeybindingResolver {
             Action::PromptCopy => t!("action.prompt_copy"),
             Action::PromptCut => t!("action.prompt_cut"),
             Action::PromptPaste => t!("action.prompt_paste"),
+            Action::PromptBindCommand => t!("action.prompt_bind_command"),
             Action::PromptMoveLeftSelecting => t!("action.prompt_move_left_selecting"),
             Action::PromptMoveRightSelecting => t!("action.prompt_move_right_selecting"),
             Action::PromptMoveHomeSelecting => t!("action.prompt_move_home_selecting"),
@@ -1942,7 +2233,17 @@ impl KeybindingResolver {
             Action::ToggleTabBar => t!("action.toggle_tab_bar"),
             Action::ToggleVerticalScrollbar => t!("action.toggle_vertical_scrollbar"),
             Action::ToggleHorizontalScrollbar => t!("action.toggle_horizontal_scrollbar"),
+            Action::ToggleIndentGuides => t!("action.toggle_indent_guides"),
+            Action::ToggleMinimap => t!("action.toggle_minimap"),
+            Action::ToggleColorSwatches => t!("action.toggle_color_swatches"),
+            Action::AdjustColor => t!("action.adjust_color"),
+            Action::ToggleAlignedView => t!("action.toggle_aligned_view"),
+            Action::ToggleMarkdownPreview => t!("action.toggle_markdown_preview"),
+            Action::ToggleFollowTail => t!("action.toggle_follow_tail"),
+            Action::FilterLogLines => t!("action.filter_log_lines"),
             Action::FocusFileExplorer => t!("action.focus_file_explorer"),
+            Action::RevealInFileExplorer => t!("action.reveal_in_file_explorer"),
+            Action::ToggleFileExplorerFollow => t!("action.toggle_file_explorer_follow"),
             Action::FocusEditor => t!("action.focus_editor"),
             Action::FileExplorerUp => t!("action.file_explorer_up"),
             Action::FileExplorerDown => t!("action.file_explorer_down"),
@@ -1960,7 +2261,9 @@ impl KeybindingResolver {
             Action::FileExplorerToggleGitignored => t!("action.file_explorer_toggle_gitignored"),
             Action::FileExplorerSearchClear => t!("action.file_explorer_search_clear"),
             Action::FileExplorerSearchBackspace => t!("action.file_explorer_search_backspace"),
+            Action::FileExplorerFilter => t!("action.file_explorer_filter"),
             Action::LspCompletion => t!("action.lsp_completion"),
+            Action::ExpandSnippet => t!("action.expand_snippet"),
             Action::LspGotoDefinition => t!("action.lsp_goto_definition"),
             Action::LspReferences => t!("action.lsp_references"),
             Action::LspRename => t!("action.lsp_rename"),
@@ -1988,6 +2291,7 @@ impl KeybindingResolver {
             Action::ToggleIndentationStyle => t!("action.toggle_indentation_style"),
             Action::ToggleTabIndicators => t!("action.toggle_tab_indicators"),
             Action::ToggleWhitespaceIndicators => t!("action.toggle_whitespace_indicators"),
+            Action::ToggleRenderWhitespace => t!("action.toggle_render_whitespace"),
             Action::ResetBufferSettings => t!("action.reset_buffer_settings"),
             Action::DumpConfig => t!("action.dump_config"),
             Action::Search => t!("action.search"),
@@ -2016,12 +2320,21 @@ impl KeybindingResolver {
             Action::SelectLocale => t!("action.select_locale"),
             Action::SwitchToPreviousTab => t!("action.switch_to_previous_tab"),
             Action::SwitchToTabByName => t!("action.switch_to_tab_by_name"),
+            Action::BufferPicker => t!("action.buffer_picker"),
             Action::OpenTerminal => t!("action.open_terminal"),
             Action::CloseTerminal => t!("action.close_terminal"),
             Action::FocusTerminal => t!("action.focus_terminal"),
             Action::TerminalEscape => t!("action.terminal_escape"),
             Action::ToggleKeyboardCapture => t!("action.toggle_keyboard_capture"),
             Action::TerminalPaste => t!("action.terminal_paste"),
+            Action::SwitchTerminal => t!("action.switch_terminal"),
+            Action::RenameTerminal => t!("action.rename_terminal"),
+            Action::RunSelectionInTerminal => t!("action.run_selection_in_terminal"),
+            Action::RunFileInTerminal => t!("action.run_file_in_terminal"),
+            Action::OpenLinkUnderCursor => t!("action.open_link_under_cursor"),
+            Action::RunTask => t!("action.run_task"),
+            Action::RerunLastTask => t!("action.rerun_last_task"),
+            Action::CancelTask => t!("action.cancel_task"),
             Action::OpenSettings => t!("action.open_settings"),
             Action::CloseSettings => t!("action.close_settings"),
             Action::SettingsSave => t!("action.settings_save"),
@@ -2040,6 +2353,7 @@ impl KeybindingResolver {
             Action::CalibrateInput => t!("action.calibrate_input"),
             Action::EventDebug => t!("action.event_debug"),
             Action::OpenKeybindingEditor => "Keybinding Editor".into(),
+            Action::ResetCommandRanking => t!("action.reset_command_ranking"),
             Action::None => t!("action.none"),
         }
         .to_string()
@@ -2081,6 +2395,18 @@ impl KeybindingResolver {
         }
     }
 
+    /// English description of an action name string, so the keybinding
+    /// editor's search finds actions by their English name in any locale.
+    pub fn format_action_english(action_name: &str) -> String {
+        let key = format!("action.{}", action_name);
+        let english = t!(&key, locale = "en");
+        if *english == key {
+            Self::format_action_from_str(action_name)
+        } else {
+            english.to_string()
+        }
+    }
+
     /// Return a sorted list of all valid action name strings.
     /// Delegates to `Action::all_action_names()` which is generated by the
     /// `define_action_str_mapping!` macro (same source of truth as `Action::from_str`).
@@ -2175,12 +2501,7 @@ impl KeybindingResolver {
             if let Some(key_code) = Self::parse_key(&binding.key) {
                 let modifiers = Self::parse_modifiers(&binding.modifiers);
                 if let Some(action) = Action::from_str(&binding.action, &binding.args) {
-                    // Determine context from "when" clause
-                    let context = if let Some(ref when) = binding.when {
-                        KeyContext::from_when_clause(when).unwrap_or(KeyContext::Normal)
-                    } else {
-                        KeyContext::Normal
-                    };
+                    let context = KeyContext::for_binding(binding);
 
                     self.bindings
                         .entry(context)
@@ -2362,6 +2683,7 @@ mod tests {
             action: "quit".to_string(), // Override Esc in popup context to quit
             args: HashMap::new(),
             when: Some("popup".to_string()),
+            context: None,
         });
 
         let resolver = KeybindingResolver::new(&config);
@@ -2380,6 +2702,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_custom_context_binding_overrides_global() {
+        use crate::config::Keybinding;
+
+        // Alt+S opens the Selection menu globally in the default keymap
+        let mut config = Config::default();
+        config.keybindings.push(Keybinding {
+            key: "s".to_string(),
+            modifiers: vec!["alt".to_string()],
+            keys: vec![],
+            action: "save".to_string(),
+            args: HashMap::new(),
+            when: None,
+            context: Some("editor".to_string()),
+        });
+
+        let resolver = KeybindingResolver::new(&config);
+        let alt_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::ALT);
+
+        assert_eq!(resolver.resolve(&alt_s, KeyContext::Normal), Action::Save);
+        assert!(matches!(
+            resolver.resolve(&alt_s, KeyContext::Prompt),
+            Action::MenuOpen(_)
+        ));
+    }
+
+    #[test]
+    fn test_key_context_for_binding() {
+        use crate::config::Keybinding;
+
+        let mut binding = Keybinding {
+            key: "a".to_string(),
+            modifiers: vec![],
+            keys: vec![],
+            action: "noop".to_string(),
+            args: HashMap::new(),
+            when: None,
+            context: None,
+        };
+        assert_eq!(KeyContext::for_binding(&binding), KeyContext::Normal);
+
+        binding.when = Some("file_explorer".to_string());
+        assert_eq!(KeyContext::for_binding(&binding), KeyContext::FileExplorer);
+
+        // `context` takes precedence over `when`
+        binding.context = Some("terminal".to_string());
+        assert_eq!(KeyContext::for_binding(&binding), KeyContext::Terminal);
+
+        for ctx in ["editor", "prompt", "explorer", "terminal", "menu"] {
+            let parsed = KeyContext::from_context_name(ctx).unwrap();
+            assert_eq!(parsed.to_context_name(), ctx);
+        }
+    }
+
     #[test]
     fn test_character_input_in_contexts() {
         let config = Config::default();
@@ -2418,6 +2794,7 @@ mod tests {
             action: "command_palette".to_string(),
             args: HashMap::new(),
             when: None, // Default to normal context
+            context: None,
         });
 
         let resolver = KeybindingResolver::new(&config);
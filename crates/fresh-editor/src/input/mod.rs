@@ -9,6 +9,7 @@ pub mod commands;
 pub mod composite_router;
 pub mod fuzzy;
 pub mod handler;
+pub mod hover_dwell;
 pub mod input_history;
 pub mod key_translator;
 pub mod keybindings;
@@ -16,6 +17,8 @@ mod line_move;
 pub mod multi_cursor;
 pub mod position_history;
 pub mod quick_open;
+pub mod repeat;
+pub mod vim;
 
 #[cfg(test)]
 pub mod tests_language_features;
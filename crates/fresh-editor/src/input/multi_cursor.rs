@@ -262,3 +262,56 @@ pub fn add_cursor_below(state: &mut EditorState, cursors: &Cursors) -> AddCursor
         }
     }
 }
+
+/// Find where each line covered by the cursors first matches `regex`
+///
+/// A cursor with a selection covers every line the selection touches; a
+/// cursor without one covers its own line. When the regex has a capture
+/// group, the position is the start of group 1, otherwise the start of the
+/// match. Lines without a match are skipped. Returns byte positions in
+/// ascending order, at most one per line.
+pub fn pattern_positions(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    regex: &regex::Regex,
+) -> Vec<usize> {
+    let mut lines: Vec<usize> = Vec::new();
+    for (_, cursor) in cursors.iter() {
+        let (start, end) = match cursor.selection_range() {
+            Some(range) => (range.start, range.end),
+            None => (cursor.position, cursor.position),
+        };
+        let first_line = state.buffer.get_line_number(start);
+        let mut last_line = state.buffer.get_line_number(end);
+        // A selection ending at the start of a line doesn't cover that line
+        if last_line > first_line && state.buffer.line_start_offset(last_line) == Some(end) {
+            last_line -= 1;
+        }
+        lines.extend(first_line..=last_line);
+    }
+    lines.sort_unstable();
+    lines.dedup();
+
+    let mut positions = Vec::new();
+    for line in lines {
+        let (Some(line_start), Some(bytes)) = (
+            state.buffer.line_start_offset(line),
+            state.buffer.get_line(line),
+        ) else {
+            continue;
+        };
+        let Ok(text) = String::from_utf8(bytes) else {
+            continue;
+        };
+        let text = text.trim_end_matches(['\n', '\r']);
+        let Some(caps) = regex.captures(text) else {
+            continue;
+        };
+        let offset = match caps.get(1) {
+            Some(group) => group.start(),
+            None => caps.get(0).map_or(0, |m| m.start()),
+        };
+        positions.push(line_start + offset);
+    }
+    positions
+}
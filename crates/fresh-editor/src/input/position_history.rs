@@ -1,21 +1,25 @@
 /// Position history for go back/forward navigation like VS Code
 ///
-/// This module tracks the user's position history across buffers,
-/// allowing navigation back and forward through editing locations.
+/// This module holds the jump list each split keeps of the places the user
+/// has jumped away from, allowing navigation back and forward through them.
 /// Similar to VS Code's Alt+Left/Alt+Right navigation.
 ///
 /// ## Architecture
 ///
-/// Position history consumes MoveCursor events from the event log and coalesces
-/// consecutive movements into single "jump" entries. This means:
-/// - Many arrow key presses = one jump entry
-/// - Each buffer switch = commits pending movement and adds new entry
-/// - Idle period = commits pending movement
+/// An entry is pushed with the cursor position *before* a "far" movement:
+/// - Switching to another buffer (tabs, quick open, go to definition)
+/// - Go to line and search confirmation
+/// - Any cursor jump of more than `FAR_JUMP_LINES` lines (clicks, paging)
 ///
-/// This matches VS Code's behavior where you can navigate back through your
-/// editing trail, not through every single keystroke.
+/// Ordinary cursor movement is never recorded, so walking the list with
+/// back/forward keeps the forward branch until the next far jump truncates it.
+use std::path::PathBuf;
+
 use crate::model::event::BufferId;
 
+/// Cursor movements spanning more lines than this are recorded as jumps
+pub const FAR_JUMP_LINES: usize = 5;
+
 /// A single entry in the position history
 #[derive(Clone, Debug, PartialEq)]
 pub struct PositionEntry {
@@ -27,6 +31,9 @@ pub struct PositionEntry {
 
     /// Optional selection anchor
     pub anchor: Option<usize>,
+
+    /// File backing the buffer, used to reopen it if it was closed
+    pub path: Option<PathBuf>,
 }
 
 impl PositionEntry {
@@ -36,29 +43,23 @@ impl PositionEntry {
             buffer_id,
             position,
             anchor,
+            path: None,
         }
     }
-}
 
-/// Pending movement that may be coalesced with subsequent movements
-#[derive(Clone, Debug)]
-struct PendingMovement {
-    /// Starting position of this movement sequence
-    start_entry: PositionEntry,
+    /// Set the file backing the entry's buffer
+    pub fn with_path(mut self, path: Option<PathBuf>) -> Self {
+        self.path = path;
+        self
+    }
 }
 
-/// Distance threshold for considering a movement "large" (in bytes)
-/// Movements larger than this will not be coalesced
-const LARGE_JUMP_THRESHOLD: usize = 50;
-
 /// Position history manager
 ///
-/// This tracks navigation history across the editor, storing positions
-/// the user has visited. It maintains a stack with a current index,
+/// This tracks navigation history for a split, storing positions
+/// the user has jumped from. It maintains a stack with a current index,
 /// allowing back/forward navigation.
-///
-/// Movements are coalesced: consecutive MoveCursor events within a short
-/// time period are treated as a single "jump" for navigation purposes.
+#[derive(Clone, Debug)]
 pub struct PositionHistory {
     /// Stack of position entries
     entries: Vec<PositionEntry>,
@@ -69,10 +70,6 @@ pub struct PositionHistory {
 
     /// Maximum number of entries to keep
     max_entries: usize,
-
-    /// Pending movement that hasn't been committed yet
-    /// Gets committed when: buffer switches, timeout expires, or significant event
-    pending_movement: Option<PendingMovement>,
 }
 
 impl PositionHistory {
@@ -87,58 +84,12 @@ impl PositionHistory {
             entries: Vec::new(),
             current_index: None,
             max_entries,
-            pending_movement: None,
-        }
-    }
-
-    /// Record a cursor movement event
-    ///
-    /// This is called for EVERY MoveCursor event. Consecutive small movements are coalesced
-    /// into a single history entry. The movement is committed to history when:
-    /// - Buffer changes
-    /// - Large jump detected (> 50 bytes distance from pending start position)
-    /// - User triggers back/forward navigation
-    pub fn record_movement(&mut self, buffer_id: BufferId, position: usize, anchor: Option<usize>) {
-        let entry = PositionEntry::new(buffer_id, position, anchor);
-
-        if let Some(pending) = &mut self.pending_movement {
-            // Check if this is a continuation of the current movement
-            if pending.start_entry.buffer_id == buffer_id {
-                // Calculate distance from the pending movement's start position
-                let distance = position.abs_diff(pending.start_entry.position);
-
-                // Check if this is a small movement that should be coalesced
-                if distance <= LARGE_JUMP_THRESHOLD {
-                    // Small movement - keep coalescing, don't commit yet
-                    return;
-                }
-            }
-
-            // Different buffer or large jump - commit the pending movement
-            self.commit_pending_movement();
-        }
-
-        // Start a new pending movement
-        self.pending_movement = Some(PendingMovement { start_entry: entry });
-    }
-
-    /// Commit any pending movement to history
-    ///
-    /// This is called when:
-    /// - Switching buffers
-    /// - Before navigating back/forward
-    pub fn commit_pending_movement(&mut self) {
-        if let Some(pending) = self.pending_movement.take() {
-            // Always call push(), which handles both:
-            // 1. Truncating forward history (if we're not at the end)
-            // 2. Checking for duplicates before adding
-            self.push(pending.start_entry);
         }
     }
 
     /// Push a new position to the history
     ///
-    /// This is called when the user makes a significant navigation:
+    /// This is called with the position the user is leaving on a far jump:
     /// - Switching buffers
     /// - Large cursor movements (e.g., search, go-to-definition)
     /// - Opening a file
@@ -173,12 +124,8 @@ impl PositionHistory {
 
     /// Navigate back in history
     ///
-    /// Commits any pending movement first, then returns the previous position.
-    /// Returns None if we're at the beginning of history.
+    /// Returns the previous position, or None if we're at the beginning of history.
     pub fn back(&mut self) -> Option<&PositionEntry> {
-        // Commit any pending movement before navigating
-        self.commit_pending_movement();
-
         if self.entries.is_empty() {
             return None;
         }
@@ -232,6 +179,15 @@ impl PositionHistory {
         self.current_index.and_then(|idx| self.entries.get(idx))
     }
 
+    /// Point entries for a closed buffer at the buffer that reopened its file
+    pub fn rebind_buffer(&mut self, old: BufferId, new: BufferId) {
+        for entry in &mut self.entries {
+            if entry.buffer_id == old {
+                entry.buffer_id = new;
+            }
+        }
+    }
+
     /// Clear all history
     pub fn clear(&mut self) {
         self.entries.clear();
@@ -431,6 +387,36 @@ mod tests {
         assert_eq!(history.current(), Some(&make_entry(1, 20)));
     }
 
+    #[test]
+    fn test_back_and_forward_keep_forward_history() {
+        let mut history = PositionHistory::new();
+        history.push(make_entry(1, 10));
+        history.push(make_entry(1, 20));
+        history.push(make_entry(1, 30));
+
+        history.back();
+        history.back();
+        history.forward();
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.current(), Some(&make_entry(1, 20)));
+        assert!(history.can_go_forward());
+    }
+
+    #[test]
+    fn test_rebind_buffer() {
+        let mut history = PositionHistory::new();
+        history.push(make_entry(1, 10));
+        history.push(make_entry(2, 20));
+        history.push(make_entry(1, 30));
+
+        history.rebind_buffer(BufferId(1), BufferId(3));
+
+        assert_eq!(history.current(), Some(&make_entry(3, 30)));
+        assert_eq!(history.back(), Some(&make_entry(2, 20)));
+        assert_eq!(history.back(), Some(&make_entry(3, 10)));
+    }
+
     #[test]
     fn test_clear() {
         let mut history = PositionHistory::new();
@@ -5,7 +5,7 @@
 //! - Empty prefix: file finder
 //! - `>`: command palette
 //! - `#`: symbol finder (future)
-//! - `@`: go to symbol in file (future)
+//! - `@`: go to symbol in file
 //! - `:`: go to line
 //!
 //! Providers are registered with a prefix and handle suggestion generation
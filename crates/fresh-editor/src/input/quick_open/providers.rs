@@ -322,10 +322,13 @@ impl QuickOpenProvider for GotoLineProvider {
 /// Provider for finding files in the project
 ///
 /// This is the default provider (empty prefix) that provides file suggestions
-/// using git ls-files, fd, find, or directory traversal.
+/// from the workspace file index once it is built, and otherwise using
+/// git ls-files, fd, find, or directory traversal.
 pub struct FileProvider {
     /// Cached file list (populated lazily)
     file_cache: std::sync::Arc<std::sync::RwLock<Option<Vec<FileEntry>>>>,
+    /// Workspace file index, when enabled
+    index: std::sync::RwLock<Option<std::sync::Arc<crate::services::file_index::FileIndex>>>,
     /// Frecency data for ranking
     frecency: std::sync::Arc<std::sync::RwLock<std::collections::HashMap<String, FrecencyData>>>,
 }
@@ -342,10 +345,34 @@ struct FrecencyData {
     last_access: std::time::Instant,
 }
 
+impl FrecencyData {
+    fn score(&self) -> f64 {
+        let hours_since_access = self.last_access.elapsed().as_secs_f64() / 3600.0;
+
+        // Mozilla-style frecency weighting
+        let recency_weight = if hours_since_access < 4.0 {
+            100.0
+        } else if hours_since_access < 24.0 {
+            70.0
+        } else if hours_since_access < 24.0 * 7.0 {
+            50.0
+        } else if hours_since_access < 24.0 * 30.0 {
+            30.0
+        } else if hours_since_access < 24.0 * 90.0 {
+            10.0
+        } else {
+            1.0
+        };
+
+        self.access_count as f64 * recency_weight
+    }
+}
+
 impl FileProvider {
     pub fn new() -> Self {
         Self {
             file_cache: std::sync::Arc::new(std::sync::RwLock::new(None)),
+            index: std::sync::RwLock::new(None),
             frecency: std::sync::Arc::new(std::sync::RwLock::new(std::collections::HashMap::new())),
         }
     }
@@ -357,6 +384,19 @@ impl FileProvider {
         }
     }
 
+    /// Serve suggestions from a workspace file index instead of listing
+    /// files with external commands
+    pub fn set_index(&self, index: Option<std::sync::Arc<crate::services::file_index::FileIndex>>) {
+        if let Ok(mut current) = self.index.write() {
+            *current = index;
+        }
+    }
+
+    /// The workspace file index, if one is set
+    pub fn index(&self) -> Option<std::sync::Arc<crate::services::file_index::FileIndex>> {
+        self.index.read().ok().and_then(|index| index.clone())
+    }
+
     /// Record file access for frecency ranking
     pub fn record_access(&self, path: &str) {
         if let Ok(mut frecency) = self.frecency.write() {
@@ -372,29 +412,49 @@ impl FileProvider {
     fn get_frecency_score(&self, path: &str) -> f64 {
         if let Ok(frecency) = self.frecency.read() {
             if let Some(data) = frecency.get(path) {
-                let hours_since_access = data.last_access.elapsed().as_secs_f64() / 3600.0;
-
-                // Mozilla-style frecency weighting
-                let recency_weight = if hours_since_access < 4.0 {
-                    100.0
-                } else if hours_since_access < 24.0 {
-                    70.0
-                } else if hours_since_access < 24.0 * 7.0 {
-                    50.0
-                } else if hours_since_access < 24.0 * 30.0 {
-                    30.0
-                } else if hours_since_access < 24.0 * 90.0 {
-                    10.0
-                } else {
-                    1.0
-                };
-
-                return data.access_count as f64 * recency_weight;
+                return data.score();
             }
         }
         0.0
     }
 
+    /// Files from the workspace index, scored like [`Self::load_files`]
+    /// entries. Only matching files are copied out of the index, so a
+    /// keystroke costs one fuzzy match per indexed path.
+    fn indexed_files(
+        &self,
+        index: &crate::services::file_index::FileIndex,
+        query: &str,
+    ) -> Vec<(FileEntry, i32)> {
+        let frecency = self.frecency.read().ok();
+        let frecency_score = |path: &str| {
+            frecency
+                .as_ref()
+                .and_then(|frecency| frecency.get(path))
+                .map_or(0.0, FrecencyData::score)
+        };
+        let mut files = Vec::new();
+        index.for_each_path(|path| {
+            let score = if query.is_empty() {
+                0
+            } else {
+                let match_result = fuzzy_match(query, path);
+                if !match_result.matched {
+                    return;
+                }
+                match_result.score
+            };
+            files.push((
+                FileEntry {
+                    relative_path: path.to_string(),
+                    frecency_score: frecency_score(path),
+                },
+                score,
+            ));
+        });
+        files
+    }
+
     /// Load files from the project directory
     fn load_files(&self, cwd: &str) -> Vec<FileEntry> {
         // Check cache first
@@ -534,9 +594,22 @@ impl QuickOpenProvider for FileProvider {
     }
 
     fn suggestions(&self, query: &str, context: &QuickOpenContext) -> Vec<Suggestion> {
-        let files = self.load_files(&context.cwd);
+        let files = match self.index().filter(|index| index.is_ready()) {
+            Some(index) => self.indexed_files(&index, query),
+            None => self
+                .load_files(&context.cwd)
+                .into_iter()
+                .filter_map(|file| {
+                    if query.is_empty() {
+                        return Some((file, 0));
+                    }
+                    let match_result = fuzzy_match(query, &file.relative_path);
+                    match_result.matched.then_some((file, match_result.score))
+                })
+                .collect(),
+        };
 
-        if files.is_empty() {
+        if files.is_empty() && query.is_empty() {
             return vec![Suggestion {
                 text: t!("quick_open.no_files").to_string(),
                 description: None,
@@ -553,28 +626,19 @@ impl QuickOpenProvider for FileProvider {
             // Sort by frecency when no query
             let mut files = files;
             files.sort_by(|a, b| {
-                b.frecency_score
-                    .partial_cmp(&a.frecency_score)
+                b.0.frecency_score
+                    .partial_cmp(&a.0.frecency_score)
                     .unwrap_or(std::cmp::Ordering::Equal)
             });
+            files.truncate(max_results);
             files
-                .into_iter()
-                .take(max_results)
-                .map(|f| (f, 0))
-                .collect()
         } else {
-            // Filter and score by fuzzy match
+            // Boost fuzzy match scores by frecency (normalized)
             files
                 .into_iter()
-                .filter_map(|file| {
-                    let match_result = fuzzy_match(query, &file.relative_path);
-                    if match_result.matched {
-                        // Boost score by frecency (normalized)
-                        let frecency_boost = (file.frecency_score / 100.0).min(20.0) as i32;
-                        Some((file, match_result.score + frecency_boost))
-                    } else {
-                        None
-                    }
+                .map(|(file, score)| {
+                    let frecency_boost = (file.frecency_score / 100.0).min(20.0) as i32;
+                    (file, score + frecency_boost)
                 })
                 .collect()
         };
@@ -0,0 +1,199 @@
+//! Dot-repeat: remembering the last edit so `Action::RepeatLast` can replay it
+//!
+//! Every action that reaches the editor is offered to [`RepeatState::record`].
+//! Repeatable actions (see [`Action::is_repeatable`]) replace the remembered
+//! edit, runs of typed text are coalesced into a single insert, and anything
+//! else leaves the remembered edit alone. Replaying goes back through the
+//! regular action handling, so it applies to every cursor like the original.
+
+use crate::input::keybindings::Action;
+use serde::{Deserialize, Serialize};
+
+/// An edit that can be replayed at the current cursors
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RepeatableEdit {
+    /// Consecutively typed text, including newlines and tabs
+    Insert(String),
+    /// Any other repeatable action (delete word, duplicate line, ...)
+    Action(Action),
+}
+
+impl RepeatableEdit {
+    /// The actions that replay this edit
+    pub fn to_actions(&self) -> Vec<Action> {
+        match self {
+            Self::Insert(text) => text
+                .chars()
+                .map(|c| match c {
+                    '\n' => Action::InsertNewline,
+                    '\t' => Action::InsertTab,
+                    c => Action::InsertChar(c),
+                })
+                .collect(),
+            Self::Action(action) => vec![action.clone()],
+        }
+    }
+}
+
+/// Tracks the last repeatable edit
+#[derive(Debug, Default)]
+pub struct RepeatState {
+    last: Option<RepeatableEdit>,
+    /// Whether the previous recorded action was typed text, so the next
+    /// character extends the same insert
+    inserting: bool,
+    /// Set while replaying, so the replayed actions aren't recorded again
+    replaying: bool,
+}
+
+impl RepeatState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The edit `Action::RepeatLast` would replay
+    pub fn last(&self) -> Option<&RepeatableEdit> {
+        self.last.as_ref()
+    }
+
+    /// Record an action handled by the editor
+    pub fn record(&mut self, action: &Action) {
+        if self.replaying {
+            return;
+        }
+
+        let typed = match action {
+            Action::InsertChar(c) => Some(*c),
+            Action::InsertNewline => Some('\n'),
+            Action::InsertTab => Some('\t'),
+            _ => None,
+        };
+
+        if let Some(c) = typed {
+            match &mut self.last {
+                Some(RepeatableEdit::Insert(text)) if self.inserting => text.push(c),
+                last => *last = Some(RepeatableEdit::Insert(c.to_string())),
+            }
+            self.inserting = true;
+            return;
+        }
+
+        if action.is_repeatable() {
+            self.last = Some(RepeatableEdit::Action(action.clone()));
+        }
+        self.inserting = false;
+    }
+
+    /// Start replaying; returns the actions to run, if there's anything to repeat
+    pub fn begin_replay(&mut self) -> Option<Vec<Action>> {
+        let actions = self.last.as_ref()?.to_actions();
+        self.replaying = true;
+        self.inserting = false;
+        Some(actions)
+    }
+
+    /// Finish a replay started with [`Self::begin_replay`]
+    pub fn end_replay(&mut self) {
+        self.replaying = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record_all(state: &mut RepeatState, actions: &[Action]) {
+        for action in actions {
+            state.record(action);
+        }
+    }
+
+    #[test]
+    fn test_typed_text_is_coalesced() {
+        let mut state = RepeatState::new();
+        record_all(
+            &mut state,
+            &[
+                Action::InsertChar('a'),
+                Action::InsertChar('b'),
+                Action::InsertNewline,
+                Action::InsertChar('c'),
+            ],
+        );
+        assert_eq!(
+            state.last(),
+            Some(&RepeatableEdit::Insert("ab\nc".to_string()))
+        );
+        assert_eq!(
+            state.last().unwrap().to_actions(),
+            vec![
+                Action::InsertChar('a'),
+                Action::InsertChar('b'),
+                Action::InsertNewline,
+                Action::InsertChar('c'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_movement_ends_insert_but_keeps_it() {
+        let mut state = RepeatState::new();
+        record_all(
+            &mut state,
+            &[
+                Action::InsertChar('a'),
+                Action::MoveLeft,
+                Action::ToggleLineNumbers,
+            ],
+        );
+        assert_eq!(state.last(), Some(&RepeatableEdit::Insert("a".to_string())));
+
+        // Typing after a movement starts a new insert
+        state.record(&Action::InsertChar('b'));
+        assert_eq!(state.last(), Some(&RepeatableEdit::Insert("b".to_string())));
+    }
+
+    #[test]
+    fn test_repeatable_action_replaces_insert() {
+        let mut state = RepeatState::new();
+        record_all(
+            &mut state,
+            &[Action::InsertChar('a'), Action::DeleteWordBackward],
+        );
+        assert_eq!(
+            state.last(),
+            Some(&RepeatableEdit::Action(Action::DeleteWordBackward))
+        );
+    }
+
+    #[test]
+    fn test_replay_is_not_recorded() {
+        let mut state = RepeatState::new();
+        state.record(&Action::InsertChar('a'));
+
+        let actions = state.begin_replay().unwrap();
+        for action in &actions {
+            state.record(action);
+        }
+        state.end_replay();
+        assert_eq!(state.last(), Some(&RepeatableEdit::Insert("a".to_string())));
+
+        // Typing after a replay doesn't extend the replayed insert
+        state.record(&Action::InsertChar('b'));
+        assert_eq!(state.last(), Some(&RepeatableEdit::Insert("b".to_string())));
+    }
+
+    #[test]
+    fn test_nothing_to_replay() {
+        let mut state = RepeatState::new();
+        state.record(&Action::MoveDown);
+        assert!(state.begin_replay().is_none());
+    }
+
+    #[test]
+    fn test_edit_serializes() {
+        let edit = RepeatableEdit::Insert("fn main".to_string());
+        let json = serde_json::to_string(&edit).unwrap();
+        assert_eq!(serde_json::from_str::<RepeatableEdit>(&json).unwrap(), edit);
+    }
+}
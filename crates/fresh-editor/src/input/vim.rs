@@ -0,0 +1,640 @@
+//! Vim-style modal input
+//!
+//! When `editor.keymap` is `"vim"`, keys typed in the text area go through
+//! [`VimState`] before the regular keymap. The state machine tracks the
+//! current mode plus any pending count, operator or `g` prefix, and turns
+//! complete key sequences into existing [`Action`]s. It never touches the
+//! buffer itself, so everything it does goes through the same paths as the
+//! non-modal bindings (undo, macros, multi-cursor).
+//!
+//! Keys it doesn't claim are reported as [`VimOutcome::Passthrough`] and
+//! resolved by the active keybinding map: everything in insert mode, keys
+//! with Ctrl/Alt, and non-character keys like arrows or function keys.
+//!
+//! Ex commands (`:`) and named registers aren't implemented. They would fit
+//! in as another pending state next to `pending_g` and as a field replacing
+//! `linewise_register`.
+
+use crate::input::keybindings::Action;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// The current Vim mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VimMode {
+    #[default]
+    Normal,
+    Insert,
+    Visual,
+    VisualLine,
+}
+
+impl VimMode {
+    /// Mode name shown in the status bar
+    pub fn label(&self) -> &'static str {
+        match self {
+            VimMode::Normal => "NORMAL",
+            VimMode::Insert => "INSERT",
+            VimMode::Visual => "VISUAL",
+            VimMode::VisualLine => "VISUAL LINE",
+        }
+    }
+}
+
+/// How to leave the selection once the outcome's actions have run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionFixup {
+    /// Leave cursors and selections as the actions left them
+    None,
+    /// Drop the selection, keeping the cursor where it is
+    Clear,
+    /// Drop the selection and move the cursor to where it started
+    CollapseToStart,
+}
+
+/// Result of feeding one key to [`VimState::handle_key`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VimOutcome {
+    /// The key was consumed: run the actions in order, then apply the fixup
+    Run(Vec<Action>, SelectionFixup),
+    /// Not a Vim key; resolve it through the regular keymap
+    Passthrough,
+}
+
+impl VimOutcome {
+    fn run(actions: Vec<Action>) -> Self {
+        VimOutcome::Run(actions, SelectionFixup::None)
+    }
+
+    fn consumed() -> Self {
+        VimOutcome::Run(Vec::new(), SelectionFixup::None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Delete,
+    Change,
+    Yank,
+}
+
+impl Operator {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'd' => Some(Operator::Delete),
+            'c' => Some(Operator::Change),
+            'y' => Some(Operator::Yank),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Motion {
+    Left,
+    Right,
+    Up,
+    Down,
+    WordForward,
+    WordBackward,
+    WordEnd,
+    LineStart,
+    LineEnd,
+    DocumentStart,
+    /// `G`: the last line, or line N when a count was given
+    DocumentEnd,
+}
+
+impl Motion {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'h' => Some(Motion::Left),
+            'l' | ' ' => Some(Motion::Right),
+            'k' => Some(Motion::Up),
+            'j' => Some(Motion::Down),
+            'w' => Some(Motion::WordForward),
+            'b' => Some(Motion::WordBackward),
+            'e' => Some(Motion::WordEnd),
+            '0' => Some(Motion::LineStart),
+            '$' => Some(Motion::LineEnd),
+            'G' => Some(Motion::DocumentEnd),
+            _ => None,
+        }
+    }
+
+    /// The action that performs this motion, moving or extending the selection
+    fn action(self, select: bool) -> Action {
+        match (self, select) {
+            (Motion::Left, false) => Action::MoveLeft,
+            (Motion::Left, true) => Action::SelectLeft,
+            (Motion::Right, false) => Action::MoveRight,
+            (Motion::Right, true) => Action::SelectRight,
+            (Motion::Up, false) => Action::MoveUp,
+            (Motion::Up, true) => Action::SelectUp,
+            (Motion::Down, false) => Action::MoveDown,
+            (Motion::Down, true) => Action::SelectDown,
+            (Motion::WordForward, false) => Action::MoveWordRight,
+            (Motion::WordForward, true) => Action::SelectWordRight,
+            (Motion::WordBackward, false) => Action::MoveWordLeft,
+            (Motion::WordBackward, true) => Action::SelectWordLeft,
+            (Motion::WordEnd, false) => Action::MoveWordEnd,
+            (Motion::WordEnd, true) => Action::SelectWordEnd,
+            (Motion::LineStart, false) => Action::MoveLineStart,
+            (Motion::LineStart, true) => Action::SelectLineStart,
+            (Motion::LineEnd, false) => Action::MoveLineEnd,
+            (Motion::LineEnd, true) => Action::SelectLineEnd,
+            (Motion::DocumentStart, false) => Action::MoveDocumentStart,
+            (Motion::DocumentStart, true) => Action::SelectDocumentStart,
+            (Motion::DocumentEnd, false) => Action::MoveDocumentEnd,
+            (Motion::DocumentEnd, true) => Action::SelectDocumentEnd,
+        }
+    }
+
+    /// Expand the motion with its count into a list of actions
+    fn actions(self, count: Option<usize>, select: bool) -> Vec<Action> {
+        match (self, count) {
+            // `NG` / `Ngg` jump to line N instead of repeating
+            (Motion::DocumentStart | Motion::DocumentEnd, Some(line)) => {
+                let mut actions = vec![Motion::DocumentStart.action(select)];
+                actions.extend(std::iter::repeat_n(
+                    Motion::Down.action(select),
+                    line.saturating_sub(1),
+                ));
+                actions
+            }
+            (Motion::DocumentStart | Motion::DocumentEnd | Motion::LineStart, None) => {
+                vec![self.action(select)]
+            }
+            _ => vec![self.action(select); count.unwrap_or(1)],
+        }
+    }
+}
+
+/// Upper bound on counts, so a stray `99999999j` can't queue millions of actions
+const MAX_COUNT: usize = 10_000;
+
+/// Modal input state machine
+#[derive(Debug, Clone, Default)]
+pub struct VimState {
+    mode: VimMode,
+    /// Count typed so far for the next command (`3` in `3dw`)
+    count: Option<usize>,
+    /// Operator waiting for a motion, with the count typed before it
+    operator: Option<(Operator, Option<usize>)>,
+    /// `g` was typed and the next key completes a `g` command
+    pending_g: bool,
+    /// Whether the clipboard holds whole lines from `yy`/`dd`/`V`, which
+    /// makes `p`/`P` paste below/above the current line
+    linewise_register: bool,
+}
+
+impl VimState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn mode(&self) -> VimMode {
+        self.mode
+    }
+
+    /// Whether a partially typed command is waiting for more keys
+    pub fn has_pending(&self) -> bool {
+        self.count.is_some() || self.operator.is_some() || self.pending_g
+    }
+
+    /// Drop any partially typed command and return to normal mode
+    pub fn reset(&mut self) {
+        self.mode = VimMode::Normal;
+        self.clear_pending();
+    }
+
+    fn clear_pending(&mut self) {
+        self.count = None;
+        self.operator = None;
+        self.pending_g = false;
+    }
+
+    /// Feed one key press to the state machine
+    pub fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> VimOutcome {
+        match self.mode {
+            VimMode::Insert => self.handle_insert(code),
+            VimMode::Normal => self.handle_normal(code, modifiers),
+            VimMode::Visual | VimMode::VisualLine => self.handle_visual(code, modifiers),
+        }
+    }
+
+    fn handle_insert(&mut self, code: KeyCode) -> VimOutcome {
+        if code == KeyCode::Esc {
+            self.mode = VimMode::Normal;
+            return VimOutcome::consumed();
+        }
+        VimOutcome::Passthrough
+    }
+
+    /// Accumulate a count digit. `0` only counts when a count is already
+    /// being typed; on its own it is the line-start motion.
+    fn push_count(&mut self, c: char) -> bool {
+        let Some(digit) = c.to_digit(10) else {
+            return false;
+        };
+        if digit == 0 && self.count.is_none() {
+            return false;
+        }
+        let count = self.count.unwrap_or(0) * 10 + digit as usize;
+        self.count = Some(count.min(MAX_COUNT));
+        true
+    }
+
+    /// Resolve a motion key, including the two-key `gg`. Returns `Err` with
+    /// the outcome to report when the key didn't complete a motion.
+    fn take_motion(&mut self, c: char) -> Result<Option<Motion>, VimOutcome> {
+        if self.pending_g {
+            self.pending_g = false;
+            return match c {
+                'g' => Ok(Some(Motion::DocumentStart)),
+                'e' => Ok(Some(Motion::WordEnd)),
+                _ => {
+                    self.clear_pending();
+                    Err(VimOutcome::consumed())
+                }
+            };
+        }
+        if c == 'g' {
+            self.pending_g = true;
+            return Err(VimOutcome::consumed());
+        }
+        Ok(Motion::from_char(c))
+    }
+
+    fn handle_normal(&mut self, code: KeyCode, modifiers: KeyModifiers) -> VimOutcome {
+        if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+            self.clear_pending();
+            if code == KeyCode::Char('r') && modifiers == KeyModifiers::CONTROL {
+                return VimOutcome::run(vec![Action::Redo]);
+            }
+            return VimOutcome::Passthrough;
+        }
+
+        let c = match code {
+            KeyCode::Char(c) => c,
+            KeyCode::Esc if self.has_pending() => {
+                self.clear_pending();
+                return VimOutcome::consumed();
+            }
+            _ => {
+                self.clear_pending();
+                return VimOutcome::Passthrough;
+            }
+        };
+
+        if !self.pending_g && self.push_count(c) {
+            return VimOutcome::consumed();
+        }
+
+        let motion = match self.take_motion(c) {
+            Ok(motion) => motion,
+            Err(outcome) => return outcome,
+        };
+
+        if let Some((operator, op_count)) = self.operator {
+            return self.apply_operator(operator, op_count, motion, c);
+        }
+
+        if let Some(motion) = motion {
+            let count = self.count.take();
+            return VimOutcome::run(motion.actions(count, false));
+        }
+
+        if let Some(operator) = Operator::from_char(c) {
+            self.operator = Some((operator, self.count.take()));
+            return VimOutcome::consumed();
+        }
+
+        let count = self.count.take().unwrap_or(1);
+        let outcome = match c {
+            'i' => self.enter_insert(vec![]),
+            'a' => self.enter_insert(vec![Action::MoveRight]),
+            'I' => self.enter_insert(vec![Action::MoveLineStart]),
+            'A' => self.enter_insert(vec![Action::MoveLineEnd]),
+            'o' => self.enter_insert(vec![Action::MoveLineEnd, Action::InsertNewline]),
+            'O' => self.enter_insert(vec![
+                Action::MoveLineStart,
+                Action::InsertNewline,
+                Action::MoveUp,
+            ]),
+            'x' => VimOutcome::run(vec![Action::DeleteForward; count]),
+            'X' => VimOutcome::run(vec![Action::DeleteBackward; count]),
+            'D' => VimOutcome::run(vec![Action::SelectLineEnd, Action::Cut]),
+            'C' => {
+                self.linewise_register = false;
+                self.enter_insert(vec![Action::SelectLineEnd, Action::Cut])
+            }
+            'p' => VimOutcome::run(self.paste_actions(true)),
+            'P' => VimOutcome::run(self.paste_actions(false)),
+            'u' => VimOutcome::run(vec![Action::Undo; count]),
+            'v' => {
+                self.mode = VimMode::Visual;
+                VimOutcome::run(vec![Action::SelectRight])
+            }
+            'V' => {
+                self.mode = VimMode::VisualLine;
+                VimOutcome::run(vec![Action::SelectLine])
+            }
+            // Unbound printable keys do nothing in normal mode
+            _ => VimOutcome::consumed(),
+        };
+        self.clear_pending();
+        outcome
+    }
+
+    fn enter_insert(&mut self, actions: Vec<Action>) -> VimOutcome {
+        self.mode = VimMode::Insert;
+        VimOutcome::run(actions)
+    }
+
+    fn paste_actions(&self, after: bool) -> Vec<Action> {
+        match (self.linewise_register, after) {
+            (true, true) => vec![
+                Action::MoveDown,
+                Action::MoveLineStart,
+                Action::Paste,
+                Action::MoveUp,
+                Action::MoveLineStart,
+            ],
+            (true, false) => vec![
+                Action::MoveLineStart,
+                Action::Paste,
+                Action::MoveUp,
+                Action::MoveLineStart,
+            ],
+            (false, true) => vec![Action::MoveRight, Action::Paste],
+            (false, false) => vec![Action::Paste],
+        }
+    }
+
+    /// Complete `operator` with the key that followed it: a motion, or the
+    /// operator's own key for the linewise form (`dd`, `cc`, `yy`)
+    fn apply_operator(
+        &mut self,
+        operator: Operator,
+        op_count: Option<usize>,
+        motion: Option<Motion>,
+        c: char,
+    ) -> VimOutcome {
+        let motion_count = self.count.take();
+        self.clear_pending();
+
+        // `2d3w` deletes six words
+        let count = match (op_count, motion_count) {
+            (None, None) => None,
+            (a, b) => Some((a.unwrap_or(1) * b.unwrap_or(1)).min(MAX_COUNT)),
+        };
+
+        if Operator::from_char(c) == Some(operator) {
+            let lines = count.unwrap_or(1);
+            let mut select = vec![Action::SelectLine];
+            select.extend(std::iter::repeat_n(Action::SelectDown, lines - 1));
+            return match operator {
+                Operator::Delete => {
+                    self.linewise_register = true;
+                    select.push(Action::Cut);
+                    VimOutcome::run(select)
+                }
+                Operator::Change => {
+                    // Keep the (now empty) line to type into
+                    self.linewise_register = false;
+                    let mut actions = vec![Action::MoveLineStart];
+                    actions.extend(std::iter::repeat_n(Action::SelectDown, lines - 1));
+                    actions.extend([Action::SelectLineEnd, Action::Cut]);
+                    self.enter_insert(actions)
+                }
+                Operator::Yank => {
+                    self.linewise_register = true;
+                    select.push(Action::Copy);
+                    VimOutcome::Run(select, SelectionFixup::CollapseToStart)
+                }
+            };
+        }
+
+        let Some(mut motion) = motion else {
+            // Not a motion: cancel the operator
+            return VimOutcome::consumed();
+        };
+        // Like Vim, `cw` changes to the end of the word and keeps the space after it
+        if operator == Operator::Change && motion == Motion::WordForward {
+            motion = Motion::WordEnd;
+        }
+
+        self.linewise_register = false;
+        let mut actions = motion.actions(count, true);
+        match operator {
+            Operator::Delete => {
+                actions.push(Action::Cut);
+                VimOutcome::run(actions)
+            }
+            Operator::Change => {
+                actions.push(Action::Cut);
+                self.enter_insert(actions)
+            }
+            Operator::Yank => {
+                actions.push(Action::Copy);
+                VimOutcome::Run(actions, SelectionFixup::CollapseToStart)
+            }
+        }
+    }
+
+    fn handle_visual(&mut self, code: KeyCode, modifiers: KeyModifiers) -> VimOutcome {
+        if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+            self.clear_pending();
+            return VimOutcome::Passthrough;
+        }
+
+        let c = match code {
+            KeyCode::Char(c) => c,
+            KeyCode::Esc => return self.leave_visual(),
+            _ => {
+                self.clear_pending();
+                return VimOutcome::Passthrough;
+            }
+        };
+
+        if !self.pending_g && self.push_count(c) {
+            return VimOutcome::consumed();
+        }
+
+        let motion = match self.take_motion(c) {
+            Ok(motion) => motion,
+            Err(outcome) => return outcome,
+        };
+
+        if let Some(motion) = motion {
+            let count = self.count.take();
+            return VimOutcome::run(motion.actions(count, true));
+        }
+
+        self.clear_pending();
+        let linewise = self.mode == VimMode::VisualLine;
+        match c {
+            'd' | 'x' => {
+                self.linewise_register = linewise;
+                self.mode = VimMode::Normal;
+                VimOutcome::run(vec![Action::Cut])
+            }
+            'c' => {
+                self.linewise_register = linewise;
+                self.enter_insert(vec![Action::Cut])
+            }
+            'y' => {
+                self.linewise_register = linewise;
+                self.mode = VimMode::Normal;
+                VimOutcome::Run(vec![Action::Copy], SelectionFixup::CollapseToStart)
+            }
+            'v' if self.mode == VimMode::Visual => self.leave_visual(),
+            'V' if self.mode == VimMode::VisualLine => self.leave_visual(),
+            'v' => {
+                self.mode = VimMode::Visual;
+                VimOutcome::consumed()
+            }
+            'V' => {
+                self.mode = VimMode::VisualLine;
+                VimOutcome::run(vec![Action::SelectLine])
+            }
+            _ => VimOutcome::consumed(),
+        }
+    }
+
+    fn leave_visual(&mut self) -> VimOutcome {
+        self.clear_pending();
+        self.mode = VimMode::Normal;
+        VimOutcome::Run(Vec::new(), SelectionFixup::Clear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(vim: &mut VimState, keys: &str) -> Vec<VimOutcome> {
+        keys.chars()
+            .map(|c| {
+                let modifiers = if c.is_ascii_uppercase() {
+                    KeyModifiers::SHIFT
+                } else {
+                    KeyModifiers::NONE
+                };
+                vim.handle_key(KeyCode::Char(c), modifiers)
+            })
+            .collect()
+    }
+
+    fn last_actions(vim: &mut VimState, keys: &str) -> Vec<Action> {
+        match feed(vim, keys).pop() {
+            Some(VimOutcome::Run(actions, _)) => actions,
+            other => panic!("expected actions, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_counted_motion() {
+        let mut vim = VimState::new();
+        assert_eq!(last_actions(&mut vim, "3w"), vec![Action::MoveWordRight; 3]);
+        assert!(!vim.has_pending());
+    }
+
+    #[test]
+    fn test_zero_is_motion_unless_counting() {
+        let mut vim = VimState::new();
+        assert_eq!(last_actions(&mut vim, "0"), vec![Action::MoveLineStart]);
+        assert_eq!(last_actions(&mut vim, "10l"), vec![Action::MoveRight; 10]);
+    }
+
+    #[test]
+    fn test_gg_and_counted_g() {
+        let mut vim = VimState::new();
+        assert_eq!(
+            last_actions(&mut vim, "gg"),
+            vec![Action::MoveDocumentStart]
+        );
+        assert_eq!(last_actions(&mut vim, "G"), vec![Action::MoveDocumentEnd]);
+        assert_eq!(
+            last_actions(&mut vim, "3G"),
+            vec![
+                Action::MoveDocumentStart,
+                Action::MoveDown,
+                Action::MoveDown
+            ]
+        );
+    }
+
+    #[test]
+    fn test_operator_with_motion_multiplies_counts() {
+        let mut vim = VimState::new();
+        let mut expected = vec![Action::SelectWordRight; 6];
+        expected.push(Action::Cut);
+        assert_eq!(last_actions(&mut vim, "2d3w"), expected);
+        assert_eq!(vim.mode(), VimMode::Normal);
+    }
+
+    #[test]
+    fn test_change_enters_insert_mode() {
+        let mut vim = VimState::new();
+        assert_eq!(
+            last_actions(&mut vim, "c$"),
+            vec![Action::SelectLineEnd, Action::Cut]
+        );
+        assert_eq!(vim.mode(), VimMode::Insert);
+        assert_eq!(
+            vim.handle_key(KeyCode::Char('x'), KeyModifiers::NONE),
+            VimOutcome::Passthrough
+        );
+        vim.handle_key(KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(vim.mode(), VimMode::Normal);
+    }
+
+    #[test]
+    fn test_yank_line_collapses_selection() {
+        let mut vim = VimState::new();
+        let outcome = feed(&mut vim, "2yy").pop().unwrap();
+        assert_eq!(
+            outcome,
+            VimOutcome::Run(
+                vec![Action::SelectLine, Action::SelectDown, Action::Copy],
+                SelectionFixup::CollapseToStart
+            )
+        );
+        // Linewise yank pastes below the current line
+        assert_eq!(last_actions(&mut vim, "p")[0], Action::MoveDown);
+    }
+
+    #[test]
+    fn test_visual_mode_extends_selection() {
+        let mut vim = VimState::new();
+        assert_eq!(last_actions(&mut vim, "v"), vec![Action::SelectRight]);
+        assert_eq!(vim.mode(), VimMode::Visual);
+        assert_eq!(last_actions(&mut vim, "e"), vec![Action::SelectWordEnd]);
+        assert_eq!(last_actions(&mut vim, "d"), vec![Action::Cut]);
+        assert_eq!(vim.mode(), VimMode::Normal);
+    }
+
+    #[test]
+    fn test_escape_leaves_visual_and_clears_selection() {
+        let mut vim = VimState::new();
+        feed(&mut vim, "V");
+        assert_eq!(vim.mode(), VimMode::VisualLine);
+        assert_eq!(
+            vim.handle_key(KeyCode::Esc, KeyModifiers::NONE),
+            VimOutcome::Run(Vec::new(), SelectionFixup::Clear)
+        );
+        assert_eq!(vim.mode(), VimMode::Normal);
+    }
+
+    #[test]
+    fn test_modified_keys_pass_through() {
+        let mut vim = VimState::new();
+        feed(&mut vim, "d");
+        assert_eq!(
+            vim.handle_key(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            VimOutcome::Passthrough
+        );
+        assert!(!vim.has_pending());
+    }
+}
@@ -48,6 +48,7 @@ use std::{
     "File location syntax:\n",
     "  file.txt:10                  Open at line 10\n",
     "  file.txt:10:5                Open at line 10, column 5\n",
+    "  +10 file.txt                 Open at line 10 (also +10:5)\n",
     "  file.txt:10-20               Select lines 10 to 20\n",
     "  file.txt:10:5-20:1           Select from line 10 col 5 to line 20 col 1\n",
     "  file.txt:10@\"msg\"            Open at line 10 with markdown popup message\n",
@@ -122,10 +123,28 @@ struct Cli {
     #[arg(long, value_name = "PATH")]
     log_file: Option<PathBuf>,
 
+    /// Most detailed log messages to write: error, warn, info, debug or trace
+    /// (overrides the log_level setting)
+    #[arg(long, value_name = "LEVEL")]
+    log_level: Option<config::LogLevel>,
+
     /// Enable event logging to the specified file
     #[arg(long, value_name = "LOG_FILE")]
     event_log: Option<PathBuf>,
 
+    /// Record keyboard, mouse, paste and resize input to a script that the
+    /// test harness can replay
+    #[arg(long, value_name = "PATH")]
+    record_input: Option<PathBuf>,
+
+    /// Apply a script of edits to FILES without a terminal, then save them
+    #[arg(long, value_name = "SCRIPT")]
+    batch: Option<PathBuf>,
+
+    /// With --batch, print the edited files instead of saving them
+    #[arg(long, requires = "batch")]
+    stdout: bool,
+
     /// Don't restore previous workspace
     #[arg(long, alias = "no-session")]
     no_restore: bool,
@@ -179,7 +198,11 @@ struct Args {
     no_plugins: bool,
     config: Option<PathBuf>,
     log_file: Option<PathBuf>,
+    log_level: Option<config::LogLevel>,
     event_log: Option<PathBuf>,
+    record_input: Option<PathBuf>,
+    batch: Option<PathBuf>,
+    stdout: bool,
     no_session: bool,
     no_upgrade_check: bool,
     dump_config: bool,
@@ -231,11 +254,13 @@ impl From<Cli> for Args {
                         Some((*name).to_string())
                     };
                     let wait = files.iter().any(|s| *s == "--wait");
-                    let file_list: Vec<String> = files
-                        .iter()
-                        .filter(|s| **s != "--wait")
-                        .map(|s| (*s).to_string())
-                        .collect();
+                    let file_list: Vec<String> = expand_line_prefix_args(
+                        files
+                            .iter()
+                            .filter(|s| **s != "--wait")
+                            .map(|s| (*s).to_string())
+                            .collect(),
+                    );
                     (
                         false,
                         None,
@@ -395,12 +420,16 @@ impl From<Cli> for Args {
         };
 
         Args {
-            files,
+            files: expand_line_prefix_args(files),
             stdin: cli.stdin,
             no_plugins: cli.no_plugins,
             config: cli.config,
             log_file: cli.log_file,
+            log_level: cli.log_level,
             event_log: cli.event_log,
+            record_input: cli.record_input,
+            batch: cli.batch,
+            stdout: cli.stdout,
             no_session: cli.no_restore,
             no_upgrade_check: cli.no_upgrade_check,
             dump_config,
@@ -655,6 +684,17 @@ fn stdin_has_data() -> bool {
     !io::stdin().is_terminal()
 }
 
+/// Read everything piped to stdin, to hand to a session's server
+fn read_piped_stdin() -> AnyhowResult<String> {
+    use std::io::Read;
+    if !stdin_has_data() {
+        anyhow::bail!("--stdin or \"-\" specified but stdin is a terminal (no piped data)");
+    }
+    let mut bytes = Vec::new();
+    io::stdin().read_to_end(&mut bytes)?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
 /// Reopen stdin from /dev/tty after reading piped content.
 /// This allows crossterm to use the terminal for keyboard input
 /// even though the original stdin was a pipe.
@@ -733,9 +773,16 @@ fn handle_first_run_setup(
         editor.enable_event_streaming(log_path)?;
     }
 
+    if let Some(script_path) = &args.record_input {
+        editor
+            .start_input_recording(script_path)
+            .with_context(|| format!("Failed to record input to {}", script_path.display()))?;
+    }
+
     if let Some(handles) = tracing_handles.take() {
         editor.set_warning_log(handles.warning.receiver, handles.warning.path);
         editor.set_status_log_path(handles.status.path);
+        editor.set_editor_log_path(handles.log_path);
     }
 
     if workspace_enabled {
@@ -800,6 +847,30 @@ fn handle_first_run_setup(
     Ok(())
 }
 
+/// Fold GNU-style `+LINE` and `+LINE:COL` arguments into the file that follows,
+/// so `fresh +42 main.rs` behaves like `fresh main.rs:42`.
+///
+/// A trailing `+LINE` with no file after it is kept as a file name.
+fn expand_line_prefix_args(files: Vec<String>) -> Vec<String> {
+    let is_line_prefix = |arg: &str| {
+        arg.strip_prefix('+')
+            .is_some_and(|rest| parse_line_col(rest).is_some())
+    };
+
+    let mut result = Vec::with_capacity(files.len());
+    let mut iter = files.into_iter().peekable();
+    while let Some(arg) = iter.next() {
+        if is_line_prefix(&arg) {
+            if let Some(file) = iter.next_if(|next| !is_line_prefix(next)) {
+                result.push(format!("{}:{}", file, &arg[1..]));
+                continue;
+            }
+        }
+        result.push(arg);
+    }
+    result
+}
+
 /// Parse a file path that may include line/column, range, and message information.
 /// Supports formats:
 /// - file.txt
@@ -1125,7 +1196,7 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
         .log_file
         .clone()
         .unwrap_or_else(fresh::services::log_dirs::main_log_path);
-    let tracing_handles = tracing_setup::init_global(&log_file);
+    let tracing_handles = tracing_setup::init_global(&log_file, args.log_level.unwrap_or_default());
 
     // Clean up stale log files from dead processes on startup
     fresh::services::log_dirs::cleanup_stale_logs();
@@ -1297,6 +1368,9 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
     if args.no_upgrade_check {
         config.check_for_updates = false;
     }
+    if let (None, Some(handles)) = (args.log_level, &tracing_handles) {
+        handles.level.set(config.log_level);
+    }
 
     // Initialize i18n with locale: CLI arg > config > environment
     // This ensures menu defaults are created with the correct translations
@@ -2033,6 +2107,95 @@ MIT
     Ok(())
 }
 
+/// Apply a batch script to the files named on the command line
+///
+/// The editor core runs against a capturing backend instead of a terminal,
+/// and is drawn before each step so actions that depend on the layout behave
+/// as they do on screen. The first failing step is reported with its line
+/// and the command exits non-zero without saving anything further.
+fn run_batch_command(script_path: &std::path::Path, args: &Args) -> AnyhowResult<()> {
+    use fresh::app::batch::parse_batch_script;
+    use fresh::server::CaptureBackend;
+
+    if args.files.is_empty() {
+        anyhow::bail!("--batch needs at least one file to edit");
+    }
+    let source = std::fs::read_to_string(script_path)
+        .with_context(|| format!("Failed to read batch script {}", script_path.display()))?;
+    let steps = parse_batch_script(&source)
+        .map_err(|e| anyhow::anyhow!("{}: {}", script_path.display(), e))?;
+
+    let working_dir = std::env::current_dir()?;
+    let dir_context = DirectoryContext::from_system()?;
+    let config = if let Some(config_path) = &args.config {
+        config::Config::load_from_file(config_path).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to load config from {}: {}",
+                config_path.display(),
+                e
+            )
+        })?
+    } else {
+        config::Config::load_with_layers(&dir_context, &working_dir)
+    };
+    fresh::i18n::init_with_config(args.locale.as_deref().or(config.locale.as_option()));
+
+    let (width, height) = (120, 40);
+    let mut terminal = Terminal::new(CaptureBackend::new(width, height))?;
+    let filesystem: std::sync::Arc<dyn FileSystem + Send + Sync> =
+        std::sync::Arc::new(StdFileSystem);
+    let mut editor = Editor::with_working_dir(
+        config,
+        width,
+        height,
+        Some(working_dir),
+        dir_context,
+        !args.no_plugins,
+        fresh::view::color_support::ColorCapability::TrueColor,
+        filesystem,
+    )
+    .context("Failed to create editor instance")?;
+
+    let locations: Vec<FileLocation> = args.files.iter().map(|f| parse_file_location(f)).collect();
+    for location in locations.iter().rev() {
+        editor
+            .open_file(&location.path)
+            .with_context(|| format!("Failed to open {}", location.path.display()))?;
+    }
+    if let Some(line) = locations[0].line {
+        editor.goto_line_col(line, locations[0].column);
+    }
+
+    for step in &steps {
+        editor.process_async_messages();
+        terminal.draw(|frame| editor.render(frame))?;
+        if let Err(e) = editor.run_batch_step(&step.step) {
+            anyhow::bail!(
+                "{}:{}: {}: {}",
+                script_path.display(),
+                step.line,
+                step.step,
+                e
+            );
+        }
+    }
+
+    for location in &locations {
+        editor.open_file(&location.path)?;
+        if args.stdout {
+            let content = editor
+                .get_buffer_content(editor.active_buffer())
+                .unwrap_or_default();
+            print!("{}", content);
+        } else if editor.active_state().buffer.is_modified() {
+            editor
+                .save()
+                .with_context(|| format!("Failed to save {}", location.path.display()))?;
+        }
+    }
+    Ok(())
+}
+
 // === Session persistence commands ===
 
 /// List active sessions
@@ -2178,14 +2341,20 @@ fn kill_session_command(session: Option<&str>, args: &Args) -> AnyhowResult<()>
 fn run_server_command(args: &Args) -> AnyhowResult<()> {
     use fresh::server::{EditorServer, EditorServerConfig};
 
-    // Initialize tracing to stderr (will go to log file when spawned detached)
-    use tracing_subscriber::{fmt, EnvFilter};
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("debug"));
-    fmt()
-        .with_env_filter(filter)
-        .with_writer(std::io::stderr)
-        .with_ansi(false)
-        .init();
+    // Log to a per-process server log. A detached server has no terminal,
+    // so its stdout/stderr (startup messages, panics) go there too rather
+    // than being lost to /dev/null.
+    let log_path = args
+        .log_file
+        .clone()
+        .unwrap_or_else(fresh::services::log_dirs::server_log_path);
+    let level_handle = tracing_setup::init_server(&log_path, args.log_level.unwrap_or_default());
+    #[cfg(unix)]
+    if !std::io::IsTerminal::is_terminal(&std::io::stderr()) {
+        if let Err(e) = fresh::server::redirect_output_to(&log_path) {
+            tracing::warn!("Failed to redirect server output to {:?}: {}", log_path, e);
+        }
+    }
 
     eprintln!(
         "[server] Starting server process for session {:?}",
@@ -2205,6 +2374,9 @@ fn run_server_command(args: &Args) -> AnyhowResult<()> {
         config::Config::load_with_layers(&dir_context, &working_dir)
     };
     eprintln!("[server] Editor config loaded");
+    if let (None, Some(handle)) = (args.log_level, &level_handle) {
+        handle.set(editor_config.log_level);
+    }
 
     let config = EditorServerConfig {
         working_dir: working_dir.clone(),
@@ -2213,6 +2385,8 @@ fn run_server_command(args: &Args) -> AnyhowResult<()> {
         editor_config,
         dir_context,
         plugins_enabled: !args.no_plugins,
+        workspace_enabled: !args.no_session,
+        log_path: Some(log_path),
     };
 
     eprintln!("[server] Creating EditorServer...");
@@ -2238,11 +2412,49 @@ fn run_server_command(args: &Args) -> AnyhowResult<()> {
     Ok(())
 }
 
-/// Open files in a running session without attaching
+/// Open the command-line files, and content piped to stdin, in the session
+/// already running for the current directory, if there is one. Returns true if the files were handed
+/// over, so no second editor should be started.
+fn forward_to_running_session(args: &Args) -> AnyhowResult<bool> {
+    let stdin_requested = args.stdin || args.files.iter().any(|f| f == "-");
+    let files: Vec<String> = args.files.iter().filter(|f| *f != "-").cloned().collect();
+    if files.is_empty() && !stdin_requested {
+        return Ok(false);
+    }
+    // Remote files and directories need an editor of their own
+    let needs_own_editor = files.iter().any(|f| match parse_location(f) {
+        ParsedLocation::Remote(_) => true,
+        ParsedLocation::Local(loc) => loc.path.is_dir(),
+    });
+    if needs_own_editor {
+        return Ok(false);
+    }
+
+    let socket_paths = SocketPaths::for_working_dir(&std::env::current_dir()?)?;
+    socket_paths.cleanup_if_stale();
+    if !socket_paths.is_server_alive() {
+        return Ok(false);
+    }
+
+    // Piped content goes to the session as an unnamed buffer
+    let stdin_content = if stdin_requested {
+        Some(read_piped_stdin()?)
+    } else {
+        None
+    };
+    run_open_files_command(None, &files, false, stdin_content, true)?;
+    Ok(true)
+}
+
+/// Open files, and content piped to stdin, in a running session without
+/// attaching. Files `forwarded` from a plain `fresh` invocation are sent one
+/// by one with `OpenFile`, which also raises the session's terminals.
 fn run_open_files_command(
     session_name: Option<&str>,
     files: &[String],
     wait: bool,
+    stdin_content: Option<String>,
+    forwarded: bool,
 ) -> AnyhowResult<()> {
     use fresh::server::daemon::is_process_running;
     use fresh::server::protocol::{
@@ -2250,7 +2462,7 @@ fn run_open_files_command(
     };
     use fresh::server::spawn_server_detached;
 
-    if files.is_empty() {
+    if files.is_empty() && stdin_content.is_none() {
         eprintln!("No files specified.");
         return Ok(());
     }
@@ -2289,7 +2501,7 @@ fn run_open_files_command(
     }
 
     // Check if we have any files to open BEFORE starting the server
-    if file_requests.is_empty() {
+    if file_requests.is_empty() && stdin_content.is_none() {
         if skipped_dirs > 0 {
             eprintln!("No files to open (only directories were specified).");
         }
@@ -2308,7 +2520,8 @@ fn run_open_files_command(
 
     // Start server if not running (like nvr does by default)
     let server_was_started = if !socket_paths.is_server_alive() {
-        let _pid = spawn_server_detached(session_name)?;
+        // Files were named explicitly, so don't restore the last workspace
+        let _pid = spawn_server_detached(session_name, true)?;
 
         // Wait for server to be ready
         loop {
@@ -2333,9 +2546,11 @@ fn run_open_files_command(
     conn.write_control(&hello_json)?;
 
     // Read server response
-    let response = conn
-        .read_control()?
-        .ok_or_else(|| anyhow::anyhow!("Server closed connection during handshake"))?;
+    let response = conn.read_control()?.ok_or_else(|| {
+        anyhow::anyhow!(
+            "Server closed connection during handshake (is the session from an older fresh?)"
+        )
+    })?;
 
     let server_msg: ServerControl = serde_json::from_str(&response)?;
 
@@ -2353,6 +2568,10 @@ fn run_open_files_command(
             eprintln!("Version mismatch: server is v{}", mismatch.server_version);
             return Ok(());
         }
+        ServerControl::Quit { reason } => {
+            eprintln!("{}", reason);
+            return Ok(());
+        }
         ServerControl::Error { message } => {
             return Err(anyhow::anyhow!("Server error: {}", message));
         }
@@ -2361,19 +2580,37 @@ fn run_open_files_command(
         }
     }
 
-    // Send OpenFiles command
-    let msg = serde_json::to_string(&ClientControl::OpenFiles {
-        files: file_requests.clone(),
-        wait,
-    })?;
-    conn.write_control(&msg)?;
+    // Send the piped content first, so the files end up in front of it
+    // like in a local editor
+    let opened_stdin = stdin_content.is_some();
+    if let Some(content) = stdin_content {
+        let msg = serde_json::to_string(&ClientControl::OpenStdin { content })?;
+        conn.write_control(&msg)?;
+    }
+
+    // Send OpenFiles command, or OpenFile per file when forwarding
+    if forwarded {
+        for file in &file_requests {
+            let msg = serde_json::to_string(&ClientControl::OpenFile { file: file.clone() })?;
+            conn.write_control(&msg)?;
+        }
+    } else if !file_requests.is_empty() {
+        let msg = serde_json::to_string(&ClientControl::OpenFiles {
+            files: file_requests.clone(),
+            wait,
+        })?;
+        conn.write_control(&msg)?;
+    }
 
     if server_was_started {
         // We just started the server — drop this fire-and-forget connection
         // and attach as a normal interactive client so the user can see the
         // editor. --wait is ignored in this path; the user quits normally.
         drop(conn);
-        return run_attach(session_name);
+        if opened_stdin {
+            reopen_stdin_from_tty()?;
+        }
+        return run_attach(session_name, true, None);
     } else if wait {
         // Existing session — block until the server sends WaitComplete
         loop {
@@ -2391,6 +2628,11 @@ fn run_open_files_command(
                 Err(_) => break,   // Connection error
             }
         }
+    } else if opened_stdin {
+        eprintln!(
+            "Opened stdin and {} file(s) in session.",
+            file_requests.len()
+        );
     } else {
         eprintln!("Opened {} file(s) in session.", file_requests.len());
     }
@@ -2399,10 +2641,24 @@ fn run_open_files_command(
 
 /// Attach to an existing session, starting a server if needed
 fn run_attach_command(args: &Args) -> AnyhowResult<()> {
-    run_attach(args.session_name.as_deref())
+    // Read piped content before the relay takes over stdin, then switch
+    // stdin back to the terminal for keyboard input
+    let stdin_content = if args.stdin || args.files.iter().any(|f| f == "-") {
+        let content = read_piped_stdin()?;
+        reopen_stdin_from_tty()?;
+        Some(content)
+    } else {
+        None
+    };
+    run_attach(args.session_name.as_deref(), args.no_session, stdin_content)
 }
 
-fn run_attach(session_name: Option<&str>) -> AnyhowResult<()> {
+/// Attach to a session; `stdin_content` is opened there as an unnamed buffer
+fn run_attach(
+    session_name: Option<&str>,
+    no_restore: bool,
+    stdin_content: Option<String>,
+) -> AnyhowResult<()> {
     use crossterm::terminal::enable_raw_mode;
     use fresh::server::protocol::{
         ClientControl, ClientHello, ServerControl, TermSize, PROTOCOL_VERSION,
@@ -2444,7 +2700,7 @@ fn run_attach(session_name: Option<&str>) -> AnyhowResult<()> {
         eprintln!("Starting server...");
 
         // Spawn server in background
-        let _pid = spawn_server_detached(session_name)?;
+        let _pid = spawn_server_detached(session_name, no_restore)?;
         true
     } else {
         false
@@ -2487,13 +2743,15 @@ fn run_attach(session_name: Option<&str>) -> AnyhowResult<()> {
     conn.write_control(&hello_json)?;
 
     // Read server response
-    let response = conn
-        .read_control()?
-        .ok_or_else(|| anyhow::anyhow!("Server closed connection during handshake"))?;
+    let response = conn.read_control()?.ok_or_else(|| {
+        anyhow::anyhow!(
+            "Server closed connection during handshake (is the session from an older fresh?)"
+        )
+    })?;
 
     let server_msg: ServerControl = serde_json::from_str(&response)?;
 
-    match server_msg {
+    let capabilities = match server_msg {
         ServerControl::Hello(server_hello) => {
             if server_hello.protocol_version != PROTOCOL_VERSION {
                 eprintln!(
@@ -2508,18 +2766,30 @@ fn run_attach(session_name: Option<&str>) -> AnyhowResult<()> {
                 server_hello.session_id,
                 server_hello.server_version
             );
+            server_hello.capabilities
         }
         ServerControl::VersionMismatch(mismatch) => {
             eprintln!("Version mismatch: server is v{}", mismatch.server_version);
             eprintln!("Please restart the server with the same version as the client.");
             return Ok(());
         }
+        ServerControl::Quit { reason } => {
+            eprintln!("{}", reason);
+            return Ok(());
+        }
         ServerControl::Error { message } => {
             return Err(anyhow::anyhow!("Server error: {}", message));
         }
         _ => {
             return Err(anyhow::anyhow!("Unexpected server response"));
         }
+    };
+
+    // Forward piped content before any input, so the buffer is there when
+    // the first frame is drawn
+    if let Some(content) = stdin_content {
+        let msg = serde_json::to_string(&ClientControl::OpenStdin { content })?;
+        conn.write_control(&msg)?;
     }
 
     // Continue to relay loop
@@ -2529,7 +2799,7 @@ fn run_attach(session_name: Option<&str>) -> AnyhowResult<()> {
     enable_raw_mode()?;
 
     // Run the client relay loop (handshake already done)
-    let result = client::run_client_relay(conn);
+    let result = client::run_client_relay(conn, &capabilities);
 
     // Best-effort: restore terminal state before printing any messages.
     // The server sends terminal setup sequences (alternate screen, mouse capture, etc.)
@@ -2555,6 +2825,10 @@ fn run_attach(session_name: Option<&str>) -> AnyhowResult<()> {
             eprintln!("Version mismatch: server is v{}", server_version);
             eprintln!("Please restart the server with the same version as the client.");
         }
+        Ok(client::ClientExitReason::Rejected { reason }) => {
+            tracing::debug!("Client exit: Rejected");
+            eprintln!("{}", reason);
+        }
         Ok(client::ClientExitReason::Error(e)) => {
             tracing::debug!("Client exit: Error({})", e);
             eprintln!("Connection error: {}", e);
@@ -2652,6 +2926,11 @@ fn real_main() -> AnyhowResult<()> {
         return init_package_command(pkg_type.clone());
     }
 
+    // Handle --batch early (runs without a terminal)
+    if let Some(script_path) = &args.batch {
+        return run_batch_command(script_path, &args);
+    }
+
     // Handle --list-sessions early (no terminal setup needed)
     if args.list_sessions {
         return list_sessions_command();
@@ -2669,7 +2948,7 @@ fn real_main() -> AnyhowResult<()> {
 
     // Handle open-file in session: send files to running session without attaching
     if let Some((session_name, files, wait)) = &args.open_files_in_session {
-        return run_open_files_command(session_name.as_deref(), files, *wait);
+        return run_open_files_command(session_name.as_deref(), files, *wait, None, false);
     }
 
     // Handle --attach: connect to existing session
@@ -2690,6 +2969,12 @@ fn real_main() -> AnyhowResult<()> {
         );
     }
 
+    // A session is already running here: open the files in it instead of
+    // starting a second editor
+    if forward_to_running_session(&args)? {
+        return Ok(());
+    }
+
     let SetupState {
         config,
         mut tracing_handles,
@@ -2746,12 +3031,15 @@ fn real_main() -> AnyhowResult<()> {
 
         // Set the process spawner (LocalProcessSpawner for local, RemoteProcessSpawner for remote)
         editor.set_process_spawner(process_spawner.clone());
+        editor.set_workspace_enabled(workspace_enabled);
 
         #[cfg(target_os = "linux")]
         if gpm_client.is_some() {
             editor.set_gpm_active(true);
         }
 
+        detect_terminal_background(&mut editor);
+
         if first_run {
             handle_first_run_setup(
                 &mut editor,
@@ -2904,9 +3192,13 @@ where
     use std::time::Instant;
 
     const FRAME_DURATION: Duration = Duration::from_millis(16); // 60fps
+    /// Longest a stream of input may hold back a frame
+    const MAX_BATCH_DURATION: Duration = Duration::from_millis(100);
     let mut last_render = Instant::now();
     let mut needs_render = true;
     let mut pending_event: Option<CrosstermEvent> = None;
+    // Where input went when the screen was last drawn
+    let mut drawn_input_target = editor.input_target();
 
     loop {
         // Run shared per-tick housekeeping (async messages, timers, auto-save, etc.)
@@ -2932,12 +3224,24 @@ where
         }
 
         if needs_render && last_render.elapsed() >= FRAME_DURATION {
-            {
-                let _span = tracing::info_span!("terminal_draw").entered();
-                terminal.draw(|frame| editor.render(frame))?;
+            // Handle the rest of the queued input before drawing, so a held
+            // key or injected keystrokes cost one frame rather than one each.
+            // Stop at an event that changed where input goes.
+            let batching = pending_event.is_none()
+                && editor.input_target() == drawn_input_target
+                && last_render.elapsed() < MAX_BATCH_DURATION;
+            if batching {
+                pending_event = poll_event(Duration::ZERO)?;
+            }
+            if !batching || pending_event.is_none() {
+                {
+                    let _span = tracing::info_span!("terminal_draw").entered();
+                    terminal.draw(|frame| editor.render(frame))?;
+                }
+                last_render = Instant::now();
+                needs_render = false;
+                drawn_input_target = editor.input_target();
             }
-            last_render = Instant::now();
-            needs_render = false;
         }
 
         let event = if let Some(e) = pending_event.take() {
@@ -2957,6 +3261,8 @@ where
         let (event, next) = coalesce_mouse_moves(event)?;
         pending_event = next;
 
+        editor.record_input(&event);
+
         // Event debug dialog receives ALL RAW events (before any translation or processing)
         // This is essential for diagnosing terminal keybinding issues
         if editor.is_event_debug_active() {
@@ -2984,6 +3290,9 @@ where
                     let translated_event = editor.key_translator().translate(key_event);
                     handle_key_event(editor, translated_event)?;
                     needs_render = true;
+                } else if key_event.kind == KeyEventKind::Release {
+                    editor.handle_key_release(key_event.code);
+                    needs_render = true;
                 }
             }
             CrosstermEvent::Mouse(mouse_event) => {
@@ -3000,6 +3309,10 @@ where
                 editor.paste_text(text);
                 needs_render = true;
             }
+            CrosstermEvent::FocusLost => {
+                editor.handle_focus_lost();
+                needs_render = true;
+            }
             _ => {}
         }
     }
@@ -3007,6 +3320,19 @@ where
     Ok(())
 }
 
+/// Ask the terminal for its background color when the theme follows it.
+///
+/// Only called at startup: the reply is read straight from stdin, which would
+/// race crossterm's event reader once the event loop is running.
+fn detect_terminal_background(editor: &mut Editor) {
+    if !editor.wants_terminal_background() {
+        return;
+    }
+    if let Some((r, g, b)) = fresh::services::terminal_background::query_terminal() {
+        editor.set_terminal_background(r, g, b);
+    }
+}
+
 /// Poll for events from both GPM and crossterm (Linux with libgpm available)
 #[cfg(target_os = "linux")]
 fn poll_with_gpm(
@@ -3204,6 +3530,31 @@ mod tests {
         assert_eq!(loc.column, Some(10));
     }
 
+    #[test]
+    fn test_expand_line_prefix_args() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            expand_line_prefix_args(args(&["+42", "foo.txt", "bar.txt"])),
+            args(&["foo.txt:42", "bar.txt"])
+        );
+        assert_eq!(
+            expand_line_prefix_args(args(&["+42:7", "foo.txt"])),
+            args(&["foo.txt:42:7"])
+        );
+        let loc = parse_file_location(&expand_line_prefix_args(args(&["+42:7", "foo.txt"]))[0]);
+        assert_eq!(loc.path, PathBuf::from("foo.txt"));
+        assert_eq!(loc.line, Some(42));
+        assert_eq!(loc.column, Some(7));
+
+        // No file after it, or not a number: left alone
+        assert_eq!(expand_line_prefix_args(args(&["+42"])), args(&["+42"]));
+        assert_eq!(
+            expand_line_prefix_args(args(&["+notes", "foo.txt"])),
+            args(&["+notes", "foo.txt"])
+        );
+    }
+
     #[test]
     fn test_parse_file_location_absolute_path() {
         let loc = parse_file_location("/home/user/foo.txt:100:5");
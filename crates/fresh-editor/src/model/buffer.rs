@@ -2,6 +2,7 @@
 /// Architecture where the tree is the single source of truth for text and line information
 use crate::model::encoding;
 use crate::model::filesystem::{FileMetadata, FileSystem, WriteOp};
+use crate::model::line_index::{self, LineStartIndex, LINE_INDEX_STRIDE};
 use crate::model::piece_tree::{
     BufferData, BufferLocation, Cursor, PieceInfo, PieceRangeIter, PieceTree, PieceView, Position,
     StringBuffer, TreeStats,
@@ -284,6 +285,10 @@ pub struct TextBuffer {
     /// so that `compute_line_feeds_static` can recount accurately.
     line_feeds_scanned: bool,
 
+    /// Sampled line starts over the part of a large file the line scanner
+    /// has reached, for exact line numbers where the tree has no line metadata
+    line_index: LineStartIndex,
+
     /// Is this a binary file? Binary files are opened read-only and render
     /// unprintable characters as code points.
     is_binary: bool,
@@ -353,6 +358,7 @@ impl TextBuffer {
             saved_file_size: None,
             version: 0,
             config: BufferConfig::default(),
+            line_index: LineStartIndex::new(),
         }
     }
 
@@ -434,6 +440,7 @@ impl TextBuffer {
             saved_file_size: Some(bytes),
             version: 0,
             config: BufferConfig::default(),
+            line_index: LineStartIndex::new(),
         }
     }
 
@@ -478,6 +485,7 @@ impl TextBuffer {
             saved_file_size: Some(bytes), // Treat initial content as "saved" state
             version: 0,
             config: BufferConfig::default(),
+            line_index: LineStartIndex::new(),
         }
     }
 
@@ -526,6 +534,7 @@ impl TextBuffer {
             saved_file_size: Some(bytes),
             version: 0,
             config: BufferConfig::default(),
+            line_index: LineStartIndex::new(),
         }
     }
 
@@ -563,6 +572,7 @@ impl TextBuffer {
             saved_file_size: None,
             version: 0,
             config: BufferConfig::default(),
+            line_index: LineStartIndex::new(),
         }
     }
 
@@ -812,6 +822,7 @@ impl TextBuffer {
             saved_file_size: Some(file_size),
             version: 0,
             config: BufferConfig::default(),
+            line_index: LineStartIndex::new(),
         })
     }
 
@@ -1715,6 +1726,7 @@ impl TextBuffer {
 
         // Count line feeds in the text to insert
         let line_feed_cnt = Some(text.iter().filter(|&&b| b == b'\n').count());
+        self.update_line_index(offset, 0, text.len(), line_feed_cnt.unwrap_or(0));
 
         // Optimization: try to append to existing buffer if insertion is at piece boundary
         let (buffer_location, buffer_offset, text_len) =
@@ -1809,6 +1821,10 @@ impl TextBuffer {
 
         // Count line feeds in the text to insert
         let line_feed_cnt = text.iter().filter(|&&b| b == b'\n').count();
+        if self.large_file {
+            let offset = self.position_to_offset(position);
+            self.update_line_index(offset, 0, text.len(), line_feed_cnt);
+        }
 
         // Create a new StringBuffer for this insertion
         let buffer_id = self.next_buffer_id;
@@ -1844,6 +1860,9 @@ impl TextBuffer {
             }
         }
 
+        let end = (offset + bytes).min(self.total_bytes());
+        self.update_line_index(offset, end - offset, 0, 0);
+
         // Update piece tree
         self.piece_tree.delete(offset, bytes, &self.buffers);
 
@@ -1860,6 +1879,12 @@ impl TextBuffer {
     /// Delete text in a line/column range
     /// This now uses the optimized piece_tree.delete_position_range() for a single traversal
     pub fn delete_range(&mut self, start: Position, end: Position) {
+        if self.large_file {
+            let start = self.position_to_offset(start);
+            let end = self.position_to_offset(end);
+            self.update_line_index(start, end.saturating_sub(start), 0, 0);
+        }
+
         // Use the optimized position-based deletion
         self.piece_tree.delete_position_range(
             start.line,
@@ -1901,6 +1926,7 @@ impl TextBuffer {
         } else {
             self.piece_tree = PieceTree::empty();
         }
+        self.line_index.clear();
 
         self.mark_content_modified();
     }
@@ -1914,6 +1940,7 @@ impl TextBuffer {
         self.piece_tree = snapshot.piece_tree.clone();
         self.buffers = snapshot.buffers.clone();
         self.next_buffer_id = snapshot.next_buffer_id;
+        self.line_index.clear();
         self.mark_content_modified();
     }
 
@@ -1939,6 +1966,16 @@ impl TextBuffer {
         // is only called for edits with non-empty insert text
         let mut buffer_info: Vec<(BufferLocation, usize, usize, Option<usize>)> = Vec::new();
 
+        if self.large_file {
+            // Edits come last to first, so each leaves the offsets of the next alone
+            let total = self.total_bytes();
+            for &(pos, del_len, text) in edits {
+                let removed = del_len.min(total.saturating_sub(pos));
+                let line_feeds = text.bytes().filter(|&b| b == b'\n').count();
+                self.update_line_index(pos, removed, text.len(), line_feeds);
+            }
+        }
+
         for (_, _, text) in edits {
             if !text.is_empty() {
                 let buffer_id = self.next_buffer_id;
@@ -2322,10 +2359,10 @@ impl TextBuffer {
     }
 
     /// Get text from a byte range as bytes
-    /// CRATE-PRIVATE: Returns empty vector if any buffers are unloaded (silently fails!)
+    /// Returns empty vector if any buffers are unloaded (silently fails!)
     /// Only use this when you KNOW the data is loaded (e.g., for syntax highlighting small regions)
     /// External code should use get_text_range_mut() or DocumentModel methods
-    pub(crate) fn slice_bytes(&self, range: Range<usize>) -> Vec<u8> {
+    pub fn slice_bytes(&self, range: Range<usize>) -> Vec<u8> {
         self.get_text_range(range.start, range.end.saturating_sub(range.start))
             .unwrap_or_default()
     }
@@ -2466,6 +2503,8 @@ impl TextBuffer {
     ///
     /// Returns `(chunks, total_bytes)`.
     pub fn prepare_line_scan(&mut self) -> (Vec<LineScanChunk>, usize) {
+        self.line_index = LineStartIndex::new();
+
         // Pre-split the tree so every leaf ≤ LOAD_CHUNK_SIZE.
         self.piece_tree.split_leaves_to_chunk_size(LOAD_CHUNK_SIZE);
 
@@ -2516,6 +2555,139 @@ impl TextBuffer {
         Ok(count)
     }
 
+    /// Count `\n` bytes in a single leaf and sample its line starts for the
+    /// line index.
+    ///
+    /// Returns the count and `(offset in leaf, line feeds before it)` for
+    /// each sample. Filesystems that count remotely return no samples.
+    pub fn scan_leaf_line_starts(
+        &self,
+        leaf: &crate::model::piece_tree::LeafData,
+    ) -> std::io::Result<(usize, Vec<(usize, usize)>)> {
+        let buffer_id = leaf.location.buffer_id();
+        let buffer = self
+            .buffers
+            .get(buffer_id)
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "buffer not found"))?;
+
+        match &buffer.data {
+            crate::model::piece_tree::BufferData::Loaded { data, .. } => {
+                let end = (leaf.offset + leaf.bytes).min(data.len());
+                Ok(line_index::sample_line_starts(
+                    &data[leaf.offset..end],
+                    LINE_INDEX_STRIDE,
+                ))
+            }
+            crate::model::piece_tree::BufferData::Unloaded {
+                file_path,
+                file_offset,
+                ..
+            } => {
+                let read_offset = *file_offset as u64 + leaf.offset as u64;
+                self.fs.sample_line_starts_in_range(
+                    file_path,
+                    read_offset,
+                    leaf.bytes,
+                    LINE_INDEX_STRIDE,
+                )
+            }
+        }
+    }
+
+    /// Add a scanned chunk of the document to the line index.
+    ///
+    /// Chunks have to come in document order; one that doesn't continue the
+    /// index, or arrives after an edit past its end, is ignored.
+    pub fn record_line_scan_chunk(
+        &mut self,
+        offset: usize,
+        len: usize,
+        line_feeds: usize,
+        samples: &[(usize, usize)],
+    ) {
+        self.line_index.push_chunk(offset, len, line_feeds, samples);
+    }
+
+    /// Scan the whole file for line feeds at once, as the incremental scan
+    /// does over several frames.
+    pub fn scan_line_feeds(&mut self) -> std::io::Result<()> {
+        let (chunks, _) = self.prepare_line_scan();
+        let leaves = self.piece_tree_leaves();
+        let mut updates = Vec::new();
+        let mut offset = 0;
+        for chunk in chunks {
+            let (count, samples) = self.scan_leaf_line_starts(&leaves[chunk.leaf_index])?;
+            self.record_line_scan_chunk(offset, chunk.byte_len, count, &samples);
+            if !chunk.already_known {
+                updates.push((chunk.leaf_index, count));
+            }
+            offset += chunk.byte_len;
+        }
+        self.rebuild_with_pristine_saved_root(&updates);
+        Ok(())
+    }
+
+    /// Keep the line index in step with an edit about to be applied.
+    fn update_line_index(
+        &mut self,
+        offset: usize,
+        removed: usize,
+        inserted: usize,
+        inserted_line_feeds: usize,
+    ) {
+        if !self.large_file {
+            return;
+        }
+        // Removals bigger than a chunk aren't read back; the index is cut
+        // back to the edit instead
+        let removed_line_feeds = if removed == 0 {
+            Some(0)
+        } else if offset < self.line_index.scanned_bytes() && removed <= LOAD_CHUNK_SIZE {
+            self.read_range_unloaded(offset, removed)
+                .map(|bytes| bytes.iter().filter(|&&b| b == b'\n').count())
+        } else {
+            None
+        };
+        self.line_index.apply_edit(
+            offset,
+            removed,
+            removed_line_feeds,
+            inserted,
+            inserted_line_feeds,
+        );
+    }
+
+    /// Read a byte range of the document without loading anything: unloaded
+    /// parts are read straight from the filesystem.
+    fn read_range_unloaded(&self, offset: usize, len: usize) -> Option<Vec<u8>> {
+        let end = (offset + len).min(self.total_bytes());
+        let mut result = Vec::with_capacity(end.saturating_sub(offset));
+        for piece in self.piece_tree.iter_pieces_in_range(offset, end) {
+            let start = offset.max(piece.doc_offset);
+            let stop = end.min(piece.doc_offset + piece.bytes);
+            if stop <= start {
+                continue;
+            }
+            let buffer_start = piece.buffer_offset + (start - piece.doc_offset);
+            let len = stop - start;
+            match &self.buffers.get(piece.location.buffer_id())?.data {
+                BufferData::Loaded { data, .. } => {
+                    result.extend_from_slice(data.get(buffer_start..buffer_start + len)?);
+                }
+                BufferData::Unloaded {
+                    file_path,
+                    file_offset,
+                    ..
+                } => {
+                    let read_offset = (*file_offset + buffer_start) as u64;
+                    let bytes = self.fs.read_range(file_path, read_offset, len).ok()?;
+                    result.extend_from_slice(&bytes);
+                }
+            }
+        }
+        Some(result)
+    }
+
     /// Return the I/O parameters for an unloaded leaf, or `None` if loaded.
     ///
     /// Used by the incremental scan to distinguish leaves that can be counted
@@ -3008,12 +3180,34 @@ impl TextBuffer {
         self.get_text_range(start, bytes)
     }
 
-    /// Get the byte offset where a line starts
+    /// Get the byte offset where a line starts.
+    ///
+    /// Large files find it through the line index, so only lines the line
+    /// scanner has reached are found before the scan completes.
     pub fn line_start_offset(&self, line: usize) -> Option<usize> {
+        if self.large_file {
+            if let Some(start) = self.line_start_from_index(line) {
+                return Some(start);
+            }
+        }
         let (start, _) = self.piece_tree.line_range(line, &self.buffers)?;
         Some(start)
     }
 
+    /// Line start read from the span of the line index holding it
+    fn line_start_from_index(&self, line: usize) -> Option<usize> {
+        let (start, line_feeds, end) = self.line_index.span_for_line(line)?;
+        if line == line_feeds {
+            return Some(start);
+        }
+        let span = self.read_range_unloaded(start, end - start)?;
+        span.iter()
+            .enumerate()
+            .filter(|(_, &b)| b == b'\n')
+            .nth(line - line_feeds - 1)
+            .map(|(i, _)| start + i + 1)
+    }
+
     /// Get piece information at a byte offset
     pub fn piece_info_at_offset(&self, offset: usize) -> Option<PieceInfo> {
         self.piece_tree.find_by_offset(offset)
@@ -3663,20 +3857,43 @@ impl TextBuffer {
 
     /// Get the line number for a given byte offset
     ///
-    /// Returns exact line number if metadata available, otherwise estimates based on bytes.
+    /// Returns the exact line number where it can be told
+    /// ([`Self::get_line_number_exact`]), otherwise an estimate
+    /// ([`Self::get_line_number_estimate`]).
+    pub fn get_line_number(&self, byte_offset: usize) -> usize {
+        self.get_line_number_exact(byte_offset)
+            .unwrap_or_else(|| self.get_line_number_estimate(byte_offset))
+    }
+
+    /// Get the exact line number for a given byte offset, if it can be told
     ///
     /// # Behavior by File Size:
-    /// - **Small files (< 1MB)**: Returns exact line number from piece tree's `line_starts` metadata
-    /// - **Large files (≥ 1MB)**: Returns estimated line number using `byte_offset / estimated_line_length`
+    /// - **Small files**: From the piece tree's `line_starts` metadata
+    /// - **Large files**: From the line index, counting line feeds on from the
+    ///   nearest sampled line start. `None` past the part of the file the line
+    ///   scanner has reached.
+    pub fn get_line_number_exact(&self, byte_offset: usize) -> Option<usize> {
+        if !self.large_file {
+            return self.offset_to_position(byte_offset).map(|pos| pos.line);
+        }
+        let byte_offset = byte_offset.min(self.total_bytes());
+        let (start, line_feeds) = self.line_index.sample_for_offset(byte_offset)?;
+        let gap = self.read_range_unloaded(start, byte_offset - start)?;
+        Some(line_feeds + gap.iter().filter(|&&b| b == b'\n').count())
+    }
+
+    /// Estimate the line number for a given byte offset
     ///
-    /// Large files don't maintain line metadata for performance reasons. The estimation
-    /// uses the configured `estimated_line_length` (default 80 bytes).
-    pub fn get_line_number(&self, byte_offset: usize) -> usize {
+    /// Uses the piece tree's line feed counts where there are any, otherwise
+    /// counts on from the end of the line index using the configured
+    /// `estimated_line_length` (default 80 bytes).
+    pub fn get_line_number_estimate(&self, byte_offset: usize) -> usize {
         self.offset_to_position(byte_offset)
             .map(|pos| pos.line)
             .unwrap_or_else(|| {
-                // Estimate line number based on configured average line length
-                byte_offset / self.config.estimated_line_length
+                let scanned = self.line_index.scanned_bytes();
+                self.line_index.scanned_line_feeds()
+                    + byte_offset.saturating_sub(scanned) / self.config.estimated_line_length
             })
     }
 
@@ -5551,6 +5768,7 @@ mod tests {
                 saved_file_size: Some(bytes),
                 version: 0,
                 config: BufferConfig::default(),
+                line_index: LineStartIndex::new(),
             }
         }
 
@@ -5820,6 +6038,7 @@ mod tests {
                 saved_file_size: Some(file_size),
                 version: 0,
                 config: BufferConfig::default(),
+                line_index: LineStartIndex::new(),
             }
         }
 
@@ -6086,6 +6305,7 @@ mod tests {
                 saved_file_size: Some(file_size),
                 version: 0,
                 config: BufferConfig::default(),
+                line_index: LineStartIndex::new(),
             };
 
             // Load a small viewport in the middle (forces chunk splitting).
@@ -6183,6 +6403,126 @@ mod tests {
                 total_leaves,
             );
         }
+
+        /// Lines of varying length, so estimates from an average are off
+        fn make_varied_content(lines: usize) -> Vec<u8> {
+            (0..lines)
+                .flat_map(|i| {
+                    let mut line = "x".repeat((i * 37) % 150);
+                    line.push('\n');
+                    line.into_bytes()
+                })
+                .collect()
+        }
+
+        fn count_lf(bytes: &[u8]) -> usize {
+            bytes.iter().filter(|&&b| b == b'\n').count()
+        }
+
+        fn nth_line_start(bytes: &[u8], line: usize) -> usize {
+            if line == 0 {
+                return 0;
+            }
+            bytes
+                .iter()
+                .enumerate()
+                .filter(|(_, &b)| b == b'\n')
+                .nth(line - 1)
+                .map(|(i, _)| i + 1)
+                .unwrap()
+        }
+
+        #[test]
+        fn test_line_index_exact_after_scan() {
+            let content = make_varied_content(60_000);
+            let tmp = tempfile::NamedTempFile::new().unwrap();
+            std::fs::write(tmp.path(), &content).unwrap();
+            let mut buf = large_file_buffer_unloaded(tmp.path(), content.len());
+
+            assert_eq!(buf.get_line_number_exact(content.len() / 2), None);
+            buf.scan_line_feeds().unwrap();
+
+            for offset in [
+                0,
+                1,
+                4_000,
+                content.len() / 3,
+                content.len() - 1,
+                content.len(),
+            ] {
+                assert_eq!(
+                    buf.get_line_number_exact(offset),
+                    Some(count_lf(&content[..offset])),
+                    "line at offset {}",
+                    offset
+                );
+            }
+            for line in [0, 1, 1023, 1024, 1025, 30_000, 59_999, 60_000] {
+                assert_eq!(
+                    buf.line_start_offset(line),
+                    Some(nth_line_start(&content, line)),
+                    "start of line {}",
+                    line
+                );
+            }
+        }
+
+        #[test]
+        fn test_line_index_is_exact_mid_scan() {
+            let content = make_varied_content(60_000);
+            let tmp = tempfile::NamedTempFile::new().unwrap();
+            std::fs::write(tmp.path(), &content).unwrap();
+            let mut buf = large_file_buffer_unloaded(tmp.path(), content.len());
+
+            // Scan only the first chunk, as after one batch of the incremental scan
+            let (chunks, _) = buf.prepare_line_scan();
+            let leaves = buf.piece_tree_leaves();
+            let (count, samples) = buf
+                .scan_leaf_line_starts(&leaves[chunks[0].leaf_index])
+                .unwrap();
+            buf.record_line_scan_chunk(0, chunks[0].byte_len, count, &samples);
+
+            let inside = chunks[0].byte_len - 10;
+            assert_eq!(
+                buf.get_line_number_exact(inside),
+                Some(count_lf(&content[..inside]))
+            );
+            assert_eq!(buf.get_line_number(inside), count_lf(&content[..inside]));
+
+            let outside = chunks[0].byte_len + 100_000;
+            assert_eq!(buf.get_line_number_exact(outside), None);
+            // The estimate counts on from the end of the scanned chunk
+            assert!(buf.get_line_number_estimate(outside) >= count);
+        }
+
+        #[test]
+        fn test_line_index_follows_edits() {
+            let mut content = make_varied_content(60_000);
+            let tmp = tempfile::NamedTempFile::new().unwrap();
+            std::fs::write(tmp.path(), &content).unwrap();
+            let mut buf = large_file_buffer_unloaded(tmp.path(), content.len());
+            buf.scan_line_feeds().unwrap();
+
+            buf.insert_bytes(500, b"new\nlines\n".to_vec());
+            content.splice(500..500, b"new\nlines\n".iter().copied());
+            buf.delete_bytes(200_000, 3_000);
+            content.drain(200_000..203_000);
+
+            for offset in [600, 150_000, 250_000, content.len()] {
+                assert_eq!(
+                    buf.get_line_number_exact(offset),
+                    Some(count_lf(&content[..offset])),
+                    "line at offset {}",
+                    offset
+                );
+            }
+            for line in [2, 5_000, 40_000] {
+                assert_eq!(
+                    buf.line_start_offset(line),
+                    Some(nth_line_start(&content, line))
+                );
+            }
+        }
     }
 }
 
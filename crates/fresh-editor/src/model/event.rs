@@ -759,6 +759,44 @@ impl EventLog {
         self.current_index - 1
     }
 
+    /// Fold the entries logged since `start` into a single batch so that one
+    /// undo reverts them together. Used by query-replace, which logs each
+    /// confirmed replacement as it happens. Does nothing when there is redo
+    /// history or fewer than two write actions to fold.
+    pub fn merge_since(&mut self, start: usize, description: String) {
+        if start >= self.entries.len() || self.current_index != self.entries.len() {
+            return;
+        }
+        let writes = self.entries[start..]
+            .iter()
+            .filter(|entry| entry.event.is_write_action())
+            .count();
+        if writes < 2 {
+            return;
+        }
+
+        // Readonly events without an inverse would make the whole batch
+        // non-invertible; undo skips them anyway
+        let events: Vec<Event> = self
+            .entries
+            .drain(start..)
+            .map(|entry| entry.event)
+            .filter(|event| event.inverse().is_some())
+            .collect();
+        let merged_end = self.current_index;
+        self.entries.push(LogEntry::new(Event::Batch {
+            events,
+            description,
+        }));
+        self.current_index = self.entries.len();
+
+        self.saved_at_index = match self.saved_at_index {
+            Some(saved_idx) if saved_idx == merged_end => Some(self.current_index),
+            Some(saved_idx) if saved_idx > start => None,
+            saved => saved,
+        };
+    }
+
     /// Get the current event index
     pub fn current_index(&self) -> usize {
         self.current_index
@@ -1076,6 +1114,40 @@ mod tests {
         assert_eq!(log.current_index(), 1);
     }
 
+    #[test]
+    fn test_merge_since_folds_into_one_undo_step() {
+        let mut log = EventLog::new();
+        log.append(Event::Insert {
+            position: 0,
+            text: "a".to_string(),
+            cursor_id: CursorId(0),
+        });
+        let start = log.current_index();
+        for (position, text) in [(1, "b"), (2, "c")] {
+            log.append(Event::Insert {
+                position,
+                text: text.to_string(),
+                cursor_id: CursorId(0),
+            });
+        }
+
+        log.merge_since(start, "Query replace".to_string());
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.current_index(), 2);
+
+        // One undo reverts both merged inserts, in reverse order
+        let inverse = log.undo();
+        assert_eq!(inverse.len(), 1);
+        match &inverse[0] {
+            Event::Batch { events, .. } => {
+                assert_eq!(events.len(), 2);
+                assert!(matches!(&events[0], Event::Delete { range, .. } if *range == (2..3)));
+            }
+            other => panic!("Expected Batch, got {other:?}"),
+        }
+        assert_eq!(log.current_index(), 1);
+    }
+
     #[test]
     fn test_event_inverse() {
         let insert = Event::Insert {
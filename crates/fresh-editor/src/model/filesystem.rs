@@ -3,7 +3,7 @@
 //! This module provides a single trait for all filesystem operations, allowing the editor
 //! to work with different backends:
 //! - `StdFileSystem`: Native filesystem using `std::fs`
-//! - `VirtualFileSystem`: In-memory filesystem for WASM/browser (to be implemented)
+//! - `VirtualFileSystem`: In-memory filesystem for WASM/browser (see `virtual_filesystem`)
 //! - Custom implementations for remote agents, network filesystems, etc.
 //!
 //! The trait is synchronous. For async UI operations (like the file explorer),
@@ -328,6 +328,25 @@ pub trait FileSystem: Send + Sync {
         Ok(data.iter().filter(|&&b| b == b'\n').count())
     }
 
+    /// Count `\n` bytes in a file range and sample every `stride`-th line start.
+    ///
+    /// Used by the line scanner to build the line index of large files.
+    /// Returns the count and `(offset in range, line feeds before it)` for
+    /// each sample. Implementations that count without reading the range may
+    /// return no samples.
+    ///
+    /// The default implementation reads the range and samples it locally.
+    fn sample_line_starts_in_range(
+        &self,
+        path: &Path,
+        offset: u64,
+        len: usize,
+        stride: usize,
+    ) -> io::Result<(usize, Vec<(usize, usize)>)> {
+        let data = self.read_range(path, offset, len)?;
+        Ok(crate::model::line_index::sample_line_starts(&data, stride))
+    }
+
     /// Write data to file atomically (temp file + rename)
     fn write_file(&self, path: &Path, data: &[u8]) -> io::Result<()>;
 
@@ -467,6 +486,29 @@ pub trait FileSystem: Send + Sync {
         }
     }
 
+    /// Check if the current user can write to an existing file
+    ///
+    /// On Unix the owner needs the owner write bit and everyone else a group
+    /// or other write bit (group membership isn't checked). Root can write
+    /// anything. Missing files count as writable.
+    fn is_writable(&self, path: &Path) -> bool {
+        let Ok(meta) = self.metadata(path) else {
+            return true;
+        };
+        #[cfg(unix)]
+        {
+            let uid = self.current_uid();
+            if uid == 0 {
+                return true;
+            }
+            if let (Some(owner), Some(permissions)) = (meta.uid, &meta.permissions) {
+                let mask = if owner == uid { 0o200 } else { 0o022 };
+                return permissions.mode() & mask != 0;
+            }
+        }
+        !meta.is_readonly
+    }
+
     /// Get a temporary file path for atomic writes
     fn temp_path_for(&self, path: &Path) -> PathBuf {
         path.with_extension("tmp")
@@ -82,6 +82,99 @@ pub fn diff_lines(saved: &[u8], current: &[u8]) -> LineDiff {
     }
 }
 
+/// Above this many line pairs the LCS table gets too big; the differing middle
+/// of the texts is then replaced as a whole.
+const MAX_EDIT_DIFF_CELLS: usize = 4_000_000;
+
+/// Compute the edits that turn `old` into `new`, as byte ranges of `old` and
+/// their replacement text, in ascending order.
+///
+/// Unchanged lines are left out, and each edit is trimmed to the characters
+/// that actually differ, so applying them moves as little text as possible.
+/// This keeps cursors, markers and folds outside the edits in place when a
+/// formatter rewrites a buffer.
+pub fn line_edits(old: &str, new: &str) -> Vec<(Range<usize>, String)> {
+    if old == new {
+        return vec![];
+    }
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+
+    // Byte offset of each old line, plus the end of the text
+    let mut old_offsets = Vec::with_capacity(old_lines.len() + 1);
+    let mut offset = 0;
+    for line in &old_lines {
+        old_offsets.push(offset);
+        offset += line.len();
+    }
+    old_offsets.push(offset);
+
+    let prefix = old_lines
+        .iter()
+        .zip(&new_lines)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old_lines[prefix..]
+        .iter()
+        .rev()
+        .zip(new_lines[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle: Vec<&[u8]> = old_lines[prefix..old_lines.len() - suffix]
+        .iter()
+        .map(|line| line.as_bytes())
+        .collect();
+    let new_middle: Vec<&[u8]> = new_lines[prefix..new_lines.len() - suffix]
+        .iter()
+        .map(|line| line.as_bytes())
+        .collect();
+
+    let matches = if old_middle.len().saturating_mul(new_middle.len()) <= MAX_EDIT_DIFF_CELLS {
+        longest_common_subsequence(&old_middle, &new_middle)
+    } else {
+        vec![]
+    };
+
+    let mut edits = Vec::new();
+    let (mut old_idx, mut new_idx) = (0, 0);
+    let end = LineMatch {
+        saved_idx: old_middle.len(),
+        current_idx: new_middle.len(),
+    };
+    for m in matches.iter().chain(std::iter::once(&end)) {
+        if m.saved_idx > old_idx || m.current_idx > new_idx {
+            let range = old_offsets[prefix + old_idx]..old_offsets[prefix + m.saved_idx];
+            let text = new_lines[prefix + new_idx..prefix + m.current_idx].concat();
+            edits.push(trim_edit(old, range, text));
+        }
+        old_idx = m.saved_idx + 1;
+        new_idx = m.current_idx + 1;
+    }
+    edits
+}
+
+/// Shrink an edit to the part between its common prefix and suffix
+fn trim_edit(old: &str, range: Range<usize>, text: String) -> (Range<usize>, String) {
+    let replaced = &old[range.clone()];
+    let prefix: usize = replaced
+        .chars()
+        .zip(text.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum();
+    let suffix: usize = replaced[prefix..]
+        .chars()
+        .rev()
+        .zip(text[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum();
+    (
+        range.start + prefix..range.end - suffix,
+        text[prefix..text.len() - suffix].to_string(),
+    )
+}
+
 /// Represents a match between saved and current line indices
 #[derive(Debug, Clone, Copy)]
 struct LineMatch {
@@ -486,6 +579,30 @@ mod tests {
         assert!(!diff.equal);
         assert_eq!(diff.changed_lines, vec![0..1]);
     }
+
+    #[test]
+    fn test_line_edits_touch_only_changed_text() {
+        assert!(line_edits("a\nb\n", "a\nb\n").is_empty());
+
+        // Reindenting one line leaves the others alone
+        let old = "fn main() {\nlet x = 1;\n    x\n}\n";
+        let new = "fn main() {\n    let x = 1;\n    x\n}\n";
+        assert_eq!(line_edits(old, new), vec![(12..12, "    ".to_string())]);
+
+        // Separate hunks stay separate
+        let old = "a\nb\nc\nd\n";
+        let new = "A\nb\nc\nD\n";
+        assert_eq!(
+            line_edits(old, new),
+            vec![(0..1, "A".to_string()), (6..7, "D".to_string())]
+        );
+
+        // Deleted lines
+        assert_eq!(
+            line_edits("a\n\n\nb\n", "a\nb\n"),
+            vec![(2..4, String::new())]
+        );
+    }
 }
 
 #[cfg(test)]
@@ -592,5 +709,20 @@ mod proptests {
                     "Ranges should not overlap: {:?} and {:?}", prev, curr);
             }
         }
+
+        /// Applying the edits back to front turns the old text into the new one
+        #[test]
+        fn line_edits_rebuild_new_text(
+            saved in multiline_string(),
+            current in multiline_string()
+        ) {
+            let old = String::from_utf8(saved).unwrap();
+            let new = String::from_utf8(current).unwrap();
+            let mut text = old.clone();
+            for (range, replacement) in line_edits(&old, &new).into_iter().rev() {
+                text.replace_range(range, &replacement);
+            }
+            prop_assert_eq!(text, new);
+        }
     }
 }
@@ -0,0 +1,319 @@
+//! Sparse index of line starts for large files.
+//!
+//! Large files are not indexed line by line, so converting between byte
+//! offsets and line numbers needs the line feeds counted up to the offset.
+//! The line scanner reads the file from the start and records here a sample
+//! at every [`LINE_INDEX_STRIDE`]-th line start, plus one at each chunk it
+//! scans. A conversion then starts from the nearest sample and reads only the
+//! bytes between it and the target, so it stays cheap whatever the offset.
+//!
+//! The index covers the prefix of the document scanned so far and is usable
+//! while the scan is still running. Edits inside that prefix shift the
+//! samples after them; past its end nothing is known and conversions there
+//! have to be estimated.
+
+/// Lines between two sampled line starts
+pub const LINE_INDEX_STRIDE: usize = 1024;
+
+/// Sampled line starts over the scanned prefix of a document
+#[derive(Debug, Clone)]
+pub struct LineStartIndex {
+    /// `(byte offset, line feeds before it)`, ascending. The start of the
+    /// document, `(0, 0)`, is implied.
+    samples: Vec<(usize, usize)>,
+    /// Bytes from the start of the document covered by the index
+    end: usize,
+    /// Line feeds in the covered bytes
+    line_feeds: usize,
+    /// Whether scanned chunks may still extend the index. An edit past its
+    /// end leaves the chunks still to come out of step with the document.
+    growing: bool,
+}
+
+impl Default for LineStartIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LineStartIndex {
+    pub fn new() -> Self {
+        Self {
+            samples: Vec::new(),
+            end: 0,
+            line_feeds: 0,
+            growing: true,
+        }
+    }
+
+    /// Bytes from the start of the document covered by the index
+    pub fn scanned_bytes(&self) -> usize {
+        self.end
+    }
+
+    /// Line feeds in the covered bytes
+    pub fn scanned_line_feeds(&self) -> usize {
+        self.line_feeds
+    }
+
+    /// Extend the index with a scanned chunk of the document.
+    ///
+    /// `samples` are `(offset in chunk, line feeds in chunk before it)`.
+    /// Returns false, leaving the index alone, unless the chunk starts where
+    /// the index ends.
+    pub fn push_chunk(
+        &mut self,
+        offset: usize,
+        len: usize,
+        line_feeds: usize,
+        samples: &[(usize, usize)],
+    ) -> bool {
+        if !self.growing || offset != self.end {
+            return false;
+        }
+        if offset > 0 {
+            self.samples.push((offset, self.line_feeds));
+        }
+        self.samples.extend(
+            samples
+                .iter()
+                .filter(|&&(at, _)| at > 0 && at < len)
+                .map(|&(at, before)| (offset + at, self.line_feeds + before)),
+        );
+        self.end += len;
+        self.line_feeds += line_feeds;
+        true
+    }
+
+    /// Extend the index with scanned bytes of the document
+    pub fn push_bytes(&mut self, offset: usize, bytes: &[u8]) -> bool {
+        let (line_feeds, samples) = sample_line_starts(bytes, LINE_INDEX_STRIDE);
+        self.push_chunk(offset, bytes.len(), line_feeds, &samples)
+    }
+
+    /// Nearest sample at or before a byte offset, as `(offset, line feeds
+    /// before it)`. `None` past the covered bytes.
+    pub fn sample_for_offset(&self, offset: usize) -> Option<(usize, usize)> {
+        if offset > self.end {
+            return None;
+        }
+        let idx = self.samples.partition_point(|&(at, _)| at <= offset);
+        Some(idx.checked_sub(1).map_or((0, 0), |idx| self.samples[idx]))
+    }
+
+    /// Span of the document holding the start of a line: from the last sample
+    /// with fewer line feeds before it, to the next sample or the end of the
+    /// covered bytes. Returns `(start, line feeds before start, end)`, or
+    /// `None` if the line starts past the covered bytes.
+    pub fn span_for_line(&self, line: usize) -> Option<(usize, usize, usize)> {
+        if line > self.line_feeds {
+            return None;
+        }
+        let idx = self.samples.partition_point(|&(_, before)| before < line);
+        let (start, before) = idx.checked_sub(1).map_or((0, 0), |idx| self.samples[idx]);
+        let end = self.samples.get(idx).map_or(self.end, |&(at, _)| at);
+        Some((start, before, end))
+    }
+
+    /// Follow an edit to the document: `removed` bytes holding
+    /// `removed_line_feeds` line feeds replaced at `offset` by `inserted` bytes
+    /// holding `inserted_line_feeds`. If the line feeds removed aren't known,
+    /// the index is cut back to the edit.
+    pub fn apply_edit(
+        &mut self,
+        offset: usize,
+        removed: usize,
+        removed_line_feeds: Option<usize>,
+        inserted: usize,
+        inserted_line_feeds: usize,
+    ) {
+        if offset >= self.end {
+            self.growing = false;
+            return;
+        }
+        let removed_end = offset + removed;
+        let removed_line_feeds = match removed_line_feeds {
+            Some(count) if removed_end <= self.end => count,
+            _ => {
+                self.truncate(offset);
+                return;
+            }
+        };
+
+        self.samples
+            .retain(|&(at, _)| at <= offset || at >= removed_end);
+        for (at, before) in self.samples.iter_mut().filter(|(at, _)| *at > offset) {
+            *at = *at - removed + inserted;
+            *before = *before - removed_line_feeds + inserted_line_feeds;
+        }
+        self.end = self.end - removed + inserted;
+        self.line_feeds = self.line_feeds - removed_line_feeds + inserted_line_feeds;
+    }
+
+    /// Forget everything past the last sample at or before `offset`
+    pub fn truncate(&mut self, offset: usize) {
+        let (end, line_feeds) = self
+            .sample_for_offset(offset)
+            .unwrap_or((self.end, self.line_feeds));
+        self.samples.retain(|&(at, _)| at < end);
+        self.end = end;
+        self.line_feeds = line_feeds;
+        self.growing = false;
+    }
+
+    /// Forget the whole index; a new scan has to start over
+    pub fn clear(&mut self) {
+        *self = Self::new();
+        self.growing = false;
+    }
+}
+
+/// Count the line feeds in `bytes` and sample every `stride`-th line start.
+///
+/// Returns the count and `(offset, line feeds before it)` for each sample.
+pub fn sample_line_starts(bytes: &[u8], stride: usize) -> (usize, Vec<(usize, usize)>) {
+    let stride = stride.max(1);
+    let mut samples = Vec::new();
+    let mut line_feeds = 0;
+    for (pos, _) in bytes.iter().enumerate().filter(|(_, &b)| b == b'\n') {
+        line_feeds += 1;
+        if line_feeds % stride == 0 {
+            samples.push((pos + 1, line_feeds));
+        }
+    }
+    (line_feeds, samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(count: usize) -> Vec<u8> {
+        (0..count)
+            .flat_map(|i| format!("line {}\n", i).into_bytes())
+            .collect()
+    }
+
+    fn line_starts(bytes: &[u8]) -> Vec<usize> {
+        std::iter::once(0)
+            .chain(
+                bytes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &b)| b == b'\n')
+                    .map(|(i, _)| i + 1),
+            )
+            .collect()
+    }
+
+    /// Line number at an offset, counted from the index's sample
+    fn line_at(index: &LineStartIndex, bytes: &[u8], offset: usize) -> Option<usize> {
+        let (start, before) = index.sample_for_offset(offset)?;
+        Some(before + bytes[start..offset].iter().filter(|&&b| b == b'\n').count())
+    }
+
+    /// Line start found by reading the index's span for the line
+    fn start_of(index: &LineStartIndex, bytes: &[u8], line: usize) -> Option<usize> {
+        let (start, before, end) = index.span_for_line(line)?;
+        if line == before {
+            return Some(start);
+        }
+        bytes[start..end]
+            .iter()
+            .enumerate()
+            .filter(|(_, &b)| b == b'\n')
+            .nth(line - before - 1)
+            .map(|(i, _)| start + i + 1)
+    }
+
+    #[test]
+    fn test_sample_line_starts() {
+        let bytes = lines(10);
+        let (count, samples) = sample_line_starts(&bytes, 4);
+        assert_eq!(count, 10);
+        let starts = line_starts(&bytes);
+        assert_eq!(samples, vec![(starts[4], 4), (starts[8], 8)]);
+    }
+
+    #[test]
+    fn test_conversions_match_a_full_count() {
+        let bytes = lines(5000);
+        let starts = line_starts(&bytes);
+        let mut index = LineStartIndex::new();
+        for (i, chunk) in bytes.chunks(7000).enumerate() {
+            assert!(index.push_bytes(i * 7000, chunk));
+        }
+        assert_eq!(index.scanned_bytes(), bytes.len());
+        assert_eq!(index.scanned_line_feeds(), 5000);
+
+        for line in [0, 1, 1023, 1024, 1025, 2048, 4999, 5000] {
+            assert_eq!(start_of(&index, &bytes, line), Some(starts[line]));
+        }
+        for offset in [0, 1, starts[1024], starts[1024] - 1, bytes.len()] {
+            let expected = bytes[..offset].iter().filter(|&&b| b == b'\n').count();
+            assert_eq!(line_at(&index, &bytes, offset), Some(expected));
+        }
+        assert_eq!(start_of(&index, &bytes, 5001), None);
+        assert_eq!(line_at(&index, &bytes, bytes.len() + 1), None);
+    }
+
+    #[test]
+    fn test_partial_scan_covers_only_its_prefix() {
+        let bytes = lines(3000);
+        let mut index = LineStartIndex::new();
+        assert!(index.push_bytes(0, &bytes[..10_000]));
+        // A chunk that doesn't continue the prefix is ignored
+        assert!(!index.push_bytes(20_000, &bytes[20_000..30_000]));
+
+        assert!(line_at(&index, &bytes, 9_000).is_some());
+        assert_eq!(index.sample_for_offset(10_001), None);
+    }
+
+    #[test]
+    fn test_edits_shift_later_samples() {
+        let mut bytes = lines(4000);
+        let mut index = LineStartIndex::new();
+        index.push_bytes(0, &bytes);
+
+        // Replace a span holding three line feeds with one holding one
+        let at = line_starts(&bytes)[10] + 2;
+        let removed: Vec<u8> = bytes[at..at + 30].to_vec();
+        let removed_lf = removed.iter().filter(|&&b| b == b'\n').count();
+        bytes.splice(at..at + 30, b"xx\nyy".iter().copied());
+        index.apply_edit(at, 30, Some(removed_lf), 5, 1);
+
+        let starts = line_starts(&bytes);
+        assert_eq!(index.scanned_bytes(), bytes.len());
+        assert_eq!(index.scanned_line_feeds(), starts.len() - 1);
+        for line in [5, 11, 1500, 3000, starts.len() - 1] {
+            assert_eq!(start_of(&index, &bytes, line), Some(starts[line]));
+        }
+    }
+
+    #[test]
+    fn test_unknown_edit_truncates() {
+        let bytes = lines(4000);
+        let mut index = LineStartIndex::new();
+        index.push_bytes(0, &bytes);
+        let at = line_starts(&bytes)[2500];
+        index.apply_edit(at, 100, None, 0, 0);
+
+        assert!(index.scanned_bytes() <= at);
+        assert_eq!(
+            line_at(&index, &bytes, 1000),
+            Some(bytes[..1000].iter().filter(|&&b| b == b'\n').count())
+        );
+        // The scan can't pick up again after the cut
+        assert!(!index.push_bytes(index.scanned_bytes(), &bytes[index.scanned_bytes()..]));
+    }
+
+    #[test]
+    fn test_edit_past_the_end_stops_growth() {
+        let bytes = lines(100);
+        let mut index = LineStartIndex::new();
+        index.push_bytes(0, &bytes[..300]);
+        index.apply_edit(500, 0, Some(0), 3, 0);
+        assert!(!index.push_bytes(300, &bytes[300..]));
+        assert_eq!(index.scanned_bytes(), 300);
+    }
+}
@@ -13,7 +13,9 @@ pub mod encoding_heuristics;
 pub mod event;
 pub mod filesystem;
 pub mod line_diff;
+pub mod line_index;
 pub mod marker;
 pub mod marker_tree;
 pub mod piece_tree;
 pub mod piece_tree_diff;
+pub mod virtual_filesystem;
@@ -0,0 +1,612 @@
+//! In-memory filesystem for WASM/browser builds
+//!
+//! `VirtualFileSystem` keeps files and directories in a map behind a mutex, so
+//! buffers and config can be opened and saved where there is no disk. It can
+//! record which paths changed since the last call to
+//! [`VirtualFileSystem::take_changes`], which the browser layer uses to mirror
+//! the files into IndexedDB without a callback per write.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Cursor, Read, Seek, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use super::filesystem::{
+    DirEntry, EntryType, FileMetadata, FilePermissions, FileReader, FileSystem, FileWriter,
+};
+
+/// A file's content and attributes
+#[derive(Debug, Clone, Default)]
+struct VirtualFile {
+    data: Vec<u8>,
+    modified: Option<SystemTime>,
+    readonly: bool,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    files: BTreeMap<PathBuf, VirtualFile>,
+    dirs: BTreeSet<PathBuf>,
+    /// Paths written or removed since the last `take_changes`, when tracking
+    changed: Option<BTreeSet<PathBuf>>,
+    /// Counter for unique temp file names
+    next_temp: u64,
+}
+
+impl State {
+    fn mark_changed(&mut self, path: &Path) {
+        if let Some(changed) = &mut self.changed {
+            changed.insert(path.to_path_buf());
+        }
+    }
+
+    fn parent_exists(&self, path: &Path) -> bool {
+        path.parent()
+            .is_none_or(|parent| self.dirs.contains(parent))
+    }
+
+    fn file_mut(&mut self, path: &Path) -> io::Result<&mut VirtualFile> {
+        self.files.get_mut(path).ok_or_else(|| not_found(path))
+    }
+}
+
+/// In-memory implementation of [`FileSystem`]
+///
+/// Cloning gives another handle to the same files. Relative paths are resolved
+/// against `/`, and `..` components are folded away.
+#[derive(Debug, Clone)]
+pub struct VirtualFileSystem {
+    state: Arc<Mutex<State>>,
+}
+
+/// Modification time for new content; `SystemTime::now` panics on wasm32
+fn now() -> Option<SystemTime> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Some(SystemTime::now())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        None
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{}: no such file or directory", path.display()),
+    )
+}
+
+fn already_exists(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        format!("{}: already exists", path.display()),
+    )
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.starts_with('.'))
+}
+
+/// Absolute, normalized form of a path used as the map key
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::from("/");
+    for component in path.components() {
+        match component {
+            Component::Prefix(prefix) => normalized = PathBuf::from(prefix.as_os_str()),
+            Component::RootDir => normalized.push("/"),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::Normal(name) => normalized.push(name),
+        }
+    }
+    normalized
+}
+
+impl VirtualFileSystem {
+    /// Create an empty filesystem holding only the root directory
+    pub fn new() -> Self {
+        let mut state = State::default();
+        state.dirs.insert(PathBuf::from("/"));
+        Self {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // A panic while holding the lock leaves the maps consistent, so keep going
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Add a file, creating its parent directories. Used to import files
+    /// (e.g. picked through the browser) and to restore persisted ones.
+    pub fn insert_file(&self, path: &Path, data: Vec<u8>) {
+        let path = normalize(path);
+        let mut state = self.lock();
+        let mut dir = path.parent();
+        while let Some(d) = dir {
+            state.dirs.insert(d.to_path_buf());
+            dir = d.parent();
+        }
+        state.files.insert(
+            path.clone(),
+            VirtualFile {
+                data,
+                modified: now(),
+                readonly: false,
+            },
+        );
+        state.mark_changed(&path);
+    }
+
+    /// Paths of all files, sorted
+    pub fn file_paths(&self) -> Vec<PathBuf> {
+        self.lock().files.keys().cloned().collect()
+    }
+
+    /// Start recording which paths change, for [`Self::take_changes`]
+    pub fn track_changes(&self) {
+        let mut state = self.lock();
+        if state.changed.is_none() {
+            state.changed = Some(BTreeSet::new());
+        }
+    }
+
+    /// Paths changed since the last call with their current content, or None
+    /// for files that were removed. Empty unless [`Self::track_changes`] was called.
+    pub fn take_changes(&self) -> Vec<(PathBuf, Option<Vec<u8>>)> {
+        let mut state = self.lock();
+        let Some(changed) = state.changed.as_mut().map(std::mem::take) else {
+            return Vec::new();
+        };
+        changed
+            .into_iter()
+            .map(|path| {
+                let data = state.files.get(&path).map(|f| f.data.clone());
+                (path, data)
+            })
+            .collect()
+    }
+
+    fn writer(&self, path: PathBuf) -> Box<dyn FileWriter> {
+        Box::new(VirtualFileWriter {
+            fs: self.clone(),
+            path,
+        })
+    }
+}
+
+impl Default for VirtualFileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writer appending to a virtual file. Writes land in the map immediately.
+struct VirtualFileWriter {
+    fs: VirtualFileSystem,
+    path: PathBuf,
+}
+
+impl Write for VirtualFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut state = self.fs.lock();
+        let file = state.file_mut(&self.path)?;
+        file.data.extend_from_slice(buf);
+        file.modified = now();
+        state.mark_changed(&self.path);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl FileWriter for VirtualFileWriter {
+    fn sync_all(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reader over a snapshot of a virtual file
+struct VirtualFileReader(Cursor<Vec<u8>>);
+
+impl Read for VirtualFileReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl Seek for VirtualFileReader {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        self.0.seek(pos)
+    }
+}
+
+impl FileReader for VirtualFileReader {}
+
+impl FileSystem for VirtualFileSystem {
+    fn read_file(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let path = normalize(path);
+        let state = self.lock();
+        state
+            .files
+            .get(&path)
+            .map(|f| f.data.clone())
+            .ok_or_else(|| not_found(&path))
+    }
+
+    fn read_range(&self, path: &Path, offset: u64, len: usize) -> io::Result<Vec<u8>> {
+        let path = normalize(path);
+        let state = self.lock();
+        let data = &state.files.get(&path).ok_or_else(|| not_found(&path))?.data;
+        let start = usize::try_from(offset).unwrap_or(usize::MAX);
+        data.get(start..start.saturating_add(len))
+            .map(|range| range.to_vec())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "read past end of file"))
+    }
+
+    fn write_file(&self, path: &Path, data: &[u8]) -> io::Result<()> {
+        let path = normalize(path);
+        let mut state = self.lock();
+        if !state.parent_exists(&path) {
+            return Err(not_found(&path));
+        }
+        let file = state.files.entry(path.clone()).or_default();
+        file.data = data.to_vec();
+        file.modified = now();
+        state.mark_changed(&path);
+        Ok(())
+    }
+
+    fn create_file(&self, path: &Path) -> io::Result<Box<dyn FileWriter>> {
+        self.write_file(path, &[])?;
+        Ok(self.writer(normalize(path)))
+    }
+
+    fn open_file(&self, path: &Path) -> io::Result<Box<dyn FileReader>> {
+        let data = self.read_file(path)?;
+        Ok(Box::new(VirtualFileReader(Cursor::new(data))))
+    }
+
+    fn open_file_for_write(&self, path: &Path) -> io::Result<Box<dyn FileWriter>> {
+        let path = normalize(path);
+        {
+            let mut state = self.lock();
+            state.file_mut(&path)?.data.clear();
+            state.mark_changed(&path);
+        }
+        Ok(self.writer(path))
+    }
+
+    fn open_file_for_append(&self, path: &Path) -> io::Result<Box<dyn FileWriter>> {
+        let path = normalize(path);
+        if !self.lock().files.contains_key(&path) {
+            self.write_file(&path, &[])?;
+        }
+        Ok(self.writer(path))
+    }
+
+    fn set_file_length(&self, path: &Path, len: u64) -> io::Result<()> {
+        let path = normalize(path);
+        let mut state = self.lock();
+        let file = state.file_mut(&path)?;
+        file.data
+            .resize(usize::try_from(len).unwrap_or(usize::MAX), 0);
+        file.modified = now();
+        state.mark_changed(&path);
+        Ok(())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let (from, to) = (normalize(from), normalize(to));
+        let mut state = self.lock();
+        if !state.parent_exists(&to) {
+            return Err(not_found(&to));
+        }
+        if let Some(file) = state.files.remove(&from) {
+            state.files.insert(to.clone(), file);
+            state.mark_changed(&from);
+            state.mark_changed(&to);
+            return Ok(());
+        }
+        if !state.dirs.contains(&from) {
+            return Err(not_found(&from));
+        }
+        // Move the directory and everything below it
+        let moved_dirs: Vec<PathBuf> = state
+            .dirs
+            .iter()
+            .filter(|d| d.starts_with(&from))
+            .cloned()
+            .collect();
+        for dir in moved_dirs {
+            state.dirs.remove(&dir);
+            let suffix = dir.strip_prefix(&from).unwrap_or(Path::new(""));
+            state.dirs.insert(to.join(suffix));
+        }
+        let moved_files: Vec<PathBuf> = state
+            .files
+            .keys()
+            .filter(|p| p.starts_with(&from))
+            .cloned()
+            .collect();
+        for path in moved_files {
+            if let Some(file) = state.files.remove(&path) {
+                let suffix = path.strip_prefix(&from).unwrap_or(Path::new(""));
+                let new_path = to.join(suffix);
+                state.mark_changed(&path);
+                state.mark_changed(&new_path);
+                state.files.insert(new_path, file);
+            }
+        }
+        Ok(())
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64> {
+        let data = self.read_file(from)?;
+        self.write_file(to, &data)?;
+        Ok(data.len() as u64)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        let path = normalize(path);
+        let mut state = self.lock();
+        state.files.remove(&path).ok_or_else(|| not_found(&path))?;
+        state.mark_changed(&path);
+        Ok(())
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        let path = normalize(path);
+        let mut state = self.lock();
+        if !state.dirs.contains(&path) {
+            return Err(not_found(&path));
+        }
+        let has_children = state.files.keys().any(|p| p.parent() == Some(&path))
+            || state.dirs.iter().any(|d| d.parent() == Some(&path));
+        if has_children {
+            return Err(io::Error::other(format!(
+                "{}: directory not empty",
+                path.display()
+            )));
+        }
+        state.dirs.remove(&path);
+        Ok(())
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        let path = normalize(path);
+        let state = self.lock();
+        if let Some(file) = state.files.get(&path) {
+            let mut meta = FileMetadata::new(file.data.len() as u64)
+                .with_hidden(is_hidden(&path))
+                .with_readonly(file.readonly);
+            meta.modified = file.modified;
+            return Ok(meta);
+        }
+        if state.dirs.contains(&path) {
+            return Ok(FileMetadata::new(0).with_hidden(is_hidden(&path)));
+        }
+        Err(not_found(&path))
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.metadata(path)
+    }
+
+    fn is_dir(&self, path: &Path) -> io::Result<bool> {
+        let path = normalize(path);
+        let state = self.lock();
+        if state.dirs.contains(&path) {
+            Ok(true)
+        } else if state.files.contains_key(&path) {
+            Ok(false)
+        } else {
+            Err(not_found(&path))
+        }
+    }
+
+    fn is_file(&self, path: &Path) -> io::Result<bool> {
+        self.is_dir(path).map(|is_dir| !is_dir)
+    }
+
+    fn set_permissions(&self, path: &Path, permissions: &FilePermissions) -> io::Result<()> {
+        let path = normalize(path);
+        let mut state = self.lock();
+        if let Some(file) = state.files.get_mut(&path) {
+            file.readonly = permissions.is_readonly();
+            Ok(())
+        } else if state.dirs.contains(&path) {
+            Ok(())
+        } else {
+            Err(not_found(&path))
+        }
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        let path = normalize(path);
+        let state = self.lock();
+        if !state.dirs.contains(&path) {
+            return Err(not_found(&path));
+        }
+        let entry = |child: &PathBuf, entry_type: EntryType| {
+            let name = child
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            DirEntry::new(child.clone(), name, entry_type)
+        };
+        let dirs = state
+            .dirs
+            .iter()
+            .filter(|d| d.parent() == Some(&path))
+            .map(|d| entry(d, EntryType::Directory));
+        let files = state
+            .files
+            .keys()
+            .filter(|p| p.parent() == Some(&path))
+            .map(|p| entry(p, EntryType::File));
+        Ok(dirs.chain(files).collect())
+    }
+
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        let path = normalize(path);
+        let mut state = self.lock();
+        if state.dirs.contains(&path) || state.files.contains_key(&path) {
+            return Err(already_exists(&path));
+        }
+        if !state.parent_exists(&path) {
+            return Err(not_found(&path));
+        }
+        state.dirs.insert(path);
+        Ok(())
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        let path = normalize(path);
+        let mut state = self.lock();
+        if state.files.contains_key(&path) {
+            return Err(already_exists(&path));
+        }
+        let mut dir = Some(path.as_path());
+        while let Some(d) = dir {
+            state.dirs.insert(d.to_path_buf());
+            dir = d.parent();
+        }
+        Ok(())
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = normalize(path);
+        let state = self.lock();
+        if state.files.contains_key(&path) || state.dirs.contains(&path) {
+            Ok(path)
+        } else {
+            Err(not_found(&path))
+        }
+    }
+
+    fn current_uid(&self) -> u32 {
+        0
+    }
+
+    fn unique_temp_path(&self, dest_path: &Path) -> PathBuf {
+        let mut state = self.lock();
+        state.next_temp += 1;
+        let file_name = dest_path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "fresh-save".to_string());
+        normalize(dest_path).with_file_name(format!("{}-{}.tmp", file_name, state.next_temp))
+    }
+
+    fn home_dir(&self) -> io::Result<PathBuf> {
+        Ok(PathBuf::from("/"))
+    }
+
+    fn sudo_write(
+        &self,
+        _path: &Path,
+        _data: &[u8],
+        _mode: u32,
+        _uid: u32,
+        _gid: u32,
+    ) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "sudo is not available in a virtual filesystem",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_read_and_list() {
+        let fs = VirtualFileSystem::new();
+        fs.create_dir_all(Path::new("/project/src")).unwrap();
+        fs.write_file(Path::new("/project/src/main.rs"), b"fn main() {}")
+            .unwrap();
+
+        assert_eq!(
+            fs.read_file(Path::new("/project/./src/../src/main.rs"))
+                .unwrap(),
+            b"fn main() {}"
+        );
+        assert_eq!(
+            fs.read_range(Path::new("/project/src/main.rs"), 3, 4)
+                .unwrap(),
+            b"main"
+        );
+        assert!(fs.is_dir(Path::new("/project")).unwrap());
+        assert!(fs.is_file(Path::new("/project/src/main.rs")).unwrap());
+
+        let names: Vec<String> = fs
+            .read_dir(Path::new("/project/src"))
+            .unwrap()
+            .into_iter()
+            .map(|e| e.name)
+            .collect();
+        assert_eq!(names, vec!["main.rs"]);
+
+        // Files need an existing parent directory, like on disk
+        assert!(fs.write_file(Path::new("/missing/file.txt"), b"").is_err());
+    }
+
+    #[test]
+    fn test_writers_and_rename() {
+        let fs = VirtualFileSystem::new();
+        {
+            let mut writer = fs.create_file(Path::new("/a.txt")).unwrap();
+            writer.write_all(b"hello").unwrap();
+        }
+        {
+            let mut writer = fs.open_file_for_append(Path::new("/a.txt")).unwrap();
+            writer.write_all(b" world").unwrap();
+        }
+        assert_eq!(fs.read_file(Path::new("/a.txt")).unwrap(), b"hello world");
+
+        fs.create_dir(Path::new("/dir")).unwrap();
+        fs.rename(Path::new("/a.txt"), Path::new("/dir/b.txt"))
+            .unwrap();
+        assert!(!fs.exists(Path::new("/a.txt")));
+
+        fs.rename(Path::new("/dir"), Path::new("/moved")).unwrap();
+        assert_eq!(
+            fs.read_file(Path::new("/moved/b.txt")).unwrap(),
+            b"hello world"
+        );
+        assert!(fs.remove_dir(Path::new("/moved")).is_err());
+        fs.remove_file(Path::new("/moved/b.txt")).unwrap();
+        fs.remove_dir(Path::new("/moved")).unwrap();
+    }
+
+    #[test]
+    fn test_take_changes() {
+        let fs = VirtualFileSystem::new();
+        fs.insert_file(Path::new("/untracked.txt"), b"x".to_vec());
+        assert!(fs.take_changes().is_empty());
+
+        fs.track_changes();
+        fs.insert_file(Path::new("/notes/todo.txt"), b"milk".to_vec());
+        fs.remove_file(Path::new("/untracked.txt")).unwrap();
+        assert_eq!(
+            fs.take_changes(),
+            vec![
+                (PathBuf::from("/notes/todo.txt"), Some(b"milk".to_vec())),
+                (PathBuf::from("/untracked.txt"), None),
+            ]
+        );
+        assert!(fs.take_changes().is_empty());
+    }
+}
@@ -4,10 +4,12 @@
 //! enabling a 4-level overlay architecture (System → User → Project → Session).
 
 use crate::config::{
-    AcceptSuggestionOnEnter, ClipboardConfig, CursorStyle, FileBrowserConfig, FileExplorerConfig,
-    FormatterConfig, HighlighterPreference, Keybinding, KeybindingMapName, KeymapConfig,
-    LanguageConfig, LineEndingOption, OnSaveAction, PluginConfig, TerminalConfig, ThemeName,
-    WarningsConfig,
+    AcceptSuggestionOnEnter, AutoSaveMode, ClipboardConfig, ClipboardProvider, CursorStyle,
+    DiffViewLayout, EditorKeymap, FileBrowserConfig, FileExplorerConfig, FormatterConfig,
+    HighlighterPreference, IndentRulesConfig, Keybinding, KeybindingMapName, KeymapConfig,
+    LanguageConfig, LineEndingOption, LineNumberMode, LinterConfig, OnSaveAction, PluginConfig,
+    RenderWhitespace, StatusBarConfig, StatusBarSegment, TerminalConfig, ThemeName,
+    TrimTrailingWhitespace, WarningsConfig, WordCompletion,
 };
 use crate::types::LspServerConfig;
 use serde::{Deserialize, Serialize};
@@ -76,16 +78,24 @@ where
 pub struct PartialConfig {
     pub version: Option<u32>,
     pub theme: Option<ThemeName>,
+    pub light_theme: Option<ThemeName>,
+    pub dark_theme: Option<ThemeName>,
+    pub syntax_styles: Option<HashMap<String, crate::config::SyntaxStyle>>,
     pub locale: Option<String>,
+    pub highlight_missing_translations: Option<bool>,
     pub check_for_updates: Option<bool>,
+    pub log_level: Option<crate::config::LogLevel>,
     pub editor: Option<PartialEditorConfig>,
     pub file_explorer: Option<PartialFileExplorerConfig>,
     pub file_browser: Option<PartialFileBrowserConfig>,
     pub clipboard: Option<PartialClipboardConfig>,
     pub terminal: Option<PartialTerminalConfig>,
+    pub status_bar: Option<PartialStatusBarConfig>,
     pub keybindings: Option<Vec<Keybinding>>,
     pub keybinding_maps: Option<HashMap<String, KeymapConfig>>,
     pub active_keybinding_map: Option<KeybindingMapName>,
+    pub abbreviations: Option<HashMap<String, String>>,
+    pub tasks: Option<HashMap<String, String>>,
     pub languages: Option<HashMap<String, PartialLanguageConfig>>,
     pub lsp: Option<HashMap<String, LspServerConfig>>,
     pub warnings: Option<PartialWarningsConfig>,
@@ -97,8 +107,13 @@ impl Merge for PartialConfig {
     fn merge_from(&mut self, other: &Self) {
         self.version.merge_from(&other.version);
         self.theme.merge_from(&other.theme);
+        self.light_theme.merge_from(&other.light_theme);
+        self.dark_theme.merge_from(&other.dark_theme);
         self.locale.merge_from(&other.locale);
+        self.highlight_missing_translations
+            .merge_from(&other.highlight_missing_translations);
         self.check_for_updates.merge_from(&other.check_for_updates);
+        self.log_level.merge_from(&other.log_level);
 
         // Nested structs: merge recursively
         merge_partial(&mut self.editor, &other.editor);
@@ -106,6 +121,7 @@ impl Merge for PartialConfig {
         merge_partial(&mut self.file_browser, &other.file_browser);
         merge_partial(&mut self.clipboard, &other.clipboard);
         merge_partial(&mut self.terminal, &other.terminal);
+        merge_partial(&mut self.status_bar, &other.status_bar);
         merge_partial(&mut self.warnings, &other.warnings);
         merge_partial(&mut self.packages, &other.packages);
 
@@ -114,6 +130,9 @@ impl Merge for PartialConfig {
 
         // HashMaps: merge entries, higher precedence wins on key collision
         merge_hashmap(&mut self.keybinding_maps, &other.keybinding_maps);
+        merge_hashmap(&mut self.syntax_styles, &other.syntax_styles);
+        merge_hashmap(&mut self.abbreviations, &other.abbreviations);
+        merge_hashmap(&mut self.tasks, &other.tasks);
         merge_hashmap_recursive(&mut self.languages, &other.languages);
         merge_hashmap_recursive(&mut self.lsp, &other.lsp);
         merge_hashmap_recursive(&mut self.plugins, &other.plugins);
@@ -138,8 +157,12 @@ fn merge_partial<T: Merge + Clone>(target: &mut Option<T>, other: &Option<T>) {
 pub struct PartialEditorConfig {
     pub tab_size: Option<usize>,
     pub auto_indent: Option<bool>,
+    pub auto_close: Option<bool>,
+    pub smart_home: Option<bool>,
+    pub abbreviations_in_code_only: Option<bool>,
+    pub max_line_length: Option<usize>,
     pub line_numbers: Option<bool>,
-    pub relative_line_numbers: Option<bool>,
+    pub line_number_mode: Option<LineNumberMode>,
     pub scroll_offset: Option<usize>,
     pub syntax_highlighting: Option<bool>,
     pub line_wrap: Option<bool>,
@@ -153,33 +176,57 @@ pub struct PartialEditorConfig {
     pub auto_recovery_save_interval_secs: Option<u32>,
     pub auto_save_enabled: Option<bool>,
     pub auto_save_interval_secs: Option<u32>,
+    pub auto_save: Option<AutoSaveMode>,
+    pub auto_save_delay_ms: Option<u64>,
     pub highlight_context_bytes: Option<usize>,
+    pub syntax_highlight_max_column: Option<usize>,
     pub mouse_hover_enabled: Option<bool>,
     pub mouse_hover_delay_ms: Option<u64>,
+    pub mouse_tooltip_delay_ms: Option<u64>,
+    pub mouse_horizontal_scroll_columns: Option<usize>,
     pub double_click_time_ms: Option<u64>,
     pub auto_revert_poll_interval_ms: Option<u64>,
     pub read_concurrency: Option<usize>,
     pub file_tree_poll_interval_ms: Option<u64>,
+    pub file_index: Option<bool>,
     pub default_line_ending: Option<LineEndingOption>,
-    pub trim_trailing_whitespace_on_save: Option<bool>,
+    pub trim_trailing_whitespace_on_save: Option<TrimTrailingWhitespace>,
+    #[serde(alias = "insert_final_newline")]
     pub ensure_final_newline_on_save: Option<bool>,
+    pub readonly_globs: Option<Vec<String>>,
     pub highlight_matching_brackets: Option<bool>,
     pub rainbow_brackets: Option<bool>,
+    pub bracket_pair_colorization: Option<bool>,
     pub cursor_style: Option<CursorStyle>,
     pub keyboard_disambiguate_escape_codes: Option<bool>,
     pub keyboard_report_event_types: Option<bool>,
     pub keyboard_report_alternate_keys: Option<bool>,
     pub keyboard_report_all_keys_as_escape_codes: Option<bool>,
+    pub keymap: Option<EditorKeymap>,
+    pub chord_timeout_ms: Option<u64>,
+    pub session_independent_views: Option<bool>,
     pub quick_suggestions: Option<bool>,
     pub quick_suggestions_delay_ms: Option<u64>,
     pub suggest_on_trigger_characters: Option<bool>,
     pub accept_suggestion_on_enter: Option<AcceptSuggestionOnEnter>,
+    pub word_completion: Option<WordCompletion>,
+    pub word_completion_min_chars: Option<usize>,
     pub show_menu_bar: Option<bool>,
     pub show_tab_bar: Option<bool>,
     pub show_vertical_scrollbar: Option<bool>,
     pub show_horizontal_scrollbar: Option<bool>,
+    pub scroll_past_end: Option<bool>,
+    pub vertical_scroll_offset: Option<usize>,
+    pub horizontal_scroll_offset: Option<usize>,
+    pub cursor_centering: Option<bool>,
     pub use_terminal_bg: Option<bool>,
     pub rulers: Option<Vec<usize>>,
+    pub indent_guides: Option<bool>,
+    pub highlight_current_line: Option<bool>,
+    pub color_swatches: Option<bool>,
+    pub minimap: Option<bool>,
+    pub minimap_min_width: Option<usize>,
+    pub diff_view_layout: Option<DiffViewLayout>,
     pub whitespace_show: Option<bool>,
     pub whitespace_spaces_leading: Option<bool>,
     pub whitespace_spaces_inner: Option<bool>,
@@ -187,15 +234,21 @@ pub struct PartialEditorConfig {
     pub whitespace_tabs_leading: Option<bool>,
     pub whitespace_tabs_inner: Option<bool>,
     pub whitespace_tabs_trailing: Option<bool>,
+    pub render_whitespace: Option<RenderWhitespace>,
+    pub highlight_trailing_whitespace: Option<bool>,
 }
 
 impl Merge for PartialEditorConfig {
     fn merge_from(&mut self, other: &Self) {
         self.tab_size.merge_from(&other.tab_size);
         self.auto_indent.merge_from(&other.auto_indent);
+        self.auto_close.merge_from(&other.auto_close);
+        self.smart_home.merge_from(&other.smart_home);
+        self.abbreviations_in_code_only
+            .merge_from(&other.abbreviations_in_code_only);
+        self.max_line_length.merge_from(&other.max_line_length);
         self.line_numbers.merge_from(&other.line_numbers);
-        self.relative_line_numbers
-            .merge_from(&other.relative_line_numbers);
+        self.line_number_mode.merge_from(&other.line_number_mode);
         self.scroll_offset.merge_from(&other.scroll_offset);
         self.syntax_highlighting
             .merge_from(&other.syntax_highlighting);
@@ -217,12 +270,21 @@ impl Merge for PartialEditorConfig {
         self.auto_save_enabled.merge_from(&other.auto_save_enabled);
         self.auto_save_interval_secs
             .merge_from(&other.auto_save_interval_secs);
+        self.auto_save.merge_from(&other.auto_save);
+        self.auto_save_delay_ms
+            .merge_from(&other.auto_save_delay_ms);
         self.highlight_context_bytes
             .merge_from(&other.highlight_context_bytes);
+        self.syntax_highlight_max_column
+            .merge_from(&other.syntax_highlight_max_column);
         self.mouse_hover_enabled
             .merge_from(&other.mouse_hover_enabled);
         self.mouse_hover_delay_ms
             .merge_from(&other.mouse_hover_delay_ms);
+        self.mouse_tooltip_delay_ms
+            .merge_from(&other.mouse_tooltip_delay_ms);
+        self.mouse_horizontal_scroll_columns
+            .merge_from(&other.mouse_horizontal_scroll_columns);
         self.double_click_time_ms
             .merge_from(&other.double_click_time_ms);
         self.auto_revert_poll_interval_ms
@@ -230,15 +292,19 @@ impl Merge for PartialEditorConfig {
         self.read_concurrency.merge_from(&other.read_concurrency);
         self.file_tree_poll_interval_ms
             .merge_from(&other.file_tree_poll_interval_ms);
+        self.file_index.merge_from(&other.file_index);
         self.default_line_ending
             .merge_from(&other.default_line_ending);
         self.trim_trailing_whitespace_on_save
             .merge_from(&other.trim_trailing_whitespace_on_save);
         self.ensure_final_newline_on_save
             .merge_from(&other.ensure_final_newline_on_save);
+        self.readonly_globs.merge_from(&other.readonly_globs);
         self.highlight_matching_brackets
             .merge_from(&other.highlight_matching_brackets);
         self.rainbow_brackets.merge_from(&other.rainbow_brackets);
+        self.bracket_pair_colorization
+            .merge_from(&other.bracket_pair_colorization);
         self.cursor_style.merge_from(&other.cursor_style);
         self.keyboard_disambiguate_escape_codes
             .merge_from(&other.keyboard_disambiguate_escape_codes);
@@ -248,6 +314,10 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.keyboard_report_alternate_keys);
         self.keyboard_report_all_keys_as_escape_codes
             .merge_from(&other.keyboard_report_all_keys_as_escape_codes);
+        self.keymap.merge_from(&other.keymap);
+        self.chord_timeout_ms.merge_from(&other.chord_timeout_ms);
+        self.session_independent_views
+            .merge_from(&other.session_independent_views);
         self.quick_suggestions.merge_from(&other.quick_suggestions);
         self.quick_suggestions_delay_ms
             .merge_from(&other.quick_suggestions_delay_ms);
@@ -255,14 +325,30 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.suggest_on_trigger_characters);
         self.accept_suggestion_on_enter
             .merge_from(&other.accept_suggestion_on_enter);
+        self.word_completion.merge_from(&other.word_completion);
+        self.word_completion_min_chars
+            .merge_from(&other.word_completion_min_chars);
         self.show_menu_bar.merge_from(&other.show_menu_bar);
         self.show_tab_bar.merge_from(&other.show_tab_bar);
         self.show_vertical_scrollbar
             .merge_from(&other.show_vertical_scrollbar);
         self.show_horizontal_scrollbar
             .merge_from(&other.show_horizontal_scrollbar);
+        self.scroll_past_end.merge_from(&other.scroll_past_end);
+        self.vertical_scroll_offset
+            .merge_from(&other.vertical_scroll_offset);
+        self.horizontal_scroll_offset
+            .merge_from(&other.horizontal_scroll_offset);
+        self.cursor_centering.merge_from(&other.cursor_centering);
         self.use_terminal_bg.merge_from(&other.use_terminal_bg);
         self.rulers.merge_from(&other.rulers);
+        self.indent_guides.merge_from(&other.indent_guides);
+        self.highlight_current_line
+            .merge_from(&other.highlight_current_line);
+        self.color_swatches.merge_from(&other.color_swatches);
+        self.minimap.merge_from(&other.minimap);
+        self.minimap_min_width.merge_from(&other.minimap_min_width);
+        self.diff_view_layout.merge_from(&other.diff_view_layout);
         self.whitespace_show.merge_from(&other.whitespace_show);
         self.whitespace_spaces_leading
             .merge_from(&other.whitespace_spaces_leading);
@@ -276,6 +362,9 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.whitespace_tabs_inner);
         self.whitespace_tabs_trailing
             .merge_from(&other.whitespace_tabs_trailing);
+        self.render_whitespace.merge_from(&other.render_whitespace);
+        self.highlight_trailing_whitespace
+            .merge_from(&other.highlight_trailing_whitespace);
     }
 }
 
@@ -287,6 +376,7 @@ pub struct PartialFileExplorerConfig {
     pub show_hidden: Option<bool>,
     pub show_gitignored: Option<bool>,
     pub custom_ignore_patterns: Option<Vec<String>>,
+    pub follow_active_file: Option<bool>,
     pub width: Option<f32>,
 }
 
@@ -297,6 +387,8 @@ impl Merge for PartialFileExplorerConfig {
         self.show_gitignored.merge_from(&other.show_gitignored);
         self.custom_ignore_patterns
             .merge_from(&other.custom_ignore_patterns);
+        self.follow_active_file
+            .merge_from(&other.follow_active_file);
         self.width.merge_from(&other.width);
     }
 }
@@ -318,15 +410,19 @@ impl Merge for PartialFileBrowserConfig {
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(default)]
 pub struct PartialClipboardConfig {
+    pub provider: Option<ClipboardProvider>,
     pub use_osc52: Option<bool>,
     pub use_system_clipboard: Option<bool>,
+    pub history_size: Option<usize>,
 }
 
 impl Merge for PartialClipboardConfig {
     fn merge_from(&mut self, other: &Self) {
+        self.provider.merge_from(&other.provider);
         self.use_osc52.merge_from(&other.use_osc52);
         self.use_system_clipboard
             .merge_from(&other.use_system_clipboard);
+        self.history_size.merge_from(&other.history_size);
     }
 }
 
@@ -335,12 +431,31 @@ impl Merge for PartialClipboardConfig {
 #[serde(default)]
 pub struct PartialTerminalConfig {
     pub jump_to_end_on_output: Option<bool>,
+    pub link_patterns: Option<Vec<String>>,
 }
 
 impl Merge for PartialTerminalConfig {
     fn merge_from(&mut self, other: &Self) {
         self.jump_to_end_on_output
             .merge_from(&other.jump_to_end_on_output);
+        self.link_patterns.merge_from(&other.link_patterns);
+    }
+}
+
+/// Partial status bar configuration.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PartialStatusBarConfig {
+    pub left: Option<Vec<StatusBarSegment>>,
+    pub center: Option<Vec<StatusBarSegment>>,
+    pub right: Option<Vec<StatusBarSegment>>,
+}
+
+impl Merge for PartialStatusBarConfig {
+    fn merge_from(&mut self, other: &Self) {
+        self.left.merge_from(&other.left);
+        self.center.merge_from(&other.center);
+        self.right.merge_from(&other.right);
     }
 }
 
@@ -396,6 +511,8 @@ pub struct PartialLanguageConfig {
     pub filenames: Option<Vec<String>>,
     pub grammar: Option<String>,
     pub comment_prefix: Option<String>,
+    pub block_comment_start: Option<String>,
+    pub block_comment_end: Option<String>,
     pub auto_indent: Option<bool>,
     pub highlighter: Option<HighlighterPreference>,
     pub textmate_grammar: Option<std::path::PathBuf>,
@@ -405,6 +522,15 @@ pub struct PartialLanguageConfig {
     pub formatter: Option<FormatterConfig>,
     pub format_on_save: Option<bool>,
     pub on_save: Option<Vec<OnSaveAction>>,
+    pub linter: Option<LinterConfig>,
+    pub run_command: Option<String>,
+    pub line_wrap: Option<bool>,
+    pub auto_close_pairs: Option<Vec<String>>,
+    pub indent_rules: Option<IndentRulesConfig>,
+    pub abbreviations: Option<HashMap<String, String>>,
+    pub trim_trailing_whitespace_on_save: Option<TrimTrailingWhitespace>,
+    #[serde(alias = "insert_final_newline")]
+    pub ensure_final_newline_on_save: Option<bool>,
 }
 
 impl Merge for PartialLanguageConfig {
@@ -413,6 +539,9 @@ impl Merge for PartialLanguageConfig {
         self.filenames.merge_from(&other.filenames);
         self.grammar.merge_from(&other.grammar);
         self.comment_prefix.merge_from(&other.comment_prefix);
+        self.block_comment_start
+            .merge_from(&other.block_comment_start);
+        self.block_comment_end.merge_from(&other.block_comment_end);
         self.auto_indent.merge_from(&other.auto_indent);
         self.highlighter.merge_from(&other.highlighter);
         self.textmate_grammar.merge_from(&other.textmate_grammar);
@@ -423,6 +552,16 @@ impl Merge for PartialLanguageConfig {
         self.formatter.merge_from(&other.formatter);
         self.format_on_save.merge_from(&other.format_on_save);
         self.on_save.merge_from(&other.on_save);
+        self.linter.merge_from(&other.linter);
+        self.run_command.merge_from(&other.run_command);
+        self.line_wrap.merge_from(&other.line_wrap);
+        self.auto_close_pairs.merge_from(&other.auto_close_pairs);
+        self.indent_rules.merge_from(&other.indent_rules);
+        merge_hashmap(&mut self.abbreviations, &other.abbreviations);
+        self.trim_trailing_whitespace_on_save
+            .merge_from(&other.trim_trailing_whitespace_on_save);
+        self.ensure_final_newline_on_save
+            .merge_from(&other.ensure_final_newline_on_save);
     }
 }
 
@@ -452,8 +591,12 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
         Self {
             tab_size: Some(cfg.tab_size),
             auto_indent: Some(cfg.auto_indent),
+            auto_close: Some(cfg.auto_close),
+            smart_home: Some(cfg.smart_home),
+            abbreviations_in_code_only: Some(cfg.abbreviations_in_code_only),
+            max_line_length: Some(cfg.max_line_length),
             line_numbers: Some(cfg.line_numbers),
-            relative_line_numbers: Some(cfg.relative_line_numbers),
+            line_number_mode: Some(cfg.line_number_mode),
             scroll_offset: Some(cfg.scroll_offset),
             syntax_highlighting: Some(cfg.syntax_highlighting),
             line_wrap: Some(cfg.line_wrap),
@@ -467,18 +610,26 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             auto_recovery_save_interval_secs: Some(cfg.auto_recovery_save_interval_secs),
             auto_save_enabled: Some(cfg.auto_save_enabled),
             auto_save_interval_secs: Some(cfg.auto_save_interval_secs),
+            auto_save: Some(cfg.auto_save),
+            auto_save_delay_ms: Some(cfg.auto_save_delay_ms),
             highlight_context_bytes: Some(cfg.highlight_context_bytes),
+            syntax_highlight_max_column: Some(cfg.syntax_highlight_max_column),
             mouse_hover_enabled: Some(cfg.mouse_hover_enabled),
             mouse_hover_delay_ms: Some(cfg.mouse_hover_delay_ms),
+            mouse_tooltip_delay_ms: Some(cfg.mouse_tooltip_delay_ms),
+            mouse_horizontal_scroll_columns: Some(cfg.mouse_horizontal_scroll_columns),
             double_click_time_ms: Some(cfg.double_click_time_ms),
             auto_revert_poll_interval_ms: Some(cfg.auto_revert_poll_interval_ms),
             read_concurrency: Some(cfg.read_concurrency),
             file_tree_poll_interval_ms: Some(cfg.file_tree_poll_interval_ms),
+            file_index: Some(cfg.file_index),
             default_line_ending: Some(cfg.default_line_ending.clone()),
             trim_trailing_whitespace_on_save: Some(cfg.trim_trailing_whitespace_on_save),
             ensure_final_newline_on_save: Some(cfg.ensure_final_newline_on_save),
+            readonly_globs: Some(cfg.readonly_globs.clone()),
             highlight_matching_brackets: Some(cfg.highlight_matching_brackets),
             rainbow_brackets: Some(cfg.rainbow_brackets),
+            bracket_pair_colorization: Some(cfg.bracket_pair_colorization),
             cursor_style: Some(cfg.cursor_style),
             keyboard_disambiguate_escape_codes: Some(cfg.keyboard_disambiguate_escape_codes),
             keyboard_report_event_types: Some(cfg.keyboard_report_event_types),
@@ -486,16 +637,31 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             keyboard_report_all_keys_as_escape_codes: Some(
                 cfg.keyboard_report_all_keys_as_escape_codes,
             ),
+            keymap: Some(cfg.keymap),
+            chord_timeout_ms: Some(cfg.chord_timeout_ms),
+            session_independent_views: Some(cfg.session_independent_views),
             quick_suggestions: Some(cfg.quick_suggestions),
             quick_suggestions_delay_ms: Some(cfg.quick_suggestions_delay_ms),
             suggest_on_trigger_characters: Some(cfg.suggest_on_trigger_characters),
             accept_suggestion_on_enter: Some(cfg.accept_suggestion_on_enter),
+            word_completion: Some(cfg.word_completion),
+            word_completion_min_chars: Some(cfg.word_completion_min_chars),
             show_menu_bar: Some(cfg.show_menu_bar),
             show_tab_bar: Some(cfg.show_tab_bar),
             show_vertical_scrollbar: Some(cfg.show_vertical_scrollbar),
             show_horizontal_scrollbar: Some(cfg.show_horizontal_scrollbar),
+            scroll_past_end: Some(cfg.scroll_past_end),
+            vertical_scroll_offset: Some(cfg.vertical_scroll_offset),
+            horizontal_scroll_offset: Some(cfg.horizontal_scroll_offset),
+            cursor_centering: Some(cfg.cursor_centering),
             use_terminal_bg: Some(cfg.use_terminal_bg),
             rulers: Some(cfg.rulers.clone()),
+            indent_guides: Some(cfg.indent_guides),
+            highlight_current_line: Some(cfg.highlight_current_line),
+            color_swatches: Some(cfg.color_swatches),
+            minimap: Some(cfg.minimap),
+            minimap_min_width: Some(cfg.minimap_min_width),
+            diff_view_layout: Some(cfg.diff_view_layout),
             whitespace_show: Some(cfg.whitespace_show),
             whitespace_spaces_leading: Some(cfg.whitespace_spaces_leading),
             whitespace_spaces_inner: Some(cfg.whitespace_spaces_inner),
@@ -503,6 +669,8 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             whitespace_tabs_leading: Some(cfg.whitespace_tabs_leading),
             whitespace_tabs_inner: Some(cfg.whitespace_tabs_inner),
             whitespace_tabs_trailing: Some(cfg.whitespace_tabs_trailing),
+            render_whitespace: Some(cfg.render_whitespace),
+            highlight_trailing_whitespace: Some(cfg.highlight_trailing_whitespace),
         }
     }
 }
@@ -513,10 +681,14 @@ impl PartialEditorConfig {
         crate::config::EditorConfig {
             tab_size: self.tab_size.unwrap_or(defaults.tab_size),
             auto_indent: self.auto_indent.unwrap_or(defaults.auto_indent),
+            auto_close: self.auto_close.unwrap_or(defaults.auto_close),
+            smart_home: self.smart_home.unwrap_or(defaults.smart_home),
+            abbreviations_in_code_only: self
+                .abbreviations_in_code_only
+                .unwrap_or(defaults.abbreviations_in_code_only),
+            max_line_length: self.max_line_length.unwrap_or(defaults.max_line_length),
             line_numbers: self.line_numbers.unwrap_or(defaults.line_numbers),
-            relative_line_numbers: self
-                .relative_line_numbers
-                .unwrap_or(defaults.relative_line_numbers),
+            line_number_mode: self.line_number_mode.unwrap_or(defaults.line_number_mode),
             scroll_offset: self.scroll_offset.unwrap_or(defaults.scroll_offset),
             syntax_highlighting: self
                 .syntax_highlighting
@@ -546,15 +718,28 @@ impl PartialEditorConfig {
             auto_save_interval_secs: self
                 .auto_save_interval_secs
                 .unwrap_or(defaults.auto_save_interval_secs),
+            auto_save: self.auto_save.unwrap_or(defaults.auto_save),
+            auto_save_delay_ms: self
+                .auto_save_delay_ms
+                .unwrap_or(defaults.auto_save_delay_ms),
             highlight_context_bytes: self
                 .highlight_context_bytes
                 .unwrap_or(defaults.highlight_context_bytes),
+            syntax_highlight_max_column: self
+                .syntax_highlight_max_column
+                .unwrap_or(defaults.syntax_highlight_max_column),
             mouse_hover_enabled: self
                 .mouse_hover_enabled
                 .unwrap_or(defaults.mouse_hover_enabled),
             mouse_hover_delay_ms: self
                 .mouse_hover_delay_ms
                 .unwrap_or(defaults.mouse_hover_delay_ms),
+            mouse_tooltip_delay_ms: self
+                .mouse_tooltip_delay_ms
+                .unwrap_or(defaults.mouse_tooltip_delay_ms),
+            mouse_horizontal_scroll_columns: self
+                .mouse_horizontal_scroll_columns
+                .unwrap_or(defaults.mouse_horizontal_scroll_columns),
             double_click_time_ms: self
                 .double_click_time_ms
                 .unwrap_or(defaults.double_click_time_ms),
@@ -565,6 +750,7 @@ impl PartialEditorConfig {
             file_tree_poll_interval_ms: self
                 .file_tree_poll_interval_ms
                 .unwrap_or(defaults.file_tree_poll_interval_ms),
+            file_index: self.file_index.unwrap_or(defaults.file_index),
             default_line_ending: self
                 .default_line_ending
                 .unwrap_or(defaults.default_line_ending.clone()),
@@ -574,10 +760,16 @@ impl PartialEditorConfig {
             ensure_final_newline_on_save: self
                 .ensure_final_newline_on_save
                 .unwrap_or(defaults.ensure_final_newline_on_save),
+            readonly_globs: self
+                .readonly_globs
+                .unwrap_or_else(|| defaults.readonly_globs.clone()),
             highlight_matching_brackets: self
                 .highlight_matching_brackets
                 .unwrap_or(defaults.highlight_matching_brackets),
             rainbow_brackets: self.rainbow_brackets.unwrap_or(defaults.rainbow_brackets),
+            bracket_pair_colorization: self
+                .bracket_pair_colorization
+                .unwrap_or(defaults.bracket_pair_colorization),
             cursor_style: self.cursor_style.unwrap_or(defaults.cursor_style),
             keyboard_disambiguate_escape_codes: self
                 .keyboard_disambiguate_escape_codes
@@ -591,6 +783,11 @@ impl PartialEditorConfig {
             keyboard_report_all_keys_as_escape_codes: self
                 .keyboard_report_all_keys_as_escape_codes
                 .unwrap_or(defaults.keyboard_report_all_keys_as_escape_codes),
+            keymap: self.keymap.unwrap_or(defaults.keymap),
+            chord_timeout_ms: self.chord_timeout_ms.unwrap_or(defaults.chord_timeout_ms),
+            session_independent_views: self
+                .session_independent_views
+                .unwrap_or(defaults.session_independent_views),
             quick_suggestions: self.quick_suggestions.unwrap_or(defaults.quick_suggestions),
             quick_suggestions_delay_ms: self
                 .quick_suggestions_delay_ms
@@ -601,6 +798,10 @@ impl PartialEditorConfig {
             accept_suggestion_on_enter: self
                 .accept_suggestion_on_enter
                 .unwrap_or(defaults.accept_suggestion_on_enter),
+            word_completion: self.word_completion.unwrap_or(defaults.word_completion),
+            word_completion_min_chars: self
+                .word_completion_min_chars
+                .unwrap_or(defaults.word_completion_min_chars),
             show_menu_bar: self.show_menu_bar.unwrap_or(defaults.show_menu_bar),
             show_tab_bar: self.show_tab_bar.unwrap_or(defaults.show_tab_bar),
             show_vertical_scrollbar: self
@@ -609,8 +810,24 @@ impl PartialEditorConfig {
             show_horizontal_scrollbar: self
                 .show_horizontal_scrollbar
                 .unwrap_or(defaults.show_horizontal_scrollbar),
+            scroll_past_end: self.scroll_past_end.unwrap_or(defaults.scroll_past_end),
+            vertical_scroll_offset: self
+                .vertical_scroll_offset
+                .unwrap_or(defaults.vertical_scroll_offset),
+            horizontal_scroll_offset: self
+                .horizontal_scroll_offset
+                .unwrap_or(defaults.horizontal_scroll_offset),
+            cursor_centering: self.cursor_centering.unwrap_or(defaults.cursor_centering),
             use_terminal_bg: self.use_terminal_bg.unwrap_or(defaults.use_terminal_bg),
             rulers: self.rulers.unwrap_or_else(|| defaults.rulers.clone()),
+            indent_guides: self.indent_guides.unwrap_or(defaults.indent_guides),
+            highlight_current_line: self
+                .highlight_current_line
+                .unwrap_or(defaults.highlight_current_line),
+            color_swatches: self.color_swatches.unwrap_or(defaults.color_swatches),
+            minimap: self.minimap.unwrap_or(defaults.minimap),
+            minimap_min_width: self.minimap_min_width.unwrap_or(defaults.minimap_min_width),
+            diff_view_layout: self.diff_view_layout.unwrap_or(defaults.diff_view_layout),
             whitespace_show: self.whitespace_show.unwrap_or(defaults.whitespace_show),
             whitespace_spaces_leading: self
                 .whitespace_spaces_leading
@@ -630,6 +847,10 @@ impl PartialEditorConfig {
             whitespace_tabs_trailing: self
                 .whitespace_tabs_trailing
                 .unwrap_or(defaults.whitespace_tabs_trailing),
+            render_whitespace: self.render_whitespace.unwrap_or(defaults.render_whitespace),
+            highlight_trailing_whitespace: self
+                .highlight_trailing_whitespace
+                .unwrap_or(defaults.highlight_trailing_whitespace),
         }
     }
 }
@@ -641,6 +862,7 @@ impl From<&FileExplorerConfig> for PartialFileExplorerConfig {
             show_hidden: Some(cfg.show_hidden),
             show_gitignored: Some(cfg.show_gitignored),
             custom_ignore_patterns: Some(cfg.custom_ignore_patterns.clone()),
+            follow_active_file: Some(cfg.follow_active_file),
             width: Some(cfg.width),
         }
     }
@@ -655,6 +877,9 @@ impl PartialFileExplorerConfig {
             custom_ignore_patterns: self
                 .custom_ignore_patterns
                 .unwrap_or_else(|| defaults.custom_ignore_patterns.clone()),
+            follow_active_file: self
+                .follow_active_file
+                .unwrap_or(defaults.follow_active_file),
             width: self.width.unwrap_or(defaults.width),
         }
     }
@@ -679,8 +904,10 @@ impl PartialFileBrowserConfig {
 impl From<&ClipboardConfig> for PartialClipboardConfig {
     fn from(cfg: &ClipboardConfig) -> Self {
         Self {
+            provider: Some(cfg.provider),
             use_osc52: Some(cfg.use_osc52),
             use_system_clipboard: Some(cfg.use_system_clipboard),
+            history_size: Some(cfg.history_size),
         }
     }
 }
@@ -688,10 +915,32 @@ impl From<&ClipboardConfig> for PartialClipboardConfig {
 impl PartialClipboardConfig {
     pub fn resolve(self, defaults: &ClipboardConfig) -> ClipboardConfig {
         ClipboardConfig {
+            provider: self.provider.unwrap_or(defaults.provider),
             use_osc52: self.use_osc52.unwrap_or(defaults.use_osc52),
             use_system_clipboard: self
                 .use_system_clipboard
                 .unwrap_or(defaults.use_system_clipboard),
+            history_size: self.history_size.unwrap_or(defaults.history_size),
+        }
+    }
+}
+
+impl From<&StatusBarConfig> for PartialStatusBarConfig {
+    fn from(cfg: &StatusBarConfig) -> Self {
+        Self {
+            left: Some(cfg.left.clone()),
+            center: Some(cfg.center.clone()),
+            right: Some(cfg.right.clone()),
+        }
+    }
+}
+
+impl PartialStatusBarConfig {
+    pub fn resolve(self, defaults: &StatusBarConfig) -> StatusBarConfig {
+        StatusBarConfig {
+            left: self.left.unwrap_or_else(|| defaults.left.clone()),
+            center: self.center.unwrap_or_else(|| defaults.center.clone()),
+            right: self.right.unwrap_or_else(|| defaults.right.clone()),
         }
     }
 }
@@ -700,6 +949,7 @@ impl From<&TerminalConfig> for PartialTerminalConfig {
     fn from(cfg: &TerminalConfig) -> Self {
         Self {
             jump_to_end_on_output: Some(cfg.jump_to_end_on_output),
+            link_patterns: Some(cfg.link_patterns.clone()),
         }
     }
 }
@@ -710,6 +960,9 @@ impl PartialTerminalConfig {
             jump_to_end_on_output: self
                 .jump_to_end_on_output
                 .unwrap_or(defaults.jump_to_end_on_output),
+            link_patterns: self
+                .link_patterns
+                .unwrap_or_else(|| defaults.link_patterns.clone()),
         }
     }
 }
@@ -776,6 +1029,8 @@ impl From<&LanguageConfig> for PartialLanguageConfig {
             filenames: Some(cfg.filenames.clone()),
             grammar: Some(cfg.grammar.clone()),
             comment_prefix: cfg.comment_prefix.clone(),
+            block_comment_start: cfg.block_comment_start.clone(),
+            block_comment_end: cfg.block_comment_end.clone(),
             auto_indent: Some(cfg.auto_indent),
             highlighter: Some(cfg.highlighter),
             textmate_grammar: cfg.textmate_grammar.clone(),
@@ -785,6 +1040,14 @@ impl From<&LanguageConfig> for PartialLanguageConfig {
             formatter: cfg.formatter.clone(),
             format_on_save: Some(cfg.format_on_save),
             on_save: Some(cfg.on_save.clone()),
+            linter: cfg.linter.clone(),
+            run_command: cfg.run_command.clone(),
+            line_wrap: cfg.line_wrap,
+            auto_close_pairs: cfg.auto_close_pairs.clone(),
+            indent_rules: cfg.indent_rules.clone(),
+            abbreviations: cfg.abbreviations.clone(),
+            trim_trailing_whitespace_on_save: cfg.trim_trailing_whitespace_on_save,
+            ensure_final_newline_on_save: cfg.ensure_final_newline_on_save,
         }
     }
 }
@@ -800,6 +1063,12 @@ impl PartialLanguageConfig {
             comment_prefix: self
                 .comment_prefix
                 .or_else(|| defaults.comment_prefix.clone()),
+            block_comment_start: self
+                .block_comment_start
+                .or_else(|| defaults.block_comment_start.clone()),
+            block_comment_end: self
+                .block_comment_end
+                .or_else(|| defaults.block_comment_end.clone()),
             auto_indent: self.auto_indent.unwrap_or(defaults.auto_indent),
             highlighter: self.highlighter.unwrap_or(defaults.highlighter),
             textmate_grammar: self
@@ -813,6 +1082,22 @@ impl PartialLanguageConfig {
             formatter: self.formatter.or_else(|| defaults.formatter.clone()),
             format_on_save: self.format_on_save.unwrap_or(defaults.format_on_save),
             on_save: self.on_save.unwrap_or_else(|| defaults.on_save.clone()),
+            linter: self.linter.or_else(|| defaults.linter.clone()),
+            run_command: self.run_command.or_else(|| defaults.run_command.clone()),
+            line_wrap: self.line_wrap.or(defaults.line_wrap),
+            auto_close_pairs: self
+                .auto_close_pairs
+                .or_else(|| defaults.auto_close_pairs.clone()),
+            indent_rules: self.indent_rules.or_else(|| defaults.indent_rules.clone()),
+            abbreviations: self
+                .abbreviations
+                .or_else(|| defaults.abbreviations.clone()),
+            trim_trailing_whitespace_on_save: self
+                .trim_trailing_whitespace_on_save
+                .or(defaults.trim_trailing_whitespace_on_save),
+            ensure_final_newline_on_save: self
+                .ensure_final_newline_on_save
+                .or(defaults.ensure_final_newline_on_save),
         }
     }
 }
@@ -822,16 +1107,24 @@ impl From<&crate::config::Config> for PartialConfig {
         Self {
             version: Some(cfg.version),
             theme: Some(cfg.theme.clone()),
+            light_theme: Some(cfg.light_theme.clone()),
+            dark_theme: Some(cfg.dark_theme.clone()),
+            syntax_styles: Some(cfg.syntax_styles.clone()),
             locale: cfg.locale.0.clone(),
+            highlight_missing_translations: Some(cfg.highlight_missing_translations),
             check_for_updates: Some(cfg.check_for_updates),
+            log_level: Some(cfg.log_level),
             editor: Some(PartialEditorConfig::from(&cfg.editor)),
             file_explorer: Some(PartialFileExplorerConfig::from(&cfg.file_explorer)),
             file_browser: Some(PartialFileBrowserConfig::from(&cfg.file_browser)),
             clipboard: Some(PartialClipboardConfig::from(&cfg.clipboard)),
             terminal: Some(PartialTerminalConfig::from(&cfg.terminal)),
+            status_bar: Some(PartialStatusBarConfig::from(&cfg.status_bar)),
             keybindings: Some(cfg.keybindings.clone()),
             keybinding_maps: Some(cfg.keybinding_maps.clone()),
             active_keybinding_map: Some(cfg.active_keybinding_map.clone()),
+            abbreviations: Some(cfg.abbreviations.clone()),
+            tasks: Some(cfg.tasks.clone()),
             languages: Some(
                 cfg.languages
                     .iter()
@@ -932,10 +1225,23 @@ impl PartialConfig {
         crate::config::Config {
             version: self.version.unwrap_or(defaults.version),
             theme: self.theme.unwrap_or_else(|| defaults.theme.clone()),
+            light_theme: self
+                .light_theme
+                .unwrap_or_else(|| defaults.light_theme.clone()),
+            dark_theme: self
+                .dark_theme
+                .unwrap_or_else(|| defaults.dark_theme.clone()),
+            syntax_styles: self
+                .syntax_styles
+                .unwrap_or_else(|| defaults.syntax_styles.clone()),
             locale: crate::config::LocaleName::from(
                 self.locale.or_else(|| defaults.locale.0.clone()),
             ),
+            highlight_missing_translations: self
+                .highlight_missing_translations
+                .unwrap_or(defaults.highlight_missing_translations),
             check_for_updates: self.check_for_updates.unwrap_or(defaults.check_for_updates),
+            log_level: self.log_level.unwrap_or(defaults.log_level),
             editor: self
                 .editor
                 .map(|e| e.resolve(&defaults.editor))
@@ -956,6 +1262,10 @@ impl PartialConfig {
                 .terminal
                 .map(|e| e.resolve(&defaults.terminal))
                 .unwrap_or_else(|| defaults.terminal.clone()),
+            status_bar: self
+                .status_bar
+                .map(|e| e.resolve(&defaults.status_bar))
+                .unwrap_or_else(|| defaults.status_bar.clone()),
             keybindings: self
                 .keybindings
                 .unwrap_or_else(|| defaults.keybindings.clone()),
@@ -963,6 +1273,10 @@ impl PartialConfig {
             active_keybinding_map: self
                 .active_keybinding_map
                 .unwrap_or_else(|| defaults.active_keybinding_map.clone()),
+            abbreviations: self
+                .abbreviations
+                .unwrap_or_else(|| defaults.abbreviations.clone()),
+            tasks: self.tasks.unwrap_or_else(|| defaults.tasks.clone()),
             languages,
             lsp,
             warnings: self
@@ -986,6 +1300,8 @@ impl Default for LanguageConfig {
             filenames: Vec::new(),
             grammar: String::new(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: true,
             highlighter: HighlighterPreference::default(),
             textmate_grammar: None,
@@ -995,6 +1311,14 @@ impl Default for LanguageConfig {
             formatter: None,
             format_on_save: false,
             on_save: Vec::new(),
+            linter: None,
+            run_command: None,
+            line_wrap: None,
+            auto_close_pairs: None,
+            indent_rules: None,
+            abbreviations: None,
+            trim_trailing_whitespace_on_save: None,
+            ensure_final_newline_on_save: None,
         }
     }
 }
@@ -0,0 +1,92 @@
+//! Abbreviation lookup
+//!
+//! Abbreviations map a typed word to the text replacing it once a word
+//! boundary is typed (e.g. "teh" to "the"). Tables are looked up in
+//! precedence order. An exact match is used as written; otherwise the word is
+//! matched ignoring case and the expansion follows the case it was typed in:
+//! "Teh" becomes "The" and "TEH" becomes "THE".
+
+use std::collections::HashMap;
+
+/// Expansion of `word` from the first table that has it, or None
+pub fn lookup_abbreviation(tables: &[&HashMap<String, String>], word: &str) -> Option<String> {
+    if word.is_empty() {
+        return None;
+    }
+    if let Some(expansion) = tables.iter().find_map(|table| table.get(word)) {
+        return Some(expansion.clone());
+    }
+    let lower = word.to_lowercase();
+    tables.iter().find_map(|table| {
+        table
+            .iter()
+            .filter(|(abbreviation, _)| abbreviation.to_lowercase() == lower)
+            .min_by(|a, b| a.0.cmp(b.0))
+            .map(|(_, expansion)| match_case(word, expansion))
+    })
+}
+
+/// `expansion` in the case `typed` was written in
+///
+/// All-caps words (with more than one letter) give an all-caps expansion, a
+/// capitalized word gives a capitalized expansion. Anything else is left as
+/// written.
+pub fn match_case(typed: &str, expansion: &str) -> String {
+    let letters: Vec<char> = typed.chars().filter(|c| c.is_alphabetic()).collect();
+    if letters.len() > 1 && letters.iter().all(|c| c.is_uppercase()) {
+        return expansion.to_uppercase();
+    }
+    if letters.first().is_some_and(|c| c.is_uppercase()) {
+        let mut chars = expansion.chars();
+        if let Some(first) = chars.next() {
+            return first.to_uppercase().chain(chars).collect();
+        }
+    }
+    expansion.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_lookup_follows_typed_case() {
+        let global = table(&[("teh", "the"), ("fnmain", "fn main() {}")]);
+        let tables = [&global];
+        assert_eq!(lookup_abbreviation(&tables, "teh").as_deref(), Some("the"));
+        assert_eq!(lookup_abbreviation(&tables, "Teh").as_deref(), Some("The"));
+        assert_eq!(lookup_abbreviation(&tables, "TEH").as_deref(), Some("THE"));
+        assert_eq!(
+            lookup_abbreviation(&tables, "fnmain").as_deref(),
+            Some("fn main() {}")
+        );
+        assert_eq!(lookup_abbreviation(&tables, "the"), None);
+        assert_eq!(lookup_abbreviation(&tables, ""), None);
+    }
+
+    #[test]
+    fn test_lookup_precedence_and_exact_match() {
+        let language = table(&[("pr", "println!")]);
+        let global = table(&[("pr", "print"), ("Gh", "GitHub"), ("gh", "gh cli")]);
+        let tables = [&language, &global];
+        assert_eq!(
+            lookup_abbreviation(&tables, "pr").as_deref(),
+            Some("println!")
+        );
+        assert_eq!(
+            lookup_abbreviation(&tables, "Gh").as_deref(),
+            Some("GitHub")
+        );
+        assert_eq!(
+            lookup_abbreviation(&tables, "gh").as_deref(),
+            Some("gh cli")
+        );
+    }
+}
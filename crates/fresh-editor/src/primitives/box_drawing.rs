@@ -0,0 +1,174 @@
+//! Box-drawing characters and how lines of them join.
+//!
+//! A box-drawing character is described by its arms: the sides of its cell
+//! that its lines reach. A cell drawn into joins every neighbor with an arm
+//! reaching into it, so a horizontal line crossing `│` makes `┼` and one
+//! ending on it makes `┤`. Only light lines are drawn; rounded corners and
+//! half lines are read as arms too, so drawing next to them joins up.
+//!
+//! Text is treated as a grid with one character per cell.
+
+pub const UP: u8 = 1;
+pub const DOWN: u8 = 2;
+pub const LEFT: u8 = 4;
+pub const RIGHT: u8 = 8;
+
+/// Light box-drawing characters with two or more arms
+const GLYPHS: [(char, u8); 11] = [
+    ('─', LEFT | RIGHT),
+    ('│', UP | DOWN),
+    ('┌', DOWN | RIGHT),
+    ('┐', DOWN | LEFT),
+    ('└', UP | RIGHT),
+    ('┘', UP | LEFT),
+    ('├', UP | DOWN | RIGHT),
+    ('┤', UP | DOWN | LEFT),
+    ('┬', DOWN | LEFT | RIGHT),
+    ('┴', UP | LEFT | RIGHT),
+    ('┼', UP | DOWN | LEFT | RIGHT),
+];
+
+/// Arms of a character; 0 if it isn't a box-drawing line
+pub fn arms(ch: char) -> u8 {
+    match ch {
+        '╭' => DOWN | RIGHT,
+        '╮' => DOWN | LEFT,
+        '╰' => UP | RIGHT,
+        '╯' => UP | LEFT,
+        '╴' => LEFT,
+        '╵' => UP,
+        '╶' => RIGHT,
+        '╷' => DOWN,
+        _ => GLYPHS
+            .iter()
+            .find(|(glyph, _)| *glyph == ch)
+            .map_or(0, |(_, arms)| *arms),
+    }
+}
+
+/// Character with the given arms. A single arm is drawn as a whole line.
+pub fn glyph(arms: u8) -> Option<char> {
+    let arms = match arms {
+        // A single arm
+        UP | DOWN => UP | DOWN,
+        LEFT | RIGHT => LEFT | RIGHT,
+        arms => arms,
+    };
+    GLYPHS
+        .iter()
+        .find(|(_, glyph_arms)| *glyph_arms == arms)
+        .map(|(glyph, _)| *glyph)
+}
+
+/// Direction pointing back the other way
+pub fn opposite(direction: u8) -> u8 {
+    match direction {
+        UP => DOWN,
+        DOWN => UP,
+        LEFT => RIGHT,
+        RIGHT => LEFT,
+        _ => 0,
+    }
+}
+
+/// Sides of a cell whose neighbor has an arm reaching into it. Neighbors are
+/// given as `[up, down, left, right]`.
+pub fn arms_from_neighbors(neighbors: [char; 4]) -> u8 {
+    [UP, DOWN, LEFT, RIGHT]
+        .into_iter()
+        .zip(neighbors)
+        .filter(|&(side, neighbor)| arms(neighbor) & opposite(side) != 0)
+        .fold(0, |joined, (side, _)| joined | side)
+}
+
+/// Character on the border of a box `width` cells wide and `height` tall at
+/// `(row, column)` within it, or `None` inside it
+pub fn box_border(row: usize, column: usize, width: usize, height: usize) -> Option<char> {
+    let top = row == 0;
+    let bottom = row + 1 == height;
+    let left = column == 0;
+    let right = column + 1 == width;
+    match (top, bottom, left, right) {
+        (true, _, true, _) => Some('┌'),
+        (true, _, _, true) => Some('┐'),
+        (_, true, true, _) => Some('└'),
+        (_, true, _, true) => Some('┘'),
+        (true, _, _, _) | (_, true, _, _) => Some('─'),
+        (_, _, true, _) | (_, _, _, true) => Some('│'),
+        _ => None,
+    }
+}
+
+/// Character in cell `column` of a line, a space past its end
+pub fn char_at(line: &str, column: usize) -> char {
+    line.chars().nth(column).unwrap_or(' ')
+}
+
+/// Put `ch` in cell `column` of a line, padding it with spaces if it is
+/// shorter
+pub fn set_char(line: &mut String, column: usize, ch: char) {
+    let len = line.chars().count();
+    if column >= len {
+        line.push_str(&" ".repeat(column - len));
+        line.push(ch);
+        return;
+    }
+    let (start, old) = line
+        .char_indices()
+        .nth(column)
+        .expect("column is inside the line");
+    line.replace_range(start..start + old.len_utf8(), ch.encode_utf8(&mut [0; 4]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arms_round_trip() {
+        for (ch, ch_arms) in GLYPHS {
+            assert_eq!(arms(ch), ch_arms);
+            assert_eq!(glyph(ch_arms), Some(ch));
+        }
+        assert_eq!(arms('x'), 0);
+        assert_eq!(glyph(0), None);
+        assert_eq!(glyph(LEFT), Some('─'));
+        assert_eq!(glyph(arms('╭') | UP), Some('├'));
+    }
+
+    #[test]
+    fn test_joins() {
+        // A horizontal line crossing or ending on a vertical one
+        assert_eq!(glyph(arms('│') | LEFT | RIGHT), Some('┼'));
+        assert_eq!(glyph(arms('│') | LEFT), Some('┤'));
+        // Only neighbors reaching into the cell count
+        assert_eq!(arms_from_neighbors(['│', '─', '┐', 'x']), UP);
+        assert_eq!(
+            arms_from_neighbors(['┬', '┴', '├', '┤']),
+            UP | DOWN | LEFT | RIGHT
+        );
+    }
+
+    #[test]
+    fn test_box_border() {
+        let rows: Vec<String> = (0..3)
+            .map(|row| {
+                (0..4)
+                    .map(|column| box_border(row, column, 4, 3).unwrap_or('.'))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(rows, vec!["┌──┐", "│..│", "└──┘"]);
+    }
+
+    #[test]
+    fn test_set_char() {
+        let mut line = "a─c".to_string();
+        set_char(&mut line, 1, '┼');
+        assert_eq!(line, "a┼c");
+        set_char(&mut line, 5, '│');
+        assert_eq!(line, "a┼c  │");
+        assert_eq!(char_at(&line, 1), '┼');
+        assert_eq!(char_at(&line, 9), ' ');
+    }
+}
@@ -0,0 +1,297 @@
+//! Color literal scanning
+//!
+//! Finds CSS-style color literals — `#rgb`, `#rrggbb`, `rgb(r, g, b)` and
+//! `hsl(h, s%, l%)` — in text, so a swatch of the color can be drawn next to
+//! each one. The scanner is a single pass over the bytes that only looks at
+//! `#`, `r` and `h`, cheap enough to run on the visible lines every frame.
+
+use std::ops::Range;
+
+/// Longest `rgb(…)`/`hsl(…)` literal looked at, in bytes
+const MAX_FUNCTION_LEN: usize = 40;
+
+/// How a color literal is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorNotation {
+    /// `#rgb`
+    ShortHex,
+    /// `#rrggbb`
+    Hex,
+    /// `rgb(r, g, b)`
+    Rgb,
+    /// `hsl(h, s%, l%)`
+    Hsl,
+}
+
+impl ColorNotation {
+    /// Names of the three channels, for prompts
+    pub fn channel_names(self) -> &'static str {
+        match self {
+            Self::Hsl => "H S L",
+            _ => "R G B",
+        }
+    }
+
+    /// Largest value of each channel
+    pub fn channel_max(self) -> [u16; 3] {
+        match self {
+            Self::Hsl => [360, 100, 100],
+            _ => [255, 255, 255],
+        }
+    }
+
+    /// The color of channel values written in this notation
+    pub fn to_rgb(self, channels: [u16; 3]) -> [u8; 3] {
+        match self {
+            Self::Hsl => hsl_to_rgb(channels),
+            _ => channels.map(|c| c.min(255) as u8),
+        }
+    }
+
+    /// Channel values written as a literal in this notation. A short hex
+    /// literal is written long when the color has no short form.
+    pub fn format(self, channels: [u16; 3]) -> String {
+        let [a, b, c] = channels;
+        match self {
+            Self::ShortHex if channels.iter().all(|c| c % 17 == 0 && *c <= 255) => {
+                format!("#{:x}{:x}{:x}", a / 17, b / 17, c / 17)
+            }
+            Self::ShortHex | Self::Hex => format!("#{:02x}{:02x}{:02x}", a, b, c),
+            Self::Rgb => format!("rgb({}, {}, {})", a, b, c),
+            Self::Hsl => format!("hsl({}, {}%, {}%)", a, b, c),
+        }
+    }
+}
+
+/// A color literal found in text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorLiteral {
+    /// Byte range of the literal in the scanned text
+    pub range: Range<usize>,
+    pub notation: ColorNotation,
+    /// Channel values as written: red, green and blue from 0 to 255, or hue
+    /// from 0 to 360 and saturation and lightness from 0 to 100
+    pub channels: [u16; 3],
+}
+
+impl ColorLiteral {
+    /// The literal's color
+    pub fn rgb(&self) -> [u8; 3] {
+        self.notation.to_rgb(self.channels)
+    }
+}
+
+/// All color literals in `text`, in order
+pub fn find_color_literals(text: &[u8]) -> Vec<ColorLiteral> {
+    let mut literals = Vec::new();
+    let mut i = 0;
+    while i < text.len() {
+        let literal = match text[i] {
+            b'#' | b'r' | b'R' | b'h' | b'H' if i == 0 || !is_word_byte(text[i - 1]) => {
+                literal_starting_at(text, i)
+            }
+            _ => None,
+        };
+        match literal {
+            Some(literal) => {
+                i = literal.range.end;
+                literals.push(literal);
+            }
+            None => i += 1,
+        }
+    }
+    literals
+}
+
+/// The color literal in `text` that contains byte `offset` or ends there
+pub fn color_literal_at(text: &[u8], offset: usize) -> Option<ColorLiteral> {
+    find_color_literals(text)
+        .into_iter()
+        .find(|literal| literal.range.start <= offset && offset <= literal.range.end)
+}
+
+/// Channel values typed as three numbers separated by spaces or commas,
+/// each clamped to what `notation` allows
+pub fn parse_channels(notation: ColorNotation, input: &str) -> Option<[u16; 3]> {
+    let mut values = input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(|part| part.trim_end_matches('%').parse::<u16>().ok());
+    let mut channels = [0; 3];
+    for channel in &mut channels {
+        *channel = values.next()??;
+    }
+    if values.next().is_some() {
+        return None;
+    }
+    let max = notation.channel_max();
+    Some([0, 1, 2].map(|i| channels[i].min(max[i])))
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'&' || byte == b'-'
+}
+
+fn literal_starting_at(text: &[u8], start: usize) -> Option<ColorLiteral> {
+    if text[start] == b'#' {
+        return hex_literal(text, start);
+    }
+    let name = text.get(start..start + 4)?.to_ascii_lowercase();
+    let notation = match name.as_slice() {
+        b"rgb(" => ColorNotation::Rgb,
+        b"hsl(" => ColorNotation::Hsl,
+        _ => return None,
+    };
+    let args_start = start + 4;
+    let limit = (start + MAX_FUNCTION_LEN).min(text.len());
+    let close = args_start + text[args_start..limit].iter().position(|&b| b == b')')?;
+    let args = std::str::from_utf8(&text[args_start..close]).ok()?;
+    let channels = function_channels(notation, args)?;
+    Some(ColorLiteral {
+        range: start..close + 1,
+        notation,
+        channels,
+    })
+}
+
+fn hex_literal(text: &[u8], start: usize) -> Option<ColorLiteral> {
+    let digits = text[start + 1..]
+        .iter()
+        .take_while(|b| b.is_ascii_hexdigit())
+        .count();
+    let end = start + 1 + digits;
+    if text.get(end).is_some_and(|&b| is_word_byte(b)) {
+        return None;
+    }
+    let hex =
+        |range: Range<usize>| u16::from_str_radix(std::str::from_utf8(&text[range]).ok()?, 16).ok();
+    let (notation, channels) = match digits {
+        3 => {
+            let channel = |i: usize| hex(start + 1 + i..start + 2 + i).map(|v| v * 17);
+            (
+                ColorNotation::ShortHex,
+                [channel(0)?, channel(1)?, channel(2)?],
+            )
+        }
+        6 => {
+            let channel = |i: usize| hex(start + 1 + 2 * i..start + 3 + 2 * i);
+            (ColorNotation::Hex, [channel(0)?, channel(1)?, channel(2)?])
+        }
+        _ => return None,
+    };
+    Some(ColorLiteral {
+        range: start..end,
+        notation,
+        channels,
+    })
+}
+
+/// Channels of the arguments of `rgb(…)` or `hsl(…)`: three numbers
+/// separated by commas or spaces, with `%` on saturation and lightness
+fn function_channels(notation: ColorNotation, args: &str) -> Option<[u16; 3]> {
+    let mut parts = args
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty());
+    let mut channels = [0; 3];
+    for (i, channel) in channels.iter_mut().enumerate() {
+        let part = parts.next()?;
+        let number = match (notation, i) {
+            (ColorNotation::Hsl, 0) => part.strip_suffix("deg").unwrap_or(part),
+            (ColorNotation::Hsl, _) => part.strip_suffix('%')?,
+            _ => part,
+        };
+        let value = number.parse::<f32>().ok()?;
+        if !(0.0..=notation.channel_max()[i] as f32).contains(&value) {
+            return None;
+        }
+        *channel = value.round() as u16;
+    }
+    parts.next().is_none().then_some(channels)
+}
+
+fn hsl_to_rgb([h, s, l]: [u16; 3]) -> [u8; 3] {
+    let h = f32::from(h % 360) / 60.0;
+    let s = f32::from(s.min(100)) / 100.0;
+    let l = f32::from(l.min(100)) / 100.0;
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = l - chroma / 2.0;
+    [r, g, b].map(|v| ((v + m) * 255.0).round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(text: &str) -> Vec<(&str, [u8; 3])> {
+        find_color_literals(text.as_bytes())
+            .into_iter()
+            .map(|literal| (&text[literal.range.clone()], literal.rgb()))
+            .collect()
+    }
+
+    #[test]
+    fn test_find_color_literals() {
+        assert_eq!(
+            found("a { color: #ff8800; border: 1px solid #0f0 }"),
+            vec![("#ff8800", [255, 136, 0]), ("#0f0", [0, 255, 0])]
+        );
+        assert_eq!(
+            found("rgb(10, 20, 30) HSL(120 100% 25%) hsl(0deg,0%,100%)"),
+            vec![
+                ("rgb(10, 20, 30)", [10, 20, 30]),
+                ("HSL(120 100% 25%)", [0, 128, 0]),
+                ("hsl(0deg,0%,100%)", [255, 255, 255]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_color_literals_rejects_lookalikes() {
+        // Wrong digit counts, words, entities, ids and out-of-range channels
+        assert_eq!(
+            found("#ffff #12345678 #abcz &#123; item#abc #fade rgb(300, 0, 0)"),
+            vec![]
+        );
+        assert_eq!(found("argb(1, 2, 3) rgb(1, 2) hsl(10, 20, 30)"), vec![]);
+    }
+
+    #[test]
+    fn test_color_literal_at() {
+        let text = b"x = #123456;";
+        assert_eq!(color_literal_at(text, 4).map(|l| l.range), Some(4..11));
+        assert_eq!(color_literal_at(text, 11).map(|l| l.range), Some(4..11));
+        assert_eq!(color_literal_at(text, 2), None);
+    }
+
+    #[test]
+    fn test_format_and_parse_channels() {
+        assert_eq!(ColorNotation::ShortHex.format([255, 0, 170]), "#f0a");
+        assert_eq!(ColorNotation::ShortHex.format([255, 1, 170]), "#ff01aa");
+        assert_eq!(ColorNotation::Rgb.format([1, 2, 3]), "rgb(1, 2, 3)");
+        assert_eq!(
+            ColorNotation::Hsl.format([200, 50, 40]),
+            "hsl(200, 50%, 40%)"
+        );
+
+        assert_eq!(
+            parse_channels(ColorNotation::Rgb, "1, 2 300"),
+            Some([1, 2, 255])
+        );
+        assert_eq!(
+            parse_channels(ColorNotation::Hsl, "200 50% 40%"),
+            Some([200, 50, 40])
+        );
+        assert_eq!(parse_channels(ColorNotation::Rgb, "1 2"), None);
+        assert_eq!(parse_channels(ColorNotation::Rgb, "1 2 3 4"), None);
+        assert_eq!(parse_channels(ColorNotation::Rgb, "1 x 3"), None);
+    }
+}
@@ -0,0 +1,255 @@
+//! Delimiter-separated files: CSV, TSV and the like.
+//!
+//! Each line is a row and the delimiter splits it into fields. A field
+//! starting with `"` is quoted: delimiters inside it belong to the field and
+//! `""` stands for a quote. A quoted field doesn't run on past the end of
+//! its line.
+
+use std::ops::Range;
+use std::path::Path;
+
+use crate::primitives::display_width::str_width;
+
+/// Columns colored by their index; the ones past it are left plain so very
+/// wide files don't turn into noise
+pub const MAX_COLORED_COLUMNS: usize = 32;
+
+/// Bytes from the start of a file looked at to guess its delimiter
+pub const SNIFF_BYTES: usize = 4096;
+
+/// Non-blank lines looked at to guess the delimiter
+const SNIFF_LINES: usize = 20;
+
+/// Delimiter of a file with a delimited extension, guessed from the first
+/// lines of its text (`head`)
+///
+/// A `.csv` file may be separated by `,`, `;`, tabs or `|`; the one splitting
+/// the most lines into as many fields as the first line wins, with `,` on a
+/// tie. `.tsv` and `.tab` files use tabs and `.psv` files `|`.
+pub fn detect_delimiter(path: &Path, head: &str) -> Option<u8> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    let candidates: &[u8] = match extension.as_str() {
+        "csv" => b",;\t|",
+        "tsv" | "tab" => b"\t",
+        "psv" => b"|",
+        _ => return None,
+    };
+
+    let mut lines: Vec<&str> = head
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(SNIFF_LINES + 1)
+        .collect();
+    // The last line may be cut short when the file is longer than `head`
+    if lines.len() > 1 && (lines.len() > SNIFF_LINES || !head.ends_with('\n')) {
+        lines.pop();
+    }
+
+    let score = |delimiter: u8| {
+        let count = |line: &str| field_ranges(line.as_bytes(), delimiter).len() - 1;
+        let first = lines.first().map_or(0, |&line| count(line));
+        if first == 0 {
+            return (0, 0);
+        }
+        let matching = lines.iter().filter(|&&line| count(line) == first).count();
+        (matching, first)
+    };
+    // `max_by_key` keeps the last of equal scores, so go through them backwards
+    candidates
+        .iter()
+        .rev()
+        .copied()
+        .max_by_key(|&delimiter| score(delimiter))
+}
+
+/// Byte ranges of the fields of a line (without its line ending), quotes
+/// included
+pub fn field_ranges(line: &[u8], delimiter: u8) -> Vec<Range<usize>> {
+    let mut fields = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+    let mut i = 0;
+    while i < line.len() {
+        let byte = line[i];
+        if quoted {
+            if byte == b'"' {
+                if line.get(i + 1) == Some(&b'"') {
+                    i += 1;
+                } else {
+                    quoted = false;
+                }
+            }
+        } else if byte == b'"' && i == start {
+            quoted = true;
+        } else if byte == delimiter {
+            fields.push(start..i);
+            start = i + 1;
+        }
+        i += 1;
+    }
+    fields.push(start..line.len());
+    fields
+}
+
+/// Index of the field `offset` is in; a delimiter counts with the field
+/// before it
+pub fn column_at(line: &[u8], delimiter: u8, offset: usize) -> usize {
+    let fields = field_ranges(line, delimiter);
+    fields
+        .iter()
+        .position(|field| offset <= field.end)
+        .unwrap_or(fields.len() - 1)
+}
+
+/// Text of field `column` of a line, without its quotes and surrounding
+/// spaces, or `None` when the line has fewer fields
+pub fn field_text(line: &[u8], delimiter: u8, column: usize) -> Option<String> {
+    let range = field_ranges(line, delimiter).into_iter().nth(column)?;
+    let text = String::from_utf8_lossy(&line[range]);
+    let text = text.trim();
+    let text = match text.strip_prefix('"') {
+        Some(quoted) => quoted
+            .strip_suffix('"')
+            .unwrap_or(quoted)
+            .replace("\"\"", "\""),
+        None => text.to_string(),
+    };
+    Some(text)
+}
+
+/// Lines of `bytes` with their offsets, without line endings
+fn lines(bytes: &[u8]) -> impl Iterator<Item = (usize, &[u8])> {
+    let mut offset = 0;
+    bytes.split(|&byte| byte == b'\n').map(move |line| {
+        let start = offset;
+        offset += line.len() + 1;
+        (start, line.strip_suffix(b"\r").unwrap_or(line))
+    })
+}
+
+/// Byte ranges of the fields in `bytes`, which start at a line start
+/// `bytes_start` in the buffer, with their column index. Empty fields and
+/// columns from `MAX_COLORED_COLUMNS` on are left out.
+pub fn column_spans(bytes: &[u8], bytes_start: usize, delimiter: u8) -> Vec<(Range<usize>, usize)> {
+    let mut spans = Vec::new();
+    for (line_start, line) in lines(bytes) {
+        let start = bytes_start + line_start;
+        for (column, field) in field_ranges(line, delimiter)
+            .into_iter()
+            .take(MAX_COLORED_COLUMNS)
+            .enumerate()
+        {
+            if !field.is_empty() {
+                spans.push((start + field.start..start + field.end, column));
+            }
+        }
+    }
+    spans
+}
+
+/// Spaces to show before each delimiter in `bytes`, which start at a line
+/// start `bytes_start` in the buffer, so every field is as wide as the
+/// widest in its column. Returns the delimiter offsets and their padding,
+/// in order.
+pub fn column_padding(bytes: &[u8], bytes_start: usize, delimiter: u8) -> Vec<(usize, usize)> {
+    let rows: Vec<(usize, Vec<(usize, usize)>)> = lines(bytes)
+        .map(|(line_start, line)| {
+            let fields = field_ranges(line, delimiter)
+                .into_iter()
+                .map(|field| (field.end, str_width(&String::from_utf8_lossy(&line[field]))))
+                .collect();
+            (bytes_start + line_start, fields)
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = Vec::new();
+    for (_, fields) in &rows {
+        if widths.len() < fields.len() {
+            widths.resize(fields.len(), 0);
+        }
+        for (width, (_, field_width)) in widths.iter_mut().zip(fields) {
+            *width = (*width).max(*field_width);
+        }
+    }
+
+    let mut padding = Vec::new();
+    for (start, fields) in &rows {
+        // The last field of a row has no delimiter after it
+        let delimited = fields.len().saturating_sub(1);
+        for ((end, field_width), width) in fields.iter().take(delimited).zip(&widths) {
+            if width > field_width {
+                padding.push((start + end, width - field_width));
+            }
+        }
+    }
+    padding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_ranges_quoted() {
+        let line = br#"a,"b, c",d"#;
+        assert_eq!(field_ranges(line, b','), vec![0..1, 2..8, 9..10]);
+        // An escaped quote doesn't end the field
+        let line = br#""say ""hi"", ok",2"#;
+        assert_eq!(field_ranges(line, b','), vec![0..16, 17..18]);
+        // A quote inside an unquoted field is just a character
+        let line = br#"5" screen,x"#;
+        assert_eq!(field_ranges(line, b','), vec![0..9, 10..11]);
+        assert_eq!(field_ranges(b"", b','), vec![0..0]);
+    }
+
+    #[test]
+    fn test_column_at_and_field_text() {
+        let line = br#"id,"name, full",age"#;
+        assert_eq!(column_at(line, b',', 0), 0);
+        // The delimiter goes with the field before it
+        assert_eq!(column_at(line, b',', 2), 0);
+        assert_eq!(column_at(line, b',', 3), 1);
+        assert_eq!(column_at(line, b',', 19), 2);
+        assert_eq!(field_text(line, b',', 1).as_deref(), Some("name, full"));
+        assert_eq!(
+            field_text(br#"" a ""b"" ""#, b',', 0).as_deref(),
+            Some(r#" a "b" "#)
+        );
+        assert_eq!(field_text(line, b',', 3), None);
+    }
+
+    #[test]
+    fn test_detect_delimiter() {
+        let csv = Path::new("data.csv");
+        assert_eq!(detect_delimiter(csv, "a,b,c\n1,2,3\n"), Some(b','));
+        assert_eq!(detect_delimiter(csv, "a;b;c\n1,5;2;3\n"), Some(b';'));
+        // A comma inside quotes doesn't count
+        assert_eq!(detect_delimiter(csv, "\"x,y\"\tb\n1\t2\n"), Some(b'\t'));
+        assert_eq!(detect_delimiter(csv, "just text\n"), Some(b','));
+        assert_eq!(
+            detect_delimiter(Path::new("DATA.TSV"), "a,b\n"),
+            Some(b'\t')
+        );
+        assert_eq!(detect_delimiter(Path::new("notes.txt"), "a,b\n"), None);
+    }
+
+    #[test]
+    fn test_column_spans_caps_columns() {
+        let spans = column_spans(b"a,,bc\r\nd", 10, b',');
+        assert_eq!(spans, vec![(10..11, 0), (13..15, 2), (17..18, 0)]);
+
+        let wide = ["x"; MAX_COLORED_COLUMNS + 5].join(",");
+        let spans = column_spans(wide.as_bytes(), 0, b',');
+        assert_eq!(spans.len(), MAX_COLORED_COLUMNS);
+    }
+
+    #[test]
+    fn test_column_padding() {
+        let text = "id,name,x\n10,\"a, b\",y\n3,日本,z";
+        // Columns are 2 and 6 wide; wide characters count double
+        assert_eq!(
+            column_padding(text.as_bytes(), 0, b','),
+            vec![(7, 2), (23, 1), (30, 2)]
+        );
+    }
+}
@@ -5,12 +5,19 @@
 //! All code paths that set or change a buffer's language should go through this module.
 
 use crate::config::LanguageConfig;
+use crate::model::buffer::Buffer;
 use crate::primitives::highlight_engine::HighlightEngine;
 use crate::primitives::highlighter::Language;
 use crate::primitives::GrammarRegistry;
 use std::collections::HashMap;
 use std::path::Path;
 
+/// Lines at the start and at the end of a file searched for a modeline
+const MODELINE_LINES: usize = 5;
+
+/// Bytes read from each end of a file for content-based detection
+const CONTENT_SNIFF_BYTES: usize = 4096;
+
 /// The result of language detection — groups the three things that must stay in sync
 /// on an `EditorState`: the language name, the highlighting engine, and the
 /// tree-sitter `Language` (used for reference highlighting, indentation, etc.).
@@ -55,6 +62,105 @@ impl DetectedLanguage {
         }
     }
 
+    /// Detect language from a file path and the file's content.
+    ///
+    /// A Vim or Emacs modeline in the first or last lines names the language
+    /// outright and wins over the path. When the path says nothing either,
+    /// the first line is checked for a shebang (`#!/usr/bin/env python3`) or
+    /// another marker a grammar recognizes, so extensionless scripts are
+    /// highlighted.
+    pub fn from_path_and_content(
+        path: &Path,
+        buffer: &Buffer,
+        registry: &GrammarRegistry,
+        languages: &HashMap<String, LanguageConfig>,
+    ) -> Self {
+        let (head, tail) = sniff(buffer);
+        if let Some(detected) = Self::from_modeline(&head, &tail, registry, languages) {
+            return detected;
+        }
+
+        let detected = Self::from_path(path, registry, languages);
+        if detected.name != "text" || detected.highlighter.has_highlighting() {
+            return detected;
+        }
+        let first_line = head.lines().next().unwrap_or_default();
+        Self::from_first_line(first_line, registry, languages).unwrap_or(detected)
+    }
+
+    /// Detect language from content alone, for buffers without a file name
+    /// such as text piped to stdin.
+    ///
+    /// Modelines and shebangs are checked as in [`Self::from_path_and_content`];
+    /// failing those, the text is recognized by its shape: unified diffs,
+    /// JSON, XML and HTML.
+    pub fn from_content(
+        buffer: &Buffer,
+        registry: &GrammarRegistry,
+        languages: &HashMap<String, LanguageConfig>,
+    ) -> Self {
+        let (head, tail) = sniff(buffer);
+        if let Some(detected) = Self::from_modeline(&head, &tail, registry, languages) {
+            return detected;
+        }
+        let first_line = head.lines().next().unwrap_or_default();
+        Self::from_first_line(first_line, registry, languages)
+            .or_else(|| {
+                let token = content_language(&head, &tail)?;
+                Self::from_language_token(token, registry, languages)
+            })
+            .unwrap_or_else(Self::plain_text)
+    }
+
+    fn from_modeline(
+        head: &str,
+        tail: &str,
+        registry: &GrammarRegistry,
+        languages: &HashMap<String, LanguageConfig>,
+    ) -> Option<Self> {
+        let token = head
+            .lines()
+            .take(MODELINE_LINES)
+            .chain(tail.lines().rev().take(MODELINE_LINES))
+            .find_map(modeline_language)?;
+        Self::from_language_token(token, registry, languages)
+    }
+
+    /// Language from a shebang, or another first-line marker a grammar
+    /// recognizes
+    fn from_first_line(
+        first_line: &str,
+        registry: &GrammarRegistry,
+        languages: &HashMap<String, LanguageConfig>,
+    ) -> Option<Self> {
+        shebang_interpreter(first_line)
+            .and_then(|interpreter| Self::from_language_token(interpreter, registry, languages))
+            .or_else(|| {
+                let syntax = registry.find_syntax_by_first_line(first_line)?;
+                Self::from_syntax_name(&syntax.name, registry, languages)
+            })
+    }
+
+    /// Language named by a modeline or shebang: a config language ID, or a
+    /// syntax name or extension the grammar registry knows.
+    pub fn from_language_token(
+        token: &str,
+        registry: &GrammarRegistry,
+        languages: &HashMap<String, LanguageConfig>,
+    ) -> Option<Self> {
+        let token = token.to_lowercase();
+        let token = match token.as_str() {
+            "node" | "nodejs" | "deno" | "bun" => "js",
+            "shell" | "shell-script" | "ash" | "dash" | "ksh" => "sh",
+            token => token,
+        };
+        let syntax = languages
+            .get(token)
+            .and_then(|lang_config| registry.find_syntax_by_name(&lang_config.grammar))
+            .or_else(|| registry.syntax_set().find_syntax_by_token(token))?;
+        Self::from_syntax_name(&syntax.name, registry, languages)
+    }
+
     /// Detect language from a file path using only built-in rules (no user config).
     ///
     /// Used by `from_file()` (the legacy constructor) and for virtual buffer names
@@ -124,6 +230,107 @@ impl DetectedLanguage {
     }
 }
 
+/// The first and last [`CONTENT_SNIFF_BYTES`] of a buffer
+fn sniff(buffer: &Buffer) -> (String, String) {
+    let len = buffer.len();
+    let head = buffer.slice_bytes(0..len.min(CONTENT_SNIFF_BYTES));
+    let tail = buffer.slice_bytes(len.saturating_sub(CONTENT_SNIFF_BYTES)..len);
+    (
+        String::from_utf8_lossy(&head).into_owned(),
+        String::from_utf8_lossy(&tail).into_owned(),
+    )
+}
+
+/// Language token for text recognizable by its shape alone: unified diffs
+/// (including `git log -p` and `git show` output), JSON, XML and HTML
+fn content_language(head: &str, tail: &str) -> Option<&'static str> {
+    let lines: Vec<&str> = head.lines().collect();
+    let is_diff = lines.iter().any(|line| line.starts_with("diff --git "))
+        || lines
+            .windows(2)
+            .any(|pair| pair[0].starts_with("--- ") && pair[1].starts_with("+++ "))
+        || lines.first().is_some_and(|line| line.starts_with("@@ "));
+    if is_diff {
+        return Some("diff");
+    }
+
+    let start = head.trim_start();
+    let end = tail.trim_end();
+    let bracketed = (start.starts_with('{') && end.ends_with('}'))
+        || (start.starts_with('[') && end.ends_with(']'));
+    // What follows the bracket must open a JSON value, so log lines like
+    // "[INFO] started]" aren't taken for arrays
+    let first_value = start.get(1..).unwrap_or_default().trim_start();
+    let opens_value = first_value.starts_with(['"', '{', '[', '}', ']', '-'])
+        || first_value.starts_with(|c: char| c.is_ascii_digit())
+        || ["true", "false", "null"]
+            .iter()
+            .any(|word| first_value.starts_with(word));
+    if bracketed && opens_value {
+        return Some("json");
+    }
+    let lower = start.get(..16).unwrap_or(start).to_ascii_lowercase();
+    if lower.starts_with("<!doctype html") || lower.starts_with("<html") {
+        return Some("html");
+    }
+    if lower.starts_with("<?xml") {
+        return Some("xml");
+    }
+    None
+}
+
+/// Language named by a Vim modeline (`vim: set ft=python:`) or an Emacs
+/// one (`-*- mode: python -*-`, `-*- python -*-`)
+fn modeline_language(line: &str) -> Option<&str> {
+    emacs_mode(line).or_else(|| vim_filetype(line))
+}
+
+fn vim_filetype(line: &str) -> Option<&str> {
+    let options_start = ["vim:", "vi:", "ex:"]
+        .iter()
+        .flat_map(|marker| {
+            line.match_indices(marker)
+                .filter(|&(i, _)| i == 0 || line[..i].ends_with(char::is_whitespace))
+                .map(|(i, _)| i + marker.len())
+        })
+        .min()?;
+    line[options_start..]
+        .split(|c: char| c.is_whitespace() || c == ':')
+        .find_map(|option| {
+            ["ft=", "filetype=", "syntax=", "syn="]
+                .iter()
+                .find_map(|name| option.strip_prefix(name))
+        })
+        .filter(|language| !language.is_empty())
+}
+
+fn emacs_mode(line: &str) -> Option<&str> {
+    let (_, rest) = line.split_once("-*-")?;
+    let (variables, _) = rest.split_once("-*-")?;
+    let mode = if variables.contains(':') {
+        variables.split(';').find_map(|variable| {
+            let (name, value) = variable.split_once(':')?;
+            name.trim().eq_ignore_ascii_case("mode").then_some(value)
+        })?
+    } else {
+        variables
+    };
+    Some(mode.trim()).filter(|mode| !mode.is_empty())
+}
+
+/// Interpreter a shebang line runs, without its version:
+/// `#!/usr/bin/env python3` and `#!/usr/bin/python3.11` both give `python`
+fn shebang_interpreter(line: &str) -> Option<&str> {
+    let mut words = line.strip_prefix("#!")?.split_whitespace();
+    let mut program = words.next()?.rsplit('/').next()?;
+    if program == "env" {
+        // Skip env's flags and variable assignments
+        program = words.find(|word| !word.starts_with('-') && !word.contains('='))?;
+    }
+    let name = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+    Some(name).filter(|name| !name.is_empty())
+}
+
 /// Resolve a syntect syntax display name to its canonical config language ID.
 ///
 /// The config `[languages]` section is the single authoritative registry of
@@ -145,3 +352,101 @@ pub fn resolve_language_id(
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::filesystem::StdFileSystem;
+    use crate::primitives::grammar::LocalGrammarLoader;
+    use std::sync::Arc;
+
+    fn detect(path: &str, content: &str) -> DetectedLanguage {
+        let registry = GrammarRegistry::load(&LocalGrammarLoader::embedded_only());
+        let languages = crate::config::Config::default().languages;
+        let buffer = Buffer::from_str(content, 0, Arc::new(StdFileSystem));
+        DetectedLanguage::from_path_and_content(Path::new(path), &buffer, &registry, &languages)
+    }
+
+    #[test]
+    fn test_shebang_interpreter() {
+        assert_eq!(
+            shebang_interpreter("#!/usr/bin/env python3"),
+            Some("python")
+        );
+        assert_eq!(
+            shebang_interpreter("#!/usr/bin/python3.11 -u"),
+            Some("python")
+        );
+        assert_eq!(shebang_interpreter("#!/bin/bash -e"), Some("bash"));
+        assert_eq!(
+            shebang_interpreter("#!/usr/bin/env -S NODE_ENV=dev node"),
+            Some("node")
+        );
+        assert_eq!(shebang_interpreter("# not a shebang"), None);
+        assert_eq!(shebang_interpreter("#!"), None);
+    }
+
+    #[test]
+    fn test_modeline_language() {
+        assert_eq!(modeline_language("# vim: set ft=python :"), Some("python"));
+        assert_eq!(modeline_language("// vim:ts=4:filetype=c:"), Some("c"));
+        assert_eq!(
+            modeline_language("# -*- mode: ruby; coding: utf-8 -*-"),
+            Some("ruby")
+        );
+        assert_eq!(modeline_language(";; -*- sh -*-"), Some("sh"));
+        assert_eq!(modeline_language("index: ft=python"), None);
+        assert_eq!(modeline_language("# vim: set ts=4 :"), None);
+    }
+
+    #[test]
+    fn test_detect_from_content() {
+        // Extensionless scripts are detected from their shebang
+        let detected = detect("deploy", "#!/usr/bin/env bash\necho hi\n");
+        assert_eq!(detected.name, "bash");
+        assert!(detected.highlighter.has_highlighting());
+        assert_eq!(detect("tool", "#!/usr/bin/python3\n").name, "python");
+
+        // A modeline wins over the extension
+        let detected = detect("notes.txt", "x = 1\n\n# vim: ft=python\n");
+        assert_eq!(detected.name, "python");
+
+        // The path still decides when the content says nothing
+        assert_eq!(detect("main.rs", "#!/bin/sh\n").name, "rust");
+        assert_eq!(detect("README", "hello\n").name, "text");
+    }
+
+    #[test]
+    fn test_content_language() {
+        let diff = "diff --git a/x b/x\nindex 1..2\n--- a/x\n+++ b/x\n@@ -1 +1 @@\n-a\n+b\n";
+        assert_eq!(content_language(diff, diff), Some("diff"));
+        let plain_diff = "--- old.txt\n+++ new.txt\n@@ -1 +1 @@\n";
+        assert_eq!(content_language(plain_diff, plain_diff), Some("diff"));
+        let json = "  {\n  \"a\": [1, 2]\n}\n";
+        assert_eq!(content_language(json, json), Some("json"));
+        assert_eq!(content_language("[1, 2]", "[1, 2]"), Some("json"));
+        assert_eq!(content_language("[INFO] up]", "[INFO] up]"), None);
+        assert_eq!(
+            content_language("<!DOCTYPE html>\n<html>", "</html>"),
+            Some("html")
+        );
+        assert_eq!(content_language("<?xml version=\"1.0\"?>", ""), Some("xml"));
+        assert_eq!(content_language("hello\n", "hello\n"), None);
+    }
+
+    #[test]
+    fn test_detect_from_content_alone() {
+        let registry = GrammarRegistry::load(&LocalGrammarLoader::embedded_only());
+        let languages = crate::config::Config::default().languages;
+        let detect = |content: &str| {
+            let buffer = Buffer::from_str(content, 0, Arc::new(StdFileSystem));
+            DetectedLanguage::from_content(&buffer, &registry, &languages)
+        };
+
+        let diff = detect("diff --git a/x b/x\n--- a/x\n+++ b/x\n@@ -1 +1 @@\n-a\n+b\n");
+        assert!(diff.highlighter.has_highlighting());
+        assert!(detect("{\"key\": true}\n").highlighter.has_highlighting());
+        assert_eq!(detect("#!/usr/bin/env python3\n").name, "python");
+        assert_eq!(detect("just some words\n").name, "text");
+    }
+}
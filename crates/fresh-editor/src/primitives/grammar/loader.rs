@@ -520,6 +520,8 @@ mod tests {
                 filenames: vec!["CUSTOMBUILD".to_string()],
                 grammar: "Bourne Again Shell (bash)".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: crate::config::HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -529,6 +531,14 @@ mod tests {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -824,6 +824,8 @@ mod tests {
                 filenames: vec!["*.conf".to_string(), "*rc".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: crate::config::HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -833,6 +835,14 @@ mod tests {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -864,6 +874,8 @@ mod tests {
                 filenames: vec!["/etc/**/rc.*".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: crate::config::HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -873,6 +885,14 @@ mod tests {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -909,6 +929,8 @@ mod tests {
                 filenames: vec!["lfrc".to_string()],
                 grammar: "python".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: crate::config::HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -918,6 +940,14 @@ mod tests {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -929,6 +959,8 @@ mod tests {
                 filenames: vec!["*rc".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: crate::config::HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -938,6 +970,14 @@ mod tests {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
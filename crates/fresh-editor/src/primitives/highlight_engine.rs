@@ -21,11 +21,12 @@ use crate::primitives::grammar::GrammarRegistry;
 use crate::primitives::highlighter::{
     highlight_color, HighlightCategory, HighlightSpan, Highlighter, Language,
 };
-use crate::view::theme::Theme;
+use crate::view::theme::{ScopeStyle, ScopeStyles, Theme};
+use ratatui::style::{Color, Modifier};
 use std::ops::Range;
 use std::path::Path;
 use std::sync::Arc;
-use syntect::parsing::SyntaxSet;
+use syntect::parsing::{ParseState, ScopeStack, SyntaxSet};
 
 /// Map TextMate scope to highlight category
 fn scope_to_category(scope: &str) -> Option<HighlightCategory> {
@@ -213,6 +214,12 @@ pub struct TextMateEngine {
     syntax_index: usize,
     cache: Option<TextMateCache>,
     last_buffer_len: usize,
+    /// Parser states at line starts, sorted by offset, so a viewport can be
+    /// parsed exactly without starting from the top of the buffer
+    checkpoints: Vec<ParseCheckpoint>,
+    /// Checkpoints after this offset were shifted by an edit and are
+    /// re-checked before being resumed from
+    unverified_from: Option<usize>,
     /// Tree-sitter language for non-highlighting features (indentation, semantic highlighting)
     /// Even when using syntect for highlighting, we track the language for other features
     ts_language: Option<Language>,
@@ -222,17 +229,36 @@ pub struct TextMateEngine {
 struct TextMateCache {
     range: Range<usize>,
     spans: Vec<CachedSpan>,
+    /// Style overrides the spans were matched against
+    scope_styles: ScopeStyles,
 }
 
 #[derive(Debug, Clone)]
 struct CachedSpan {
     range: Range<usize>,
-    category: crate::primitives::highlighter::HighlightCategory,
+    category: Option<HighlightCategory>,
+    style: Option<ScopeStyle>,
+}
+
+/// Parser state at the start of a line, from which parsing can resume
+#[derive(Debug, Clone)]
+struct ParseCheckpoint {
+    offset: usize,
+    state: ParseState,
+    scopes: ScopeStack,
 }
 
 /// Maximum bytes to parse in a single operation
 const MAX_PARSE_BYTES: usize = 1024 * 1024;
 
+/// Lines between parse checkpoints
+const CHECKPOINT_INTERVAL_LINES: usize = 128;
+
+/// Furthest a viewport may be from the last good checkpoint for parsing to
+/// resume from it. Beyond that, parsing starts afresh `context_bytes` before
+/// the viewport, which can misjudge constructs opened earlier.
+const MAX_RESUME_BYTES: usize = 256 * 1024;
+
 impl TextMateEngine {
     /// Create a new TextMate engine for the given syntax
     pub fn new(syntax_set: Arc<SyntaxSet>, syntax_index: usize) -> Self {
@@ -241,6 +267,8 @@ impl TextMateEngine {
             syntax_index,
             cache: None,
             last_buffer_len: 0,
+            checkpoints: Vec::new(),
+            unverified_from: None,
             ts_language: None,
         }
     }
@@ -256,6 +284,8 @@ impl TextMateEngine {
             syntax_index,
             cache: None,
             last_buffer_len: 0,
+            checkpoints: Vec::new(),
+            unverified_from: None,
             ts_language,
         }
     }
@@ -267,8 +297,9 @@ impl TextMateEngine {
 
     /// Highlight the visible viewport range
     ///
-    /// `context_bytes` controls how far before/after the viewport to parse for accurate
-    /// highlighting of multi-line constructs (strings, comments, nested blocks).
+    /// Parsing resumes from the nearest checkpoint before the viewport. When
+    /// there is none close enough, `context_bytes` controls how far before the
+    /// viewport to start, and how far after it to parse either way.
     pub fn highlight_viewport(
         &mut self,
         buffer: &Buffer,
@@ -277,13 +308,12 @@ impl TextMateEngine {
         theme: &Theme,
         context_bytes: usize,
     ) -> Vec<HighlightSpan> {
-        use syntect::parsing::{ParseState, ScopeStack};
-
         // Check cache validity
         if let Some(cache) = &self.cache {
             if cache.range.start <= viewport_start
                 && cache.range.end >= viewport_end
                 && self.last_buffer_len == buffer.len()
+                && cache.scope_styles.ptr_eq(&theme.scope_styles)
             {
                 return cache
                     .spans
@@ -291,137 +321,56 @@ impl TextMateEngine {
                     .filter(|span| {
                         span.range.start < viewport_end && span.range.end > viewport_start
                     })
-                    .map(|span| HighlightSpan {
-                        range: span.range.clone(),
-                        color: highlight_color(span.category, theme),
+                    .map(|span| {
+                        let (color, modifiers) = span_style(span.category, span.style, theme);
+                        HighlightSpan {
+                            range: span.range.clone(),
+                            color,
+                            modifiers,
+                        }
                     })
                     .collect();
             }
         }
 
-        // Cache miss - parse viewport region
-        let parse_start = viewport_start.saturating_sub(context_bytes);
+        // Cache miss - parse from the last good checkpoint before the
+        // viewport when it's close enough, otherwise from a fresh state a
+        // little before the viewport
         let parse_end = (viewport_end + context_bytes).min(buffer.len());
+        self.verify_checkpoints(buffer, viewport_start);
+        let checkpoint = self.checkpoint_before(viewport_start);
+        let exact = viewport_start - checkpoint.offset <= MAX_RESUME_BYTES;
+        let origin = if exact {
+            checkpoint
+        } else {
+            self.fresh_state(viewport_start.saturating_sub(context_bytes))
+        };
+        let parse_start = origin.offset;
 
         if parse_end <= parse_start || parse_end - parse_start > MAX_PARSE_BYTES {
             return Vec::new();
         }
 
-        let syntax = &self.syntax_set.syntaxes()[self.syntax_index];
-        let mut state = ParseState::new(syntax);
-        let mut spans = Vec::new();
-
-        // Get content
         let content = buffer.slice_bytes(parse_start..parse_end);
-        let content_str = match std::str::from_utf8(&content) {
-            Ok(s) => s,
-            Err(_) => return Vec::new(),
-        };
-
-        // Parse line by line - manually track line boundaries to handle CRLF correctly
-        // str::lines() strips both \n and \r\n, losing the distinction
-        let content_bytes = content_str.as_bytes();
-        let mut pos = 0;
-        let mut current_offset = parse_start;
-        let mut current_scopes = ScopeStack::new();
-
-        while pos < content_bytes.len() {
-            let line_start = pos;
-            let mut line_end = pos;
-
-            // Scan for line ending (find \n or \r\n or end of content)
-            while line_end < content_bytes.len() {
-                if content_bytes[line_end] == b'\n' {
-                    line_end += 1;
-                    break;
-                } else if content_bytes[line_end] == b'\r' {
-                    if line_end + 1 < content_bytes.len() && content_bytes[line_end + 1] == b'\n' {
-                        line_end += 2; // CRLF
-                    } else {
-                        line_end += 1; // CR only
-                    }
-                    break;
-                }
-                line_end += 1;
-            }
-
-            // Get the line content and actual byte length
-            let line_bytes = &content_bytes[line_start..line_end];
-            let actual_line_byte_len = line_bytes.len();
-
-            // Create line string for syntect - strip CR if present, ensure single \n
-            let line_str = match std::str::from_utf8(line_bytes) {
-                Ok(s) => s,
-                Err(_) => {
-                    pos = line_end;
-                    current_offset += actual_line_byte_len;
-                    continue;
-                }
-            };
-
-            // Remove trailing \r\n or \n, then add single \n for syntect
-            let line_content = line_str.trim_end_matches(&['\r', '\n'][..]);
-            let line_for_syntect = if line_end < content_bytes.len() || line_str.ends_with('\n') {
-                format!("{}\n", line_content)
-            } else {
-                line_content.to_string()
-            };
-
-            let ops = match state.parse_line(&line_for_syntect, &self.syntax_set) {
-                Ok(ops) => ops,
-                Err(_) => {
-                    pos = line_end;
-                    current_offset += actual_line_byte_len;
-                    continue;
-                }
-            };
+        if std::str::from_utf8(&content).is_err() {
+            return Vec::new();
+        }
 
-            // Convert operations to spans
-            // Note: syntect offsets are relative to line_for_syntect, but we need
-            // to map them to the actual buffer positions
-            let mut syntect_offset = 0;
-            let line_content_len = line_content.len();
-
-            for (op_offset, op) in ops {
-                // Handle any text before this operation (but only within content, not newline)
-                let clamped_op_offset = op_offset.min(line_content_len);
-                if clamped_op_offset > syntect_offset {
-                    if let Some(category) = Self::scope_stack_to_category(&current_scopes) {
-                        let byte_start = current_offset + syntect_offset;
-                        let byte_end = current_offset + clamped_op_offset;
-                        if byte_start < byte_end {
-                            spans.push(CachedSpan {
-                                range: byte_start..byte_end,
-                                category,
-                            });
-                        }
-                    }
+        // Only a parse that started exactly can leave checkpoints behind
+        let syntax_set = Arc::clone(&self.syntax_set);
+        let mut lines = 0;
+        let mut spans = parse_lines(
+            &syntax_set,
+            &content,
+            origin,
+            Some(&theme.scope_styles),
+            |offset, state, scopes| {
+                if exact {
+                    self.reach_line_start(offset, state, scopes, &mut lines);
                 }
-                syntect_offset = clamped_op_offset;
-
-                // Scope stack errors are non-fatal for highlighting
-                #[allow(clippy::let_underscore_must_use)]
-                let _ = current_scopes.apply(&op);
-            }
-
-            // Handle remaining text on line (content only, not line ending)
-            if syntect_offset < line_content_len {
-                if let Some(category) = Self::scope_stack_to_category(&current_scopes) {
-                    let byte_start = current_offset + syntect_offset;
-                    let byte_end = current_offset + line_content_len;
-                    if byte_start < byte_end {
-                        spans.push(CachedSpan {
-                            range: byte_start..byte_end,
-                            category,
-                        });
-                    }
-                }
-            }
-
-            // Advance by actual byte length (including real line terminator)
-            pos = line_end;
-            current_offset += actual_line_byte_len;
-        }
+                true
+            },
+        );
 
         // Merge adjacent spans
         Self::merge_adjacent_spans(&mut spans);
@@ -430,6 +379,7 @@ impl TextMateEngine {
         self.cache = Some(TextMateCache {
             range: parse_start..parse_end,
             spans: spans.clone(),
+            scope_styles: theme.scope_styles.clone(),
         });
         self.last_buffer_len = buffer.len();
 
@@ -437,25 +387,39 @@ impl TextMateEngine {
         spans
             .into_iter()
             .filter(|span| span.range.start < viewport_end && span.range.end > viewport_start)
-            .map(|span| HighlightSpan {
-                range: span.range,
-                color: highlight_color(span.category, theme),
+            .map(|span| {
+                let (color, modifiers) = span_style(span.category, span.style, theme);
+                HighlightSpan {
+                    range: span.range,
+                    color,
+                    modifiers,
+                }
             })
             .collect()
     }
 
-    /// Map scope stack to highlight category
-    fn scope_stack_to_category(scopes: &syntect::parsing::ScopeStack) -> Option<HighlightCategory> {
-        for scope in scopes.as_slice().iter().rev() {
-            let scope_str = scope.build_string();
-            if let Some(cat) = scope_to_category(&scope_str) {
-                return Some(cat);
-            }
-        }
-        None
+    /// Highlight categories of the spans overlapping `start..end`
+    ///
+    /// Parses the range the same way as [`Self::highlight_viewport`] when it
+    /// isn't cached.
+    pub fn category_spans(
+        &mut self,
+        buffer: &Buffer,
+        start: usize,
+        end: usize,
+        theme: &Theme,
+        context_bytes: usize,
+    ) -> Vec<(Range<usize>, HighlightCategory)> {
+        self.highlight_viewport(buffer, start, end, theme, context_bytes);
+        self.cache
+            .iter()
+            .flat_map(|cache| cache.spans.iter())
+            .filter(|span| span.range.start < end && span.range.end > start)
+            .filter_map(|span| Some((span.range.clone(), span.category?)))
+            .collect()
     }
 
-    /// Merge adjacent spans with same category
+    /// Merge adjacent spans with same category and style
     fn merge_adjacent_spans(spans: &mut Vec<CachedSpan>) {
         if spans.len() < 2 {
             return;
@@ -464,6 +428,7 @@ impl TextMateEngine {
         let mut write_idx = 0;
         for read_idx in 1..spans.len() {
             if spans[write_idx].category == spans[read_idx].category
+                && spans[write_idx].style == spans[read_idx].style
                 && spans[write_idx].range.end == spans[read_idx].range.start
             {
                 spans[write_idx].range.end = spans[read_idx].range.end;
@@ -477,6 +442,157 @@ impl TextMateEngine {
         spans.truncate(write_idx + 1);
     }
 
+    /// A fresh parser state at `offset`
+    fn fresh_state(&self, offset: usize) -> ParseCheckpoint {
+        ParseCheckpoint {
+            offset,
+            state: ParseState::new(&self.syntax_set.syntaxes()[self.syntax_index]),
+            scopes: ScopeStack::new(),
+        }
+    }
+
+    /// The last checkpoint known good at or before `offset`, or the start of
+    /// the buffer
+    fn checkpoint_before(&self, offset: usize) -> ParseCheckpoint {
+        let limit = offset.min(self.unverified_from.unwrap_or(usize::MAX));
+        self.checkpoints
+            .iter()
+            .rev()
+            .find(|checkpoint| checkpoint.offset <= limit)
+            .cloned()
+            .unwrap_or_else(|| self.fresh_state(0))
+    }
+
+    /// Record the exact parser state at the line start `offset`, `lines`
+    /// lines after the previous checkpoint. Returns true when the state
+    /// matches a checkpoint an edit shifted: the text after it is unchanged,
+    /// so it and every later checkpoint still hold.
+    fn reach_line_start(
+        &mut self,
+        offset: usize,
+        state: &ParseState,
+        scopes: &ScopeStack,
+        lines: &mut usize,
+    ) -> bool {
+        *lines += 1;
+        let mut converged = false;
+        if let Some(unverified_from) = self.unverified_from.filter(|&from| offset > from) {
+            // Shifted checkpoints passed over no longer start a line
+            self.checkpoints.retain(|checkpoint| {
+                checkpoint.offset <= unverified_from || checkpoint.offset >= offset
+            });
+            if let Ok(i) = self
+                .checkpoints
+                .binary_search_by_key(&offset, |checkpoint| checkpoint.offset)
+            {
+                let checkpoint = &mut self.checkpoints[i];
+                if checkpoint.state == *state && checkpoint.scopes == *scopes {
+                    converged = true;
+                } else {
+                    checkpoint.state = state.clone();
+                    checkpoint.scopes = scopes.clone();
+                }
+            }
+            let more = self
+                .checkpoints
+                .last()
+                .is_some_and(|checkpoint| checkpoint.offset > offset);
+            self.unverified_from = (more && !converged).then_some(offset);
+        }
+
+        match self
+            .checkpoints
+            .binary_search_by_key(&offset, |checkpoint| checkpoint.offset)
+        {
+            Ok(_) => *lines = 0,
+            Err(i) if *lines >= CHECKPOINT_INTERVAL_LINES => {
+                self.checkpoints.insert(
+                    i,
+                    ParseCheckpoint {
+                        offset,
+                        state: state.clone(),
+                        scopes: scopes.clone(),
+                    },
+                );
+                *lines = 0;
+            }
+            Err(_) => {}
+        }
+        converged
+    }
+
+    /// Re-check the checkpoints an edit shifted, up to `up_to`, by parsing
+    /// from the last good one. This usually stops at the first shifted
+    /// checkpoint, so an edit far above the viewport costs a few lines of
+    /// parsing rather than everything in between.
+    fn verify_checkpoints(&mut self, buffer: &Buffer, up_to: usize) {
+        let Some(unverified_from) = self.unverified_from else {
+            return;
+        };
+        if unverified_from >= up_to {
+            return;
+        }
+        let origin = self.checkpoint_before(unverified_from);
+        let end = (origin.offset + MAX_RESUME_BYTES).min(buffer.len());
+        let content = buffer.slice_bytes(origin.offset..end);
+        let syntax_set = Arc::clone(&self.syntax_set);
+        let mut lines = 0;
+        let mut stopped = false;
+        parse_lines(
+            &syntax_set,
+            &content,
+            origin,
+            None,
+            |offset, state, scopes| {
+                let converged = self.reach_line_start(offset, state, scopes, &mut lines);
+                stopped = converged || offset >= up_to;
+                !stopped
+            },
+        );
+        if !stopped && end == buffer.len() {
+            // Shifted checkpoints left at the end no longer start a line
+            if let Some(unverified_from) = self.unverified_from.take() {
+                self.checkpoints
+                    .retain(|checkpoint| checkpoint.offset <= unverified_from);
+            }
+        }
+    }
+
+    /// Account for an edit replacing `deleted` bytes at `position` with
+    /// `inserted` bytes. Checkpoints up to the edit stay valid; later ones
+    /// are shifted and re-checked before they are resumed from.
+    pub fn adjust_for_edit(&mut self, position: usize, deleted: usize, inserted: usize) {
+        // Text before the cached range may open or close a construct in it
+        if self
+            .cache
+            .as_ref()
+            .is_some_and(|cache| position <= cache.range.end)
+        {
+            self.cache = None;
+        }
+
+        self.checkpoints.retain_mut(|checkpoint| {
+            if checkpoint.offset <= position {
+                true
+            } else if checkpoint.offset <= position + deleted {
+                false
+            } else {
+                checkpoint.offset = checkpoint.offset - deleted + inserted;
+                true
+            }
+        });
+        if self
+            .checkpoints
+            .last()
+            .is_some_and(|checkpoint| checkpoint.offset > position)
+        {
+            self.unverified_from = Some(
+                self.unverified_from
+                    .map_or(position, |from| from.min(position)),
+            );
+        }
+    }
+
     /// Invalidate cache for edited range
     pub fn invalidate_range(&mut self, edit_range: Range<usize>) {
         if let Some(cache) = &self.cache {
@@ -484,11 +600,15 @@ impl TextMateEngine {
                 self.cache = None;
             }
         }
+        self.checkpoints
+            .retain(|checkpoint| checkpoint.offset <= edit_range.start);
     }
 
     /// Invalidate all cache
     pub fn invalidate_all(&mut self) {
         self.cache = None;
+        self.checkpoints.clear();
+        self.unverified_from = None;
     }
 
     /// Get syntax name
@@ -720,6 +840,42 @@ impl HighlightEngine {
         }
     }
 
+    /// Byte ranges highlighted as strings or comments that overlap
+    /// `start..end`, sorted, with touching ranges merged
+    ///
+    /// Lets syntax-aware features such as bracket matching tell code from
+    /// text. Empty when the buffer has no highlighting.
+    pub fn string_and_comment_ranges(
+        &mut self,
+        buffer: &Buffer,
+        start: usize,
+        end: usize,
+        theme: &Theme,
+        context_bytes: usize,
+    ) -> Vec<Range<usize>> {
+        let mut spans = match self {
+            Self::TreeSitter(h) => h.category_spans(buffer, start, end, theme, context_bytes),
+            Self::TextMate(h) => h.category_spans(buffer, start, end, theme, context_bytes),
+            Self::None => Vec::new(),
+        };
+        spans.sort_by_key(|(range, _)| range.start);
+
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for (range, category) in spans {
+            if !matches!(
+                category,
+                HighlightCategory::String | HighlightCategory::Comment
+            ) {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+        ranges
+    }
+
     /// Invalidate cache for an edited range
     pub fn invalidate_range(&mut self, edit_range: Range<usize>) {
         match self {
@@ -729,6 +885,24 @@ impl HighlightEngine {
         }
     }
 
+    /// Update cached highlighting for `len` bytes inserted at `position`
+    pub fn adjust_for_insert(&mut self, position: usize, len: usize) {
+        match self {
+            Self::TreeSitter(h) => h.invalidate_range(position..position + len),
+            Self::TextMate(h) => h.adjust_for_edit(position, 0, len),
+            Self::None => {}
+        }
+    }
+
+    /// Update cached highlighting for `len` bytes deleted at `position`
+    pub fn adjust_for_delete(&mut self, position: usize, len: usize) {
+        match self {
+            Self::TreeSitter(h) => h.invalidate_range(position..position + len),
+            Self::TextMate(h) => h.adjust_for_edit(position, len, 0),
+            Self::None => {}
+        }
+    }
+
     /// Invalidate entire cache
     pub fn invalidate_all(&mut self) {
         match self {
@@ -783,8 +957,6 @@ pub fn highlight_string(
     registry: &GrammarRegistry,
     theme: &Theme,
 ) -> Vec<HighlightSpan> {
-    use syntect::parsing::{ParseState, ScopeStack};
-
     // Find syntax by language token (handles aliases like "py" -> Python)
     let syntax = match registry.syntax_set().find_syntax_by_token(lang_hint) {
         Some(s) => s,
@@ -824,13 +996,16 @@ pub fn highlight_string(
         for (op_offset, op) in ops {
             let clamped_op_offset = op_offset.min(line_content_len);
             if clamped_op_offset > syntect_offset {
-                if let Some(category) = scope_stack_to_category(&current_scopes) {
+                let (category, style) = scope_stack_style(&current_scopes, &theme.scope_styles);
+                if category.is_some() || style.is_some() {
                     let byte_start = line_start + syntect_offset;
                     let byte_end = line_start + clamped_op_offset;
                     if byte_start < byte_end {
+                        let (color, modifiers) = span_style(category, style, theme);
                         spans.push(HighlightSpan {
                             range: byte_start..byte_end,
-                            color: highlight_color(category, theme),
+                            color,
+                            modifiers,
                         });
                     }
                 }
@@ -843,13 +1018,16 @@ pub fn highlight_string(
 
         // Handle remaining text on line
         if syntect_offset < line_content_len {
-            if let Some(category) = scope_stack_to_category(&current_scopes) {
+            let (category, style) = scope_stack_style(&current_scopes, &theme.scope_styles);
+            if category.is_some() || style.is_some() {
                 let byte_start = line_start + syntect_offset;
                 let byte_end = line_start + line_content_len;
                 if byte_start < byte_end {
+                    let (color, modifiers) = span_style(category, style, theme);
                     spans.push(HighlightSpan {
                         range: byte_start..byte_end,
-                        color: highlight_color(category, theme),
+                        color,
+                        modifiers,
                     });
                 }
             }
@@ -864,15 +1042,154 @@ pub fn highlight_string(
     spans
 }
 
-/// Map scope stack to highlight category (for highlight_string)
-fn scope_stack_to_category(scopes: &syntect::parsing::ScopeStack) -> Option<HighlightCategory> {
+/// Parse `content`, which starts at buffer offset `start.offset`, line by
+/// line from `start`'s state. Spans are collected when `scope_styles` is
+/// given. `at_line_start` sees the offset and state at the start of every
+/// line after the first, and parsing stops when it returns false.
+fn parse_lines(
+    syntax_set: &SyntaxSet,
+    content: &[u8],
+    start: ParseCheckpoint,
+    scope_styles: Option<&ScopeStyles>,
+    mut at_line_start: impl FnMut(usize, &ParseState, &ScopeStack) -> bool,
+) -> Vec<CachedSpan> {
+    let ParseCheckpoint {
+        offset: parse_start,
+        mut state,
+        scopes: mut current_scopes,
+    } = start;
+    let mut spans = Vec::new();
+    let mut push_span = |range: Range<usize>, scopes: &ScopeStack| {
+        let Some(scope_styles) = scope_styles else {
+            return;
+        };
+        let (category, style) = scope_stack_style(scopes, scope_styles);
+        if (category.is_some() || style.is_some()) && range.start < range.end {
+            spans.push(CachedSpan {
+                range,
+                category,
+                style,
+            });
+        }
+    };
+
+    // Parse line by line - manually track line boundaries to handle CRLF correctly
+    // str::lines() strips both \n and \r\n, losing the distinction
+    let mut pos = 0;
+    while pos < content.len() {
+        let current_offset = parse_start + pos;
+        if pos > 0 && !at_line_start(current_offset, &state, &current_scopes) {
+            break;
+        }
+        let line_start = pos;
+        let mut line_end = pos;
+
+        // Scan for line ending (find \n or \r\n or end of content)
+        while line_end < content.len() {
+            if content[line_end] == b'\n' {
+                line_end += 1;
+                break;
+            } else if content[line_end] == b'\r' {
+                if line_end + 1 < content.len() && content[line_end + 1] == b'\n' {
+                    line_end += 2; // CRLF
+                } else {
+                    line_end += 1; // CR only
+                }
+                break;
+            }
+            line_end += 1;
+        }
+        // Advance by actual byte length (including real line terminator)
+        pos = line_end;
+
+        // Create line string for syntect - strip CR if present, ensure single \n
+        let Ok(line_str) = std::str::from_utf8(&content[line_start..line_end]) else {
+            continue;
+        };
+
+        // Remove trailing \r\n or \n, then add single \n for syntect
+        let line_content = line_str.trim_end_matches(&['\r', '\n'][..]);
+        let line_for_syntect = if line_end < content.len() || line_str.ends_with('\n') {
+            format!("{}\n", line_content)
+        } else {
+            line_content.to_string()
+        };
+
+        let Ok(ops) = state.parse_line(&line_for_syntect, syntax_set) else {
+            continue;
+        };
+
+        // Convert operations to spans
+        // Note: syntect offsets are relative to line_for_syntect, but we need
+        // to map them to the actual buffer positions
+        let mut syntect_offset = 0;
+        let line_content_len = line_content.len();
+
+        for (op_offset, op) in ops {
+            // Handle any text before this operation (but only within content, not newline)
+            let clamped_op_offset = op_offset.min(line_content_len);
+            if clamped_op_offset > syntect_offset {
+                push_span(
+                    current_offset + syntect_offset..current_offset + clamped_op_offset,
+                    &current_scopes,
+                );
+            }
+            syntect_offset = clamped_op_offset;
+
+            // Scope stack errors are non-fatal for highlighting
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = current_scopes.apply(&op);
+        }
+
+        // Handle remaining text on line (content only, not line ending)
+        if syntect_offset < line_content_len {
+            push_span(
+                current_offset + syntect_offset..current_offset + line_content_len,
+                &current_scopes,
+            );
+        }
+    }
+    spans
+}
+
+/// Map a scope stack to its highlight category and user style override.
+/// Each comes from the innermost scope that has one.
+fn scope_stack_style(
+    scopes: &syntect::parsing::ScopeStack,
+    overrides: &ScopeStyles,
+) -> (Option<HighlightCategory>, Option<ScopeStyle>) {
+    let mut category = None;
+    let mut style = None;
     for scope in scopes.as_slice().iter().rev() {
         let scope_str = scope.build_string();
-        if let Some(cat) = scope_to_category(&scope_str) {
-            return Some(cat);
+        if category.is_none() {
+            category = scope_to_category(&scope_str);
+        }
+        if style.is_none() && !overrides.is_empty() {
+            style = overrides.get(&scope_str);
+        }
+        if category.is_some() && (style.is_some() || overrides.is_empty()) {
+            break;
         }
     }
-    None
+    (category, style)
+}
+
+/// Resolve a span's color and modifiers. An override's color beats the
+/// category color; overridden text with no category uses the editor color.
+fn span_style(
+    category: Option<HighlightCategory>,
+    style: Option<ScopeStyle>,
+    theme: &Theme,
+) -> (Color, Modifier) {
+    let color = style
+        .and_then(|style| style.fg)
+        .or_else(|| category.map(|category| highlight_color(category, theme)))
+        .unwrap_or(theme.editor_fg);
+    (
+        color,
+        style.map_or(Modifier::empty(), |style| style.modifiers),
+    )
 }
 
 /// Merge adjacent spans with same color
@@ -884,6 +1201,7 @@ fn merge_adjacent_highlight_spans(spans: &mut Vec<HighlightSpan>) {
     let mut write_idx = 0;
     for read_idx in 1..spans.len() {
         if spans[write_idx].color == spans[read_idx].color
+            && spans[write_idx].modifiers == spans[read_idx].modifiers
             && spans[write_idx].range.end == spans[read_idx].range.start
         {
             spans[write_idx].range.end = spans[read_idx].range.end;
@@ -1187,4 +1505,119 @@ mod tests {
             Some(HighlightCategory::Operator)
         );
     }
+
+    fn textmate_for(path: &str) -> TextMateEngine {
+        let registry =
+            GrammarRegistry::load(&crate::primitives::grammar::LocalGrammarLoader::embedded_only());
+        match HighlightEngine::for_file(Path::new(path), &registry) {
+            HighlightEngine::TextMate(tm) => *tm,
+            _ => panic!("Expected TextMate engine for {path}"),
+        }
+    }
+
+    /// 2000 lines of code with a block comment on lines 1500 to 1600
+    fn long_file_with_comment() -> String {
+        (0..2000)
+            .map(|i| match i {
+                1500 => "/* start\n",
+                1600 => "end */\n",
+                _ => "let x = 1;\n",
+            })
+            .collect()
+    }
+
+    fn line_offset(text: &str, line: usize) -> usize {
+        text.split_inclusive('\n').take(line).map(str::len).sum()
+    }
+
+    #[test]
+    fn test_edit_far_above_viewport_keeps_highlighting_below() {
+        let theme = Theme::load_builtin(theme::THEME_LIGHT).unwrap();
+        let content = long_file_with_comment();
+        let mut buffer = Buffer::from_str(&content, 0, test_fs());
+        let mut tm = textmate_for("test.rs");
+
+        // The viewport is inside the comment, far from where it opens
+        let start = line_offset(&content, 1550);
+        let end = line_offset(&content, 1560);
+        let before = tm.category_spans(&buffer, start, end, &theme, 0);
+        assert!(!before.is_empty());
+        assert!(before
+            .iter()
+            .all(|(_, category)| *category == HighlightCategory::Comment));
+        assert!(!tm.checkpoints.is_empty());
+
+        // A line added at the top shifts everything below it
+        let inserted = "let y = 2;\n";
+        buffer.insert(0, inserted);
+        tm.adjust_for_edit(0, 0, inserted.len());
+        let shift = inserted.len();
+        let after = tm.category_spans(&buffer, start + shift, end + shift, &theme, 0);
+        let shifted: Vec<_> = before
+            .iter()
+            .map(|(range, category)| (range.start + shift..range.end + shift, *category))
+            .collect();
+        assert_eq!(after, shifted);
+        // The shifted checkpoints were confirmed by the first one reached
+        assert_eq!(tm.unverified_from, None);
+
+        let fresh =
+            textmate_for("test.rs").category_spans(&buffer, start + shift, end + shift, &theme, 0);
+        assert_eq!(after, fresh);
+    }
+
+    #[test]
+    fn test_edit_far_above_viewport_rehighlights_below() {
+        let theme = Theme::load_builtin(theme::THEME_LIGHT).unwrap();
+        let content = long_file_with_comment();
+        let mut buffer = Buffer::from_str(&content, 0, test_fs());
+        let mut tm = textmate_for("test.rs");
+
+        let start = line_offset(&content, 1550);
+        let end = line_offset(&content, 1560);
+        tm.category_spans(&buffer, start, end, &theme, 0);
+
+        // Deleting the line that opens the comment turns the viewport back
+        // into code, even though checkpoints recorded it as a comment
+        let opener = line_offset(&content, 1500)..line_offset(&content, 1501);
+        buffer.delete(opener.clone());
+        tm.adjust_for_edit(opener.start, opener.len(), 0);
+        let (start, end) = (start - opener.len(), end - opener.len());
+        let after = tm.category_spans(&buffer, start, end, &theme, 0);
+        assert!(after
+            .iter()
+            .any(|(_, category)| *category == HighlightCategory::Number));
+        assert!(!after
+            .iter()
+            .any(|(_, category)| *category == HighlightCategory::Comment));
+
+        let fresh = textmate_for("test.rs").category_spans(&buffer, start, end, &theme, 0);
+        assert_eq!(after, fresh);
+    }
+
+    #[test]
+    fn test_adjust_for_edit_shifts_and_drops_checkpoints() {
+        let theme = Theme::load_builtin(theme::THEME_LIGHT).unwrap();
+        let content = long_file_with_comment();
+        let buffer = Buffer::from_str(&content, 0, test_fs());
+        let mut tm = textmate_for("test.rs");
+        tm.category_spans(&buffer, 0, content.len(), &theme, 0);
+        let offsets: Vec<usize> = tm.checkpoints.iter().map(|c| c.offset).collect();
+        assert_eq!(offsets[0], line_offset(&content, CHECKPOINT_INTERVAL_LINES));
+
+        // Deleting across the second checkpoint drops it, keeps the first
+        // and shifts the rest
+        let deleted = offsets[1] - 5..offsets[1] + 5;
+        tm.adjust_for_edit(deleted.start, deleted.len(), 0);
+        let adjusted: Vec<usize> = tm.checkpoints.iter().map(|c| c.offset).collect();
+        let expected: Vec<usize> = std::iter::once(offsets[0])
+            .chain(offsets[2..].iter().map(|offset| offset - deleted.len()))
+            .collect();
+        assert_eq!(adjusted, expected);
+        assert_eq!(tm.unverified_from, Some(deleted.start));
+
+        tm.invalidate_all();
+        assert!(tm.checkpoints.is_empty());
+        assert_eq!(tm.unverified_from, None);
+    }
 }
@@ -16,7 +16,7 @@ use fresh_languages::tree_sitter_highlight::{
     HighlightConfiguration, HighlightEvent, Highlighter as TSHighlighter,
 };
 pub use fresh_languages::{HighlightCategory, Language};
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier};
 use std::ops::Range;
 
 /// Maximum bytes to parse in a single operation (for viewport highlighting)
@@ -46,6 +46,8 @@ pub struct HighlightSpan {
     pub range: Range<usize>,
     /// Color for this span
     pub color: Color,
+    /// Text modifiers (bold, italic, ...) from syntax style overrides
+    pub modifiers: Modifier,
 }
 
 /// Internal span used for caching (stores category instead of color)
@@ -124,6 +126,7 @@ impl Highlighter {
                     .map(|span| HighlightSpan {
                         range: span.range.clone(),
                         color: highlight_color(span.category, theme),
+                        modifiers: Modifier::empty(),
                     })
                     .collect();
             }
@@ -209,10 +212,32 @@ impl Highlighter {
             .map(|span| HighlightSpan {
                 range: span.range,
                 color: highlight_color(span.category, theme),
+                modifiers: Modifier::empty(),
             })
             .collect()
     }
 
+    /// Highlight categories of the spans overlapping `start..end`
+    ///
+    /// Parses the range the same way as [`Self::highlight_viewport`] when it
+    /// isn't cached.
+    pub fn category_spans(
+        &mut self,
+        buffer: &Buffer,
+        start: usize,
+        end: usize,
+        theme: &Theme,
+        context_bytes: usize,
+    ) -> Vec<(Range<usize>, HighlightCategory)> {
+        self.highlight_viewport(buffer, start, end, theme, context_bytes);
+        self.cache
+            .iter()
+            .flat_map(|cache| cache.spans.iter())
+            .filter(|span| span.range.start < end && span.range.end > start)
+            .map(|span| (span.range.clone(), span.category))
+            .collect()
+    }
+
     /// Invalidate cache for an edited range
     ///
     /// Call this when the buffer is edited to mark the cache as stale.
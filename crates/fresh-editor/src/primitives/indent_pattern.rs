@@ -22,9 +22,108 @@
 //! ```
 //!
 //! The pattern matcher sees the unmatched `{` and increases indent by tab_size.
+//!
+//! # Indent rules
+//!
+//! A language can also have regex [`IndentRules`]: a line matching the
+//! increase pattern indents the line after it one level more, and a line
+//! matching the decrease pattern sits one level less than the line above it.
+//! The decrease pattern is checked when one of the language's dedent trigger
+//! characters is typed (e.g. the `:` of Python's `else:`). Built-in rules
+//! cover Rust, Python, YAML and C-like languages, and each can be changed per
+//! language with `indent_rules`.
+
+use regex::Regex;
 
 use crate::model::buffer::Buffer;
 
+/// Opening bracket at the end of a line, before an optional `//` comment
+const C_LIKE_INCREASE: &str = r"[\{\[\(]\s*(//.*)?$";
+/// Closing bracket at the start of a line
+const C_LIKE_DECREASE: &str = r"^\s*[\}\]\)]";
+/// Opening bracket or match arm arrow at the end of a line
+const RUST_INCREASE: &str = r"([\{\[\(]|=>)\s*(//.*)?$";
+/// Block statement ending in `:`, or an opening bracket
+const PYTHON_INCREASE: &str = r"(^\s*(class|def|async\s+def|if|elif|else|for|async\s+for|while|try|except|finally|with|async\s+with|match|case)\b.*:|[\{\[\(])\s*(#.*)?$";
+/// Clause continuing a block statement, or a closing bracket
+const PYTHON_DECREASE: &str = r"^\s*((elif|else|except|finally)\b.*:|[\}\]\)])";
+/// Key without a value, block scalar, or list item dash on its own
+const YAML_INCREASE: &str = r"^[^#]*(:(\s*[|>][-+0-9]*)?|^\s*-)\s*(#.*)?$";
+
+/// Regex sources and dedent triggers of a language's indent rules
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndentPatterns {
+    /// A line matching this indents the line after it one level more
+    pub increase: Option<String>,
+    /// A line matching this sits one level less than the line above it
+    pub decrease: Option<String>,
+    /// Characters that check the line against `decrease` when typed
+    pub dedent_triggers: String,
+}
+
+impl IndentPatterns {
+    /// Built-in patterns for a language
+    pub fn for_language(language: &str) -> Self {
+        let (increase, decrease, dedent_triggers) = match language {
+            "rust" => (RUST_INCREASE, Some(C_LIKE_DECREASE), "}])"),
+            "python" => (PYTHON_INCREASE, Some(PYTHON_DECREASE), ":}])"),
+            "yaml" => (YAML_INCREASE, None, ""),
+            "c" | "cpp" | "csharp" | "java" | "javascript" | "typescript" | "go" | "kotlin"
+            | "swift" | "scala" | "dart" | "php" | "zig" | "json" | "jsonc" | "css" | "scss"
+            | "less" => (C_LIKE_INCREASE, Some(C_LIKE_DECREASE), "}])"),
+            _ => return Self::default(),
+        };
+        Self {
+            increase: Some(increase.to_string()),
+            decrease: decrease.map(str::to_string),
+            dedent_triggers: dedent_triggers.to_string(),
+        }
+    }
+}
+
+/// Compiled indent rules of a language
+#[derive(Debug, Clone, Default)]
+pub struct IndentRules {
+    patterns: IndentPatterns,
+    increase: Option<Regex>,
+    decrease: Option<Regex>,
+}
+
+impl IndentRules {
+    /// Compile the patterns. An empty or invalid pattern turns its rule off.
+    pub fn new(patterns: IndentPatterns) -> Self {
+        let compile = |pattern: &Option<String>| {
+            let pattern = pattern.as_deref().filter(|pattern| !pattern.is_empty())?;
+            Regex::new(pattern)
+                .map_err(|e| tracing::warn!("Invalid indent pattern {:?}: {}", pattern, e))
+                .ok()
+        };
+        Self {
+            increase: compile(&patterns.increase),
+            decrease: compile(&patterns.decrease),
+            patterns,
+        }
+    }
+
+    /// The patterns the rules were compiled from
+    pub fn patterns(&self) -> &IndentPatterns {
+        &self.patterns
+    }
+
+    /// Whether typing `ch` checks the line against the decrease pattern
+    pub fn is_dedent_trigger(&self, ch: char) -> bool {
+        self.decrease.is_some() && self.patterns.dedent_triggers.contains(ch)
+    }
+}
+
+/// Visual width of the indentation at the start of `text`
+fn indent_width(text: &str, tab_size: usize) -> usize {
+    text.chars()
+        .take_while(|ch| *ch == ' ' || *ch == '\t')
+        .map(|ch| if ch == '\t' { tab_size } else { 1 })
+        .sum()
+}
+
 /// Pattern-based indent calculator (WASM-compatible)
 ///
 /// Uses heuristic pattern matching instead of tree-sitter AST analysis.
@@ -45,6 +144,59 @@ impl PatternIndentCalculator {
         Self::get_current_line_indent(buffer, position, tab_size)
     }
 
+    /// Indent for a new line at `position` by the increase rule: one level
+    /// more than the line the cursor is on when the text before the cursor
+    /// matches it. `None` when the rule doesn't apply.
+    pub fn calculate_indent_with_rules(
+        buffer: &Buffer,
+        position: usize,
+        tab_size: usize,
+        rules: &IndentRules,
+    ) -> Option<usize> {
+        let increase = rules.increase.as_ref()?;
+        let line_start = buffer.line_start_offset(buffer.get_line_number(position))?;
+        let before = buffer.slice_bytes(line_start..position);
+        let before = String::from_utf8_lossy(&before);
+        let before = before.trim_end_matches('\r');
+        if before.trim().is_empty() || !increase.is_match(before) {
+            return None;
+        }
+        Some(indent_width(before, tab_size) + tab_size)
+    }
+
+    /// Indent for a line that reads `line` once a dedent trigger is typed
+    /// on it, by the decrease rule: one level less than the nearest line
+    /// above with text, or the same as it when that line opens a block.
+    /// `None` unless the line matches and this moves it to the left.
+    pub fn calculate_dedent_with_rules(
+        buffer: &Buffer,
+        line_start: usize,
+        line: &str,
+        tab_size: usize,
+        rules: &IndentRules,
+    ) -> Option<usize> {
+        if !rules.decrease.as_ref()?.is_match(line) {
+            return None;
+        }
+        let line_number = buffer.get_line_number(line_start);
+        let above = (0..line_number).rev().find_map(|number| {
+            let bytes = buffer.get_line(number)?;
+            let text = String::from_utf8_lossy(&bytes).trim_end().to_string();
+            (!text.trim().is_empty()).then_some(text)
+        })?;
+        let above_indent = indent_width(&above, tab_size);
+        let opens_block = rules
+            .increase
+            .as_ref()
+            .is_some_and(|increase| increase.is_match(&above));
+        let indent = if opens_block {
+            above_indent
+        } else {
+            above_indent.saturating_sub(tab_size)
+        };
+        (indent < indent_width(line, tab_size)).then_some(indent)
+    }
+
     /// Calculate the correct indent for a closing delimiter being typed
     ///
     /// When typing `}`, `]`, or `)`, this finds the matching opening delimiter
@@ -320,6 +472,79 @@ mod tests {
         assert_eq!(indent, 4);
     }
 
+    #[test]
+    fn test_increase_rule() {
+        let rules = IndentRules::new(IndentPatterns::for_language("python"));
+        let buffer = make_buffer("class A:\n    def f(self):  # comment\n        x = {'a': 1}");
+        let indent =
+            |pos| PatternIndentCalculator::calculate_indent_with_rules(&buffer, pos, 4, &rules);
+        assert_eq!(indent(8), Some(4));
+        let second_line_end = buffer.line_start_offset(2).unwrap() - 1;
+        assert_eq!(indent(second_line_end), Some(8));
+        // Not a block statement
+        assert_eq!(indent(buffer.len()), None);
+
+        let rules = IndentRules::new(IndentPatterns::for_language("yaml"));
+        let buffer = make_buffer("jobs:\n  build: |\n  name: x");
+        let indent =
+            |pos| PatternIndentCalculator::calculate_indent_with_rules(&buffer, pos, 2, &rules);
+        assert_eq!(indent(5), Some(2));
+        assert_eq!(indent(buffer.line_start_offset(2).unwrap() - 1), Some(4));
+        assert_eq!(indent(buffer.len()), None);
+    }
+
+    #[test]
+    fn test_decrease_rule() {
+        let rules = IndentRules::new(IndentPatterns::for_language("python"));
+        assert!(rules.is_dedent_trigger(':'));
+        assert!(!rules.is_dedent_trigger('x'));
+
+        let buffer = make_buffer("if a:\n    b()\n\n    else:");
+        let line_start = buffer.line_start_offset(3).unwrap();
+        let dedent = |line: &str| {
+            PatternIndentCalculator::calculate_dedent_with_rules(
+                &buffer, line_start, line, 4, &rules,
+            )
+        };
+        assert_eq!(dedent("    else:"), Some(0));
+        // Already dedented, or not a continuing clause
+        assert_eq!(dedent("else:"), None);
+        assert_eq!(dedent("    elsewhere = 1"), None);
+
+        // Right after the line opening the block, it lines up with it
+        let buffer = make_buffer("try:\n    except:");
+        let line_start = buffer.line_start_offset(1).unwrap();
+        assert_eq!(
+            PatternIndentCalculator::calculate_dedent_with_rules(
+                &buffer,
+                line_start,
+                "    except:",
+                4,
+                &rules
+            ),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_rules_can_be_turned_off() {
+        let rules = IndentRules::new(IndentPatterns {
+            increase: Some(String::new()),
+            decrease: Some("(".to_string()),
+            dedent_triggers: ")".to_string(),
+        });
+        let buffer = make_buffer("f() {\n    )");
+        assert_eq!(
+            PatternIndentCalculator::calculate_indent_with_rules(&buffer, 5, 4, &rules),
+            None
+        );
+        assert!(!rules.is_dedent_trigger(')'));
+        assert_eq!(
+            IndentPatterns::for_language("text"),
+            IndentPatterns::default()
+        );
+    }
+
     #[test]
     fn test_nested_braces() {
         let buffer = make_buffer("fn main() {\n    if true {\n        inner\n    }\n");
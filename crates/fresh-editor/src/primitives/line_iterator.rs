@@ -27,6 +27,12 @@ use crate::model::buffer::TextBuffer;
 /// This is generous enough for any practical line while preventing OOM from 10MB+ lines.
 const MAX_LINE_BYTES: usize = 100_000;
 
+/// Largest chunk read at once while scanning for a line boundary. Scans start
+/// at the estimated line length and double up to this, so ordinary lines stay
+/// cheap while a long minified line takes a handful of reads instead of one
+/// per estimated line length.
+const MAX_SCAN_CHUNK: usize = 64 * 1024;
+
 pub struct LineIterator<'a> {
     buffer: &'a mut TextBuffer,
     /// Current byte position in the document (points to start of current line)
@@ -55,6 +61,7 @@ impl<'a> LineIterator<'a> {
         // Scan backward in chunks until we find a newline or reach position 0
         // The chunk_size is just a hint for performance - we MUST find the actual line start
         let mut search_end = byte_pos;
+        let mut chunk_size = chunk_size.max(1);
 
         loop {
             let scan_start = search_end.saturating_sub(chunk_size);
@@ -63,11 +70,9 @@ impl<'a> LineIterator<'a> {
             // Load the chunk we need to scan
             if let Ok(chunk) = buffer.get_text_range_mut(scan_start, scan_len) {
                 // Scan backward through the chunk to find the last newline
-                for i in (0..chunk.len()).rev() {
-                    if chunk[i] == b'\n' {
-                        // Found newline - line starts at the next byte
-                        return scan_start + i + 1;
-                    }
+                if let Some(i) = chunk.iter().rposition(|&b| b == b'\n') {
+                    // Found newline - line starts at the next byte
+                    return scan_start + i + 1;
                 }
             }
 
@@ -79,7 +84,54 @@ impl<'a> LineIterator<'a> {
 
             // Continue searching from earlier position
             search_end = scan_start;
+            chunk_size = (chunk_size * 2).min(MAX_SCAN_CHUNK);
+        }
+    }
+
+    /// Byte offset of the start of the line containing `byte_pos`
+    pub fn line_start(
+        buffer: &mut TextBuffer,
+        byte_pos: usize,
+        estimated_line_length: usize,
+    ) -> usize {
+        let byte_pos = byte_pos.min(buffer.len());
+        Self::find_line_start_backward(buffer, byte_pos, estimated_line_length)
+    }
+
+    /// Byte offset of the end of the line containing `byte_pos`: the first
+    /// byte of its line ending (the `\r` of a `\r\n`), or the buffer length
+    /// on the last line. Unlike `next_line`, this is not capped at
+    /// `MAX_LINE_BYTES` and never builds the line's text.
+    pub fn line_end(
+        buffer: &mut TextBuffer,
+        byte_pos: usize,
+        estimated_line_length: usize,
+    ) -> usize {
+        let buffer_len = buffer.len();
+        let mut scan_start = byte_pos.min(buffer_len);
+        let mut chunk_size = estimated_line_length.max(1);
+        let mut line_end = buffer_len;
+        while scan_start < buffer_len {
+            let scan_len = chunk_size.min(buffer_len - scan_start);
+            let Ok(chunk) = buffer.get_text_range_mut(scan_start, scan_len) else {
+                break;
+            };
+            if let Some(i) = chunk.iter().position(|&b| b == b'\n') {
+                line_end = scan_start + i;
+                break;
+            }
+            scan_start += scan_len;
+            chunk_size = (chunk_size * 2).min(MAX_SCAN_CHUNK);
         }
+
+        // Stop before any `\r` of the line ending
+        while line_end > byte_pos {
+            match buffer.get_text_range_mut(line_end - 1, 1) {
+                Ok(bytes) if bytes.first() == Some(&b'\r') => line_end -= 1,
+                _ => break,
+            }
+        }
+        line_end
     }
 
     pub(crate) fn new(
@@ -186,11 +238,13 @@ impl<'a> LineIterator<'a> {
         if !found_newline && self.current_pos + line_len < self.buffer_len {
             // Line is longer than expected, keep loading until we find newline, EOF, or hit limit
             let mut extended_chunk = chunk;
+            let mut step = estimated_max_line_length;
             while !found_newline
                 && self.current_pos + extended_chunk.len() < self.buffer_len
                 && extended_chunk.len() < MAX_LINE_BYTES
             {
-                let additional_bytes = estimated_max_line_length
+                step = (step * 2).min(MAX_SCAN_CHUNK);
+                let additional_bytes = step
                     .min(self.buffer_len - self.current_pos - extended_chunk.len())
                     .min(MAX_LINE_BYTES - extended_chunk.len()); // Don't exceed limit
                 match self
@@ -333,6 +387,35 @@ mod tests {
     }
     use super::*;
 
+    #[test]
+    fn test_line_start_and_end_of_long_line() {
+        let long = "x".repeat(300_000);
+        let text = format!("ab\r\n{long}\r\ncd");
+        let mut buffer = TextBuffer::from_bytes(text.clone().into_bytes(), test_fs());
+        let long_start = 4;
+        let long_end = long_start + long.len();
+
+        assert_eq!(LineIterator::line_start(&mut buffer, 1, 80), 0);
+        assert_eq!(LineIterator::line_end(&mut buffer, 1, 80), 2);
+        assert_eq!(
+            LineIterator::line_start(&mut buffer, long_end - 1, 80),
+            long_start
+        );
+        // Past the MAX_LINE_BYTES cap of next_line
+        assert_eq!(
+            LineIterator::line_end(&mut buffer, long_start + 150_000, 80),
+            long_end
+        );
+        assert_eq!(
+            LineIterator::line_start(&mut buffer, text.len(), 80),
+            long_end + 2
+        );
+        assert_eq!(
+            LineIterator::line_end(&mut buffer, text.len(), 80),
+            text.len()
+        );
+    }
+
     #[test]
     fn test_line_iterator_new_at_line_start() {
         let mut buffer = TextBuffer::from_bytes(b"Hello\nWorld\nTest".to_vec(), test_fs());
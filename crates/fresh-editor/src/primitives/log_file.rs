@@ -0,0 +1,122 @@
+//! Log files: the level words of their lines and filtering lines by pattern.
+//!
+//! Levels are recognized as upper-case words standing on their own, such as
+//! `ERROR` in `[ERROR]` or `level=ERROR`, so the same words in lower case in
+//! the messages themselves aren't picked up.
+
+use std::ops::Range;
+use std::path::Path;
+
+use regex::bytes::Regex;
+
+/// Severity of a log line, by the level word in it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    /// Level named by `word`
+    fn from_word(word: &[u8]) -> Option<Self> {
+        match word {
+            b"FATAL" | b"CRITICAL" | b"CRIT" | b"PANIC" | b"ERROR" | b"ERR" => Some(Self::Error),
+            b"WARNING" | b"WARN" => Some(Self::Warn),
+            b"NOTICE" | b"INFO" => Some(Self::Info),
+            b"DEBUG" | b"TRACE" => Some(Self::Debug),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `path` names a log file: `app.log`, or a rotated one like
+/// `app.log.1`
+pub fn is_log_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let name = name.to_ascii_lowercase();
+    let name = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    let name = name.strip_suffix('.').unwrap_or(name);
+    name.len() > ".log".len() && name.ends_with(".log")
+}
+
+/// Byte ranges of the level words in `bytes`, which start at `bytes_start`
+/// in the buffer and at the start of a line
+pub fn level_spans(bytes: &[u8], bytes_start: usize) -> Vec<(Range<usize>, LogLevel)> {
+    let is_word_byte = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80;
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !is_word_byte(bytes[i]) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && is_word_byte(bytes[i]) {
+            i += 1;
+        }
+        if let Some(level) = LogLevel::from_word(&bytes[start..i]) {
+            spans.push((bytes_start + start..bytes_start + i, level));
+        }
+    }
+    spans
+}
+
+/// Byte ranges of the runs of lines of `text` that don't match `pattern`,
+/// each running from the start of its first line to the start of the line
+/// after it. The first line is never hidden, as a fold needs a line above it
+/// to collapse into.
+pub fn hidden_line_ranges(text: &[u8], pattern: &Regex) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut offset = 0;
+    for (index, line) in text.split_inclusive(|&b| b == b'\n').enumerate() {
+        let start = offset;
+        offset += line.len();
+        let content = line.strip_suffix(b"\n").unwrap_or(line);
+        let content = content.strip_suffix(b"\r").unwrap_or(content);
+        if index == 0 || pattern.is_match(content) {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = offset,
+            _ => ranges.push(start..offset),
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_log_file() {
+        assert!(is_log_file(Path::new("/var/log/app.log")));
+        assert!(is_log_file(Path::new("server.LOG")));
+        assert!(is_log_file(Path::new("app.log.1")));
+        assert!(!is_log_file(Path::new("catalog")));
+        assert!(!is_log_file(Path::new(".log")));
+        assert!(!is_log_file(Path::new("changelog.md")));
+    }
+
+    #[test]
+    fn test_level_spans() {
+        let line = b"12:00 [ERROR] disk full; WARNINGS=0 level=WARN info\n";
+        let spans = level_spans(line, 100);
+        assert_eq!(
+            spans,
+            vec![(107..112, LogLevel::Error), (142..146, LogLevel::Warn)]
+        );
+    }
+
+    #[test]
+    fn test_hidden_line_ranges() {
+        let text = b"start\nerror a\nok\nfine\nerror b\nok\n";
+        let pattern = Regex::new("error").unwrap();
+        // "ok\nfine\n" and the last "ok\n"; "start" stays as the first line
+        assert_eq!(hidden_line_ranges(text, &pattern), vec![14..22, 30..33]);
+    }
+}
@@ -15,16 +15,28 @@
 //! | Reference highlighting | `reference_highlight_text` | `reference_highlighter` |
 
 // Pure modules - available for both runtime and WASM
+pub mod abbreviation;
+pub mod box_drawing;
+pub mod color_literal;
+pub mod delimited;
 pub mod display_width;
 pub mod glob_match;
 pub mod grapheme;
 pub mod line_wrapping;
+pub mod log_file;
+pub mod number_literal;
 pub mod path_utils;
+pub mod reflow;
 pub mod snippet;
+pub mod surround;
+pub mod text_links;
 pub mod text_property;
+pub mod unicode_names;
 
 // Modules depending on model::buffer - available for both runtime and WASM
 pub mod line_iterator;
+pub mod selection_stats;
+pub mod word_index;
 pub mod word_navigation;
 
 // Modules using ratatui types (Color, Style, etc.) - available for both runtime and WASM
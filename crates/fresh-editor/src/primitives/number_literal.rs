@@ -0,0 +1,128 @@
+//! Numbers in a line of text, for Increment Number and Decrement Number.
+//!
+//! A number is a run of decimal digits, with a `-` in front of it when that
+//! doesn't follow a word (`x = -3` is negative, `a-3` isn't), or a hex
+//! number like `0x1f`. Digits inside a word count too, so `item7` holds a
+//! 7. Adding to a number keeps its width when it has leading zeros, and
+//! the case of its hex digits.
+
+use std::ops::Range;
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80
+}
+
+/// The numbers of `line`, in order
+fn numbers(line: &[u8]) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < line.len() {
+        let after_word = i > 0 && is_word_byte(line[i - 1]);
+        let is_hex = line[i] == b'0'
+            && matches!(line.get(i + 1), Some(b'x' | b'X'))
+            && line.get(i + 2).is_some_and(u8::is_ascii_hexdigit)
+            && !after_word;
+        if is_hex {
+            let mut end = i + 2;
+            while end < line.len() && line[end].is_ascii_hexdigit() {
+                end += 1;
+            }
+            spans.push(i..end);
+            i = end;
+        } else if line[i].is_ascii_digit() {
+            let mut end = i;
+            while end < line.len() && line[end].is_ascii_digit() {
+                end += 1;
+            }
+            let negative = i > 0 && line[i - 1] == b'-' && (i < 2 || !is_word_byte(line[i - 2]));
+            spans.push(if negative { i - 1 } else { i }..end);
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    spans
+}
+
+/// The number under byte `pos` of `line`, or else the first one after it
+pub fn number_at(line: &str, pos: usize) -> Option<Range<usize>> {
+    numbers(line.as_bytes())
+        .into_iter()
+        .find(|span| span.end > pos)
+}
+
+/// `number` with `amount` added. Hex numbers wrap around as 64-bit
+/// unsigned values. `None` when `number` isn't a number or the sum doesn't
+/// fit in 64 bits.
+pub fn add_to_number(number: &str, amount: i64) -> Option<String> {
+    if let Some(digits) = number
+        .strip_prefix("0x")
+        .or_else(|| number.strip_prefix("0X"))
+    {
+        let value = u64::from_str_radix(digits, 16).ok()?;
+        let sum = value.wrapping_add_signed(amount);
+        let hex = if digits.bytes().any(|b| b.is_ascii_uppercase()) {
+            format!("{sum:0width$X}", width = digits.len())
+        } else {
+            format!("{sum:0width$x}", width = digits.len())
+        };
+        return Some(format!("{}{hex}", &number[..2]));
+    }
+
+    let digits = number.strip_prefix('-').unwrap_or(number);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let value: i64 = number.parse().ok()?;
+    let sum = value.checked_add(amount)?;
+    let width = if digits.len() > 1 && digits.starts_with('0') {
+        digits.len()
+    } else {
+        0
+    };
+    let sign = if sum < 0 { "-" } else { "" };
+    Some(format!("{sign}{:0width$}", sum.unsigned_abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(line: &str, pos: usize) -> Option<&str> {
+        number_at(line, pos).map(|span| &line[span])
+    }
+
+    #[test]
+    fn test_number_at() {
+        assert_eq!(found("x = 42;", 0), Some("42"));
+        assert_eq!(found("x = 42;", 5), Some("42"));
+        assert_eq!(found("x = 42;", 6), None);
+        assert_eq!(found("a = -3, b = 4", 4), Some("-3"));
+        assert_eq!(found("a = -3, b = 4", 6), Some("4"));
+        // A dash after a word is not a sign
+        assert_eq!(found("page-3", 0), Some("3"));
+        assert_eq!(found("item7", 0), Some("7"));
+        assert_eq!(found("mask 0x1f", 8), Some("0x1f"));
+        assert_eq!(found("0xzz", 0), Some("0"));
+        assert_eq!(found("v1.25", 1), Some("1"));
+        assert_eq!(found("v1.25", 2), Some("25"));
+    }
+
+    #[test]
+    fn test_add_to_number() {
+        assert_eq!(add_to_number("41", 1).as_deref(), Some("42"));
+        assert_eq!(add_to_number("-1", 1).as_deref(), Some("0"));
+        assert_eq!(add_to_number("0", -5).as_deref(), Some("-5"));
+        assert_eq!(add_to_number("007", 1).as_deref(), Some("008"));
+        assert_eq!(add_to_number("099", 1).as_deref(), Some("100"));
+        assert_eq!(add_to_number("010", -11).as_deref(), Some("-001"));
+        assert_eq!(add_to_number("0x1f", 1).as_deref(), Some("0x20"));
+        assert_eq!(add_to_number("0X0F", 1).as_deref(), Some("0X10"));
+        assert_eq!(
+            add_to_number("0x00", -1).as_deref(),
+            Some("0xffffffffffffffff")
+        );
+        assert_eq!(add_to_number("9223372036854775807", 1), None);
+        assert_eq!(add_to_number("x", 1), None);
+    }
+}
@@ -25,7 +25,7 @@ use crate::model::buffer::Buffer;
 use crate::primitives::highlighter::{HighlightSpan, Language};
 use crate::primitives::word_navigation::{find_word_end, find_word_start, is_word_char};
 use fresh_languages::tree_sitter::{Parser, Query, QueryCursor, StreamingIterator};
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier};
 use std::ops::Range;
 
 /// Default subtle background color for occurrence highlights
@@ -519,6 +519,7 @@ impl ReferenceHighlighter {
                     highlights.push(HighlightSpan {
                         range: range.clone(),
                         color: self.highlight_color,
+                        modifiers: Modifier::empty(),
                     });
                 }
             }
@@ -555,6 +556,7 @@ impl ReferenceHighlighter {
                         highlights.push(HighlightSpan {
                             range: range.clone(),
                             color: self.highlight_color,
+                            modifiers: Modifier::empty(),
                         });
                     }
                 }
@@ -568,6 +570,7 @@ impl ReferenceHighlighter {
                     highlights.push(HighlightSpan {
                         range: range.clone(),
                         color: self.highlight_color,
+                        modifiers: Modifier::empty(),
                     });
                 }
             }
@@ -667,6 +670,7 @@ impl ReferenceHighlighter {
             .map(|(range, _)| HighlightSpan {
                 range,
                 color: self.highlight_color,
+                modifiers: Modifier::empty(),
             })
             .collect()
     }
@@ -709,6 +713,7 @@ impl ReferenceHighlighter {
             .map(|range| HighlightSpan {
                 range,
                 color: self.highlight_color,
+                modifiers: Modifier::empty(),
             })
             .collect()
     }
@@ -0,0 +1,309 @@
+//! Joining lines and rewrapping them at a width.
+//!
+//! A line starts with a prefix: its indentation, then a comment marker and
+//! the spaces after it when it has one. Joined lines lose their prefix when
+//! it has the same marker as the first line's, and their indentation
+//! otherwise. Rewrapped lines keep the prefix of the paragraph they belong
+//! to, so a wrapped `// ` comment stays a comment.
+//!
+//! Widths are display widths. Text in wide characters (Chinese, Japanese,
+//! Korean) has no spaces between words, so a line may break between any two
+//! wide characters, and no space is put back where two of them meet.
+
+use crate::primitives::display_width::{char_width, str_width};
+
+/// Split a line into its prefix and the rest, returning the comment marker
+/// of the prefix if it has one.
+///
+/// A marker repeated or followed by `!` is taken whole, so `///` and `//!`
+/// count as `//`. A `*` is only a marker when it doesn't close a block
+/// comment.
+pub fn split_prefix<'a, 'm>(
+    line: &'a str,
+    markers: &[&'m str],
+) -> (&'a str, Option<&'m str>, &'a str) {
+    let indent = line.len() - line.trim_start_matches([' ', '\t']).len();
+    let after_indent = &line[indent..];
+    let marker = markers
+        .iter()
+        .filter(|marker| !marker.is_empty() && after_indent.starts_with(**marker))
+        .max_by_key(|marker| marker.len())
+        .copied();
+    let Some(marker) = marker else {
+        return (&line[..indent], None, after_indent);
+    };
+
+    let repeated = marker.chars().last().unwrap_or(' ');
+    let after_marker =
+        after_indent[marker.len()..].trim_start_matches(|ch| ch == repeated || ch == '!');
+    if marker.ends_with('*') && after_marker.starts_with('/') {
+        return (&line[..indent], None, after_indent);
+    }
+    let rest = after_marker.trim_start_matches([' ', '\t']);
+    let prefix_len = line.len() - rest.len();
+    (&line[..prefix_len], Some(marker), rest)
+}
+
+/// Whether a space goes between `before` and `after` when they are put on
+/// one line. Not after whitespace, and not between two wide characters.
+fn needs_space(before: &str, after: &str) -> bool {
+    match (before.chars().last(), after.chars().next()) {
+        (Some(last), Some(first)) => {
+            !last.is_whitespace() && !(char_width(last) > 1 && char_width(first) > 1)
+        }
+        _ => false,
+    }
+}
+
+/// Join `lines` into one line.
+///
+/// Each line after the first loses its trailing whitespace and its prefix
+/// when its marker matches the first line's, or its indentation otherwise,
+/// and is joined on with a single space. Blank lines add nothing.
+pub fn join_lines(lines: &[&str], markers: &[&str]) -> String {
+    let Some((first, rest)) = lines.split_first() else {
+        return String::new();
+    };
+    let (_, marker, _) = split_prefix(first, markers);
+    let mut joined = first.trim_end().to_string();
+    for line in rest {
+        let (_, line_marker, text) = split_prefix(line, markers);
+        let text = if marker.is_some() && line_marker == marker {
+            text
+        } else {
+            line.trim_start()
+        };
+        let text = text.trim_end();
+        if text.is_empty() {
+            continue;
+        }
+        if needs_space(&joined, text) {
+            joined.push(' ');
+        }
+        joined.push_str(text);
+    }
+    joined
+}
+
+/// Pieces of a word a line may break between: each wide character alone,
+/// and the runs of other characters between them
+fn break_pieces(word: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    for (i, ch) in word.char_indices() {
+        if char_width(ch) > 1 {
+            if start < i {
+                pieces.push(&word[start..i]);
+            }
+            pieces.push(&word[i..i + ch.len_utf8()]);
+            start = i + ch.len_utf8();
+        }
+    }
+    if start < word.len() {
+        pieces.push(&word[start..]);
+    }
+    pieces
+}
+
+/// Display width of a prefix, with tabs taken to the next tab stop
+fn prefix_width(prefix: &str, tab_size: usize) -> usize {
+    prefix.chars().fold(0, |width, ch| match ch {
+        '\t' => (width / tab_size.max(1) + 1) * tab_size.max(1),
+        ch => width + char_width(ch),
+    })
+}
+
+/// Fill the text of a paragraph's lines into lines no wider than `width`,
+/// starting the first with `first_prefix` and the others with
+/// `continuation`. A piece wider than the room left goes on a line of its
+/// own.
+fn fill(
+    texts: &[&str],
+    first_prefix: &str,
+    continuation: &str,
+    width: usize,
+    tab_size: usize,
+) -> Vec<String> {
+    let mut pieces: Vec<(&str, bool)> = Vec::new();
+    for text in texts {
+        for (i, word) in text.split_whitespace().enumerate() {
+            for (j, piece) in break_pieces(word).into_iter().enumerate() {
+                // Words on a line are spaced, pieces of a word aren't, and
+                // lines meet the way joined lines do
+                let space = if j > 0 {
+                    false
+                } else if i > 0 {
+                    true
+                } else {
+                    pieces
+                        .last()
+                        .is_some_and(|(last, _)| needs_space(last, piece))
+                };
+                pieces.push((piece, space));
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut line = first_prefix.to_string();
+    let mut line_width = prefix_width(first_prefix, tab_size);
+    let mut empty = true;
+    for (piece, space) in pieces {
+        let piece_width = str_width(piece);
+        let space = space && !empty;
+        if !empty && line_width + usize::from(space) + piece_width > width {
+            lines.push(line.trim_end().to_string());
+            line = continuation.to_string();
+            line_width = prefix_width(continuation, tab_size);
+            empty = true;
+        } else if space {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(piece);
+        line_width += piece_width;
+        empty = false;
+    }
+    lines.push(line.trim_end().to_string());
+    lines
+}
+
+/// Rewrap `lines` so that none is wider than `width` where its words allow.
+///
+/// Lines are grouped into paragraphs, ended by a blank line (or one holding
+/// only a comment marker) and by a change of marker. Blank lines are kept.
+/// A paragraph's first line keeps its prefix and the others take the prefix
+/// of its second line, so a hanging indent survives.
+pub fn hard_wrap(lines: &[&str], width: usize, tab_size: usize, markers: &[&str]) -> Vec<String> {
+    let is_blank = |line: &str| split_prefix(line, markers).2.trim().is_empty();
+    let mut wrapped = Vec::new();
+    let mut start = 0;
+    while start < lines.len() {
+        let (prefix, marker, _) = split_prefix(lines[start], markers);
+        if is_blank(lines[start]) {
+            wrapped.push(lines[start].trim_end().to_string());
+            start += 1;
+            continue;
+        }
+        let end = (start + 1..lines.len())
+            .find(|&i| is_blank(lines[i]) || split_prefix(lines[i], markers).1 != marker)
+            .unwrap_or(lines.len());
+        let continuation = match end - start {
+            1 => prefix,
+            _ => split_prefix(lines[start + 1], markers).0,
+        };
+        let texts: Vec<&str> = lines[start..end]
+            .iter()
+            .map(|line| split_prefix(line, markers).2)
+            .collect();
+        wrapped.extend(fill(&texts, prefix, continuation, width, tab_size));
+        start = end;
+    }
+    wrapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_prefix() {
+        let markers = ["//", "*"];
+        assert_eq!(
+            split_prefix("    // text", &markers),
+            ("    // ", Some("//"), "text")
+        );
+        assert_eq!(
+            split_prefix("/// doc", &markers),
+            ("/// ", Some("//"), "doc")
+        );
+        assert_eq!(
+            split_prefix(" * item", &markers),
+            (" * ", Some("*"), "item")
+        );
+        assert_eq!(split_prefix(" */", &markers), (" ", None, "*/"));
+        assert_eq!(split_prefix("\tcode", &markers), ("\t", None, "code"));
+    }
+
+    #[test]
+    fn test_join_collapses_indentation() {
+        let lines = ["fn main() {   ", "    call(", "        x);"];
+        assert_eq!(join_lines(&lines, &["//"]), "fn main() { call( x);");
+        // Blank lines add nothing
+        assert_eq!(join_lines(&["a", "   ", "b"], &[]), "a b");
+    }
+
+    #[test]
+    fn test_join_removes_comment_markers() {
+        let lines = ["    // first part", "    //   second part", "// third"];
+        assert_eq!(
+            join_lines(&lines, &["//"]),
+            "    // first part second part third"
+        );
+        // Only when the first line is a comment too
+        assert_eq!(join_lines(&["x = 1", "# note"], &["#"]), "x = 1 # note");
+    }
+
+    #[test]
+    fn test_join_wide_characters_without_space() {
+        assert_eq!(
+            join_lines(&["日本語の", "文章です"], &[]),
+            "日本語の文章です"
+        );
+        assert_eq!(join_lines(&["中文", "text"], &[]), "中文 text");
+    }
+
+    #[test]
+    fn test_hard_wrap_keeps_comment_prefix() {
+        let lines = ["    // The quick brown fox jumps over the lazy dog again"];
+        assert_eq!(
+            hard_wrap(&lines, 24, 4, &["//"]),
+            vec![
+                "    // The quick brown",
+                "    // fox jumps over",
+                "    // the lazy dog",
+                "    // again",
+            ]
+        );
+
+        let lines = ["/**", " * one two", " * three four five", " */"];
+        assert_eq!(
+            hard_wrap(&lines, 12, 4, &["//", "*"]),
+            vec!["/**", " * one two", " * three", " * four five", " */"]
+        );
+    }
+
+    #[test]
+    fn test_hard_wrap_paragraphs() {
+        let lines = ["# a b c d", "#", "# e f", "plain words here"];
+        assert_eq!(
+            hard_wrap(&lines, 5, 4, &["#"]),
+            vec!["# a b", "# c d", "#", "# e f", "plain", "words", "here"]
+        );
+        // Short lines are joined up, and the second line's indent is kept
+        let lines = ["- one", "  two", "  three"];
+        assert_eq!(hard_wrap(&lines, 80, 4, &[]), vec!["- one two three"]);
+        assert_eq!(hard_wrap(&lines, 10, 4, &[]), vec!["- one two", "  three"]);
+    }
+
+    #[test]
+    fn test_hard_wrap_wide_characters() {
+        // Each character is two columns wide and breaks anywhere
+        let lines = ["これは日本語の文章です"];
+        assert_eq!(
+            hard_wrap(&lines, 10, 4, &[]),
+            vec!["これは日本", "語の文章で", "す"]
+        );
+        let lines = ["// 中文注释 and text"];
+        assert_eq!(
+            hard_wrap(&lines, 12, 4, &["//"]),
+            vec!["// 中文注释", "// and text"]
+        );
+    }
+
+    #[test]
+    fn test_hard_wrap_long_word_gets_own_line() {
+        let lines = ["a verylongword b"];
+        assert_eq!(hard_wrap(&lines, 5, 4, &[]), vec!["a", "verylongword", "b"]);
+    }
+}
@@ -0,0 +1,219 @@
+//! Size of the selections, for the status bar
+//!
+//! The status bar shows how much is selected on every render, so the counts
+//! are kept from one render to the next. Lines come from the buffer's line
+//! numbers of the two ends of each selection. Characters are counted from
+//! the bytes, and when a selection only moved one of its ends since the last
+//! count (the usual case while it is extended) only the bytes between the old
+//! and the new end are read. Words are counted only when asked for, and kept
+//! until the selections or the buffer change.
+
+use std::ops::Range;
+
+use crate::model::buffer::Buffer;
+
+/// Selections larger than this are counted in bytes rather than characters,
+/// and their words aren't counted
+const MAX_COUNTED_SELECTION_BYTES: usize = 1024 * 1024;
+
+/// Total size of the selections
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SelectionSize {
+    /// Selections that aren't empty
+    pub selections: usize,
+    pub lines: usize,
+    pub chars: usize,
+}
+
+/// A counted selection
+#[derive(Debug, Clone)]
+struct Counted {
+    range: Range<usize>,
+    lines: usize,
+    chars: usize,
+}
+
+/// Counts of the selections, kept between renders
+#[derive(Debug, Clone, Default)]
+pub struct SelectionStats {
+    /// Buffer version the counts match
+    version: Option<u64>,
+    counted: Vec<Counted>,
+    /// Words in the counted selections, once asked for. `None` inside when
+    /// they are too large to count.
+    words: Option<Option<usize>>,
+}
+
+impl SelectionStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lines and characters in `ranges`, summed
+    pub fn size(&mut self, buffer: &mut Buffer, ranges: &[Range<usize>]) -> SelectionSize {
+        if self.version != Some(buffer.version()) {
+            self.version = Some(buffer.version());
+            self.counted.clear();
+            self.words = None;
+        }
+
+        let ranges: Vec<Range<usize>> = ranges.iter().filter(|r| !r.is_empty()).cloned().collect();
+        let same = ranges.len() == self.counted.len()
+            && ranges
+                .iter()
+                .zip(&self.counted)
+                .all(|(range, counted)| *range == counted.range);
+        if !same {
+            let previous = std::mem::take(&mut self.counted);
+            self.counted = ranges
+                .into_iter()
+                .enumerate()
+                .map(|(i, range)| count(buffer, range, previous.get(i)))
+                .collect();
+            self.words = None;
+        }
+
+        SelectionSize {
+            selections: self.counted.len(),
+            lines: self.counted.iter().map(|counted| counted.lines).sum(),
+            chars: self.counted.iter().map(|counted| counted.chars).sum(),
+        }
+    }
+
+    /// Words in the selections last passed to [`Self::size`], or `None` when
+    /// they are too large to count
+    pub fn words(&mut self, buffer: &mut Buffer) -> Option<usize> {
+        if let Some(words) = self.words {
+            return words;
+        }
+        let total: usize = self.counted.iter().map(|counted| counted.range.len()).sum();
+        let words = (total <= MAX_COUNTED_SELECTION_BYTES).then(|| {
+            self.counted
+                .iter()
+                .map(|counted| count_words(&read(buffer, counted.range.clone())))
+                .sum()
+        });
+        self.words = Some(words);
+        words
+    }
+}
+
+/// Count a selection, reading only what changed since `previous` when one of
+/// its ends stayed put
+fn count(buffer: &mut Buffer, range: Range<usize>, previous: Option<&Counted>) -> Counted {
+    let chars = match previous {
+        Some(previous) if previous.range == range => previous.chars,
+        Some(previous) if previous.range.start == range.start => {
+            resize(buffer, previous.chars, previous.range.end, range.end)
+        }
+        Some(previous) if previous.range.end == range.end => {
+            resize(buffer, previous.chars, range.start, previous.range.start)
+        }
+        _ => count_chars(buffer, range.clone()),
+    };
+
+    // A selection ending at the start of a line doesn't take in that line
+    let last = if buffer.get_text_range_mut(range.end - 1, 1).ok().as_deref() == Some(b"\n") {
+        range.end - 1
+    } else {
+        range.end
+    };
+    let lines = buffer.get_line_number(last) - buffer.get_line_number(range.start) + 1;
+
+    Counted {
+        range,
+        lines,
+        chars,
+    }
+}
+
+/// Characters after one end of a selection moved from `from` to `to`, where
+/// the selection grows when `to` is past `from`
+fn resize(buffer: &mut Buffer, chars: usize, from: usize, to: usize) -> usize {
+    if to >= from {
+        chars + count_chars(buffer, from..to)
+    } else {
+        chars.saturating_sub(count_chars(buffer, to..from))
+    }
+}
+
+/// Characters in a range of the buffer, or its bytes when it is too large to
+/// read
+fn count_chars(buffer: &mut Buffer, range: Range<usize>) -> usize {
+    if range.len() > MAX_COUNTED_SELECTION_BYTES {
+        return range.len();
+    }
+    // Every UTF-8 character has exactly one byte that isn't a continuation
+    read(buffer, range)
+        .iter()
+        .filter(|&&b| b & 0xC0 != 0x80)
+        .count()
+}
+
+fn read(buffer: &mut Buffer, range: Range<usize>) -> Vec<u8> {
+    buffer
+        .get_text_range_mut(range.start, range.len())
+        .unwrap_or_default()
+}
+
+/// Words in `bytes`: runs of anything but whitespace
+fn count_words(bytes: &[u8]) -> usize {
+    String::from_utf8_lossy(bytes).split_whitespace().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_counts_lines_and_chars() {
+        let mut buffer = Buffer::from_str_test("héllo\nworld\nagain\n");
+        let mut stats = SelectionStats::new();
+
+        // Within one line, multibyte characters counted once
+        assert_eq!(
+            stats.size(&mut buffer, &[0..6]),
+            SelectionSize {
+                selections: 1,
+                lines: 1,
+                chars: 5
+            }
+        );
+        // Ending at the start of a line doesn't count that line
+        let size = stats.size(&mut buffer, &[0..13]);
+        assert_eq!((size.lines, size.chars), (2, 12));
+        let size = stats.size(&mut buffer, &[0..14]);
+        assert_eq!((size.lines, size.chars), (3, 13));
+        // Empty selections are left out, the others summed
+        let size = stats.size(&mut buffer, &[0..2, 3..3, 7..12]);
+        assert_eq!((size.selections, size.lines, size.chars), (2, 2, 7));
+    }
+
+    #[test]
+    fn test_moving_one_end_matches_a_full_count() {
+        let text = "ünïcode text\nmore text\n";
+        let mut buffer = Buffer::from_str_test(text);
+        let mut stats = SelectionStats::new();
+        let mut moves = vec![0..2, 0..5, 0..text.len(), 0..3, 2..3, 9..text.len()];
+        moves.extend([14..text.len(), 14..14, 3..9]);
+        for range in moves {
+            let size = stats.size(&mut buffer, &[range.clone()]);
+            let expected = text[range.clone()].chars().count();
+            assert_eq!(size.chars, expected, "{:?}", range);
+        }
+    }
+
+    #[test]
+    fn test_edits_recount() {
+        let mut buffer = Buffer::from_str_test("one two");
+        let mut stats = SelectionStats::new();
+        assert_eq!(stats.size(&mut buffer, &[0..7]).chars, 7);
+        assert_eq!(stats.words(&mut buffer), Some(2));
+
+        buffer.insert(3, " and");
+        assert_eq!(stats.size(&mut buffer, &[0..7]).chars, 7);
+        assert_eq!(stats.words(&mut buffer), Some(2));
+        assert_eq!(stats.size(&mut buffer, &[0..11]).chars, 11);
+        assert_eq!(stats.words(&mut buffer), Some(3));
+    }
+}
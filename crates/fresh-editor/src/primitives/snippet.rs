@@ -1,12 +1,19 @@
-//! LSP snippet parser and expander.
+//! Snippet parser, expander and tabstop session.
 //!
-//! Parses LSP snippet syntax and expands it to plain text with cursor positioning.
-//! Supports:
+//! Parses LSP/VS Code snippet syntax, expands it to plain text and records
+//! where each tabstop ended up. Supports:
 //! - `$0` - final cursor position
-//! - `$n` - tabstops (expanded as empty, cursor goes to $0 or end)
-//! - `${n:text}` - tabstops with default text (uses the default)
+//! - `$n` - tabstops (empty, or mirroring the text of `${n:...}` elsewhere)
+//! - `${n:text}` - tabstops with default text, which may nest other tabstops
 //! - `${n|choice1,choice2|}` - choices (uses first choice)
+//! - `${VAR:text}` - variables (expanded to their default text)
 //! - `\\$` - escaped dollar sign
+//!
+//! `SnippetSession` then tracks the tabstop ranges of an inserted snippet
+//! through edits, so Tab can move between them.
+
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
 
 /// Result of expanding a snippet
 #[derive(Debug, Clone, PartialEq)]
@@ -15,6 +22,65 @@ pub struct ExpandedSnippet {
     pub text: String,
     /// Cursor offset from start of inserted text (where $0 was, or end if no $0)
     pub cursor_offset: usize,
+    /// Tabstops other than `$0`, in order, with their ranges in `text`
+    pub tabstops: Vec<Tabstop>,
+}
+
+/// A numbered tabstop, with one range per occurrence (mirrors)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tabstop {
+    pub index: u32,
+    pub ranges: Vec<Range<usize>>,
+}
+
+impl ExpandedSnippet {
+    /// Continue every line after the first at `indent`
+    ///
+    /// Keeps the cursor offset and tabstop ranges pointing at the same text.
+    pub fn indented(self, indent: &str) -> Self {
+        if indent.is_empty() || !self.text.contains('\n') {
+            return self;
+        }
+        let mut text = String::with_capacity(self.text.len());
+        let mut line_starts = Vec::new();
+        for (i, c) in self.text.char_indices() {
+            text.push(c);
+            if c == '\n' {
+                text.push_str(indent);
+                line_starts.push(i + 1);
+            }
+        }
+        let shift = |offset: usize| {
+            offset + indent.len() * line_starts.iter().filter(|&&s| s <= offset).count()
+        };
+        Self {
+            text,
+            cursor_offset: shift(self.cursor_offset),
+            tabstops: self
+                .tabstops
+                .into_iter()
+                .map(|tabstop| Tabstop {
+                    index: tabstop.index,
+                    ranges: tabstop
+                        .ranges
+                        .into_iter()
+                        .map(|range| shift(range.start)..shift(range.end))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Parsed snippet syntax
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    /// A tabstop (`index` is a number) or a variable (`index` is `None`)
+    Placeholder {
+        index: Option<u32>,
+        children: Vec<Node>,
+    },
 }
 
 /// Expand an LSP snippet to plain text
@@ -32,143 +98,335 @@ pub struct ExpandedSnippet {
 /// let result = expand_snippet("foo(${1:arg})");
 /// assert_eq!(result.text, "foo(arg)");
 /// assert_eq!(result.cursor_offset, 8); // cursor at end (no $0)
+/// assert_eq!(result.tabstops[0].ranges, vec![4..7]);
 /// ```
 pub fn expand_snippet(snippet: &str) -> ExpandedSnippet {
-    let mut result = String::new();
-    let mut cursor_offset: Option<usize> = None;
     let mut chars = snippet.chars().peekable();
+    let nodes = parse_nodes(&mut chars, false);
+
+    let mut defaults = HashMap::new();
+    collect_defaults(&nodes, &mut defaults);
+
+    let mut renderer = Renderer {
+        text: String::new(),
+        final_offset: None,
+        tabstops: BTreeMap::new(),
+        defaults,
+        expanding: Vec::new(),
+    };
+    renderer.render(&nodes);
+
+    ExpandedSnippet {
+        cursor_offset: renderer.final_offset.unwrap_or(renderer.text.len()),
+        tabstops: renderer
+            .tabstops
+            .into_iter()
+            .map(|(index, ranges)| Tabstop { index, ranges })
+            .collect(),
+        text: renderer.text,
+    }
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+
+/// Parse until the end of input, or the `}` closing a placeholder when
+/// `in_braces`
+fn parse_nodes(chars: &mut Chars, in_braces: bool) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut text = String::new();
+    // Literal braces inside a placeholder, so `${1:{x}}` keeps `{x}`
+    let mut depth = 0;
 
     while let Some(c) = chars.next() {
-        if c == '\\' {
-            // Escape sequence
-            if let Some(&next) = chars.peek() {
-                if next == '$' || next == '\\' || next == '}' {
-                    result.push(chars.next().unwrap());
-                    continue;
+        match c {
+            '\\' => {
+                // Escape sequence
+                match chars.peek() {
+                    Some(&next) if next == '$' || next == '\\' || next == '}' => {
+                        text.push(next);
+                        chars.next();
+                    }
+                    _ => text.push(c),
                 }
             }
-            result.push(c);
-        } else if c == '$' {
-            // Snippet placeholder
-            if let Some(&next) = chars.peek() {
-                if next == '{' {
-                    // ${...} syntax
+            '{' if in_braces => {
+                depth += 1;
+                text.push(c);
+            }
+            '}' if in_braces => {
+                if depth == 0 {
+                    break;
+                }
+                depth -= 1;
+                text.push(c);
+            }
+            '$' => match chars.peek() {
+                Some('{') => {
                     chars.next(); // consume '{'
-                    let (expanded, is_final) = parse_brace_placeholder(&mut chars);
-                    if is_final {
-                        cursor_offset = Some(result.len());
+                    flush_text(&mut text, &mut nodes);
+                    nodes.push(parse_brace_placeholder(chars));
+                }
+                Some(next) if next.is_ascii_digit() => {
+                    flush_text(&mut text, &mut nodes);
+                    let index = parse_number(chars);
+                    nodes.push(Node::Placeholder {
+                        index,
+                        children: Vec::new(),
+                    });
+                }
+                // Not a valid placeholder, keep the $
+                _ => text.push(c),
+            },
+            _ => text.push(c),
+        }
+    }
+
+    flush_text(&mut text, &mut nodes);
+    nodes
+}
+
+fn flush_text(text: &mut String, nodes: &mut Vec<Node>) {
+    if !text.is_empty() {
+        nodes.push(Node::Text(std::mem::take(text)));
+    }
+}
+
+fn parse_number(chars: &mut Chars) -> Option<u32> {
+    let mut num = String::new();
+    while let Some(&d) = chars.peek() {
+        if !d.is_ascii_digit() {
+            break;
+        }
+        num.push(d);
+        chars.next();
+    }
+    num.parse().ok()
+}
+
+/// Parse a ${...} placeholder, after the `${`
+fn parse_brace_placeholder(chars: &mut Chars) -> Node {
+    let index = match chars.peek() {
+        Some(d) if d.is_ascii_digit() => parse_number(chars),
+        _ => {
+            // Variable name, expanded to its default
+            while chars
+                .peek()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || *c == '_')
+            {
+                chars.next();
+            }
+            None
+        }
+    };
+
+    let children = match chars.next() {
+        // Default text: ${n:default}
+        Some(':') => parse_nodes(chars, true),
+        // Choices: ${n|choice1,choice2|}, use the first choice
+        Some('|') => {
+            let mut choice = String::new();
+            let mut first = true;
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => {
+                        if let Some(next) = chars.next() {
+                            if first {
+                                choice.push(next);
+                            }
+                        }
                     }
-                    result.push_str(&expanded);
-                } else if next.is_ascii_digit() {
-                    // $n syntax
-                    let mut num = String::new();
-                    while let Some(&d) = chars.peek() {
-                        if d.is_ascii_digit() {
-                            num.push(chars.next().unwrap());
-                        } else {
+                    ',' => first = false,
+                    '|' if chars.peek() == Some(&'}') => {
+                        chars.next();
+                        break;
+                    }
+                    _ if first => choice.push(c),
+                    _ => {}
+                }
+            }
+            vec![Node::Text(choice)]
+        }
+        // Just a tabstop number, no default
+        Some('}') | None => Vec::new(),
+        // Unsupported syntax (e.g. transforms), skip to the closing brace
+        Some(_) => {
+            let mut depth = 1;
+            for c in chars.by_ref() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
                             break;
                         }
                     }
-                    if num == "0" {
-                        cursor_offset = Some(result.len());
-                    }
-                    // Other tabstops expand to nothing
-                } else {
-                    // Not a valid placeholder, keep the $
-                    result.push(c);
+                    _ => {}
                 }
-            } else {
-                result.push(c);
             }
-        } else {
-            result.push(c);
+            Vec::new()
         }
-    }
+    };
 
-    ExpandedSnippet {
-        cursor_offset: cursor_offset.unwrap_or(result.len()),
-        text: result,
+    Node::Placeholder { index, children }
+}
+
+/// The first non-empty text of each tabstop, used by its mirrors
+fn collect_defaults<'a>(nodes: &'a [Node], defaults: &mut HashMap<u32, &'a [Node]>) {
+    for node in nodes {
+        if let Node::Placeholder { index, children } = node {
+            if let Some(index) = index {
+                if !children.is_empty() {
+                    defaults.entry(*index).or_insert(children.as_slice());
+                }
+            }
+            collect_defaults(children, defaults);
+        }
     }
 }
 
-/// Parse a ${...} placeholder
-/// Returns (expanded_text, is_final_cursor)
-fn parse_brace_placeholder(chars: &mut std::iter::Peekable<std::str::Chars>) -> (String, bool) {
-    let mut content = String::new();
-    let mut depth = 1;
+struct Renderer<'a> {
+    text: String,
+    final_offset: Option<usize>,
+    tabstops: BTreeMap<u32, Vec<Range<usize>>>,
+    defaults: HashMap<u32, &'a [Node]>,
+    /// Tabstops being expanded, to stop a tabstop mirroring itself
+    expanding: Vec<u32>,
+}
 
-    // Collect everything until matching '}'
-    while let Some(c) = chars.next() {
-        if c == '{' {
-            depth += 1;
-            content.push(c);
-        } else if c == '}' {
-            depth -= 1;
-            if depth == 0 {
-                break;
-            }
-            content.push(c);
-        } else if c == '\\' {
-            // Handle escapes inside braces
-            if let Some(&next) = chars.peek() {
-                if next == '$' || next == '\\' || next == '}' || next == '|' {
-                    content.push(chars.next().unwrap());
-                    continue;
+impl<'a> Renderer<'a> {
+    fn render(&mut self, nodes: &'a [Node]) {
+        for node in nodes {
+            match node {
+                Node::Text(text) => self.text.push_str(text),
+                Node::Placeholder {
+                    index: None,
+                    children,
+                } => self.render(children),
+                Node::Placeholder {
+                    index: Some(0),
+                    children,
+                } => {
+                    self.final_offset.get_or_insert(self.text.len());
+                    self.render(children);
+                }
+                Node::Placeholder {
+                    index: Some(index),
+                    children,
+                } => {
+                    let start = self.text.len();
+                    if !self.expanding.contains(index) {
+                        let content = if children.is_empty() {
+                            self.defaults.get(index).copied().unwrap_or_default()
+                        } else {
+                            children.as_slice()
+                        };
+                        self.expanding.push(*index);
+                        self.render(content);
+                        self.expanding.pop();
+                    }
+                    self.tabstops
+                        .entry(*index)
+                        .or_default()
+                        .push(start..self.text.len());
                 }
             }
-            content.push(c);
-        } else {
-            content.push(c);
         }
     }
+}
 
-    // Parse the content: n or n:default or n|choices|
-    let (tabstop, default) = parse_placeholder_content(&content);
-
-    let is_final = tabstop == Some(0);
-    (default, is_final)
+/// Tabstops of an inserted snippet that are still being filled in
+///
+/// Holds buffer ranges for each tabstop in visiting order, with the final
+/// cursor position last. The ranges follow edits through
+/// `adjust_for_insert`/`adjust_for_delete`; typing at the edge of the
+/// current tabstop grows it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnippetSession {
+    tabstops: Vec<Vec<Range<usize>>>,
+    current: usize,
 }
 
-/// Parse placeholder content like "1", "1:default", or "1|a,b,c|"
-/// Returns (tabstop_number, default_text)
-fn parse_placeholder_content(content: &str) -> (Option<u32>, String) {
-    // Find the tabstop number
-    let mut chars = content.chars().peekable();
-    let mut num_str = String::new();
-
-    while let Some(&c) = chars.peek() {
-        if c.is_ascii_digit() {
-            num_str.push(chars.next().unwrap());
-        } else {
-            break;
+impl SnippetSession {
+    /// Session for a snippet inserted at `position`, `None` if it has no
+    /// tabstops to visit
+    pub fn new(expanded: &ExpandedSnippet, position: usize) -> Option<Self> {
+        if expanded.tabstops.is_empty() {
+            return None;
         }
+        let mut tabstops: Vec<Vec<Range<usize>>> = expanded
+            .tabstops
+            .iter()
+            .map(|tabstop| {
+                tabstop
+                    .ranges
+                    .iter()
+                    .map(|range| position + range.start..position + range.end)
+                    .collect()
+            })
+            .collect();
+        let final_position = position + expanded.cursor_offset;
+        tabstops.push(vec![final_position..final_position]);
+        Some(Self {
+            tabstops,
+            current: 0,
+        })
+    }
+
+    /// Ranges of the current tabstop, the first one is the primary
+    pub fn current_ranges(&self) -> &[Range<usize>] {
+        &self.tabstops[self.current]
     }
 
-    let tabstop = num_str.parse::<u32>().ok();
+    /// Whether the current tabstop is the final cursor position
+    pub fn is_at_final(&self) -> bool {
+        self.current + 1 == self.tabstops.len()
+    }
+
+    /// Move to the next tabstop
+    pub fn next(&mut self) {
+        self.current = (self.current + 1).min(self.tabstops.len() - 1);
+    }
 
-    // Check what follows
-    match chars.peek() {
-        Some(':') => {
-            // Default text: ${n:default}
-            chars.next(); // consume ':'
-            let default: String = chars.collect();
-            // Recursively expand nested snippets in default
-            let expanded = expand_snippet(&default);
-            (tabstop, expanded.text)
+    /// Move to the previous tabstop, returns false at the first one
+    pub fn prev(&mut self) -> bool {
+        if self.current == 0 {
+            return false;
         }
-        Some('|') => {
-            // Choices: ${n|choice1,choice2|}
-            chars.next(); // consume '|'
-            let choices: String = chars.collect();
-            // Remove trailing '|' if present
-            let choices = choices.trim_end_matches('|');
-            // Use first choice
-            let first_choice = choices.split(',').next().unwrap_or("");
-            (tabstop, first_choice.to_string())
+        self.current -= 1;
+        true
+    }
+
+    /// Adjust the ranges for `length` bytes inserted at `position`
+    pub fn adjust_for_insert(&mut self, position: usize, length: usize) {
+        for (i, ranges) in self.tabstops.iter_mut().enumerate() {
+            let active = i == self.current;
+            for range in ranges {
+                let inside = if active {
+                    range.start <= position && position <= range.end
+                } else {
+                    range.start < position && position < range.end
+                };
+                if inside {
+                    range.end += length;
+                } else if position <= range.start {
+                    range.start += length;
+                    range.end += length;
+                }
+            }
         }
-        _ => {
-            // Just a tabstop number, no default
-            (tabstop, String::new())
+    }
+
+    /// Adjust the ranges for `length` bytes deleted at `position`
+    pub fn adjust_for_delete(&mut self, position: usize, length: usize) {
+        let adjust = |offset: usize| {
+            if offset >= position + length {
+                offset - length
+            } else {
+                offset.min(position)
+            }
+        };
+        for range in self.tabstops.iter_mut().flatten() {
+            *range = adjust(range.start)..adjust(range.end);
         }
     }
 }
@@ -277,4 +535,74 @@ mod tests {
         assert_eq!(result.text, "foo()");
         assert_eq!(result.cursor_offset, 5);
     }
+
+    #[test]
+    fn test_tabstop_ranges() {
+        let result = expand_snippet("fn ${1:name}(${2:args}) { $0 }");
+        assert_eq!(
+            result.tabstops,
+            vec![
+                Tabstop {
+                    index: 1,
+                    ranges: vec![3..7]
+                },
+                Tabstop {
+                    index: 2,
+                    ranges: vec![8..12]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mirrored_tabstop() {
+        let result = expand_snippet("let ${1:x} = $2; use($1);");
+        assert_eq!(result.text, "let x = ; use(x);");
+        assert_eq!(result.tabstops[0].ranges, vec![4..5, 14..15]);
+        assert_eq!(result.tabstops[1].ranges, vec![8..8]);
+    }
+
+    #[test]
+    fn test_variable_uses_default() {
+        let result = expand_snippet("${TM_FILENAME:file}: ${UNKNOWN}$1");
+        assert_eq!(result.text, "file: ");
+        assert_eq!(result.tabstops[0].ranges, vec![6..6]);
+    }
+
+    #[test]
+    fn test_indented() {
+        let result = expand_snippet("if ${1:cond} {\n    $0\n}").indented("  ");
+        assert_eq!(result.text, "if cond {\n      \n  }");
+        assert_eq!(result.cursor_offset, 16);
+        assert_eq!(result.tabstops[0].ranges, vec![3..7]);
+    }
+
+    #[test]
+    fn test_session_follows_edits() {
+        let expanded = expand_snippet("f(${1:a}, $1, ${2:b})$0");
+        assert_eq!(expanded.text, "f(a, a, b)");
+        // Inserted after 10 bytes of other text
+        let mut session = SnippetSession::new(&expanded, 10).unwrap();
+        assert_eq!(session.current_ranges(), &[12..13, 15..16]);
+
+        // Replace both mirrors of the first tabstop with "xy"
+        session.adjust_for_delete(15, 1);
+        session.adjust_for_insert(15, 2);
+        session.adjust_for_delete(12, 1);
+        session.adjust_for_insert(12, 2);
+        assert_eq!(session.current_ranges(), &[12..14, 16..18]);
+
+        session.next();
+        assert_eq!(session.current_ranges(), &[20..21]);
+        assert!(!session.is_at_final());
+        session.next();
+        assert!(session.is_at_final());
+        assert_eq!(session.current_ranges(), &[22..22]);
+        assert!(session.prev());
+    }
+
+    #[test]
+    fn test_no_session_without_tabstops() {
+        assert_eq!(SnippetSession::new(&expand_snippet("foo($0)"), 0), None);
+    }
 }
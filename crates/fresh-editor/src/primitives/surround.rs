@@ -0,0 +1,96 @@
+//! Delimiter pairs for surrounding text: the pair asked for in a prompt,
+//! and the quotes around a spot on a line.
+//!
+//! Brackets around text are found with the bracket matcher (see
+//! [`crate::view::bracket_highlight_overlay::find_enclosing_bracket`]);
+//! quotes can't be matched that way since the same character opens and
+//! closes them, so they are paired up from the start of the line.
+
+/// Quote characters recognized around text
+const QUOTES: &[u8] = b"\"'`";
+
+/// Brackets whose other half is filled in when only one is typed
+const BRACKETS: &[(char, char)] = &[('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Opening and closing text for what was typed in a surround prompt: a
+/// bracket stands for its pair (either half will do), any other character
+/// for itself on both sides, and two characters for the opening and closing
+/// ones
+pub fn surround_pair(input: &str) -> Option<(String, String)> {
+    let mut chars = input.chars();
+    let (first, second) = (chars.next()?, chars.next());
+    if chars.next().is_some() {
+        return None;
+    }
+    let (open, close) = match second {
+        Some(second) => (first, second),
+        None => BRACKETS
+            .iter()
+            .find(|(open, close)| first == *open || first == *close)
+            .copied()
+            .unwrap_or((first, first)),
+    };
+    Some((open.to_string(), close.to_string()))
+}
+
+/// Positions in `line` of the innermost quotes around `start..end`, which
+/// must be within the line. A quote escaped with a backslash inside a
+/// string doesn't end it.
+pub fn enclosing_quotes(line: &[u8], start: usize, end: usize) -> Option<(usize, usize)> {
+    let mut open: Option<(usize, u8)> = None;
+    let mut i = 0;
+    while i < line.len() {
+        let b = line[i];
+        if b == b'\\' && open.is_some() {
+            i += 2;
+            continue;
+        }
+        if QUOTES.contains(&b) {
+            match open {
+                None => open = Some((i, b)),
+                Some((open_pos, quote)) if quote == b => {
+                    if open_pos < start && i >= end {
+                        return Some((open_pos, i));
+                    }
+                    open = None;
+                }
+                Some(_) => {}
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(open: &str, close: &str) -> Option<(String, String)> {
+        Some((open.to_string(), close.to_string()))
+    }
+
+    #[test]
+    fn test_surround_pair() {
+        assert_eq!(surround_pair("("), pair("(", ")"));
+        assert_eq!(surround_pair("]"), pair("[", "]"));
+        assert_eq!(surround_pair("\""), pair("\"", "\""));
+        assert_eq!(surround_pair("*"), pair("*", "*"));
+        assert_eq!(surround_pair("«»"), pair("«", "»"));
+        assert_eq!(surround_pair(""), None);
+        assert_eq!(surround_pair("abc"), None);
+    }
+
+    #[test]
+    fn test_enclosing_quotes() {
+        let line = br#"say("a \"quoted\" word", 'b')"#;
+        // Inside the first string, past its escaped quotes
+        assert_eq!(enclosing_quotes(line, 18, 18), Some((4, 22)));
+        assert_eq!(enclosing_quotes(line, 5, 22), Some((4, 22)));
+        assert_eq!(enclosing_quotes(line, 26, 26), Some((25, 27)));
+        // Between the strings
+        assert_eq!(enclosing_quotes(line, 23, 23), None);
+        // Quotes of the other kind inside a string don't count
+        assert_eq!(enclosing_quotes(b"x = \"it's\"", 6, 6), Some((4, 9)));
+    }
+}
@@ -0,0 +1,223 @@
+//! URLs and file paths in buffer text
+//!
+//! Finds the link under a position of a single line, for Ctrl+click and
+//! "Open Link Under Cursor" in editor buffers. Links are whitespace-delimited
+//! tokens, tolerant of the punctuation prose and markup wrap them in: quotes,
+//! angle brackets, brackets and a trailing `.`, `,` or `:`. A path may end in
+//! `:line` or `:line:col`.
+
+use std::ops::Range;
+
+const URL_SCHEMES: [&str; 2] = ["http://", "https://"];
+
+/// Where a link in buffer text points
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextLinkTarget {
+    /// An `http://` or `https://` URL
+    Url(String),
+    /// A relative or absolute file path; line and column are 1-based
+    Path {
+        path: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+}
+
+/// A link found in a line of text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextLink {
+    /// Byte range of the link in the line, including any `:line` suffix
+    pub range: Range<usize>,
+    pub target: TextLinkTarget,
+}
+
+/// Characters that never belong to a link
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '"' | '\'' | '`' | '<' | '>')
+}
+
+/// The link covering byte `offset` of `line`
+pub fn link_at(line: &str, offset: usize) -> Option<TextLink> {
+    if offset >= line.len() || !line.is_char_boundary(offset) {
+        return None;
+    }
+    if line[offset..].chars().next().is_some_and(is_separator) {
+        return None;
+    }
+    let start = line[..offset]
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| is_separator(c))
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    let end = line[offset..]
+        .char_indices()
+        .find(|&(_, c)| is_separator(c))
+        .map_or(line.len(), |(i, _)| offset + i);
+    // A URL glued to the text before it, as in markdown's `[text](url)`
+    let start = URL_SCHEMES
+        .iter()
+        .flat_map(|scheme| line[start..end].match_indices(scheme))
+        .map(|(i, _)| start + i)
+        .filter(|&i| i <= offset)
+        .max()
+        .unwrap_or(start);
+
+    let range = trim_token(line, start..end);
+    if !range.contains(&offset) {
+        return None;
+    }
+    let token = &line[range.clone()];
+    let target = if is_url(token) {
+        TextLinkTarget::Url(token.to_string())
+    } else {
+        path_target(token)?
+    };
+    Some(TextLink { range, target })
+}
+
+/// Drop the punctuation a token picks up from the surrounding text: opening
+/// brackets in front, and closing brackets and sentence punctuation behind.
+/// A closing bracket is kept when the token opens one, as in
+/// `https://en.wikipedia.org/wiki/Rust_(programming_language)`.
+fn trim_token(line: &str, mut range: Range<usize>) -> Range<usize> {
+    while let Some(c) = line[range.clone()].chars().next() {
+        if !matches!(c, '(' | '[' | '{') {
+            break;
+        }
+        range.start += c.len_utf8();
+    }
+    while let Some(c) = line[range.clone()].chars().next_back() {
+        let token = &line[range.clone()];
+        let keep = match c {
+            ')' => token.matches('(').count() >= token.matches(')').count(),
+            ']' => token.matches('[').count() >= token.matches(']').count(),
+            '}' => token.matches('{').count() >= token.matches('}').count(),
+            '.' | ',' | ';' | ':' | '!' | '?' => false,
+            _ => true,
+        };
+        if keep {
+            break;
+        }
+        range.end -= c.len_utf8();
+    }
+    range
+}
+
+fn is_url(token: &str) -> bool {
+    URL_SCHEMES
+        .iter()
+        .any(|scheme| token.len() > scheme.len() && token.starts_with(scheme))
+}
+
+/// A path with an optional `:line[:col]` suffix. Tokens that don't look
+/// like paths — no separator and no extension — are not links.
+fn path_target(token: &str) -> Option<TextLinkTarget> {
+    let mut path = token;
+    let mut numbers = Vec::new();
+    while numbers.len() < 2 {
+        let Some((rest, suffix)) = path.rsplit_once(':') else {
+            break;
+        };
+        match suffix.parse::<usize>() {
+            Ok(n) if !rest.is_empty() => {
+                numbers.insert(0, n);
+                path = rest;
+            }
+            _ => break,
+        }
+    }
+
+    let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    let has_extension = file_name
+        .rsplit_once('.')
+        .is_some_and(|(stem, ext)| !stem.is_empty() && is_extension(ext));
+    let has_separator = path.contains(['/', '\\']);
+    if !(has_separator || has_extension) || path.contains("://") {
+        return None;
+    }
+    if !path.chars().any(char::is_alphanumeric) {
+        return None;
+    }
+
+    Some(TextLinkTarget::Path {
+        path: path.to_string(),
+        line: numbers.first().copied(),
+        column: numbers.get(1).copied(),
+    })
+}
+
+fn is_extension(ext: &str) -> bool {
+    (1..=10).contains(&ext.len())
+        && ext.chars().all(|c| c.is_ascii_alphanumeric())
+        && ext.chars().any(|c| c.is_ascii_alphabetic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(line: &str, offset: usize) -> Option<(&str, TextLinkTarget)> {
+        link_at(line, offset).map(|link| (&line[link.range.clone()], link.target))
+    }
+
+    fn path(path: &str, line: Option<usize>, column: Option<usize>) -> TextLinkTarget {
+        TextLinkTarget::Path {
+            path: path.to_string(),
+            line,
+            column,
+        }
+    }
+
+    #[test]
+    fn test_urls() {
+        let url = |s: &str| TextLinkTarget::Url(s.to_string());
+        assert_eq!(
+            link("see https://example.com/a?b=1.", 10),
+            Some((
+                "https://example.com/a?b=1",
+                url("https://example.com/a?b=1")
+            ))
+        );
+        assert_eq!(
+            link("<https://example.com>", 3),
+            Some(("https://example.com", url("https://example.com")))
+        );
+        assert_eq!(
+            link("[docs](http://x.org/a_(b))", 12),
+            Some(("http://x.org/a_(b)", url("http://x.org/a_(b)")))
+        );
+        assert_eq!(link("https://", 2), None);
+    }
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(
+            link("error at src/main.rs:42:7:", 12),
+            Some(("src/main.rs:42:7", path("src/main.rs", Some(42), Some(7))))
+        );
+        assert_eq!(
+            link(r#"open "/tmp/notes.txt", please"#, 8),
+            Some(("/tmp/notes.txt", path("/tmp/notes.txt", None, None)))
+        );
+        assert_eq!(
+            link("(see README.md:3)", 6),
+            Some(("README.md:3", path("README.md", Some(3), None)))
+        );
+        assert_eq!(
+            link("cd ../lib", 5),
+            Some(("../lib", path("../lib", None, None)))
+        );
+    }
+
+    #[test]
+    fn test_non_links() {
+        // Plain words, version numbers, punctuation and separators
+        assert_eq!(link("hello world", 2), None);
+        assert_eq!(link("version 1.2", 9), None);
+        assert_eq!(link("a ... b", 3), None);
+        assert_eq!(link("x src/a.rs", 1), None);
+        assert_eq!(link("ftp://host/file", 3), None);
+        // The trailing period is not part of the link
+        assert_eq!(link("see a/b.", 7), None);
+    }
+}
@@ -0,0 +1,3420 @@
+//! Unicode characters by name
+//!
+//! A table of names for the characters most often inserted by hand: arrows,
+//! box drawing, math, common symbols and emoji. Names are the Unicode
+//! character names in lowercase. Characters outside the table can still be
+//! inserted by codepoint.
+
+/// Named characters, grouped by block
+pub static UNICODE_NAMES: &[(char, &str)] = &[
+    // Latin-1 punctuation and symbols
+    ('\u{a1}', "inverted exclamation mark"),
+    ('\u{a2}', "cent sign"),
+    ('\u{a3}', "pound sign"),
+    ('\u{a4}', "currency sign"),
+    ('\u{a5}', "yen sign"),
+    ('\u{a6}', "broken bar"),
+    ('\u{a7}', "section sign"),
+    ('\u{a8}', "diaeresis"),
+    ('\u{a9}', "copyright sign"),
+    ('\u{aa}', "feminine ordinal indicator"),
+    ('\u{ab}', "left-pointing double angle quotation mark"),
+    ('\u{ac}', "not sign"),
+    ('\u{ae}', "registered sign"),
+    ('\u{af}', "macron"),
+    ('\u{b0}', "degree sign"),
+    ('\u{b1}', "plus-minus sign"),
+    ('\u{b2}', "superscript two"),
+    ('\u{b3}', "superscript three"),
+    ('\u{b4}', "acute accent"),
+    ('\u{b5}', "micro sign"),
+    ('\u{b6}', "pilcrow sign"),
+    ('\u{b7}', "middle dot"),
+    ('\u{b8}', "cedilla"),
+    ('\u{b9}', "superscript one"),
+    ('\u{ba}', "masculine ordinal indicator"),
+    ('\u{bb}', "right-pointing double angle quotation mark"),
+    ('\u{bc}', "vulgar fraction one quarter"),
+    ('\u{bd}', "vulgar fraction one half"),
+    ('\u{be}', "vulgar fraction three quarters"),
+    ('\u{bf}', "inverted question mark"),
+    ('\u{d7}', "multiplication sign"),
+    ('\u{f7}', "division sign"),
+    // Greek letters
+    ('\u{391}', "greek capital letter alpha"),
+    ('\u{392}', "greek capital letter beta"),
+    ('\u{393}', "greek capital letter gamma"),
+    ('\u{394}', "greek capital letter delta"),
+    ('\u{395}', "greek capital letter epsilon"),
+    ('\u{396}', "greek capital letter zeta"),
+    ('\u{397}', "greek capital letter eta"),
+    ('\u{398}', "greek capital letter theta"),
+    ('\u{399}', "greek capital letter iota"),
+    ('\u{39a}', "greek capital letter kappa"),
+    ('\u{39b}', "greek capital letter lamda"),
+    ('\u{39c}', "greek capital letter mu"),
+    ('\u{39d}', "greek capital letter nu"),
+    ('\u{39e}', "greek capital letter xi"),
+    ('\u{39f}', "greek capital letter omicron"),
+    ('\u{3a0}', "greek capital letter pi"),
+    ('\u{3a1}', "greek capital letter rho"),
+    ('\u{3a3}', "greek capital letter sigma"),
+    ('\u{3a4}', "greek capital letter tau"),
+    ('\u{3a5}', "greek capital letter upsilon"),
+    ('\u{3a6}', "greek capital letter phi"),
+    ('\u{3a7}', "greek capital letter chi"),
+    ('\u{3a8}', "greek capital letter psi"),
+    ('\u{3a9}', "greek capital letter omega"),
+    ('\u{3b1}', "greek small letter alpha"),
+    ('\u{3b2}', "greek small letter beta"),
+    ('\u{3b3}', "greek small letter gamma"),
+    ('\u{3b4}', "greek small letter delta"),
+    ('\u{3b5}', "greek small letter epsilon"),
+    ('\u{3b6}', "greek small letter zeta"),
+    ('\u{3b7}', "greek small letter eta"),
+    ('\u{3b8}', "greek small letter theta"),
+    ('\u{3b9}', "greek small letter iota"),
+    ('\u{3ba}', "greek small letter kappa"),
+    ('\u{3bb}', "greek small letter lamda"),
+    ('\u{3bc}', "greek small letter mu"),
+    ('\u{3bd}', "greek small letter nu"),
+    ('\u{3be}', "greek small letter xi"),
+    ('\u{3bf}', "greek small letter omicron"),
+    ('\u{3c0}', "greek small letter pi"),
+    ('\u{3c1}', "greek small letter rho"),
+    ('\u{3c2}', "greek small letter final sigma"),
+    ('\u{3c3}', "greek small letter sigma"),
+    ('\u{3c4}', "greek small letter tau"),
+    ('\u{3c5}', "greek small letter upsilon"),
+    ('\u{3c6}', "greek small letter phi"),
+    ('\u{3c7}', "greek small letter chi"),
+    ('\u{3c8}', "greek small letter psi"),
+    ('\u{3c9}', "greek small letter omega"),
+    // General punctuation
+    ('\u{2010}', "hyphen"),
+    ('\u{2011}', "non-breaking hyphen"),
+    ('\u{2012}', "figure dash"),
+    ('\u{2013}', "en dash"),
+    ('\u{2014}', "em dash"),
+    ('\u{2015}', "horizontal bar"),
+    ('\u{2016}', "double vertical line"),
+    ('\u{2017}', "double low line"),
+    ('\u{2018}', "left single quotation mark"),
+    ('\u{2019}', "right single quotation mark"),
+    ('\u{201a}', "single low-9 quotation mark"),
+    ('\u{201b}', "single high-reversed-9 quotation mark"),
+    ('\u{201c}', "left double quotation mark"),
+    ('\u{201d}', "right double quotation mark"),
+    ('\u{201e}', "double low-9 quotation mark"),
+    ('\u{201f}', "double high-reversed-9 quotation mark"),
+    ('\u{2020}', "dagger"),
+    ('\u{2021}', "double dagger"),
+    ('\u{2022}', "bullet"),
+    ('\u{2023}', "triangular bullet"),
+    ('\u{2024}', "one dot leader"),
+    ('\u{2025}', "two dot leader"),
+    ('\u{2026}', "horizontal ellipsis"),
+    ('\u{2027}', "hyphenation point"),
+    ('\u{2030}', "per mille sign"),
+    ('\u{2031}', "per ten thousand sign"),
+    ('\u{2032}', "prime"),
+    ('\u{2033}', "double prime"),
+    ('\u{2034}', "triple prime"),
+    ('\u{2035}', "reversed prime"),
+    ('\u{2036}', "reversed double prime"),
+    ('\u{2037}', "reversed triple prime"),
+    ('\u{2038}', "caret"),
+    ('\u{2039}', "single left-pointing angle quotation mark"),
+    ('\u{203a}', "single right-pointing angle quotation mark"),
+    ('\u{203b}', "reference mark"),
+    ('\u{203c}', "double exclamation mark"),
+    ('\u{203d}', "interrobang"),
+    ('\u{203e}', "overline"),
+    ('\u{203f}', "undertie"),
+    ('\u{2040}', "character tie"),
+    ('\u{2041}', "caret insertion point"),
+    ('\u{2042}', "asterism"),
+    ('\u{2043}', "hyphen bullet"),
+    ('\u{2044}', "fraction slash"),
+    ('\u{2045}', "left square bracket with quill"),
+    ('\u{2046}', "right square bracket with quill"),
+    ('\u{2047}', "double question mark"),
+    ('\u{2048}', "question exclamation mark"),
+    ('\u{2049}', "exclamation question mark"),
+    ('\u{204a}', "tironian sign et"),
+    ('\u{204b}', "reversed pilcrow sign"),
+    ('\u{204c}', "black leftwards bullet"),
+    ('\u{204d}', "black rightwards bullet"),
+    ('\u{204e}', "low asterisk"),
+    ('\u{204f}', "reversed semicolon"),
+    ('\u{2050}', "close up"),
+    ('\u{2051}', "two asterisks aligned vertically"),
+    ('\u{2052}', "commercial minus sign"),
+    ('\u{2053}', "swung dash"),
+    ('\u{2054}', "inverted undertie"),
+    ('\u{2055}', "flower punctuation mark"),
+    ('\u{2056}', "three dot punctuation"),
+    ('\u{2057}', "quadruple prime"),
+    ('\u{2058}', "four dot punctuation"),
+    ('\u{2059}', "five dot punctuation"),
+    ('\u{205a}', "two dot punctuation"),
+    ('\u{205b}', "four dot mark"),
+    ('\u{205c}', "dotted cross"),
+    ('\u{205d}', "tricolon"),
+    ('\u{205e}', "vertical four dots"),
+    // Superscripts and subscripts
+    ('\u{2070}', "superscript zero"),
+    ('\u{2071}', "superscript latin small letter i"),
+    ('\u{2074}', "superscript four"),
+    ('\u{2075}', "superscript five"),
+    ('\u{2076}', "superscript six"),
+    ('\u{2077}', "superscript seven"),
+    ('\u{2078}', "superscript eight"),
+    ('\u{2079}', "superscript nine"),
+    ('\u{207a}', "superscript plus sign"),
+    ('\u{207b}', "superscript minus"),
+    ('\u{207c}', "superscript equals sign"),
+    ('\u{207d}', "superscript left parenthesis"),
+    ('\u{207e}', "superscript right parenthesis"),
+    ('\u{207f}', "superscript latin small letter n"),
+    ('\u{2080}', "subscript zero"),
+    ('\u{2081}', "subscript one"),
+    ('\u{2082}', "subscript two"),
+    ('\u{2083}', "subscript three"),
+    ('\u{2084}', "subscript four"),
+    ('\u{2085}', "subscript five"),
+    ('\u{2086}', "subscript six"),
+    ('\u{2087}', "subscript seven"),
+    ('\u{2088}', "subscript eight"),
+    ('\u{2089}', "subscript nine"),
+    ('\u{208a}', "subscript plus sign"),
+    ('\u{208b}', "subscript minus"),
+    ('\u{208c}', "subscript equals sign"),
+    ('\u{208d}', "subscript left parenthesis"),
+    ('\u{208e}', "subscript right parenthesis"),
+    ('\u{2090}', "latin subscript small letter a"),
+    ('\u{2091}', "latin subscript small letter e"),
+    ('\u{2092}', "latin subscript small letter o"),
+    ('\u{2093}', "latin subscript small letter x"),
+    ('\u{2094}', "latin subscript small letter schwa"),
+    ('\u{2095}', "latin subscript small letter h"),
+    ('\u{2096}', "latin subscript small letter k"),
+    ('\u{2097}', "latin subscript small letter l"),
+    ('\u{2098}', "latin subscript small letter m"),
+    ('\u{2099}', "latin subscript small letter n"),
+    ('\u{209a}', "latin subscript small letter p"),
+    ('\u{209b}', "latin subscript small letter s"),
+    ('\u{209c}', "latin subscript small letter t"),
+    // Currency symbols
+    ('\u{20a0}', "euro-currency sign"),
+    ('\u{20a1}', "colon sign"),
+    ('\u{20a2}', "cruzeiro sign"),
+    ('\u{20a3}', "french franc sign"),
+    ('\u{20a4}', "lira sign"),
+    ('\u{20a5}', "mill sign"),
+    ('\u{20a6}', "naira sign"),
+    ('\u{20a7}', "peseta sign"),
+    ('\u{20a8}', "rupee sign"),
+    ('\u{20a9}', "won sign"),
+    ('\u{20aa}', "new sheqel sign"),
+    ('\u{20ab}', "dong sign"),
+    ('\u{20ac}', "euro sign"),
+    ('\u{20ad}', "kip sign"),
+    ('\u{20ae}', "tugrik sign"),
+    ('\u{20af}', "drachma sign"),
+    ('\u{20b0}', "german penny sign"),
+    ('\u{20b1}', "peso sign"),
+    ('\u{20b2}', "guarani sign"),
+    ('\u{20b3}', "austral sign"),
+    ('\u{20b4}', "hryvnia sign"),
+    ('\u{20b5}', "cedi sign"),
+    ('\u{20b6}', "livre tournois sign"),
+    ('\u{20b7}', "spesmilo sign"),
+    ('\u{20b8}', "tenge sign"),
+    ('\u{20b9}', "indian rupee sign"),
+    ('\u{20ba}', "turkish lira sign"),
+    ('\u{20bb}', "nordic mark sign"),
+    ('\u{20bc}', "manat sign"),
+    ('\u{20bd}', "ruble sign"),
+    ('\u{20be}', "lari sign"),
+    ('\u{20bf}', "bitcoin sign"),
+    ('\u{20c0}', "som sign"),
+    // Letterlike symbols
+    ('\u{2100}', "account of"),
+    ('\u{2101}', "addressed to the subject"),
+    ('\u{2102}', "double-struck capital c"),
+    ('\u{2103}', "degree celsius"),
+    ('\u{2104}', "centre line symbol"),
+    ('\u{2105}', "care of"),
+    ('\u{2106}', "cada una"),
+    ('\u{2107}', "euler constant"),
+    ('\u{2108}', "scruple"),
+    ('\u{2109}', "degree fahrenheit"),
+    ('\u{210a}', "script small g"),
+    ('\u{210b}', "script capital h"),
+    ('\u{210c}', "black-letter capital h"),
+    ('\u{210d}', "double-struck capital h"),
+    ('\u{210e}', "planck constant"),
+    ('\u{210f}', "planck constant over two pi"),
+    ('\u{2110}', "script capital i"),
+    ('\u{2111}', "black-letter capital i"),
+    ('\u{2112}', "script capital l"),
+    ('\u{2113}', "script small l"),
+    ('\u{2114}', "l b bar symbol"),
+    ('\u{2115}', "double-struck capital n"),
+    ('\u{2116}', "numero sign"),
+    ('\u{2117}', "sound recording copyright"),
+    ('\u{2118}', "script capital p"),
+    ('\u{2119}', "double-struck capital p"),
+    ('\u{211a}', "double-struck capital q"),
+    ('\u{211b}', "script capital r"),
+    ('\u{211c}', "black-letter capital r"),
+    ('\u{211d}', "double-struck capital r"),
+    ('\u{211e}', "prescription take"),
+    ('\u{211f}', "response"),
+    ('\u{2120}', "service mark"),
+    ('\u{2121}', "telephone sign"),
+    ('\u{2122}', "trade mark sign"),
+    ('\u{2123}', "versicle"),
+    ('\u{2124}', "double-struck capital z"),
+    ('\u{2125}', "ounce sign"),
+    ('\u{2126}', "ohm sign"),
+    ('\u{2127}', "inverted ohm sign"),
+    ('\u{2128}', "black-letter capital z"),
+    ('\u{2129}', "turned greek small letter iota"),
+    ('\u{212a}', "kelvin sign"),
+    ('\u{212b}', "angstrom sign"),
+    ('\u{212c}', "script capital b"),
+    ('\u{212d}', "black-letter capital c"),
+    ('\u{212e}', "estimated symbol"),
+    ('\u{212f}', "script small e"),
+    ('\u{2130}', "script capital e"),
+    ('\u{2131}', "script capital f"),
+    ('\u{2132}', "turned capital f"),
+    ('\u{2133}', "script capital m"),
+    ('\u{2134}', "script small o"),
+    ('\u{2135}', "alef symbol"),
+    ('\u{2136}', "bet symbol"),
+    ('\u{2137}', "gimel symbol"),
+    ('\u{2138}', "dalet symbol"),
+    ('\u{2139}', "information source"),
+    ('\u{213a}', "rotated capital q"),
+    ('\u{213b}', "facsimile sign"),
+    ('\u{213c}', "double-struck small pi"),
+    ('\u{213d}', "double-struck small gamma"),
+    ('\u{213e}', "double-struck capital gamma"),
+    ('\u{213f}', "double-struck capital pi"),
+    ('\u{2140}', "double-struck n-ary summation"),
+    ('\u{2141}', "turned sans-serif capital g"),
+    ('\u{2142}', "turned sans-serif capital l"),
+    ('\u{2143}', "reversed sans-serif capital l"),
+    ('\u{2144}', "turned sans-serif capital y"),
+    ('\u{2145}', "double-struck italic capital d"),
+    ('\u{2146}', "double-struck italic small d"),
+    ('\u{2147}', "double-struck italic small e"),
+    ('\u{2148}', "double-struck italic small i"),
+    ('\u{2149}', "double-struck italic small j"),
+    ('\u{214a}', "property line"),
+    ('\u{214b}', "turned ampersand"),
+    ('\u{214c}', "per sign"),
+    ('\u{214d}', "aktieselskab"),
+    ('\u{214e}', "turned small f"),
+    ('\u{214f}', "symbol for samaritan source"),
+    // Number forms
+    ('\u{2150}', "vulgar fraction one seventh"),
+    ('\u{2151}', "vulgar fraction one ninth"),
+    ('\u{2152}', "vulgar fraction one tenth"),
+    ('\u{2153}', "vulgar fraction one third"),
+    ('\u{2154}', "vulgar fraction two thirds"),
+    ('\u{2155}', "vulgar fraction one fifth"),
+    ('\u{2156}', "vulgar fraction two fifths"),
+    ('\u{2157}', "vulgar fraction three fifths"),
+    ('\u{2158}', "vulgar fraction four fifths"),
+    ('\u{2159}', "vulgar fraction one sixth"),
+    ('\u{215a}', "vulgar fraction five sixths"),
+    ('\u{215b}', "vulgar fraction one eighth"),
+    ('\u{215c}', "vulgar fraction three eighths"),
+    ('\u{215d}', "vulgar fraction five eighths"),
+    ('\u{215e}', "vulgar fraction seven eighths"),
+    ('\u{215f}', "fraction numerator one"),
+    ('\u{2160}', "roman numeral one"),
+    ('\u{2161}', "roman numeral two"),
+    ('\u{2162}', "roman numeral three"),
+    ('\u{2163}', "roman numeral four"),
+    ('\u{2164}', "roman numeral five"),
+    ('\u{2165}', "roman numeral six"),
+    ('\u{2166}', "roman numeral seven"),
+    ('\u{2167}', "roman numeral eight"),
+    ('\u{2168}', "roman numeral nine"),
+    ('\u{2169}', "roman numeral ten"),
+    ('\u{216a}', "roman numeral eleven"),
+    ('\u{216b}', "roman numeral twelve"),
+    ('\u{216c}', "roman numeral fifty"),
+    ('\u{216d}', "roman numeral one hundred"),
+    ('\u{216e}', "roman numeral five hundred"),
+    ('\u{216f}', "roman numeral one thousand"),
+    ('\u{2170}', "small roman numeral one"),
+    ('\u{2171}', "small roman numeral two"),
+    ('\u{2172}', "small roman numeral three"),
+    ('\u{2173}', "small roman numeral four"),
+    ('\u{2174}', "small roman numeral five"),
+    ('\u{2175}', "small roman numeral six"),
+    ('\u{2176}', "small roman numeral seven"),
+    ('\u{2177}', "small roman numeral eight"),
+    ('\u{2178}', "small roman numeral nine"),
+    ('\u{2179}', "small roman numeral ten"),
+    ('\u{217a}', "small roman numeral eleven"),
+    ('\u{217b}', "small roman numeral twelve"),
+    ('\u{217c}', "small roman numeral fifty"),
+    ('\u{217d}', "small roman numeral one hundred"),
+    ('\u{217e}', "small roman numeral five hundred"),
+    ('\u{217f}', "small roman numeral one thousand"),
+    ('\u{2180}', "roman numeral one thousand c d"),
+    ('\u{2181}', "roman numeral five thousand"),
+    ('\u{2182}', "roman numeral ten thousand"),
+    ('\u{2183}', "roman numeral reversed one hundred"),
+    ('\u{2184}', "latin small letter reversed c"),
+    ('\u{2185}', "roman numeral six late form"),
+    ('\u{2186}', "roman numeral fifty early form"),
+    ('\u{2187}', "roman numeral fifty thousand"),
+    ('\u{2188}', "roman numeral one hundred thousand"),
+    ('\u{2189}', "vulgar fraction zero thirds"),
+    ('\u{218a}', "turned digit two"),
+    ('\u{218b}', "turned digit three"),
+    // Arrows
+    ('\u{2190}', "leftwards arrow"),
+    ('\u{2191}', "upwards arrow"),
+    ('\u{2192}', "rightwards arrow"),
+    ('\u{2193}', "downwards arrow"),
+    ('\u{2194}', "left right arrow"),
+    ('\u{2195}', "up down arrow"),
+    ('\u{2196}', "north west arrow"),
+    ('\u{2197}', "north east arrow"),
+    ('\u{2198}', "south east arrow"),
+    ('\u{2199}', "south west arrow"),
+    ('\u{219a}', "leftwards arrow with stroke"),
+    ('\u{219b}', "rightwards arrow with stroke"),
+    ('\u{219c}', "leftwards wave arrow"),
+    ('\u{219d}', "rightwards wave arrow"),
+    ('\u{219e}', "leftwards two headed arrow"),
+    ('\u{219f}', "upwards two headed arrow"),
+    ('\u{21a0}', "rightwards two headed arrow"),
+    ('\u{21a1}', "downwards two headed arrow"),
+    ('\u{21a2}', "leftwards arrow with tail"),
+    ('\u{21a3}', "rightwards arrow with tail"),
+    ('\u{21a4}', "leftwards arrow from bar"),
+    ('\u{21a5}', "upwards arrow from bar"),
+    ('\u{21a6}', "rightwards arrow from bar"),
+    ('\u{21a7}', "downwards arrow from bar"),
+    ('\u{21a8}', "up down arrow with base"),
+    ('\u{21a9}', "leftwards arrow with hook"),
+    ('\u{21aa}', "rightwards arrow with hook"),
+    ('\u{21ab}', "leftwards arrow with loop"),
+    ('\u{21ac}', "rightwards arrow with loop"),
+    ('\u{21ad}', "left right wave arrow"),
+    ('\u{21ae}', "left right arrow with stroke"),
+    ('\u{21af}', "downwards zigzag arrow"),
+    ('\u{21b0}', "upwards arrow with tip leftwards"),
+    ('\u{21b1}', "upwards arrow with tip rightwards"),
+    ('\u{21b2}', "downwards arrow with tip leftwards"),
+    ('\u{21b3}', "downwards arrow with tip rightwards"),
+    ('\u{21b4}', "rightwards arrow with corner downwards"),
+    ('\u{21b5}', "downwards arrow with corner leftwards"),
+    ('\u{21b6}', "anticlockwise top semicircle arrow"),
+    ('\u{21b7}', "clockwise top semicircle arrow"),
+    ('\u{21b8}', "north west arrow to long bar"),
+    (
+        '\u{21b9}',
+        "leftwards arrow to bar over rightwards arrow to bar",
+    ),
+    ('\u{21ba}', "anticlockwise open circle arrow"),
+    ('\u{21bb}', "clockwise open circle arrow"),
+    ('\u{21bc}', "leftwards harpoon with barb upwards"),
+    ('\u{21bd}', "leftwards harpoon with barb downwards"),
+    ('\u{21be}', "upwards harpoon with barb rightwards"),
+    ('\u{21bf}', "upwards harpoon with barb leftwards"),
+    ('\u{21c0}', "rightwards harpoon with barb upwards"),
+    ('\u{21c1}', "rightwards harpoon with barb downwards"),
+    ('\u{21c2}', "downwards harpoon with barb rightwards"),
+    ('\u{21c3}', "downwards harpoon with barb leftwards"),
+    ('\u{21c4}', "rightwards arrow over leftwards arrow"),
+    ('\u{21c5}', "upwards arrow leftwards of downwards arrow"),
+    ('\u{21c6}', "leftwards arrow over rightwards arrow"),
+    ('\u{21c7}', "leftwards paired arrows"),
+    ('\u{21c8}', "upwards paired arrows"),
+    ('\u{21c9}', "rightwards paired arrows"),
+    ('\u{21ca}', "downwards paired arrows"),
+    ('\u{21cb}', "leftwards harpoon over rightwards harpoon"),
+    ('\u{21cc}', "rightwards harpoon over leftwards harpoon"),
+    ('\u{21cd}', "leftwards double arrow with stroke"),
+    ('\u{21ce}', "left right double arrow with stroke"),
+    ('\u{21cf}', "rightwards double arrow with stroke"),
+    ('\u{21d0}', "leftwards double arrow"),
+    ('\u{21d1}', "upwards double arrow"),
+    ('\u{21d2}', "rightwards double arrow"),
+    ('\u{21d3}', "downwards double arrow"),
+    ('\u{21d4}', "left right double arrow"),
+    ('\u{21d5}', "up down double arrow"),
+    ('\u{21d6}', "north west double arrow"),
+    ('\u{21d7}', "north east double arrow"),
+    ('\u{21d8}', "south east double arrow"),
+    ('\u{21d9}', "south west double arrow"),
+    ('\u{21da}', "leftwards triple arrow"),
+    ('\u{21db}', "rightwards triple arrow"),
+    ('\u{21dc}', "leftwards squiggle arrow"),
+    ('\u{21dd}', "rightwards squiggle arrow"),
+    ('\u{21de}', "upwards arrow with double stroke"),
+    ('\u{21df}', "downwards arrow with double stroke"),
+    ('\u{21e0}', "leftwards dashed arrow"),
+    ('\u{21e1}', "upwards dashed arrow"),
+    ('\u{21e2}', "rightwards dashed arrow"),
+    ('\u{21e3}', "downwards dashed arrow"),
+    ('\u{21e4}', "leftwards arrow to bar"),
+    ('\u{21e5}', "rightwards arrow to bar"),
+    ('\u{21e6}', "leftwards white arrow"),
+    ('\u{21e7}', "upwards white arrow"),
+    ('\u{21e8}', "rightwards white arrow"),
+    ('\u{21e9}', "downwards white arrow"),
+    ('\u{21ea}', "upwards white arrow from bar"),
+    ('\u{21eb}', "upwards white arrow on pedestal"),
+    (
+        '\u{21ec}',
+        "upwards white arrow on pedestal with horizontal bar",
+    ),
+    (
+        '\u{21ed}',
+        "upwards white arrow on pedestal with vertical bar",
+    ),
+    ('\u{21ee}', "upwards white double arrow"),
+    ('\u{21ef}', "upwards white double arrow on pedestal"),
+    ('\u{21f0}', "rightwards white arrow from wall"),
+    ('\u{21f1}', "north west arrow to corner"),
+    ('\u{21f2}', "south east arrow to corner"),
+    ('\u{21f3}', "up down white arrow"),
+    ('\u{21f4}', "right arrow with small circle"),
+    ('\u{21f5}', "downwards arrow leftwards of upwards arrow"),
+    ('\u{21f6}', "three rightwards arrows"),
+    ('\u{21f7}', "leftwards arrow with vertical stroke"),
+    ('\u{21f8}', "rightwards arrow with vertical stroke"),
+    ('\u{21f9}', "left right arrow with vertical stroke"),
+    ('\u{21fa}', "leftwards arrow with double vertical stroke"),
+    ('\u{21fb}', "rightwards arrow with double vertical stroke"),
+    ('\u{21fc}', "left right arrow with double vertical stroke"),
+    ('\u{21fd}', "leftwards open-headed arrow"),
+    ('\u{21fe}', "rightwards open-headed arrow"),
+    ('\u{21ff}', "left right open-headed arrow"),
+    ('\u{27f0}', "upwards quadruple arrow"),
+    ('\u{27f1}', "downwards quadruple arrow"),
+    ('\u{27f2}', "anticlockwise gapped circle arrow"),
+    ('\u{27f3}', "clockwise gapped circle arrow"),
+    ('\u{27f4}', "right arrow with circled plus"),
+    ('\u{27f5}', "long leftwards arrow"),
+    ('\u{27f6}', "long rightwards arrow"),
+    ('\u{27f7}', "long left right arrow"),
+    ('\u{27f8}', "long leftwards double arrow"),
+    ('\u{27f9}', "long rightwards double arrow"),
+    ('\u{27fa}', "long left right double arrow"),
+    ('\u{27fb}', "long leftwards arrow from bar"),
+    ('\u{27fc}', "long rightwards arrow from bar"),
+    ('\u{27fd}', "long leftwards double arrow from bar"),
+    ('\u{27fe}', "long rightwards double arrow from bar"),
+    ('\u{27ff}', "long rightwards squiggle arrow"),
+    (
+        '\u{2900}',
+        "rightwards two-headed arrow with vertical stroke",
+    ),
+    (
+        '\u{2901}',
+        "rightwards two-headed arrow with double vertical stroke",
+    ),
+    ('\u{2902}', "leftwards double arrow with vertical stroke"),
+    ('\u{2903}', "rightwards double arrow with vertical stroke"),
+    ('\u{2904}', "left right double arrow with vertical stroke"),
+    ('\u{2905}', "rightwards two-headed arrow from bar"),
+    ('\u{2906}', "leftwards double arrow from bar"),
+    ('\u{2907}', "rightwards double arrow from bar"),
+    ('\u{2908}', "downwards arrow with horizontal stroke"),
+    ('\u{2909}', "upwards arrow with horizontal stroke"),
+    ('\u{290a}', "upwards triple arrow"),
+    ('\u{290b}', "downwards triple arrow"),
+    ('\u{290c}', "leftwards double dash arrow"),
+    ('\u{290d}', "rightwards double dash arrow"),
+    ('\u{290e}', "leftwards triple dash arrow"),
+    ('\u{290f}', "rightwards triple dash arrow"),
+    ('\u{2910}', "rightwards two-headed triple dash arrow"),
+    ('\u{2911}', "rightwards arrow with dotted stem"),
+    ('\u{2912}', "upwards arrow to bar"),
+    ('\u{2913}', "downwards arrow to bar"),
+    (
+        '\u{2914}',
+        "rightwards arrow with tail with vertical stroke",
+    ),
+    (
+        '\u{2915}',
+        "rightwards arrow with tail with double vertical stroke",
+    ),
+    ('\u{2916}', "rightwards two-headed arrow with tail"),
+    (
+        '\u{2917}',
+        "rightwards two-headed arrow with tail with vertical stroke",
+    ),
+    (
+        '\u{2918}',
+        "rightwards two-headed arrow with tail with double vertical stroke",
+    ),
+    ('\u{2919}', "leftwards arrow-tail"),
+    ('\u{291a}', "rightwards arrow-tail"),
+    ('\u{291b}', "leftwards double arrow-tail"),
+    ('\u{291c}', "rightwards double arrow-tail"),
+    ('\u{291d}', "leftwards arrow to black diamond"),
+    ('\u{291e}', "rightwards arrow to black diamond"),
+    ('\u{291f}', "leftwards arrow from bar to black diamond"),
+    ('\u{2920}', "rightwards arrow from bar to black diamond"),
+    ('\u{2921}', "north west and south east arrow"),
+    ('\u{2922}', "north east and south west arrow"),
+    ('\u{2923}', "north west arrow with hook"),
+    ('\u{2924}', "north east arrow with hook"),
+    ('\u{2925}', "south east arrow with hook"),
+    ('\u{2926}', "south west arrow with hook"),
+    ('\u{2927}', "north west arrow and north east arrow"),
+    ('\u{2928}', "north east arrow and south east arrow"),
+    ('\u{2929}', "south east arrow and south west arrow"),
+    ('\u{292a}', "south west arrow and north west arrow"),
+    ('\u{292b}', "rising diagonal crossing falling diagonal"),
+    ('\u{292c}', "falling diagonal crossing rising diagonal"),
+    ('\u{292d}', "south east arrow crossing north east arrow"),
+    ('\u{292e}', "north east arrow crossing south east arrow"),
+    ('\u{292f}', "falling diagonal crossing north east arrow"),
+    ('\u{2930}', "rising diagonal crossing south east arrow"),
+    ('\u{2931}', "north east arrow crossing north west arrow"),
+    ('\u{2932}', "north west arrow crossing north east arrow"),
+    ('\u{2933}', "wave arrow pointing directly right"),
+    ('\u{2934}', "arrow pointing rightwards then curving upwards"),
+    (
+        '\u{2935}',
+        "arrow pointing rightwards then curving downwards",
+    ),
+    (
+        '\u{2936}',
+        "arrow pointing downwards then curving leftwards",
+    ),
+    (
+        '\u{2937}',
+        "arrow pointing downwards then curving rightwards",
+    ),
+    ('\u{2938}', "right-side arc clockwise arrow"),
+    ('\u{2939}', "left-side arc anticlockwise arrow"),
+    ('\u{293a}', "top arc anticlockwise arrow"),
+    ('\u{293b}', "bottom arc anticlockwise arrow"),
+    ('\u{293c}', "top arc clockwise arrow with minus"),
+    ('\u{293d}', "top arc anticlockwise arrow with plus"),
+    ('\u{293e}', "lower right semicircular clockwise arrow"),
+    ('\u{293f}', "lower left semicircular anticlockwise arrow"),
+    ('\u{2940}', "anticlockwise closed circle arrow"),
+    ('\u{2941}', "clockwise closed circle arrow"),
+    ('\u{2942}', "rightwards arrow above short leftwards arrow"),
+    ('\u{2943}', "leftwards arrow above short rightwards arrow"),
+    ('\u{2944}', "short rightwards arrow above leftwards arrow"),
+    ('\u{2945}', "rightwards arrow with plus below"),
+    ('\u{2946}', "leftwards arrow with plus below"),
+    ('\u{2947}', "rightwards arrow through x"),
+    ('\u{2948}', "left right arrow through small circle"),
+    ('\u{2949}', "upwards two-headed arrow from small circle"),
+    ('\u{294a}', "left barb up right barb down harpoon"),
+    ('\u{294b}', "left barb down right barb up harpoon"),
+    ('\u{294c}', "up barb right down barb left harpoon"),
+    ('\u{294d}', "up barb left down barb right harpoon"),
+    ('\u{294e}', "left barb up right barb up harpoon"),
+    ('\u{294f}', "up barb right down barb right harpoon"),
+    ('\u{2950}', "left barb down right barb down harpoon"),
+    ('\u{2951}', "up barb left down barb left harpoon"),
+    ('\u{2952}', "leftwards harpoon with barb up to bar"),
+    ('\u{2953}', "rightwards harpoon with barb up to bar"),
+    ('\u{2954}', "upwards harpoon with barb right to bar"),
+    ('\u{2955}', "downwards harpoon with barb right to bar"),
+    ('\u{2956}', "leftwards harpoon with barb down to bar"),
+    ('\u{2957}', "rightwards harpoon with barb down to bar"),
+    ('\u{2958}', "upwards harpoon with barb left to bar"),
+    ('\u{2959}', "downwards harpoon with barb left to bar"),
+    ('\u{295a}', "leftwards harpoon with barb up from bar"),
+    ('\u{295b}', "rightwards harpoon with barb up from bar"),
+    ('\u{295c}', "upwards harpoon with barb right from bar"),
+    ('\u{295d}', "downwards harpoon with barb right from bar"),
+    ('\u{295e}', "leftwards harpoon with barb down from bar"),
+    ('\u{295f}', "rightwards harpoon with barb down from bar"),
+    ('\u{2960}', "upwards harpoon with barb left from bar"),
+    ('\u{2961}', "downwards harpoon with barb left from bar"),
+    (
+        '\u{2962}',
+        "leftwards harpoon with barb up above leftwards harpoon with barb down",
+    ),
+    (
+        '\u{2963}',
+        "upwards harpoon with barb left beside upwards harpoon with barb right",
+    ),
+    (
+        '\u{2964}',
+        "rightwards harpoon with barb up above rightwards harpoon with barb down",
+    ),
+    (
+        '\u{2965}',
+        "downwards harpoon with barb left beside downwards harpoon with barb right",
+    ),
+    (
+        '\u{2966}',
+        "leftwards harpoon with barb up above rightwards harpoon with barb up",
+    ),
+    (
+        '\u{2967}',
+        "leftwards harpoon with barb down above rightwards harpoon with barb down",
+    ),
+    (
+        '\u{2968}',
+        "rightwards harpoon with barb up above leftwards harpoon with barb up",
+    ),
+    (
+        '\u{2969}',
+        "rightwards harpoon with barb down above leftwards harpoon with barb down",
+    ),
+    ('\u{296a}', "leftwards harpoon with barb up above long dash"),
+    (
+        '\u{296b}',
+        "leftwards harpoon with barb down below long dash",
+    ),
+    (
+        '\u{296c}',
+        "rightwards harpoon with barb up above long dash",
+    ),
+    (
+        '\u{296d}',
+        "rightwards harpoon with barb down below long dash",
+    ),
+    (
+        '\u{296e}',
+        "upwards harpoon with barb left beside downwards harpoon with barb right",
+    ),
+    (
+        '\u{296f}',
+        "downwards harpoon with barb left beside upwards harpoon with barb right",
+    ),
+    ('\u{2970}', "right double arrow with rounded head"),
+    ('\u{2971}', "equals sign above rightwards arrow"),
+    ('\u{2972}', "tilde operator above rightwards arrow"),
+    ('\u{2973}', "leftwards arrow above tilde operator"),
+    ('\u{2974}', "rightwards arrow above tilde operator"),
+    ('\u{2975}', "rightwards arrow above almost equal to"),
+    ('\u{2976}', "less-than above leftwards arrow"),
+    ('\u{2977}', "leftwards arrow through less-than"),
+    ('\u{2978}', "greater-than above rightwards arrow"),
+    ('\u{2979}', "subset above rightwards arrow"),
+    ('\u{297a}', "leftwards arrow through subset"),
+    ('\u{297b}', "superset above leftwards arrow"),
+    ('\u{297c}', "left fish tail"),
+    ('\u{297d}', "right fish tail"),
+    ('\u{297e}', "up fish tail"),
+    ('\u{297f}', "down fish tail"),
+    // Mathematical operators
+    ('\u{2200}', "for all"),
+    ('\u{2201}', "complement"),
+    ('\u{2202}', "partial differential"),
+    ('\u{2203}', "there exists"),
+    ('\u{2204}', "there does not exist"),
+    ('\u{2205}', "empty set"),
+    ('\u{2206}', "increment"),
+    ('\u{2207}', "nabla"),
+    ('\u{2208}', "element of"),
+    ('\u{2209}', "not an element of"),
+    ('\u{220a}', "small element of"),
+    ('\u{220b}', "contains as member"),
+    ('\u{220c}', "does not contain as member"),
+    ('\u{220d}', "small contains as member"),
+    ('\u{220e}', "end of proof"),
+    ('\u{220f}', "n-ary product"),
+    ('\u{2210}', "n-ary coproduct"),
+    ('\u{2211}', "n-ary summation"),
+    ('\u{2212}', "minus sign"),
+    ('\u{2213}', "minus-or-plus sign"),
+    ('\u{2214}', "dot plus"),
+    ('\u{2215}', "division slash"),
+    ('\u{2216}', "set minus"),
+    ('\u{2217}', "asterisk operator"),
+    ('\u{2218}', "ring operator"),
+    ('\u{2219}', "bullet operator"),
+    ('\u{221a}', "square root"),
+    ('\u{221b}', "cube root"),
+    ('\u{221c}', "fourth root"),
+    ('\u{221d}', "proportional to"),
+    ('\u{221e}', "infinity"),
+    ('\u{221f}', "right angle"),
+    ('\u{2220}', "angle"),
+    ('\u{2221}', "measured angle"),
+    ('\u{2222}', "spherical angle"),
+    ('\u{2223}', "divides"),
+    ('\u{2224}', "does not divide"),
+    ('\u{2225}', "parallel to"),
+    ('\u{2226}', "not parallel to"),
+    ('\u{2227}', "logical and"),
+    ('\u{2228}', "logical or"),
+    ('\u{2229}', "intersection"),
+    ('\u{222a}', "union"),
+    ('\u{222b}', "integral"),
+    ('\u{222c}', "double integral"),
+    ('\u{222d}', "triple integral"),
+    ('\u{222e}', "contour integral"),
+    ('\u{222f}', "surface integral"),
+    ('\u{2230}', "volume integral"),
+    ('\u{2231}', "clockwise integral"),
+    ('\u{2232}', "clockwise contour integral"),
+    ('\u{2233}', "anticlockwise contour integral"),
+    ('\u{2234}', "therefore"),
+    ('\u{2235}', "because"),
+    ('\u{2236}', "ratio"),
+    ('\u{2237}', "proportion"),
+    ('\u{2238}', "dot minus"),
+    ('\u{2239}', "excess"),
+    ('\u{223a}', "geometric proportion"),
+    ('\u{223b}', "homothetic"),
+    ('\u{223c}', "tilde operator"),
+    ('\u{223d}', "reversed tilde"),
+    ('\u{223e}', "inverted lazy s"),
+    ('\u{223f}', "sine wave"),
+    ('\u{2240}', "wreath product"),
+    ('\u{2241}', "not tilde"),
+    ('\u{2242}', "minus tilde"),
+    ('\u{2243}', "asymptotically equal to"),
+    ('\u{2244}', "not asymptotically equal to"),
+    ('\u{2245}', "approximately equal to"),
+    ('\u{2246}', "approximately but not actually equal to"),
+    ('\u{2247}', "neither approximately nor actually equal to"),
+    ('\u{2248}', "almost equal to"),
+    ('\u{2249}', "not almost equal to"),
+    ('\u{224a}', "almost equal or equal to"),
+    ('\u{224b}', "triple tilde"),
+    ('\u{224c}', "all equal to"),
+    ('\u{224d}', "equivalent to"),
+    ('\u{224e}', "geometrically equivalent to"),
+    ('\u{224f}', "difference between"),
+    ('\u{2250}', "approaches the limit"),
+    ('\u{2251}', "geometrically equal to"),
+    ('\u{2252}', "approximately equal to or the image of"),
+    ('\u{2253}', "image of or approximately equal to"),
+    ('\u{2254}', "colon equals"),
+    ('\u{2255}', "equals colon"),
+    ('\u{2256}', "ring in equal to"),
+    ('\u{2257}', "ring equal to"),
+    ('\u{2258}', "corresponds to"),
+    ('\u{2259}', "estimates"),
+    ('\u{225a}', "equiangular to"),
+    ('\u{225b}', "star equals"),
+    ('\u{225c}', "delta equal to"),
+    ('\u{225d}', "equal to by definition"),
+    ('\u{225e}', "measured by"),
+    ('\u{225f}', "questioned equal to"),
+    ('\u{2260}', "not equal to"),
+    ('\u{2261}', "identical to"),
+    ('\u{2262}', "not identical to"),
+    ('\u{2263}', "strictly equivalent to"),
+    ('\u{2264}', "less-than or equal to"),
+    ('\u{2265}', "greater-than or equal to"),
+    ('\u{2266}', "less-than over equal to"),
+    ('\u{2267}', "greater-than over equal to"),
+    ('\u{2268}', "less-than but not equal to"),
+    ('\u{2269}', "greater-than but not equal to"),
+    ('\u{226a}', "much less-than"),
+    ('\u{226b}', "much greater-than"),
+    ('\u{226c}', "between"),
+    ('\u{226d}', "not equivalent to"),
+    ('\u{226e}', "not less-than"),
+    ('\u{226f}', "not greater-than"),
+    ('\u{2270}', "neither less-than nor equal to"),
+    ('\u{2271}', "neither greater-than nor equal to"),
+    ('\u{2272}', "less-than or equivalent to"),
+    ('\u{2273}', "greater-than or equivalent to"),
+    ('\u{2274}', "neither less-than nor equivalent to"),
+    ('\u{2275}', "neither greater-than nor equivalent to"),
+    ('\u{2276}', "less-than or greater-than"),
+    ('\u{2277}', "greater-than or less-than"),
+    ('\u{2278}', "neither less-than nor greater-than"),
+    ('\u{2279}', "neither greater-than nor less-than"),
+    ('\u{227a}', "precedes"),
+    ('\u{227b}', "succeeds"),
+    ('\u{227c}', "precedes or equal to"),
+    ('\u{227d}', "succeeds or equal to"),
+    ('\u{227e}', "precedes or equivalent to"),
+    ('\u{227f}', "succeeds or equivalent to"),
+    ('\u{2280}', "does not precede"),
+    ('\u{2281}', "does not succeed"),
+    ('\u{2282}', "subset of"),
+    ('\u{2283}', "superset of"),
+    ('\u{2284}', "not a subset of"),
+    ('\u{2285}', "not a superset of"),
+    ('\u{2286}', "subset of or equal to"),
+    ('\u{2287}', "superset of or equal to"),
+    ('\u{2288}', "neither a subset of nor equal to"),
+    ('\u{2289}', "neither a superset of nor equal to"),
+    ('\u{228a}', "subset of with not equal to"),
+    ('\u{228b}', "superset of with not equal to"),
+    ('\u{228c}', "multiset"),
+    ('\u{228d}', "multiset multiplication"),
+    ('\u{228e}', "multiset union"),
+    ('\u{228f}', "square image of"),
+    ('\u{2290}', "square original of"),
+    ('\u{2291}', "square image of or equal to"),
+    ('\u{2292}', "square original of or equal to"),
+    ('\u{2293}', "square cap"),
+    ('\u{2294}', "square cup"),
+    ('\u{2295}', "circled plus"),
+    ('\u{2296}', "circled minus"),
+    ('\u{2297}', "circled times"),
+    ('\u{2298}', "circled division slash"),
+    ('\u{2299}', "circled dot operator"),
+    ('\u{229a}', "circled ring operator"),
+    ('\u{229b}', "circled asterisk operator"),
+    ('\u{229c}', "circled equals"),
+    ('\u{229d}', "circled dash"),
+    ('\u{229e}', "squared plus"),
+    ('\u{229f}', "squared minus"),
+    ('\u{22a0}', "squared times"),
+    ('\u{22a1}', "squared dot operator"),
+    ('\u{22a2}', "right tack"),
+    ('\u{22a3}', "left tack"),
+    ('\u{22a4}', "down tack"),
+    ('\u{22a5}', "up tack"),
+    ('\u{22a6}', "assertion"),
+    ('\u{22a7}', "models"),
+    ('\u{22a8}', "true"),
+    ('\u{22a9}', "forces"),
+    ('\u{22aa}', "triple vertical bar right turnstile"),
+    ('\u{22ab}', "double vertical bar double right turnstile"),
+    ('\u{22ac}', "does not prove"),
+    ('\u{22ad}', "not true"),
+    ('\u{22ae}', "does not force"),
+    (
+        '\u{22af}',
+        "negated double vertical bar double right turnstile",
+    ),
+    ('\u{22b0}', "precedes under relation"),
+    ('\u{22b1}', "succeeds under relation"),
+    ('\u{22b2}', "normal subgroup of"),
+    ('\u{22b3}', "contains as normal subgroup"),
+    ('\u{22b4}', "normal subgroup of or equal to"),
+    ('\u{22b5}', "contains as normal subgroup or equal to"),
+    ('\u{22b6}', "original of"),
+    ('\u{22b7}', "image of"),
+    ('\u{22b8}', "multimap"),
+    ('\u{22b9}', "hermitian conjugate matrix"),
+    ('\u{22ba}', "intercalate"),
+    ('\u{22bb}', "xor"),
+    ('\u{22bc}', "nand"),
+    ('\u{22bd}', "nor"),
+    ('\u{22be}', "right angle with arc"),
+    ('\u{22bf}', "right triangle"),
+    ('\u{22c0}', "n-ary logical and"),
+    ('\u{22c1}', "n-ary logical or"),
+    ('\u{22c2}', "n-ary intersection"),
+    ('\u{22c3}', "n-ary union"),
+    ('\u{22c4}', "diamond operator"),
+    ('\u{22c5}', "dot operator"),
+    ('\u{22c6}', "star operator"),
+    ('\u{22c7}', "division times"),
+    ('\u{22c8}', "bowtie"),
+    ('\u{22c9}', "left normal factor semidirect product"),
+    ('\u{22ca}', "right normal factor semidirect product"),
+    ('\u{22cb}', "left semidirect product"),
+    ('\u{22cc}', "right semidirect product"),
+    ('\u{22cd}', "reversed tilde equals"),
+    ('\u{22ce}', "curly logical or"),
+    ('\u{22cf}', "curly logical and"),
+    ('\u{22d0}', "double subset"),
+    ('\u{22d1}', "double superset"),
+    ('\u{22d2}', "double intersection"),
+    ('\u{22d3}', "double union"),
+    ('\u{22d4}', "pitchfork"),
+    ('\u{22d5}', "equal and parallel to"),
+    ('\u{22d6}', "less-than with dot"),
+    ('\u{22d7}', "greater-than with dot"),
+    ('\u{22d8}', "very much less-than"),
+    ('\u{22d9}', "very much greater-than"),
+    ('\u{22da}', "less-than equal to or greater-than"),
+    ('\u{22db}', "greater-than equal to or less-than"),
+    ('\u{22dc}', "equal to or less-than"),
+    ('\u{22dd}', "equal to or greater-than"),
+    ('\u{22de}', "equal to or precedes"),
+    ('\u{22df}', "equal to or succeeds"),
+    ('\u{22e0}', "does not precede or equal"),
+    ('\u{22e1}', "does not succeed or equal"),
+    ('\u{22e2}', "not square image of or equal to"),
+    ('\u{22e3}', "not square original of or equal to"),
+    ('\u{22e4}', "square image of or not equal to"),
+    ('\u{22e5}', "square original of or not equal to"),
+    ('\u{22e6}', "less-than but not equivalent to"),
+    ('\u{22e7}', "greater-than but not equivalent to"),
+    ('\u{22e8}', "precedes but not equivalent to"),
+    ('\u{22e9}', "succeeds but not equivalent to"),
+    ('\u{22ea}', "not normal subgroup of"),
+    ('\u{22eb}', "does not contain as normal subgroup"),
+    ('\u{22ec}', "not normal subgroup of or equal to"),
+    ('\u{22ed}', "does not contain as normal subgroup or equal"),
+    ('\u{22ee}', "vertical ellipsis"),
+    ('\u{22ef}', "midline horizontal ellipsis"),
+    ('\u{22f0}', "up right diagonal ellipsis"),
+    ('\u{22f1}', "down right diagonal ellipsis"),
+    ('\u{22f2}', "element of with long horizontal stroke"),
+    (
+        '\u{22f3}',
+        "element of with vertical bar at end of horizontal stroke",
+    ),
+    (
+        '\u{22f4}',
+        "small element of with vertical bar at end of horizontal stroke",
+    ),
+    ('\u{22f5}', "element of with dot above"),
+    ('\u{22f6}', "element of with overbar"),
+    ('\u{22f7}', "small element of with overbar"),
+    ('\u{22f8}', "element of with underbar"),
+    ('\u{22f9}', "element of with two horizontal strokes"),
+    ('\u{22fa}', "contains with long horizontal stroke"),
+    (
+        '\u{22fb}',
+        "contains with vertical bar at end of horizontal stroke",
+    ),
+    (
+        '\u{22fc}',
+        "small contains with vertical bar at end of horizontal stroke",
+    ),
+    ('\u{22fd}', "contains with overbar"),
+    ('\u{22fe}', "small contains with overbar"),
+    ('\u{22ff}', "z notation bag membership"),
+    // Miscellaneous technical
+    ('\u{2300}', "diameter sign"),
+    ('\u{2301}', "electric arrow"),
+    ('\u{2302}', "house"),
+    ('\u{2303}', "up arrowhead"),
+    ('\u{2304}', "down arrowhead"),
+    ('\u{2305}', "projective"),
+    ('\u{2306}', "perspective"),
+    ('\u{2307}', "wavy line"),
+    ('\u{2308}', "left ceiling"),
+    ('\u{2309}', "right ceiling"),
+    ('\u{230a}', "left floor"),
+    ('\u{230b}', "right floor"),
+    ('\u{230c}', "bottom right crop"),
+    ('\u{230d}', "bottom left crop"),
+    ('\u{230e}', "top right crop"),
+    ('\u{230f}', "top left crop"),
+    ('\u{2310}', "reversed not sign"),
+    ('\u{2311}', "square lozenge"),
+    ('\u{2312}', "arc"),
+    ('\u{2313}', "segment"),
+    ('\u{2314}', "sector"),
+    ('\u{2315}', "telephone recorder"),
+    ('\u{2316}', "position indicator"),
+    ('\u{2317}', "viewdata square"),
+    ('\u{2318}', "place of interest sign"),
+    ('\u{2319}', "turned not sign"),
+    ('\u{231a}', "watch"),
+    ('\u{231b}', "hourglass"),
+    ('\u{231c}', "top left corner"),
+    ('\u{231d}', "top right corner"),
+    ('\u{231e}', "bottom left corner"),
+    ('\u{231f}', "bottom right corner"),
+    ('\u{2320}', "top half integral"),
+    ('\u{2321}', "bottom half integral"),
+    ('\u{2322}', "frown"),
+    ('\u{2323}', "smile"),
+    ('\u{2324}', "up arrowhead between two horizontal bars"),
+    ('\u{2325}', "option key"),
+    ('\u{2326}', "erase to the right"),
+    ('\u{2327}', "x in a rectangle box"),
+    ('\u{2328}', "keyboard"),
+    ('\u{2329}', "left-pointing angle bracket"),
+    ('\u{232a}', "right-pointing angle bracket"),
+    ('\u{232b}', "erase to the left"),
+    ('\u{232c}', "benzene ring"),
+    ('\u{232d}', "cylindricity"),
+    ('\u{232e}', "all around-profile"),
+    ('\u{232f}', "symmetry"),
+    ('\u{2330}', "total runout"),
+    ('\u{2331}', "dimension origin"),
+    ('\u{2332}', "conical taper"),
+    ('\u{2333}', "slope"),
+    ('\u{2334}', "counterbore"),
+    ('\u{2335}', "countersink"),
+    ('\u{2336}', "apl functional symbol i-beam"),
+    ('\u{2337}', "apl functional symbol squish quad"),
+    ('\u{2338}', "apl functional symbol quad equal"),
+    ('\u{2339}', "apl functional symbol quad divide"),
+    ('\u{233a}', "apl functional symbol quad diamond"),
+    ('\u{233b}', "apl functional symbol quad jot"),
+    ('\u{233c}', "apl functional symbol quad circle"),
+    ('\u{233d}', "apl functional symbol circle stile"),
+    ('\u{233e}', "apl functional symbol circle jot"),
+    ('\u{233f}', "apl functional symbol slash bar"),
+    ('\u{2340}', "apl functional symbol backslash bar"),
+    ('\u{2341}', "apl functional symbol quad slash"),
+    ('\u{2342}', "apl functional symbol quad backslash"),
+    ('\u{2343}', "apl functional symbol quad less-than"),
+    ('\u{2344}', "apl functional symbol quad greater-than"),
+    ('\u{2345}', "apl functional symbol leftwards vane"),
+    ('\u{2346}', "apl functional symbol rightwards vane"),
+    ('\u{2347}', "apl functional symbol quad leftwards arrow"),
+    ('\u{2348}', "apl functional symbol quad rightwards arrow"),
+    ('\u{2349}', "apl functional symbol circle backslash"),
+    ('\u{234a}', "apl functional symbol down tack underbar"),
+    ('\u{234b}', "apl functional symbol delta stile"),
+    ('\u{234c}', "apl functional symbol quad down caret"),
+    ('\u{234d}', "apl functional symbol quad delta"),
+    ('\u{234e}', "apl functional symbol down tack jot"),
+    ('\u{234f}', "apl functional symbol upwards vane"),
+    ('\u{2350}', "apl functional symbol quad upwards arrow"),
+    ('\u{2351}', "apl functional symbol up tack overbar"),
+    ('\u{2352}', "apl functional symbol del stile"),
+    ('\u{2353}', "apl functional symbol quad up caret"),
+    ('\u{2354}', "apl functional symbol quad del"),
+    ('\u{2355}', "apl functional symbol up tack jot"),
+    ('\u{2356}', "apl functional symbol downwards vane"),
+    ('\u{2357}', "apl functional symbol quad downwards arrow"),
+    ('\u{2358}', "apl functional symbol quote underbar"),
+    ('\u{2359}', "apl functional symbol delta underbar"),
+    ('\u{235a}', "apl functional symbol diamond underbar"),
+    ('\u{235b}', "apl functional symbol jot underbar"),
+    ('\u{235c}', "apl functional symbol circle underbar"),
+    ('\u{235d}', "apl functional symbol up shoe jot"),
+    ('\u{235e}', "apl functional symbol quote quad"),
+    ('\u{235f}', "apl functional symbol circle star"),
+    ('\u{2360}', "apl functional symbol quad colon"),
+    ('\u{2361}', "apl functional symbol up tack diaeresis"),
+    ('\u{2362}', "apl functional symbol del diaeresis"),
+    ('\u{2363}', "apl functional symbol star diaeresis"),
+    ('\u{2364}', "apl functional symbol jot diaeresis"),
+    ('\u{2365}', "apl functional symbol circle diaeresis"),
+    ('\u{2366}', "apl functional symbol down shoe stile"),
+    ('\u{2367}', "apl functional symbol left shoe stile"),
+    ('\u{2368}', "apl functional symbol tilde diaeresis"),
+    ('\u{2369}', "apl functional symbol greater-than diaeresis"),
+    ('\u{236a}', "apl functional symbol comma bar"),
+    ('\u{236b}', "apl functional symbol del tilde"),
+    ('\u{236c}', "apl functional symbol zilde"),
+    ('\u{236d}', "apl functional symbol stile tilde"),
+    ('\u{236e}', "apl functional symbol semicolon underbar"),
+    ('\u{236f}', "apl functional symbol quad not equal"),
+    ('\u{2370}', "apl functional symbol quad question"),
+    ('\u{2371}', "apl functional symbol down caret tilde"),
+    ('\u{2372}', "apl functional symbol up caret tilde"),
+    ('\u{2373}', "apl functional symbol iota"),
+    ('\u{2374}', "apl functional symbol rho"),
+    ('\u{2375}', "apl functional symbol omega"),
+    ('\u{2376}', "apl functional symbol alpha underbar"),
+    ('\u{2377}', "apl functional symbol epsilon underbar"),
+    ('\u{2378}', "apl functional symbol iota underbar"),
+    ('\u{2379}', "apl functional symbol omega underbar"),
+    ('\u{237a}', "apl functional symbol alpha"),
+    ('\u{237b}', "not check mark"),
+    ('\u{237c}', "right angle with downwards zigzag arrow"),
+    ('\u{237d}', "shouldered open box"),
+    ('\u{237e}', "bell symbol"),
+    ('\u{237f}', "vertical line with middle dot"),
+    ('\u{2380}', "insertion symbol"),
+    ('\u{2381}', "continuous underline symbol"),
+    ('\u{2382}', "discontinuous underline symbol"),
+    ('\u{2383}', "emphasis symbol"),
+    ('\u{2384}', "composition symbol"),
+    ('\u{2385}', "white square with centre vertical line"),
+    ('\u{2386}', "enter symbol"),
+    ('\u{2387}', "alternative key symbol"),
+    ('\u{2388}', "helm symbol"),
+    ('\u{2389}', "circled horizontal bar with notch"),
+    ('\u{238a}', "circled triangle down"),
+    ('\u{238b}', "broken circle with northwest arrow"),
+    ('\u{238c}', "undo symbol"),
+    ('\u{238d}', "monostable symbol"),
+    ('\u{238e}', "hysteresis symbol"),
+    ('\u{238f}', "open-circuit-output h-type symbol"),
+    ('\u{2390}', "open-circuit-output l-type symbol"),
+    ('\u{2391}', "passive-pull-down-output symbol"),
+    ('\u{2392}', "passive-pull-up-output symbol"),
+    ('\u{2393}', "direct current symbol form two"),
+    ('\u{2394}', "software-function symbol"),
+    ('\u{2395}', "apl functional symbol quad"),
+    ('\u{2396}', "decimal separator key symbol"),
+    ('\u{2397}', "previous page"),
+    ('\u{2398}', "next page"),
+    ('\u{2399}', "print screen symbol"),
+    ('\u{239a}', "clear screen symbol"),
+    ('\u{239b}', "left parenthesis upper hook"),
+    ('\u{239c}', "left parenthesis extension"),
+    ('\u{239d}', "left parenthesis lower hook"),
+    ('\u{239e}', "right parenthesis upper hook"),
+    ('\u{239f}', "right parenthesis extension"),
+    ('\u{23a0}', "right parenthesis lower hook"),
+    ('\u{23a1}', "left square bracket upper corner"),
+    ('\u{23a2}', "left square bracket extension"),
+    ('\u{23a3}', "left square bracket lower corner"),
+    ('\u{23a4}', "right square bracket upper corner"),
+    ('\u{23a5}', "right square bracket extension"),
+    ('\u{23a6}', "right square bracket lower corner"),
+    ('\u{23a7}', "left curly bracket upper hook"),
+    ('\u{23a8}', "left curly bracket middle piece"),
+    ('\u{23a9}', "left curly bracket lower hook"),
+    ('\u{23aa}', "curly bracket extension"),
+    ('\u{23ab}', "right curly bracket upper hook"),
+    ('\u{23ac}', "right curly bracket middle piece"),
+    ('\u{23ad}', "right curly bracket lower hook"),
+    ('\u{23ae}', "integral extension"),
+    ('\u{23af}', "horizontal line extension"),
+    (
+        '\u{23b0}',
+        "upper left or lower right curly bracket section",
+    ),
+    (
+        '\u{23b1}',
+        "upper right or lower left curly bracket section",
+    ),
+    ('\u{23b2}', "summation top"),
+    ('\u{23b3}', "summation bottom"),
+    ('\u{23b4}', "top square bracket"),
+    ('\u{23b5}', "bottom square bracket"),
+    ('\u{23b6}', "bottom square bracket over top square bracket"),
+    ('\u{23b7}', "radical symbol bottom"),
+    ('\u{23b8}', "left vertical box line"),
+    ('\u{23b9}', "right vertical box line"),
+    ('\u{23ba}', "horizontal scan line-1"),
+    ('\u{23bb}', "horizontal scan line-3"),
+    ('\u{23bc}', "horizontal scan line-7"),
+    ('\u{23bd}', "horizontal scan line-9"),
+    ('\u{23be}', "dentistry symbol light vertical and top right"),
+    (
+        '\u{23bf}',
+        "dentistry symbol light vertical and bottom right",
+    ),
+    ('\u{23c0}', "dentistry symbol light vertical with circle"),
+    (
+        '\u{23c1}',
+        "dentistry symbol light down and horizontal with circle",
+    ),
+    (
+        '\u{23c2}',
+        "dentistry symbol light up and horizontal with circle",
+    ),
+    ('\u{23c3}', "dentistry symbol light vertical with triangle"),
+    (
+        '\u{23c4}',
+        "dentistry symbol light down and horizontal with triangle",
+    ),
+    (
+        '\u{23c5}',
+        "dentistry symbol light up and horizontal with triangle",
+    ),
+    ('\u{23c6}', "dentistry symbol light vertical and wave"),
+    (
+        '\u{23c7}',
+        "dentistry symbol light down and horizontal with wave",
+    ),
+    (
+        '\u{23c8}',
+        "dentistry symbol light up and horizontal with wave",
+    ),
+    ('\u{23c9}', "dentistry symbol light down and horizontal"),
+    ('\u{23ca}', "dentistry symbol light up and horizontal"),
+    ('\u{23cb}', "dentistry symbol light vertical and top left"),
+    (
+        '\u{23cc}',
+        "dentistry symbol light vertical and bottom left",
+    ),
+    ('\u{23cd}', "square foot"),
+    ('\u{23ce}', "return symbol"),
+    ('\u{23cf}', "eject symbol"),
+    ('\u{23d0}', "vertical line extension"),
+    ('\u{23d1}', "metrical breve"),
+    ('\u{23d2}', "metrical long over short"),
+    ('\u{23d3}', "metrical short over long"),
+    ('\u{23d4}', "metrical long over two shorts"),
+    ('\u{23d5}', "metrical two shorts over long"),
+    ('\u{23d6}', "metrical two shorts joined"),
+    ('\u{23d7}', "metrical triseme"),
+    ('\u{23d8}', "metrical tetraseme"),
+    ('\u{23d9}', "metrical pentaseme"),
+    ('\u{23da}', "earth ground"),
+    ('\u{23db}', "fuse"),
+    ('\u{23dc}', "top parenthesis"),
+    ('\u{23dd}', "bottom parenthesis"),
+    ('\u{23de}', "top curly bracket"),
+    ('\u{23df}', "bottom curly bracket"),
+    ('\u{23e0}', "top tortoise shell bracket"),
+    ('\u{23e1}', "bottom tortoise shell bracket"),
+    ('\u{23e2}', "white trapezium"),
+    ('\u{23e3}', "benzene ring with circle"),
+    ('\u{23e4}', "straightness"),
+    ('\u{23e5}', "flatness"),
+    ('\u{23e6}', "ac current"),
+    ('\u{23e7}', "electrical intersection"),
+    ('\u{23e8}', "decimal exponent symbol"),
+    ('\u{23e9}', "black right-pointing double triangle"),
+    ('\u{23ea}', "black left-pointing double triangle"),
+    ('\u{23eb}', "black up-pointing double triangle"),
+    ('\u{23ec}', "black down-pointing double triangle"),
+    (
+        '\u{23ed}',
+        "black right-pointing double triangle with vertical bar",
+    ),
+    (
+        '\u{23ee}',
+        "black left-pointing double triangle with vertical bar",
+    ),
+    (
+        '\u{23ef}',
+        "black right-pointing triangle with double vertical bar",
+    ),
+    ('\u{23f0}', "alarm clock"),
+    ('\u{23f1}', "stopwatch"),
+    ('\u{23f2}', "timer clock"),
+    ('\u{23f3}', "hourglass with flowing sand"),
+    ('\u{23f4}', "black medium left-pointing triangle"),
+    ('\u{23f5}', "black medium right-pointing triangle"),
+    ('\u{23f6}', "black medium up-pointing triangle"),
+    ('\u{23f7}', "black medium down-pointing triangle"),
+    ('\u{23f8}', "double vertical bar"),
+    ('\u{23f9}', "black square for stop"),
+    ('\u{23fa}', "black circle for record"),
+    ('\u{23fb}', "power symbol"),
+    ('\u{23fc}', "power on-off symbol"),
+    ('\u{23fd}', "power on symbol"),
+    ('\u{23fe}', "power sleep symbol"),
+    ('\u{23ff}', "observer eye symbol"),
+    // Box drawing
+    ('\u{2500}', "box drawings light horizontal"),
+    ('\u{2501}', "box drawings heavy horizontal"),
+    ('\u{2502}', "box drawings light vertical"),
+    ('\u{2503}', "box drawings heavy vertical"),
+    ('\u{2504}', "box drawings light triple dash horizontal"),
+    ('\u{2505}', "box drawings heavy triple dash horizontal"),
+    ('\u{2506}', "box drawings light triple dash vertical"),
+    ('\u{2507}', "box drawings heavy triple dash vertical"),
+    ('\u{2508}', "box drawings light quadruple dash horizontal"),
+    ('\u{2509}', "box drawings heavy quadruple dash horizontal"),
+    ('\u{250a}', "box drawings light quadruple dash vertical"),
+    ('\u{250b}', "box drawings heavy quadruple dash vertical"),
+    ('\u{250c}', "box drawings light down and right"),
+    ('\u{250d}', "box drawings down light and right heavy"),
+    ('\u{250e}', "box drawings down heavy and right light"),
+    ('\u{250f}', "box drawings heavy down and right"),
+    ('\u{2510}', "box drawings light down and left"),
+    ('\u{2511}', "box drawings down light and left heavy"),
+    ('\u{2512}', "box drawings down heavy and left light"),
+    ('\u{2513}', "box drawings heavy down and left"),
+    ('\u{2514}', "box drawings light up and right"),
+    ('\u{2515}', "box drawings up light and right heavy"),
+    ('\u{2516}', "box drawings up heavy and right light"),
+    ('\u{2517}', "box drawings heavy up and right"),
+    ('\u{2518}', "box drawings light up and left"),
+    ('\u{2519}', "box drawings up light and left heavy"),
+    ('\u{251a}', "box drawings up heavy and left light"),
+    ('\u{251b}', "box drawings heavy up and left"),
+    ('\u{251c}', "box drawings light vertical and right"),
+    ('\u{251d}', "box drawings vertical light and right heavy"),
+    ('\u{251e}', "box drawings up heavy and right down light"),
+    ('\u{251f}', "box drawings down heavy and right up light"),
+    ('\u{2520}', "box drawings vertical heavy and right light"),
+    ('\u{2521}', "box drawings down light and right up heavy"),
+    ('\u{2522}', "box drawings up light and right down heavy"),
+    ('\u{2523}', "box drawings heavy vertical and right"),
+    ('\u{2524}', "box drawings light vertical and left"),
+    ('\u{2525}', "box drawings vertical light and left heavy"),
+    ('\u{2526}', "box drawings up heavy and left down light"),
+    ('\u{2527}', "box drawings down heavy and left up light"),
+    ('\u{2528}', "box drawings vertical heavy and left light"),
+    ('\u{2529}', "box drawings down light and left up heavy"),
+    ('\u{252a}', "box drawings up light and left down heavy"),
+    ('\u{252b}', "box drawings heavy vertical and left"),
+    ('\u{252c}', "box drawings light down and horizontal"),
+    ('\u{252d}', "box drawings left heavy and right down light"),
+    ('\u{252e}', "box drawings right heavy and left down light"),
+    ('\u{252f}', "box drawings down light and horizontal heavy"),
+    ('\u{2530}', "box drawings down heavy and horizontal light"),
+    ('\u{2531}', "box drawings right light and left down heavy"),
+    ('\u{2532}', "box drawings left light and right down heavy"),
+    ('\u{2533}', "box drawings heavy down and horizontal"),
+    ('\u{2534}', "box drawings light up and horizontal"),
+    ('\u{2535}', "box drawings left heavy and right up light"),
+    ('\u{2536}', "box drawings right heavy and left up light"),
+    ('\u{2537}', "box drawings up light and horizontal heavy"),
+    ('\u{2538}', "box drawings up heavy and horizontal light"),
+    ('\u{2539}', "box drawings right light and left up heavy"),
+    ('\u{253a}', "box drawings left light and right up heavy"),
+    ('\u{253b}', "box drawings heavy up and horizontal"),
+    ('\u{253c}', "box drawings light vertical and horizontal"),
+    (
+        '\u{253d}',
+        "box drawings left heavy and right vertical light",
+    ),
+    (
+        '\u{253e}',
+        "box drawings right heavy and left vertical light",
+    ),
+    (
+        '\u{253f}',
+        "box drawings vertical light and horizontal heavy",
+    ),
+    (
+        '\u{2540}',
+        "box drawings up heavy and down horizontal light",
+    ),
+    (
+        '\u{2541}',
+        "box drawings down heavy and up horizontal light",
+    ),
+    (
+        '\u{2542}',
+        "box drawings vertical heavy and horizontal light",
+    ),
+    (
+        '\u{2543}',
+        "box drawings left up heavy and right down light",
+    ),
+    (
+        '\u{2544}',
+        "box drawings right up heavy and left down light",
+    ),
+    (
+        '\u{2545}',
+        "box drawings left down heavy and right up light",
+    ),
+    (
+        '\u{2546}',
+        "box drawings right down heavy and left up light",
+    ),
+    (
+        '\u{2547}',
+        "box drawings down light and up horizontal heavy",
+    ),
+    (
+        '\u{2548}',
+        "box drawings up light and down horizontal heavy",
+    ),
+    (
+        '\u{2549}',
+        "box drawings right light and left vertical heavy",
+    ),
+    (
+        '\u{254a}',
+        "box drawings left light and right vertical heavy",
+    ),
+    ('\u{254b}', "box drawings heavy vertical and horizontal"),
+    ('\u{254c}', "box drawings light double dash horizontal"),
+    ('\u{254d}', "box drawings heavy double dash horizontal"),
+    ('\u{254e}', "box drawings light double dash vertical"),
+    ('\u{254f}', "box drawings heavy double dash vertical"),
+    ('\u{2550}', "box drawings double horizontal"),
+    ('\u{2551}', "box drawings double vertical"),
+    ('\u{2552}', "box drawings down single and right double"),
+    ('\u{2553}', "box drawings down double and right single"),
+    ('\u{2554}', "box drawings double down and right"),
+    ('\u{2555}', "box drawings down single and left double"),
+    ('\u{2556}', "box drawings down double and left single"),
+    ('\u{2557}', "box drawings double down and left"),
+    ('\u{2558}', "box drawings up single and right double"),
+    ('\u{2559}', "box drawings up double and right single"),
+    ('\u{255a}', "box drawings double up and right"),
+    ('\u{255b}', "box drawings up single and left double"),
+    ('\u{255c}', "box drawings up double and left single"),
+    ('\u{255d}', "box drawings double up and left"),
+    ('\u{255e}', "box drawings vertical single and right double"),
+    ('\u{255f}', "box drawings vertical double and right single"),
+    ('\u{2560}', "box drawings double vertical and right"),
+    ('\u{2561}', "box drawings vertical single and left double"),
+    ('\u{2562}', "box drawings vertical double and left single"),
+    ('\u{2563}', "box drawings double vertical and left"),
+    ('\u{2564}', "box drawings down single and horizontal double"),
+    ('\u{2565}', "box drawings down double and horizontal single"),
+    ('\u{2566}', "box drawings double down and horizontal"),
+    ('\u{2567}', "box drawings up single and horizontal double"),
+    ('\u{2568}', "box drawings up double and horizontal single"),
+    ('\u{2569}', "box drawings double up and horizontal"),
+    (
+        '\u{256a}',
+        "box drawings vertical single and horizontal double",
+    ),
+    (
+        '\u{256b}',
+        "box drawings vertical double and horizontal single",
+    ),
+    ('\u{256c}', "box drawings double vertical and horizontal"),
+    ('\u{256d}', "box drawings light arc down and right"),
+    ('\u{256e}', "box drawings light arc down and left"),
+    ('\u{256f}', "box drawings light arc up and left"),
+    ('\u{2570}', "box drawings light arc up and right"),
+    (
+        '\u{2571}',
+        "box drawings light diagonal upper right to lower left",
+    ),
+    (
+        '\u{2572}',
+        "box drawings light diagonal upper left to lower right",
+    ),
+    ('\u{2573}', "box drawings light diagonal cross"),
+    ('\u{2574}', "box drawings light left"),
+    ('\u{2575}', "box drawings light up"),
+    ('\u{2576}', "box drawings light right"),
+    ('\u{2577}', "box drawings light down"),
+    ('\u{2578}', "box drawings heavy left"),
+    ('\u{2579}', "box drawings heavy up"),
+    ('\u{257a}', "box drawings heavy right"),
+    ('\u{257b}', "box drawings heavy down"),
+    ('\u{257c}', "box drawings light left and heavy right"),
+    ('\u{257d}', "box drawings light up and heavy down"),
+    ('\u{257e}', "box drawings heavy left and light right"),
+    ('\u{257f}', "box drawings heavy up and light down"),
+    // Block elements
+    ('\u{2580}', "upper half block"),
+    ('\u{2581}', "lower one eighth block"),
+    ('\u{2582}', "lower one quarter block"),
+    ('\u{2583}', "lower three eighths block"),
+    ('\u{2584}', "lower half block"),
+    ('\u{2585}', "lower five eighths block"),
+    ('\u{2586}', "lower three quarters block"),
+    ('\u{2587}', "lower seven eighths block"),
+    ('\u{2588}', "full block"),
+    ('\u{2589}', "left seven eighths block"),
+    ('\u{258a}', "left three quarters block"),
+    ('\u{258b}', "left five eighths block"),
+    ('\u{258c}', "left half block"),
+    ('\u{258d}', "left three eighths block"),
+    ('\u{258e}', "left one quarter block"),
+    ('\u{258f}', "left one eighth block"),
+    ('\u{2590}', "right half block"),
+    ('\u{2591}', "light shade"),
+    ('\u{2592}', "medium shade"),
+    ('\u{2593}', "dark shade"),
+    ('\u{2594}', "upper one eighth block"),
+    ('\u{2595}', "right one eighth block"),
+    ('\u{2596}', "quadrant lower left"),
+    ('\u{2597}', "quadrant lower right"),
+    ('\u{2598}', "quadrant upper left"),
+    (
+        '\u{2599}',
+        "quadrant upper left and lower left and lower right",
+    ),
+    ('\u{259a}', "quadrant upper left and lower right"),
+    (
+        '\u{259b}',
+        "quadrant upper left and upper right and lower left",
+    ),
+    (
+        '\u{259c}',
+        "quadrant upper left and upper right and lower right",
+    ),
+    ('\u{259d}', "quadrant upper right"),
+    ('\u{259e}', "quadrant upper right and lower left"),
+    (
+        '\u{259f}',
+        "quadrant upper right and lower left and lower right",
+    ),
+    // Geometric shapes
+    ('\u{25a0}', "black square"),
+    ('\u{25a1}', "white square"),
+    ('\u{25a2}', "white square with rounded corners"),
+    ('\u{25a3}', "white square containing black small square"),
+    ('\u{25a4}', "square with horizontal fill"),
+    ('\u{25a5}', "square with vertical fill"),
+    ('\u{25a6}', "square with orthogonal crosshatch fill"),
+    ('\u{25a7}', "square with upper left to lower right fill"),
+    ('\u{25a8}', "square with upper right to lower left fill"),
+    ('\u{25a9}', "square with diagonal crosshatch fill"),
+    ('\u{25aa}', "black small square"),
+    ('\u{25ab}', "white small square"),
+    ('\u{25ac}', "black rectangle"),
+    ('\u{25ad}', "white rectangle"),
+    ('\u{25ae}', "black vertical rectangle"),
+    ('\u{25af}', "white vertical rectangle"),
+    ('\u{25b0}', "black parallelogram"),
+    ('\u{25b1}', "white parallelogram"),
+    ('\u{25b2}', "black up-pointing triangle"),
+    ('\u{25b3}', "white up-pointing triangle"),
+    ('\u{25b4}', "black up-pointing small triangle"),
+    ('\u{25b5}', "white up-pointing small triangle"),
+    ('\u{25b6}', "black right-pointing triangle"),
+    ('\u{25b7}', "white right-pointing triangle"),
+    ('\u{25b8}', "black right-pointing small triangle"),
+    ('\u{25b9}', "white right-pointing small triangle"),
+    ('\u{25ba}', "black right-pointing pointer"),
+    ('\u{25bb}', "white right-pointing pointer"),
+    ('\u{25bc}', "black down-pointing triangle"),
+    ('\u{25bd}', "white down-pointing triangle"),
+    ('\u{25be}', "black down-pointing small triangle"),
+    ('\u{25bf}', "white down-pointing small triangle"),
+    ('\u{25c0}', "black left-pointing triangle"),
+    ('\u{25c1}', "white left-pointing triangle"),
+    ('\u{25c2}', "black left-pointing small triangle"),
+    ('\u{25c3}', "white left-pointing small triangle"),
+    ('\u{25c4}', "black left-pointing pointer"),
+    ('\u{25c5}', "white left-pointing pointer"),
+    ('\u{25c6}', "black diamond"),
+    ('\u{25c7}', "white diamond"),
+    ('\u{25c8}', "white diamond containing black small diamond"),
+    ('\u{25c9}', "fisheye"),
+    ('\u{25ca}', "lozenge"),
+    ('\u{25cb}', "white circle"),
+    ('\u{25cc}', "dotted circle"),
+    ('\u{25cd}', "circle with vertical fill"),
+    ('\u{25ce}', "bullseye"),
+    ('\u{25cf}', "black circle"),
+    ('\u{25d0}', "circle with left half black"),
+    ('\u{25d1}', "circle with right half black"),
+    ('\u{25d2}', "circle with lower half black"),
+    ('\u{25d3}', "circle with upper half black"),
+    ('\u{25d4}', "circle with upper right quadrant black"),
+    ('\u{25d5}', "circle with all but upper left quadrant black"),
+    ('\u{25d6}', "left half black circle"),
+    ('\u{25d7}', "right half black circle"),
+    ('\u{25d8}', "inverse bullet"),
+    ('\u{25d9}', "inverse white circle"),
+    ('\u{25da}', "upper half inverse white circle"),
+    ('\u{25db}', "lower half inverse white circle"),
+    ('\u{25dc}', "upper left quadrant circular arc"),
+    ('\u{25dd}', "upper right quadrant circular arc"),
+    ('\u{25de}', "lower right quadrant circular arc"),
+    ('\u{25df}', "lower left quadrant circular arc"),
+    ('\u{25e0}', "upper half circle"),
+    ('\u{25e1}', "lower half circle"),
+    ('\u{25e2}', "black lower right triangle"),
+    ('\u{25e3}', "black lower left triangle"),
+    ('\u{25e4}', "black upper left triangle"),
+    ('\u{25e5}', "black upper right triangle"),
+    ('\u{25e6}', "white bullet"),
+    ('\u{25e7}', "square with left half black"),
+    ('\u{25e8}', "square with right half black"),
+    ('\u{25e9}', "square with upper left diagonal half black"),
+    ('\u{25ea}', "square with lower right diagonal half black"),
+    ('\u{25eb}', "white square with vertical bisecting line"),
+    ('\u{25ec}', "white up-pointing triangle with dot"),
+    ('\u{25ed}', "up-pointing triangle with left half black"),
+    ('\u{25ee}', "up-pointing triangle with right half black"),
+    ('\u{25ef}', "large circle"),
+    ('\u{25f0}', "white square with upper left quadrant"),
+    ('\u{25f1}', "white square with lower left quadrant"),
+    ('\u{25f2}', "white square with lower right quadrant"),
+    ('\u{25f3}', "white square with upper right quadrant"),
+    ('\u{25f4}', "white circle with upper left quadrant"),
+    ('\u{25f5}', "white circle with lower left quadrant"),
+    ('\u{25f6}', "white circle with lower right quadrant"),
+    ('\u{25f7}', "white circle with upper right quadrant"),
+    ('\u{25f8}', "upper left triangle"),
+    ('\u{25f9}', "upper right triangle"),
+    ('\u{25fa}', "lower left triangle"),
+    ('\u{25fb}', "white medium square"),
+    ('\u{25fc}', "black medium square"),
+    ('\u{25fd}', "white medium small square"),
+    ('\u{25fe}', "black medium small square"),
+    ('\u{25ff}', "lower right triangle"),
+    // Miscellaneous symbols
+    ('\u{2600}', "black sun with rays"),
+    ('\u{2601}', "cloud"),
+    ('\u{2602}', "umbrella"),
+    ('\u{2603}', "snowman"),
+    ('\u{2604}', "comet"),
+    ('\u{2605}', "black star"),
+    ('\u{2606}', "white star"),
+    ('\u{2607}', "lightning"),
+    ('\u{2608}', "thunderstorm"),
+    ('\u{2609}', "sun"),
+    ('\u{260a}', "ascending node"),
+    ('\u{260b}', "descending node"),
+    ('\u{260c}', "conjunction"),
+    ('\u{260d}', "opposition"),
+    ('\u{260e}', "black telephone"),
+    ('\u{260f}', "white telephone"),
+    ('\u{2610}', "ballot box"),
+    ('\u{2611}', "ballot box with check"),
+    ('\u{2612}', "ballot box with x"),
+    ('\u{2613}', "saltire"),
+    ('\u{2614}', "umbrella with rain drops"),
+    ('\u{2615}', "hot beverage"),
+    ('\u{2616}', "white shogi piece"),
+    ('\u{2617}', "black shogi piece"),
+    ('\u{2618}', "shamrock"),
+    ('\u{2619}', "reversed rotated floral heart bullet"),
+    ('\u{261a}', "black left pointing index"),
+    ('\u{261b}', "black right pointing index"),
+    ('\u{261c}', "white left pointing index"),
+    ('\u{261d}', "white up pointing index"),
+    ('\u{261e}', "white right pointing index"),
+    ('\u{261f}', "white down pointing index"),
+    ('\u{2620}', "skull and crossbones"),
+    ('\u{2621}', "caution sign"),
+    ('\u{2622}', "radioactive sign"),
+    ('\u{2623}', "biohazard sign"),
+    ('\u{2624}', "caduceus"),
+    ('\u{2625}', "ankh"),
+    ('\u{2626}', "orthodox cross"),
+    ('\u{2627}', "chi rho"),
+    ('\u{2628}', "cross of lorraine"),
+    ('\u{2629}', "cross of jerusalem"),
+    ('\u{262a}', "star and crescent"),
+    ('\u{262b}', "farsi symbol"),
+    ('\u{262c}', "adi shakti"),
+    ('\u{262d}', "hammer and sickle"),
+    ('\u{262e}', "peace symbol"),
+    ('\u{262f}', "yin yang"),
+    ('\u{2630}', "trigram for heaven"),
+    ('\u{2631}', "trigram for lake"),
+    ('\u{2632}', "trigram for fire"),
+    ('\u{2633}', "trigram for thunder"),
+    ('\u{2634}', "trigram for wind"),
+    ('\u{2635}', "trigram for water"),
+    ('\u{2636}', "trigram for mountain"),
+    ('\u{2637}', "trigram for earth"),
+    ('\u{2638}', "wheel of dharma"),
+    ('\u{2639}', "white frowning face"),
+    ('\u{263a}', "white smiling face"),
+    ('\u{263b}', "black smiling face"),
+    ('\u{263c}', "white sun with rays"),
+    ('\u{263d}', "first quarter moon"),
+    ('\u{263e}', "last quarter moon"),
+    ('\u{263f}', "mercury"),
+    ('\u{2640}', "female sign"),
+    ('\u{2641}', "earth"),
+    ('\u{2642}', "male sign"),
+    ('\u{2643}', "jupiter"),
+    ('\u{2644}', "saturn"),
+    ('\u{2645}', "uranus"),
+    ('\u{2646}', "neptune"),
+    ('\u{2647}', "pluto"),
+    ('\u{2648}', "aries"),
+    ('\u{2649}', "taurus"),
+    ('\u{264a}', "gemini"),
+    ('\u{264b}', "cancer"),
+    ('\u{264c}', "leo"),
+    ('\u{264d}', "virgo"),
+    ('\u{264e}', "libra"),
+    ('\u{264f}', "scorpius"),
+    ('\u{2650}', "sagittarius"),
+    ('\u{2651}', "capricorn"),
+    ('\u{2652}', "aquarius"),
+    ('\u{2653}', "pisces"),
+    ('\u{2654}', "white chess king"),
+    ('\u{2655}', "white chess queen"),
+    ('\u{2656}', "white chess rook"),
+    ('\u{2657}', "white chess bishop"),
+    ('\u{2658}', "white chess knight"),
+    ('\u{2659}', "white chess pawn"),
+    ('\u{265a}', "black chess king"),
+    ('\u{265b}', "black chess queen"),
+    ('\u{265c}', "black chess rook"),
+    ('\u{265d}', "black chess bishop"),
+    ('\u{265e}', "black chess knight"),
+    ('\u{265f}', "black chess pawn"),
+    ('\u{2660}', "black spade suit"),
+    ('\u{2661}', "white heart suit"),
+    ('\u{2662}', "white diamond suit"),
+    ('\u{2663}', "black club suit"),
+    ('\u{2664}', "white spade suit"),
+    ('\u{2665}', "black heart suit"),
+    ('\u{2666}', "black diamond suit"),
+    ('\u{2667}', "white club suit"),
+    ('\u{2668}', "hot springs"),
+    ('\u{2669}', "quarter note"),
+    ('\u{266a}', "eighth note"),
+    ('\u{266b}', "beamed eighth notes"),
+    ('\u{266c}', "beamed sixteenth notes"),
+    ('\u{266d}', "music flat sign"),
+    ('\u{266e}', "music natural sign"),
+    ('\u{266f}', "music sharp sign"),
+    ('\u{2670}', "west syriac cross"),
+    ('\u{2671}', "east syriac cross"),
+    ('\u{2672}', "universal recycling symbol"),
+    ('\u{2673}', "recycling symbol for type-1 plastics"),
+    ('\u{2674}', "recycling symbol for type-2 plastics"),
+    ('\u{2675}', "recycling symbol for type-3 plastics"),
+    ('\u{2676}', "recycling symbol for type-4 plastics"),
+    ('\u{2677}', "recycling symbol for type-5 plastics"),
+    ('\u{2678}', "recycling symbol for type-6 plastics"),
+    ('\u{2679}', "recycling symbol for type-7 plastics"),
+    ('\u{267a}', "recycling symbol for generic materials"),
+    ('\u{267b}', "black universal recycling symbol"),
+    ('\u{267c}', "recycled paper symbol"),
+    ('\u{267d}', "partially-recycled paper symbol"),
+    ('\u{267e}', "permanent paper sign"),
+    ('\u{267f}', "wheelchair symbol"),
+    ('\u{2680}', "die face-1"),
+    ('\u{2681}', "die face-2"),
+    ('\u{2682}', "die face-3"),
+    ('\u{2683}', "die face-4"),
+    ('\u{2684}', "die face-5"),
+    ('\u{2685}', "die face-6"),
+    ('\u{2686}', "white circle with dot right"),
+    ('\u{2687}', "white circle with two dots"),
+    ('\u{2688}', "black circle with white dot right"),
+    ('\u{2689}', "black circle with two white dots"),
+    ('\u{268a}', "monogram for yang"),
+    ('\u{268b}', "monogram for yin"),
+    ('\u{268c}', "digram for greater yang"),
+    ('\u{268d}', "digram for lesser yin"),
+    ('\u{268e}', "digram for lesser yang"),
+    ('\u{268f}', "digram for greater yin"),
+    ('\u{2690}', "white flag"),
+    ('\u{2691}', "black flag"),
+    ('\u{2692}', "hammer and pick"),
+    ('\u{2693}', "anchor"),
+    ('\u{2694}', "crossed swords"),
+    ('\u{2695}', "staff of aesculapius"),
+    ('\u{2696}', "scales"),
+    ('\u{2697}', "alembic"),
+    ('\u{2698}', "flower"),
+    ('\u{2699}', "gear"),
+    ('\u{269a}', "staff of hermes"),
+    ('\u{269b}', "atom symbol"),
+    ('\u{269c}', "fleur-de-lis"),
+    ('\u{269d}', "outlined white star"),
+    ('\u{269e}', "three lines converging right"),
+    ('\u{269f}', "three lines converging left"),
+    ('\u{26a0}', "warning sign"),
+    ('\u{26a1}', "high voltage sign"),
+    ('\u{26a2}', "doubled female sign"),
+    ('\u{26a3}', "doubled male sign"),
+    ('\u{26a4}', "interlocked female and male sign"),
+    ('\u{26a5}', "male and female sign"),
+    ('\u{26a6}', "male with stroke sign"),
+    ('\u{26a7}', "male with stroke and male and female sign"),
+    ('\u{26a8}', "vertical male with stroke sign"),
+    ('\u{26a9}', "horizontal male with stroke sign"),
+    ('\u{26aa}', "medium white circle"),
+    ('\u{26ab}', "medium black circle"),
+    ('\u{26ac}', "medium small white circle"),
+    ('\u{26ad}', "marriage symbol"),
+    ('\u{26ae}', "divorce symbol"),
+    ('\u{26af}', "unmarried partnership symbol"),
+    ('\u{26b0}', "coffin"),
+    ('\u{26b1}', "funeral urn"),
+    ('\u{26b2}', "neuter"),
+    ('\u{26b3}', "ceres"),
+    ('\u{26b4}', "pallas"),
+    ('\u{26b5}', "juno"),
+    ('\u{26b6}', "vesta"),
+    ('\u{26b7}', "chiron"),
+    ('\u{26b8}', "black moon lilith"),
+    ('\u{26b9}', "sextile"),
+    ('\u{26ba}', "semisextile"),
+    ('\u{26bb}', "quincunx"),
+    ('\u{26bc}', "sesquiquadrate"),
+    ('\u{26bd}', "soccer ball"),
+    ('\u{26be}', "baseball"),
+    ('\u{26bf}', "squared key"),
+    ('\u{26c0}', "white draughts man"),
+    ('\u{26c1}', "white draughts king"),
+    ('\u{26c2}', "black draughts man"),
+    ('\u{26c3}', "black draughts king"),
+    ('\u{26c4}', "snowman without snow"),
+    ('\u{26c5}', "sun behind cloud"),
+    ('\u{26c6}', "rain"),
+    ('\u{26c7}', "black snowman"),
+    ('\u{26c8}', "thunder cloud and rain"),
+    ('\u{26c9}', "turned white shogi piece"),
+    ('\u{26ca}', "turned black shogi piece"),
+    ('\u{26cb}', "white diamond in square"),
+    ('\u{26cc}', "crossing lanes"),
+    ('\u{26cd}', "disabled car"),
+    ('\u{26ce}', "ophiuchus"),
+    ('\u{26cf}', "pick"),
+    ('\u{26d0}', "car sliding"),
+    ('\u{26d1}', "helmet with white cross"),
+    ('\u{26d2}', "circled crossing lanes"),
+    ('\u{26d3}', "chains"),
+    ('\u{26d4}', "no entry"),
+    ('\u{26d5}', "alternate one-way left way traffic"),
+    ('\u{26d6}', "black two-way left way traffic"),
+    ('\u{26d7}', "white two-way left way traffic"),
+    ('\u{26d8}', "black left lane merge"),
+    ('\u{26d9}', "white left lane merge"),
+    ('\u{26da}', "drive slow sign"),
+    ('\u{26db}', "heavy white down-pointing triangle"),
+    ('\u{26dc}', "left closed entry"),
+    ('\u{26dd}', "squared saltire"),
+    (
+        '\u{26de}',
+        "falling diagonal in white circle in black square",
+    ),
+    ('\u{26df}', "black truck"),
+    ('\u{26e0}', "restricted left entry-1"),
+    ('\u{26e1}', "restricted left entry-2"),
+    ('\u{26e2}', "astronomical symbol for uranus"),
+    ('\u{26e3}', "heavy circle with stroke and two dots above"),
+    ('\u{26e4}', "pentagram"),
+    ('\u{26e5}', "right-handed interlaced pentagram"),
+    ('\u{26e6}', "left-handed interlaced pentagram"),
+    ('\u{26e7}', "inverted pentagram"),
+    ('\u{26e8}', "black cross on shield"),
+    ('\u{26e9}', "shinto shrine"),
+    ('\u{26ea}', "church"),
+    ('\u{26eb}', "castle"),
+    ('\u{26ec}', "historic site"),
+    ('\u{26ed}', "gear without hub"),
+    ('\u{26ee}', "gear with handles"),
+    ('\u{26ef}', "map symbol for lighthouse"),
+    ('\u{26f0}', "mountain"),
+    ('\u{26f1}', "umbrella on ground"),
+    ('\u{26f2}', "fountain"),
+    ('\u{26f3}', "flag in hole"),
+    ('\u{26f4}', "ferry"),
+    ('\u{26f5}', "sailboat"),
+    ('\u{26f6}', "square four corners"),
+    ('\u{26f7}', "skier"),
+    ('\u{26f8}', "ice skate"),
+    ('\u{26f9}', "person with ball"),
+    ('\u{26fa}', "tent"),
+    ('\u{26fb}', "japanese bank symbol"),
+    ('\u{26fc}', "headstone graveyard symbol"),
+    ('\u{26fd}', "fuel pump"),
+    ('\u{26fe}', "cup on black square"),
+    ('\u{26ff}', "white flag with horizontal middle black stripe"),
+    // Dingbats
+    ('\u{2700}', "black safety scissors"),
+    ('\u{2701}', "upper blade scissors"),
+    ('\u{2702}', "black scissors"),
+    ('\u{2703}', "lower blade scissors"),
+    ('\u{2704}', "white scissors"),
+    ('\u{2705}', "white heavy check mark"),
+    ('\u{2706}', "telephone location sign"),
+    ('\u{2707}', "tape drive"),
+    ('\u{2708}', "airplane"),
+    ('\u{2709}', "envelope"),
+    ('\u{270a}', "raised fist"),
+    ('\u{270b}', "raised hand"),
+    ('\u{270c}', "victory hand"),
+    ('\u{270d}', "writing hand"),
+    ('\u{270e}', "lower right pencil"),
+    ('\u{270f}', "pencil"),
+    ('\u{2710}', "upper right pencil"),
+    ('\u{2711}', "white nib"),
+    ('\u{2712}', "black nib"),
+    ('\u{2713}', "check mark"),
+    ('\u{2714}', "heavy check mark"),
+    ('\u{2715}', "multiplication x"),
+    ('\u{2716}', "heavy multiplication x"),
+    ('\u{2717}', "ballot x"),
+    ('\u{2718}', "heavy ballot x"),
+    ('\u{2719}', "outlined greek cross"),
+    ('\u{271a}', "heavy greek cross"),
+    ('\u{271b}', "open centre cross"),
+    ('\u{271c}', "heavy open centre cross"),
+    ('\u{271d}', "latin cross"),
+    ('\u{271e}', "shadowed white latin cross"),
+    ('\u{271f}', "outlined latin cross"),
+    ('\u{2720}', "maltese cross"),
+    ('\u{2721}', "star of david"),
+    ('\u{2722}', "four teardrop-spoked asterisk"),
+    ('\u{2723}', "four balloon-spoked asterisk"),
+    ('\u{2724}', "heavy four balloon-spoked asterisk"),
+    ('\u{2725}', "four club-spoked asterisk"),
+    ('\u{2726}', "black four pointed star"),
+    ('\u{2727}', "white four pointed star"),
+    ('\u{2728}', "sparkles"),
+    ('\u{2729}', "stress outlined white star"),
+    ('\u{272a}', "circled white star"),
+    ('\u{272b}', "open centre black star"),
+    ('\u{272c}', "black centre white star"),
+    ('\u{272d}', "outlined black star"),
+    ('\u{272e}', "heavy outlined black star"),
+    ('\u{272f}', "pinwheel star"),
+    ('\u{2730}', "shadowed white star"),
+    ('\u{2731}', "heavy asterisk"),
+    ('\u{2732}', "open centre asterisk"),
+    ('\u{2733}', "eight spoked asterisk"),
+    ('\u{2734}', "eight pointed black star"),
+    ('\u{2735}', "eight pointed pinwheel star"),
+    ('\u{2736}', "six pointed black star"),
+    ('\u{2737}', "eight pointed rectilinear black star"),
+    ('\u{2738}', "heavy eight pointed rectilinear black star"),
+    ('\u{2739}', "twelve pointed black star"),
+    ('\u{273a}', "sixteen pointed asterisk"),
+    ('\u{273b}', "teardrop-spoked asterisk"),
+    ('\u{273c}', "open centre teardrop-spoked asterisk"),
+    ('\u{273d}', "heavy teardrop-spoked asterisk"),
+    ('\u{273e}', "six petalled black and white florette"),
+    ('\u{273f}', "black florette"),
+    ('\u{2740}', "white florette"),
+    ('\u{2741}', "eight petalled outlined black florette"),
+    ('\u{2742}', "circled open centre eight pointed star"),
+    ('\u{2743}', "heavy teardrop-spoked pinwheel asterisk"),
+    ('\u{2744}', "snowflake"),
+    ('\u{2745}', "tight trifoliate snowflake"),
+    ('\u{2746}', "heavy chevron snowflake"),
+    ('\u{2747}', "sparkle"),
+    ('\u{2748}', "heavy sparkle"),
+    ('\u{2749}', "balloon-spoked asterisk"),
+    ('\u{274a}', "eight teardrop-spoked propeller asterisk"),
+    ('\u{274b}', "heavy eight teardrop-spoked propeller asterisk"),
+    ('\u{274c}', "cross mark"),
+    ('\u{274d}', "shadowed white circle"),
+    ('\u{274e}', "negative squared cross mark"),
+    ('\u{274f}', "lower right drop-shadowed white square"),
+    ('\u{2750}', "upper right drop-shadowed white square"),
+    ('\u{2751}', "lower right shadowed white square"),
+    ('\u{2752}', "upper right shadowed white square"),
+    ('\u{2753}', "black question mark ornament"),
+    ('\u{2754}', "white question mark ornament"),
+    ('\u{2755}', "white exclamation mark ornament"),
+    ('\u{2756}', "black diamond minus white x"),
+    ('\u{2757}', "heavy exclamation mark symbol"),
+    ('\u{2758}', "light vertical bar"),
+    ('\u{2759}', "medium vertical bar"),
+    ('\u{275a}', "heavy vertical bar"),
+    (
+        '\u{275b}',
+        "heavy single turned comma quotation mark ornament",
+    ),
+    ('\u{275c}', "heavy single comma quotation mark ornament"),
+    (
+        '\u{275d}',
+        "heavy double turned comma quotation mark ornament",
+    ),
+    ('\u{275e}', "heavy double comma quotation mark ornament"),
+    ('\u{275f}', "heavy low single comma quotation mark ornament"),
+    ('\u{2760}', "heavy low double comma quotation mark ornament"),
+    ('\u{2761}', "curved stem paragraph sign ornament"),
+    ('\u{2762}', "heavy exclamation mark ornament"),
+    ('\u{2763}', "heavy heart exclamation mark ornament"),
+    ('\u{2764}', "heavy black heart"),
+    ('\u{2765}', "rotated heavy black heart bullet"),
+    ('\u{2766}', "floral heart"),
+    ('\u{2767}', "rotated floral heart bullet"),
+    ('\u{2768}', "medium left parenthesis ornament"),
+    ('\u{2769}', "medium right parenthesis ornament"),
+    ('\u{276a}', "medium flattened left parenthesis ornament"),
+    ('\u{276b}', "medium flattened right parenthesis ornament"),
+    ('\u{276c}', "medium left-pointing angle bracket ornament"),
+    ('\u{276d}', "medium right-pointing angle bracket ornament"),
+    (
+        '\u{276e}',
+        "heavy left-pointing angle quotation mark ornament",
+    ),
+    (
+        '\u{276f}',
+        "heavy right-pointing angle quotation mark ornament",
+    ),
+    ('\u{2770}', "heavy left-pointing angle bracket ornament"),
+    ('\u{2771}', "heavy right-pointing angle bracket ornament"),
+    ('\u{2772}', "light left tortoise shell bracket ornament"),
+    ('\u{2773}', "light right tortoise shell bracket ornament"),
+    ('\u{2774}', "medium left curly bracket ornament"),
+    ('\u{2775}', "medium right curly bracket ornament"),
+    ('\u{2776}', "dingbat negative circled digit one"),
+    ('\u{2777}', "dingbat negative circled digit two"),
+    ('\u{2778}', "dingbat negative circled digit three"),
+    ('\u{2779}', "dingbat negative circled digit four"),
+    ('\u{277a}', "dingbat negative circled digit five"),
+    ('\u{277b}', "dingbat negative circled digit six"),
+    ('\u{277c}', "dingbat negative circled digit seven"),
+    ('\u{277d}', "dingbat negative circled digit eight"),
+    ('\u{277e}', "dingbat negative circled digit nine"),
+    ('\u{277f}', "dingbat negative circled number ten"),
+    ('\u{2780}', "dingbat circled sans-serif digit one"),
+    ('\u{2781}', "dingbat circled sans-serif digit two"),
+    ('\u{2782}', "dingbat circled sans-serif digit three"),
+    ('\u{2783}', "dingbat circled sans-serif digit four"),
+    ('\u{2784}', "dingbat circled sans-serif digit five"),
+    ('\u{2785}', "dingbat circled sans-serif digit six"),
+    ('\u{2786}', "dingbat circled sans-serif digit seven"),
+    ('\u{2787}', "dingbat circled sans-serif digit eight"),
+    ('\u{2788}', "dingbat circled sans-serif digit nine"),
+    ('\u{2789}', "dingbat circled sans-serif number ten"),
+    ('\u{278a}', "dingbat negative circled sans-serif digit one"),
+    ('\u{278b}', "dingbat negative circled sans-serif digit two"),
+    (
+        '\u{278c}',
+        "dingbat negative circled sans-serif digit three",
+    ),
+    ('\u{278d}', "dingbat negative circled sans-serif digit four"),
+    ('\u{278e}', "dingbat negative circled sans-serif digit five"),
+    ('\u{278f}', "dingbat negative circled sans-serif digit six"),
+    (
+        '\u{2790}',
+        "dingbat negative circled sans-serif digit seven",
+    ),
+    (
+        '\u{2791}',
+        "dingbat negative circled sans-serif digit eight",
+    ),
+    ('\u{2792}', "dingbat negative circled sans-serif digit nine"),
+    ('\u{2793}', "dingbat negative circled sans-serif number ten"),
+    ('\u{2794}', "heavy wide-headed rightwards arrow"),
+    ('\u{2795}', "heavy plus sign"),
+    ('\u{2796}', "heavy minus sign"),
+    ('\u{2797}', "heavy division sign"),
+    ('\u{2798}', "heavy south east arrow"),
+    ('\u{2799}', "heavy rightwards arrow"),
+    ('\u{279a}', "heavy north east arrow"),
+    ('\u{279b}', "drafting point rightwards arrow"),
+    ('\u{279c}', "heavy round-tipped rightwards arrow"),
+    ('\u{279d}', "triangle-headed rightwards arrow"),
+    ('\u{279e}', "heavy triangle-headed rightwards arrow"),
+    ('\u{279f}', "dashed triangle-headed rightwards arrow"),
+    ('\u{27a0}', "heavy dashed triangle-headed rightwards arrow"),
+    ('\u{27a1}', "black rightwards arrow"),
+    ('\u{27a2}', "three-d top-lighted rightwards arrowhead"),
+    ('\u{27a3}', "three-d bottom-lighted rightwards arrowhead"),
+    ('\u{27a4}', "black rightwards arrowhead"),
+    (
+        '\u{27a5}',
+        "heavy black curved downwards and rightwards arrow",
+    ),
+    (
+        '\u{27a6}',
+        "heavy black curved upwards and rightwards arrow",
+    ),
+    ('\u{27a7}', "squat black rightwards arrow"),
+    ('\u{27a8}', "heavy concave-pointed black rightwards arrow"),
+    ('\u{27a9}', "right-shaded white rightwards arrow"),
+    ('\u{27aa}', "left-shaded white rightwards arrow"),
+    ('\u{27ab}', "back-tilted shadowed white rightwards arrow"),
+    ('\u{27ac}', "front-tilted shadowed white rightwards arrow"),
+    (
+        '\u{27ad}',
+        "heavy lower right-shadowed white rightwards arrow",
+    ),
+    (
+        '\u{27ae}',
+        "heavy upper right-shadowed white rightwards arrow",
+    ),
+    (
+        '\u{27af}',
+        "notched lower right-shadowed white rightwards arrow",
+    ),
+    ('\u{27b0}', "curly loop"),
+    (
+        '\u{27b1}',
+        "notched upper right-shadowed white rightwards arrow",
+    ),
+    ('\u{27b2}', "circled heavy white rightwards arrow"),
+    ('\u{27b3}', "white-feathered rightwards arrow"),
+    ('\u{27b4}', "black-feathered south east arrow"),
+    ('\u{27b5}', "black-feathered rightwards arrow"),
+    ('\u{27b6}', "black-feathered north east arrow"),
+    ('\u{27b7}', "heavy black-feathered south east arrow"),
+    ('\u{27b8}', "heavy black-feathered rightwards arrow"),
+    ('\u{27b9}', "heavy black-feathered north east arrow"),
+    ('\u{27ba}', "teardrop-barbed rightwards arrow"),
+    ('\u{27bb}', "heavy teardrop-shanked rightwards arrow"),
+    ('\u{27bc}', "wedge-tailed rightwards arrow"),
+    ('\u{27bd}', "heavy wedge-tailed rightwards arrow"),
+    ('\u{27be}', "open-outlined rightwards arrow"),
+    ('\u{27bf}', "double curly loop"),
+    // Emoji: symbols and pictographs
+    ('\u{1f300}', "cyclone"),
+    ('\u{1f301}', "foggy"),
+    ('\u{1f302}', "closed umbrella"),
+    ('\u{1f303}', "night with stars"),
+    ('\u{1f304}', "sunrise over mountains"),
+    ('\u{1f305}', "sunrise"),
+    ('\u{1f306}', "cityscape at dusk"),
+    ('\u{1f307}', "sunset over buildings"),
+    ('\u{1f308}', "rainbow"),
+    ('\u{1f309}', "bridge at night"),
+    ('\u{1f30a}', "water wave"),
+    ('\u{1f30b}', "volcano"),
+    ('\u{1f30c}', "milky way"),
+    ('\u{1f30d}', "earth globe europe-africa"),
+    ('\u{1f30e}', "earth globe americas"),
+    ('\u{1f30f}', "earth globe asia-australia"),
+    ('\u{1f310}', "globe with meridians"),
+    ('\u{1f311}', "new moon symbol"),
+    ('\u{1f312}', "waxing crescent moon symbol"),
+    ('\u{1f313}', "first quarter moon symbol"),
+    ('\u{1f314}', "waxing gibbous moon symbol"),
+    ('\u{1f315}', "full moon symbol"),
+    ('\u{1f316}', "waning gibbous moon symbol"),
+    ('\u{1f317}', "last quarter moon symbol"),
+    ('\u{1f318}', "waning crescent moon symbol"),
+    ('\u{1f319}', "crescent moon"),
+    ('\u{1f31a}', "new moon with face"),
+    ('\u{1f31b}', "first quarter moon with face"),
+    ('\u{1f31c}', "last quarter moon with face"),
+    ('\u{1f31d}', "full moon with face"),
+    ('\u{1f31e}', "sun with face"),
+    ('\u{1f31f}', "glowing star"),
+    ('\u{1f320}', "shooting star"),
+    ('\u{1f321}', "thermometer"),
+    ('\u{1f322}', "black droplet"),
+    ('\u{1f323}', "white sun"),
+    ('\u{1f324}', "white sun with small cloud"),
+    ('\u{1f325}', "white sun behind cloud"),
+    ('\u{1f326}', "white sun behind cloud with rain"),
+    ('\u{1f327}', "cloud with rain"),
+    ('\u{1f328}', "cloud with snow"),
+    ('\u{1f329}', "cloud with lightning"),
+    ('\u{1f32a}', "cloud with tornado"),
+    ('\u{1f32b}', "fog"),
+    ('\u{1f32c}', "wind blowing face"),
+    ('\u{1f32d}', "hot dog"),
+    ('\u{1f32e}', "taco"),
+    ('\u{1f32f}', "burrito"),
+    ('\u{1f330}', "chestnut"),
+    ('\u{1f331}', "seedling"),
+    ('\u{1f332}', "evergreen tree"),
+    ('\u{1f333}', "deciduous tree"),
+    ('\u{1f334}', "palm tree"),
+    ('\u{1f335}', "cactus"),
+    ('\u{1f336}', "hot pepper"),
+    ('\u{1f337}', "tulip"),
+    ('\u{1f338}', "cherry blossom"),
+    ('\u{1f339}', "rose"),
+    ('\u{1f33a}', "hibiscus"),
+    ('\u{1f33b}', "sunflower"),
+    ('\u{1f33c}', "blossom"),
+    ('\u{1f33d}', "ear of maize"),
+    ('\u{1f33e}', "ear of rice"),
+    ('\u{1f33f}', "herb"),
+    ('\u{1f340}', "four leaf clover"),
+    ('\u{1f341}', "maple leaf"),
+    ('\u{1f342}', "fallen leaf"),
+    ('\u{1f343}', "leaf fluttering in wind"),
+    ('\u{1f344}', "mushroom"),
+    ('\u{1f345}', "tomato"),
+    ('\u{1f346}', "aubergine"),
+    ('\u{1f347}', "grapes"),
+    ('\u{1f348}', "melon"),
+    ('\u{1f349}', "watermelon"),
+    ('\u{1f34a}', "tangerine"),
+    ('\u{1f34b}', "lemon"),
+    ('\u{1f34c}', "banana"),
+    ('\u{1f34d}', "pineapple"),
+    ('\u{1f34e}', "red apple"),
+    ('\u{1f34f}', "green apple"),
+    ('\u{1f350}', "pear"),
+    ('\u{1f351}', "peach"),
+    ('\u{1f352}', "cherries"),
+    ('\u{1f353}', "strawberry"),
+    ('\u{1f354}', "hamburger"),
+    ('\u{1f355}', "slice of pizza"),
+    ('\u{1f356}', "meat on bone"),
+    ('\u{1f357}', "poultry leg"),
+    ('\u{1f358}', "rice cracker"),
+    ('\u{1f359}', "rice ball"),
+    ('\u{1f35a}', "cooked rice"),
+    ('\u{1f35b}', "curry and rice"),
+    ('\u{1f35c}', "steaming bowl"),
+    ('\u{1f35d}', "spaghetti"),
+    ('\u{1f35e}', "bread"),
+    ('\u{1f35f}', "french fries"),
+    ('\u{1f360}', "roasted sweet potato"),
+    ('\u{1f361}', "dango"),
+    ('\u{1f362}', "oden"),
+    ('\u{1f363}', "sushi"),
+    ('\u{1f364}', "fried shrimp"),
+    ('\u{1f365}', "fish cake with swirl design"),
+    ('\u{1f366}', "soft ice cream"),
+    ('\u{1f367}', "shaved ice"),
+    ('\u{1f368}', "ice cream"),
+    ('\u{1f369}', "doughnut"),
+    ('\u{1f36a}', "cookie"),
+    ('\u{1f36b}', "chocolate bar"),
+    ('\u{1f36c}', "candy"),
+    ('\u{1f36d}', "lollipop"),
+    ('\u{1f36e}', "custard"),
+    ('\u{1f36f}', "honey pot"),
+    ('\u{1f370}', "shortcake"),
+    ('\u{1f371}', "bento box"),
+    ('\u{1f372}', "pot of food"),
+    ('\u{1f373}', "cooking"),
+    ('\u{1f374}', "fork and knife"),
+    ('\u{1f375}', "teacup without handle"),
+    ('\u{1f376}', "sake bottle and cup"),
+    ('\u{1f377}', "wine glass"),
+    ('\u{1f378}', "cocktail glass"),
+    ('\u{1f379}', "tropical drink"),
+    ('\u{1f37a}', "beer mug"),
+    ('\u{1f37b}', "clinking beer mugs"),
+    ('\u{1f37c}', "baby bottle"),
+    ('\u{1f37d}', "fork and knife with plate"),
+    ('\u{1f37e}', "bottle with popping cork"),
+    ('\u{1f37f}', "popcorn"),
+    ('\u{1f380}', "ribbon"),
+    ('\u{1f381}', "wrapped present"),
+    ('\u{1f382}', "birthday cake"),
+    ('\u{1f383}', "jack-o-lantern"),
+    ('\u{1f384}', "christmas tree"),
+    ('\u{1f385}', "father christmas"),
+    ('\u{1f386}', "fireworks"),
+    ('\u{1f387}', "firework sparkler"),
+    ('\u{1f388}', "balloon"),
+    ('\u{1f389}', "party popper"),
+    ('\u{1f38a}', "confetti ball"),
+    ('\u{1f38b}', "tanabata tree"),
+    ('\u{1f38c}', "crossed flags"),
+    ('\u{1f38d}', "pine decoration"),
+    ('\u{1f38e}', "japanese dolls"),
+    ('\u{1f38f}', "carp streamer"),
+    ('\u{1f390}', "wind chime"),
+    ('\u{1f391}', "moon viewing ceremony"),
+    ('\u{1f392}', "school satchel"),
+    ('\u{1f393}', "graduation cap"),
+    ('\u{1f394}', "heart with tip on the left"),
+    ('\u{1f395}', "bouquet of flowers"),
+    ('\u{1f396}', "military medal"),
+    ('\u{1f397}', "reminder ribbon"),
+    ('\u{1f398}', "musical keyboard with jacks"),
+    ('\u{1f399}', "studio microphone"),
+    ('\u{1f39a}', "level slider"),
+    ('\u{1f39b}', "control knobs"),
+    ('\u{1f39c}', "beamed ascending musical notes"),
+    ('\u{1f39d}', "beamed descending musical notes"),
+    ('\u{1f39e}', "film frames"),
+    ('\u{1f39f}', "admission tickets"),
+    ('\u{1f3a0}', "carousel horse"),
+    ('\u{1f3a1}', "ferris wheel"),
+    ('\u{1f3a2}', "roller coaster"),
+    ('\u{1f3a3}', "fishing pole and fish"),
+    ('\u{1f3a4}', "microphone"),
+    ('\u{1f3a5}', "movie camera"),
+    ('\u{1f3a6}', "cinema"),
+    ('\u{1f3a7}', "headphone"),
+    ('\u{1f3a8}', "artist palette"),
+    ('\u{1f3a9}', "top hat"),
+    ('\u{1f3aa}', "circus tent"),
+    ('\u{1f3ab}', "ticket"),
+    ('\u{1f3ac}', "clapper board"),
+    ('\u{1f3ad}', "performing arts"),
+    ('\u{1f3ae}', "video game"),
+    ('\u{1f3af}', "direct hit"),
+    ('\u{1f3b0}', "slot machine"),
+    ('\u{1f3b1}', "billiards"),
+    ('\u{1f3b2}', "game die"),
+    ('\u{1f3b3}', "bowling"),
+    ('\u{1f3b4}', "flower playing cards"),
+    ('\u{1f3b5}', "musical note"),
+    ('\u{1f3b6}', "multiple musical notes"),
+    ('\u{1f3b7}', "saxophone"),
+    ('\u{1f3b8}', "guitar"),
+    ('\u{1f3b9}', "musical keyboard"),
+    ('\u{1f3ba}', "trumpet"),
+    ('\u{1f3bb}', "violin"),
+    ('\u{1f3bc}', "musical score"),
+    ('\u{1f3bd}', "running shirt with sash"),
+    ('\u{1f3be}', "tennis racquet and ball"),
+    ('\u{1f3bf}', "ski and ski boot"),
+    ('\u{1f3c0}', "basketball and hoop"),
+    ('\u{1f3c1}', "chequered flag"),
+    ('\u{1f3c2}', "snowboarder"),
+    ('\u{1f3c3}', "runner"),
+    ('\u{1f3c4}', "surfer"),
+    ('\u{1f3c5}', "sports medal"),
+    ('\u{1f3c6}', "trophy"),
+    ('\u{1f3c7}', "horse racing"),
+    ('\u{1f3c8}', "american football"),
+    ('\u{1f3c9}', "rugby football"),
+    ('\u{1f3ca}', "swimmer"),
+    ('\u{1f3cb}', "weight lifter"),
+    ('\u{1f3cc}', "golfer"),
+    ('\u{1f3cd}', "racing motorcycle"),
+    ('\u{1f3ce}', "racing car"),
+    ('\u{1f3cf}', "cricket bat and ball"),
+    ('\u{1f3d0}', "volleyball"),
+    ('\u{1f3d1}', "field hockey stick and ball"),
+    ('\u{1f3d2}', "ice hockey stick and puck"),
+    ('\u{1f3d3}', "table tennis paddle and ball"),
+    ('\u{1f3d4}', "snow capped mountain"),
+    ('\u{1f3d5}', "camping"),
+    ('\u{1f3d6}', "beach with umbrella"),
+    ('\u{1f3d7}', "building construction"),
+    ('\u{1f3d8}', "house buildings"),
+    ('\u{1f3d9}', "cityscape"),
+    ('\u{1f3da}', "derelict house building"),
+    ('\u{1f3db}', "classical building"),
+    ('\u{1f3dc}', "desert"),
+    ('\u{1f3dd}', "desert island"),
+    ('\u{1f3de}', "national park"),
+    ('\u{1f3df}', "stadium"),
+    ('\u{1f3e0}', "house building"),
+    ('\u{1f3e1}', "house with garden"),
+    ('\u{1f3e2}', "office building"),
+    ('\u{1f3e3}', "japanese post office"),
+    ('\u{1f3e4}', "european post office"),
+    ('\u{1f3e5}', "hospital"),
+    ('\u{1f3e6}', "bank"),
+    ('\u{1f3e7}', "automated teller machine"),
+    ('\u{1f3e8}', "hotel"),
+    ('\u{1f3e9}', "love hotel"),
+    ('\u{1f3ea}', "convenience store"),
+    ('\u{1f3eb}', "school"),
+    ('\u{1f3ec}', "department store"),
+    ('\u{1f3ed}', "factory"),
+    ('\u{1f3ee}', "izakaya lantern"),
+    ('\u{1f3ef}', "japanese castle"),
+    ('\u{1f3f0}', "european castle"),
+    ('\u{1f3f1}', "white pennant"),
+    ('\u{1f3f2}', "black pennant"),
+    ('\u{1f3f3}', "waving white flag"),
+    ('\u{1f3f4}', "waving black flag"),
+    ('\u{1f3f5}', "rosette"),
+    ('\u{1f3f6}', "black rosette"),
+    ('\u{1f3f7}', "label"),
+    ('\u{1f3f8}', "badminton racquet and shuttlecock"),
+    ('\u{1f3f9}', "bow and arrow"),
+    ('\u{1f3fa}', "amphora"),
+    ('\u{1f3fb}', "emoji modifier fitzpatrick type-1-2"),
+    ('\u{1f3fc}', "emoji modifier fitzpatrick type-3"),
+    ('\u{1f3fd}', "emoji modifier fitzpatrick type-4"),
+    ('\u{1f3fe}', "emoji modifier fitzpatrick type-5"),
+    ('\u{1f3ff}', "emoji modifier fitzpatrick type-6"),
+    ('\u{1f400}', "rat"),
+    ('\u{1f401}', "mouse"),
+    ('\u{1f402}', "ox"),
+    ('\u{1f403}', "water buffalo"),
+    ('\u{1f404}', "cow"),
+    ('\u{1f405}', "tiger"),
+    ('\u{1f406}', "leopard"),
+    ('\u{1f407}', "rabbit"),
+    ('\u{1f408}', "cat"),
+    ('\u{1f409}', "dragon"),
+    ('\u{1f40a}', "crocodile"),
+    ('\u{1f40b}', "whale"),
+    ('\u{1f40c}', "snail"),
+    ('\u{1f40d}', "snake"),
+    ('\u{1f40e}', "horse"),
+    ('\u{1f40f}', "ram"),
+    ('\u{1f410}', "goat"),
+    ('\u{1f411}', "sheep"),
+    ('\u{1f412}', "monkey"),
+    ('\u{1f413}', "rooster"),
+    ('\u{1f414}', "chicken"),
+    ('\u{1f415}', "dog"),
+    ('\u{1f416}', "pig"),
+    ('\u{1f417}', "boar"),
+    ('\u{1f418}', "elephant"),
+    ('\u{1f419}', "octopus"),
+    ('\u{1f41a}', "spiral shell"),
+    ('\u{1f41b}', "bug"),
+    ('\u{1f41c}', "ant"),
+    ('\u{1f41d}', "honeybee"),
+    ('\u{1f41e}', "lady beetle"),
+    ('\u{1f41f}', "fish"),
+    ('\u{1f420}', "tropical fish"),
+    ('\u{1f421}', "blowfish"),
+    ('\u{1f422}', "turtle"),
+    ('\u{1f423}', "hatching chick"),
+    ('\u{1f424}', "baby chick"),
+    ('\u{1f425}', "front-facing baby chick"),
+    ('\u{1f426}', "bird"),
+    ('\u{1f427}', "penguin"),
+    ('\u{1f428}', "koala"),
+    ('\u{1f429}', "poodle"),
+    ('\u{1f42a}', "dromedary camel"),
+    ('\u{1f42b}', "bactrian camel"),
+    ('\u{1f42c}', "dolphin"),
+    ('\u{1f42d}', "mouse face"),
+    ('\u{1f42e}', "cow face"),
+    ('\u{1f42f}', "tiger face"),
+    ('\u{1f430}', "rabbit face"),
+    ('\u{1f431}', "cat face"),
+    ('\u{1f432}', "dragon face"),
+    ('\u{1f433}', "spouting whale"),
+    ('\u{1f434}', "horse face"),
+    ('\u{1f435}', "monkey face"),
+    ('\u{1f436}', "dog face"),
+    ('\u{1f437}', "pig face"),
+    ('\u{1f438}', "frog face"),
+    ('\u{1f439}', "hamster face"),
+    ('\u{1f43a}', "wolf face"),
+    ('\u{1f43b}', "bear face"),
+    ('\u{1f43c}', "panda face"),
+    ('\u{1f43d}', "pig nose"),
+    ('\u{1f43e}', "paw prints"),
+    ('\u{1f43f}', "chipmunk"),
+    ('\u{1f440}', "eyes"),
+    ('\u{1f441}', "eye"),
+    ('\u{1f442}', "ear"),
+    ('\u{1f443}', "nose"),
+    ('\u{1f444}', "mouth"),
+    ('\u{1f445}', "tongue"),
+    ('\u{1f446}', "white up pointing backhand index"),
+    ('\u{1f447}', "white down pointing backhand index"),
+    ('\u{1f448}', "white left pointing backhand index"),
+    ('\u{1f449}', "white right pointing backhand index"),
+    ('\u{1f44a}', "fisted hand sign"),
+    ('\u{1f44b}', "waving hand sign"),
+    ('\u{1f44c}', "ok hand sign"),
+    ('\u{1f44d}', "thumbs up sign"),
+    ('\u{1f44e}', "thumbs down sign"),
+    ('\u{1f44f}', "clapping hands sign"),
+    ('\u{1f450}', "open hands sign"),
+    ('\u{1f451}', "crown"),
+    ('\u{1f452}', "womans hat"),
+    ('\u{1f453}', "eyeglasses"),
+    ('\u{1f454}', "necktie"),
+    ('\u{1f455}', "t-shirt"),
+    ('\u{1f456}', "jeans"),
+    ('\u{1f457}', "dress"),
+    ('\u{1f458}', "kimono"),
+    ('\u{1f459}', "bikini"),
+    ('\u{1f45a}', "womans clothes"),
+    ('\u{1f45b}', "purse"),
+    ('\u{1f45c}', "handbag"),
+    ('\u{1f45d}', "pouch"),
+    ('\u{1f45e}', "mans shoe"),
+    ('\u{1f45f}', "athletic shoe"),
+    ('\u{1f460}', "high-heeled shoe"),
+    ('\u{1f461}', "womans sandal"),
+    ('\u{1f462}', "womans boots"),
+    ('\u{1f463}', "footprints"),
+    ('\u{1f464}', "bust in silhouette"),
+    ('\u{1f465}', "busts in silhouette"),
+    ('\u{1f466}', "boy"),
+    ('\u{1f467}', "girl"),
+    ('\u{1f468}', "man"),
+    ('\u{1f469}', "woman"),
+    ('\u{1f46a}', "family"),
+    ('\u{1f46b}', "man and woman holding hands"),
+    ('\u{1f46c}', "two men holding hands"),
+    ('\u{1f46d}', "two women holding hands"),
+    ('\u{1f46e}', "police officer"),
+    ('\u{1f46f}', "woman with bunny ears"),
+    ('\u{1f470}', "bride with veil"),
+    ('\u{1f471}', "person with blond hair"),
+    ('\u{1f472}', "man with gua pi mao"),
+    ('\u{1f473}', "man with turban"),
+    ('\u{1f474}', "older man"),
+    ('\u{1f475}', "older woman"),
+    ('\u{1f476}', "baby"),
+    ('\u{1f477}', "construction worker"),
+    ('\u{1f478}', "princess"),
+    ('\u{1f479}', "japanese ogre"),
+    ('\u{1f47a}', "japanese goblin"),
+    ('\u{1f47b}', "ghost"),
+    ('\u{1f47c}', "baby angel"),
+    ('\u{1f47d}', "extraterrestrial alien"),
+    ('\u{1f47e}', "alien monster"),
+    ('\u{1f47f}', "imp"),
+    ('\u{1f480}', "skull"),
+    ('\u{1f481}', "information desk person"),
+    ('\u{1f482}', "guardsman"),
+    ('\u{1f483}', "dancer"),
+    ('\u{1f484}', "lipstick"),
+    ('\u{1f485}', "nail polish"),
+    ('\u{1f486}', "face massage"),
+    ('\u{1f487}', "haircut"),
+    ('\u{1f488}', "barber pole"),
+    ('\u{1f489}', "syringe"),
+    ('\u{1f48a}', "pill"),
+    ('\u{1f48b}', "kiss mark"),
+    ('\u{1f48c}', "love letter"),
+    ('\u{1f48d}', "ring"),
+    ('\u{1f48e}', "gem stone"),
+    ('\u{1f48f}', "kiss"),
+    ('\u{1f490}', "bouquet"),
+    ('\u{1f491}', "couple with heart"),
+    ('\u{1f492}', "wedding"),
+    ('\u{1f493}', "beating heart"),
+    ('\u{1f494}', "broken heart"),
+    ('\u{1f495}', "two hearts"),
+    ('\u{1f496}', "sparkling heart"),
+    ('\u{1f497}', "growing heart"),
+    ('\u{1f498}', "heart with arrow"),
+    ('\u{1f499}', "blue heart"),
+    ('\u{1f49a}', "green heart"),
+    ('\u{1f49b}', "yellow heart"),
+    ('\u{1f49c}', "purple heart"),
+    ('\u{1f49d}', "heart with ribbon"),
+    ('\u{1f49e}', "revolving hearts"),
+    ('\u{1f49f}', "heart decoration"),
+    ('\u{1f4a0}', "diamond shape with a dot inside"),
+    ('\u{1f4a1}', "electric light bulb"),
+    ('\u{1f4a2}', "anger symbol"),
+    ('\u{1f4a3}', "bomb"),
+    ('\u{1f4a4}', "sleeping symbol"),
+    ('\u{1f4a5}', "collision symbol"),
+    ('\u{1f4a6}', "splashing sweat symbol"),
+    ('\u{1f4a7}', "droplet"),
+    ('\u{1f4a8}', "dash symbol"),
+    ('\u{1f4a9}', "pile of poo"),
+    ('\u{1f4aa}', "flexed biceps"),
+    ('\u{1f4ab}', "dizzy symbol"),
+    ('\u{1f4ac}', "speech balloon"),
+    ('\u{1f4ad}', "thought balloon"),
+    ('\u{1f4ae}', "white flower"),
+    ('\u{1f4af}', "hundred points symbol"),
+    ('\u{1f4b0}', "money bag"),
+    ('\u{1f4b1}', "currency exchange"),
+    ('\u{1f4b2}', "heavy dollar sign"),
+    ('\u{1f4b3}', "credit card"),
+    ('\u{1f4b4}', "banknote with yen sign"),
+    ('\u{1f4b5}', "banknote with dollar sign"),
+    ('\u{1f4b6}', "banknote with euro sign"),
+    ('\u{1f4b7}', "banknote with pound sign"),
+    ('\u{1f4b8}', "money with wings"),
+    ('\u{1f4b9}', "chart with upwards trend and yen sign"),
+    ('\u{1f4ba}', "seat"),
+    ('\u{1f4bb}', "personal computer"),
+    ('\u{1f4bc}', "briefcase"),
+    ('\u{1f4bd}', "minidisc"),
+    ('\u{1f4be}', "floppy disk"),
+    ('\u{1f4bf}', "optical disc"),
+    ('\u{1f4c0}', "dvd"),
+    ('\u{1f4c1}', "file folder"),
+    ('\u{1f4c2}', "open file folder"),
+    ('\u{1f4c3}', "page with curl"),
+    ('\u{1f4c4}', "page facing up"),
+    ('\u{1f4c5}', "calendar"),
+    ('\u{1f4c6}', "tear-off calendar"),
+    ('\u{1f4c7}', "card index"),
+    ('\u{1f4c8}', "chart with upwards trend"),
+    ('\u{1f4c9}', "chart with downwards trend"),
+    ('\u{1f4ca}', "bar chart"),
+    ('\u{1f4cb}', "clipboard"),
+    ('\u{1f4cc}', "pushpin"),
+    ('\u{1f4cd}', "round pushpin"),
+    ('\u{1f4ce}', "paperclip"),
+    ('\u{1f4cf}', "straight ruler"),
+    ('\u{1f4d0}', "triangular ruler"),
+    ('\u{1f4d1}', "bookmark tabs"),
+    ('\u{1f4d2}', "ledger"),
+    ('\u{1f4d3}', "notebook"),
+    ('\u{1f4d4}', "notebook with decorative cover"),
+    ('\u{1f4d5}', "closed book"),
+    ('\u{1f4d6}', "open book"),
+    ('\u{1f4d7}', "green book"),
+    ('\u{1f4d8}', "blue book"),
+    ('\u{1f4d9}', "orange book"),
+    ('\u{1f4da}', "books"),
+    ('\u{1f4db}', "name badge"),
+    ('\u{1f4dc}', "scroll"),
+    ('\u{1f4dd}', "memo"),
+    ('\u{1f4de}', "telephone receiver"),
+    ('\u{1f4df}', "pager"),
+    ('\u{1f4e0}', "fax machine"),
+    ('\u{1f4e1}', "satellite antenna"),
+    ('\u{1f4e2}', "public address loudspeaker"),
+    ('\u{1f4e3}', "cheering megaphone"),
+    ('\u{1f4e4}', "outbox tray"),
+    ('\u{1f4e5}', "inbox tray"),
+    ('\u{1f4e6}', "package"),
+    ('\u{1f4e7}', "e-mail symbol"),
+    ('\u{1f4e8}', "incoming envelope"),
+    ('\u{1f4e9}', "envelope with downwards arrow above"),
+    ('\u{1f4ea}', "closed mailbox with lowered flag"),
+    ('\u{1f4eb}', "closed mailbox with raised flag"),
+    ('\u{1f4ec}', "open mailbox with raised flag"),
+    ('\u{1f4ed}', "open mailbox with lowered flag"),
+    ('\u{1f4ee}', "postbox"),
+    ('\u{1f4ef}', "postal horn"),
+    ('\u{1f4f0}', "newspaper"),
+    ('\u{1f4f1}', "mobile phone"),
+    ('\u{1f4f2}', "mobile phone with rightwards arrow at left"),
+    ('\u{1f4f3}', "vibration mode"),
+    ('\u{1f4f4}', "mobile phone off"),
+    ('\u{1f4f5}', "no mobile phones"),
+    ('\u{1f4f6}', "antenna with bars"),
+    ('\u{1f4f7}', "camera"),
+    ('\u{1f4f8}', "camera with flash"),
+    ('\u{1f4f9}', "video camera"),
+    ('\u{1f4fa}', "television"),
+    ('\u{1f4fb}', "radio"),
+    ('\u{1f4fc}', "videocassette"),
+    ('\u{1f4fd}', "film projector"),
+    ('\u{1f4fe}', "portable stereo"),
+    ('\u{1f4ff}', "prayer beads"),
+    ('\u{1f500}', "twisted rightwards arrows"),
+    (
+        '\u{1f501}',
+        "clockwise rightwards and leftwards open circle arrows",
+    ),
+    (
+        '\u{1f502}',
+        "clockwise rightwards and leftwards open circle arrows with circled one overlay",
+    ),
+    (
+        '\u{1f503}',
+        "clockwise downwards and upwards open circle arrows",
+    ),
+    (
+        '\u{1f504}',
+        "anticlockwise downwards and upwards open circle arrows",
+    ),
+    ('\u{1f505}', "low brightness symbol"),
+    ('\u{1f506}', "high brightness symbol"),
+    ('\u{1f507}', "speaker with cancellation stroke"),
+    ('\u{1f508}', "speaker"),
+    ('\u{1f509}', "speaker with one sound wave"),
+    ('\u{1f50a}', "speaker with three sound waves"),
+    ('\u{1f50b}', "battery"),
+    ('\u{1f50c}', "electric plug"),
+    ('\u{1f50d}', "left-pointing magnifying glass"),
+    ('\u{1f50e}', "right-pointing magnifying glass"),
+    ('\u{1f50f}', "lock with ink pen"),
+    ('\u{1f510}', "closed lock with key"),
+    ('\u{1f511}', "key"),
+    ('\u{1f512}', "lock"),
+    ('\u{1f513}', "open lock"),
+    ('\u{1f514}', "bell"),
+    ('\u{1f515}', "bell with cancellation stroke"),
+    ('\u{1f516}', "bookmark"),
+    ('\u{1f517}', "link symbol"),
+    ('\u{1f518}', "radio button"),
+    ('\u{1f519}', "back with leftwards arrow above"),
+    ('\u{1f51a}', "end with leftwards arrow above"),
+    (
+        '\u{1f51b}',
+        "on with exclamation mark with left right arrow above",
+    ),
+    ('\u{1f51c}', "soon with rightwards arrow above"),
+    ('\u{1f51d}', "top with upwards arrow above"),
+    ('\u{1f51e}', "no one under eighteen symbol"),
+    ('\u{1f51f}', "keycap ten"),
+    ('\u{1f520}', "input symbol for latin capital letters"),
+    ('\u{1f521}', "input symbol for latin small letters"),
+    ('\u{1f522}', "input symbol for numbers"),
+    ('\u{1f523}', "input symbol for symbols"),
+    ('\u{1f524}', "input symbol for latin letters"),
+    ('\u{1f525}', "fire"),
+    ('\u{1f526}', "electric torch"),
+    ('\u{1f527}', "wrench"),
+    ('\u{1f528}', "hammer"),
+    ('\u{1f529}', "nut and bolt"),
+    ('\u{1f52a}', "hocho"),
+    ('\u{1f52b}', "pistol"),
+    ('\u{1f52c}', "microscope"),
+    ('\u{1f52d}', "telescope"),
+    ('\u{1f52e}', "crystal ball"),
+    ('\u{1f52f}', "six pointed star with middle dot"),
+    ('\u{1f530}', "japanese symbol for beginner"),
+    ('\u{1f531}', "trident emblem"),
+    ('\u{1f532}', "black square button"),
+    ('\u{1f533}', "white square button"),
+    ('\u{1f534}', "large red circle"),
+    ('\u{1f535}', "large blue circle"),
+    ('\u{1f536}', "large orange diamond"),
+    ('\u{1f537}', "large blue diamond"),
+    ('\u{1f538}', "small orange diamond"),
+    ('\u{1f539}', "small blue diamond"),
+    ('\u{1f53a}', "up-pointing red triangle"),
+    ('\u{1f53b}', "down-pointing red triangle"),
+    ('\u{1f53c}', "up-pointing small red triangle"),
+    ('\u{1f53d}', "down-pointing small red triangle"),
+    ('\u{1f53e}', "lower right shadowed white circle"),
+    ('\u{1f53f}', "upper right shadowed white circle"),
+    ('\u{1f540}', "circled cross pommee"),
+    ('\u{1f541}', "cross pommee with half-circle below"),
+    ('\u{1f542}', "cross pommee"),
+    ('\u{1f543}', "notched left semicircle with three dots"),
+    ('\u{1f544}', "notched right semicircle with three dots"),
+    ('\u{1f545}', "symbol for marks chapter"),
+    ('\u{1f546}', "white latin cross"),
+    ('\u{1f547}', "heavy latin cross"),
+    ('\u{1f548}', "celtic cross"),
+    ('\u{1f549}', "om symbol"),
+    ('\u{1f54a}', "dove of peace"),
+    ('\u{1f54b}', "kaaba"),
+    ('\u{1f54c}', "mosque"),
+    ('\u{1f54d}', "synagogue"),
+    ('\u{1f54e}', "menorah with nine branches"),
+    ('\u{1f54f}', "bowl of hygieia"),
+    ('\u{1f550}', "clock face one oclock"),
+    ('\u{1f551}', "clock face two oclock"),
+    ('\u{1f552}', "clock face three oclock"),
+    ('\u{1f553}', "clock face four oclock"),
+    ('\u{1f554}', "clock face five oclock"),
+    ('\u{1f555}', "clock face six oclock"),
+    ('\u{1f556}', "clock face seven oclock"),
+    ('\u{1f557}', "clock face eight oclock"),
+    ('\u{1f558}', "clock face nine oclock"),
+    ('\u{1f559}', "clock face ten oclock"),
+    ('\u{1f55a}', "clock face eleven oclock"),
+    ('\u{1f55b}', "clock face twelve oclock"),
+    ('\u{1f55c}', "clock face one-thirty"),
+    ('\u{1f55d}', "clock face two-thirty"),
+    ('\u{1f55e}', "clock face three-thirty"),
+    ('\u{1f55f}', "clock face four-thirty"),
+    ('\u{1f560}', "clock face five-thirty"),
+    ('\u{1f561}', "clock face six-thirty"),
+    ('\u{1f562}', "clock face seven-thirty"),
+    ('\u{1f563}', "clock face eight-thirty"),
+    ('\u{1f564}', "clock face nine-thirty"),
+    ('\u{1f565}', "clock face ten-thirty"),
+    ('\u{1f566}', "clock face eleven-thirty"),
+    ('\u{1f567}', "clock face twelve-thirty"),
+    ('\u{1f568}', "right speaker"),
+    ('\u{1f569}', "right speaker with one sound wave"),
+    ('\u{1f56a}', "right speaker with three sound waves"),
+    ('\u{1f56b}', "bullhorn"),
+    ('\u{1f56c}', "bullhorn with sound waves"),
+    ('\u{1f56d}', "ringing bell"),
+    ('\u{1f56e}', "book"),
+    ('\u{1f56f}', "candle"),
+    ('\u{1f570}', "mantelpiece clock"),
+    ('\u{1f571}', "black skull and crossbones"),
+    ('\u{1f572}', "no piracy"),
+    ('\u{1f573}', "hole"),
+    ('\u{1f574}', "man in business suit levitating"),
+    ('\u{1f575}', "sleuth or spy"),
+    ('\u{1f576}', "dark sunglasses"),
+    ('\u{1f577}', "spider"),
+    ('\u{1f578}', "spider web"),
+    ('\u{1f579}', "joystick"),
+    ('\u{1f57a}', "man dancing"),
+    ('\u{1f57b}', "left hand telephone receiver"),
+    ('\u{1f57c}', "telephone receiver with page"),
+    ('\u{1f57d}', "right hand telephone receiver"),
+    ('\u{1f57e}', "white touchtone telephone"),
+    ('\u{1f57f}', "black touchtone telephone"),
+    ('\u{1f580}', "telephone on top of modem"),
+    ('\u{1f581}', "clamshell mobile phone"),
+    ('\u{1f582}', "back of envelope"),
+    ('\u{1f583}', "stamped envelope"),
+    ('\u{1f584}', "envelope with lightning"),
+    ('\u{1f585}', "flying envelope"),
+    ('\u{1f586}', "pen over stamped envelope"),
+    ('\u{1f587}', "linked paperclips"),
+    ('\u{1f588}', "black pushpin"),
+    ('\u{1f589}', "lower left pencil"),
+    ('\u{1f58a}', "lower left ballpoint pen"),
+    ('\u{1f58b}', "lower left fountain pen"),
+    ('\u{1f58c}', "lower left paintbrush"),
+    ('\u{1f58d}', "lower left crayon"),
+    ('\u{1f58e}', "left writing hand"),
+    ('\u{1f58f}', "turned ok hand sign"),
+    ('\u{1f590}', "raised hand with fingers splayed"),
+    ('\u{1f591}', "reversed raised hand with fingers splayed"),
+    ('\u{1f592}', "reversed thumbs up sign"),
+    ('\u{1f593}', "reversed thumbs down sign"),
+    ('\u{1f594}', "reversed victory hand"),
+    ('\u{1f595}', "reversed hand with middle finger extended"),
+    (
+        '\u{1f596}',
+        "raised hand with part between middle and ring fingers",
+    ),
+    ('\u{1f597}', "white down pointing left hand index"),
+    ('\u{1f598}', "sideways white left pointing index"),
+    ('\u{1f599}', "sideways white right pointing index"),
+    ('\u{1f59a}', "sideways black left pointing index"),
+    ('\u{1f59b}', "sideways black right pointing index"),
+    ('\u{1f59c}', "black left pointing backhand index"),
+    ('\u{1f59d}', "black right pointing backhand index"),
+    ('\u{1f59e}', "sideways white up pointing index"),
+    ('\u{1f59f}', "sideways white down pointing index"),
+    ('\u{1f5a0}', "sideways black up pointing index"),
+    ('\u{1f5a1}', "sideways black down pointing index"),
+    ('\u{1f5a2}', "black up pointing backhand index"),
+    ('\u{1f5a3}', "black down pointing backhand index"),
+    ('\u{1f5a4}', "black heart"),
+    ('\u{1f5a5}', "desktop computer"),
+    ('\u{1f5a6}', "keyboard and mouse"),
+    ('\u{1f5a7}', "three networked computers"),
+    ('\u{1f5a8}', "printer"),
+    ('\u{1f5a9}', "pocket calculator"),
+    ('\u{1f5aa}', "black hard shell floppy disk"),
+    ('\u{1f5ab}', "white hard shell floppy disk"),
+    ('\u{1f5ac}', "soft shell floppy disk"),
+    ('\u{1f5ad}', "tape cartridge"),
+    ('\u{1f5ae}', "wired keyboard"),
+    ('\u{1f5af}', "one button mouse"),
+    ('\u{1f5b0}', "two button mouse"),
+    ('\u{1f5b1}', "three button mouse"),
+    ('\u{1f5b2}', "trackball"),
+    ('\u{1f5b3}', "old personal computer"),
+    ('\u{1f5b4}', "hard disk"),
+    ('\u{1f5b5}', "screen"),
+    ('\u{1f5b6}', "printer icon"),
+    ('\u{1f5b7}', "fax icon"),
+    ('\u{1f5b8}', "optical disc icon"),
+    ('\u{1f5b9}', "document with text"),
+    ('\u{1f5ba}', "document with text and picture"),
+    ('\u{1f5bb}', "document with picture"),
+    ('\u{1f5bc}', "frame with picture"),
+    ('\u{1f5bd}', "frame with tiles"),
+    ('\u{1f5be}', "frame with an x"),
+    ('\u{1f5bf}', "black folder"),
+    ('\u{1f5c0}', "folder"),
+    ('\u{1f5c1}', "open folder"),
+    ('\u{1f5c2}', "card index dividers"),
+    ('\u{1f5c3}', "card file box"),
+    ('\u{1f5c4}', "file cabinet"),
+    ('\u{1f5c5}', "empty note"),
+    ('\u{1f5c6}', "empty note page"),
+    ('\u{1f5c7}', "empty note pad"),
+    ('\u{1f5c8}', "note"),
+    ('\u{1f5c9}', "note page"),
+    ('\u{1f5ca}', "note pad"),
+    ('\u{1f5cb}', "empty document"),
+    ('\u{1f5cc}', "empty page"),
+    ('\u{1f5cd}', "empty pages"),
+    ('\u{1f5ce}', "document"),
+    ('\u{1f5cf}', "page"),
+    ('\u{1f5d0}', "pages"),
+    ('\u{1f5d1}', "wastebasket"),
+    ('\u{1f5d2}', "spiral note pad"),
+    ('\u{1f5d3}', "spiral calendar pad"),
+    ('\u{1f5d4}', "desktop window"),
+    ('\u{1f5d5}', "minimize"),
+    ('\u{1f5d6}', "maximize"),
+    ('\u{1f5d7}', "overlap"),
+    ('\u{1f5d8}', "clockwise right and left semicircle arrows"),
+    ('\u{1f5d9}', "cancellation x"),
+    ('\u{1f5da}', "increase font size symbol"),
+    ('\u{1f5db}', "decrease font size symbol"),
+    ('\u{1f5dc}', "compression"),
+    ('\u{1f5dd}', "old key"),
+    ('\u{1f5de}', "rolled-up newspaper"),
+    ('\u{1f5df}', "page with circled text"),
+    ('\u{1f5e0}', "stock chart"),
+    ('\u{1f5e1}', "dagger knife"),
+    ('\u{1f5e2}', "lips"),
+    ('\u{1f5e3}', "speaking head in silhouette"),
+    ('\u{1f5e4}', "three rays above"),
+    ('\u{1f5e5}', "three rays below"),
+    ('\u{1f5e6}', "three rays left"),
+    ('\u{1f5e7}', "three rays right"),
+    ('\u{1f5e8}', "left speech bubble"),
+    ('\u{1f5e9}', "right speech bubble"),
+    ('\u{1f5ea}', "two speech bubbles"),
+    ('\u{1f5eb}', "three speech bubbles"),
+    ('\u{1f5ec}', "left thought bubble"),
+    ('\u{1f5ed}', "right thought bubble"),
+    ('\u{1f5ee}', "left anger bubble"),
+    ('\u{1f5ef}', "right anger bubble"),
+    ('\u{1f5f0}', "mood bubble"),
+    ('\u{1f5f1}', "lightning mood bubble"),
+    ('\u{1f5f2}', "lightning mood"),
+    ('\u{1f5f3}', "ballot box with ballot"),
+    ('\u{1f5f4}', "ballot script x"),
+    ('\u{1f5f5}', "ballot box with script x"),
+    ('\u{1f5f6}', "ballot bold script x"),
+    ('\u{1f5f7}', "ballot box with bold script x"),
+    ('\u{1f5f8}', "light check mark"),
+    ('\u{1f5f9}', "ballot box with bold check"),
+    ('\u{1f5fa}', "world map"),
+    ('\u{1f5fb}', "mount fuji"),
+    ('\u{1f5fc}', "tokyo tower"),
+    ('\u{1f5fd}', "statue of liberty"),
+    ('\u{1f5fe}', "silhouette of japan"),
+    ('\u{1f5ff}', "moyai"),
+    // Emoji: emoticons
+    ('\u{1f600}', "grinning face"),
+    ('\u{1f601}', "grinning face with smiling eyes"),
+    ('\u{1f602}', "face with tears of joy"),
+    ('\u{1f603}', "smiling face with open mouth"),
+    ('\u{1f604}', "smiling face with open mouth and smiling eyes"),
+    ('\u{1f605}', "smiling face with open mouth and cold sweat"),
+    (
+        '\u{1f606}',
+        "smiling face with open mouth and tightly-closed eyes",
+    ),
+    ('\u{1f607}', "smiling face with halo"),
+    ('\u{1f608}', "smiling face with horns"),
+    ('\u{1f609}', "winking face"),
+    ('\u{1f60a}', "smiling face with smiling eyes"),
+    ('\u{1f60b}', "face savouring delicious food"),
+    ('\u{1f60c}', "relieved face"),
+    ('\u{1f60d}', "smiling face with heart-shaped eyes"),
+    ('\u{1f60e}', "smiling face with sunglasses"),
+    ('\u{1f60f}', "smirking face"),
+    ('\u{1f610}', "neutral face"),
+    ('\u{1f611}', "expressionless face"),
+    ('\u{1f612}', "unamused face"),
+    ('\u{1f613}', "face with cold sweat"),
+    ('\u{1f614}', "pensive face"),
+    ('\u{1f615}', "confused face"),
+    ('\u{1f616}', "confounded face"),
+    ('\u{1f617}', "kissing face"),
+    ('\u{1f618}', "face throwing a kiss"),
+    ('\u{1f619}', "kissing face with smiling eyes"),
+    ('\u{1f61a}', "kissing face with closed eyes"),
+    ('\u{1f61b}', "face with stuck-out tongue"),
+    ('\u{1f61c}', "face with stuck-out tongue and winking eye"),
+    (
+        '\u{1f61d}',
+        "face with stuck-out tongue and tightly-closed eyes",
+    ),
+    ('\u{1f61e}', "disappointed face"),
+    ('\u{1f61f}', "worried face"),
+    ('\u{1f620}', "angry face"),
+    ('\u{1f621}', "pouting face"),
+    ('\u{1f622}', "crying face"),
+    ('\u{1f623}', "persevering face"),
+    ('\u{1f624}', "face with look of triumph"),
+    ('\u{1f625}', "disappointed but relieved face"),
+    ('\u{1f626}', "frowning face with open mouth"),
+    ('\u{1f627}', "anguished face"),
+    ('\u{1f628}', "fearful face"),
+    ('\u{1f629}', "weary face"),
+    ('\u{1f62a}', "sleepy face"),
+    ('\u{1f62b}', "tired face"),
+    ('\u{1f62c}', "grimacing face"),
+    ('\u{1f62d}', "loudly crying face"),
+    ('\u{1f62e}', "face with open mouth"),
+    ('\u{1f62f}', "hushed face"),
+    ('\u{1f630}', "face with open mouth and cold sweat"),
+    ('\u{1f631}', "face screaming in fear"),
+    ('\u{1f632}', "astonished face"),
+    ('\u{1f633}', "flushed face"),
+    ('\u{1f634}', "sleeping face"),
+    ('\u{1f635}', "dizzy face"),
+    ('\u{1f636}', "face without mouth"),
+    ('\u{1f637}', "face with medical mask"),
+    ('\u{1f638}', "grinning cat face with smiling eyes"),
+    ('\u{1f639}', "cat face with tears of joy"),
+    ('\u{1f63a}', "smiling cat face with open mouth"),
+    ('\u{1f63b}', "smiling cat face with heart-shaped eyes"),
+    ('\u{1f63c}', "cat face with wry smile"),
+    ('\u{1f63d}', "kissing cat face with closed eyes"),
+    ('\u{1f63e}', "pouting cat face"),
+    ('\u{1f63f}', "crying cat face"),
+    ('\u{1f640}', "weary cat face"),
+    ('\u{1f641}', "slightly frowning face"),
+    ('\u{1f642}', "slightly smiling face"),
+    ('\u{1f643}', "upside-down face"),
+    ('\u{1f644}', "face with rolling eyes"),
+    ('\u{1f645}', "face with no good gesture"),
+    ('\u{1f646}', "face with ok gesture"),
+    ('\u{1f647}', "person bowing deeply"),
+    ('\u{1f648}', "see-no-evil monkey"),
+    ('\u{1f649}', "hear-no-evil monkey"),
+    ('\u{1f64a}', "speak-no-evil monkey"),
+    ('\u{1f64b}', "happy person raising one hand"),
+    ('\u{1f64c}', "person raising both hands in celebration"),
+    ('\u{1f64d}', "person frowning"),
+    ('\u{1f64e}', "person with pouting face"),
+    ('\u{1f64f}', "person with folded hands"),
+    // Emoji: transport and map symbols
+    ('\u{1f680}', "rocket"),
+    ('\u{1f681}', "helicopter"),
+    ('\u{1f682}', "steam locomotive"),
+    ('\u{1f683}', "railway car"),
+    ('\u{1f684}', "high-speed train"),
+    ('\u{1f685}', "high-speed train with bullet nose"),
+    ('\u{1f686}', "train"),
+    ('\u{1f687}', "metro"),
+    ('\u{1f688}', "light rail"),
+    ('\u{1f689}', "station"),
+    ('\u{1f68a}', "tram"),
+    ('\u{1f68b}', "tram car"),
+    ('\u{1f68c}', "bus"),
+    ('\u{1f68d}', "oncoming bus"),
+    ('\u{1f68e}', "trolleybus"),
+    ('\u{1f68f}', "bus stop"),
+    ('\u{1f690}', "minibus"),
+    ('\u{1f691}', "ambulance"),
+    ('\u{1f692}', "fire engine"),
+    ('\u{1f693}', "police car"),
+    ('\u{1f694}', "oncoming police car"),
+    ('\u{1f695}', "taxi"),
+    ('\u{1f696}', "oncoming taxi"),
+    ('\u{1f697}', "automobile"),
+    ('\u{1f698}', "oncoming automobile"),
+    ('\u{1f699}', "recreational vehicle"),
+    ('\u{1f69a}', "delivery truck"),
+    ('\u{1f69b}', "articulated lorry"),
+    ('\u{1f69c}', "tractor"),
+    ('\u{1f69d}', "monorail"),
+    ('\u{1f69e}', "mountain railway"),
+    ('\u{1f69f}', "suspension railway"),
+    ('\u{1f6a0}', "mountain cableway"),
+    ('\u{1f6a1}', "aerial tramway"),
+    ('\u{1f6a2}', "ship"),
+    ('\u{1f6a3}', "rowboat"),
+    ('\u{1f6a4}', "speedboat"),
+    ('\u{1f6a5}', "horizontal traffic light"),
+    ('\u{1f6a6}', "vertical traffic light"),
+    ('\u{1f6a7}', "construction sign"),
+    ('\u{1f6a8}', "police cars revolving light"),
+    ('\u{1f6a9}', "triangular flag on post"),
+    ('\u{1f6aa}', "door"),
+    ('\u{1f6ab}', "no entry sign"),
+    ('\u{1f6ac}', "smoking symbol"),
+    ('\u{1f6ad}', "no smoking symbol"),
+    ('\u{1f6ae}', "put litter in its place symbol"),
+    ('\u{1f6af}', "do not litter symbol"),
+    ('\u{1f6b0}', "potable water symbol"),
+    ('\u{1f6b1}', "non-potable water symbol"),
+    ('\u{1f6b2}', "bicycle"),
+    ('\u{1f6b3}', "no bicycles"),
+    ('\u{1f6b4}', "bicyclist"),
+    ('\u{1f6b5}', "mountain bicyclist"),
+    ('\u{1f6b6}', "pedestrian"),
+    ('\u{1f6b7}', "no pedestrians"),
+    ('\u{1f6b8}', "children crossing"),
+    ('\u{1f6b9}', "mens symbol"),
+    ('\u{1f6ba}', "womens symbol"),
+    ('\u{1f6bb}', "restroom"),
+    ('\u{1f6bc}', "baby symbol"),
+    ('\u{1f6bd}', "toilet"),
+    ('\u{1f6be}', "water closet"),
+    ('\u{1f6bf}', "shower"),
+    ('\u{1f6c0}', "bath"),
+    ('\u{1f6c1}', "bathtub"),
+    ('\u{1f6c2}', "passport control"),
+    ('\u{1f6c3}', "customs"),
+    ('\u{1f6c4}', "baggage claim"),
+    ('\u{1f6c5}', "left luggage"),
+    ('\u{1f6c6}', "triangle with rounded corners"),
+    ('\u{1f6c7}', "prohibited sign"),
+    ('\u{1f6c8}', "circled information source"),
+    ('\u{1f6c9}', "boys symbol"),
+    ('\u{1f6ca}', "girls symbol"),
+    ('\u{1f6cb}', "couch and lamp"),
+    ('\u{1f6cc}', "sleeping accommodation"),
+    ('\u{1f6cd}', "shopping bags"),
+    ('\u{1f6ce}', "bellhop bell"),
+    ('\u{1f6cf}', "bed"),
+    ('\u{1f6d0}', "place of worship"),
+    ('\u{1f6d1}', "octagonal sign"),
+    ('\u{1f6d2}', "shopping trolley"),
+    ('\u{1f6d3}', "stupa"),
+    ('\u{1f6d4}', "pagoda"),
+    ('\u{1f6d5}', "hindu temple"),
+    ('\u{1f6d6}', "hut"),
+    ('\u{1f6d7}', "elevator"),
+    ('\u{1f6dd}', "playground slide"),
+    ('\u{1f6de}', "wheel"),
+    ('\u{1f6df}', "ring buoy"),
+    ('\u{1f6e0}', "hammer and wrench"),
+    ('\u{1f6e1}', "shield"),
+    ('\u{1f6e2}', "oil drum"),
+    ('\u{1f6e3}', "motorway"),
+    ('\u{1f6e4}', "railway track"),
+    ('\u{1f6e5}', "motor boat"),
+    ('\u{1f6e6}', "up-pointing military airplane"),
+    ('\u{1f6e7}', "up-pointing airplane"),
+    ('\u{1f6e8}', "up-pointing small airplane"),
+    ('\u{1f6e9}', "small airplane"),
+    ('\u{1f6ea}', "northeast-pointing airplane"),
+    ('\u{1f6eb}', "airplane departure"),
+    ('\u{1f6ec}', "airplane arriving"),
+    ('\u{1f6f0}', "satellite"),
+    ('\u{1f6f1}', "oncoming fire engine"),
+    ('\u{1f6f2}', "diesel locomotive"),
+    ('\u{1f6f3}', "passenger ship"),
+    ('\u{1f6f4}', "scooter"),
+    ('\u{1f6f5}', "motor scooter"),
+    ('\u{1f6f6}', "canoe"),
+    ('\u{1f6f7}', "sled"),
+    ('\u{1f6f8}', "flying saucer"),
+    ('\u{1f6f9}', "skateboard"),
+    ('\u{1f6fa}', "auto rickshaw"),
+    ('\u{1f6fb}', "pickup truck"),
+    ('\u{1f6fc}', "roller skate"),
+    // Emoji: supplemental symbols and pictographs
+    ('\u{1f900}', "circled cross formee with four dots"),
+    ('\u{1f901}', "circled cross formee with two dots"),
+    ('\u{1f902}', "circled cross formee"),
+    ('\u{1f903}', "left half circle with four dots"),
+    ('\u{1f904}', "left half circle with three dots"),
+    ('\u{1f905}', "left half circle with two dots"),
+    ('\u{1f906}', "left half circle with dot"),
+    ('\u{1f907}', "left half circle"),
+    ('\u{1f908}', "downward facing hook"),
+    ('\u{1f909}', "downward facing notched hook"),
+    ('\u{1f90a}', "downward facing hook with dot"),
+    ('\u{1f90b}', "downward facing notched hook with dot"),
+    ('\u{1f90c}', "pinched fingers"),
+    ('\u{1f90d}', "white heart"),
+    ('\u{1f90e}', "brown heart"),
+    ('\u{1f90f}', "pinching hand"),
+    ('\u{1f910}', "zipper-mouth face"),
+    ('\u{1f911}', "money-mouth face"),
+    ('\u{1f912}', "face with thermometer"),
+    ('\u{1f913}', "nerd face"),
+    ('\u{1f914}', "thinking face"),
+    ('\u{1f915}', "face with head-bandage"),
+    ('\u{1f916}', "robot face"),
+    ('\u{1f917}', "hugging face"),
+    ('\u{1f918}', "sign of the horns"),
+    ('\u{1f919}', "call me hand"),
+    ('\u{1f91a}', "raised back of hand"),
+    ('\u{1f91b}', "left-facing fist"),
+    ('\u{1f91c}', "right-facing fist"),
+    ('\u{1f91d}', "handshake"),
+    ('\u{1f91e}', "hand with index and middle fingers crossed"),
+    ('\u{1f91f}', "i love you hand sign"),
+    ('\u{1f920}', "face with cowboy hat"),
+    ('\u{1f921}', "clown face"),
+    ('\u{1f922}', "nauseated face"),
+    ('\u{1f923}', "rolling on the floor laughing"),
+    ('\u{1f924}', "drooling face"),
+    ('\u{1f925}', "lying face"),
+    ('\u{1f926}', "face palm"),
+    ('\u{1f927}', "sneezing face"),
+    ('\u{1f928}', "face with one eyebrow raised"),
+    ('\u{1f929}', "grinning face with star eyes"),
+    (
+        '\u{1f92a}',
+        "grinning face with one large and one small eye",
+    ),
+    ('\u{1f92b}', "face with finger covering closed lips"),
+    ('\u{1f92c}', "serious face with symbols covering mouth"),
+    (
+        '\u{1f92d}',
+        "smiling face with smiling eyes and hand covering mouth",
+    ),
+    ('\u{1f92e}', "face with open mouth vomiting"),
+    ('\u{1f92f}', "shocked face with exploding head"),
+    ('\u{1f930}', "pregnant woman"),
+    ('\u{1f931}', "breast-feeding"),
+    ('\u{1f932}', "palms up together"),
+    ('\u{1f933}', "selfie"),
+    ('\u{1f934}', "prince"),
+    ('\u{1f935}', "man in tuxedo"),
+    ('\u{1f936}', "mother christmas"),
+    ('\u{1f937}', "shrug"),
+    ('\u{1f938}', "person doing cartwheel"),
+    ('\u{1f939}', "juggling"),
+    ('\u{1f93a}', "fencer"),
+    ('\u{1f93b}', "modern pentathlon"),
+    ('\u{1f93c}', "wrestlers"),
+    ('\u{1f93d}', "water polo"),
+    ('\u{1f93e}', "handball"),
+    ('\u{1f93f}', "diving mask"),
+    ('\u{1f940}', "wilted flower"),
+    ('\u{1f941}', "drum with drumsticks"),
+    ('\u{1f942}', "clinking glasses"),
+    ('\u{1f943}', "tumbler glass"),
+    ('\u{1f944}', "spoon"),
+    ('\u{1f945}', "goal net"),
+    ('\u{1f946}', "rifle"),
+    ('\u{1f947}', "first place medal"),
+    ('\u{1f948}', "second place medal"),
+    ('\u{1f949}', "third place medal"),
+    ('\u{1f94a}', "boxing glove"),
+    ('\u{1f94b}', "martial arts uniform"),
+    ('\u{1f94c}', "curling stone"),
+    ('\u{1f94d}', "lacrosse stick and ball"),
+    ('\u{1f94e}', "softball"),
+    ('\u{1f94f}', "flying disc"),
+    ('\u{1f950}', "croissant"),
+    ('\u{1f951}', "avocado"),
+    ('\u{1f952}', "cucumber"),
+    ('\u{1f953}', "bacon"),
+    ('\u{1f954}', "potato"),
+    ('\u{1f955}', "carrot"),
+    ('\u{1f956}', "baguette bread"),
+    ('\u{1f957}', "green salad"),
+    ('\u{1f958}', "shallow pan of food"),
+    ('\u{1f959}', "stuffed flatbread"),
+    ('\u{1f95a}', "egg"),
+    ('\u{1f95b}', "glass of milk"),
+    ('\u{1f95c}', "peanuts"),
+    ('\u{1f95d}', "kiwifruit"),
+    ('\u{1f95e}', "pancakes"),
+    ('\u{1f95f}', "dumpling"),
+    ('\u{1f960}', "fortune cookie"),
+    ('\u{1f961}', "takeout box"),
+    ('\u{1f962}', "chopsticks"),
+    ('\u{1f963}', "bowl with spoon"),
+    ('\u{1f964}', "cup with straw"),
+    ('\u{1f965}', "coconut"),
+    ('\u{1f966}', "broccoli"),
+    ('\u{1f967}', "pie"),
+    ('\u{1f968}', "pretzel"),
+    ('\u{1f969}', "cut of meat"),
+    ('\u{1f96a}', "sandwich"),
+    ('\u{1f96b}', "canned food"),
+    ('\u{1f96c}', "leafy green"),
+    ('\u{1f96d}', "mango"),
+    ('\u{1f96e}', "moon cake"),
+    ('\u{1f96f}', "bagel"),
+    (
+        '\u{1f970}',
+        "smiling face with smiling eyes and three hearts",
+    ),
+    ('\u{1f971}', "yawning face"),
+    ('\u{1f972}', "smiling face with tear"),
+    ('\u{1f973}', "face with party horn and party hat"),
+    ('\u{1f974}', "face with uneven eyes and wavy mouth"),
+    ('\u{1f975}', "overheated face"),
+    ('\u{1f976}', "freezing face"),
+    ('\u{1f977}', "ninja"),
+    ('\u{1f978}', "disguised face"),
+    ('\u{1f979}', "face holding back tears"),
+    ('\u{1f97a}', "face with pleading eyes"),
+    ('\u{1f97b}', "sari"),
+    ('\u{1f97c}', "lab coat"),
+    ('\u{1f97d}', "goggles"),
+    ('\u{1f97e}', "hiking boot"),
+    ('\u{1f97f}', "flat shoe"),
+    ('\u{1f980}', "crab"),
+    ('\u{1f981}', "lion face"),
+    ('\u{1f982}', "scorpion"),
+    ('\u{1f983}', "turkey"),
+    ('\u{1f984}', "unicorn face"),
+    ('\u{1f985}', "eagle"),
+    ('\u{1f986}', "duck"),
+    ('\u{1f987}', "bat"),
+    ('\u{1f988}', "shark"),
+    ('\u{1f989}', "owl"),
+    ('\u{1f98a}', "fox face"),
+    ('\u{1f98b}', "butterfly"),
+    ('\u{1f98c}', "deer"),
+    ('\u{1f98d}', "gorilla"),
+    ('\u{1f98e}', "lizard"),
+    ('\u{1f98f}', "rhinoceros"),
+    ('\u{1f990}', "shrimp"),
+    ('\u{1f991}', "squid"),
+    ('\u{1f992}', "giraffe face"),
+    ('\u{1f993}', "zebra face"),
+    ('\u{1f994}', "hedgehog"),
+    ('\u{1f995}', "sauropod"),
+    ('\u{1f996}', "t-rex"),
+    ('\u{1f997}', "cricket"),
+    ('\u{1f998}', "kangaroo"),
+    ('\u{1f999}', "llama"),
+    ('\u{1f99a}', "peacock"),
+    ('\u{1f99b}', "hippopotamus"),
+    ('\u{1f99c}', "parrot"),
+    ('\u{1f99d}', "raccoon"),
+    ('\u{1f99e}', "lobster"),
+    ('\u{1f99f}', "mosquito"),
+    ('\u{1f9a0}', "microbe"),
+    ('\u{1f9a1}', "badger"),
+    ('\u{1f9a2}', "swan"),
+    ('\u{1f9a3}', "mammoth"),
+    ('\u{1f9a4}', "dodo"),
+    ('\u{1f9a5}', "sloth"),
+    ('\u{1f9a6}', "otter"),
+    ('\u{1f9a7}', "orangutan"),
+    ('\u{1f9a8}', "skunk"),
+    ('\u{1f9a9}', "flamingo"),
+    ('\u{1f9aa}', "oyster"),
+    ('\u{1f9ab}', "beaver"),
+    ('\u{1f9ac}', "bison"),
+    ('\u{1f9ad}', "seal"),
+    ('\u{1f9ae}', "guide dog"),
+    ('\u{1f9af}', "probing cane"),
+    ('\u{1f9b0}', "emoji component red hair"),
+    ('\u{1f9b1}', "emoji component curly hair"),
+    ('\u{1f9b2}', "emoji component bald"),
+    ('\u{1f9b3}', "emoji component white hair"),
+    ('\u{1f9b4}', "bone"),
+    ('\u{1f9b5}', "leg"),
+    ('\u{1f9b6}', "foot"),
+    ('\u{1f9b7}', "tooth"),
+    ('\u{1f9b8}', "superhero"),
+    ('\u{1f9b9}', "supervillain"),
+    ('\u{1f9ba}', "safety vest"),
+    ('\u{1f9bb}', "ear with hearing aid"),
+    ('\u{1f9bc}', "motorized wheelchair"),
+    ('\u{1f9bd}', "manual wheelchair"),
+    ('\u{1f9be}', "mechanical arm"),
+    ('\u{1f9bf}', "mechanical leg"),
+    ('\u{1f9c0}', "cheese wedge"),
+    ('\u{1f9c1}', "cupcake"),
+    ('\u{1f9c2}', "salt shaker"),
+    ('\u{1f9c3}', "beverage box"),
+    ('\u{1f9c4}', "garlic"),
+    ('\u{1f9c5}', "onion"),
+    ('\u{1f9c6}', "falafel"),
+    ('\u{1f9c7}', "waffle"),
+    ('\u{1f9c8}', "butter"),
+    ('\u{1f9c9}', "mate drink"),
+    ('\u{1f9ca}', "ice cube"),
+    ('\u{1f9cb}', "bubble tea"),
+    ('\u{1f9cc}', "troll"),
+    ('\u{1f9cd}', "standing person"),
+    ('\u{1f9ce}', "kneeling person"),
+    ('\u{1f9cf}', "deaf person"),
+    ('\u{1f9d0}', "face with monocle"),
+    ('\u{1f9d1}', "adult"),
+    ('\u{1f9d2}', "child"),
+    ('\u{1f9d3}', "older adult"),
+    ('\u{1f9d4}', "bearded person"),
+    ('\u{1f9d5}', "person with headscarf"),
+    ('\u{1f9d6}', "person in steamy room"),
+    ('\u{1f9d7}', "person climbing"),
+    ('\u{1f9d8}', "person in lotus position"),
+    ('\u{1f9d9}', "mage"),
+    ('\u{1f9da}', "fairy"),
+    ('\u{1f9db}', "vampire"),
+    ('\u{1f9dc}', "merperson"),
+    ('\u{1f9dd}', "elf"),
+    ('\u{1f9de}', "genie"),
+    ('\u{1f9df}', "zombie"),
+    ('\u{1f9e0}', "brain"),
+    ('\u{1f9e1}', "orange heart"),
+    ('\u{1f9e2}', "billed cap"),
+    ('\u{1f9e3}', "scarf"),
+    ('\u{1f9e4}', "gloves"),
+    ('\u{1f9e5}', "coat"),
+    ('\u{1f9e6}', "socks"),
+    ('\u{1f9e7}', "red gift envelope"),
+    ('\u{1f9e8}', "firecracker"),
+    ('\u{1f9e9}', "jigsaw puzzle piece"),
+    ('\u{1f9ea}', "test tube"),
+    ('\u{1f9eb}', "petri dish"),
+    ('\u{1f9ec}', "dna double helix"),
+    ('\u{1f9ed}', "compass"),
+    ('\u{1f9ee}', "abacus"),
+    ('\u{1f9ef}', "fire extinguisher"),
+    ('\u{1f9f0}', "toolbox"),
+    ('\u{1f9f1}', "brick"),
+    ('\u{1f9f2}', "magnet"),
+    ('\u{1f9f3}', "luggage"),
+    ('\u{1f9f4}', "lotion bottle"),
+    ('\u{1f9f5}', "spool of thread"),
+    ('\u{1f9f6}', "ball of yarn"),
+    ('\u{1f9f7}', "safety pin"),
+    ('\u{1f9f8}', "teddy bear"),
+    ('\u{1f9f9}', "broom"),
+    ('\u{1f9fa}', "basket"),
+    ('\u{1f9fb}', "roll of paper"),
+    ('\u{1f9fc}', "bar of soap"),
+    ('\u{1f9fd}', "sponge"),
+    ('\u{1f9fe}', "receipt"),
+    ('\u{1f9ff}', "nazar amulet"),
+];
+
+/// Name of a character in the table
+pub fn unicode_name(ch: char) -> Option<&'static str> {
+    UNICODE_NAMES
+        .iter()
+        .find(|(named, _)| *named == ch)
+        .map(|(_, name)| *name)
+}
+
+/// Character written as a hex codepoint: `U+2713`, `u+2713`, `0x2713` or
+/// just `2713`. Without a prefix there has to be a digit, so words such as
+/// "face" are taken as names.
+pub fn parse_codepoint(text: &str) -> Option<char> {
+    let text = text.trim();
+    let hex = match ["U+", "u+", "0x", "0X"]
+        .iter()
+        .find_map(|prefix| text.strip_prefix(prefix))
+    {
+        Some(hex) => hex,
+        None if text.bytes().any(|b| b.is_ascii_digit()) => text,
+        None => return None,
+    };
+    if hex.is_empty() || hex.len() > 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+}
+
+/// A character as `U+XXXX`
+pub fn format_codepoint(ch: char) -> String {
+    format!("U+{:04X}", ch as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_codepoint() {
+        assert_eq!(parse_codepoint("U+2713"), Some('✓'));
+        assert_eq!(parse_codepoint("u+1f600"), Some('😀'));
+        assert_eq!(parse_codepoint(" 0x41 "), Some('A'));
+        assert_eq!(parse_codepoint("e9"), Some('é'));
+        // Surrogates, values past the last codepoint and names aren't characters
+        assert_eq!(parse_codepoint("D800"), None);
+        assert_eq!(parse_codepoint("110000"), None);
+        assert_eq!(parse_codepoint("check mark"), None);
+        assert_eq!(parse_codepoint("U+"), None);
+        assert_eq!(parse_codepoint("face"), None);
+        assert_eq!(parse_codepoint("U+face"), Some('\u{face}'));
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(unicode_name('✓'), Some("check mark"));
+        assert_eq!(unicode_name('→'), Some("rightwards arrow"));
+        assert_eq!(unicode_name('a'), None);
+        assert_eq!(format_codepoint('✓'), "U+2713");
+        assert_eq!(format_codepoint('😀'), "U+1F600");
+    }
+}
@@ -0,0 +1,233 @@
+//! Incrementally maintained index of the words in a buffer
+//!
+//! Counts every identifier-like word (runs of `is_word_char` bytes) so word
+//! completion can offer them without rescanning the buffer. The index is
+//! built on first use, then patched on each insert and delete by recounting
+//! only the words touching the edited range. Any other change to the buffer
+//! (bulk edits, reloads) is caught by the buffer version and triggers a
+//! rebuild on next use.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::model::buffer::Buffer;
+use crate::primitives::word_navigation::is_word_char;
+
+/// Bytes read on each side of an edit to find the words it touches
+const EDIT_CONTEXT_BYTES: usize = 256;
+
+/// Shortest word worth indexing
+const MIN_WORD_LEN: usize = 2;
+
+/// Word counts of a buffer
+#[derive(Debug, Clone, Default)]
+pub struct WordIndex {
+    counts: HashMap<String, usize>,
+    /// Buffer version the counts match, `None` until built
+    version: Option<u64>,
+    /// Set by `before_edit` when the index was current, so `after_edit`
+    /// knows the edit can be patched in
+    pending: bool,
+}
+
+impl WordIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bring the index up to date with the buffer, rebuilding it if needed
+    ///
+    /// Large files that are loaded lazily are not indexed.
+    pub fn refresh(&mut self, buffer: &Buffer) {
+        if self.version == Some(buffer.version()) {
+            return;
+        }
+        self.counts.clear();
+        self.version = None;
+        if buffer.is_large_file() {
+            return;
+        }
+        if let Some(text) = buffer.to_string() {
+            for word in words(text.as_bytes()) {
+                *self.counts.entry(word.to_string()).or_default() += 1;
+            }
+            self.version = Some(buffer.version());
+        }
+    }
+
+    /// Forget the words touching `start..end` before that range is edited
+    pub fn before_edit(&mut self, buffer: &Buffer, start: usize, end: usize) {
+        self.pending = self.version == Some(buffer.version());
+        if self.pending {
+            self.recount(buffer, start, end, false);
+        }
+    }
+
+    /// Count the words touching `start..end` once the edit put its text there
+    pub fn after_edit(&mut self, buffer: &Buffer, start: usize, end: usize) {
+        if std::mem::take(&mut self.pending) {
+            self.recount(buffer, start, end, true);
+            self.version = Some(buffer.version());
+        }
+    }
+
+    /// Number of occurrences of a word
+    pub fn count(&self, word: &str) -> usize {
+        self.counts.get(word).copied().unwrap_or(0)
+    }
+
+    /// Indexed words starting with `prefix` (ignoring case), best first
+    ///
+    /// `nearby` is the text around the cursor, which sits at `cursor` within
+    /// it. Words found there rank first, closest first; the rest follow by
+    /// number of occurrences. The prefix itself is left out.
+    pub fn matching_words(&self, prefix: &str, nearby: &[u8], cursor: usize) -> Vec<&str> {
+        let prefix_lower = prefix.to_lowercase();
+        let distances = word_distances(nearby, cursor);
+        let mut matches: Vec<(&str, usize)> = self
+            .counts
+            .iter()
+            .filter(|(word, _)| {
+                word.as_str() != prefix && word.to_lowercase().starts_with(&prefix_lower)
+            })
+            .map(|(word, &count)| (word.as_str(), count))
+            .collect();
+        matches.sort_by_key(|&(word, count)| {
+            (
+                distances.get(word).copied().unwrap_or(usize::MAX),
+                Reverse(count),
+                word,
+            )
+        });
+        matches.into_iter().map(|(word, _)| word).collect()
+    }
+
+    /// Add (or remove) the words overlapping `start..end`, widened to whole words
+    fn recount(&mut self, buffer: &Buffer, start: usize, end: usize, add: bool) {
+        let window_start = start.saturating_sub(EDIT_CONTEXT_BYTES);
+        let window_end = (end + EDIT_CONTEXT_BYTES).min(buffer.len());
+        if window_start >= window_end {
+            return;
+        }
+        let bytes = buffer.slice_bytes(window_start..window_end);
+        let mut from = (start - window_start).min(bytes.len());
+        let mut to = (end - window_start).min(bytes.len());
+        while from > 0 && is_word_char(bytes[from - 1]) {
+            from -= 1;
+        }
+        while to < bytes.len() && is_word_char(bytes[to]) {
+            to += 1;
+        }
+
+        for word in words(&bytes[from..to]) {
+            if add {
+                *self.counts.entry(word.to_string()).or_default() += 1;
+            } else if let Some(count) = self.counts.get_mut(word) {
+                *count -= 1;
+                if *count == 0 {
+                    self.counts.remove(word);
+                }
+            }
+        }
+    }
+}
+
+/// The indexable words of a byte slice
+fn words(bytes: &[u8]) -> impl Iterator<Item = &str> {
+    bytes
+        .split(|&b| !is_word_char(b))
+        .filter(|word| word.len() >= MIN_WORD_LEN && !word[0].is_ascii_digit())
+        // Word characters are ASCII, so every word is valid UTF-8
+        .filter_map(|word| std::str::from_utf8(word).ok())
+}
+
+/// Distance in bytes from `cursor` to the closest occurrence of each word
+fn word_distances(bytes: &[u8], cursor: usize) -> HashMap<&str, usize> {
+    let mut distances = HashMap::new();
+    let mut offset = 0;
+    for chunk in bytes.split(|&b| !is_word_char(b)) {
+        let (start, end) = (offset, offset + chunk.len());
+        offset = end + 1;
+        let Some(word) = words(chunk).next() else {
+            continue;
+        };
+        let distance = if end < cursor {
+            cursor - end
+        } else {
+            start.saturating_sub(cursor)
+        };
+        distances
+            .entry(word)
+            .and_modify(|d: &mut usize| *d = (*d).min(distance))
+            .or_insert(distance);
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(text: &str) -> Buffer {
+        Buffer::from_str_test(text)
+    }
+
+    #[test]
+    fn test_refresh_counts_words() {
+        let buffer = buffer("let value = value + 1; // 42 x");
+        let mut index = WordIndex::new();
+        index.refresh(&buffer);
+        assert_eq!(index.count("value"), 2);
+        assert_eq!(index.count("let"), 1);
+        // Numbers and single letters are skipped
+        assert_eq!(index.count("42"), 0);
+        assert_eq!(index.count("x"), 0);
+    }
+
+    #[test]
+    fn test_edits_patch_index() {
+        let mut buffer = buffer("alpha beta");
+        let mut index = WordIndex::new();
+        index.refresh(&buffer);
+
+        // Typing inside a word replaces it
+        index.before_edit(&buffer, 2, 2);
+        buffer.insert(2, "XX");
+        index.after_edit(&buffer, 2, 4);
+        assert_eq!(index.count("alpha"), 0);
+        assert_eq!(index.count("alXXpha"), 1);
+
+        // Deleting the space joins two words
+        index.before_edit(&buffer, 7, 8);
+        buffer.delete(7..8);
+        index.after_edit(&buffer, 7, 7);
+        assert_eq!(index.count("alXXpha"), 0);
+        assert_eq!(index.count("beta"), 0);
+        assert_eq!(index.count("alXXphabeta"), 1);
+
+        // Patched counts match a full rebuild
+        let mut rebuilt = WordIndex::new();
+        rebuilt.refresh(&buffer);
+        assert_eq!(index.counts, rebuilt.counts);
+    }
+
+    #[test]
+    fn test_matching_words_ranking() {
+        let text = "render renderer renderer rename re";
+        let buffer = buffer(text);
+        let mut index = WordIndex::new();
+        index.refresh(&buffer);
+
+        // Without context, more frequent words come first
+        assert_eq!(
+            index.matching_words("ren", b"", 0),
+            vec!["renderer", "rename", "render"]
+        );
+        // Words near the cursor come first
+        let cursor = text.find("rename").unwrap();
+        assert_eq!(
+            index.matching_words("Ren", text.as_bytes(), cursor),
+            vec!["rename", "renderer", "render"]
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! Word boundary detection and navigation helpers
 
+use std::ops::Range;
+
 use crate::model::buffer::Buffer;
 
 /// Check if a byte is a word character (alphanumeric or underscore)
@@ -423,6 +425,34 @@ pub fn find_word_start_right(buffer: &Buffer, pos: usize) -> usize {
     start + current_idx
 }
 
+/// The two words Transpose Words swaps in `line` for a cursor at byte `pos`:
+/// the word under the cursor, or the last one before it, and the word after
+/// that. `None` when there is no word after it.
+pub fn words_to_transpose(line: &str, pos: usize) -> Option<(Range<usize>, Range<usize>)> {
+    let mut words: Vec<Range<usize>> = Vec::new();
+    let mut word_start = None;
+    for (i, ch) in line
+        .char_indices()
+        .chain(std::iter::once((line.len(), ' ')))
+    {
+        match (word_start, ch.is_alphanumeric() || ch == '_') {
+            (None, true) => word_start = Some(i),
+            (Some(start), false) => {
+                words.push(start..i);
+                word_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    let first = words
+        .iter()
+        .rposition(|word| word.start <= pos)
+        .unwrap_or(0);
+    let second = words.get(first + 1)?.clone();
+    Some((words[first].clone(), second))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -586,6 +616,19 @@ mod tests {
         assert_eq!(find_word_end_bytes(bytes, 7), 18);
     }
 
+    #[test]
+    fn test_words_to_transpose() {
+        let line = "let été = other_name;";
+        // Inside, at the start and at the end of a word
+        assert_eq!(words_to_transpose(line, 5), Some((4..9, 12..22)));
+        assert_eq!(words_to_transpose(line, 4), Some((4..9, 12..22)));
+        assert_eq!(words_to_transpose(line, 9), Some((4..9, 12..22)));
+        // Before the first word, and on the last one
+        assert_eq!(words_to_transpose("  a b", 0), Some((2..3, 4..5)));
+        assert_eq!(words_to_transpose(line, 15), None);
+        assert_eq!(words_to_transpose("", 0), None);
+    }
+
     // Property-based tests
     #[cfg(test)]
     mod property_tests {
@@ -41,6 +41,7 @@ impl CaptureBackend {
 
     /// Take the captured output buffer, leaving an empty buffer
     pub fn take_buffer(&mut self) -> Vec<u8> {
+        crate::services::perf::record_output_bytes(self.buffer.len());
         std::mem::take(&mut self.buffer)
     }
 
@@ -2,6 +2,7 @@
 
 use std::io;
 use std::os::unix::io::AsRawFd;
+use std::path::Path;
 
 /// Daemonize the current process
 ///
@@ -9,7 +10,7 @@ use std::os::unix::io::AsRawFd;
 /// 1. Forks the process (first fork)
 /// 2. Creates a new session with setsid()
 /// 3. Forks again (second fork) to prevent acquiring a controlling terminal
-/// 4. Redirects stdin/stdout/stderr to /dev/null
+/// 4. Redirects stdin to /dev/null and stdout/stderr to the server log
 /// 5. Changes working directory to /
 ///
 /// Returns Ok(()) in the daemon process, or an error if daemonization fails.
@@ -34,15 +35,13 @@ pub fn daemonize() -> io::Result<()> {
         _ => std::process::exit(0), // Parent exits
     }
 
-    // Redirect stdin/stdout/stderr to /dev/null
+    // Redirect stdin to /dev/null, and stdout/stderr to the log of the
+    // final daemon process so panics and startup messages aren't lost
     let devnull = std::fs::File::open("/dev/null")?;
-    let devnull_fd = devnull.as_raw_fd();
-
     unsafe {
-        libc::dup2(devnull_fd, 0); // stdin
-        libc::dup2(devnull_fd, 1); // stdout
-        libc::dup2(devnull_fd, 2); // stderr
+        libc::dup2(devnull.as_raw_fd(), 0); // stdin
     }
+    redirect_output_to(&crate::services::log_dirs::server_log_path())?;
 
     // Change to root directory to avoid holding mount points
     std::env::set_current_dir("/")?;
@@ -55,12 +54,32 @@ pub fn daemonize() -> io::Result<()> {
     Ok(())
 }
 
+/// Redirect stdout and stderr to `log_path`, appending
+///
+/// The file stays open in append mode, so the output interleaves with other
+/// writers of the same log instead of overwriting them.
+pub fn redirect_output_to(log_path: &Path) -> io::Result<()> {
+    let log = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path)?;
+    let log_fd = log.as_raw_fd();
+
+    unsafe {
+        if libc::dup2(log_fd, 1) == -1 || libc::dup2(log_fd, 2) == -1 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
 /// Spawn the server as a detached background process
 ///
 /// This is used when the client starts and no server is running.
 /// The server inherits the current working directory.
+/// With `no_restore`, the server starts without the saved workspace.
 /// Returns the PID of the spawned server (intermediate, not final daemon PID).
-pub fn spawn_server_detached(session_name: Option<&str>) -> io::Result<u32> {
+pub fn spawn_server_detached(session_name: Option<&str>, no_restore: bool) -> io::Result<u32> {
     let exe = std::env::current_exe()?;
 
     let mut args = vec!["--server".to_string()];
@@ -70,6 +89,10 @@ pub fn spawn_server_detached(session_name: Option<&str>) -> io::Result<u32> {
         args.push(name.to_string());
     }
 
+    if no_restore {
+        args.push("--no-restore".to_string());
+    }
+
     // Use Command to spawn, which properly handles the process
     let child = std::process::Command::new(&exe)
         .args(&args)
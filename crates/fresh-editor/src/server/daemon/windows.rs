@@ -27,8 +27,9 @@ pub fn daemonize() -> io::Result<()> {
 ///
 /// This is used when the client starts and no server is running.
 /// The server inherits the current working directory.
+/// With `no_restore`, the server starts without the saved workspace.
 /// Returns the PID of the spawned server.
-pub fn spawn_server_detached(session_name: Option<&str>) -> io::Result<u32> {
+pub fn spawn_server_detached(session_name: Option<&str>, no_restore: bool) -> io::Result<u32> {
     let exe = std::env::current_exe()?;
 
     let mut cmd = std::process::Command::new(&exe);
@@ -38,6 +39,10 @@ pub fn spawn_server_detached(session_name: Option<&str>) -> io::Result<u32> {
         cmd.arg("--session-name").arg(name);
     }
 
+    if no_restore {
+        cmd.arg("--no-restore");
+    }
+
     cmd.creation_flags(DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP);
     cmd.stdin(std::process::Stdio::null());
     cmd.stdout(std::process::Stdio::null());
@@ -25,7 +25,8 @@ use crate::server::capture_backend::{
 use crate::server::input_parser::InputParser;
 use crate::server::ipc::{ServerConnection, ServerListener, SocketPaths, StreamWrapper};
 use crate::server::protocol::{
-    ClientControl, ServerControl, ServerHello, TermSize, VersionMismatch, PROTOCOL_VERSION,
+    capability, negotiate_capabilities, version_mismatch_reason, ClientControl, ServerControl,
+    ServerHello, TermSize, PROTOCOL_VERSION,
 };
 use crate::view::color_support::ColorCapability;
 
@@ -44,6 +45,11 @@ pub struct EditorServerConfig {
     pub dir_context: DirectoryContext,
     /// Whether plugins are enabled
     pub plugins_enabled: bool,
+    /// Whether to restore the working directory's workspace on start and
+    /// save it while running and on shutdown
+    pub workspace_enabled: bool,
+    /// Server log file, shown by "Show Logs"
+    pub log_path: Option<PathBuf>,
 }
 
 /// Editor server that manages editor state and client connections
@@ -59,6 +65,12 @@ pub struct EditorServer {
     term_size: TermSize,
     /// Index of the client that most recently provided input (for per-client detach)
     last_input_client: Option<usize>,
+    /// ID of the client that asked to quit while the quit dialog is open; only
+    /// that client can answer it
+    quit_dialog_client: Option<u64>,
+    /// A client left; with independent views its cursor is still on the
+    /// others' screens until they are redrawn
+    client_left: bool,
     /// Next wait ID for --wait tracking
     next_wait_id: u64,
     /// Maps wait_id → client_id for clients waiting on file events
@@ -128,12 +140,18 @@ struct ConnectedClient {
     data_writer: ClientDataWriter,
     term_size: TermSize,
     env: std::collections::HashMap<String, Option<String>>,
+    /// Name the client gave in its hello
+    name: Option<String>,
+    /// Optional features in use for this client
+    capabilities: Vec<String>,
     id: u64,
     input_parser: InputParser,
     /// Whether this client needs a full screen render on next frame
     needs_full_render: bool,
     /// If set, this client is waiting for a --wait completion signal
     wait_id: Option<u64>,
+    /// Screen of this client's own view, when clients have independent views
+    terminal: Option<Terminal<CaptureBackend>>,
 }
 
 impl EditorServer {
@@ -163,6 +181,8 @@ impl EditorServer {
             shutdown: Arc::new(AtomicBool::new(false)),
             term_size: TermSize::new(80, 24), // Default until first client connects
             last_input_client: None,
+            quit_dialog_client: None,
+            client_left: false,
             next_wait_id: 1,
             waiting_clients: std::collections::HashMap::new(),
         })
@@ -213,7 +233,7 @@ impl EditorServer {
                         .map(|e| e.config().editor.cursor_style)
                         .unwrap_or(self.config.editor_config.editor.cursor_style);
                     match self.handle_new_connection(conn, next_client_id, cursor_style) {
-                        Ok(client) => {
+                        Ok(mut client) => {
                             tracing::info!("Client {} connected", client.id);
 
                             // Initialize editor on first-ever client, or update size if reconnecting
@@ -230,6 +250,19 @@ impl EditorServer {
                             }
                             // Note: full redraw is handled via client.needs_full_render flag
 
+                            if self.independent_views() {
+                                self.attach_client_view(&mut client)?;
+                            }
+
+                            // The client's terminal decides the "auto" theme
+                            if self
+                                .editor
+                                .as_ref()
+                                .is_some_and(|e| e.wants_terminal_background())
+                            {
+                                client.request_background();
+                            }
+
                             self.clients.push(client);
                             self.last_client_activity = Instant::now();
                             next_client_id += 1;
@@ -280,6 +313,10 @@ impl EditorServer {
                     if idx < self.clients.len() {
                         tracing::info!("Client {} requested detach", self.clients[idx].id);
                         let client = self.clients.remove(idx);
+                        if let Some(ref mut editor) = self.editor {
+                            editor.remove_client_view(client.id);
+                        }
+                        needs_render = true;
                         let teardown = terminal_teardown_sequences();
                         // Best-effort: client may already be disconnected
                         #[allow(clippy::let_underscore_must_use)]
@@ -304,6 +341,10 @@ impl EditorServer {
                 continue;
             }
 
+            if std::mem::take(&mut self.client_left) && self.independent_views() {
+                needs_render = true;
+            }
+
             // Handle resize
             if resize_occurred {
                 self.update_terminal_size()?;
@@ -313,11 +354,17 @@ impl EditorServer {
             // Process input events
             if !input_events.is_empty() {
                 self.last_client_activity = Instant::now();
-                for event in input_events {
+                for (client_id, event) in input_events {
+                    if self.independent_views() {
+                        if let Some(ref mut editor) = self.editor {
+                            editor.activate_client_view(client_id);
+                        }
+                    }
                     if self.handle_event(event)? {
                         needs_render = true;
                     }
                 }
+                self.update_quit_dialog_client();
             }
 
             // Process async messages from editor
@@ -328,6 +375,8 @@ impl EditorServer {
                 if editor.process_pending_file_opens() {
                     needs_render = true;
                 }
+                // Sessions save on the same timers as a local editor
+                editor.process_periodic_saves();
 
                 // Process completed --wait operations
                 for wait_id in editor.take_completed_waits() {
@@ -346,6 +395,15 @@ impl EditorServer {
                 if editor.check_mouse_hover_timer() {
                     needs_render = true;
                 }
+                if editor.check_hover_tooltip_timer() {
+                    needs_render = true;
+                }
+                if editor.check_chord_timeout() {
+                    needs_render = true;
+                }
+                if editor.check_markdown_preview_timer() {
+                    needs_render = true;
+                }
             }
 
             // Render and broadcast if needed
@@ -360,6 +418,13 @@ impl EditorServer {
         }
 
         // Clean shutdown
+        if self.config.workspace_enabled {
+            if let Some(ref mut editor) = self.editor {
+                if let Err(e) = editor.save_workspace() {
+                    tracing::warn!("Failed to save workspace: {}", e);
+                }
+            }
+        }
         self.disconnect_all_clients("Server shutting down")?;
 
         Ok(())
@@ -400,6 +465,18 @@ impl EditorServer {
                 .unwrap_or_else(|| "session".to_string())
         });
         editor.set_session_name(Some(session_display_name));
+        if let Some(log_path) = &self.config.log_path {
+            editor.set_editor_log_path(log_path.clone());
+        }
+
+        if self.config.workspace_enabled {
+            editor.set_workspace_enabled(true);
+            match editor.try_restore_workspace() {
+                Ok(true) => tracing::info!("Workspace restored successfully"),
+                Ok(false) => tracing::debug!("No previous workspace found"),
+                Err(e) => tracing::warn!("Failed to restore workspace: {}", e),
+            }
+        }
 
         self.terminal = Some(terminal);
         self.editor = Some(editor);
@@ -441,22 +518,10 @@ impl EditorServer {
 
         // Check protocol version
         if hello.protocol_version != PROTOCOL_VERSION {
-            let mismatch = VersionMismatch {
-                server_version: env!("CARGO_PKG_VERSION").to_string(),
-                client_version: hello.client_version.clone(),
-                action: if hello.protocol_version > PROTOCOL_VERSION {
-                    "upgrade_server".to_string()
-                } else {
-                    "restart_server".to_string()
-                },
-                message: format!(
-                    "Protocol version mismatch: server={}, client={}",
-                    PROTOCOL_VERSION, hello.protocol_version
-                ),
-            };
-
-            let response = serde_json::to_string(&ServerControl::VersionMismatch(mismatch))
-                .map_err(|e| io::Error::other(e.to_string()))?;
+            let response = serde_json::to_string(&ServerControl::Quit {
+                reason: version_mismatch_reason(&hello),
+            })
+            .map_err(|e| io::Error::other(e.to_string()))?;
             conn.write_control(&response)?;
 
             return Err(io::Error::other("Version mismatch"));
@@ -467,7 +532,12 @@ impl EditorServer {
             crate::workspace::encode_path_for_filename(&self.config.working_dir)
         });
 
-        let server_hello = ServerHello::new(session_id);
+        let capabilities = negotiate_capabilities(&hello.capabilities);
+        tracing::debug!("Client {} capabilities: {:?}", client_id, capabilities);
+        let server_hello = ServerHello {
+            capabilities: capabilities.clone(),
+            ..ServerHello::new(session_id)
+        };
         let response = serde_json::to_string(&ServerControl::Hello(server_hello))
             .map_err(|e| io::Error::other(e.to_string()))?;
         conn.write_control(&response)?;
@@ -500,21 +570,37 @@ impl EditorServer {
             data_writer,
             term_size: hello.term_size,
             env: hello.env,
+            name: hello.name,
+            capabilities,
             id: client_id,
             input_parser: InputParser::new(),
             needs_full_render: true,
             wait_id: None,
+            terminal: None,
         })
     }
 
     /// Process messages from connected clients
-    /// Returns (input_events, resize_occurred, index of client that provided input)
-    fn process_clients(&mut self) -> io::Result<(Vec<Event>, bool, Option<usize>)> {
+    /// Returns (input_events with the ID of the client that sent each,
+    /// resize_occurred, index of client that provided input)
+    #[allow(clippy::type_complexity)]
+    fn process_clients(&mut self) -> io::Result<(Vec<(u64, Event)>, bool, Option<usize>)> {
         let mut disconnected = Vec::new();
         let mut input_source_client: Option<usize> = None;
         let mut input_events = Vec::new();
         let mut resize_occurred = false;
         let mut control_messages: Vec<(usize, ClientControl)> = Vec::new();
+        let wants_background = self
+            .editor
+            .as_ref()
+            .is_some_and(|e| e.wants_terminal_background());
+        let quit_dialog_client = self.quit_dialog_client.filter(|_| {
+            !self.independent_views()
+                && self
+                    .editor
+                    .as_ref()
+                    .is_some_and(|e| e.is_quit_dialog_open())
+        });
 
         for (idx, client) in self.clients.iter_mut().enumerate() {
             // Read from data socket
@@ -537,7 +623,10 @@ impl EditorServer {
                         client.id,
                         n
                     );
-                    let events = client.input_parser.parse(&buf[..n]);
+                    let mut events = client.input_parser.parse(&buf[..n]);
+                    if quit_dialog_client.is_some_and(|id| id != client.id) {
+                        events.retain(|e| !is_user_input(e));
+                    }
                     tracing::debug!(
                         "[server] Client {} parsed {} events",
                         client.id,
@@ -546,15 +635,22 @@ impl EditorServer {
                     if !events.is_empty() {
                         input_source_client = Some(idx);
                     }
-                    input_events.extend(events);
+                    // The terminal may have switched between light and dark while unfocused
+                    if wants_background && events.iter().any(|e| matches!(e, Event::FocusGained)) {
+                        client.request_background();
+                    }
+                    input_events.extend(events.into_iter().map(|e| (client.id, e)));
                 }
                 Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
                     // No data available - check if we have a pending escape sequence
                     // that should be flushed due to timeout
-                    let timeout_events = client.input_parser.flush_timeout();
+                    let mut timeout_events = client.input_parser.flush_timeout();
+                    if quit_dialog_client.is_some_and(|id| id != client.id) {
+                        timeout_events.retain(|e| !is_user_input(e));
+                    }
                     if !timeout_events.is_empty() {
                         input_source_client = Some(idx);
-                        input_events.extend(timeout_events);
+                        input_events.extend(timeout_events.into_iter().map(|e| (client.id, e)));
                     }
                 }
                 Err(e) => {
@@ -566,59 +662,33 @@ impl EditorServer {
             }
             let _ = data_eof; // Suppress unused warning
 
-            // Check control socket
-            // On Windows, don't toggle nonblocking mode - it fails on named pipes
-            // Best-effort: nonblocking mode for control socket polling
-            #[cfg(not(windows))]
-            #[allow(clippy::let_underscore_must_use)]
-            let _ = client.conn.control.set_nonblocking(true);
-
-            // On Windows, use try_read pattern instead of blocking read_line
-            #[cfg(windows)]
-            {
-                let mut buf = [0u8; 1024];
-                match client.conn.control.try_read(&mut buf) {
-                    Ok(0) => {
-                        tracing::debug!("Client {} control stream closed (EOF)", client.id);
-                        disconnected.push(idx);
-                    }
-                    Ok(n) => {
-                        // Try to parse as control message
-                        if let Ok(s) = std::str::from_utf8(&buf[..n]) {
-                            for line in s.lines() {
-                                if !line.trim().is_empty() {
-                                    if let Ok(msg) = serde_json::from_str::<ClientControl>(line) {
-                                        control_messages.push((idx, msg));
-                                    }
-                                }
-                            }
+            // Check control socket: take every message that has arrived
+            loop {
+                match client.conn.try_read_control() {
+                    Ok(Some(line)) => {
+                        if let Ok(msg) = serde_json::from_str::<ClientControl>(&line) {
+                            control_messages.push((idx, msg));
                         }
                     }
-                    Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
-                    Err(e) => {
-                        tracing::warn!("Client {} control read error: {}", client.id, e);
-                    }
-                }
-            }
-
-            #[cfg(not(windows))]
-            {
-                let mut reader = std::io::BufReader::new(&client.conn.control);
-                let mut line = String::new();
-                match std::io::BufRead::read_line(&mut reader, &mut line) {
-                    Ok(0) => {
+                    Ok(None) => break,
+                    Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
                         tracing::debug!("Client {} control stream closed (EOF)", client.id);
                         disconnected.push(idx);
+                        break;
                     }
-                    Ok(_) if !line.trim().is_empty() => {
-                        if let Ok(msg) = serde_json::from_str::<ClientControl>(&line) {
-                            control_messages.push((idx, msg));
-                        }
+                    Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                        // Nothing after a malformed frame can be trusted
+                        tracing::warn!(
+                            "Client {} sent a malformed control frame: {}",
+                            client.id,
+                            e
+                        );
+                        disconnected.push(idx);
+                        break;
                     }
-                    Ok(_) => {}
-                    Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
                     Err(e) => {
                         tracing::warn!("Client {} control read error: {}", client.id, e);
+                        break;
                     }
                 }
             }
@@ -638,8 +708,11 @@ impl EditorServer {
                 continue;
             }
 
-            // Always process OpenFiles - it's a one-shot command from clients that disconnect immediately
-            if let ClientControl::OpenFiles { .. } = msg {
+            // Always process OpenFiles, OpenStdin and OpenFile - they're one-shot commands from clients that disconnect immediately
+            if let ClientControl::OpenFiles { .. }
+            | ClientControl::OpenStdin { .. }
+            | ClientControl::OpenFile { .. } = msg
+            {
                 // Fall through to process it
             } else if disconnected.contains(&idx) {
                 // Skip other messages from disconnected clients
@@ -651,7 +724,9 @@ impl EditorServer {
                     tracing::warn!("Unexpected Hello from client");
                 }
                 ClientControl::Resize { cols, rows } => {
-                    if let Some(client) = self.clients.get_mut(idx) {
+                    if self.independent_views() {
+                        self.resize_client_view(idx, cols, rows);
+                    } else if let Some(client) = self.clients.get_mut(idx) {
                         client.term_size = TermSize::new(cols, rows);
                         // Update server size to match first client
                         if idx == 0 {
@@ -719,6 +794,54 @@ impl EditorServer {
                         resize_occurred = true; // Force re-render
                     }
                 }
+                ClientControl::OpenFile { file } => {
+                    if let Some(ref mut editor) = self.editor {
+                        editor.queue_file_open(
+                            std::path::PathBuf::from(&file.path),
+                            file.line,
+                            file.column,
+                            file.end_line,
+                            file.end_column,
+                            file.message,
+                            None,
+                        );
+                        resize_occurred = true; // Force re-render
+                    }
+                    // The sender exits right away; the file shows up in the
+                    // terminals of the clients that stay attached
+                    for (i, client) in self.clients.iter().enumerate() {
+                        if i != idx && !disconnected.contains(&i) {
+                            client.raise();
+                        }
+                    }
+                }
+                ClientControl::BackgroundColor { r, g, b } => {
+                    if let Some(ref mut editor) = self.editor {
+                        editor.set_terminal_background(r, g, b);
+                        resize_occurred = true; // Force re-render
+                    }
+                }
+                ClientControl::Unknown => {
+                    tracing::debug!("Ignoring unknown control message from client {}", idx);
+                }
+                ClientControl::OpenStdin { content } => {
+                    if let Some(ref mut editor) = self.editor {
+                        let client_id = self.clients.get(idx).map_or(0, |client| client.id);
+                        // The buffer loads from a file like a local stdin buffer
+                        let temp_path = std::env::temp_dir().join(format!(
+                            "fresh-stdin-{}-{}.tmp",
+                            std::process::id(),
+                            client_id
+                        ));
+                        let opened = std::fs::write(&temp_path, content)
+                            .map_err(anyhow::Error::from)
+                            .and_then(|()| editor.open_stdin_buffer(&temp_path, None));
+                        if let Err(e) = opened {
+                            tracing::warn!("Failed to open stdin from client {}: {}", idx, e);
+                        }
+                        resize_occurred = true; // Force re-render
+                    }
+                }
                 ClientControl::Quit => unreachable!(), // Handled above
             }
         }
@@ -750,6 +873,14 @@ impl EditorServer {
             let teardown = terminal_teardown_sequences();
             let _ = client.data_writer.try_write(&teardown);
             tracing::info!("Client {} disconnected", client.id);
+            if let Some(ref mut editor) = self.editor {
+                editor.remove_client_view(client.id);
+            }
+            self.client_left = true;
+            // Hand an open quit dialog to whichever client answers next
+            if self.quit_dialog_client == Some(client.id) {
+                self.quit_dialog_client = None;
+            }
             // Invalidate input source if that client disconnected
             if input_source_client == Some(idx) {
                 input_source_client = None;
@@ -759,6 +890,25 @@ impl EditorServer {
         Ok((input_events, resize_occurred, input_source_client))
     }
 
+    /// Give the quit dialog to the client whose input opened it, and forget
+    /// the owner once it closes. Clients attaching meanwhile see the dialog in
+    /// the shared frame but cannot answer it.
+    fn update_quit_dialog_client(&mut self) {
+        let open = !self.independent_views()
+            && self
+                .editor
+                .as_ref()
+                .is_some_and(|e| e.is_quit_dialog_open());
+        if !open {
+            self.quit_dialog_client = None;
+        } else if self.quit_dialog_client.is_none() {
+            self.quit_dialog_client = self
+                .last_input_client
+                .and_then(|idx| self.clients.get(idx))
+                .map(|client| client.id);
+        }
+    }
+
     /// Update terminal size after resize
     fn update_terminal_size(&mut self) -> io::Result<()> {
         if let Some(ref mut terminal) = self.terminal {
@@ -786,6 +936,9 @@ impl EditorServer {
                         .handle_key(key_event.code, key_event.modifiers)
                         .map_err(|e| io::Error::other(e.to_string()))?;
                     Ok(true)
+                } else if key_event.kind == KeyEventKind::Release {
+                    editor.handle_key_release(key_event.code);
+                    Ok(true)
                 } else {
                     Ok(false)
                 }
@@ -801,12 +954,58 @@ impl EditorServer {
                 editor.paste_text(text);
                 Ok(true)
             }
+            Event::FocusLost => {
+                editor.handle_focus_lost();
+                Ok(true)
+            }
             _ => Ok(false),
         }
     }
 
+    /// Whether each client gets its own view instead of sharing one screen
+    fn independent_views(&self) -> bool {
+        self.config.editor_config.editor.session_independent_views
+    }
+
+    /// Give a newly connected client its own screen and view, sized to its
+    /// terminal
+    fn attach_client_view(&mut self, client: &mut ConnectedClient) -> io::Result<()> {
+        let backend = CaptureBackend::new(client.term_size.cols, client.term_size.rows);
+        let terminal = Terminal::new(backend)
+            .map_err(|e| io::Error::other(format!("Failed to create terminal: {}", e)))?;
+        client.terminal = Some(terminal);
+
+        if let Some(ref mut editor) = self.editor {
+            editor.activate_client_view(client.id);
+            editor.resize(client.term_size.cols, client.term_size.rows);
+            if let Some(name) = &client.name {
+                editor.set_client_name(client.id, name.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Resize one client's screen and view
+    fn resize_client_view(&mut self, idx: usize, cols: u16, rows: u16) {
+        let Some(client) = self.clients.get_mut(idx) else {
+            return;
+        };
+        client.term_size = TermSize::new(cols, rows);
+        if let Some(ref mut terminal) = client.terminal {
+            terminal.backend_mut().resize(cols, rows);
+        }
+        if let Some(ref mut editor) = self.editor {
+            editor.activate_client_view(client.id);
+            editor.resize(cols, rows);
+        }
+    }
+
     /// Render the editor and broadcast output to all clients
     fn render_and_broadcast(&mut self) -> io::Result<()> {
+        if self.independent_views() {
+            return self.render_client_views();
+        }
+
         let Some(ref mut editor) = self.editor else {
             return Ok(());
         };
@@ -831,6 +1030,19 @@ impl EditorServer {
 
         // Take any pending escape sequences (e.g., cursor style changes)
         let pending_sequences = editor.take_pending_escape_sequences();
+        let clipboard_sequences = editor.take_clipboard_sequences();
+
+        // One screen for everyone: use the colors every client can show,
+        // which is 24-bit unless a client reported a limit
+        let capabilities: Vec<ColorCapability> =
+            self.clients.iter().map(|c| c.color_capability()).collect();
+        editor.set_color_capability(if capabilities.contains(&ColorCapability::Color16) {
+            ColorCapability::Color16
+        } else if capabilities.contains(&ColorCapability::Color256) {
+            ColorCapability::Color256
+        } else {
+            ColorCapability::TrueColor
+        });
 
         // Render to capture backend
         terminal
@@ -840,7 +1052,7 @@ impl EditorServer {
         // Get the captured output
         let output = terminal.backend_mut().take_buffer();
 
-        if output.is_empty() && pending_sequences.is_empty() {
+        if output.is_empty() && pending_sequences.is_empty() && clipboard_sequences.is_empty() {
             return Ok(());
         }
 
@@ -850,16 +1062,7 @@ impl EditorServer {
                 continue;
             }
             // Combine pending sequences and output into a single frame
-            let frame = if !pending_sequences.is_empty() && !output.is_empty() {
-                let mut combined = Vec::with_capacity(pending_sequences.len() + output.len());
-                combined.extend_from_slice(&pending_sequences);
-                combined.extend_from_slice(&output);
-                combined
-            } else if !pending_sequences.is_empty() {
-                pending_sequences.clone()
-            } else {
-                output.clone()
-            };
+            let frame = client.frame_bytes(&pending_sequences, &clipboard_sequences, &output);
 
             if !frame.is_empty() && !client.data_writer.try_write(&frame) {
                 tracing::warn!("Client {} output buffer full, dropping frame", client.id);
@@ -871,6 +1074,48 @@ impl EditorServer {
         Ok(())
     }
 
+    /// Render each client's own view to its own screen
+    fn render_client_views(&mut self) -> io::Result<()> {
+        let Some(ref mut editor) = self.editor else {
+            return Ok(());
+        };
+
+        // Take any pending escape sequences (e.g., cursor style changes)
+        let pending_sequences = editor.take_pending_escape_sequences();
+        let clipboard_sequences = editor.take_clipboard_sequences();
+
+        for client in &mut self.clients {
+            if client.wait_id.is_some() {
+                continue;
+            }
+            let color_capability = client.color_capability();
+            let Some(ref mut terminal) = client.terminal else {
+                continue;
+            };
+            if client.needs_full_render {
+                terminal.backend_mut().reset_style_state();
+                // Best-effort terminal clear for full redraw
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = terminal.clear();
+            }
+
+            editor.activate_client_view(client.id);
+            editor.set_color_capability(color_capability);
+            terminal
+                .draw(|frame| editor.render(frame))
+                .map_err(|e| io::Error::other(e.to_string()))?;
+
+            let output = terminal.backend_mut().take_buffer();
+            let frame = client.frame_bytes(&pending_sequences, &clipboard_sequences, &output);
+            if !frame.is_empty() && !client.data_writer.try_write(&frame) {
+                tracing::warn!("Client {} output buffer full, dropping frame", client.id);
+            }
+            client.needs_full_render = false;
+        }
+
+        Ok(())
+    }
+
     /// Disconnect all clients
     fn disconnect_all_clients(&mut self, reason: &str) -> io::Result<()> {
         let teardown = terminal_teardown_sequences();
@@ -892,6 +1137,38 @@ impl EditorServer {
 }
 
 impl ConnectedClient {
+    /// Whether a negotiated feature is in use for this client
+    fn supports(&self, capability: &str) -> bool {
+        self.capabilities.iter().any(|c| c == capability)
+    }
+
+    /// Bytes to send for one frame: the editor's escape sequences, clipboard
+    /// copies if the terminal takes them, then the drawn output, marked as a
+    /// synchronized update when the terminal supports it
+    fn frame_bytes(&self, pending: &[u8], clipboard: &[u8], output: &[u8]) -> Vec<u8> {
+        let mut frame = Vec::with_capacity(pending.len() + clipboard.len() + output.len() + 16);
+        frame.extend_from_slice(pending);
+        if self.supports(capability::OSC52) {
+            frame.extend_from_slice(clipboard);
+        }
+        if !output.is_empty() {
+            let sync = self.supports(capability::SYNC_OUTPUT);
+            if sync {
+                frame.extend_from_slice(b"\x1b[?2026h");
+            }
+            frame.extend_from_slice(output);
+            if sync {
+                frame.extend_from_slice(b"\x1b[?2026l");
+            }
+        }
+        frame
+    }
+
+    /// Colors the client's terminal can show
+    fn color_capability(&self) -> ColorCapability {
+        crate::server::protocol::color_capability(&self.capabilities)
+    }
+
     /// Get the client's TERM environment variable
     #[allow(dead_code)]
     pub fn term(&self) -> Option<&str> {
@@ -907,4 +1184,26 @@ impl ConnectedClient {
             .map(|v| v == "truecolor" || v == "24bit")
             .unwrap_or(false)
     }
+
+    /// Ask the client to report its terminal's background color
+    fn request_background(&self) {
+        let msg = serde_json::to_string(&ServerControl::QueryBackground).unwrap_or_default();
+        // Best-effort: clients that can't query their terminal never answer
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = self.conn.write_control(&msg);
+    }
+
+    /// Ask the client to bring its terminal window to the front
+    fn raise(&self) {
+        let msg = serde_json::to_string(&ServerControl::Raise).unwrap_or_default();
+        // Best-effort: many terminals ignore window operations
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = self.conn.write_control(&msg);
+    }
+}
+
+/// Keys, mouse and paste: input that acts on the editor, as opposed to focus
+/// and resize notifications
+fn is_user_input(event: &Event) -> bool {
+    matches!(event, Event::Key(_) | Event::Mouse(_) | Event::Paste(_))
 }
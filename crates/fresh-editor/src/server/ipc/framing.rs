@@ -0,0 +1,253 @@
+//! Framing of control channel messages
+//!
+//! Each control message is sent as a frame: the payload length as a 4-byte
+//! big-endian number, then that many bytes of JSON. Unlike one JSON object
+//! per line, this works whatever the payload contains, and a reader takes in
+//! whole messages instead of scanning byte by byte for their end.
+//!
+//! Peers from before framing send and expect one JSON object per line. Frames
+//! are capped at 64 MiB (enough for large piped input), so the first byte of a
+//! frame is at most 3 while a JSON line starts with `{`. The decoder tells the two apart for
+//! each message and remembers a peer that sends lines, so it can be answered
+//! in lines too.
+
+use std::io;
+
+/// Largest control message accepted, in bytes
+pub const MAX_CONTROL_FRAME_LEN: usize = 64 * 1024 * 1024;
+
+/// Bytes in the length prefix of a frame
+const LENGTH_PREFIX_LEN: usize = 4;
+
+/// Encode a control message as a frame
+pub fn encode_frame(msg: &str) -> io::Result<Vec<u8>> {
+    let payload = msg.as_bytes();
+    if payload.len() > MAX_CONTROL_FRAME_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "control message of {} bytes exceeds the {} byte limit",
+                payload.len(),
+                MAX_CONTROL_FRAME_LEN
+            ),
+        ));
+    }
+    let mut frame = Vec::with_capacity(LENGTH_PREFIX_LEN + payload.len());
+    frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    frame.extend_from_slice(payload);
+    Ok(frame)
+}
+
+/// Encode a control message as a line, for peers from before framing
+pub fn encode_line(msg: &str) -> Vec<u8> {
+    let mut line = msg.trim_end_matches('\n').as_bytes().to_vec();
+    line.push(b'\n');
+    line
+}
+
+/// Splits the bytes received on a control channel into messages
+#[derive(Debug, Default)]
+pub struct FrameDecoder {
+    buf: Vec<u8>,
+    legacy_peer: bool,
+}
+
+impl FrameDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add received bytes
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Whether the peer sends one message per line (it predates framing)
+    pub fn is_legacy_peer(&self) -> bool {
+        self.legacy_peer
+    }
+
+    /// Take the next complete message, or `None` until more bytes arrive.
+    ///
+    /// Fails on a message over [`MAX_CONTROL_FRAME_LEN`] or one that isn't
+    /// UTF-8; the channel can't be trusted after that.
+    pub fn next_message(&mut self) -> io::Result<Option<String>> {
+        // Line breaks between messages are skipped: the first frame a client
+        // sends ends with one, so servers from before framing stop reading it
+        let gap = self
+            .buf
+            .iter()
+            .take_while(|&&b| b == b'\n' || b == b'\r')
+            .count();
+        self.buf.drain(..gap);
+
+        let Some(&first) = self.buf.first() else {
+            return Ok(None);
+        };
+        if first == b'{' {
+            return self.next_line();
+        }
+
+        if self.buf.len() < LENGTH_PREFIX_LEN {
+            return Ok(None);
+        }
+        let mut prefix = [0u8; LENGTH_PREFIX_LEN];
+        prefix.copy_from_slice(&self.buf[..LENGTH_PREFIX_LEN]);
+        let len = u32::from_be_bytes(prefix) as usize;
+        if len > MAX_CONTROL_FRAME_LEN {
+            return Err(oversized(len));
+        }
+        if self.buf.len() < LENGTH_PREFIX_LEN + len {
+            return Ok(None);
+        }
+        let frame: Vec<u8> = self.buf.drain(..LENGTH_PREFIX_LEN + len).collect();
+        self.legacy_peer = false;
+        to_message(frame[LENGTH_PREFIX_LEN..].to_vec()).map(Some)
+    }
+
+    /// Take a newline-terminated message from a peer from before framing
+    fn next_line(&mut self) -> io::Result<Option<String>> {
+        let Some(end) = self.buf.iter().position(|&b| b == b'\n') else {
+            if self.buf.len() > MAX_CONTROL_FRAME_LEN {
+                return Err(oversized(self.buf.len()));
+            }
+            return Ok(None);
+        };
+        let mut line: Vec<u8> = self.buf.drain(..=end).collect();
+        line.pop();
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+        self.legacy_peer = true;
+        to_message(line).map(Some)
+    }
+}
+
+fn oversized(len: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "control message of {} bytes exceeds the {} byte limit",
+            len, MAX_CONTROL_FRAME_LEN
+        ),
+    )
+}
+
+fn to_message(payload: Vec<u8>) -> io::Result<String> {
+    String::from_utf8(payload).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_all(decoder: &mut FrameDecoder) -> Vec<String> {
+        let mut messages = Vec::new();
+        while let Some(msg) = decoder.next_message().unwrap() {
+            messages.push(msg);
+        }
+        messages
+    }
+
+    #[test]
+    fn test_frame_roundtrip_with_newlines_in_payload() {
+        let msg = "{\"type\":\"open_stdin\",\"content\":\"a\nb\n\"}";
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&encode_frame(msg).unwrap());
+        assert_eq!(decode_all(&mut decoder), vec![msg.to_string()]);
+        assert!(!decoder.is_legacy_peer());
+    }
+
+    #[test]
+    fn test_partial_frames_are_completed_byte_by_byte() {
+        let mut bytes = encode_frame("{\"type\":\"ping\"}").unwrap();
+        bytes.extend(encode_frame("{\"type\":\"detach\"}").unwrap());
+
+        let mut decoder = FrameDecoder::new();
+        let mut messages = Vec::new();
+        for byte in &bytes {
+            decoder.push(std::slice::from_ref(byte));
+            messages.extend(decode_all(&mut decoder));
+        }
+        assert_eq!(
+            messages,
+            vec!["{\"type\":\"ping\"}", "{\"type\":\"detach\"}"]
+        );
+    }
+
+    #[test]
+    fn test_split_at_every_position() {
+        let msg = "{\"type\":\"resize\",\"cols\":80,\"rows\":24}";
+        let frame = encode_frame(msg).unwrap();
+        for split in 0..=frame.len() {
+            let mut decoder = FrameDecoder::new();
+            decoder.push(&frame[..split]);
+            let mut messages = decode_all(&mut decoder);
+            decoder.push(&frame[split..]);
+            messages.extend(decode_all(&mut decoder));
+            assert_eq!(messages, vec![msg.to_string()], "split at {}", split);
+        }
+    }
+
+    #[test]
+    fn test_interleaved_messages_in_one_read() {
+        let mut bytes = Vec::new();
+        for i in 0..50 {
+            bytes.extend(encode_frame(&format!("{{\"n\":{}}}", i)).unwrap());
+        }
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&bytes);
+        let messages = decode_all(&mut decoder);
+        assert_eq!(messages.len(), 50);
+        assert_eq!(messages[49], "{\"n\":49}");
+    }
+
+    #[test]
+    fn test_oversized_length_is_rejected() {
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&((MAX_CONTROL_FRAME_LEN + 1) as u32).to_be_bytes());
+        let err = decoder.next_message().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&u32::MAX.to_be_bytes());
+        assert!(decoder.next_message().is_err());
+
+        let too_long = "x".repeat(MAX_CONTROL_FRAME_LEN + 1);
+        assert!(encode_frame(&too_long).is_err());
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_rejected() {
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&2u32.to_be_bytes());
+        decoder.push(&[0xff, 0xfe]);
+        assert!(decoder.next_message().is_err());
+    }
+
+    #[test]
+    fn test_legacy_lines_are_detected() {
+        let mut decoder = FrameDecoder::new();
+        decoder.push(b"{\"type\":\"hello\"}\r\n{\"type\":\"pi");
+        assert_eq!(decode_all(&mut decoder), vec!["{\"type\":\"hello\"}"]);
+        assert!(decoder.is_legacy_peer());
+
+        decoder.push(b"ng\"}\n");
+        assert_eq!(decode_all(&mut decoder), vec!["{\"type\":\"ping\"}"]);
+        assert_eq!(encode_line("{\"type\":\"pong\"}"), b"{\"type\":\"pong\"}\n");
+    }
+
+    #[test]
+    fn test_line_break_after_frame_is_skipped() {
+        let mut bytes = encode_frame("{\"type\":\"hello\"}").unwrap();
+        bytes.push(b'\n');
+        bytes.extend(encode_frame("{\"type\":\"quit\"}").unwrap());
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&bytes);
+        assert_eq!(
+            decode_all(&mut decoder),
+            vec!["{\"type\":\"hello\"}", "{\"type\":\"quit\"}"]
+        );
+        assert!(!decoder.is_legacy_peer());
+    }
+}
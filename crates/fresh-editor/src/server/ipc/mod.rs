@@ -4,10 +4,12 @@
 //! - Unix domain sockets on Linux/macOS
 //! - Named pipes on Windows
 //!
-//! Each session has two sockets: data (byte stream) and control (JSON messages).
+//! Each session has two sockets: data (byte stream) and control (JSON messages,
+//! length-prefixed; see [`framing`]).
 
-use std::io::{self, BufRead, BufReader, Read, Write};
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
 use interprocess::local_socket::{
@@ -16,6 +18,10 @@ use interprocess::local_socket::{
 
 use crate::workspace::encode_path_for_filename;
 
+pub mod framing;
+
+use framing::FrameDecoder;
+
 // Platform-specific implementations
 #[cfg(unix)]
 mod platform_unix;
@@ -250,6 +256,7 @@ impl ServerListener {
         Ok(Some(ServerConnection {
             data: StreamWrapper::new(data_stream),
             control: StreamWrapper::new(control_stream),
+            control_decoder: Mutex::new(FrameDecoder::new()),
         }))
     }
 
@@ -357,12 +364,83 @@ impl Read for &StreamWrapper {
     }
 }
 
+/// Read a control message, waiting for it. `None` at end of stream.
+fn read_control_message(
+    control: &StreamWrapper,
+    decoder: &Mutex<FrameDecoder>,
+) -> io::Result<Option<String>> {
+    let mut decoder = lock_decoder(decoder)?;
+    let mut reader = control;
+    let mut buf = [0u8; 4096];
+    loop {
+        if let Some(msg) = decoder.next_message()? {
+            return Ok(Some(msg));
+        }
+        let n = Read::read(&mut reader, &mut buf)?;
+        if n == 0 {
+            return Ok(None);
+        }
+        decoder.push(&buf[..n]);
+    }
+}
+
+/// Take a control message that has already arrived. `None` when there is
+/// none yet; an `UnexpectedEof` error once the peer has closed the channel.
+fn try_read_control_message(
+    control: &StreamWrapper,
+    decoder: &Mutex<FrameDecoder>,
+) -> io::Result<Option<String>> {
+    let mut decoder = lock_decoder(decoder)?;
+    let mut buf = [0u8; 4096];
+    loop {
+        if let Some(msg) = decoder.next_message()? {
+            return Ok(Some(msg));
+        }
+        match control.try_read(&mut buf) {
+            Ok(0) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "control channel closed",
+                ))
+            }
+            Ok(n) => decoder.push(&buf[..n]),
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => return Ok(None),
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Write a control message, as a line if the peer predates framing
+fn write_control_message(
+    control: &StreamWrapper,
+    decoder: &Mutex<FrameDecoder>,
+    msg: &str,
+) -> io::Result<()> {
+    let bytes = if lock_decoder(decoder)?.is_legacy_peer() {
+        framing::encode_line(msg)
+    } else {
+        framing::encode_frame(msg)?
+    };
+    control.write_all(&bytes)?;
+    control.flush()
+}
+
+fn lock_decoder(
+    decoder: &Mutex<FrameDecoder>,
+) -> io::Result<std::sync::MutexGuard<'_, FrameDecoder>> {
+    decoder
+        .lock()
+        .map_err(|_| io::Error::new(io::ErrorKind::Other, "mutex poisoned"))
+}
+
 /// A client connection (from the server's perspective)
 pub struct ServerConnection {
     /// Data stream for raw byte stream
     pub data: StreamWrapper,
     /// Control stream for JSON messages
     pub control: StreamWrapper,
+    /// Control bytes received but not yet taken as messages
+    control_decoder: Mutex<FrameDecoder>,
 }
 
 impl ServerConnection {
@@ -383,22 +461,18 @@ impl ServerConnection {
         // switching after connection. The pipe should already be in blocking mode.
         #[cfg(not(windows))]
         self.control.set_nonblocking(false)?;
-        let mut reader = BufReader::new(&self.control);
-        let mut line = String::new();
-        match reader.read_line(&mut line) {
-            Ok(0) => Ok(None), // EOF
-            Ok(_) => Ok(Some(line)),
-            Err(e) => Err(e),
-        }
+        read_control_message(&self.control, &self.control_decoder)
+    }
+
+    /// Take a control message that has already arrived (non-blocking).
+    /// Fails with `UnexpectedEof` once the client has closed the channel.
+    pub fn try_read_control(&self) -> io::Result<Option<String>> {
+        try_read_control_message(&self.control, &self.control_decoder)
     }
 
     /// Write a control message
     pub fn write_control(&self, msg: &str) -> io::Result<()> {
-        self.control.write_all(msg.as_bytes())?;
-        if !msg.ends_with('\n') {
-            self.control.write_all(b"\n")?;
-        }
-        self.control.flush()
+        write_control_message(&self.control, &self.control_decoder, msg)
     }
 }
 
@@ -408,6 +482,10 @@ pub struct ClientConnection {
     pub data: StreamWrapper,
     /// Control stream for JSON messages
     pub control: StreamWrapper,
+    /// Control bytes received but not yet taken as messages
+    control_decoder: Mutex<FrameDecoder>,
+    /// Whether a control message has been written yet
+    control_written: AtomicBool,
 }
 
 impl ClientConnection {
@@ -426,6 +504,8 @@ impl ClientConnection {
         Ok(Self {
             data: StreamWrapper::new(data),
             control: StreamWrapper::new(control),
+            control_decoder: Mutex::new(FrameDecoder::new()),
+            control_written: AtomicBool::new(false),
         })
     }
 
@@ -445,24 +525,28 @@ impl ClientConnection {
         self.data.flush()
     }
 
-    /// Read a control message
+    /// Read a control message (blocking)
     pub fn read_control(&self) -> io::Result<Option<String>> {
-        let mut reader = BufReader::new(&self.control);
-        let mut line = String::new();
-        match reader.read_line(&mut line) {
-            Ok(0) => Ok(None),
-            Ok(_) => Ok(Some(line)),
-            Err(e) => Err(e),
-        }
+        read_control_message(&self.control, &self.control_decoder)
+    }
+
+    /// Take a control message that has already arrived (non-blocking).
+    /// Fails with `UnexpectedEof` once the server has closed the channel.
+    pub fn try_read_control(&self) -> io::Result<Option<String>> {
+        try_read_control_message(&self.control, &self.control_decoder)
     }
 
     /// Write a control message
     pub fn write_control(&self, msg: &str) -> io::Result<()> {
-        self.control.write_all(msg.as_bytes())?;
-        if !msg.ends_with('\n') {
+        write_control_message(&self.control, &self.control_decoder, msg)?;
+        // End the first frame (the hello) with a line break: a server from
+        // before framing then reads it as a malformed line and turns the
+        // client away, instead of waiting for the line to end
+        if !self.control_written.swap(true, Ordering::Relaxed) {
             self.control.write_all(b"\n")?;
+            self.control.flush()?;
         }
-        self.control.flush()
+        Ok(())
     }
 
     /// Get the raw file descriptors for use with poll/select (Unix only)
@@ -24,6 +24,8 @@ mod runner;
 mod tests;
 
 pub use capture_backend::{terminal_setup_sequences, terminal_teardown_sequences, CaptureBackend};
+#[cfg(unix)]
+pub use daemon::redirect_output_to;
 pub use daemon::{
     daemonize, is_process_running, read_pid_file, spawn_server_detached, write_pid_file,
 };
@@ -3,13 +3,80 @@
 //! The protocol uses two channels:
 //! - **Data channel**: Raw bytes, no framing (stdin→server, server→stdout)
 //! - **Control channel**: JSON messages for out-of-band communication
+//!
+//! Both sides open with a hello carrying the protocol version and the
+//! optional features they support (see [`capability`]). The server turns away
+//! clients of another protocol version with a `Quit` whose reason says why.
+//! Fields and message types a side doesn't know are skipped, so either side
+//! can be extended without breaking the other.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::view::color_support::ColorCapability;
+
+use crate::server::ipc::framing::{encode_frame, FrameDecoder};
+
 /// Protocol version - must match between client and server
 pub const PROTOCOL_VERSION: u32 = 1;
 
+/// Optional features named in the hellos. A feature is used only when the
+/// client lists it and the server answers with it.
+pub mod capability {
+    /// Frames are wrapped in synchronized output markers (DEC mode 2026)
+    pub const SYNC_OUTPUT: &str = "sync_output";
+    /// Clipboard copies reach the client's terminal as OSC 52 sequences
+    pub const OSC52: &str = "osc52";
+    /// The client's terminal shows only the 256-color palette. Clients that
+    /// name no color limit get 24-bit color.
+    pub const COLOR256: &str = "color256";
+    /// The client's terminal shows only the 16 basic colors
+    pub const COLOR16: &str = "color16";
+    /// The client detaches by itself on `Ctrl+\`
+    pub const DETACH_KEY: &str = "detach_key";
+
+    /// Features the server can use
+    pub const SERVER: [&str; 5] = [SYNC_OUTPUT, OSC52, COLOR256, COLOR16, DETACH_KEY];
+}
+
+/// Features both the client and the server support
+pub fn negotiate_capabilities(client: &[String]) -> Vec<String> {
+    capability::SERVER
+        .iter()
+        .filter(|name| client.iter().any(|c| c == *name))
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Colors to draw with for a client that negotiated `capabilities`
+pub fn color_capability(capabilities: &[String]) -> ColorCapability {
+    let has = |name: &str| capabilities.iter().any(|c| c == name);
+    if has(capability::COLOR16) {
+        ColorCapability::Color16
+    } else if has(capability::COLOR256) {
+        ColorCapability::Color256
+    } else {
+        ColorCapability::TrueColor
+    }
+}
+
+/// Why a client of another protocol version can't attach
+pub fn version_mismatch_reason(hello: &ClientHello) -> String {
+    let advice = if hello.protocol_version > PROTOCOL_VERSION {
+        "Restart the session with the newer fresh to attach to it."
+    } else {
+        "Attach with the fresh the session was started with, or restart the session."
+    };
+    format!(
+        "Cannot attach: the session runs fresh {} (protocol {}) and this client is fresh {} (protocol {}). {}",
+        env!("CARGO_PKG_VERSION"),
+        PROTOCOL_VERSION,
+        hello.client_version,
+        hello.protocol_version,
+        advice
+    )
+}
+
 /// Terminal size in columns and rows
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TermSize {
@@ -35,6 +102,13 @@ pub struct ClientHello {
     /// Environment variables relevant for rendering
     /// Keys: TERM, COLORTERM, LANG, LC_ALL
     pub env: HashMap<String, Option<String>>,
+    /// Name shown on this client's cursor to the other clients of a session
+    /// (from `FRESH_CLIENT_NAME`, else the user name)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Optional features the client supports (see [`capability`])
+    #[serde(default)]
+    pub capabilities: Vec<String>,
 }
 
 impl ClientHello {
@@ -47,12 +121,29 @@ impl ClientHello {
             env.insert(key.to_string(), std::env::var(key).ok());
         }
 
-        Self {
+        let mut hello = Self {
             protocol_version: PROTOCOL_VERSION,
             client_version: env!("CARGO_PKG_VERSION").to_string(),
             term_size,
             env,
+            name: ["FRESH_CLIENT_NAME", "USER", "USERNAME"]
+                .iter()
+                .find_map(|key| std::env::var(key).ok().filter(|name| !name.is_empty())),
+            capabilities: Vec::new(),
+        };
+
+        let mut capabilities = vec![capability::SYNC_OUTPUT, capability::OSC52];
+        match ColorCapability::known_limit() {
+            Some(ColorCapability::Color256) => capabilities.push(capability::COLOR256),
+            Some(ColorCapability::Color16) => capabilities.push(capability::COLOR16),
+            Some(ColorCapability::TrueColor) | None => {}
+        }
+        // Only the Unix relay watches for the detach key
+        if cfg!(unix) {
+            capabilities.push(capability::DETACH_KEY);
         }
+        hello.capabilities = capabilities.into_iter().map(String::from).collect();
+        hello
     }
 
     /// Get the TERM value
@@ -79,6 +170,9 @@ pub struct ServerHello {
     pub server_version: String,
     /// Session identifier (encoded working directory)
     pub session_id: String,
+    /// Optional features in use for this client: the ones both sides support
+    #[serde(default)]
+    pub capabilities: Vec<String>,
 }
 
 impl ServerHello {
@@ -87,8 +181,14 @@ impl ServerHello {
             protocol_version: PROTOCOL_VERSION,
             server_version: env!("CARGO_PKG_VERSION").to_string(),
             session_id,
+            capabilities: Vec::new(),
         }
     }
+
+    /// Whether a negotiated feature is in use
+    pub fn supports(&self, capability: &str) -> bool {
+        self.capabilities.iter().any(|c| c == capability)
+    }
 }
 
 /// Version mismatch error response
@@ -121,6 +221,17 @@ pub enum ClientControl {
         #[serde(default)]
         wait: bool,
     },
+    /// Background color reported by the client's terminal (answer to `QueryBackground`)
+    BackgroundColor { r: u8, g: u8, b: u8 },
+    /// Content piped to the client's stdin (`command | fresh -`), to open in
+    /// an unnamed buffer. Invalid UTF-8 is replaced.
+    OpenStdin { content: String },
+    /// A file named to a plain `fresh` started where a session is already
+    /// running. Opened like `OpenFiles`, and the attached clients are raised.
+    OpenFile { file: FileRequest },
+    /// A message from a newer client, ignored
+    #[serde(other)]
+    Unknown,
 }
 
 /// A file to open with optional line/column position, range, and hover message
@@ -143,7 +254,8 @@ pub struct FileRequest {
 pub enum ServerControl {
     /// Handshake response
     Hello(ServerHello),
-    /// Version mismatch error
+    /// Version mismatch error, from servers before the mismatch was reported
+    /// as a `Quit`
     VersionMismatch(VersionMismatch),
     /// Keepalive pong
     Pong,
@@ -157,6 +269,13 @@ pub enum ServerControl {
     Error { message: String },
     /// Signal that a --wait operation has completed
     WaitComplete,
+    /// Ask the client to query its terminal's background color (OSC 11)
+    QueryBackground,
+    /// Ask the client to bring its terminal window to the front
+    Raise,
+    /// A message from a newer server, ignored
+    #[serde(other)]
+    Unknown,
 }
 
 /// Wrapper for control channel messages (used for JSON serialization)
@@ -167,20 +286,32 @@ pub enum ControlMessage {
     Server(ServerControl),
 }
 
-/// Read a JSON control message from a reader
-pub fn read_control_message<R: std::io::BufRead>(reader: &mut R) -> std::io::Result<String> {
-    let mut line = String::new();
-    reader.read_line(&mut line)?;
-    Ok(line)
+/// Read a framed JSON control message from a reader
+pub fn read_control_message<R: std::io::Read>(reader: &mut R) -> std::io::Result<String> {
+    let mut decoder = FrameDecoder::new();
+    let mut byte = [0u8; 1];
+    loop {
+        if let Some(msg) = decoder.next_message()? {
+            return Ok(msg);
+        }
+        // Byte by byte up to the frame's end, leaving what follows unread
+        if reader.read(&mut byte)? == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "control channel closed",
+            ));
+        }
+        decoder.push(&byte);
+    }
 }
 
-/// Write a JSON control message to a writer
+/// Write a JSON control message to a writer, framed
 pub fn write_control_message<W: std::io::Write>(
     writer: &mut W,
     msg: &impl Serialize,
 ) -> std::io::Result<()> {
     let json = serde_json::to_string(msg).map_err(|e| std::io::Error::other(e.to_string()))?;
-    writeln!(writer, "{}", json)?;
+    writer.write_all(&encode_frame(&json)?)?;
     writer.flush()
 }
 
@@ -285,6 +416,24 @@ mod tests {
                 }],
                 wait: false,
             },
+            ClientControl::BackgroundColor {
+                r: 255,
+                g: 255,
+                b: 255,
+            },
+            ClientControl::OpenStdin {
+                content: "diff --git a/x b/x\n".to_string(),
+            },
+            ClientControl::OpenFile {
+                file: FileRequest {
+                    path: "/test/main.rs".to_string(),
+                    line: Some(42),
+                    column: Some(7),
+                    end_line: None,
+                    end_column: None,
+                    message: None,
+                },
+            },
         ];
 
         for variant in variants {
@@ -309,6 +458,8 @@ mod tests {
                 message: "error".to_string(),
             },
             ServerControl::WaitComplete,
+            ServerControl::QueryBackground,
+            ServerControl::Raise,
         ];
 
         for variant in variants {
@@ -316,4 +467,121 @@ mod tests {
             let _: ServerControl = serde_json::from_str(&json).unwrap();
         }
     }
+
+    #[test]
+    fn test_hello_from_newer_client_with_extra_fields() {
+        let json = serde_json::json!({
+            "type": "hello",
+            "protocol_version": PROTOCOL_VERSION,
+            "client_version": "99.0.0",
+            "term_size": { "cols": 80, "rows": 24 },
+            "env": {},
+            "capabilities": ["sync_output", "hologram"],
+            "future_field": { "nested": true }
+        });
+        let parsed: ClientControl = serde_json::from_str(&json.to_string()).unwrap();
+        match parsed {
+            ClientControl::Hello(hello) => {
+                assert_eq!(hello.client_version, "99.0.0");
+                assert_eq!(
+                    negotiate_capabilities(&hello.capabilities),
+                    vec![capability::SYNC_OUTPUT.to_string()]
+                );
+            }
+            other => panic!("Expected Hello, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_hello_from_older_client_without_capabilities() {
+        let json = r#"{"type":"hello","protocol_version":1,"client_version":"0.1.0","term_size":{"cols":80,"rows":24},"env":{}}"#;
+        let parsed: ClientControl = serde_json::from_str(json).unwrap();
+        match parsed {
+            ClientControl::Hello(hello) => {
+                assert!(hello.capabilities.is_empty());
+                assert!(hello.name.is_none());
+            }
+            other => panic!("Expected Hello, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_color_capability_defaults_to_truecolor() {
+        let names = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(color_capability(&[]), ColorCapability::TrueColor);
+        assert_eq!(
+            color_capability(&names(&[capability::SYNC_OUTPUT])),
+            ColorCapability::TrueColor
+        );
+        assert_eq!(
+            color_capability(&names(&[capability::COLOR256])),
+            ColorCapability::Color256
+        );
+        assert_eq!(
+            color_capability(&names(&[capability::COLOR256, capability::COLOR16])),
+            ColorCapability::Color16
+        );
+
+        // An older client's hello without capabilities keeps 24-bit color
+        let json = r#"{"type":"hello","protocol_version":1,"client_version":"0.1.0","term_size":{"cols":80,"rows":24},"env":{}}"#;
+        let Ok(ClientControl::Hello(hello)) = serde_json::from_str::<ClientControl>(json) else {
+            panic!("Expected Hello");
+        };
+        assert_eq!(
+            color_capability(&negotiate_capabilities(&hello.capabilities)),
+            ColorCapability::TrueColor
+        );
+    }
+
+    #[test]
+    fn test_server_hello_roundtrip_with_capabilities() {
+        let hello = ServerHello {
+            capabilities: vec![capability::OSC52.to_string()],
+            ..ServerHello::new("session".to_string())
+        };
+        let json = serde_json::to_string(&ServerControl::Hello(hello)).unwrap();
+        match serde_json::from_str::<ServerControl>(&json).unwrap() {
+            ServerControl::Hello(parsed) => {
+                assert!(parsed.supports(capability::OSC52));
+                assert!(!parsed.supports(capability::SYNC_OUTPUT));
+            }
+            other => panic!("Expected Hello, got {:?}", other),
+        }
+
+        // A server hello without capabilities, as older servers send it
+        let json =
+            r#"{"type":"hello","protocol_version":1,"server_version":"0.1.0","session_id":"s"}"#;
+        match serde_json::from_str::<ServerControl>(json).unwrap() {
+            ServerControl::Hello(parsed) => assert!(parsed.capabilities.is_empty()),
+            other => panic!("Expected Hello, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unknown_messages_are_skipped() {
+        let client: ClientControl =
+            serde_json::from_str(r#"{"type":"teleport","where":"mars"}"#).unwrap();
+        assert!(matches!(client, ClientControl::Unknown));
+
+        let server: ServerControl =
+            serde_json::from_str(r#"{"type":"fireworks","count":3}"#).unwrap();
+        assert!(matches!(server, ServerControl::Unknown));
+
+        // Known messages still parse with fields they don't know
+        let resize: ClientControl =
+            serde_json::from_str(r#"{"type":"resize","cols":90,"rows":30,"dpi":2}"#).unwrap();
+        assert!(matches!(
+            resize,
+            ClientControl::Resize { cols: 90, rows: 30 }
+        ));
+    }
+
+    #[test]
+    fn test_version_mismatch_reason_is_readable() {
+        let mut hello = ClientHello::new(TermSize::new(80, 24));
+        hello.protocol_version = PROTOCOL_VERSION + 1;
+        let reason = version_mismatch_reason(&hello);
+        assert!(reason.contains(&format!("protocol {}", PROTOCOL_VERSION + 1)));
+        assert!(reason.contains("Restart the session"));
+    }
 }
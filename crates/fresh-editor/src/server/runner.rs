@@ -16,7 +16,7 @@ use crate::server::capture_backend::{terminal_setup_sequences, terminal_teardown
 use crate::server::input_parser::InputParser;
 use crate::server::ipc::{ServerConnection, ServerListener, SocketPaths};
 use crate::server::protocol::{
-    ClientControl, ServerControl, ServerHello, TermSize, VersionMismatch, PROTOCOL_VERSION,
+    version_mismatch_reason, ClientControl, ServerControl, ServerHello, TermSize, PROTOCOL_VERSION,
 };
 use crossterm::event::Event;
 
@@ -173,22 +173,10 @@ impl Server {
 
         // Check protocol version
         if hello.protocol_version != PROTOCOL_VERSION {
-            let mismatch = VersionMismatch {
-                server_version: env!("CARGO_PKG_VERSION").to_string(),
-                client_version: hello.client_version.clone(),
-                action: if hello.protocol_version > PROTOCOL_VERSION {
-                    "upgrade_server".to_string()
-                } else {
-                    "restart_server".to_string()
-                },
-                message: format!(
-                    "Protocol version mismatch: server={}, client={}",
-                    PROTOCOL_VERSION, hello.protocol_version
-                ),
-            };
-
-            let response = serde_json::to_string(&ServerControl::VersionMismatch(mismatch))
-                .map_err(|e| io::Error::other(e.to_string()))?;
+            let response = serde_json::to_string(&ServerControl::Quit {
+                reason: version_mismatch_reason(&hello),
+            })
+            .map_err(|e| io::Error::other(e.to_string()))?;
             conn.write_control(&response)?;
 
             return Err(io::Error::other("Version mismatch"));
@@ -278,41 +266,27 @@ impl Server {
                 }
             }
 
-            // Try to read a control message (non-blocking)
-            // Use try_read which internally handles non-blocking mode and Windows pipe quirks
-            let mut buf = [0u8; 4096];
-            match client.conn.control.try_read(&mut buf) {
-                Ok(0) => {
-                    // On Windows, Ok(0) in non-blocking mode might mean "no data"
-                    // Only treat as EOF if we're sure the pipe is closed
-                    #[cfg(not(windows))]
-                    {
-                        // On Unix, Ok(0) means EOF
+            // Take the control messages that have arrived (non-blocking)
+            loop {
+                match client.conn.try_read_control() {
+                    Ok(Some(line)) => {
+                        if let Ok(msg) = serde_json::from_str::<ClientControl>(&line) {
+                            control_messages.push((idx, msg));
+                        }
+                    }
+                    Ok(None) => break,
+                    Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                        // On Windows, a pipe can look closed while it isn't;
+                        // actual disconnection shows when a write fails
+                        #[cfg(not(windows))]
                         disconnected.push(idx);
+                        break;
                     }
-                    // On Windows, just ignore - we'll detect actual disconnection
-                    // when write fails or we get a real error
-                }
-                Ok(n) => {
-                    // Parse the bytes as a line (looking for newline)
-                    let data = &buf[..n];
-                    if let Ok(text) = std::str::from_utf8(data) {
-                        for line in text.lines() {
-                            let line = line.trim();
-                            if !line.is_empty() {
-                                if let Ok(msg) = serde_json::from_str::<ClientControl>(line) {
-                                    control_messages.push((idx, msg));
-                                }
-                            }
-                        }
+                    Err(e) => {
+                        tracing::warn!("Client {} control read error: {}", client.id, e);
+                        break;
                     }
                 }
-                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
-                    // No message available
-                }
-                Err(e) => {
-                    tracing::warn!("Client {} control read error: {}", client.id, e);
-                }
             }
         }
 
@@ -369,13 +343,16 @@ impl Server {
                 // TODO: Check for unsaved changes, prompt, etc.
                 self.shutdown.store(true, Ordering::SeqCst);
             }
-            ClientControl::OpenFiles { .. } => {
+            ClientControl::OpenFiles { .. }
+            | ClientControl::OpenStdin { .. }
+            | ClientControl::OpenFile { .. } => {
                 // This runner doesn't have an editor, so we can't open files
                 tracing::warn!(
-                    "Client {} sent OpenFiles but no editor is running",
+                    "Client {} sent files to open but no editor is running",
                     client.id
                 );
             }
+            ClientControl::BackgroundColor { .. } | ClientControl::Unknown => {}
         }
         Ok(())
     }
@@ -152,7 +152,16 @@ mod integration_tests {
         let response = conn.read_control().unwrap().unwrap();
         let server_msg: ServerControl = serde_json::from_str(&response).unwrap();
 
-        assert!(matches!(server_msg, ServerControl::VersionMismatch(_)));
+        match server_msg {
+            ServerControl::Quit { reason } => {
+                assert!(
+                    reason.contains("protocol 999"),
+                    "unreadable reason: {}",
+                    reason
+                );
+            }
+            other => panic!("Expected Quit, got {:?}", other),
+        }
 
         shutdown.store(true, Ordering::SeqCst);
         drop(server_handle.join());
@@ -594,6 +603,8 @@ mod integration_tests {
             editor_config: config,
             dir_context,
             plugins_enabled: false,
+            workspace_enabled: false,
+            log_path: None,
         };
 
         let (paths_tx, paths_rx) = mpsc::channel();
@@ -759,6 +770,8 @@ mod integration_tests {
             editor_config: config,
             dir_context,
             plugins_enabled: false,
+            workspace_enabled: false,
+            log_path: None,
         };
 
         let (paths_tx, paths_rx) = mpsc::channel();
@@ -911,7 +924,21 @@ mod integration_tests {
         SocketPaths,
         std::path::PathBuf,
     ) {
-        use crate::config::Config;
+        setup_editor_server_e2e_with_config(test_name, crate::config::Config::default())
+    }
+
+    /// Like `setup_editor_server_e2e`, with the given editor config
+    fn setup_editor_server_e2e_with_config(
+        test_name: &str,
+        config: crate::config::Config,
+    ) -> (
+        ClientConnection,
+        Vec<u8>,
+        std::sync::Arc<std::sync::atomic::AtomicBool>,
+        thread::JoinHandle<std::io::Result<()>>,
+        SocketPaths,
+        std::path::PathBuf,
+    ) {
         use crate::config_io::DirectoryContext;
         use crate::server::editor_server::{EditorServer, EditorServerConfig};
         use std::sync::mpsc;
@@ -921,7 +948,6 @@ mod integration_tests {
         std::fs::create_dir_all(&temp_dir).unwrap();
 
         let session_name = unique_session_name(test_name);
-        let config = Config::default();
         let dir_context = DirectoryContext::for_testing(&temp_dir);
 
         let server_config = EditorServerConfig {
@@ -931,6 +957,8 @@ mod integration_tests {
             editor_config: config,
             dir_context,
             plugins_enabled: false,
+            workspace_enabled: false,
+            log_path: None,
         };
 
         let (paths_tx, paths_rx) = mpsc::channel();
@@ -1016,6 +1044,99 @@ mod integration_tests {
         result
     }
 
+    /// A file forwarded by a second `fresh` opens at its position, and the
+    /// attached client is asked to raise its terminal
+    #[test]
+    fn test_open_file_raises_attached_client() {
+        use crate::server::protocol::FileRequest;
+
+        let (conn, mut output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e("open-file");
+
+        let path = temp_dir.join("forwarded.txt");
+        let content: String = (1..=60).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(&path, content.replace("line 42\n", "FORWARDED_42\n")).unwrap();
+
+        // The forwarding client hands over the file and goes away
+        let forwarder = ClientConnection::connect(&socket_paths).expect("Failed to connect");
+        let hello = ClientHello::new(TermSize::new(80, 24));
+        forwarder
+            .write_control(&serde_json::to_string(&ClientControl::Hello(hello)).unwrap())
+            .unwrap();
+        forwarder.read_control().unwrap().unwrap();
+        let open = ClientControl::OpenFile {
+            file: FileRequest {
+                path: path.to_string_lossy().to_string(),
+                line: Some(42),
+                column: Some(1),
+                end_line: None,
+                end_column: None,
+                message: None,
+            },
+        };
+        forwarder
+            .write_control(&serde_json::to_string(&open).unwrap())
+            .unwrap();
+        drop(forwarder);
+
+        loop {
+            let msg = conn
+                .read_control()
+                .unwrap()
+                .expect("Server closed connection");
+            if matches!(
+                serde_json::from_str::<ServerControl>(&msg).unwrap(),
+                ServerControl::Raise
+            ) {
+                break;
+            }
+        }
+        // Line 42 is scrolled into view
+        read_until_contains(&conn, &mut output, "FORWARDED_42");
+
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
+    /// The server runs the `auto_save` timers like a local editor
+    #[test]
+    fn test_auto_save_after_delay_in_session() {
+        use crate::config::{AutoSaveMode, Config};
+        use crate::server::protocol::FileRequest;
+
+        let mut config = Config::default();
+        config.editor.auto_save = AutoSaveMode::AfterDelay;
+        config.editor.auto_save_delay_ms = 50;
+        let (conn, mut output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e_with_config("auto-save", config);
+
+        let path = temp_dir.join("notes.txt");
+        std::fs::write(&path, "ORIGINAL\n").unwrap();
+        let open = ClientControl::OpenFiles {
+            files: vec![FileRequest {
+                path: path.to_string_lossy().to_string(),
+                line: None,
+                column: None,
+                end_line: None,
+                end_column: None,
+                message: None,
+            }],
+            wait: false,
+        };
+        conn.write_control(&serde_json::to_string(&open).unwrap())
+            .unwrap();
+        read_until_contains(&conn, &mut output, "ORIGINAL");
+
+        conn.write_data(b"EDITED ").unwrap();
+        read_until_contains(&conn, &mut output, "EDITED");
+
+        // No save command: the idle delay alone writes the file
+        while std::fs::read_to_string(&path).unwrap() != "EDITED ORIGINAL\n" {
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
     /// E2E regression test for issue #1089:
     /// ESC followed by mouse event should NOT insert mouse codes as text.
     ///
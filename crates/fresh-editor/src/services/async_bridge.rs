@@ -13,9 +13,9 @@
 use crate::services::terminal::TerminalId;
 use crate::view::file_tree::{FileTreeView, NodeId};
 use lsp_types::{
-    CodeActionOrCommand, CompletionItem, Diagnostic, FoldingRange, InlayHint, Location,
-    SemanticTokensFullDeltaResult, SemanticTokensLegend, SemanticTokensRangeResult,
-    SemanticTokensResult, SignatureHelp,
+    CodeActionOrCommand, CompletionItem, Diagnostic, DocumentSymbolResponse, FoldingRange,
+    InlayHint, Location, SelectionRange, SemanticTokensFullDeltaResult, SemanticTokensLegend,
+    SemanticTokensRangeResult, SemanticTokensResult, SignatureHelp,
 };
 use serde_json::Value;
 use std::sync::mpsc;
@@ -52,6 +52,8 @@ pub enum AsyncMessage {
         semantic_tokens_range: bool,
         /// Whether the server supports folding ranges
         folding_ranges_supported: bool,
+        /// Whether the server supports selection ranges
+        selection_ranges_supported: bool,
     },
 
     /// LSP server crashed or failed
@@ -62,6 +64,13 @@ pub enum AsyncMessage {
         stderr_log_path: Option<std::path::PathBuf>,
     },
 
+    /// LSP server grew past its memory limit and is being stopped
+    LspMemoryLimitExceeded {
+        language: String,
+        rss_bytes: u64,
+        limit_bytes: u64,
+    },
+
     /// LSP completion response
     LspCompletion {
         request_id: u64,
@@ -137,6 +146,20 @@ pub enum AsyncMessage {
         ranges: Vec<FoldingRange>,
     },
 
+    /// LSP document symbols response (textDocument/documentSymbol)
+    LspDocumentSymbols {
+        request_id: u64,
+        /// None if the server has no symbols or the request failed
+        symbols: Option<DocumentSymbolResponse>,
+    },
+
+    /// LSP selection ranges response (textDocument/selectionRange), one
+    /// chain per requested position; empty if the request failed
+    LspSelectionRanges {
+        request_id: u64,
+        ranges: Vec<SelectionRange>,
+    },
+
     /// LSP semantic tokens response (full, full/delta, or range)
     LspSemanticTokens {
         request_id: u64,
@@ -163,6 +186,15 @@ pub enum AsyncMessage {
     /// File explorer node refresh completed
     FileExplorerRefreshNode(NodeId),
 
+    /// File explorer directory listing completed
+    ///
+    /// Sent when a directory finishes loading after being expanded, and when
+    /// an expanded directory is re-read because it changed on disk.
+    FileExplorerDirLoaded {
+        node_id: NodeId,
+        result: std::io::Result<Vec<crate::services::fs::DirEntry>>,
+    },
+
     /// File explorer expand to path completed
     /// Contains the updated FileTreeView with the path expanded and selected
     FileExplorerExpandedToPath(FileTreeView),
@@ -366,6 +398,7 @@ mod tests {
                 semantic_tokens_full_delta: false,
                 semantic_tokens_range: false,
                 folding_ranges_supported: false,
+                selection_ranges_supported: false,
             })
             .unwrap();
 
@@ -401,6 +434,7 @@ mod tests {
                 semantic_tokens_full_delta: false,
                 semantic_tokens_range: false,
                 folding_ranges_supported: false,
+                selection_ranges_supported: false,
             })
             .unwrap();
         sender
@@ -412,6 +446,7 @@ mod tests {
                 semantic_tokens_full_delta: false,
                 semantic_tokens_range: false,
                 folding_ranges_supported: false,
+                selection_ranges_supported: false,
             })
             .unwrap();
 
@@ -445,6 +480,7 @@ mod tests {
                 semantic_tokens_full_delta: false,
                 semantic_tokens_range: false,
                 folding_ranges_supported: false,
+                selection_ranges_supported: false,
             })
             .unwrap();
         sender2
@@ -456,6 +492,7 @@ mod tests {
                 semantic_tokens_full_delta: false,
                 semantic_tokens_range: false,
                 folding_ranges_supported: false,
+                selection_ranges_supported: false,
             })
             .unwrap();
 
@@ -559,6 +596,7 @@ mod tests {
                 semantic_tokens_full_delta: false,
                 semantic_tokens_range: false,
                 folding_ranges_supported: false,
+                selection_ranges_supported: false,
             })
             .unwrap();
 
@@ -581,6 +619,7 @@ mod tests {
                 semantic_tokens_full_delta: false,
                 semantic_tokens_range: false,
                 folding_ranges_supported: false,
+                selection_ranges_supported: false,
             })
             .unwrap();
 
@@ -608,6 +647,7 @@ mod tests {
                 semantic_tokens_full_delta: false,
                 semantic_tokens_range: false,
                 folding_ranges_supported: false,
+                selection_ranges_supported: false,
             })
             .unwrap();
         sender
@@ -619,6 +659,7 @@ mod tests {
                 semantic_tokens_full_delta: false,
                 semantic_tokens_range: false,
                 folding_ranges_supported: false,
+                selection_ranges_supported: false,
             })
             .unwrap();
         sender
@@ -630,6 +671,7 @@ mod tests {
                 semantic_tokens_full_delta: false,
                 semantic_tokens_range: false,
                 folding_ranges_supported: false,
+                selection_ranges_supported: false,
             })
             .unwrap();
 
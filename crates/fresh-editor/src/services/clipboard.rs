@@ -2,14 +2,20 @@
 //!
 //! This module provides a unified clipboard interface that:
 //! - Maintains an internal clipboard for in-editor copy/paste
-//! - Uses crossterm's OSC 52 escape sequences for copying to system clipboard
+//! - Walks a chain of providers (native OS clipboard, OSC 52, internal) for
+//!   each copy and paste, so one failing provider only affects that operation
+//! - Uses crossterm's OSC 52 escape sequences for copying to system clipboard,
+//!   handing them to the client terminal in session mode
 //! - Uses arboard crate for reading from system clipboard
 //! - Supports copying HTML-formatted text for rich text editors
 //! - Gracefully falls back to internal clipboard if system clipboard is unavailable
 //! - Respects clipboard configuration to disable problematic methods
+//! - Keeps a bounded history of copied texts for "Paste from History"
 
+use crate::config::ClipboardProvider;
 use crossterm::clipboard::CopyToClipboard;
-use crossterm::execute;
+use crossterm::{execute, Command};
+use std::collections::VecDeque;
 use std::io::{stdout, Write};
 use std::sync::Mutex;
 
@@ -17,17 +23,35 @@ use std::sync::Mutex;
 /// On X11, the clipboard owner must stay alive to respond to paste requests from other apps.
 static SYSTEM_CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
 
+/// Default number of entries kept in the clipboard history
+const DEFAULT_HISTORY_SIZE: usize = 20;
+
 /// Clipboard manager that handles both internal and system clipboard
 #[derive(Debug, Clone, Default)]
 pub struct Clipboard {
     /// Internal clipboard content (always available)
     internal: String,
+    /// Previously copied texts, newest first, without duplicates
+    history: VecDeque<String>,
+    /// Maximum number of entries in `history`
+    history_size: usize,
     /// When true, paste() uses internal clipboard only (for testing)
     internal_only: bool,
     /// When true, OSC 52 escape sequences are used for clipboard copy
     use_osc52: bool,
     /// When true, system clipboard (arboard/X11/Wayland) is used for copy/paste
     use_system_clipboard: bool,
+    /// Configured provider, `Auto` to pick from the environment
+    provider: ClipboardProvider,
+    /// Providers tried in order for each operation (never contains `Auto`)
+    chain: Vec<ClipboardProvider>,
+    /// When true, OSC 52 sequences are queued for the client terminal
+    /// instead of being written to stdout (session mode)
+    passthrough: bool,
+    /// OSC 52 sequences waiting to be sent to the client terminal
+    pending_passthrough: String,
+    /// Set when the last native clipboard access failed
+    native_failed: bool,
 }
 
 impl Clipboard {
@@ -35,10 +59,23 @@ impl Clipboard {
     pub fn new() -> Self {
         Self {
             internal: String::new(),
+            history: VecDeque::new(),
+            history_size: DEFAULT_HISTORY_SIZE,
             internal_only: false,
             use_osc52: true,
             use_system_clipboard: true,
+            provider: ClipboardProvider::Auto,
+            chain: Vec::new(),
+            passthrough: false,
+            pending_passthrough: String::new(),
+            native_failed: false,
         }
+        .with_chain()
+    }
+
+    fn with_chain(mut self) -> Self {
+        self.rebuild_chain();
+        self
     }
 
     /// Update clipboard configuration from editor config.
@@ -46,6 +83,53 @@ impl Clipboard {
     pub fn apply_config(&mut self, config: &crate::config::ClipboardConfig) {
         self.use_osc52 = config.use_osc52;
         self.use_system_clipboard = config.use_system_clipboard;
+        self.provider = config.provider;
+        self.history_size = config.history_size;
+        self.history.truncate(self.history_size);
+        self.rebuild_chain();
+    }
+
+    /// Route OSC 52 sequences to the client terminal (session mode)
+    ///
+    /// In session mode stdout is not the user's terminal, so the sequences
+    /// are queued and picked up with `take_passthrough_sequences`.
+    pub fn set_passthrough(&mut self, enabled: bool) {
+        self.passthrough = enabled;
+        self.rebuild_chain();
+    }
+
+    /// Take the OSC 52 sequences queued for the client terminal
+    pub fn take_passthrough_sequences(&mut self) -> String {
+        std::mem::take(&mut self.pending_passthrough)
+    }
+
+    /// Providers tried in order for each copy and paste
+    pub fn chain(&self) -> &[ClipboardProvider] {
+        &self.chain
+    }
+
+    /// True when only the internal clipboard is usable, so text copied in
+    /// other applications can't be pasted
+    pub fn internal_fallback_only(&self) -> bool {
+        self.chain.iter().all(|provider| match provider {
+            ClipboardProvider::Native => self.native_failed,
+            ClipboardProvider::Internal => true,
+            ClipboardProvider::Auto | ClipboardProvider::Osc52 => false,
+        })
+    }
+
+    fn rebuild_chain(&mut self) {
+        let remote = self.passthrough
+            || std::env::var_os("SSH_CONNECTION").is_some()
+            || std::env::var_os("SSH_TTY").is_some();
+        self.chain = provider_chain(
+            self.provider,
+            self.use_osc52,
+            self.use_system_clipboard,
+            native_display_available(),
+            remote,
+        );
+        self.native_failed = false;
     }
 
     /// Enable internal-only mode (for testing)
@@ -61,8 +145,9 @@ impl Clipboard {
     /// Returns true if successful, false otherwise.
     pub fn copy_html(&mut self, html: &str, plain_text: &str) -> bool {
         self.internal = plain_text.to_string();
+        self.push_history(plain_text.to_string());
 
-        if !self.use_system_clipboard {
+        if !self.chain.contains(&ClipboardProvider::Native) {
             return false;
         }
 
@@ -73,6 +158,7 @@ impl Clipboard {
                     Ok(cb) => *guard = Some(cb),
                     Err(e) => {
                         tracing::debug!("arboard clipboard init failed for HTML: {}", e);
+                        self.native_failed = true;
                         return false;
                     }
                 }
@@ -95,71 +181,117 @@ impl Clipboard {
         false
     }
 
-    /// Copy text to both internal and system clipboard
+    /// Copy text to the internal clipboard and the provider chain
     ///
-    /// Tries multiple methods to maximize compatibility:
-    /// 1. OSC 52 escape sequence (works in Konsole, Kitty, Alacritty, Wezterm, xterm, iTerm2)
-    /// 2. arboard crate (works via X11/Wayland APIs in Gnome Console, XFCE Terminal, etc.)
-    ///
-    /// Methods can be disabled via clipboard configuration.
+    /// Providers are tried in chain order until one confirms the write:
+    /// - Native: arboard (X11/Wayland, macOS, Windows); confirms on success
+    /// - OSC 52: escape sequence for the terminal (Konsole, Kitty, Alacritty,
+    ///   Wezterm, xterm, iTerm2); the terminal never acknowledges it, so the
+    ///   next provider is still tried
+    /// - Internal: always confirms
     pub fn copy(&mut self, text: String) {
         self.internal = text.clone();
+        self.push_history(text.clone());
+
+        for provider in self.chain.clone() {
+            let confirmed = match provider {
+                ClipboardProvider::Native => self.write_native(&text),
+                ClipboardProvider::Osc52 => {
+                    self.write_osc52(&text);
+                    false
+                }
+                ClipboardProvider::Internal | ClipboardProvider::Auto => true,
+            };
+            if confirmed {
+                break;
+            }
+        }
+    }
 
-        // Try OSC 52 first (works in modern terminals)
-        // Note: This doesn't "fail" in a detectable way - it just sends escape sequences
-        // that the terminal may or may not handle
-        if self.use_osc52 {
-            let osc52_result = execute!(stdout(), CopyToClipboard::to_clipboard_from(&text));
-            if let Err(e) = &osc52_result {
-                tracing::debug!("Crossterm OSC 52 clipboard copy failed: {}", e);
+    /// Send an OSC 52 copy sequence to the terminal
+    ///
+    /// Note: This doesn't "fail" in a detectable way - it just sends escape
+    /// sequences that the terminal may or may not handle
+    fn write_osc52(&mut self, text: &str) {
+        let command = CopyToClipboard::to_clipboard_from(text);
+        if self.passthrough {
+            if let Err(e) = command.write_ansi(&mut self.pending_passthrough) {
+                tracing::debug!("OSC 52 sequence encoding failed: {}", e);
             }
-            // Best-effort flush — if stdout is broken, we can't recover.
-            #[allow(clippy::let_underscore_must_use)]
-            let _ = stdout().flush();
+            return;
         }
 
-        // Also try arboard (works via X11/Wayland in terminals without OSC 52 support)
-        // This provides coverage for Gnome Console, XFCE Terminal, and similar
-        //
-        // Important: On X11, the clipboard owner must stay alive to respond to paste requests.
-        // We store the clipboard in a static so it lives for the application lifetime.
-        if self.use_system_clipboard {
-            if let Ok(mut guard) = SYSTEM_CLIPBOARD.lock() {
-                // Create clipboard if it doesn't exist yet
-                if guard.is_none() {
-                    match arboard::Clipboard::new() {
-                        Ok(cb) => *guard = Some(cb),
-                        Err(e) => {
-                            tracing::debug!("arboard clipboard init failed: {}", e);
-                        }
-                    }
+        if let Err(e) = execute!(stdout(), command) {
+            tracing::debug!("Crossterm OSC 52 clipboard copy failed: {}", e);
+        }
+        // Best-effort flush — if stdout is broken, we can't recover.
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = stdout().flush();
+    }
+
+    /// Write text to the OS clipboard, returning whether it succeeded
+    ///
+    /// Important: On X11, the clipboard owner must stay alive to respond to paste requests.
+    /// We store the clipboard in a static so it lives for the application lifetime.
+    fn write_native(&mut self, text: &str) -> bool {
+        let Ok(mut guard) = SYSTEM_CLIPBOARD.lock() else {
+            return false;
+        };
+
+        // Create clipboard if it doesn't exist yet
+        if guard.is_none() {
+            match arboard::Clipboard::new() {
+                Ok(cb) => *guard = Some(cb),
+                Err(e) => {
+                    tracing::debug!("arboard clipboard init failed: {}", e);
                 }
+            }
+        }
 
-                // Try to set text on the clipboard
-                if let Some(clipboard) = guard.as_mut() {
-                    if let Err(e) = clipboard.set_text(&text) {
-                        tracing::debug!("arboard copy failed: {}, recreating clipboard", e);
-                        // If set_text fails, try recreating the clipboard
-                        drop(guard);
-                        if let Ok(mut guard) = SYSTEM_CLIPBOARD.lock() {
-                            if let Ok(new_clipboard) = arboard::Clipboard::new() {
-                                *guard = Some(new_clipboard);
-                                if let Some(cb) = guard.as_mut() {
-                                    // Best-effort retry; first attempt already failed.
-                                    #[allow(clippy::let_underscore_must_use)]
-                                    let _ = cb.set_text(&text);
-                                }
-                            }
-                        }
+        let mut written = false;
+        if let Some(clipboard) = guard.as_mut() {
+            match clipboard.set_text(text) {
+                Ok(()) => written = true,
+                Err(e) => {
+                    tracing::debug!("arboard copy failed: {}, recreating clipboard", e);
+                    // If set_text fails, try recreating the clipboard
+                    if let Ok(mut new_clipboard) = arboard::Clipboard::new() {
+                        written = new_clipboard.set_text(text).is_ok();
+                        *guard = Some(new_clipboard);
                     }
                 }
             }
         }
+
+        self.native_failed = !written;
+        written
+    }
+
+    /// Read text from the OS clipboard
+    fn read_native(&mut self) -> Option<String> {
+        let mut guard = SYSTEM_CLIPBOARD.lock().ok()?;
+
+        // Create clipboard if it doesn't exist yet
+        if guard.is_none() {
+            match arboard::Clipboard::new() {
+                Ok(cb) => *guard = Some(cb),
+                Err(e) => {
+                    tracing::debug!("arboard clipboard init failed: {}", e);
+                    self.native_failed = true;
+                    return None;
+                }
+            }
+        }
+
+        let text = guard.as_mut()?.get_text();
+        self.native_failed = text.is_err();
+        text.ok().filter(|text| !text.is_empty())
     }
 
-    /// Get text from clipboard, preferring system clipboard
+    /// Get text from the first provider in the chain that has some
     ///
-    /// Tries system clipboard first, falls back to internal clipboard.
+    /// OSC 52 can't be read (terminals answer queries asynchronously, if at
+    /// all), so it is skipped; the internal clipboard is the last resort.
     /// If internal_only mode is enabled (for testing), skips system clipboard.
     pub fn paste(&mut self) -> Option<String> {
         // In internal-only mode, skip system clipboard entirely
@@ -167,33 +299,25 @@ impl Clipboard {
             return self.paste_internal();
         }
 
-        // Try arboard crate via the static clipboard (reads from system clipboard)
-        if self.use_system_clipboard {
-            if let Ok(mut guard) = SYSTEM_CLIPBOARD.lock() {
-                // Create clipboard if it doesn't exist yet
-                if guard.is_none() {
-                    if let Ok(cb) = arboard::Clipboard::new() {
-                        *guard = Some(cb);
-                    }
-                }
-
-                if let Some(clipboard) = guard.as_mut() {
-                    if let Ok(text) = clipboard.get_text() {
-                        if !text.is_empty() {
-                            self.internal = text.clone();
-                            return Some(text);
+        for provider in self.chain.clone() {
+            match provider {
+                ClipboardProvider::Native => {
+                    if let Some(text) = self.read_native() {
+                        // Text copied in another application joins the history
+                        if text != self.internal {
+                            self.push_history(text.clone());
                         }
+                        self.internal = text.clone();
+                        return Some(text);
                     }
                 }
+                ClipboardProvider::Osc52 => {}
+                ClipboardProvider::Internal | ClipboardProvider::Auto => break,
             }
         }
 
         // Fall back to internal clipboard
-        if self.internal.is_empty() {
-            None
-        } else {
-            Some(self.internal.clone())
-        }
+        self.paste_internal()
     }
 
     /// Get the internal clipboard content without checking system clipboard
@@ -216,6 +340,24 @@ impl Clipboard {
         }
     }
 
+    /// Previously copied texts, newest first
+    pub fn history(&self) -> &VecDeque<String> {
+        &self.history
+    }
+
+    /// Add a copied text to the front of the history
+    ///
+    /// An equal entry further back is moved to the front rather than kept
+    /// twice, and the oldest entries are dropped beyond `history_size`.
+    fn push_history(&mut self, text: String) {
+        if text.is_empty() {
+            return;
+        }
+        self.history.retain(|entry| *entry != text);
+        self.history.push_front(text);
+        self.history.truncate(self.history_size);
+    }
+
     /// Check if clipboard is empty (checks both internal and system)
     pub fn is_empty(&self) -> bool {
         if !self.internal.is_empty() {
@@ -223,7 +365,7 @@ impl Clipboard {
         }
 
         // Check system clipboard via the static clipboard
-        if self.use_system_clipboard {
+        if self.chain.contains(&ClipboardProvider::Native) {
             if let Ok(mut guard) = SYSTEM_CLIPBOARD.lock() {
                 if guard.is_none() {
                     if let Ok(cb) = arboard::Clipboard::new() {
@@ -243,6 +385,49 @@ impl Clipboard {
     }
 }
 
+/// Whether the OS clipboard can be reached
+///
+/// On Linux and the BSDs this needs an X11 or Wayland display, which SSH
+/// sessions and consoles usually lack.
+fn native_display_available() -> bool {
+    if cfg!(any(target_os = "macos", target_os = "windows")) {
+        return true;
+    }
+    std::env::var_os("DISPLAY").is_some() || std::env::var_os("WAYLAND_DISPLAY").is_some()
+}
+
+/// Order in which clipboard providers are tried
+///
+/// `Auto` prefers the native clipboard locally and OSC 52 when the user's
+/// terminal is on another machine (SSH or a client-server session), and
+/// leaves out providers that are disabled or unreachable. An explicit
+/// provider is used on its own. The internal clipboard always comes last.
+fn provider_chain(
+    setting: ClipboardProvider,
+    use_osc52: bool,
+    use_system_clipboard: bool,
+    native_available: bool,
+    remote: bool,
+) -> Vec<ClipboardProvider> {
+    let mut chain = match setting {
+        ClipboardProvider::Auto => {
+            let native =
+                (use_system_clipboard && native_available).then_some(ClipboardProvider::Native);
+            let osc52 = use_osc52.then_some(ClipboardProvider::Osc52);
+            let order = if remote {
+                [osc52, native]
+            } else {
+                [native, osc52]
+            };
+            order.into_iter().flatten().collect()
+        }
+        ClipboardProvider::Native | ClipboardProvider::Osc52 => vec![setting],
+        ClipboardProvider::Internal => Vec::new(),
+    };
+    chain.push(ClipboardProvider::Internal);
+    chain
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -267,8 +452,10 @@ mod tests {
     fn test_clipboard_config_disables_osc52() {
         let mut clipboard = Clipboard::new();
         let config = crate::config::ClipboardConfig {
+            provider: ClipboardProvider::Auto,
             use_osc52: false,
             use_system_clipboard: true,
+            history_size: 20,
         };
         clipboard.apply_config(&config);
         assert!(!clipboard.use_osc52);
@@ -279,8 +466,10 @@ mod tests {
     fn test_clipboard_config_disables_system() {
         let mut clipboard = Clipboard::new();
         let config = crate::config::ClipboardConfig {
+            provider: ClipboardProvider::Auto,
             use_osc52: true,
             use_system_clipboard: false,
+            history_size: 20,
         };
         clipboard.apply_config(&config);
         assert!(clipboard.use_osc52);
@@ -291,12 +480,115 @@ mod tests {
     fn test_clipboard_internal_only_mode() {
         let mut clipboard = Clipboard::new();
         let config = crate::config::ClipboardConfig {
+            provider: ClipboardProvider::Auto,
             use_osc52: false,
             use_system_clipboard: false,
+            history_size: 20,
         };
         clipboard.apply_config(&config);
 
         clipboard.copy("internal only".to_string());
         assert_eq!(clipboard.get_internal(), "internal only");
     }
+    #[test]
+    fn test_clipboard_history_dedup_and_bound() {
+        let mut clipboard = Clipboard::new();
+        let config = crate::config::ClipboardConfig {
+            provider: ClipboardProvider::Auto,
+            use_osc52: false,
+            use_system_clipboard: false,
+            history_size: 3,
+        };
+        clipboard.apply_config(&config);
+
+        for text in ["a", "b", "c", "a", "d"] {
+            clipboard.copy(text.to_string());
+        }
+        // "a" moved to the front when copied again, "b" fell off the end
+        assert_eq!(clipboard.history(), &["d", "a", "c"]);
+        assert_eq!(clipboard.get_internal(), "d");
+    }
+
+    #[test]
+    fn test_provider_chain_auto_local_prefers_native() {
+        use ClipboardProvider::*;
+        assert_eq!(
+            provider_chain(Auto, true, true, true, false),
+            vec![Native, Osc52, Internal]
+        );
+        // No display: native is skipped
+        assert_eq!(
+            provider_chain(Auto, true, true, false, false),
+            vec![Osc52, Internal]
+        );
+    }
+
+    #[test]
+    fn test_provider_chain_auto_remote_prefers_osc52() {
+        use ClipboardProvider::*;
+        assert_eq!(
+            provider_chain(Auto, true, true, true, true),
+            vec![Osc52, Native, Internal]
+        );
+        assert_eq!(
+            provider_chain(Auto, false, true, false, true),
+            vec![Internal]
+        );
+    }
+
+    #[test]
+    fn test_provider_chain_override() {
+        use ClipboardProvider::*;
+        assert_eq!(
+            provider_chain(Osc52, false, true, true, false),
+            vec![Osc52, Internal]
+        );
+        assert_eq!(
+            provider_chain(Native, true, false, false, true),
+            vec![Native, Internal]
+        );
+        assert_eq!(
+            provider_chain(Internal, true, true, true, false),
+            vec![Internal]
+        );
+    }
+
+    #[test]
+    fn test_internal_fallback_only() {
+        let mut clipboard = Clipboard::new();
+        let config = crate::config::ClipboardConfig {
+            provider: ClipboardProvider::Internal,
+            use_osc52: true,
+            use_system_clipboard: true,
+            history_size: 20,
+        };
+        clipboard.apply_config(&config);
+        assert!(clipboard.internal_fallback_only());
+
+        let config = crate::config::ClipboardConfig {
+            provider: ClipboardProvider::Osc52,
+            ..config
+        };
+        clipboard.apply_config(&config);
+        assert!(!clipboard.internal_fallback_only());
+    }
+
+    #[test]
+    fn test_passthrough_queues_osc52_sequence() {
+        let mut clipboard = Clipboard::new();
+        let config = crate::config::ClipboardConfig {
+            provider: ClipboardProvider::Osc52,
+            use_osc52: true,
+            use_system_clipboard: false,
+            history_size: 20,
+        };
+        clipboard.apply_config(&config);
+        clipboard.set_passthrough(true);
+
+        clipboard.copy("hi".to_string());
+        let sequences = clipboard.take_passthrough_sequences();
+        assert!(sequences.starts_with("\x1b]52;"));
+        assert!(clipboard.take_passthrough_sequences().is_empty());
+        assert_eq!(clipboard.get_internal(), "hi");
+    }
 }
@@ -0,0 +1,325 @@
+//! In-memory index of the files in a workspace
+//!
+//! Quick Open lists files from here instead of running `git ls-files` or
+//! `find`. The index is built by a background walk of the working directory
+//! that respects .gitignore (so untracked files are included), and the editor
+//! keeps it current as it saves, creates, renames and deletes files. A walk is
+//! started again when Quick Open is opened on an index older than
+//! [`REFRESH_AFTER`], serving the old list until the new one is ready.
+//!
+//! Directory paths are interned: each file holds the number of its directory
+//! and its own name, so a deep tree stores each directory path once instead
+//! of once per file.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Files indexed at most; the walk stops there
+pub const MAX_INDEXED_FILES: usize = 200_000;
+
+/// Age after which opening Quick Open walks the workspace again
+pub const REFRESH_AFTER: Duration = Duration::from_secs(30);
+
+/// Size and freshness of the index, for the performance HUD
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileIndexStats {
+    pub files: usize,
+    pub dirs: usize,
+    /// Approximate heap bytes used by paths
+    pub bytes: usize,
+    /// How long the last walk took
+    pub build_time: Duration,
+}
+
+/// Files of a workspace, relative to its root
+pub struct FileIndex {
+    root: PathBuf,
+    state: RwLock<IndexState>,
+    walking: AtomicBool,
+}
+
+#[derive(Debug, Default)]
+struct IndexState {
+    /// Interned directory paths relative to the root, "" for the root itself
+    dirs: Vec<Arc<str>>,
+    dir_ids: HashMap<Arc<str>, u32>,
+    files: Vec<IndexedFile>,
+    /// When the last walk finished; None until the first one has
+    refreshed: Option<Instant>,
+    build_time: Duration,
+}
+
+#[derive(Debug)]
+struct IndexedFile {
+    dir: u32,
+    name: Box<str>,
+}
+
+impl IndexState {
+    fn dir_id(&mut self, dir: &str) -> u32 {
+        if let Some(&id) = self.dir_ids.get(dir) {
+            return id;
+        }
+        let id = self.dirs.len() as u32;
+        let dir: Arc<str> = Arc::from(dir);
+        self.dirs.push(Arc::clone(&dir));
+        self.dir_ids.insert(dir, id);
+        id
+    }
+
+    /// Add a path known not to be indexed yet
+    fn push(&mut self, relative: &str) {
+        let (dir, name) = split_relative(relative);
+        let dir = self.dir_id(dir);
+        self.files.push(IndexedFile {
+            dir,
+            name: name.into(),
+        });
+    }
+
+    fn insert(&mut self, relative: &str) {
+        let (dir, name) = split_relative(relative);
+        let exists = self
+            .dir_ids
+            .get(dir)
+            .is_some_and(|&dir| self.position(dir, name).is_some());
+        if !exists {
+            self.push(relative);
+        }
+    }
+
+    fn position(&self, dir: u32, name: &str) -> Option<usize> {
+        self.files
+            .iter()
+            .position(|file| file.dir == dir && &*file.name == name)
+    }
+
+    /// Remove a file, or every file under a directory
+    fn remove(&mut self, relative: &str) {
+        let (dir, name) = split_relative(relative);
+        if let Some(position) = self
+            .dir_ids
+            .get(dir)
+            .and_then(|&dir| self.position(dir, name))
+        {
+            self.files.swap_remove(position);
+            return;
+        }
+        let prefix = format!("{relative}/");
+        let removed: Vec<u32> = self
+            .dirs
+            .iter()
+            .enumerate()
+            .filter(|(_, dir)| **dir == *relative || dir.starts_with(&prefix))
+            .map(|(id, _)| id as u32)
+            .collect();
+        if !removed.is_empty() {
+            self.files.retain(|file| !removed.contains(&file.dir));
+        }
+    }
+
+    fn stats(&self) -> FileIndexStats {
+        let dir_bytes: usize = self.dirs.iter().map(|dir| dir.len()).sum();
+        let name_bytes: usize = self.files.iter().map(|file| file.name.len()).sum();
+        FileIndexStats {
+            files: self.files.len(),
+            dirs: self.dirs.len(),
+            bytes: dir_bytes + name_bytes + self.files.len() * std::mem::size_of::<IndexedFile>(),
+            build_time: self.build_time,
+        }
+    }
+}
+
+/// Directory and file name of a relative path with "/" separators
+fn split_relative(relative: &str) -> (&str, &str) {
+    relative.rsplit_once('/').unwrap_or(("", relative))
+}
+
+impl FileIndex {
+    /// An empty index of the files under `root`; call [`Self::refresh`] to fill it
+    pub fn new(root: PathBuf) -> Arc<Self> {
+        Arc::new(Self {
+            root,
+            state: RwLock::new(IndexState::default()),
+            walking: AtomicBool::new(false),
+        })
+    }
+
+    /// Walk the workspace on a background thread, replacing the index when
+    /// done. Does nothing while a walk is already running.
+    pub fn refresh(self: &Arc<Self>) {
+        if self.walking.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let index = Arc::clone(self);
+        std::thread::spawn(move || {
+            index.refresh_blocking();
+        });
+    }
+
+    /// Walk the workspace on this thread, replacing the index
+    pub fn refresh_blocking(&self) {
+        self.walking.store(true, Ordering::Release);
+        let started = Instant::now();
+        let mut state = IndexState::default();
+        for relative in walk(&self.root) {
+            state.push(&relative);
+        }
+        state.build_time = started.elapsed();
+        state.refreshed = Some(Instant::now());
+        tracing::debug!(
+            "Indexed {} files under {:?} in {:?}",
+            state.files.len(),
+            self.root,
+            state.build_time
+        );
+        *self.write() = state;
+        self.walking.store(false, Ordering::Release);
+    }
+
+    /// Whether a walk has finished, so the index can be used
+    pub fn is_ready(&self) -> bool {
+        self.read().refreshed.is_some()
+    }
+
+    /// Whether the index is older than [`REFRESH_AFTER`] and no walk is running
+    pub fn is_stale(&self) -> bool {
+        !self.walking.load(Ordering::Acquire)
+            && self
+                .read()
+                .refreshed
+                .is_none_or(|refreshed| refreshed.elapsed() >= REFRESH_AFTER)
+    }
+
+    /// Call `f` with the relative path of every indexed file
+    pub fn for_each_path(&self, mut f: impl FnMut(&str)) {
+        let state = self.read();
+        let mut path = String::new();
+        for file in &state.files {
+            path.clear();
+            let dir = &state.dirs[file.dir as usize];
+            if !dir.is_empty() {
+                path.push_str(dir);
+                path.push('/');
+            }
+            path.push_str(&file.name);
+            f(&path);
+        }
+    }
+
+    /// Add a file created or saved by the editor
+    pub fn insert(&self, path: &Path) {
+        if let Some(relative) = self.relative(path) {
+            self.write().insert(&relative);
+        }
+    }
+
+    /// Remove a deleted file, or every file under a deleted directory
+    pub fn remove(&self, path: &Path) {
+        if let Some(relative) = self.relative(path) {
+            self.write().remove(&relative);
+        }
+    }
+
+    pub fn stats(&self) -> FileIndexStats {
+        self.read().stats()
+    }
+
+    /// `path` relative to the root with "/" separators, if it is under it
+    fn relative(&self, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(&self.root).ok()?;
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        (!relative.is_empty()).then_some(relative)
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, IndexState> {
+        self.state.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, IndexState> {
+        self.state.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Relative paths of the files under `root`, skipping what .gitignore
+/// excludes and the .git directory
+fn walk(root: &Path) -> Vec<String> {
+    let walker = ignore::WalkBuilder::new(root)
+        .hidden(false)
+        .require_git(false)
+        .filter_entry(|entry| entry.file_name() != ".git")
+        .build();
+    walker
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .filter_map(|entry| {
+            let relative = entry.path().strip_prefix(root).ok()?;
+            Some(relative.to_string_lossy().replace('\\', "/"))
+        })
+        .take(MAX_INDEXED_FILES)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(index: &FileIndex) -> Vec<String> {
+        let mut paths = Vec::new();
+        index.for_each_path(|path| paths.push(path.to_string()));
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn test_walk_respects_gitignore() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("src/app")).unwrap();
+        std::fs::create_dir_all(root.join("target/debug")).unwrap();
+        std::fs::create_dir_all(root.join(".git")).unwrap();
+        std::fs::write(root.join(".gitignore"), "target/\n").unwrap();
+        for file in [
+            "README.md",
+            "src/main.rs",
+            "src/app/mod.rs",
+            "target/debug/out",
+            ".git/HEAD",
+        ] {
+            std::fs::write(root.join(file), "").unwrap();
+        }
+
+        let index = FileIndex::new(root.to_path_buf());
+        assert!(!index.is_ready());
+        index.refresh_blocking();
+        assert!(index.is_ready());
+        assert_eq!(
+            paths(&index),
+            [".gitignore", "README.md", "src/app/mod.rs", "src/main.rs"]
+        );
+        let stats = index.stats();
+        assert_eq!((stats.files, stats.dirs), (4, 3));
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let root = PathBuf::from("/project");
+        let index = FileIndex::new(root.clone());
+        index.insert(&root.join("a/b/one.rs"));
+        index.insert(&root.join("a/b/two.rs"));
+        index.insert(&root.join("a/b/one.rs"));
+        index.insert(&root.join("top.txt"));
+        index.insert(Path::new("/elsewhere/file.rs"));
+        assert_eq!(paths(&index), ["a/b/one.rs", "a/b/two.rs", "top.txt"]);
+        // Directories are interned: both files share "a/b"
+        assert_eq!(index.stats().dirs, 2);
+
+        index.remove(&root.join("a/b/two.rs"));
+        assert_eq!(paths(&index), ["a/b/one.rs", "top.txt"]);
+        index.remove(&root.join("a"));
+        assert_eq!(paths(&index), ["top.txt"]);
+    }
+}
@@ -79,6 +79,13 @@ pub fn main_log_path() -> PathBuf {
     log_dir().join(format!("fresh-{}.log", std::process::id()))
 }
 
+/// Get the path for the session server's log file for this process.
+///
+/// Returns `{log_dir}/fresh-server-{PID}.log`
+pub fn server_log_path() -> PathBuf {
+    log_dir().join(format!("fresh-server-{}.log", std::process::id()))
+}
+
 /// Get the path for the warnings log file for this process.
 ///
 /// Returns `{log_dir}/warnings-{PID}.log`
@@ -184,12 +191,8 @@ fn cleanup_stale_logs_in_dir(dir: &std::path::Path, current_pid: u32) {
         let file_name = entry.file_name();
         let name = file_name.to_string_lossy();
 
-        // Only process .log files
-        if !name.ends_with(".log") {
-            continue;
-        }
-
-        // Try to extract PID from filename (format: something-{PID}.log)
+        // Try to extract PID from filename (format: something-{PID}.log,
+        // or something-{PID}.log.N for rotated logs)
         if let Some(pid) = extract_pid_from_filename(&name) {
             // Don't remove our own log files
             if pid == current_pid {
@@ -227,9 +230,14 @@ fn is_file_older_than(path: &std::path::Path, age: Duration) -> bool {
         .unwrap_or(false)
 }
 
-/// Extract PID from a filename like "fresh-12345.log" or "rust-12345.log"
+/// Extract PID from a filename like "fresh-12345.log", "rust-12345.log"
+/// or a rotated "fresh-12345.log.2"
 fn extract_pid_from_filename(name: &str) -> Option<u32> {
-    // Remove .log extension
+    // Remove rotation suffix and .log extension
+    let name = match name.rsplit_once('.') {
+        Some((base, n)) if n.bytes().all(|b| b.is_ascii_digit()) && !n.is_empty() => base,
+        _ => name,
+    };
     let without_ext = name.strip_suffix(".log")?;
 
     // Find the last hyphen and try to parse what follows as a PID
@@ -395,6 +403,8 @@ mod tests {
         assert_eq!(extract_pid_from_filename("fresh-12345.log"), Some(12345));
         assert_eq!(extract_pid_from_filename("rust-99999.log"), Some(99999));
         assert_eq!(extract_pid_from_filename("warnings-1.log"), Some(1));
+        assert_eq!(extract_pid_from_filename("fresh-12345.log.2"), Some(12345));
+        assert_eq!(extract_pid_from_filename("fresh-server-42.log.1"), Some(42));
         assert_eq!(extract_pid_from_filename("no-pid.txt"), None);
         assert_eq!(extract_pid_from_filename("invalid"), None);
     }
@@ -15,7 +15,7 @@ use crate::services::async_bridge::{
     AsyncBridge, AsyncMessage, LspMessageType, LspProgressValue, LspSemanticTokensResponse,
     LspServerStatus,
 };
-use crate::services::process_limits::ProcessLimits;
+use crate::services::process_limits::{self, ProcessLimits, ProcessSampler, ProcessUsage};
 use lsp_types::{
     notification::{
         DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
@@ -42,6 +42,9 @@ use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{Child, ChildStdin, ChildStdout, Command};
 use tokio::sync::{mpsc, oneshot};
 
+/// How often a running server's memory and CPU use is sampled
+const PROCESS_SAMPLE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
 /// Grace period after didOpen before sending didChange (in milliseconds)
 /// This gives the LSP server time to process didOpen before receiving changes
 const DID_OPEN_GRACE_PERIOD_MS: u64 = 200;
@@ -201,11 +204,12 @@ impl LspClientState {
 fn create_client_capabilities() -> ClientCapabilities {
     use lsp_types::{
         CodeActionClientCapabilities, CompletionClientCapabilities, DiagnosticClientCapabilities,
-        DiagnosticTag, DynamicRegistrationClientCapabilities, FoldingRangeCapability,
-        FoldingRangeClientCapabilities, FoldingRangeKind, FoldingRangeKindCapability,
-        GeneralClientCapabilities, GotoCapability, HoverClientCapabilities,
-        InlayHintClientCapabilities, MarkupKind, PublishDiagnosticsClientCapabilities,
-        RenameClientCapabilities, SignatureHelpClientCapabilities, TagSupport,
+        DiagnosticTag, DocumentSymbolClientCapabilities, DynamicRegistrationClientCapabilities,
+        FoldingRangeCapability, FoldingRangeClientCapabilities, FoldingRangeKind,
+        FoldingRangeKindCapability, GeneralClientCapabilities, GotoCapability,
+        HoverClientCapabilities, InlayHintClientCapabilities, MarkupKind,
+        PublishDiagnosticsClientCapabilities, RenameClientCapabilities,
+        SelectionRangeClientCapabilities, SignatureHelpClientCapabilities, TagSupport,
         TextDocumentClientCapabilities, TextDocumentSyncClientCapabilities,
         WorkspaceClientCapabilities, WorkspaceEditClientCapabilities,
     };
@@ -243,9 +247,14 @@ fn create_client_capabilities() -> ClientCapabilities {
                 ..Default::default()
             }),
             references: Some(DynamicRegistrationClientCapabilities::default()),
+            document_symbol: Some(DocumentSymbolClientCapabilities {
+                hierarchical_document_symbol_support: Some(true),
+                ..Default::default()
+            }),
             code_action: Some(CodeActionClientCapabilities {
                 ..Default::default()
             }),
+            selection_range: Some(SelectionRangeClientCapabilities::default()),
             rename: Some(RenameClientCapabilities {
                 dynamic_registration: Some(true),
                 prepare_support: Some(true),
@@ -392,6 +401,14 @@ fn folding_ranges_supported(capabilities: &ServerCapabilities) -> bool {
     }
 }
 
+fn selection_ranges_supported(capabilities: &ServerCapabilities) -> bool {
+    match capabilities.selection_range_provider.as_ref() {
+        Some(lsp_types::SelectionRangeProviderCapability::Simple(v)) => *v,
+        Some(_) => true,
+        None => false,
+    }
+}
+
 /// Commands sent from the main loop to the LSP task
 #[derive(Debug)]
 enum LspCommand {
@@ -503,6 +520,16 @@ enum LspCommand {
     /// Request folding ranges for a document
     FoldingRange { request_id: u64, uri: Uri },
 
+    /// Request the symbols of a document
+    DocumentSymbols { request_id: u64, uri: Uri },
+
+    /// Request the nested syntactic ranges around positions in a document
+    SelectionRanges {
+        request_id: u64,
+        uri: Uri,
+        positions: Vec<lsp_types::Position>,
+    },
+
     /// Request semantic tokens for the entire document
     SemanticTokensFull { request_id: u64, uri: Uri },
 
@@ -751,6 +778,7 @@ impl LspState {
         let (tx, rx) = oneshot::channel();
         pending.lock().unwrap().insert(id, tx);
 
+        let started = crate::services::perf::start();
         self.write_message(&request).await?;
 
         tracing::trace!("Sent LSP request id={}, waiting for response...", id);
@@ -761,6 +789,7 @@ impl LspState {
             .map_err(|_| "Response channel closed".to_string())??;
 
         tracing::trace!("Received LSP response for request id={}", id);
+        crate::services::perf::record_lsp_latency(method, started);
 
         // Remove tracking after response received
         if let Some(editor_id) = editor_request_id {
@@ -837,6 +866,7 @@ impl LspState {
             semantic_tokens_range,
         ) = extract_semantic_token_capability(&result.capabilities);
         let folding_ranges_supported = folding_ranges_supported(&result.capabilities);
+        let selection_ranges_supported = selection_ranges_supported(&result.capabilities);
 
         // Notify main loop
         let _ = self.async_tx.send(AsyncMessage::LspInitialized {
@@ -847,6 +877,7 @@ impl LspState {
             semantic_tokens_full_delta,
             semantic_tokens_range,
             folding_ranges_supported,
+            selection_ranges_supported,
         });
 
         // Send running status
@@ -1823,6 +1854,102 @@ impl LspState {
         }
     }
 
+    /// Handle document symbols request
+    #[allow(clippy::type_complexity)]
+    async fn handle_document_symbols(
+        &mut self,
+        request_id: u64,
+        uri: Uri,
+        pending: &Arc<Mutex<HashMap<i64, oneshot::Sender<Result<Value, String>>>>>,
+    ) -> Result<(), String> {
+        use lsp_types::{
+            DocumentSymbolParams, PartialResultParams, TextDocumentIdentifier,
+            WorkDoneProgressParams,
+        };
+
+        tracing::trace!("LSP: document symbols request for {}", uri.as_str());
+
+        let params = DocumentSymbolParams {
+            text_document: TextDocumentIdentifier { uri },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        };
+
+        match self
+            .send_request_sequential::<_, Option<lsp_types::DocumentSymbolResponse>>(
+                "textDocument/documentSymbol",
+                Some(params),
+                pending,
+            )
+            .await
+        {
+            Ok(symbols) => {
+                let _ = self.async_tx.send(AsyncMessage::LspDocumentSymbols {
+                    request_id,
+                    symbols,
+                });
+                Ok(())
+            }
+            Err(e) => {
+                tracing::error!("Document symbols request failed: {}", e);
+                let _ = self.async_tx.send(AsyncMessage::LspDocumentSymbols {
+                    request_id,
+                    symbols: None,
+                });
+                Err(e)
+            }
+        }
+    }
+
+    /// Handle selection ranges request
+    #[allow(clippy::type_complexity)]
+    async fn handle_selection_ranges(
+        &mut self,
+        request_id: u64,
+        uri: Uri,
+        positions: Vec<lsp_types::Position>,
+        pending: &Arc<Mutex<HashMap<i64, oneshot::Sender<Result<Value, String>>>>>,
+    ) -> Result<(), String> {
+        use lsp_types::{
+            PartialResultParams, SelectionRangeParams, TextDocumentIdentifier,
+            WorkDoneProgressParams,
+        };
+
+        tracing::trace!("LSP: selection ranges request for {}", uri.as_str());
+
+        let params = SelectionRangeParams {
+            text_document: TextDocumentIdentifier { uri },
+            positions,
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        };
+
+        match self
+            .send_request_sequential::<_, Option<Vec<lsp_types::SelectionRange>>>(
+                "textDocument/selectionRange",
+                Some(params),
+                pending,
+            )
+            .await
+        {
+            Ok(ranges) => {
+                let _ = self.async_tx.send(AsyncMessage::LspSelectionRanges {
+                    request_id,
+                    ranges: ranges.unwrap_or_default(),
+                });
+                Ok(())
+            }
+            Err(e) => {
+                tracing::error!("Selection ranges request failed: {}", e);
+                let _ = self.async_tx.send(AsyncMessage::LspSelectionRanges {
+                    request_id,
+                    ranges: Vec::new(),
+                });
+                Err(e)
+            }
+        }
+    }
+
     #[allow(clippy::type_complexity)]
     async fn handle_semantic_tokens_full(
         &mut self,
@@ -2166,6 +2293,11 @@ impl LspTask {
             )
         })?;
 
+        // Limits that can only be set on a running process (Windows Job Objects)
+        if let Err(e) = process_limits.apply_to_child(&process) {
+            tracing::warn!("Failed to apply process limits to {}: {}", command, e);
+        }
+
         let stdin = process
             .stdin
             .take()
@@ -2195,6 +2327,11 @@ impl LspTask {
         })
     }
 
+    /// OS process ID of the server, while it is running
+    fn pid(&self) -> Option<u32> {
+        self._process.id()
+    }
+
     /// Check if a command exists in PATH or as an absolute path
     fn command_exists(command: &str) -> bool {
         use std::path::Path;
@@ -2674,6 +2811,48 @@ impl LspTask {
                                 });
                             }
                         }
+                        LspCommand::DocumentSymbols { request_id, uri } => {
+                            if state.initialized {
+                                tracing::info!(
+                                    "Processing DocumentSymbols request for {}",
+                                    uri.as_str()
+                                );
+                                let _ = state
+                                    .handle_document_symbols(request_id, uri, &pending)
+                                    .await;
+                            } else {
+                                tracing::trace!(
+                                    "LSP not initialized, cannot get document symbols"
+                                );
+                                let _ = state.async_tx.send(AsyncMessage::LspDocumentSymbols {
+                                    request_id,
+                                    symbols: None,
+                                });
+                            }
+                        }
+                        LspCommand::SelectionRanges {
+                            request_id,
+                            uri,
+                            positions,
+                        } => {
+                            if state.initialized {
+                                tracing::info!(
+                                    "Processing SelectionRanges request for {}",
+                                    uri.as_str()
+                                );
+                                let _ = state
+                                    .handle_selection_ranges(request_id, uri, positions, &pending)
+                                    .await;
+                            } else {
+                                tracing::trace!(
+                                    "LSP not initialized, cannot get selection ranges"
+                                );
+                                let _ = state.async_tx.send(AsyncMessage::LspSelectionRanges {
+                                    request_id,
+                                    ranges: Vec::new(),
+                                });
+                            }
+                        }
                         LspCommand::SemanticTokensFull { request_id, uri } => {
                             if state.initialized {
                                 tracing::info!(
@@ -3234,6 +3413,70 @@ async fn handle_notification_dispatch(
     Ok(())
 }
 
+/// Resource use of a running language server process
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LspProcessStats {
+    pub pid: u32,
+    /// Latest sample; None until the first one is taken
+    pub usage: Option<ProcessUsage>,
+    /// Resident memory above which the server is stopped
+    pub memory_limit_bytes: Option<u64>,
+}
+
+/// Sample a server's resource use until it exits or its handle is dropped,
+/// stopping it if it grows past its memory limit
+#[allow(clippy::let_underscore_must_use)] // async_tx.send() fails only when the editor is shutting down
+async fn monitor_process(
+    pid: u32,
+    language: String,
+    memory_limit_bytes: Option<u64>,
+    stats: Arc<Mutex<Option<LspProcessStats>>>,
+    async_tx: std_mpsc::Sender<AsyncMessage>,
+) {
+    let mut sampler = ProcessSampler::new(pid);
+    let mut interval = tokio::time::interval(PROCESS_SAMPLE_INTERVAL);
+    loop {
+        interval.tick().await;
+        // The handle owns the other reference; once it is gone nobody reads the stats
+        if Arc::strong_count(&stats) == 1 {
+            break;
+        }
+        let Ok(usage) = sampler.sample() else {
+            break;
+        };
+        if let Ok(mut stats) = stats.lock() {
+            *stats = Some(LspProcessStats {
+                pid,
+                usage: Some(usage),
+                memory_limit_bytes,
+            });
+        }
+
+        if let Some(limit_bytes) = memory_limit_bytes.filter(|&limit| usage.rss_bytes > limit) {
+            tracing::warn!(
+                "LSP server for {} (pid {}) uses {} bytes, over its limit of {}; stopping it",
+                language,
+                pid,
+                usage.rss_bytes,
+                limit_bytes
+            );
+            // Sent before the kill so the editor sees it ahead of the crash
+            let _ = async_tx.send(AsyncMessage::LspMemoryLimitExceeded {
+                language: language.clone(),
+                rss_bytes: usage.rss_bytes,
+                limit_bytes,
+            });
+            if let Err(e) = process_limits::terminate_process(pid) {
+                tracing::warn!("Failed to stop LSP server for {}: {}", language, e);
+            }
+            break;
+        }
+    }
+    if let Ok(mut stats) = stats.lock() {
+        *stats = None;
+    }
+}
+
 /// Counter for generating unique LSP handle IDs
 static NEXT_HANDLE_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
 
@@ -3251,6 +3494,9 @@ pub struct LspHandle {
     /// Client state
     state: Arc<Mutex<LspClientState>>,
 
+    /// Sampled resource use of the server process, while it runs
+    process_stats: Arc<Mutex<Option<LspProcessStats>>>,
+
     /// Runtime handle for blocking operations
     runtime: tokio::runtime::Handle,
 }
@@ -3286,7 +3532,9 @@ impl LspHandle {
             message: None,
         });
 
+        let process_stats = Arc::new(Mutex::new(None));
         let state_clone = state.clone();
+        let process_stats_clone = process_stats.clone();
         let stderr_log_path_clone = stderr_log_path.clone();
         runtime.spawn(async move {
             match LspTask::spawn(
@@ -3300,6 +3548,26 @@ impl LspHandle {
             .await
             {
                 Ok(task) => {
+                    if let Some(pid) = task.pid() {
+                        let memory_limit_bytes = process_limits
+                            .enabled
+                            .then(|| process_limits.memory_limit_bytes())
+                            .flatten();
+                        if let Ok(mut stats) = process_stats_clone.lock() {
+                            *stats = Some(LspProcessStats {
+                                pid,
+                                usage: None,
+                                memory_limit_bytes,
+                            });
+                        }
+                        tokio::spawn(monitor_process(
+                            pid,
+                            language_clone.clone(),
+                            memory_limit_bytes,
+                            process_stats_clone,
+                            async_tx.clone(),
+                        ));
+                    }
                     task.run(command_rx).await;
                 }
                 Err(e) => {
@@ -3331,6 +3599,7 @@ impl LspHandle {
             language,
             command_tx,
             state,
+            process_stats,
             runtime: runtime.clone(),
         })
     }
@@ -3340,6 +3609,11 @@ impl LspHandle {
         self.id
     }
 
+    /// Process ID and sampled resource use of the server, while it runs
+    pub fn process_stats(&self) -> Option<LspProcessStats> {
+        self.process_stats.lock().ok().and_then(|stats| *stats)
+    }
+
     /// Get the language this handle serves
     pub fn language(&self) -> &str {
         &self.language
@@ -3674,6 +3948,29 @@ impl LspHandle {
             .map_err(|_| "Failed to send folding_range command".to_string())
     }
 
+    /// Request the symbols of a document
+    pub fn document_symbols(&self, request_id: u64, uri: Uri) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::DocumentSymbols { request_id, uri })
+            .map_err(|_| "Failed to send document_symbols command".to_string())
+    }
+
+    /// Request the nested syntactic ranges around positions in a document
+    pub fn selection_ranges(
+        &self,
+        request_id: u64,
+        uri: Uri,
+        positions: Vec<lsp_types::Position>,
+    ) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::SelectionRanges {
+                request_id,
+                uri,
+                positions,
+            })
+            .map_err(|_| "Failed to send selection_ranges command".to_string())
+    }
+
     /// Request semantic tokens for an entire document
     pub fn semantic_tokens_full(&self, request_id: u64, uri: Uri) -> Result<(), String> {
         self.command_tx
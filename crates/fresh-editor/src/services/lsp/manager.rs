@@ -84,6 +84,9 @@ pub struct LspManager {
 
     /// Whether a language supports folding ranges
     folding_ranges_support: HashMap<String, bool>,
+
+    /// Whether a language supports selection ranges
+    selection_ranges_support: HashMap<String, bool>,
 }
 
 impl LspManager {
@@ -107,6 +110,7 @@ impl LspManager {
             semantic_tokens_full_delta_support: HashMap::new(),
             semantic_tokens_range_support: HashMap::new(),
             folding_ranges_support: HashMap::new(),
+            selection_ranges_support: HashMap::new(),
         }
     }
 
@@ -205,6 +209,20 @@ impl LspManager {
         *self.folding_ranges_support.get(language).unwrap_or(&false)
     }
 
+    /// Store selection range capability information for a language
+    pub fn set_selection_ranges_supported(&mut self, language: &str, supported: bool) {
+        self.selection_ranges_support
+            .insert(language.to_string(), supported);
+    }
+
+    /// Check if the language supports selection ranges
+    pub fn selection_ranges_supported(&self, language: &str) -> bool {
+        *self
+            .selection_ranges_support
+            .get(language)
+            .unwrap_or(&false)
+    }
+
     /// Check if a character is a completion trigger for any running language server
     pub fn is_completion_trigger_char(&self, ch: char, language: &str) -> bool {
         let ch_str = ch.to_string();
@@ -877,6 +895,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "rust".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: crate::config::HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -886,6 +906,14 @@ mod tests {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
         languages.insert(
@@ -895,6 +923,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "javascript".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: crate::config::HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -904,6 +934,14 @@ mod tests {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
         languages.insert(
@@ -913,6 +951,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "c_sharp".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: crate::config::HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -922,6 +962,14 @@ mod tests {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
         languages
@@ -972,6 +1020,8 @@ mod tests {
                 filenames: vec!["/etc/**/rc.*".to_string(), "*rc".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 highlighter: crate::config::HighlighterPreference::Auto,
                 textmate_grammar: None,
@@ -981,6 +1031,14 @@ mod tests {
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
+                linter: None,
+                run_command: None,
+                line_wrap: None,
+                auto_close_pairs: None,
+                indent_rules: None,
+                abbreviations: None,
+                trim_trailing_whitespace_on_save: None,
+                ensure_final_newline_on_save: None,
             },
         );
 
@@ -5,21 +5,26 @@
 
 pub mod async_bridge;
 pub mod clipboard;
+pub mod file_index;
 pub mod fs;
 #[cfg(target_os = "linux")]
 pub mod gpm;
 pub mod log_dirs;
 pub mod lsp;
+pub mod perf;
 pub mod plugins;
 pub mod process_limits;
 pub mod recovery;
 pub mod release_checker;
 pub mod remote;
+pub mod rotating_log;
 pub mod signal_handler;
+pub mod snippets;
 pub mod status_log;
 pub mod styled_html;
 pub mod telemetry;
 pub mod terminal;
+pub mod terminal_background;
 pub mod terminal_modes;
 pub mod time_source;
 pub mod tracing_setup;
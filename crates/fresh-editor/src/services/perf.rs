@@ -0,0 +1,327 @@
+//! Lightweight performance instrumentation
+//!
+//! Instrumentation points in rendering, the capture backend and the LSP
+//! client record frame times, per-phase render times, output bytes and LSP
+//! request latency here. Nothing is measured unless the performance HUD has
+//! turned recording on: every entry point first checks a single atomic flag,
+//! so the disabled path costs one relaxed load.
+//!
+//! ```ignore
+//! let frame = perf::start();
+//! {
+//!     let _timer = perf::time(perf::Phase::Highlight);
+//!     // ...
+//! }
+//! perf::end_frame(frame);
+//! ```
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+/// Number of recent frames kept for averages
+const HISTORY_LEN: usize = 120;
+
+/// Whether instrumentation points record anything
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static STATS: Mutex<PerfStats> = Mutex::new(PerfStats::new());
+
+/// Whether performance data is being recorded
+#[inline]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Turn recording on or off; turning it on starts from empty statistics
+pub fn set_enabled(enabled: bool) {
+    if enabled && !is_enabled() {
+        *stats() = PerfStats::new();
+    }
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Start time for a measurement, or None when recording is off
+#[inline]
+pub fn start() -> Option<Instant> {
+    is_enabled().then(Instant::now)
+}
+
+/// Render phases timed separately within a frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Syntax highlighting of the visible range
+    Highlight,
+    /// Building view lines: wrapping, folding and view transforms
+    Layout,
+}
+
+/// Adds the time until it is dropped to a render phase of the current frame
+pub struct PhaseTimer(Option<(Phase, Instant)>);
+
+impl Drop for PhaseTimer {
+    fn drop(&mut self) {
+        if let Some((phase, started)) = self.0 {
+            stats().add_phase(phase, started.elapsed());
+        }
+    }
+}
+
+/// Time a render phase until the returned guard is dropped
+#[inline]
+pub fn time(phase: Phase) -> PhaseTimer {
+    PhaseTimer(start().map(|started| (phase, started)))
+}
+
+/// Finish the frame started at `started` (from [`start`])
+pub fn end_frame(started: Option<Instant>) {
+    if let Some(started) = started {
+        stats().end_frame(started.elapsed());
+    }
+}
+
+/// Record the bytes of terminal output produced for one frame
+#[inline]
+pub fn record_output_bytes(bytes: usize) {
+    if is_enabled() {
+        stats().add_output_bytes(bytes);
+    }
+}
+
+/// Record an LSP request to `method` sent at `started` (from [`start`])
+/// whose response just arrived
+pub fn record_lsp_latency(method: &str, started: Option<Instant>) {
+    if let Some(started) = started {
+        stats().add_lsp_latency(method, started.elapsed());
+    }
+}
+
+/// Summary of what has been recorded so far
+pub fn snapshot() -> PerfSnapshot {
+    stats().snapshot()
+}
+
+fn stats() -> std::sync::MutexGuard<'static, PerfStats> {
+    STATS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct FrameSample {
+    total: Duration,
+    highlight: Duration,
+    layout: Duration,
+}
+
+#[derive(Debug, Clone, Default)]
+struct LatencyStats {
+    count: u64,
+    total: Duration,
+    last: Duration,
+    max: Duration,
+}
+
+/// Recorded samples, oldest first
+#[derive(Debug)]
+struct PerfStats {
+    frames: VecDeque<FrameSample>,
+    /// Phase times of the frame being rendered
+    current: FrameSample,
+    output_bytes: VecDeque<usize>,
+    lsp: BTreeMap<String, LatencyStats>,
+}
+
+impl PerfStats {
+    const fn new() -> Self {
+        Self {
+            frames: VecDeque::new(),
+            current: FrameSample {
+                total: Duration::ZERO,
+                highlight: Duration::ZERO,
+                layout: Duration::ZERO,
+            },
+            output_bytes: VecDeque::new(),
+            lsp: BTreeMap::new(),
+        }
+    }
+
+    fn add_phase(&mut self, phase: Phase, elapsed: Duration) {
+        match phase {
+            Phase::Highlight => self.current.highlight += elapsed,
+            Phase::Layout => self.current.layout += elapsed,
+        }
+    }
+
+    fn end_frame(&mut self, total: Duration) {
+        let mut frame = std::mem::take(&mut self.current);
+        frame.total = total;
+        push_bounded(&mut self.frames, frame);
+    }
+
+    fn add_output_bytes(&mut self, bytes: usize) {
+        push_bounded(&mut self.output_bytes, bytes);
+    }
+
+    fn add_lsp_latency(&mut self, method: &str, elapsed: Duration) {
+        let entry = self.lsp.entry(method.to_string()).or_default();
+        entry.count += 1;
+        entry.total += elapsed;
+        entry.last = elapsed;
+        entry.max = entry.max.max(elapsed);
+    }
+
+    fn snapshot(&self) -> PerfSnapshot {
+        let timing = |get: fn(&FrameSample) -> Duration| Timing {
+            last_us: self.frames.back().map_or(0, |f| micros(get(f))),
+            avg_us: average(self.frames.iter().map(|f| micros(get(f)))),
+            max_us: self
+                .frames
+                .iter()
+                .map(|f| micros(get(f)))
+                .max()
+                .unwrap_or(0),
+        };
+        PerfSnapshot {
+            frames: self.frames.len(),
+            frame: timing(|f| f.total),
+            highlight: timing(|f| f.highlight),
+            layout: timing(|f| f.layout),
+            output_bytes_last: self.output_bytes.back().copied(),
+            output_bytes_avg: (!self.output_bytes.is_empty())
+                .then(|| average(self.output_bytes.iter().map(|&b| b as u64)) as usize),
+            lsp: self
+                .lsp
+                .iter()
+                .map(|(method, stats)| LspLatency {
+                    method: method.clone(),
+                    count: stats.count,
+                    last_us: micros(stats.last),
+                    avg_us: micros(stats.total) / stats.count.max(1),
+                    max_us: micros(stats.max),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn push_bounded<T>(samples: &mut VecDeque<T>, sample: T) {
+    if samples.len() == HISTORY_LEN {
+        samples.pop_front();
+    }
+    samples.push_back(sample);
+}
+
+fn micros(duration: Duration) -> u64 {
+    duration.as_micros() as u64
+}
+
+fn average(values: impl Iterator<Item = u64>) -> u64 {
+    let (sum, count) = values.fold((0, 0), |(sum, count), v| (sum + v, count + 1));
+    if count == 0 {
+        0
+    } else {
+        sum / count
+    }
+}
+
+/// Last, average and maximum of a time over the recent frames
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Timing {
+    pub last_us: u64,
+    pub avg_us: u64,
+    pub max_us: u64,
+}
+
+/// Latency of one LSP request method
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LspLatency {
+    pub method: String,
+    pub count: u64,
+    pub last_us: u64,
+    pub avg_us: u64,
+    pub max_us: u64,
+}
+
+/// Summary of the recorded performance data
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PerfSnapshot {
+    /// Frames the timings are computed over
+    pub frames: usize,
+    pub frame: Timing,
+    pub highlight: Timing,
+    pub layout: Timing,
+    /// Terminal output of the last frame, when rendering through the
+    /// capture backend (session mode)
+    pub output_bytes_last: Option<usize>,
+    pub output_bytes_avg: Option<usize>,
+    pub lsp: Vec<LspLatency>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_summarizes_frames() {
+        let mut stats = PerfStats::new();
+        stats.add_phase(Phase::Highlight, Duration::from_micros(300));
+        stats.add_phase(Phase::Layout, Duration::from_micros(100));
+        stats.add_phase(Phase::Highlight, Duration::from_micros(100));
+        stats.end_frame(Duration::from_micros(1000));
+        stats.end_frame(Duration::from_micros(3000));
+        stats.add_output_bytes(100);
+        stats.add_output_bytes(300);
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.frames, 2);
+        assert_eq!(
+            snapshot.frame,
+            Timing {
+                last_us: 3000,
+                avg_us: 2000,
+                max_us: 3000
+            }
+        );
+        // Phase times belong to the frame they were recorded in
+        assert_eq!(snapshot.highlight.max_us, 400);
+        assert_eq!(snapshot.highlight.last_us, 0);
+        assert_eq!(snapshot.layout.avg_us, 50);
+        assert_eq!(snapshot.output_bytes_last, Some(300));
+        assert_eq!(snapshot.output_bytes_avg, Some(200));
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut stats = PerfStats::new();
+        for i in 0..(HISTORY_LEN + 10) {
+            stats.end_frame(Duration::from_micros(i as u64));
+        }
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.frames, HISTORY_LEN);
+        assert_eq!(snapshot.frame.last_us, (HISTORY_LEN + 9) as u64);
+    }
+
+    #[test]
+    fn test_lsp_latency_per_method() {
+        let mut stats = PerfStats::new();
+        stats.add_lsp_latency("textDocument/hover", Duration::from_millis(10));
+        stats.add_lsp_latency("textDocument/hover", Duration::from_millis(30));
+        stats.add_lsp_latency("initialize", Duration::from_millis(5));
+
+        let lsp = stats.snapshot().lsp;
+        assert_eq!(lsp.len(), 2);
+        assert_eq!(lsp[0].method, "initialize");
+        assert_eq!(
+            lsp[1],
+            LspLatency {
+                method: "textDocument/hover".to_string(),
+                count: 2,
+                last_us: 30_000,
+                avg_us: 20_000,
+                max_us: 30_000,
+            }
+        );
+    }
+}
@@ -61,6 +61,8 @@ impl PluginServiceBridge for EditorServiceBridge {
         use crate::input::keybindings::{Action, KeyContext};
 
         let editor_command = EditorCommand {
+            id: command.action_name.clone(),
+            english_name: command.name.clone(),
             name: command.name,
             description: command.description,
             action: Action::PluginAction(command.action_name),
@@ -2,13 +2,22 @@
 ///
 /// Provides cross-platform support for limiting memory and CPU usage of spawned processes.
 /// On Linux, uses user-delegated cgroups v2 if available, otherwise falls back to setrlimit.
+/// Other Unix systems use setrlimit, and Windows places the process in a Job Object.
 /// Memory and CPU limits are decoupled - memory can work without CPU delegation.
+/// Where no CPU cap is available the process runs at a lower priority instead.
+///
+/// Limits set by the OS are backed by sampling: [`ProcessSampler`] reads a running
+/// process's resident memory and CPU use, so callers can show them and stop a
+/// process that grows past its memory limit.
 // Re-export the type from the shared types module
 pub use crate::types::ProcessLimits;
 
+#[cfg(target_os = "linux")]
 use std::fs;
 use std::io;
+#[cfg(target_os = "linux")]
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 impl ProcessLimits {
     /// Get the memory limit in bytes, computed from percentage of total system memory
@@ -25,6 +34,13 @@ impl ProcessLimits {
     /// On Linux, tries user-delegated cgroups v2, otherwise falls back to setrlimit.
     /// Memory and CPU limits are handled independently.
     pub fn apply_to_command(&self, cmd: &mut tokio::process::Command) -> io::Result<()> {
+        self.apply_to_std_command(cmd.as_std_mut())
+    }
+
+    /// Apply these limits to a std Command before spawning
+    ///
+    /// Used for short-lived tools run synchronously, like formatters and linters.
+    pub fn apply_to_std_command(&self, cmd: &mut std::process::Command) -> io::Result<()> {
         if !self.enabled {
             return Ok(());
         }
@@ -34,17 +50,159 @@ impl ProcessLimits {
             self.apply_linux_limits(cmd)
         }
 
-        #[cfg(not(target_os = "linux"))]
+        #[cfg(all(unix, not(target_os = "linux")))]
+        {
+            self.apply_rlimit_limits(cmd);
+            Ok(())
+        }
+
+        #[cfg(not(unix))]
         {
-            // TODO: Implement for macOS using setrlimit
-            // TODO: Implement for Windows using Job Objects
-            tracing::warn!("Process resource limits are not yet implemented for this platform");
+            // Job Objects can only be applied once the process exists; see apply_to_child
+            let _ = cmd;
             Ok(())
         }
     }
 
+    /// Apply the limits that can only be set on a running process
+    ///
+    /// On Windows this places the process in a Job Object capping its memory and CPU
+    /// rate. Elsewhere the limits were already applied by [`Self::apply_to_command`].
+    pub fn apply_to_child(&self, child: &tokio::process::Child) -> io::Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        #[cfg(windows)]
+        {
+            let handle = child
+                .raw_handle()
+                .ok_or_else(|| io::Error::other("process has already exited"))?;
+            self.apply_job_object(handle)
+        }
+
+        #[cfg(not(windows))]
+        {
+            let _ = child;
+            Ok(())
+        }
+    }
+
+    /// Memory limit via setrlimit and, lacking cgroups, lower CPU priority
+    #[cfg(all(unix, not(target_os = "linux")))]
+    fn apply_rlimit_limits(&self, cmd: &mut std::process::Command) {
+        use std::os::unix::process::CommandExt;
+
+        let max_memory_bytes = self.memory_limit_bytes();
+        let lower_priority = self.max_cpu_percent.is_some();
+        unsafe {
+            cmd.pre_exec(move || {
+                if let Some(mem_limit) = max_memory_bytes {
+                    if let Err(e) = apply_memory_limit_setrlimit(mem_limit) {
+                        tracing::warn!("Failed to apply memory limit via setrlimit: {}", e);
+                    }
+                }
+                if lower_priority {
+                    lower_process_priority();
+                }
+                Ok(())
+            });
+        }
+
+        tracing::info!(
+            "Using resource limits: memory={} (setrlimit), CPU={} (nice)",
+            self.max_memory_percent
+                .map(|p| format!("{}%", p))
+                .unwrap_or("unlimited".to_string()),
+            self.max_cpu_percent
+                .map(|c| format!("{}%", c))
+                .unwrap_or("unlimited".to_string()),
+        );
+    }
+
+    /// Place a process in a Job Object limiting its memory and CPU rate
+    #[cfg(windows)]
+    fn apply_job_object(&self, process: std::os::windows::io::RawHandle) -> io::Result<()> {
+        use windows_sys::Win32::Foundation::CloseHandle;
+        use windows_sys::Win32::System::JobObjects::{
+            AssignProcessToJobObject, CreateJobObjectW, JobObjectCpuRateControlInformation,
+            JobObjectExtendedLimitInformation, SetInformationJobObject,
+            JOBOBJECT_CPU_RATE_CONTROL_INFORMATION, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+            JOB_OBJECT_CPU_RATE_CONTROL_ENABLE, JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP,
+            JOB_OBJECT_LIMIT_PROCESS_MEMORY,
+        };
+
+        unsafe {
+            let job = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+            if job.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+
+            if let Some(memory_bytes) = self.memory_limit_bytes() {
+                let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+                info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_PROCESS_MEMORY;
+                info.ProcessMemoryLimit = memory_bytes as usize;
+                if SetInformationJobObject(
+                    job,
+                    JobObjectExtendedLimitInformation,
+                    &info as *const _ as *const std::ffi::c_void,
+                    std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                ) == 0
+                {
+                    tracing::warn!(
+                        "Failed to set Job Object memory limit: {}",
+                        io::Error::last_os_error()
+                    );
+                }
+            }
+
+            if let Some(cpu_pct) = self.max_cpu_percent {
+                // CpuRate is in hundredths of a percent of all processors, while
+                // max_cpu_percent counts 100% per core
+                let cores = SystemResources::cpu_count().unwrap_or(1).max(1) as u32;
+                let mut info: JOBOBJECT_CPU_RATE_CONTROL_INFORMATION = std::mem::zeroed();
+                info.ControlFlags =
+                    JOB_OBJECT_CPU_RATE_CONTROL_ENABLE | JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP;
+                info.Anonymous.CpuRate = (cpu_pct * 100 / cores).clamp(1, 10_000);
+                if SetInformationJobObject(
+                    job,
+                    JobObjectCpuRateControlInformation,
+                    &info as *const _ as *const std::ffi::c_void,
+                    std::mem::size_of::<JOBOBJECT_CPU_RATE_CONTROL_INFORMATION>() as u32,
+                ) == 0
+                {
+                    tracing::warn!(
+                        "Failed to set Job Object CPU limit: {}",
+                        io::Error::last_os_error()
+                    );
+                }
+            }
+
+            let assigned = AssignProcessToJobObject(job, process as _);
+            let error = io::Error::last_os_error();
+            // The job lives on while the process is assigned to it
+            CloseHandle(job);
+            if assigned == 0 {
+                return Err(error);
+            }
+        }
+
+        tracing::info!(
+            "Using resource limits: memory={}, CPU={} (Job Object)",
+            self.max_memory_percent
+                .map(|p| format!("{}%", p))
+                .unwrap_or("unlimited".to_string()),
+            self.max_cpu_percent
+                .map(|c| format!("{}%", c))
+                .unwrap_or("unlimited".to_string()),
+        );
+        Ok(())
+    }
+
     #[cfg(target_os = "linux")]
-    fn apply_linux_limits(&self, cmd: &mut tokio::process::Command) -> io::Result<()> {
+    fn apply_linux_limits(&self, cmd: &mut std::process::Command) -> io::Result<()> {
+        use std::os::unix::process::CommandExt;
+
         let max_memory_bytes = self.memory_limit_bytes();
         let _max_cpu_percent = self.max_cpu_percent;
 
@@ -86,6 +244,10 @@ impl ProcessLimits {
                 // If we successfully set at least one limit via cgroup, use it
                 if memory_method == "cgroup" || cpu_method == "cgroup" {
                     let cgroup_to_use = cgroup_full.clone();
+                    let lower_priority = self.max_cpu_percent.is_some() && cpu_method != "cgroup";
+                    if lower_priority {
+                        cpu_method = "nice";
+                    }
 
                     unsafe {
                         cmd.pre_exec(move || {
@@ -93,6 +255,9 @@ impl ProcessLimits {
                             if let Err(e) = move_to_cgroup(&cgroup_to_use) {
                                 tracing::warn!("Failed to move process to cgroup: {}", e);
                             }
+                            if lower_priority {
+                                lower_process_priority();
+                            }
                             Ok(())
                         });
                     }
@@ -117,8 +282,10 @@ impl ProcessLimits {
             }
         }
 
-        // Fall back to setrlimit for memory if cgroup didn't work
-        if memory_method != "cgroup" && max_memory_bytes.is_some() {
+        // Fall back to setrlimit for memory and a lower priority for CPU if
+        // cgroups didn't work
+        let lower_priority = self.max_cpu_percent.is_some();
+        if max_memory_bytes.is_some() || lower_priority {
             unsafe {
                 cmd.pre_exec(move || {
                     if let Some(mem_limit) = max_memory_bytes {
@@ -131,10 +298,18 @@ impl ProcessLimits {
                             );
                         }
                     }
+                    if lower_priority {
+                        lower_process_priority();
+                    }
                     Ok(())
                 });
             }
-            memory_method = "setrlimit";
+            if max_memory_bytes.is_some() {
+                memory_method = "setrlimit";
+            }
+            if lower_priority {
+                cpu_method = "nice";
+            }
         }
 
         tracing::info!(
@@ -259,9 +434,32 @@ impl SystemResources {
             Self::linux_total_memory_mb()
         }
 
-        #[cfg(not(target_os = "linux"))]
+        #[cfg(all(unix, not(target_os = "linux")))]
+        {
+            let pages = unsafe { libc::sysconf(libc::_SC_PHYS_PAGES) };
+            let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+            if pages <= 0 || page_size <= 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(pages as u64 * page_size as u64 / 1024 / 1024)
+        }
+
+        #[cfg(windows)]
+        {
+            use windows_sys::Win32::System::SystemInformation::{
+                GlobalMemoryStatusEx, MEMORYSTATUSEX,
+            };
+
+            let mut status: MEMORYSTATUSEX = unsafe { std::mem::zeroed() };
+            status.dwLength = std::mem::size_of::<MEMORYSTATUSEX>() as u32;
+            if unsafe { GlobalMemoryStatusEx(&mut status) } == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(status.ullTotalPhys / 1024 / 1024)
+        }
+
+        #[cfg(not(any(unix, windows)))]
         {
-            // TODO: Implement for other platforms
             Err(io::Error::new(
                 io::ErrorKind::Unsupported,
                 "Memory detection not implemented for this platform",
@@ -294,24 +492,12 @@ impl SystemResources {
 
     /// Get total number of CPU cores
     pub fn cpu_count() -> io::Result<usize> {
-        #[cfg(target_os = "linux")]
-        {
-            Ok(num_cpus())
-        }
-
-        #[cfg(not(target_os = "linux"))]
-        {
-            // TODO: Implement for other platforms
-            Err(io::Error::new(
-                io::ErrorKind::Unsupported,
-                "CPU detection not implemented for this platform",
-            ))
-        }
+        std::thread::available_parallelism().map(|n| n.get())
     }
 }
 
 /// Apply memory limit via setrlimit (fallback method)
-#[cfg(target_os = "linux")]
+#[cfg(unix)]
 fn apply_memory_limit_setrlimit(bytes: u64) -> io::Result<()> {
     use nix::sys::resource::{setrlimit, Resource};
 
@@ -323,12 +509,232 @@ fn apply_memory_limit_setrlimit(bytes: u64) -> io::Result<()> {
         .map_err(|e| io::Error::other(format!("setrlimit AS failed: {}", e)))
 }
 
-/// Get the number of CPU cores (Linux)
+/// Run the current process at a lower scheduling priority, for when its CPU
+/// use can't be capped. Called between fork and exec.
+#[cfg(unix)]
+fn lower_process_priority() {
+    unsafe {
+        libc::nice(10);
+    }
+}
+
+/// Memory and CPU use of a running process
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProcessUsage {
+    /// Resident memory in bytes
+    pub rss_bytes: u64,
+    /// CPU use since the previous sample, 100% per core
+    pub cpu_percent: f32,
+}
+
+/// Samples the resource use of a running process
+///
+/// CPU use is the CPU time the process spent between two samples, so the
+/// first sample reports 0%.
+pub struct ProcessSampler {
+    pid: u32,
+    last: Option<(Instant, Duration)>,
+}
+
+impl ProcessSampler {
+    pub fn new(pid: u32) -> Self {
+        Self { pid, last: None }
+    }
+
+    /// Current usage, or an error once the process has exited
+    pub fn sample(&mut self) -> io::Result<ProcessUsage> {
+        let (rss_bytes, cpu_time) = read_process_usage(self.pid)?;
+        let now = Instant::now();
+        let cpu_percent = match self.last {
+            Some((last_at, last_cpu)) => {
+                let wall = now.duration_since(last_at).as_secs_f32();
+                if wall > 0.0 {
+                    cpu_time.saturating_sub(last_cpu).as_secs_f32() / wall * 100.0
+                } else {
+                    0.0
+                }
+            }
+            None => 0.0,
+        };
+        self.last = Some((now, cpu_time));
+        Ok(ProcessUsage {
+            rss_bytes,
+            cpu_percent,
+        })
+    }
+}
+
+/// Resident memory in bytes and total CPU time of a process
 #[cfg(target_os = "linux")]
-fn num_cpus() -> usize {
-    std::thread::available_parallelism()
-        .map(|n| n.get())
-        .unwrap_or(1)
+fn read_process_usage(pid: u32) -> io::Result<(u64, Duration)> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid))?;
+    // The command name may contain spaces; the fields after it are fixed
+    let fields: Vec<&str> = stat
+        .rsplit_once(')')
+        .map(|(_, rest)| rest.split_whitespace().collect())
+        .unwrap_or_default();
+    let field = |n: usize| -> io::Result<u64> {
+        fields
+            .get(n)
+            .and_then(|f| f.parse().ok())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed /proc stat"))
+    };
+    // Fields after the name start at 3 (state); utime and stime are 14 and 15,
+    // rss (in pages) is 24
+    if fields.first() == Some(&"Z") {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "process has exited",
+        ));
+    }
+    let ticks = field(11)? + field(12)?;
+    let rss_pages = field(21)?;
+
+    let ticks_per_second = unsafe { libc::sysconf(libc::_SC_CLK_TCK) }.max(1) as u64;
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) }.max(1) as u64;
+    Ok((
+        rss_pages * page_size,
+        Duration::from_secs_f64(ticks as f64 / ticks_per_second as f64),
+    ))
+}
+
+/// Resident memory in bytes and total CPU time of a process, from `ps`
+#[cfg(all(unix, not(target_os = "linux")))]
+fn read_process_usage(pid: u32) -> io::Result<(u64, Duration)> {
+    let output = std::process::Command::new("ps")
+        .args(["-o", "rss=,time=", "-p", &pid.to_string()])
+        .output()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut fields = text.split_whitespace();
+    let (Some(rss_kb), Some(time)) = (fields.next(), fields.next()) else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "process has exited",
+        ));
+    };
+    let rss_kb: u64 = rss_kb
+        .parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "malformed ps output"))?;
+    let cpu_time = parse_cpu_time(time)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed ps output"))?;
+    Ok((rss_kb * 1024, cpu_time))
+}
+
+/// Parse a `ps` CPU time: `[[dd-]hh:]mm:ss[.ff]`
+#[cfg(any(test, all(unix, not(target_os = "linux"))))]
+fn parse_cpu_time(time: &str) -> Option<Duration> {
+    let (days, time) = match time.split_once('-') {
+        Some((days, time)) => (days.parse::<u64>().ok()?, time),
+        None => (0, time),
+    };
+    let mut seconds = 0.0;
+    for part in time.split(':') {
+        seconds = seconds * 60.0 + part.parse::<f64>().ok()?;
+    }
+    Some(Duration::from_secs_f64(days as f64 * 86_400.0 + seconds))
+}
+
+/// Resident memory in bytes and total CPU time of a process
+#[cfg(windows)]
+fn read_process_usage(pid: u32) -> io::Result<(u64, Duration)> {
+    use windows_sys::Win32::Foundation::{CloseHandle, FILETIME, STILL_ACTIVE};
+    use windows_sys::Win32::System::ProcessStatus::{
+        GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS,
+    };
+    use windows_sys::Win32::System::Threading::{
+        GetExitCodeProcess, GetProcessTimes, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+
+    unsafe {
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+        if process.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        let result = (|| {
+            let mut exit_code = 0;
+            if GetExitCodeProcess(process, &mut exit_code) == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if exit_code != STILL_ACTIVE as u32 {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "process has exited",
+                ));
+            }
+
+            let mut counters: PROCESS_MEMORY_COUNTERS = std::mem::zeroed();
+            counters.cb = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+            if GetProcessMemoryInfo(process, &mut counters, counters.cb) == 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let zero = FILETIME {
+                dwLowDateTime: 0,
+                dwHighDateTime: 0,
+            };
+            let (mut created, mut exited, mut kernel, mut user) = (zero, zero, zero, zero);
+            if GetProcessTimes(process, &mut created, &mut exited, &mut kernel, &mut user) == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            // FILETIME counts 100ns intervals
+            let hundred_ns = |t: FILETIME| (t.dwHighDateTime as u64) << 32 | t.dwLowDateTime as u64;
+            let cpu = Duration::from_nanos((hundred_ns(kernel) + hundred_ns(user)) * 100);
+            Ok((counters.WorkingSetSize as u64, cpu))
+        })();
+        CloseHandle(process);
+        result
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn read_process_usage(_pid: u32) -> io::Result<(u64, Duration)> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Process sampling not implemented for this platform",
+    ))
+}
+
+/// Forcibly stop a process
+pub fn terminate_process(pid: u32) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        use nix::sys::signal::{kill, Signal};
+        use nix::unistd::Pid;
+
+        kill(Pid::from_raw(pid as i32), Signal::SIGKILL)
+            .map_err(|e| io::Error::other(format!("kill failed: {}", e)))
+    }
+
+    #[cfg(windows)]
+    {
+        use windows_sys::Win32::Foundation::CloseHandle;
+        use windows_sys::Win32::System::Threading::{
+            OpenProcess, TerminateProcess, PROCESS_TERMINATE,
+        };
+
+        unsafe {
+            let process = OpenProcess(PROCESS_TERMINATE, 0, pid);
+            if process.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            let terminated = TerminateProcess(process, 1);
+            let error = io::Error::last_os_error();
+            CloseHandle(process);
+            if terminated == 0 {
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = pid;
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Process termination not implemented for this platform",
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -398,6 +804,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_cpu_time() {
+        assert_eq!(parse_cpu_time("0:01.50"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_cpu_time("01:02:03"), Some(Duration::from_secs(3723)));
+        assert_eq!(
+            parse_cpu_time("2-00:00:01"),
+            Some(Duration::from_secs(2 * 86_400 + 1))
+        );
+        assert_eq!(parse_cpu_time("abc"), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_sample_own_process() {
+        let mut sampler = ProcessSampler::new(std::process::id());
+        let first = sampler.sample().unwrap();
+        assert!(first.rss_bytes > 0);
+        assert_eq!(first.cpu_percent, 0.0);
+        assert!(sampler.sample().unwrap().cpu_percent >= 0.0);
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn test_sample_and_terminate_child() {
+        let mut child = tokio::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let pid = child.id().unwrap();
+        let mut sampler = ProcessSampler::new(pid);
+        assert!(sampler.sample().is_ok());
+
+        terminate_process(pid).unwrap();
+        child.wait().await.unwrap();
+        assert!(sampler.sample().is_err());
+    }
+
     #[test]
     fn test_process_limits_apply_to_command_disabled() {
         let limits = ProcessLimits::unlimited();
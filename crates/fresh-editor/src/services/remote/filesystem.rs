@@ -214,6 +214,21 @@ impl FileSystem for RemoteFileSystem {
             })
     }
 
+    fn sample_line_starts_in_range(
+        &self,
+        path: &Path,
+        offset: u64,
+        len: usize,
+        _stride: usize,
+    ) -> io::Result<(usize, Vec<(usize, usize)>)> {
+        // Sampling would mean sending the range over; count on the server and
+        // leave conversions to read from the chunk start instead
+        Ok((
+            self.count_line_feeds_in_range(path, offset, len)?,
+            Vec::new(),
+        ))
+    }
+
     fn write_file(&self, path: &Path, data: &[u8]) -> io::Result<()> {
         let path_str = path.to_string_lossy();
         self.channel
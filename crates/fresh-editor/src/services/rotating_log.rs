@@ -0,0 +1,174 @@
+//! Size-rotated log file
+//!
+//! The editor log is written through a `RotatingLog` so a long session can't
+//! grow it without bound. Once the file would exceed `max_size`, it is renamed
+//! to `{path}.1` (shifting older rotations to `.2`, `.3`, ...), the oldest
+//! rotation beyond `keep` is deleted, and a fresh file is started at `path`.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Default size at which the log is rotated (10 MiB)
+pub const DEFAULT_MAX_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Default number of rotated files kept next to the live log
+pub const DEFAULT_KEEP: usize = 3;
+
+/// A log file writer that rotates by size
+pub struct RotatingLog {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_size: u64,
+    keep: usize,
+}
+
+impl RotatingLog {
+    /// Create (truncating) a log at `path` with the default limits
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Self::with_limits(path, DEFAULT_MAX_SIZE, DEFAULT_KEEP)
+    }
+
+    /// Create (truncating) a log at `path`, rotating once it would exceed
+    /// `max_size` bytes and keeping at most `keep` rotated files
+    pub fn with_limits(path: &Path, max_size: u64, keep: usize) -> io::Result<Self> {
+        Ok(Self {
+            path: path.to_path_buf(),
+            file: create_appending(path)?,
+            size: 0,
+            max_size,
+            keep,
+        })
+    }
+
+    /// Path of the live log file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.keep == 0 {
+            self.file = create_appending(&self.path)?;
+        } else {
+            // The oldest rotation may not exist yet
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = fs::remove_file(rotated_path(&self.path, self.keep));
+            for n in (1..self.keep).rev() {
+                let from = rotated_path(&self.path, n);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, n + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+            self.file = create_appending(&self.path)?;
+        }
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingLog {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_size {
+            // A failed rotation shouldn't lose the message; keep appending and
+            // note the failure in the log itself, as stderr may be the terminal
+            // the editor draws on. The next attempt waits for another
+            // `max_size` bytes.
+            if let Err(e) = self.rotate() {
+                // Best-effort: the message write below reports a broken file
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = writeln!(self.file, "Failed to rotate log: {}", e);
+                self.size = 0;
+            }
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Truncate or create `path` and open it for appending, so other appending
+/// writers (e.g. a redirected stderr) don't get overwritten
+fn create_appending(path: &Path) -> io::Result<File> {
+    File::create(path)?;
+    OpenOptions::new().append(true).open(path)
+}
+
+/// Path of the `n`th rotation of `path`, e.g. `fresh-123.log.2`
+pub fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotates_when_full_and_keeps_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fresh-1.log");
+        let mut log = RotatingLog::with_limits(&path, 10, 2).unwrap();
+
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            log.write_all(line.as_bytes()).unwrap();
+        }
+        log.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fourth\n");
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 1)).unwrap(),
+            "third\n"
+        );
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 2)).unwrap(),
+            "second\n"
+        );
+        assert!(!rotated_path(&path, 3).exists());
+    }
+
+    #[test]
+    fn test_failed_rotation_is_noted_in_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fresh-1.log");
+        // A directory in the way of the rotated file makes the rename fail
+        fs::create_dir(rotated_path(&path, 1)).unwrap();
+        let mut log = RotatingLog::with_limits(&path, 10, 1).unwrap();
+
+        log.write_all(b"first\n").unwrap();
+        log.write_all(b"second\n").unwrap();
+        log.write_all(b"3\n").unwrap();
+        log.flush().unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 4, "{content}");
+        assert_eq!(lines[0], "first");
+        assert!(lines[1].starts_with("Failed to rotate log: "));
+        assert_eq!(&lines[2..], ["second", "3"]);
+    }
+
+    #[test]
+    fn test_oversized_write_goes_to_fresh_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fresh-1.log");
+        let mut log = RotatingLog::with_limits(&path, 4, 1).unwrap();
+
+        log.write_all(b"a message longer than the limit").unwrap();
+        log.flush().unwrap();
+
+        // Nothing to rotate away yet, so the message lands in the live file
+        assert!(!rotated_path(&path, 1).exists());
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "a message longer than the limit"
+        );
+    }
+}
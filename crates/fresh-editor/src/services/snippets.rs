@@ -0,0 +1,213 @@
+//! User snippet files
+//!
+//! Snippets are read from the `snippets` directory of the config dir: one
+//! `<language>.json` file per language, plus `global.json` for every
+//! language. Files use the VS Code format:
+//!
+//! ```json
+//! {
+//!   "For loop": {
+//!     "prefix": "for",
+//!     "body": ["for ${1:item} in ${2:items} {", "\t$0", "}"],
+//!     "description": "Loop over an iterator"
+//!   }
+//! }
+//! ```
+//!
+//! `prefix` and `body` may be a string or a list of strings (body lines).
+//! Files are read when first needed and again whenever they change on disk.
+
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// File holding the snippets shared by all languages
+const GLOBAL_FILE: &str = "global";
+
+/// A snippet from a user snippet file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snippet {
+    /// Name of the snippet (its key in the file)
+    pub name: String,
+    /// Words that expand to the snippet
+    pub prefixes: Vec<String>,
+    /// Body in snippet syntax
+    pub body: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl OneOrMany {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            Self::One(value) => vec![value],
+            Self::Many(values) => values,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SnippetEntry {
+    prefix: OneOrMany,
+    body: OneOrMany,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+/// Snippets of one file, with the modification time they were read at
+#[derive(Debug)]
+struct CachedFile {
+    modified: Option<SystemTime>,
+    snippets: Vec<Snippet>,
+}
+
+/// Snippet files of the config dir, cached per language
+#[derive(Debug)]
+pub struct SnippetLibrary {
+    dir: PathBuf,
+    files: HashMap<String, CachedFile>,
+}
+
+impl SnippetLibrary {
+    pub fn new(config_dir: &Path) -> Self {
+        Self {
+            dir: config_dir.join("snippets"),
+            files: HashMap::new(),
+        }
+    }
+
+    /// Snippets available in a language, its own ones first
+    pub fn for_language(&mut self, language: &str) -> Vec<Snippet> {
+        let mut snippets = self.file_snippets(language).to_vec();
+        if language != GLOBAL_FILE {
+            snippets.extend_from_slice(self.file_snippets(GLOBAL_FILE));
+        }
+        snippets
+    }
+
+    /// Snippets with a prefix starting with `typed` (ignoring case)
+    pub fn matching(&mut self, language: &str, typed: &str) -> Vec<Snippet> {
+        let typed = typed.to_lowercase();
+        self.for_language(language)
+            .into_iter()
+            .filter(|snippet| {
+                snippet
+                    .prefixes
+                    .iter()
+                    .any(|prefix| prefix.to_lowercase().starts_with(&typed))
+            })
+            .collect()
+    }
+
+    /// Snippets of `<name>.json`, re-read if the file changed
+    fn file_snippets(&mut self, name: &str) -> &[Snippet] {
+        let path = self.dir.join(format!("{}.json", name));
+        let modified = std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        let stale = self
+            .files
+            .get(name)
+            .is_none_or(|cached| cached.modified != modified);
+        if stale {
+            let snippets = if modified.is_some() {
+                load_snippet_file(&path)
+            } else {
+                Vec::new()
+            };
+            self.files
+                .insert(name.to_string(), CachedFile { modified, snippets });
+        }
+        &self.files[name].snippets
+    }
+}
+
+/// Read a snippet file, logging and skipping it if it's invalid
+fn load_snippet_file(path: &Path) -> Vec<Snippet> {
+    let result = std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| parse_snippets(&content).map_err(|e| e.to_string()));
+    match result {
+        Ok(snippets) => {
+            tracing::info!("Loaded {} snippets from {}", snippets.len(), path.display());
+            snippets
+        }
+        Err(e) => {
+            tracing::warn!("Invalid snippet file {}: {}", path.display(), e);
+            Vec::new()
+        }
+    }
+}
+
+/// Parse the contents of a snippet file
+fn parse_snippets(content: &str) -> Result<Vec<Snippet>, serde_json::Error> {
+    let entries: BTreeMap<String, SnippetEntry> = serde_json::from_str(content)?;
+    Ok(entries
+        .into_iter()
+        .map(|(name, entry)| Snippet {
+            name,
+            prefixes: entry.prefix.into_vec(),
+            body: entry.body.into_vec().join("\n"),
+            description: entry.description,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_snippets() {
+        let snippets = parse_snippets(
+            r#"{
+                "Print": { "prefix": "pr", "body": "println!(\"$1\");" },
+                "For": {
+                    "prefix": ["for", "loop"],
+                    "body": ["for ${1:x} in $2 {", "\t$0", "}"],
+                    "description": "For loop"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(snippets.len(), 2);
+        assert_eq!(snippets[0].name, "For");
+        assert_eq!(snippets[0].prefixes, vec!["for", "loop"]);
+        assert_eq!(snippets[0].body, "for ${1:x} in $2 {\n\t$0\n}");
+        assert_eq!(snippets[0].description.as_deref(), Some("For loop"));
+        assert_eq!(snippets[1].body, "println!(\"$1\");");
+    }
+
+    #[test]
+    fn test_library_language_and_global_files() {
+        let dir = TempDir::new().unwrap();
+        let snippets_dir = dir.path().join("snippets");
+        std::fs::create_dir_all(&snippets_dir).unwrap();
+        std::fs::write(
+            snippets_dir.join("rust.json"),
+            r#"{ "Test": { "prefix": "test", "body": "assert!($1);" } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            snippets_dir.join("global.json"),
+            r#"{ "Todo": { "prefix": "todo", "body": "TODO: $0" } }"#,
+        )
+        .unwrap();
+
+        let mut library = SnippetLibrary::new(dir.path());
+        let names = |snippets: Vec<Snippet>| -> Vec<String> {
+            snippets.into_iter().map(|snippet| snippet.name).collect()
+        };
+        assert_eq!(names(library.for_language("rust")), vec!["Test", "Todo"]);
+        assert_eq!(names(library.for_language("python")), vec!["Todo"]);
+        assert_eq!(names(library.matching("rust", "TE")), vec!["Test"]);
+    }
+}
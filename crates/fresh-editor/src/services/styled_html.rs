@@ -1,33 +1,129 @@
-//! Styled text rendering for clipboard copy feature
+//! Styled text rendering for clipboard copy and export
 //!
 //! This module renders styled text with syntax highlighting as HTML
-//! for pasting into rich text editors (Google Docs, Word, etc.)
+//! for pasting into rich text editors (Google Docs, Word, etc.), and as
+//! HTML fragments or ANSI text for sharing snippets.
 
 use crate::primitives::highlighter::HighlightSpan;
 use crate::view::theme::Theme;
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier};
+
+/// RGB channels of a color, or None for the terminal's default
+fn color_to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    Some(match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black | Color::Indexed(0) => (0x00, 0x00, 0x00),
+        Color::Red | Color::Indexed(1) => (0xcd, 0x31, 0x31),
+        Color::Green | Color::Indexed(2) => (0x0d, 0xbc, 0x79),
+        Color::Yellow | Color::Indexed(3) => (0xe5, 0xe5, 0x10),
+        Color::Blue | Color::Indexed(4) => (0x24, 0x72, 0xc8),
+        Color::Magenta | Color::Indexed(5) => (0xbc, 0x3f, 0xbc),
+        Color::Cyan | Color::Indexed(6) => (0x11, 0xa8, 0xcd),
+        Color::Gray | Color::Indexed(7) => (0x80, 0x80, 0x80),
+        Color::DarkGray | Color::Indexed(8) => (0x50, 0x50, 0x50),
+        Color::LightRed | Color::Indexed(9) => (0xf1, 0x4c, 0x4c),
+        Color::LightGreen | Color::Indexed(10) => (0x23, 0xd1, 0x8b),
+        Color::LightYellow | Color::Indexed(11) => (0xf5, 0xf5, 0x43),
+        Color::LightBlue | Color::Indexed(12) => (0x3b, 0x8e, 0xea),
+        Color::LightMagenta | Color::Indexed(13) => (0xd6, 0x70, 0xd6),
+        Color::LightCyan | Color::Indexed(14) => (0x29, 0xb8, 0xdb),
+        Color::White | Color::Indexed(15) => (0xe5, 0xe5, 0xe5),
+        // 6x6x6 color cube
+        Color::Indexed(i @ 16..=231) => {
+            let level = |n: u8| if n == 0 { 0 } else { 55 + n * 40 };
+            let i = i - 16;
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        // Grayscale ramp
+        Color::Indexed(i) => {
+            let gray = 8 + (i - 232) * 10;
+            (gray, gray, gray)
+        }
+        Color::Reset => return None,
+    })
+}
 
 /// Convert a ratatui Color to a CSS hex color string
-fn color_to_css(color: Color, default: &str) -> String {
-    match color {
-        Color::Rgb(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
-        Color::Black => "#000000".to_string(),
-        Color::Red => "#cd3131".to_string(),
-        Color::Green => "#0dbc79".to_string(),
-        Color::Yellow => "#e5e510".to_string(),
-        Color::Blue => "#2472c8".to_string(),
-        Color::Magenta => "#bc3fbc".to_string(),
-        Color::Cyan => "#11a8cd".to_string(),
-        Color::Gray => "#808080".to_string(),
-        Color::DarkGray => "#505050".to_string(),
-        Color::LightRed => "#f14c4c".to_string(),
-        Color::LightGreen => "#23d18b".to_string(),
-        Color::LightYellow => "#f5f543".to_string(),
-        Color::LightBlue => "#3b8eea".to_string(),
-        Color::LightMagenta => "#d670d6".to_string(),
-        Color::LightCyan => "#29b8db".to_string(),
-        Color::White => "#e5e5e5".to_string(),
-        Color::Reset | Color::Indexed(_) => default.to_string(),
+///
+/// Named and indexed colors use the standard terminal palette; `default` is
+/// used for `Reset`.
+pub fn color_to_css(color: Color, default: &str) -> String {
+    match color_to_rgb(color) {
+        Some((r, g, b)) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        None => default.to_string(),
+    }
+}
+
+/// Options for exported snippets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Number the lines, starting with this one
+    pub first_line_number: Option<usize>,
+}
+
+/// Style of a run of text, None where no span applies
+type RunStyle = Option<(Color, Modifier)>;
+
+/// The lines of `text`, each split into runs of the same style
+///
+/// Line ends are left out of the runs; the bool says whether the line had
+/// one.
+fn styled_lines<'a>(
+    text: &'a str,
+    highlight_spans: &[HighlightSpan],
+) -> Vec<(Vec<(&'a str, RunStyle)>, bool)> {
+    // Build a map of byte offset to style for quick lookup
+    let mut style_map: Vec<RunStyle> = vec![None; text.len()];
+    for span in highlight_spans {
+        let start = span.range.start.min(text.len());
+        let end = span.range.end.min(text.len());
+        for slot in &mut style_map[start..end] {
+            *slot = Some((span.color, span.modifiers));
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        let has_newline = line.ends_with('\n');
+        let content = line.strip_suffix('\n').unwrap_or(line);
+        let mut runs: Vec<(&str, RunStyle)> = Vec::new();
+        let mut run_start = 0;
+        for (offset, _) in content.char_indices() {
+            if offset > run_start
+                && style_map[line_start + offset] != style_map[line_start + run_start]
+            {
+                runs.push((
+                    &content[run_start..offset],
+                    style_map[line_start + run_start],
+                ));
+                run_start = offset;
+            }
+        }
+        if run_start < content.len() {
+            runs.push((&content[run_start..], style_map[line_start + run_start]));
+        }
+        lines.push((runs, has_newline));
+        line_start += line.len();
+    }
+    lines
+}
+
+/// Width of the line number column for `line_count` lines from `first`
+fn gutter_width(first: usize, line_count: usize) -> usize {
+    (first + line_count.saturating_sub(1)).to_string().len()
+}
+
+fn push_html_escaped(html: &mut String, text: &str) {
+    for ch in text.chars() {
+        match ch {
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '&' => html.push_str("&amp;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            _ => html.push(ch),
+        }
     }
 }
 
@@ -45,80 +141,127 @@ fn color_to_css(color: Color, default: &str) -> String {
 /// # Returns
 /// HTML string with inline styles
 pub fn render_styled_html(text: &str, highlight_spans: &[HighlightSpan], theme: &Theme) -> String {
+    render_html(text, highlight_spans, theme, ExportOptions::default())
+}
+
+/// Render styled text to a standalone HTML fragment with inline styles,
+/// optionally with line numbers in the theme's gutter color
+pub fn render_html(
+    text: &str,
+    highlight_spans: &[HighlightSpan],
+    theme: &Theme,
+    options: ExportOptions,
+) -> String {
     let bg_color = color_to_css(theme.editor_bg, "#1e1e1e");
     let fg_color = color_to_css(theme.editor_fg, "#d4d4d4");
+    let line_number_color = color_to_css(theme.line_number_fg, &fg_color);
 
-    // Build a map of byte offset to color for quick lookup
-    let mut color_map: Vec<Option<Color>> = vec![None; text.len()];
-    for span in highlight_spans {
-        let start = span.range.start.min(text.len());
-        let end = span.range.end.min(text.len());
-        for slot in &mut color_map[start..end] {
-            *slot = Some(span.color);
-        }
-    }
-
-    // Build HTML with spans for colored regions
     let mut html = String::new();
     html.push_str(&format!(
         "<pre style=\"background-color:{};color:{};font-family:'Fira Mono','Fira Code',Consolas,'Courier New',monospace;font-size:14px;padding:12px 16px;border-radius:6px;margin:0;white-space:pre;overflow-x:auto;\">",
         bg_color, fg_color
     ));
 
-    let mut current_color: Option<Color> = None;
-    let mut span_open = false;
-    let mut byte_offset = 0;
-
-    for ch in text.chars() {
-        let char_byte_len = ch.len_utf8();
-
-        // Get color for this character
-        let char_color = if byte_offset < color_map.len() {
-            color_map[byte_offset]
-        } else {
-            None
-        };
-
-        // Check if we need to change the color span
-        if char_color != current_color {
-            // Close previous span if open
-            if span_open {
-                html.push_str("</span>");
-                span_open = false;
-            }
-
-            // Open new span if we have a color
-            if let Some(color) = char_color {
-                let css_color = color_to_css(color, &fg_color);
-                html.push_str(&format!("<span style=\"color:{};\">", css_color));
-                span_open = true;
+    let lines = styled_lines(text, highlight_spans);
+    let width = options
+        .first_line_number
+        .map(|first| gutter_width(first, lines.len()));
+    for (index, (runs, has_newline)) in lines.iter().enumerate() {
+        if let (Some(first), Some(width)) = (options.first_line_number, width) {
+            html.push_str(&format!(
+                "<span style=\"color:{};user-select:none;\">{:>width$} </span>",
+                line_number_color,
+                first + index,
+                width = width
+            ));
+        }
+        for (run, style) in runs {
+            match style {
+                Some((color, modifiers)) => {
+                    html.push_str(&format!(
+                        "<span style=\"color:{};",
+                        color_to_css(*color, &fg_color)
+                    ));
+                    if modifiers.contains(Modifier::BOLD) {
+                        html.push_str("font-weight:bold;");
+                    }
+                    if modifiers.contains(Modifier::ITALIC) {
+                        html.push_str("font-style:italic;");
+                    }
+                    if modifiers.contains(Modifier::UNDERLINED) {
+                        html.push_str("text-decoration:underline;");
+                    }
+                    html.push_str("\">");
+                    push_html_escaped(&mut html, run);
+                    html.push_str("</span>");
+                }
+                None => push_html_escaped(&mut html, run),
             }
-
-            current_color = char_color;
         }
-
-        // Escape HTML special characters and add the character
-        match ch {
-            '<' => html.push_str("&lt;"),
-            '>' => html.push_str("&gt;"),
-            '&' => html.push_str("&amp;"),
-            '"' => html.push_str("&quot;"),
-            '\'' => html.push_str("&#39;"),
-            _ => html.push(ch),
+        if *has_newline {
+            html.push('\n');
         }
-
-        byte_offset += char_byte_len;
-    }
-
-    // Close any remaining span
-    if span_open {
-        html.push_str("</span>");
     }
 
     html.push_str("</pre>");
     html
 }
 
+/// SGR parameters selecting a 24-bit foreground color
+fn ansi_fg(color: Color) -> Option<String> {
+    color_to_rgb(color).map(|(r, g, b)| format!("38;2;{};{};{}", r, g, b))
+}
+
+/// Render styled text with 24-bit ANSI color escapes, for pasting into a
+/// terminal or chat that shows them
+///
+/// Unhighlighted text keeps the terminal's own colors.
+pub fn render_ansi(
+    text: &str,
+    highlight_spans: &[HighlightSpan],
+    theme: &Theme,
+    options: ExportOptions,
+) -> String {
+    let mut out = String::new();
+    let lines = styled_lines(text, highlight_spans);
+    let width = options
+        .first_line_number
+        .map(|first| gutter_width(first, lines.len()));
+    for (index, (runs, has_newline)) in lines.iter().enumerate() {
+        if let (Some(first), Some(width)) = (options.first_line_number, width) {
+            let number = format!("{:>width$} ", first + index, width = width);
+            match ansi_fg(theme.line_number_fg) {
+                Some(sgr) => out.push_str(&format!("\x1b[{}m{}\x1b[0m", sgr, number)),
+                None => out.push_str(&number),
+            }
+        }
+        for (run, style) in runs {
+            let mut params: Vec<String> = Vec::new();
+            if let Some((color, modifiers)) = style {
+                if modifiers.contains(Modifier::BOLD) {
+                    params.push("1".to_string());
+                }
+                if modifiers.contains(Modifier::ITALIC) {
+                    params.push("3".to_string());
+                }
+                if modifiers.contains(Modifier::UNDERLINED) {
+                    params.push("4".to_string());
+                }
+                params.extend(ansi_fg(*color));
+            }
+            if params.is_empty() {
+                out.push_str(run);
+            } else {
+                out.push_str(&format!("\x1b[{}m{}\x1b[0m", params.join(";"), run));
+            }
+        }
+        if *has_newline {
+            out.push('\n');
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,6 +301,7 @@ mod tests {
         let spans = vec![HighlightSpan {
             range: Range { start: 0, end: 2 },
             color: Color::Blue,
+            modifiers: ratatui::style::Modifier::empty(),
         }];
         let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
 
@@ -168,6 +312,83 @@ mod tests {
         assert!(html.contains("main()"));
     }
 
+    /// Spans for the keywords, names and string of [`RUST_FIXTURE`]
+    const RUST_FIXTURE: &str = "fn main() {\n    let s = \"<hi>\";\n    println!(\"{}\", s);\n}\n";
+
+    fn rust_fixture_spans() -> Vec<HighlightSpan> {
+        let keyword = (Color::Rgb(86, 156, 214), Modifier::BOLD);
+        let function = (Color::Rgb(220, 220, 170), Modifier::empty());
+        let string = (Color::Rgb(206, 145, 120), Modifier::ITALIC);
+        [
+            ("fn", keyword),
+            ("main", function),
+            ("let", keyword),
+            ("\"<hi>\"", string),
+            ("println!", function),
+            ("\"{}\"", string),
+        ]
+        .into_iter()
+        .map(|(token, (color, modifiers))| {
+            let start = RUST_FIXTURE.find(token).unwrap();
+            HighlightSpan {
+                range: start..start + token.len(),
+                color,
+                modifiers,
+            }
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_export_html_snapshot() {
+        let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let html = render_html(
+            RUST_FIXTURE,
+            &rust_fixture_spans(),
+            &theme,
+            ExportOptions {
+                first_line_number: Some(9),
+            },
+        );
+        insta::assert_snapshot!("export_html", html);
+    }
+
+    #[test]
+    fn test_export_ansi_snapshot() {
+        let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let ansi = render_ansi(
+            RUST_FIXTURE,
+            &rust_fixture_spans(),
+            &theme,
+            ExportOptions {
+                first_line_number: Some(9),
+            },
+        );
+        // Escapes written out so the snapshot is readable
+        insta::assert_snapshot!("export_ansi", ansi.replace('\x1b', "\\x1b"));
+
+        let plain = render_ansi(
+            RUST_FIXTURE,
+            &rust_fixture_spans(),
+            &theme,
+            ExportOptions::default(),
+        );
+        let mut parts = plain.split('\x1b');
+        let mut stripped = parts.next().unwrap_or_default().to_string();
+        for part in parts {
+            stripped.push_str(part.split_once('m').map_or(part, |(_, rest)| rest));
+        }
+        assert_eq!(stripped, RUST_FIXTURE);
+    }
+
+    #[test]
+    fn test_color_to_css_indexed() {
+        assert_eq!(color_to_css(Color::Indexed(1), "#fff"), "#cd3131");
+        assert_eq!(color_to_css(Color::Indexed(16), "#fff"), "#000000");
+        assert_eq!(color_to_css(Color::Indexed(196), "#fff"), "#ff0000");
+        assert_eq!(color_to_css(Color::Indexed(244), "#fff"), "#808080");
+    }
+
     #[test]
     fn test_color_to_css() {
         assert_eq!(color_to_css(Color::Black, "#fff"), "#000000");
@@ -0,0 +1,253 @@
+//! File locations and hyperlinks in terminal output
+//!
+//! Lines are matched against the regexes from `terminal.link_patterns`. A
+//! pattern marks the path with a `file` group and may capture `line` and
+//! `col` groups for the position, e.g. `src/main.rs:42:7` from rustc or
+//! `File "app.py", line 3` from a Python traceback. OSC 8 hyperlinks emitted
+//! by tools are recognized from the cells themselves and take precedence over
+//! pattern matches.
+//!
+//! Only lines that are actually rendered (or clicked) are scanned, so the cost
+//! is bounded by the size of the visible terminal.
+
+use super::TerminalCell;
+use regex::Regex;
+
+/// Where a link points
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    /// A location in a file; line and column are 1-based
+    File {
+        path: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    /// A URI from an OSC 8 hyperlink that isn't a `file://` URI
+    Uri(String),
+}
+
+/// A link found in one line of terminal output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalLink {
+    /// Column of the first character of the link
+    pub start: usize,
+    /// Column just past the last character of the link
+    pub end: usize,
+    pub target: LinkTarget,
+}
+
+impl TerminalLink {
+    /// Whether the link covers the given column
+    pub fn contains(&self, column: usize) -> bool {
+        self.start <= column && column < self.end
+    }
+}
+
+/// Finds links in terminal lines using the configured patterns
+#[derive(Debug, Clone, Default)]
+pub struct LinkMatcher {
+    patterns: Vec<Regex>,
+}
+
+impl LinkMatcher {
+    /// Compile the configured patterns; invalid ones are logged and skipped
+    pub fn new(patterns: &[String]) -> Self {
+        let patterns = patterns
+            .iter()
+            .filter_map(|pattern| match Regex::new(pattern) {
+                Ok(regex) if regex.capture_names().any(|name| name == Some("file")) => Some(regex),
+                Ok(_) => {
+                    tracing::warn!(
+                        "Ignoring terminal link pattern without a `file` group: {}",
+                        pattern
+                    );
+                    None
+                }
+                Err(e) => {
+                    tracing::warn!("Ignoring invalid terminal link pattern {}: {}", pattern, e);
+                    None
+                }
+            })
+            .collect();
+        Self { patterns }
+    }
+
+    /// Find the links in a row of terminal cells
+    pub fn find_links(&self, cells: &[TerminalCell]) -> Vec<TerminalLink> {
+        let mut links: Vec<TerminalLink> = Vec::new();
+
+        // OSC 8 hyperlinks: runs of cells sharing the same URI
+        let mut col = 0;
+        while col < cells.len() {
+            let Some(uri) = &cells[col].hyperlink else {
+                col += 1;
+                continue;
+            };
+            let start = col;
+            while col < cells.len() && cells[col].hyperlink.as_ref() == Some(uri) {
+                col += 1;
+            }
+            links.push(TerminalLink {
+                start,
+                end: col,
+                target: uri_target(uri),
+            });
+        }
+
+        let text: String = cells.iter().map(|cell| cell.c).collect();
+        for link in self.find_in_text(&text) {
+            if !links
+                .iter()
+                .any(|other| link.start < other.end && other.start < link.end)
+            {
+                links.push(link);
+            }
+        }
+
+        links.sort_by_key(|link| link.start);
+        links
+    }
+
+    /// Find pattern matches in a line of text; columns count characters
+    pub fn find_in_text(&self, text: &str) -> Vec<TerminalLink> {
+        let mut links: Vec<TerminalLink> = Vec::new();
+
+        for regex in &self.patterns {
+            for captures in regex.captures_iter(text) {
+                let (Some(whole), Some(file)) = (captures.get(0), captures.name("file")) else {
+                    continue;
+                };
+                let number = |name: &str| {
+                    captures
+                        .name(name)
+                        .and_then(|m| m.as_str().parse::<usize>().ok())
+                };
+                let link = TerminalLink {
+                    start: text[..whole.start()].chars().count(),
+                    end: text[..whole.end()].chars().count(),
+                    target: LinkTarget::File {
+                        path: file.as_str().to_string(),
+                        line: number("line"),
+                        column: number("col"),
+                    },
+                };
+
+                // Earlier patterns win when matches overlap
+                if !links
+                    .iter()
+                    .any(|other| link.start < other.end && other.start < link.end)
+                {
+                    links.push(link);
+                }
+            }
+        }
+
+        links.sort_by_key(|link| link.start);
+        links
+    }
+}
+
+/// Turn an OSC 8 URI into a link target, treating `file://` URIs as files
+fn uri_target(uri: &str) -> LinkTarget {
+    match uri.strip_prefix("file://") {
+        // Drop the host part: file://host/path -> /path
+        Some(rest) => LinkTarget::File {
+            path: rest.find('/').map_or(rest, |i| &rest[i..]).to_string(),
+            line: None,
+            column: None,
+        },
+        None => LinkTarget::Uri(uri.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TerminalConfig;
+
+    fn matcher() -> LinkMatcher {
+        LinkMatcher::new(&TerminalConfig::default().link_patterns)
+    }
+
+    fn file(path: &str, line: Option<usize>, column: Option<usize>) -> LinkTarget {
+        LinkTarget::File {
+            path: path.to_string(),
+            line,
+            column,
+        }
+    }
+
+    fn cells(text: &str) -> Vec<TerminalCell> {
+        text.chars()
+            .map(|c| TerminalCell {
+                c,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rustc_location() {
+        let links = matcher().find_in_text("  --> src/main.rs:42:7");
+        assert_eq!(
+            links,
+            vec![TerminalLink {
+                start: 6,
+                end: 22,
+                target: file("src/main.rs", Some(42), Some(7)),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_grep_and_gcc_locations() {
+        let links = matcher().find_in_text("lib/util.c:10: int x;");
+        assert_eq!(links[0].target, file("lib/util.c", Some(10), None));
+
+        let links = matcher().find_in_text("main.cpp:3:14: error: expected ';'");
+        assert_eq!(links[0].target, file("main.cpp", Some(3), Some(14)));
+    }
+
+    #[test]
+    fn test_python_traceback() {
+        let links = matcher().find_in_text(r#"  File "/tmp/app.py", line 12, in main"#);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].target, file("/tmp/app.py", Some(12), None));
+        assert_eq!(links[0].start, 2);
+    }
+
+    #[test]
+    fn test_columns_count_characters() {
+        let links = matcher().find_in_text("→ ä.rs:1");
+        assert_eq!(links[0].start, 2);
+        assert_eq!(links[0].end, 8);
+        assert!(links[0].contains(7));
+        assert!(!links[0].contains(8));
+    }
+
+    #[test]
+    fn test_osc8_hyperlinks() {
+        let mut row = cells("see docs and x.rs:1");
+        for cell in &mut row[4..8] {
+            cell.hyperlink = Some("https://example.com/docs".to_string());
+        }
+        for cell in &mut row[13..19] {
+            cell.hyperlink = Some("file://host/src/x.rs".to_string());
+        }
+
+        let links = matcher().find_links(&row);
+        assert_eq!(links.len(), 2);
+        assert_eq!(
+            links[0].target,
+            LinkTarget::Uri("https://example.com/docs".to_string())
+        );
+        // The hyperlink wins over the pattern match it covers
+        assert_eq!(links[1].target, file("/src/x.rs", None, None));
+    }
+
+    #[test]
+    fn test_invalid_patterns_are_skipped() {
+        let matcher = LinkMatcher::new(&["(".to_string(), r"(\w+):(\d+)".to_string()]);
+        assert!(matcher.find_in_text("foo.rs:1").is_empty());
+    }
+}
@@ -55,6 +55,8 @@ pub struct TerminalHandle {
     cwd: Option<std::path::PathBuf>,
     /// Shell executable used to spawn the terminal
     shell: String,
+    /// Exit code of the process, once it has exited
+    exit_code: Arc<Mutex<Option<u32>>>,
 }
 
 impl TerminalHandle {
@@ -106,6 +108,11 @@ impl TerminalHandle {
     pub fn shell(&self) -> &str {
         &self.shell
     }
+
+    /// Exit code of the terminal's process, or None while it is running
+    pub fn exit_code(&self) -> Option<u32> {
+        self.exit_code.lock().ok().and_then(|code| *code)
+    }
 }
 
 /// Manager for multiple terminal sessions
@@ -156,6 +163,21 @@ impl TerminalManager {
         cwd: Option<std::path::PathBuf>,
         log_path: Option<std::path::PathBuf>,
         backing_path: Option<std::path::PathBuf>,
+    ) -> Result<TerminalId, String> {
+        self.spawn_with_command(cols, rows, cwd, log_path, backing_path, None)
+    }
+
+    /// Spawn a terminal session that runs `command` through the shell instead
+    /// of an interactive shell. The terminal exits when the command does, and
+    /// its exit code is available from [`TerminalHandle::exit_code`].
+    pub fn spawn_with_command(
+        &mut self,
+        cols: u16,
+        rows: u16,
+        cwd: Option<std::path::PathBuf>,
+        log_path: Option<std::path::PathBuf>,
+        backing_path: Option<std::path::PathBuf>,
+        command: Option<&str>,
     ) -> Result<TerminalId, String> {
         let id = TerminalId(self.next_id);
         self.next_id += 1;
@@ -191,6 +213,9 @@ impl TerminalManager {
 
             // Build command
             let mut cmd = CommandBuilder::new(&shell);
+            if let Some(command) = command {
+                cmd.args([shell_command_flag(&shell), command]);
+            }
             if let Some(ref dir) = cwd {
                 cmd.cwd(dir);
             }
@@ -211,6 +236,7 @@ impl TerminalManager {
                 .slave
                 .spawn_command(cmd)
                 .map_err(|e| format!("Failed to spawn shell '{}': {}", shell, e))?;
+            let mut killer = child.clone_killer();
 
             tracing::debug!("Shell process spawned successfully");
 
@@ -235,6 +261,8 @@ impl TerminalManager {
             // Alive flag
             let alive = Arc::new(AtomicBool::new(true));
             let alive_clone = alive.clone();
+            let exit_code = Arc::new(Mutex::new(None));
+            let exit_code_clone = exit_code.clone();
 
             // Get master for I/O
             let mut master = pty_pair
@@ -392,6 +420,15 @@ impl TerminalManager {
                         }
                     }
                 }
+                // Reap the process so its exit code is known before the exit notification
+                match child.wait() {
+                    Ok(status) => {
+                        if let Ok(mut code) = exit_code_clone.lock() {
+                            *code = Some(status.exit_code());
+                        }
+                    }
+                    Err(e) => tracing::warn!("Failed to wait for terminal process: {}", e),
+                }
                 alive_clone.store(false, std::sync::atomic::Ordering::Relaxed);
                 // Best-effort flush of log/backing files during teardown.
                 if let Some(mut w) = log_writer {
@@ -440,11 +477,10 @@ impl TerminalManager {
                         }
                     }
                 }
-                // Best-effort child process cleanup during teardown.
-                #[allow(clippy::let_underscore_must_use)]
-                let _ = child.kill();
+                // Best-effort child process cleanup during teardown; the reader
+                // thread reaps it once the PTY closes.
                 #[allow(clippy::let_underscore_must_use)]
-                let _ = child.wait();
+                let _ = killer.kill();
             });
 
             // Create handle
@@ -456,6 +492,7 @@ impl TerminalManager {
                 rows,
                 cwd: cwd.clone(),
                 shell,
+                exit_code,
             })
         })();
 
@@ -533,6 +570,18 @@ impl Drop for TerminalManager {
     }
 }
 
+/// Flag that makes `shell` run a command line and exit
+fn shell_command_flag(shell: &str) -> &'static str {
+    let name = shell.to_lowercase();
+    if name.contains("powershell") || name.contains("pwsh") {
+        "-Command"
+    } else if name.ends_with("cmd.exe") || name.ends_with("cmd") {
+        "/C"
+    } else {
+        "-c"
+    }
+}
+
 /// Detect the user's shell
 pub fn detect_shell() -> String {
     // Try $SHELL environment variable first
@@ -597,4 +646,11 @@ mod tests {
         let shell = detect_shell();
         assert!(!shell.is_empty());
     }
+
+    #[test]
+    fn test_shell_command_flag() {
+        assert_eq!(shell_command_flag("/bin/bash"), "-c");
+        assert_eq!(shell_command_flag(r"C:\Windows\System32\cmd.exe"), "/C");
+        assert_eq!(shell_command_flag("pwsh.exe"), "-Command");
+    }
 }
@@ -43,13 +43,16 @@
 //! ## Module Responsibilities
 //!
 //! - `term.rs`: Terminal state and incremental streaming methods
+//! - `links.rs`: File locations and hyperlinks in terminal output
 //! - `manager.rs`: PTY lifecycle and read loop with streaming
 //! - `../app/terminal.rs`: Mode switching logic
 //! - `../app/session.rs`: Session save/restore integration
 
+pub mod links;
 mod manager;
 pub mod pty;
 pub mod term;
 
+pub use links::{LinkMatcher, LinkTarget, TerminalLink};
 pub use manager::{detect_shell, TerminalId, TerminalManager};
 pub use term::{TerminalCell, TerminalState};
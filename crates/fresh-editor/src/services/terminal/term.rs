@@ -210,6 +210,7 @@ impl TerminalState {
             let italic = flags.contains(Flags::ITALIC);
             let underline = flags.contains(Flags::UNDERLINE);
             let inverse = flags.contains(Flags::INVERSE);
+            let hyperlink = cell.hyperlink().map(|link| link.uri().to_string());
 
             cells.push(TerminalCell {
                 c,
@@ -219,6 +220,7 @@ impl TerminalState {
                 italic,
                 underline,
                 inverse,
+                hyperlink,
             });
         }
 
@@ -335,6 +337,12 @@ impl TerminalState {
         self.term.mode().contains(TermMode::ALTERNATE_SCROLL)
     }
 
+    /// Check if bracketed paste mode is enabled.
+    /// When enabled, pasted text should be wrapped in `ESC[200~` / `ESC[201~`.
+    pub fn uses_bracketed_paste(&self) -> bool {
+        self.term.mode().contains(TermMode::BRACKETED_PASTE)
+    }
+
     // =========================================================================
     // Incremental scrollback streaming
     // =========================================================================
@@ -572,6 +580,8 @@ pub struct TerminalCell {
     pub underline: bool,
     /// Inverse video flag
     pub inverse: bool,
+    /// Target of an OSC 8 hyperlink covering this cell
+    pub hyperlink: Option<String>,
 }
 
 impl Default for TerminalCell {
@@ -584,6 +594,7 @@ impl Default for TerminalCell {
             italic: false,
             underline: false,
             inverse: false,
+            hyperlink: None,
         }
     }
 }
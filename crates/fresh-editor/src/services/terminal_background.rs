@@ -0,0 +1,344 @@
+//! Terminal background color detection
+//!
+//! When `theme` is set to "auto", the editor asks the terminal for its
+//! background color with OSC 11 (`ESC ] 11 ; ? ST`). Terminals that support it
+//! answer with `ESC ] 11 ; rgb:RRRR/GGGG/BBBB` terminated by BEL or ST, and the
+//! color's luminance decides between `light_theme` and `dark_theme`.
+//!
+//! In client-server mode the server has no TTY: it asks the client to send the
+//! query, and the client relays the answer in a control message.
+
+use crate::config::{Config, ThemeName};
+use std::ops::Range;
+
+/// OSC 11 query for the terminal's background color
+pub const BACKGROUND_QUERY: &[u8] = b"\x1b]11;?\x1b\\";
+
+/// Whether a terminal background is light or dark
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Appearance {
+    Light,
+    Dark,
+}
+
+impl Appearance {
+    /// Classify a background color by its relative luminance
+    pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        let luminance = 0.2126 * f64::from(r) + 0.7152 * f64::from(g) + 0.0722 * f64::from(b);
+        if luminance > 127.5 {
+            Self::Light
+        } else {
+            Self::Dark
+        }
+    }
+}
+
+/// The theme the config selects. "auto" resolves to `light_theme` or
+/// `dark_theme`, assuming a dark background until the terminal reports one.
+pub fn resolve_theme_name(config: &Config, appearance: Option<Appearance>) -> &ThemeName {
+    if !config.theme.is_auto() {
+        return &config.theme;
+    }
+    match appearance {
+        Some(Appearance::Light) => &config.light_theme,
+        Some(Appearance::Dark) | None => &config.dark_theme,
+    }
+}
+
+/// Start of an OSC 11 response
+const RESPONSE_PREFIX: &[u8] = b"\x1b]11;";
+
+/// Longest response worth waiting for; the longest valid one is
+/// `ESC ] 11 ; rgba:RRRR/GGGG/BBBB/AAAA ST`
+const MAX_RESPONSE_LEN: usize = 64;
+
+/// Find an OSC 11 response in bytes read from the terminal.
+/// Returns the byte range of the whole response and the reported color.
+pub fn find_response(bytes: &[u8]) -> Option<(Range<usize>, (u8, u8, u8))> {
+    let start = bytes
+        .windows(RESPONSE_PREFIX.len())
+        .position(|window| window == RESPONSE_PREFIX)?;
+    let body_start = start + RESPONSE_PREFIX.len();
+    let rest = &bytes[body_start..];
+    let (body_len, terminator_len) = rest.iter().enumerate().find_map(|(i, &b)| match b {
+        0x07 => Some((i, 1)),
+        0x1b if rest.get(i + 1) == Some(&b'\\') => Some((i, 2)),
+        _ => None,
+    })?;
+
+    let rgb = parse_color(std::str::from_utf8(&rest[..body_len]).ok()?)?;
+    Some((start..body_start + body_len + terminator_len, rgb))
+}
+
+/// Where an OSC 11 response that hasn't fully arrived yet starts: either an
+/// unterminated response, or a trailing part of its prefix.
+fn incomplete_response_start(bytes: &[u8]) -> Option<usize> {
+    if let Some(start) = bytes
+        .windows(RESPONSE_PREFIX.len())
+        .position(|window| window == RESPONSE_PREFIX)
+    {
+        let rest = &bytes[start + RESPONSE_PREFIX.len()..];
+        let terminated = rest.contains(&0x07) || rest.windows(2).any(|w| w == b"\x1b\\");
+        return (!terminated && bytes.len() - start <= MAX_RESPONSE_LEN).then_some(start);
+    }
+    (1..RESPONSE_PREFIX.len())
+        .rev()
+        .find(|&len| bytes.ends_with(&RESPONSE_PREFIX[..len]))
+        .map(|len| bytes.len() - len)
+}
+
+/// Separates OSC 11 responses from the other input read from a terminal.
+///
+/// Reads can split a response. While one is expected, a partial response at
+/// the end of a read is held back until the rest arrives, so it doesn't reach
+/// the editor as keystrokes.
+#[derive(Debug, Default)]
+pub struct ResponseSplitter {
+    pending: Vec<u8>,
+    expecting: bool,
+}
+
+impl ResponseSplitter {
+    /// Note that a query was sent, so a partial response is worth waiting for
+    pub fn expect_response(&mut self) {
+        self.expecting = true;
+    }
+
+    /// Take bytes read from the terminal. Returns the regular input and the
+    /// colors of the responses found.
+    pub fn feed(&mut self, bytes: &[u8]) -> (Vec<u8>, Vec<(u8, u8, u8)>) {
+        self.pending.extend_from_slice(bytes);
+        let mut input = Vec::new();
+        let mut colors = Vec::new();
+        while let Some((range, rgb)) = find_response(&self.pending) {
+            input.extend_from_slice(&self.pending[..range.start]);
+            self.pending.drain(..range.end);
+            colors.push(rgb);
+            self.expecting = false;
+        }
+
+        let held = self
+            .expecting
+            .then(|| incomplete_response_start(&self.pending))
+            .flatten()
+            .unwrap_or(self.pending.len());
+        input.extend(self.pending.drain(..held));
+        (input, colors)
+    }
+
+    /// Give up on a held partial response, returning it as regular input
+    pub fn flush(&mut self) -> Vec<u8> {
+        if !self.pending.is_empty() {
+            self.expecting = false;
+        }
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// Parse an X11 color spec like `rgb:ffff/ffff/dddd` (1-4 hex digits per component)
+fn parse_color(spec: &str) -> Option<(u8, u8, u8)> {
+    let spec = spec
+        .strip_prefix("rgb:")
+        .or_else(|| spec.strip_prefix("rgba:"))?;
+    let mut components = spec.split('/').map(|part| {
+        if part.is_empty() || part.len() > 4 {
+            return None;
+        }
+        let value = u32::from_str_radix(part, 16).ok()?;
+        let max = (1u32 << (4 * part.len())) - 1;
+        Some((value * 255 / max) as u8)
+    });
+    Some((
+        components.next()??,
+        components.next()??,
+        components.next()??,
+    ))
+}
+
+/// Whether the bytes contain a primary device attributes reply (`ESC [ ? ... c`)
+#[cfg(unix)]
+fn has_device_attributes(bytes: &[u8]) -> bool {
+    bytes.windows(3).enumerate().any(|(i, window)| {
+        window == b"\x1b[?"
+            && bytes[i + 3..]
+                .iter()
+                .find(|b| !(b.is_ascii_digit() || **b == b';'))
+                == Some(&b'c')
+    })
+}
+
+/// Ask the terminal for its background color, waiting briefly for the answer.
+///
+/// Must be called in raw mode while nothing else is reading stdin. A device
+/// attributes request follows the query: every terminal answers it, so the
+/// wait ends early on terminals that ignore OSC 11.
+#[cfg(unix)]
+pub fn query_terminal() -> Option<(u8, u8, u8)> {
+    use nix::poll::{poll, PollFd, PollFlags, PollTimeout};
+    use std::io::{IsTerminal, Write};
+    use std::os::fd::AsFd;
+    use std::time::{Duration, Instant};
+
+    const TIMEOUT: Duration = Duration::from_millis(200);
+
+    // The reply arrives on stdin, which may be a pipe (e.g. `cat file | fresh -`)
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        return None;
+    }
+
+    let mut stdout = std::io::stdout();
+    stdout.write_all(BACKGROUND_QUERY).ok()?;
+    stdout.write_all(b"\x1b[c").ok()?;
+    stdout.flush().ok()?;
+
+    let deadline = Instant::now() + TIMEOUT;
+    let mut response = Vec::new();
+    let mut buf = [0u8; 256];
+    loop {
+        if let Some((_, rgb)) = find_response(&response) {
+            return Some(rgb);
+        }
+        if has_device_attributes(&response) {
+            tracing::debug!("Terminal did not report its background color");
+            return None;
+        }
+
+        let remaining = deadline.checked_duration_since(Instant::now())?;
+        let timeout_ms = remaining.as_millis().min(u16::MAX as u128) as u16;
+        let mut fds = [PollFd::new(stdin.as_fd(), PollFlags::POLLIN)];
+        match poll(&mut fds, PollTimeout::from(timeout_ms)) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(nix::errno::Errno::EINTR) => continue,
+            Err(_) => return None,
+        }
+
+        // Read the fd directly: std's buffered stdin would hold on to any
+        // keystrokes that arrive after the reply
+        match nix::unistd::read(stdin.as_fd(), &mut buf) {
+            Ok(0) | Err(_) => return None,
+            Ok(n) => response.extend_from_slice(&buf[..n]),
+        }
+    }
+}
+
+/// Background color queries need a Unix TTY
+#[cfg(not(unix))]
+pub fn query_terminal() -> Option<(u8, u8, u8)> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_response_st_terminated() {
+        let bytes = b"x\x1b]11;rgb:ffff/ffff/dddd\x1b\\y";
+        let (range, rgb) = find_response(bytes).unwrap();
+        assert_eq!(rgb, (255, 255, 221));
+        assert_eq!(&bytes[range], &bytes[1..bytes.len() - 1]);
+    }
+
+    #[test]
+    fn test_find_response_bel_terminated_short_components() {
+        let (range, rgb) = find_response(b"\x1b]11;rgb:1/22/333\x07").unwrap();
+        assert_eq!(rgb, (17, 34, 51));
+        assert_eq!(range, 0..18);
+    }
+
+    #[test]
+    fn test_find_response_incomplete_or_invalid() {
+        assert!(find_response(b"\x1b]11;rgb:ffff/ffff").is_none());
+        assert!(find_response(b"\x1b]11;?\x07").is_none());
+        assert!(find_response(b"\x1b]11;rgb:fffff/0/0\x07").is_none());
+    }
+
+    #[test]
+    fn test_incomplete_response_start() {
+        assert_eq!(incomplete_response_start(b"ab\x1b]11;rgb:ff"), Some(2));
+        assert_eq!(incomplete_response_start(b"ab\x1b]1"), Some(2));
+        assert_eq!(incomplete_response_start(b"ab\x1b"), Some(2));
+        assert_eq!(incomplete_response_start(b"ab\x1b]11;?\x07"), None);
+        assert_eq!(incomplete_response_start(b"abc"), None);
+        let long = [b"\x1b]11;".as_slice(), &[b'0'; MAX_RESPONSE_LEN]].concat();
+        assert_eq!(incomplete_response_start(&long), None);
+    }
+
+    #[test]
+    fn test_splitter_joins_split_response() {
+        let mut splitter = ResponseSplitter::default();
+        splitter.expect_response();
+
+        let (input, colors) = splitter.feed(b"a\x1b]11;rgb:ff");
+        assert_eq!(input, b"a");
+        assert!(colors.is_empty());
+
+        let (input, colors) = splitter.feed(b"ff/ffff/dddd\x1b");
+        assert!(input.is_empty());
+        assert!(colors.is_empty());
+
+        let (input, colors) = splitter.feed(b"\\b");
+        assert_eq!(input, b"b");
+        assert_eq!(colors, vec![(255, 255, 221)]);
+
+        // Nothing is expected any more, so Escape passes straight through
+        let (input, _) = splitter.feed(b"\x1b");
+        assert_eq!(input, b"\x1b");
+    }
+
+    #[test]
+    fn test_splitter_flush_releases_held_input() {
+        let mut splitter = ResponseSplitter::default();
+        splitter.expect_response();
+
+        let (input, _) = splitter.feed(b"\x1b");
+        assert!(input.is_empty());
+        assert_eq!(splitter.flush(), b"\x1b");
+        assert!(splitter.flush().is_empty());
+
+        let (input, _) = splitter.feed(b"\x1b");
+        assert_eq!(input, b"\x1b");
+    }
+
+    #[test]
+    fn test_appearance_from_rgb() {
+        assert_eq!(Appearance::from_rgb(255, 255, 255), Appearance::Light);
+        assert_eq!(Appearance::from_rgb(253, 246, 227), Appearance::Light);
+        assert_eq!(Appearance::from_rgb(0, 0, 0), Appearance::Dark);
+        assert_eq!(Appearance::from_rgb(40, 44, 52), Appearance::Dark);
+        // Saturated blue is dark despite a maxed channel
+        assert_eq!(Appearance::from_rgb(0, 0, 255), Appearance::Dark);
+    }
+
+    #[test]
+    fn test_resolve_theme_name() {
+        let mut config = Config::default();
+        config.theme = ThemeName::from("nostalgia");
+        assert_eq!(
+            resolve_theme_name(&config, Some(Appearance::Light)).0,
+            "nostalgia"
+        );
+
+        config.theme = ThemeName::from(ThemeName::AUTO);
+        assert_eq!(resolve_theme_name(&config, None).0, "dark");
+        assert_eq!(
+            resolve_theme_name(&config, Some(Appearance::Light)).0,
+            "light"
+        );
+        config.dark_theme = ThemeName::from("high-contrast");
+        assert_eq!(
+            resolve_theme_name(&config, Some(Appearance::Dark)).0,
+            "high-contrast"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_has_device_attributes() {
+        assert!(has_device_attributes(b"\x1b[?62;22c"));
+        assert!(!has_device_attributes(b"\x1b[?62;22"));
+        assert!(!has_device_attributes(b"\x1b[?1;2R"));
+    }
+}
@@ -6,6 +6,7 @@
 //! - Mouse capture
 //! - Keyboard enhancement flags
 //! - Bracketed paste
+//! - Focus change reporting
 //!
 //! It provides a `TerminalModes` struct that tracks which modes were enabled
 //! and can restore the terminal to its original state via the `undo()` method.
@@ -14,8 +15,9 @@ use anyhow::Result;
 use crossterm::{
     cursor::SetCursorStyle,
     event::{
-        DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
-        KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
     },
     terminal::{
         disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen,
@@ -88,6 +90,7 @@ pub struct TerminalModes {
     mouse_capture: bool,
     keyboard_enhancement: bool,
     bracketed_paste: bool,
+    focus_change: bool,
 }
 
 impl TerminalModes {
@@ -174,6 +177,15 @@ impl TerminalModes {
             tracing::debug!("Enabled bracketed paste mode");
         }
 
+        // Enable focus change reporting
+        if let Err(e) = stdout().execute(EnableFocusChange) {
+            tracing::warn!("Failed to enable focus change reporting: {}", e);
+            // Non-fatal, continue without it
+        } else {
+            modes.focus_change = true;
+            tracing::debug!("Enabled focus change reporting");
+        }
+
         Ok(modes)
     }
 
@@ -198,6 +210,13 @@ impl TerminalModes {
             tracing::debug!("Disabled bracketed paste");
         }
 
+        // Disable focus change reporting
+        if self.focus_change {
+            let _ = stdout().execute(DisableFocusChange);
+            self.focus_change = false;
+            tracing::debug!("Disabled focus change reporting");
+        }
+
         // Reset cursor style to default
         let _ = stdout().execute(SetCursorStyle::DefaultUserShape);
 
@@ -249,6 +268,11 @@ impl TerminalModes {
         self.bracketed_paste
     }
 
+    /// Returns true if focus change reporting is enabled.
+    pub fn focus_change_enabled(&self) -> bool {
+        self.focus_change
+    }
+
     /// Returns true if alternate screen is enabled.
     pub fn alternate_screen_enabled(&self) -> bool {
         self.alternate_screen
@@ -275,6 +299,9 @@ pub fn emergency_cleanup() {
     // Disable bracketed paste
     let _ = stdout().execute(DisableBracketedPaste);
 
+    // Disable focus change reporting
+    let _ = stdout().execute(DisableFocusChange);
+
     // Reset cursor style to default
     let _ = stdout().execute(SetCursorStyle::DefaultUserShape);
 
@@ -3,67 +3,121 @@
 //! This module provides shared tracing configuration used by both
 //! the main application and tests.
 
-use std::fs::File;
-use std::path::Path;
-use std::sync::Arc;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing_subscriber::filter::LevelFilter;
 use tracing_subscriber::prelude::*;
-use tracing_subscriber::{fmt, EnvFilter};
+use tracing_subscriber::{fmt, reload, EnvFilter, Registry};
 
+use super::rotating_log::RotatingLog;
 use super::status_log::{StatusLogHandle, StatusLogLayer};
 use super::warning_log::{WarningLogHandle, WarningLogLayer};
+use crate::config::LogLevel;
 
 /// Combined handles for all log layers
 pub struct TracingHandles {
     pub warning: WarningLogHandle,
     pub status: StatusLogHandle,
+    pub level: LogLevelHandle,
+    /// The main log file
+    pub log_path: PathBuf,
+}
+
+/// Changes the main log level after the subscriber is installed,
+/// e.g. once the config has been loaded
+#[derive(Clone)]
+pub struct LogLevelHandle(reload::Handle<EnvFilter, Registry>);
+
+impl LogLevelHandle {
+    pub fn set(&self, level: LogLevel) {
+        if let Err(e) = self.0.reload(env_filter(level)) {
+            tracing::warn!("Failed to change log level: {}", e);
+        }
+    }
 }
 
 /// Initialize the global tracing subscriber with file logging and warning/status capture.
 ///
 /// This sets up:
-/// - File-based logging with the given log file
-/// - Environment-based filtering (RUST_LOG) with DEBUG default
+/// - Size-rotated logging to the given log file
+/// - Environment-based filtering (RUST_LOG), falling back to `level`
 /// - Warning log layer that captures WARN+ to a separate file
 /// - Status log layer that captures status messages to a separate file
 ///
+/// Records from the `log` crate are routed through the same subscriber.
+///
 /// Returns the tracing handles if successful, None if setup failed.
-pub fn init_global(log_file_path: &Path) -> Option<TracingHandles> {
+pub fn init_global(log_file_path: &Path, level: LogLevel) -> Option<TracingHandles> {
     let (warning_layer, warning_handle) = super::warning_log::create().ok()?;
     let (status_layer, status_handle) = super::status_log::create().ok()?;
-    let log_file = File::create(log_file_path).ok()?;
+    let log_file = RotatingLog::create(log_file_path).ok()?;
 
-    let subscriber = build_subscriber(log_file, Some(warning_layer), Some(status_layer));
+    let (subscriber, level_handle) =
+        build_subscriber(log_file, level, Some(warning_layer), Some(status_layer));
     subscriber.init();
 
     Some(TracingHandles {
         warning: warning_handle,
         status: status_handle,
+        level: level_handle,
+        log_path: log_file_path.to_path_buf(),
     })
 }
 
+/// Initialize the global tracing subscriber for a session server, logging
+/// only to the given file.
+///
+/// Returns the level handle if successful, None if setup failed.
+pub fn init_server(log_file_path: &Path, level: LogLevel) -> Option<LogLevelHandle> {
+    let log_file = RotatingLog::create(log_file_path).ok()?;
+    let (subscriber, level_handle) = build_subscriber(log_file, level, None, None);
+    subscriber.init();
+    Some(level_handle)
+}
+
 /// Build a subscriber with file logging and optional warning/status layers.
 ///
 /// This is the core subscriber configuration shared between production and tests.
-pub fn build_subscriber(
-    log_file: File,
+pub fn build_subscriber<W: Write + Send + 'static>(
+    log_writer: W,
+    level: LogLevel,
     warning_layer: Option<WarningLogLayer>,
     status_layer: Option<StatusLogLayer>,
-) -> impl tracing::Subscriber + Send + Sync {
-    let env_filter = EnvFilter::from_default_env()
-        .add_directive(tracing::Level::DEBUG.into())
-        // Suppress noisy SWC debug logs
-        .add_directive("swc_ecma_transforms_base=info".parse().unwrap())
-        .add_directive("swc_common=info".parse().unwrap());
+) -> (impl tracing::Subscriber + Send + Sync, LogLevelHandle) {
+    let (filter_layer, filter_handle) = reload::Layer::new(env_filter(level));
 
     let fmt_layer = fmt::layer()
-        .with_writer(Arc::new(log_file))
+        .with_writer(Mutex::new(log_writer))
+        .with_ansi(false)
         .with_span_events(fmt::format::FmtSpan::CLOSE);
 
-    tracing_subscriber::registry()
+    let subscriber = tracing_subscriber::registry()
+        .with(filter_layer)
         .with(fmt_layer)
-        .with(env_filter)
         .with(warning_layer)
-        .with(status_layer)
+        .with(status_layer);
+    (subscriber, LogLevelHandle(filter_handle))
+}
+
+/// RUST_LOG when set, otherwise `level`
+fn env_filter(level: LogLevel) -> EnvFilter {
+    EnvFilter::builder()
+        .with_default_directive(level_filter(level).into())
+        .from_env_lossy()
+        // Suppress noisy SWC debug logs
+        .add_directive("swc_ecma_transforms_base=info".parse().unwrap())
+        .add_directive("swc_common=info".parse().unwrap())
+}
+
+fn level_filter(level: LogLevel) -> LevelFilter {
+    match level {
+        LogLevel::Error => LevelFilter::ERROR,
+        LogLevel::Warn => LevelFilter::WARN,
+        LogLevel::Info => LevelFilter::INFO,
+        LogLevel::Debug => LevelFilter::DEBUG,
+        LogLevel::Trace => LevelFilter::TRACE,
+    }
 }
 
 #[cfg(test)]
@@ -93,8 +147,9 @@ mod tests {
         let (status_layer, _status_handle) =
             super::super::status_log::create_with_path(status_log_path.to_path_buf()).unwrap();
 
-        let subscriber = build_subscriber(
+        let (subscriber, _level) = build_subscriber(
             log_file.reopen().unwrap(),
+            LogLevel::Debug,
             Some(warning_layer),
             Some(status_layer),
         );
@@ -221,4 +276,27 @@ mod tests {
             "Log should contain second warning"
         );
     }
+
+    #[test]
+    fn test_log_level_handle_filters_main_log() {
+        // RUST_LOG takes precedence over the configured level
+        if std::env::var_os("RUST_LOG").is_some() {
+            return;
+        }
+        let log_file = NamedTempFile::new().unwrap();
+        let (subscriber, level) =
+            build_subscriber(log_file.reopen().unwrap(), LogLevel::Debug, None, None);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!("before level change");
+            level.set(LogLevel::Warn);
+            tracing::info!("filtered info");
+            tracing::warn!("kept warning");
+        });
+
+        let contents = std::fs::read_to_string(log_file.path()).unwrap();
+        assert!(contents.contains("before level change"));
+        assert!(!contents.contains("filtered info"));
+        assert!(contents.contains("kept warning"));
+    }
 }
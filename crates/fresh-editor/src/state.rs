@@ -13,8 +13,12 @@ use crate::primitives::detected_language::DetectedLanguage;
 use crate::primitives::grammar::GrammarRegistry;
 use crate::primitives::highlight_engine::HighlightEngine;
 use crate::primitives::indent::IndentCalculator;
+use crate::primitives::indent_pattern::IndentRules;
 use crate::primitives::reference_highlighter::ReferenceHighlighter;
+use crate::primitives::selection_stats::SelectionStats;
+use crate::primitives::snippet::SnippetSession;
 use crate::primitives::text_property::TextPropertyManager;
+use crate::primitives::word_index::WordIndex;
 use crate::view::bracket_highlight_overlay::BracketHighlightOverlay;
 use crate::view::conceal::ConcealManager;
 use crate::view::margin::{MarginAnnotation, MarginContent, MarginManager, MarginPosition};
@@ -65,6 +69,21 @@ pub struct BufferSettings {
     /// Used for visual display of tab characters and indent calculations.
     /// Set based on language config; can be changed per-buffer by user
     pub tab_size: usize,
+
+    /// Bracket and quote pairs auto-closed when typing, as (open, close).
+    /// None uses the defaults for the buffer's language; an empty list
+    /// disables auto-closing. Resolved from config before each edit.
+    pub auto_close_pairs: Option<Vec<(char, char)>>,
+
+    /// Regex rules for auto-indent. Resolved from config before each edit,
+    /// and compiled again only when they change.
+    pub indent_rules: IndentRules,
+
+    /// Keep the cursor and view on the end of a log file as it grows
+    pub follow_tail: bool,
+
+    /// Pattern the lines of a log file must match to be shown
+    pub log_filter: Option<String>,
 }
 
 impl Default for BufferSettings {
@@ -73,6 +92,10 @@ impl Default for BufferSettings {
             whitespace: crate::config::WhitespaceVisibility::default(),
             use_tabs: false,
             tab_size: 4,
+            auto_close_pairs: None,
+            indent_rules: IndentRules::default(),
+            follow_tail: false,
+            log_filter: None,
         }
     }
 }
@@ -158,6 +181,30 @@ pub struct EditorState {
     /// Last-known LSP folding ranges for this buffer
     pub folding_ranges: Vec<FoldingRange>,
 
+    /// Words of the buffer for word completion, built on first use
+    pub word_index: WordIndex,
+
+    /// Size of the selections shown in the status bar, kept between renders
+    pub selection_stats: SelectionStats,
+
+    /// Tabstops of an inserted snippet still being filled in
+    pub snippet_session: Option<SnippetSession>,
+
+    /// Color shown by the swatch of the color literal starting at this byte
+    /// while the literal is being adjusted
+    pub color_preview: Option<(usize, [u8; 3])>,
+
+    /// Field delimiter of a CSV or TSV file, whose fields are colored by
+    /// column
+    pub delimiter: Option<u8>,
+
+    /// Show the fields of a delimited file padded so its columns line up
+    pub aligned_columns: bool,
+
+    /// A log file, whose level words are colored and which can be followed
+    /// and filtered
+    pub log_file: bool,
+
     /// The detected language for this buffer (e.g., "rust", "python", "text")
     pub language: String,
 }
@@ -209,6 +256,13 @@ impl EditorState {
             bracket_highlight_overlay: BracketHighlightOverlay::new(),
             semantic_tokens: None,
             folding_ranges: Vec::new(),
+            word_index: WordIndex::new(),
+            selection_stats: SelectionStats::new(),
+            snippet_session: None,
+            color_preview: None,
+            delimiter: None,
+            aligned_columns: false,
+            log_file: false,
             language: "text".to_string(),
         }
     }
@@ -313,11 +367,17 @@ impl EditorState {
         self.margins.adjust_for_insert(position, text.len());
 
         // Insert text into buffer
+        self.word_index
+            .before_edit(&self.buffer, position, position);
         self.buffer.insert(position, text);
+        self.word_index
+            .after_edit(&self.buffer, position, position + text.len());
+        if let Some(session) = &mut self.snippet_session {
+            session.adjust_for_insert(position, text.len());
+        }
 
         // Invalidate highlight cache for edited range
-        self.highlighter
-            .invalidate_range(position..position + text.len());
+        self.highlighter.adjust_for_insert(position, text.len());
 
         // Note: reference_highlight_overlay uses markers that auto-adjust,
         // so no manual invalidation needed
@@ -362,10 +422,17 @@ impl EditorState {
         self.margins.adjust_for_delete(range.start, len);
 
         // Delete from buffer
+        self.word_index
+            .before_edit(&self.buffer, range.start, range.end);
         self.buffer.delete(range.clone());
+        self.word_index
+            .after_edit(&self.buffer, range.start, range.start);
+        if let Some(session) = &mut self.snippet_session {
+            session.adjust_for_delete(range.start, len);
+        }
 
         // Invalidate highlight cache for edited range
-        self.highlighter.invalidate_range(range.clone());
+        self.highlighter.adjust_for_delete(range.start, len);
 
         // Note: reference_highlight_overlay uses markers that auto-adjust,
         // so no manual invalidation needed
@@ -26,6 +26,8 @@ pub mod context_keys {
     pub const SESSION_MODE: &str = "session_mode";
     pub const VERTICAL_SCROLLBAR: &str = "vertical_scrollbar";
     pub const HORIZONTAL_SCROLLBAR: &str = "horizontal_scrollbar";
+    pub const INDENT_GUIDES: &str = "indent_guides";
+    pub const MINIMAP: &str = "minimap";
     pub const SCROLL_SYNC: &str = "scroll_sync";
     pub const HAS_SAME_BUFFER_SPLITS: &str = "has_same_buffer_splits";
 }
@@ -8,6 +8,8 @@ use crate::model::buffer::Buffer;
 use crate::model::marker::MarkerList;
 use crate::view::overlay::{Overlay, OverlayFace, OverlayManager, OverlayNamespace};
 use ratatui::style::Color;
+use std::collections::HashMap;
+use std::ops::Range;
 
 /// Default rainbow bracket colors (cycle through these based on nesting depth)
 pub const DEFAULT_BRACKET_COLORS: [Color; 6] = [
@@ -59,6 +61,208 @@ fn get_bracket_pair(ch: char) -> Option<(char, char, bool)> {
     None
 }
 
+/// Check if a character is a bracket that can be matched
+pub fn is_bracket(ch: char) -> bool {
+    get_bracket_pair(ch).is_some()
+}
+
+/// Brackets colored by nesting depth and looked for around text; `<`/`>`
+/// are left out since they are usually comparison operators
+const COLORIZED_PAIRS: &[(u8, u8)] = &[(b'(', b')'), (b'[', b']'), (b'{', b'}')];
+
+/// Color every matched bracket pair in `visible` by its nesting depth.
+///
+/// `bytes` holds buffer text starting at byte offset `base`; text before
+/// `visible` provides nesting context and is assumed to start at depth 0.
+/// Unmatched and mismatched brackets keep their syntax color.
+/// Returns a map from bracket byte offset to color.
+pub fn bracket_pair_colors(
+    bytes: &[u8],
+    base: usize,
+    visible: Range<usize>,
+    colors: &[Color],
+) -> HashMap<usize, Color> {
+    let mut result = HashMap::new();
+    if colors.is_empty() {
+        return result;
+    }
+
+    // Open brackets: (byte offset, closing byte)
+    let mut stack: Vec<(usize, u8)> = Vec::new();
+    for (i, &b) in bytes.iter().enumerate() {
+        let pos = base + i;
+        if pos >= visible.end {
+            break;
+        }
+        if let Some(&(_, close)) = COLORIZED_PAIRS.iter().find(|(open, _)| *open == b) {
+            stack.push((pos, close));
+        } else if COLORIZED_PAIRS.iter().any(|(_, close)| *close == b) {
+            if stack.last().is_some_and(|(_, close)| *close == b) {
+                let (open_pos, _) = stack.pop().unwrap();
+                let color = colors[stack.len() % colors.len()];
+                if visible.contains(&open_pos) {
+                    result.insert(open_pos, color);
+                }
+                if visible.contains(&pos) {
+                    result.insert(pos, color);
+                }
+            } else {
+                // A stray closer: the context started inside a deeper level, or
+                // the code is unbalanced. Start over from here.
+                stack.clear();
+            }
+        }
+    }
+
+    // Brackets still open at the end of the visible range close below it;
+    // color them by depth so a block's opener matches its closer off-screen
+    for (depth, (open_pos, _)) in stack.iter().enumerate() {
+        if visible.contains(open_pos) {
+            result.insert(*open_pos, colors[depth % colors.len()]);
+        }
+    }
+
+    result
+}
+
+/// Find the bracket matching the one at `position`, if it is a bracket.
+///
+/// `inert` holds the byte ranges highlighted as strings or comments, sorted
+/// and non-overlapping. A bracket only pairs with brackets in the same
+/// region, so brackets in code skip those inside strings and comments, and a
+/// bracket inside a string is matched within that string.
+pub fn find_matching_bracket(
+    buffer: &Buffer,
+    position: usize,
+    inert: &[Range<usize>],
+) -> Option<usize> {
+    let byte = *buffer.slice_bytes(position..position + 1).first()?;
+    let (opening, closing, forward) = get_bracket_pair(byte as char)?;
+    scan_for_match(
+        buffer,
+        position,
+        opening as u8,
+        closing as u8,
+        forward,
+        inert,
+    )
+}
+
+/// Find the innermost `()`, `[]` or `{}` pair around `start..end`.
+///
+/// Returns the positions of the opening and closing brackets. `inert` is as
+/// for [`find_matching_bracket`]: only brackets in the same string, comment
+/// or code region as `start` are counted.
+pub fn find_enclosing_bracket(
+    buffer: &Buffer,
+    start: usize,
+    end: usize,
+    inert: &[Range<usize>],
+) -> Option<(usize, usize)> {
+    let region = inert_region(inert, start);
+    let search_limit = start.saturating_sub(MAX_BRACKET_SEARCH_BYTES);
+    // Closers passed on the way back, each hiding an opener further back
+    let mut closed: usize = 0;
+    let mut pos = start;
+    while pos > search_limit {
+        let chunk_start = pos.saturating_sub(BRACKET_SCAN_CHUNK).max(search_limit);
+        let chunk = buffer.slice_bytes(chunk_start..pos);
+        for (i, &b) in chunk.iter().enumerate().rev() {
+            let Some(&(open, close)) = COLORIZED_PAIRS
+                .iter()
+                .find(|&&(open, close)| b == open || b == close)
+            else {
+                continue;
+            };
+            if inert_region(inert, chunk_start + i) != region {
+                continue;
+            }
+            if b == close {
+                closed += 1;
+            } else if closed > 0 {
+                closed -= 1;
+            } else if let Some(matching) =
+                scan_for_match(buffer, chunk_start + i, open, close, true, inert)
+            {
+                // An opener closed inside the range doesn't enclose it
+                if matching >= end {
+                    return Some((chunk_start + i, matching));
+                }
+            }
+        }
+        pos = chunk_start;
+    }
+    None
+}
+
+/// Index of the range in `inert` containing `pos`
+fn inert_region(inert: &[Range<usize>], pos: usize) -> Option<usize> {
+    let i = inert.partition_point(|range| range.end <= pos);
+    inert.get(i).filter(|range| range.start <= pos).map(|_| i)
+}
+
+/// Scan from `position` for the bracket closing (or opening) the one there,
+/// bounded to MAX_BRACKET_SEARCH_BYTES and ignoring brackets outside the
+/// starting bracket's region of `inert`
+fn scan_for_match(
+    buffer: &Buffer,
+    position: usize,
+    open: u8,
+    close: u8,
+    forward: bool,
+    inert: &[Range<usize>],
+) -> Option<usize> {
+    let buffer_len = buffer.len();
+    let region = inert_region(inert, position);
+    let mut depth: i32 = 1;
+
+    if forward {
+        let search_limit = (position + 1 + MAX_BRACKET_SEARCH_BYTES).min(buffer_len);
+        let mut pos = position + 1;
+        while pos < search_limit {
+            let chunk_end = (pos + BRACKET_SCAN_CHUNK).min(search_limit);
+            let chunk = buffer.slice_bytes(pos..chunk_end);
+            for (i, &b) in chunk.iter().enumerate() {
+                if (b != open && b != close) || inert_region(inert, pos + i) != region {
+                    continue;
+                }
+                if b == open {
+                    depth += 1;
+                } else {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(pos + i);
+                    }
+                }
+            }
+            pos = chunk_end;
+        }
+    } else {
+        let search_limit = position.saturating_sub(MAX_BRACKET_SEARCH_BYTES);
+        let mut pos = position;
+        while pos > search_limit {
+            let chunk_start = pos.saturating_sub(BRACKET_SCAN_CHUNK).max(search_limit);
+            let chunk = buffer.slice_bytes(chunk_start..pos);
+            for (i, &b) in chunk.iter().enumerate().rev() {
+                if (b != open && b != close) || inert_region(inert, chunk_start + i) != region {
+                    continue;
+                }
+                if b == close {
+                    depth += 1;
+                } else {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(chunk_start + i);
+                    }
+                }
+            }
+            pos = chunk_start;
+        }
+    }
+
+    None
+}
+
 /// Manager for bracket highlight overlays
 pub struct BracketHighlightOverlay {
     /// Whether bracket highlighting is enabled
@@ -218,50 +422,7 @@ impl BracketHighlightOverlay {
         closing: char,
         forward: bool,
     ) -> Option<usize> {
-        let buffer_len = buffer.len();
-        let open = opening as u8;
-        let close = closing as u8;
-        let mut depth: i32 = 1;
-
-        if forward {
-            let search_limit = (position + 1 + MAX_BRACKET_SEARCH_BYTES).min(buffer_len);
-            let mut pos = position + 1;
-            while pos < search_limit {
-                let chunk_end = (pos + BRACKET_SCAN_CHUNK).min(search_limit);
-                let chunk = buffer.slice_bytes(pos..chunk_end);
-                for (i, &b) in chunk.iter().enumerate() {
-                    if b == open {
-                        depth += 1;
-                    } else if b == close {
-                        depth -= 1;
-                        if depth == 0 {
-                            return Some(pos + i);
-                        }
-                    }
-                }
-                pos = chunk_end;
-            }
-        } else {
-            let search_limit = position.saturating_sub(MAX_BRACKET_SEARCH_BYTES);
-            let mut pos = position;
-            while pos > search_limit {
-                let chunk_start = pos.saturating_sub(BRACKET_SCAN_CHUNK).max(search_limit);
-                let chunk = buffer.slice_bytes(chunk_start..pos);
-                for (i, &b) in chunk.iter().enumerate().rev() {
-                    if b == close {
-                        depth += 1;
-                    } else if b == open {
-                        depth -= 1;
-                        if depth == 0 {
-                            return Some(chunk_start + i);
-                        }
-                    }
-                }
-                pos = chunk_start;
-            }
-        }
-
-        None
+        scan_for_match(buffer, position, opening as u8, closing as u8, forward, &[])
     }
 
     /// Force clear all highlights (e.g., when switching buffers)
@@ -344,6 +505,39 @@ mod tests {
         assert_eq!(result, Some(7));
     }
 
+    #[test]
+    fn test_find_matching_bracket_skips_strings_and_comments() {
+        // f(")", x) // (
+        let text = "f(\")\", x) // (";
+        let buffer = Buffer::from_str_test(text);
+        let inert = [2..5, 10..text.len()];
+
+        assert_eq!(find_matching_bracket(&buffer, 1, &inert), Some(8));
+        assert_eq!(find_matching_bracket(&buffer, 8, &inert), Some(1));
+        // Without syntax information the quoted bracket matches
+        assert_eq!(find_matching_bracket(&buffer, 1, &[]), Some(3));
+        // A bracket inside a string looks only inside that string
+        assert_eq!(find_matching_bracket(&buffer, 3, &inert), None);
+        // Not on a bracket
+        assert_eq!(find_matching_bracket(&buffer, 0, &inert), None);
+    }
+
+    #[test]
+    fn test_find_enclosing_bracket() {
+        // f(a, [b], ")") // (
+        let text = "f(a, [b], \")\") // (";
+        let buffer = Buffer::from_str_test(text);
+        let inert = [10..13, 15..text.len()];
+
+        assert_eq!(find_enclosing_bracket(&buffer, 3, 3, &inert), Some((1, 13)));
+        assert_eq!(find_enclosing_bracket(&buffer, 6, 7, &inert), Some((5, 7)));
+        // Past a closed pair on the way back
+        assert_eq!(find_enclosing_bracket(&buffer, 9, 9, &inert), Some((1, 13)));
+        // A range reaching past the brackets isn't inside them
+        assert_eq!(find_enclosing_bracket(&buffer, 6, 9, &inert), Some((1, 13)));
+        assert_eq!(find_enclosing_bracket(&buffer, 0, 0, &inert), None);
+    }
+
     #[test]
     fn test_nesting_depth() {
         let buffer = Buffer::from_str_test("((()))");
@@ -367,4 +561,33 @@ mod tests {
             2
         );
     }
+
+    #[test]
+    fn test_bracket_pair_colors_by_depth() {
+        let colors = [Color::Red, Color::Green, Color::Blue];
+        let text = b"f(a[0], {b: (c)}) < d";
+        let result = bracket_pair_colors(text, 0, 0..text.len(), &colors);
+
+        assert_eq!(result.get(&1), Some(&Color::Red)); // (
+        assert_eq!(result.get(&16), Some(&Color::Red)); // )
+        assert_eq!(result.get(&3), Some(&Color::Green)); // [
+        assert_eq!(result.get(&5), Some(&Color::Green)); // ]
+        assert_eq!(result.get(&8), Some(&Color::Green)); // {
+        assert_eq!(result.get(&12), Some(&Color::Blue)); // (
+        assert_eq!(result.get(&14), Some(&Color::Blue)); // )
+        assert!(!result.contains_key(&18)); // comparison, not a bracket
+    }
+
+    #[test]
+    fn test_bracket_pair_colors_uses_context_before_visible_range() {
+        let colors = [Color::Red, Color::Green];
+        // Visible range starts inside the outer braces
+        let text = b"{ x { y } }";
+        let result = bracket_pair_colors(text, 100, 102..111, &colors);
+
+        assert!(!result.contains_key(&100));
+        assert_eq!(result.get(&104), Some(&Color::Green));
+        assert_eq!(result.get(&108), Some(&Color::Green));
+        assert_eq!(result.get(&110), Some(&Color::Red));
+    }
 }
@@ -31,13 +31,8 @@ impl ColorCapability {
     /// Can be overridden with FRESH_COLOR_MODE env var: "truecolor", "256", or "16"
     pub fn detect() -> Self {
         // Check for manual override first
-        if let Ok(mode) = std::env::var("FRESH_COLOR_MODE") {
-            match mode.to_lowercase().as_str() {
-                "truecolor" | "24bit" | "true" => return ColorCapability::TrueColor,
-                "256" | "256color" => return ColorCapability::Color256,
-                "16" | "basic" | "ansi" => return ColorCapability::Color16,
-                _ => {} // Fall through to auto-detection
-            }
+        if let Some(capability) = Self::from_env_override() {
+            return capability;
         }
 
         // Check TERM first for multiplexers that don't support truecolor
@@ -119,6 +114,34 @@ impl ColorCapability {
         // Default to 256 as safe middle ground
         ColorCapability::Color256
     }
+
+    /// A limit the terminal is known to have: set with FRESH_COLOR_MODE, or
+    /// implied by a TERM that can't show 24-bit color (GNU Screen, the Linux
+    /// console). Unlike `detect`, an unrecognized terminal has no limit.
+    pub fn known_limit() -> Option<Self> {
+        if let Some(capability) = Self::from_env_override() {
+            return Some(capability).filter(|c| *c != ColorCapability::TrueColor);
+        }
+        let term = std::env::var("TERM").ok()?.to_lowercase();
+        if term.starts_with("screen") {
+            Some(ColorCapability::Color256)
+        } else if term == "linux" || term == "cons25" || term == "dumb" {
+            Some(ColorCapability::Color16)
+        } else {
+            None
+        }
+    }
+
+    /// The capability set with FRESH_COLOR_MODE, if any
+    fn from_env_override() -> Option<Self> {
+        let mode = std::env::var("FRESH_COLOR_MODE").ok()?;
+        match mode.to_lowercase().as_str() {
+            "truecolor" | "24bit" | "true" => Some(ColorCapability::TrueColor),
+            "256" | "256color" => Some(ColorCapability::Color256),
+            "16" | "basic" | "ansi" => Some(ColorCapability::Color16),
+            _ => None, // Fall through to auto-detection
+        }
+    }
 }
 
 /// Convert an RGB color to the nearest 256-color palette index
@@ -157,31 +157,26 @@ impl IgnorePatterns {
     }
 
     /// Check if path matches any custom patterns
+    ///
+    /// `dir/` and plain names match a whole path component, `*.ext` matches
+    /// the end of the file name.
     fn matches_custom_pattern(&self, path: &Path) -> bool {
-        let path_str = path.to_string_lossy();
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
 
-        for pattern in &self.custom_patterns {
-            // Simple pattern matching (could be improved with glob crate)
-            if pattern.ends_with('/') {
-                // Directory pattern
-                if path_str.contains(pattern.trim_end_matches('/')) {
-                    return true;
-                }
-            } else if pattern.starts_with('*') {
+        self.custom_patterns.iter().any(|pattern| {
+            if let Some(suffix) = pattern.strip_prefix('*') {
                 // Extension pattern like "*.o"
-                let ext = pattern.trim_start_matches('*');
-                if path_str.ends_with(ext) {
-                    return true;
-                }
+                file_name.ends_with(suffix)
             } else {
-                // Exact match
-                if path_str.contains(pattern) {
-                    return true;
-                }
+                // Directory pattern like "target/", or an exact name
+                let name = pattern.trim_end_matches('/');
+                path.components()
+                    .any(|component| component.as_os_str() == name)
             }
-        }
-
-        false
+        })
     }
 
     /// Set whether to show hidden files
@@ -294,6 +289,12 @@ mod tests {
             patterns.get_status(Path::new("/foo/src/main.rs"), false),
             IgnoreStatus::Visible
         );
+
+        // Names only match whole path components
+        assert_eq!(
+            patterns.get_status(Path::new("/foo/my_target_notes.txt"), false),
+            IgnoreStatus::Visible
+        );
     }
 
     #[test]
@@ -1,11 +1,15 @@
 use super::node::{NodeId, NodeState, TreeNode};
-use crate::model::filesystem::DirEntry;
+use crate::model::filesystem::{DirEntry, EntryType};
 use crate::services::fs::FsManager;
 use std::collections::HashMap;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+/// Path of the synthetic "External" directory that lists files outside the
+/// root. It is relative, so it never collides with a real entry.
+const EXTERNAL_SECTION_PATH: &str = "<external>";
+
 /// File tree with lazy loading support
 ///
 /// The tree starts with just the root node. Directories are only read
@@ -130,28 +134,54 @@ impl FileTree {
             return Ok(());
         }
 
-        // Set state to loading
-        if let Some(node) = self.get_node_mut(id) {
-            node.state = NodeState::Loading;
+        let Some(path) = self.start_loading(id) else {
+            return Ok(());
+        };
+        let result = self.fs_manager.list_dir_with_metadata(path).await;
+        self.finish_loading(id, result)
+    }
+
+    /// Mark a collapsed directory as loading and return the path to list
+    ///
+    /// Pair with [`Self::finish_loading`] once the listing is available; this
+    /// lets the listing run on a background task. Returns `None` when there is
+    /// nothing to read (the node is not a collapsed directory, or is the
+    /// external section, which is expanded immediately).
+    pub fn start_loading(&mut self, id: NodeId) -> Option<PathBuf> {
+        let is_external = self.is_external_section(id);
+        let node = self.get_node_mut(id)?;
+        if !node.is_dir() || node.is_expanded() {
+            return None;
         }
 
-        // Read directory contents with metadata (for file sizes)
-        let path = self.get_node(id).unwrap().entry.path.clone();
-        let result = self.fs_manager.list_dir_with_metadata(path.clone()).await;
+        // The external section has no directory to read; its children are kept
+        if is_external {
+            node.state = NodeState::Expanded;
+            return None;
+        }
+
+        node.state = NodeState::Loading;
+        Some(node.entry.path.clone())
+    }
+
+    /// Fill in the children of a loading directory from its listing
+    ///
+    /// Listings for nodes that are no longer loading (collapsed or removed
+    /// while the read was pending) are ignored.
+    pub fn finish_loading(
+        &mut self,
+        id: NodeId,
+        result: io::Result<Vec<DirEntry>>,
+    ) -> io::Result<()> {
+        if !self.get_node(id).is_some_and(|node| node.is_loading()) {
+            return Ok(());
+        }
 
         match result {
             Ok(entries) => {
-                // Sort entries: directories first, then by name
-                let mut sorted_entries = entries;
-                sorted_entries.sort_by(|a, b| match (a.is_dir(), b.is_dir()) {
-                    (true, false) => std::cmp::Ordering::Less,
-                    (false, true) => std::cmp::Ordering::Greater,
-                    _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                });
-
                 // Create child nodes
                 let mut child_ids = Vec::new();
-                for entry in sorted_entries {
+                for entry in sort_entries(entries) {
                     let child_id = self.add_node(entry, Some(id));
                     child_ids.push(child_id);
                 }
@@ -174,11 +204,81 @@ impl FileTree {
         }
     }
 
+    /// Update the children of an expanded directory from a fresh listing
+    ///
+    /// Unlike [`Self::refresh_node`], entries that still exist keep their
+    /// node, so expanded subdirectories stay expanded. Returns the paths
+    /// that were added or removed.
+    pub fn sync_children(&mut self, id: NodeId, entries: Vec<DirEntry>) -> Vec<PathBuf> {
+        let Some(node) = self.get_node(id) else {
+            return Vec::new();
+        };
+        if !node.is_expanded() {
+            return Vec::new();
+        }
+
+        let mut existing: HashMap<PathBuf, NodeId> = node
+            .children
+            .iter()
+            .filter_map(|&child_id| {
+                self.get_node(child_id)
+                    .map(|child| (child.entry.path.clone(), child_id))
+            })
+            .collect();
+
+        let mut changed = Vec::new();
+        let mut child_ids = Vec::new();
+        for entry in sort_entries(entries) {
+            let kept = existing.remove(&entry.path);
+            match kept {
+                Some(child_id)
+                    if self
+                        .get_node(child_id)
+                        .is_some_and(|child| child.is_dir() == entry.is_dir()) =>
+                {
+                    if let Some(child) = self.get_node_mut(child_id) {
+                        child.entry = entry;
+                    }
+                    child_ids.push(child_id);
+                }
+                _ => {
+                    // New entry, or one whose type changed
+                    if let Some(child_id) = kept {
+                        self.remove_node_recursive(child_id);
+                    }
+                    changed.push(entry.path.clone());
+                    child_ids.push(self.add_node(entry, Some(id)));
+                }
+            }
+        }
+
+        for (path, child_id) in existing {
+            if self.is_external_section(child_id) {
+                child_ids.push(child_id);
+                continue;
+            }
+            self.remove_node_recursive(child_id);
+            changed.push(path);
+        }
+
+        if let Some(node) = self.get_node_mut(id) {
+            node.children = child_ids;
+        }
+        changed
+    }
+
     /// Collapse a directory node
     ///
     /// This removes all child nodes from memory to save space.
     /// They will be reloaded if the directory is expanded again.
     pub fn collapse_node(&mut self, id: NodeId) {
+        if self.is_external_section(id) {
+            if let Some(node) = self.get_node_mut(id) {
+                node.state = NodeState::Collapsed;
+            }
+            return;
+        }
+
         if let Some(node) = self.get_node(id) {
             if !node.is_dir() {
                 return;
@@ -220,11 +320,26 @@ impl FileTree {
 
     /// Refresh a node (re-read directory contents)
     ///
-    /// This is useful when filesystem contents have changed.
+    /// This is useful when filesystem contents have changed. An expanded
+    /// directory is updated in place (see [`Self::sync_children`]); anything
+    /// else is collapsed and re-expanded.
     pub async fn refresh_node(&mut self, id: NodeId) -> io::Result<()> {
-        // Collapse and re-expand
-        self.collapse_node(id);
-        self.expand_node(id).await
+        let node = self
+            .get_node(id)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Node not found"))?;
+
+        if !node.is_expanded() {
+            self.collapse_node(id);
+            return self.expand_node(id).await;
+        }
+        if self.is_external_section(id) {
+            return Ok(());
+        }
+
+        let path = node.entry.path.clone();
+        let entries = self.fs_manager.list_dir_with_metadata(path).await?;
+        self.sync_children(id, entries);
+        Ok(())
     }
 
     /// Get all visible nodes in tree order
@@ -275,6 +390,51 @@ impl FileTree {
         self.path_to_node.get(&full_path).copied()
     }
 
+    /// Add a file outside the root to the synthetic "External" directory,
+    /// which is created at the end of the root's children if needed
+    ///
+    /// The file is listed by its full path. Returns the file's node.
+    pub fn add_external_file(&mut self, path: &Path) -> NodeId {
+        if let Some(&id) = self.path_to_node.get(path) {
+            return id;
+        }
+
+        let section_id = match self.path_to_node.get(Path::new(EXTERNAL_SECTION_PATH)) {
+            Some(&id) => id,
+            None => {
+                let entry = DirEntry::new(
+                    PathBuf::from(EXTERNAL_SECTION_PATH),
+                    "External".to_string(),
+                    EntryType::Directory,
+                );
+                let id = self.add_node(entry, Some(self.root_id));
+                let root_id = self.root_id;
+                if let Some(root) = self.get_node_mut(root_id) {
+                    root.children.push(id);
+                }
+                id
+            }
+        };
+
+        let entry = DirEntry::new(
+            path.to_path_buf(),
+            path.display().to_string(),
+            EntryType::File,
+        );
+        let file_id = self.add_node(entry, Some(section_id));
+        if let Some(section) = self.get_node_mut(section_id) {
+            section.children.push(file_id);
+            section.state = NodeState::Expanded;
+        }
+        file_id
+    }
+
+    /// Check if a node is the synthetic "External" directory
+    pub fn is_external_section(&self, id: NodeId) -> bool {
+        self.get_node(id)
+            .is_some_and(|node| node.entry.path == Path::new(EXTERNAL_SECTION_PATH))
+    }
+
     /// Add a new node to the tree
     fn add_node(&mut self, entry: DirEntry, parent: Option<NodeId>) -> NodeId {
         let id = NodeId(self.next_id);
@@ -386,6 +546,16 @@ impl FileTree {
     }
 }
 
+/// Sort directory entries: directories first, then by name
+fn sort_entries(mut entries: Vec<DirEntry>) -> Vec<DirEntry> {
+    entries.sort_by(|a, b| match (a.is_dir(), b.is_dir()) {
+        (true, false) => std::cmp::Ordering::Less,
+        (false, true) => std::cmp::Ordering::Greater,
+        _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+    });
+    entries
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -627,6 +797,71 @@ mod tests {
         assert_eq!(dir1.children.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_refresh_keeps_expanded_subdirectories() {
+        let (temp_dir, mut tree) = create_test_tree().await;
+        let root_path = temp_dir.path().to_path_buf();
+
+        let subdir_id = tree
+            .expand_to_path(&root_path.join("dir2/subdir"))
+            .await
+            .unwrap();
+        tree.expand_node(subdir_id).await.unwrap();
+        let dir1_id = tree.get_node_by_path(&root_path.join("dir1")).unwrap().id;
+
+        std_fs::write(root_path.join("file5.txt"), "content5").unwrap();
+        std_fs::remove_dir_all(root_path.join("dir1")).unwrap();
+
+        let entries = tree
+            .fs_manager
+            .list_dir_with_metadata(root_path.clone())
+            .await
+            .unwrap();
+        let mut changed = tree.sync_children(tree.root_id(), entries);
+        changed.sort();
+        assert_eq!(
+            changed,
+            vec![root_path.join("dir1"), root_path.join("file5.txt")]
+        );
+
+        // dir1 and its children are gone, dir2/subdir is still expanded
+        assert!(tree.get_node(dir1_id).is_none());
+        assert!(tree
+            .get_node_by_path(&root_path.join("dir1/file1.txt"))
+            .is_none());
+        assert!(tree.get_node(subdir_id).unwrap().is_expanded());
+        assert!(tree
+            .get_node_by_path(&root_path.join("dir2/subdir/file3.txt"))
+            .is_some());
+
+        let names: Vec<_> = tree
+            .get_node(tree.root_id())
+            .unwrap()
+            .children
+            .iter()
+            .map(|&id| tree.get_node(id).unwrap().entry.name.clone())
+            .collect();
+        assert_eq!(names, vec!["dir2", "file4.txt", "file5.txt"]);
+    }
+
+    #[tokio::test]
+    async fn test_listing_ignored_after_collapse() {
+        let (_temp_dir, mut tree) = create_test_tree().await;
+        let root_id = tree.root_id();
+
+        let path = tree.start_loading(root_id).unwrap();
+        assert!(tree.get_node(root_id).unwrap().is_loading());
+        let entries = tree.fs_manager.list_dir_with_metadata(path).await;
+
+        // Collapsed while the listing was pending
+        tree.collapse_node(root_id);
+        tree.finish_loading(root_id, entries).unwrap();
+
+        let root = tree.get_node(root_id).unwrap();
+        assert!(root.is_collapsed());
+        assert!(root.children.is_empty());
+    }
+
     #[tokio::test]
     async fn test_find_by_relative_path() {
         let (_temp_dir, mut tree) = create_test_tree().await;
@@ -723,4 +958,25 @@ mod tests {
 
         assert!(result.is_none(), "Should return None for nonexistent paths");
     }
+
+    #[tokio::test]
+    async fn test_add_external_file() {
+        let (_temp_dir, mut tree) = create_test_tree().await;
+        let root_id = tree.root_id();
+        tree.expand_node(root_id).await.unwrap();
+
+        let outside_path = PathBuf::from("/tmp/somefile.txt");
+        let file_id = tree.add_external_file(&outside_path);
+
+        let section_id = tree.get_node(file_id).unwrap().parent.unwrap();
+        assert!(tree.is_external_section(section_id));
+        assert_eq!(
+            tree.get_node(root_id).unwrap().children.last(),
+            Some(&section_id)
+        );
+        assert!(tree.get_visible_nodes().contains(&file_id));
+
+        // Adding the same path again reuses the node
+        assert_eq!(tree.add_external_file(&outside_path), file_id);
+    }
 }
@@ -4,9 +4,13 @@ use super::search::FileExplorerSearch;
 use super::tree::FileTree;
 use crate::input::fuzzy::FuzzyMatch;
 use crate::model::filesystem::DirEntry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
+/// Maximum number of entries read for the filter, so a huge unignored tree
+/// doesn't fill memory with nodes
+const FILTER_WALK_MAX_ENTRIES: usize = 50_000;
+
 /// View state for file tree navigation and filtering
 #[derive(Debug)]
 pub struct FileTreeView {
@@ -24,6 +28,16 @@ pub struct FileTreeView {
     pub(crate) viewport_height: usize,
     /// Search state for quick navigation
     search: FileExplorerSearch,
+    /// Filter narrowing the tree to matching paths
+    filter: FileExplorerSearch,
+    /// Whether the filter input is open
+    filter_open: bool,
+    /// Directories expanded only so the filter could search them
+    filter_expanded: Vec<NodeId>,
+    /// Directories being read in the background for the filter
+    filter_loading: HashSet<NodeId>,
+    /// Number of entries the filter has read or walked
+    filter_entries: usize,
 }
 
 /// Sort mode for file tree entries
@@ -49,6 +63,11 @@ impl FileTreeView {
             ignore_patterns: IgnorePatterns::new(),
             viewport_height: 10, // Default, will be updated during rendering
             search: FileExplorerSearch::new(),
+            filter: FileExplorerSearch::new(),
+            filter_open: false,
+            filter_expanded: Vec::new(),
+            filter_loading: HashSet::new(),
+            filter_entries: 0,
         }
     }
 
@@ -64,6 +83,11 @@ impl FileTreeView {
 
     /// Recursively collect visible nodes, skipping ignored subtrees.
     fn collect_filtered_visible(&self, id: NodeId, result: &mut Vec<NodeId>) {
+        if self.filter.is_active() {
+            self.collect_filter_matches(id, result);
+            return;
+        }
+
         let is_root = id == self.tree.root_id();
         if !is_root && !self.is_node_visible(id) {
             return;
@@ -80,6 +104,35 @@ impl FileTreeView {
         }
     }
 
+    /// Collect the nodes of a subtree that match the filter, along with the
+    /// directories leading to them. Returns whether anything was collected.
+    fn collect_filter_matches(&self, id: NodeId, result: &mut Vec<NodeId>) -> bool {
+        let is_root = id == self.tree.root_id();
+        if !is_root && !self.is_node_visible(id) {
+            return false;
+        }
+        let Some(node) = self.tree.get_node(id) else {
+            return false;
+        };
+
+        let start = result.len();
+        result.push(id);
+
+        let mut any_child_matches = false;
+        if node.is_expanded() {
+            for &child_id in &node.children {
+                any_child_matches |= self.collect_filter_matches(child_id, result);
+            }
+        }
+
+        if is_root || any_child_matches || self.node_matches_filter(id) {
+            true
+        } else {
+            result.truncate(start);
+            false
+        }
+    }
+
     /// Set the viewport height (should be called during rendering)
     pub fn set_viewport_height(&mut self, height: usize) {
         self.viewport_height = height;
@@ -353,9 +406,11 @@ impl FileTreeView {
         self.ignore_patterns.toggle_show_hidden();
     }
 
-    /// Toggle showing gitignored files
+    /// Toggle showing gitignored files and files matching the custom hidden patterns
     pub fn toggle_show_gitignored(&mut self) {
         self.ignore_patterns.toggle_show_gitignored();
+        let show = self.ignore_patterns.show_gitignored();
+        self.ignore_patterns.set_show_custom_ignored(show);
     }
 
     /// Check if a node should be visible (not filtered by ignore patterns)
@@ -376,11 +431,38 @@ impl FileTreeView {
         self.ignore_patterns.load_gitignore(dir_path)
     }
 
+    /// Apply a fresh listing of an expanded directory
+    ///
+    /// Expansion state is preserved (see [`FileTree::sync_children`]). If the
+    /// selected node was removed, its nearest remaining ancestor is selected.
+    /// Returns the paths that were added or removed.
+    pub fn sync_dir_children(&mut self, id: NodeId, entries: Vec<DirEntry>) -> Vec<PathBuf> {
+        let ancestors = self
+            .selected_node
+            .map(|selected| self.tree.get_ancestors(selected))
+            .unwrap_or_default();
+
+        let changed = self.tree.sync_children(id, entries);
+
+        if !changed.is_empty() {
+            if let Some(selected) = self.selected_node {
+                if self.tree.get_node(selected).is_none() {
+                    self.selected_node = ancestors
+                        .into_iter()
+                        .rev()
+                        .find(|&ancestor| self.tree.get_node(ancestor).is_some());
+                }
+            }
+        }
+        changed
+    }
+
     /// Expand all parent directories and select the given file path
     ///
     /// This is useful for revealing a specific file in the tree when switching
     /// focus to the file explorer. All parent directories will be expanded as needed,
-    /// and the file will be selected.
+    /// and the file will be selected. Files outside the root directory are
+    /// listed under a synthetic "External" directory instead.
     ///
     /// # Arguments
     ///
@@ -390,11 +472,15 @@ impl FileTreeView {
     ///
     /// Returns true if the file was successfully expanded and selected, false otherwise.
     /// This will return false if:
-    /// - The path is not under the root directory
     /// - The path doesn't exist
     /// - There was an error expanding intermediate directories
     pub async fn expand_and_select_file(&mut self, path: &std::path::Path) -> bool {
-        if let Some(node_id) = self.tree.expand_to_path(path).await {
+        let node_id = if path.starts_with(self.tree.root_path()) {
+            self.tree.expand_to_path(path).await
+        } else {
+            Some(self.tree.add_external_file(path))
+        };
+        if let Some(node_id) = node_id {
             self.selected_node = Some(node_id);
             true
         } else {
@@ -458,6 +544,172 @@ impl FileTreeView {
         self.search.clear();
     }
 
+    // ==================== Filter Methods ====================
+
+    /// Get the current filter query
+    pub fn filter_query(&self) -> &str {
+        self.filter.query()
+    }
+
+    /// Check if the filter input is open
+    pub fn is_filter_open(&self) -> bool {
+        self.filter_open
+    }
+
+    /// Open the filter input
+    ///
+    /// Returns the directories to read so the filter also finds files in
+    /// collapsed directories. They are listed in the background, and each
+    /// listing is passed to [`Self::filter_dir_loaded`], so the filter
+    /// narrows the tree as it loads. Ignored and symlinked directories are
+    /// never read.
+    pub fn open_filter(&mut self) -> Vec<(NodeId, PathBuf)> {
+        self.search.clear();
+        self.filter.clear();
+        self.filter_open = true;
+        self.filter_entries = 0;
+        self.start_filter_reads(self.tree.root_id())
+    }
+
+    /// Check if the filter is still reading directories
+    pub fn is_filter_indexing(&self) -> bool {
+        !self.filter_loading.is_empty()
+    }
+
+    /// Check if a directory is being read for the filter
+    pub fn is_filter_loading(&self, id: NodeId) -> bool {
+        self.filter_loading.contains(&id)
+    }
+
+    /// Add the listing of a directory read for the filter, returning the
+    /// directories under it to read next
+    pub fn filter_dir_loaded(
+        &mut self,
+        id: NodeId,
+        result: std::io::Result<Vec<DirEntry>>,
+    ) -> Vec<(NodeId, PathBuf)> {
+        if !self.filter_loading.remove(&id) {
+            return Vec::new();
+        }
+        let Some(dir_path) = self.tree.get_node(id).map(|node| node.entry.path.clone()) else {
+            return Vec::new();
+        };
+        if let Err(e) = self.tree.finish_loading(id, result) {
+            tracing::debug!("Failed to expand {:?} for filter: {}", dir_path, e);
+            return Vec::new();
+        }
+        if let Err(e) = self.ignore_patterns.load_gitignore(&dir_path) {
+            tracing::warn!("Failed to load .gitignore from {:?}: {}", dir_path, e);
+        }
+        self.filter_expanded.push(id);
+
+        // Matches found before this listing stay selected
+        let root_id = self.tree.root_id();
+        let has_match_selected = self
+            .selected_node
+            .is_some_and(|selected| selected != root_id && self.node_matches_filter(selected));
+        if self.filter.is_active() && !has_match_selected {
+            self.select_first_filter_match();
+        }
+
+        self.start_filter_reads(id)
+    }
+
+    /// Walk the loaded directories under `id` and mark the collapsed ones
+    /// the filter should search as loading, returning them
+    fn start_filter_reads(&mut self, id: NodeId) -> Vec<(NodeId, PathBuf)> {
+        let root_id = self.tree.root_id();
+        let mut reads = Vec::new();
+        let mut pending = vec![id];
+        while let Some(id) = pending.pop() {
+            let Some(node) = self.tree.get_node(id) else {
+                continue;
+            };
+            if !node.is_dir() || (id != root_id && !self.is_node_visible(id)) {
+                continue;
+            }
+
+            if node.is_expanded() {
+                self.filter_entries += node.children.len();
+                pending.extend(node.children.iter().rev());
+            } else if !node.entry.is_symlink()
+                && !node.is_loading()
+                && self.filter_entries < FILTER_WALK_MAX_ENTRIES
+            {
+                if let Some(path) = self.tree.start_loading(id) {
+                    self.filter_loading.insert(id);
+                    reads.push((id, path));
+                }
+            }
+        }
+        reads
+    }
+
+    /// Close the filter input and show the whole tree again
+    ///
+    /// Directories expanded for the filter are collapsed again, except the
+    /// ones leading to the selected node, and pending reads are abandoned.
+    pub fn close_filter(&mut self) {
+        self.filter.clear();
+        self.filter_open = false;
+
+        // Listings still on their way are dropped
+        for id in std::mem::take(&mut self.filter_loading) {
+            self.tree.collapse_node(id);
+        }
+
+        let keep: HashSet<NodeId> = self
+            .selected_node
+            .map(|id| self.tree.get_ancestors(id).into_iter().collect())
+            .unwrap_or_default();
+        for id in std::mem::take(&mut self.filter_expanded) {
+            if !keep.contains(&id) {
+                self.tree.collapse_node(id);
+            }
+        }
+        if self
+            .selected_node
+            .is_some_and(|id| self.tree.get_node(id).is_none())
+        {
+            self.selected_node = Some(self.tree.root_id());
+        }
+    }
+
+    /// Add a character to the filter query and select the first match
+    pub fn filter_push_char(&mut self, c: char) {
+        self.filter.push_char(c);
+        self.select_first_filter_match();
+    }
+
+    /// Remove the last character from the filter query
+    pub fn filter_pop_char(&mut self) {
+        self.filter.pop_char();
+        self.select_first_filter_match();
+    }
+
+    /// Check if a node's path (relative to the root) matches the filter
+    fn node_matches_filter(&self, node_id: NodeId) -> bool {
+        self.tree.get_node(node_id).is_some_and(|node| {
+            let path = node
+                .entry
+                .path
+                .strip_prefix(self.tree.root_path())
+                .unwrap_or(node.entry.path.as_path());
+            self.filter.matches(&path.to_string_lossy())
+        })
+    }
+
+    /// Select the first node matching the filter, or the root if nothing matches
+    fn select_first_filter_match(&mut self) {
+        let root_id = self.tree.root_id();
+        let first = self
+            .filtered_visible_nodes()
+            .into_iter()
+            .find(|&id| id != root_id && self.node_matches_filter(id));
+        self.selected_node = Some(first.unwrap_or(root_id));
+        self.update_scroll_for_selection();
+    }
+
     /// Get nodes that match the current search query
     fn get_matching_nodes(&self) -> Vec<NodeId> {
         if !self.search.is_active() {
@@ -543,13 +795,17 @@ impl FileTreeView {
 
     /// Get match result for a node's name (for highlighting)
     pub fn get_match_for_node(&self, node_id: NodeId) -> Option<FuzzyMatch> {
-        if !self.search.is_active() {
+        let query = if self.filter.is_active() {
+            &self.filter
+        } else if self.search.is_active() {
+            &self.search
+        } else {
             return None;
-        }
+        };
 
         self.tree
             .get_node(node_id)
-            .and_then(|node| self.search.match_name(&node.entry.name))
+            .and_then(|node| query.match_name(&node.entry.name))
     }
 
     /// Check if a node matches the current search
@@ -595,6 +851,18 @@ mod tests {
         (temp_dir, view)
     }
 
+    /// Open the filter and feed it the listings it asks for, as the editor
+    /// does from its background tasks
+    async fn open_filter_and_load(view: &mut FileTreeView) {
+        let manager = FsManager::new(Arc::new(StdFileSystem));
+        let mut reads = view.open_filter();
+        while let Some((id, path)) = reads.pop() {
+            let result = manager.list_dir_with_metadata(path).await;
+            reads.extend(view.filter_dir_loaded(id, result));
+        }
+        assert!(!view.is_filter_indexing());
+    }
+
     #[tokio::test]
     async fn test_view_creation() {
         let (_temp_dir, view) = create_test_view().await;
@@ -766,4 +1034,90 @@ mod tests {
         view.set_sort_mode(SortMode::Modified);
         assert_eq!(view.get_sort_mode(), SortMode::Modified);
     }
+
+    #[tokio::test]
+    async fn test_filter_keeps_ancestors_of_matches() {
+        let (_temp_dir, mut view) = create_test_view().await;
+        let root_id = view.tree().root_id();
+        view.tree_mut().expand_node(root_id).await.unwrap();
+
+        open_filter_and_load(&mut view).await;
+        for c in "file2".chars() {
+            view.filter_push_char(c);
+        }
+
+        let names: Vec<String> = view
+            .get_display_nodes()
+            .into_iter()
+            .filter(|&(id, _)| id != root_id)
+            .map(|(id, _)| view.tree().get_node(id).unwrap().entry.name.clone())
+            .collect();
+        assert_eq!(names, vec!["dir1", "file2.txt"]);
+        assert_eq!(view.get_selected_entry().unwrap().name, "file2.txt");
+
+        // Closing the filter keeps the selection's directory open and
+        // collapses the rest
+        view.close_filter();
+        let dir1 = view.tree().root_path().join("dir1");
+        let dir2 = view.tree().root_path().join("dir2");
+        assert!(view.tree().get_node_by_path(&dir1).unwrap().is_expanded());
+        assert!(!view.tree().get_node_by_path(&dir2).unwrap().is_expanded());
+        assert_eq!(view.get_selected_entry().unwrap().name, "file2.txt");
+    }
+
+    #[tokio::test]
+    async fn test_filter_does_not_read_ignored_directories() {
+        let (temp_dir, mut view) = create_test_view().await;
+        std_fs::write(temp_dir.path().join("dir1/ignored.txt"), "").unwrap();
+        view.ignore_patterns_mut()
+            .add_custom_pattern("dir1/".to_string());
+        let root_id = view.tree().root_id();
+        view.tree_mut().expand_node(root_id).await.unwrap();
+
+        open_filter_and_load(&mut view).await;
+        let dir1 = view.tree().root_path().join("dir1");
+        assert!(!view.tree().get_node_by_path(&dir1).unwrap().is_expanded());
+
+        view.filter_push_char('i');
+        assert!(view.get_display_nodes().iter().all(|&(id, _)| view
+            .tree()
+            .get_node(id)
+            .unwrap()
+            .entry
+            .name
+            != "ignored.txt"));
+    }
+
+    #[tokio::test]
+    async fn test_filter_reads_directories_incrementally() {
+        let (_temp_dir, mut view) = create_test_view().await;
+        let root_id = view.tree().root_id();
+        view.tree_mut().expand_node(root_id).await.unwrap();
+
+        let reads = view.open_filter();
+        assert_eq!(reads.len(), 2);
+        assert!(view.is_filter_indexing());
+        for c in "file2".chars() {
+            view.filter_push_char(c);
+        }
+
+        // Matches show up as their directory's listing arrives
+        let manager = FsManager::new(Arc::new(StdFileSystem));
+        let (dir1_id, dir1_path) = reads
+            .iter()
+            .find(|(_, path)| path.ends_with("dir1"))
+            .cloned()
+            .unwrap();
+        let result = manager.list_dir_with_metadata(dir1_path).await;
+        assert!(view.filter_dir_loaded(dir1_id, result).is_empty());
+        assert_eq!(view.get_selected_entry().unwrap().name, "file2.txt");
+        assert!(view.is_filter_indexing());
+
+        // Closing the filter abandons the read still pending
+        view.close_filter();
+        assert!(!view.is_filter_indexing());
+        let dir2 = view.tree().root_path().join("dir2");
+        let dir2_node = view.tree().get_node_by_path(&dir2).unwrap();
+        assert!(!dir2_node.is_loading() && !dir2_node.is_expanded());
+    }
 }
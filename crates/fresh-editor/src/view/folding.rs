@@ -3,6 +3,8 @@
 //! Provides a marker-based system for tracking collapsed folding ranges.
 //! Fold ranges are stored as byte markers so they auto-adjust on edits.
 
+use std::ops::Range;
+
 use crate::model::buffer::Buffer;
 use crate::model::marker::{MarkerId, MarkerList};
 
@@ -168,6 +170,30 @@ impl FoldManager {
         ranges
     }
 
+    /// Byte ranges hidden by collapsed folds, sorted, with nested and
+    /// overlapping folds merged.
+    pub fn hidden_byte_ranges(
+        &self,
+        buffer: &Buffer,
+        marker_list: &MarkerList,
+    ) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = self
+            .resolved_ranges(buffer, marker_list)
+            .into_iter()
+            .map(|range| range.start_byte..range.end_byte)
+            .collect();
+        ranges.sort_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+
     /// Return a map of header_byte -> placeholder for collapsed folds.
     pub fn collapsed_header_bytes(
         &self,
@@ -253,6 +279,27 @@ impl Default for FoldManager {
     }
 }
 
+/// Parts of `range` outside the `hidden` ranges, which must be sorted and not
+/// overlap, as from [`FoldManager::hidden_byte_ranges`]
+pub fn visible_byte_ranges(range: Range<usize>, hidden: &[Range<usize>]) -> Vec<Range<usize>> {
+    let first = hidden.partition_point(|hidden| hidden.end <= range.start);
+    let mut visible = Vec::new();
+    let mut start = range.start;
+    for hidden in hidden[first..]
+        .iter()
+        .take_while(|hidden| hidden.start < range.end)
+    {
+        if hidden.start > start {
+            visible.push(start..hidden.start);
+        }
+        start = start.max(hidden.end);
+    }
+    if start < range.end {
+        visible.push(start..range.end);
+    }
+    visible
+}
+
 /// Indent-based folding fallback for when LSP folding ranges are not available.
 ///
 /// Computes foldable ranges by analyzing indentation levels, reusing the same
@@ -263,28 +310,31 @@ pub mod indent_folding {
     use crate::primitives::indent_pattern::PatternIndentCalculator;
 
     /// Find the byte offset of the start of the line containing `pos`.
-    /// Scans backward for `\n` (or returns 0).
+    /// Scans backward for `\n` (or returns 0), in chunks that double in
+    /// size so a long minified line costs a few slices rather than one per byte.
     pub fn find_line_start_byte(buffer: &Buffer, pos: usize) -> usize {
-        if pos == 0 {
-            return 0;
-        }
-        let mut p = pos.min(buffer.len()).saturating_sub(1);
-        loop {
-            match PatternIndentCalculator::byte_at(buffer, p) {
-                Some(b'\n') => return p + 1,
-                None => return 0,
-                _ => {
-                    if p == 0 {
-                        return 0;
-                    }
-                    p -= 1;
-                }
+        const MAX_CHUNK: usize = 64 * 1024;
+        let mut end = pos.min(buffer.len());
+        let mut chunk = 256;
+        while end > 0 {
+            let start = end.saturating_sub(chunk);
+            let bytes = buffer.slice_bytes(start..end);
+            if bytes.is_empty() {
+                // Unloaded region: nothing more to scan
+                return 0;
+            }
+            if let Some(i) = bytes.iter().rposition(|&b| b == b'\n') {
+                return start + i + 1;
             }
+            end = start;
+            chunk = (chunk * 2).min(MAX_CHUNK);
         }
+        0
     }
 
     /// Measure leading indent of a line given as a byte slice (no trailing `\n`).
-    fn slice_indent(line: &[u8], tab_size: usize) -> (usize, bool) {
+    /// Returns the indent in columns and whether the line is blank.
+    pub fn slice_indent(line: &[u8], tab_size: usize) -> (usize, bool) {
         let mut indent = 0;
         let mut all_blank = true;
         for &b in line {
@@ -518,6 +568,17 @@ pub mod indent_folding {
             assert_eq!(slice_indent(b"  \r", 4), (2, true));
         }
 
+        #[test]
+        fn test_find_line_start_byte_long_line() {
+            let long = "x".repeat(200_000);
+            let text = format!("ab\n{long}\ncd");
+            let buffer = Buffer::from_str_test(&text);
+
+            assert_eq!(find_line_start_byte(&buffer, 1), 0);
+            assert_eq!(find_line_start_byte(&buffer, 3 + long.len()), 3);
+            assert_eq!(find_line_start_byte(&buffer, text.len()), text.len() - 2);
+        }
+
         #[test]
         fn test_foldable_lines_basic() {
             let text = b"fn main() {\n    println!();\n}\n";
@@ -560,3 +621,33 @@ pub mod indent_folding {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_byte_ranges() {
+        let hidden = [5..10, 20..30];
+        assert_eq!(
+            visible_byte_ranges(0..40, &hidden),
+            vec![0..5, 10..20, 30..40]
+        );
+        // Starting or ending inside a hidden range
+        assert_eq!(visible_byte_ranges(7..25, &hidden), vec![10..20]);
+        assert_eq!(visible_byte_ranges(22..28, &hidden), vec![]);
+        assert_eq!(visible_byte_ranges(10..20, &hidden), vec![10..20]);
+        assert_eq!(visible_byte_ranges(0..3, &[]), vec![0..3]);
+    }
+
+    #[test]
+    fn test_hidden_byte_ranges_merge_nested_folds() {
+        let text = "a\n  b\n    c\n  d\ne\n";
+        let buffer = Buffer::from_str_test(text);
+        let mut marker_list = MarkerList::new();
+        let mut folds = FoldManager::new();
+        folds.add(&mut marker_list, 6, 12, None);
+        folds.add(&mut marker_list, 2, 16, None);
+        assert_eq!(folds.hidden_byte_ranges(&buffer, &marker_list), vec![2..16]);
+    }
+}
@@ -0,0 +1,166 @@
+//! Indent guide layout
+//!
+//! Guides are vertical lines drawn at each indent level inside a line's
+//! leading whitespace. Line indents are measured with the same rules as
+//! indent-based folding ([`indent_folding::slice_indent`]), and blank lines
+//! carry the guides of the code around them so a block reads as one column.
+//!
+//! [`indent_folding::slice_indent`]: crate::view::folding::indent_folding::slice_indent
+
+use std::ops::Range;
+
+/// Indent guides for a run of consecutive lines
+#[derive(Debug, Clone, Default)]
+pub struct IndentGuides {
+    /// Columns covered by guides on each line (guides sit at multiples of `unit` below this)
+    extents: Vec<usize>,
+    /// Visual columns per indent level
+    unit: usize,
+    /// Column and line range of the guide for the block containing the cursor
+    active: Option<(usize, Range<usize>)>,
+}
+
+impl IndentGuides {
+    /// Lay out guides for `indents`, the leading indent of each line in visual
+    /// columns (`None` for blank lines). `cursor_line` indexes into `indents`.
+    pub fn new(indents: &[Option<usize>], cursor_line: Option<usize>, unit: usize) -> Self {
+        let unit = unit.max(1);
+
+        // A blank line takes the smaller indent of its nearest non-blank
+        // neighbours, so guides continue through it only where both sides have them
+        let mut extents = vec![0; indents.len()];
+        let mut prev = None;
+        for (i, indent) in indents.iter().enumerate() {
+            if indent.is_some() {
+                prev = *indent;
+            }
+            extents[i] = indent.or(prev).unwrap_or(0);
+        }
+        let mut next = None;
+        for (i, indent) in indents.iter().enumerate().rev() {
+            match indent {
+                Some(_) => next = *indent,
+                None => extents[i] = extents[i].min(next.unwrap_or(0)),
+            }
+        }
+
+        let active =
+            cursor_line.and_then(|cursor| Self::active_guide(indents, &extents, cursor, unit));
+        Self {
+            extents,
+            unit,
+            active,
+        }
+    }
+
+    /// Find the guide of the innermost block containing the cursor line. When
+    /// the cursor line opens a block (the next code line is indented deeper),
+    /// that block's guide is the active one.
+    fn active_guide(
+        indents: &[Option<usize>],
+        extents: &[usize],
+        cursor: usize,
+        unit: usize,
+    ) -> Option<(usize, Range<usize>)> {
+        let cursor_indent = *extents.get(cursor)?;
+        let next_indent = indents[cursor + 1..].iter().find_map(|indent| *indent);
+        let opens_block =
+            indents[cursor].is_some() && next_indent.is_some_and(|next| next > cursor_indent);
+
+        let (column, start) = if opens_block {
+            (cursor_indent / unit * unit, cursor + 1)
+        } else if cursor_indent == 0 {
+            return None;
+        } else {
+            let column = (cursor_indent - 1) / unit * unit;
+            let mut start = cursor;
+            while start > 0 && extents[start - 1] > column {
+                start -= 1;
+            }
+            (column, start)
+        };
+
+        let mut end = start;
+        while end < extents.len() && extents[end] > column {
+            end += 1;
+        }
+        (end > start).then_some((column, start..end))
+    }
+
+    /// Whether `line` has a guide at visual column `col`. Returns `Some(true)`
+    /// for the active guide, `Some(false)` for any other guide.
+    pub fn guide_at(&self, line: usize, col: usize) -> Option<bool> {
+        let extent = *self.extents.get(line)?;
+        if col >= extent || col % self.unit != 0 {
+            return None;
+        }
+        Some(
+            self.active
+                .as_ref()
+                .is_some_and(|(column, lines)| *column == col && lines.contains(&line)),
+        )
+    }
+
+    /// Columns covered by guides on `line`
+    pub fn extent(&self, line: usize) -> usize {
+        self.extents.get(line).copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guides_at_each_indent_level() {
+        // fn main() {
+        //     if x {
+        //         y();
+        //     }
+        // }
+        let indents = [Some(0), Some(4), Some(8), Some(4), Some(0)];
+        let guides = IndentGuides::new(&indents, None, 4);
+
+        assert_eq!(guides.guide_at(0, 0), None);
+        assert_eq!(guides.guide_at(1, 0), Some(false));
+        assert_eq!(guides.guide_at(1, 4), None);
+        assert_eq!(guides.guide_at(2, 0), Some(false));
+        assert_eq!(guides.guide_at(2, 4), Some(false));
+        assert_eq!(guides.guide_at(2, 2), None);
+    }
+
+    #[test]
+    fn test_blank_lines_continue_enclosing_guides() {
+        let indents = [Some(0), Some(8), None, Some(4), None, Some(0)];
+        let guides = IndentGuides::new(&indents, None, 4);
+
+        // Between indent 8 and indent 4: only the shared guide at column 0
+        assert_eq!(guides.extent(2), 4);
+        assert_eq!(guides.guide_at(2, 0), Some(false));
+        // Before a top-level line: no guides
+        assert_eq!(guides.extent(4), 0);
+    }
+
+    #[test]
+    fn test_active_guide_of_block_containing_cursor() {
+        let indents = [Some(0), Some(4), Some(8), None, Some(8), Some(4), Some(0)];
+
+        // Cursor inside the inner block: its guide at column 4 spans the block
+        let guides = IndentGuides::new(&indents, Some(2), 4);
+        assert_eq!(guides.active, Some((4, 2..5)));
+        assert_eq!(guides.guide_at(3, 4), Some(true));
+        assert_eq!(guides.guide_at(2, 0), Some(false));
+
+        // Cursor on the line that opens the inner block
+        let guides = IndentGuides::new(&indents, Some(1), 4);
+        assert_eq!(guides.active, Some((4, 2..5)));
+
+        // Cursor on a closing line of the outer block
+        let guides = IndentGuides::new(&indents, Some(5), 4);
+        assert_eq!(guides.active, Some((0, 1..6)));
+
+        // Top-level line that opens nothing
+        let guides = IndentGuides::new(&[Some(0), Some(0)], Some(0), 4);
+        assert_eq!(guides.active, None);
+    }
+}
@@ -6,7 +6,7 @@ use crate::app::keybinding_editor::{
     BindingSource, ContextFilter, DeleteResult, EditMode, KeybindingEditor, SearchMode,
     SourceFilter,
 };
-use crate::input::keybindings::{format_keybinding, KeybindingResolver};
+use crate::input::keybindings::KeybindingResolver;
 use crate::view::theme::Theme;
 use crate::view::ui::scrollbar::{render_scrollbar, ScrollbarColors};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -1363,21 +1363,22 @@ fn handle_edit_dialog_input(
                     dialog.focus_area = 1;
                     dialog.mode = EditMode::EditingAction;
                 }
-                (KeyCode::Enter, KeyModifiers::NONE) if dialog.key_code.is_some() => {
+                (KeyCode::Enter, KeyModifiers::NONE) if !dialog.keys.is_empty() => {
                     dialog.focus_area = 1;
                     dialog.mode = EditMode::EditingAction;
                 }
+                (KeyCode::Backspace, KeyModifiers::NONE) if !dialog.keys.is_empty() => {
+                    dialog.clear_keys();
+                    dialog.conflicts.clear();
+                }
                 _ => {
                     // Record the key (but not modifier-only presses)
                     match event.code {
                         KeyCode::Modifier(_) => {}
                         _ => {
-                            dialog.key_code = Some(event.code);
-                            dialog.modifiers = event.modifiers;
-                            dialog.key_display = format_keybinding(&event.code, &event.modifiers);
+                            dialog.record_key(event.code, event.modifiers);
                             // Check conflicts
-                            dialog.conflicts =
-                                editor.find_conflicts(event.code, event.modifiers, &dialog.context);
+                            dialog.conflicts = editor.find_conflicts(&dialog.keys, &dialog.context);
                         }
                     }
                 }
@@ -1493,10 +1494,7 @@ fn handle_edit_dialog_input(
                         dialog.context =
                             dialog.context_options[dialog.context_option_index].clone();
                         // Update conflicts
-                        if let Some(key_code) = dialog.key_code {
-                            dialog.conflicts =
-                                editor.find_conflicts(key_code, dialog.modifiers, &dialog.context);
-                        }
+                        dialog.conflicts = editor.find_conflicts(&dialog.keys, &dialog.context);
                     }
                 }
                 (KeyCode::Right, _) => {
@@ -1504,10 +1502,7 @@ fn handle_edit_dialog_input(
                         dialog.context_option_index += 1;
                         dialog.context =
                             dialog.context_options[dialog.context_option_index].clone();
-                        if let Some(key_code) = dialog.key_code {
-                            dialog.conflicts =
-                                editor.find_conflicts(key_code, dialog.modifiers, &dialog.context);
-                        }
+                        dialog.conflicts = editor.find_conflicts(&dialog.keys, &dialog.context);
                     }
                 }
                 (KeyCode::Enter, _) => {
@@ -425,6 +425,20 @@ impl MarginManager {
         }
     }
 
+    /// Get the byte positions of all line indicators in a namespace
+    pub fn line_indicator_positions(&self, namespace: &str) -> Vec<usize> {
+        self.line_indicators
+            .iter()
+            .filter(|(_, indicators)| indicators.contains_key(namespace))
+            .filter_map(|(&marker_id, _)| self.indicator_markers.get_position(MarkerId(marker_id)))
+            .collect()
+    }
+
+    /// Get the byte position of a line indicator's marker
+    pub fn line_indicator_position(&self, marker_id: MarkerId) -> Option<usize> {
+        self.indicator_markers.get_position(marker_id)
+    }
+
     /// Get the line indicator for a specific line number
     ///
     /// This looks up all indicators whose markers resolve to the given line.
@@ -894,6 +908,31 @@ mod tests {
         assert_eq!(breakpoint.unwrap().symbol, "●");
     }
 
+    #[test]
+    fn test_line_indicator_positions_by_namespace() {
+        let mut manager = MarginManager::new();
+        manager.set_line_indicator(
+            line_to_byte(1),
+            "git-gutter".to_string(),
+            LineIndicator::new("│", Color::Green, 10),
+        );
+        manager.set_line_indicator(
+            line_to_byte(3),
+            "breakpoints".to_string(),
+            LineIndicator::new("●", Color::Red, 20),
+        );
+        manager.set_line_indicator(
+            line_to_byte(4),
+            "git-gutter".to_string(),
+            LineIndicator::new("│", Color::Yellow, 10),
+        );
+
+        let mut positions = manager.line_indicator_positions("git-gutter");
+        positions.sort_unstable();
+        assert_eq!(positions, vec![line_to_byte(1), line_to_byte(4)]);
+        assert!(manager.line_indicator_positions("bookmarks").is_empty());
+    }
+
     #[test]
     fn test_line_indicator_remove_specific() {
         let mut manager = MarginManager::new();
@@ -948,6 +987,26 @@ mod tests {
         assert!(manager.get_line_indicator(6, byte_to_line).is_some());
     }
 
+    #[test]
+    fn test_line_indicator_position_follows_line_start() {
+        let mut manager = MarginManager::new();
+
+        let marker_id = manager.set_line_indicator(
+            line_to_byte(5),
+            "bookmark".to_string(),
+            LineIndicator::new("◆", Color::Blue, 20),
+        );
+
+        // A line inserted right at the indicator pushes it down with the text
+        manager.adjust_for_insert(line_to_byte(5), 10);
+
+        assert_eq!(
+            manager.line_indicator_position(marker_id),
+            Some(line_to_byte(6))
+        );
+        assert!(manager.get_line_indicator(6, byte_to_line).is_some());
+    }
+
     #[test]
     fn test_line_indicator_shifts_on_delete() {
         let mut manager = MarginManager::new();
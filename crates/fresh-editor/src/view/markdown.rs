@@ -1,13 +1,15 @@
 //! Markdown parsing and rendering for terminal display
 //!
 //! This module provides markdown-to-styled-text conversion for popups,
-//! hover documentation, and other UI elements. It also provides word
-//! wrapping utilities for styled text.
+//! hover documentation, the markdown preview pane and other UI elements.
+//! It also provides word wrapping utilities for styled text.
 
+use crate::primitives::display_width::str_width;
 use crate::primitives::grammar::GrammarRegistry;
 use crate::primitives::highlight_engine::highlight_string;
 use crate::primitives::highlighter::HighlightSpan;
-use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use crate::view::scroll_sync::SyncAnchor;
+use pulldown_cmark::{Alignment, Event, Options, Parser, Tag, TagEnd};
 use ratatui::style::{Color, Modifier, Style};
 
 /// Word-wrap a single line of text to fit within a given width.
@@ -346,231 +348,548 @@ fn add_code_text_to_lines(lines: &mut Vec<StyledLine>, text: &str, style: Style)
     }
 }
 
+/// How [`render_markdown`] lays text out
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarkdownOptions {
+    /// Keep the line breaks inside paragraphs instead of joining their lines
+    /// with spaces. LSP hover docstrings are often laid out line by line.
+    pub keep_soft_breaks: bool,
+}
+
+/// Markdown rendered to styled lines
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RenderedMarkdown {
+    pub lines: Vec<StyledLine>,
+    /// 0-based source line each rendered line comes from; never decreases
+    pub source_lines: Vec<usize>,
+}
+
+impl RenderedMarkdown {
+    /// Scroll sync anchors from each source line to the first rendered line
+    /// coming from it or from a later line
+    pub fn sync_anchors(&self) -> Vec<SyncAnchor> {
+        let last = self.source_lines.last().copied().unwrap_or(0);
+        let mut rendered = 0;
+        (0..=last)
+            .map(|source| {
+                while self
+                    .source_lines
+                    .get(rendered)
+                    .is_some_and(|&line| line < source)
+                {
+                    rendered += 1;
+                }
+                SyncAnchor {
+                    left_line: source,
+                    right_line: rendered,
+                }
+            })
+            .collect()
+    }
+}
+
 /// Parse markdown text into styled lines for terminal rendering
 ///
 /// If `registry` is provided, uses syntect for syntax highlighting in code blocks,
 /// which supports ~150+ languages. If None, falls back to uniform code styling.
+/// Line breaks inside paragraphs are kept, as hover docstrings expect.
 pub fn parse_markdown(
     text: &str,
     theme: &crate::view::theme::Theme,
     registry: Option<&GrammarRegistry>,
 ) -> Vec<StyledLine> {
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_STRIKETHROUGH);
+    let options = MarkdownOptions {
+        keep_soft_breaks: true,
+    };
+    render_markdown(text, theme, registry, options).lines
+}
 
-    let parser = Parser::new_ext(text, options);
-    let mut lines: Vec<StyledLine> = vec![StyledLine::new()];
+/// Render a markdown document to styled lines: headings, emphasis, lists,
+/// block quotes, tables and code blocks (highlighted with `registry` when
+/// given), remembering which source line each rendered line comes from
+pub fn render_markdown(
+    text: &str,
+    theme: &crate::view::theme::Theme,
+    registry: Option<&GrammarRegistry>,
+    options: MarkdownOptions,
+) -> RenderedMarkdown {
+    let mut parser_options = Options::empty();
+    parser_options.insert(Options::ENABLE_STRIKETHROUGH);
+    parser_options.insert(Options::ENABLE_TABLES);
+
+    let mut renderer = Renderer::new(text, theme, registry, options);
+    for (event, range) in Parser::new_ext(text, parser_options).into_offset_iter() {
+        renderer.event(event, range.start);
+    }
+    renderer.finish()
+}
 
-    // Style stack for nested formatting
-    let mut style_stack: Vec<Style> = vec![Style::default()];
-    let mut in_code_block = false;
-    let mut code_block_lang = String::new();
-    // Track current link URL (if inside a link)
-    let mut current_link_url: Option<String> = None;
+/// A list being rendered
+struct ListLevel {
+    /// Number of the next item of an ordered list
+    next_number: Option<u64>,
+    /// Width of the current item's marker, which its later lines are
+    /// indented by
+    indent: usize,
+}
 
-    for event in parser {
-        match event {
-            Event::Start(tag) => {
-                match tag {
-                    Tag::Strong => {
-                        let current = *style_stack.last().unwrap_or(&Style::default());
-                        style_stack.push(current.add_modifier(Modifier::BOLD));
-                    }
-                    Tag::Emphasis => {
-                        let current = *style_stack.last().unwrap_or(&Style::default());
-                        style_stack.push(current.add_modifier(Modifier::ITALIC));
-                    }
-                    Tag::Strikethrough => {
-                        let current = *style_stack.last().unwrap_or(&Style::default());
-                        style_stack.push(current.add_modifier(Modifier::CROSSED_OUT));
-                    }
-                    Tag::CodeBlock(kind) => {
-                        in_code_block = true;
-                        code_block_lang = match kind {
-                            pulldown_cmark::CodeBlockKind::Fenced(lang) => lang.to_string(),
-                            pulldown_cmark::CodeBlockKind::Indented => String::new(),
-                        };
-                        // Start new line for code block
-                        if !lines.last().map(|l| l.spans.is_empty()).unwrap_or(true) {
-                            lines.push(StyledLine::new());
-                        }
-                    }
-                    Tag::Heading { .. } => {
-                        let current = *style_stack.last().unwrap_or(&Style::default());
-                        style_stack
-                            .push(current.add_modifier(Modifier::BOLD).fg(theme.help_key_fg));
-                    }
-                    Tag::Link { dest_url, .. } => {
-                        let current = *style_stack.last().unwrap_or(&Style::default());
-                        style_stack
-                            .push(current.add_modifier(Modifier::UNDERLINED).fg(Color::Cyan));
-                        // Store the link URL for text spans inside this link
-                        current_link_url = Some(dest_url.to_string());
-                    }
-                    Tag::Image { .. } => {
-                        let current = *style_stack.last().unwrap_or(&Style::default());
-                        style_stack
-                            .push(current.add_modifier(Modifier::UNDERLINED).fg(Color::Cyan));
-                    }
-                    Tag::List(_) | Tag::Item => {
-                        // Start list items on new line
-                        if !lines.last().map(|l| l.spans.is_empty()).unwrap_or(true) {
-                            lines.push(StyledLine::new());
-                        }
-                    }
-                    Tag::Paragraph => {
-                        // Start paragraphs on new line if we have any prior content.
-                        // This preserves blank lines from previous paragraph ends.
-                        let has_prior_content = lines.iter().any(|l| !l.spans.is_empty());
-                        if has_prior_content {
-                            lines.push(StyledLine::new());
-                        }
-                    }
-                    _ => {}
-                }
+/// A table being rendered; rows are laid out once all cells are known
+struct Table {
+    alignments: Vec<Alignment>,
+    /// Source line and cells of each row, the head row first
+    rows: Vec<(usize, Vec<Vec<StyledSpan>>)>,
+}
+
+/// Markdown event consumer building [`RenderedMarkdown`]
+struct Renderer<'a> {
+    theme: &'a crate::view::theme::Theme,
+    registry: Option<&'a GrammarRegistry>,
+    options: MarkdownOptions,
+    /// Byte offset of each source line start
+    line_starts: Vec<usize>,
+    lines: Vec<StyledLine>,
+    source_lines: Vec<usize>,
+    /// Source line of the event being rendered
+    source_line: usize,
+    has_content: bool,
+    /// Style stack for nested formatting
+    style_stack: Vec<Style>,
+    /// URL of the link being rendered
+    link_url: Option<String>,
+    /// Language of the code block being rendered
+    code_block: Option<String>,
+    lists: Vec<ListLevel>,
+    /// Marker of a list item not yet followed by any text
+    pending_marker: Option<String>,
+    quote_depth: usize,
+    table: Option<Table>,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(
+        text: &str,
+        theme: &'a crate::view::theme::Theme,
+        registry: Option<&'a GrammarRegistry>,
+        options: MarkdownOptions,
+    ) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            theme,
+            registry,
+            options,
+            line_starts,
+            lines: vec![StyledLine::new()],
+            source_lines: vec![0],
+            source_line: 0,
+            has_content: false,
+            style_stack: vec![Style::default()],
+            link_url: None,
+            code_block: None,
+            lists: Vec::new(),
+            pending_marker: None,
+            quote_depth: 0,
+            table: None,
+        }
+    }
+
+    fn style(&self) -> Style {
+        *self.style_stack.last().unwrap_or(&Style::default())
+    }
+
+    fn push_style(&mut self, style: impl FnOnce(Style) -> Style) {
+        let current = self.style();
+        self.style_stack.push(style(current));
+    }
+
+    fn new_line(&mut self) {
+        self.lines.push(StyledLine::new());
+        self.source_lines.push(self.source_line);
+    }
+
+    /// Continue on a new line unless the current one is still empty
+    fn start_line(&mut self) {
+        if self.lines.last().is_some_and(|line| !line.spans.is_empty()) {
+            self.new_line();
+        }
+    }
+
+    /// Start a block on a new line, one blank line below the previous block
+    fn start_block(&mut self) {
+        if !self.has_content {
+            return;
+        }
+        while self.lines.last().is_some_and(|line| line.spans.is_empty()) {
+            self.lines.pop();
+            self.source_lines.pop();
+        }
+        self.new_line();
+        self.new_line();
+    }
+
+    /// Add text to the current line, or to the current table cell. The first
+    /// text on a line is preceded by the quote bars and list indentation.
+    fn push(&mut self, text: &str, style: Style) {
+        if text.is_empty() {
+            return;
+        }
+        let link_url = self.link_url.clone();
+        if let Some(table) = &mut self.table {
+            if let Some(cell) = table
+                .rows
+                .last_mut()
+                .and_then(|(_, cells)| cells.last_mut())
+            {
+                cell.push(StyledSpan {
+                    text: text.to_string(),
+                    style,
+                    link_url,
+                });
             }
-            Event::End(tag_end) => {
-                match tag_end {
-                    TagEnd::Strong
-                    | TagEnd::Emphasis
-                    | TagEnd::Strikethrough
-                    | TagEnd::Heading(_)
-                    | TagEnd::Image => {
-                        style_stack.pop();
-                    }
-                    TagEnd::Link => {
-                        style_stack.pop();
-                        // Clear link URL when exiting the link
-                        current_link_url = None;
-                    }
-                    TagEnd::CodeBlock => {
-                        in_code_block = false;
-                        code_block_lang.clear();
-                        // End code block with new line
-                        lines.push(StyledLine::new());
-                    }
-                    TagEnd::Paragraph => {
-                        // Add blank line after paragraph
-                        lines.push(StyledLine::new());
-                    }
-                    TagEnd::Item => {
-                        // Items end naturally
-                    }
-                    _ => {}
-                }
+            return;
+        }
+
+        if self.lines.last().is_some_and(|line| line.spans.is_empty()) {
+            let prefix = self.line_prefix();
+            if let Some(source_line) = self.source_lines.last_mut() {
+                *source_line = self.source_line;
             }
-            Event::Text(text) => {
-                if in_code_block {
-                    // Try syntax highlighting for code blocks using syntect
-                    let spans = if let Some(reg) = registry {
-                        if !code_block_lang.is_empty() {
-                            let s = highlight_string(&text, &code_block_lang, reg, theme);
-                            // Check coverage - if < 20% highlighted, content may not be valid code
-                            let highlighted_bytes: usize =
-                                s.iter().map(|span| span.range.end - span.range.start).sum();
-                            let non_ws_bytes =
-                                text.bytes().filter(|b| !b.is_ascii_whitespace()).count();
-                            let good_coverage =
-                                non_ws_bytes == 0 || highlighted_bytes * 5 >= non_ws_bytes;
-                            if good_coverage {
-                                s
-                            } else {
-                                Vec::new()
-                            }
-                        } else {
-                            Vec::new()
-                        }
-                    } else {
-                        Vec::new()
-                    };
+            if let Some(line) = self.lines.last_mut() {
+                line.spans.extend(prefix);
+            }
+        }
+        self.has_content = true;
+        if let Some(line) = self.lines.last_mut() {
+            line.push_with_link(text.to_string(), style, link_url);
+        }
+    }
 
-                    if !spans.is_empty() {
-                        let highlighted_lines =
-                            highlight_code_to_styled_lines(&text, &spans, theme);
-                        for (i, styled_line) in highlighted_lines.into_iter().enumerate() {
-                            if i > 0 {
-                                lines.push(StyledLine::new());
-                            }
-                            // Merge spans into the current line
-                            if let Some(current_line) = lines.last_mut() {
-                                for span in styled_line.spans {
-                                    current_line.push(span.text, span.style);
-                                }
-                            }
-                        }
-                    } else {
-                        // Fallback: uniform code style for unknown languages
-                        let code_style = Style::default()
-                            .fg(theme.help_key_fg)
-                            .bg(theme.inline_code_bg);
-                        for (i, part) in text.split('\n').enumerate() {
-                            if i > 0 {
-                                lines.push(StyledLine::new());
-                            }
-                            if !part.is_empty() {
-                                if let Some(line) = lines.last_mut() {
-                                    line.push(part.to_string(), code_style);
-                                }
-                            }
-                        }
-                    }
+    /// Quote bars, list indentation and any pending list marker starting a
+    /// line
+    fn line_prefix(&mut self) -> Vec<StyledSpan> {
+        let mut prefix = Vec::new();
+        if self.quote_depth > 0 {
+            prefix.push(StyledSpan {
+                text: "│ ".repeat(self.quote_depth),
+                style: Style::default().fg(Color::DarkGray),
+                link_url: None,
+            });
+        }
+        if let Some((current, outer)) = self.lists.split_last() {
+            let outer_indent: usize = outer.iter().map(|level| level.indent).sum();
+            let (text, style) = match self.pending_marker.take() {
+                Some(marker) => (
+                    format!("{}{}", " ".repeat(outer_indent), marker),
+                    Style::default().fg(self.theme.help_key_fg),
+                ),
+                None => (" ".repeat(outer_indent + current.indent), Style::default()),
+            };
+            if !text.is_empty() {
+                prefix.push(StyledSpan {
+                    text,
+                    style,
+                    link_url: None,
+                });
+            }
+        }
+        prefix
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        self.line_starts
+            .partition_point(|&start| start <= offset)
+            .saturating_sub(1)
+    }
+
+    fn event(&mut self, event: Event, offset: usize) {
+        // End events carry the range of the whole element
+        if !matches!(event, Event::End(_)) {
+            self.source_line = self.line_of(offset);
+        }
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag_end) => self.end(tag_end),
+            Event::Text(text) => {
+                if let Some(lang) = self.code_block.clone() {
+                    self.code_text(&text, &lang);
                 } else {
-                    let current_style = *style_stack.last().unwrap_or(&Style::default());
-                    // Split text by newlines and add to lines
+                    let style = self.style();
                     for (i, part) in text.split('\n').enumerate() {
                         if i > 0 {
-                            lines.push(StyledLine::new());
-                        }
-                        if !part.is_empty() {
-                            if let Some(line) = lines.last_mut() {
-                                // Include link URL if we're inside a link
-                                line.push_with_link(
-                                    part.to_string(),
-                                    current_style,
-                                    current_link_url.clone(),
-                                );
-                            }
+                            self.new_line();
                         }
+                        self.push(part, style);
                     }
                 }
             }
             Event::Code(code) => {
                 // Inline code - render with background styling (no backticks needed)
                 let style = Style::default()
-                    .fg(theme.help_key_fg)
-                    .bg(theme.inline_code_bg);
-                if let Some(line) = lines.last_mut() {
-                    line.push(code.to_string(), style);
-                }
+                    .fg(self.theme.help_key_fg)
+                    .bg(self.theme.inline_code_bg);
+                self.push(&code, style);
             }
             Event::SoftBreak => {
-                // Soft break - preserve as newline for better docstring/hover formatting
-                // (Standard markdown renders soft breaks as spaces, but for LSP hover
-                // content which often contains formatted docstrings, newlines are better)
-                lines.push(StyledLine::new());
+                // Standard markdown joins the lines of a paragraph, but LSP
+                // hover docstrings read better with their line breaks kept
+                if self.options.keep_soft_breaks && self.table.is_none() {
+                    self.new_line();
+                } else {
+                    self.push(" ", self.style());
+                }
             }
             Event::HardBreak => {
-                // Hard break - new line
-                lines.push(StyledLine::new());
+                if self.table.is_some() {
+                    self.push(" ", self.style());
+                } else {
+                    self.new_line();
+                }
             }
             Event::Rule => {
-                // Horizontal rule
-                lines.push(StyledLine::new());
-                if let Some(line) = lines.last_mut() {
-                    line.push("─".repeat(40), Style::default().fg(Color::DarkGray));
+                self.start_block();
+                self.push(&"─".repeat(40), Style::default().fg(Color::DarkGray));
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Strong => self.push_style(|style| style.add_modifier(Modifier::BOLD)),
+            Tag::Emphasis => self.push_style(|style| style.add_modifier(Modifier::ITALIC)),
+            Tag::Strikethrough => {
+                self.push_style(|style| style.add_modifier(Modifier::CROSSED_OUT))
+            }
+            Tag::CodeBlock(kind) => {
+                self.start_block();
+                self.code_block = Some(match kind {
+                    pulldown_cmark::CodeBlockKind::Fenced(lang) => lang.to_string(),
+                    pulldown_cmark::CodeBlockKind::Indented => String::new(),
+                });
+            }
+            Tag::Heading { .. } => {
+                self.start_block();
+                let fg = self.theme.help_key_fg;
+                self.push_style(|style| style.add_modifier(Modifier::BOLD).fg(fg));
+            }
+            Tag::Link { dest_url, .. } => {
+                self.push_style(|style| style.add_modifier(Modifier::UNDERLINED).fg(Color::Cyan));
+                // Store the link URL for text spans inside this link
+                self.link_url = Some(dest_url.to_string());
+            }
+            Tag::Image { .. } => {
+                self.push_style(|style| style.add_modifier(Modifier::UNDERLINED).fg(Color::Cyan));
+            }
+            Tag::List(first_number) => {
+                if self.lists.is_empty() {
+                    self.start_block();
+                } else {
+                    self.start_line();
+                }
+                self.lists.push(ListLevel {
+                    next_number: first_number,
+                    indent: 0,
+                });
+            }
+            Tag::Item => {
+                self.start_line();
+                let depth = self.lists.len();
+                if let Some(level) = self.lists.last_mut() {
+                    let marker = match level.next_number {
+                        Some(number) => {
+                            level.next_number = Some(number + 1);
+                            format!("{number}. ")
+                        }
+                        None if depth % 2 == 1 => "• ".to_string(),
+                        None => "◦ ".to_string(),
+                    };
+                    level.indent = unicode_width::UnicodeWidthStr::width(marker.as_str());
+                    self.pending_marker = Some(marker);
+                }
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                self.quote_depth += 1;
+                self.push_style(|style| style.add_modifier(Modifier::ITALIC));
+            }
+            Tag::Paragraph => self.start_block(),
+            Tag::Table(alignments) => {
+                self.start_block();
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                let source_line = self.source_line;
+                if let Some(table) = &mut self.table {
+                    table.rows.push((source_line, Vec::new()));
+                }
+            }
+            Tag::TableCell => {
+                if let Some((_, cells)) = self.table.as_mut().and_then(|t| t.rows.last_mut()) {
+                    cells.push(Vec::new());
                 }
-                lines.push(StyledLine::new());
             }
             _ => {}
         }
     }
 
-    // Remove trailing empty lines
-    while lines.last().map(|l| l.spans.is_empty()).unwrap_or(false) {
-        lines.pop();
+    fn end(&mut self, tag_end: TagEnd) {
+        match tag_end {
+            TagEnd::Strong
+            | TagEnd::Emphasis
+            | TagEnd::Strikethrough
+            | TagEnd::Heading(_)
+            | TagEnd::Image => {
+                self.style_stack.pop();
+            }
+            TagEnd::Link => {
+                self.style_stack.pop();
+                // Clear link URL when exiting the link
+                self.link_url = None;
+            }
+            TagEnd::CodeBlock => self.code_block = None,
+            TagEnd::List(_) => {
+                self.lists.pop();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+                self.style_stack.pop();
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.table_lines(table);
+                }
+            }
+            _ => {}
+        }
     }
 
-    lines
+    /// Add the text of a code block, highlighted by its language when
+    /// possible
+    fn code_text(&mut self, text: &str, lang: &str) {
+        // Try syntax highlighting for code blocks using syntect
+        let spans = match self.registry {
+            Some(registry) if !lang.is_empty() => {
+                let spans = highlight_string(text, lang, registry, self.theme);
+                // Check coverage - if < 20% highlighted, content may not be valid code
+                let highlighted_bytes: usize = spans
+                    .iter()
+                    .map(|span| span.range.end - span.range.start)
+                    .sum();
+                let non_ws_bytes = text.bytes().filter(|b| !b.is_ascii_whitespace()).count();
+                if non_ws_bytes == 0 || highlighted_bytes * 5 >= non_ws_bytes {
+                    spans
+                } else {
+                    Vec::new()
+                }
+            }
+            _ => Vec::new(),
+        };
+
+        let code_lines = if spans.is_empty() {
+            // Fallback: uniform code style for unknown languages
+            let mut lines = vec![StyledLine::new()];
+            let code_style = Style::default()
+                .fg(self.theme.help_key_fg)
+                .bg(self.theme.inline_code_bg);
+            add_code_text_to_lines(&mut lines, text, code_style);
+            lines
+        } else {
+            highlight_code_to_styled_lines(text, &spans, self.theme)
+        };
+
+        let first_line = self.source_line;
+        for (i, code_line) in code_lines.into_iter().enumerate() {
+            if i > 0 {
+                self.source_line = first_line + i;
+                self.new_line();
+            }
+            for span in code_line.spans {
+                self.push(&span.text, span.style);
+            }
+        }
+    }
+
+    /// Lay out a table with its columns padded to the same width
+    fn table_lines(&mut self, table: Table) {
+        let border = Style::default().fg(Color::DarkGray);
+        let width =
+            |cell: &[StyledSpan]| -> usize { cell.iter().map(|span| str_width(&span.text)).sum() };
+        let mut widths: Vec<usize> = Vec::new();
+        for (_, cells) in &table.rows {
+            if widths.len() < cells.len() {
+                widths.resize(cells.len(), 0);
+            }
+            for (column, cell) in cells.iter().enumerate() {
+                widths[column] = widths[column].max(width(cell));
+            }
+        }
+
+        for (row, (source_line, cells)) in table.rows.iter().enumerate() {
+            self.source_line = *source_line;
+            self.start_line();
+            for (column, column_width) in widths.iter().enumerate() {
+                if column > 0 {
+                    self.push(" │ ", border);
+                }
+                let cell = cells.get(column).map(Vec::as_slice).unwrap_or_default();
+                let padding = column_width - width(cell);
+                let (before, after) = match table.alignments.get(column) {
+                    Some(Alignment::Right) => (padding, 0),
+                    Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+                    _ => (0, padding),
+                };
+                self.push(&" ".repeat(before), Style::default());
+                for span in cell {
+                    let style = if row == 0 {
+                        span.style.add_modifier(Modifier::BOLD)
+                    } else {
+                        span.style
+                    };
+                    self.link_url = span.link_url.clone();
+                    self.push(&span.text, style);
+                }
+                self.link_url = None;
+                // Trailing padding only matters between columns
+                if column + 1 < widths.len() {
+                    self.push(&" ".repeat(after), Style::default());
+                }
+            }
+
+            if row == 0 {
+                // The delimiter row below the head
+                self.source_line = source_line + 1;
+                self.new_line();
+                let rule = widths
+                    .iter()
+                    .map(|&width| "─".repeat(width))
+                    .collect::<Vec<_>>()
+                    .join("─┼─");
+                self.push(&rule, border);
+            }
+        }
+    }
+
+    fn finish(mut self) -> RenderedMarkdown {
+        // Remove trailing empty lines
+        while self.lines.last().is_some_and(|line| line.spans.is_empty()) {
+            self.lines.pop();
+            self.source_lines.pop();
+        }
+        // Content comes in source order, apart from oddities like footnotes
+        let mut max = 0;
+        for source_line in &mut self.source_lines {
+            max = max.max(*source_line);
+            *source_line = max;
+        }
+        RenderedMarkdown {
+            lines: self.lines,
+            source_lines: self.source_lines,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1221,4 +1540,49 @@ mod tests {
             assert!(width <= 20, "Line exceeds max width: {}", line);
         }
     }
+
+    #[test]
+    fn test_render_document_blocks() {
+        let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let text = "# Title\n\nSome *text*\nwrapped.\n\n- one\n- two\n  1. sub\n\n> quoted\n";
+        let rendered = render_markdown(text, &theme, None, MarkdownOptions::default());
+
+        let texts: Vec<String> = rendered.lines.iter().map(get_line_text).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Title",
+                "",
+                "Some text wrapped.",
+                "",
+                "• one",
+                "• two",
+                "  1. sub",
+                "",
+                "│ quoted",
+            ]
+        );
+        assert!(has_modifier(&rendered.lines[8], Modifier::ITALIC));
+
+        assert_eq!(rendered.source_lines, vec![0, 2, 2, 5, 5, 6, 7, 9, 9]);
+        let anchors = rendered.sync_anchors();
+        let right_lines: Vec<usize> = anchors.iter().map(|a| a.right_line).collect();
+        assert_eq!(right_lines, vec![0, 1, 1, 3, 3, 3, 5, 6, 7, 7]);
+    }
+
+    #[test]
+    fn test_render_table() {
+        let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let text = "| Name | Qty |\n|:-----|----:|\n| apple | 3 |\n| kiwi | 12 |\n";
+        let rendered = render_markdown(text, &theme, None, MarkdownOptions::default());
+
+        let texts: Vec<String> = rendered.lines.iter().map(get_line_text).collect();
+        assert_eq!(
+            texts,
+            vec!["Name  │ Qty", "──────┼────", "apple │   3", "kiwi  │  12"]
+        );
+        assert!(has_modifier(&rendered.lines[0], Modifier::BOLD));
+        assert!(!has_modifier(&rendered.lines[2], Modifier::BOLD));
+        assert_eq!(rendered.source_lines, vec![0, 1, 2, 3]);
+    }
 }
@@ -0,0 +1,155 @@
+//! Minimap layout
+//!
+//! The minimap is a narrow strip between the text and the scrollbar showing a
+//! squeezed picture of the buffer. Each cell is a braille character: its four
+//! dot rows are four consecutive source lines and each of its two dot columns
+//! covers a few text columns. Lines are sampled as plain text (no syntax
+//! highlighting), and only the lines the strip shows are read, so the cost
+//! doesn't grow with file size.
+//!
+//! The rightmost column is a tick lane marking rows that contain diagnostics
+//! or search matches.
+
+use crate::view::overlay::{Overlay, OverlayNamespace};
+
+/// Width of the minimap strip in cells, including the tick lane
+pub const MINIMAP_WIDTH: u16 = 12;
+
+/// Source lines per minimap row (one per braille dot row)
+pub const LINES_PER_ROW: usize = 4;
+
+/// Text columns covered by one braille dot column
+const COLUMNS_PER_DOT: usize = 4;
+
+/// Braille dot bits, indexed by `[line within the row][dot column]`
+const DOT_BITS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// A marker in the minimap's tick lane. Ordered by precedence: when a row has
+/// several, the greatest is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MinimapTick {
+    SearchMatch,
+    Warning,
+    Error,
+}
+
+impl MinimapTick {
+    /// The tick an overlay contributes, if any. Diagnostics are told apart by
+    /// priority (Error=100, Warning=50); info and hint diagnostics get no tick.
+    pub fn for_overlay(overlay: &Overlay) -> Option<Self> {
+        let namespace = overlay.namespace.as_ref()?;
+        if *namespace == crate::services::lsp::diagnostics::lsp_diagnostic_namespace() {
+            match overlay.priority {
+                100 => Some(Self::Error),
+                50 => Some(Self::Warning),
+                _ => None,
+            }
+        } else if *namespace == OverlayNamespace::from_string("search".to_string()) {
+            Some(Self::SearchMatch)
+        } else {
+            None
+        }
+    }
+}
+
+/// One row of the minimap
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinimapRow {
+    /// Byte offset of the first source line in this row
+    pub start_byte: usize,
+    /// Braille cells for the row's text columns
+    pub cells: String,
+    /// Marker for the tick lane
+    pub tick: Option<MinimapTick>,
+}
+
+/// First source line shown at the top of a minimap holding `capacity` lines.
+///
+/// Short buffers start at the top. Longer ones scroll proportionally to the
+/// viewport, so the minimap reaches the end of the buffer exactly when the
+/// viewport does, and the viewport always stays inside the minimap.
+pub fn first_line(
+    total_lines: usize,
+    top_line: usize,
+    viewport_height: usize,
+    capacity: usize,
+) -> usize {
+    if total_lines <= capacity {
+        return 0;
+    }
+    let max_first = total_lines - capacity;
+    let max_top = total_lines.saturating_sub(viewport_height).max(1);
+    (top_line.min(max_top) * max_first / max_top).min(max_first)
+}
+
+/// Encode up to [`LINES_PER_ROW`] lines as `cells` braille characters. A dot
+/// is set when its span of columns holds any non-whitespace character.
+pub fn encode_row<S: AsRef<str>>(lines: &[S], cells: usize, tab_size: usize) -> String {
+    let tab_size = tab_size.max(1);
+    let dot_columns = cells * 2;
+    let mut masks = vec![0u8; cells];
+
+    for (line_idx, line) in lines.iter().take(LINES_PER_ROW).enumerate() {
+        let mut col = 0;
+        for ch in line.as_ref().chars() {
+            let dot = col / COLUMNS_PER_DOT;
+            if dot >= dot_columns {
+                break;
+            }
+            match ch {
+                '\n' | '\r' => break,
+                '\t' => col += tab_size - col % tab_size,
+                c if c.is_whitespace() => col += 1,
+                _ => {
+                    masks[dot / 2] |= DOT_BITS[line_idx][dot % 2];
+                    col += 1;
+                }
+            }
+        }
+    }
+
+    masks
+        .into_iter()
+        .map(|mask| match mask {
+            0 => ' ',
+            _ => char::from_u32(0x2800 + mask as u32).unwrap_or(' '),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_line_scrolls_proportionally() {
+        // Everything fits: no scrolling
+        assert_eq!(first_line(50, 10, 20, 80), 0);
+
+        // 1000 lines, 20-line viewport, 80-line minimap
+        assert_eq!(first_line(1000, 0, 20, 80), 0);
+        assert_eq!(first_line(1000, 980, 20, 80), 920);
+        let first = first_line(1000, 490, 20, 80);
+        assert!(first <= 490 && first + 80 >= 510);
+    }
+
+    #[test]
+    fn test_encode_row_sets_dots_for_text() {
+        // Four columns per dot: "abcd" fills the left dot of the first cell
+        assert_eq!(encode_row(&["abcd"], 2, 4), "\u{2801} ");
+        // Second line, right dot column
+        assert_eq!(encode_row(&["", "    x"], 1, 4), "\u{2810}");
+        // Fourth line uses the bottom dot row
+        assert_eq!(encode_row(&["", "", "", "x"], 1, 4), "\u{2840}");
+        // Blank lines leave cells empty
+        assert_eq!(encode_row(&["   ", ""], 2, 4), "  ");
+    }
+
+    #[test]
+    fn test_encode_row_expands_tabs_and_clips() {
+        // A tab moves to column 8, the first dot column of the second cell
+        assert_eq!(encode_row(&["\tx"], 2, 8), " \u{2801}");
+        // Text past the last cell is dropped
+        assert_eq!(encode_row(&[&"x".repeat(100)], 1, 4), "\u{2809}");
+    }
+}
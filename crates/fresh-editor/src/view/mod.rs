@@ -25,8 +25,12 @@ pub mod dimming;
 #[cfg(any(feature = "runtime", feature = "wasm"))]
 pub mod folding;
 #[cfg(any(feature = "runtime", feature = "wasm"))]
+pub mod indent_guides;
+#[cfg(any(feature = "runtime", feature = "wasm"))]
 pub mod margin;
 #[cfg(any(feature = "runtime", feature = "wasm"))]
+pub mod minimap;
+#[cfg(any(feature = "runtime", feature = "wasm"))]
 pub mod overlay;
 #[cfg(any(feature = "runtime", feature = "wasm"))]
 pub mod scroll_sync;
@@ -59,6 +63,8 @@ pub mod keybinding_editor;
 #[cfg(feature = "runtime")]
 pub mod markdown;
 #[cfg(feature = "runtime")]
+pub mod perf_hud;
+#[cfg(feature = "runtime")]
 pub mod popup;
 #[cfg(feature = "runtime")]
 pub mod popup_input;
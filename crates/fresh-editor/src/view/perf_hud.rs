@@ -0,0 +1,156 @@
+//! Performance HUD rendering
+//!
+//! Draws the performance overlay in the top-right corner, on top of the
+//! editor content, without taking focus.
+
+use crate::services::file_index::FileIndexStats;
+use crate::services::perf::{PerfSnapshot, Timing};
+use crate::view::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use rust_i18n::t;
+
+/// Width of the overlay in characters
+const HUD_WIDTH: u16 = 44;
+
+/// LSP methods listed, slowest last request first
+const MAX_LSP_ROWS: usize = 4;
+
+/// Render the performance HUD for `snapshot` into the top-right of `area`.
+/// `file_index` is the size of the workspace file index, when there is one.
+pub fn render_perf_hud(
+    frame: &mut Frame,
+    area: Rect,
+    snapshot: &PerfSnapshot,
+    marker_count: usize,
+    file_index: Option<FileIndexStats>,
+    theme: &Theme,
+) {
+    let label_style = Style::default().fg(theme.line_number_fg);
+    let value_style = Style::default().fg(theme.popup_text_fg);
+    let row = |label: String, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<10}", label), label_style),
+            Span::styled(value, value_style),
+        ])
+    };
+
+    let mut lines = vec![
+        row(
+            t!("perf_hud.frame").to_string(),
+            format_timing(&snapshot.frame),
+        ),
+        row(
+            t!("perf_hud.highlight").to_string(),
+            format_timing(&snapshot.highlight),
+        ),
+        row(
+            t!("perf_hud.layout").to_string(),
+            format_timing(&snapshot.layout),
+        ),
+        row(
+            t!("perf_hud.output").to_string(),
+            match (snapshot.output_bytes_last, snapshot.output_bytes_avg) {
+                (Some(last), Some(avg)) => {
+                    format!("{} avg {}", format_bytes(last), format_bytes(avg))
+                }
+                _ => "-".to_string(),
+            },
+        ),
+        row(t!("perf_hud.markers").to_string(), marker_count.to_string()),
+    ];
+    if let Some(stats) = file_index {
+        lines.push(row(
+            t!("perf_hud.files").to_string(),
+            format!(
+                "{} {} in {}",
+                stats.files,
+                format_bytes(stats.bytes),
+                format_micros(stats.build_time.as_micros() as u64)
+            ),
+        ));
+    }
+
+    let mut lsp: Vec<_> = snapshot.lsp.iter().collect();
+    lsp.sort_by_key(|latency| std::cmp::Reverse(latency.last_us));
+    for latency in lsp.into_iter().take(MAX_LSP_ROWS) {
+        let method = latency.method.rsplit('/').next().unwrap_or(&latency.method);
+        lines.push(row(
+            method.to_string(),
+            format!(
+                "{} avg {} ({})",
+                format_micros(latency.last_us),
+                format_micros(latency.avg_us),
+                latency.count
+            ),
+        ));
+    }
+
+    let width = HUD_WIDTH.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let hud_area = Rect {
+        x: area.x + area.width.saturating_sub(width),
+        // Below the menu bar when there is room
+        y: area.y + u16::from(area.height > height),
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, hud_area);
+    let block = Block::default()
+        .title(Span::styled(
+            t!("perf_hud.title").to_string(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.popup_border_fg))
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_text_fg));
+    frame.render_widget(Paragraph::new(lines).block(block), hud_area);
+}
+
+/// "last avg max" in milliseconds
+fn format_timing(timing: &Timing) -> String {
+    format!(
+        "{} avg {} max {}",
+        format_micros(timing.last_us),
+        format_micros(timing.avg_us),
+        format_micros(timing.max_us)
+    )
+}
+
+fn format_micros(micros: u64) -> String {
+    format!("{:.1}ms", micros as f64 / 1000.0)
+}
+
+fn format_bytes(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{}B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1}K", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1}M", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_timing() {
+        let timing = Timing {
+            last_us: 1240,
+            avg_us: 800,
+            max_us: 12_000,
+        };
+        assert_eq!(format_timing(&timing), "1.2ms avg 0.8ms max 12.0ms");
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(3072), "3.0K");
+        assert_eq!(format_bytes(5 * 1024 * 1024 / 2), "2.5M");
+    }
+}
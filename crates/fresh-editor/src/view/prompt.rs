@@ -67,10 +67,30 @@ pub enum PromptType {
     SetBookmark,
     /// Jump to a bookmark - prompts for register (0-9)
     JumpToBookmark,
+    /// Label for a new line bookmark at the cursor
+    BookmarkLabel,
+    /// Pick a line bookmark from any buffer (select from list)
+    BrowseBookmarks,
+    /// Pick an entry of the clipboard history to paste (select from list)
+    PasteFromHistory,
+    /// Pick a Unicode character by name or codepoint to insert (select from list)
+    InsertUnicodeChar,
+    /// Pick where an HTML or ANSI export goes (select from list)
+    ExportDestination,
     /// Set compose width (empty clears to viewport)
     SetComposeWidth,
     /// Add a vertical ruler at a column position
     AddRuler,
+    /// Regex to place one cursor per line on before aligning them
+    AlignByPattern,
+    /// Width to rewrap the paragraphs at
+    HardWrap,
+    /// Regex the lines of a log file must match to be shown
+    FilterLogLines,
+    /// Character or pair to surround the selections with
+    SurroundWith,
+    /// Pair to put in place of the one around the cursors (empty removes it)
+    ChangeSurrounding,
     /// Remove a vertical ruler (select from list)
     RemoveRuler,
     /// Set tab size for current buffer
@@ -81,6 +101,8 @@ pub enum PromptType {
     SetEncoding,
     /// Set language/syntax highlighting for current buffer
     SetLanguage,
+    /// Pick the language of a new scratch buffer
+    NewScratchBuffer,
     /// Stop a running LSP server (select from list)
     StopLspServer,
     /// Select a theme (select from list)
@@ -98,6 +120,12 @@ pub enum PromptType {
     ConfirmRevert,
     /// Confirm saving over a file that changed on disk
     ConfirmSaveConflict,
+    /// Keep, reload or diff a modified buffer whose file changed on disk
+    ConfirmExternalChange {
+        buffer_id: crate::model::event::BufferId,
+    },
+    /// Offer to restart a language server stopped for exceeding its memory limit
+    ConfirmLspRestart { language: String },
     /// Confirm saving with sudo after permission denied
     ConfirmSudoSave {
         info: crate::model::buffer::SudoSaveRequired,
@@ -109,8 +137,12 @@ pub enum PromptType {
     ConfirmCloseBuffer {
         buffer_id: crate::model::event::BufferId,
     },
-    /// Confirm quitting with modified buffers
+    /// Confirm quitting with unsaved work (save all/discard all/review/cancel)
     ConfirmQuitWithModified,
+    /// Confirm one item while reviewing unsaved work before quitting
+    QuitReview {
+        buffer_id: crate::model::event::BufferId,
+    },
     /// File Explorer rename operation
     /// Stores the original path and name for the file/directory being renamed
     FileExplorerRename {
@@ -130,6 +162,24 @@ pub enum PromptType {
     ConfirmLargeFileEncoding { path: std::path::PathBuf },
     /// Switch to a tab by name (from the current split's open buffers)
     SwitchToTab,
+    /// Buffer picker: open buffers in most recently used order, previewing the
+    /// selected one
+    SwitchBuffer,
+    /// Switch to an open terminal
+    SwitchTerminal,
+    /// Run a task from the `tasks` config (select from list)
+    RunTask,
+    /// Rename a terminal's tab title
+    RenameTerminal {
+        buffer_id: crate::model::event::BufferId,
+    },
+    /// Adjust the channels of a color literal, previewed on its swatch
+    AdjustColor {
+        buffer_id: crate::model::event::BufferId,
+        literal: crate::primitives::color_literal::ColorLiteral,
+    },
+    /// Confirm closing a split that holds running terminals
+    ConfirmCloseSplit,
     /// Run shell command on buffer/selection
     /// If replace is true, replace the input with the output
     /// If replace is false, output goes to a new buffer
@@ -348,6 +398,16 @@ impl Prompt {
         }
     }
 
+    /// Whether this prompt lists commands: the command prompt, or Quick Open
+    /// with the `>` prefix
+    pub fn is_command_palette(&self) -> bool {
+        match self.prompt_type {
+            PromptType::Command => true,
+            PromptType::QuickOpen => self.input.starts_with('>'),
+            _ => false,
+        }
+    }
+
     /// Get the currently selected suggestion value
     pub fn selected_value(&self) -> Option<String> {
         self.selected_suggestion
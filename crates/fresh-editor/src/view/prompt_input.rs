@@ -63,6 +63,18 @@ impl InputHandler for Prompt {
                 ctx.defer(DeferredAction::UpdatePromptSuggestions);
                 InputResult::Consumed
             }
+            // Delete at the end of the buffer picker's filter closes the
+            // selected buffer
+            KeyCode::Delete
+                if matches!(
+                    self.prompt_type,
+                    crate::view::prompt::PromptType::SwitchBuffer
+                ) && !self.has_selection()
+                    && self.cursor_pos == self.input.len() =>
+            {
+                ctx.defer(DeferredAction::CloseBufferFromPicker);
+                InputResult::Consumed
+            }
             KeyCode::Delete => {
                 if self.has_selection() {
                     self.delete_selection();
@@ -145,6 +157,7 @@ impl InputHandler for Prompt {
                                 self.prompt_type,
                                 crate::view::prompt::PromptType::Plugin { .. }
                                     | crate::view::prompt::PromptType::QuickOpen
+                                    | crate::view::prompt::PromptType::SwitchBuffer
                             );
                         if should_sync {
                             if let Some(suggestion) = self.suggestions.get(new_selected) {
@@ -189,6 +202,7 @@ impl InputHandler for Prompt {
                                 self.prompt_type,
                                 crate::view::prompt::PromptType::Plugin { .. }
                                     | crate::view::prompt::PromptType::QuickOpen
+                                    | crate::view::prompt::PromptType::SwitchBuffer
                             );
                         if should_sync {
                             if let Some(suggestion) = self.suggestions.get(new_selected) {
@@ -235,6 +249,27 @@ impl InputHandler for Prompt {
                 InputResult::Consumed
             }
 
+            // Ctrl+Tab and Ctrl+Shift+Tab cycle through the buffer picker
+            KeyCode::Tab | KeyCode::BackTab
+                if ctrl
+                    && matches!(
+                        self.prompt_type,
+                        crate::view::prompt::PromptType::SwitchBuffer
+                    ) =>
+            {
+                let len = self.suggestions.len();
+                if len > 0 {
+                    let selected = self.selected_suggestion.unwrap_or(0);
+                    let backward = shift || event.code == KeyCode::BackTab;
+                    self.selected_suggestion = Some(if backward {
+                        (selected + len - 1) % len
+                    } else {
+                        (selected + 1) % len
+                    });
+                }
+                InputResult::Consumed
+            }
+
             // Tab accepts suggestion
             KeyCode::Tab => {
                 if let Some(selected) = self.selected_suggestion {
@@ -268,6 +303,14 @@ impl InputHandler for Prompt {
                 InputResult::Consumed
             }
 
+            // F2 binds the highlighted command palette entry to a key
+            KeyCode::F(2) if self.is_command_palette() => {
+                ctx.defer(DeferredAction::ExecuteAction(
+                    crate::input::keybindings::Action::PromptBindCommand,
+                ));
+                InputResult::Consumed
+            }
+
             _ => InputResult::Consumed, // Modal - consume all unhandled keys
         }
     }
@@ -23,6 +23,7 @@
 /// └────────────────────┘      └──────────┴─────────┘
 ///  (horizontal split)          (mixed splits)
 /// ```
+use crate::input::position_history::PositionHistory;
 use crate::model::buffer::Buffer;
 use crate::model::cursor::Cursors;
 use crate::model::event::{BufferId, ContainerId, LeafId, SplitDirection, SplitId};
@@ -33,7 +34,7 @@ use crate::view::viewport::Viewport;
 use crate::{services::plugins::api::ViewTransformPayload, state::ViewMode};
 use ratatui::layout::Rect;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// A node in the split tree
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -127,6 +128,43 @@ impl BufferViewState {
         self.viewport.ensure_visible(buffer, &cursor, &hidden);
     }
 
+    /// Scroll by `delta` lines as shown (negative scrolls up), so a folded
+    /// range counts as the one line of its header
+    pub fn scroll_shown_lines(
+        &mut self,
+        buffer: &mut Buffer,
+        marker_list: &MarkerList,
+        delta: isize,
+    ) {
+        let folds = self.folds.resolved_ranges(buffer, marker_list);
+        for _ in 0..delta.unsigned_abs() {
+            let top_before = (self.viewport.top_byte, self.viewport.top_view_line_offset);
+            if delta > 0 {
+                self.viewport.scroll_down(buffer, 1);
+            } else {
+                self.viewport.scroll_up(buffer, 1);
+            }
+            let top_line = buffer.get_line_number(self.viewport.top_byte);
+            if let Some(fold) = folds
+                .iter()
+                .find(|fold| top_line >= fold.start_line && top_line <= fold.end_line)
+            {
+                let target_line = if delta > 0 {
+                    fold.end_line.saturating_add(1)
+                } else {
+                    fold.header_line
+                };
+                self.viewport.top_byte = buffer
+                    .line_start_offset(target_line)
+                    .unwrap_or_else(|| buffer.len());
+                self.viewport.top_view_line_offset = 0;
+            }
+            if (self.viewport.top_byte, self.viewport.top_view_line_offset) == top_before {
+                break;
+            }
+        }
+    }
+
     /// Create a new buffer view state with defaults
     pub fn new(width: u16, height: u16) -> Self {
         Self {
@@ -187,6 +225,10 @@ pub struct SplitViewState {
     /// The currently displayed buffer is tracked in the SplitNode::Leaf
     pub open_buffers: Vec<BufferId>,
 
+    /// Pinned tabs. They always sit at the start of `open_buffers` and are
+    /// skipped by "Close Others" / "Close All".
+    pub pinned_buffers: HashSet<BufferId>,
+
     /// Horizontal scroll offset for the tabs in this split
     pub tab_scroll_offset: usize,
 
@@ -201,6 +243,9 @@ pub struct SplitViewState {
     /// Used for "Switch to Previous Tab" and for returning to previous buffer when closing
     pub focus_history: Vec<BufferId>,
 
+    /// Jump list for Navigate Back/Forward in this split
+    pub jump_list: PositionHistory,
+
     /// Sync group ID for synchronized scrolling
     /// Splits with the same sync_group will scroll together
     pub sync_group: Option<u32>,
@@ -236,10 +281,12 @@ impl SplitViewState {
             active_buffer: buffer_id,
             keyed_states,
             open_buffers: vec![buffer_id],
+            pinned_buffers: HashSet::new(),
             tab_scroll_offset: 0,
             layout: None,
             layout_dirty: true,
             focus_history: Vec::new(),
+            jump_list: PositionHistory::new(),
             sync_group: None,
             composite_view: None,
         }
@@ -354,6 +401,7 @@ impl SplitViewState {
     /// Remove a buffer from this split's tabs and clean up its keyed state
     pub fn remove_buffer(&mut self, buffer_id: BufferId) {
         self.open_buffers.retain(|&id| id != buffer_id);
+        self.pinned_buffers.remove(&buffer_id);
         // Clean up keyed state (but never remove the active buffer's state)
         if buffer_id != self.active_buffer {
             self.keyed_states.remove(&buffer_id);
@@ -365,6 +413,35 @@ impl SplitViewState {
         self.open_buffers.contains(&buffer_id)
     }
 
+    /// Check if a buffer's tab is pinned in this split
+    pub fn is_pinned(&self, buffer_id: BufferId) -> bool {
+        self.pinned_buffers.contains(&buffer_id)
+    }
+
+    /// Pin or unpin a tab. A newly pinned tab moves to the end of the pinned
+    /// group; an unpinned one moves to the start of the unpinned tabs.
+    pub fn set_pinned(&mut self, buffer_id: BufferId, pinned: bool) {
+        let Some(current_idx) = self.open_buffers.iter().position(|&id| id == buffer_id) else {
+            return;
+        };
+        if pinned {
+            self.pinned_buffers.insert(buffer_id);
+        } else {
+            self.pinned_buffers.remove(&buffer_id);
+        }
+        self.open_buffers.remove(current_idx);
+        let target_idx = self.pinned_tab_count();
+        self.open_buffers.insert(target_idx, buffer_id);
+    }
+
+    /// Number of pinned tabs (they occupy the first slots of `open_buffers`)
+    pub fn pinned_tab_count(&self) -> usize {
+        self.open_buffers
+            .iter()
+            .filter(|id| self.pinned_buffers.contains(id))
+            .count()
+    }
+
     /// Push a buffer to the focus history (LRU-style)
     /// If the buffer is already in history, it's moved to the end
     pub fn push_focus(&mut self, buffer_id: BufferId) {
@@ -484,6 +561,29 @@ impl SplitNode {
         }
     }
 
+    /// Get the rectangle a split occupies when the tree is laid out in `rect`
+    pub fn rect_of(&self, target_id: SplitId, rect: Rect) -> Option<Rect> {
+        if self.id() == target_id {
+            return Some(rect);
+        }
+
+        match self {
+            Self::Leaf { .. } => None,
+            Self::Split {
+                direction,
+                first,
+                second,
+                ratio,
+                ..
+            } => {
+                let (first_rect, second_rect) = split_rect(rect, *direction, *ratio);
+                first
+                    .rect_of(target_id, first_rect)
+                    .or_else(|| second.rect_of(target_id, second_rect))
+            }
+        }
+    }
+
     /// Get all leaf nodes (buffer views) with their rectangles
     pub fn get_leaves_with_rects(&self, rect: Rect) -> Vec<(LeafId, BufferId, Rect)> {
         match self {
@@ -603,6 +703,9 @@ impl SplitNode {
     }
 }
 
+/// Smallest size, in rows or columns, that keyboard resizing leaves a split
+const MIN_SPLIT_SIZE: u16 = 3;
+
 /// Split a rectangle into two parts based on direction and ratio
 /// Leaves 1 character space for the separator line between splits
 fn split_rect(rect: Rect, direction: SplitDirection, ratio: f32) -> (Rect, Rect) {
@@ -655,7 +758,7 @@ fn split_rect(rect: Rect, direction: SplitDirection, ratio: f32) -> (Rect, Rect)
 }
 
 /// Manager for the split view system
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SplitManager {
     /// Root of the split tree
     root: SplitNode,
@@ -944,6 +1047,53 @@ impl SplitManager {
         }
     }
 
+    /// Grow a leaf split by `cells` rows or columns along its parent's axis
+    ///
+    /// Negative values shrink it. Both sides keep at least [`MIN_SPLIT_SIZE`]
+    /// cells and the ratio stays within the usual 0.1..0.9 range. Returns
+    /// false if the leaf has no parent or is already at the limit.
+    pub fn resize_leaf(&mut self, leaf_id: LeafId, cells: i32, viewport_rect: Rect) -> bool {
+        let Some(container_id) = self.parent_container_of(leaf_id) else {
+            return false;
+        };
+        let Some(container_rect) = self.root.rect_of(container_id.into(), viewport_rect) else {
+            return false;
+        };
+        let Some(SplitNode::Split {
+            direction,
+            first,
+            ratio,
+            ..
+        }) = self.root.find_mut(container_id.into())
+        else {
+            return false;
+        };
+
+        // One row or column goes to the separator
+        let total = match direction {
+            SplitDirection::Horizontal => container_rect.height,
+            SplitDirection::Vertical => container_rect.width,
+        }
+        .saturating_sub(1) as i32;
+        let min = (MIN_SPLIT_SIZE as i32).max((total as f32 * 0.1).ceil() as i32);
+        if total < min * 2 {
+            return false;
+        }
+
+        let first_size = (total as f32 * *ratio).round() as i32;
+        let delta = if first.find(leaf_id.into()).is_some() {
+            cells
+        } else {
+            -cells
+        };
+        let new_first = (first_size + delta).clamp(min, total - min);
+        if new_first == first_size {
+            return false;
+        }
+        *ratio = new_first as f32 / total as f32;
+        true
+    }
+
     /// Find the parent container of a leaf
     pub fn parent_container_of(&self, leaf_id: LeafId) -> Option<ContainerId> {
         self.root.parent_container_of(leaf_id.into())
@@ -1437,4 +1587,60 @@ mod tests {
         // Only split is labeled — returns None
         assert_eq!(manager.find_unlabeled_leaf(), None);
     }
+
+    #[test]
+    fn test_pinned_tabs_stay_leftmost() {
+        let mut view_state = SplitViewState::with_buffer(80, 24, BufferId(0));
+        view_state.add_buffer(BufferId(1));
+        view_state.add_buffer(BufferId(2));
+
+        view_state.set_pinned(BufferId(2), true);
+        view_state.set_pinned(BufferId(1), true);
+        assert_eq!(
+            view_state.open_buffers,
+            vec![BufferId(2), BufferId(1), BufferId(0)]
+        );
+        assert_eq!(view_state.pinned_tab_count(), 2);
+
+        // Unpinning moves the tab just past the remaining pinned tabs
+        view_state.set_pinned(BufferId(2), false);
+        assert_eq!(
+            view_state.open_buffers,
+            vec![BufferId(1), BufferId(2), BufferId(0)]
+        );
+
+        view_state.remove_buffer(BufferId(1));
+        assert!(!view_state.is_pinned(BufferId(1)));
+        assert_eq!(view_state.pinned_tab_count(), 0);
+    }
+
+    #[test]
+    fn test_resize_leaf_by_cells() {
+        let rect = Rect {
+            x: 0,
+            y: 0,
+            width: 101,
+            height: 40,
+        };
+        let mut manager = SplitManager::new(BufferId(0));
+        let first = manager.active_split();
+        let second = manager
+            .split_active(SplitDirection::Vertical, BufferId(1), 0.5)
+            .unwrap();
+        let container = manager.parent_container_of(second).unwrap();
+
+        // Growing the right pane moves the separator left by one column
+        assert!(manager.resize_leaf(second, 1, rect));
+        assert_eq!(manager.get_ratio(container.into()), Some(0.49));
+
+        // Shrinking stops at 10% of the width
+        assert!(manager.resize_leaf(first, -100, rect));
+        assert_eq!(manager.get_ratio(container.into()), Some(0.1));
+        assert!(!manager.resize_leaf(first, -1, rect));
+
+        // A lone split has nothing to resize against
+        let mut single = SplitManager::new(BufferId(0));
+        let leaf = single.active_split();
+        assert!(!single.resize_leaf(leaf, 1, rect));
+    }
 }
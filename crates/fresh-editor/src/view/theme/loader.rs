@@ -3,11 +3,25 @@
 //! This module provides:
 //! - `ThemeRegistry`: A pure data structure holding all loaded themes
 //! - `ThemeLoader`: Scans and loads themes into a registry
+//!
+//! User themes may be JSON or TOML and may set `extends` to the name of
+//! another theme, in which case only the overridden colors need to be given.
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::types::{
+    parse_hex_color, DiagnosticColors, EditorColors, SearchColors, SyntaxColors, Theme, ThemeFile,
+    ThemeInfo, UiColors, BUILTIN_THEMES, NAMED_COLORS,
+};
 
-use super::types::{Theme, ThemeFile, ThemeInfo, BUILTIN_THEMES};
+/// Sections every theme file must define (directly or through `extends`)
+const SECTIONS: [&str; 5] = ["editor", "ui", "search", "diagnostic", "syntax"];
 
 /// Normalize a theme name for consistent lookup and storage.
 ///
@@ -18,6 +32,21 @@ pub fn normalize_theme_name(name: &str) -> String {
     name.to_lowercase().replace('_', "-").replace(' ', "-")
 }
 
+/// A theme file that could not be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeError {
+    /// Path of the theme file
+    pub path: PathBuf,
+    /// What is wrong, prefixed with the offending key where there is one
+    pub message: String,
+}
+
+impl std::fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
 /// A registry holding all loaded themes.
 ///
 /// This is a pure data structure - no I/O operations.
@@ -28,6 +57,8 @@ pub struct ThemeRegistry {
     themes: HashMap<String, Theme>,
     /// Theme metadata for listing
     theme_list: Vec<ThemeInfo>,
+    /// Theme files that failed to load
+    errors: Vec<ThemeError>,
 }
 
 impl ThemeRegistry {
@@ -65,6 +96,21 @@ impl ThemeRegistry {
     pub fn is_empty(&self) -> bool {
         self.themes.is_empty()
     }
+
+    /// Theme files that were found but could not be loaded.
+    pub fn errors(&self) -> &[ThemeError] {
+        &self.errors
+    }
+}
+
+/// A theme file read from disk, before `extends` is resolved.
+struct PendingTheme {
+    /// Normalized theme name
+    name: String,
+    pack: String,
+    path: PathBuf,
+    /// Parsed file contents, or why parsing failed
+    value: Result<Value, String>,
 }
 
 /// Loads themes and creates a ThemeRegistry.
@@ -107,9 +153,11 @@ impl ThemeLoader {
             }
         }
 
+        let mut pending = Vec::new();
+
         // Load user themes from ~/.config/fresh/themes/ (recursively)
         if let Some(ref user_dir) = self.user_themes_dir {
-            self.scan_directory(user_dir, "user", &mut themes, &mut theme_list);
+            self.scan_directory(user_dir, "user", &mut pending);
         }
 
         // Load theme packages from ~/.config/fresh/themes/packages/*/
@@ -127,21 +175,11 @@ impl ThemeLoader {
                                     // Check for package.json to get theme metadata
                                     let manifest_path = path.join("package.json");
                                     if manifest_path.exists() {
-                                        self.load_package_themes(
-                                            &path,
-                                            name,
-                                            &mut themes,
-                                            &mut theme_list,
-                                        );
+                                        self.load_package_themes(&path, name, &mut pending);
                                     } else {
                                         // Fallback: scan directory for JSON files
                                         let pack_name = format!("pkg/{}", name);
-                                        self.scan_directory(
-                                            &path,
-                                            &pack_name,
-                                            &mut themes,
-                                            &mut theme_list,
-                                        );
+                                        self.scan_directory(&path, &pack_name, &mut pending);
                                     }
                                 }
                             }
@@ -151,17 +189,68 @@ impl ThemeLoader {
             }
         }
 
-        ThemeRegistry { themes, theme_list }
+        // Resolve `extends` and validate once every file has been read, so
+        // themes can extend each other regardless of scan order
+        let mut errors = Vec::new();
+        for (index, theme) in pending.iter().enumerate() {
+            // Skip if already loaded (embedded themes take priority)
+            if themes.contains_key(&theme.name) {
+                continue;
+            }
+            match resolve_theme(index, &pending) {
+                Ok(theme_file) => {
+                    themes.insert(theme.name.clone(), theme_file.into());
+                    theme_list.push(ThemeInfo::new(theme.name.clone(), theme.pack.clone()));
+                }
+                Err(messages) => {
+                    for message in messages {
+                        let error = ThemeError {
+                            path: theme.path.clone(),
+                            message,
+                        };
+                        tracing::warn!("Failed to load theme: {}", error);
+                        errors.push(error);
+                    }
+                }
+            }
+        }
+
+        ThemeRegistry {
+            themes,
+            theme_list,
+            errors,
+        }
+    }
+
+    /// Paths and modification times of all theme files in the user themes
+    /// directory. Compare two stamps to detect added, removed or edited themes.
+    pub fn files_stamp(&self) -> Vec<(PathBuf, SystemTime)> {
+        fn walk(dir: &Path, stamp: &mut Vec<(PathBuf, SystemTime)>) {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                return;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    walk(&path, stamp);
+                } else if is_theme_file(&path) {
+                    if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                        stamp.push((path, modified));
+                    }
+                }
+            }
+        }
+
+        let mut stamp = Vec::new();
+        if let Some(ref user_dir) = self.user_themes_dir {
+            walk(user_dir, &mut stamp);
+        }
+        stamp.sort();
+        stamp
     }
 
     /// Load themes from a package with package.json manifest.
-    fn load_package_themes(
-        &self,
-        pkg_dir: &Path,
-        pkg_name: &str,
-        themes: &mut HashMap<String, Theme>,
-        theme_list: &mut Vec<ThemeInfo>,
-    ) {
+    fn load_package_themes(&self, pkg_dir: &Path, pkg_name: &str, pending: &mut Vec<PendingTheme>) {
         let manifest_path = pkg_dir.join("package.json");
         let manifest_content = match std::fs::read_to_string(&manifest_path) {
             Ok(c) => c,
@@ -184,20 +273,8 @@ impl ThemeLoader {
                     ) {
                         let theme_path = pkg_dir.join(file);
                         if theme_path.exists() {
-                            if let Ok(content) = std::fs::read_to_string(&theme_path) {
-                                if let Ok(theme_file) = serde_json::from_str::<ThemeFile>(&content)
-                                {
-                                    let theme: Theme = theme_file.into();
-                                    let normalized_name = normalize_theme_name(name);
-                                    // Don't overwrite existing themes
-                                    if !themes.contains_key(&normalized_name) {
-                                        themes.insert(normalized_name.clone(), theme);
-                                        let pack_name = format!("pkg/{}", pkg_name);
-                                        theme_list
-                                            .push(ThemeInfo::new(normalized_name, &pack_name));
-                                    }
-                                }
-                            }
+                            let pack_name = format!("pkg/{}", pkg_name);
+                            pending.push(PendingTheme::read(theme_path, name, &pack_name));
                         }
                     }
                 }
@@ -207,17 +284,11 @@ impl ThemeLoader {
 
         // Fallback: if no fresh.themes, scan for JSON files
         let pack_name = format!("pkg/{}", pkg_name);
-        self.scan_directory(pkg_dir, &pack_name, themes, theme_list);
+        self.scan_directory(pkg_dir, &pack_name, pending);
     }
 
     /// Recursively scan a directory for theme files.
-    fn scan_directory(
-        &self,
-        dir: &Path,
-        pack: &str,
-        themes: &mut HashMap<String, Theme>,
-        theme_list: &mut Vec<ThemeInfo>,
-    ) {
+    fn scan_directory(&self, dir: &Path, pack: &str, pending: &mut Vec<PendingTheme>) {
         let entries = match std::fs::read_dir(dir) {
             Ok(e) => e,
             Err(_) => return,
@@ -241,26 +312,194 @@ impl ThemeLoader {
                 } else {
                     format!("{}/{}", pack, subdir_name)
                 };
-                self.scan_directory(&path, &new_pack, themes, theme_list);
-            } else if path.extension().is_some_and(|ext| ext == "json") {
-                // Load theme file
+                self.scan_directory(&path, &new_pack, pending);
+            } else if is_theme_file(&path) {
                 let raw_name = path.file_stem().unwrap().to_string_lossy().to_string();
-                let name = normalize_theme_name(&raw_name);
+                pending.push(PendingTheme::read(path, &raw_name, pack));
+            }
+        }
+    }
+}
 
-                // Skip if already loaded (embedded themes take priority)
-                if themes.contains_key(&name) {
-                    continue;
-                }
+impl PendingTheme {
+    /// Read and parse a theme file. A file without a `name` takes the given one.
+    fn read(path: PathBuf, name: &str, pack: &str) -> Self {
+        let value = parse_theme_file(&path).map(|mut value| {
+            if let Value::Object(ref mut object) = value {
+                object
+                    .entry("name")
+                    .or_insert_with(|| Value::String(name.to_string()));
+            }
+            value
+        });
+        Self {
+            name: normalize_theme_name(name),
+            pack: pack.to_string(),
+            path,
+            value,
+        }
+    }
+}
+
+/// Whether a path looks like a theme file (by extension)
+fn is_theme_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext == "json" || ext == "toml")
+}
+
+/// Parse a JSON or TOML theme file into a JSON value
+fn parse_theme_file(path: &Path) -> Result<Value, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    if path.extension().is_some_and(|ext| ext == "toml") {
+        toml::from_str(&content).map_err(|e| match e.span() {
+            Some(span) => format!(
+                "invalid TOML at line {}: {}",
+                content[..span.start].matches('\n').count() + 1,
+                e.message().trim()
+            ),
+            None => format!("invalid TOML: {}", e.message().trim()),
+        })
+    } else {
+        serde_json::from_str(&content).map_err(|e| format!("invalid JSON: {}", e))
+    }
+}
+
+/// Resolve a pending theme's `extends` chain, validate it and build the theme.
+/// Returns every problem found, each naming the offending key.
+fn resolve_theme(index: usize, pending: &[PendingTheme]) -> Result<ThemeFile, Vec<String>> {
+    let value = resolve_extends(index, pending, &mut Vec::new()).map_err(|e| vec![e])?;
+    let problems = validate_theme(&value);
+    if !problems.is_empty() {
+        return Err(problems);
+    }
+    serde_json::from_value(value).map_err(|e| vec![e.to_string()])
+}
 
-                if let Ok(content) = std::fs::read_to_string(&path) {
-                    if let Ok(theme_file) = serde_json::from_str::<ThemeFile>(&content) {
-                        let theme: Theme = theme_file.into();
-                        themes.insert(name.clone(), theme);
-                        theme_list.push(ThemeInfo::new(name, pack));
+/// Merge a theme's value over the theme it extends, recursively.
+/// `chain` holds the themes currently being resolved, to detect cycles.
+fn resolve_extends(
+    index: usize,
+    pending: &[PendingTheme],
+    chain: &mut Vec<usize>,
+) -> Result<Value, String> {
+    let mut value = pending[index].value.clone()?;
+    let base = match value.as_object_mut().and_then(|o| o.remove("extends")) {
+        None => return Ok(value),
+        Some(Value::String(base)) => base,
+        Some(_) => return Err("extends: expected a theme name".to_string()),
+    };
+
+    let base_name = normalize_theme_name(&base);
+    let mut merged = if let Some(builtin) = BUILTIN_THEMES
+        .iter()
+        .find(|b| normalize_theme_name(b.name) == base_name)
+    {
+        serde_json::from_str(builtin.json).map_err(|e| e.to_string())?
+    } else if let Some(base_index) = pending.iter().position(|p| p.name == base_name) {
+        if base_index == index || chain.contains(&base_index) {
+            return Err(format!("extends: circular reference to \"{}\"", base));
+        }
+        chain.push(index);
+        let base_value = resolve_extends(base_index, pending, chain);
+        chain.pop();
+        base_value.map_err(|_| format!("extends: base theme \"{}\" failed to load", base))?
+    } else {
+        return Err(format!("extends: unknown theme \"{}\"", base));
+    };
+
+    merge_values(&mut merged, value);
+    Ok(merged)
+}
+
+/// Deep-merge `overlay` into `base`: tables merge key by key, anything else replaces.
+fn merge_values(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_values(existing, value),
+                    None => {
+                        base.insert(key, value);
                     }
                 }
             }
         }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Check a resolved theme for unknown keys, malformed colors and missing sections.
+fn validate_theme(value: &Value) -> Vec<String> {
+    let Some(object) = value.as_object() else {
+        return vec!["expected a table of theme sections".to_string()];
+    };
+
+    let mut problems = Vec::new();
+    for (key, section) in object {
+        if key == "name" {
+            if !section.is_string() {
+                problems.push("name: expected a string".to_string());
+            }
+            continue;
+        }
+        let Some(known) = section_keys(key) else {
+            problems.push(format!("{}: unknown key", key));
+            continue;
+        };
+        let Some(colors) = section.as_object() else {
+            problems.push(format!("{}: expected a table of colors", key));
+            continue;
+        };
+        for (name, color) in colors {
+            if !known.contains(name) {
+                problems.push(format!("{}.{}: unknown key", key, name));
+            } else if let Err(e) = check_color(color) {
+                problems.push(format!("{}.{}: {}", key, name, e));
+            }
+        }
+    }
+    for section in SECTIONS {
+        if !object.contains_key(section) {
+            problems.push(format!("{}: missing section", section));
+        }
+    }
+    problems
+}
+
+/// Keys allowed in a theme section, or None for an unknown section
+fn section_keys(section: &str) -> Option<Vec<String>> {
+    fn field_names<T: DeserializeOwned + Serialize>() -> Vec<String> {
+        serde_json::from_value::<T>(Value::Object(Default::default()))
+            .ok()
+            .and_then(|defaults| serde_json::to_value(defaults).ok())
+            .and_then(|value| value.as_object().map(|o| o.keys().cloned().collect()))
+            .unwrap_or_default()
+    }
+
+    match section {
+        "editor" => Some(field_names::<EditorColors>()),
+        "ui" => Some(field_names::<UiColors>()),
+        "search" => Some(field_names::<SearchColors>()),
+        "diagnostic" => Some(field_names::<DiagnosticColors>()),
+        "syntax" => Some(field_names::<SyntaxColors>()),
+        _ => None,
+    }
+}
+
+/// Check that a value is `[r, g, b]`, `"#RRGGBB"` or a known color name
+fn check_color(value: &Value) -> Result<(), String> {
+    match value {
+        Value::String(name) if NAMED_COLORS.contains(&name.as_str()) => Ok(()),
+        Value::String(hex) if hex.starts_with('#') => parse_hex_color(hex)
+            .map(|_| ())
+            .ok_or_else(|| format!("invalid hex color \"{}\"", hex)),
+        Value::String(name) => Err(format!("unknown color name \"{}\"", name)),
+        Value::Array(parts)
+            if parts.len() == 3 && parts.iter().all(|p| p.as_u64().is_some_and(|n| n <= 255)) =>
+        {
+            Ok(())
+        }
+        _ => Err("expected [r, g, b] with values 0-255 or a color name".to_string()),
     }
 }
 
@@ -596,4 +835,176 @@ mod tests {
             "Nested theme should have subdirectory in pack name"
         );
     }
+
+    #[test]
+    fn test_user_theme_extends_builtin() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let themes_dir = temp_dir.path().to_path_buf();
+
+        std::fs::write(
+            themes_dir.join("my-dark.json"),
+            r#"{ "extends": "dark", "editor": { "bg": [1, 2, 3] } }"#,
+        )
+        .unwrap();
+
+        let registry = ThemeLoader::new(themes_dir).load_all();
+        assert!(registry.errors().is_empty(), "{:?}", registry.errors());
+
+        let theme = registry
+            .get("my-dark")
+            .expect("Extending theme should load");
+        let dark = registry.get("dark").unwrap();
+        assert_eq!(theme.name, "my-dark");
+        assert_eq!(theme.editor_bg, ratatui::style::Color::Rgb(1, 2, 3));
+        assert_eq!(theme.editor_fg, dark.editor_fg);
+        assert_eq!(theme.tab_active_bg, dark.tab_active_bg);
+    }
+
+    #[test]
+    fn test_toml_theme_extends_user_theme() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let themes_dir = temp_dir.path().to_path_buf();
+
+        std::fs::write(
+            themes_dir.join("base.toml"),
+            "extends = \"light\"\n\n[editor]\nbg = [200, 200, 200]\nfg = \"Blue\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            themes_dir.join("child.toml"),
+            "name = \"Child Theme\"\nextends = \"base\"\n\n[editor]\nfg = [9, 9, 9]\n",
+        )
+        .unwrap();
+
+        let registry = ThemeLoader::new(themes_dir).load_all();
+        assert!(registry.errors().is_empty(), "{:?}", registry.errors());
+
+        let child = registry.get("child").expect("TOML theme should load");
+        assert_eq!(child.name, "Child Theme");
+        assert_eq!(child.editor_bg, ratatui::style::Color::Rgb(200, 200, 200));
+        assert_eq!(child.editor_fg, ratatui::style::Color::Rgb(9, 9, 9));
+        assert_eq!(
+            registry.get("base").unwrap().editor_fg,
+            ratatui::style::Color::Blue
+        );
+    }
+
+    #[test]
+    fn test_theme_validation_errors_name_the_key() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let themes_dir = temp_dir.path().to_path_buf();
+
+        std::fs::write(
+            themes_dir.join("broken.json"),
+            r#"{
+                "extends": "dark",
+                "editor": { "bg": "Purple", "bogus": [0, 0, 0] },
+                "ui": { "tab_active_bg": [300, 0, 0] }
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(themes_dir.join("partial.json"), r#"{ "editor": {} }"#).unwrap();
+        std::fs::write(themes_dir.join("orphan.toml"), "extends = \"nope\"\n").unwrap();
+        std::fs::write(themes_dir.join("syntax.toml"), "[editor\n").unwrap();
+
+        let registry = ThemeLoader::new(themes_dir.clone()).load_all();
+        for name in ["broken", "partial", "orphan", "syntax"] {
+            assert!(!registry.contains(name), "{} should not load", name);
+        }
+
+        let messages: Vec<String> = registry.errors().iter().map(|e| e.to_string()).collect();
+        let has = |file: &str, message: &str| {
+            let expected = format!("{}: {}", themes_dir.join(file).display(), message);
+            messages.contains(&expected)
+        };
+        assert!(
+            has("broken.json", "editor.bg: unknown color name \"Purple\""),
+            "{:?}",
+            messages
+        );
+        assert!(
+            has("broken.json", "editor.bogus: unknown key"),
+            "{:?}",
+            messages
+        );
+        assert!(
+            has(
+                "broken.json",
+                "ui.tab_active_bg: expected [r, g, b] with values 0-255 or a color name"
+            ),
+            "{:?}",
+            messages
+        );
+        assert!(has("partial.json", "ui: missing section"), "{:?}", messages);
+        assert!(
+            has("orphan.toml", "extends: unknown theme \"nope\""),
+            "{:?}",
+            messages
+        );
+        assert!(
+            messages
+                .iter()
+                .any(|m| m.contains("syntax.toml: invalid TOML at line 1")),
+            "{:?}",
+            messages
+        );
+    }
+
+    #[test]
+    fn test_circular_extends_is_an_error() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let themes_dir = temp_dir.path().to_path_buf();
+
+        std::fs::write(themes_dir.join("a.toml"), "extends = \"b\"\n").unwrap();
+        std::fs::write(themes_dir.join("b.toml"), "extends = \"a\"\n").unwrap();
+
+        let registry = ThemeLoader::new(themes_dir).load_all();
+        assert!(!registry.contains("a"));
+        assert!(!registry.contains("b"));
+        assert!(registry
+            .errors()
+            .iter()
+            .any(|e| e.message.starts_with("extends: circular reference")));
+    }
+
+    #[test]
+    fn test_files_stamp_changes_when_theme_edited() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let themes_dir = temp_dir.path().to_path_buf();
+        let loader = ThemeLoader::new(themes_dir.clone());
+
+        let empty = loader.files_stamp();
+        assert!(empty.is_empty());
+
+        let path = themes_dir.join("mine.toml");
+        std::fs::write(&path, "extends = \"dark\"\n").unwrap();
+        let written = loader.files_stamp();
+        assert_eq!(written.len(), 1);
+
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() + std::time::Duration::from_secs(5))
+            .unwrap();
+        assert_ne!(loader.files_stamp(), written);
+
+        // Unrelated files are ignored
+        std::fs::write(themes_dir.join("notes.txt"), "hello").unwrap();
+        assert_eq!(loader.files_stamp().len(), 1);
+    }
+
+    #[test]
+    fn test_pack_label_marks_user_themes() {
+        assert_eq!(ThemeInfo::new("mine", "user").pack_label(), "(user)");
+        assert_eq!(
+            ThemeInfo::new("nested", "user/my-collection").pack_label(),
+            "my-collection (user)"
+        );
+        assert_eq!(
+            ThemeInfo::new("packaged", "pkg/my-pack").pack_label(),
+            "pkg/my-pack"
+        );
+        assert_eq!(ThemeInfo::new("dark", "").pack_label(), "");
+    }
 }
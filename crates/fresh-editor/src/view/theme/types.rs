@@ -3,9 +3,11 @@
 //! This module contains all theme-related data structures that can be used
 //! without filesystem access. This enables WASM compatibility and easier testing.
 
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
 
 pub const THEME_DARK: &str = "dark";
 pub const THEME_LIGHT: &str = "light";
@@ -44,6 +46,16 @@ impl ThemeInfo {
         }
     }
 
+    /// Pack label for the theme picker: themes from the user themes
+    /// directory are marked "(user)", after their subdirectory if any
+    pub fn pack_label(&self) -> String {
+        match self.pack.strip_prefix("user") {
+            Some("") => "(user)".to_string(),
+            Some(subdir) if subdir.starts_with('/') => format!("{} (user)", &subdir[1..]),
+            _ => self.pack.clone(),
+        }
+    }
+
     /// Get display name showing pack if present
     pub fn display_name(&self) -> String {
         if self.pack.is_empty() {
@@ -99,14 +111,49 @@ pub fn brighten_color(color: Color, amount: u8) -> Color {
 pub enum ColorDef {
     /// RGB color as [r, g, b]
     Rgb(u8, u8, u8),
-    /// Named color
+    /// Named color, or hex as "#RRGGBB"
     Named(String),
 }
 
+/// Color names accepted by `ColorDef::Named`
+pub const NAMED_COLORS: &[&str] = &[
+    "Black",
+    "Red",
+    "Green",
+    "Yellow",
+    "Blue",
+    "Magenta",
+    "Cyan",
+    "Gray",
+    "DarkGray",
+    "LightRed",
+    "LightGreen",
+    "LightYellow",
+    "LightBlue",
+    "LightMagenta",
+    "LightCyan",
+    "White",
+    "Default",
+    "Reset",
+];
+
+/// Parse a "#RRGGBB" hex color
+pub fn parse_hex_color(spec: &str) -> Option<Color> {
+    let hex = spec.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?))
+}
+
 impl From<ColorDef> for Color {
     fn from(def: ColorDef) -> Self {
         match def {
             ColorDef::Rgb(r, g, b) => Color::Rgb(r, g, b),
+            ColorDef::Named(name) if name.starts_with('#') => {
+                parse_hex_color(&name).unwrap_or(Color::White)
+            }
             ColorDef::Named(name) => match name.as_str() {
                 "Black" => Color::Black,
                 "Red" => Color::Red,
@@ -224,6 +271,12 @@ pub struct EditorColors {
     /// Whitespace indicator foreground color (for tab arrows and space dots)
     #[serde(default = "default_whitespace_indicator_fg")]
     pub whitespace_indicator_fg: ColorDef,
+    /// Indent guide color
+    #[serde(default = "default_indent_guide_fg")]
+    pub indent_guide_fg: ColorDef,
+    /// Indent guide color for the block containing the cursor
+    #[serde(default = "default_indent_guide_active_fg")]
+    pub indent_guide_active_fg: ColorDef,
 }
 
 // Default editor colors (for minimal themes)
@@ -266,6 +319,12 @@ fn default_ruler_bg() -> ColorDef {
 fn default_whitespace_indicator_fg() -> ColorDef {
     ColorDef::Rgb(70, 70, 70) // Subdued dark gray, subtle but visible
 }
+fn default_indent_guide_fg() -> ColorDef {
+    ColorDef::Rgb(60, 60, 60)
+}
+fn default_indent_guide_active_fg() -> ColorDef {
+    ColorDef::Rgb(110, 110, 110)
+}
 
 /// UI element colors (tabs, menus, status bar, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -818,6 +877,10 @@ pub struct Theme {
     // Whitespace indicator color (tab arrows, space dots)
     pub whitespace_indicator_fg: Color,
 
+    // Indent guide colors
+    pub indent_guide_fg: Color,
+    pub indent_guide_active_fg: Color,
+
     // Diff highlighting colors
     pub diff_add_bg: Color,
     pub diff_remove_bg: Color,
@@ -939,6 +1002,57 @@ pub struct Theme {
     pub syntax_variable: Color,
     pub syntax_constant: Color,
     pub syntax_operator: Color,
+
+    // User syntax style overrides by TextMate scope (from config, not theme files)
+    pub scope_styles: ScopeStyles,
+}
+
+/// Style override for a syntax scope, replacing the theme's category color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScopeStyle {
+    pub fg: Option<Color>,
+    pub modifiers: Modifier,
+}
+
+/// Syntax style overrides keyed by TextMate scope.
+///
+/// A key matches a scope equal to it or one it is a dot-separated prefix of
+/// ("keyword" matches "keyword.control.rust"); the longest matching key wins.
+/// Cloning shares the table, so highlighter caches can cheaply tell whether
+/// the overrides they were built with are still current.
+#[derive(Debug, Clone, Default)]
+pub struct ScopeStyles(Arc<Vec<(String, ScopeStyle)>>);
+
+impl ScopeStyles {
+    /// Build a table from (scope, style) pairs
+    pub fn new(styles: impl IntoIterator<Item = (String, ScopeStyle)>) -> Self {
+        let mut styles: Vec<_> = styles.into_iter().collect();
+        // Longest keys first so the first match is the most specific
+        styles.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        Self(Arc::new(styles))
+    }
+
+    /// Whether there are no overrides
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The override for a single scope, if any key matches it
+    pub fn get(&self, scope: &str) -> Option<ScopeStyle> {
+        self.0
+            .iter()
+            .find(|(key, _)| {
+                scope
+                    .strip_prefix(key.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+            })
+            .map(|(_, style)| *style)
+    }
+
+    /// Whether both tables are the same instance
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 impl From<ThemeFile> for Theme {
@@ -955,6 +1069,8 @@ impl From<ThemeFile> for Theme {
             line_number_bg: file.editor.line_number_bg.into(),
             ruler_bg: file.editor.ruler_bg.into(),
             whitespace_indicator_fg: file.editor.whitespace_indicator_fg.into(),
+            indent_guide_fg: file.editor.indent_guide_fg.into(),
+            indent_guide_active_fg: file.editor.indent_guide_active_fg.into(),
             diff_add_bg: file.editor.diff_add_bg.clone().into(),
             diff_remove_bg: file.editor.diff_remove_bg.clone().into(),
             diff_modify_bg: file.editor.diff_modify_bg.into(),
@@ -1042,6 +1158,7 @@ impl From<ThemeFile> for Theme {
             syntax_variable: file.syntax.variable.into(),
             syntax_constant: file.syntax.constant.into(),
             syntax_operator: file.syntax.operator.into(),
+            scope_styles: ScopeStyles::default(),
         }
     }
 }
@@ -1064,6 +1181,8 @@ impl From<Theme> for ThemeFile {
                 diff_modify_bg: theme.diff_modify_bg.into(),
                 ruler_bg: theme.ruler_bg.into(),
                 whitespace_indicator_fg: theme.whitespace_indicator_fg.into(),
+                indent_guide_fg: theme.indent_guide_fg.into(),
+                indent_guide_active_fg: theme.indent_guide_active_fg.into(),
             },
             ui: UiColors {
                 tab_active_fg: theme.tab_active_fg.into(),
@@ -1168,6 +1287,29 @@ impl Theme {
             .map(|tf| tf.into())
     }
 
+    /// Layer the config's per-scope syntax style overrides on this theme.
+    /// Overrides with an unrecognized color keep the theme's color.
+    pub fn set_syntax_styles(&mut self, styles: &HashMap<String, crate::config::SyntaxStyle>) {
+        self.scope_styles = ScopeStyles::new(styles.iter().map(|(scope, style)| {
+            let fg = style.fg.as_deref().and_then(|spec| {
+                let color = parse_hex_color(spec).or_else(|| {
+                    NAMED_COLORS
+                        .contains(&spec)
+                        .then(|| ColorDef::Named(spec.to_string()).into())
+                });
+                if color.is_none() {
+                    tracing::warn!("Unknown color '{}' in syntax style for '{}'", spec, scope);
+                }
+                color
+            });
+            let mut modifiers = Modifier::empty();
+            modifiers.set(Modifier::BOLD, style.bold);
+            modifiers.set(Modifier::ITALIC, style.italic);
+            modifiers.set(Modifier::UNDERLINED, style.underline);
+            (scope.clone(), ScopeStyle { fg, modifiers })
+        }));
+    }
+
     /// Parse theme from JSON string (no I/O).
     pub fn from_json(json: &str) -> Result<Self, String> {
         let theme_file: ThemeFile =
@@ -1207,8 +1349,12 @@ impl Theme {
                 "diff_add_bg" => Some(self.diff_add_bg),
                 "diff_remove_bg" => Some(self.diff_remove_bg),
                 "diff_modify_bg" => Some(self.diff_modify_bg),
+                "diff_add_highlight_bg" => Some(self.diff_add_highlight_bg),
+                "diff_remove_highlight_bg" => Some(self.diff_remove_highlight_bg),
                 "ruler_bg" => Some(self.ruler_bg),
                 "whitespace_indicator_fg" => Some(self.whitespace_indicator_fg),
+                "indent_guide_fg" => Some(self.indent_guide_fg),
+                "indent_guide_active_fg" => Some(self.indent_guide_active_fg),
                 _ => None,
             },
             "ui" => match field {
@@ -1338,4 +1484,66 @@ mod tests {
         let color: Color = ColorDef::Named("Reset".to_string()).into();
         assert_eq!(color, Color::Reset);
     }
+
+    #[test]
+    fn test_hex_color() {
+        assert_eq!(parse_hex_color("#ff8800"), Some(Color::Rgb(255, 136, 0)));
+        assert_eq!(parse_hex_color("ff8800"), None);
+        assert_eq!(parse_hex_color("#ff880"), None);
+        assert_eq!(parse_hex_color("#+f8800"), None);
+        let color: Color = ColorDef::Named("#0a0B0c".to_string()).into();
+        assert_eq!(color, Color::Rgb(10, 11, 12));
+    }
+
+    #[test]
+    fn test_scope_styles_longest_prefix_wins() {
+        let mut theme = Theme::load_builtin(THEME_DARK).unwrap();
+        let styles = HashMap::from([
+            (
+                "keyword".to_string(),
+                crate::config::SyntaxStyle {
+                    fg: Some("Red".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "keyword.control".to_string(),
+                crate::config::SyntaxStyle {
+                    fg: Some("#ff8800".to_string()),
+                    bold: true,
+                    ..Default::default()
+                },
+            ),
+            (
+                "comment".to_string(),
+                crate::config::SyntaxStyle {
+                    fg: Some("NotAColor".to_string()),
+                    italic: true,
+                    ..Default::default()
+                },
+            ),
+        ]);
+        theme.set_syntax_styles(&styles);
+        let scope_styles = &theme.scope_styles;
+
+        let control = scope_styles.get("keyword.control.rust").unwrap();
+        assert_eq!(control.fg, Some(Color::Rgb(255, 136, 0)));
+        assert_eq!(control.modifiers, Modifier::BOLD);
+        assert_eq!(
+            scope_styles.get("keyword.control").unwrap().fg,
+            Some(Color::Rgb(255, 136, 0))
+        );
+        assert_eq!(
+            scope_styles.get("keyword.operator.rust").unwrap().fg,
+            Some(Color::Red)
+        );
+        // Prefixes only match whole scope segments
+        assert!(scope_styles.get("keywords").is_none());
+        assert!(scope_styles.get("string.quoted").is_none());
+
+        // An unknown color keeps the theme color but still applies modifiers
+        let comment = scope_styles.get("comment.line").unwrap();
+        assert_eq!(comment.fg, None);
+        assert_eq!(comment.modifiers, Modifier::ITALIC);
+    }
 }
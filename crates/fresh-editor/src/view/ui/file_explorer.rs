@@ -45,6 +45,7 @@ impl FileExplorerRenderer {
         remote_connection: Option<&str>,
     ) {
         let search_active = view.is_search_active();
+        let filter_open = view.is_filter_open();
 
         // Update viewport height for scrolling calculations
         // Account for borders (top + bottom = 2)
@@ -77,7 +78,7 @@ impl FileExplorerRenderer {
                 let actual_idx = scroll_offset + viewport_idx;
                 let is_selected = selected_index == Some(actual_idx);
                 // Get match positions for highlighting
-                let fuzzy_match = if search_active {
+                let fuzzy_match = if search_active || filter_open {
                     view.get_match_for_node(node_id)
                 } else {
                     None
@@ -106,8 +107,12 @@ impl FileExplorerRenderer {
             .map(|kb| format!(" ({})", kb))
             .unwrap_or_default();
 
-        // Show search query in title when search is active
-        let title = if search_active {
+        // Show the filter or search query in title when active
+        let title = if filter_open && view.is_filter_indexing() {
+            format!(" Filter: {} (indexing…) ", view.filter_query())
+        } else if filter_open {
+            format!(" Filter: {} ", view.filter_query())
+        } else if search_active {
             format!(" /{} ", view.search_query())
         } else if let Some(host) = remote_connection {
             // Extract just the hostname from "user@host" or "user@host:port"
@@ -321,11 +326,17 @@ impl FileExplorerRenderer {
             .map(|(s, _)| str_width(s))
             .unwrap_or(0);
 
-        // Error indicator
-        let error_text = if node.is_error() { " [Error]" } else { "" };
-        let error_width = str_width(error_text);
+        // Error or loading indicator
+        let state_text = if node.is_error() {
+            " [Error]"
+        } else if node.is_loading() {
+            " Loading…"
+        } else {
+            ""
+        };
+        let state_width = str_width(state_text);
 
-        let total_right_width = right_indicator_width + error_width;
+        let total_right_width = right_indicator_width + state_width;
 
         // Calculate padding for right-alignment
         let min_gap = 1;
@@ -342,12 +353,17 @@ impl FileExplorerRenderer {
             spans.push(Span::styled(symbol, Style::default().fg(color)));
         }
 
-        // Error indicator
+        // Error or loading indicator
         if node.is_error() {
             spans.push(Span::styled(
-                error_text,
+                state_text,
                 Style::default().fg(theme.diagnostic_error_fg),
             ));
+        } else if node.is_loading() {
+            spans.push(Span::styled(
+                state_text,
+                Style::default().fg(theme.line_number_fg),
+            ));
         }
 
         ListItem::new(Line::from(spans)).style(Style::default().bg(theme.editor_bg))
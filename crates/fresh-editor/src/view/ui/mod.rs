@@ -11,6 +11,7 @@
 //! - `scrollbar` - Reusable scrollbar widget
 //! - `scroll_panel` - Reusable scrollable panel for variable-height items
 //! - `file_browser` - File open dialog popup
+//! - `tooltip` - Mouse hover tooltips
 
 // WASM-compatible modules (pure rendering, no runtime deps)
 pub mod focus;
@@ -37,6 +38,8 @@ pub mod status_bar;
 pub mod suggestions;
 #[cfg(feature = "runtime")]
 pub mod tabs;
+#[cfg(feature = "runtime")]
+pub mod tooltip;
 
 // Re-export main types for convenience
 #[cfg(feature = "runtime")]
@@ -56,7 +59,10 @@ pub use scrollbar::{render_scrollbar, ScrollbarColors, ScrollbarState};
 #[cfg(feature = "runtime")]
 pub use split_rendering::SplitRenderer;
 #[cfg(feature = "runtime")]
-pub use status_bar::{truncate_path, StatusBarLayout, StatusBarRenderer, TruncatedPath};
+pub use status_bar::{
+    truncate_path, PluginStatusSegment, StatusBarLayout, StatusBarRenderer, StatusBarSegments,
+    TruncatedPath,
+};
 #[cfg(feature = "runtime")]
 pub use suggestions::SuggestionsRenderer;
 #[cfg(feature = "runtime")]
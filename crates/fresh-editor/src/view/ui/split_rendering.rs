@@ -4,6 +4,7 @@ use std::collections::BTreeMap;
 
 use crate::app::types::ViewLineMapping;
 use crate::app::BufferMetadata;
+use crate::config::LineNumberMode;
 use crate::model::buffer::Buffer;
 use crate::model::cursor::SelectionMode;
 use crate::model::event::{BufferId, EventLog, LeafId, SplitDirection};
@@ -12,6 +13,8 @@ use crate::primitives::ansi_background::AnsiBackground;
 use crate::primitives::display_width::char_width;
 use crate::state::{EditorState, ViewMode};
 use crate::view::folding::FoldManager;
+use crate::view::indent_guides::IndentGuides;
+use crate::view::minimap::{self, MinimapRow, MinimapTick};
 use crate::view::split::SplitManager;
 use crate::view::theme::color_to_rgb;
 use crate::view::ui::tabs::TabsRenderer;
@@ -35,6 +38,18 @@ use std::ops::Range;
 /// memory usage reasonable (~80KB per ViewLine instead of hundreds of MB).
 const MAX_SAFE_LINE_WIDTH: usize = 10_000;
 
+/// How much wrapped text `build_base_tokens` needs: once past `from_byte`,
+/// enough to fill the visible rows at `row_width` chars per row. Lets a
+/// wrapped megabyte-long line be laid out a screenful at a time.
+#[derive(Debug, Clone, Copy)]
+struct WrapRowBudget {
+    /// Width rows wrap at; wrapping never fits more chars than this per row
+    row_width: usize,
+    /// Rows are counted from here: the later of the view top and the cursor,
+    /// so the cursor's row is always laid out
+    from_byte: usize,
+}
+
 /// Compute character-level diff between two strings, returning ranges of changed characters.
 /// Returns a tuple of (old_changed_ranges, new_changed_ranges) where each range indicates
 /// character indices that differ between the strings.
@@ -342,6 +357,13 @@ struct DecorationContext {
     line_indicators: BTreeMap<usize, crate::view::margin::LineIndicator>,
     /// Fold indicators indexed by line-start byte offset
     fold_indicators: BTreeMap<usize, FoldIndicator>,
+    /// End byte of each collapsed fold's hidden range, indexed by its start
+    /// byte (just past the header line's newline)
+    collapsed_folds: HashMap<usize, usize>,
+    /// Bracket pair colors indexed by bracket byte offset
+    bracket_colors: HashMap<usize, Color>,
+    /// Swatch colors indexed by the start byte of each color literal in view
+    swatch_colors: HashMap<usize, Color>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -381,6 +403,7 @@ struct BufferLayoutOutput {
 struct SplitLayout {
     tabs_rect: Rect,
     content_rect: Rect,
+    minimap_rect: Rect,
     scrollbar_rect: Rect,
     horizontal_scrollbar_rect: Rect,
 }
@@ -412,8 +435,12 @@ struct LineRenderInput<'a> {
     estimated_lines: usize,
     /// Left column offset for horizontal scrolling
     left_column: usize,
-    /// Whether to show relative line numbers (distance from cursor)
-    relative_line_numbers: bool,
+    /// Absolute, relative or hybrid line numbers
+    line_number_mode: LineNumberMode,
+    /// Draw indent guides in leading whitespace
+    indent_guides: bool,
+    /// Give the cursor line the current-line background
+    highlight_current_line: bool,
     /// Session mode: use hardware cursor only, skip REVERSED style for software cursor
     session_mode: bool,
     /// No hardware cursor: always render software cursor indicators
@@ -435,6 +462,8 @@ struct CharStyleContext<'a> {
     theme: &'a crate::view::theme::Theme,
     /// Pre-resolved syntax highlight color for this byte position (from cursor-based lookup)
     highlight_color: Option<Color>,
+    /// Modifiers from syntax style overrides for this byte position
+    highlight_modifiers: Modifier,
     /// Pre-resolved semantic token color for this byte position (from cursor-based lookup)
     semantic_token_color: Option<Color>,
     viewport_overlays: &'a [(crate::view::overlay::Overlay, Range<usize>)],
@@ -465,10 +494,12 @@ struct LeftMarginContext<'a> {
     line_indicators: &'a BTreeMap<usize, crate::view::margin::LineIndicator>,
     /// Fold indicators (line_start_byte -> indicator)
     fold_indicators: &'a BTreeMap<usize, FoldIndicator>,
-    /// Line-start byte of the cursor line (for relative line numbers and cursor highlight)
+    /// Line-start byte of the cursor line (for cursor highlight)
     cursor_line_start_byte: usize,
-    /// Whether to show relative line numbers
-    relative_line_numbers: bool,
+    /// Line number of the cursor line (for relative line numbers)
+    cursor_line_num: usize,
+    /// Absolute, relative or hybrid line numbers
+    line_number_mode: LineNumberMode,
     /// Whether to show line numbers in the gutter
     show_line_numbers: bool,
     /// Whether the gutter shows byte offsets instead of line numbers
@@ -564,14 +595,13 @@ fn render_left_margin(
             Style::default().fg(ctx.theme.line_number_fg)
         };
         push_span_with_map(line_spans, line_view_map, rendered_text, margin_style, None);
-    } else if ctx.relative_line_numbers {
-        // Relative line numbers: show distance from cursor, or absolute for cursor line
-        let display_num = if is_cursor_line {
-            // Show absolute line number for the cursor line (1-indexed)
+    } else if ctx.line_number_mode != LineNumberMode::Absolute && ctx.show_line_numbers {
+        // Relative line numbers: show distance from the cursor line. Hybrid mode
+        // shows the absolute number (1-indexed) on the cursor line instead of 0.
+        let display_num = if is_cursor_line && ctx.line_number_mode == LineNumberMode::Hybrid {
             ctx.gutter_num + 1
         } else {
-            // Show relative distance for other lines
-            ctx.gutter_num.abs_diff(ctx.cursor_line_start_byte)
+            ctx.gutter_num.abs_diff(ctx.cursor_line_num)
         };
         let rendered_text = format!(
             "{:>width$}",
@@ -614,15 +644,15 @@ fn render_left_margin(
     }
 }
 
-/// Advance a cursor through sorted, non-overlapping spans to find the color at `byte_pos`.
-/// Returns the color if `byte_pos` falls inside a span, and advances `cursor` past any
+/// Advance a cursor through sorted, non-overlapping spans to find the span at `byte_pos`.
+/// Returns the span if `byte_pos` falls inside one, and advances `cursor` past any
 /// spans that end before `byte_pos` so subsequent calls are O(1) amortized.
 #[inline]
-fn span_color_at(
-    spans: &[crate::primitives::highlighter::HighlightSpan],
+fn span_at<'a>(
+    spans: &'a [crate::primitives::highlighter::HighlightSpan],
     cursor: &mut usize,
     byte_pos: usize,
-) -> Option<Color> {
+) -> Option<&'a crate::primitives::highlighter::HighlightSpan> {
     while *cursor < spans.len() {
         let span = &spans[*cursor];
         if span.range.end <= byte_pos {
@@ -630,7 +660,7 @@ fn span_color_at(
         } else if span.range.start > byte_pos {
             return None;
         } else {
-            return Some(span.color);
+            return Some(span);
         }
     }
     None
@@ -690,7 +720,9 @@ fn compute_char_style(ctx: &CharStyleContext) -> CharStyleOutput {
         s
     } else if let Some(color) = highlight_color {
         // Apply syntax highlighting
-        Style::default().fg(color)
+        Style::default()
+            .fg(color)
+            .add_modifier(ctx.highlight_modifiers)
     } else {
         // Default color from theme
         Style::default().fg(ctx.theme.editor_fg)
@@ -844,7 +876,14 @@ impl SplitRenderer {
         hovered_close_split: Option<LeafId>,
         hovered_maximize_split: Option<LeafId>,
         is_maximized: bool,
-        relative_line_numbers: bool,
+        line_number_mode: LineNumberMode,
+        indent_guides: bool,
+        highlight_current_line: bool,
+        bracket_pair_colorization: bool,
+        color_swatches: bool,
+        syntax_highlight_max_column: usize,
+        show_minimap: bool,
+        minimap_min_width: usize,
         tab_bar_visible: bool,
         use_terminal_bg: bool,
         session_mode: bool,
@@ -858,6 +897,7 @@ impl SplitRenderer {
         Vec<(LeafId, u16, u16, u16)>,                      // maximize split button areas
         HashMap<LeafId, Vec<ViewLineMapping>>,             // view line mappings for mouse clicks
         Vec<(LeafId, BufferId, Rect, usize, usize, usize)>, // horizontal scrollbar areas (rect + max_content_width + thumb_start + thumb_end)
+        Vec<(LeafId, BufferId, Rect, Vec<usize>)>, // minimap areas (rect + start byte of each row)
     ) {
         let _span = tracing::trace_span!("render_content").entered();
 
@@ -874,18 +914,23 @@ impl SplitRenderer {
         let mut close_split_areas = Vec::new();
         let mut maximize_split_areas = Vec::new();
         let mut view_line_mappings: HashMap<LeafId, Vec<ViewLineMapping>> = HashMap::new();
+        let mut minimap_areas = Vec::new();
 
         // Render each split
         for (split_id, buffer_id, split_area) in visible_buffers {
             let is_active = split_id == active_split_id;
 
+            let is_composite = buffers
+                .get(&buffer_id)
+                .is_some_and(|state| state.is_composite_buffer);
             let layout = Self::split_layout(
                 split_area,
                 tab_bar_visible,
                 show_vertical_scrollbar,
                 show_horizontal_scrollbar,
+                Self::minimap_width(show_minimap, minimap_min_width, split_area, is_composite),
             );
-            let (split_buffers, tab_scroll_offset) =
+            let (split_buffers, tab_scroll_offset, pinned_buffers) =
                 Self::split_buffers_for_tabs(split_view_states.as_deref(), split_id, buffer_id);
 
             // Determine hover state for this split's tabs
@@ -912,6 +957,7 @@ impl SplitRenderer {
                     is_active,
                     tab_scroll_offset,
                     tab_hover_for_split,
+                    &pinned_buffers,
                 );
 
                 // Store the tab layout for this split
@@ -1127,7 +1173,12 @@ impl SplitRenderer {
                     highlight_context_bytes,
                     buffer_id,
                     hide_cursor,
-                    relative_line_numbers,
+                    line_number_mode,
+                    indent_guides,
+                    highlight_current_line,
+                    bracket_pair_colorization,
+                    color_swatches,
+                    syntax_highlight_max_column,
                     use_terminal_bg,
                     session_mode,
                     software_cursor_only,
@@ -1170,6 +1221,22 @@ impl SplitRenderer {
                     (0, 0)
                 };
 
+                if layout.minimap_rect.width > 0 {
+                    let _span = tracing::trace_span!("render_minimap").entered();
+                    let row_starts = Self::render_minimap(
+                        frame,
+                        state,
+                        &viewport,
+                        view_line_mappings.get(&split_id).map(Vec::as_slice),
+                        layout.minimap_rect,
+                        theme,
+                        large_file_threshold_bytes,
+                        estimated_line_length,
+                        use_terminal_bg,
+                    );
+                    minimap_areas.push((split_id, buffer_id, layout.minimap_rect, row_starts));
+                }
+
                 // Compute the actual max line length for horizontal scrollbar
                 let max_content_width = if show_horizontal_scrollbar && !viewport.line_wrap_enabled
                 {
@@ -1248,6 +1315,7 @@ impl SplitRenderer {
             maximize_split_areas,
             view_line_mappings,
             horizontal_scrollbar_areas,
+            minimap_areas,
         )
     }
 
@@ -1264,7 +1332,14 @@ impl SplitRenderer {
         lsp_waiting: bool,
         estimated_line_length: usize,
         highlight_context_bytes: usize,
-        relative_line_numbers: bool,
+        line_number_mode: LineNumberMode,
+        indent_guides: bool,
+        highlight_current_line: bool,
+        bracket_pair_colorization: bool,
+        color_swatches: bool,
+        syntax_highlight_max_column: usize,
+        show_minimap: bool,
+        minimap_min_width: usize,
         use_terminal_bg: bool,
         session_mode: bool,
         software_cursor_only: bool,
@@ -1279,18 +1354,24 @@ impl SplitRenderer {
         for (split_id, buffer_id, split_area) in visible_buffers {
             let is_active = split_id == active_split_id;
 
+            let state = match buffers.get_mut(&buffer_id) {
+                Some(s) => s,
+                None => continue,
+            };
+
             let layout = Self::split_layout(
                 split_area,
                 tab_bar_visible,
                 show_vertical_scrollbar,
                 show_horizontal_scrollbar,
+                Self::minimap_width(
+                    show_minimap,
+                    minimap_min_width,
+                    split_area,
+                    state.is_composite_buffer,
+                ),
             );
 
-            let state = match buffers.get_mut(&buffer_id) {
-                Some(s) => s,
-                None => continue,
-            };
-
             // Skip composite buffers — they don't produce view_line_mappings
             if state.is_composite_buffer {
                 view_line_mappings.insert(split_id, Vec::new());
@@ -1357,7 +1438,12 @@ impl SplitRenderer {
                 view_prefs.view_transform,
                 estimated_line_length,
                 highlight_context_bytes,
-                relative_line_numbers,
+                line_number_mode,
+                indent_guides,
+                highlight_current_line,
+                bracket_pair_colorization,
+                color_swatches,
+                syntax_highlight_max_column,
                 use_terminal_bg,
                 session_mode,
                 software_cursor_only,
@@ -1539,13 +1625,17 @@ impl SplitRenderer {
                         .unwrap_or(source_state.buffer.len());
 
                     // Get syntax highlighting spans from the highlighter
-                    let highlight_spans = source_state.highlighter.highlight_viewport(
-                        &source_state.buffer,
-                        top_byte,
-                        end_byte,
-                        theme,
-                        1024, // highlight_context_bytes
-                    );
+                    let highlight_spans = {
+                        let _timer =
+                            crate::services::perf::time(crate::services::perf::Phase::Highlight);
+                        source_state.highlighter.highlight_viewport(
+                            &source_state.buffer,
+                            top_byte,
+                            end_byte,
+                            theme,
+                            1024, // highlight_context_bytes
+                        )
+                    };
 
                     // Create a temporary viewport for building view data
                     let pane_width = pane_widths.get(pane_idx).copied().unwrap_or(80);
@@ -1568,6 +1658,7 @@ impl SplitRenderer {
                         None,         // No view transform
                         80,           // estimated_line_length
                         lines_needed, // visible_count - enough to cover the range
+                        top_byte,     // primary_cursor_position (wrapping is off)
                         false,        // line_wrap_enabled
                         content_width,
                         gutter_width,
@@ -1882,9 +1973,8 @@ impl SplitRenderer {
             // Get source byte position for this character
             let byte_pos = char_source_bytes.get(char_idx).and_then(|b| *b);
 
-            // Get syntax highlight color via cursor-based O(1) lookup
-            let highlight_color =
-                byte_pos.and_then(|bp| span_color_at(highlight_spans, &mut hl_cursor, bp));
+            // Get syntax highlight span via cursor-based O(1) lookup
+            let highlight = byte_pos.and_then(|bp| span_at(highlight_spans, &mut hl_cursor, bp));
 
             // Check if this character is in an inline diff range
             let in_inline_range = inline_ranges.iter().any(|r| r.contains(&char_idx));
@@ -1904,8 +1994,11 @@ impl SplitRenderer {
             };
 
             // Build character style
-            let char_style = if let Some(color) = highlight_color {
-                Style::default().fg(color).bg(char_bg)
+            let char_style = if let Some(span) = highlight {
+                Style::default()
+                    .fg(span.color)
+                    .add_modifier(span.modifiers)
+                    .bg(char_bg)
             } else {
                 Style::default().fg(theme.editor_fg).bg(char_bg)
             };
@@ -2045,11 +2138,29 @@ impl SplitRenderer {
         (thumb_start, thumb_end)
     }
 
+    /// Width of the minimap strip for a split: zero when the minimap is off,
+    /// the split is narrower than `minimap_min_width`, or it shows a composite buffer.
+    fn minimap_width(
+        show_minimap: bool,
+        minimap_min_width: usize,
+        split_area: Rect,
+        is_composite: bool,
+    ) -> u16 {
+        // Always leave the text at least a few times the strip's width
+        let min_width = minimap_min_width.max(minimap::MINIMAP_WIDTH as usize * 4);
+        if show_minimap && !is_composite && split_area.width as usize >= min_width {
+            minimap::MINIMAP_WIDTH
+        } else {
+            0
+        }
+    }
+
     fn split_layout(
         split_area: Rect,
         tab_bar_visible: bool,
         show_vertical_scrollbar: bool,
         show_horizontal_scrollbar: bool,
+        minimap_width: u16,
     ) -> SplitLayout {
         let tabs_height = if tab_bar_visible { 1u16 } else { 0u16 };
         let scrollbar_width = if show_vertical_scrollbar { 1u16 } else { 0u16 };
@@ -2063,12 +2174,21 @@ impl SplitRenderer {
         let content_rect = Rect::new(
             split_area.x,
             split_area.y + tabs_height,
-            split_area.width.saturating_sub(scrollbar_width),
+            split_area
+                .width
+                .saturating_sub(scrollbar_width)
+                .saturating_sub(minimap_width),
             split_area
                 .height
                 .saturating_sub(tabs_height)
                 .saturating_sub(hscrollbar_height),
         );
+        let minimap_rect = Rect::new(
+            content_rect.x + content_rect.width,
+            content_rect.y,
+            minimap_width,
+            content_rect.height,
+        );
         let scrollbar_rect = Rect::new(
             split_area.x + split_area.width.saturating_sub(scrollbar_width),
             split_area.y + tabs_height,
@@ -2081,13 +2201,14 @@ impl SplitRenderer {
         let horizontal_scrollbar_rect = Rect::new(
             split_area.x,
             split_area.y + split_area.height.saturating_sub(hscrollbar_height),
-            split_area.width.saturating_sub(scrollbar_width),
+            content_rect.width,
             hscrollbar_height,
         );
 
         SplitLayout {
             tabs_rect,
             content_rect,
+            minimap_rect,
             scrollbar_rect,
             horizontal_scrollbar_rect,
         }
@@ -2097,16 +2218,17 @@ impl SplitRenderer {
         split_view_states: Option<&HashMap<LeafId, crate::view::split::SplitViewState>>,
         split_id: LeafId,
         buffer_id: BufferId,
-    ) -> (Vec<BufferId>, usize) {
+    ) -> (Vec<BufferId>, usize, HashSet<BufferId>) {
         if let Some(view_states) = split_view_states {
             if let Some(view_state) = view_states.get(&split_id) {
                 return (
                     view_state.open_buffers.clone(),
                     view_state.tab_scroll_offset,
+                    view_state.pinned_buffers.clone(),
                 );
             }
         }
-        (vec![buffer_id], 0)
+        (vec![buffer_id], 0, HashSet::new())
     }
 
     fn sync_viewport_to_content(
@@ -2371,6 +2493,139 @@ impl SplitRenderer {
         (thumb_start, thumb_end)
     }
 
+    /// Render the minimap strip for a split.
+    ///
+    /// Only the source lines the strip can show are sampled, starting from
+    /// [`minimap::first_line`]. Large files have no line index, so their
+    /// minimap simply starts at the viewport. Returns the start byte of each
+    /// row for mouse hit testing.
+    #[allow(clippy::too_many_arguments)]
+    fn render_minimap(
+        frame: &mut Frame,
+        state: &mut EditorState,
+        viewport: &crate::view::viewport::Viewport,
+        view_lines: Option<&[ViewLineMapping]>,
+        minimap_rect: Rect,
+        theme: &crate::view::theme::Theme,
+        large_file_threshold_bytes: u64,
+        estimated_line_length: usize,
+        use_terminal_bg: bool,
+    ) -> Vec<usize> {
+        let height = minimap_rect.height as usize;
+        if height == 0 || minimap_rect.width == 0 {
+            return Vec::new();
+        }
+        let capacity = height * minimap::LINES_PER_ROW;
+        let text_cells = minimap_rect.width.saturating_sub(1) as usize;
+        let buffer_len = state.buffer.len();
+
+        let start_byte = if buffer_len > large_file_threshold_bytes as usize || buffer_len == 0 {
+            viewport.top_byte.min(buffer_len)
+        } else {
+            let total_lines = state.buffer.get_line_number(buffer_len.saturating_sub(1)) + 1;
+            let top_line = state
+                .buffer
+                .get_line_number(viewport.top_byte.min(buffer_len));
+            let first =
+                minimap::first_line(total_lines, top_line, viewport.height as usize, capacity);
+            state.buffer.line_start_offset(first).unwrap_or(0)
+        };
+
+        // Sample the lines shown in the strip
+        let tab_size = state.buffer_settings.tab_size;
+        let mut line_starts = Vec::with_capacity(capacity);
+        let mut lines = Vec::with_capacity(capacity);
+        let mut end_byte = start_byte;
+        let mut iter = state
+            .buffer
+            .line_iterator(start_byte, estimated_line_length);
+        while lines.len() < capacity {
+            let Some((line_start, content)) = iter.next_line() else {
+                break;
+            };
+            end_byte = line_start + content.len();
+            line_starts.push(line_start);
+            lines.push(content);
+        }
+
+        let mut rows: Vec<MinimapRow> = lines
+            .chunks(minimap::LINES_PER_ROW)
+            .zip(line_starts.chunks(minimap::LINES_PER_ROW))
+            .map(|(chunk, starts)| MinimapRow {
+                start_byte: starts[0],
+                cells: minimap::encode_row(chunk, text_cells, tab_size),
+                tick: None,
+            })
+            .collect();
+
+        // Diagnostics and search matches become ticks on their rows
+        for (overlay, range) in
+            state
+                .overlays
+                .query_viewport(start_byte, end_byte, &state.marker_list)
+        {
+            let Some(tick) = MinimapTick::for_overlay(overlay) else {
+                continue;
+            };
+            let line = line_starts
+                .partition_point(|&start| start <= range.start)
+                .saturating_sub(1);
+            if let Some(row) = rows.get_mut(line / minimap::LINES_PER_ROW) {
+                row.tick = row.tick.max(Some(tick));
+            }
+        }
+
+        // Byte range shown in the viewport
+        let view_start = viewport.top_byte;
+        let view_end = view_lines
+            .and_then(|mappings| mappings.iter().map(|m| m.line_end_byte).max())
+            .unwrap_or(view_start);
+
+        let editor_bg = if use_terminal_bg {
+            Color::Reset
+        } else {
+            theme.editor_bg
+        };
+        for y in 0..height {
+            let row = rows.get(y);
+            let in_view = row.is_some_and(|row| {
+                let row_end = rows.get(y + 1).map_or(end_byte, |next| next.start_byte);
+                row.start_byte <= view_end && row_end > view_start
+            });
+            let bg = if in_view {
+                theme.current_line_bg
+            } else {
+                editor_bg
+            };
+            let cells = row.map_or("", |row| row.cells.as_str());
+            let tick = match row.and_then(|row| row.tick) {
+                Some(MinimapTick::Error) => Some(theme.diagnostic_error_fg),
+                Some(MinimapTick::Warning) => Some(theme.diagnostic_warning_fg),
+                Some(MinimapTick::SearchMatch) => Some(theme.search_match_bg),
+                None => None,
+            }
+            .map_or(Span::styled(" ", Style::default().bg(bg)), |color| {
+                Span::styled("▐", Style::default().fg(color).bg(bg))
+            });
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", cells, width = text_cells),
+                    Style::default().fg(theme.line_number_fg).bg(bg),
+                ),
+                tick,
+            ]);
+            let row_area = Rect::new(
+                minimap_rect.x,
+                minimap_rect.y + y as u16,
+                minimap_rect.width,
+                1,
+            );
+            frame.render_widget(Paragraph::new(line), row_area);
+        }
+
+        rows.iter().map(|row| row.start_byte).collect()
+    }
+
     /// Compute the maximum line length encountered so far (in display columns).
     /// Only scans the currently visible lines (plus a small margin) and updates
     /// the running maximum stored in the viewport. This avoids scanning the
@@ -2496,6 +2751,7 @@ impl SplitRenderer {
         view_transform: Option<ViewTransformPayload>,
         estimated_line_length: usize,
         visible_count: usize,
+        primary_cursor_position: usize,
         line_wrap_enabled: bool,
         content_width: usize,
         gutter_width: usize,
@@ -2503,6 +2759,7 @@ impl SplitRenderer {
         folds: &FoldManager,
         theme: &crate::view::theme::Theme,
     ) -> ViewData {
+        let _timer = crate::services::perf::time(crate::services::perf::Phase::Layout);
         let adjusted_visible_count = Self::fold_adjusted_visible_count(
             &state.buffer,
             &state.marker_list,
@@ -2515,7 +2772,12 @@ impl SplitRenderer {
         let is_binary = state.buffer.is_binary();
         let line_ending = state.buffer.line_ending();
 
-        // Build base token stream from source
+        // Build base token stream from source. With wrapping on, a long line
+        // is only tokenized as far as the view can show.
+        let wrap_budget = line_wrap_enabled.then(|| WrapRowBudget {
+            row_width: content_width.max(1),
+            from_byte: viewport.top_byte.max(primary_cursor_position),
+        });
         let base_tokens = Self::build_base_tokens(
             &mut state.buffer,
             viewport.top_byte,
@@ -2523,6 +2785,7 @@ impl SplitRenderer {
             adjusted_visible_count,
             is_binary,
             line_ending,
+            wrap_budget,
         );
 
         // Use plugin transform if available, otherwise use base tokens
@@ -2567,6 +2830,36 @@ impl SplitRenderer {
             }
         }
 
+        // Line up the columns of a delimited file with padding that isn't in
+        // the buffer, measured over the lines in view
+        if state.aligned_columns && !has_view_transform && !is_binary {
+            if let Some(delimiter) = state.delimiter {
+                let viewport_end = tokens
+                    .iter()
+                    .rev()
+                    .find_map(|t| match (&t.kind, t.source_offset) {
+                        (fresh_core::api::ViewTokenWireKind::Text(text), Some(offset)) => {
+                            Some(offset + text.len())
+                        }
+                        (_, Some(offset)) => Some(offset + 1),
+                        _ => None,
+                    })
+                    .unwrap_or(viewport.top_byte)
+                    .min(state.buffer.len());
+                let bytes = state
+                    .buffer
+                    .slice_bytes(viewport.top_byte..viewport_end.max(viewport.top_byte));
+                let padding = crate::primitives::delimited::column_padding(
+                    &bytes,
+                    viewport.top_byte,
+                    delimiter,
+                );
+                if !padding.is_empty() {
+                    tokens = Self::apply_column_padding(tokens, &padding);
+                }
+            }
+        }
+
         // Apply wrapping transform - always enabled for safety, but with different thresholds.
         // When line_wrap is on: wrap at viewport width for normal text flow.
         // When line_wrap is off: wrap at MAX_SAFE_LINE_WIDTH to prevent memory exhaustion
@@ -2995,6 +3288,76 @@ impl SplitRenderer {
         output
     }
 
+    /// Insert spaces without a source byte before the byte offsets in
+    /// `padding` (sorted, with the number of spaces for each), splitting the
+    /// text tokens they fall inside
+    fn apply_column_padding(
+        tokens: Vec<fresh_core::api::ViewTokenWire>,
+        padding: &[(usize, usize)],
+    ) -> Vec<fresh_core::api::ViewTokenWire> {
+        use fresh_core::api::{ViewTokenWire, ViewTokenWireKind};
+
+        let spaces = |output: &mut Vec<ViewTokenWire>, count: usize| {
+            output.extend((0..count).map(|_| ViewTokenWire {
+                source_offset: None,
+                kind: ViewTokenWireKind::Space,
+                style: None,
+            }));
+        };
+
+        let mut output = Vec::with_capacity(tokens.len() + padding.len());
+        let mut next = 0;
+        for token in tokens {
+            let Some(offset) = token.source_offset else {
+                // Injected tokens pass through
+                output.push(token);
+                continue;
+            };
+            while next < padding.len() && padding[next].0 < offset {
+                next += 1;
+            }
+            let text = match &token.kind {
+                ViewTokenWireKind::Text(text) if text.len() > 1 => text.clone(),
+                _ => {
+                    if next < padding.len() && padding[next].0 == offset {
+                        spaces(&mut output, padding[next].1);
+                        next += 1;
+                    }
+                    output.push(token);
+                    continue;
+                }
+            };
+
+            // Padding goes before delimiters, which are single bytes, so the
+            // text splits on character boundaries
+            let mut piece_start = 0;
+            while next < padding.len() && padding[next].0 < offset + text.len() {
+                let at = padding[next].0 - offset;
+                if at > piece_start {
+                    output.push(ViewTokenWire {
+                        source_offset: Some(offset + piece_start),
+                        kind: ViewTokenWireKind::Text(text[piece_start..at].to_string()),
+                        style: token.style.clone(),
+                    });
+                }
+                spaces(&mut output, padding[next].1);
+                piece_start = at;
+                next += 1;
+            }
+            if piece_start == 0 {
+                output.push(token);
+            } else {
+                output.push(ViewTokenWire {
+                    source_offset: Some(offset + piece_start),
+                    kind: ViewTokenWireKind::Text(text[piece_start..].to_string()),
+                    style: token.style,
+                });
+            }
+        }
+
+        output
+    }
+
     /// Apply conceal ranges to a token stream.
     ///
     /// Handles partial token overlap: if a Text token spans bytes that are
@@ -3136,6 +3499,7 @@ impl SplitRenderer {
         output
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn build_base_tokens(
         buffer: &mut Buffer,
         top_byte: usize,
@@ -3143,6 +3507,7 @@ impl SplitRenderer {
         visible_count: usize,
         is_binary: bool,
         line_ending: crate::model::buffer::LineEnding,
+        wrap_budget: Option<WrapRowBudget>,
     ) -> Vec<fresh_core::api::ViewTokenWire> {
         use crate::model::buffer::LineEnding;
         use fresh_core::api::{ViewTokenWire, ViewTokenWireKind};
@@ -3163,8 +3528,11 @@ impl SplitRenderer {
         let mut iter = buffer.line_iterator(top_byte, estimated_line_length);
         let mut lines_seen = 0usize;
         let max_lines = visible_count.saturating_add(4);
+        // Wrapped rows counted against `wrap_budget`, and chars in the current one
+        let mut wrapped_rows = 0usize;
+        let mut row_chars = 0usize;
 
-        while lines_seen < max_lines {
+        while lines_seen < max_lines && wrapped_rows < max_lines {
             if let Some((line_start, line_content)) = iter.next_line() {
                 let mut byte_offset = 0usize;
                 let content_bytes = line_content.as_bytes();
@@ -3186,6 +3554,18 @@ impl SplitRenderer {
                             break;
                         }
                     }
+                    if let Some(budget) = wrap_budget {
+                        if line_start + byte_offset >= budget.from_byte {
+                            if row_chars >= budget.row_width {
+                                row_chars = 0;
+                                wrapped_rows += 1;
+                                if wrapped_rows >= max_lines {
+                                    break;
+                                }
+                            }
+                            row_chars += 1;
+                        }
+                    }
                     chars_this_line += 1;
 
                     let ch_len = ch.len_utf8();
@@ -3276,6 +3656,14 @@ impl SplitRenderer {
                     byte_offset += ch_len;
                 }
                 lines_seen += 1;
+                // A line end starts a new row; the pieces `next_line` splits an
+                // overlong line into don't
+                if wrap_budget.is_some_and(|budget| line_start + byte_offset > budget.from_byte)
+                    && line_content.ends_with('\n')
+                {
+                    wrapped_rows += 1;
+                    row_chars = 0;
+                }
             } else {
                 break;
             }
@@ -3472,6 +3860,7 @@ impl SplitRenderer {
             visible_count,
             is_binary,
             line_ending,
+            None,
         )
     }
 
@@ -3850,6 +4239,9 @@ impl SplitRenderer {
         theme: &crate::view::theme::Theme,
         highlight_context_bytes: usize,
         view_mode: &ViewMode,
+        bracket_pair_colorization: bool,
+        color_swatches: bool,
+        syntax_highlight_max_column: usize,
     ) -> DecorationContext {
         use crate::view::folding::indent_folding;
 
@@ -3861,13 +4253,92 @@ impl SplitRenderer {
             .saturating_add(viewport_size)
             .min(state.buffer.len());
 
-        let highlight_spans = state.highlighter.highlight_viewport(
-            &state.buffer,
-            highlight_start,
-            highlight_end,
-            theme,
-            highlight_context_bytes,
-        );
+        // Parsing a megabyte-long minified line would stall every frame, so
+        // long lines are only highlighted up to the configured column
+        let highlight_range = if syntax_highlight_max_column == 0 {
+            highlight_start..highlight_end
+        } else {
+            let bytes_start = highlight_start.saturating_sub(syntax_highlight_max_column);
+            let bytes = state.buffer.slice_bytes(bytes_start..highlight_end);
+            Self::highlight_range_within_max_column(
+                &bytes,
+                bytes_start,
+                highlight_start..highlight_end,
+                syntax_highlight_max_column,
+            )
+        };
+
+        let mut highlight_spans = if highlight_range.is_empty() {
+            Vec::new()
+        } else {
+            let _timer = crate::services::perf::time(crate::services::perf::Phase::Highlight);
+            state.highlighter.highlight_viewport(
+                &state.buffer,
+                highlight_range.start,
+                highlight_range.end,
+                theme,
+                highlight_context_bytes,
+            )
+        };
+        if highlight_range.end < highlight_end {
+            // Leave everything past the cap unstyled, even where a token
+            // such as a string runs across it
+            highlight_spans.retain_mut(|span| {
+                span.range.end = span.range.end.min(highlight_range.end);
+                span.range.start < span.range.end
+            });
+        }
+
+        // Delimited files are colored by column instead of by syntax
+        if let Some(delimiter) = state.delimiter {
+            let colors = [
+                theme.syntax_keyword,
+                theme.syntax_string,
+                theme.syntax_function,
+                theme.syntax_type,
+                theme.syntax_constant,
+                theme.syntax_variable,
+            ];
+            let start = indent_folding::find_line_start_byte(&state.buffer, highlight_range.start);
+            let bytes = state
+                .buffer
+                .slice_bytes(start..highlight_range.end.max(start));
+            highlight_spans = crate::primitives::delimited::column_spans(&bytes, start, delimiter)
+                .into_iter()
+                .map(
+                    |(range, column)| crate::primitives::highlighter::HighlightSpan {
+                        range,
+                        color: colors[column % colors.len()],
+                        modifiers: Modifier::empty(),
+                    },
+                )
+                .collect();
+        }
+
+        // Log files have their level words colored by severity
+        if state.log_file {
+            use crate::primitives::log_file::{self, LogLevel};
+            let start = indent_folding::find_line_start_byte(&state.buffer, highlight_range.start);
+            let bytes = state
+                .buffer
+                .slice_bytes(start..highlight_range.end.max(start));
+            highlight_spans = log_file::level_spans(&bytes, start)
+                .into_iter()
+                .map(|(range, level)| {
+                    let (color, modifiers) = match level {
+                        LogLevel::Error => (theme.diagnostic_error_fg, Modifier::BOLD),
+                        LogLevel::Warn => (theme.diagnostic_warning_fg, Modifier::empty()),
+                        LogLevel::Info => (theme.diagnostic_info_fg, Modifier::empty()),
+                        LogLevel::Debug => (theme.diagnostic_hint_fg, Modifier::empty()),
+                    };
+                    crate::primitives::highlighter::HighlightSpan {
+                        range,
+                        color,
+                        modifiers,
+                    }
+                })
+                .collect();
+        }
 
         // Update reference highlight overlays (debounced, creates overlays that auto-adjust)
         state.reference_highlight_overlay.update(
@@ -3907,6 +4378,7 @@ impl SplitRenderer {
                     semantic_token_spans.push(crate::primitives::highlighter::HighlightSpan {
                         range,
                         color: *color,
+                        modifiers: Modifier::empty(),
                     });
                 }
                 continue;
@@ -3957,6 +4429,43 @@ impl SplitRenderer {
 
         let fold_indicators =
             Self::fold_indicators_for_viewport(state, folds, viewport_start, viewport_end);
+        let collapsed_folds = folds
+            .resolved_ranges(&state.buffer, &state.marker_list)
+            .into_iter()
+            .map(|range| (range.start_byte, range.end_byte))
+            .collect();
+
+        // Scan back from the viewport so nesting depth carries over from
+        // brackets opened above it
+        let bracket_colors = if bracket_pair_colorization {
+            let context_start = viewport_start.saturating_sub(highlight_context_bytes);
+            let bytes = state.buffer.slice_bytes(context_start..viewport_end);
+            crate::view::bracket_highlight_overlay::bracket_pair_colors(
+                &bytes,
+                context_start,
+                viewport_start..viewport_end,
+                &crate::view::bracket_highlight_overlay::DEFAULT_BRACKET_COLORS,
+            )
+        } else {
+            HashMap::new()
+        };
+
+        let swatch_colors = if color_swatches {
+            let bytes = state.buffer.slice_bytes(viewport_start..viewport_end);
+            crate::primitives::color_literal::find_color_literals(&bytes)
+                .into_iter()
+                .map(|literal| {
+                    let start = viewport_start + literal.range.start;
+                    let [r, g, b] = match state.color_preview {
+                        Some((preview_start, rgb)) if preview_start == start => rgb,
+                        _ => literal.rgb(),
+                    };
+                    (start, Color::Rgb(r, g, b))
+                })
+                .collect()
+        } else {
+            HashMap::new()
+        };
 
         DecorationContext {
             highlight_spans,
@@ -3966,7 +4475,70 @@ impl SplitRenderer {
             diagnostic_lines,
             line_indicators,
             fold_indicators,
+            collapsed_folds,
+            bracket_colors,
+            swatch_colors,
+        }
+    }
+
+    /// Narrow `range` so no line is highlighted past `max_column` bytes.
+    /// `bytes` holds the buffer from `bytes_start` to `range.end`, where
+    /// `bytes_start` is a line start or at least `max_column` bytes before
+    /// `range.start`. Highlighting stops at the cap of the first over-long
+    /// line, as the syntax state after it is unknown without parsing all of
+    /// it; a range starting past the cap skips ahead to the next line.
+    fn highlight_range_within_max_column(
+        bytes: &[u8],
+        bytes_start: usize,
+        range: Range<usize>,
+        max_column: usize,
+    ) -> Range<usize> {
+        let mut start = range.start;
+        let mut line_start = bytes_start;
+        for line in bytes.split(|&b| b == b'\n') {
+            let line_end = line_start + line.len();
+            if line.len() > max_column {
+                let cap = line_start + max_column;
+                if cap > start {
+                    return start..cap.min(range.end);
+                }
+                start = start.max(line_end + 1);
+            }
+            line_start = line_end + 1;
+        }
+        start.min(range.end)..range.end
+    }
+
+    /// Lay out indent guides for the view lines being rendered. Wrapped
+    /// continuations and injected lines take the indent of the line above so
+    /// they don't split a block's guide.
+    fn indent_guides_for_view_lines(
+        view_lines: &[ViewLine],
+        cursor_line_start_byte: usize,
+        tab_size: usize,
+    ) -> IndentGuides {
+        use crate::view::folding::indent_folding;
+
+        let mut indents = Vec::with_capacity(view_lines.len());
+        let mut cursor_line = None;
+        let mut prev = None;
+        for (idx, line) in view_lines.iter().enumerate() {
+            if !should_show_line_number(line) {
+                indents.push(prev);
+                continue;
+            }
+            if line.char_source_bytes.iter().find_map(|b| *b) == Some(cursor_line_start_byte) {
+                cursor_line = Some(idx);
+            }
+            let text = line.text.trim_end_matches('\n');
+            let (indent, blank) = indent_folding::slice_indent(text.as_bytes(), tab_size);
+            let indent = (!blank).then_some(indent);
+            if indent.is_some() {
+                prev = indent;
+            }
+            indents.push(indent);
         }
+        IndentGuides::new(&indents, cursor_line, tab_size)
     }
 
     fn fold_indicators_for_viewport(
@@ -4044,10 +4616,18 @@ impl SplitRenderer {
         let mut iter_temp = state
             .buffer
             .line_iterator(viewport_start, estimated_line_length);
+        // A screenful never shows more than MAX_SAFE_LINE_WIDTH chars per
+        // row, so stop early on long lines rather than reading all of them
+        let max_end =
+            viewport_start.saturating_add(visible_count.saturating_mul(MAX_SAFE_LINE_WIDTH));
         let mut viewport_end = viewport_start;
         for _ in 0..visible_count {
             if let Some((line_start, line_content)) = iter_temp.next_line() {
                 viewport_end = line_start + line_content.len();
+                if viewport_end >= max_end {
+                    viewport_end = max_end;
+                    break;
+                }
             } else {
                 break;
             }
@@ -4073,7 +4653,9 @@ impl SplitRenderer {
             line_wrap,
             estimated_lines,
             left_column,
-            relative_line_numbers,
+            line_number_mode,
+            indent_guides,
+            highlight_current_line,
             session_mode,
             software_cursor_only,
             show_line_numbers,
@@ -4088,6 +4670,13 @@ impl SplitRenderer {
         // Compute cursor line start byte — universal key for cursor line highlight
         let cursor_line_start_byte =
             indent_folding::find_line_start_byte(&state.buffer, primary_cursor_position);
+        // Cursor line number for relative line numbers — a line index lookup, so
+        // moving the cursor only changes the gutter text, not the layout
+        let cursor_line_num = if line_number_mode == LineNumberMode::Absolute || byte_offset_mode {
+            0
+        } else {
+            state.buffer.get_line_number(cursor_line_start_byte)
+        };
 
         let highlight_spans = &decorations.highlight_spans;
         let semantic_token_spans = &decorations.semantic_token_spans;
@@ -4096,6 +4685,14 @@ impl SplitRenderer {
         let diagnostic_lines = &decorations.diagnostic_lines;
         let line_indicators = &decorations.line_indicators;
 
+        let guides = indent_guides.then(|| {
+            Self::indent_guides_for_view_lines(
+                view_lines,
+                cursor_line_start_byte,
+                state.buffer_settings.tab_size,
+            )
+        });
+
         // Cursors for O(1) amortized span lookups (spans are sorted by byte range)
         let mut hl_cursor = 0usize;
         let mut sem_cursor = 0usize;
@@ -4110,6 +4707,8 @@ impl SplitRenderer {
         let mut last_line_end: Option<LastLineEnd> = None;
         let mut last_gutter_num: Option<usize> = None;
         let mut trailing_empty_line_rendered = false;
+        // Line-start byte of the source line the current row belongs to
+        let mut row_line_start: Option<usize> = None;
 
         let is_empty_buffer = state.buffer.is_empty();
 
@@ -4137,6 +4736,7 @@ impl SplitRenderer {
             } else {
                 break;
             };
+            let view_line_idx = view_iter_idx;
 
             // Extract line data
             let line_content = current_view_line.text.clone();
@@ -4166,6 +4766,10 @@ impl SplitRenderer {
 
             view_iter_idx += 1;
 
+            // Only the last row of a source line has trailing whitespace; a
+            // wrapped row can end in the space the line was broken at
+            let ends_source_line = line_has_newline || view_iter_idx >= view_lines.len();
+
             if lines_rendered >= visible_line_count {
                 break;
             }
@@ -4218,6 +4822,41 @@ impl SplitRenderer {
 
             lines_rendered += 1;
 
+            // Wrapped continuations belong to the line above; a row that starts
+            // mid-line at the top of the view looks its line up
+            let has_source = line_char_source_bytes.iter().any(Option::is_some);
+            if !is_continuation {
+                row_line_start = line_start_byte;
+            } else if row_line_start.is_none() && has_source {
+                row_line_start = line_char_source_bytes
+                    .iter()
+                    .find_map(|b| *b)
+                    .map(|byte| indent_folding::find_line_start_byte(&state.buffer, byte));
+            }
+            // Injected virtual lines are never part of the cursor line
+            let on_cursor_line = highlight_current_line
+                && (has_source || !is_continuation)
+                && row_line_start == Some(cursor_line_start_byte);
+
+            // A collapsed fold header ends with a placeholder: virtual chars just
+            // before its newline that stand in for the hidden text. The
+            // placeholder counts as selected when a selection covers any of it.
+            let fold_placeholder = line_char_source_bytes
+                .iter()
+                .rposition(Option::is_some)
+                .and_then(|newline_idx| {
+                    let hidden_start = line_char_source_bytes[newline_idx]? + 1;
+                    let hidden_end = *decorations.collapsed_folds.get(&hidden_start)?;
+                    let first_idx = line_char_source_bytes[..newline_idx]
+                        .iter()
+                        .rposition(Option::is_some)
+                        .map_or(0, |idx| idx + 1);
+                    let selected = selection_ranges
+                        .iter()
+                        .any(|range| range.start < hidden_end && hidden_start < range.end);
+                    Some((first_idx..newline_idx, selected))
+                });
+
             // Apply horizontal scrolling - skip characters before left_column
             let left_col = left_column;
 
@@ -4244,7 +4883,8 @@ impl SplitRenderer {
                     line_indicators,
                     fold_indicators: &decorations.fold_indicators,
                     cursor_line_start_byte,
-                    relative_line_numbers,
+                    cursor_line_num,
+                    line_number_mode,
                     show_line_numbers,
                     byte_offset_mode,
                 },
@@ -4393,10 +5033,18 @@ impl SplitRenderer {
                     let is_primary_cursor = is_cursor && byte_pos == Some(primary_cursor_position);
                     let exclude_from_selection = is_cursor && !(is_active && is_primary_cursor);
 
+                    let is_in_selected_placeholder =
+                        fold_placeholder
+                            .as_ref()
+                            .is_some_and(|(placeholder_chars, selected)| {
+                                *selected && placeholder_chars.contains(&display_char_idx)
+                            });
+
                     let is_selected = !exclude_from_selection
                         && (byte_pos.is_some_and(|bp| {
                             selection_ranges.iter().any(|range| range.contains(&bp))
-                        }) || is_in_block_selection);
+                        }) || is_in_block_selection
+                            || is_in_selected_placeholder);
 
                     // Compute character style using helper function
                     // char_styles is indexed by character position, not visual column
@@ -4405,10 +5053,11 @@ impl SplitRenderer {
                         .and_then(|s| s.as_ref());
 
                     // Resolve highlight/semantic colors via cursor-based O(1) lookup
-                    let (highlight_color, semantic_token_color) = match byte_pos {
+                    let (highlight, semantic_token_color) = match byte_pos {
                         Some(bp) => (
-                            span_color_at(highlight_spans, &mut hl_cursor, bp),
-                            span_color_at(semantic_token_spans, &mut sem_cursor, bp),
+                            span_at(highlight_spans, &mut hl_cursor, bp),
+                            span_at(semantic_token_spans, &mut sem_cursor, bp)
+                                .map(|span| span.color),
                         ),
                         None => (None, None),
                     };
@@ -4423,13 +5072,20 @@ impl SplitRenderer {
                         is_cursor,
                         is_selected,
                         theme,
-                        highlight_color,
+                        highlight_color: byte_pos
+                            .and_then(|bp| decorations.bracket_colors.get(&bp).copied())
+                            .or(highlight.map(|span| span.color)),
+                        highlight_modifiers: highlight
+                            .map_or(Modifier::empty(), |span| span.modifiers),
                         semantic_token_color,
                         viewport_overlays,
                         primary_cursor_position,
                         is_active,
                         session_mode,
                     });
+                    if on_cursor_line && !is_cursor && style.bg.is_none() {
+                        style = style.bg(theme.current_line_bg);
+                    }
 
                     // Determine display character (tabs already expanded in ViewLineIterator)
                     // Show tab indicator (→) or space indicator (·) based on granular
@@ -4466,10 +5122,35 @@ impl SplitRenderer {
                                     ws.spaces_trailing
                                 } else {
                                     ws.spaces_inner
+                                        || (ws.spaces_inner_runs && {
+                                            // Boundary mode skips single spaces between words
+                                            let next = line_chars_for_ws.get(display_char_idx + 1);
+                                            let prev = display_char_idx
+                                                .checked_sub(1)
+                                                .and_then(|idx| line_chars_for_ws.get(idx));
+                                            next == Some(&' ') || prev == Some(&' ')
+                                        })
                                 }
                             }
                         }
                     };
+                    // Spaces and expanded tabs after the last non-space character
+                    let is_trailing_whitespace = ch == ' '
+                        && state.buffer_settings.whitespace.highlight_trailing
+                        && ends_source_line
+                        && byte_pos.is_some()
+                        && last_non_ws_idx.is_none_or(|last| display_char_idx > last);
+
+                    // Indent guides replace spaces in leading whitespace
+                    let indent_guide = guides
+                        .as_ref()
+                        .filter(|_| {
+                            !is_continuation
+                                && ch == ' '
+                                && !is_cursor
+                                && first_non_ws_idx.is_none_or(|first| display_char_idx < first)
+                        })
+                        .and_then(|guides| guides.guide_at(view_line_idx, col_offset));
 
                     let display_char: &str = if is_cursor && lsp_waiting && is_active {
                         "⋯"
@@ -4481,6 +5162,8 @@ impl SplitRenderer {
                         "\\n"
                     } else if ch == '\n' {
                         ""
+                    } else if indent_guide.is_some() {
+                        "│"
                     } else if ws_show_tab {
                         // Visual indicator for tab: show → at the first position
                         is_whitespace_indicator = true;
@@ -4500,6 +5183,28 @@ impl SplitRenderer {
                     if is_whitespace_indicator && !is_cursor && !is_selected {
                         style = style.fg(theme.whitespace_indicator_fg);
                     }
+                    if is_trailing_whitespace && !is_cursor && !is_selected {
+                        style = style.bg(theme.diagnostic_warning_bg);
+                    }
+                    if let Some(active) = indent_guide {
+                        style = style.fg(if active {
+                            theme.indent_guide_active_fg
+                        } else {
+                            theme.indent_guide_fg
+                        });
+                    }
+
+                    if let Some(color) = byte_pos.and_then(|bp| decorations.swatch_colors.get(&bp))
+                    {
+                        span_acc.flush(&mut line_spans, &mut line_view_map);
+                        push_span_with_map(
+                            &mut line_spans,
+                            &mut line_view_map,
+                            " ".to_string(),
+                            Style::default().bg(*color),
+                            None,
+                        );
+                    }
 
                     if let Some(bp) = byte_pos {
                         if let Some(vtexts) = virtual_text_lookup.get(&bp) {
@@ -4641,6 +5346,43 @@ impl SplitRenderer {
             // Flush any remaining accumulated text at end of line
             span_acc.flush(&mut line_spans, &mut line_view_map);
 
+            // Carry indent guides across blank lines past their own whitespace.
+            // Skipped when a cursor sits on the line so its indicator keeps its column.
+            if let Some(guides) = guides
+                .as_ref()
+                .filter(|_| line_has_newline && !is_continuation && first_non_ws_idx.is_none())
+            {
+                let has_cursor = cursor_positions.iter().any(|&pos| {
+                    first_line_byte_pos.is_some_and(|start| pos >= start)
+                        && last_line_byte_pos.is_some_and(|end| pos <= end)
+                });
+                let text_cols: usize = line_content
+                    .trim_end_matches(['\n', '\r'])
+                    .chars()
+                    .map(char_width)
+                    .sum();
+                let last_guide = (text_cols..guides.extent(view_line_idx))
+                    .rev()
+                    .find(|&col| guides.guide_at(view_line_idx, col).is_some());
+                if let Some(last_guide) = last_guide.filter(|_| !has_cursor) {
+                    for col in text_cols.max(left_col)..=last_guide {
+                        let (text, style) = match guides.guide_at(view_line_idx, col) {
+                            Some(true) => ("│", Style::default().fg(theme.indent_guide_active_fg)),
+                            Some(false) => ("│", Style::default().fg(theme.indent_guide_fg)),
+                            None => (" ", Style::default()),
+                        };
+                        push_span_with_map(
+                            &mut line_spans,
+                            &mut line_view_map,
+                            text.to_string(),
+                            style,
+                            None,
+                        );
+                        visible_char_count += 1;
+                    }
+                }
+            }
+
             // Set last_seg_y early so cursor detection works for both empty and non-empty lines
             // For lines without wrapping, this will be the final y position
             // Also set for empty content lines (regardless of line_wrap) so cursor at EOF can be positioned
@@ -4844,6 +5586,21 @@ impl SplitRenderer {
                 }
             }
 
+            // Carry the current-line background to the right edge of the window
+            if on_cursor_line {
+                let remaining_cols =
+                    (render_area.width as usize).saturating_sub(line_view_map.len());
+                if remaining_cols > 0 {
+                    push_span_with_map(
+                        &mut line_spans,
+                        &mut line_view_map,
+                        " ".repeat(remaining_cols),
+                        Style::default().bg(theme.current_line_bg),
+                        None,
+                    );
+                }
+            }
+
             // For virtual rows (no source bytes), inherit from previous row
             let prev_line_end_byte = view_line_mappings
                 .last()
@@ -5003,6 +5760,19 @@ impl SplitRenderer {
                             implicit_gutter_num,
                             width = state.margins.left_config.width
                         )
+                    } else if line_number_mode != LineNumberMode::Absolute && show_line_numbers {
+                        let is_cursor_line = cursor_line_start_byte == implicit_line_byte;
+                        let display_num =
+                            if is_cursor_line && line_number_mode == LineNumberMode::Hybrid {
+                                implicit_gutter_num + 1
+                            } else {
+                                implicit_gutter_num.abs_diff(cursor_line_num)
+                            };
+                        format!(
+                            "{:>width$}",
+                            display_num,
+                            width = state.margins.left_config.width
+                        )
                     } else {
                         let estimated_lines = state.buffer.line_count().unwrap_or(
                             (state.buffer.len() / state.buffer.estimated_line_length()).max(1),
@@ -5160,7 +5930,12 @@ impl SplitRenderer {
         view_transform: Option<ViewTransformPayload>,
         estimated_line_length: usize,
         highlight_context_bytes: usize,
-        relative_line_numbers: bool,
+        line_number_mode: LineNumberMode,
+        indent_guides: bool,
+        highlight_current_line: bool,
+        bracket_pair_colorization: bool,
+        color_swatches: bool,
+        syntax_highlight_max_column: usize,
         use_terminal_bg: bool,
         session_mode: bool,
         software_cursor_only: bool,
@@ -5207,6 +5982,7 @@ impl SplitRenderer {
         // Clone view_transform so we can reuse it if scrolling triggers a rebuild
         let view_transform_for_rebuild = view_transform.clone();
 
+        let primary_cursor_position = cursors.primary().position;
         let view_data = {
             let _span = tracing::trace_span!("build_view_data").entered();
             Self::build_view_data(
@@ -5215,6 +5991,7 @@ impl SplitRenderer {
                 view_transform,
                 estimated_line_length,
                 visible_count,
+                primary_cursor_position,
                 line_wrap,
                 render_area.width as usize,
                 gutter_width,
@@ -5243,6 +6020,7 @@ impl SplitRenderer {
                 view_transform_for_rebuild,
                 estimated_line_length,
                 visible_count,
+                primary_cursor_position,
                 line_wrap,
                 render_area.width as usize,
                 gutter_width,
@@ -5271,6 +6049,7 @@ impl SplitRenderer {
                     vt,
                     estimated_line_length,
                     visible_count,
+                    primary_cursor_position,
                     line_wrap,
                     render_area.width as usize,
                     gutter_width,
@@ -5344,6 +6123,9 @@ impl SplitRenderer {
             theme,
             highlight_context_bytes,
             &view_mode,
+            bracket_pair_colorization,
+            color_swatches,
+            syntax_highlight_max_column,
         );
 
         let calculated_offset = viewport.top_view_line_offset;
@@ -5379,7 +6161,9 @@ impl SplitRenderer {
             line_wrap,
             estimated_lines,
             left_column: viewport.left_column,
-            relative_line_numbers,
+            line_number_mode,
+            indent_guides,
+            highlight_current_line,
             session_mode,
             software_cursor_only,
             show_line_numbers,
@@ -5572,7 +6356,12 @@ impl SplitRenderer {
         highlight_context_bytes: usize,
         _buffer_id: BufferId,
         hide_cursor: bool,
-        relative_line_numbers: bool,
+        line_number_mode: LineNumberMode,
+        indent_guides: bool,
+        highlight_current_line: bool,
+        bracket_pair_colorization: bool,
+        color_swatches: bool,
+        syntax_highlight_max_column: usize,
         use_terminal_bg: bool,
         session_mode: bool,
         software_cursor_only: bool,
@@ -5593,7 +6382,12 @@ impl SplitRenderer {
             view_transform,
             estimated_line_length,
             highlight_context_bytes,
-            relative_line_numbers,
+            line_number_mode,
+            indent_guides,
+            highlight_current_line,
+            bracket_pair_colorization,
+            color_swatches,
+            syntax_highlight_max_column,
             use_terminal_bg,
             session_mode,
             software_cursor_only,
@@ -5940,6 +6734,7 @@ mod tests {
             None,
             content.len().max(1),
             visible_count,
+            0,
             false, // line wrap disabled for tests
             render_area.width as usize,
             gutter_width,
@@ -5973,6 +6768,9 @@ mod tests {
             &theme,
             100_000,           // default highlight context bytes
             &ViewMode::Source, // Tests use source mode
+            false,             // bracket_pair_colorization
+            false,             // color_swatches
+            10_000,            // syntax_highlight_max_column
         );
 
         let output = SplitRenderer::render_view_lines(LineRenderInput {
@@ -5990,7 +6788,9 @@ mod tests {
             line_wrap: viewport.line_wrap_enabled,
             estimated_lines,
             left_column: viewport.left_column,
-            relative_line_numbers: false,
+            line_number_mode: LineNumberMode::Absolute,
+            indent_guides: false,
+            highlight_current_line: false,
             session_mode: false,
             software_cursor_only: false,
             show_line_numbers: true, // Tests show line numbers
@@ -6025,6 +6825,7 @@ mod tests {
             None,
             content.len().max(1),
             viewport.visible_line_count(),
+            0,
             false,
             40,
             gutter_width,
@@ -7308,6 +8109,92 @@ mod tests {
         s.trim_end().to_string()
     }
 
+    #[test]
+    fn test_highlight_range_within_max_column() {
+        let text = format!("short\n{}\nafter", "x".repeat(30));
+        let bytes = text.as_bytes();
+        let long_start = 6;
+        let after_start = long_start + 31;
+
+        // Highlighting stops at the cap of the long line
+        assert_eq!(
+            SplitRenderer::highlight_range_within_max_column(bytes, 0, 0..text.len(), 10),
+            0..long_start + 10
+        );
+        // A range starting past the cap skips to the next line; the bytes
+        // then start `max_column` before the range rather than at a line
+        let start = long_start + 15;
+        assert_eq!(
+            SplitRenderer::highlight_range_within_max_column(
+                &bytes[start - 10..],
+                start - 10,
+                start..text.len(),
+                10
+            ),
+            after_start..text.len()
+        );
+        // Lines within the cap are untouched
+        assert_eq!(
+            SplitRenderer::highlight_range_within_max_column(bytes, 0, 0..text.len(), 100),
+            0..text.len()
+        );
+    }
+
+    #[test]
+    fn test_build_base_tokens_wrap_budget() {
+        use fresh_core::api::ViewTokenWireKind;
+
+        let text_len = |tokens: &[fresh_core::api::ViewTokenWire]| -> usize {
+            tokens
+                .iter()
+                .map(|t| match &t.kind {
+                    ViewTokenWireKind::Text(s) => s.len(),
+                    _ => 1,
+                })
+                .sum()
+        };
+        let max_offset = |tokens: &[fresh_core::api::ViewTokenWire]| -> usize {
+            tokens.iter().filter_map(|t| t.source_offset).max().unwrap()
+        };
+        let mut buffer = Buffer::from_str(&"x".repeat(100_000), 1024, test_fs());
+        let build = |buffer: &mut Buffer, wrap_budget| {
+            SplitRenderer::build_base_tokens(
+                buffer,
+                0,
+                80,
+                10,
+                false,
+                crate::model::buffer::LineEnding::LF,
+                wrap_budget,
+            )
+        };
+
+        // Unwrapped, whole MAX_SAFE_LINE_WIDTH rows are tokenized
+        let unwrapped = build(&mut buffer, None);
+        assert!(text_len(&unwrapped) >= MAX_SAFE_LINE_WIDTH);
+
+        // Wrapped at 40 columns, only the (10 + 4) rows the view needs
+        let wrapped = build(
+            &mut buffer,
+            Some(WrapRowBudget {
+                row_width: 40,
+                from_byte: 0,
+            }),
+        );
+        assert!(text_len(&wrapped) <= 14 * 40 + 1);
+
+        // Rows are counted from the cursor, so its row is laid out
+        let to_cursor = build(
+            &mut buffer,
+            Some(WrapRowBudget {
+                row_width: 40,
+                from_byte: 50_000,
+            }),
+        );
+        assert!(max_offset(&to_cursor) > 50_000);
+        assert!(max_offset(&to_cursor) < 51_000);
+    }
+
     #[test]
     fn test_apply_osc8_to_cells_preserves_adjacent_cells() {
         use ratatui::buffer::Buffer;
@@ -1,9 +1,13 @@
 //! Status bar and prompt/minibuffer rendering
 
+use std::collections::HashMap;
 use std::path::Path;
 
 use crate::app::WarningLevel;
+use crate::config::{StatusBarConfig, StatusBarSegment};
+use crate::primitives::delimited;
 use crate::primitives::display_width::{char_width, str_width};
+use crate::primitives::selection_stats::SelectionSize;
 use crate::state::EditorState;
 use crate::view::prompt::Prompt;
 use ratatui::layout::Rect;
@@ -28,6 +32,112 @@ pub struct StatusBarLayout {
     pub language_indicator: Option<(u16, u16, u16)>,
     /// Status message area (row, start_col, end_col) - clickable to show full history
     pub message_area: Option<(u16, u16, u16)>,
+    /// Cursor position area (row, start_col, end_col) - clickable to go to a line
+    pub cursor_position: Option<(u16, u16, u16)>,
+    /// Plugin segment areas (name, row, start_col, end_col) - clickable to run their command
+    pub plugin_segments: Vec<(String, u16, u16, u16)>,
+}
+
+impl StatusBarLayout {
+    /// Record where a segment was drawn, for the segments that can be clicked
+    fn record(&mut self, segment: &str, row: u16, start: u16, end: u16) {
+        let area = Some((row, start, end));
+        match segment {
+            "lsp_status" => self.lsp_indicator = area,
+            "warnings" => self.warning_badge = area,
+            "line_ending" => self.line_ending_indicator = area,
+            "encoding" => self.encoding_indicator = area,
+            "language" => self.language_indicator = area,
+            "messages" => self.message_area = area,
+            "cursor_position" => self.cursor_position = area,
+            name => self
+                .plugin_segments
+                .push((name.to_string(), row, start, end)),
+        }
+    }
+}
+
+/// A status bar segment set by a plugin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginStatusSegment {
+    /// Text shown in the status bar
+    pub text: String,
+    /// Command run when the segment is clicked
+    pub command: Option<String>,
+}
+
+/// What the configurable status bar segments show besides the buffer state
+pub struct StatusBarSegments<'a> {
+    /// Which segments are shown where
+    pub config: &'a StatusBarConfig,
+    /// Segments set by plugins, by name
+    pub plugin_segments: &'a HashMap<String, PluginStatusSegment>,
+    /// Git branch of the working directory (for the git_branch segment)
+    pub git_branch: Option<&'a str>,
+    /// Current time (for the clock segment)
+    pub clock: Option<&'a str>,
+    /// Whether the active split scrolls together with another (for the
+    /// scroll_link segment)
+    pub scroll_linked: bool,
+}
+
+/// Join the texts of a left or center group
+///
+/// Flags (modified, read-only, chord) follow the previous segment after a
+/// space, as do segments after a prefix (mode, session, remote, clipboard);
+/// everything else is separated by " | ". Returns the text and the columns
+/// (name, start, end) of each segment in it.
+fn join_segments(segments: Vec<(String, String)>) -> (String, Vec<(String, usize, usize)>) {
+    let mut text = String::new();
+    let mut columns = Vec::with_capacity(segments.len());
+    let mut previous: Option<String> = None;
+    for (name, segment) in segments {
+        if let Some(previous) = &previous {
+            let separator = if matches!(name.as_str(), "modified" | "read_only" | "chord")
+                || matches!(
+                    previous.as_str(),
+                    "mode" | "session" | "remote" | "clipboard"
+                ) {
+                " "
+            } else {
+                " | "
+            };
+            text.push_str(separator);
+        }
+        let start = str_width(&text);
+        text.push_str(&segment);
+        columns.push((name.clone(), start, str_width(&text)));
+        previous = Some(name);
+    }
+    (text, columns)
+}
+
+/// Cut `text` down to `max_width` columns, ending it with "..." when it is cut
+fn truncate_status_text(text: &str, max_width: usize) -> String {
+    if str_width(text) <= max_width {
+        return text.to_string();
+    }
+    // Take characters up to visual width limit
+    let take_width = |limit: usize| -> String {
+        let mut width = 0;
+        text.chars()
+            .take_while(|ch| {
+                let w = char_width(*ch);
+                if width + w <= limit {
+                    width += w;
+                    true
+                } else {
+                    false
+                }
+            })
+            .collect()
+    };
+    let truncate_at = max_width.saturating_sub(3); // -3 for "..."
+    if truncate_at > 0 {
+        format!("{}...", take_width(truncate_at))
+    } else {
+        take_width(max_width)
+    }
 }
 
 /// Status bar hover state for styling clickable indicators
@@ -58,6 +168,7 @@ pub enum SearchOptionsHover {
     WholeWord,
     Regex,
     ConfirmEach,
+    PreserveCase,
 }
 
 /// Layout information for search options bar hit testing
@@ -73,6 +184,8 @@ pub struct SearchOptionsLayout {
     pub regex: Option<(u16, u16)>,
     /// Confirm Each checkbox area (start_col, end_col) - only present in replace mode
     pub confirm_each: Option<(u16, u16)>,
+    /// Preserve Case checkbox area (start_col, end_col) - only present in replace mode
+    pub preserve_case: Option<(u16, u16)>,
 }
 
 impl SearchOptionsLayout {
@@ -102,6 +215,11 @@ impl SearchOptionsLayout {
                 return Some(SearchOptionsHover::ConfirmEach);
             }
         }
+        if let Some((start, end)) = self.preserve_case {
+            if x >= start && x < end {
+                return Some(SearchOptionsHover::PreserveCase);
+            }
+        }
         None
     }
 }
@@ -262,6 +380,11 @@ impl StatusBarRenderer {
     /// * `general_warning_count` - Number of general warnings (for badge display)
     /// * `remote_connection` - Optional remote connection info (e.g., "user@host")
     /// * `session_name` - Optional session name (for session persistence mode)
+    /// * `vim_mode` - Optional modal editing mode name (e.g., "NORMAL")
+    /// * `search_match` - Match under the cursor (if any) and total matches of the active search
+    /// * `clipboard_internal_only` - Whether only the internal clipboard is usable
+    /// * `read_only` - Whether the buffer is a read-only file
+    /// * `segments` - Configured segments and the values only some of them need
     ///
     /// # Returns
     /// Layout information with positions of clickable indicators
@@ -284,6 +407,11 @@ impl StatusBarRenderer {
         hover: StatusBarHover,
         remote_connection: Option<&str>,
         session_name: Option<&str>,
+        vim_mode: Option<&str>,
+        search_match: Option<(Option<usize>, usize)>,
+        clipboard_internal_only: bool,
+        read_only: bool,
+        segments: &StatusBarSegments,
     ) -> StatusBarLayout {
         Self::render_status(
             frame,
@@ -303,6 +431,11 @@ impl StatusBarRenderer {
             hover,
             remote_connection,
             session_name,
+            vim_mode,
+            search_match,
+            clipboard_internal_only,
+            read_only,
+            segments,
         )
     }
 
@@ -488,34 +621,25 @@ impl StatusBarRenderer {
         hover: StatusBarHover,
         remote_connection: Option<&str>,
         session_name: Option<&str>,
+        vim_mode: Option<&str>,
+        search_match: Option<(Option<usize>, usize)>,
+        clipboard_internal_only: bool,
+        read_only: bool,
+        segments: &StatusBarSegments,
     ) -> StatusBarLayout {
         // Initialize layout tracking
         let mut layout = StatusBarLayout::default();
-        // Use the pre-computed display name from buffer metadata
-        let filename = display_name;
+        let available_width = area.width as usize;
+        let config = segments.config;
 
-        let modified = if state.buffer.is_modified() {
-            " [+]"
-        } else {
-            ""
-        };
+        let modified = state.buffer.is_modified();
 
         // Format chord state if present
-        let chord_display = if !chord_state.is_empty() {
-            let chord_str = chord_state
-                .iter()
-                .map(|(code, modifiers)| {
-                    crate::input::keybindings::format_keybinding(code, modifiers)
-                })
-                .collect::<Vec<_>>()
-                .join(" ");
-            format!(" [{}]", chord_str)
-        } else {
-            String::new()
-        };
-
-        // View mode indicator (view_mode now lives in SplitViewState/BufferViewState)
-        // Not available here — status bar shows only buffer-level info.
+        let chord_display = chord_state
+            .iter()
+            .map(|(code, modifiers)| crate::input::keybindings::format_keybinding(code, modifiers))
+            .collect::<Vec<_>>()
+            .join(" ");
 
         let cursor = *cursors.primary();
 
@@ -530,6 +654,100 @@ impl StatusBarRenderer {
             let line_num = state.primary_cursor_line_number.value();
             (line_num, col)
         };
+        let byte_offset_mode = state.buffer.line_count().is_none();
+        // Line and column are 0-indexed internally, but displayed as 1-indexed (standard editor convention)
+        let cursor_position = if byte_offset_mode {
+            format!("Byte {}", cursor.position)
+        } else {
+            format!("Ln {}, Col {}", line + 1, col + 1)
+        };
+
+        // Field of a delimited file the cursor is in, named by the header row
+        let csv_column = state
+            .delimiter
+            .filter(|_| config.contains("csv_column"))
+            .map(|delimiter| {
+                let mut iter = state.buffer.line_iterator(cursor.position, 80);
+                let line_start = iter.current_position();
+                let line = iter.next_line().map(|(_, text)| text).unwrap_or_default();
+                let line = line.trim_end_matches(['\n', '\r']);
+                let column = delimited::column_at(
+                    line.as_bytes(),
+                    delimiter,
+                    cursor.position.saturating_sub(line_start),
+                );
+                let header = state.buffer.get_line(0).unwrap_or_default();
+                let header = String::from_utf8_lossy(&header);
+                let header = header.trim_end_matches(['\n', '\r']);
+                match delimited::field_text(header.as_bytes(), delimiter, column) {
+                    Some(name) if !name.is_empty() => {
+                        t!("status.csv_column", index = column + 1, name = name).to_string()
+                    }
+                    _ => t!("status.csv_column_index", index = column + 1).to_string(),
+                }
+            });
+
+        // Whether a log file is followed, so it's clear when following paused
+        let log_follow = state.log_file.then(|| {
+            if state.buffer_settings.follow_tail {
+                t!("status.log_following").to_string()
+            } else {
+                t!("status.log_follow_paused").to_string()
+            }
+        });
+
+        // Size of the selections over all cursors (only when a segment shows it)
+        let selection_size =
+            if config.contains("selection_size") || config.contains("selection_words") {
+                let ranges: Vec<_> = cursors
+                    .iter()
+                    .filter_map(|(_, cursor)| cursor.selection_range())
+                    .collect();
+                state.selection_stats.size(&mut state.buffer, &ranges)
+            } else {
+                SelectionSize::default()
+            };
+        let selection_words = if config.contains("selection_words") && selection_size.chars > 0 {
+            state.selection_stats.words(&mut state.buffer)
+        } else {
+            None
+        };
+        // Columns and rows of a rectangular selection of the primary cursor
+        let block_size = cursor
+            .block_anchor
+            .filter(|_| cursor.has_block_selection())
+            .map(|anchor| {
+                let line = state.buffer.get_line_number(cursor.position);
+                let line_start = state.buffer.line_start_offset(line).unwrap_or(0);
+                let column = cursor.position.saturating_sub(line_start);
+                (
+                    anchor.column.abs_diff(column),
+                    anchor.line.abs_diff(line) + 1,
+                )
+            });
+        let selection_text = {
+            let size = if let Some((columns, rows)) = block_size {
+                t!("status.selection_block", columns = columns, rows = rows).to_string()
+            } else if selection_size.lines > 1 {
+                t!(
+                    "status.selection_lines",
+                    lines = selection_size.lines,
+                    chars = selection_size.chars
+                )
+                .to_string()
+            } else {
+                t!("status.selection_chars", chars = selection_size.chars).to_string()
+            };
+            if selection_size.selections > 1 {
+                format!(
+                    "{} ({})",
+                    size,
+                    t!("status.cursors", count = selection_size.selections)
+                )
+            } else {
+                size
+            }
+        };
 
         // Count diagnostics by severity
         let diagnostics = state.overlays.all();
@@ -552,145 +770,124 @@ impl StatusBarRenderer {
         }
 
         // Build diagnostics summary if there are any
-        let diagnostics_summary = if error_count + warning_count + info_count > 0 {
-            let mut parts = Vec::new();
-            if error_count > 0 {
-                parts.push(format!("E:{}", error_count));
-            }
-            if warning_count > 0 {
-                parts.push(format!("W:{}", warning_count));
-            }
-            if info_count > 0 {
-                parts.push(format!("I:{}", info_count));
-            }
-            format!(" | {}", parts.join(" "))
-        } else {
-            String::new()
-        };
-
-        // Build cursor count indicator (only show if multiple cursors)
-        let cursor_count_indicator = if cursors.count() > 1 {
-            format!(" | {}", t!("status.cursors", count = cursors.count()))
-        } else {
-            String::new()
-        };
-
-        // Build status message parts
-        let mut message_parts: Vec<&str> = Vec::new();
-        if let Some(msg) = status_message {
-            if !msg.is_empty() {
-                message_parts.push(msg);
-            }
+        let mut diagnostic_parts = Vec::new();
+        if error_count > 0 {
+            diagnostic_parts.push(format!("E:{}", error_count));
         }
-        if let Some(msg) = plugin_status_message {
-            if !msg.is_empty() {
-                message_parts.push(msg);
-            }
+        if warning_count > 0 {
+            diagnostic_parts.push(format!("W:{}", warning_count));
         }
+        if info_count > 0 {
+            diagnostic_parts.push(format!("I:{}", info_count));
+        }
+        let diagnostics_summary = diagnostic_parts.join(" ");
 
-        let message_suffix = if message_parts.is_empty() {
-            String::new()
-        } else {
-            format!(" | {}", message_parts.join(" | "))
-        };
-
-        // Build left status (file info, position, diagnostics, messages)
-        // Session/Remote indicator comes first (if present), then filename
-        // Line and column are 0-indexed internally, but displayed as 1-indexed (standard editor convention)
-        // For virtual buffers with hidden cursors, don't show line/column info
-        let remote_prefix = remote_connection
-            .map(|conn| format!("[SSH:{}] ", conn))
-            .unwrap_or_default();
-        let session_prefix = session_name
-            .map(|name| format!("[{}] ", name))
-            .unwrap_or_default();
-        let byte_offset_mode = state.buffer.line_count().is_none();
-        let base_status = if state.show_cursors {
-            if byte_offset_mode {
-                format!(
-                    "{session_prefix}{remote_prefix}{filename}{modified} | Byte {}{diagnostics_summary}{cursor_count_indicator}",
-                    cursor.position
-                )
-            } else {
-                format!(
-                    "{session_prefix}{remote_prefix}{filename}{modified} | Ln {}, Col {}{diagnostics_summary}{cursor_count_indicator}",
-                    line + 1,
-                    col + 1
-                )
-            }
-        } else {
-            // Virtual buffer - just show filename and modified indicator
-            format!("{session_prefix}{remote_prefix}{filename}{modified}{diagnostics_summary}")
-        };
-
-        // Track where the message starts for click detection
-        let base_and_chord_width = str_width(&base_status) + str_width(&chord_display);
-        let message_width = str_width(&message_suffix);
-
-        let left_status = format!("{base_status}{chord_display}{message_suffix}");
-
-        // Build right-side indicators (these stay fixed on the right)
-        // Order: [Line ending] [Language] [LSP indicator] [warning badge] [update] [Palette]
-        // Note: Remote indicator is now on the left side, before the filename
-
-        // Line ending indicator (clickable to change format)
-        let line_ending_text = format!(" {} ", state.buffer.line_ending().display_name());
-        let line_ending_width = str_width(&line_ending_text);
-
-        // Encoding indicator (clickable to change encoding)
-        let encoding = state.buffer.encoding();
-        let encoding_text = format!(" {} ", encoding.display_name());
-        let encoding_width = str_width(&encoding_text);
-
-        // Language indicator (clickable to change language)
-        let language_text = format!(" {} ", &state.language);
-        let language_width = str_width(&language_text);
-
-        // LSP indicator (right-aligned, with colored background if warning/error)
-        let lsp_indicator = if !lsp_status.is_empty() {
-            format!(" {} ", lsp_status)
-        } else {
-            String::new()
-        };
-        let lsp_indicator_width = str_width(&lsp_indicator);
-
-        // General warning badge (right-aligned)
-        let warning_badge = if general_warning_count > 0 {
-            format!(" [⚠ {}] ", general_warning_count)
-        } else {
-            String::new()
-        };
-        let warning_badge_width = str_width(&warning_badge);
-
-        // Build update indicator for right side (if update available)
-        let update_indicator = update_available
-            .map(|version| format!(" {} ", t!("status.update_available", version = version)));
-        let update_width = update_indicator.as_ref().map(|s| s.len()).unwrap_or(0);
-
-        // Build Quick Open / Command Palette indicator for right side
-        // Always show the indicator on the right side
+        // Build status message parts
+        let message_parts: Vec<&str> = [status_message, plugin_status_message]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .filter(|msg| !msg.is_empty())
+            .collect();
+
+        let line_ending = state.buffer.line_ending().display_name();
+        let encoding = state.buffer.encoding().display_name();
+        let language = state.language.clone();
+
+        // Quick Open / Command Palette shortcut
         let cmd_palette_shortcut = keybindings
             .get_keybinding_for_action(
                 &crate::input::keybindings::Action::QuickOpen,
                 crate::input::keybindings::KeyContext::Global,
             )
             .unwrap_or_else(|| "?".to_string());
-        let cmd_palette_indicator = t!("status.palette", shortcut = cmd_palette_shortcut);
-        let padded_cmd_palette = format!(" {} ", cmd_palette_indicator);
 
-        // Calculate available width and right side width
-        // Right side: [Line ending] [Encoding] [Language] [LSP indicator] [warning badge] [update] [Palette]
-        let available_width = area.width as usize;
-        let cmd_palette_width = str_width(&padded_cmd_palette);
-        let right_side_width = line_ending_width
-            + encoding_width
-            + language_width
-            + lsp_indicator_width
-            + warning_badge_width
-            + update_width
-            + cmd_palette_width;
-
-        // Only show command palette indicator if there's enough space (at least 15 chars for minimal display)
+        // For virtual buffers with hidden cursors, don't show cursor related info
+        let show_cursors = state.show_cursors;
+
+        // Text of a segment, None when it has nothing to show
+        let segment_text = |name: &str| -> Option<String> {
+            let text = match name {
+                "mode" => format!("-- {} --", vim_mode?),
+                "session" => format!("[{}]", session_name?),
+                "remote" => format!("[SSH:{}]", remote_connection?),
+                // Explains why text copied in other applications can't be pasted
+                "clipboard" if clipboard_internal_only => {
+                    format!("[{}]", t!("status.clipboard_internal"))
+                }
+                "file_name" => display_name.to_string(),
+                "modified" if modified => "[+]".to_string(),
+                "read_only" if read_only => "[RO]".to_string(),
+                "cursor_position" if show_cursors => cursor_position.clone(),
+                "csv_column" if show_cursors => csv_column.clone()?,
+                "log_follow" => log_follow.clone()?,
+                "scroll_link" if segments.scroll_linked => t!("status.scroll_linked").to_string(),
+                "selection_size" if show_cursors && selection_size.chars > 0 => {
+                    selection_text.clone()
+                }
+                "selection_words" if show_cursors => {
+                    t!("status.selection_words", count = selection_words?).to_string()
+                }
+                "diagnostics" => diagnostics_summary.clone(),
+                "cursor_count" if show_cursors && cursors.count() > 1 => {
+                    t!("status.cursors", count = cursors.count()).to_string()
+                }
+                "search_matches" if show_cursors => match search_match? {
+                    (Some(index), total) => {
+                        t!("search.match_of", current = index + 1, total = total).to_string()
+                    }
+                    (None, total) => t!("status.search_matches", count = total).to_string(),
+                },
+                "chord" if !chord_display.is_empty() => format!("[{}]", chord_display),
+                "messages" => message_parts.join(" | "),
+                "git_branch" => segments.git_branch?.to_string(),
+                "clock" => segments.clock?.to_string(),
+                "line_ending" => line_ending.to_string(),
+                "encoding" => encoding.to_string(),
+                "language" => language.clone(),
+                "lsp_status" => lsp_status.to_string(),
+                "warnings" if general_warning_count > 0 => {
+                    format!("[⚠ {}]", general_warning_count)
+                }
+                "update" => {
+                    let version = update_available?;
+                    t!("status.update_available", version = version).to_string()
+                }
+                "palette" => t!("status.palette", shortcut = cmd_palette_shortcut).to_string(),
+                "clipboard" | "modified" | "read_only" | "cursor_position" | "csv_column"
+                | "selection_size" | "selection_words" | "cursor_count" | "search_matches"
+                | "chord" | "warnings" | "scroll_link" => return None,
+                plugin => segments.plugin_segments.get(plugin)?.text.clone(),
+            };
+            (!text.is_empty()).then_some(text)
+        };
+
+        // Segments of a group that fit the current width and have something to show
+        let group = |entries: &[StatusBarSegment]| -> Vec<(String, String)> {
+            entries
+                .iter()
+                .filter(|entry| available_width >= entry.min_width as usize)
+                .filter_map(|entry| Some((entry.segment.clone(), segment_text(&entry.segment)?)))
+                .collect()
+        };
+
+        // Build left status (file info, position, diagnostics, messages)
+        let (left_status, left_columns) = join_segments(group(&config.left));
+        let (center_status, center_columns) = join_segments(group(&config.center));
+
+        // Build right-side indicators (these stay fixed on the right), each
+        // padded with a space on both sides
+        let right_segments: Vec<(String, String)> = group(&config.right)
+            .into_iter()
+            .map(|(name, text)| (name, format!(" {} ", text)))
+            .collect();
+        let right_side_width: usize = right_segments.iter().map(|(_, text)| str_width(text)).sum();
+
+        let status_style = Style::default()
+            .fg(theme.status_bar_fg)
+            .bg(theme.status_bar_bg);
+
+        // Only show the right side if there's enough space (at least 15 chars for minimal display)
         let spans = if available_width >= 15 {
             // Reserve space for right side indicators
             let left_max_width = if available_width > right_side_width + 1 {
@@ -702,281 +899,163 @@ impl StatusBarRenderer {
             let mut spans = vec![];
 
             // Truncate left status if it's too long (use visual width, not char count)
-            let left_visual_width = str_width(&left_status);
-            let displayed_left = if left_visual_width > left_max_width {
-                let truncate_at = left_max_width.saturating_sub(3); // -3 for "..."
-                if truncate_at > 0 {
-                    // Take characters up to visual width limit
-                    let mut width = 0;
-                    let truncated: String = left_status
-                        .chars()
-                        .take_while(|ch| {
-                            let w = char_width(*ch);
-                            if width + w <= truncate_at {
-                                width += w;
-                                true
-                            } else {
-                                false
-                            }
-                        })
-                        .collect();
-                    format!("{}...", truncated)
-                } else {
-                    String::from("...")
+            let displayed_left = truncate_status_text(&left_status, left_max_width);
+            let displayed_left_len = str_width(&displayed_left);
+            spans.push(Span::styled(displayed_left, status_style));
+
+            // Track left segments for click detection, as far as they are visible
+            for (name, start, end) in &left_columns {
+                let end = (*end).min(displayed_left_len);
+                if end > *start {
+                    layout.record(name, area.y, area.x + *start as u16, area.x + end as u16);
                 }
+            }
+
+            // Where the right side indicators start
+            let right_start = if displayed_left_len + right_side_width < available_width {
+                available_width - right_side_width
             } else {
-                left_status.clone()
+                displayed_left_len + 1
             };
 
-            let displayed_left_len = str_width(&displayed_left);
-
-            // Track message area for click detection (if there's a message)
-            if message_width > 0 {
-                // The message starts after base_and_chord, but might be truncated
-                let msg_start = base_and_chord_width.min(displayed_left_len);
-                let msg_end = displayed_left_len;
-                if msg_end > msg_start {
-                    layout.message_area =
-                        Some((area.y, area.x + msg_start as u16, area.x + msg_end as u16));
+            // Center group goes in the middle, if it fits between the other two
+            let center_width = str_width(&center_status);
+            let mut current = displayed_left_len;
+            if center_width > 0 {
+                let center_start =
+                    (available_width.saturating_sub(center_width) / 2).max(displayed_left_len + 1);
+                if center_start + center_width < right_start {
+                    spans.push(Span::styled(
+                        " ".repeat(center_start - current),
+                        status_style,
+                    ));
+                    for (name, start, end) in &center_columns {
+                        layout.record(
+                            name,
+                            area.y,
+                            area.x + (center_start + start) as u16,
+                            area.x + (center_start + end) as u16,
+                        );
+                    }
+                    spans.push(Span::styled(center_status, status_style));
+                    current = center_start + center_width;
                 }
             }
 
-            spans.push(Span::styled(
-                displayed_left.clone(),
-                Style::default()
-                    .fg(theme.status_bar_fg)
-                    .bg(theme.status_bar_bg),
-            ));
-
             // Add spacing to push right side indicators to the right
-            if displayed_left_len + right_side_width < available_width {
-                let padding_len = available_width - displayed_left_len - right_side_width;
-                spans.push(Span::styled(
-                    " ".repeat(padding_len),
-                    Style::default()
-                        .fg(theme.status_bar_fg)
-                        .bg(theme.status_bar_bg),
-                ));
-            } else if displayed_left_len < available_width {
-                // Add minimal space
+            if current < available_width {
                 spans.push(Span::styled(
-                    " ",
-                    Style::default()
-                        .fg(theme.status_bar_fg)
-                        .bg(theme.status_bar_bg),
+                    " ".repeat(right_start.saturating_sub(current).max(1)),
+                    status_style,
                 ));
             }
 
             // Track current column for layout positions
-            let mut current_col = area.x + displayed_left_len as u16;
-            if displayed_left_len + right_side_width < available_width {
-                current_col = area.x + (available_width - right_side_width) as u16;
-            }
-
-            // Add line ending indicator (clickable to change format)
-            {
-                let is_hovering = hover == StatusBarHover::LineEndingIndicator;
-                // Record position for click detection
-                layout.line_ending_indicator =
-                    Some((area.y, current_col, current_col + line_ending_width as u16));
-                let (fg, bg) = if is_hovering {
-                    (theme.menu_hover_fg, theme.menu_hover_bg)
-                } else {
-                    (theme.status_bar_fg, theme.status_bar_bg)
-                };
-                let mut style = Style::default().fg(fg).bg(bg);
-                if is_hovering {
-                    style = style.add_modifier(Modifier::UNDERLINED);
-                }
-                spans.push(Span::styled(line_ending_text.clone(), style));
-                current_col += line_ending_width as u16;
-            }
-
-            // Add encoding indicator (clickable to change encoding)
-            {
-                let is_hovering = hover == StatusBarHover::EncodingIndicator;
-                // Record position for click detection
-                layout.encoding_indicator =
-                    Some((area.y, current_col, current_col + encoding_width as u16));
-                let (fg, bg) = if is_hovering {
-                    (theme.menu_hover_fg, theme.menu_hover_bg)
-                } else {
-                    (theme.status_bar_fg, theme.status_bar_bg)
-                };
-                let mut style = Style::default().fg(fg).bg(bg);
-                if is_hovering {
-                    style = style.add_modifier(Modifier::UNDERLINED);
-                }
-                spans.push(Span::styled(encoding_text.clone(), style));
-                current_col += encoding_width as u16;
-            }
-
-            // Add language indicator (clickable to change language)
-            {
-                let is_hovering = hover == StatusBarHover::LanguageIndicator;
-                // Record position for click detection
-                layout.language_indicator =
-                    Some((area.y, current_col, current_col + language_width as u16));
-                let (fg, bg) = if is_hovering {
-                    (theme.menu_hover_fg, theme.menu_hover_bg)
-                } else {
-                    (theme.status_bar_fg, theme.status_bar_bg)
-                };
-                let mut style = Style::default().fg(fg).bg(bg);
-                if is_hovering {
-                    style = style.add_modifier(Modifier::UNDERLINED);
-                }
-                spans.push(Span::styled(language_text.clone(), style));
-                current_col += language_width as u16;
-            }
-
-            // Add LSP indicator with colored background if warning/error
-            if !lsp_indicator.is_empty() {
-                let is_hovering = hover == StatusBarHover::LspIndicator;
-                let (lsp_fg, lsp_bg) = match (warning_level, is_hovering) {
-                    (WarningLevel::Error, true) => (
-                        theme.status_error_indicator_hover_fg,
-                        theme.status_error_indicator_hover_bg,
-                    ),
-                    (WarningLevel::Error, false) => (
-                        theme.status_error_indicator_fg,
-                        theme.status_error_indicator_bg,
-                    ),
-                    (WarningLevel::Warning, true) => (
-                        theme.status_warning_indicator_hover_fg,
-                        theme.status_warning_indicator_hover_bg,
-                    ),
-                    (WarningLevel::Warning, false) => (
-                        theme.status_warning_indicator_fg,
-                        theme.status_warning_indicator_bg,
-                    ),
-                    (WarningLevel::None, _) => (theme.status_bar_fg, theme.status_bar_bg),
-                };
-                // Record LSP indicator position for click detection
-                layout.lsp_indicator = Some((
-                    area.y,
-                    current_col,
-                    current_col + lsp_indicator_width as u16,
-                ));
-                current_col += lsp_indicator_width as u16;
-                let mut style = Style::default().fg(lsp_fg).bg(lsp_bg);
-                if is_hovering && warning_level != WarningLevel::None {
-                    style = style.add_modifier(Modifier::UNDERLINED);
-                }
-                spans.push(Span::styled(lsp_indicator.clone(), style));
-            }
-
-            // Add general warning badge if there are warnings
-            if !warning_badge.is_empty() {
-                let is_hovering = hover == StatusBarHover::WarningBadge;
-                // Record warning badge position for click detection
-                layout.warning_badge = Some((
-                    area.y,
-                    current_col,
-                    current_col + warning_badge_width as u16,
-                ));
-                current_col += warning_badge_width as u16;
-                let (fg, bg) = if is_hovering {
-                    (
-                        theme.status_warning_indicator_hover_fg,
-                        theme.status_warning_indicator_hover_bg,
-                    )
-                } else {
-                    (
-                        theme.status_warning_indicator_fg,
-                        theme.status_warning_indicator_bg,
-                    )
+            let mut current_col = area.x + right_start as u16;
+
+            for (name, text) in right_segments {
+                let width = str_width(&text) as u16;
+                layout.record(&name, area.y, current_col, current_col + width);
+                current_col += width;
+
+                let hover_target = match name.as_str() {
+                    "line_ending" => Some(StatusBarHover::LineEndingIndicator),
+                    "encoding" => Some(StatusBarHover::EncodingIndicator),
+                    "language" => Some(StatusBarHover::LanguageIndicator),
+                    "lsp_status" => Some(StatusBarHover::LspIndicator),
+                    "warnings" => Some(StatusBarHover::WarningBadge),
+                    _ => None,
                 };
-                let mut style = Style::default().fg(fg).bg(bg);
-                if is_hovering {
-                    style = style.add_modifier(Modifier::UNDERLINED);
-                }
-                spans.push(Span::styled(warning_badge.clone(), style));
-            }
-            // Keep current_col in scope to avoid unused warning
-            let _ = current_col;
-
-            // Add update indicator if available (with highlighted styling)
-            if let Some(ref update_text) = update_indicator {
-                spans.push(Span::styled(
-                    update_text.clone(),
-                    Style::default()
+                let is_hovering = hover_target.is_some_and(|target| hover == target);
+
+                let style = match name.as_str() {
+                    // LSP indicator with colored background if warning/error
+                    "lsp_status" => {
+                        let (lsp_fg, lsp_bg) = match (warning_level, is_hovering) {
+                            (WarningLevel::Error, true) => (
+                                theme.status_error_indicator_hover_fg,
+                                theme.status_error_indicator_hover_bg,
+                            ),
+                            (WarningLevel::Error, false) => (
+                                theme.status_error_indicator_fg,
+                                theme.status_error_indicator_bg,
+                            ),
+                            (WarningLevel::Warning, true) => (
+                                theme.status_warning_indicator_hover_fg,
+                                theme.status_warning_indicator_hover_bg,
+                            ),
+                            (WarningLevel::Warning, false) => (
+                                theme.status_warning_indicator_fg,
+                                theme.status_warning_indicator_bg,
+                            ),
+                            (WarningLevel::None, _) => (theme.status_bar_fg, theme.status_bar_bg),
+                        };
+                        let mut style = Style::default().fg(lsp_fg).bg(lsp_bg);
+                        if is_hovering && warning_level != WarningLevel::None {
+                            style = style.add_modifier(Modifier::UNDERLINED);
+                        }
+                        style
+                    }
+                    // General warning badge
+                    "warnings" => {
+                        let (fg, bg) = if is_hovering {
+                            (
+                                theme.status_warning_indicator_hover_fg,
+                                theme.status_warning_indicator_hover_bg,
+                            )
+                        } else {
+                            (
+                                theme.status_warning_indicator_fg,
+                                theme.status_warning_indicator_bg,
+                            )
+                        };
+                        let mut style = Style::default().fg(fg).bg(bg);
+                        if is_hovering {
+                            style = style.add_modifier(Modifier::UNDERLINED);
+                        }
+                        style
+                    }
+                    // Update indicator (with highlighted styling)
+                    "update" => Style::default()
                         .fg(theme.menu_highlight_fg)
                         .bg(theme.menu_dropdown_bg),
-                ));
+                    // Command palette indicator with distinct styling
+                    "palette" => Style::default()
+                        .fg(theme.help_indicator_fg)
+                        .bg(theme.help_indicator_bg),
+                    // Clickable indicators (line ending, encoding, language)
+                    _ if is_hovering => Style::default()
+                        .fg(theme.menu_hover_fg)
+                        .bg(theme.menu_hover_bg)
+                        .add_modifier(Modifier::UNDERLINED),
+                    _ => status_style,
+                };
+                spans.push(Span::styled(text, style));
             }
 
-            // Add command palette indicator with distinct styling and padding
-            spans.push(Span::styled(
-                padded_cmd_palette.clone(),
-                Style::default()
-                    .fg(theme.help_indicator_fg)
-                    .bg(theme.help_indicator_bg),
-            ));
-
             spans
         } else {
-            // Terminal too narrow or no command palette indicator - fill entire width with left status
-            let mut spans = vec![];
-            let left_visual_width = str_width(&left_status);
-            let displayed_left = if left_visual_width > available_width {
-                let truncate_at = available_width.saturating_sub(3);
-                if truncate_at > 0 {
-                    // Take characters up to visual width limit
-                    let mut width = 0;
-                    let truncated: String = left_status
-                        .chars()
-                        .take_while(|ch| {
-                            let w = char_width(*ch);
-                            if width + w <= truncate_at {
-                                width += w;
-                                true
-                            } else {
-                                false
-                            }
-                        })
-                        .collect();
-                    format!("{}...", truncated)
-                } else {
-                    // Take characters up to available width
-                    let mut width = 0;
-                    left_status
-                        .chars()
-                        .take_while(|ch| {
-                            let w = char_width(*ch);
-                            if width + w <= available_width {
-                                width += w;
-                                true
-                            } else {
-                                false
-                            }
-                        })
-                        .collect()
-                }
-            } else {
-                left_status.clone()
-            };
-
-            spans.push(Span::styled(
-                displayed_left.clone(),
-                Style::default()
-                    .fg(theme.status_bar_fg)
-                    .bg(theme.status_bar_bg),
-            ));
+            // Terminal too narrow - fill entire width with left status
+            let displayed_left = truncate_status_text(&left_status, available_width);
+            let displayed_left_len = str_width(&displayed_left);
+            let mut spans = vec![Span::styled(displayed_left, status_style)];
 
             // Fill remaining width
-            if displayed_left.len() < available_width {
+            if displayed_left_len < available_width {
                 spans.push(Span::styled(
-                    " ".repeat(available_width - displayed_left.len()),
-                    Style::default()
-                        .fg(theme.status_bar_fg)
-                        .bg(theme.status_bar_bg),
+                    " ".repeat(available_width - displayed_left_len),
+                    status_style,
                 ));
             }
 
             spans
         };
 
+        // Only plugin segments that are shown keep their click areas
+        layout
+            .plugin_segments
+            .retain(|(name, ..)| segments.plugin_segments.contains_key(name));
+
         let status_line = Paragraph::new(Line::from(spans));
 
         frame.render_widget(status_line, area);
@@ -991,6 +1070,7 @@ impl StatusBarRenderer {
     /// - Whole Word (Alt+W)
     /// - Regex (Alt+R)
     /// - Confirm Each (Alt+I) - only shown in replace mode
+    /// - Preserve Case (Alt+P) - only shown in replace mode
     ///
     /// # Returns
     /// Layout information for hit testing mouse clicks on checkboxes
@@ -1002,6 +1082,7 @@ impl StatusBarRenderer {
         whole_word: bool,
         use_regex: bool,
         confirm_each: Option<bool>, // None = don't show, Some(value) = show with this state
+        preserve_case: Option<bool>, // Same as confirm_each
         theme: &crate::view::theme::Theme,
         keybindings: &crate::input::keybindings::KeybindingResolver,
         hover: SearchOptionsHover,
@@ -1212,6 +1293,44 @@ impl StatusBarRenderer {
             layout.confirm_each = Some((confirm_start, current_col));
         }
 
+        // Preserve Case option (only shown in replace mode)
+        if let Some(preserve_value) = preserve_case {
+            let preserve_shortcut =
+                get_shortcut(&crate::input::keybindings::Action::ToggleSearchPreserveCase);
+            let preserve_checkbox = if preserve_value { "[x]" } else { "[ ]" };
+
+            // Separator
+            spans.push(Span::styled("   ", base_style));
+            current_col += 3;
+
+            let preserve_hovered = hover == SearchOptionsHover::PreserveCase;
+            let preserve_start = current_col;
+            let preserve_label = format!("{} {}", preserve_checkbox, t!("search.preserve_case"));
+            let preserve_shortcut_text = preserve_shortcut
+                .as_ref()
+                .map(|s| format!(" ({})", s))
+                .unwrap_or_default();
+            let preserve_full_width =
+                str_width(&preserve_label) + str_width(&preserve_shortcut_text);
+
+            spans.push(Span::styled(
+                preserve_label,
+                get_checkbox_style(preserve_hovered, preserve_value),
+            ));
+            if !preserve_shortcut_text.is_empty() {
+                spans.push(Span::styled(
+                    preserve_shortcut_text,
+                    if preserve_hovered {
+                        hover_shortcut_style
+                    } else {
+                        shortcut_style
+                    },
+                ));
+            }
+            current_col += preserve_full_width as u16;
+            layout.preserve_case = Some((preserve_start, current_col));
+        }
+
         // Fill remaining space
         let current_width = (current_col - area.x) as usize;
         let available_width = area.width as usize;
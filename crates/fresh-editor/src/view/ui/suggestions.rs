@@ -256,12 +256,13 @@ impl SuggestionsRenderer {
                     } else {
                         keybinding.clone()
                     };
-                    spans.push(Span::styled(kb_text.clone(), keybinding_style));
+                    // Right-align the keybinding within its column
                     let kb_display_width = str_width(&kb_text);
                     let kb_padding = keybinding_column_width.saturating_sub(kb_display_width);
                     if kb_padding > 0 {
                         spans.push(Span::styled(" ".repeat(kb_padding), base_style));
                     }
+                    spans.push(Span::styled(kb_text, keybinding_style));
                 } else {
                     // No keybinding for this command, pad the column
                     spans.push(Span::styled(
@@ -10,7 +10,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Paragraph};
 use ratatui::Frame;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Hit area for a single tab
 #[derive(Debug, Clone)]
@@ -219,14 +219,14 @@ pub fn calculate_tab_widths(
             ""
         };
 
-        let binary_indicator = if buffer_metadata.get(id).map(|m| m.binary).unwrap_or(false) {
-            " [BIN]"
-        } else {
-            ""
+        let flag_indicator = match buffer_metadata.get(id) {
+            Some(m) if m.binary => " [BIN]",
+            Some(m) if m.read_only && !m.is_virtual() => " [RO]",
+            _ => "",
         };
 
-        // Same format as render_for_split: " {name}{modified}{binary_indicator} " + "× "
-        let tab_name_text = format!(" {name}{modified}{binary_indicator} ");
+        // Same format as render_for_split: " {name}{modified}{flag_indicator} " + "× "
+        let tab_name_text = format!(" {name}{modified}{flag_indicator} ");
         let close_text = "× ";
         let tab_width = str_width(&tab_name_text) + str_width(close_text);
 
@@ -255,6 +255,7 @@ impl TabsRenderer {
     /// * `theme` - The active theme for colors
     /// * `is_active_split` - Whether this split is the active one
     /// * `hovered_tab` - Optional (buffer_id, is_close_button) if a tab is being hovered
+    /// * `pinned_buffers` - Pinned tabs, drawn with a pin marker instead of a close button
     ///
     /// # Returns
     /// `TabLayout` containing hit areas for mouse interaction.
@@ -271,6 +272,7 @@ impl TabsRenderer {
         is_active_split: bool,
         tab_scroll_offset: usize,
         hovered_tab: Option<(BufferId, bool)>, // (buffer_id, is_close_button)
+        pinned_buffers: &HashSet<BufferId>,
     ) -> TabLayout {
         let mut layout = TabLayout::new(area);
         const SCROLL_INDICATOR_LEFT: &str = "<";
@@ -333,10 +335,10 @@ impl TabsRenderer {
             } else {
                 ""
             };
-            let binary_indicator = if buffer_metadata.get(id).map(|m| m.binary).unwrap_or(false) {
-                " [BIN]"
-            } else {
-                ""
+            let flag_indicator = match buffer_metadata.get(id) {
+                Some(m) if m.binary => " [BIN]",
+                Some(m) if m.read_only && !m.is_virtual() => " [RO]",
+                _ => "",
             };
 
             let is_active = *id == active_buffer;
@@ -379,12 +381,16 @@ impl TabsRenderer {
                 base_style
             };
 
-            // Build tab content: " {name}{modified}{binary_indicator} "
-            let tab_name_text = format!(" {name}{modified}{binary_indicator} ");
+            // Build tab content: " {name}{modified}{flag_indicator} "
+            let tab_name_text = format!(" {name}{modified}{flag_indicator} ");
             let tab_name_width = str_width(&tab_name_text);
 
-            // Close button: "× "
-            let close_text = "× ";
+            // Close button: "× ", or a pin marker for pinned tabs
+            let close_text = if pinned_buffers.contains(id) {
+                "• "
+            } else {
+                "× "
+            };
             let close_width = str_width(close_text);
 
             let total_width = tab_name_width + close_width;
@@ -648,6 +654,7 @@ impl TabsRenderer {
             true, // Legacy behavior: always treat as active
             0,    // Default tab_scroll_offset for legacy render
             None, // No hover state for legacy render
+            &HashSet::new(),
         );
     }
 }
@@ -0,0 +1,149 @@
+//! Mouse hover tooltip rendering
+//!
+//! A tooltip is a small bordered box of plain text drawn next to the mouse
+//! pointer, on top of everything else. It is placed below the pointer when it
+//! fits and above it otherwise, and is shifted left to stay on screen.
+
+use crate::primitives::display_width::{char_width, str_width};
+use crate::view::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Widest a tooltip gets, including its border
+const MAX_TOOLTIP_WIDTH: u16 = 80;
+
+/// Most text lines shown; longer content ends with "…"
+const MAX_TOOLTIP_LINES: usize = 15;
+
+/// A tooltip shown for the cell under the mouse pointer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tooltip {
+    /// Screen cell the pointer rested on
+    pub anchor: (u16, u16),
+    /// Text lines, wrapped to fit when rendered
+    pub lines: Vec<String>,
+}
+
+/// Render `tooltip` within `screen`
+pub fn render_tooltip(frame: &mut Frame, screen: Rect, tooltip: &Tooltip, theme: &Theme) {
+    let max_text_width = MAX_TOOLTIP_WIDTH.min(screen.width).saturating_sub(2) as usize;
+    if max_text_width == 0 {
+        return;
+    }
+    let mut lines = wrap_lines(&tooltip.lines, max_text_width);
+    if lines.len() > MAX_TOOLTIP_LINES {
+        lines.truncate(MAX_TOOLTIP_LINES);
+        lines.push("…".to_string());
+    }
+    let text_width = lines.iter().map(|l| str_width(l)).max().unwrap_or(0);
+
+    let area = tooltip_area(
+        tooltip.anchor,
+        text_width as u16 + 2,
+        lines.len() as u16 + 2,
+        screen,
+    );
+    if area.width < 3 || area.height < 3 {
+        return;
+    }
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.popup_border_fg))
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_text_fg));
+    let lines: Vec<Line> = lines.into_iter().map(Line::from).collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Where a `width` x `height` tooltip for the pointer at `anchor` goes:
+/// below the pointer if it fits, else above it, shifted to stay in `screen`
+fn tooltip_area(anchor: (u16, u16), width: u16, height: u16, screen: Rect) -> Rect {
+    let width = width.min(screen.width);
+    let height = height.min(screen.height);
+    let right = screen.x + screen.width;
+    let bottom = screen.y + screen.height;
+    let (col, row) = anchor;
+
+    let x = col.min(right.saturating_sub(width)).max(screen.x);
+    let y = if row + 1 + height <= bottom {
+        row + 1
+    } else if row >= screen.y + height {
+        row - height
+    } else {
+        bottom.saturating_sub(height)
+    };
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+/// Break each line into pieces at most `width` columns wide
+fn wrap_lines(lines: &[String], width: usize) -> Vec<String> {
+    let mut wrapped = Vec::new();
+    for line in lines {
+        let mut current = String::new();
+        let mut current_width = 0;
+        for ch in line.chars() {
+            let w = char_width(ch);
+            if current_width + w > width && !current.is_empty() {
+                wrapped.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+            current.push(ch);
+            current_width += w;
+        }
+        wrapped.push(current);
+    }
+    wrapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCREEN: Rect = Rect {
+        x: 0,
+        y: 0,
+        width: 80,
+        height: 24,
+    };
+
+    #[test]
+    fn test_tooltip_area_clamps_to_screen() {
+        // Below and starting at the pointer when there is room
+        assert_eq!(
+            tooltip_area((10, 5), 20, 4, SCREEN),
+            Rect::new(10, 6, 20, 4)
+        );
+        // Shifted left at the right edge
+        assert_eq!(
+            tooltip_area((75, 5), 20, 4, SCREEN),
+            Rect::new(60, 6, 20, 4)
+        );
+        // Above the pointer at the bottom edge
+        assert_eq!(
+            tooltip_area((10, 22), 20, 4, SCREEN),
+            Rect::new(10, 18, 20, 4)
+        );
+        // Never larger than the screen
+        assert_eq!(
+            tooltip_area((10, 5), 100, 30, SCREEN),
+            Rect::new(0, 0, 80, 24)
+        );
+    }
+
+    #[test]
+    fn test_wrap_lines() {
+        let lines = vec!["abcdefgh".to_string(), String::new(), "ab".to_string()];
+        assert_eq!(wrap_lines(&lines, 3), vec!["abc", "def", "gh", "", "ab"]);
+    }
+}
@@ -31,6 +31,13 @@ pub struct Viewport {
     /// Horizontal scroll offset (columns to keep visible left/right of cursor)
     pub horizontal_scroll_offset: usize,
 
+    /// Whether the last line may scroll up to the top of the viewport,
+    /// leaving empty rows below it
+    pub scroll_past_end: bool,
+
+    /// Whether the cursor line is kept vertically centered
+    pub cursor_centering: bool,
+
     /// Whether line wrapping is enabled
     /// When true, horizontal scrolling is disabled
     pub line_wrap_enabled: bool,
@@ -72,6 +79,8 @@ impl Viewport {
             height,
             scroll_offset: 3,
             horizontal_scroll_offset: 5,
+            scroll_past_end: false,
+            cursor_centering: false,
             line_wrap_enabled: false,
             needs_sync: false,
             skip_resize_sync: false,
@@ -140,6 +149,27 @@ impl Viewport {
         self.height as usize
     }
 
+    /// Rows of content that must remain below the top of the viewport when
+    /// scrolling down: a full viewport, or just the last line when scrolling
+    /// past the end is allowed
+    fn rows_kept_filled(&self) -> usize {
+        if self.scroll_past_end {
+            self.visible_line_count().min(1)
+        } else {
+            self.visible_line_count()
+        }
+    }
+
+    /// Rows kept between the cursor and the top/bottom edge
+    fn effective_scroll_offset(&self, viewport_lines: usize) -> usize {
+        if self.cursor_centering {
+            // Leaves the middle row (or the middle two, for an even height)
+            viewport_lines.saturating_sub(1) / 2
+        } else {
+            self.scroll_offset.min(viewport_lines / 2)
+        }
+    }
+
     /// Calculate the gutter width based on buffer length
     /// Format: "[indicator]{:>N} │ " where N is the number of digits for line numbers
     /// - Indicator column: 1 char (space, or symbols like ●/✗/⚠)
@@ -361,7 +391,7 @@ impl Viewport {
     /// This ensures the viewport is always filled with content when possible.
     /// Returns true if position was adjusted, false if no adjustment needed.
     fn apply_visual_scroll_limit(&mut self, buffer: &mut Buffer, wrap_config: &WrapConfig) {
-        let viewport_height = self.visible_line_count();
+        let viewport_height = self.rows_kept_filled();
         if viewport_height == 0 {
             return;
         }
@@ -480,7 +510,7 @@ impl Viewport {
         };
 
         // Apply scroll limit: don't scroll past the point where viewport can't be filled
-        let max_top_idx = view_lines.len().saturating_sub(viewport_height);
+        let max_top_idx = view_lines.len().saturating_sub(self.rows_kept_filled());
         let clamped_idx = target_idx.min(max_top_idx);
 
         // Get the source byte for the target view line
@@ -620,7 +650,7 @@ impl Viewport {
             };
 
             // Apply scroll limit
-            let max_top = view_lines.len().saturating_sub(viewport_height);
+            let max_top = view_lines.len().saturating_sub(self.rows_kept_filled());
             let new_offset = target_top.min(max_top);
 
             tracing::trace!(
@@ -787,7 +817,7 @@ impl Viewport {
             proposed_top_byte
         );
 
-        let viewport_height = self.visible_line_count();
+        let viewport_height = self.rows_kept_filled();
         if viewport_height == 0 {
             self.top_byte = proposed_top_byte;
             return;
@@ -803,6 +833,12 @@ impl Viewport {
         // If we can't reach viewport_height lines before hitting EOF,
         // then we need to adjust backward
         let mut iter = buffer.line_iterator(proposed_top_byte, 80);
+        // The end of a file without a trailing newline is inside its last line
+        let proposed_top_byte = if proposed_top_byte >= buffer_len {
+            iter.current_position()
+        } else {
+            proposed_top_byte
+        };
         let mut lines_visible = 0;
 
         while let Some((_, _)) = iter.next_line() {
@@ -916,6 +952,40 @@ impl Viewport {
         true
     }
 
+    /// Scroll so the cursor's line sits in the middle of the viewport.
+    ///
+    /// `hidden_ranges` are collapsed fold regions, which take no rows.
+    pub(crate) fn center_on_cursor(
+        &mut self,
+        buffer: &mut Buffer,
+        cursor: &Cursor,
+        hidden_ranges: &[(usize, usize)],
+    ) {
+        let target_rows_from_top = self.visible_line_count() / 2;
+
+        let mut iter = buffer.line_iterator(cursor.position, 80);
+        let mut visible_counted = 0;
+        while visible_counted < target_rows_from_top {
+            if iter.prev().is_none() {
+                break; // Hit beginning of buffer
+            }
+            while let Some((start, _end)) =
+                Self::containing_hidden_range(hidden_ranges, iter.current_position())
+            {
+                while iter.current_position() >= start {
+                    if iter.prev().is_none() {
+                        break;
+                    }
+                }
+            }
+            visible_counted += 1;
+        }
+
+        let new_top_byte = iter.current_position();
+        self.set_top_byte_with_limit(buffer, new_top_byte);
+        self.top_view_line_offset = 0;
+    }
+
     /// Mark viewport as needing synchronization with cursor positions
     /// This defers the actual viewport update until sync_with_cursor is called
     pub fn mark_needs_sync(&mut self) {
@@ -1016,7 +1086,7 @@ impl Viewport {
         // Check if cursor is visible by counting VISUAL ROWS between top_byte and cursor
         // When line wrapping is enabled, we need to count wrapped rows, not logical lines!
         // Apply scroll_offset to keep cursor away from edges
-        let effective_offset = self.scroll_offset.min(viewport_lines / 2);
+        let effective_offset = self.effective_scroll_offset(viewport_lines);
 
         // Track whether cursor needs scrolling and in which direction:
         // true = cursor is near/above the top edge, false = near/below the bottom edge
@@ -1330,7 +1400,7 @@ impl Viewport {
 
         // If not visible, scroll to show it with scroll offset
         if !target_is_visible {
-            let effective_offset = self.scroll_offset.min(visible_count / 2);
+            let effective_offset = self.effective_scroll_offset(visible_count);
             let target_line_from_top = effective_offset;
 
             // Move backwards from target to find new top_byte
@@ -1769,4 +1839,50 @@ mod tests {
             vp.left_column
         );
     }
+
+    fn numbered_lines(count: usize) -> String {
+        (0..count)
+            .map(|i| format!("line{}", i))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_scroll_past_end() {
+        let mut buffer = Buffer::from_str_test(&numbered_lines(30));
+        let last_line = buffer.line_start_offset(29).unwrap();
+        let mut vp = Viewport::new(80, 10);
+
+        // By default the last line stays at the bottom edge
+        vp.scroll_down(&mut buffer, 100);
+        assert_eq!(vp.top_byte, buffer.line_start_offset(20).unwrap());
+
+        // With scroll past end it can reach the top
+        vp.scroll_past_end = true;
+        vp.scroll_down(&mut buffer, 100);
+        assert_eq!(vp.top_byte, last_line);
+    }
+
+    #[test]
+    fn test_cursor_centering_skips_folded_lines() {
+        let mut buffer = Buffer::from_str_test(&numbered_lines(60));
+        let mut vp = Viewport::new(80, 11);
+        vp.cursor_centering = true;
+
+        // Lines 21..=30 are folded away
+        let hidden = [(
+            buffer.line_start_offset(21).unwrap(),
+            buffer.line_start_offset(31).unwrap(),
+        )];
+        let cursor = Cursor::new(buffer.line_start_offset(35).unwrap());
+        vp.ensure_visible(&mut buffer, &cursor, &hidden);
+
+        // Five visible lines above the cursor: 34, 33, 32, 31 and the fold header 20
+        assert_eq!(vp.top_byte, buffer.line_start_offset(20).unwrap());
+
+        // Recentering gives the same result
+        vp.top_byte = 0;
+        vp.center_on_cursor(&mut buffer, &cursor, &hidden);
+        assert_eq!(vp.top_byte, buffer.line_start_offset(20).unwrap());
+    }
 }
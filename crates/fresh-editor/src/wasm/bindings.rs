@@ -0,0 +1,182 @@
+//! JavaScript API for embedding the editor in a page
+//!
+//! A thin wasm-bindgen layer over `HostEditor`. The host sizes the editor in
+//! cells, forwards keyboard and mouse events, and paints the cell grid returned
+//! by `render()`. TypeScript declarations live in `fresh.d.ts` next to this
+//! file.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+use super::host::{key_from_dom, mouse_from_dom, HostEditor};
+
+/// Debounce for change callbacks when the host doesn't pick one
+const DEFAULT_DEBOUNCE_MS: i32 = 250;
+
+fn to_js(value: &impl Serialize) -> Result<JsValue, JsValue> {
+    let json = serde_json::to_string(value).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    js_sys::JSON::parse(&json)
+}
+
+/// A change callback and its pending debounce timer
+struct Subscription {
+    flush: Closure<dyn FnMut()>,
+    debounce_ms: i32,
+    timer: Option<i32>,
+}
+
+/// An embedded editor instance
+#[wasm_bindgen]
+pub struct FreshEditor {
+    editor: Rc<RefCell<HostEditor>>,
+    subscription: RefCell<Option<Subscription>>,
+}
+
+#[wasm_bindgen]
+impl FreshEditor {
+    /// Create an editor `cols` x `rows` cells in size, e.g. the container's
+    /// pixel size divided by the host font's cell size
+    pub fn create(cols: u16, rows: u16) -> FreshEditor {
+        FreshEditor {
+            editor: Rc::new(RefCell::new(HostEditor::new(cols, rows))),
+            subscription: RefCell::new(None),
+        }
+    }
+
+    /// Replace the document; `language` is a grammar name or extension
+    #[wasm_bindgen(js_name = loadDocument)]
+    pub fn load_document(&self, name: &str, text: &str, language: Option<String>) {
+        self.editor
+            .borrow_mut()
+            .load_document(name, text, language.as_deref());
+    }
+
+    #[wasm_bindgen(js_name = getText)]
+    pub fn get_text(&self) -> String {
+        self.editor.borrow().text()
+    }
+
+    /// Call `callback` with a change payload once edits pause for
+    /// `debounce_ms`. With `full` the payload carries the whole text instead of
+    /// the edits. Replaces any previous callback.
+    #[wasm_bindgen(js_name = onChange)]
+    pub fn on_change(
+        &self,
+        callback: js_sys::Function,
+        debounce_ms: Option<i32>,
+        full: Option<bool>,
+    ) {
+        self.off_change();
+        let editor: Weak<RefCell<HostEditor>> = Rc::downgrade(&self.editor);
+        let full = full.unwrap_or(false);
+        let flush = Closure::<dyn FnMut()>::new(move || {
+            let Some(editor) = editor.upgrade() else {
+                return;
+            };
+            // Release the editor before calling out, the callback may read it
+            let change = editor.borrow_mut().take_change(full);
+            let Some(change) = change else {
+                return;
+            };
+            let result =
+                to_js(&change).and_then(|payload| callback.call1(&JsValue::NULL, &payload));
+            if let Err(e) = result {
+                tracing::warn!("Change callback failed: {:?}", e);
+            }
+        });
+        *self.subscription.borrow_mut() = Some(Subscription {
+            flush,
+            debounce_ms: debounce_ms.unwrap_or(DEFAULT_DEBOUNCE_MS).max(0),
+            timer: None,
+        });
+    }
+
+    /// Stop reporting changes
+    #[wasm_bindgen(js_name = offChange)]
+    pub fn off_change(&self) {
+        if let Some(subscription) = self.subscription.borrow_mut().take() {
+            if let (Some(timer), Some(window)) = (subscription.timer, web_sys::window()) {
+                window.clear_timeout_with_handle(timer);
+            }
+        }
+    }
+
+    /// Switch to a built-in theme ("dark", "light", "dracula", ...)
+    #[wasm_bindgen(js_name = setTheme)]
+    pub fn set_theme(&self, name: &str) -> Result<(), JsValue> {
+        self.editor
+            .borrow_mut()
+            .set_theme(name)
+            .map_err(|e| JsValue::from_str(&e))
+    }
+
+    pub fn resize(&self, cols: u16, rows: u16) {
+        self.editor.borrow_mut().resize(cols, rows);
+    }
+
+    /// Forward a `keydown` event. Returns true when the editor used the key,
+    /// so the host should call `preventDefault()`.
+    #[wasm_bindgen(js_name = dispatchKey)]
+    pub fn dispatch_key(&self, key: &str, ctrl: bool, alt: bool, shift: bool, meta: bool) -> bool {
+        let Some(event) = key_from_dom(key, ctrl, alt, shift, meta) else {
+            return false;
+        };
+        let (handled, edited) = {
+            let mut editor = self.editor.borrow_mut();
+            let version = editor.version();
+            let handled = editor.handle_key(event);
+            (handled, editor.version() != version)
+        };
+        if edited {
+            self.schedule_change();
+        }
+        handled
+    }
+
+    /// Forward a mouse event at a grid cell. `kind` is "down", "drag", "up",
+    /// "wheel_up", "wheel_down", "wheel_left" or "wheel_right"; `button` is
+    /// the DOM `MouseEvent.button`.
+    #[wasm_bindgen(js_name = dispatchMouse)]
+    pub fn dispatch_mouse(&self, kind: &str, col: u16, row: u16, button: i16) -> bool {
+        match mouse_from_dom(kind, button) {
+            Some(kind) => self.editor.borrow_mut().handle_mouse(kind, col, row),
+            None => false,
+        }
+    }
+
+    /// The visible area as `cols * rows` cells, row by row
+    pub fn render(&self) -> Result<JsValue, JsValue> {
+        let cells = self.editor.borrow_mut().render();
+        to_js(&cells)
+    }
+}
+
+impl FreshEditor {
+    /// (Re)start the debounce timer of the change callback
+    fn schedule_change(&self) {
+        let mut subscription = self.subscription.borrow_mut();
+        let (Some(subscription), Some(window)) = (subscription.as_mut(), web_sys::window()) else {
+            return;
+        };
+        if let Some(timer) = subscription.timer.take() {
+            window.clear_timeout_with_handle(timer);
+        }
+        match window.set_timeout_with_callback_and_timeout_and_arguments_0(
+            subscription.flush.as_ref().unchecked_ref(),
+            subscription.debounce_ms,
+        ) {
+            Ok(timer) => subscription.timer = Some(timer),
+            Err(e) => tracing::warn!("Failed to schedule change callback: {:?}", e),
+        }
+    }
+}
+
+impl Drop for FreshEditor {
+    fn drop(&mut self) {
+        self.off_change();
+    }
+}
@@ -0,0 +1,256 @@
+//! Browser hooks exposed to JavaScript
+//!
+//! - `BrowserFileSystem` wraps the editor's `VirtualFileSystem`. Pages import
+//!   files picked through the File System Access API with `importFile` and
+//!   write them back with the bytes from `exportFile`. `load` and `persist`
+//!   mirror the files into IndexedDB so they survive a reload.
+//! - `copyToClipboard` / `readClipboard` bridge to `navigator.clipboard`.
+//! - The session config round-trips through `localStorage`.
+
+use std::path::Path;
+
+use js_sys::{Array, Promise, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+use web_sys::{IdbDatabase, IdbRequest, IdbTransactionMode};
+
+use crate::model::filesystem::FileSystem;
+use crate::model::virtual_filesystem::VirtualFileSystem;
+use crate::partial_config::SessionConfig;
+
+/// IndexedDB object store holding file contents keyed by path
+const FILES_STORE: &str = "files";
+
+/// localStorage key for the session config
+const SESSION_KEY: &str = "fresh.session";
+
+fn window() -> Result<web_sys::Window, JsValue> {
+    web_sys::window().ok_or_else(|| JsValue::from_str("no window object"))
+}
+
+fn to_js_error(error: impl std::fmt::Display) -> JsValue {
+    js_sys::Error::new(&error.to_string()).into()
+}
+
+/// Resolve once an IndexedDB request succeeds or fails
+fn request_future(request: &IdbRequest) -> JsFuture {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let success_request = request.clone();
+        let on_success = Closure::once_into_js(move || {
+            let result = success_request.result().unwrap_or(JsValue::UNDEFINED);
+            if let Err(e) = resolve.call1(&JsValue::NULL, &result) {
+                tracing::warn!("IndexedDB resolve failed: {:?}", e);
+            }
+        });
+        let error_request = request.clone();
+        let on_error = Closure::once_into_js(move || {
+            let error = error_request
+                .error()
+                .ok()
+                .flatten()
+                .map(JsValue::from)
+                .unwrap_or(JsValue::UNDEFINED);
+            if let Err(e) = reject.call1(&JsValue::NULL, &error) {
+                tracing::warn!("IndexedDB reject failed: {:?}", e);
+            }
+        });
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+    JsFuture::from(promise)
+}
+
+/// Open (and on first use create) the database holding persisted files
+async fn open_database(name: &str) -> Result<IdbDatabase, JsValue> {
+    let factory = window()?
+        .indexed_db()?
+        .ok_or_else(|| JsValue::from_str("IndexedDB is not available"))?;
+    let request = factory.open_with_u32(name, 1)?;
+
+    let upgrade_request = request.clone();
+    let on_upgrade = Closure::once_into_js(move || {
+        if let Ok(result) = upgrade_request.result() {
+            let db: IdbDatabase = result.unchecked_into();
+            if let Err(e) = db.create_object_store(FILES_STORE) {
+                tracing::warn!("Failed to create IndexedDB store: {:?}", e);
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade.unchecked_ref()));
+
+    Ok(request_future(&request).await?.unchecked_into())
+}
+
+async fn load_files(fs: &VirtualFileSystem, db_name: &str) -> Result<(), JsValue> {
+    let db = open_database(db_name).await?;
+    let store = db
+        .transaction_with_str(FILES_STORE)?
+        .object_store(FILES_STORE)?;
+    // Issue both requests before awaiting so the transaction stays active
+    let keys_request = store.get_all_keys()?;
+    let values_request = store.get_all()?;
+    let keys = Array::from(&request_future(&keys_request).await?);
+    let values = Array::from(&request_future(&values_request).await?);
+    db.close();
+
+    for (key, value) in keys.iter().zip(values.iter()) {
+        if let Some(path) = key.as_string() {
+            fs.insert_file(Path::new(&path), Uint8Array::new(&value).to_vec());
+        }
+    }
+    // What was just loaded is already persisted
+    fs.take_changes();
+    Ok(())
+}
+
+async fn persist_changes(fs: &VirtualFileSystem, db_name: &str) -> Result<u32, JsValue> {
+    let changes = fs.take_changes();
+    if changes.is_empty() {
+        return Ok(0);
+    }
+    let db = open_database(db_name).await?;
+    let store = db
+        .transaction_with_str_and_mode(FILES_STORE, IdbTransactionMode::Readwrite)?
+        .object_store(FILES_STORE)?;
+
+    let mut last_request = None;
+    for (path, data) in &changes {
+        let key = JsValue::from_str(&path.to_string_lossy());
+        let request = match data {
+            Some(data) => store.put_with_key(&Uint8Array::from(data.as_slice()), &key)?,
+            None => store.delete(&key)?,
+        };
+        last_request = Some(request);
+    }
+    // Requests in a transaction complete in order
+    if let Some(request) = last_request {
+        request_future(&request).await?;
+    }
+    db.close();
+    Ok(changes.len() as u32)
+}
+
+/// The editor's virtual filesystem, as seen from JavaScript
+#[wasm_bindgen]
+pub struct BrowserFileSystem {
+    fs: VirtualFileSystem,
+}
+
+#[wasm_bindgen]
+impl BrowserFileSystem {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        let fs = VirtualFileSystem::new();
+        fs.track_changes();
+        Self { fs }
+    }
+
+    /// Add or replace a file, e.g. one read from a `FileSystemFileHandle`
+    #[wasm_bindgen(js_name = importFile)]
+    pub fn import_file(&self, path: &str, data: &[u8]) {
+        self.fs.insert_file(Path::new(path), data.to_vec());
+    }
+
+    /// Contents of a file, e.g. to write through a `FileSystemWritableFileStream`
+    #[wasm_bindgen(js_name = exportFile)]
+    pub fn export_file(&self, path: &str) -> Result<Vec<u8>, JsValue> {
+        self.fs.read_file(Path::new(path)).map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = removeFile)]
+    pub fn remove_file(&self, path: &str) -> Result<(), JsValue> {
+        self.fs.remove_file(Path::new(path)).map_err(to_js_error)
+    }
+
+    /// Paths of all files, sorted
+    #[wasm_bindgen(js_name = listFiles)]
+    pub fn list_files(&self) -> Vec<String> {
+        self.fs
+            .file_paths()
+            .into_iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    /// Restore the files persisted in the IndexedDB database `db_name`
+    pub fn load(&self, db_name: String) -> Promise {
+        let fs = self.fs.clone();
+        future_to_promise(async move {
+            load_files(&fs, &db_name).await?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Write files changed since the last `load` or `persist` to the IndexedDB
+    /// database `db_name`, resolving to how many paths were updated
+    pub fn persist(&self, db_name: String) -> Promise {
+        let fs = self.fs.clone();
+        future_to_promise(async move {
+            let count = persist_changes(&fs, &db_name).await?;
+            Ok(JsValue::from(count))
+        })
+    }
+}
+
+impl BrowserFileSystem {
+    /// Handle to the shared filesystem, for buffers and config
+    pub fn filesystem(&self) -> VirtualFileSystem {
+        self.fs.clone()
+    }
+}
+
+impl Default for BrowserFileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Copy text to the system clipboard through `navigator.clipboard`
+#[wasm_bindgen(js_name = copyToClipboard)]
+pub async fn copy_to_clipboard(text: String) -> Result<(), JsValue> {
+    let clipboard = window()?.navigator().clipboard();
+    JsFuture::from(clipboard.write_text(&text)).await?;
+    Ok(())
+}
+
+/// Read text from the system clipboard. Browsers may ask the user first.
+#[wasm_bindgen(js_name = readClipboard)]
+pub async fn read_clipboard() -> Result<String, JsValue> {
+    let clipboard = window()?.navigator().clipboard();
+    let text = JsFuture::from(clipboard.read_text()).await?;
+    Ok(text.as_string().unwrap_or_default())
+}
+
+/// Store the session config in `localStorage`
+pub fn save_session(session: &SessionConfig) -> Result<(), JsValue> {
+    let storage = window()?
+        .local_storage()?
+        .ok_or_else(|| JsValue::from_str("localStorage is not available"))?;
+    let json = serde_json::to_string(session).map_err(to_js_error)?;
+    storage.set_item(SESSION_KEY, &json)
+}
+
+/// Load the session config from `localStorage`, or an empty one
+pub fn load_session() -> SessionConfig {
+    let stored = window()
+        .ok()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(SESSION_KEY).ok().flatten());
+    match stored.map(|json| serde_json::from_str(&json)) {
+        Some(Ok(session)) => session,
+        Some(Err(e)) => {
+            tracing::warn!("Ignoring invalid session in localStorage: {}", e);
+            SessionConfig::new()
+        }
+        None => SessionConfig::new(),
+    }
+}
+
+/// Remove the session config from `localStorage`
+pub fn clear_session() -> Result<(), JsValue> {
+    match window()?.local_storage()? {
+        Some(storage) => storage.remove_item(SESSION_KEY),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,64 @@
+// TypeScript declarations for the embedding API in `bindings.rs`.
+
+/** One cell of the rendered grid. */
+export interface Cell {
+  /** Text drawn in the cell; empty for the second half of a wide character. */
+  ch: string;
+  /** Foreground as `#rrggbb`, or null for the host's default. */
+  fg: string | null;
+  /** Background as `#rrggbb`, or null for the host's default. */
+  bg: string | null;
+  /** Modifier bits: bold = 1, dim = 2, italic = 4, underlined = 8, reversed = 64. */
+  mods: number;
+}
+
+/** A replaced range. Offsets are JavaScript string indices into the text before the edit. */
+export interface TextEdit {
+  start: number;
+  end: number;
+  text: string;
+}
+
+export type ChangePayload =
+  | { kind: "full"; version: number; text: string }
+  | { kind: "incremental"; version: number; edits: TextEdit[] };
+
+export type MouseKind =
+  | "down"
+  | "drag"
+  | "up"
+  | "wheel_up"
+  | "wheel_down"
+  | "wheel_left"
+  | "wheel_right";
+
+export class FreshEditor {
+  private constructor();
+  free(): void;
+
+  /** Create an editor `cols` x `rows` cells in size. */
+  static create(cols: number, rows: number): FreshEditor;
+
+  /** Replace the document. `language` is a grammar name ("Rust") or extension ("py"). */
+  loadDocument(name: string, text: string, language?: string): void;
+  getText(): string;
+
+  /**
+   * Call `callback` once edits pause for `debounceMs` (default 250).
+   * With `full` the payload carries the whole text instead of the edits.
+   */
+  onChange(callback: (change: ChangePayload) => void, debounceMs?: number, full?: boolean): void;
+  offChange(): void;
+
+  /** Switch to a built-in theme ("dark", "light", "dracula", ...). Throws for unknown names. */
+  setTheme(name: string): void;
+  resize(cols: number, rows: number): void;
+
+  /** Forward a `keydown` event; true means the editor used it and the host should `preventDefault()`. */
+  dispatchKey(key: string, ctrl: boolean, alt: boolean, shift: boolean, meta: boolean): boolean;
+  /** Forward a mouse event at a grid cell; `button` is `MouseEvent.button`. */
+  dispatchMouse(kind: MouseKind, col: number, row: number, button: number): boolean;
+
+  /** The visible area as `cols * rows` cells, row by row. */
+  render(): Cell[];
+}
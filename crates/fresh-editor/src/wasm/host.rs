@@ -0,0 +1,753 @@
+//! Host-driven editor for embedding Fresh in web pages
+//!
+//! `HostEditor` holds a single document and renders it into a grid of cells
+//! that the page paints itself, to a canvas or the DOM. Input comes from the
+//! host as key and mouse events, and edits are collected into change payloads
+//! the host can subscribe to. Nothing here touches browser APIs, so the
+//! JavaScript bindings in `bindings` stay a thin layer over it.
+
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind};
+use ratatui::style::{Color, Modifier};
+use serde::Serialize;
+
+use super::LARGE_FILE_THRESHOLD;
+use crate::model::buffer::Buffer;
+use crate::model::cursor::Cursor;
+use crate::model::filesystem::FileSystem;
+use crate::model::virtual_filesystem::VirtualFileSystem;
+use crate::primitives::display_width::char_width;
+use crate::primitives::grammar::GrammarRegistry;
+use crate::primitives::textmate_engine::TextMateEngine;
+use crate::view::theme::{color_to_rgb, Theme, THEME_DARK};
+
+/// Columns per tab stop
+const TAB_WIDTH: usize = 4;
+
+/// Lines scrolled per mouse wheel step
+const SCROLL_LINES: usize = 3;
+
+/// Bytes parsed around the viewport for multi-line constructs
+const HIGHLIGHT_CONTEXT_BYTES: usize = 10_000;
+
+/// One cell of the rendered grid
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RenderedCell {
+    /// Text drawn in the cell; empty for the second half of a wide character
+    pub ch: String,
+    /// Foreground as `#rrggbb`, or None for the host's default
+    pub fg: Option<String>,
+    /// Background as `#rrggbb`, or None for the host's default
+    pub bg: Option<String>,
+    /// ratatui `Modifier` bits (bold = 1, italic = 4, underlined = 8, ...)
+    pub mods: u16,
+}
+
+/// A replaced range in the document. Offsets count UTF-16 code units, like
+/// JavaScript string indices, and `end` refers to the text before the edit.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+/// Edits made since the host last asked, either as the whole new text or as
+/// the list of edits to apply in order
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChangePayload {
+    Full { version: u64, text: String },
+    Incremental { version: u64, edits: Vec<TextEdit> },
+}
+
+/// Editor state behind the embedding API
+pub struct HostEditor {
+    buffer: Buffer,
+    filesystem: Arc<dyn FileSystem + Send + Sync>,
+    cursor: Cursor,
+    /// Visual column kept while moving up and down
+    goal_column: Option<usize>,
+    name: String,
+    grammars: Arc<GrammarRegistry>,
+    highlighter: Option<TextMateEngine>,
+    theme: Theme,
+    width: u16,
+    height: u16,
+    top_line: usize,
+    left_column: usize,
+    version: u64,
+    pending_edits: Vec<TextEdit>,
+}
+
+impl HostEditor {
+    /// Create an editor with an empty document filling `width` x `height` cells
+    pub fn new(width: u16, height: u16) -> Self {
+        let filesystem: Arc<dyn FileSystem + Send + Sync> = Arc::new(VirtualFileSystem::new());
+        Self {
+            buffer: Buffer::empty(filesystem.clone()),
+            filesystem,
+            cursor: Cursor::new(0),
+            goal_column: None,
+            name: String::new(),
+            grammars: Arc::new(GrammarRegistry::default()),
+            highlighter: None,
+            theme: Theme::load_builtin(THEME_DARK).expect("dark theme is built in"),
+            width,
+            height,
+            top_line: 0,
+            left_column: 0,
+            version: 0,
+            pending_edits: Vec::new(),
+        }
+    }
+
+    /// Replace the document. `language` is a grammar name or file extension
+    /// ("Rust", "py"); without one the language is guessed from `name`.
+    /// Loading is not reported as a change.
+    pub fn load_document(&mut self, name: &str, text: &str, language: Option<&str>) {
+        self.buffer = Buffer::from_str(text, LARGE_FILE_THRESHOLD, self.filesystem.clone());
+        self.cursor = Cursor::new(0);
+        self.goal_column = None;
+        self.name = name.to_string();
+        self.highlighter = self.find_highlighter(name, language);
+        self.top_line = 0;
+        self.left_column = 0;
+        self.version += 1;
+        self.pending_edits.clear();
+    }
+
+    fn find_highlighter(&self, name: &str, language: Option<&str>) -> Option<TextMateEngine> {
+        let syntax_set = self.grammars.syntax_set_arc();
+        let by_language = language.and_then(|language| {
+            syntax_set.syntaxes().iter().position(|syntax| {
+                syntax.name.eq_ignore_ascii_case(language)
+                    || syntax
+                        .file_extensions
+                        .iter()
+                        .any(|ext| ext.eq_ignore_ascii_case(language))
+            })
+        });
+        match by_language {
+            Some(index) => Some(TextMateEngine::new(syntax_set, index)),
+            None => TextMateEngine::for_file(Path::new(name), &self.grammars),
+        }
+    }
+
+    /// Name the document was loaded with
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The whole document text
+    pub fn text(&self) -> String {
+        self.buffer.to_string().unwrap_or_default()
+    }
+
+    /// Incremented by every edit and load
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Switch to a built-in theme
+    pub fn set_theme(&mut self, name: &str) -> Result<(), String> {
+        self.theme = Theme::load_builtin(name).ok_or_else(|| format!("Unknown theme: {}", name))?;
+        Ok(())
+    }
+
+    /// Change the size of the rendered grid
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.width = width;
+        self.height = height;
+        self.scroll_to_cursor();
+    }
+
+    /// Edits since the last call, or None if nothing changed. `full` asks for
+    /// the whole text instead of the individual edits.
+    pub fn take_change(&mut self, full: bool) -> Option<ChangePayload> {
+        if self.pending_edits.is_empty() {
+            return None;
+        }
+        let edits = std::mem::take(&mut self.pending_edits);
+        Some(if full {
+            ChangePayload::Full {
+                version: self.version,
+                text: self.text(),
+            }
+        } else {
+            ChangePayload::Incremental {
+                version: self.version,
+                edits,
+            }
+        })
+    }
+
+    // =========================================================================
+    // Lines and columns
+    // =========================================================================
+
+    fn line_count(&self) -> usize {
+        self.buffer.line_count().unwrap_or(1).max(1)
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        self.buffer.get_line_number(offset)
+    }
+
+    fn line_start(&self, line: usize) -> usize {
+        self.buffer
+            .line_start_offset(line)
+            .unwrap_or_else(|| self.buffer.len())
+    }
+
+    /// Text of a line without its line ending
+    fn line_text(&self, line: usize) -> String {
+        let bytes = self.buffer.get_line(line).unwrap_or_default();
+        let mut text = String::from_utf8_lossy(&bytes).into_owned();
+        if text.ends_with('\n') {
+            text.pop();
+            if text.ends_with('\r') {
+                text.pop();
+            }
+        }
+        text
+    }
+
+    fn line_end(&self, line: usize) -> usize {
+        self.line_start(line) + self.line_text(line).len()
+    }
+
+    fn gutter_width(&self) -> usize {
+        self.line_count().to_string().len() + 2
+    }
+
+    fn text_width(&self) -> usize {
+        (self.width as usize).saturating_sub(self.gutter_width())
+    }
+
+    /// Visual column of the cursor within its line
+    fn cursor_column(&self) -> usize {
+        let line = self.line_of(self.cursor.position);
+        let text = self.line_text(line);
+        let in_line = self.cursor.position - self.line_start(line);
+        visual_column(&text, in_line.min(text.len()))
+    }
+
+    fn utf16_len(&self, range: Range<usize>) -> usize {
+        String::from_utf8_lossy(&self.buffer.slice_bytes(range))
+            .encode_utf16()
+            .count()
+    }
+
+    // =========================================================================
+    // Editing
+    // =========================================================================
+
+    /// Replace `range` with `text`, leaving the cursor after the new text
+    fn replace(&mut self, range: Range<usize>, text: &str) {
+        if range.is_empty() && text.is_empty() {
+            return;
+        }
+        let start = self.utf16_len(0..range.start);
+        let end = start + self.utf16_len(range.clone());
+        if !range.is_empty() {
+            self.buffer.delete(range.clone());
+        }
+        if !text.is_empty() {
+            self.buffer.insert(range.start, text);
+        }
+        if let Some(highlighter) = &mut self.highlighter {
+            highlighter.invalidate_all();
+        }
+        self.cursor = Cursor::new(range.start + text.len());
+        self.version += 1;
+        self.pending_edits.push(TextEdit {
+            start,
+            end,
+            text: text.to_string(),
+        });
+    }
+
+    /// Insert text over the selection
+    pub fn insert_text(&mut self, text: &str) {
+        let range = self.cursor.selection_start()..self.cursor.selection_end();
+        self.replace(range, text);
+    }
+
+    fn delete_backward(&mut self) {
+        let range = match self.cursor.selection_range() {
+            Some(range) => range,
+            None if self.cursor.position > 0 => {
+                self.buffer.prev_char_boundary(self.cursor.position)..self.cursor.position
+            }
+            None => return,
+        };
+        self.replace(range, "");
+    }
+
+    fn delete_forward(&mut self) {
+        let range = match self.cursor.selection_range() {
+            Some(range) => range,
+            None if self.cursor.position < self.buffer.len() => {
+                self.cursor.position..self.buffer.next_char_boundary(self.cursor.position)
+            }
+            None => return,
+        };
+        self.replace(range, "");
+    }
+
+    // =========================================================================
+    // Cursor movement
+    // =========================================================================
+
+    fn move_horizontal(&mut self, forward: bool, extend: bool) {
+        let target = match self.cursor.selection_range() {
+            // Collapse an existing selection to the side moved towards
+            Some(range) if !extend => {
+                if forward {
+                    range.end
+                } else {
+                    range.start
+                }
+            }
+            _ if forward => self.buffer.next_char_boundary(self.cursor.position),
+            _ => self.buffer.prev_char_boundary(self.cursor.position),
+        };
+        self.cursor.move_to(target, extend);
+    }
+
+    fn move_vertical(&mut self, lines: isize, extend: bool) {
+        let goal = self.goal_column.unwrap_or_else(|| self.cursor_column());
+        let current = self.line_of(self.cursor.position) as isize;
+        let last = self.line_count() as isize - 1;
+        let line = (current + lines).clamp(0, last) as usize;
+        let text = self.line_text(line);
+        let target = self.line_start(line) + byte_at_visual_column(&text, goal);
+        self.cursor.move_to(target, extend);
+        self.goal_column = Some(goal);
+    }
+
+    /// Keep the cursor inside the visible area
+    fn scroll_to_cursor(&mut self) {
+        let height = (self.height as usize).max(1);
+        let line = self.line_of(self.cursor.position);
+        if line < self.top_line {
+            self.top_line = line;
+        } else if line >= self.top_line + height {
+            self.top_line = line + 1 - height;
+        }
+
+        let width = self.text_width().max(1);
+        let column = self.cursor_column();
+        if column < self.left_column {
+            self.left_column = column;
+        } else if column >= self.left_column + width {
+            self.left_column = column + 1 - width;
+        }
+    }
+
+    /// Byte offset of the document position shown in a grid cell
+    fn offset_at_cell(&self, column: u16, row: u16) -> usize {
+        let line = (self.top_line + row as usize).min(self.line_count() - 1);
+        let text = self.line_text(line);
+        let visual = (column as usize).saturating_sub(self.gutter_width()) + self.left_column;
+        self.line_start(line) + byte_at_visual_column(&text, visual)
+    }
+
+    // =========================================================================
+    // Input
+    // =========================================================================
+
+    /// Handle a key press. Returns false for keys the editor doesn't use, so
+    /// the host can let the page handle them.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        let ctrl = key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::SUPER);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let page = (self.height as isize).max(1);
+
+        let vertical = matches!(
+            key.code,
+            KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown
+        );
+        match key.code {
+            KeyCode::Char('a') if ctrl => {
+                self.cursor = Cursor::with_selection(0, self.buffer.len());
+            }
+            KeyCode::Char(c) if !ctrl && !alt => self.insert_text(c.encode_utf8(&mut [0; 4])),
+            KeyCode::Enter => self.insert_text("\n"),
+            KeyCode::Tab => {
+                let spaces = TAB_WIDTH - self.cursor_column() % TAB_WIDTH;
+                self.insert_text(&" ".repeat(spaces));
+            }
+            KeyCode::Backspace => self.delete_backward(),
+            KeyCode::Delete => self.delete_forward(),
+            KeyCode::Left => self.move_horizontal(false, shift),
+            KeyCode::Right => self.move_horizontal(true, shift),
+            KeyCode::Up => self.move_vertical(-1, shift),
+            KeyCode::Down => self.move_vertical(1, shift),
+            KeyCode::PageUp => self.move_vertical(-page, shift),
+            KeyCode::PageDown => self.move_vertical(page, shift),
+            KeyCode::Home if ctrl => self.cursor.move_to(0, shift),
+            KeyCode::End if ctrl => self.cursor.move_to(self.buffer.len(), shift),
+            KeyCode::Home => {
+                let start = self.line_start(self.line_of(self.cursor.position));
+                self.cursor.move_to(start, shift);
+            }
+            KeyCode::End => {
+                let end = self.line_end(self.line_of(self.cursor.position));
+                self.cursor.move_to(end, shift);
+            }
+            _ => return false,
+        }
+        if !vertical {
+            self.goal_column = None;
+        }
+        self.scroll_to_cursor();
+        true
+    }
+
+    /// Handle a mouse event at a grid cell. Returns false for events the
+    /// editor ignores.
+    pub fn handle_mouse(&mut self, kind: MouseEventKind, column: u16, row: u16) -> bool {
+        match kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let offset = self.offset_at_cell(column, row);
+                self.cursor.move_to(offset, false);
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let offset = self.offset_at_cell(column, row);
+                self.cursor.move_to(offset, true);
+            }
+            MouseEventKind::ScrollUp => {
+                self.top_line = self.top_line.saturating_sub(SCROLL_LINES);
+                return true;
+            }
+            MouseEventKind::ScrollDown => {
+                self.top_line = (self.top_line + SCROLL_LINES).min(self.line_count() - 1);
+                return true;
+            }
+            MouseEventKind::ScrollLeft => {
+                self.left_column = self.left_column.saturating_sub(SCROLL_LINES);
+                return true;
+            }
+            MouseEventKind::ScrollRight => {
+                self.left_column += SCROLL_LINES;
+                return true;
+            }
+            _ => return false,
+        }
+        self.goal_column = None;
+        self.scroll_to_cursor();
+        true
+    }
+
+    // =========================================================================
+    // Rendering
+    // =========================================================================
+
+    /// Render the visible area into `width * height` cells, row by row
+    pub fn render(&mut self) -> Vec<RenderedCell> {
+        let width = self.width as usize;
+        let height = self.height as usize;
+        let line_count = self.line_count();
+        let gutter = self.gutter_width();
+        let number_width = gutter - 2;
+        let text_width = self.text_width();
+        let theme = &self.theme;
+
+        let first_line = self.top_line.min(line_count - 1);
+        let last_line = (first_line + height).min(line_count);
+        let view_start = self.line_start(first_line);
+        let view_end = if last_line < line_count {
+            self.line_start(last_line)
+        } else {
+            self.buffer.len()
+        };
+        let spans = match &mut self.highlighter {
+            Some(highlighter) => highlighter.highlight_viewport(
+                &self.buffer,
+                view_start,
+                view_end,
+                theme,
+                HIGHLIGHT_CONTEXT_BYTES,
+            ),
+            None => Vec::new(),
+        };
+        let selection = self.cursor.selection_range();
+        let cursor_line = self.line_of(self.cursor.position);
+
+        let mut cells = Vec::with_capacity(width * height);
+        for row in 0..height {
+            let mut row_cells = Vec::with_capacity(width);
+            let line = first_line + row;
+            if line >= line_count {
+                row_cells.extend(
+                    std::iter::repeat_n(' ', gutter)
+                        .map(|c| cell(c, theme.line_number_fg, theme.line_number_bg)),
+                );
+                row_cells.resize(width, cell(' ', theme.editor_fg, theme.editor_bg));
+                row_cells.truncate(width);
+                cells.extend(row_cells);
+                continue;
+            }
+
+            let number = format!(" {:>number_width$} ", line + 1);
+            row_cells.extend(
+                number
+                    .chars()
+                    .map(|c| cell(c, theme.line_number_fg, theme.line_number_bg)),
+            );
+
+            let row_bg = if line == cursor_line {
+                theme.current_line_bg
+            } else {
+                theme.editor_bg
+            };
+            let start = self.line_start(line);
+            let text = self.line_text(line);
+            let mut text_cells = vec![cell(' ', theme.editor_fg, row_bg); text_width];
+            let mut column = 0;
+            let mut put = |column: usize, value: RenderedCell| {
+                if let Some(slot) = column
+                    .checked_sub(self.left_column)
+                    .and_then(|c| text_cells.get_mut(c))
+                {
+                    *slot = value;
+                }
+            };
+            for (i, c) in text.char_indices() {
+                let offset = start + i;
+                let selected = selection.as_ref().is_some_and(|r| r.contains(&offset));
+                let mut fg = spans
+                    .iter()
+                    .find(|span| span.range.contains(&offset))
+                    .map_or(theme.editor_fg, |span| span.color);
+                let mut bg = if selected { theme.selection_bg } else { row_bg };
+                if offset == self.cursor.position {
+                    fg = theme.editor_bg;
+                    bg = theme.cursor;
+                }
+                if c == '\t' {
+                    let tab_cells = TAB_WIDTH - column % TAB_WIDTH;
+                    for _ in 0..tab_cells {
+                        put(column, cell(' ', fg, bg));
+                        column += 1;
+                    }
+                    continue;
+                }
+                let (c, cell_width) = match char_width(c) {
+                    0 => ('\u{fffd}', 1),
+                    w => (c, w),
+                };
+                put(column, cell(c, fg, bg));
+                for extra in 1..cell_width {
+                    let mut continuation = cell(' ', fg, bg);
+                    continuation.ch.clear();
+                    put(column + extra, continuation);
+                }
+                column += cell_width;
+            }
+            if line == cursor_line && self.cursor.position == start + text.len() {
+                put(column, cell(' ', theme.editor_bg, theme.cursor));
+            }
+
+            row_cells.extend(text_cells);
+            row_cells.truncate(width);
+            cells.extend(row_cells);
+        }
+        cells
+    }
+}
+
+fn hex(color: Color) -> Option<String> {
+    color_to_rgb(color).map(|(r, g, b)| format!("#{:02x}{:02x}{:02x}", r, g, b))
+}
+
+fn cell(c: char, fg: Color, bg: Color) -> RenderedCell {
+    RenderedCell {
+        ch: c.to_string(),
+        fg: hex(fg),
+        bg: hex(bg),
+        mods: Modifier::empty().bits(),
+    }
+}
+
+/// Visual column of a byte offset in a line, expanding tabs
+fn visual_column(text: &str, byte: usize) -> usize {
+    text[..byte].chars().fold(0, |column, c| {
+        if c == '\t' {
+            column + TAB_WIDTH - column % TAB_WIDTH
+        } else {
+            column + char_width(c).max(1)
+        }
+    })
+}
+
+/// Byte offset in a line of the character at (or the end of the line before)
+/// a visual column
+fn byte_at_visual_column(text: &str, target: usize) -> usize {
+    let mut column = 0;
+    for (i, c) in text.char_indices() {
+        let next = if c == '\t' {
+            column + TAB_WIDTH - column % TAB_WIDTH
+        } else {
+            column + char_width(c).max(1)
+        };
+        if next > target {
+            return i;
+        }
+        column = next;
+    }
+    text.len()
+}
+
+/// Translate a DOM `KeyboardEvent` (its `key` and modifier flags) into a key
+/// event, or None for keys with no meaning to the editor (e.g. "Shift" alone)
+pub fn key_from_dom(key: &str, ctrl: bool, alt: bool, shift: bool, meta: bool) -> Option<KeyEvent> {
+    let code = match key {
+        "Enter" => KeyCode::Enter,
+        "Tab" => KeyCode::Tab,
+        "Backspace" => KeyCode::Backspace,
+        "Delete" => KeyCode::Delete,
+        "Escape" => KeyCode::Esc,
+        "ArrowLeft" => KeyCode::Left,
+        "ArrowRight" => KeyCode::Right,
+        "ArrowUp" => KeyCode::Up,
+        "ArrowDown" => KeyCode::Down,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Insert" => KeyCode::Insert,
+        _ => {
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => match key.strip_prefix('F').and_then(|n| n.parse::<u8>().ok()) {
+                    Some(n) => KeyCode::F(n),
+                    None => return None,
+                },
+            }
+        }
+    };
+
+    let mut modifiers = KeyModifiers::empty();
+    modifiers.set(KeyModifiers::CONTROL, ctrl);
+    modifiers.set(KeyModifiers::ALT, alt);
+    modifiers.set(KeyModifiers::SHIFT, shift);
+    modifiers.set(KeyModifiers::SUPER, meta);
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// Translate a host mouse event name ("down", "drag", "up", "wheel_up",
+/// "wheel_down", "wheel_left", "wheel_right") and DOM button number
+pub fn mouse_from_dom(kind: &str, button: i16) -> Option<MouseEventKind> {
+    let button = match button {
+        1 => MouseButton::Middle,
+        2 => MouseButton::Right,
+        _ => MouseButton::Left,
+    };
+    Some(match kind {
+        "down" => MouseEventKind::Down(button),
+        "drag" => MouseEventKind::Drag(button),
+        "up" => MouseEventKind::Up(button),
+        "wheel_up" => MouseEventKind::ScrollUp,
+        "wheel_down" => MouseEventKind::ScrollDown,
+        "wheel_left" => MouseEventKind::ScrollLeft,
+        "wheel_right" => MouseEventKind::ScrollRight,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(key: &str) -> KeyEvent {
+        key_from_dom(key, false, false, false, false).unwrap()
+    }
+
+    fn row_text(cells: &[RenderedCell], width: usize, row: usize) -> String {
+        cells[row * width..(row + 1) * width]
+            .iter()
+            .map(|c| c.ch.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_typing_reports_incremental_and_full_changes() {
+        let mut editor = HostEditor::new(20, 3);
+        editor.load_document("notes.txt", "héllo", None);
+        assert_eq!(editor.take_change(false), None);
+
+        editor.handle_key(key("End"));
+        editor.handle_key(key("!"));
+        editor.handle_key(key("ArrowLeft"));
+        editor.handle_key(key("ArrowLeft"));
+        editor.handle_key(key("Backspace"));
+        assert_eq!(editor.text(), "hélo!");
+
+        // Offsets are in UTF-16 units, so "é" counts once
+        assert_eq!(
+            editor.take_change(false),
+            Some(ChangePayload::Incremental {
+                version: editor.version(),
+                edits: vec![
+                    TextEdit {
+                        start: 5,
+                        end: 5,
+                        text: "!".to_string()
+                    },
+                    TextEdit {
+                        start: 3,
+                        end: 4,
+                        text: String::new()
+                    },
+                ],
+            })
+        );
+
+        editor.handle_key(key("Enter"));
+        assert_eq!(
+            editor.take_change(true),
+            Some(ChangePayload::Full {
+                version: editor.version(),
+                text: "hél\no!".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_render_grid_and_mouse() {
+        let mut editor = HostEditor::new(12, 3);
+        editor.load_document("main.rs", "fn main() {}\n\tx", None);
+        let cells = editor.render();
+        assert_eq!(cells.len(), 12 * 3);
+        assert_eq!(row_text(&cells, 12, 0), " 1 fn main()");
+        assert_eq!(row_text(&cells, 12, 1), " 2     x    ");
+        assert_eq!(row_text(&cells, 12, 2), "            ");
+        // The cursor cell uses the theme's cursor color
+        assert_eq!(cells[3].bg, hex(editor.theme.cursor));
+
+        // Clicking the "x" after the tab puts the cursor before it
+        assert!(editor.handle_mouse(MouseEventKind::Down(MouseButton::Left), 7, 1));
+        editor.insert_text("y");
+        assert_eq!(editor.text(), "fn main() {}\n\tyx");
+    }
+
+    #[test]
+    fn test_key_from_dom() {
+        assert_eq!(key("ArrowUp").code, KeyCode::Up);
+        assert_eq!(key("F5").code, KeyCode::F(5));
+        assert_eq!(key("ä").code, KeyCode::Char('ä'));
+        assert!(key_from_dom("Shift", false, false, true, false).is_none());
+        let select_all = key_from_dom("a", true, false, false, false).unwrap();
+        assert_eq!(select_all.modifiers, KeyModifiers::CONTROL);
+    }
+}
@@ -14,10 +14,22 @@
 //! - Virtual filesystem (in-memory or IndexedDB-backed)
 //! - Rendering to browser terminal (via Ratzilla)
 //!
+//! `host::HostEditor` is the core of the embedding API: it renders a document
+//! into a cell grid and takes host-driven key and mouse input. On wasm32,
+//! `bindings` exposes it to JavaScript (declarations in `fresh.d.ts`) and the
+//! `browser` submodule exposes the filesystem, clipboard and session hooks.
+//!
 //! # Usage
 //!
 //! Build with: `cargo build --no-default-features --features wasm`
 
+#[cfg(target_arch = "wasm32")]
+pub mod bindings;
+#[cfg(target_arch = "wasm32")]
+pub mod browser;
+pub mod host;
+
+use std::path::Path;
 use std::sync::Arc;
 
 // Re-export core types for WASM consumers
@@ -26,35 +38,63 @@ pub use crate::model::cursor::{Cursor, Cursors};
 pub use crate::model::event::{Event, EventLog};
 pub use crate::model::filesystem::{FileSystem, NoopFileSystem, StdFileSystem};
 pub use crate::model::piece_tree::{PieceTree, Position};
+pub use crate::model::virtual_filesystem::VirtualFileSystem;
 
 /// Default large file threshold for WASM (100MB)
 const LARGE_FILE_THRESHOLD: usize = 100 * 1024 * 1024;
 
 /// WASM-specific editor state
 ///
-/// This provides a simple wrapper around the core Buffer type
-/// that uses NoopFileSystem since browsers don't have direct filesystem access.
+/// This provides a simple wrapper around the core Buffer type. Files are
+/// opened from and saved to a `VirtualFileSystem`, since browsers don't have
+/// direct filesystem access.
 pub struct WasmEditor {
     buffer: Buffer,
+    filesystem: VirtualFileSystem,
 }
 
 impl WasmEditor {
     /// Create a new WASM editor with an empty buffer
     pub fn new() -> Self {
-        let fs: Arc<dyn FileSystem + Send + Sync> = Arc::new(NoopFileSystem);
+        Self::with_filesystem(VirtualFileSystem::new())
+    }
+
+    /// Create a new WASM editor with an empty buffer backed by `filesystem`
+    pub fn with_filesystem(filesystem: VirtualFileSystem) -> Self {
+        let fs: Arc<dyn FileSystem + Send + Sync> = Arc::new(filesystem.clone());
         Self {
             buffer: Buffer::empty(fs),
+            filesystem,
         }
     }
 
     /// Create a new WASM editor with initial content
     pub fn with_content(content: &str) -> Self {
-        let fs: Arc<dyn FileSystem + Send + Sync> = Arc::new(NoopFileSystem);
+        let filesystem = VirtualFileSystem::new();
+        let fs: Arc<dyn FileSystem + Send + Sync> = Arc::new(filesystem.clone());
         Self {
             buffer: Buffer::from_str(content, LARGE_FILE_THRESHOLD, fs),
+            filesystem,
         }
     }
 
+    /// Replace the buffer with a file from the virtual filesystem
+    pub fn open(&mut self, path: &Path) -> anyhow::Result<()> {
+        let fs: Arc<dyn FileSystem + Send + Sync> = Arc::new(self.filesystem.clone());
+        self.buffer = Buffer::load_from_file(path, LARGE_FILE_THRESHOLD, fs)?;
+        Ok(())
+    }
+
+    /// Save the buffer to `path` in the virtual filesystem
+    pub fn save_as(&mut self, path: &Path) -> anyhow::Result<()> {
+        self.buffer.save_to_file(path)
+    }
+
+    /// Get the virtual filesystem files are opened from and saved to
+    pub fn filesystem(&self) -> &VirtualFileSystem {
+        &self.filesystem
+    }
+
     /// Get the buffer content as a string
     ///
     /// Returns None if the buffer contains invalid UTF-8
@@ -135,4 +175,18 @@ mod tests {
         editor.delete(5, 13); // Delete ", World!"
         assert_eq!(editor.content(), Some("Hello".to_string()));
     }
+
+    #[test]
+    fn test_wasm_editor_open_and_save() {
+        let fs = VirtualFileSystem::new();
+        fs.insert_file(Path::new("/notes.txt"), b"draft".to_vec());
+
+        let mut editor = WasmEditor::with_filesystem(fs.clone());
+        editor.open(Path::new("/notes.txt")).unwrap();
+        assert_eq!(editor.content(), Some("draft".to_string()));
+
+        editor.insert(5, " two");
+        editor.save_as(Path::new("/notes.txt")).unwrap();
+        assert_eq!(fs.read_file(Path::new("/notes.txt")).unwrap(), b"draft two");
+    }
 }
@@ -74,6 +74,15 @@ pub struct Workspace {
     #[serde(default)]
     pub bookmarks: HashMap<char, SerializedBookmark>,
 
+    /// Line bookmarks shown in the gutter
+    #[serde(default)]
+    pub line_bookmarks: Vec<SerializedLineBookmark>,
+
+    /// Languages set by hand with "Set Language" (syntax name or
+    /// "Plain Text"), keyed by file path relative to working_dir
+    #[serde(default)]
+    pub language_overrides: HashMap<PathBuf, String>,
+
     /// Open terminal workspaces (for restoration)
     #[serde(default)]
     pub terminals: Vec<SerializedTerminalWorkspace>,
@@ -83,6 +92,15 @@ pub struct Workspace {
     #[serde(default)]
     pub external_files: Vec<PathBuf>,
 
+    /// Untitled scratch buffers with content, so they survive restarts
+    #[serde(default)]
+    pub scratch_buffers: Vec<SerializedScratchBuffer>,
+
+    /// Files in most recently used order, for the buffer picker
+    /// (relative to working_dir, absolute outside it)
+    #[serde(default)]
+    pub buffer_mru: Vec<PathBuf>,
+
     /// Timestamp when workspace was saved (Unix epoch seconds)
     pub saved_at: u64,
 }
@@ -144,6 +162,10 @@ pub struct SerializedSplitViewState {
     #[serde(default)]
     pub file_states: HashMap<PathBuf, SerializedFileState>,
 
+    /// Number of pinned tabs at the start of open_tabs
+    #[serde(default)]
+    pub pinned_tab_count: usize,
+
     /// Tab scroll offset
     #[serde(default)]
     pub tab_scroll_offset: usize,
@@ -236,7 +258,7 @@ pub struct WorkspaceConfigOverrides {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub line_numbers: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub relative_line_numbers: Option<bool>,
+    pub line_number_mode: Option<crate::config::LineNumberMode>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub line_wrap: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -307,6 +329,8 @@ pub struct SearchOptions {
     pub use_regex: bool,
     #[serde(default)]
     pub confirm_each: bool,
+    #[serde(default)]
+    pub preserve_case: bool,
 }
 
 /// Serialized bookmark (file path + byte offset)
@@ -318,11 +342,38 @@ pub struct SerializedBookmark {
     pub position: usize,
 }
 
-/// Reference to an open tab (file path or terminal index)
+/// Serialized line bookmark (file path + line, with an optional label)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializedLineBookmark {
+    /// File path (relative to working_dir)
+    pub file_path: PathBuf,
+    /// Line number (0-indexed)
+    pub line: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// Reference to an open tab (file path, terminal index or scratch buffer
+/// number)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SerializedTabRef {
     File(PathBuf),
     Terminal(usize),
+    Scratch(usize),
+}
+
+/// Content of an untitled scratch buffer ("untitled-2")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializedScratchBuffer {
+    pub number: usize,
+    pub content: String,
+    /// Language chosen for it, as a syntax name or "Plain Text"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// ID of its crash-recovery file, so recovery refills this buffer
+    /// instead of opening a second copy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery_id: Option<String>,
 }
 
 /// Persisted metadata for a terminal workspace
@@ -335,6 +386,9 @@ pub struct SerializedTerminalWorkspace {
     pub rows: u16,
     pub log_path: PathBuf,
     pub backing_path: PathBuf,
+    /// Title given with "Rename Terminal", if any
+    #[serde(default)]
+    pub title: Option<String>,
 }
 
 // ============================================================================
@@ -760,8 +814,12 @@ impl Workspace {
             histories: WorkspaceHistories::default(),
             search_options: SearchOptions::default(),
             bookmarks: HashMap::new(),
+            line_bookmarks: Vec::new(),
+            language_overrides: HashMap::new(),
             terminals: Vec::new(),
             external_files: Vec::new(),
+            scratch_buffers: Vec::new(),
+            buffer_mru: Vec::new(),
             saved_at: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .unwrap_or_default()
@@ -971,6 +1029,7 @@ mod tests {
             whole_word: true,
             use_regex: false,
             confirm_each: true,
+            preserve_case: true,
         };
 
         let json = serde_json::to_string(&options).unwrap();
@@ -980,6 +1039,7 @@ mod tests {
         assert!(restored.whole_word);
         assert!(!restored.use_regex);
         assert!(restored.confirm_each);
+        assert!(restored.preserve_case);
     }
 
     #[test]
@@ -1011,11 +1071,13 @@ mod tests {
                 open_tabs: vec![
                     SerializedTabRef::File(PathBuf::from("README.md")),
                     SerializedTabRef::File(PathBuf::from("src/lib.rs")),
+                    SerializedTabRef::Scratch(1),
                 ],
                 active_tab_index: Some(0),
                 open_files: vec![PathBuf::from("README.md"), PathBuf::from("src/lib.rs")],
                 active_file_index: 0,
                 file_states: HashMap::new(),
+                pinned_tab_count: 0,
                 tab_scroll_offset: 0,
                 view_mode: SerializedViewMode::Source,
                 compose_width: None,
@@ -1031,6 +1093,14 @@ mod tests {
             },
         );
 
+        // Add an untitled scratch buffer
+        workspace.scratch_buffers.push(SerializedScratchBuffer {
+            number: 1,
+            content: "notes\n".to_string(),
+            language: Some("markdown".to_string()),
+            recovery_id: None,
+        });
+
         // Set search options
         workspace.search_options.case_sensitive = true;
         workspace.search_options.use_regex = true;
@@ -1051,6 +1121,11 @@ mod tests {
         let split_state = restored.split_states.get(&1).unwrap();
         assert_eq!(split_state.open_files.len(), 2);
         assert_eq!(split_state.open_files[0], PathBuf::from("README.md"));
+        assert!(matches!(
+            split_state.open_tabs[2],
+            SerializedTabRef::Scratch(1)
+        ));
+        assert_eq!(restored.scratch_buffers[0].content, "notes\n");
     }
 
     #[test]
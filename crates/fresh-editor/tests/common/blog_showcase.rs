@@ -2,7 +2,14 @@
 //
 // Captures multi-frame animations of the editor with key-press indicators
 // and mouse cursor overlays. Used to generate animated GIFs for blog posts.
+//
+// Each frame is written as SVG, and finalize() also encodes the frames
+// directly into showcase.gif using a built-in 8x8 bitmap font. Set
+// SHOWCASE_FORMATS to a comma-separated list of `gif` and `apng` to choose
+// the encoded files, or to `svg` to only write SVG frames for
+// scripts/frames-to-gif.sh (better fonts, but needs resvg and ffmpeg).
 
+use fresh::services::styled_html::color_to_css;
 use ratatui::buffer::Buffer;
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
@@ -35,6 +42,64 @@ pub struct ShowcaseMetadata {
 /// Values > 1.0 slow down, values < 1.0 speed up.
 const SPEED_FACTOR: f32 = 2.0;
 
+/// Animation files encoded directly by finalize()
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EncodeFormats {
+    pub gif: bool,
+    pub apng: bool,
+}
+
+impl EncodeFormats {
+    /// Formats from SHOWCASE_FORMATS, defaulting to GIF only
+    pub fn from_env() -> Self {
+        match std::env::var("SHOWCASE_FORMATS") {
+            Ok(value) => Self::parse(&value),
+            Err(_) => Self {
+                gif: true,
+                apng: false,
+            },
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        let mut formats = Self::default();
+        for name in value.split(',').map(str::trim) {
+            match name {
+                "gif" => formats.gif = true,
+                "apng" => formats.apng = true,
+                "svg" | "" => {}
+                other => eprintln!("Unknown SHOWCASE_FORMATS entry '{other}', ignoring"),
+            }
+        }
+        formats
+    }
+
+    fn any(self) -> bool {
+        self.gif || self.apng
+    }
+}
+
+/// Overlays drawn on top of the terminal cells
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrameOverlay {
+    pub key_indicator: Option<String>,
+    /// Mouse pointer in cells; fractional while moving along a path
+    pub mouse: Option<(f32, f32)>,
+    /// Click ripple around the pointer, from 0.0 (just clicked) to 1.0 (gone)
+    pub click_ripple: Option<f32>,
+    /// Cell that was just typed into
+    pub typed_cell: Option<(u16, u16)>,
+}
+
+/// A captured frame kept for direct encoding
+#[derive(Debug, Clone)]
+pub struct RasterFrame {
+    pub buffer: Buffer,
+    pub cursor_pos: (u16, u16),
+    pub overlay: FrameOverlay,
+    pub duration_ms: u32,
+}
+
 /// Builder for capturing animated blog showcases
 pub struct BlogShowcase {
     name: String,
@@ -47,6 +112,8 @@ pub struct BlogShowcase {
     term_width: u16,
     term_height: u16,
     last_key: Option<String>,
+    formats: EncodeFormats,
+    raster_frames: Vec<RasterFrame>,
 }
 
 impl BlogShowcase {
@@ -77,6 +144,8 @@ impl BlogShowcase {
             term_width: 0,
             term_height: 0,
             last_key: None,
+            formats: EncodeFormats::from_env(),
+            raster_frames: Vec::new(),
         }
     }
 
@@ -95,11 +164,96 @@ impl BlogShowcase {
         mouse_pos: Option<(u16, u16)>,
         duration_ms: u32,
     ) -> io::Result<()> {
+        let overlay = FrameOverlay {
+            key_indicator: self.sticky_key(key_indicator),
+            mouse: mouse_pos.map(|(col, row)| (col as f32, row as f32)),
+            ..Default::default()
+        };
+        self.push_frame(buffer, cursor_pos, overlay, duration_ms)
+    }
+
+    /// Capture a frame right after typing, highlighting the cell just typed
+    /// into (the one before the cursor).
+    pub fn capture_typing_frame(
+        &mut self,
+        buffer: &Buffer,
+        cursor_pos: (u16, u16),
+        key_indicator: Option<&str>,
+        duration_ms: u32,
+    ) -> io::Result<()> {
+        let overlay = FrameOverlay {
+            key_indicator: self.sticky_key(key_indicator),
+            typed_cell: cursor_pos.0.checked_sub(1).map(|col| (col, cursor_pos.1)),
+            ..Default::default()
+        };
+        self.push_frame(buffer, cursor_pos, overlay, duration_ms)
+    }
+
+    /// Capture `steps` frames moving the mouse pointer from `from` to `to`
+    /// with ease-in-out, ending exactly on `to`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn mouse_path(
+        &mut self,
+        buffer: &Buffer,
+        cursor_pos: (u16, u16),
+        key_indicator: Option<&str>,
+        from: (u16, u16),
+        to: (u16, u16),
+        steps: usize,
+        duration_ms_each: u32,
+    ) -> io::Result<()> {
+        for step in 1..=steps {
+            let t = ease_in_out(step as f32 / steps as f32);
+            let lerp = |a: u16, b: u16| a as f32 + (b as f32 - a as f32) * t;
+            let overlay = FrameOverlay {
+                key_indicator: self.sticky_key(key_indicator),
+                mouse: Some((lerp(from.0, to.0), lerp(from.1, to.1))),
+                ..Default::default()
+            };
+            self.push_frame(buffer, cursor_pos, overlay, duration_ms_each)?;
+        }
+        Ok(())
+    }
+
+    /// Capture `steps` frames of a ripple spreading out from a click at
+    /// `mouse_pos`.
+    pub fn click_frames(
+        &mut self,
+        buffer: &Buffer,
+        cursor_pos: (u16, u16),
+        key_indicator: Option<&str>,
+        mouse_pos: (u16, u16),
+        steps: usize,
+        duration_ms_each: u32,
+    ) -> io::Result<()> {
+        for step in 0..steps {
+            let overlay = FrameOverlay {
+                key_indicator: self.sticky_key(key_indicator),
+                mouse: Some((mouse_pos.0 as f32, mouse_pos.1 as f32)),
+                click_ripple: Some(step as f32 / steps as f32),
+                ..Default::default()
+            };
+            self.push_frame(buffer, cursor_pos, overlay, duration_ms_each)?;
+        }
+        Ok(())
+    }
+
+    /// The key label to show: a new one replaces the last, which stays
+    /// visible until then
+    fn sticky_key(&mut self, key_indicator: Option<&str>) -> Option<String> {
         if key_indicator.is_some() {
             self.last_key = key_indicator.map(|s| s.to_string());
         }
-        let effective_key = self.last_key.as_deref();
+        self.last_key.clone()
+    }
 
+    fn push_frame(
+        &mut self,
+        buffer: &Buffer,
+        cursor_pos: (u16, u16),
+        overlay: FrameOverlay,
+        duration_ms: u32,
+    ) -> io::Result<()> {
         self.term_width = buffer.area.width;
         self.term_height = buffer.area.height;
 
@@ -108,16 +262,28 @@ impl BlogShowcase {
         let filename = format!("frame_{:04}.svg", self.frame_index);
         let filepath = self.frames_dir.join(&filename);
 
-        render_showcase_frame(buffer, cursor_pos, effective_key, mouse_pos, &filepath)?;
+        render_showcase_frame(buffer, cursor_pos, &overlay, &filepath)?;
 
+        let duration_ms = (duration_ms as f32 * SPEED_FACTOR) as u32;
         self.frames.push(FrameInfo {
             index: self.frame_index,
             filename,
-            duration_ms: (duration_ms as f32 * SPEED_FACTOR) as u32,
-            key_indicator: effective_key.map(|s| s.to_string()),
-            mouse_pos,
+            duration_ms,
+            key_indicator: overlay.key_indicator.clone(),
+            mouse_pos: overlay
+                .mouse
+                .map(|(col, row)| (col.round() as u16, row.round() as u16)),
         });
 
+        if self.formats.any() {
+            self.raster_frames.push(RasterFrame {
+                buffer: buffer.clone(),
+                cursor_pos,
+                overlay,
+                duration_ms,
+            });
+        }
+
         self.frame_index += 1;
         Ok(())
     }
@@ -145,7 +311,8 @@ impl BlogShowcase {
         Ok(())
     }
 
-    /// Finalize the showcase: write metadata JSON and blog post stub.
+    /// Finalize the showcase: write metadata JSON, the directly encoded
+    /// animations and a blog post stub.
     /// Returns the path to the blog directory.
     pub fn finalize(self) -> io::Result<PathBuf> {
         if self.frames.is_empty() {
@@ -166,6 +333,13 @@ impl BlogShowcase {
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
         fs::write(&metadata_path, json)?;
 
+        if self.formats.gif {
+            encode_gif(&self.raster_frames, &self.blog_dir.join("showcase.gif"))?;
+        }
+        if self.formats.apng {
+            encode_apng(&self.raster_frames, &self.blog_dir.join("showcase.apng"))?;
+        }
+
         // Write blog post markdown stub (only if it doesn't exist yet)
         let md_path = self.blog_dir.join("index.md");
         if !md_path.exists() {
@@ -184,7 +358,7 @@ outline: false
 </div>
 
 <!-- Generated by: cargo test --package fresh-editor --test e2e_tests blog_showcase_{name} -- --ignored -->
-<!-- Then run: scripts/frames-to-gif.sh docs/blog/{name} -->
+<!-- With SHOWCASE_FORMATS=svg, then run: scripts/frames-to-gif.sh docs/blog/{name} -->
 "#,
                 title = self.title,
                 desc = self.description,
@@ -205,12 +379,36 @@ const CHAR_WIDTH: u16 = 9;
 const CHAR_HEIGHT: u16 = 18;
 const FONT_SIZE: u16 = 14;
 
+/// Just-typed cell highlight
+const TYPED_HEX: &str = "#f9e2af";
+const TYPED_OPACITY: f32 = 0.3;
+
+/// Click ripple ring
+const RIPPLE_HEX: &str = "#89b4fa";
+const RIPPLE_MAX_RADIUS: f32 = 18.0;
+
+/// Cubic ease-in-out for `t` in 0.0..=1.0
+fn ease_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+/// Radius and opacity of the click ripple at `progress`
+fn ripple_shape(progress: f32) -> (f32, f32) {
+    (
+        4.0 + (RIPPLE_MAX_RADIUS - 4.0) * progress,
+        0.8 * (1.0 - progress),
+    )
+}
+
 /// Render a single animation frame to SVG with optional key indicator and mouse cursor.
 fn render_showcase_frame(
     buffer: &Buffer,
     cursor_pos: (u16, u16),
-    key_indicator: Option<&str>,
-    mouse_pos: Option<(u16, u16)>,
+    overlay: &FrameOverlay,
     path: &Path,
 ) -> io::Result<()> {
     let width = buffer.area.width;
@@ -293,6 +491,18 @@ fn render_showcase_frame(
         }
     }
 
+    // Just-typed cell highlight
+    if let Some((tx, ty)) = overlay.typed_cell {
+        svg.push_str(&format!(
+            r##"  <rect x="{}" y="{}" width="{}" height="{}" fill="{TYPED_HEX}" opacity="{TYPED_OPACITY}"/>"##,
+            tx * CHAR_WIDTH,
+            ty * CHAR_HEIGHT,
+            CHAR_WIDTH,
+            CHAR_HEIGHT
+        ));
+        svg.push('\n');
+    }
+
     // Editor cursor
     let (cx, cy) = cursor_pos;
     svg.push_str(&format!(
@@ -305,11 +515,18 @@ fn render_showcase_frame(
     svg.push('\n');
 
     // Mouse cursor overlay
-    if let Some((mx, my)) = mouse_pos {
-        let px = mx * CHAR_WIDTH;
-        let py = my * CHAR_HEIGHT;
+    if let Some((mx, my)) = overlay.mouse {
+        let px = mx * CHAR_WIDTH as f32;
+        let py = my * CHAR_HEIGHT as f32;
+        if let Some(progress) = overlay.click_ripple {
+            let (radius, opacity) = ripple_shape(progress);
+            svg.push_str(&format!(
+                r##"  <circle cx="{px:.1}" cy="{py:.1}" r="{radius:.1}" fill="none" stroke="{RIPPLE_HEX}" stroke-width="2" opacity="{opacity:.2}"/>
+"##
+            ));
+        }
         svg.push_str(&format!(
-            r##"  <g transform="translate({px},{py})" opacity="0.95">
+            r##"  <g transform="translate({px:.1},{py:.1})" opacity="0.95">
     <polygon points="0,0 0,18 5,14 9,21 12,19 8,12 14,12" fill="#ffffff" stroke="#000000" stroke-width="1.2" stroke-linejoin="round"/>
   </g>
 "##
@@ -317,7 +534,7 @@ fn render_showcase_frame(
     }
 
     // Key indicator badge (bottom-right corner)
-    if let Some(key) = key_indicator {
+    if let Some(key) = &overlay.key_indicator {
         let badge_text = key;
         let text_len = badge_text.len() as u16;
         let badge_w = text_len * 13 + 32;
@@ -346,7 +563,301 @@ fn render_showcase_frame(
 }
 
 // ---------------------------------------------------------------------------
-// Helpers (Catppuccin Mocha palette)
+// Direct GIF/APNG encoding
+// ---------------------------------------------------------------------------
+
+const BACKGROUND: [u8; 3] = [0x1e, 0x1e, 0x2e];
+const CURSOR_COLOR: [u8; 3] = [0xcd, 0xd6, 0xf4];
+const BADGE_FILL: [u8; 3] = [0x31, 0x32, 0x44];
+const BADGE_BORDER: [u8; 3] = [0x58, 0x5b, 0x70];
+
+/// Mouse pointer outline, matching the SVG overlay
+const POINTER: [(f32, f32); 7] = [
+    (0.0, 0.0),
+    (0.0, 18.0),
+    (5.0, 14.0),
+    (9.0, 21.0),
+    (12.0, 19.0),
+    (8.0, 12.0),
+    (14.0, 12.0),
+];
+
+/// RGBA pixels of one frame
+struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: u32, height: u32) -> Self {
+        let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+        for _ in 0..width * height {
+            pixels.extend_from_slice(&[BACKGROUND[0], BACKGROUND[1], BACKGROUND[2], 255]);
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Blend `rgb` over the pixel at (x, y) with the given opacity
+    fn blend(&mut self, x: u32, y: u32, rgb: [u8; 3], alpha: f32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let i = ((y * self.width + x) * 4) as usize;
+        for (channel, value) in rgb.iter().enumerate() {
+            let old = self.pixels[i + channel] as f32;
+            self.pixels[i + channel] = (old + (*value as f32 - old) * alpha).round() as u8;
+        }
+    }
+
+    fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, rgb: [u8; 3], alpha: f32) {
+        for py in y..y + height {
+            for px in x..x + width {
+                self.blend(px, py, rgb, alpha);
+            }
+        }
+    }
+
+    /// Draw `ch` stretched over the `[x, y, width, height]` box
+    fn draw_char(&mut self, ch: char, [x, y, width, height]: [u32; 4], rgb: [u8; 3], bold: bool) {
+        let Some(rows) = glyph(ch) else {
+            // Hollow box for characters the font lacks
+            self.fill_rect(x + 1, y + 2, width.saturating_sub(3), 1, rgb, 1.0);
+            self.fill_rect(x + 1, y + height - 3, width.saturating_sub(3), 1, rgb, 1.0);
+            self.fill_rect(x + 1, y + 2, 1, height.saturating_sub(4), rgb, 1.0);
+            self.fill_rect(x + width - 2, y + 2, 1, height.saturating_sub(4), rgb, 1.0);
+            return;
+        };
+        for py in 0..height {
+            let row = rows[(py * 8 / height) as usize];
+            for px in 0..width {
+                if row & (1 << (px * 8 / width)) != 0 {
+                    self.blend(x + px, y + py, rgb, 1.0);
+                    if bold {
+                        self.blend(x + px + 1, y + py, rgb, 1.0);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draw a 2px ring centered on (cx, cy)
+    fn draw_ring(&mut self, cx: f32, cy: f32, radius: f32, rgb: [u8; 3], alpha: f32) {
+        let min_x = (cx - radius - 2.0).max(0.0) as u32;
+        let min_y = (cy - radius - 2.0).max(0.0) as u32;
+        for py in min_y..(cy + radius + 2.0) as u32 {
+            for px in min_x..(cx + radius + 2.0) as u32 {
+                let distance = (px as f32 + 0.5 - cx).hypot(py as f32 + 0.5 - cy);
+                if (distance - radius).abs() <= 1.0 {
+                    self.blend(px, py, rgb, alpha);
+                }
+            }
+        }
+    }
+
+    /// Fill the pointer polygon offset to (x, y), outlined in black
+    fn draw_pointer(&mut self, x: u32, y: u32) {
+        let inside = |px: f32, py: f32| {
+            let mut inside = false;
+            let mut j = POINTER.len() - 1;
+            for i in 0..POINTER.len() {
+                let ((xi, yi), (xj, yj)) = (POINTER[i], POINTER[j]);
+                if (yi > py) != (yj > py) && px < (xj - xi) * (py - yi) / (yj - yi) + xi {
+                    inside = !inside;
+                }
+                j = i;
+            }
+            inside
+        };
+        for py in 0..22 {
+            for px in 0..15 {
+                let center = (px as f32 + 0.5, py as f32 + 0.5);
+                if !inside(center.0, center.1) {
+                    continue;
+                }
+                let edge = [(-1.0, 0.0), (1.0, 0.0), (0.0, -1.0), (0.0, 1.0)]
+                    .iter()
+                    .any(|(dx, dy)| !inside(center.0 + dx, center.1 + dy));
+                let rgb = if edge { [0, 0, 0] } else { [0xff, 0xff, 0xff] };
+                self.blend(x + px, y + py, rgb, 0.95);
+            }
+        }
+    }
+}
+
+/// Bitmap for `ch` from the embedded 8x8 font; bit 0 is the leftmost pixel
+fn glyph(ch: char) -> Option<[u8; 8]> {
+    use font8x8::UnicodeFonts;
+    font8x8::BASIC_FONTS
+        .get(ch)
+        .or_else(|| font8x8::LATIN_FONTS.get(ch))
+        .or_else(|| font8x8::BOX_FONTS.get(ch))
+        .or_else(|| font8x8::BLOCK_FONTS.get(ch))
+        .or_else(|| font8x8::GREEK_FONTS.get(ch))
+        .or_else(|| font8x8::MISC_FONTS.get(ch))
+}
+
+fn color_to_rgb(color: Color) -> [u8; 3] {
+    hex_to_rgb(&color_to_hex(color))
+}
+
+/// Channels of a `#rrggbb` color
+fn hex_to_rgb(hex: &str) -> [u8; 3] {
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0);
+    [channel(1), channel(3), channel(5)]
+}
+
+/// Rasterize a frame at the same size and with the same overlays as the SVG
+fn rasterize_frame(frame: &RasterFrame) -> Canvas {
+    let buffer = &frame.buffer;
+    let (cell_w, cell_h) = (CHAR_WIDTH as u32, CHAR_HEIGHT as u32);
+    let mut canvas = Canvas::new(
+        buffer.area.width as u32 * cell_w,
+        buffer.area.height as u32 * cell_h,
+    );
+
+    for y in 0..buffer.area.height {
+        for x in 0..buffer.area.width {
+            let cell = &buffer[(x, y)];
+            let style = cell.style();
+            let (px, py) = (x as u32 * cell_w, y as u32 * cell_h);
+
+            if let Some(bg) = style.bg {
+                if !matches!(bg, Color::Reset) {
+                    canvas.fill_rect(px, py, cell_w, cell_h, color_to_rgb(bg), 1.0);
+                }
+            }
+
+            let symbol = cell.symbol();
+            if !symbol.trim().is_empty() {
+                let fg = color_to_rgb(style.fg.unwrap_or(Color::White));
+                let bold = style.add_modifier.contains(ratatui::style::Modifier::BOLD);
+                if let Some(ch) = symbol.chars().next() {
+                    canvas.draw_char(ch, [px, py, cell_w, cell_h], fg, bold);
+                }
+                if style
+                    .add_modifier
+                    .contains(ratatui::style::Modifier::UNDERLINED)
+                {
+                    canvas.fill_rect(px, py + cell_h - 2, cell_w, 1, fg, 1.0);
+                }
+            }
+        }
+    }
+
+    if let Some((tx, ty)) = frame.overlay.typed_cell {
+        canvas.fill_rect(
+            tx as u32 * cell_w,
+            ty as u32 * cell_h,
+            cell_w,
+            cell_h,
+            hex_to_rgb(TYPED_HEX),
+            TYPED_OPACITY,
+        );
+    }
+
+    let (cx, cy) = frame.cursor_pos;
+    canvas.fill_rect(
+        cx as u32 * cell_w,
+        cy as u32 * cell_h,
+        cell_w,
+        cell_h,
+        CURSOR_COLOR,
+        0.85,
+    );
+
+    if let Some((mx, my)) = frame.overlay.mouse {
+        let (px, py) = (mx * cell_w as f32, my * cell_h as f32);
+        if let Some(progress) = frame.overlay.click_ripple {
+            let (radius, opacity) = ripple_shape(progress);
+            canvas.draw_ring(px, py, radius, hex_to_rgb(RIPPLE_HEX), opacity);
+        }
+        canvas.draw_pointer(px.round() as u32, py.round() as u32);
+    }
+
+    // Key indicator badge (bottom-right corner), text at twice the cell size
+    if let Some(key) = &frame.overlay.key_indicator {
+        let (glyph_w, glyph_h) = (cell_w * 2 - 2, cell_h);
+        let badge_w = key.chars().count() as u32 * glyph_w + 32;
+        let badge_h = 36;
+        let badge_x = canvas.width.saturating_sub(badge_w + 16);
+        let badge_y = canvas.height.saturating_sub(badge_h + 14);
+        canvas.fill_rect(badge_x, badge_y, badge_w, badge_h, BADGE_BORDER, 1.0);
+        canvas.fill_rect(
+            badge_x + 1,
+            badge_y + 1,
+            badge_w - 2,
+            badge_h - 2,
+            BADGE_FILL,
+            1.0,
+        );
+        for (i, ch) in key.chars().enumerate() {
+            let x = badge_x + 16 + i as u32 * glyph_w;
+            let y = badge_y + (badge_h - glyph_h) / 2;
+            canvas.draw_char(ch, [x, y, glyph_w, glyph_h], CURSOR_COLOR, true);
+        }
+    }
+
+    canvas
+}
+
+/// Encode frames into a looping GIF with their per-frame durations
+pub fn encode_gif(frames: &[RasterFrame], path: &Path) -> io::Result<()> {
+    let Some(first) = frames.first() else {
+        return Ok(());
+    };
+    let canvas = rasterize_frame(first);
+    let (width, height) = (canvas.width as u16, canvas.height as u16);
+
+    let file = io::BufWriter::new(fs::File::create(path)?);
+    let mut encoder = gif::Encoder::new(file, width, height, &[]).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    for frame in frames {
+        let mut pixels = rasterize_frame(frame).pixels;
+        let mut gif_frame = gif::Frame::from_rgba_speed(width, height, &mut pixels, 10);
+        // GIF delays are in hundredths of a second; viewers treat
+        // anything below 2 as "as slow as they like"
+        gif_frame.delay = (frame.duration_ms / 10).clamp(2, u16::MAX as u32) as u16;
+        encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+/// Encode frames into a looping APNG with their per-frame durations
+pub fn encode_apng(frames: &[RasterFrame], path: &Path) -> io::Result<()> {
+    let Some(first) = frames.first() else {
+        return Ok(());
+    };
+    let canvas = rasterize_frame(first);
+
+    let file = io::BufWriter::new(fs::File::create(path)?);
+    let mut encoder = png::Encoder::new(file, canvas.width, canvas.height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .set_animated(frames.len() as u32, 0)
+        .map_err(io::Error::other)?;
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    for frame in frames {
+        writer
+            .set_frame_delay(frame.duration_ms.min(u16::MAX as u32) as u16, 1000)
+            .map_err(io::Error::other)?;
+        writer
+            .write_image_data(&rasterize_frame(frame).pixels)
+            .map_err(io::Error::other)?;
+    }
+    writer.finish().map_err(io::Error::other)
+}
+
+// ---------------------------------------------------------------------------
+// Helpers (Catppuccin Mocha palette for the 16 named colors; RGB and the
+// 256-color palette map like exported snippets)
 // ---------------------------------------------------------------------------
 
 fn color_to_hex(color: Color) -> String {
@@ -368,7 +879,7 @@ fn color_to_hex(color: Color) -> String {
         Color::LightMagenta => "#f5c2e7".to_string(),
         Color::LightCyan => "#94e2d5".to_string(),
         Color::White => "#cdd6f4".to_string(),
-        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        Color::Rgb(..) => color_to_css(color, "#cdd6f4"),
         Color::Indexed(i) => match i {
             0 => "#1e1e2e".to_string(),
             1 => "#f38ba8".to_string(),
@@ -386,7 +897,7 @@ fn color_to_hex(color: Color) -> String {
             13 => "#f5c2e7".to_string(),
             14 => "#94e2d5".to_string(),
             15 => "#cdd6f4".to_string(),
-            _ => "#bac2de".to_string(),
+            _ => color_to_css(color, "#bac2de"),
         },
     }
 }
@@ -469,3 +980,89 @@ mod tests {
         assert!(result.join("frames/frame_0001.svg").exists());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    fn frame(text: &str, key: Option<&str>, duration_ms: u32) -> RasterFrame {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+        buffer.set_string(0, 0, text, Style::default().fg(Color::Green));
+        RasterFrame {
+            buffer,
+            cursor_pos: (text.len() as u16, 0),
+            overlay: FrameOverlay {
+                key_indicator: key.map(str::to_string),
+                mouse: Some((3.5, 2.0)),
+                click_ripple: Some(0.5),
+                typed_cell: (text.len() as u16).checked_sub(1).map(|col| (col, 0)),
+            },
+            duration_ms,
+        }
+    }
+
+    #[test]
+    fn test_encode_gif_frames_and_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("showcase.gif");
+        let frames = [
+            frame("h", None, 100),
+            frame("hi", Some("Ctrl+D"), 250),
+            frame("hi!", Some("Ctrl+D"), 1000),
+        ];
+        encode_gif(&frames, &path).unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(
+            (decoder.width(), decoder.height()),
+            (20 * CHAR_WIDTH, 5 * CHAR_HEIGHT)
+        );
+        let mut delays = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            delays.push(frame.delay);
+        }
+        assert_eq!(delays, [10, 25, 100]);
+    }
+
+    #[test]
+    fn test_encode_apng_frames_and_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("showcase.apng");
+        encode_apng(&[frame("a", None, 100), frame("ab", None, 100)], &path).unwrap();
+
+        let reader = png::Decoder::new(fs::File::open(&path).unwrap())
+            .read_info()
+            .unwrap();
+        let info = reader.info();
+        assert_eq!(
+            (info.width, info.height),
+            (20 * CHAR_WIDTH as u32, 5 * CHAR_HEIGHT as u32)
+        );
+        assert_eq!(info.animation_control.map(|ac| ac.num_frames), Some(2));
+    }
+
+    #[test]
+    fn test_ease_in_out_endpoints() {
+        assert_eq!(ease_in_out(0.0), 0.0);
+        assert_eq!(ease_in_out(0.5), 0.5);
+        assert_eq!(ease_in_out(1.0), 1.0);
+        assert!(ease_in_out(0.25) < 0.25);
+        assert!(ease_in_out(0.75) > 0.75);
+    }
+
+    #[test]
+    fn test_encode_formats_parse() {
+        assert_eq!(
+            EncodeFormats::parse("gif, apng"),
+            EncodeFormats {
+                gif: true,
+                apng: true
+            }
+        );
+        assert!(!EncodeFormats::parse("svg").any());
+    }
+}
@@ -346,6 +346,9 @@ pub struct EditorTestHarness {
     /// Enable this only in tests that focus on simple text editing operations
     enable_shadow_validation: bool,
 
+    /// Number of frames drawn so far
+    render_count: usize,
+
     /// Shadow undo stack: (content_before, undo_cursor, redo_cursor)
     /// undo_cursor = cursor position after undoing (accounts for adjust_for_edit)
     /// redo_cursor = cursor position after redoing (post-action cursor)
@@ -361,6 +364,9 @@ pub struct EditorTestHarness {
     /// Terminal dimensions for vt100
     term_width: u16,
     term_height: u16,
+
+    /// Message log of the scripted LSP server attached with `attach_lsp`
+    lsp_log: Option<PathBuf>,
 }
 
 impl EditorTestHarness {
@@ -435,6 +441,8 @@ impl EditorTestHarness {
         // Only override auto_indent if no config was explicitly provided
         if !config_was_provided {
             config.editor.auto_indent = false; // Disable for simpler testing
+            // Word completion popups would swallow Enter after typing repeated words
+            config.editor.word_completion = fresh::config::WordCompletion::Off;
         }
         // Force "default" keybinding map for consistent test behavior across platforms
         // (Config::default() uses platform-specific keymaps which breaks test assumptions)
@@ -501,11 +509,13 @@ impl EditorTestHarness {
             shadow_string: String::new(),
             shadow_cursor: 0,
             enable_shadow_validation: false,
+            render_count: 0,
             shadow_undo_stack: Vec::new(),
             shadow_redo_stack: Vec::new(),
             vt100_parser: vt100::Parser::new(height, width, 0),
             term_width: width,
             term_height: height,
+            lsp_log: None,
         })
     }
 
@@ -746,18 +756,25 @@ impl EditorTestHarness {
         Ok(())
     }
 
-    /// Send the same key press multiple times without rendering after each one
+    /// Send the same key press multiple times, batched like queued input in main.rs
     /// This is optimized for tests that need to send many keys in a row (e.g., scrolling)
-    /// Only renders once at the end, which is much faster than calling send_key() in a loop
+    /// Renders only when a key changes where input goes (e.g. opens a prompt) and
+    /// once at the end, which is much faster than calling send_key() in a loop
     pub fn send_key_repeat(
         &mut self,
         code: KeyCode,
         modifiers: KeyModifiers,
         count: usize,
     ) -> anyhow::Result<()> {
+        let mut input_target = self.editor.input_target();
         for _ in 0..count {
             // Call handle_key directly without rendering (unlike send_key which renders every time)
             self.editor.handle_key(code, modifiers)?;
+            if self.editor.input_target() != input_target {
+                let _ = self.editor.process_async_messages();
+                self.render()?;
+                input_target = self.editor.input_target();
+            }
         }
         // Process any async messages that accumulated
         let _ = self.editor.process_async_messages();
@@ -935,9 +952,15 @@ impl EditorTestHarness {
         self.terminal.draw(|frame| {
             self.editor.render(frame);
         })?;
+        self.render_count += 1;
         Ok(())
     }
 
+    /// Number of frames drawn so far
+    pub fn render_count(&self) -> usize {
+        self.render_count
+    }
+
     /// Render through the real CrosstermBackend and parse with vt100
     /// This tests the actual ANSI escape sequences, not just the buffer contents
     /// Returns the screen content as parsed by a real terminal emulator
@@ -2048,6 +2071,39 @@ impl EditorTestHarness {
         self.wait_until(move |h| h.screen_to_string().contains(&text))
     }
 
+    /// Follow a scripted LSP server's messages with `wait_for_lsp_request`
+    /// and `wait_for_lsp_notification`
+    pub fn attach_lsp(&mut self, server: &crate::common::scripted_lsp::ScriptedLspServer) {
+        self.lsp_log = Some(server.log_path().to_path_buf());
+    }
+
+    /// Wait until the attached scripted LSP server has received a `method`
+    /// request
+    pub fn wait_for_lsp_request(&mut self, method: &str) -> anyhow::Result<()> {
+        self.wait_for_lsp_message(method, true)
+    }
+
+    /// Wait until the attached scripted LSP server has received a `method`
+    /// notification, e.g. `textDocument/didChange`
+    pub fn wait_for_lsp_notification(&mut self, method: &str) -> anyhow::Result<()> {
+        self.wait_for_lsp_message(method, false)
+    }
+
+    fn wait_for_lsp_message(&mut self, method: &str, request: bool) -> anyhow::Result<()> {
+        let log = self
+            .lsp_log
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("No scripted LSP server attached"))?;
+        tracing::info!("wait_for_lsp_message: {:?}", method);
+        self.wait_until(|_| {
+            crate::common::scripted_lsp::read_log(&log)
+                .iter()
+                .any(|message| {
+                    message["method"] == method && message.get("id").is_some() == request
+                })
+        })
+    }
+
     /// Wait for buffer content to match expected value
     /// Useful for async plugin operations that modify the buffer
     pub fn wait_for_buffer_content(&mut self, expected: &str) -> anyhow::Result<()> {
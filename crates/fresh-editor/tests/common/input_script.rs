@@ -0,0 +1,542 @@
+// Input scripts - replayable sequences of input with screen checkpoints
+//
+// A script is a list of statements separated by newlines or `;`. `#` starts
+// a comment. Strings are double-quoted with `\"`, `\\`, `\n`, `\r` and `\t`
+// escapes. Scripts recorded with `fresh --record-input PATH` use the same
+// format, so a bug report's recording can be replayed as a test:
+//
+//     key Ctrl+P; type "sort lines"; key Enter
+//     expect_screen "apple"
+//
+// Statements:
+//     key COMBO [COUNT]           e.g. `key Ctrl+Shift+Left`, `key Down 5`
+//     type "TEXT"
+//     paste "TEXT"
+//     click COL ROW [MODIFIERS]   press and release the left button
+//     drag COL ROW COL ROW        left-button drag between two cells
+//     mouse KIND [BUTTON] COL ROW [MODIFIERS]
+//                                 KIND is down, up, drag, move, scroll_up,
+//                                 scroll_down, scroll_left or scroll_right
+//     resize WIDTH HEIGHT
+//     wait MILLIS                 advance the test clock
+//     wait_for "TEXT"             wait until the screen shows TEXT
+//     expect_screen "TEXT"
+//     expect_not_screen "TEXT"
+//     expect_buffer "TEXT"        the active buffer's whole content
+//
+// When an expectation fails, the screen is written to
+// `$CARGO_TARGET_TMPDIR/input-scripts/<script>-line<N>.txt` and the error
+// shows it too.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context};
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use fresh::input::keybindings::KeybindingResolver;
+
+use super::harness::EditorTestHarness;
+
+/// One statement of an input script
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptStep {
+    Key {
+        code: KeyCode,
+        modifiers: KeyModifiers,
+        count: usize,
+    },
+    Type(String),
+    Paste(String),
+    Click {
+        col: u16,
+        row: u16,
+        modifiers: KeyModifiers,
+    },
+    Drag {
+        from: (u16, u16),
+        to: (u16, u16),
+    },
+    Mouse(MouseEvent),
+    Resize {
+        width: u16,
+        height: u16,
+    },
+    Wait(Duration),
+    WaitFor(String),
+    ExpectScreen(String),
+    ExpectNotScreen(String),
+    ExpectBuffer(String),
+}
+
+/// A parsed statement and the line it starts on
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptLine {
+    pub line: usize,
+    pub step: ScriptStep,
+}
+
+/// A word or a quoted string
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+}
+
+impl Token {
+    fn text(&self) -> &str {
+        match self {
+            Token::Word(text) | Token::Quoted(text) => text,
+        }
+    }
+}
+
+/// Split a script into statements of tokens, each with its line number
+fn tokenize(source: &str) -> Result<Vec<(usize, Vec<Token>)>, String> {
+    let mut statements = Vec::new();
+    let mut current: Vec<Token> = Vec::new();
+    let mut start_line = 1;
+    let mut line = 1;
+    let mut chars = source.chars().peekable();
+
+    let mut finish = |current: &mut Vec<Token>, start_line: usize| {
+        if !current.is_empty() {
+            statements.push((start_line, std::mem::take(current)));
+        }
+    };
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\n' | ';' => {
+                finish(&mut current, start_line);
+                if ch == '\n' {
+                    line += 1;
+                }
+            }
+            '#' => {
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            c if c.is_whitespace() => {}
+            '"' => {
+                if current.is_empty() {
+                    start_line = line;
+                }
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('n') => text.push('\n'),
+                            Some('r') => text.push('\r'),
+                            Some('t') => text.push('\t'),
+                            Some(c @ ('"' | '\\')) => text.push(c),
+                            Some(c) => {
+                                return Err(format!("line {}: unknown escape \\{}", line, c))
+                            }
+                            None => return Err(format!("line {}: unterminated string", line)),
+                        },
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            text.push(c);
+                        }
+                        None => return Err(format!("line {}: unterminated string", line)),
+                    }
+                }
+                current.push(Token::Quoted(text));
+            }
+            c => {
+                if current.is_empty() {
+                    start_line = line;
+                }
+                let mut word = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, ';' | '#' | '"') {
+                        break;
+                    }
+                    word.push(next);
+                    chars.next();
+                }
+                current.push(Token::Word(word));
+            }
+        }
+    }
+    finish(&mut current, start_line);
+    Ok(statements)
+}
+
+/// Modifier names joined by `+`, e.g. "Ctrl+Shift"
+fn parse_modifiers(text: &str) -> Result<KeyModifiers, String> {
+    let mut modifiers = KeyModifiers::NONE;
+    for name in text.split('+').filter(|name| !name.is_empty()) {
+        modifiers |= match name.to_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            "super" | "cmd" | "command" | "meta" => KeyModifiers::SUPER,
+            _ => return Err(format!("unknown modifier '{}'", name)),
+        };
+    }
+    Ok(modifiers)
+}
+
+/// A key combination such as "Ctrl+Shift+Left", "Enter" or "Ctrl++"
+pub fn parse_key_combo(combo: &str) -> Result<(KeyCode, KeyModifiers), String> {
+    let (modifiers, key) = if combo == "+" {
+        ("", "+")
+    } else if let Some(modifiers) = combo.strip_suffix("++") {
+        (modifiers, "+")
+    } else {
+        combo.rsplit_once('+').unwrap_or(("", combo))
+    };
+    let modifiers = parse_modifiers(modifiers)?;
+    // Letters are uppercase exactly when Shift is held, like terminals
+    // report them, so `Ctrl+P` and `Ctrl+p` are the same key
+    let mut chars = key.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(ch), None) if modifiers.contains(KeyModifiers::SHIFT) => {
+            KeyCode::Char(ch.to_ascii_uppercase())
+        }
+        (Some(ch), None) => KeyCode::Char(ch.to_ascii_lowercase()),
+        _ => KeybindingResolver::parse_key_public(key)
+            .ok_or_else(|| format!("unknown key '{}'", key))?,
+    };
+    Ok((code, modifiers))
+}
+
+fn parse_number<T: std::str::FromStr>(token: Option<&Token>, what: &str) -> Result<T, String> {
+    let token = token.ok_or_else(|| format!("missing {}", what))?;
+    token
+        .text()
+        .parse()
+        .map_err(|_| format!("{} must be a number, got '{}'", what, token.text()))
+}
+
+fn parse_button(name: &str) -> Result<MouseButton, String> {
+    match name {
+        "left" => Ok(MouseButton::Left),
+        "right" => Ok(MouseButton::Right),
+        "middle" => Ok(MouseButton::Middle),
+        _ => Err(format!("unknown mouse button '{}'", name)),
+    }
+}
+
+/// `mouse KIND [BUTTON] COL ROW [MODIFIERS]`
+fn parse_mouse(args: &[Token]) -> Result<MouseEvent, String> {
+    let kind_name = args.first().ok_or("missing mouse event kind")?.text();
+    let with_button = |make: fn(MouseButton) -> MouseEventKind| -> Result<_, String> {
+        let button = args.get(1).ok_or("missing mouse button")?.text();
+        Ok((make(parse_button(button)?), 2))
+    };
+    let (kind, position_at) = match kind_name {
+        "down" => with_button(MouseEventKind::Down)?,
+        "up" => with_button(MouseEventKind::Up)?,
+        "drag" => with_button(MouseEventKind::Drag)?,
+        "move" => (MouseEventKind::Moved, 1),
+        "scroll_up" => (MouseEventKind::ScrollUp, 1),
+        "scroll_down" => (MouseEventKind::ScrollDown, 1),
+        "scroll_left" => (MouseEventKind::ScrollLeft, 1),
+        "scroll_right" => (MouseEventKind::ScrollRight, 1),
+        _ => return Err(format!("unknown mouse event kind '{}'", kind_name)),
+    };
+    let modifiers = match args.get(position_at + 2) {
+        Some(token) => parse_modifiers(token.text())?,
+        None => KeyModifiers::NONE,
+    };
+    Ok(MouseEvent {
+        kind,
+        column: parse_number(args.get(position_at), "column")?,
+        row: parse_number(args.get(position_at + 1), "row")?,
+        modifiers,
+    })
+}
+
+/// The single string argument of a statement
+fn parse_string(args: &[Token], command: &str) -> Result<String, String> {
+    match args {
+        [Token::Quoted(text)] => Ok(text.clone()),
+        _ => Err(format!("{} takes one quoted string", command)),
+    }
+}
+
+fn parse_step(command: &str, args: &[Token]) -> Result<ScriptStep, String> {
+    Ok(match command {
+        "key" => {
+            let combo = args.first().ok_or("missing key")?.text();
+            let (code, modifiers) = parse_key_combo(combo)?;
+            let count = match args.get(1) {
+                Some(_) => parse_number(args.get(1), "count")?,
+                None => 1,
+            };
+            ScriptStep::Key {
+                code,
+                modifiers,
+                count,
+            }
+        }
+        "type" => ScriptStep::Type(parse_string(args, command)?),
+        "paste" => ScriptStep::Paste(parse_string(args, command)?),
+        "click" => ScriptStep::Click {
+            col: parse_number(args.first(), "column")?,
+            row: parse_number(args.get(1), "row")?,
+            modifiers: match args.get(2) {
+                Some(token) => parse_modifiers(token.text())?,
+                None => KeyModifiers::NONE,
+            },
+        },
+        "drag" => ScriptStep::Drag {
+            from: (
+                parse_number(args.first(), "column")?,
+                parse_number(args.get(1), "row")?,
+            ),
+            to: (
+                parse_number(args.get(2), "column")?,
+                parse_number(args.get(3), "row")?,
+            ),
+        },
+        "mouse" => ScriptStep::Mouse(parse_mouse(args)?),
+        "resize" => ScriptStep::Resize {
+            width: parse_number(args.first(), "width")?,
+            height: parse_number(args.get(1), "height")?,
+        },
+        "wait" => ScriptStep::Wait(Duration::from_millis(parse_number(
+            args.first(),
+            "milliseconds",
+        )?)),
+        "wait_for" => ScriptStep::WaitFor(parse_string(args, command)?),
+        "expect_screen" => ScriptStep::ExpectScreen(parse_string(args, command)?),
+        "expect_not_screen" => ScriptStep::ExpectNotScreen(parse_string(args, command)?),
+        "expect_buffer" => ScriptStep::ExpectBuffer(parse_string(args, command)?),
+        _ => return Err(format!("unknown statement '{}'", command)),
+    })
+}
+
+/// Parse an input script
+pub fn parse_script(source: &str) -> anyhow::Result<Vec<ScriptLine>> {
+    tokenize(source)
+        .map_err(|e| anyhow!(e))?
+        .into_iter()
+        .map(|(line, tokens)| {
+            let Token::Word(command) = &tokens[0] else {
+                bail!("line {}: expected a statement, got a string", line);
+            };
+            let step =
+                parse_step(command, &tokens[1..]).map_err(|e| anyhow!("line {}: {}", line, e))?;
+            Ok(ScriptLine { line, step })
+        })
+        .collect()
+}
+
+/// Where a failed expectation's screen is written
+fn failure_dump_path(script_name: &str, line: usize) -> PathBuf {
+    let name: String = script_name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("input-scripts")
+        .join(format!("{}-line{}.txt", name, line))
+}
+
+impl EditorTestHarness {
+    /// Replay an input script, failing at the first unmet expectation
+    pub fn run_script(&mut self, script_name: &str, source: &str) -> anyhow::Result<()> {
+        let steps = parse_script(source).with_context(|| format!("in script {}", script_name))?;
+        for ScriptLine { line, step } in steps {
+            if let Some(failure) = self.run_script_step(&step)? {
+                let screen = self.screen_to_string();
+                let dump_path = failure_dump_path(script_name, line);
+                if let Some(parent) = dump_path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&dump_path, &screen)?;
+                bail!(
+                    "{} line {}: {}\nScreen (saved to {}):\n{}",
+                    script_name,
+                    line,
+                    failure,
+                    dump_path.display(),
+                    screen
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Replay an input script file, e.g. one saved by `--record-input`
+    pub fn run_script_file(&mut self, path: &Path) -> anyhow::Result<()> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read script {}", path.display()))?;
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        self.run_script(&name, &source)
+    }
+
+    /// Run one step, returning a description of the failure for an unmet
+    /// expectation
+    fn run_script_step(&mut self, step: &ScriptStep) -> anyhow::Result<Option<String>> {
+        match step {
+            ScriptStep::Key {
+                code,
+                modifiers,
+                count,
+            } => {
+                for _ in 0..*count {
+                    self.send_key(*code, *modifiers)?;
+                }
+            }
+            ScriptStep::Type(text) => self.type_text(text)?,
+            ScriptStep::Paste(text) => {
+                self.editor_mut().paste_text(text.clone());
+                self.render()?;
+            }
+            ScriptStep::Click {
+                col,
+                row,
+                modifiers,
+            } => {
+                for kind in [
+                    MouseEventKind::Down(MouseButton::Left),
+                    MouseEventKind::Up(MouseButton::Left),
+                ] {
+                    self.send_mouse(MouseEvent {
+                        kind,
+                        column: *col,
+                        row: *row,
+                        modifiers: *modifiers,
+                    })?;
+                }
+                self.render()?;
+            }
+            ScriptStep::Drag { from, to } => self.mouse_drag(from.0, from.1, to.0, to.1)?,
+            ScriptStep::Mouse(event) => {
+                self.send_mouse(*event)?;
+                self.render()?;
+            }
+            ScriptStep::Resize { width, height } => self.resize(*width, *height)?,
+            ScriptStep::Wait(duration) => {
+                // Logical time only, so debounces expire without slowing
+                // the test; use `wait_for` to wait on async work
+                self.advance_time(*duration);
+                self.process_async_and_render()?;
+            }
+            ScriptStep::WaitFor(text) => self.wait_for_screen_contains(text)?,
+            ScriptStep::ExpectScreen(text) => {
+                if !self.screen_to_string().contains(text.as_str()) {
+                    return Ok(Some(format!("expected screen to contain {:?}", text)));
+                }
+            }
+            ScriptStep::ExpectNotScreen(text) => {
+                if self.screen_to_string().contains(text.as_str()) {
+                    return Ok(Some(format!("expected screen not to contain {:?}", text)));
+                }
+            }
+            ScriptStep::ExpectBuffer(expected) => {
+                let content = self.get_buffer_content();
+                if content.as_deref() != Some(expected.as_str()) {
+                    return Ok(Some(format!(
+                        "expected buffer {:?}, got {:?}",
+                        expected, content
+                    )));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_script() {
+        let steps = parse_script(
+            "key Ctrl+P; type \"sort lines\" # open the palette\n\
+             key Down 3\n\
+             paste \"a;b\\n\"\n\
+             mouse down left 10 4 Shift; click 3 2\n\
+             resize 100 30; wait 250\n\
+             expect_screen \"apple\"",
+        )
+        .unwrap();
+        let steps: Vec<(usize, ScriptStep)> = steps.into_iter().map(|s| (s.line, s.step)).collect();
+        assert_eq!(
+            steps,
+            [
+                (
+                    1,
+                    ScriptStep::Key {
+                        code: KeyCode::Char('p'),
+                        modifiers: KeyModifiers::CONTROL,
+                        count: 1
+                    }
+                ),
+                (1, ScriptStep::Type("sort lines".to_string())),
+                (
+                    2,
+                    ScriptStep::Key {
+                        code: KeyCode::Down,
+                        modifiers: KeyModifiers::NONE,
+                        count: 3
+                    }
+                ),
+                (3, ScriptStep::Paste("a;b\n".to_string())),
+                (
+                    4,
+                    ScriptStep::Mouse(MouseEvent {
+                        kind: MouseEventKind::Down(MouseButton::Left),
+                        column: 10,
+                        row: 4,
+                        modifiers: KeyModifiers::SHIFT,
+                    })
+                ),
+                (
+                    4,
+                    ScriptStep::Click {
+                        col: 3,
+                        row: 2,
+                        modifiers: KeyModifiers::NONE
+                    }
+                ),
+                (
+                    5,
+                    ScriptStep::Resize {
+                        width: 100,
+                        height: 30
+                    }
+                ),
+                (5, ScriptStep::Wait(Duration::from_millis(250))),
+                (6, ScriptStep::ExpectScreen("apple".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_key_combo() {
+        assert_eq!(
+            parse_key_combo("Ctrl+Shift+Left"),
+            Ok((KeyCode::Left, KeyModifiers::CONTROL | KeyModifiers::SHIFT))
+        );
+        assert_eq!(
+            parse_key_combo("Ctrl++"),
+            Ok((KeyCode::Char('+'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            parse_key_combo("F5"),
+            Ok((KeyCode::F(5), KeyModifiers::NONE))
+        );
+        assert!(parse_key_combo("Hyper+x").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors_name_the_line() {
+        let error = parse_script("type \"ok\"\nkey Nope").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: unknown key 'Nope'");
+        assert!(parse_script("type \"open").is_err());
+    }
+}
@@ -17,6 +17,12 @@ pub mod git_test_helper;
 pub mod harness;
 #[cfg(test)]
 #[allow(dead_code)]
+pub mod input_script;
+#[cfg(test)]
+#[allow(dead_code)]
+pub mod scripted_lsp;
+#[cfg(test)]
+#[allow(dead_code)]
 pub mod scrollbar;
 #[cfg(test)]
 #[allow(dead_code)]
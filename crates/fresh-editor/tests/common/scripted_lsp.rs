@@ -0,0 +1,323 @@
+//! Scripted fake LSP server written in Rust
+//!
+//! Unlike the Bash servers in `fake_lsp.rs`, this one runs on every platform:
+//! the editor launches the test binary itself with `--scripted-lsp-server`,
+//! and a constructor function below serves JSON-RPC over stdio before the
+//! test runner starts.
+//!
+//! Responses are scripted per method, and notifications can be pushed after a
+//! given message arrives. Every message the server receives is appended to a
+//! log, so tests can assert on `didOpen`/`didChange` contents and wait for
+//! requests with `EditorTestHarness::wait_for_lsp_request`.
+//!
+//! ```ignore
+//! let lsp = ScriptedLspServer::new()
+//!     .capability("foldingRangeProvider", json!(true))
+//!     .respond("textDocument/foldingRange", json!([{"startLine": 10, "endLine": 30}]));
+//! config.lsp.insert("rust".to_string(), lsp.server_config()?);
+//! let mut harness = EditorTestHarness::with_config(80, 24, config)?;
+//! harness.attach_lsp(&lsp);
+//! harness.open_file(&path)?;
+//! harness.wait_for_lsp_request("textDocument/foldingRange")?;
+//! ```
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+/// Argument that turns the test binary into the server
+const SERVER_ARG: &str = "--scripted-lsp-server";
+
+/// Placeholder replaced by the triggering message's document URI
+const URI_PLACEHOLDER: &str = "$uri";
+
+/// What the server answers, shared with the server process as JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LspScript {
+    capabilities: serde_json::Map<String, Value>,
+    /// Result per request method; other requests get `null`
+    responses: HashMap<String, Value>,
+    pushes: Vec<Push>,
+    /// Where received messages are appended, one JSON object per line
+    log_path: PathBuf,
+}
+
+/// A notification sent after a message with method `after` arrives
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Push {
+    after: String,
+    method: String,
+    params: Value,
+}
+
+/// A scripted LSP server for one test
+pub struct ScriptedLspServer {
+    dir: TempDir,
+    script: LspScript,
+}
+
+impl ScriptedLspServer {
+    /// A server with full text sync and semantic tokens, answering
+    /// `null` to every request until scripted otherwise
+    pub fn new() -> Self {
+        let dir = tempfile::tempdir().expect("create scripted LSP directory");
+        let capabilities = json!({
+            "textDocumentSync": 1,
+            "semanticTokensProvider": {
+                "legend": {
+                    "tokenTypes": ["keyword", "function", "variable"],
+                    "tokenModifiers": ["declaration", "deprecated"]
+                },
+                "full": {"delta": true},
+                "range": true
+            }
+        });
+        let script = LspScript {
+            capabilities: capabilities.as_object().cloned().unwrap_or_default(),
+            responses: HashMap::from([(
+                "textDocument/semanticTokens/full".to_string(),
+                json!({"resultId": "1", "data": [0, 0, 2, 0, 0, 0, 3, 4, 1, 0]}),
+            )]),
+            pushes: Vec::new(),
+            log_path: dir.path().join("received.jsonl"),
+        };
+        Self { dir, script }
+    }
+
+    /// Advertise a server capability, e.g. `("foldingRangeProvider", true)`
+    pub fn capability(mut self, name: &str, value: Value) -> Self {
+        self.script.capabilities.insert(name.to_string(), value);
+        self
+    }
+
+    /// Answer every `method` request with `result`
+    ///
+    /// String values equal to `"$uri"` become the request's document URI.
+    pub fn respond(mut self, method: &str, result: Value) -> Self {
+        self.script.responses.insert(method.to_string(), result);
+        self
+    }
+
+    /// Send a `method` notification each time a message `after` arrives,
+    /// e.g. `publishDiagnostics` after `textDocument/didSave`
+    pub fn push_after(mut self, after: &str, method: &str, params: Value) -> Self {
+        self.script.pushes.push(Push {
+            after: after.to_string(),
+            method: method.to_string(),
+            params,
+        });
+        self
+    }
+
+    /// Server config launching this script; call after scripting
+    pub fn server_config(&self) -> anyhow::Result<fresh::services::lsp::LspServerConfig> {
+        let script_path = self.dir.path().join("script.json");
+        fs::write(&script_path, serde_json::to_string(&self.script)?)?;
+        Ok(fresh::services::lsp::LspServerConfig {
+            command: std::env::current_exe()?.to_string_lossy().to_string(),
+            args: vec![
+                SERVER_ARG.to_string(),
+                script_path.to_string_lossy().to_string(),
+            ],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+        })
+    }
+
+    /// Where received messages are logged
+    pub fn log_path(&self) -> &Path {
+        &self.script.log_path
+    }
+
+    /// All messages received so far, in order
+    pub fn received(&self) -> Vec<Value> {
+        read_log(self.log_path())
+    }
+
+    /// Params of every `method` notification received so far
+    pub fn notifications(&self, method: &str) -> Vec<Value> {
+        self.received()
+            .into_iter()
+            .filter(|message| message["method"] == method && message.get("id").is_none())
+            .map(|message| message["params"].clone())
+            .collect()
+    }
+
+    /// Params of every `method` request received so far
+    pub fn requests(&self, method: &str) -> Vec<Value> {
+        self.received()
+            .into_iter()
+            .filter(|message| message["method"] == method && message.get("id").is_some())
+            .map(|message| message["params"].clone())
+            .collect()
+    }
+}
+
+impl Default for ScriptedLspServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Messages in a server log; a missing log means nothing arrived yet
+pub fn read_log(path: &Path) -> Vec<Value> {
+    fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Serve the script when the test binary is launched as the server
+#[ctor::ctor]
+fn run_scripted_lsp_server() {
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() != Some(SERVER_ARG) {
+        return;
+    }
+    let status = match args.next() {
+        Some(script_path) => match serve(Path::new(&script_path)) {
+            Ok(()) => 0,
+            Err(e) => {
+                eprintln!("scripted LSP server failed: {e}");
+                1
+            }
+        },
+        None => {
+            eprintln!("usage: {SERVER_ARG} SCRIPT");
+            2
+        }
+    };
+    std::process::exit(status);
+}
+
+fn serve(script_path: &Path) -> io::Result<()> {
+    let script: LspScript = serde_json::from_str(&fs::read_to_string(script_path)?)?;
+    let mut log = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&script.log_path)?;
+    let mut input = BufReader::new(io::stdin().lock());
+    let mut output = io::stdout().lock();
+
+    while let Some(message) = read_message(&mut input)? {
+        writeln!(log, "{message}")?;
+        log.flush()?;
+
+        let method = message["method"].as_str().unwrap_or_default();
+        let uri = message["params"]["textDocument"]["uri"].clone();
+        if let Some(id) = message.get("id") {
+            let result = match method {
+                "initialize" => json!({"capabilities": script.capabilities}),
+                _ => script.responses.get(method).cloned().unwrap_or(Value::Null),
+            };
+            write_message(
+                &mut output,
+                &json!({"jsonrpc": "2.0", "id": id, "result": substitute_uri(result, &uri)}),
+            )?;
+        }
+        for push in script.pushes.iter().filter(|push| push.after == method) {
+            write_message(
+                &mut output,
+                &json!({
+                    "jsonrpc": "2.0",
+                    "method": push.method,
+                    "params": substitute_uri(push.params.clone(), &uri),
+                }),
+            )?;
+        }
+        if method == "exit" {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Read one `Content-Length` framed message; None at end of input
+fn read_message(input: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                content_length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+    let length = content_length
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing Content-Length"))?;
+    let mut body = vec![0; length];
+    input.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+fn write_message(output: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = message.to_string();
+    write!(output, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    output.flush()
+}
+
+/// Replace `"$uri"` strings anywhere in `value`
+fn substitute_uri(value: Value, uri: &Value) -> Value {
+    match value {
+        Value::String(s) if s == URI_PLACEHOLDER => uri.clone(),
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| substitute_uri(item, uri))
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, item)| (key, substitute_uri(item, uri)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_round_trip() {
+        let mut framed = Vec::new();
+        write_message(&mut framed, &json!({"method": "initialized"})).unwrap();
+        write_message(&mut framed, &json!({"id": 1, "method": "shutdown"})).unwrap();
+
+        let mut input = io::Cursor::new(framed);
+        assert_eq!(
+            read_message(&mut input).unwrap(),
+            Some(json!({"method": "initialized"}))
+        );
+        assert_eq!(
+            read_message(&mut input).unwrap(),
+            Some(json!({"id": 1, "method": "shutdown"}))
+        );
+        assert_eq!(read_message(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn test_substitute_uri() {
+        let uri = json!("file:///a.rs");
+        assert_eq!(
+            substitute_uri(json!({"uri": "$uri", "items": ["$uri", 1]}), &uri),
+            json!({"uri": "file:///a.rs", "items": ["file:///a.rs", 1]})
+        );
+    }
+}
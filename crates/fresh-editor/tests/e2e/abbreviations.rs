@@ -0,0 +1,108 @@
+//! Tests for abbreviation expansion.
+//!
+//! Tests that:
+//! - A word boundary after an abbreviation expands it, following its case
+//! - Backspace right after an expansion puts the abbreviation back
+//! - Language abbreviations win over the global ones
+//! - `editor.abbreviations_in_code_only` leaves comments alone
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::collections::HashMap;
+
+fn config_with(abbreviations: &[(&str, &str)]) -> Config {
+    let mut config = Config::default();
+    config.editor.auto_indent = false;
+    config.editor.auto_close = false;
+    config.abbreviations = abbreviations
+        .iter()
+        .map(|(abbreviation, expansion)| (abbreviation.to_string(), expansion.to_string()))
+        .collect();
+    config
+}
+
+/// Typed boundaries expand abbreviations in the case they were typed in.
+#[test]
+fn test_abbreviation_expands_on_word_boundary() {
+    let config = config_with(&[("teh", "the"), ("fnmain", "fn main() {}")]);
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.type_text("teh Teh TEH, tehx ").unwrap();
+    harness.assert_buffer_content("the The THE, tehx ");
+
+    harness.type_text("fnmain").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("the The THE, tehx fn main() {}\n");
+}
+
+/// Backspace right after an expansion restores the abbreviation once.
+#[test]
+fn test_backspace_reverts_expansion() {
+    let config = config_with(&[("teh", "the")]);
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.type_text("teh ").unwrap();
+    harness.assert_buffer_content("the ");
+
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("teh ");
+    assert_eq!(harness.cursor_position(), 4);
+
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("teh");
+
+    // Only right after the expansion
+    harness.type_text(" x").unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("the");
+}
+
+/// A language's own table takes precedence over the global one.
+#[test]
+fn test_language_abbreviations_take_precedence() {
+    let mut config = config_with(&[("pr", "print"), ("teh", "the")]);
+    config.languages.get_mut("rust").unwrap().abbreviations =
+        Some(HashMap::from([("pr".to_string(), "println!".to_string())]));
+
+    let fixture = TestFixture::new("main.rs", "").unwrap();
+    let mut harness = EditorTestHarness::with_config(80, 24, config.clone()).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.type_text("pr(teh ").unwrap();
+    harness.assert_buffer_content("println!(the ");
+
+    let fixture = TestFixture::new("notes.txt", "").unwrap();
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.type_text("pr(").unwrap();
+    harness.assert_buffer_content("print(");
+}
+
+/// With `abbreviations_in_code_only`, comments keep what was typed.
+#[test]
+fn test_abbreviations_in_code_only() {
+    let mut config = config_with(&[("teh", "the")]);
+    config.editor.abbreviations_in_code_only = true;
+
+    let fixture = TestFixture::new("code.rs", "// teh\nteh\n").unwrap();
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text(" ").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text(" ").unwrap();
+    harness.assert_buffer_content("// teh \nthe \n");
+}
@@ -0,0 +1,139 @@
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(">command"))
+        .unwrap();
+    harness.type_text(command).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Cursors at the end of lines of different lengths are padded to the longest
+#[test]
+fn test_align_cursors_at_line_ends() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("x\nlonger\nmid").unwrap();
+
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.editor_mut().add_cursor_below();
+    harness.editor_mut().add_cursor_below();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_count(), 3);
+
+    run_command(&mut harness, "align cursors");
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "x     \nlonger\nmid   "
+    );
+    assert_eq!(harness.cursor_count(), 3);
+}
+
+/// All padding inserted by one alignment is undone in a single step
+#[test]
+fn test_align_cursors_single_undo_step() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("x\nlonger\nmid").unwrap();
+
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.editor_mut().add_cursor_below();
+    harness.editor_mut().add_cursor_below();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    run_command(&mut harness, "align cursors");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "x     \nlonger\nmid   "
+    );
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "x\nlonger\nmid");
+}
+
+/// Align by Pattern puts a cursor on the first match in each selected line
+#[test]
+fn test_align_by_pattern() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("a = 1\nbbb = 2\ncc = 3").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "align by pattern");
+    harness.type_text("=").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "a   = 1\nbbb = 2\ncc  = 3"
+    );
+    assert_eq!(harness.cursor_count(), 3);
+}
+
+/// A capture group marks where the cursor goes within the match
+#[test]
+fn test_align_by_pattern_capture_group() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("let a: u8\nlet long: u32").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "align by pattern");
+    harness.type_text(":( )").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "let a:    u8\nlet long: u32"
+    );
+}
+
+/// An invalid regex leaves the buffer untouched
+#[test]
+fn test_align_by_pattern_invalid_regex() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("a = 1\nbbb = 2").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "align by pattern");
+    harness.type_text("(").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "a = 1\nbbb = 2");
+    harness.assert_screen_contains("Invalid regex");
+}
@@ -2047,3 +2047,62 @@ fn test_go_nested_auto_indent_uses_only_tabs() {
         "Nested Go indent should be two tabs"
     );
 }
+
+/// Typing the colon of `else:` dedents the line to its `if`; pasting the same
+/// text leaves it as it is
+#[test]
+fn test_python_else_dedents_on_colon_but_not_on_paste() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.py");
+    std::fs::write(&file_path, "if a:\n    b()").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("else").unwrap();
+    harness.assert_buffer_content("if a:\n    b()\n    else");
+    harness.type_text(":").unwrap();
+    harness.assert_buffer_content("if a:\n    b()\nelse:");
+
+    harness
+        .editor_mut()
+        .paste_text("\nif c:\n    d()\n    else:".to_string());
+    harness.assert_buffer_content("if a:\n    b()\nelse:\nif c:\n    d()\n    else:");
+}
+
+/// A language's increase rule can be set in config
+#[test]
+fn test_configured_increase_indent_pattern() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "loop_start ->").unwrap();
+
+    let mut config = Config::default();
+    config.editor.auto_indent = true;
+    config.languages.get_mut("rust").unwrap().indent_rules =
+        Some(fresh::config::IndentRulesConfig {
+            increase_indent_pattern: Some(r"->\s*$".to_string()),
+            ..Default::default()
+        });
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("loop_start ->\n    ");
+}
@@ -466,3 +466,88 @@ fn test_auto_revert_with_temp_rename_save() {
         harness.assert_buffer_content(&new_content);
     }
 }
+
+/// Test that a file renamed over the open one is picked up even when it
+/// carries an older modification time (e.g. a backup put back in place)
+#[test]
+fn test_auto_revert_rename_over_with_older_mtime() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("restored.txt");
+
+    write_and_sync(&file_path, "Current content");
+    harness.open_file(&file_path).unwrap();
+
+    let temp_path = project_dir.join(".restored.txt.bak");
+    write_and_sync(&temp_path, "Older content");
+    let older = std::time::SystemTime::now() - Duration::from_secs(3600);
+    File::options()
+        .write(true)
+        .open(&temp_path)
+        .unwrap()
+        .set_modified(older)
+        .unwrap();
+    fs::rename(&temp_path, &file_path).unwrap();
+
+    harness
+        .wait_until(|h| h.get_buffer_content().unwrap() == "Older content")
+        .unwrap();
+}
+
+/// Test that deleting the open file keeps the buffer and marks it deleted,
+/// and that the file coming back is reloaded
+#[test]
+fn test_auto_revert_file_deleted_on_disk() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("doomed.txt");
+
+    write_and_sync(&file_path, "Still here");
+    harness.open_file(&file_path).unwrap();
+
+    fs::remove_file(&file_path).unwrap();
+    harness
+        .wait_until(|h| h.get_status_bar().contains("[deleted]"))
+        .unwrap();
+    harness.assert_buffer_content("Still here");
+
+    harness.sleep(FILE_CHANGE_DELAY);
+    write_and_sync(&file_path, "Back again");
+    harness
+        .wait_until(|h| h.get_buffer_content().unwrap() == "Back again")
+        .unwrap();
+    assert!(!harness.get_status_bar().contains("[deleted]"));
+}
+
+/// Test that a change on disk under unsaved changes asks before reloading
+#[test]
+fn test_external_change_with_unsaved_changes_prompts() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("conflict.txt");
+
+    write_and_sync(&file_path, "Original");
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(" edited").unwrap();
+
+    harness.sleep(FILE_CHANGE_DELAY);
+    write_and_sync(&file_path, "Changed elsewhere");
+    harness
+        .wait_until(|h| {
+            h.screen_to_string()
+                .contains("conflict.txt changed on disk")
+        })
+        .unwrap();
+    harness.assert_buffer_content("Original edited");
+
+    harness.type_text("r").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("Changed elsewhere");
+}
@@ -0,0 +1,108 @@
+//! E2E tests for batch mode (`fresh --batch SCRIPT FILES`)
+//!
+//! The steps run against a harness editor, and the command line is checked by
+//! running the binary on temporary files.
+
+use crate::common::harness::EditorTestHarness;
+use fresh::app::batch::parse_batch_script;
+
+fn run_steps(harness: &mut EditorTestHarness, script: &str) -> Result<(), String> {
+    for line in parse_batch_script(script)? {
+        harness.editor_mut().run_batch_step(&line.step)?;
+    }
+    Ok(())
+}
+
+/// Steps edit the active buffer through the editor's own actions
+#[test]
+fn test_batch_steps_edit_buffer() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let path = temp_dir.path().join("fruit.txt");
+    std::fs::write(&path, "cherry\napple\nbanana")?;
+
+    let mut harness = EditorTestHarness::new(80, 24)?;
+    harness.open_file(&path)?;
+    run_steps(
+        &mut harness,
+        r#"action select_all; action sort_lines
+           replace "an" "AN"
+           goto 3; action move_line_end; type "!"
+           expect_buffer "apple\nbANANa\ncherry!"
+           save"#,
+    )
+    .map_err(anyhow::Error::msg)?;
+
+    assert_eq!(std::fs::read_to_string(&path)?, "apple\nbANANa\ncherry!");
+    Ok(())
+}
+
+/// Steps that find nothing or need an answer fail instead of doing nothing
+#[test]
+fn test_batch_steps_report_failures() -> anyhow::Result<()> {
+    let mut harness = EditorTestHarness::new(80, 24)?;
+    run_steps(&mut harness, r#"type "one two""#).map_err(anyhow::Error::msg)?;
+
+    let error = run_steps(&mut harness, r#"search "three""#).unwrap_err();
+    assert!(error.contains("three"), "unexpected error: {}", error);
+
+    let error = run_steps(&mut harness, r#"replace "three" "3""#).unwrap_err();
+    assert!(error.contains("three"), "unexpected error: {}", error);
+
+    let error = run_steps(&mut harness, "action goto_line").unwrap_err();
+    assert!(
+        error.contains("batch mode can't answer"),
+        "unexpected error: {}",
+        error
+    );
+    assert!(!harness.editor().is_prompting());
+
+    let error = run_steps(&mut harness, r#"expect_buffer "one""#).unwrap_err();
+    assert_eq!(error, r#"buffer is "one two", expected "one""#);
+    Ok(())
+}
+
+/// `fresh --batch` saves the files, or prints them with `--stdout`, and
+/// exits non-zero naming the step that failed
+#[test]
+#[cfg(unix)]
+fn test_batch_command_line() -> anyhow::Result<()> {
+    use std::process::Command;
+
+    let temp_dir = tempfile::tempdir()?;
+    let file = temp_dir.path().join("main.txt");
+    std::fs::write(&file, "foo bar foo\n")?;
+    let script = temp_dir.path().join("rename.fresh");
+    std::fs::write(&script, "replace \"foo\" \"baz\"\n")?;
+
+    let fresh = |args: &[&std::path::Path]| {
+        Command::new(env!("CARGO_BIN_EXE_fresh"))
+            .arg("--no-plugins")
+            .args(args)
+            .current_dir(temp_dir.path())
+            .env("HOME", temp_dir.path())
+            .env("XDG_CONFIG_HOME", temp_dir.path().join("config"))
+            .env("XDG_DATA_HOME", temp_dir.path().join("data"))
+            .env("XDG_STATE_HOME", temp_dir.path().join("state"))
+            .output()
+    };
+
+    let output = fresh(&["--batch".as_ref(), &script, "--stdout".as_ref(), &file])?;
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "baz bar baz\n");
+    assert_eq!(std::fs::read_to_string(&file)?, "foo bar foo\n");
+
+    let output = fresh(&["--batch".as_ref(), &script, &file])?;
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(std::fs::read_to_string(&file)?, "baz bar baz\n");
+
+    // Nothing is left to replace now
+    let output = fresh(&["--batch".as_ref(), &script, &file])?;
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("rename.fresh:1: replace \"foo\" \"baz\""),
+        "unexpected stderr: {}",
+        stderr
+    );
+    Ok(())
+}
@@ -5,7 +5,8 @@
 //
 // Usage:
 //   cargo test --package fresh-editor --test e2e_tests blog_showcase_ -- --ignored --nocapture
-//   # Then for each generated showcase:
+//   # Each showcase's showcase.gif is written directly. For resvg-rendered
+//   # GIFs, run with SHOWCASE_FORMATS=svg and then for each showcase:
 //   scripts/frames-to-gif.sh docs/blog/editing/multi-cursor
 //   scripts/frames-to-gif.sh docs/blog/editing/search-replace
 //   # ... etc
@@ -40,6 +41,41 @@ fn snap_mouse(
         .unwrap();
 }
 
+/// Glide the mouse pointer from `from` to `to` over `steps` frames
+fn snap_mouse_path(
+    h: &mut EditorTestHarness,
+    s: &mut BlogShowcase,
+    from: (u16, u16),
+    to: (u16, u16),
+    steps: usize,
+) {
+    h.render().unwrap();
+    let c = h.screen_cursor_position();
+    s.mouse_path(h.buffer(), c, None, from, to, steps, 30)
+        .unwrap();
+}
+
+/// Show a click at `mouse` with a spreading ripple
+fn snap_click(
+    h: &mut EditorTestHarness,
+    s: &mut BlogShowcase,
+    key: Option<&str>,
+    mouse: (u16, u16),
+    ms: u32,
+) {
+    h.render().unwrap();
+    let c = h.screen_cursor_position();
+    s.click_frames(h.buffer(), c, key, mouse, 3, ms / 3)
+        .unwrap();
+}
+
+/// Show the text just typed, with the typed cell highlighted
+fn snap_typed(h: &mut EditorTestHarness, s: &mut BlogShowcase, key: Option<&str>, ms: u32) {
+    h.render().unwrap();
+    let c = h.screen_cursor_position();
+    s.capture_typing_frame(h.buffer(), c, key, ms).unwrap();
+}
+
 fn hold(h: &mut EditorTestHarness, s: &mut BlogShowcase, count: usize, ms: u32) {
     h.render().unwrap();
     let c = h.screen_cursor_position();
@@ -169,7 +205,7 @@ fn blog_showcase_editing_multi_cursor() {
     // Type replacement
     for ch in "entry".chars() {
         h.send_key(KeyCode::Char(ch), KeyModifiers::NONE).unwrap();
-        snap(&mut h, &mut s, Some(&ch.to_string()), 50);
+        snap_typed(&mut h, &mut s, Some(&ch.to_string()), 50);
     }
     hold(&mut h, &mut s, 6, 100);
 
@@ -354,13 +390,16 @@ fn blog_showcase_editing_triple_click() {
     let click_row = 6u16;
     let click_col = 15u16;
 
+    // Move the mouse in from the lower right
+    snap_mouse_path(&mut h, &mut s, (70, 20), (click_col, click_row), 10);
+
     // First click
     h.mouse_click(click_col, click_row).unwrap();
-    snap_mouse(&mut h, &mut s, Some("Click"), (click_col, click_row), 120);
+    snap_click(&mut h, &mut s, Some("Click"), (click_col, click_row), 120);
 
     // Second click (double-click selects word)
     h.mouse_click(click_col, click_row).unwrap();
-    snap_mouse(
+    snap_click(
         &mut h,
         &mut s,
         Some("Double-click"),
@@ -370,17 +409,25 @@ fn blog_showcase_editing_triple_click() {
 
     // Third click (triple-click selects line)
     h.mouse_click(click_col, click_row).unwrap();
-    snap_mouse(
+    snap_click(
         &mut h,
         &mut s,
         Some("Triple-click"),
         (click_col, click_row),
         200,
     );
-    hold(&mut h, &mut s, 5, 100);
+    snap_mouse(
+        &mut h,
+        &mut s,
+        Some("Triple-click"),
+        (click_col, click_row),
+        400,
+    );
 
     // Click elsewhere to deselect
+    snap_mouse_path(&mut h, &mut s, (click_col, click_row), (5, 10), 8);
     h.mouse_click(5, 10).unwrap();
+    snap_click(&mut h, &mut s, Some("Click"), (5, 10), 120);
     hold(&mut h, &mut s, 3, 100);
 
     s.finalize().unwrap();
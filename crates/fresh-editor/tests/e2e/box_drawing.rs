@@ -0,0 +1,118 @@
+//! Tests for box drawing.
+//!
+//! Tests that:
+//! - Draw Box replaces the border of a block selection and undoes in one step
+//! - Draw Box refuses selections that aren't blocks
+//! - Line Drawing Mode draws joined lines with the arrow keys
+//! - Line Drawing Mode overwrites typed characters
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command_name).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn press(harness: &mut EditorTestHarness, code: KeyCode, times: usize) {
+    for _ in 0..times {
+        harness.send_key(code, KeyModifiers::NONE).unwrap();
+    }
+}
+
+fn block_select(harness: &mut EditorTestHarness, code: KeyCode, times: usize) {
+    for _ in 0..times {
+        harness
+            .send_key(code, KeyModifiers::ALT | KeyModifiers::SHIFT)
+            .unwrap();
+    }
+}
+
+/// The border of the block is replaced and the inside kept; undo brings back
+/// the text as it was.
+#[test]
+fn test_draw_box_on_block_selection() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let text = "abcdef\nabcdef\nabcdef\nabcdef";
+    harness.type_text(text).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    press(&mut harness, KeyCode::Right, 1);
+    block_select(&mut harness, KeyCode::Down, 2);
+    block_select(&mut harness, KeyCode::Right, 3);
+
+    run_command(&mut harness, "Draw Box");
+    harness.assert_buffer_content("a┌──┐f\na│cd│f\na└──┘f\nabcdef");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content(text);
+}
+
+/// A plain selection isn't a rectangle, so nothing is drawn.
+#[test]
+fn test_draw_box_refuses_plain_selection() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("abc\ndef").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+
+    run_command(&mut harness, "Draw Box");
+    harness.assert_buffer_content("abc\ndef");
+    harness.assert_screen_contains("needs a block selection");
+}
+
+/// Strokes turn corners, close into a box and cross existing lines.
+#[test]
+fn test_line_drawing_joins_lines() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    run_command(&mut harness, "Toggle Line Drawing");
+
+    press(&mut harness, KeyCode::Right, 3);
+    press(&mut harness, KeyCode::Down, 2);
+    press(&mut harness, KeyCode::Left, 3);
+    press(&mut harness, KeyCode::Up, 2);
+    harness.assert_buffer_content("┌──┐\n│  │\n└──┘");
+
+    // Across the middle of the box
+    press(&mut harness, KeyCode::Down, 1);
+    press(&mut harness, KeyCode::Right, 3);
+    harness.assert_buffer_content("┌──┐\n├──┤\n└──┘");
+
+    // Each stroke is undone on its own
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("┌──┐\n├──│\n└──┘");
+}
+
+/// Typing replaces the character under the cursor until the mode is off.
+#[test]
+fn test_line_drawing_overwrites_typing() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("abc").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+
+    run_command(&mut harness, "Toggle Line Drawing");
+    harness.type_text("XY").unwrap();
+    harness.assert_buffer_content("XYc");
+
+    run_command(&mut harness, "Toggle Line Drawing");
+    harness.type_text("Z").unwrap();
+    harness.assert_buffer_content("XYZc");
+}
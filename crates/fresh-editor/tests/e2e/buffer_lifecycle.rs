@@ -129,6 +129,118 @@ fn test_quit_with_confirmation_cancel() {
     );
 }
 
+/// Open two files and modify both, for the quit dialog tests
+fn open_two_modified_files(
+    harness: &mut EditorTestHarness,
+    temp_dir: &tempfile::TempDir,
+) -> (std::path::PathBuf, std::path::PathBuf) {
+    let alpha = temp_dir.path().join("alpha.txt");
+    let beta = temp_dir.path().join("beta.txt");
+    std::fs::write(&alpha, "alpha").unwrap();
+    std::fs::write(&beta, "beta").unwrap();
+
+    harness.open_file(&alpha).unwrap();
+    harness.type_text("1").unwrap();
+    harness.open_file(&beta).unwrap();
+    harness.type_text("2").unwrap();
+    harness.render().unwrap();
+    (alpha, beta)
+}
+
+/// Answer the open prompt with a key and Enter
+fn answer_prompt(harness: &mut EditorTestHarness, key: char) {
+    harness
+        .send_key(KeyCode::Char(key), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test that the quit dialog lists every buffer with unsaved changes
+#[test]
+fn test_quit_dialog_lists_unsaved_buffers() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    open_two_modified_files(&mut harness, &temp_dir);
+
+    harness
+        .send_key(KeyCode::Char('q'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("2 items have unsaved work");
+    harness.assert_screen_contains("(s)ave all, (d)iscard all, (r)eview");
+    harness.assert_screen_contains("alpha.txt");
+    harness.assert_screen_contains("beta.txt");
+    harness.assert_screen_contains("unsaved changes");
+    assert!(!harness.should_quit());
+}
+
+/// Test that Save All writes every modified buffer and quits
+#[test]
+fn test_quit_dialog_save_all() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let (alpha, beta) = open_two_modified_files(&mut harness, &temp_dir);
+
+    harness
+        .send_key(KeyCode::Char('q'), KeyModifiers::CONTROL)
+        .unwrap();
+    answer_prompt(&mut harness, 's');
+
+    assert!(harness.should_quit(), "Editor should quit after saving all");
+    assert_eq!(std::fs::read_to_string(&alpha).unwrap(), "1alpha");
+    assert_eq!(std::fs::read_to_string(&beta).unwrap(), "2beta");
+}
+
+/// Test that Review asks about each buffer in turn, showing it in the editor
+#[test]
+fn test_quit_dialog_review_each_buffer() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let (alpha, beta) = open_two_modified_files(&mut harness, &temp_dir);
+
+    harness
+        .send_key(KeyCode::Char('q'), KeyModifiers::CONTROL)
+        .unwrap();
+    answer_prompt(&mut harness, 'r');
+
+    // The first buffer is shown and asked about
+    harness.assert_screen_contains("1alpha");
+    harness.assert_screen_contains("alpha.txt' modified");
+    answer_prompt(&mut harness, 's');
+    assert!(!harness.should_quit());
+
+    // Then the second
+    harness.assert_screen_contains("2beta");
+    harness.assert_screen_contains("beta.txt' modified");
+    answer_prompt(&mut harness, 'd');
+
+    assert!(harness.should_quit(), "Editor should quit after the review");
+    assert_eq!(std::fs::read_to_string(&alpha).unwrap(), "1alpha");
+    assert_eq!(std::fs::read_to_string(&beta).unwrap(), "beta");
+}
+
+/// Test that cancelling during the review keeps the editor open
+#[test]
+fn test_quit_dialog_review_cancel() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let (alpha, _beta) = open_two_modified_files(&mut harness, &temp_dir);
+
+    harness
+        .send_key(KeyCode::Char('q'), KeyModifiers::CONTROL)
+        .unwrap();
+    answer_prompt(&mut harness, 'r');
+    answer_prompt(&mut harness, 'c');
+
+    assert!(!harness.should_quit(), "Editor should stay open");
+    harness.assert_screen_contains("Quit cancelled");
+    assert_eq!(std::fs::read_to_string(&alpha).unwrap(), "alpha");
+}
+
 /// Test that undo restores non-dirty status when undoing all changes
 #[test]
 fn test_undo_restores_non_dirty_status() {
@@ -644,3 +756,56 @@ fn test_close_returns_to_previous_focused() {
         screen
     );
 }
+
+/// Test that New File buffers are numbered, reusing the lowest free number
+#[test]
+fn test_new_file_buffers_are_numbered() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    let first = harness.editor().active_buffer();
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("untitled-1");
+    harness.assert_screen_contains("untitled-2");
+
+    // Closing untitled-1 frees its number for the next new buffer
+    harness.editor_mut().close_buffer(first).unwrap();
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("untitled-1");
+    harness.assert_screen_contains("untitled-2");
+    harness.assert_screen_not_contains("untitled-3");
+}
+
+/// Test that a scratch buffer emptied again closes and quits without asking
+#[test]
+fn test_emptied_scratch_buffer_needs_no_save_prompt() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    let scratch = harness.editor().active_buffer();
+    harness.type_text("abc").unwrap();
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+
+    harness
+        .send_key(KeyCode::Char('q'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert!(
+        harness.should_quit(),
+        "An emptied scratch buffer should not hold up quitting"
+    );
+    assert!(harness.editor_mut().close_buffer(scratch).is_ok());
+}
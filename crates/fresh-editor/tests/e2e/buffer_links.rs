@@ -0,0 +1,135 @@
+//! E2E tests for opening URLs and file paths in editor buffers
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::style::Modifier;
+
+const NOTES: &str = "See (target.txt:3) or <https://example.com/docs>.\n";
+
+/// A project with notes.md linking to target.txt, with notes.md open
+fn open_notes() -> (tempfile::TempDir, EditorTestHarness) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("notes.md"), NOTES).unwrap();
+    std::fs::write(
+        temp_dir.path().join("target.txt"),
+        "first\nsecond\nthird line\n",
+    )
+    .unwrap();
+    let mut harness =
+        EditorTestHarness::with_working_dir(100, 24, temp_dir.path().to_path_buf()).unwrap();
+    harness
+        .open_file(&temp_dir.path().join("notes.md"))
+        .unwrap();
+    harness.render().unwrap();
+    (temp_dir, harness)
+}
+
+fn send_mouse(harness: &mut EditorTestHarness, kind: MouseEventKind, col: u16, row: u16) {
+    harness
+        .send_mouse(MouseEvent {
+            kind,
+            column: col,
+            row,
+            modifiers: KeyModifiers::CONTROL,
+        })
+        .unwrap();
+}
+
+fn ctrl_click(harness: &mut EditorTestHarness, col: u16, row: u16) {
+    send_mouse(harness, MouseEventKind::Down(MouseButton::Left), col, row);
+    send_mouse(harness, MouseEventKind::Up(MouseButton::Left), col, row);
+    harness.render().unwrap();
+}
+
+fn is_underlined(harness: &EditorTestHarness, col: u16, row: u16) -> bool {
+    harness
+        .get_cell_style(col, row)
+        .is_some_and(|style| style.add_modifier.contains(Modifier::UNDERLINED))
+}
+
+#[test]
+fn test_ctrl_click_path_opens_file_at_line() {
+    let (_temp_dir, mut harness) = open_notes();
+    let (x, y) = harness.find_text_on_screen("target.txt:3").unwrap();
+    ctrl_click(&mut harness, x + 2, y);
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "first\nsecond\nthird line\n"
+    );
+    assert_eq!(harness.cursor_position(), "first\nsecond\n".len());
+}
+
+#[test]
+fn test_ctrl_click_url_opens_browser() {
+    let (_temp_dir, mut harness) = open_notes();
+    let (x, y) = harness.find_text_on_screen("https://").unwrap();
+    ctrl_click(&mut harness, x + 10, y);
+
+    assert_eq!(
+        harness.editor().opened_urls_for_test(),
+        ["https://example.com/docs".to_string()]
+    );
+    // The buffer stays as it was
+    assert_eq!(harness.get_buffer_content().unwrap(), NOTES);
+}
+
+#[test]
+fn test_plain_click_on_link_moves_cursor() {
+    let (_temp_dir, mut harness) = open_notes();
+    let (x, y) = harness.find_text_on_screen("https://").unwrap();
+    harness.mouse_click(x + 10, y).unwrap();
+
+    assert!(harness.editor().opened_urls_for_test().is_empty());
+    assert_eq!(harness.get_buffer_content().unwrap(), NOTES);
+    assert_eq!(
+        harness.cursor_position(),
+        NOTES.find("https://").unwrap() + 10
+    );
+}
+
+#[test]
+fn test_ctrl_hover_underlines_link() {
+    let (_temp_dir, mut harness) = open_notes();
+    let (x, y) = harness.find_text_on_screen("target.txt:3").unwrap();
+    assert!(!is_underlined(&harness, x, y));
+
+    // Without Ctrl nothing is underlined
+    harness.mouse_move(x + 2, y).unwrap();
+    assert!(!is_underlined(&harness, x, y));
+
+    // With Ctrl the whole link is, but not the brackets around it
+    send_mouse(&mut harness, MouseEventKind::Moved, x + 2, y);
+    harness.render().unwrap();
+    assert!(is_underlined(&harness, x, y));
+    assert!(is_underlined(&harness, x + 11, y));
+    assert!(!is_underlined(&harness, x - 1, y));
+    assert!(!is_underlined(&harness, x + 12, y));
+
+    // Moving off the link clears the underline
+    send_mouse(&mut harness, MouseEventKind::Moved, x - 3, y);
+    harness.render().unwrap();
+    assert!(!is_underlined(&harness, x, y));
+}
+
+#[test]
+fn test_open_link_under_cursor_in_buffer() {
+    let (_temp_dir, mut harness) = open_notes();
+    // Move the cursor onto "target.txt:3"
+    harness
+        .send_key_repeat(KeyCode::Right, KeyModifiers::NONE, 6)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Open Link Under Cursor").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "first\nsecond\nthird line\n"
+    );
+}
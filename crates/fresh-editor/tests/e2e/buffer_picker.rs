@@ -0,0 +1,112 @@
+// End-to-end tests for the buffer picker: most recently used order, preview,
+// closing buffers from the list
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers, ModifierKeyCode};
+use tempfile::TempDir;
+
+/// Open file_a, file_b and file_c (in that order) with identifiable content
+fn open_three_files(harness: &mut EditorTestHarness) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    for name in ["a", "b", "c"] {
+        let path = temp_dir.path().join(format!("file_{}.txt", name));
+        std::fs::write(&path, format!("CONTENT_{}", name.to_uppercase())).unwrap();
+        harness.open_file(&path).unwrap();
+    }
+    harness.render().unwrap();
+    harness.assert_screen_contains("CONTENT_C");
+    temp_dir
+}
+
+fn ctrl_tab(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test that Ctrl+Tab previews buffers in recently used order and Esc puts
+/// the original back
+#[test]
+fn test_buffer_picker_previews_and_cancels() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _temp_dir = open_three_files(&mut harness);
+
+    // Opens on the buffer used before the current one
+    ctrl_tab(&mut harness);
+    harness.assert_screen_contains("Switch to buffer:");
+    harness.assert_screen_contains("CONTENT_B");
+    harness.assert_screen_not_contains("CONTENT_C");
+
+    // Each Ctrl+Tab moves further back
+    ctrl_tab(&mut harness);
+    harness.assert_screen_contains("CONTENT_A");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("CONTENT_C");
+    harness.assert_screen_not_contains("CONTENT_A");
+}
+
+/// Test that confirming a buffer makes it the most recently used one
+#[test]
+fn test_buffer_picker_updates_recent_order() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _temp_dir = open_three_files(&mut harness);
+
+    ctrl_tab(&mut harness);
+    ctrl_tab(&mut harness);
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("CONTENT_A");
+
+    // Ctrl+Tab now goes back to file_c, the buffer left last
+    ctrl_tab(&mut harness);
+    harness.assert_screen_contains("CONTENT_C");
+}
+
+/// Test that releasing Ctrl after Ctrl+Tab switches to the selected buffer
+#[test]
+fn test_buffer_picker_switches_on_ctrl_release() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _temp_dir = open_three_files(&mut harness);
+
+    ctrl_tab(&mut harness);
+    harness
+        .editor_mut()
+        .handle_key_release(KeyCode::Modifier(ModifierKeyCode::LeftControl));
+    harness.render().unwrap();
+
+    harness.assert_screen_not_contains("Switch to buffer:");
+    harness.assert_screen_contains("CONTENT_B");
+}
+
+/// Test that the picker marks modified buffers and closes the selected
+/// buffer with Delete
+#[test]
+fn test_buffer_picker_marks_modified_and_closes() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _temp_dir = open_three_files(&mut harness);
+
+    harness.type_text("x").unwrap();
+    ctrl_tab(&mut harness);
+    harness.assert_screen_contains("file_c.txt [+]");
+
+    // file_b is selected; Delete closes it and the picker stays open
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Switch to buffer:");
+    harness.assert_screen_not_contains("file_b.txt");
+
+    // The modified file_c is not closed
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("file_c.txt [+]");
+}
@@ -0,0 +1,105 @@
+// End-to-end tests for independent client views: each client of a session
+// keeps its own layout over the shared buffers
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use tempfile::TempDir;
+
+/// Client 1 on alpha.txt, client 2 on beta.txt
+fn two_clients_on_different_files(harness: &mut EditorTestHarness) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let alpha = temp_dir.path().join("alpha.txt");
+    let beta = temp_dir.path().join("beta.txt");
+    std::fs::write(&alpha, "ALPHA_CONTENT").unwrap();
+    std::fs::write(&beta, "BETA_CONTENT").unwrap();
+
+    harness.open_file(&alpha).unwrap();
+    harness.editor_mut().activate_client_view(1);
+    harness.editor_mut().activate_client_view(2);
+    harness.open_file(&beta).unwrap();
+    harness.render().unwrap();
+    temp_dir
+}
+
+/// Test that each client keeps showing its own buffer
+#[test]
+fn test_client_views_show_their_own_buffer() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _temp_dir = two_clients_on_different_files(&mut harness);
+
+    harness.assert_screen_contains("BETA_CONTENT");
+    harness.assert_screen_not_contains("ALPHA_CONTENT");
+
+    harness.editor_mut().activate_client_view(1);
+    harness.render().unwrap();
+    harness.assert_screen_contains("ALPHA_CONTENT");
+    harness.assert_screen_not_contains("BETA_CONTENT");
+}
+
+/// Test that an edit from one client shows in the other and leaves the other
+/// client's cursor on the same text
+#[test]
+fn test_client_views_share_edits() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let temp_dir = two_clients_on_different_files(&mut harness);
+
+    // Client 2 also looks at alpha.txt, with its cursor at the end
+    harness
+        .open_file(&temp_dir.path().join("alpha.txt"))
+        .unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+
+    // Client 1 types at the start
+    harness.editor_mut().activate_client_view(1);
+    harness.type_text("hello ").unwrap();
+
+    // Client 2 sees the edit and types where its cursor was
+    harness.editor_mut().activate_client_view(2);
+    harness.type_text("!").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("hello ALPHA_CONTENT!");
+}
+
+/// Test that a buffer closed by one client disappears from the other's view
+#[test]
+fn test_client_views_drop_closed_buffers() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _temp_dir = two_clients_on_different_files(&mut harness);
+    let beta = harness.editor().active_buffer();
+
+    harness.editor_mut().activate_client_view(1);
+    harness.editor_mut().close_buffer(beta).unwrap();
+
+    harness.editor_mut().activate_client_view(2);
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("BETA_CONTENT");
+    assert_ne!(harness.editor().active_buffer(), beta);
+}
+
+/// Test that a client sees the cursor of another client on the same buffer,
+/// tagged with its name, until that client leaves
+#[test]
+fn test_client_views_show_other_clients_cursors() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let temp_dir = two_clients_on_different_files(&mut harness);
+    harness.editor_mut().set_client_name(2, "bob".to_string());
+
+    // Not shown while client 2 is on another buffer
+    harness.editor_mut().activate_client_view(1);
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("bob");
+
+    harness.editor_mut().activate_client_view(2);
+    harness
+        .open_file(&temp_dir.path().join("alpha.txt"))
+        .unwrap();
+    harness.editor_mut().activate_client_view(1);
+    harness.render().unwrap();
+    harness.assert_screen_contains("bob");
+
+    harness.editor_mut().remove_client_view(2);
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("bob");
+}
@@ -0,0 +1,128 @@
+//! Tests for the clipboard history.
+//!
+//! Tests that:
+//! - Cycle Paste replaces the text just pasted with older entries and wraps
+//! - Cycle Paste after another action pastes the newest entry instead
+//! - Paste from History pastes the picked entry and moves it to the front
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+/// Editor that only uses the internal clipboard, so tests don't share state
+fn harness_with_internal_clipboard() -> EditorTestHarness {
+    let mut config = Config::default();
+    config.clipboard.use_osc52 = false;
+    config.clipboard.use_system_clipboard = false;
+    EditorTestHarness::with_config(80, 24, config).unwrap()
+}
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command_name).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Select the word at `position` and copy it
+fn copy_word_at(harness: &mut EditorTestHarness, position: usize) {
+    let cursors = harness.editor_mut().active_cursors_mut();
+    cursors.primary_mut().position = position;
+    cursors.primary_mut().anchor = None;
+    harness
+        .send_key(KeyCode::Char('w'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+}
+
+fn goto_end(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+}
+
+/// Each Cycle Paste swaps the pasted text for the next older entry.
+#[test]
+fn test_cycle_paste_replaces_with_older_entries() {
+    let mut harness = harness_with_internal_clipboard();
+    harness.type_text("one two\n").unwrap();
+    copy_word_at(&mut harness, 0);
+    copy_word_at(&mut harness, 4);
+
+    goto_end(&mut harness);
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("one two\ntwo");
+
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("one two\none");
+
+    // Past the oldest entry the cycle wraps to the newest
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("one two\ntwo");
+
+    // A single undo brings back the previous paste
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("one two\none");
+}
+
+/// Cycle Paste after any other action starts a new paste instead of
+/// replacing text.
+#[test]
+fn test_cycle_paste_after_other_action_pastes_newest() {
+    let mut harness = harness_with_internal_clipboard();
+    harness.type_text("one two\n").unwrap();
+    copy_word_at(&mut harness, 0);
+    copy_word_at(&mut harness, 4);
+
+    goto_end(&mut harness);
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(" ").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("one two\ntwo two");
+}
+
+/// Paste from History pastes the picked entry, which then becomes the
+/// clipboard content.
+#[test]
+fn test_paste_from_history_promotes_entry() {
+    let mut harness = harness_with_internal_clipboard();
+    harness.type_text("one two three\n").unwrap();
+    copy_word_at(&mut harness, 0);
+    copy_word_at(&mut harness, 4);
+    copy_word_at(&mut harness, 8);
+
+    goto_end(&mut harness);
+    run_command(&mut harness, "Paste from History");
+    harness.type_text("one").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("one two three\none");
+
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("one two three\noneone");
+}
@@ -0,0 +1,162 @@
+//! E2E tests for color swatches and the color adjuster
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use ratatui::style::Color;
+
+const CSS: &str = "a { color: #ff8800; }\n";
+
+fn harness_with_swatches(fixture: &TestFixture) -> EditorTestHarness {
+    let mut config = Config::default();
+    config.editor.color_swatches = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(command_name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Screen position of the swatch drawn before `literal`
+fn swatch_position(harness: &EditorTestHarness, literal: &str) -> (u16, u16) {
+    let (x, y) = harness
+        .find_text_on_screen(literal)
+        .unwrap_or_else(|| panic!("{literal:?} not on screen"));
+    (x - 1, y)
+}
+
+fn swatch_bg(harness: &EditorTestHarness, literal: &str) -> Option<Color> {
+    let (x, y) = swatch_position(harness, literal);
+    harness.get_cell_style(x, y).and_then(|style| style.bg)
+}
+
+/// Replace the prompt's prefilled channels with `channels`
+fn retype_channels(harness: &mut EditorTestHarness, channels: &str) {
+    harness
+        .send_key_repeat(KeyCode::Backspace, KeyModifiers::NONE, 20)
+        .unwrap();
+    harness.type_text(channels).unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_color_swatch_drawn_before_literal() {
+    let fixture = TestFixture::new("style.css", CSS).unwrap();
+    let harness = harness_with_swatches(&fixture);
+    harness.assert_screen_contains("color:  #ff8800;");
+    assert_eq!(
+        swatch_bg(&harness, "#ff8800"),
+        Some(Color::Rgb(255, 136, 0))
+    );
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("color: #ff8800;");
+    harness.assert_screen_not_contains("color:  #ff8800;");
+}
+
+#[test]
+fn test_toggle_color_swatches_command() {
+    let fixture = TestFixture::new("toggle.css", CSS).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("color: #ff8800;");
+
+    run_command(&mut harness, "Toggle Color Swatches");
+    harness.assert_screen_contains("Color swatches shown");
+    harness.assert_screen_contains("color:  #ff8800;");
+
+    run_command(&mut harness, "Toggle Color Swatches");
+    harness.assert_screen_contains("Color swatches hidden");
+    harness.assert_screen_contains("color: #ff8800;");
+}
+
+#[test]
+fn test_click_swatch_previews_and_applies_color() {
+    let fixture = TestFixture::new("click.css", CSS).unwrap();
+    let mut harness = harness_with_swatches(&fixture);
+
+    let (x, y) = swatch_position(&harness, "#ff8800");
+    harness.mouse_click(x, y).unwrap();
+    harness.assert_screen_contains("Color (R G B): 255 136 0");
+
+    // The swatch follows what is typed before anything is written
+    retype_channels(&mut harness, "0 255 0");
+    assert_eq!(swatch_bg(&harness, "#ff8800"), Some(Color::Rgb(0, 255, 0)));
+    assert_eq!(harness.get_buffer_content().unwrap(), CSS);
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "a { color: #00ff00; }\n"
+    );
+    assert_eq!(swatch_bg(&harness, "#00ff00"), Some(Color::Rgb(0, 255, 0)));
+
+    // A single undo restores the original literal
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), CSS);
+}
+
+#[test]
+fn test_cancel_color_adjuster_restores_swatch() {
+    let fixture = TestFixture::new("cancel.css", CSS).unwrap();
+    let mut harness = harness_with_swatches(&fixture);
+
+    let (x, y) = swatch_position(&harness, "#ff8800");
+    harness.mouse_click(x, y).unwrap();
+    retype_channels(&mut harness, "0 0 255");
+    assert_eq!(swatch_bg(&harness, "#ff8800"), Some(Color::Rgb(0, 0, 255)));
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        swatch_bg(&harness, "#ff8800"),
+        Some(Color::Rgb(255, 136, 0))
+    );
+    assert_eq!(harness.get_buffer_content().unwrap(), CSS);
+}
+
+#[test]
+fn test_adjust_color_command_at_cursor() {
+    let fixture = TestFixture::new("cursor.css", "p { color: hsl(0, 100%, 50%); }\n").unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Adjust Color");
+    harness.assert_screen_contains("No color literal at cursor");
+
+    // Move onto the literal
+    harness
+        .send_key_repeat(KeyCode::Right, KeyModifiers::NONE, 12)
+        .unwrap();
+    run_command(&mut harness, "Adjust Color");
+    harness.assert_screen_contains("Color (H S L): 0 100 50");
+
+    retype_channels(&mut harness, "120 50 25");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "p { color: hsl(120, 50%, 25%); }\n"
+    );
+}
@@ -407,7 +407,7 @@ fn test_command_palette_new_file_switches_buffer() {
 
     // Should now have two tabs
     harness.assert_screen_contains("test.txt");
-    harness.assert_screen_contains("[No Name]");
+    harness.assert_screen_contains("untitled-1");
 
     // The important part: the CONTENT should now be empty (new buffer)
     // NOT showing the original content anymore
@@ -972,3 +972,99 @@ fn test_command_palette_select_cursor_style() {
         .wait_for_screen_contains("Cursor style changed")
         .unwrap();
 }
+
+/// Keybindings are right-aligned in their column of the command palette
+#[test]
+fn test_command_palette_keybinding_right_aligned() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+
+    // Column where the keybinding on the row of `command` ends
+    let keybinding_end =
+        |harness: &mut EditorTestHarness, query: &str, command: &str, key: &str| {
+            harness
+                .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+                .unwrap();
+            harness.type_text(query).unwrap();
+            harness.render().unwrap();
+            let screen = harness.screen_to_string();
+            let row = screen
+                .lines()
+                .find(|line| line.contains(command) && line.contains(key))
+                .unwrap_or_else(|| panic!("no row for {command} with {key}:\n{screen}"))
+                .to_string();
+            harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+            let start = row.find(key).unwrap();
+            row[..start].chars().count() + key.chars().count()
+        };
+
+    let find_next_end = keybinding_end(&mut harness, "find next", "Find Next", "F3");
+    // Ctrl+G (or ⌘+G on macOS)
+    let goto_line_end = keybinding_end(&mut harness, "go to line", "Go to Line", "+G");
+    assert_eq!(find_next_end, goto_line_end);
+}
+
+/// F2 on a palette entry opens the keybinding editor ready to bind that command
+#[test]
+fn test_command_palette_f2_binds_command() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("go to line").unwrap();
+    harness.send_key(KeyCode::F(2), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Keybinding Editor");
+    harness.assert_screen_contains("Add Keybinding");
+    harness.assert_screen_contains("goto_line");
+
+    // The dialog is waiting for the key to bind
+    harness
+        .send_key(KeyCode::Char('k'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Ctrl+K");
+}
+
+/// Commands run from the palette are listed first the next time it opens
+#[test]
+fn test_command_palette_ranks_used_commands_first() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("go to line").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    let screen = harness.screen_to_string();
+    let goto_row = screen.lines().position(|l| l.contains("Go to Line"));
+    let add_cursor_row = screen.lines().position(|l| l.contains("Add Cursor Above"));
+    assert!(
+        goto_row.is_some() && (add_cursor_row.is_none() || goto_row < add_cursor_row),
+        "Go to Line should be listed first:\n{screen}"
+    );
+
+    // Resetting the ranking brings back alphabetical order
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("reset command ranking").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Command ranking reset");
+}
@@ -0,0 +1,55 @@
+//! Tests for the `*Editor Log*` buffer opened by "Show Logs".
+//!
+//! Tests that:
+//! - The tail of the log file is shown read-only
+//! - Lines appended to the log are followed
+//! - A rotated log is reloaded from the new file
+
+use crate::common::harness::EditorTestHarness;
+use std::io::Write;
+use std::time::Duration;
+
+#[test]
+fn test_show_logs_follows_log_file() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let log_path = temp_dir.path().join("fresh-1.log");
+    std::fs::write(&log_path, "INFO editor starting\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.editor_mut().set_editor_log_path(log_path.clone());
+    harness.editor_mut().open_editor_log();
+    harness.render().unwrap();
+    harness.assert_screen_contains("*Editor Log*");
+    harness.assert_screen_contains("INFO editor starting");
+    assert!(harness.editor().is_active_buffer_read_only());
+
+    // Appended lines show up on the next check; a partial line waits
+    let mut log = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&log_path)
+        .unwrap();
+    write!(log, "WARN something happened\nDEBUG half").unwrap();
+    log.flush().unwrap();
+    harness.advance_time(Duration::from_secs(1));
+    assert!(harness.editor_mut().check_editor_log());
+    harness.render().unwrap();
+    harness.assert_screen_contains("WARN something happened");
+    harness.assert_screen_not_contains("DEBUG half");
+
+    // Checks are throttled
+    writeln!(log, " a line").unwrap();
+    log.flush().unwrap();
+    assert!(!harness.editor_mut().check_editor_log());
+    harness.advance_time(Duration::from_secs(1));
+    assert!(harness.editor_mut().check_editor_log());
+    harness.render().unwrap();
+    harness.assert_screen_contains("DEBUG half a line");
+
+    // After rotation the buffer shows the new file
+    std::fs::write(&log_path, "INFO rotated\n").unwrap();
+    harness.advance_time(Duration::from_secs(1));
+    assert!(harness.editor_mut().check_editor_log());
+    harness.render().unwrap();
+    harness.assert_screen_contains("INFO rotated");
+    harness.assert_screen_not_contains("INFO editor starting");
+}
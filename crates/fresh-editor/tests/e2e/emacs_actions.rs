@@ -1,6 +1,7 @@
 //! End-to-end tests for Emacs-style actions
 //!
-//! Tests for the new actions: transpose_chars, open_line, recenter, set_mark
+//! Tests for the new actions: transpose_chars, transpose_words, open_line,
+//! recenter, set_mark
 //!
 //! Note: Shadow validation is disabled because these new actions
 //! aren't tracked by the harness's shadow buffer yet.
@@ -97,6 +98,61 @@ fn test_transpose_chars_at_end() {
     harness.assert_buffer_content("ab");
 }
 
+/// Test transpose_chars swaps whole characters, not bytes, in one undo step
+#[test]
+fn test_transpose_chars_multibyte() {
+    let mut harness = emacs_harness(80, 24);
+
+    harness.type_text("aéb").unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Char('t'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("abé");
+    // The cursor moves past the swapped pair
+    assert_eq!(harness.cursor_position(), "abé".len());
+
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("aéb");
+}
+
+// =============================================================================
+// Transpose Words (M-t) Tests
+// =============================================================================
+
+/// Test transpose_words swaps the word under the cursor with the next one,
+/// and repeating it carries the word further
+#[test]
+fn test_transpose_words() {
+    let mut harness = emacs_harness(80, 24);
+
+    harness.type_text("one two, three").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('t'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("two one, three");
+    assert_eq!(harness.cursor_position(), 7);
+
+    harness
+        .send_key(KeyCode::Char('t'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("two three, one");
+
+    // On the last word there is nothing to swap with
+    harness
+        .send_key(KeyCode::Char('t'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("two three, one");
+}
+
 // =============================================================================
 // Open Line (C-o) Tests
 // =============================================================================
@@ -0,0 +1,162 @@
+//! Tests for Expand Selection and Shrink Selection.
+//!
+//! Tests that:
+//! - Expanding goes word, bracket contents, brackets, and on to the buffer
+//! - Shrinking walks back down to the cursor as it was
+//! - An indentation block is one of the steps
+//! - Each cursor expands on its own
+//! - Moving the cursor forgets the expansions
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(">command"))
+        .unwrap();
+    harness.type_text(command).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// A buffer holding `text`, the cursor `right` characters into line `line`
+fn harness_at(text: &str, line: usize, right: usize) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(text).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key_repeat(KeyCode::Down, KeyModifiers::NONE, line)
+        .unwrap();
+    harness
+        .send_key_repeat(KeyCode::Right, KeyModifiers::NONE, right)
+        .unwrap();
+    harness
+}
+
+/// Text selected by each cursor, in buffer order
+fn selections(harness: &mut EditorTestHarness) -> Vec<String> {
+    let mut ranges: Vec<_> = harness
+        .editor()
+        .active_cursors()
+        .iter()
+        .filter_map(|(_, cursor)| cursor.selection_range())
+        .collect();
+    ranges.sort_by_key(|range| range.start);
+    ranges
+        .into_iter()
+        .map(|range| {
+            harness
+                .editor_mut()
+                .active_state_mut()
+                .get_text_range(range.start, range.end)
+        })
+        .collect()
+}
+
+#[test]
+fn test_expand_then_shrink() {
+    let text = "fn main() {\n    call(first, [second, third]);\n}\n";
+    let mut harness = harness_at(text, 1, 19);
+
+    let expected = [
+        "second",
+        "second, third",
+        "[second, third]",
+        "first, [second, third]",
+        "(first, [second, third])",
+    ];
+    for step in expected {
+        run_command(&mut harness, "Expand Selection");
+        assert_eq!(harness.get_selected_text(), step);
+    }
+
+    // On out through the blocks to the whole buffer
+    let mut last = harness.get_selected_text();
+    let mut grown = 0;
+    while last != text {
+        run_command(&mut harness, "Expand Selection");
+        let next = harness.get_selected_text();
+        assert!(next.len() > last.len() && next.contains(&last), "{next:?}");
+        last = next;
+        grown += 1;
+        assert!(grown < 10, "never reached the whole buffer");
+    }
+    run_command(&mut harness, "Expand Selection");
+    assert_eq!(harness.get_selected_text(), text);
+
+    // Back down the same way
+    for _ in 0..grown {
+        run_command(&mut harness, "Shrink Selection");
+    }
+    for step in expected.iter().rev() {
+        assert_eq!(harness.get_selected_text(), *step);
+        run_command(&mut harness, "Shrink Selection");
+    }
+    harness.assert_no_selection();
+    let cursor = harness.editor().active_cursors().primary().position;
+    assert_eq!(cursor, "fn main() {\n".len() + 19);
+
+    run_command(&mut harness, "Shrink Selection");
+    harness.assert_screen_contains("Nothing to shrink");
+}
+
+#[test]
+fn test_expand_to_indentation_block() {
+    let text = "def f():\n    if x:\n        y = 1\n        z = 2\n    return 3\n";
+    let mut harness = harness_at(text, 2, 8);
+
+    run_command(&mut harness, "Expand Selection");
+    assert_eq!(harness.get_selected_text(), "y");
+    run_command(&mut harness, "Expand Selection");
+    let block = harness.get_selected_text();
+    assert!(block.starts_with("    if x:"), "{block:?}");
+    assert!(block.contains("z = 2") && !block.contains("return"));
+
+    run_command(&mut harness, "Expand Selection");
+    let outer = harness.get_selected_text();
+    assert!(outer.starts_with("def f():") && outer.contains("return 3"));
+}
+
+#[test]
+fn test_each_cursor_expands_on_its_own() {
+    let mut harness = harness_at("a(one)\nbb(two, 2)\n", 0, 3);
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::CONTROL | KeyModifiers::ALT)
+        .unwrap();
+    assert_eq!(harness.editor().active_cursors().count(), 2);
+
+    run_command(&mut harness, "Expand Selection");
+    assert_eq!(selections(&mut harness), vec!["one", "two"]);
+
+    // "one" is all there is between its brackets, so they come next
+    run_command(&mut harness, "Expand Selection");
+    assert_eq!(selections(&mut harness), vec!["(one)", "two, 2"]);
+
+    run_command(&mut harness, "Shrink Selection");
+    assert_eq!(selections(&mut harness), vec!["one", "two"]);
+}
+
+#[test]
+fn test_cursor_movement_forgets_expansions() {
+    let mut harness = harness_at("call(alpha, beta)\n", 0, 7);
+    run_command(&mut harness, "Expand Selection");
+    run_command(&mut harness, "Expand Selection");
+    assert_eq!(harness.get_selected_text(), "alpha, beta");
+
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    run_command(&mut harness, "Shrink Selection");
+    harness.assert_screen_contains("Nothing to shrink");
+
+    // Expanding again starts from the cursor where it is now
+    run_command(&mut harness, "Expand Selection");
+    assert_eq!(harness.get_selected_text(), "call");
+}
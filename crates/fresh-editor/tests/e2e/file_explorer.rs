@@ -2929,3 +2929,141 @@ fn test_file_explorer_border_drag_resizes() {
         actual_delta
     );
 }
+
+/// "Reveal in Explorer" opens the explorer, expands the directory chain and
+/// selects the active file
+#[test]
+fn test_reveal_in_file_explorer_selects_active_file() {
+    let project = tempfile::TempDir::new().unwrap();
+    let project_root = project.path().to_path_buf();
+    fs::create_dir_all(project_root.join("src")).unwrap();
+    fs::write(project_root.join("src/utils.rs"), "pub fn util() {}\n").unwrap();
+    fs::write(project_root.join("README.md"), "readme").unwrap();
+
+    let mut harness = EditorTestHarness::with_working_dir(120, 40, project_root).unwrap();
+
+    // Open the file from the palette's file mode, as a user would
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("utils").unwrap();
+    harness.wait_for_screen_contains("src/utils.rs").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.get_buffer_content().as_deref() == Some("pub fn util() {}\n"))
+        .unwrap();
+    assert!(harness.editor().file_explorer().is_none());
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Reveal in Explorer").unwrap();
+    harness
+        .wait_for_screen_contains("Reveal in Explorer")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .wait_until(|h| {
+            h.editor()
+                .file_explorer()
+                .and_then(|e| e.get_selected_entry())
+                .map(|e| e.name.as_str() == "utils.rs")
+                .unwrap_or(false)
+        })
+        .unwrap();
+    harness.wait_for_file_explorer_item("utils.rs").unwrap();
+}
+
+/// With follow mode on, switching buffers moves the explorer selection
+#[test]
+fn test_file_explorer_follows_active_file() {
+    let mut config = fresh::config::Config::default();
+    config.file_explorer.follow_active_file = true;
+    let mut harness = EditorTestHarness::with_temp_project_and_config(120, 40, config).unwrap();
+    let project_root = harness.project_dir().unwrap();
+
+    fs::create_dir_all(project_root.join("src")).unwrap();
+    fs::write(project_root.join("src/utils.rs"), "pub fn util() {}\n").unwrap();
+    fs::write(project_root.join("README.md"), "readme").unwrap();
+
+    harness.editor_mut().toggle_file_explorer();
+    harness.wait_for_file_explorer().unwrap();
+    harness.editor_mut().focus_editor();
+
+    harness
+        .editor_mut()
+        .open_file(&project_root.join("src/utils.rs"))
+        .unwrap();
+    harness
+        .wait_until(|h| {
+            h.editor()
+                .file_explorer()
+                .and_then(|e| e.get_selected_entry())
+                .map(|e| e.name.as_str() == "utils.rs")
+                .unwrap_or(false)
+        })
+        .unwrap();
+
+    harness
+        .editor_mut()
+        .open_file(&project_root.join("README.md"))
+        .unwrap();
+    harness
+        .wait_until(|h| {
+            h.editor()
+                .file_explorer()
+                .and_then(|e| e.get_selected_entry())
+                .map(|e| e.name.as_str() == "README.md")
+                .unwrap_or(false)
+        })
+        .unwrap();
+}
+
+/// Files created outside the editor show up without collapsing expanded
+/// directories or moving the selection
+#[test]
+fn test_file_explorer_picks_up_external_changes() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap();
+
+    fs::create_dir(project_root.join("src")).unwrap();
+    fs::write(project_root.join("src/lib.rs"), "// lib").unwrap();
+
+    harness.editor_mut().focus_file_explorer();
+    harness.wait_for_file_explorer().unwrap();
+
+    // Expand src
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_file_explorer_item("lib.rs").unwrap();
+
+    // Let the watcher record the current directory state
+    harness.advance_time(std::time::Duration::from_secs(5));
+    harness.process_async_and_render().unwrap();
+
+    fs::write(project_root.join("external.txt"), "created outside").unwrap();
+    fs::write(project_root.join("src/util.rs"), "// util").unwrap();
+    harness.advance_time(std::time::Duration::from_secs(5));
+    harness.wait_for_file_explorer_item("external.txt").unwrap();
+    harness.wait_for_file_explorer_item("util.rs").unwrap();
+
+    harness.assert_screen_contains("lib.rs");
+    let selected = harness
+        .editor()
+        .file_explorer()
+        .and_then(|e| e.get_selected_entry())
+        .map(|e| e.name.clone());
+    assert_eq!(selected.as_deref(), Some("src"));
+}
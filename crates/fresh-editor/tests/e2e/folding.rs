@@ -230,6 +230,47 @@ fn test_cursor_down_skips_folded_lines() {
     );
 }
 
+/// Jumping to a bracket hidden in a collapsed fold expands the fold.
+#[test]
+fn test_goto_matching_bracket_unfolds_target() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    let content = "fn main() {\n    let a = 1;\n    let b = 2;\n}\nfn other() {}\n";
+    let fixture = TestFixture::new("fold_bracket.rs", content).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+
+    set_fold_range(&mut harness, 0, 3);
+    harness.render().unwrap();
+    let buffer_id = harness.editor().active_buffer();
+    harness.editor_mut().toggle_fold_at_line(buffer_id, 0);
+    harness.render().unwrap();
+
+    let row = layout::CONTENT_START_ROW as u16;
+    let row_text = harness.get_row_text(row + 1);
+    assert!(
+        row_text.contains("fn other"),
+        "Precondition failed: fold not collapsed. Row text: '{row_text}'"
+    );
+
+    let open = content.find('{').unwrap();
+    harness
+        .editor_mut()
+        .active_cursors_mut()
+        .primary_mut()
+        .position = open;
+    harness
+        .send_key(KeyCode::Char(']'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.cursor_position(), content.find("}\n").unwrap());
+    let row_text = harness.get_row_text(row + 1);
+    assert!(
+        row_text.contains("let a = 1"),
+        "Fold hiding the matching bracket should be expanded. Row text: '{row_text}'"
+    );
+}
+
 #[test]
 fn test_folding_preserves_syntax_highlighting_after_skip() {
     let mut harness = EditorTestHarness::new(80, 24).unwrap();
@@ -455,16 +496,18 @@ fn assert_gutter_matches_content(harness: &EditorTestHarness) {
 /// a 60-line file, folds lines 10..30 via a fake LSP, then scrolls down and
 /// back up one line at a time, checking every rendered frame.
 #[test]
-#[cfg_attr(
-    target_os = "windows",
-    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
-)]
 fn test_folded_gutter_line_numbers_match_content_during_scroll() -> anyhow::Result<()> {
-    use crate::common::fake_lsp::FakeLspServer;
+    use crate::common::scripted_lsp::ScriptedLspServer;
+    use serde_json::json;
 
-    // 1. Spawn fake LSP that advertises foldingRangeProvider and returns
+    // 1. Script a fake LSP that advertises foldingRangeProvider and returns
     //    a single range covering lines 10..30.
-    let _fake_server = FakeLspServer::spawn_with_folding_ranges()?;
+    let lsp = ScriptedLspServer::new()
+        .capability("foldingRangeProvider", json!(true))
+        .respond(
+            "textDocument/foldingRange",
+            json!([{"startLine": 10, "endLine": 30}]),
+        );
 
     // 2. Create a 60-line file where every line is "line N\n".
     let temp_dir = tempfile::tempdir()?;
@@ -478,19 +521,7 @@ fn test_folded_gutter_line_numbers_match_content_during_scroll() -> anyhow::Resu
     //    loop.
     let mut config = fresh::config::Config::default();
     config.editor.enable_semantic_tokens_full = true;
-    config.lsp.insert(
-        "rust".to_string(),
-        fresh::services::lsp::LspServerConfig {
-            command: FakeLspServer::folding_ranges_script_path()
-                .to_string_lossy()
-                .to_string(),
-            args: vec![],
-            enabled: true,
-            auto_start: true,
-            process_limits: fresh::services::process_limits::ProcessLimits::default(),
-            initialization_options: None,
-        },
-    );
+    config.lsp.insert("rust".to_string(), lsp.server_config()?);
 
     let mut harness = EditorTestHarness::with_config_and_working_dir(
         80,
@@ -499,13 +530,20 @@ fn test_folded_gutter_line_numbers_match_content_during_scroll() -> anyhow::Resu
         temp_dir.path().to_path_buf(),
     )?;
 
+    harness.attach_lsp(&lsp);
+
     harness.open_file(&test_file)?;
     harness.render()?;
 
-    // 4. Wait for the LSP to deliver folding ranges (the expand indicator ▾
-    //    appears in the gutter once ranges are available).
+    // 4. Wait for the editor to ask for folding ranges, then for them to be
+    //    delivered (the expand indicator ▾ appears in the gutter).
+    harness.wait_for_lsp_request("textDocument/foldingRange")?;
     harness.wait_for_screen_contains("▾")?;
 
+    // The server saw the file as it is on disk.
+    let opened = lsp.notifications("textDocument/didOpen");
+    assert_eq!(opened[0]["textDocument"]["text"], content.as_str());
+
     // 5. Collapse the fold by clicking the gutter indicator at line 10.
     let fold_row = layout::CONTENT_START_ROW as u16 + 10;
     harness.mouse_click(0, fold_row)?;
@@ -1501,3 +1539,149 @@ fn test_fold_unfold_at_end_of_large_file_gutter_click() {
     harness.assert_screen_contains("b_body_1");
     harness.assert_screen_contains("b_body_3");
 }
+
+#[test]
+fn test_current_line_highlight_covers_fold_placeholder() {
+    let content: String = (0..30).map(|i| format!("line {i}\n")).collect();
+    let mut config = fresh::config::Config::default();
+    config.editor.highlight_current_line = true;
+
+    let fixture = TestFixture::new("fold_current_line.py", &content).unwrap();
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    set_fold_range(&mut harness, 2, 6);
+    harness.render().unwrap();
+
+    harness
+        .mouse_click(0, (layout::CONTENT_START_ROW + 2) as u16)
+        .unwrap();
+    harness.assert_screen_not_contains("line 4");
+
+    set_cursor_line(&mut harness, 2);
+    harness.render().unwrap();
+
+    let current_line_bg = harness.editor().theme().current_line_bg;
+    let (row, col) = find_text_position(&harness, "...");
+    for x in col..col + 3 {
+        assert_eq!(
+            harness.get_cell_style(x, row).and_then(|style| style.bg),
+            Some(current_line_bg),
+            "placeholder cell {x} should have the current-line background"
+        );
+    }
+    // The background carries on past the end of the text
+    for x in [col + 3, col + 20, 70] {
+        assert_eq!(
+            harness.get_cell_style(x, row).and_then(|style| style.bg),
+            Some(current_line_bg),
+            "cell {x} past the placeholder should have the current-line background"
+        );
+    }
+
+    // Other lines are left alone
+    let (other_row, other_col) = find_text_position(&harness, "line 8");
+    assert_ne!(
+        harness
+            .get_cell_style(other_col, other_row)
+            .and_then(|style| style.bg),
+        Some(current_line_bg)
+    );
+}
+
+#[test]
+fn test_selection_across_fold_highlights_placeholder_and_copies_hidden_lines() {
+    let content: String = (0..30).map(|i| format!("line {i}\n")).collect();
+    let fixture = TestFixture::new("fold_selection.py", &content).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    set_fold_range(&mut harness, 2, 6);
+    harness.render().unwrap();
+
+    harness
+        .mouse_click(0, (layout::CONTENT_START_ROW + 2) as u16)
+        .unwrap();
+    harness.assert_screen_not_contains("line 4");
+
+    // Select from the start of line 1 to the start of line 8
+    let (anchor, position) = {
+        let buffer = &mut harness.editor_mut().active_state_mut().buffer;
+        (
+            buffer.line_start_offset(1).unwrap(),
+            buffer.line_start_offset(8).unwrap(),
+        )
+    };
+    let cursors = harness.editor_mut().active_cursors_mut();
+    cursors.primary_mut().anchor = Some(anchor);
+    cursors.primary_mut().position = position;
+    harness.render().unwrap();
+
+    let selection_bg = harness.editor().theme().selection_bg;
+    let (row, col) = find_text_position(&harness, "...");
+    for x in col..col + 3 {
+        assert_eq!(
+            harness.get_cell_style(x, row).and_then(|style| style.bg),
+            Some(selection_bg),
+            "placeholder cell {x} should be drawn selected"
+        );
+    }
+
+    // Copying takes the hidden lines too
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+    let expected: String = (1..8).map(|i| format!("line {i}\n")).collect();
+    assert_eq!(harness.editor_mut().clipboard_content_for_test(), expected);
+}
+
+#[test]
+fn test_copy_visible_leaves_out_folded_lines() {
+    let content: String = (0..30).map(|i| format!("line {i}\n")).collect();
+    let fixture = TestFixture::new("fold_copy_visible.py", &content).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    set_fold_range(&mut harness, 2, 6);
+    harness.render().unwrap();
+
+    harness
+        .mouse_click(0, (layout::CONTENT_START_ROW + 2) as u16)
+        .unwrap();
+    harness.assert_screen_not_contains("line 4");
+
+    // Select from the middle of line 1 to the middle of line 8
+    let (anchor, position) = {
+        let buffer = &mut harness.editor_mut().active_state_mut().buffer;
+        (
+            buffer.line_start_offset(1).unwrap() + 2,
+            buffer.line_start_offset(8).unwrap() + 4,
+        )
+    };
+    let cursors = harness.editor_mut().active_cursors_mut();
+    cursors.primary_mut().anchor = Some(anchor);
+    cursors.primary_mut().position = position;
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Copy Visible").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(
+        harness.editor_mut().clipboard_content_for_test(),
+        "ne 1\nline 2\nline 7\nline"
+    );
+
+    // The fold is still collapsed and plain copy still takes the hidden lines
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("line 4");
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert!(harness
+        .editor_mut()
+        .clipboard_content_for_test()
+        .contains("line 4"));
+}
@@ -1,5 +1,5 @@
 use crate::common::harness::EditorTestHarness;
-use crossterm::event::{KeyCode, KeyModifiers};
+use crossterm::event::{KeyCode, KeyModifiers, MouseEvent, MouseEventKind};
 use fresh::config::Config;
 
 /// Helper: check if any scrollbar-colored cell exists at the given row
@@ -27,6 +27,24 @@ fn long_lines_content(num_lines: usize, line_length: usize) -> String {
         .join("\n")
 }
 
+/// Send a horizontal wheel step over the content area
+fn scroll_horizontally(
+    harness: &mut EditorTestHarness,
+    kind: MouseEventKind,
+    modifiers: KeyModifiers,
+) {
+    let (first_content_row, _) = harness.content_area_rows();
+    harness
+        .send_mouse(MouseEvent {
+            kind,
+            column: 40,
+            row: first_content_row as u16,
+            modifiers,
+        })
+        .unwrap();
+    harness.render().unwrap();
+}
+
 /// Test that the horizontal scrollbar is visible at the bottom of the split
 /// when line wrap is disabled and content has long lines
 #[test]
@@ -238,3 +256,72 @@ fn test_horizontal_scrollbar_in_split_view() {
     // Both splits should show their content
     harness.assert_screen_contains("Line 0:");
 }
+
+/// Test that Shift+wheel and sideways wheel events scroll by the configured
+/// number of columns
+#[test]
+fn test_horizontal_wheel_scrolls_configured_columns() {
+    let mut config = Config::default();
+    config.editor.line_wrap = false;
+    config.editor.mouse_horizontal_scroll_columns = 5;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness
+        .load_buffer_from_text(&long_lines_content(50, 200))
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_viewport().left_column, 0);
+
+    scroll_horizontally(
+        &mut harness,
+        MouseEventKind::ScrollRight,
+        KeyModifiers::NONE,
+    );
+    assert_eq!(harness.editor().active_viewport().left_column, 5);
+
+    scroll_horizontally(
+        &mut harness,
+        MouseEventKind::ScrollDown,
+        KeyModifiers::SHIFT,
+    );
+    assert_eq!(harness.editor().active_viewport().left_column, 10);
+
+    scroll_horizontally(&mut harness, MouseEventKind::ScrollUp, KeyModifiers::SHIFT);
+    assert_eq!(harness.editor().active_viewport().left_column, 5);
+
+    scroll_horizontally(&mut harness, MouseEventKind::ScrollLeft, KeyModifiers::NONE);
+    scroll_horizontally(&mut harness, MouseEventKind::ScrollLeft, KeyModifiers::NONE);
+    assert_eq!(harness.editor().active_viewport().left_column, 0);
+}
+
+/// Test that scrolling right stops once the longest visible line ends on
+/// screen, even if a longer line exists further down the file
+#[test]
+fn test_horizontal_scroll_clamped_to_longest_visible_line() {
+    let mut config = Config::default();
+    config.editor.line_wrap = false;
+    config.editor.mouse_horizontal_scroll_columns = 10;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let mut content = long_lines_content(40, 120);
+    content.push_str("\n");
+    content.push_str(&"Y".repeat(1000));
+    harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+
+    for _ in 0..50 {
+        scroll_horizontally(
+            &mut harness,
+            MouseEventKind::ScrollRight,
+            KeyModifiers::NONE,
+        );
+    }
+
+    let left_column = harness.editor().active_viewport().left_column;
+    assert!(
+        left_column > 0 && left_column < 120,
+        "Scrolling should stop at the end of the visible lines, left_column = {left_column}"
+    );
+    // The ends of the visible lines are on screen
+    harness.assert_screen_contains("XXX");
+}
@@ -0,0 +1,145 @@
+//! E2E tests for mouse hover tooltips (diagnostics, fold placeholders, tabs)
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::{layout, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::view::overlay::Overlay;
+use lsp_types::FoldingRange;
+use std::time::Duration;
+
+const TOOLTIP_DELAY: Duration = Duration::from_millis(400);
+
+/// Rest the mouse at `(col, row)` until the tooltip delay has passed
+fn rest_mouse(harness: &mut EditorTestHarness, col: u16, row: u16) {
+    harness.mouse_move(col, row).unwrap();
+    harness.advance_time(TOOLTIP_DELAY);
+    harness.editor_mut().check_hover_tooltip_timer();
+    harness.render().unwrap();
+}
+
+fn tooltip_lines(harness: &EditorTestHarness) -> Option<Vec<String>> {
+    harness.editor().hover_tooltip().map(|t| t.lines.clone())
+}
+
+fn screen_position(harness: &EditorTestHarness, needle: &str) -> (u16, u16) {
+    let (start_row, end_row) = harness.content_area_rows();
+    for row in start_row..=end_row {
+        let text = harness.get_row_text(row as u16);
+        if let Some(byte_offset) = text.find(needle) {
+            return (text[..byte_offset].chars().count() as u16, row as u16);
+        }
+    }
+    panic!(
+        "Expected to find '{}' on screen.\nScreen:\n{}",
+        needle,
+        harness.screen_to_string()
+    );
+}
+
+#[test]
+fn test_diagnostic_tooltip_after_dwell_and_dismissed_on_move_and_key() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let fixture = TestFixture::new("tooltip.rs", "let value = 1;\nlet other = 2;\n").unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    {
+        let state = harness.editor_mut().active_state_mut();
+        let overlay = Overlay::error(
+            &mut state.marker_list,
+            4..9,
+            Some("unused variable: `value`".to_string()),
+        );
+        state.overlays.add(overlay);
+    }
+    harness.render().unwrap();
+
+    let (col, row) = screen_position(&harness, "value");
+
+    // Nothing before the pointer has rested long enough
+    harness.mouse_move(col + 1, row).unwrap();
+    harness.advance_time(TOOLTIP_DELAY / 2);
+    assert!(!harness.editor_mut().check_hover_tooltip_timer());
+    harness.advance_time(TOOLTIP_DELAY / 2);
+    assert!(harness.editor_mut().check_hover_tooltip_timer());
+    harness.render().unwrap();
+    harness.assert_screen_contains("unused variable: `value`");
+
+    // Any movement dismisses it
+    harness.mouse_move(col + 2, row).unwrap();
+    assert_eq!(tooltip_lines(&harness), None);
+    harness.assert_screen_not_contains("unused variable");
+
+    // So does a key press
+    rest_mouse(&mut harness, col + 2, row);
+    harness.assert_screen_contains("unused variable: `value`");
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("unused variable");
+
+    // Text without a diagnostic has no tooltip
+    let (col, row) = screen_position(&harness, "other");
+    rest_mouse(&mut harness, col, row);
+    assert_eq!(tooltip_lines(&harness), None);
+}
+
+#[test]
+fn test_fold_placeholder_tooltip_shows_hidden_lines() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let content: String = (0..30).map(|i| format!("line {i}\n")).collect();
+    let fixture = TestFixture::new("tooltip_fold.py", &content).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.editor_mut().active_state_mut().folding_ranges = vec![FoldingRange {
+        start_line: 2,
+        end_line: 6,
+        start_character: None,
+        end_character: None,
+        kind: None,
+        collapsed_text: None,
+    }];
+    harness.render().unwrap();
+
+    // Collapse the fold from the gutter
+    harness
+        .mouse_click(0, (layout::CONTENT_START_ROW + 2) as u16)
+        .unwrap();
+    harness.assert_screen_not_contains("line 4");
+
+    let (col, row) = screen_position(&harness, "...");
+    rest_mouse(&mut harness, col + 1, row);
+    assert_eq!(
+        tooltip_lines(&harness),
+        Some(vec![
+            "line 3".to_string(),
+            "line 4".to_string(),
+            "line 5".to_string(),
+            "line 6".to_string(),
+        ])
+    );
+    harness.assert_screen_contains("line 4");
+
+    // The header text itself has no tooltip
+    rest_mouse(&mut harness, col - 2, row);
+    assert_eq!(tooltip_lines(&harness), None);
+}
+
+#[test]
+fn test_tab_tooltip_shows_full_path() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let fixture = TestFixture::new("tooltip_tab.txt", "hello\n").unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    let path = harness
+        .editor()
+        .active_state()
+        .buffer
+        .file_path()
+        .unwrap()
+        .display()
+        .to_string();
+
+    // The tab bar is the row above the content
+    rest_mouse(&mut harness, 3, (layout::CONTENT_START_ROW - 1) as u16);
+    assert_eq!(tooltip_lines(&harness), Some(vec![path]));
+}
@@ -0,0 +1,112 @@
+//! E2E tests for indent guides and bracket pair colorization.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+/// Helper: gutter width for a small buffer is 1 (indicator) + 4 (digits) + 3 (" │ ") = 8
+const SMALL_BUFFER_GUTTER: u16 = 8;
+
+const NESTED_BLOCKS: &str = "fn main() {\n    if x {\n        y();\n    }\n}\n";
+
+fn fg_at(harness: &EditorTestHarness, x: u16, y: u16) -> Option<ratatui::style::Color> {
+    harness.get_cell_style(x, y).and_then(|s| s.fg)
+}
+
+/// Guides appear at each indent level, with the cursor's block highlighted.
+#[test]
+fn test_indent_guides_render_in_leading_whitespace() {
+    let mut config = Config::default();
+    config.editor.indent_guides = true;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let _fixture = harness.load_buffer_from_text(NESTED_BLOCKS).unwrap();
+    harness.render().unwrap();
+
+    let (first_row, _) = harness.content_area_rows();
+    let row = first_row as u16;
+
+    // No guide on the top-level line
+    assert_eq!(
+        harness.get_cell(SMALL_BUFFER_GUTTER, row).as_deref(),
+        Some("f")
+    );
+    // One guide inside `main`, two inside `if`
+    assert_eq!(
+        harness.get_cell(SMALL_BUFFER_GUTTER, row + 1).as_deref(),
+        Some("│")
+    );
+    assert_eq!(
+        harness.get_cell(SMALL_BUFFER_GUTTER, row + 2).as_deref(),
+        Some("│")
+    );
+    assert_eq!(
+        harness
+            .get_cell(SMALL_BUFFER_GUTTER + 4, row + 2)
+            .as_deref(),
+        Some("│")
+    );
+    assert_eq!(
+        harness
+            .get_cell(SMALL_BUFFER_GUTTER + 2, row + 2)
+            .as_deref(),
+        Some(" ")
+    );
+
+    // The cursor is on the line opening `main`, so its guide is the active one
+    assert_ne!(
+        fg_at(&harness, SMALL_BUFFER_GUTTER, row + 2),
+        fg_at(&harness, SMALL_BUFFER_GUTTER + 4, row + 2),
+        "Active guide should stand out from the inner guide"
+    );
+}
+
+/// Indent guides are off by default and toggle from the command palette.
+#[test]
+fn test_toggle_indent_guides_command() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(NESTED_BLOCKS).unwrap();
+    harness.render().unwrap();
+
+    let (first_row, _) = harness.content_area_rows();
+    let row = first_row as u16 + 1;
+    assert_eq!(
+        harness.get_cell(SMALL_BUFFER_GUTTER, row).as_deref(),
+        Some(" ")
+    );
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Toggle Indent Guides").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_cell(SMALL_BUFFER_GUTTER, row).as_deref(),
+        Some("│")
+    );
+    harness.assert_screen_contains("Indent guides shown");
+}
+
+/// Bracket pairs share a color that changes with nesting depth.
+#[test]
+fn test_bracket_pair_colorization() {
+    let mut config = Config::default();
+    config.editor.bracket_pair_colorization = true;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let _fixture = harness.load_buffer_from_text("a(b[c]d)e\n").unwrap();
+    harness.render().unwrap();
+
+    let (first_row, _) = harness.content_area_rows();
+    let row = first_row as u16;
+    let fg = |col: u16| fg_at(&harness, SMALL_BUFFER_GUTTER + col, row);
+
+    assert_eq!(fg(1), fg(7), "( and ) should share a color");
+    assert_eq!(fg(3), fg(5), "[ and ] should share a color");
+    assert_ne!(fg(1), fg(3), "Nested pair should use the next color");
+    assert_ne!(fg(1), fg(0), "Brackets should differ from plain text");
+}
@@ -0,0 +1,37 @@
+//! E2E tests for handling queued input events before drawing
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+#[test]
+fn test_queued_keys_are_drawn_once() {
+    let content: String = (0..5000).map(|i| format!("line {i}\n")).collect();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+
+    let renders = harness.render_count();
+    harness
+        .send_key_repeat(KeyCode::Down, KeyModifiers::NONE, 1000)
+        .unwrap();
+    let frames = harness.render_count() - renders;
+    assert!(frames < 10, "1000 queued Down keys drew {frames} frames");
+
+    assert_eq!(
+        harness.cursor_position(),
+        content.find("line 1000\n").unwrap()
+    );
+    harness.assert_screen_contains("line 1000");
+}
+
+#[test]
+fn test_key_that_opens_prompt_ends_batch() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    let renders = harness.render_count();
+    harness
+        .send_key_repeat(KeyCode::Char('p'), KeyModifiers::CONTROL, 1)
+        .unwrap();
+    // Once when the palette opened, and once at the end
+    assert_eq!(harness.render_count() - renders, 2);
+    harness.assert_screen_contains(">command");
+}
@@ -0,0 +1,63 @@
+use crate::common::harness::EditorTestHarness;
+use std::path::Path;
+
+/// Inline scripts drive the editor like the equivalent harness calls
+#[test]
+fn test_script_sorts_lines() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .run_script(
+            "sort_lines_inline",
+            r#"type "cherry\napple\nbanana"; key Ctrl+A
+               key Ctrl+P; wait_for ">command"; type "sort lines"; key Enter
+               expect_screen "apple"; expect_not_screen ">command""#,
+        )
+        .unwrap();
+    harness.assert_buffer_content("apple\nbanana\ncherry");
+}
+
+/// Script files, such as ones saved with `--record-input`, replay the same way
+#[test]
+fn test_script_file_replays() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .run_script_file(
+            &Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/fixtures/input_scripts/sort_lines.fresh"),
+        )
+        .unwrap();
+}
+
+/// Mouse, paste and resize statements reach the editor
+#[test]
+fn test_script_mouse_paste_and_resize() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .run_script(
+            "mouse_paste_resize",
+            r#"paste "first\nsecond"
+               resize 100 30
+               wait 100
+               key Ctrl+Home
+               mouse scroll_down 10 5; mouse scroll_up 10 5
+               expect_buffer "first\nsecond""#,
+        )
+        .unwrap();
+    assert_eq!(harness.buffer().area.width, 100);
+}
+
+/// A failed expectation names the line and saves the screen
+#[test]
+fn test_script_mismatch_dumps_screen() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let error = harness
+        .run_script("mismatch", "type \"hello\"\nexpect_screen \"goodbye\"")
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with("mismatch line 2: expected screen to contain \"goodbye\""));
+    let dump_path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("input-scripts/mismatch-line2.txt");
+    assert!(std::fs::read_to_string(dump_path)
+        .unwrap()
+        .contains("hello"));
+}
@@ -1042,8 +1042,8 @@ fn test_edit_dialog_context_cycling() {
     harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
     harness.render().unwrap();
 
-    // Default context should be "normal"
-    harness.assert_screen_contains("normal");
+    // Default context should be "editor"
+    harness.assert_screen_contains("[editor]");
 
     // Press Right to cycle context
     harness
@@ -1654,6 +1654,232 @@ fn test_add_binding_conflict_warning_for_existing_key() {
     );
 }
 
+/// Test that conflicts are only reported against bindings of the same context
+#[test]
+fn test_add_binding_conflict_is_per_context() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    open_keybinding_editor(&mut harness);
+
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::NONE)
+        .unwrap();
+    // Ctrl+S is bound to "save" in the editor context
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Conflict");
+
+    // Tab past the action field to the context field, switch to "prompt"
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("[prompt]");
+    harness.assert_screen_not_contains("Conflict");
+}
+
+// ========================
+// Key sequence recording
+// ========================
+
+/// Open the Add dialog and record the given keys
+fn record_keys_in_add_dialog(harness: &mut EditorTestHarness, keys: &[(KeyCode, KeyModifiers)]) {
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Add Keybinding");
+
+    for &(code, modifiers) in keys {
+        harness.send_key(code, modifiers).unwrap();
+    }
+    harness.render().unwrap();
+}
+
+const CTRL_ALT: KeyModifiers = KeyModifiers::CONTROL.union(KeyModifiers::ALT);
+
+/// Test that consecutive key presses are recorded as one sequence
+#[test]
+fn test_record_key_sequence() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    open_keybinding_editor(&mut harness);
+
+    record_keys_in_add_dialog(
+        &mut harness,
+        &[
+            (KeyCode::Char('j'), CTRL_ALT),
+            (KeyCode::Char('l'), CTRL_ALT),
+        ],
+    );
+
+    harness.assert_screen_contains("Ctrl+Alt+J Ctrl+Alt+L");
+}
+
+/// Test that Backspace clears the recorded sequence so it can be re-recorded
+#[test]
+fn test_backspace_clears_recorded_sequence() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    open_keybinding_editor(&mut harness);
+
+    record_keys_in_add_dialog(
+        &mut harness,
+        &[
+            (KeyCode::Char('j'), CTRL_ALT),
+            (KeyCode::Char('l'), CTRL_ALT),
+        ],
+    );
+    harness.assert_screen_contains("Ctrl+Alt+J Ctrl+Alt+L");
+
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Ctrl+Alt+J Ctrl+Alt+L");
+
+    // Recording starts from scratch
+    harness.send_key(KeyCode::Char('l'), CTRL_ALT).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Ctrl+Alt+L");
+    harness.assert_screen_not_contains("Ctrl+Alt+J");
+}
+
+/// Test that a sequence starting with an existing single-key binding is
+/// flagged, since that binding would fire before the sequence completes
+#[test]
+fn test_key_sequence_prefix_conflict() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    open_keybinding_editor(&mut harness);
+
+    // Ctrl+S is bound to "save" in the keymap
+    record_keys_in_add_dialog(
+        &mut harness,
+        &[
+            (KeyCode::Char('s'), KeyModifiers::CONTROL),
+            (KeyCode::Char('x'), KeyModifiers::NONE),
+        ],
+    );
+
+    harness.assert_screen_contains("Ctrl+S X");
+    let screen = harness.screen_to_string();
+    assert!(
+        screen.contains("Conflict"),
+        "Sequence starting with Ctrl+S should conflict with save.\nScreen:\n{}",
+        screen
+    );
+}
+
+/// Test that a recorded sequence is saved and dispatched as a chord
+#[test]
+fn test_add_key_sequence_binding_full_flow() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    open_keybinding_editor(&mut harness);
+
+    record_keys_in_add_dialog(
+        &mut harness,
+        &[
+            (KeyCode::Char('j'), CTRL_ALT),
+            (KeyCode::Char('d'), KeyModifiers::NONE),
+        ],
+    );
+    harness.assert_screen_contains("Ctrl+Alt+J D");
+
+    // Tab to Action field, type "duplicate_line" and accept autocomplete
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    for ch in "duplicate_line".chars() {
+        harness
+            .send_key(KeyCode::Char(ch), KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    // Tab to context, then to Save button, press Enter to save the dialog
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("modified");
+
+    // Save and close keybinding editor with Ctrl+S
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Keybinding Editor");
+
+    let chord = harness
+        .config()
+        .keybindings
+        .iter()
+        .find(|kb| kb.action == "duplicate_line")
+        .expect("duplicate_line binding should be saved")
+        .keys
+        .clone();
+    assert_eq!(chord.len(), 2, "Binding should be saved as a chord");
+
+    harness.type_text("aaa").unwrap();
+
+    // The first key is pending and hinted in the status bar
+    harness.send_key(KeyCode::Char('j'), CTRL_ALT).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("[Ctrl+Alt+J]");
+
+    harness
+        .send_key(KeyCode::Char('d'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "aaa\naaa");
+}
+
+/// Test that a pending chord prefix is dropped after `chord_timeout_ms`
+#[test]
+fn test_chord_prefix_times_out() {
+    let mut config = fresh::config::Config::default();
+    config.editor.chord_timeout_ms = 1;
+    config.keybindings.push(fresh::config::Keybinding {
+        key: String::new(),
+        modifiers: vec![],
+        keys: vec![
+            fresh::config::KeyPress {
+                key: "j".to_string(),
+                modifiers: vec!["ctrl".to_string(), "alt".to_string()],
+            },
+            fresh::config::KeyPress {
+                key: "d".to_string(),
+                modifiers: vec![],
+            },
+        ],
+        action: "duplicate_line".to_string(),
+        args: std::collections::HashMap::new(),
+        when: None,
+        context: None,
+    });
+    let mut harness = EditorTestHarness::with_config(120, 40, config).unwrap();
+
+    harness.send_key(KeyCode::Char('j'), CTRL_ALT).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("[Ctrl+Alt+J]");
+
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    assert!(harness.editor_mut().check_chord_timeout());
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("[Ctrl+Alt+J]");
+
+    // The second key is now typed normally instead of completing the chord
+    harness
+        .send_key(KeyCode::Char('d'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "d");
+}
+
 // ========================
 // Terminal mode interaction
 // ========================
@@ -0,0 +1,233 @@
+//! Tests for line bookmarks.
+//!
+//! Tests that:
+//! - Toggling a bookmark shows it in the gutter and toggling again removes it
+//! - Next/Previous Bookmark cycle through the buffer and wrap around
+//! - Bookmarks stay on their line when text is inserted above them
+//! - The bookmark picker jumps across buffers
+//! - Bookmarks and their labels are restored with the workspace
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+const CONTENT: &str = "line 1\nline 2\nline 3\nline 4\nline 5\nline 6\n";
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command_name).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn set_cursor(harness: &mut EditorTestHarness, position: usize) {
+    let cursors = harness.editor_mut().active_cursors_mut();
+    cursors.primary_mut().position = position;
+    cursors.primary_mut().anchor = None;
+}
+
+fn toggle_bookmark(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(
+            KeyCode::Char('b'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn line_start(line: usize) -> usize {
+    CONTENT
+        .split_inclusive('\n')
+        .take(line - 1)
+        .map(str::len)
+        .sum()
+}
+
+/// Toggling adds a gutter symbol, toggling again on the same line removes it.
+#[test]
+fn test_toggle_bookmark_in_gutter() {
+    let fixture = TestFixture::new("toggle.txt", CONTENT).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("◆");
+
+    // Anywhere on the line toggles the line's bookmark
+    set_cursor(&mut harness, line_start(3) + 2);
+    toggle_bookmark(&mut harness);
+    harness.assert_screen_contains("◆");
+
+    set_cursor(&mut harness, line_start(3));
+    toggle_bookmark(&mut harness);
+    harness.assert_screen_not_contains("◆");
+}
+
+/// Next and Previous Bookmark visit bookmarked lines in order and wrap.
+#[test]
+fn test_next_previous_bookmark_wrap() {
+    let fixture = TestFixture::new("cycle.txt", CONTENT).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    for line in [2, 5] {
+        set_cursor(&mut harness, line_start(line) + 1);
+        toggle_bookmark(&mut harness);
+    }
+    set_cursor(&mut harness, 0);
+
+    let next = KeyModifiers::CONTROL | KeyModifiers::ALT;
+    harness.send_key(KeyCode::Char('n'), next).unwrap();
+    assert_eq!(harness.cursor_position(), line_start(2));
+    harness.send_key(KeyCode::Char('n'), next).unwrap();
+    assert_eq!(harness.cursor_position(), line_start(5));
+    harness.send_key(KeyCode::Char('n'), next).unwrap();
+    assert_eq!(harness.cursor_position(), line_start(2));
+
+    harness.send_key(KeyCode::Char('p'), next).unwrap();
+    assert_eq!(harness.cursor_position(), line_start(5));
+    harness.send_key(KeyCode::Char('p'), next).unwrap();
+    assert_eq!(harness.cursor_position(), line_start(2));
+}
+
+/// A bookmark follows its line when lines are inserted above it.
+#[test]
+fn test_bookmark_survives_edit_above() {
+    let fixture = TestFixture::new("edit.txt", CONTENT).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    set_cursor(&mut harness, line_start(4));
+    toggle_bookmark(&mut harness);
+
+    set_cursor(&mut harness, 0);
+    harness.type_text("new\nlines\n").unwrap();
+    set_cursor(&mut harness, 0);
+
+    harness
+        .send_key(
+            KeyCode::Char('n'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    let position = harness.cursor_position();
+    let content = harness.get_buffer_content().unwrap();
+    assert!(content[position..].starts_with("line 4"));
+}
+
+/// Clearing the buffer's bookmarks leaves nothing to cycle through.
+#[test]
+fn test_clear_buffer_bookmarks() {
+    let fixture = TestFixture::new("clear.txt", CONTENT).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    for line in [1, 3, 6] {
+        set_cursor(&mut harness, line_start(line));
+        toggle_bookmark(&mut harness);
+    }
+    harness.assert_screen_contains("◆");
+
+    run_command(&mut harness, "Clear Bookmarks in Buffer");
+    harness.assert_screen_not_contains("◆");
+
+    set_cursor(&mut harness, line_start(2));
+    harness
+        .send_key(
+            KeyCode::Char('n'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    assert_eq!(harness.cursor_position(), line_start(2));
+}
+
+/// The picker lists labeled bookmarks of other buffers and jumps to them.
+#[test]
+fn test_browse_bookmarks_jumps_to_other_buffer() {
+    let first = TestFixture::new("first.txt", CONTENT).unwrap();
+    let second = TestFixture::new("second.txt", "alpha\nbeta\n").unwrap();
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&first.path).unwrap();
+    harness.render().unwrap();
+
+    set_cursor(&mut harness, line_start(5));
+    run_command(&mut harness, "Add Labeled Bookmark");
+    harness.type_text("setup").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.open_file(&second.path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Browse Bookmarks");
+    // The picker shows the label and a preview of the line
+    harness.assert_screen_contains("setup (");
+    harness.assert_screen_contains("line 5");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), CONTENT);
+    assert_eq!(harness.cursor_position(), line_start(5));
+}
+
+/// Line bookmarks and their labels come back with the workspace.
+#[test]
+fn test_bookmarks_restored_with_workspace() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let file = project_dir.join("saved.txt");
+    std::fs::write(&file, CONTENT).unwrap();
+
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            100,
+            24,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+        harness.open_file(&file).unwrap();
+        harness.render().unwrap();
+
+        set_cursor(&mut harness, line_start(3));
+        run_command(&mut harness, "Add Labeled Bookmark");
+        harness.type_text("todo").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+
+        harness.editor_mut().save_workspace().unwrap();
+    }
+
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            100,
+            24,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+        harness.editor_mut().try_restore_workspace().unwrap();
+        harness.render().unwrap();
+        harness.assert_screen_contains("◆");
+
+        run_command(&mut harness, "Browse Bookmarks");
+        harness.assert_screen_contains("todo (saved.txt:3)");
+    }
+}
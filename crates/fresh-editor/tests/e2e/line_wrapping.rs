@@ -1905,8 +1905,8 @@ fn test_visual_line_movement_up_down() {
     );
 }
 
-/// Test that End key navigates to end of visual (wrapped) line segment
-/// and Home key navigates to start of visual segment
+/// Test that End key navigates to end of visual (wrapped) line segment first,
+/// and to the end of the physical line when pressed again
 /// This is the fix for issue #979: End key should go to end of visual line, not physical line
 #[test]
 fn test_end_key_goes_to_visual_line_end() {
@@ -1961,7 +1961,7 @@ fn test_end_key_goes_to_visual_line_end() {
         "End should move cursor forward from position 0"
     );
 
-    // Press End again - should go to end of second visual segment
+    // Press End again - should go to end of the physical line (last segment)
     harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
     harness.render().unwrap();
 
@@ -1969,28 +1969,25 @@ fn test_end_key_goes_to_visual_line_end() {
     let (_end_x_2, end_y_2) = harness.screen_cursor_position();
     eprintln!("After second End: pos={}, screen_y={}", end_pos_2, end_y_2);
 
-    assert!(
-        end_pos_2 > end_pos_1,
-        "Second End should move past first visual line end"
+    assert_eq!(
+        end_pos_2,
+        long_text.len(),
+        "Second End should reach physical line end"
     );
-    // Should be on the next visual row
     assert_eq!(
         end_y_2,
-        start_y + 1,
-        "Second End should move to next visual row"
+        start_y + 2,
+        "Second End should move to the last visual row"
     );
 
-    // Press End once more - should reach end of physical line (last segment)
+    // Press End once more - already at physical line end, cursor stays put
     harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
     harness.render().unwrap();
 
-    let end_pos_3 = harness.cursor_position();
-    eprintln!("After third End: pos={}", end_pos_3);
-
     assert_eq!(
-        end_pos_3,
+        harness.cursor_position(),
         long_text.len(),
-        "Third End should reach physical line end"
+        "Third End should stay at physical line end"
     );
 }
 
@@ -2032,23 +2029,17 @@ fn test_home_key_goes_to_visual_line_start() {
         home_pos_1
     );
 
-    // Press Home again - should go to start of previous visual segment
+    // Press Home again - should go straight to the physical line start
     harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
     harness.render().unwrap();
 
     let home_pos_2 = harness.cursor_position();
     eprintln!("After second Home: pos={}", home_pos_2);
 
-    assert!(home_pos_2 < home_pos_1, "Second Home should move backward");
-
-    // Press Home once more - should reach physical line start
-    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
-    harness.render().unwrap();
-
-    let home_pos_3 = harness.cursor_position();
-    eprintln!("After third Home: pos={}", home_pos_3);
-
-    assert_eq!(home_pos_3, 0, "Third Home should reach physical line start");
+    assert_eq!(
+        home_pos_2, 0,
+        "Second Home should reach physical line start"
+    );
 }
 
 /// Test Alt+Shift+Up/Down (block select) works with line wrapping enabled
@@ -2156,3 +2147,55 @@ fn test_add_cursor_below_with_line_wrap_enabled() {
         "Should have 2 cursors after add cursor below"
     );
 }
+
+/// Test that a language's `line_wrap` overrides the editor default, and that
+/// Toggle Line Wrap only affects the active buffer
+#[test]
+fn test_line_wrap_language_override_and_per_buffer_toggle() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let long_line = format!("{} TAILWORD\n", "word ".repeat(30));
+    let rust_path = temp_dir.path().join("main.rs");
+    let text_path = temp_dir.path().join("notes.txt");
+    std::fs::write(&rust_path, format!("// {}", long_line)).unwrap();
+    std::fs::write(&text_path, &long_line).unwrap();
+
+    let mut config = Config::default();
+    config.languages.get_mut("rust").unwrap().line_wrap = Some(false);
+    let mut harness = EditorTestHarness::with_config(60, 24, config).unwrap();
+
+    // Rust opts out of wrapping, so the end of the line is scrolled off-screen
+    harness.open_file(&rust_path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("TAILWORD");
+
+    // Plain text follows editor.line_wrap
+    harness.open_file(&text_path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("TAILWORD");
+
+    // Toggling wrap off for the text buffer leaves the Rust buffer alone
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Toggle Line Wrap").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("TAILWORD");
+
+    harness.open_file(&rust_path).unwrap();
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Toggle Line Wrap").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("TAILWORD");
+
+    harness.open_file(&text_path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("TAILWORD");
+}
@@ -0,0 +1,164 @@
+//! Tests for log files.
+//!
+//! Tests that:
+//! - Level words are colored and log files open read-only
+//! - A followed log file shows its new end when it grows on disk
+//! - Moving the cursor up pauses following until Toggle Follow Tail
+//! - Filter Log Lines hides the lines not matching, keeping line numbers
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::style::Color;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// Long enough for the file's modification time to change
+const FILE_CHANGE_DELAY: Duration = Duration::from_millis(2100);
+
+const LOG: &str = "\
+starting up
+12:00:01 INFO listening on :8080
+12:00:02 DEBUG accepted connection
+12:00:03 ERROR disk full
+12:00:04 WARN retrying, error count 1
+12:00:05 INFO request done
+";
+
+fn write_and_sync(path: &Path, content: &str) {
+    let mut file = File::create(path).unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file.sync_all().unwrap();
+}
+
+fn numbered_lines(count: usize) -> String {
+    (1..=count).map(|i| format!("event {i}\n")).collect()
+}
+
+/// A project holding `name` with `content`, opened
+fn open(name: &str, content: &str) -> (EditorTestHarness, std::path::PathBuf) {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let path = harness.project_dir().unwrap().join(name);
+    write_and_sync(&path, content);
+    harness.open_file(&path).unwrap();
+    harness.render().unwrap();
+    (harness, path)
+}
+
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(">command"))
+        .unwrap();
+    harness.type_text(command).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn fg_of(harness: &EditorTestHarness, text: &str) -> Option<Color> {
+    let (x, y) = harness
+        .find_text_on_screen(text)
+        .unwrap_or_else(|| panic!("{text:?} not on screen"));
+    harness.get_cell_style(x, y).and_then(|style| style.fg)
+}
+
+#[test]
+fn test_levels_colored_and_read_only() {
+    let (mut harness, _path) = open("app.log", LOG);
+
+    let error = fg_of(&harness, "ERROR");
+    assert_ne!(error, fg_of(&harness, "WARN"));
+    assert_ne!(error, fg_of(&harness, "disk"));
+    // Lower case words in messages aren't levels
+    assert_eq!(fg_of(&harness, "error count"), fg_of(&harness, "retrying"));
+
+    harness.type_text("x").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), LOG);
+}
+
+#[test]
+fn test_follow_shows_new_end() {
+    let (mut harness, path) = open("app.log", &numbered_lines(50));
+    harness.assert_screen_contains("event 50");
+    assert!(harness.get_status_bar().contains("Following"));
+
+    harness.sleep(FILE_CHANGE_DELAY);
+    write_and_sync(&path, &numbered_lines(80));
+    harness
+        .wait_until(|h| h.screen_to_string().contains("event 80"))
+        .expect("the view should follow the end of the log");
+}
+
+#[test]
+fn test_moving_up_pauses_follow() {
+    let (mut harness, path) = open("app.log", &numbered_lines(50));
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert!(harness.get_status_bar().contains("Follow paused"));
+
+    harness.sleep(FILE_CHANGE_DELAY);
+    write_and_sync(&path, &numbered_lines(80));
+    let grown = numbered_lines(80);
+    harness
+        .wait_until(|h| h.get_buffer_content().unwrap() == grown)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(!harness.screen_to_string().contains("event 80"));
+
+    run_command(&mut harness, "Toggle Follow Tail");
+    harness.assert_screen_contains("event 80");
+    assert!(harness.get_status_bar().contains("Following"));
+}
+
+#[test]
+fn test_filter_hides_lines_keeping_numbers() {
+    let (mut harness, _path) = open("app.log", LOG);
+
+    run_command(&mut harness, "Filter Log Lines");
+    harness.type_text("ERROR|WARN").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    assert!(screen.contains("disk full"));
+    assert!(screen.contains("retrying"));
+    assert!(!screen.contains("listening"));
+    assert!(!screen.contains("request done"));
+    // The first line stays, standing in for the two hidden below it
+    assert!(screen.contains("starting up"));
+    assert!(screen.contains("[2 hidden]"));
+    // Lines keep their numbers
+    let (_, y) = harness.find_text_on_screen("disk full").unwrap();
+    assert!(harness.get_screen_row(y as usize).contains("4 │"));
+    assert_eq!(harness.get_buffer_content().unwrap(), LOG);
+
+    // An empty pattern shows everything again
+    run_command(&mut harness, "Filter Log Lines");
+    for _ in 0.."ERROR|WARN".len() {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("listening");
+    harness.assert_screen_contains("request done");
+}
+
+#[test]
+fn test_follow_only_for_log_files() {
+    let (mut harness, _path) = open("notes.txt", "plain text\n");
+    run_command(&mut harness, "Toggle Follow Tail");
+    harness.assert_screen_contains("Following is only for log files");
+}
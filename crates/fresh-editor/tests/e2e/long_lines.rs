@@ -0,0 +1,96 @@
+//! E2E tests for files with very long lines, such as minified bundles
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::time::{Duration, Instant};
+
+/// Generous enough for unoptimized builds on slow CI machines; before long
+/// lines were capped, a render of this file took far longer
+const RENDER_BUDGET: Duration = Duration::from_secs(5);
+
+/// A minified script: one 5MB line with no newline
+fn minified_js() -> String {
+    let chunk = "var a=\"#fff\",b=function(c){return c*2+a.length};";
+    chunk.repeat(5 * 1024 * 1024 / chunk.len())
+}
+
+fn open_timed(fixture: &TestFixture, line_wrap: bool) -> EditorTestHarness {
+    let mut config = Config::default();
+    config.editor.line_wrap = line_wrap;
+    let mut harness = EditorTestHarness::with_config(100, 30, config).unwrap();
+    let start = Instant::now();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed < RENDER_BUDGET,
+        "opening and rendering a 5MB line took {elapsed:?}"
+    );
+    harness
+}
+
+fn assert_renders_quickly(harness: &mut EditorTestHarness) {
+    let start = Instant::now();
+    harness.render().unwrap();
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed < RENDER_BUDGET,
+        "rendering a 5MB line took {elapsed:?}"
+    );
+}
+
+#[test]
+fn test_huge_single_line_renders_quickly_wrapped() {
+    let fixture = TestFixture::new("bundle.min.js", &minified_js()).unwrap();
+    let mut harness = open_timed(&fixture, true);
+    harness.assert_screen_contains("var a=\"#fff\",b=function(c)");
+    assert_renders_quickly(&mut harness);
+}
+
+#[test]
+fn test_huge_single_line_renders_quickly_unwrapped() {
+    let fixture = TestFixture::new("bundle.min.js", &minified_js()).unwrap();
+    let mut harness = open_timed(&fixture, false);
+    harness.assert_screen_contains("var a=\"#fff\",b=function(c)");
+    assert_renders_quickly(&mut harness);
+}
+
+#[test]
+fn test_end_and_home_on_huge_single_line() {
+    let content = minified_js();
+    let fixture = TestFixture::new("bundle.min.js", &content).unwrap();
+    let mut harness = open_timed(&fixture, true);
+
+    // End reaches the real end of the line, well past any per-line cap
+    let start = Instant::now();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), content.len());
+    assert_renders_quickly(&mut harness);
+
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+    assert_renders_quickly(&mut harness);
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed < RENDER_BUDGET * 2,
+        "End and Home on a 5MB line took {elapsed:?}"
+    );
+}
+
+#[test]
+fn test_word_motion_on_huge_single_line() {
+    let fixture = TestFixture::new("bundle.min.js", &minified_js()).unwrap();
+    let mut harness = open_timed(&fixture, true);
+
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::CONTROL)
+        .unwrap();
+    let position = harness.cursor_position();
+    assert!(
+        position > 0 && position <= "var a".len(),
+        "moved to {position}"
+    );
+    assert_renders_quickly(&mut harness);
+}
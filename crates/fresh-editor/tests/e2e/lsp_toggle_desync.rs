@@ -181,6 +181,7 @@ fn test_lsp_toggle_off_edit_toggle_on_causes_desync() -> anyhow::Result<()> {
         action: "lsp_toggle_for_buffer".to_string(),
         args: std::collections::HashMap::new(),
         when: None,
+        context: None,
     });
 
     // Create harness
@@ -301,6 +302,7 @@ fn test_lsp_toggle_off_sends_did_close() -> anyhow::Result<()> {
         action: "lsp_toggle_for_buffer".to_string(),
         args: std::collections::HashMap::new(),
         when: None,
+        context: None,
     });
 
     let mut harness = EditorTestHarness::with_config_and_working_dir(
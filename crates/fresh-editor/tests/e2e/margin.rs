@@ -71,6 +71,46 @@ fn test_initial_buffer_respects_line_numbers_config() {
     harness.assert_screen_contains("Hello");
 }
 
+/// Test relative and hybrid line numbers follow the cursor line
+#[test]
+fn test_relative_and_hybrid_line_numbers() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "alpha\nbravo\ncharlie\ndelta\necho\n").unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.editor.line_number_mode = fresh::config::LineNumberMode::Relative;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Cursor on line 1: it shows 0, the others their distance from it
+    harness.assert_screen_contains("  0 │ alpha");
+    harness.assert_screen_contains("  3 │ delta");
+
+    // Moving the cursor renumbers the gutter around the new cursor line
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+    harness.assert_screen_contains("  3 │ alpha");
+    harness.assert_screen_contains("  0 │ delta");
+    harness.assert_screen_contains("  1 │ echo");
+
+    // Hybrid mode shows the absolute number on the cursor line
+    let mut config = fresh::config::Config::default();
+    config.editor.line_number_mode = fresh::config::LineNumberMode::Hybrid;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+    harness.assert_screen_contains("  4 │ delta");
+    harness.assert_screen_contains("  2 │ bravo");
+    harness.assert_screen_contains("  1 │ echo");
+}
+
 /// Test that line numbers adjust width for large files
 #[test]
 fn test_margin_large_file_line_numbers() {
@@ -0,0 +1,147 @@
+//! Tests for the markdown preview pane.
+//!
+//! Tests that:
+//! - Toggle Markdown Preview shows the buffer rendered in a split beside it,
+//!   while typing still goes to the source
+//! - The preview catches up once edits pause
+//! - The preview scrolls with its source
+//! - Ctrl+click on a link in the preview opens it
+//! - Running the command again closes the preview, and it refuses files that
+//!   aren't markdown
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use std::time::Duration;
+
+const README: &str = "# Title\n\n- item one\n- item two\n\n| A | B |\n|---|---|\n| 1 | 2 |\n\nSee [the docs](https://example.com/docs).\n";
+
+fn open(fixture: &TestFixture) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::new(120, 30).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(">command"))
+        .unwrap();
+    harness.type_text(command).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn ctrl_click(harness: &mut EditorTestHarness, col: u16, row: u16) {
+    for kind in [
+        MouseEventKind::Down(MouseButton::Left),
+        MouseEventKind::Up(MouseButton::Left),
+    ] {
+        harness
+            .send_mouse(MouseEvent {
+                kind,
+                column: col,
+                row,
+                modifiers: KeyModifiers::CONTROL,
+            })
+            .unwrap();
+    }
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_preview_renders_beside_source() {
+    let fixture = TestFixture::new("README.md", README).unwrap();
+    let mut harness = open(&fixture);
+
+    run_command(&mut harness, "Toggle Markdown Preview");
+    harness.assert_screen_contains("• item one");
+    harness.assert_screen_contains("A │ B");
+    harness.assert_screen_contains("See the docs.");
+    // The source is still there, unrendered
+    harness.assert_screen_contains("- item one");
+
+    harness.type_text("x").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), format!("x{README}"));
+}
+
+#[test]
+fn test_preview_updates_after_edits_pause() {
+    let fixture = TestFixture::new("README.md", README).unwrap();
+    let mut harness = open(&fixture);
+    run_command(&mut harness, "Toggle Markdown Preview");
+
+    // Type "Hello " at the start of the heading text
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("Hello ").unwrap();
+    harness.render().unwrap();
+    // Only the source shows it so far
+    assert_eq!(harness.screen_to_string().matches("Hello Title").count(), 1);
+
+    harness.advance_time(Duration::from_millis(300));
+    harness.render().unwrap();
+    assert_eq!(harness.screen_to_string().matches("Hello Title").count(), 2);
+}
+
+#[test]
+fn test_preview_scrolls_with_source() {
+    let text: String = (1..=60).map(|i| format!("Para {i}\n\n")).collect();
+    let fixture = TestFixture::new("long.md", &text).unwrap();
+    let mut harness = open(&fixture);
+    run_command(&mut harness, "Toggle Markdown Preview");
+    assert!(!harness.screen_to_string().contains("Para 60"));
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    // Both panes show the end of the document
+    assert_eq!(harness.screen_to_string().matches("Para 60").count(), 2);
+}
+
+#[test]
+fn test_ctrl_click_link_in_preview_opens_it() {
+    let fixture = TestFixture::new("README.md", README).unwrap();
+    let mut harness = open(&fixture);
+    run_command(&mut harness, "Toggle Markdown Preview");
+
+    let (x, y) = harness.find_text_on_screen("See the docs.").unwrap();
+    ctrl_click(&mut harness, x + 6, y);
+    assert_eq!(
+        harness.editor().opened_urls_for_test(),
+        ["https://example.com/docs".to_string()]
+    );
+}
+
+#[test]
+fn test_toggle_again_closes_preview() {
+    let fixture = TestFixture::new("README.md", README).unwrap();
+    let mut harness = open(&fixture);
+    run_command(&mut harness, "Toggle Markdown Preview");
+    harness.assert_screen_contains("• item one");
+
+    run_command(&mut harness, "Toggle Markdown Preview");
+    harness.assert_screen_contains("Markdown preview closed");
+    assert!(!harness.screen_to_string().contains("• item one"));
+    assert_eq!(harness.get_buffer_content().unwrap(), README);
+}
+
+#[test]
+fn test_preview_only_for_markdown() {
+    let fixture = TestFixture::new("notes.txt", "# Not markdown\n").unwrap();
+    let mut harness = open(&fixture);
+    run_command(&mut harness, "Toggle Markdown Preview");
+    harness.assert_screen_contains("Preview is only for markdown files");
+}
@@ -0,0 +1,73 @@
+//! Tests for Go to Matching Bracket and Select to Matching Bracket.
+//!
+//! Tests that:
+//! - Brackets inside strings and comments are skipped when matching code
+//! - The jump works in both directions
+//! - The selection variant takes in both brackets
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command_name).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn set_cursor(harness: &mut EditorTestHarness, position: usize) {
+    let cursors = harness.editor_mut().active_cursors_mut();
+    cursors.primary_mut().position = position;
+    cursors.primary_mut().anchor = None;
+}
+
+/// Brackets in a string or comment don't pair with brackets in code.
+#[test]
+fn test_goto_matching_bracket_skips_strings_and_comments() {
+    let content = "fn main() {\n    let s = \"}\";\n    // }\n}\n";
+    let fixture = TestFixture::new("brackets.rs", content).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    let open = content.find('{').unwrap();
+    let close = content.rfind('}').unwrap();
+    set_cursor(&mut harness, open);
+
+    harness
+        .send_key(KeyCode::Char(']'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.cursor_position(), close);
+
+    harness
+        .send_key(KeyCode::Char(']'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.cursor_position(), open);
+}
+
+/// Select to Matching Bracket selects the bracketed text with both brackets,
+/// from either end.
+#[test]
+fn test_select_to_matching_bracket() {
+    let content = "call(a, [b]);";
+    let fixture = TestFixture::new("select.rs", content).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    set_cursor(&mut harness, content.find('(').unwrap());
+    run_command(&mut harness, "Select to Matching Bracket");
+    assert_eq!(harness.get_selected_text(), "(a, [b])");
+
+    set_cursor(&mut harness, content.find(']').unwrap());
+    run_command(&mut harness, "Select to Matching Bracket");
+    assert_eq!(harness.get_selected_text(), "[b]");
+}
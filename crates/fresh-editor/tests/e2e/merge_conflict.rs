@@ -1,5 +1,5 @@
 use crate::common::fixtures::TestFixture;
-use crate::common::harness::{copy_plugin, EditorTestHarness};
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
 use crossterm::event::{KeyCode, KeyModifiers};
 use std::fs;
 use std::path::Path;
@@ -28,6 +28,7 @@ fn setup_merge_conflict_test(project_root: &Path) {
     // Create plugins directory and copy plugin + i18n
     let plugins_dir = project_root.join("plugins");
     fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
     copy_plugin(&plugins_dir, "merge_conflict");
 }
 
@@ -895,6 +896,7 @@ fn test_diff3_conflict_with_base_section() {
     // Copy plugin
     let plugins_dir = project_root.join("plugins");
     fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
     copy_plugin(&plugins_dir, "merge_conflict");
     eprintln!("Copied plugin files");
 
@@ -1228,3 +1230,134 @@ fn test_merge_mouse_click_on_buttons() {
         .unwrap();
     harness.render().unwrap();
 }
+
+/// Two conflicts for the inline (in-file) resolution tests
+const TWO_INLINE_CONFLICTS: &str = "start\n<<<<<<< HEAD\nours one\n=======\ntheirs one\n>>>>>>> branch\nmiddle\n<<<<<<< HEAD\nours two\n=======\ntheirs two\n>>>>>>> branch\nend\n";
+
+/// Open a file with two conflicts and wait for the inline hints
+fn inline_conflict_harness() -> (EditorTestHarness, tempfile::TempDir) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    setup_merge_conflict_test(&project_root);
+
+    let file = project_root.join("conflict.txt");
+    fs::write(&file, TWO_INLINE_CONFLICTS).unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, Default::default(), project_root)
+            .unwrap();
+    harness.open_file(&file).unwrap();
+    harness
+        .wait_for_screen_contains("Accept Ours | Accept Theirs | Accept Both")
+        .unwrap();
+    harness
+        .wait_for_screen_contains("2 conflict(s) remaining")
+        .unwrap();
+
+    (harness, temp_dir)
+}
+
+fn run_inline_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+/// Accepting a side replaces the whole conflict, and one undo restores it
+#[test]
+fn test_inline_conflict_accept_theirs_undo() {
+    let (mut harness, _temp_dir) = inline_conflict_harness();
+
+    // Cursor inside the first conflict
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    run_inline_command(&mut harness, "Merge: Accept Theirs");
+
+    harness
+        .wait_for_screen_contains("1 conflict(s) remaining")
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "start\ntheirs one\nmiddle\n<<<<<<< HEAD\nours two\n=======\ntheirs two\n>>>>>>> branch\nend\n"
+    );
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("2 conflict(s) remaining")
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), TWO_INLINE_CONFLICTS);
+}
+
+/// Next/previous conflict jump between the <<<<<<< markers and wrap around
+#[test]
+fn test_inline_conflict_navigation() {
+    let (mut harness, _temp_dir) = inline_conflict_harness();
+    let first = TWO_INLINE_CONFLICTS.find("<<<<<<<").unwrap();
+    let second = TWO_INLINE_CONFLICTS.rfind("<<<<<<<").unwrap();
+
+    run_inline_command(&mut harness, "Merge: Go to Next Conflict");
+    harness
+        .wait_until(|h| h.cursor_position() == first)
+        .unwrap();
+
+    run_inline_command(&mut harness, "Merge: Go to Next Conflict");
+    harness
+        .wait_until(|h| h.cursor_position() == second)
+        .unwrap();
+    harness.wait_for_screen_contains("Conflict 2 of 2").unwrap();
+
+    run_inline_command(&mut harness, "Merge: Go to Next Conflict");
+    harness
+        .wait_until(|h| h.cursor_position() == first)
+        .unwrap();
+
+    run_inline_command(&mut harness, "Merge: Go to Previous Conflict");
+    harness
+        .wait_until(|h| h.cursor_position() == second)
+        .unwrap();
+}
+
+/// Clicking a hint resolves that conflict; the last one clears the count
+#[test]
+fn test_inline_conflict_click_hint() {
+    let (mut harness, _temp_dir) = inline_conflict_harness();
+
+    let screen = harness.screen_to_string();
+    let (row, line) = screen
+        .lines()
+        .enumerate()
+        .find(|(_, line)| line.contains("Accept Both"))
+        .unwrap();
+    let col = line[..line.find("Accept Both").unwrap()].chars().count() + 2;
+    harness.mouse_click(col as u16, row as u16).unwrap();
+
+    harness
+        .wait_for_screen_contains("1 conflict(s) remaining")
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "start\nours one\ntheirs one\nmiddle\n<<<<<<< HEAD\nours two\n=======\ntheirs two\n>>>>>>> branch\nend\n"
+    );
+
+    // The remaining conflict is the next one from the cursor
+    run_inline_command(&mut harness, "Merge: Go to Next Conflict");
+    harness.wait_for_screen_contains("Conflict 1 of 1").unwrap();
+    run_inline_command(&mut harness, "Merge: Accept Ours");
+    harness
+        .wait_for_screen_contains("All conflicts resolved")
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "start\nours one\ntheirs one\nmiddle\nours two\nend\n"
+    );
+    harness.assert_screen_not_contains("Accept Ours");
+}
@@ -0,0 +1,90 @@
+//! E2E tests for the minimap strip beside the scrollbar.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+/// Minimap strip width (text cells + tick lane), left of the 1-column scrollbar
+const MINIMAP_WIDTH: u16 = 12;
+
+fn numbered_lines(count: usize) -> String {
+    (0..count)
+        .map(|i| format!("line {i}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Whether any braille cell is drawn where the minimap would be
+fn minimap_visible(harness: &EditorTestHarness, width: u16) -> bool {
+    let (first_row, last_row) = harness.content_area_rows();
+    let first_col = width - 1 - MINIMAP_WIDTH;
+    (first_row..=last_row).any(|row| {
+        (first_col..width - 1).any(|col| {
+            harness
+                .get_cell(col, row as u16)
+                .and_then(|cell| cell.chars().next())
+                .is_some_and(|c| ('\u{2801}'..='\u{28FF}').contains(&c))
+        })
+    })
+}
+
+/// The minimap shows on wide terminals and toggles from the command palette.
+#[test]
+fn test_minimap_renders_and_toggles() {
+    let mut config = Config::default();
+    config.editor.minimap = true;
+
+    let mut harness = EditorTestHarness::with_config(200, 40, config).unwrap();
+    let _fixture = harness.load_buffer_from_text(&numbered_lines(500)).unwrap();
+    harness.render().unwrap();
+    assert!(minimap_visible(&harness, 200), "Minimap should be drawn");
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Toggle Minimap").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert!(!minimap_visible(&harness, 200), "Minimap should be hidden");
+    harness.assert_screen_contains("Minimap hidden");
+}
+
+/// Splits narrower than `minimap_min_width` don't get a minimap.
+#[test]
+fn test_minimap_hidden_on_narrow_terminal() {
+    let mut config = Config::default();
+    config.editor.minimap = true;
+
+    let mut harness = EditorTestHarness::with_config(100, 40, config).unwrap();
+    let _fixture = harness.load_buffer_from_text(&numbered_lines(500)).unwrap();
+    harness.render().unwrap();
+
+    assert!(!minimap_visible(&harness, 100));
+}
+
+/// Clicking in the minimap centers the viewport on the lines under the mouse.
+#[test]
+fn test_minimap_click_scrolls_viewport() {
+    let mut config = Config::default();
+    config.editor.minimap = true;
+
+    let mut harness = EditorTestHarness::with_config(200, 40, config).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text(&numbered_lines(1000))
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.top_byte(), 0);
+
+    // Each minimap row covers four lines, so the last row starts at line 4 * (rows - 1)
+    let (first_row, last_row) = harness.content_area_rows();
+    let target_line = 4 * (last_row - first_row);
+    harness
+        .mouse_click(200 - 1 - MINIMAP_WIDTH, last_row as u16)
+        .unwrap();
+
+    assert!(harness.top_byte() > 0, "Viewport should have scrolled");
+    harness.assert_screen_contains(&format!("line {target_line}"));
+}
@@ -1,22 +1,33 @@
+pub mod abbreviations;
+pub mod align_cursors;
 pub mod altgr_shift;
 pub mod ansi_cursor;
 pub mod auto_indent;
 pub mod auto_revert;
 pub mod basic;
+pub mod batch_mode;
 pub mod binary_file;
 pub mod block_selection;
+pub mod box_drawing;
 pub mod blog_showcases;
 pub mod buffer_lifecycle;
+pub mod buffer_links;
+pub mod buffer_picker;
 pub mod buffer_settings_commands;
 pub mod case_conversion;
+pub mod client_views;
+pub mod clipboard_history;
+pub mod color_swatches;
 pub mod command_palette;
 pub mod crash_repro;
 pub mod crlf_rendering;
 pub mod ctrl_end_wrapped;
 pub mod document_model;
 pub mod duplicate_line;
+pub mod editor_log;
 pub mod emacs_actions;
 pub mod encoding;
+pub mod expand_selection;
 pub mod explorer_menu;
 pub mod file_browser;
 pub mod file_explorer;
@@ -26,16 +37,23 @@ pub mod glob_language_detection;
 #[cfg(feature = "gui")]
 pub mod gui;
 pub mod horizontal_scrollbar;
+pub mod hover_tooltip;
 pub mod indent_dedent;
+pub mod indent_guides;
+pub mod input_batching;
+pub mod input_script;
 pub mod keybinding_editor;
 pub mod language_features_e2e;
 pub mod large_file_inplace_write_bug;
 pub mod large_file_mode;
 pub mod lifecycle;
+pub mod line_bookmarks;
 pub mod line_wrap_scroll_bugs;
 pub mod line_wrapping;
 pub mod live_grep;
 pub mod locale;
+pub mod log_files;
+pub mod long_lines;
 pub mod lsp;
 pub mod lsp_completion_french_locale;
 pub mod lsp_completion_popup_behavior;
@@ -46,18 +64,24 @@ pub mod lsp_toggle_desync;
 pub mod macros;
 pub mod margin;
 pub mod markdown_compose;
+pub mod markdown_preview;
+pub mod matching_bracket;
 pub mod menu_bar;
 pub mod menu_cursor_bleed;
 pub mod menu_tab_color_bleed;
 pub mod merge_conflict;
+pub mod minimap;
 pub mod mouse;
 pub mod movement;
 pub mod multi_file_opening;
 pub mod multibyte_characters;
 pub mod multicursor;
+pub mod number_increment;
 pub mod on_save_actions;
 pub mod open_folder;
 pub mod paste;
+pub mod perf_hud;
+pub mod plugin_edits;
 #[cfg(feature = "plugins")]
 pub mod plugins;
 pub mod popup_selection;
@@ -67,9 +91,14 @@ pub mod position_history_debug;
 pub mod position_history_truncate_debug;
 pub mod prompt;
 pub mod prompt_editing;
+pub mod quick_open_modes;
+pub mod rainbow_csv;
+pub mod read_only;
 pub mod recovery;
+pub mod reflow;
 pub mod remote_fs_test;
 pub mod rendering;
+pub mod repeat_last;
 pub mod save_as_language_detection;
 pub mod scroll_clearing;
 pub mod scrolling;
@@ -84,17 +113,22 @@ pub mod side_by_side_diff_scroll;
 pub mod slow_filesystem;
 pub mod smart_editing;
 pub mod smart_home;
+pub mod snippets;
 pub mod sort_lines;
 pub mod split_focus_tab_click;
 pub mod split_tabs;
 pub mod split_view;
 pub mod split_view_expectations;
 pub mod split_view_markdown_compose;
+pub mod status_bar_segments;
 pub mod stdin_input;
 pub mod sudo_save_prompt;
+pub mod surround;
 #[cfg(unix)]
 pub mod symlinks;
+pub mod synchronized_scrolling;
 pub mod syntax_language_case;
+pub mod tab_actions;
 pub mod tab_config;
 pub mod tab_drag;
 pub mod tab_indent_selection;
@@ -110,12 +144,16 @@ pub mod triple_click;
 pub mod undo_bulk_edit_after_save;
 pub mod undo_redo;
 pub mod unicode_cursor;
+pub mod unicode_input;
 pub mod unicode_prompt_bugs;
 pub mod update_notification;
 pub mod vertical_rulers;
 #[cfg(feature = "plugins")]
 pub mod vi_mode;
+pub mod vim_keymap;
 pub mod virtual_lines;
 pub mod visual_regression;
 pub mod warning_indicators;
+pub mod whitespace_rendering;
+pub mod word_completion;
 pub mod workspace;
@@ -2062,3 +2062,139 @@ fn test_hover_popup_position_with_file_explorer() {
         screen
     );
 }
+
+fn send_left_mouse(
+    harness: &mut EditorTestHarness,
+    kind: crossterm::event::MouseEventKind,
+    col: u16,
+    row: u16,
+    modifiers: KeyModifiers,
+) {
+    harness
+        .send_mouse(crossterm::event::MouseEvent {
+            kind,
+            column: col,
+            row,
+            modifiers,
+        })
+        .unwrap();
+}
+
+/// Screen (col, row) of the first occurrence of `needle` in the content area
+fn text_screen_position(harness: &EditorTestHarness, needle: &str) -> (u16, u16) {
+    let (start_row, end_row) = harness.content_area_rows();
+    for row in start_row..=end_row {
+        let text = harness.get_row_text(row as u16);
+        if let Some(byte_offset) = text.find(needle) {
+            return (text[..byte_offset].chars().count() as u16, row as u16);
+        }
+    }
+    panic!(
+        "'{}' not on screen:\n{}",
+        needle,
+        harness.screen_to_string()
+    );
+}
+
+/// Press the left button `clicks` times at (col, row), leaving it held down
+/// after the last press
+fn multi_click_and_hold(harness: &mut EditorTestHarness, clicks: usize, col: u16, row: u16) {
+    use crossterm::event::{MouseButton, MouseEventKind};
+    for _ in 1..clicks {
+        harness.mouse_click(col, row).unwrap();
+    }
+    send_left_mouse(
+        harness,
+        MouseEventKind::Down(MouseButton::Left),
+        col,
+        row,
+        KeyModifiers::NONE,
+    );
+}
+
+fn drag_to(harness: &mut EditorTestHarness, col: u16, row: u16) {
+    use crossterm::event::{MouseButton, MouseEventKind};
+    send_left_mouse(
+        harness,
+        MouseEventKind::Drag(MouseButton::Left),
+        col,
+        row,
+        KeyModifiers::NONE,
+    );
+    harness.render().unwrap();
+}
+
+fn release(harness: &mut EditorTestHarness, col: u16, row: u16) {
+    use crossterm::event::{MouseButton, MouseEventKind};
+    send_left_mouse(
+        harness,
+        MouseEventKind::Up(MouseButton::Left),
+        col,
+        row,
+        KeyModifiers::NONE,
+    );
+    harness.render().unwrap();
+}
+
+/// Dragging after a double-click extends the selection word by word in both
+/// directions, and Shift+click keeps extending by words
+#[test]
+fn test_double_click_drag_extends_by_word() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("alpha beta gamma delta\nsecond line\n")
+        .unwrap();
+    harness.render().unwrap();
+
+    let (beta_col, row) = text_screen_position(&harness, "beta");
+    let (gamma_col, _) = text_screen_position(&harness, "gamma");
+    let (alpha_col, _) = text_screen_position(&harness, "alpha");
+    let (delta_col, _) = text_screen_position(&harness, "delta");
+
+    multi_click_and_hold(&mut harness, 2, beta_col + 1, row);
+    harness.render().unwrap();
+    assert_eq!(harness.get_selected_text(), "beta");
+
+    // Forwards into the middle of "gamma" selects all of it
+    drag_to(&mut harness, gamma_col + 2, row);
+    assert_eq!(harness.get_selected_text(), "beta gamma");
+
+    // Backwards past the anchor keeps "beta" selected
+    drag_to(&mut harness, alpha_col + 1, row);
+    assert_eq!(harness.get_selected_text(), "alpha beta");
+    release(&mut harness, alpha_col + 1, row);
+
+    harness.mouse_shift_click(delta_col + 1, row).unwrap();
+    assert_eq!(harness.get_selected_text(), "beta gamma delta");
+
+    // After a plain click, Shift+click extends by characters again
+    harness.mouse_click(alpha_col + 1, row).unwrap();
+    harness.mouse_shift_click(gamma_col + 2, row).unwrap();
+    assert_eq!(harness.get_selected_text(), "lpha beta ga");
+}
+
+/// Dragging after a triple-click extends the selection line by line, also
+/// above the clicked line
+#[test]
+fn test_triple_click_drag_extends_by_line() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("first line\nsecond line\nthird line\nfourth line\n")
+        .unwrap();
+    harness.render().unwrap();
+
+    let (col, first_row) = text_screen_position(&harness, "first");
+    let (_, second_row) = text_screen_position(&harness, "second");
+    let (_, third_row) = text_screen_position(&harness, "third");
+
+    multi_click_and_hold(&mut harness, 3, col + 3, second_row);
+    harness.render().unwrap();
+    assert_eq!(harness.get_selected_text(), "second line\n");
+
+    drag_to(&mut harness, col + 1, third_row);
+    assert_eq!(harness.get_selected_text(), "second line\nthird line\n");
+
+    drag_to(&mut harness, col + 5, first_row);
+    assert_eq!(harness.get_selected_text(), "first line\nsecond line\n");
+    release(&mut harness, col + 5, first_row);
+}
@@ -130,6 +130,36 @@ fn test_move_line_up_down_shortcuts() {
     harness.assert_buffer_content("A\nB\nC");
 }
 
+/// A selection over several lines moves as a unit, stays selected, and is
+/// restored by a single undo
+#[test]
+fn test_move_selected_lines_as_unit() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.enable_shadow_validation();
+
+    harness.type_text("A\nB\nC\nD").unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    }
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    assert_eq!(harness.get_selection_range(), Some(0..4));
+
+    harness.send_key(KeyCode::Down, KeyModifiers::ALT).unwrap();
+    harness.assert_buffer_content("C\nA\nB\nD");
+    assert_eq!(harness.get_selection_range(), Some(2..6));
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("A\nB\nC\nD");
+}
+
 /// Test basic editing operations: insert, modify, delete, newline
 /// This test verifies both buffer state and rendered screen output
 #[test]
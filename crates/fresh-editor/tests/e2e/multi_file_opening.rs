@@ -339,3 +339,24 @@ fn test_open_files_from_different_directories() {
     harness.render().unwrap();
     harness.assert_buffer_content("fn main() {}");
 }
+
+/// A file queued with a line and column (as from `fresh file:L:C` or a
+/// session's open-file request) opens scrolled to that position
+#[test]
+fn test_queued_file_open_at_line_col_is_visible() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("long.txt");
+    let content: String = (1..=200).map(|i| format!("line {i}\n")).collect();
+    std::fs::write(&file, &content).unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .editor_mut()
+        .queue_file_open(file, Some(150), Some(3), None, None, None, None);
+    harness.editor_mut().process_pending_file_opens();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("line 150");
+    let line_start: usize = (1..150).map(|i| format!("line {i}\n").len()).sum();
+    assert_eq!(harness.cursor_position(), line_start + 2);
+}
@@ -0,0 +1,148 @@
+//! Tests for Increment Number, Decrement Number and Increment Numbers as
+//! Sequence.
+//!
+//! Tests that:
+//! - The number under or after the cursor changes, and the cursor stays on
+//!   it so repeating keeps changing the same number
+//! - Negative, hex and zero-padded numbers keep their form
+//! - The sequence variant adds 1, 2, 3, … at successive cursors, placing
+//!   each cursor on its number even as lengths change
+//! - Each change is one undo step
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(">command"))
+        .unwrap();
+    harness.type_text(command).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// A buffer holding `text` with the cursor `right` characters into it
+fn harness_at(text: &str, right: usize) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(text).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key_repeat(KeyCode::Right, KeyModifiers::NONE, right)
+        .unwrap();
+    harness
+}
+
+/// Positions of the cursors, in buffer order
+fn cursor_positions(harness: &EditorTestHarness) -> Vec<usize> {
+    let mut positions: Vec<usize> = harness
+        .editor()
+        .active_cursors()
+        .iter()
+        .map(|(_, cursor)| cursor.position)
+        .collect();
+    positions.sort();
+    positions
+}
+
+#[test]
+fn test_increment_and_decrement_number() {
+    // The first number after the cursor is the one changed
+    let mut harness = harness_at("x = 9; y = 1;", 0);
+
+    run_command(&mut harness, "Increment Number");
+    assert_eq!(harness.get_buffer_content().unwrap(), "x = 10; y = 1;");
+    assert_eq!(cursor_positions(&harness), vec![5]);
+
+    // Still on "10", not moved on to the next number
+    run_command(&mut harness, "Increment Number");
+    assert_eq!(harness.get_buffer_content().unwrap(), "x = 11; y = 1;");
+
+    run_command(&mut harness, "Decrement Number");
+    run_command(&mut harness, "Decrement Number");
+    assert_eq!(harness.get_buffer_content().unwrap(), "x = 9; y = 1;");
+    assert_eq!(cursor_positions(&harness), vec![4]);
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "x = 10; y = 1;");
+}
+
+#[test]
+fn test_negative_number_crosses_zero() {
+    let mut harness = harness_at("offset = -1", 0);
+
+    run_command(&mut harness, "Increment Number");
+    assert_eq!(harness.get_buffer_content().unwrap(), "offset = 0");
+    run_command(&mut harness, "Decrement Number");
+    run_command(&mut harness, "Decrement Number");
+    assert_eq!(harness.get_buffer_content().unwrap(), "offset = -2");
+
+    // A dash after a word is not a sign
+    let mut harness = harness_at("page-3", 0);
+    run_command(&mut harness, "Increment Number");
+    assert_eq!(harness.get_buffer_content().unwrap(), "page-4");
+}
+
+#[test]
+fn test_hex_and_zero_padding_keep_their_form() {
+    let mut harness = harness_at("id 007, mask 0x0F", 0);
+    run_command(&mut harness, "Increment Number");
+    assert_eq!(harness.get_buffer_content().unwrap(), "id 008, mask 0x0F");
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    run_command(&mut harness, "Increment Number");
+    assert_eq!(harness.get_buffer_content().unwrap(), "id 008, mask 0x10");
+
+    let mut harness = harness_at("frame_099.png", 0);
+    run_command(&mut harness, "Increment Number");
+    assert_eq!(harness.get_buffer_content().unwrap(), "frame_100.png");
+}
+
+#[test]
+fn test_sequence_at_each_cursor() {
+    let mut harness = harness_at("item 9\nitem 9\nitem 9\nnone\n", 0);
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::CONTROL | KeyModifiers::ALT)
+            .unwrap();
+    }
+    assert_eq!(harness.editor().active_cursors().count(), 4);
+
+    run_command(&mut harness, "Increment Numbers as Sequence");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "item 10\nitem 11\nitem 12\nnone\n"
+    );
+    // Each cursor on the last digit of its number, and the one on a line
+    // without a number moved along with the text
+    assert_eq!(cursor_positions(&harness), vec![6, 14, 22, 24]);
+
+    // Plain increment changes every cursor's number by the same amount
+    run_command(&mut harness, "Increment Number");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "item 11\nitem 12\nitem 13\nnone\n"
+    );
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "item 9\nitem 9\nitem 9\nnone\n"
+    );
+}
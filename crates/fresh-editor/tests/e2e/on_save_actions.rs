@@ -10,7 +10,11 @@
 
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
-use fresh::config::{Config, FormatterConfig, LanguageConfig, OnSaveAction};
+use fresh::config::{
+    Config, FormatterConfig, LanguageConfig, LinterConfig, OnSaveAction, TrimTrailingWhitespace,
+};
+use fresh::view::margin::LineIndicator;
+use ratatui::style::Color;
 use tempfile::TempDir;
 
 /// Test format_on_save with formatter (replaces buffer content)
@@ -41,6 +45,8 @@ fn test_format_on_save() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             highlighter: Default::default(),
             textmate_grammar: None,
@@ -50,6 +56,14 @@ fn test_format_on_save() {
             formatter: Some(formatter),
             format_on_save: true,
             on_save: vec![],
+            linter: None,
+            run_command: None,
+            line_wrap: None,
+            auto_close_pairs: None,
+            indent_rules: None,
+            abbreviations: None,
+            trim_trailing_whitespace_on_save: None,
+            ensure_final_newline_on_save: None,
         },
     );
 
@@ -104,6 +118,8 @@ fn test_on_save_linter_style() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             highlighter: Default::default(),
             textmate_grammar: None,
@@ -113,6 +129,14 @@ fn test_on_save_linter_style() {
             formatter: None,
             format_on_save: false,
             on_save: vec![action],
+            linter: None,
+            run_command: None,
+            line_wrap: None,
+            auto_close_pairs: None,
+            indent_rules: None,
+            abbreviations: None,
+            trim_trailing_whitespace_on_save: None,
+            ensure_final_newline_on_save: None,
         },
     );
 
@@ -167,6 +191,8 @@ fn test_on_save_action_failure() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             highlighter: Default::default(),
             textmate_grammar: None,
@@ -176,6 +202,14 @@ fn test_on_save_action_failure() {
             formatter: None,
             format_on_save: false,
             on_save: vec![action],
+            linter: None,
+            run_command: None,
+            line_wrap: None,
+            auto_close_pairs: None,
+            indent_rules: None,
+            abbreviations: None,
+            trim_trailing_whitespace_on_save: None,
+            ensure_final_newline_on_save: None,
         },
     );
 
@@ -239,6 +273,8 @@ fn test_on_save_file_placeholder() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             highlighter: Default::default(),
             textmate_grammar: None,
@@ -248,6 +284,14 @@ fn test_on_save_file_placeholder() {
             formatter: None,
             format_on_save: false,
             on_save: vec![action],
+            linter: None,
+            run_command: None,
+            line_wrap: None,
+            auto_close_pairs: None,
+            indent_rules: None,
+            abbreviations: None,
+            trim_trailing_whitespace_on_save: None,
+            ensure_final_newline_on_save: None,
         },
     );
 
@@ -305,6 +349,8 @@ fn test_formatter_stdin_mode() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             highlighter: Default::default(),
             textmate_grammar: None,
@@ -314,6 +360,14 @@ fn test_formatter_stdin_mode() {
             formatter: Some(formatter),
             format_on_save: true,
             on_save: vec![],
+            linter: None,
+            run_command: None,
+            line_wrap: None,
+            auto_close_pairs: None,
+            indent_rules: None,
+            abbreviations: None,
+            trim_trailing_whitespace_on_save: None,
+            ensure_final_newline_on_save: None,
         },
     );
 
@@ -378,6 +432,8 @@ fn test_on_save_stops_on_failure() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             highlighter: Default::default(),
             textmate_grammar: None,
@@ -387,6 +443,14 @@ fn test_on_save_stops_on_failure() {
             formatter: None,
             format_on_save: false,
             on_save: vec![action1, action2],
+            linter: None,
+            run_command: None,
+            line_wrap: None,
+            auto_close_pairs: None,
+            indent_rules: None,
+            abbreviations: None,
+            trim_trailing_whitespace_on_save: None,
+            ensure_final_newline_on_save: None,
         },
     );
 
@@ -472,6 +536,8 @@ fn test_formatter_not_found_shows_message() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             highlighter: Default::default(),
             textmate_grammar: None,
@@ -481,6 +547,14 @@ fn test_formatter_not_found_shows_message() {
             formatter: Some(formatter),
             format_on_save: true,
             on_save: vec![],
+            linter: None,
+            run_command: None,
+            line_wrap: None,
+            auto_close_pairs: None,
+            indent_rules: None,
+            abbreviations: None,
+            trim_trailing_whitespace_on_save: None,
+            ensure_final_newline_on_save: None,
         },
     );
 
@@ -519,7 +593,7 @@ fn test_trim_trailing_whitespace_on_save() {
     std::fs::write(&file_path, "line 1   \nline 2\t\t\nline 3  \n").unwrap();
 
     let mut config = Config::default();
-    config.editor.trim_trailing_whitespace_on_save = true;
+    config.editor.trim_trailing_whitespace_on_save = TrimTrailingWhitespace::All;
 
     let mut harness =
         EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
@@ -587,7 +661,7 @@ fn test_whitespace_cleanup_combined() {
     std::fs::write(&file_path, "line 1   \nline 2\t\nline 3").unwrap();
 
     let mut config = Config::default();
-    config.editor.trim_trailing_whitespace_on_save = true;
+    config.editor.trim_trailing_whitespace_on_save = TrimTrailingWhitespace::All;
     config.editor.ensure_final_newline_on_save = true;
 
     let mut harness =
@@ -622,7 +696,7 @@ fn test_whitespace_cleanup_no_change_needed() {
     std::fs::write(&file_path, "line 1\nline 2\n").unwrap();
 
     let mut config = Config::default();
-    config.editor.trim_trailing_whitespace_on_save = true;
+    config.editor.trim_trailing_whitespace_on_save = TrimTrailingWhitespace::All;
     config.editor.ensure_final_newline_on_save = true;
 
     let mut harness =
@@ -647,3 +721,312 @@ fn test_whitespace_cleanup_no_change_needed() {
     // Content should remain the same
     harness.assert_buffer_content("line 1\nline 2\n");
 }
+
+/// Test modified_lines_only trims only lines marked in the git gutter
+#[test]
+fn test_trim_trailing_whitespace_modified_lines_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("test.rs");
+    std::fs::write(&file_path, "line 1  \nline 2  \nline 3  \n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.trim_trailing_whitespace_on_save = TrimTrailingWhitespace::ModifiedLinesOnly;
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Mark the second line as changed, the way the git gutter plugin does
+    harness
+        .editor_mut()
+        .active_state_mut()
+        .margins
+        .set_line_indicator(
+            9,
+            "git-gutter".to_string(),
+            LineIndicator::new("~", Color::Yellow, 10),
+        );
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("line 1  \nline 2\nline 3  \n");
+}
+
+/// Test trimming keeps whitespace to the left of the cursor so its column is preserved
+#[test]
+fn test_trim_trailing_whitespace_keeps_cursor_column() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("test.rs");
+    std::fs::write(&file_path, "foo   \nbar  \n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.trim_trailing_whitespace_on_save = TrimTrailingWhitespace::All;
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Put the cursor inside the trailing whitespace of the first line
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 5);
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("foo  \nbar\n");
+    assert_eq!(harness.cursor_position(), 5);
+}
+
+/// Test a language can override the global whitespace settings
+#[test]
+fn test_whitespace_cleanup_language_override() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("test.rs");
+    std::fs::write(&file_path, "line 1  \nline 2").unwrap();
+
+    let mut config = Config::default();
+    config.editor.trim_trailing_whitespace_on_save = TrimTrailingWhitespace::All;
+    let rust = config.languages.get_mut("rust").unwrap();
+    rust.trim_trailing_whitespace_on_save = Some(TrimTrailingWhitespace::Off);
+    rust.ensure_final_newline_on_save = Some(true);
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("line 1  \nline 2\n");
+}
+
+/// Test one undo reverts both whitespace cleanup and format-on-save
+#[test]
+#[cfg_attr(not(unix), ignore = "On-save actions require Unix-like environment")]
+fn test_whitespace_cleanup_and_format_undo_together() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("unsorted.txt");
+    std::fs::write(&file_path, "cherry  \napple").unwrap();
+
+    let mut config = Config::default();
+    config.editor.trim_trailing_whitespace_on_save = TrimTrailingWhitespace::All;
+    config.editor.ensure_final_newline_on_save = true;
+    config.languages.insert(
+        "plaintext".to_string(),
+        LanguageConfig {
+            extensions: vec!["txt".to_string()],
+            grammar: "plaintext".to_string(),
+            formatter: Some(FormatterConfig {
+                command: "sort".to_string(),
+                args: vec![],
+                stdin: true,
+                timeout_ms: 5000,
+            }),
+            format_on_save: true,
+            ..Default::default()
+        },
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("apple\ncherry\n");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("cherry  \napple");
+}
+
+/// Config with a formatter and linter for plaintext files
+fn plaintext_tools_config(
+    formatter: Option<FormatterConfig>,
+    linter: Option<LinterConfig>,
+) -> Config {
+    let mut config = Config::default();
+    config.languages.insert(
+        "plaintext".to_string(),
+        LanguageConfig {
+            extensions: vec!["txt".to_string()],
+            grammar: "plaintext".to_string(),
+            format_on_save: formatter.is_some(),
+            formatter,
+            linter,
+            ..Default::default()
+        },
+    );
+    config
+}
+
+/// Test formatting only edits the lines that changed, so a cursor below them
+/// stays on its line
+#[test]
+#[cfg_attr(not(unix), ignore = "On-save actions require Unix-like environment")]
+fn test_format_keeps_cursor_on_unchanged_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("test.txt");
+    std::fs::write(&file_path, "one\ntwo    words\nthree\n").unwrap();
+
+    let config = plaintext_tools_config(
+        Some(FormatterConfig {
+            command: "tr".to_string(),
+            args: vec!["-s".to_string(), "' '".to_string()],
+            stdin: true,
+            timeout_ms: 5000,
+        }),
+        None,
+    );
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Cursor on "three", after "th"
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.cursor_position(), 19);
+
+    harness.editor_mut().format_buffer().unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("one\ntwo words\nthree\n");
+    assert_eq!(harness.cursor_position(), 16);
+}
+
+/// Test a failing formatter shows its error output and leaves the buffer alone
+#[test]
+#[cfg_attr(not(unix), ignore = "On-save actions require Unix-like environment")]
+fn test_formatter_failure_shows_error_popup() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("test.txt");
+    std::fs::write(&file_path, "content\n").unwrap();
+
+    let config = plaintext_tools_config(
+        Some(FormatterConfig {
+            command: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                "'echo \"syntax error on line 1\" >&2; exit 2'".to_string(),
+            ],
+            stdin: true,
+            timeout_ms: 5000,
+        }),
+        None,
+    );
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    assert!(harness.editor_mut().format_buffer().is_err());
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("content\n");
+    harness.assert_screen_contains("Formatter sh failed");
+    harness.assert_screen_contains("syntax error on line 1");
+}
+
+/// Test problems printed by the linter show up as diagnostics
+#[test]
+#[cfg_attr(not(unix), ignore = "On-save actions require Unix-like environment")]
+fn test_linter_output_becomes_diagnostics() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("test.txt");
+    std::fs::write(&file_path, "first\nsecond line\n").unwrap();
+
+    let config = plaintext_tools_config(
+        None,
+        Some(LinterConfig {
+            command: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                "'echo $FILE:2:8: [warning] trailing word; echo other.txt:1:1: [error] elsewhere'"
+                    .to_string(),
+            ],
+            stdin: false,
+            pattern: r"^(?P<file>[^:]+):(?P<line>\d+):(?P<col>\d+): \[(?P<severity>\w+)\] (?P<message>.*)$"
+                .to_string(),
+            timeout_ms: 5000,
+        }),
+    );
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    let diagnostics: Vec<_> = harness
+        .editor()
+        .get_stored_diagnostics()
+        .values()
+        .flatten()
+        .cloned()
+        .collect();
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!(diagnostics[0].message, "trailing word");
+    assert_eq!(diagnostics[0].source.as_deref(), Some("sh"));
+    assert_eq!(diagnostics[0].range.start.line, 1);
+    assert_eq!(diagnostics[0].range.start.character, 7);
+    assert_eq!(
+        diagnostics[0].severity,
+        Some(lsp_types::DiagnosticSeverity::WARNING)
+    );
+}
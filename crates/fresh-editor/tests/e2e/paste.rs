@@ -4,6 +4,7 @@
 //! - Paste with selection (should replace selection)
 //! - Multi-cursor paste
 //! - Paste undo atomicity
+//! - Spreading lines across cursors and huge pastes
 //!
 //! Issue #372: External paste should behave like internal paste
 
@@ -839,3 +840,46 @@ fn test_paste_crlf_into_prompt() {
     // Prompt should contain the text (newlines may be shown differently in prompt)
     harness.assert_screen_contains("line1");
 }
+
+/// Test that a paste with one line per cursor puts a line at each cursor
+#[test]
+fn test_paste_distributes_lines_across_cursors() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("aaa\nbbb\nccc").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.editor_mut().add_cursor_below();
+    harness.editor_mut().add_cursor_below();
+    assert_eq!(harness.editor().active_cursors().count(), 3);
+
+    // A trailing newline doesn't count as a fourth line
+    harness.editor_mut().paste_text("1\n2\n3\n".to_string());
+    harness.render().unwrap();
+    harness.assert_buffer_content("1aaa\n2bbb\n3ccc");
+
+    // With a different number of lines, each cursor gets the whole text
+    harness.editor_mut().paste_text("x\ny".to_string());
+    harness.render().unwrap();
+    harness.assert_buffer_content("1x\nyaaa\n2x\nybbb\n3x\nyccc");
+}
+
+/// Test that a huge paste is one undo step and reports its size
+#[test]
+fn test_huge_paste_is_one_edit() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("start").unwrap();
+
+    let text = "let value = 42;\n".repeat(100_000);
+    harness.editor_mut().paste_text(text.clone());
+    harness.render().unwrap();
+    harness.assert_buffer_content(&format!("start{text}"));
+    assert_eq!(harness.cursor_position(), "start".len() + text.len());
+    harness.assert_screen_contains("Pasted 100000 lines");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("start");
+}
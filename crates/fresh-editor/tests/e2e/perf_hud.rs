@@ -0,0 +1,41 @@
+//! Tests for the performance HUD and performance report.
+//!
+//! Tests that:
+//! - Toggling the HUD draws the overlay and records frames
+//! - The report is valid JSON containing the recorded frames
+
+use crate::common::harness::EditorTestHarness;
+
+#[test]
+fn test_performance_hud_and_report() {
+    let mut harness = EditorTestHarness::new(100, 30).unwrap();
+    harness.type_text("fn main() {}\n").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("markers");
+
+    harness.editor_mut().toggle_performance_hud();
+    assert!(harness.editor().is_performance_hud_visible());
+    for _ in 0..3 {
+        harness.render().unwrap();
+    }
+    harness.assert_screen_contains("Performance");
+    harness.assert_screen_contains("frame");
+    harness.assert_screen_contains("markers");
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let report_path = temp_dir.path().join("perf-report.json");
+    harness
+        .editor()
+        .write_performance_report(&report_path)
+        .unwrap();
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    assert_eq!(report["recording"], true);
+    assert_eq!(report["active_buffer_bytes"], 13);
+    assert!(report["stats"]["frames"].as_u64().unwrap() >= 3);
+    assert!(report["stats"]["frame"]["max_us"].is_u64());
+
+    harness.editor_mut().toggle_performance_hud();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("markers");
+}
@@ -0,0 +1,76 @@
+//! Tests for buffer edits requested by plugins.
+//!
+//! Tests that:
+//! - replaceRange is undone in a single step
+//! - Edits outside the buffer are rejected and reported in the plugin log
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::services::plugins::api::PluginCommand;
+
+/// A replaced range comes back with one undo.
+#[test]
+fn test_replace_range_is_single_undo_step() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello world").unwrap();
+    let buffer_id = harness.editor().active_buffer();
+
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::ReplaceRange {
+            buffer_id,
+            range: 6..11,
+            text: "there".to_string(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "hello there");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "hello world");
+}
+
+/// An edit past the end of the buffer leaves it untouched and shows up in
+/// the plugin log.
+#[test]
+fn test_out_of_range_edit_is_logged() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("short").unwrap();
+    let buffer_id = harness.editor().active_buffer();
+
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::DeleteRange {
+            buffer_id,
+            range: 2..50,
+        })
+        .unwrap();
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::InsertText {
+            buffer_id,
+            position: 99,
+            text: "x".to_string(),
+        })
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "short");
+    assert_eq!(harness.editor().plugin_log().len(), 2);
+
+    harness.editor_mut().open_plugin_log();
+    harness.render().unwrap();
+    harness.assert_screen_contains("*Plugin Log*");
+    harness.assert_screen_contains("Edit rejected: range 2..50");
+
+    // New entries are appended while the log is open
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::LogPluginIssue {
+            message: "Slow plugin hook 'after_insert': took 900 ms".to_string(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("took 900 ms");
+}
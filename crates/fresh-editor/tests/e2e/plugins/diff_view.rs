@@ -0,0 +1,142 @@
+//! E2E tests for the diff view plugin (Diff Against Saved / Diff Two Buffers)
+
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, DiffViewLayout};
+use std::fs;
+
+/// Create a harness with the diff_view plugin loaded and `file.txt` open
+fn diff_view_harness(config: Config) -> (EditorTestHarness, tempfile::TempDir) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    copy_plugin(&plugins_dir, "diff_view");
+
+    let file = project_root.join("file.txt");
+    fs::write(&file, "alpha\nbravo charlie\ndelta\n").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root).unwrap();
+    harness
+        .wait_for_async(
+            |h| h.editor().mode_registry().has_mode("diff-view-pane"),
+            10_000,
+        )
+        .unwrap();
+    harness.open_file(&file).unwrap();
+    harness.wait_for_screen_contains("bravo charlie").unwrap();
+
+    (harness, temp_dir)
+}
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+/// Change "charlie" on line 2 to "CHARLIE" without saving
+fn edit_line_two(harness: &mut EditorTestHarness) {
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    for _ in 0.."charlie".len() {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text("CHARLIE").unwrap();
+    harness.render().unwrap();
+}
+
+/// Side by side, the saved and edited lines sit on the same row; Enter
+/// returns to that line in the editable buffer
+#[test]
+fn test_diff_against_saved_side_by_side() {
+    let (mut harness, _temp_dir) = diff_view_harness(Config::default());
+    edit_line_two(&mut harness);
+
+    // Move away so Enter has to jump back to the changed line
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    run_command(&mut harness, "Diff Against Saved");
+
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            screen.contains("bravo charlie") && screen.contains("bravo CHARLIE")
+        })
+        .unwrap();
+    let screen = harness.screen_to_string();
+    let row = screen
+        .lines()
+        .find(|line| line.contains("bravo charlie"))
+        .unwrap();
+    assert!(
+        row.contains("bravo CHARLIE"),
+        "Old and new lines should be aligned on one row:\n{screen}"
+    );
+
+    // Move to the changed row in the new pane and jump to it
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| !h.screen_to_string().contains("bravo charlie"))
+        .unwrap();
+
+    let content = harness.get_buffer_content().unwrap();
+    assert_eq!(content, "alpha\nbravo CHARLIE\ndelta\n");
+    let line_two_start = "alpha\n".len();
+    assert_eq!(harness.cursor_position(), line_two_start);
+}
+
+/// The unified layout lists removed lines above the lines replacing them
+#[test]
+fn test_diff_against_saved_unified() {
+    let mut config = Config::default();
+    config.editor.diff_view_layout = DiffViewLayout::Unified;
+    let (mut harness, _temp_dir) = diff_view_harness(config);
+    edit_line_two(&mut harness);
+
+    run_command(&mut harness, "Diff Against Saved");
+    harness.wait_for_screen_contains("- bravo charlie").unwrap();
+
+    let screen = harness.screen_to_string();
+    let lines: Vec<&str> = screen.lines().collect();
+    let removed = lines
+        .iter()
+        .position(|l| l.contains("- bravo charlie"))
+        .unwrap();
+    assert!(
+        lines[removed + 1].contains("+ bravo CHARLIE"),
+        "Added line should follow the removed one:\n{screen}"
+    );
+
+    // q closes the view and leaves the buffer as it was
+    harness
+        .send_key(KeyCode::Char('q'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| !h.screen_to_string().contains("- bravo charlie"))
+        .unwrap();
+    harness.assert_screen_contains("bravo CHARLIE");
+}
+
+/// A buffer that matches its file has nothing to show
+#[test]
+fn test_diff_against_saved_without_changes() {
+    let (mut harness, _temp_dir) = diff_view_harness(Config::default());
+
+    run_command(&mut harness, "Diff Against Saved");
+    harness.wait_for_screen_contains("No differences").unwrap();
+}
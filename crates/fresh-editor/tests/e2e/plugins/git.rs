@@ -1976,6 +1976,154 @@ fn test_git_blame_scroll_with_many_virtual_lines() {
     );
 }
 
+/// Helper to run a git blame command by its palette name
+fn run_blame_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test line blame shows the commit for the cursor line and follows the cursor
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_blame_line_blame_follows_cursor() {
+    let repo = GitTestRepo::new();
+
+    repo.create_file("test.txt", "Line 1\nLine 2\n");
+    repo.git_add(&["test.txt"]);
+    repo.git_commit("FIRST_LINE_COMMIT");
+
+    repo.create_file("test.txt", "Line 1\nLine 2\nLine 3\n");
+    repo.git_add(&["test.txt"]);
+    repo.git_commit("SECOND_LINE_COMMIT");
+
+    repo.setup_git_blame_plugin();
+
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+
+    let file_path = repo.path.join("test.txt");
+    harness.open_file(&file_path).unwrap();
+    harness
+        .wait_until(|h| h.get_buffer_content().unwrap().contains("Line 3"))
+        .unwrap();
+
+    run_blame_command(&mut harness, "Git Blame: Toggle Line Blame");
+
+    // Cursor starts on line 1, from the first commit
+    harness
+        .wait_until(|h| h.screen_to_string().contains("FIRST_LINE_COMMIT"))
+        .unwrap();
+    let screen = harness.screen_to_string();
+    let line = screen.lines().find(|l| l.contains("Line 1")).unwrap();
+    assert!(
+        line.contains("Test User") && line.contains("FIRST_LINE_COMMIT"),
+        "Blame should be at the end of the cursor line:\n{screen}"
+    );
+
+    // Line 3 was added by the second commit
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("SECOND_LINE_COMMIT"))
+        .unwrap();
+    let screen = harness.screen_to_string();
+    assert!(
+        !screen.contains("FIRST_LINE_COMMIT"),
+        "Only the cursor line should be annotated:\n{screen}"
+    );
+
+    // Toggling again removes the annotation
+    run_blame_command(&mut harness, "Git Blame: Toggle Line Blame");
+    harness
+        .wait_until(|h| !h.screen_to_string().contains("SECOND_LINE_COMMIT"))
+        .unwrap();
+}
+
+/// Test the blame file view lists commit and author beside each line
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_blame_file_view() {
+    let repo = GitTestRepo::new();
+
+    repo.create_file("test.txt", "Line 1\nLine 2\n");
+    repo.git_add(&["test.txt"]);
+    repo.git_commit("First commit");
+
+    repo.create_file("test.txt", "Line 1\nLine 2\nLine 3\n");
+    repo.git_add(&["test.txt"]);
+    repo.git_commit("Second commit");
+
+    repo.setup_git_blame_plugin();
+
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+
+    let file_path = repo.path.join("test.txt");
+    harness.open_file(&file_path).unwrap();
+    harness
+        .wait_until(|h| h.get_buffer_content().unwrap().contains("Line 3"))
+        .unwrap();
+
+    run_blame_command(&mut harness, "Git Blame File");
+
+    let short_hash = |rev: &str| {
+        let output = std::process::Command::new("git")
+            .args(["rev-parse", "--short=7", rev])
+            .current_dir(&repo.path)
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    };
+    let first = short_hash("HEAD~1");
+    let second = short_hash("HEAD");
+
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            screen.contains(&first) && screen.contains(&second)
+        })
+        .unwrap();
+
+    // Each blame row sits beside the source line it describes
+    let screen = harness.screen_to_string();
+    println!("Blame file view:\n{screen}");
+    let row = |text: &str| screen.lines().find(|l| l.contains(text)).unwrap();
+    assert!(row("Line 1").contains(&first) && row("Line 1").contains("Test User"));
+    assert!(row("Line 3").contains(&second));
+
+    // q closes the view
+    harness
+        .send_key(KeyCode::Char('q'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| !h.screen_to_string().contains(&second))
+        .unwrap();
+    assert!(harness.screen_to_string().contains("Line 3"));
+}
+
 // =============================================================================
 // View Transform Tests - Minimal reproduction of byte 0 header bug
 // =============================================================================
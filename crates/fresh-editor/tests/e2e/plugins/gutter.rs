@@ -68,6 +68,17 @@ fn get_indicator_lines(screen: &str, symbol: &str) -> Vec<usize> {
     lines_with_indicator
 }
 
+/// Git gutter indicator symbols (added, modified, deleted)
+const GIT_SYMBOLS: [&str; 3] = ["+", "~", "–"];
+
+/// Count git gutter indicators of any kind on content lines
+fn count_git_indicators(screen: &str) -> usize {
+    GIT_SYMBOLS
+        .iter()
+        .map(|symbol| count_gutter_indicators(screen, symbol))
+        .sum()
+}
+
 /// Wait for a gutter indicator to appear on any line
 fn wait_for_indicator(harness: &mut EditorTestHarness, symbol: &str) {
     let symbol = symbol.to_string();
@@ -106,7 +117,7 @@ fn trigger_git_gutter_refresh(harness: &mut EditorTestHarness) {
         .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
         .unwrap();
     harness.render().unwrap();
-    harness.type_text("Git Gutter").unwrap();
+    harness.type_text("Git Gutter: Refresh").unwrap();
     harness
         .send_key(KeyCode::Enter, KeyModifiers::NONE)
         .unwrap();
@@ -189,8 +200,8 @@ fn start_server(config: Config) {
     harness
         .wait_until(|h| {
             let screen = h.screen_to_string();
-            // Look for the modified indicator (│) in the gutter
-            has_gutter_indicator(&screen, "│")
+            // Look for the modified indicator (~) in the gutter
+            has_gutter_indicator(&screen, "~")
         })
         .unwrap();
 
@@ -244,7 +255,7 @@ fn test_git_gutter_updates_after_save() {
 
     // Initially, there should be no git gutter indicators (file matches HEAD)
     let screen = harness.screen_to_string();
-    let initial_indicators = count_gutter_indicators(&screen, "│");
+    let initial_indicators = count_gutter_indicators(&screen, "+");
 
     // Make a change
     harness.type_text("// New comment\n").unwrap();
@@ -258,7 +269,7 @@ fn test_git_gutter_updates_after_save() {
         .wait_until(|h| {
             let screen = h.screen_to_string();
             // After save, there should be git indicators (file differs from HEAD)
-            count_gutter_indicators(&screen, "│") > initial_indicators
+            count_gutter_indicators(&screen, "+") > initial_indicators
         })
         .unwrap();
 
@@ -318,7 +329,7 @@ fn start_server(config: Config) {
         .wait_until(|h| {
             let screen = h.screen_to_string();
             // Should have multiple added line indicators
-            count_gutter_indicators(&screen, "│") >= 3
+            count_gutter_indicators(&screen, "+") >= 3
         })
         .unwrap();
 
@@ -362,11 +373,11 @@ fn start_server(config: Config) {
 
     open_file(&mut harness, &repo.path, "src/main.rs");
 
-    // Wait for indicators - deleted lines show as ▾
+    // Wait for indicators - deleted lines show as –
     harness
         .wait_until(|h| {
             let screen = h.screen_to_string();
-            has_gutter_indicator(&screen, "▾") || has_gutter_indicator(&screen, "│")
+            has_gutter_indicator(&screen, "–") || has_gutter_indicator(&screen, "~")
         })
         .unwrap();
 
@@ -421,7 +432,7 @@ fn start_server(config: Config) {
     harness
         .wait_until(|h| {
             let screen = h.screen_to_string();
-            has_gutter_indicator(&screen, "│")
+            has_gutter_indicator(&screen, "~")
         })
         .unwrap();
 
@@ -481,7 +492,7 @@ fn start_server(config: Config) {
     println!("After commit screen:\n{}", screen);
 
     // After commit, there should be no git indicators (file matches HEAD)
-    let indicators = count_gutter_indicators(&screen, "│");
+    let indicators = count_git_indicators(&screen);
     assert_eq!(
         indicators, 0,
         "Git gutter should have no indicators after changes are committed"
@@ -522,13 +533,163 @@ fn test_git_gutter_untracked_file() {
     println!("Untracked file screen:\n{}", screen);
 
     // Untracked files should have no git indicators
-    let indicators = count_gutter_indicators(&screen, "│");
+    let indicators = count_git_indicators(&screen);
     assert_eq!(
         indicators, 0,
         "Git gutter should have no indicators for untracked files"
     );
 }
 
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Create a repo with a committed test.txt, then overwrite it on disk
+fn setup_committed_file(committed: &str, working: &str) -> (GitTestRepo, DirGuard) {
+    let repo = GitTestRepo::new();
+    let original_dir = repo.change_to_repo_dir();
+    let guard = DirGuard::new(original_dir);
+
+    repo.create_file("test.txt", committed);
+    repo.git_add_all();
+    repo.git_commit("Initial commit");
+    repo.modify_file("test.txt", working);
+    repo.setup_git_gutter_plugin();
+
+    (repo, guard)
+}
+
+/// Test that git gutter diffs the unsaved buffer content, not the file on disk
+// TODO: Fix git gutter tests on Windows - they fail due to git command output differences
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_gutter_tracks_unsaved_edits() {
+    let content = "line 1\nline 2\nline 3\nline 4\n";
+    let (repo, _guard) = setup_committed_file(content, content);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+    open_file(&mut harness, &repo.path, "test.txt");
+
+    // Edit line 2 without saving
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text(" CHANGED").unwrap();
+    wait_for_indicator_on_line(&mut harness, "~", 1);
+
+    // Delete the last line: the marker goes on the line above it
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    wait_for_indicator_on_line(&mut harness, "–", 2);
+
+    // Add a line at the top: the other markers shift down
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("line 0\n").unwrap();
+    wait_for_indicator_on_line(&mut harness, "+", 0);
+    wait_for_indicator_on_line(&mut harness, "~", 2);
+    wait_for_indicator_on_line(&mut harness, "–", 3);
+}
+
+/// Test Next/Previous Change navigation, including wrap-around
+// TODO: Fix git gutter tests on Windows - they fail due to git command output differences
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_gutter_next_and_previous_change() {
+    let (repo, _guard) =
+        setup_committed_file("a\nb\nc\nd\ne\nf\ng\nh\n", "a\nb\nC\nd\ne\nf\nG\nh\n");
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+    open_file(&mut harness, &repo.path, "test.txt");
+    wait_for_indicator_on_line(&mut harness, "~", 6);
+
+    // Line starts are two bytes apart ("x\n")
+    run_command(&mut harness, "Git Gutter: Next Change");
+    harness.wait_for_screen_contains("Change 1 of 2").unwrap();
+    assert_eq!(harness.cursor_position(), 4);
+
+    run_command(&mut harness, "Git Gutter: Next Change");
+    harness.wait_for_screen_contains("Change 2 of 2").unwrap();
+    assert_eq!(harness.cursor_position(), 12);
+
+    // Wraps back to the first change
+    run_command(&mut harness, "Git Gutter: Next Change");
+    harness.wait_for_screen_contains("Change 1 of 2").unwrap();
+    assert_eq!(harness.cursor_position(), 4);
+
+    // And backwards past the start to the last one
+    run_command(&mut harness, "Git Gutter: Previous Change");
+    harness.wait_for_screen_contains("Change 2 of 2").unwrap();
+    assert_eq!(harness.cursor_position(), 12);
+}
+
+/// Test that Revert Hunk restores the HEAD text as a single undo step
+// TODO: Fix git gutter tests on Windows - they fail due to git command output differences
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_gutter_revert_hunk() {
+    let (repo, _guard) = setup_committed_file(
+        "line 1\nline 2\nline 3\n",
+        "line 1\nline two\nextra\nline 3\n",
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+    open_file(&mut harness, &repo.path, "test.txt");
+    wait_for_indicator_on_line(&mut harness, "~", 1);
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    run_command(&mut harness, "Git Gutter: Revert Hunk");
+    harness
+        .wait_until(|h| h.get_buffer_content().as_deref() == Some("line 1\nline 2\nline 3\n"))
+        .unwrap();
+    harness
+        .wait_until(|h| count_git_indicators(&h.screen_to_string()) == 0)
+        .unwrap();
+
+    // One undo brings the whole hunk back
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().as_deref(),
+        Some("line 1\nline two\nextra\nline 3\n")
+    );
+}
+
 // =============================================================================
 // Buffer Modified Tests
 // =============================================================================
@@ -680,7 +841,7 @@ fn start_server(config: Config) {
     harness
         .wait_until(|h| {
             let screen = h.screen_to_string();
-            has_gutter_indicator(&screen, "│")
+            has_gutter_indicator(&screen, "~")
         })
         .unwrap();
 
@@ -697,7 +858,7 @@ fn start_server(config: Config) {
     println!("Both plugins screen:\n{}", screen);
 
     // Should still have indicators (from either or both plugins)
-    let total_indicators = count_gutter_indicators(&screen, "│");
+    let total_indicators = count_gutter_indicators(&screen, "│") + count_git_indicators(&screen);
     assert!(
         total_indicators >= 1,
         "Should have indicators from both git changes and unsaved changes"
@@ -751,7 +912,7 @@ fn start_server(config: Config) {
     harness
         .wait_until(|h| {
             let screen = h.screen_to_string();
-            has_gutter_indicator(&screen, "│")
+            has_gutter_indicator(&screen, "~")
         })
         .unwrap();
 
@@ -760,9 +921,9 @@ fn start_server(config: Config) {
 
     // The git gutter indicator (priority 10) should be visible,
     // not overridden by buffer_modified (priority 5)
-    // Both use │ symbol but with different colors
+    // Git gutter draws ~ for the modified line, buffer modified would draw │
     assert!(
-        has_gutter_indicator(&screen, "│"),
+        has_gutter_indicator(&screen, "~"),
         "Higher priority indicator should be visible"
     );
 }
@@ -828,13 +989,13 @@ line 5: unchanged
     trigger_git_gutter_refresh(&mut harness);
 
     // Wait for git gutter indicator to appear on line 2 (0-indexed, which is line 3 in the file)
-    wait_for_indicator_on_line(&mut harness, "│", 2);
+    wait_for_indicator_on_line(&mut harness, "~", 2);
 
     let screen = harness.screen_to_string();
     println!("=== After opening modified file ===\n{}", screen);
 
     // STEP 1: Verify git gutter shows indicator on the modified line (line 3, 0-indexed = line 2)
-    let indicator_lines = get_indicator_lines(&screen, "│");
+    let indicator_lines = get_indicator_lines(&screen, "~");
     println!("Indicator lines after open: {:?}", indicator_lines);
 
     // STEP 2: Now make an in-editor change - insert a newline before line 3
@@ -857,28 +1018,32 @@ line 5: unchanged
     harness.type_text("NEW LINE INSERTED\n").unwrap();
     harness.render().unwrap();
 
-    // Wait for indicator to appear on the newly inserted line (now at index 2)
-    wait_for_indicator_on_line(&mut harness, "│", 2);
+    // Wait for the git gutter to diff the unsaved buffer: the newly inserted
+    // line (now at index 2) is an addition
+    wait_for_indicator_on_line(&mut harness, "+", 2);
 
     let screen_after_insert = harness.screen_to_string();
     println!("=== After inserting new line ===\n{}", screen_after_insert);
 
     // STEP 3: Verify indicators
-    let indicator_lines_after = get_indicator_lines(&screen_after_insert, "│");
+    let indicator_lines_after = get_indicator_lines(&screen_after_insert, "+");
     println!("Indicator lines after insert: {:?}", indicator_lines_after);
 
     // After inserting a line before line 3:
-    // - The newly inserted line (now line 3) should have an unsaved-changes indicator
-    // - The originally modified line (now line 4) should still have a git indicator
-    // Both use │ symbol, so we should see indicators on at least 2 lines
+    // - The newly inserted line (now line 3) should have an added indicator
+    // - The originally modified line (now line 4) should still have a modified indicator
 
-    // Count total indicators - should have at least 2 (one for unsaved change, one for git change)
-    let indicator_count = count_gutter_indicators(&screen_after_insert, "│");
+    // Count total indicators - should have at least 2 (one added, one modified)
+    let indicator_count = count_git_indicators(&screen_after_insert);
     println!("Total indicators after insert: {}", indicator_count);
 
     // We expect indicators on:
-    // - Line index 2: the newly inserted "NEW LINE INSERTED" (unsaved change)
-    // - Line index 3: the original "line 3: MODIFIED!" which moved down (git change)
+    // - Line index 2: the newly inserted "NEW LINE INSERTED" (+)
+    // - Line index 3: the original "line 3: MODIFIED!" which moved down (~)
+    assert!(
+        get_indicator_lines(&screen_after_insert, "~").contains(&3),
+        "Modified line should keep its indicator after shifting down"
+    );
 
     // STEP 4: Save the file and verify git indicators update
     save_file(&mut harness);
@@ -887,12 +1052,12 @@ line 5: unchanged
     trigger_git_gutter_refresh(&mut harness);
 
     // Wait for indicators to update (should still have git indicators after save)
-    wait_for_indicator(&mut harness, "│");
+    wait_for_indicator(&mut harness, "+");
 
     let screen_after_save = harness.screen_to_string();
     println!("=== After save ===\n{}", screen_after_save);
 
-    let indicator_lines_after_save = get_indicator_lines(&screen_after_save, "│");
+    let indicator_lines_after_save = get_indicator_lines(&screen_after_save, "+");
     println!(
         "Indicator lines after save: {:?}",
         indicator_lines_after_save
@@ -906,10 +1071,7 @@ line 5: unchanged
     // The test passes if we can see that the indicator system is working
     // Even if async timing makes exact line matching difficult
     println!("\n=== Test Summary ===");
-    println!(
-        "Initial indicator count: {}",
-        get_indicator_lines(&screen, "│").len()
-    );
+    println!("Initial indicator count: {}", indicator_lines.len());
     println!("After insert indicator count: {}", indicator_count);
     println!(
         "After save indicator count: {}",
@@ -1380,10 +1542,10 @@ fn test_indicator_line_shifting() {
 
     open_file(&mut harness, &repo.path, "test.txt");
     trigger_git_gutter_refresh(&mut harness);
-    wait_for_indicator(&mut harness, "│");
+    wait_for_indicator(&mut harness, "~");
 
     let screen_initial = harness.screen_to_string();
-    let lines_initial = get_indicator_lines(&screen_initial, "│");
+    let lines_initial = get_indicator_lines(&screen_initial, "~");
     println!("=== Initial state ===\n{}", screen_initial);
     println!("Initial indicator lines: {:?}", lines_initial);
 
@@ -1405,10 +1567,10 @@ fn test_indicator_line_shifting() {
     save_file(&mut harness);
     trigger_git_gutter_refresh(&mut harness);
     // Wait for indicators to appear (the inserted lines should show as added)
-    wait_for_indicator(&mut harness, "│");
+    wait_for_indicator(&mut harness, "+");
 
     let screen_after = harness.screen_to_string();
-    let lines_after = get_indicator_lines(&screen_after, "│");
+    let lines_after = get_indicator_lines(&screen_after, "+");
     println!(
         "=== After inserting 2 lines at beginning ===\n{}",
         screen_after
@@ -3,6 +3,7 @@
 
 pub mod audit_mode;
 pub mod diagnostics_panel_jump;
+pub mod diff_view;
 pub mod diff_cursor;
 pub mod find_file;
 pub mod git;
@@ -12,4 +13,6 @@ pub mod lsp_find_references;
 pub mod markdown_source;
 pub mod package_manager;
 pub mod plugin;
+pub mod plugin_panel;
+pub mod search_replace;
 pub mod theme_editor;
@@ -0,0 +1,191 @@
+//! Tests for the Manage Plugins panel.
+//!
+//! Tests that:
+//! - Plugins show with their header version and load status
+//! - Space disables a plugin (dropping its commands) and enables it again
+//! - Reloading a broken plugin shows the error, and Enter the full message
+//! - Unloading a plugin removes the menu items that ran its actions
+
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Menu, MenuItem};
+use fresh::services::plugins::api::{MenuPosition, PluginCommand};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const GREETER_PLUGIN: &str = r#"/// <reference path="./lib/fresh.d.ts" />
+// @version 1.2.0
+const editor = getEditor();
+
+globalThis.greet = function(): void {
+    editor.setStatus("Hello from greeter");
+};
+
+editor.registerCommand("Greeter: Say Hello", "Say hello", "greet", null);
+editor.setStatus("Greeter loaded");
+"#;
+
+/// Project with a plugins directory holding the greeter plugin
+fn greeter_project() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    fs::write(plugins_dir.join("greeter.ts"), GREETER_PLUGIN).unwrap();
+    (temp_dir, project_root)
+}
+
+fn open_panel_on_greeter(harness: &mut EditorTestHarness) {
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Greeter loaded"))
+        .unwrap();
+    harness.editor_mut().open_plugin_panel();
+    // The greeter is the last line of the panel
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+}
+
+fn greeter_line(harness: &EditorTestHarness) -> String {
+    harness
+        .screen_to_string()
+        .lines()
+        .find(|line| line.contains("greeter"))
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[test]
+fn test_plugin_panel_lists_and_toggles_plugins() {
+    let (_temp_dir, project_root) = greeter_project();
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, Default::default(), project_root)
+            .unwrap();
+    open_panel_on_greeter(&mut harness);
+
+    harness.assert_screen_contains("*Plugins*");
+    let line = greeter_line(&harness);
+    assert!(line.contains("[x]"), "line: {line}");
+    assert!(line.contains("1.2.0"), "line: {line}");
+    assert!(line.contains("loaded"), "line: {line}");
+
+    // Space disables it and drops its command
+    harness
+        .send_key(KeyCode::Char(' '), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    let line = greeter_line(&harness);
+    assert!(line.contains("[ ]"), "line: {line}");
+    assert!(line.contains("disabled"), "line: {line}");
+    assert!(!harness.editor().config().plugins["greeter"].enabled);
+    assert!(!harness
+        .editor()
+        .command_registry()
+        .read()
+        .unwrap()
+        .get_all()
+        .iter()
+        .any(|c| c.name == "Greeter: Say Hello"));
+
+    // Space again loads it back
+    harness
+        .send_key(KeyCode::Char(' '), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    let line = greeter_line(&harness);
+    assert!(line.contains("[x]"), "line: {line}");
+    assert!(line.contains("loaded"), "line: {line}");
+    assert!(harness.editor().config().plugins["greeter"].enabled);
+}
+
+#[test]
+fn test_plugin_panel_reload_shows_error() {
+    let (_temp_dir, project_root) = greeter_project();
+    let plugin_path = project_root.join("plugins").join("greeter.ts");
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, Default::default(), project_root)
+            .unwrap();
+    open_panel_on_greeter(&mut harness);
+
+    fs::write(
+        &plugin_path,
+        "const editor = getEditor();\nundefinedFunction();\n",
+    )
+    .unwrap();
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    let line = greeter_line(&harness);
+    assert!(line.contains("error:"), "line: {line}");
+
+    // Enter shows the whole error
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("*Plugin: greeter*");
+    harness.assert_screen_contains("Last error:");
+    harness.assert_screen_contains("undefinedFunction");
+
+    // Fixing the file and reloading clears the error
+    fs::write(&plugin_path, GREETER_PLUGIN).unwrap();
+    harness.editor_mut().open_plugin_panel();
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    let line = greeter_line(&harness);
+    assert!(line.contains("loaded"), "line: {line}");
+    assert!(!line.contains("error:"), "line: {line}");
+}
+
+#[test]
+fn test_unloaded_plugin_menu_items_are_removed() {
+    let mut harness = EditorTestHarness::new(120, 30).unwrap();
+    let action = |label: &str, action: &str| MenuItem::Action {
+        label: label.to_string(),
+        action: action.to_string(),
+        args: HashMap::new(),
+        when: None,
+        checkbox: None,
+    };
+
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::AddMenu {
+            menu: Menu {
+                id: None,
+                label: "Greeter".to_string(),
+                items: vec![action("Say Hello", "greet")],
+                when: None,
+            },
+            position: MenuPosition::Bottom,
+        })
+        .unwrap();
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::AddMenuItem {
+            menu_label: "Help".to_string(),
+            item: action("Greeter Help", "greet_help"),
+            position: MenuPosition::Bottom,
+        })
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Greeter");
+
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::PluginUnloaded {
+            name: "greeter".to_string(),
+            actions: vec!["greet".to_string(), "greet_help".to_string()],
+        })
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Greeter");
+}
@@ -0,0 +1,264 @@
+//! E2E tests for the project-wide search and replace plugin
+
+use crate::common::git_test_helper::{DirGuard, GitTestRepo};
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::fs;
+
+/// Create a committed repo with two files containing "frobnicate" and one without
+fn setup_repo() -> GitTestRepo {
+    let repo = GitTestRepo::new();
+    repo.create_file("a.txt", "frobnicate one\nfrobnicate two\n");
+    repo.create_file("b.txt", "nothing here\n");
+    repo.create_file("c.txt", "frobnicate three\n");
+    repo.git_add_all();
+    repo.git_commit("initial");
+
+    let plugins_dir = repo.path.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    copy_plugin(&plugins_dir, "search_replace");
+    repo
+}
+
+/// Run "Search and Replace in Project" and wait for the results panel
+fn search_and_replace(harness: &mut EditorTestHarness, search: &str, replace: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Search and Replace in Project").unwrap();
+    harness
+        .wait_for_screen_contains("Search and Replace in Project")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .wait_for_screen_contains("Search (in project):")
+        .unwrap();
+    harness.type_text(search).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.wait_for_screen_contains("Replace with:").unwrap();
+    harness.type_text(replace).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.wait_for_screen_contains("Scope:").unwrap();
+}
+
+/// Matches are grouped by file; applying edits unopened files on disk and
+/// open buffers in memory
+#[test]
+fn test_replace_in_files_applies_to_disk_and_open_buffers() {
+    let repo = setup_repo();
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+    harness.open_file(&repo.path.join("c.txt")).unwrap();
+
+    search_and_replace(&mut harness, "frobnicate", "twiddle");
+    harness.assert_screen_contains("3 matches in 2 files");
+    harness.assert_screen_contains("[x] a.txt (2)");
+    harness.assert_screen_contains("[x] c.txt (1)");
+    harness.assert_screen_not_contains("b.txt");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("Replaced 3 occurrences in 2 files")
+        .unwrap();
+
+    // Unopened file is rewritten on disk
+    assert_eq!(
+        fs::read_to_string(repo.path.join("a.txt")).unwrap(),
+        "twiddle one\ntwiddle two\n"
+    );
+    // Open file is edited in its buffer, not on disk
+    assert_eq!(
+        fs::read_to_string(repo.path.join("c.txt")).unwrap(),
+        "frobnicate three\n"
+    );
+    assert_eq!(harness.get_buffer_content().unwrap(), "twiddle three\n");
+}
+
+/// Unchecking a file row skips all of its matches
+#[test]
+fn test_replace_in_files_file_checkbox() {
+    let repo = setup_repo();
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+
+    search_and_replace(&mut harness, "frobnicate", "twiddle");
+
+    // Header, search, replace, scope, blank, results, blank, then the first file
+    for _ in 0..7 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness
+        .send_key(KeyCode::Char(' '), KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("[ ] a.txt (2)").unwrap();
+    harness.assert_screen_contains("(1 selected)");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("Replaced 1 occurrences in 1 files")
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(repo.path.join("a.txt")).unwrap(),
+        "frobnicate one\nfrobnicate two\n"
+    );
+    assert_eq!(
+        fs::read_to_string(repo.path.join("c.txt")).unwrap(),
+        "twiddle three\n"
+    );
+}
+
+/// The "all files" scope adds untracked files but still honors .gitignore
+#[test]
+fn test_replace_in_files_scope_respects_gitignore() {
+    let repo = setup_repo();
+    repo.create_file(".gitignore", "ignored.txt\n");
+    repo.create_file("untracked.txt", "frobnicate\n");
+    repo.create_file("ignored.txt", "frobnicate\n");
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+
+    search_and_replace(&mut harness, "frobnicate", "twiddle");
+    harness.assert_screen_contains("git-tracked files");
+    harness.assert_screen_not_contains("untracked.txt");
+
+    harness
+        .send_key(KeyCode::Char('t'), KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("untracked.txt").unwrap();
+    harness.assert_screen_contains("respecting .gitignore");
+    harness.assert_screen_not_contains("ignored.txt");
+}
+
+/// Regex mode expands capture groups in the replacement
+#[test]
+fn test_replace_in_files_regex_capture_groups() {
+    let repo = setup_repo();
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+
+    search_and_replace(&mut harness, r"frobnicate (\w+)", "$1 frobnicated");
+    // Literal search finds nothing; switch to regex
+    harness.assert_screen_contains("No matches found");
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("3 matches in 2 files")
+        .unwrap();
+    harness.assert_screen_contains("+ one frobnicated");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("Replaced 3 occurrences in 2 files")
+        .unwrap();
+    assert_eq!(
+        fs::read_to_string(repo.path.join("a.txt")).unwrap(),
+        "one frobnicated\ntwo frobnicated\n"
+    );
+}
+
+/// replaceInFile rejects offsets that would split a character of an open
+/// buffer, leaving it untouched
+#[test]
+fn test_replace_in_file_rejects_split_character() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().to_path_buf();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerCommand("Test: Split Character", "", "test_split_character", null);
+
+globalThis.test_split_character = async function(): Promise<void> {
+    const path = editor.getBufferPath(editor.getActiveBufferId());
+    try {
+        // "é" is bytes 3..5; ending at 4 lands inside it
+        await editor.replaceInFile(path, [{ start: 3, end: 4, text: "x" }]);
+        editor.setStatus("edit applied");
+    } catch (e) {
+        editor.setStatus(`edit rejected: ${e instanceof Error ? e.message : String(e)}`);
+    }
+};
+"#;
+    fs::write(plugins_dir.join("test_split.ts"), test_plugin).unwrap();
+    let file = project_root.join("text.txt");
+    fs::write(&file, "café\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        project_root,
+    )
+    .unwrap();
+    harness.open_file(&file).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Test: Split Character").unwrap();
+    harness
+        .wait_for_screen_contains("Test: Split Character")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.wait_for_screen_contains("edit rejected").unwrap();
+    harness.assert_screen_not_contains("edit applied");
+    assert_eq!(harness.get_buffer_content().unwrap(), "café\n");
+}
@@ -1,3 +1,4 @@
+use crate::common::fixtures::TestFixture;
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
 
@@ -250,3 +251,110 @@ fn test_position_history_with_next_prev_buffer() {
     harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
     harness.assert_buffer_content("Third");
 }
+
+/// Test that moving the cursor after navigating back keeps the forward history
+#[test]
+fn test_small_moves_keep_forward_history() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("Buffer 1").unwrap();
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Buffer 2").unwrap();
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Buffer 3").unwrap();
+
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    harness.assert_buffer_content("Buffer 1");
+
+    // Ordinary movement is not a jump
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+
+    harness.send_key(KeyCode::Right, KeyModifiers::ALT).unwrap();
+    harness.assert_buffer_content("Buffer 2");
+    harness.send_key(KeyCode::Right, KeyModifiers::ALT).unwrap();
+    harness.assert_buffer_content("Buffer 3");
+}
+
+/// Test that go to line records the position it jumped from
+#[test]
+fn test_goto_line_recorded_in_history() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    let content: String = (1..=30).map(|i| format!("Line {i}\n")).collect();
+    harness.type_text(&content).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    let start_pos = harness.cursor_position();
+
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("20").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let line20_pos = harness.cursor_position();
+    assert_eq!(line20_pos, content.find("Line 20").unwrap());
+
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    assert_eq!(harness.cursor_position(), start_pos);
+
+    harness.send_key(KeyCode::Right, KeyModifiers::ALT).unwrap();
+    assert_eq!(harness.cursor_position(), line20_pos);
+}
+
+/// Test that navigating back to a closed buffer reopens its file
+#[test]
+fn test_navigate_back_reopens_closed_buffer() {
+    let first = TestFixture::new("first.txt", "alpha\nbeta\ngamma\n").unwrap();
+    let second = TestFixture::new("second.txt", "other\n").unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.open_file(&first.path).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    let saved_pos = harness.cursor_position();
+    let first_id = harness.editor().active_buffer();
+
+    harness.open_file(&second.path).unwrap();
+    harness.editor_mut().close_buffer(first_id).unwrap();
+
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\n");
+    assert_eq!(harness.cursor_position(), saved_pos);
+
+    harness.send_key(KeyCode::Right, KeyModifiers::ALT).unwrap();
+    harness.assert_buffer_content("other\n");
+}
+
+/// Test that each split keeps its own jump list
+#[test]
+fn test_jump_list_is_per_split() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("Buffer 1").unwrap();
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Buffer 2").unwrap();
+
+    // The new split starts with a copy of the list
+    harness.editor_mut().split_pane_vertical();
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Buffer 3").unwrap();
+
+    // The jump to Buffer 3 happened in the other split
+    harness.editor_mut().next_split();
+    harness.assert_buffer_content("Buffer 2");
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    harness.assert_buffer_content("Buffer 1");
+}
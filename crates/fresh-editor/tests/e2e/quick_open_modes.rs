@@ -0,0 +1,179 @@
+//! Tests for the file, line and symbol modes of Quick Open.
+//!
+//! Tests that:
+//! - Files are found through the workspace file index, which skips
+//!   .gitignore'd paths, and the index can be turned off
+//! - `:N` scrolls to line N while it is typed, without moving the cursor
+//! - Escape puts the original scroll position back
+//! - Deleting the `:` prefix hands back to the other modes and restores the scroll
+//! - Enter jumps to the previewed line
+//! - `@` explains when the buffer has no language server
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+fn open_numbered_file(lines: usize) -> (EditorTestHarness, TestFixture) {
+    let content: String = (1..=lines).map(|i| format!("row-{i}-end\n")).collect();
+    let fixture = TestFixture::new("numbered.txt", &content).unwrap();
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    (harness, fixture)
+}
+
+/// Open Quick Open and replace the default `>` prefix with `input`
+fn quick_open(harness: &mut EditorTestHarness, input: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text(input).unwrap();
+    harness.render().unwrap();
+}
+
+/// Typing a line number previews it; Escape scrolls back.
+#[test]
+fn test_goto_line_preview_and_restore() {
+    let (mut harness, _fixture) = open_numbered_file(300);
+    harness.assert_screen_contains("row-1-end");
+
+    quick_open(&mut harness, ":150");
+    harness.assert_screen_contains("row-150-end");
+    harness.assert_screen_not_contains("row-1-end");
+    // Only the view moved
+    assert_eq!(harness.cursor_position(), 0);
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("row-1-end");
+    harness.assert_screen_not_contains("row-150-end");
+    assert_eq!(harness.cursor_position(), 0);
+}
+
+/// The preview follows the number as it is edited.
+#[test]
+fn test_goto_line_preview_follows_input() {
+    let (mut harness, _fixture) = open_numbered_file(300);
+
+    quick_open(&mut harness, ":250");
+    harness.assert_screen_contains("row-250-end");
+
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("row-25-end");
+    harness.assert_screen_not_contains("row-250-end");
+}
+
+/// Deleting the `:` prefix restores the scroll position and leaves line mode.
+#[test]
+fn test_goto_line_prefix_deleted_restores_scroll() {
+    let (mut harness, _fixture) = open_numbered_file(300);
+
+    quick_open(&mut harness, ":150");
+    harness.assert_screen_contains("row-150-end");
+
+    for _ in 0..4 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text(">").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("row-1-end");
+    harness.assert_screen_not_contains("row-150-end");
+    // Back in command mode
+    harness.assert_screen_contains("Add Cursor Above");
+}
+
+/// Enter jumps to the previewed line.
+#[test]
+fn test_goto_line_preview_confirm() {
+    let (mut harness, _fixture) = open_numbered_file(300);
+
+    quick_open(&mut harness, ":150");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("row-150-end");
+    let expected: usize = (1..150).map(|i| format!("row-{i}-end\n").len()).sum();
+    assert_eq!(harness.cursor_position(), expected);
+
+    // The view follows the cursor again
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("row-151-end");
+}
+
+/// `@` without a language server says so instead of listing nothing.
+#[test]
+fn test_symbol_mode_without_language_server() {
+    let (mut harness, _fixture) = open_numbered_file(10);
+
+    quick_open(&mut harness, "@");
+    harness.assert_screen_contains("No language server");
+
+    // Deleting the prefix goes back to the file finder
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text(">").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("No language server");
+}
+
+/// A project with a file nested a few levels deep and an ignored build copy
+fn indexed_project() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("src/deep/inner")).unwrap();
+    std::fs::create_dir_all(root.join("build")).unwrap();
+    std::fs::write(root.join(".gitignore"), "build/\n").unwrap();
+    std::fs::write(root.join("src/deep/inner/needle.rs"), "").unwrap();
+    std::fs::write(root.join("build/needle.rs"), "").unwrap();
+    std::fs::write(root.join("src/main.rs"), "").unwrap();
+    dir
+}
+
+/// File queries are answered from the index once it is built.
+#[test]
+fn test_file_mode_uses_workspace_index() {
+    let project = indexed_project();
+    let mut harness =
+        EditorTestHarness::with_working_dir(100, 30, project.path().to_path_buf()).unwrap();
+    harness
+        .wait_until(|h| h.editor().file_index_stats().is_some())
+        .unwrap();
+    let stats = harness.editor().file_index_stats().unwrap();
+    assert_eq!(stats.files, 3, "{stats:?}");
+
+    quick_open(&mut harness, "needle");
+    harness.assert_screen_contains("src/deep/inner/needle.rs");
+    harness.assert_screen_not_contains("build/needle.rs");
+}
+
+/// With the index turned off there is none to report.
+#[test]
+fn test_file_index_disabled() {
+    let project = indexed_project();
+    let mut config = Config::default();
+    config.editor.file_index = false;
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        config,
+        project.path().to_path_buf(),
+    )
+    .unwrap();
+    assert!(harness.editor().file_index_stats().is_none());
+
+    quick_open(&mut harness, "main");
+    harness.assert_screen_contains("src/main.rs");
+}
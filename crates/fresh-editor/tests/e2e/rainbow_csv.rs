@@ -0,0 +1,110 @@
+//! Tests for CSV and TSV files.
+//!
+//! Tests that:
+//! - Fields are colored by column, quoted fields with the delimiter inside
+//!   them included
+//! - The status bar names the column under the cursor from the header row
+//! - Toggle Aligned View pads the columns on screen without changing the file
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::style::Color;
+
+const CSV: &str = "id,name,price\n1,\"Smith, John\",9.50\n";
+
+fn open(fixture: &TestFixture) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(">command"))
+        .unwrap();
+    harness.type_text(command).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Foreground color of the first character of `text` on screen
+fn fg_of(harness: &EditorTestHarness, text: &str) -> Option<Color> {
+    let (x, y) = harness
+        .find_text_on_screen(text)
+        .unwrap_or_else(|| panic!("{text:?} not on screen"));
+    harness.get_cell_style(x, y).and_then(|style| style.fg)
+}
+
+/// Each column has its own color; the comma inside the quoted name doesn't
+/// start a new one.
+#[test]
+fn test_fields_colored_by_column() {
+    let fixture = TestFixture::new("data.csv", CSV).unwrap();
+    let harness = open(&fixture);
+
+    let name = fg_of(&harness, "name");
+    assert!(name.is_some());
+    assert_eq!(fg_of(&harness, "Smith"), name);
+    assert_eq!(fg_of(&harness, "John"), name);
+    assert_eq!(fg_of(&harness, "9.50"), fg_of(&harness, "price"));
+    assert_ne!(fg_of(&harness, "price"), name);
+}
+
+/// The status bar shows the cursor's column and its header name.
+#[test]
+fn test_status_bar_names_column() {
+    let fixture = TestFixture::new("data.csv", CSV).unwrap();
+    let mut harness = open(&fixture);
+    harness.assert_screen_contains("Column 1: id");
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Column 3: price");
+
+    // A plain text file has no column
+    let fixture = TestFixture::new("notes.txt", "a,b\n").unwrap();
+    let harness = open(&fixture);
+    harness.assert_screen_not_contains("Column 1");
+}
+
+/// The aligned view pads the fields on screen only, and toggles back off.
+#[test]
+fn test_aligned_view_pads_columns() {
+    let fixture = TestFixture::new("data.csv", CSV).unwrap();
+    let mut harness = open(&fixture);
+
+    run_command(&mut harness, "toggle aligned view");
+    harness.assert_screen_contains("Aligned view on");
+    harness.assert_screen_contains("id,name         ,price");
+    harness.assert_screen_contains("1 ,\"Smith, John\",9.50");
+    harness.assert_buffer_content(CSV);
+
+    // The cursor moves over the real text, past the padding
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), "id,name,price".len());
+
+    run_command(&mut harness, "toggle aligned view");
+    harness.assert_screen_contains("id,name,price");
+    harness.assert_screen_not_contains("id,name ");
+}
+
+/// Files that aren't delimited can't be aligned.
+#[test]
+fn test_aligned_view_needs_delimited_file() {
+    let fixture = TestFixture::new("notes.txt", "a,b\n").unwrap();
+    let mut harness = open(&fixture);
+
+    run_command(&mut harness, "toggle aligned view");
+    harness.assert_screen_contains("Aligned view is only for CSV and TSV files");
+    harness.assert_screen_contains("a,b");
+}
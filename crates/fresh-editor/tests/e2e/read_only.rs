@@ -0,0 +1,156 @@
+//! Tests for read-only file buffers.
+//!
+//! Tests that:
+//! - Files matching `editor.readonly_globs` open read-only with a lock indicator
+//! - Edits in a read-only buffer are refused while navigation keeps working
+//! - Toggle Read-Only locks and unlocks a buffer
+//! - Saving a read-only buffer offers Save As, unless it lacks write
+//!   permission, where saving offers sudo
+//! - Unlocking a file without write permission warns that saving needs sudo
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command_name).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// A file under `target/` opens read-only: typing does nothing, moving does.
+#[test]
+fn test_readonly_glob_opens_read_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir_all(project_dir.join("target")).unwrap();
+    let file = project_dir.join("target").join("generated.rs");
+    std::fs::write(&file, "fn generated() {}\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        Config::default(),
+        project_dir.clone(),
+    )
+    .unwrap();
+    harness.open_file(&file).unwrap();
+    harness.render().unwrap();
+    assert!(harness.editor().is_editing_disabled());
+    harness.assert_screen_contains("[RO]");
+
+    harness.type_text("x").unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("fn generated() {}\n");
+    harness.assert_screen_contains("Editing disabled in this buffer");
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 17);
+}
+
+/// Toggle Read-Only locks a normal file and unlocks it again.
+#[test]
+fn test_toggle_read_only() {
+    let fixture = TestFixture::new("notes.txt", "hello\n").unwrap();
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("[RO]");
+
+    run_command(&mut harness, "Toggle Read-Only");
+    harness.assert_screen_contains("[RO]");
+    harness.type_text("x").unwrap();
+    harness
+        .send_key(KeyCode::Char('t'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello\n");
+
+    run_command(&mut harness, "Toggle Read-Only");
+    harness.assert_screen_not_contains("[RO]");
+    harness.type_text("x").unwrap();
+    harness.assert_buffer_content("xhello\n");
+}
+
+/// Saving a read-only buffer opens Save As instead of writing the file.
+#[test]
+fn test_save_read_only_offers_save_as() {
+    let fixture = TestFixture::new("locked.txt", "content\n").unwrap();
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Toggle Read-Only");
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Save as:");
+    harness.assert_screen_contains("File is read-only");
+}
+
+/// Unlocking a file without write permission warns that saving needs sudo.
+#[cfg(unix)]
+#[test]
+fn test_unlock_without_permission_warns() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let fixture = TestFixture::new("protected.txt", "content\n").unwrap();
+    std::fs::set_permissions(&fixture.path, std::fs::Permissions::from_mode(0o444)).unwrap();
+    // Root can write anyway, nothing to warn about
+    if std::fs::OpenOptions::new()
+        .write(true)
+        .open(&fixture.path)
+        .is_ok()
+    {
+        return;
+    }
+
+    let mut harness = EditorTestHarness::new(140, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    assert!(harness.editor().is_editing_disabled());
+
+    run_command(&mut harness, "Toggle Read-Only");
+    assert!(!harness.editor().is_editing_disabled());
+    harness.assert_screen_contains("saving will offer sudo");
+}
+
+/// A file read-only for lack of permission keeps the sudo save, not Save As.
+#[cfg(unix)]
+#[test]
+fn test_save_without_permission_offers_sudo() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let fixture = TestFixture::new("protected.txt", "content\n").unwrap();
+    std::fs::set_permissions(&fixture.path, std::fs::Permissions::from_mode(0o444)).unwrap();
+    // Root can write anyway, so there's no sudo prompt to see
+    if std::fs::OpenOptions::new()
+        .write(true)
+        .open(&fixture.path)
+        .is_ok()
+    {
+        return;
+    }
+
+    let mut harness = EditorTestHarness::new(140, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("[RO]");
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Save with sudo?");
+    harness.assert_screen_not_contains("Save as:");
+}
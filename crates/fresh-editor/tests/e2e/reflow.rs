@@ -0,0 +1,122 @@
+//! Tests for joining and rewrapping lines.
+//!
+//! Tests that:
+//! - Join Lines joins each cursor's line with the next, collapsing indentation
+//! - Join Lines removes the comment markers of joined comment lines
+//! - Hard Wrap rewraps the comment at the cursor, keeping its prefix, in one
+//!   undo step
+//! - Hard Wrap breaks text in wide characters by display width
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(">command"))
+        .unwrap();
+    harness.type_text(command).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Type a width into the Hard Wrap prompt over the default one
+fn enter_width(harness: &mut EditorTestHarness, width: &str) {
+    harness.assert_screen_contains("Wrap at width:");
+    for _ in 0..4 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text(width).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Every cursor joins its line with the next one; the indentation of the
+/// joined line becomes a single space.
+#[test]
+fn test_join_lines_at_every_cursor() {
+    let fixture = TestFixture::new("notes.txt", "x1\n    a\nx2\n\tb").unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('d'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.cursor_count(), 2);
+
+    run_command(&mut harness, "join lines");
+    harness.assert_buffer_content("x1 a\nx2 b");
+    assert_eq!(harness.cursor_count(), 2);
+}
+
+/// Joined comment lines lose their markers; code lines keep their text.
+#[test]
+fn test_join_lines_removes_comment_markers() {
+    let fixture = TestFixture::new(
+        "code.rs",
+        "    // first part\n    //   second part\nlet x = 1;\n",
+    )
+    .unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "join lines");
+    harness.assert_buffer_content("    // first part second part\nlet x = 1;\n");
+    harness.assert_screen_contains("Joined 2 lines");
+}
+
+/// The comment paragraph at the cursor is rewrapped with its `// ` prefix on
+/// every line, and a single undo brings back the original.
+#[test]
+fn test_hard_wrap_keeps_comment_prefix() {
+    let text = "    // The quick brown fox jumps over the lazy dog\nfn main() {}\n";
+    let fixture = TestFixture::new("code.rs", text).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "hard wrap");
+    enter_width(&mut harness, "24");
+    harness.assert_buffer_content(
+        "    // The quick brown\n    // fox jumps over\n    // the lazy dog\nfn main() {}\n",
+    );
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content(text);
+}
+
+/// Wide characters count two columns each and a line may break between any
+/// two of them. The prompt starts from `editor.max_line_length`.
+#[test]
+fn test_hard_wrap_wide_characters() {
+    let mut config = Config::default();
+    config.editor.max_line_length = 10;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.type_text("これは日本語の文章です").unwrap();
+
+    run_command(&mut harness, "hard wrap");
+    harness.assert_screen_contains("Wrap at width: 10");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("これは日本\n語の文章で\nす");
+}
@@ -0,0 +1,107 @@
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(">command"))
+        .unwrap();
+    harness.type_text(command).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Press the default Repeat Last Edit binding (Alt+.)
+fn repeat_last(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('.'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Typed text is replayed as one insert at the cursor
+#[test]
+fn test_repeat_after_insert() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello ").unwrap();
+
+    repeat_last(&mut harness);
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "hello hello ");
+}
+
+/// A word deletion is replayed at the new cursor position
+#[test]
+fn test_repeat_after_delete_word() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("one two three").unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::CONTROL)
+        .unwrap();
+    let after_one_delete = harness.get_buffer_content().unwrap();
+    assert_ne!(after_one_delete, "one two three");
+
+    repeat_last(&mut harness);
+
+    // Same result as deleting a word twice
+    let mut expected = EditorTestHarness::new(80, 24).unwrap();
+    expected.type_text("one two three").unwrap();
+    for _ in 0..2 {
+        expected
+            .send_key(KeyCode::Backspace, KeyModifiers::CONTROL)
+            .unwrap();
+    }
+    let repeated = harness.get_buffer_content().unwrap();
+    assert_ne!(repeated, after_one_delete);
+    assert_eq!(repeated, expected.get_buffer_content().unwrap());
+}
+
+/// Movements and UI actions don't replace the remembered edit
+#[test]
+fn test_repeat_skips_non_repeatable_actions() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("ab").unwrap();
+
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    // Typing into the palette prompt isn't recorded either
+    run_command(&mut harness, "toggle line numbers");
+
+    repeat_last(&mut harness);
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "aabb");
+}
+
+/// The replayed edit applies to every cursor
+#[test]
+fn test_repeat_with_multiple_cursors() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("x\ny").unwrap();
+
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.editor_mut().add_cursor_below();
+    harness.type_text("-").unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    repeat_last(&mut harness);
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "-x-\n-y-");
+}
+
+/// Repeating with no previous edit only shows a message
+#[test]
+fn test_repeat_with_nothing_recorded() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    repeat_last(&mut harness);
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "");
+    harness.assert_screen_contains("Nothing to repeat");
+}
@@ -2660,3 +2660,103 @@ fn test_vertical_scroll_margin_up() {
         );
     }
 }
+
+/// Lines "Line 000" to "Line {count-1}" without a trailing newline
+fn numbered_lines(count: usize) -> String {
+    (0..count)
+        .map(|i| format!("Line {i:03}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Test that the vertical scroll margin follows `vertical_scroll_offset`
+#[test]
+fn test_configured_vertical_scroll_offset() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use fresh::config::Config;
+
+    let mut config = Config::default();
+    config.editor.vertical_scroll_offset = 6;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let _fixture = harness.load_buffer_from_text(&numbered_lines(60)).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    let viewport_height = harness.viewport_height();
+    for _ in 0..viewport_height - 6 - 1 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    assert_eq!(
+        harness.top_line_number(),
+        0,
+        "Viewport should not scroll before cursor reaches the 6-line margin"
+    );
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.top_line_number(), 1);
+}
+
+/// Test that with `scroll_past_end` the mouse wheel can scroll the last line
+/// up to the top of the content area
+#[test]
+fn test_scroll_past_end_with_mouse_wheel() {
+    use fresh::config::Config;
+
+    for (scroll_past_end, expected_top) in [(false, None), (true, Some(59))] {
+        let mut config = Config::default();
+        config.editor.scroll_past_end = scroll_past_end;
+        let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+        let _fixture = harness.load_buffer_from_text(&numbered_lines(60)).unwrap();
+        harness.render().unwrap();
+
+        let (first_row, _) = harness.content_area_rows();
+        for _ in 0..40 {
+            harness.mouse_scroll_down(40, first_row as u16).unwrap();
+        }
+
+        let expected_top = expected_top.unwrap_or(60 - harness.viewport_height());
+        assert_eq!(
+            harness.top_line_number(),
+            expected_top,
+            "scroll_past_end={scroll_past_end}"
+        );
+        assert!(harness
+            .get_row_text(first_row as u16)
+            .contains(&format!("Line {expected_top:03}")));
+        harness.assert_screen_contains("Line 059");
+    }
+}
+
+/// Test that `cursor_centering` keeps the cursor line in the middle of the view
+#[test]
+fn test_cursor_centering_keeps_cursor_in_middle() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use fresh::config::Config;
+
+    let mut config = Config::default();
+    config.editor.cursor_centering = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let _fixture = harness.load_buffer_from_text(&numbered_lines(100)).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    let viewport_height = harness.viewport_height();
+    let (first_row, _) = harness.content_area_rows();
+    for moves in 1..=40 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.render().unwrap();
+        if moves < viewport_height / 2 {
+            // Near the start of the file the view can't scroll up far enough
+            continue;
+        }
+        let cursor_row = harness.screen_cursor_position().1 as usize - first_row;
+        assert!(
+            ((viewport_height - 1) / 2..=viewport_height / 2).contains(&cursor_row),
+            "Cursor row {cursor_row} should be centered in a {viewport_height}-row view after {moves} moves"
+        );
+    }
+}
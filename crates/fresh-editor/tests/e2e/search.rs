@@ -2478,3 +2478,229 @@ fn test_regex_replace_with_capture_group() {
     let content = harness.get_buffer_content().unwrap();
     assert_eq!(content, "ooblaoobla");
 }
+
+/// Preserve case adapts the replacement to each match's case
+#[test]
+fn test_replace_preserve_case() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "item Item ITEM\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(140, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("[ ] Preserve case");
+
+    // Match every case variant, and preserve the case of each
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::ALT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("[x] Preserve case");
+
+    harness.type_text("item").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("entry").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "entry Entry ENTRY\n");
+}
+
+/// \u, \U and \E change the case of the expanded replacement
+#[test]
+fn test_regex_replace_case_modifiers() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "get_value set_name").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::ALT)
+        .unwrap();
+    harness.type_text(r"(\w+)_(\w+)").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text(r"$1\u$2 \U$1\E").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "getValue GET setName SET"
+    );
+}
+
+/// An invalid regex keeps the prompt open with the error instead of
+/// silently falling back or aborting the replace
+#[test]
+fn test_replace_invalid_regex_keeps_prompt_open() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "foo(1)").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::ALT)
+        .unwrap();
+    harness.type_text("foo(").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Invalid regex: unclosed group");
+    harness.assert_screen_contains("Replace: foo(");
+
+    // Fix the pattern in place and carry on
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text(r"\(").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains(r"Replace 'foo\(' with: ");
+}
+
+/// All replacements confirmed in one query-replace run undo together
+#[test]
+fn test_query_replace_is_one_undo_step() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "foo foo foo").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(
+            KeyCode::Char('r'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    harness.type_text("foo").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("bar").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Replace? (y)es (n)o (a)ll (c)ancel: ");
+
+    for key in ['y', 'n', 'y'] {
+        harness
+            .send_key(KeyCode::Char(key), KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "bar foo bar");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "foo foo foo");
+}
+
+/// Open "foo bar foo baz foo" and search for "foo"
+fn search_three_foos() -> (TempDir, EditorTestHarness) {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "foo bar foo baz foo").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("foo").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    (temp_dir, harness)
+}
+
+/// The status bar keeps showing the match position after the prompt closes
+#[test]
+fn test_search_match_indicator_after_prompt_closes() {
+    let (_temp_dir, mut harness) = search_three_foos();
+    harness.assert_screen_contains("Match 1 of 3");
+    assert_eq!(harness.count_search_highlights(), 3);
+
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 8);
+    harness.assert_screen_contains("Match 2 of 3");
+
+    harness
+        .send_key(KeyCode::F(3), KeyModifiers::SHIFT)
+        .unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+    harness.assert_screen_contains("Match 1 of 3");
+}
+
+/// Editing the buffer updates the match count and highlights
+#[test]
+fn test_search_match_count_follows_edits() {
+    let (_temp_dir, mut harness) = search_three_foos();
+
+    // Off a match, only the total is shown
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.assert_screen_contains("3 matches");
+    harness.assert_screen_not_contains("Match 1 of 3");
+
+    harness.type_text(" foo").unwrap();
+    harness.assert_screen_contains("4 matches");
+    assert_eq!(harness.count_search_highlights(), 4);
+
+    // F3 continues through the updated matches
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 8);
+    harness.assert_screen_contains("Match 2 of 4");
+}
+
+/// Escape clears the highlights and the match indicator
+#[test]
+fn test_escape_clears_search_highlights() {
+    let (_temp_dir, mut harness) = search_three_foos();
+    assert_eq!(harness.count_search_highlights(), 3);
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.count_search_highlights(), 0);
+    harness.assert_screen_not_contains("of 3");
+    assert_eq!(harness.get_buffer_content().unwrap(), "foo bar foo baz foo");
+}
@@ -229,6 +229,104 @@ fn test_no_auto_close_when_config_disabled() {
     );
 }
 
+/// Test auto-close is disabled by the auto_close setting
+#[test]
+fn test_no_auto_close_when_auto_close_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut config = Config::default();
+    config.editor.auto_indent = true;
+    config.editor.auto_close = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("foo(\"").unwrap();
+    harness.render().unwrap();
+
+    let content = harness.get_buffer_content().unwrap();
+    assert_eq!(
+        content, "foo(\"",
+        "Should NOT auto-close when auto_close is disabled"
+    );
+}
+
+/// Test that a language can choose which pairs auto-close
+#[test]
+fn test_auto_close_pairs_language_override() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut config = Config::default();
+    config.editor.auto_indent = true;
+    config.languages.get_mut("rust").unwrap().auto_close_pairs = Some(vec!["()".to_string()]);
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("f(\"").unwrap();
+    harness.render().unwrap();
+
+    let content = harness.get_buffer_content().unwrap();
+    assert_eq!(
+        content, "f(\")",
+        "Only the configured pairs should auto-close"
+    );
+}
+
+/// Test that typing an opening bracket or quote wraps the selection at every cursor
+#[test]
+fn test_surround_selection_multiple_cursors() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "foo bar foo").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+
+    // Select the first "foo" and add a cursor at the second one
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness.editor_mut().add_cursor_at_next_match();
+    harness.render().unwrap();
+
+    harness.type_text("(").unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "(foo) bar (foo)",
+        "Selection should be wrapped in parens at each cursor"
+    );
+
+    // The wrapped text stays selected, so pairs can be nested
+    harness.type_text("\"").unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "(\"foo\") bar (\"foo\")"
+    );
+
+    let mut selections: Vec<_> = harness
+        .editor()
+        .active_cursors()
+        .iter()
+        .map(|(_, c)| (c.anchor, c.position))
+        .collect();
+    selections.sort();
+    assert_eq!(selections, vec![(Some(2), 5), (Some(14), 17)]);
+
+    // One undo removes both quotes at every cursor
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "(foo) bar (foo)");
+}
+
 // =============================================================================
 // Bracket Skip-Over Tests
 // =============================================================================
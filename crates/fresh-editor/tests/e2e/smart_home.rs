@@ -86,3 +86,58 @@ fn test_smart_home_respects_soft_wrap() {
         pos_after_end
     );
 }
+
+/// Home goes to the first non-whitespace character first, then to column 0,
+/// then back again.
+#[test]
+fn test_smart_home_toggles_indent_and_line_start() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("    let x = 1;").unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 4);
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 4);
+}
+
+/// With `smart_home` off, Home always goes to column 0.
+#[test]
+fn test_smart_home_disabled_goes_to_line_start() {
+    let mut config = Config::default();
+    config.editor.smart_home = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let _fixture = harness.load_buffer_from_text("    let x = 1;").unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::SHIFT)
+        .unwrap();
+    assert_eq!(harness.get_selected_text(), "    let x = 1;");
+}
+
+/// Shift+Home extends the selection to the indentation, then to column 0.
+#[test]
+fn test_select_smart_home_extends_selection() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("    let x = 1;").unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::SHIFT)
+        .unwrap();
+    assert_eq!(harness.cursor_position(), 4);
+    assert_eq!(harness.get_selected_text(), "let x = 1;");
+
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::SHIFT)
+        .unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+    assert_eq!(harness.get_selected_text(), "    let x = 1;");
+}
@@ -0,0 +1,190 @@
+//! Tests for snippets.
+//!
+//! Tests that:
+//! - Expand Snippet inserts the snippet for the prefix before the cursor
+//! - Tab and Shift+Tab move between tabstops, mirrored tabstops change together
+//! - Snippet lines continue at the indentation of the cursor line
+//! - Snippets are offered in the completion popup
+//! - LSP completion items in snippet format get tabstops too
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::config_io::DirectoryContext;
+use fresh::model::event::{
+    Event, PopupContentData, PopupData, PopupKindHint, PopupListItemData, PopupPositionData,
+};
+use tempfile::TempDir;
+
+/// Editor whose config dir has the given `snippets/global.json`
+fn harness_with_snippets(temp_dir: &TempDir, snippets: &str) -> EditorTestHarness {
+    let dir_context = DirectoryContext::for_testing(&temp_dir.path().join("context"));
+    let snippets_dir = dir_context.config_dir.join("snippets");
+    std::fs::create_dir_all(&snippets_dir).unwrap();
+    std::fs::write(snippets_dir.join("global.json"), snippets).unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir_all(&project_dir).unwrap();
+
+    let mut config = Config::default();
+    config.editor.auto_indent = false;
+    EditorTestHarness::with_shared_dir_context(100, 24, config, project_dir, dir_context).unwrap()
+}
+
+fn expand_snippet(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('j'), KeyModifiers::ALT)
+        .unwrap();
+}
+
+fn selected_text(harness: &EditorTestHarness) -> String {
+    let range = harness
+        .editor()
+        .active_cursors()
+        .primary()
+        .selection_range()
+        .unwrap_or_default();
+    harness.get_buffer_content().unwrap()[range].to_string()
+}
+
+/// Expanding fills in the body; typing edits both mirrors of a tabstop and
+/// Tab/Shift+Tab move between tabstops.
+#[test]
+fn test_expand_snippet_with_mirrored_tabstops() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = harness_with_snippets(
+        &temp_dir,
+        r#"{ "Let": { "prefix": "letm", "body": "let ${1:name} = $2;\nuse_it($1);$0" } }"#,
+    );
+    harness.type_text("letm").unwrap();
+    expand_snippet(&mut harness);
+    harness.assert_buffer_content("let name = ;\nuse_it(name);");
+    assert_eq!(selected_text(&harness), "name");
+    assert_eq!(harness.editor().active_cursors().count(), 2);
+
+    harness.type_text("x").unwrap();
+    harness.assert_buffer_content("let x = ;\nuse_it(x);");
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.editor().active_cursors().count(), 1);
+    assert_eq!(harness.cursor_position(), 8);
+    harness.type_text("1").unwrap();
+
+    harness
+        .send_key(KeyCode::BackTab, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(selected_text(&harness), "x");
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.assert_buffer_content("let x = 1;\nuse_it(x);");
+    assert_eq!(harness.cursor_position(), 21);
+}
+
+/// Lines of a snippet continue at the indentation of the cursor line.
+#[test]
+fn test_snippet_follows_line_indentation() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = harness_with_snippets(
+        &temp_dir,
+        r#"{ "If": { "prefix": "iff", "body": ["if ${1:cond} {", "\t$0", "}"] } }"#,
+    );
+    harness.type_text("    iff").unwrap();
+    expand_snippet(&mut harness);
+    harness.assert_buffer_content("    if cond {\n        \n    }");
+    assert_eq!(selected_text(&harness), "cond");
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 22);
+}
+
+/// Expand Snippet without a matching prefix says so.
+#[test]
+fn test_expand_snippet_without_match() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = harness_with_snippets(
+        &temp_dir,
+        r#"{ "Todo": { "prefix": "todo", "body": "TODO: $0" } }"#,
+    );
+    harness.type_text("nothing").unwrap();
+    expand_snippet(&mut harness);
+    harness.render().unwrap();
+    harness.assert_buffer_content("nothing");
+    harness.assert_screen_contains("No snippet matches 'nothing'");
+}
+
+/// Snippets matching the typed word show up in the completion popup.
+#[test]
+fn test_snippet_in_completion_popup() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = harness_with_snippets(
+        &temp_dir,
+        r#"{ "Todo": { "prefix": "todo", "body": "TODO(${1:me}): $0", "description": "Todo note" } }"#,
+    );
+    harness.type_text("tod").unwrap();
+    harness
+        .send_key(KeyCode::Char(' '), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Todo note");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("TODO(me): ");
+    harness.type_text("bob").unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.type_text("fix").unwrap();
+    harness.assert_buffer_content("TODO(bob): fix");
+}
+
+/// An LSP item with insertTextFormat=Snippet gets a tabstop session.
+#[test]
+fn test_lsp_snippet_item_tabstops() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = harness_with_snippets(&temp_dir, "{}");
+    harness.type_text("ca").unwrap();
+
+    let body = "call(${1:a}, ${2:b})";
+    harness
+        .editor_mut()
+        .set_completion_items(vec![lsp_types::CompletionItem {
+            label: "call".to_string(),
+            kind: Some(lsp_types::CompletionItemKind::FUNCTION),
+            insert_text: Some(body.to_string()),
+            insert_text_format: Some(lsp_types::InsertTextFormat::SNIPPET),
+            ..Default::default()
+        }]);
+    harness
+        .apply_event(Event::ShowPopup {
+            popup: PopupData {
+                kind: PopupKindHint::Completion,
+                title: Some("Completion".to_string()),
+                description: None,
+                transient: false,
+                content: PopupContentData::List {
+                    items: vec![PopupListItemData {
+                        text: "call".to_string(),
+                        detail: None,
+                        icon: None,
+                        data: Some(body.to_string()),
+                    }],
+                    selected: 0,
+                },
+                position: PopupPositionData::BelowCursor,
+                width: 50,
+                max_height: 15,
+                bordered: true,
+            },
+        })
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("call(a, b)");
+    assert_eq!(selected_text(&harness), "a");
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.type_text("z").unwrap();
+    harness.assert_buffer_content("call(a, z)");
+}
@@ -162,37 +162,70 @@ fn test_cannot_close_last_split() {
     harness.assert_screen_contains("Cannot close split");
 }
 
-/// Test split size adjustment
-/// Note: This test is disabled because adjusting split size requires
-/// targeting the parent split container, not the leaf nodes.
-/// This is a known limitation that will be addressed in a future update.
-#[test]
-#[ignore]
-fn test_split_size_adjustment() {
-    let mut harness = EditorTestHarness::new(100, 24).unwrap();
-
-    // Create a split
+fn run_split_command(harness: &mut EditorTestHarness, name: &str) {
     harness
-        .send_key(KeyCode::Char('v'), KeyModifiers::ALT)
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
         .unwrap();
-
-    // Increase split size (Alt+=)
+    harness.render().unwrap();
+    harness.type_text(name).unwrap();
     harness
-        .send_key(KeyCode::Char('='), KeyModifiers::ALT)
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
         .unwrap();
-
-    // Should see status message
     harness.render().unwrap();
-    harness.assert_screen_contains("Adjusted split size by 5%");
+}
+
+/// Column of the only split separator on screen
+fn separator_col(harness: &EditorTestHarness) -> u16 {
+    let separators = harness.editor().get_separator_areas();
+    assert_eq!(separators.len(), 1);
+    separators[0].2
+}
 
-    // Decrease split size (Alt+-)
+/// Test split size adjustment one column at a time, and equalizing
+#[test]
+fn test_split_size_adjustment() {
+    let mut harness = EditorTestHarness::new(101, 24).unwrap();
+
+    run_split_command(&mut harness, "split vert");
+    let initial = separator_col(&harness);
+
+    // The new split is on the right, so growing it moves the separator left
+    run_split_command(&mut harness, "Increase Split Size");
+    assert_eq!(separator_col(&harness), initial - 1);
+    run_split_command(&mut harness, "Increase Split Size");
+    assert_eq!(separator_col(&harness), initial - 2);
+
+    run_split_command(&mut harness, "Decrease Split Size");
+    assert_eq!(separator_col(&harness), initial - 1);
+
+    run_split_command(&mut harness, "Equalize Splits");
+    harness.assert_screen_contains("Equalized split sizes");
+    assert_eq!(separator_col(&harness), initial);
+}
+
+/// Shrinking a split keeps its cursor in view and stops at a minimum size
+#[test]
+fn test_shrink_split_keeps_cursor_visible() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file = temp_dir.path().join("long.txt");
+    let text: String = (1..=100).map(|i| format!("line {i}\n")).collect();
+    std::fs::write(&file, text).unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+    harness.open_file(&file).unwrap();
+
+    run_split_command(&mut harness, "split horiz");
     harness
-        .send_key(KeyCode::Char('-'), KeyModifiers::ALT)
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
         .unwrap();
-
-    // Should see status message
     harness.render().unwrap();
-    harness.assert_screen_contains("Adjusted split size by -5%");
+    harness.assert_screen_contains("line 100");
+
+    for _ in 0..30 {
+        run_split_command(&mut harness, "Decrease Split Size");
+    }
+    harness.assert_screen_contains("Split cannot be resized further");
+    harness.assert_screen_contains("line 100");
 }
 
 /// Test multiple splits (nested)
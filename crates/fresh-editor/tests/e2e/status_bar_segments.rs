@@ -0,0 +1,217 @@
+//! Tests for configurable status bar segments.
+//!
+//! Tests that:
+//! - The `status_bar` groups decide which segments show and in what order
+//! - The selection segments show lines, characters, blocks and words selected
+//! - Segments with a `min_width` disappear on narrow terminals
+//! - Clicking Ln/Col opens Go to Line
+//! - Plugins can set and remove their own segments
+//! - The git_branch segment shows the checked out branch
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, StatusBarSegment};
+use fresh::services::plugins::api::PluginCommand;
+use tempfile::TempDir;
+
+fn segments(names: &[&str]) -> Vec<StatusBarSegment> {
+    names
+        .iter()
+        .map(|name| StatusBarSegment::new(name))
+        .collect()
+}
+
+/// Only the configured segments show, in the configured order.
+#[test]
+fn test_custom_status_bar_segments() {
+    let mut config = Config::default();
+    config.status_bar.left = segments(&["cursor_position", "file_name", "selection_size"]);
+    config.status_bar.right = segments(&["encoding"]);
+
+    let fixture = TestFixture::new("notes.txt", "hello world\n").unwrap();
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    let status = harness.get_status_bar();
+    assert!(
+        status.contains("Ln 1, Col 4 | notes.txt | sel: 3 chars"),
+        "status bar: {status}"
+    );
+    assert!(status.trim_end().ends_with("UTF-8"), "status bar: {status}");
+    assert!(!status.contains("Palette"), "status bar: {status}");
+}
+
+/// The selection size follows multi-line, block and multi-cursor selections,
+/// and words are counted when their segment is shown.
+#[test]
+fn test_selection_size_segments() {
+    let mut config = Config::default();
+    config.status_bar.left = segments(&["file_name", "selection_size", "selection_words"]);
+    config.status_bar.right = Vec::new();
+
+    let fixture = TestFixture::new(
+        "notes.txt",
+        "foo bar
+foo baz
+foo
+",
+    )
+    .unwrap();
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    assert!(!harness.get_status_bar().contains("sel:"));
+
+    // Two whole lines: the line the selection ends at the start of isn't counted
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness.render().unwrap();
+    let status = harness.get_status_bar();
+    assert!(
+        status.contains("notes.txt | sel: 2 lines, 16 chars | 4 words"),
+        "status bar: {status}"
+    );
+
+    // Rectangular selection
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::ALT | KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::ALT | KeyModifiers::SHIFT)
+        .unwrap();
+    harness.render().unwrap();
+    let status = harness.get_status_bar();
+    assert!(status.contains("sel: 3×2 block"), "status bar: {status}");
+
+    // One selection per cursor, summed
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Char('d'), KeyModifiers::CONTROL)
+            .unwrap();
+    }
+    harness.render().unwrap();
+    let status = harness.get_status_bar();
+    assert!(
+        status.contains("sel: 9 chars (3 cursors) | 3 words"),
+        "status bar: {status}"
+    );
+}
+
+/// A segment whose min_width is wider than the terminal is left out.
+#[test]
+fn test_min_width_hides_segments() {
+    let mut config = Config::default();
+    config.status_bar.right = vec![
+        StatusBarSegment {
+            segment: "palette".to_string(),
+            min_width: 120,
+        },
+        StatusBarSegment::new("encoding"),
+    ];
+
+    let mut harness = EditorTestHarness::with_config(100, 24, config.clone()).unwrap();
+    harness.render().unwrap();
+    let status = harness.get_status_bar();
+    assert!(!status.contains("Palette"), "status bar: {status}");
+    assert!(status.contains("UTF-8"), "status bar: {status}");
+
+    let mut harness = EditorTestHarness::with_config(140, 24, config).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Palette");
+}
+
+/// Clicking Ln/Col starts the Go to Line prompt.
+#[test]
+fn test_click_cursor_position_opens_goto_line() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.type_text("abc").unwrap();
+    harness.render().unwrap();
+
+    let status = harness.get_status_bar();
+    let col = status.find("Ln 1, Col 4").expect("cursor position shown");
+    let row = crate::common::harness::layout::status_bar_row(24) as u16;
+    harness.mouse_click(col as u16 + 1, row).unwrap();
+    harness.render().unwrap();
+
+    assert!(
+        harness.get_prompt_line().contains("Go to line:"),
+        "prompt: {}",
+        harness.get_prompt_line()
+    );
+}
+
+/// Plugin segments show where the config lists them, until removed.
+#[test]
+fn test_plugin_status_bar_segment() {
+    let mut config = Config::default();
+    config.status_bar.center = segments(&["build"]);
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::SetStatusBarSegment {
+            name: "build".to_string(),
+            text: "build: ok".to_string(),
+            command: None,
+        })
+        .unwrap();
+    harness.render().unwrap();
+    assert!(harness.get_status_bar().contains("build: ok"));
+
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::RemoveStatusBarSegment {
+            name: "build".to_string(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+    assert!(!harness.get_status_bar().contains("build: ok"));
+}
+
+/// The git_branch segment shows the branch from .git/HEAD.
+#[test]
+fn test_git_branch_segment() {
+    let temp_dir = TempDir::new().unwrap();
+    let project = temp_dir.path().join("project");
+    std::fs::create_dir_all(project.join(".git")).unwrap();
+    std::fs::create_dir_all(project.join("src")).unwrap();
+    std::fs::write(project.join(".git/HEAD"), "ref: refs/heads/topic-branch\n").unwrap();
+
+    let mut config = Config::default();
+    config.status_bar.right = segments(&["git_branch"]);
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project.join("src"))
+            .unwrap();
+
+    assert!(harness.editor_mut().check_status_bar_refresh());
+    harness.render().unwrap();
+    assert!(
+        harness.get_status_bar().contains("topic-branch"),
+        "status bar: {}",
+        harness.get_status_bar()
+    );
+}
@@ -139,6 +139,33 @@ fn test_stdin_special_characters() {
     harness.assert_buffer_content(content);
 }
 
+/// Test the language of a stdin buffer is detected from its content
+#[test]
+fn test_stdin_language_detected_from_content() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    let temp_file = create_stdin_temp_file("{\n  \"name\": \"fresh\"\n}\n");
+    harness
+        .editor_mut()
+        .open_stdin_buffer(temp_file.path(), None)
+        .unwrap();
+    assert_eq!(harness.editor().active_state().language, "json");
+
+    let temp_file = create_stdin_temp_file("#!/usr/bin/env python3\nprint('hi')\n");
+    harness
+        .editor_mut()
+        .open_stdin_buffer(temp_file.path(), None)
+        .unwrap();
+    assert_eq!(harness.editor().active_state().language, "python");
+
+    let temp_file = create_stdin_temp_file("just some text\n");
+    harness
+        .editor_mut()
+        .open_stdin_buffer(temp_file.path(), None)
+        .unwrap();
+    assert_eq!(harness.editor().active_state().language, "text");
+}
+
 /// Test stdin streaming with background thread updates buffer progressively
 #[test]
 fn test_stdin_streaming_progress() {
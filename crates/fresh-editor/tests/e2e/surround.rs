@@ -0,0 +1,130 @@
+//! Tests for Surround Selection With… and Change Surrounding.
+//!
+//! Tests that:
+//! - A selection is wrapped in the pair typed, and one undo takes it back
+//! - Without a selection each cursor's word is wrapped
+//! - The quotes or brackets around the cursor are changed or removed,
+//!   skipping escaped quotes
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(">command"))
+        .unwrap();
+    harness.type_text(command).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Run a command whose prompt is answered with `answer`
+fn run_with_answer(harness: &mut EditorTestHarness, command: &str, answer: &str) {
+    run_command(harness, command);
+    harness.type_text(answer).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// A buffer holding `text` with the cursor `right` characters into it
+fn harness_at(text: &str, right: usize) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(text).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key_repeat(KeyCode::Right, KeyModifiers::NONE, right)
+        .unwrap();
+    harness
+}
+
+#[test]
+fn test_surround_selection_and_undo() {
+    let mut harness = harness_at("hello world", 0);
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+        .unwrap();
+    assert_eq!(harness.get_selected_text(), "hello");
+
+    run_with_answer(&mut harness, "Surround Selection With", ")");
+    assert_eq!(harness.get_buffer_content().unwrap(), "(hello) world");
+    assert_eq!(harness.get_selected_text(), "hello");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "hello world");
+}
+
+#[test]
+fn test_surround_word_at_each_cursor() {
+    let mut harness = harness_at("one two\nthree four\n", 1);
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::CONTROL | KeyModifiers::ALT)
+        .unwrap();
+
+    run_with_answer(&mut harness, "Surround Selection With", "\"");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "\"one\" two\n\"three\" four\n"
+    );
+
+    // A two-character answer gives the opening and closing text
+    run_with_answer(&mut harness, "Surround Selection With", "<>");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "\"<one>\" two\n\"<three>\" four\n"
+    );
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "\"one\" two\n\"three\" four\n"
+    );
+}
+
+#[test]
+fn test_change_and_remove_surrounding() {
+    // The cursor is on the "c" after the escaped quotes
+    let mut harness = harness_at(r#"say("a \"b\" c")"#, 13);
+
+    run_command(&mut harness, "Change Surrounding");
+    harness.assert_screen_contains("Change \"\" to");
+    harness.type_text("'").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), r#"say('a \"b\" c')"#);
+
+    run_with_answer(&mut harness, "Change Surrounding", "");
+    assert_eq!(harness.get_buffer_content().unwrap(), r#"say(a \"b\" c)"#);
+
+    // The brackets are next, the cursor still inside them
+    run_with_answer(&mut harness, "Change Surrounding", "[");
+    assert_eq!(harness.get_buffer_content().unwrap(), r#"say[a \"b\" c]"#);
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), r#"say(a \"b\" c)"#);
+}
+
+#[test]
+fn test_change_surrounding_needs_a_pair() {
+    let mut harness = harness_at("plain text", 2);
+    run_command(&mut harness, "Change Surrounding");
+    harness.assert_screen_contains("No quotes or brackets around the cursor");
+    assert_eq!(harness.get_buffer_content().unwrap(), "plain text");
+}
@@ -0,0 +1,150 @@
+//! Tests for synchronized scrolling of two splits.
+//!
+//! Tests that:
+//! - Wheel scrolling one linked split scrolls the other by as many lines
+//! - Cursor jumps scroll only their own split, while scroll keys move both
+//! - A folded range counts as one line
+//! - Unlinking, or closing one of the splits, ends the link
+
+use crate::common::harness::{layout, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use lsp_types::FoldingRange;
+
+fn numbered_lines(word: &str) -> String {
+    (1..=100).map(|i| format!("{word} {i:03}\n")).collect()
+}
+
+/// Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(">command"))
+        .unwrap();
+    harness.type_text(command).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// `alpha.txt` on the left and `beta.txt` on the right, the right active
+fn side_by_side() -> EditorTestHarness {
+    let mut harness = EditorTestHarness::with_temp_project(120, 30).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let alpha = project_dir.join("alpha.txt");
+    let beta = project_dir.join("beta.txt");
+    std::fs::write(&alpha, numbered_lines("alpha")).unwrap();
+    std::fs::write(&beta, numbered_lines("beta")).unwrap();
+
+    harness.open_file(&alpha).unwrap();
+    run_command(&mut harness, "Split Vertical");
+    harness.open_file(&beta).unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+/// The first content row, holding the top line of both splits
+fn top_row(harness: &EditorTestHarness) -> String {
+    harness.get_row_text(layout::CONTENT_START_ROW as u16)
+}
+
+#[test]
+fn test_wheel_scroll_moves_both_splits() {
+    let mut harness = side_by_side();
+    run_command(&mut harness, "Toggle Synchronized Scrolling");
+    assert!(harness.get_status_bar().contains("Scroll linked"));
+
+    harness.mouse_scroll_down(90, 10).unwrap();
+    let row = top_row(&harness);
+    assert!(!row.contains("beta 001"), "the right split should scroll");
+    let top = (2..=20)
+        .find(|n| row.contains(&format!("beta {n:03}")))
+        .unwrap_or_else(|| panic!("no beta line at the top: {row:?}"));
+    assert!(
+        row.contains(&format!("alpha {top:03}")),
+        "the left split should scroll as far: {row:?}"
+    );
+}
+
+#[test]
+fn test_cursor_jumps_scroll_only_their_split() {
+    let mut harness = side_by_side();
+    run_command(&mut harness, "Toggle Synchronized Scrolling");
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("beta 100");
+    assert!(top_row(&harness).contains("alpha 001"));
+
+    // Scroll keys move the other split too
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(top_row(&harness).contains("alpha 002"));
+}
+
+#[test]
+fn test_folded_range_counts_as_one_line() {
+    let mut harness = side_by_side();
+
+    // Fold "alpha 004" to "alpha 007" under "alpha 003" in the left split
+    run_command(&mut harness, "Next Split");
+    harness.editor_mut().active_state_mut().folding_ranges = vec![FoldingRange {
+        start_line: 2,
+        end_line: 6,
+        start_character: None,
+        end_character: None,
+        kind: None,
+        collapsed_text: None,
+    }];
+    harness.render().unwrap();
+    harness
+        .mouse_click(0, (layout::CONTENT_START_ROW + 2) as u16)
+        .unwrap();
+    assert!(!harness.screen_to_string().contains("alpha 004"));
+
+    run_command(&mut harness, "Toggle Synchronized Scrolling");
+    run_command(&mut harness, "Next Split");
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::CONTROL)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    // Three lines shown went by on each side
+    let row = top_row(&harness);
+    assert!(row.contains("beta 004"), "{row:?}");
+    assert!(row.contains("alpha 008"), "{row:?}");
+}
+
+#[test]
+fn test_unlink_and_close_end_the_link() {
+    let mut harness = side_by_side();
+    run_command(&mut harness, "Toggle Synchronized Scrolling");
+    run_command(&mut harness, "Toggle Synchronized Scrolling");
+    harness.assert_screen_contains("Scrolling unlinked");
+    assert!(!harness.get_status_bar().contains("Scroll linked"));
+
+    harness.mouse_scroll_down(90, 10).unwrap();
+    assert!(!top_row(&harness).contains("beta 001"));
+    assert!(top_row(&harness).contains("alpha 001"));
+
+    run_command(&mut harness, "Toggle Synchronized Scrolling");
+    assert!(harness.get_status_bar().contains("Scroll linked"));
+    run_command(&mut harness, "Close Split");
+    assert!(!harness.get_status_bar().contains("Scroll linked"));
+}
+
+#[test]
+fn test_link_needs_two_splits() {
+    let mut harness = EditorTestHarness::new(120, 30).unwrap();
+    run_command(&mut harness, "Toggle Synchronized Scrolling");
+    harness.assert_screen_contains("Synchronized scrolling needs two splits");
+}
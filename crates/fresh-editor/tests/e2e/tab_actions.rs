@@ -0,0 +1,126 @@
+//! E2E tests for tab actions: pinning, middle-click close and reopening closed tabs
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use fresh::model::event::BufferId;
+use tempfile::TempDir;
+
+/// Open three files and return their buffer ids in opening order
+fn setup_three_tabs() -> (EditorTestHarness, TempDir, Vec<BufferId>) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = EditorTestHarness::new(100, 30).unwrap();
+    let mut ids = Vec::new();
+    for i in 1..=3 {
+        let path = temp_dir.path().join(format!("file{}.txt", i));
+        std::fs::write(
+            &path,
+            format!("line one {i}\nline two {i}\nline three {i}\n"),
+        )
+        .unwrap();
+        harness.open_file(&path).unwrap();
+        ids.push(harness.editor().active_buffer());
+    }
+    harness.render().unwrap();
+    (harness, temp_dir, ids)
+}
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// A pinned tab moves to the left edge and is kept by Close Other Tabs
+#[test]
+fn test_pinned_tab_is_leftmost_and_survives_close_others() {
+    let (mut harness, temp_dir, ids) = setup_three_tabs();
+    let split = harness.editor().get_active_split();
+
+    // file3 is active; pin it
+    run_command(&mut harness, "Pin/Unpin Tab");
+    assert_eq!(
+        harness.editor().get_split_tabs(split),
+        vec![ids[2], ids[0], ids[1]]
+    );
+
+    // Switch to file1 and close everything else
+    harness
+        .open_file(&temp_dir.path().join("file1.txt"))
+        .unwrap();
+    run_command(&mut harness, "Close Other Tabs");
+
+    assert_eq!(harness.editor().get_split_tabs(split), vec![ids[2], ids[0]]);
+    assert_eq!(harness.editor().active_buffer(), ids[0]);
+}
+
+/// Middle-clicking a tab closes it without switching to it first
+#[test]
+fn test_middle_click_closes_tab() {
+    let (mut harness, _temp_dir, ids) = setup_three_tabs();
+    let split = harness.editor().get_active_split();
+
+    let (row, col) = harness
+        .editor()
+        .get_tab_layouts()
+        .values()
+        .flat_map(|layout| layout.tabs.iter())
+        .find(|tab| tab.buffer_id == ids[0])
+        .map(|tab| (tab.tab_area.y, tab.tab_area.x + tab.tab_area.width / 2))
+        .expect("file1 tab should be laid out");
+
+    harness
+        .send_mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Middle),
+            column: col,
+            row,
+            modifiers: KeyModifiers::empty(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.editor().get_split_tabs(split), vec![ids[1], ids[2]]);
+    assert_eq!(harness.editor().active_buffer(), ids[2]);
+}
+
+/// Reopen Closed Tab brings back the last closed file at its old cursor position
+#[test]
+fn test_reopen_closed_tab_restores_cursor() {
+    let (mut harness, _temp_dir, ids) = setup_three_tabs();
+    let split = harness.editor().get_active_split();
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    let cursor = harness.cursor_position();
+    assert!(cursor > 0);
+
+    harness
+        .send_key(KeyCode::Char('w'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().get_split_tabs(split), vec![ids[0], ids[1]]);
+
+    harness
+        .send_key(
+            KeyCode::Char('t'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        )
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("file3.txt");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "line one 3\nline two 3\nline three 3\n"
+    );
+    assert_eq!(harness.cursor_position(), cursor);
+
+    // Nothing left to reopen
+    run_command(&mut harness, "Reopen Closed Tab");
+    harness.assert_screen_contains("No recently closed tabs");
+}
@@ -16,7 +16,7 @@ use crossterm::event::{KeyCode, KeyModifiers};
 use fresh::services::terminal::TerminalState;
 use portable_pty::{native_pty_system, PtySize};
 
-fn harness_or_skip(width: u16, height: u16) -> Option<EditorTestHarness> {
+fn pty_available() -> bool {
     if native_pty_system()
         .openpty(PtySize {
             rows: 1,
@@ -27,6 +27,13 @@ fn harness_or_skip(width: u16, height: u16) -> Option<EditorTestHarness> {
         .is_err()
     {
         eprintln!("Skipping terminal test: PTY not available in this environment");
+        return false;
+    }
+    true
+}
+
+fn harness_or_skip(width: u16, height: u16) -> Option<EditorTestHarness> {
+    if !pty_available() {
         return None;
     }
 
@@ -3009,3 +3016,263 @@ fn test_bracket_paste_in_terminal_mode() {
     // Clean up: send Ctrl+D to exit cat
     harness.editor_mut().send_terminal_input(b"\x04");
 }
+
+/// Test "Switch Terminal" lists open terminals and shows the picked one
+#[test]
+fn test_switch_terminal_picker() {
+    let mut harness = harness_or_return!(120, 24);
+
+    harness.editor_mut().open_terminal();
+    let first = harness.editor().active_buffer_id();
+    harness.editor_mut().open_terminal();
+    harness.render().unwrap();
+    assert_ne!(harness.editor().active_buffer_id(), first);
+
+    harness.editor_mut().start_switch_terminal_prompt();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Switch to terminal:");
+
+    harness.type_text("Terminal 0").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.editor().active_buffer_id(), first);
+    assert!(harness.editor().is_terminal_mode());
+}
+
+/// Test renaming a terminal changes its tab title
+#[test]
+fn test_rename_terminal() {
+    let mut harness = harness_or_return!(80, 24);
+
+    harness.editor_mut().open_terminal();
+    harness.editor_mut().start_rename_terminal_prompt();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Rename terminal:");
+
+    // The prompt starts with the current title
+    for _ in 0.."Terminal 0".len() {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text("build").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("*build*");
+    harness.assert_screen_not_contains("*Terminal 0*");
+}
+
+/// Test closing a split that holds a running terminal asks first
+#[test]
+fn test_close_split_with_running_terminal_confirms() {
+    let mut harness = harness_or_return!(120, 24);
+
+    harness.editor_mut().split_pane_vertical();
+    harness.editor_mut().open_terminal();
+    let terminal_buffer = harness.editor().active_buffer_id();
+    harness.render().unwrap();
+
+    // Declining keeps the split and the terminal
+    harness.editor_mut().close_active_split();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Closing this split stops *Terminal 0*");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(harness.editor().is_terminal_buffer(terminal_buffer));
+
+    // Confirming closes the split and stops the terminal
+    harness.editor_mut().close_active_split();
+    harness.type_text("y").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(!harness.editor().is_terminal_buffer(terminal_buffer));
+    harness.assert_screen_not_contains("*Terminal 0*");
+}
+
+/// Test "Run Selection in Terminal" runs the current line in a new terminal
+/// while the editor buffer stays active
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses Unix shell arithmetic
+fn test_run_selection_in_terminal() {
+    let mut harness = harness_or_return!(100, 24);
+    let editor_buffer = harness.editor().active_buffer_id();
+
+    harness.type_text("echo SEL_$((20+22))").unwrap();
+    harness.editor_mut().run_selection_in_terminal();
+    harness.render().unwrap();
+
+    assert_eq!(harness.editor().active_buffer_id(), editor_buffer);
+    harness.assert_screen_contains("*Terminal 0*");
+
+    // Only one terminal is open, so the picker selects it
+    harness.editor_mut().start_switch_terminal_prompt();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("SEL_42"))
+        .unwrap();
+}
+
+/// Test "Run File" runs the file with its language's run command
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses bash
+fn test_run_file_in_terminal() {
+    let mut harness = harness_or_return!(100, 24);
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let script = temp_dir.path().join("script.sh");
+    std::fs::write(&script, "echo FILE_$((6*7))\n").unwrap();
+    harness.open_file(&script).unwrap();
+
+    harness.editor_mut().run_file_in_terminal();
+    harness.render().unwrap();
+
+    let terminal_buffer = harness.editor().active_buffer_id();
+    assert!(harness.editor().is_terminal_buffer(terminal_buffer));
+    harness
+        .wait_until(|h| h.screen_to_string().contains("FILE_42"))
+        .unwrap();
+}
+
+/// Test "Run File" explains how to configure a language without a run command
+#[test]
+fn test_run_file_without_run_command() {
+    let mut harness = harness_or_return!(100, 24);
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file = temp_dir.path().join("notes.md");
+    std::fs::write(&file, "# Notes\n").unwrap();
+    harness.open_file(&file).unwrap();
+
+    harness.editor_mut().run_file_in_terminal();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("No run command configured for markdown");
+    assert!(!harness
+        .editor()
+        .is_terminal_buffer(harness.editor().active_buffer_id()));
+}
+
+/// Test clicking a file location in terminal output opens it in an editor split
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses printf
+fn test_click_file_location_in_terminal() {
+    let mut harness = harness_or_return!(100, 24);
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let target = temp_dir.path().join("target.txt");
+    std::fs::write(&target, "first line\nsecond line\n").unwrap();
+
+    harness.editor_mut().open_terminal();
+    harness.render().unwrap();
+    let terminal_buffer = harness.editor().active_buffer_id();
+
+    // printf keeps the command line itself from looking like a location
+    harness
+        .type_text(&format!("printf '%s:2\\n' {}", target.display()))
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let location = format!("{}:2", target.display());
+    harness
+        .wait_until(|h| h.find_text_on_screen(&location).is_some())
+        .unwrap();
+
+    let (col, row) = harness.find_text_on_screen(&location).unwrap();
+    harness.mouse_click(col + 1, row).unwrap();
+    harness.render().unwrap();
+
+    let active = harness.editor().active_buffer_id();
+    assert_ne!(active, terminal_buffer);
+    assert!(!harness.editor().is_terminal_buffer(active));
+    // The cursor lands at the start of line 2
+    assert_eq!(harness.cursor_position(), "first line\n".len());
+    // The terminal stays visible in its own split
+    harness.assert_screen_contains("second line");
+    assert!(harness.find_text_on_screen(&location).is_some());
+}
+
+/// Harness whose config defines the given tasks, or None without a PTY
+fn task_harness(tasks: &[(&str, &str)]) -> Option<EditorTestHarness> {
+    if !pty_available() {
+        return None;
+    }
+    let mut config = fresh::config::Config::default();
+    for (name, command) in tasks {
+        config.tasks.insert(name.to_string(), command.to_string());
+    }
+    EditorTestHarness::with_config(100, 24, config).ok()
+}
+
+/// Test a task runs in its own panel and reports its exit status
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses sh syntax
+fn test_run_task_reports_exit_status() {
+    let Some(mut harness) = task_harness(&[("check", "echo TASK_$((6*7)); exit 3")]) else {
+        return;
+    };
+
+    harness.editor_mut().start_run_task_prompt();
+    harness.render().unwrap();
+    harness.assert_screen_contains("echo TASK_");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .wait_until(|h| h.screen_to_string().contains("failed with exit code 3"))
+        .unwrap();
+    harness.assert_screen_contains("*Task: check*");
+    harness.assert_screen_contains("TASK_42");
+}
+
+/// Test rerunning the last task and cancelling a running one
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses sleep
+fn test_rerun_and_cancel_task() {
+    let Some(mut harness) = task_harness(&[("serve", "echo STARTED; sleep 30")]) else {
+        return;
+    };
+
+    harness.editor_mut().rerun_last_task();
+    harness.render().unwrap();
+    harness.assert_screen_contains("No task has been run yet");
+
+    harness.editor_mut().run_task("serve");
+    harness
+        .wait_until(|h| h.screen_to_string().contains("STARTED"))
+        .unwrap();
+    harness.editor_mut().cancel_task();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Task serve cancelled after"))
+        .unwrap();
+
+    // Rerunning replaces the finished panel instead of adding another tab
+    harness.editor_mut().rerun_last_task();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("STARTED"))
+        .unwrap();
+    assert_eq!(
+        harness.screen_to_string().matches("*Task: serve*").count(),
+        1
+    );
+    harness.editor_mut().cancel_task();
+}
+
+/// Test "Run Task" explains how to add tasks when none are configured
+#[test]
+fn test_run_task_without_tasks() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.editor_mut().start_run_task_prompt();
+    harness.render().unwrap();
+    harness.assert_screen_contains("No tasks configured");
+}
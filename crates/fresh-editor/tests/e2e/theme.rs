@@ -4,7 +4,7 @@ use crate::common::harness::{EditorTestHarness, HarnessOptions};
 use crossterm::event::{KeyCode, KeyModifiers};
 use fresh::config::Config;
 use fresh::config_io::DirectoryContext;
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier};
 use std::fs;
 use tempfile::TempDir;
 
@@ -540,3 +540,169 @@ fn test_issue_1001_config_with_spaces_in_theme_name_loads_correctly() {
 
     drop(temp_dir);
 }
+
+#[test]
+fn test_auto_theme_follows_terminal_background() {
+    let config = Config {
+        theme: "auto".into(),
+        light_theme: "light".into(),
+        dark_theme: "nostalgia".into(),
+        ..Default::default()
+    };
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    assert!(harness.editor().wants_terminal_background());
+
+    // Dark until the terminal reports its background
+    assert_eq!(harness.editor().theme().name, "nostalgia");
+
+    harness.editor_mut().set_terminal_background(250, 250, 245);
+    harness.render().unwrap();
+    assert_eq!(harness.editor().theme().name, "light");
+    let bg = harness.get_cell_style(5, 3).and_then(|s| s.bg);
+    assert_eq!(bg, Some(Color::Rgb(255, 255, 255)));
+
+    harness.editor_mut().set_terminal_background(20, 20, 30);
+    assert_eq!(harness.editor().theme().name, "nostalgia");
+}
+
+#[test]
+fn test_terminal_background_ignored_without_auto_theme() {
+    let config = Config {
+        theme: "dark".into(),
+        ..Default::default()
+    };
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    assert!(!harness.editor().wants_terminal_background());
+
+    harness.editor_mut().set_terminal_background(255, 255, 255);
+    assert_eq!(harness.editor().theme().name, "dark");
+}
+
+/// A user theme written in TOML extends a builtin, and edits to the file are
+/// picked up without restarting.
+#[test]
+fn test_user_toml_theme_extends_builtin_and_reloads_on_change() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+
+    let themes_dir = temp_dir.path().join("config").join("themes");
+    fs::create_dir_all(&themes_dir).unwrap();
+    let theme_path = themes_dir.join("mine.toml");
+    fs::write(
+        &theme_path,
+        "extends = \"dark\"\n\n[editor]\nbg = [10, 20, 30]\n",
+    )
+    .unwrap();
+
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir_all(project_root.join("plugins")).unwrap();
+
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(Config {
+                theme: "mine".into(),
+                ..Default::default()
+            })
+            .with_working_dir(project_root)
+            .with_shared_dir_context(dir_context)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    harness.render().unwrap();
+
+    let bg = harness.get_cell_style(5, 3).and_then(|s| s.bg);
+    assert_eq!(bg, Some(Color::Rgb(10, 20, 30)));
+    // Colors not overridden come from the base theme
+    let dark = fresh::view::theme::ThemeLoader::embedded_only()
+        .load_all()
+        .get_cloned("dark")
+        .unwrap();
+    assert_eq!(harness.editor().theme().editor_fg, dark.editor_fg);
+
+    fs::write(
+        &theme_path,
+        "extends = \"dark\"\n\n[editor]\nbg = [40, 50, 60]\n",
+    )
+    .unwrap();
+    // Make sure the edit is visible even on filesystems with coarse mtimes
+    fs::File::options()
+        .write(true)
+        .open(&theme_path)
+        .unwrap()
+        .set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(5))
+        .unwrap();
+
+    harness.advance_time(std::time::Duration::from_secs(5));
+    assert!(harness.editor_mut().poll_theme_changes());
+    harness.render().unwrap();
+
+    let bg = harness.get_cell_style(5, 3).and_then(|s| s.bg);
+    assert_eq!(bg, Some(Color::Rgb(40, 50, 60)));
+}
+
+/// Per-scope syntax style overrides from the config restyle matching tokens,
+/// with the longest matching scope winning, and take effect on config reload.
+#[test]
+fn test_syntax_style_overrides_apply_on_config_reload() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+    let config_path = dir_context.config_path();
+
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir_all(project_root.join("plugins")).unwrap();
+    let source_path = project_root.join("hello.rs");
+    fs::write(
+        &source_path,
+        include_str!("../fixtures/syntax_highlighting/hello.rs"),
+    )
+    .unwrap();
+
+    let mut harness = EditorTestHarness::create(
+        100,
+        30,
+        HarnessOptions::new()
+            .with_config(Config {
+                theme: "dark".into(),
+                ..Default::default()
+            })
+            .with_working_dir(project_root)
+            .with_shared_dir_context(dir_context)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    harness.open_file(&source_path).unwrap();
+
+    let (x, y) = harness.find_text_on_screen("World").unwrap();
+    let before = harness.get_cell_style(x, y).unwrap();
+    assert_ne!(before.fg, Some(Color::Rgb(255, 136, 0)));
+    assert!(!before.add_modifier.contains(Modifier::BOLD));
+
+    fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+    fs::write(
+        &config_path,
+        r##"{
+            "theme": "dark",
+            "syntax_styles": {
+                "string": { "fg": "#00ff00" },
+                "string.quoted": { "fg": "#ff8800", "bold": true }
+            }
+        }"##,
+    )
+    .unwrap();
+    harness.editor_mut().reload_config();
+    harness.render().unwrap();
+
+    let after = harness.get_cell_style(x, y).unwrap();
+    assert_eq!(after.fg, Some(Color::Rgb(255, 136, 0)));
+    assert!(after.add_modifier.contains(Modifier::BOLD));
+
+    // Tokens outside the overridden scopes keep the theme's style
+    let (x, y) = harness.find_text_on_screen("message").unwrap();
+    let other = harness.get_cell_style(x, y).unwrap();
+    assert_ne!(other.fg, Some(Color::Rgb(255, 136, 0)));
+    assert!(!other.add_modifier.contains(Modifier::BOLD));
+}
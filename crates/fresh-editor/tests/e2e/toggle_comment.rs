@@ -3,6 +3,7 @@
 //! Tests that:
 //! - Toggle comment uses language-specific comment prefixes from config
 //! - Selection is preserved after commenting/uncommenting
+//! - Block comments wrap and unwrap the selection
 
 use crate::common::harness::{EditorTestHarness, HarnessOptions};
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -42,7 +43,7 @@ fn test_toggle_comment_rust_prefix() {
     harness.render().unwrap();
 
     // Toggle comment on first line
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     let content = harness.get_buffer_content().unwrap();
     assert!(
@@ -66,7 +67,7 @@ fn test_toggle_comment_python_prefix() {
     harness.render().unwrap();
 
     // Toggle comment on first line
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     let content = harness.get_buffer_content().unwrap();
     assert!(
@@ -90,7 +91,7 @@ fn test_toggle_comment_shell_prefix() {
     harness.render().unwrap();
 
     // Toggle comment on first line
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     let content = harness.get_buffer_content().unwrap();
     assert!(
@@ -136,7 +137,7 @@ fn test_toggle_comment_preserves_selection() {
     let selection_len_before = selection_before.end - selection_before.start;
 
     // Toggle comment
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     // Verify content is commented
     let content = harness.get_buffer_content().unwrap();
@@ -198,7 +199,7 @@ fn test_toggle_uncomment_preserves_selection() {
     );
 
     // Toggle comment (uncomment)
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     // Verify content is uncommented
     let content = harness.get_buffer_content().unwrap();
@@ -237,7 +238,7 @@ fn test_toggle_comment_roundtrip_with_selection() {
     harness.render().unwrap();
 
     // Toggle comment (comment all)
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     // Verify content is commented
     let content = harness.get_buffer_content().unwrap();
@@ -256,7 +257,7 @@ fn test_toggle_comment_roundtrip_with_selection() {
     harness.render().unwrap();
 
     // Toggle comment again (uncomment all)
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     // Verify content is back to original
     let content = harness.get_buffer_content().unwrap();
@@ -296,7 +297,7 @@ fn test_toggle_comment_single_line_no_newline() {
     );
 
     // Toggle comment - this was causing infinite loop
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     // Verify content is commented
     let content = harness.get_buffer_content().unwrap();
@@ -325,7 +326,7 @@ fn test_toggle_comment_yaml_prefix() {
     harness.render().unwrap();
 
     // Toggle comment on first line
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     let content = harness.get_buffer_content().unwrap();
     assert!(
@@ -349,7 +350,7 @@ fn test_toggle_comment_yml_prefix() {
     harness.render().unwrap();
 
     // Toggle comment on first line
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     let content = harness.get_buffer_content().unwrap();
     assert!(
@@ -380,7 +381,7 @@ fn test_toggle_comment_selection_at_buffer_end() {
     harness.render().unwrap();
 
     // Toggle comment
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     // Verify both lines are commented
     let content = harness.get_buffer_content().unwrap();
@@ -390,3 +391,147 @@ fn test_toggle_comment_selection_at_buffer_end() {
         content
     );
 }
+
+/// Test that the comment marker is placed at the smallest indentation of the selection
+#[test]
+fn test_toggle_comment_aligns_at_min_indent() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "fn f() {\n    a();\n\n        b();\n}").unwrap();
+
+    let mut harness = EditorTestHarness::create(80, 24, HarnessOptions::new()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Select lines 2-4
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+
+    run_command(&mut harness, "Toggle Line Comment");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn f() {\n    // a();\n\n    //     b();\n}",
+        "Markers should line up at the smallest indent and skip blank lines"
+    );
+
+    // Toggling again restores the original text
+    run_command(&mut harness, "Toggle Line Comment");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn f() {\n    a();\n\n        b();\n}"
+    );
+}
+
+/// Test that languages without a configured prefix use the built-in one
+#[test]
+fn test_toggle_comment_lua_prefix() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.lua");
+    std::fs::write(&file_path, "print(1)\n").unwrap();
+
+    let mut harness = EditorTestHarness::create(80, 24, HarnessOptions::new()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Toggle Line Comment");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "-- print(1)\n");
+}
+
+/// Test that every cursor's line is toggled in a single undo step
+#[test]
+fn test_toggle_comment_multiple_cursors() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.py");
+    let original = "a = 1\nb = 2\nc = 3\n";
+    std::fs::write(&file_path, original).unwrap();
+
+    let mut harness = EditorTestHarness::create(80, 24, HarnessOptions::new()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.editor_mut().add_cursor_below();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Toggle Line Comment");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "# a = 1\n# b = 2\nc = 3\n"
+    );
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), original);
+}
+
+/// Test that Toggle Block Comment wraps the exact selection and unwraps it again
+#[test]
+fn test_toggle_block_comment_selection() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    let original = "let x = value;";
+    std::fs::write(&file_path, original).unwrap();
+
+    let mut harness = EditorTestHarness::create(80, 24, HarnessOptions::new()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Select "value"
+    for _ in 0..8 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    for _ in 0..5 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+
+    run_command(&mut harness, "Toggle Block Comment");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "let x = /* value */;"
+    );
+    assert_eq!(
+        harness.get_selected_text(),
+        "value",
+        "The commented text should stay selected"
+    );
+
+    run_command(&mut harness, "Toggle Block Comment");
+    assert_eq!(harness.get_buffer_content().unwrap(), original);
+
+    // Both toggles undo in one step each
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "let x = /* value */;"
+    );
+}
+
+/// Test that Toggle Block Comment does nothing for languages without block comments
+#[test]
+fn test_toggle_block_comment_unsupported_language() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.py");
+    std::fs::write(&file_path, "x = 1\n").unwrap();
+
+    let mut harness = EditorTestHarness::create(80, 24, HarnessOptions::new()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Toggle Block Comment");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "x = 1\n");
+    harness.assert_screen_contains("No block comment syntax");
+}
@@ -67,7 +67,7 @@ fn test_undo_past_bulk_edit_after_save_does_not_corrupt_buffer() {
 
     // Step 2: Toggle comment on the current line -> creates a BulkEdit event
     // that snapshots the piece tree (which now references multiple buffers)
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     let content_after_comment = harness.get_buffer_content().unwrap();
     assert!(
@@ -209,7 +209,7 @@ fn test_bulk_edit_save_undo_minimal() {
     let original_content = harness.get_buffer_content().unwrap();
 
     // Step 1: Toggle comment (BulkEdit)
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
 
     let commented = harness.get_buffer_content().unwrap();
     assert_ne!(
@@ -305,7 +305,7 @@ fn test_multiple_save_cycles_with_bulk_edit_undo() {
 
     // Cycle 1: edit -> toggle comment -> save -> type
     harness.type_text("A").unwrap();
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
     harness
         .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
         .unwrap();
@@ -313,7 +313,7 @@ fn test_multiple_save_cycles_with_bulk_edit_undo() {
     harness.type_text("B").unwrap();
 
     // Cycle 2: toggle comment again -> save -> type
-    run_command(&mut harness, "Toggle Comment");
+    run_command(&mut harness, "Toggle Line Comment");
     harness
         .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
         .unwrap();
@@ -0,0 +1,95 @@
+//! Tests for Insert Unicode Character.
+//!
+//! Tests that:
+//! - A typed codepoint is inserted at every cursor
+//! - Characters can be found by name
+//! - The last inserted character is offered first next time
+//! - The picker can insert into the prompt it was opened from
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn open_picker(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(
+            KeyCode::Char('u'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        )
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Open the picker, type `query` and pick the highlighted character
+fn insert_char(harness: &mut EditorTestHarness, query: &str) {
+    open_picker(harness);
+    harness.type_text(query).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// A codepoint typed in full is inserted at each cursor, whether or not the
+/// table names it.
+#[test]
+fn test_codepoint_inserted_at_every_cursor() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("a\nb\nc").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.editor_mut().add_cursor_below();
+    harness.editor_mut().add_cursor_below();
+    assert_eq!(harness.cursor_count(), 3);
+
+    insert_char(&mut harness, "U+2713");
+    harness.assert_buffer_content("✓a\n✓b\n✓c");
+
+    // A four-byte character the table doesn't name
+    insert_char(&mut harness, "1F9A9");
+    harness.assert_buffer_content("✓🦩a\n✓🦩b\n✓🦩c");
+}
+
+/// Typing part of a name narrows the picker to that character.
+#[test]
+fn test_character_found_by_name() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    open_picker(&mut harness);
+    harness.type_text("snowman without").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("U+26C4");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("⛄");
+}
+
+/// The most recently inserted character is at the top of the picker.
+#[test]
+fn test_recent_character_offered_first() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    insert_char(&mut harness, "U+2192");
+    insert_char(&mut harness, "U+2500");
+
+    insert_char(&mut harness, "");
+    harness.assert_buffer_content("→──");
+}
+
+/// Opened from the search prompt, the picker inserts into the search text
+/// and hands the prompt back.
+#[test]
+fn test_insert_into_open_prompt() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("plain ✓ done").unwrap();
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    insert_char(&mut harness, "2713");
+    assert!(harness.editor().is_prompting());
+    assert!(harness.get_prompt_line().contains('✓'));
+    harness.assert_buffer_content("plain ✓ done");
+}
@@ -0,0 +1,120 @@
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, EditorConfig, EditorKeymap};
+
+fn vim_harness() -> EditorTestHarness {
+    let config = Config {
+        editor: EditorConfig {
+            keymap: EditorKeymap::Vim,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    EditorTestHarness::with_config(80, 24, config).unwrap()
+}
+
+/// Enter insert mode, type `text`, and return to normal mode
+fn insert(harness: &mut EditorTestHarness, text: &str) {
+    harness.type_text("i").unwrap();
+    harness.type_text(text).unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+}
+
+/// Normal mode swallows printable keys; `i` switches to insert mode
+#[test]
+fn test_vim_normal_and_insert_modes() {
+    let mut harness = vim_harness();
+    harness.render().unwrap();
+    harness.assert_screen_contains("-- NORMAL --");
+
+    harness.type_text("q").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "");
+
+    harness.type_text("i").unwrap();
+    harness.assert_screen_contains("-- INSERT --");
+    harness.type_text("hello").unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.assert_screen_contains("-- NORMAL --");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "hello");
+}
+
+/// `dw` deletes a word, `2dw` deletes two
+#[test]
+fn test_vim_delete_word_with_count() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "one two three four");
+
+    harness.type_text("0dw").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "two three four");
+
+    harness.type_text("2dw").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "four");
+}
+
+/// `dd` removes the current line and `p` puts it back below
+#[test]
+fn test_vim_delete_line_and_paste() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "first\nsecond\nthird\n");
+
+    harness.type_text("ggdd").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "second\nthird\n");
+
+    harness.type_text("p").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "second\nfirst\nthird\n"
+    );
+}
+
+/// `cw` replaces a word and leaves insert mode active
+#[test]
+fn test_vim_change_word() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "old name");
+
+    harness.type_text("0cw").unwrap();
+    harness.type_text("new").unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "new name");
+}
+
+/// Visual-line selection deletes whole lines
+#[test]
+fn test_vim_visual_line_delete() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "a\nb\nc\nd");
+
+    harness.type_text("ggjVj").unwrap();
+    harness.assert_screen_contains("-- VISUAL LINE --");
+    harness.type_text("d").unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "a\nd");
+    harness.assert_screen_contains("-- NORMAL --");
+}
+
+/// `yy` copies without changing the buffer or leaving a selection
+#[test]
+fn test_vim_yank_line_keeps_buffer() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "keep\nme\n");
+
+    harness.type_text("ggyyjp").unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "keep\nme\nkeep\n");
+}
+
+/// `u` undoes the last change
+#[test]
+fn test_vim_undo() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "abc");
+
+    harness.type_text("0x").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "bc");
+
+    harness.type_text("u").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "abc");
+}
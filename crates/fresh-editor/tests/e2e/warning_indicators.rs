@@ -3,6 +3,7 @@
 //! Tests the warning domain system including:
 //! - ShowWarnings command
 //! - ShowLspStatus command
+//! - ShowProcessStatus command
 //! - ClearWarnings command
 //! - Status bar warning badge display
 
@@ -106,6 +107,55 @@ fn test_show_lsp_status_no_lsp() {
     harness.assert_screen_contains("No LSP server active");
 }
 
+/// Test ShowProcessStatus command execution when no LSP is running
+#[test]
+fn test_show_process_status_no_lsp() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Show LSP/Process Status").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("No LSP servers are currently running");
+}
+
+/// Test that ShowProcessStatus lists a running server with its pid
+#[test]
+fn test_show_process_status_lists_running_server() -> anyhow::Result<()> {
+    use crate::common::scripted_lsp::ScriptedLspServer;
+
+    let lsp = ScriptedLspServer::new();
+    let temp_dir = tempfile::tempdir()?;
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, "fn main() {}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert("rust".to_string(), lsp.server_config()?);
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+    harness.attach_lsp(&lsp);
+    harness.open_file(&test_file)?;
+    harness.wait_for_lsp_notification("textDocument/didOpen")?;
+
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.type_text("Show LSP/Process Status")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_for_screen_contains("Language Server Processes")?;
+    harness.assert_screen_contains("rust");
+    harness.assert_screen_contains("pid");
+
+    Ok(())
+}
+
 /// Test that status log buffer stays read-only after revert
 ///
 /// Reproduces the bug where opening a log file via the status bar sets
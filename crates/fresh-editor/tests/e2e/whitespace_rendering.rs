@@ -0,0 +1,112 @@
+//! E2E tests for whitespace rendering modes and trailing whitespace highlighting
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, RenderWhitespace};
+
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(command_name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Screen text of the first content row
+fn first_line_text(harness: &EditorTestHarness) -> String {
+    let (start_row, _) = harness.content_area_rows();
+    harness.get_row_text(start_row as u16)
+}
+
+#[test]
+fn test_render_whitespace_modes() {
+    let fixture = TestFixture::new("spaces.txt", "x  y z  \n").unwrap();
+    let cases = [
+        (RenderWhitespace::None, "x  y z  "),
+        (RenderWhitespace::Trailing, "x  y z··"),
+        (RenderWhitespace::Boundary, "x··y z··"),
+        (RenderWhitespace::All, "x··y·z··"),
+    ];
+    for (mode, expected) in cases {
+        let mut config = Config::default();
+        config.editor.render_whitespace = mode;
+        let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+        harness.open_file(&fixture.path).unwrap();
+        harness.render().unwrap();
+        let text = first_line_text(&harness);
+        assert!(
+            text.contains(expected),
+            "{mode:?} should render {expected:?}, got {text:?}"
+        );
+    }
+}
+
+#[test]
+fn test_toggle_whitespace_rendering_cycles_modes() {
+    let fixture = TestFixture::new("cycle.txt", "x  y z  \n").unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    assert!(first_line_text(&harness).contains("x  y z  "));
+
+    for (expected, status) in [
+        ("x  y z··", "trailing"),
+        ("x··y z··", "boundary"),
+        ("x··y·z··", "all"),
+        ("x  y z  ", "none"),
+    ] {
+        run_command(&mut harness, "Toggle Whitespace Rendering");
+        harness.assert_screen_contains(&format!("Whitespace rendering: {status}"));
+        let text = first_line_text(&harness);
+        assert!(
+            text.contains(expected),
+            "expected {expected:?}, got {text:?}"
+        );
+    }
+}
+
+#[test]
+fn test_highlight_trailing_whitespace() {
+    let content = "let x = 1;  \t\nok\n";
+    let fixture = TestFixture::new("trailing.txt", content).unwrap();
+    let mut config = Config::default();
+    config.editor.highlight_trailing_whitespace = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    // Keep the cursor off the first line so it doesn't cover a cell
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    let warning_bg = harness.editor().theme().diagnostic_warning_bg;
+    let (start_row, _) = harness.content_area_rows();
+    let row = start_row as u16;
+    let text = harness.get_row_text(row);
+    let text_col = text[..text.find("let").unwrap()].chars().count() as u16;
+    let bg_at = |col: u16| harness.get_cell_style(col, row).and_then(|style| style.bg);
+
+    // The two spaces and the tab after "1;" are highlighted
+    let after_text = text_col + "let x = 1;".len() as u16;
+    for col in after_text..after_text + 3 {
+        assert_eq!(
+            bg_at(col),
+            Some(warning_bg),
+            "column {col} should be highlighted"
+        );
+    }
+    // Spaces between words are not
+    assert_ne!(bg_at(text_col + 3), Some(warning_bg));
+    // Highlighting is only drawn; the text is unchanged
+    harness.assert_screen_not_contains("·");
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.editor_mut().clipboard_content_for_test(), content);
+}
@@ -0,0 +1,105 @@
+//! Tests for word completion from buffer text.
+//!
+//! Tests that:
+//! - Completion without a language server offers the buffer's words,
+//!   closest to the cursor first, and Enter accepts one
+//! - Typing keeps filtering the words and Escape dismisses the popup
+//! - Nothing is offered below `editor.word_completion_min_chars` or when
+//!   word completion is off
+//! - `open_buffers` also offers words from other open buffers
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, WordCompletion};
+
+fn harness_with(word_completion: WordCompletion) -> EditorTestHarness {
+    let mut config = Config::default();
+    config.editor.word_completion = word_completion;
+    EditorTestHarness::with_config(100, 24, config).unwrap()
+}
+
+fn trigger_completion(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char(' '), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn popup_visible(harness: &EditorTestHarness) -> bool {
+    harness.editor().active_state().popups.is_visible()
+}
+
+/// Words of the buffer are offered, the closest one first.
+#[test]
+fn test_word_completion_offers_buffer_words() {
+    let mut harness = harness_with(WordCompletion::Buffer);
+    harness
+        .type_text("render_frame other\nrender_text\nren")
+        .unwrap();
+    trigger_completion(&mut harness);
+    assert!(popup_visible(&harness));
+    harness.assert_screen_contains("buffer word");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("render_frame other\nrender_text\nrender_text");
+    assert!(!popup_visible(&harness));
+}
+
+/// Typing narrows the words down, Escape closes the popup.
+#[test]
+fn test_word_completion_filters_and_dismisses() {
+    let mut harness = harness_with(WordCompletion::Buffer);
+    harness.type_text("render_frame render_text\nren").unwrap();
+    trigger_completion(&mut harness);
+    assert!(popup_visible(&harness));
+
+    harness.type_text("der_f").unwrap();
+    harness.render().unwrap();
+    assert!(popup_visible(&harness));
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("render_frame render_text\nrender_frame");
+
+    harness.type_text("\nren").unwrap();
+    trigger_completion(&mut harness);
+    assert!(popup_visible(&harness));
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    assert!(!popup_visible(&harness));
+    harness.assert_buffer_content("render_frame render_text\nrender_frame\nren");
+}
+
+/// Short prefixes and `word_completion: off` offer nothing.
+#[test]
+fn test_word_completion_min_chars_and_off() {
+    let mut harness = harness_with(WordCompletion::Buffer);
+    harness.type_text("render_frame\nre").unwrap();
+    trigger_completion(&mut harness);
+    assert!(!popup_visible(&harness));
+
+    let mut harness = harness_with(WordCompletion::Off);
+    harness.type_text("render_frame\nren").unwrap();
+    trigger_completion(&mut harness);
+    assert!(!popup_visible(&harness));
+}
+
+/// `open_buffers` offers words of other buffers, named after their file.
+#[test]
+fn test_word_completion_open_buffers() {
+    let fixture = TestFixture::new("words.txt", "transmogrify\n").unwrap();
+    let mut harness = harness_with(WordCompletion::OpenBuffers);
+    harness.open_file(&fixture.path).unwrap();
+    harness.new_buffer().unwrap();
+    harness.type_text("trans").unwrap();
+    trigger_completion(&mut harness);
+    assert!(popup_visible(&harness));
+    harness.assert_screen_contains("words.txt");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("transmogrify");
+}
@@ -180,12 +180,62 @@ fn test_session_handles_missing_files() {
             "Session restore should handle missing files"
         );
 
+        // The skipped file is named in a one-line notice
+        let status = harness.editor().get_status_message().cloned();
+        assert_eq!(
+            status.as_deref(),
+            Some("Skipped 1 missing file(s) from the last session: d.txt")
+        );
+
         // Surviving file's content should be accessible
         harness.open_file(&file1).unwrap();
         harness.assert_buffer_content("Content that survives");
     }
 }
 
+/// Test that the workspace is saved periodically after input, not only on exit
+#[test]
+fn test_session_auto_saves_after_input() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file = project_dir.join("notes.txt");
+    std::fs::write(&file, "line 1\nline 2\nline 3\n").unwrap();
+
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            80,
+            24,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+        harness.editor_mut().set_workspace_enabled(true);
+
+        harness.open_file(&file).unwrap();
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+
+        // Saves are debounced
+        assert!(!harness.editor_mut().auto_save_workspace().unwrap());
+        harness.advance_time(std::time::Duration::from_secs(6));
+        assert!(harness.editor_mut().auto_save_workspace().unwrap());
+
+        // Nothing changed since, so there is nothing more to save
+        harness.advance_time(std::time::Duration::from_secs(6));
+        assert!(!harness.editor_mut().auto_save_workspace().unwrap());
+        // Dropped without save_workspace(), as if the process were killed
+    }
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Config::default(), project_dir)
+            .unwrap();
+    assert!(harness.editor_mut().try_restore_workspace().unwrap());
+    harness.render().unwrap();
+    harness.assert_screen_contains("notes.txt");
+    assert_eq!(harness.cursor_position(), "line 1\n".len());
+}
+
 /// Test that session does not auto-load (simulating --no-session behavior)
 #[test]
 fn test_no_session_flag_behavior() {
@@ -1534,3 +1584,60 @@ fn test_session_restores_split_labels() {
         );
     }
 }
+
+/// Test that a language chosen with "Set Language" survives a session restore
+#[test]
+fn test_session_restores_language_override() {
+    use crate::common::harness::HarnessOptions;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let file = project_dir.join("notes.txt");
+    std::fs::write(&file, "x = 1\n").unwrap();
+    let options = || {
+        HarnessOptions::new()
+            .with_working_dir(project_dir.clone())
+            .without_empty_plugins_dir()
+            .with_full_grammar_registry()
+    };
+
+    // First session: set the language by hand and save
+    {
+        let mut harness = EditorTestHarness::create(80, 24, options()).unwrap();
+        harness.open_file(&file).unwrap();
+        assert_eq!(harness.editor().active_state().language, "text");
+
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text("Set Language").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.wait_for_prompt().unwrap();
+        harness.type_text("Python").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        assert_eq!(harness.editor().active_state().language, "python");
+
+        let workspace = harness.editor().capture_workspace();
+        assert_eq!(
+            workspace
+                .language_overrides
+                .get(std::path::Path::new("notes.txt")),
+            Some(&"Python".to_string())
+        );
+        harness.editor_mut().save_workspace().unwrap();
+    }
+
+    // Second session: the override is applied again on restore
+    {
+        let mut harness = EditorTestHarness::create(80, 24, options()).unwrap();
+        let restored = harness.editor_mut().try_restore_workspace().unwrap();
+        assert!(restored, "Session should have been restored");
+        harness.open_file(&file).unwrap();
+        assert_eq!(harness.editor().active_state().language, "python");
+    }
+}
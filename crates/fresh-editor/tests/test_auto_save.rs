@@ -1,7 +1,7 @@
 mod common;
 
 use common::harness::EditorTestHarness;
-use fresh::config::Config;
+use fresh::config::{AutoSaveMode, Config};
 use std::fs;
 use std::time::Duration;
 
@@ -13,6 +13,14 @@ fn auto_save_config(interval_secs: u32) -> Config {
     config
 }
 
+/// Helper to create a config using one of the `auto_save` modes
+fn auto_save_mode_config(mode: AutoSaveMode) -> Config {
+    let mut config = Config::default();
+    config.editor.auto_save = mode;
+    config.editor.auto_save_delay_ms = 500;
+    config
+}
+
 #[test]
 fn test_persistent_auto_save_basic() -> anyhow::Result<()> {
     let config = auto_save_config(2);
@@ -129,3 +137,104 @@ fn test_auto_recovery_save_throttled_before_interval() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_auto_save_after_delay_waits_for_idle() -> anyhow::Result<()> {
+    let config = auto_save_mode_config(AutoSaveMode::AfterDelay);
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config)?;
+    let temp_dir = harness.project_dir().unwrap();
+    let file_path = temp_dir.join("idle.txt");
+    fs::write(&file_path, "Original")?;
+
+    harness.open_file(&file_path)?;
+    harness.type_text("A")?;
+    harness.advance_time(Duration::from_millis(300));
+
+    // Another edit restarts the idle timer
+    harness.type_text("B")?;
+    harness.advance_time(Duration::from_millis(300));
+    assert_eq!(harness.editor_mut().process_auto_save()?, 0);
+
+    harness.advance_time(Duration::from_millis(300));
+    assert_eq!(harness.editor_mut().process_auto_save()?, 1);
+    assert!(fs::read_to_string(&file_path)?.contains("AB"));
+
+    // Nothing new to save until the next edit
+    harness.advance_time(Duration::from_millis(600));
+    assert_eq!(harness.editor_mut().process_auto_save()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_auto_save_on_focus_change() -> anyhow::Result<()> {
+    let config = auto_save_mode_config(AutoSaveMode::OnFocusChange);
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config)?;
+    let temp_dir = harness.project_dir().unwrap();
+    let first = temp_dir.join("first.txt");
+    let second = temp_dir.join("second.txt");
+    fs::write(&first, "one")?;
+    fs::write(&second, "two")?;
+
+    harness.open_file(&first)?;
+    harness.type_text("1")?;
+
+    // Switching to another buffer saves the one that was left
+    harness.open_file(&second)?;
+    assert_eq!(harness.editor_mut().process_auto_save()?, 1);
+    assert!(fs::read_to_string(&first)?.contains('1'));
+
+    // Losing terminal focus saves the active buffer
+    harness.type_text("2")?;
+    harness.editor_mut().handle_focus_lost();
+    assert!(fs::read_to_string(&second)?.contains('2'));
+    assert!(!harness.editor().active_state().buffer.is_modified());
+
+    Ok(())
+}
+
+#[test]
+fn test_auto_save_skips_buffers_without_a_file() -> anyhow::Result<()> {
+    let config = auto_save_mode_config(AutoSaveMode::AfterDelay);
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config)?;
+    harness.new_buffer()?;
+    harness.type_text("scratch")?;
+
+    harness.advance_time(Duration::from_millis(600));
+    assert_eq!(harness.editor_mut().process_auto_save()?, 0);
+    assert!(harness.editor().active_state().buffer.is_modified());
+
+    Ok(())
+}
+
+#[test]
+fn test_auto_save_prompts_instead_of_overwriting_external_change() -> anyhow::Result<()> {
+    let config = auto_save_mode_config(AutoSaveMode::AfterDelay);
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config)?;
+    let temp_dir = harness.project_dir().unwrap();
+    let file_path = temp_dir.join("conflict.txt");
+    fs::write(&file_path, "Original")?;
+
+    harness.open_file(&file_path)?;
+    harness.type_text("mine")?;
+
+    fs::write(&file_path, "theirs")?;
+    // Make sure the change is visible even on filesystems with coarse mtimes
+    fs::File::options()
+        .write(true)
+        .open(&file_path)?
+        .set_modified(std::time::SystemTime::now() + Duration::from_secs(5))?;
+
+    harness.advance_time(Duration::from_millis(600));
+    assert_eq!(harness.editor_mut().process_auto_save()?, 0);
+    assert_eq!(fs::read_to_string(&file_path)?, "theirs");
+
+    harness.render()?;
+    harness.assert_screen_contains("File changed on disk");
+
+    Ok(())
+}
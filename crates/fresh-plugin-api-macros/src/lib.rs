@@ -626,6 +626,8 @@ declare function getEditor(): EditorAPI;
 
 /** Handle for a cancellable async operation */
 interface ProcessHandle<T> extends PromiseLike<T> {
+  /** Process ID, as passed to the onProcessStdout/onProcessStderr hooks */
+  readonly processId: number;
   /** Promise that resolves to the result when complete */
   readonly result: Promise<T>;
   /** Cancel/kill the operation. Returns true if cancelled, false if already completed */
@@ -89,7 +89,7 @@
 use anyhow::{anyhow, Result};
 use fresh_core::api::{
     ActionSpec, BufferInfo, CompositeHunk, CreateCompositeBufferOptions, EditorStateSnapshot,
-    JsCallbackId, LanguagePackConfig, LspServerPackConfig, OverlayOptions, PluginCommand,
+    FileEdit, JsCallbackId, LanguagePackConfig, LspServerPackConfig, OverlayOptions, PluginCommand,
     PluginResponse,
 };
 use fresh_core::command::Command;
@@ -639,6 +639,30 @@ impl JsEditorApi {
             .send(PluginCommand::SetStatus { message: msg });
     }
 
+    /// Set the text of a status bar segment, shown where its name is listed in
+    /// the `status_bar` config - clicking it runs `command` if given
+    pub fn set_status_bar_segment(
+        &self,
+        name: String,
+        text: String,
+        command: rquickjs::function::Opt<String>,
+    ) -> bool {
+        self.command_sender
+            .send(PluginCommand::SetStatusBarSegment {
+                name,
+                text,
+                command: command.0,
+            })
+            .is_ok()
+    }
+
+    /// Remove a status bar segment set with setStatusBarSegment
+    pub fn remove_status_bar_segment(&self, name: String) -> bool {
+        self.command_sender
+            .send(PluginCommand::RemoveStatusBarSegment { name })
+            .is_ok()
+    }
+
     // === Clipboard ===
 
     pub fn copy_to_clipboard(&self, text: String) {
@@ -891,10 +915,11 @@ impl JsEditorApi {
 
     /// Get the line number (0-indexed) of the primary cursor
     pub fn get_cursor_line(&self) -> u32 {
-        // This would require line counting from the buffer
-        // For now, return 0 - proper implementation needs buffer access
-        // TODO: Add line number tracking to EditorStateSnapshot
-        0
+        self.state_snapshot
+            .read()
+            .ok()
+            .and_then(|s| s.primary_cursor_line)
+            .unwrap_or(0) as u32
     }
 
     /// Get the byte offset of the start of a line (0-indexed line number)
@@ -1049,6 +1074,17 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    /// Replace a range in a buffer with new text as a single undo step
+    pub fn replace_range(&self, buffer_id: u32, start: u32, end: u32, text: String) -> bool {
+        self.command_sender
+            .send(PluginCommand::ReplaceRange {
+                buffer_id: BufferId(buffer_id as usize),
+                range: (start as usize)..(end as usize),
+                text,
+            })
+            .is_ok()
+    }
+
     /// Insert text at cursor position in active buffer
     pub fn insert_at_cursor(&self, text: String) -> bool {
         self.command_sender
@@ -1269,6 +1305,36 @@ impl JsEditorApi {
         std::fs::write(&path, content).is_ok()
     }
 
+    /// Replace byte ranges in a file (async)
+    ///
+    /// Edits the buffer in memory if the file is open (a single undo step),
+    /// otherwise rewrites the file on disk with an atomic save. Binary and
+    /// non-UTF-8 files are rejected. Resolves with the number of edits applied.
+    #[plugin_api(async_promise, js_name = "replaceInFile", ts_return = "number")]
+    #[qjs(rename = "_replaceInFileStart")]
+    pub fn replace_in_file_start(
+        &self,
+        _ctx: rquickjs::Ctx<'_>,
+        path: String,
+        edits: Vec<FileEdit>,
+    ) -> u64 {
+        let id = {
+            let mut id_ref = self.next_request_id.borrow_mut();
+            let id = *id_ref;
+            *id_ref += 1;
+            self.callback_contexts
+                .borrow_mut()
+                .insert(id, self.plugin_name.clone());
+            id
+        };
+        let _ = self.command_sender.send(PluginCommand::ReplaceInFile {
+            path: std::path::PathBuf::from(path),
+            edits,
+            callback_id: JsCallbackId::new(id),
+        });
+        id
+    }
+
     /// Read directory contents (returns array of {name, is_file, is_dir})
     #[plugin_api(ts_return = "DirEntry[]")]
     pub fn read_dir<'js>(
@@ -3544,6 +3610,7 @@ impl QuickJsBackend {
                             globalThis._pendingCallbacks.set(callbackId, { resolve, reject });
                         });
                         return {
+                            get processId() { return callbackId; },
                             get result() { return resultPromise; },
                             then(onFulfilled, onRejected) {
                                 return resultPromise.then(onFulfilled, onRejected);
@@ -3911,6 +3978,46 @@ impl QuickJsBackend {
             .send(PluginCommand::SetStatus { message });
     }
 
+    /// Drop the handlers, actions and context a plugin registered
+    ///
+    /// Returns the names of the actions it owned, sorted, so the editor can
+    /// remove menu items that would run them.
+    pub fn cleanup_plugin(&self, plugin_name: &str) -> Vec<String> {
+        for handlers in self.event_handlers.borrow_mut().values_mut() {
+            handlers.retain(|h| h.plugin_name != plugin_name);
+        }
+        let mut actions = Vec::new();
+        self.registered_actions
+            .borrow_mut()
+            .retain(|action_name, handler| {
+                let owned = handler.plugin_name == plugin_name;
+                if owned {
+                    actions.push(action_name.clone());
+                }
+                !owned
+            });
+        self.callback_contexts
+            .borrow_mut()
+            .retain(|_, name| name != plugin_name);
+        self.plugin_contexts.borrow_mut().remove(plugin_name);
+        actions.sort();
+        actions
+    }
+
+    /// Append a line to the editor's plugin log without touching the status bar
+    pub fn send_plugin_issue(&self, message: String) {
+        let _ = self
+            .command_sender
+            .send(PluginCommand::LogPluginIssue { message });
+    }
+
+    /// Tell the editor a plugin was unloaded, with the actions it owned
+    pub fn send_plugin_unloaded(&self, name: String, actions: Vec<String>) {
+        let _ = self
+            .command_sender
+            .send(PluginCommand::PluginUnloaded { name, actions });
+    }
+
     /// Send a hook-completed sentinel to the editor.
     /// This signals that all commands from the hook have been sent,
     /// allowing the render loop to wait deterministically.
@@ -4154,6 +4261,30 @@ mod tests {
         assert!(backend.has_handlers("test_event"));
     }
 
+    #[test]
+    fn test_cleanup_plugin_drops_handlers_and_actions() {
+        let (mut backend, _rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis.onSave = function() {};
+            globalThis.sayHello = function() {};
+            editor.on("after_file_save", "onSave");
+            editor.registerCommand("Say Hello", "", "sayHello", null);
+        "#,
+                "greeter.js",
+            )
+            .unwrap();
+        assert!(backend.has_handlers("after_file_save"));
+
+        let actions = backend.cleanup_plugin("greeter");
+        assert_eq!(actions, vec!["sayHello".to_string()]);
+        assert!(!backend.has_handlers("after_file_save"));
+        assert!(!backend.plugin_contexts.borrow().contains_key("greeter"));
+    }
+
     // ==================== API Tests ====================
 
     #[test]
@@ -4605,6 +4736,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_api_replace_range() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            editor.replaceRange(0, 4, 9, "world");
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        let cmd = rx.try_recv().unwrap();
+        match cmd {
+            PluginCommand::ReplaceRange { range, text, .. } => {
+                assert_eq!(range, 4..9);
+                assert_eq!(text, "world");
+            }
+            _ => panic!("Expected ReplaceRange, got {:?}", cmd),
+        }
+    }
+
     #[test]
     fn test_api_insert_text() {
         let (mut backend, rx) = create_test_backend();
@@ -5387,6 +5542,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_api_set_status_bar_segment() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            editor.setStatusBarSegment("build", "ok", "run_build");
+            editor.setStatusBarSegment("spell", "en");
+            editor.removeStatusBarSegment("spell");
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        let cmd = rx.try_recv().unwrap();
+        match cmd {
+            PluginCommand::SetStatusBarSegment {
+                name,
+                text,
+                command,
+            } => {
+                assert_eq!(name, "build");
+                assert_eq!(text, "ok");
+                assert_eq!(command.as_deref(), Some("run_build"));
+            }
+            _ => panic!("Expected SetStatusBarSegment, got {:?}", cmd),
+        }
+        match rx.try_recv().unwrap() {
+            PluginCommand::SetStatusBarSegment { command, .. } => assert_eq!(command, None),
+            cmd => panic!("Expected SetStatusBarSegment, got {:?}", cmd),
+        }
+        match rx.try_recv().unwrap() {
+            PluginCommand::RemoveStatusBarSegment { name } => assert_eq!(name, "spell"),
+            cmd => panic!("Expected RemoveStatusBarSegment, got {:?}", cmd),
+        }
+    }
+
     // ==================== Buffer Operations Tests ====================
 
     #[test]
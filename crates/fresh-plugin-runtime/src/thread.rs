@@ -25,6 +25,9 @@ use std::time::Duration;
 // Re-export PluginConfig from fresh-core
 pub use fresh_core::config::PluginConfig;
 
+/// Hooks and actions running longer than this are reported in the plugin log
+const SLOW_PLUGIN_HANDLER: Duration = Duration::from_millis(500);
+
 /// Request messages sent to the plugin thread
 #[derive(Debug)]
 pub enum PluginRequest {
@@ -827,7 +830,9 @@ async fn plugin_thread_loop(
                     }) => {
                         // Start the action without blocking - this allows us to process
                         // ResolveCallback requests that the action may be waiting for.
+                        let action_start = std::time::Instant::now();
                         let result = runtime.borrow_mut().start_action(&action_name);
+                        report_slow_handler(&runtime, "action", &action_name, action_start);
                         let _ = response.send(result);
                         has_pending_work = true; // Action may have started async work
                     }
@@ -867,6 +872,26 @@ async fn plugin_thread_loop(
 /// - No spawn_local calls exist that could create concurrent access to `runtime`
 /// - The runtime Rc<RefCell<>> is never shared with other concurrent tasks
 
+/// Log a hook or action that blocked the plugin thread for too long
+fn report_slow_handler(
+    runtime: &Rc<RefCell<QuickJsBackend>>,
+    kind: &str,
+    name: &str,
+    started: std::time::Instant,
+) {
+    let elapsed = started.elapsed();
+    if elapsed >= SLOW_PLUGIN_HANDLER {
+        let message = format!(
+            "Slow plugin {} '{}': took {} ms",
+            kind,
+            name,
+            elapsed.as_millis()
+        );
+        tracing::warn!("{}", message);
+        runtime.borrow().send_plugin_issue(message);
+    }
+}
+
 /// Run a hook with Rc<RefCell<QuickJsBackend>>
 ///
 /// # Safety (clippy::await_holding_refcell_ref)
@@ -974,6 +999,7 @@ async fn handle_request(
                 // Surface the error to the UI
                 runtime.borrow_mut().send_status(error_msg);
             }
+            report_slow_handler(&runtime, "hook", &hook_name, hook_start);
             // Send sentinel so the main thread can wait deterministically
             // for all commands from this hook to be available.
             runtime.borrow().send_hook_completed(hook_name.clone());
@@ -1287,6 +1313,13 @@ fn unload_plugin_internal(
             .services
             .unregister_commands_by_plugin(name);
 
+        // Drop its event handlers, actions and JS context, and let the editor
+        // remove menu items that ran its actions
+        let actions = runtime.borrow().cleanup_plugin(name);
+        runtime
+            .borrow()
+            .send_plugin_unloaded(name.to_string(), actions);
+
         Ok(())
     } else {
         Err(anyhow!("Plugin '{}' not found", name))
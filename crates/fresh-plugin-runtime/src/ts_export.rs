@@ -19,7 +19,7 @@ use fresh_core::api::{
     BufferSavedDiff, CompositeHunk, CompositeLayoutConfig, CompositePaneStyle,
     CompositeSourceConfig, CreateCompositeBufferOptions, CreateTerminalOptions,
     CreateVirtualBufferInExistingSplitOptions, CreateVirtualBufferInSplitOptions,
-    CreateVirtualBufferOptions, CursorInfo, DirEntry, FormatterPackConfig, JsDiagnostic,
+    CreateVirtualBufferOptions, CursorInfo, DirEntry, FileEdit, FormatterPackConfig, JsDiagnostic,
     JsPosition, JsRange, JsTextPropertyEntry, LanguagePackConfig, LayoutHints, LspServerPackConfig,
     SpawnResult, TerminalResult, TextPropertiesAtCursor, TsHighlightSpan, ViewTokenStyle,
     ViewTokenWire, ViewTokenWireKind, ViewportInfo, VirtualBufferResult,
@@ -86,6 +86,7 @@ fn get_type_decl(type_name: &str) -> Option<String> {
         // Prompt and directory types
         "PromptSuggestion" | "Suggestion" => Some(Suggestion::decl()),
         "DirEntry" => Some(DirEntry::decl()),
+        "FileEdit" => Some(FileEdit::decl()),
 
         // Diagnostic types
         "JsDiagnostic" => Some(JsDiagnostic::decl()),
@@ -362,6 +363,7 @@ mod tests {
             "VirtualBufferResult",
             "PromptSuggestion",
             "DirEntry",
+            "FileEdit",
             "JsDiagnostic",
             "JsRange",
             "JsPosition",
@@ -722,6 +724,8 @@ mod tests {
             "warn",
             "error",
             "setStatus",
+            "setStatusBarSegment",
+            "removeStatusBarSegment",
             "copyToClipboard",
             "setClipboard",
             "registerCommand",
@@ -747,6 +751,7 @@ mod tests {
             "getBufferSavedDiff",
             "insertText",
             "deleteRange",
+            "replaceRange",
             "insertAtCursor",
             "openFile",
             "openFileInSplit",
@@ -765,6 +770,7 @@ mod tests {
             "fileExists",
             "readFile",
             "writeFile",
+            "replaceInFile",
             "readDir",
             "getConfig",
             "getUserConfig",
@@ -6,6 +6,9 @@ set -euo pipefail
 # Usage:
 #   scripts/update-showcases.sh              # run all showcases
 #   scripts/update-showcases.sh multi-cursor # run only matching showcases
+#
+# The tests encode showcase.gif themselves. With SHOWCASE_FORMATS=svg they
+# only write SVG frames, and this script converts them with frames-to-gif.sh.
 
 FILTER="${1:-blog_showcase_}"
 SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
@@ -17,6 +20,12 @@ cd "$ROOT_DIR"
 echo "=== Running showcase tests (filter: $FILTER) ==="
 cargo test --package fresh-editor --test e2e_tests "$FILTER" -- --ignored --nocapture
 
+if [[ "${SHOWCASE_FORMATS:-gif}" != "svg" ]]; then
+    echo ""
+    echo "Done. All showcases updated."
+    exit 0
+fi
+
 echo ""
 echo "=== Generating GIFs ==="
 fail=0